@@ -0,0 +1,254 @@
+//! Tab completion of global names and property accesses in the REPL.
+//!
+//! The completed expression is never evaluated. Instead, the values of the properties are read
+//! from their descriptors, so getters are never called. Completion stops at proxies, since their
+//! traps could have side effects.
+
+use std::collections::BTreeSet;
+
+use boa_engine::{
+    builtins::proxy::Proxy, property::PropertyKey, Context, JsObject, JsString, JsValue,
+};
+
+use crate::helper::KEYWORDS;
+
+/// Completes the identifier or property access that ends `line`.
+///
+/// Returns the position where the completed name starts, and the sorted candidates.
+pub(crate) fn complete(line: &str, context: &mut Context) -> (usize, Vec<String>) {
+    let start = name_start(line);
+    let prefix = &line[start..];
+    if prefix.starts_with(|c: char| c.is_ascii_digit()) {
+        return (start, Vec::new());
+    }
+
+    let mut candidates = BTreeSet::new();
+    match member_access(&line[..start]) {
+        Access::Global => {
+            let global = context.global_object();
+            property_names(&global, prefix, &mut candidates, context);
+            candidates.extend(
+                KEYWORDS
+                    .iter()
+                    .filter(|keyword| keyword.starts_with(prefix))
+                    .map(|keyword| (*keyword).to_owned()),
+            );
+        }
+        Access::Unknown => return (start, Vec::new()),
+        Access::Path(path) => {
+            let Some(object) = resolve_path(&path, context) else {
+                return (start, Vec::new());
+            };
+            property_names(&object, prefix, &mut candidates, context);
+        }
+    }
+
+    (start, candidates.into_iter().collect())
+}
+
+/// Checks if `c` can be part of an identifier.
+fn is_identifier_char(c: char) -> bool {
+    c == '$' || c == '_' || c.is_alphanumeric()
+}
+
+/// Checks if `name` can be written after a dot in a property access.
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(is_identifier_char)
+}
+
+/// Gets the start of the identifier that ends `line`, or `line.len()` if it doesn't end with one.
+fn name_start(line: &str) -> usize {
+    line.char_indices()
+        .rev()
+        .take_while(|(_, c)| is_identifier_char(*c))
+        .last()
+        .map_or(line.len(), |(index, _)| index)
+}
+
+/// The object whose properties complete a name.
+enum Access<'a> {
+    /// The name isn't a property access, so it is completed with the global names.
+    Global,
+    /// The name is a property of an object that isn't reached by a chain of identifiers.
+    Unknown,
+    /// The name is a property of the object reached by following the identifiers.
+    Path(Vec<&'a str>),
+}
+
+/// Checks if the text before the completed name is a property access.
+fn member_access(before: &str) -> Access<'_> {
+    let Some(before) = before.strip_suffix('.') else {
+        return Access::Global;
+    };
+    let mut expression = before.strip_suffix('?').unwrap_or(before);
+
+    let mut path = Vec::new();
+    loop {
+        let start = name_start(expression);
+        let name = &expression[start..];
+        if !is_identifier(name) {
+            return Access::Unknown;
+        }
+        path.push(name);
+
+        let rest = &expression[..start];
+        match rest.strip_suffix('.') {
+            Some(rest) => expression = rest.strip_suffix('?').unwrap_or(rest),
+            None => break,
+        }
+    }
+
+    path.reverse();
+    Access::Path(path)
+}
+
+/// Finds the object reached by following `path` from the global object, without calling any
+/// getters.
+fn resolve_path(path: &[&str], context: &mut Context) -> Option<JsObject> {
+    let mut object = context.global_object();
+    for name in path {
+        let key = PropertyKey::from(JsString::from(*name));
+        let value = lookup(&object, &key, context)?;
+        object = value.to_object(context).ok()?;
+    }
+    Some(object)
+}
+
+/// Gets the value of the data property `key` of `object` or its prototypes.
+fn lookup(object: &JsObject, key: &PropertyKey, context: &mut Context) -> Option<JsValue> {
+    let mut current = Some(object.clone());
+    while let Some(object) = current {
+        if object.is::<Proxy>() {
+            return None;
+        }
+        if let Some(property) = object.get_own_property(key.clone(), context).ok()? {
+            return property.value().cloned();
+        }
+        current = object.prototype();
+    }
+    None
+}
+
+/// Adds the names of the properties of `object` and its prototypes that start with `prefix`.
+fn property_names(
+    object: &JsObject,
+    prefix: &str,
+    names: &mut BTreeSet<String>,
+    context: &mut Context,
+) {
+    let mut current = Some(object.clone());
+    while let Some(object) = current {
+        if object.is::<Proxy>() {
+            return;
+        }
+        let Ok(keys) = object.own_property_keys(context) else {
+            return;
+        };
+        names.extend(keys.into_iter().filter_map(|key| {
+            match key {
+                PropertyKey::String(name) => name
+                    .to_std_string()
+                    .ok()
+                    .filter(|name| name.starts_with(prefix) && is_identifier(name)),
+                PropertyKey::Index(_) | PropertyKey::Symbol(_) => None,
+            }
+        }));
+        current = object.prototype();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::complete;
+    use boa_engine::{Context, JsValue, Source};
+
+    fn context() -> Context {
+        let mut context = Context::default();
+        context
+            .eval(Source::from_bytes(
+                r"
+                var calls = 0;
+                var config = {
+                    name: 'boa',
+                    nested: { depth: 1, deeper: { value: true } },
+                    get lazy() { calls++; return { hidden: 1 }; },
+                    'not an identifier': 0,
+                };
+                var proxy = new Proxy({ target: 1 }, {
+                    ownKeys() { calls++; return []; },
+                    getOwnPropertyDescriptor() { calls++; },
+                });
+                ",
+            ))
+            .expect("the setup script must not fail");
+        context
+    }
+
+    fn calls(context: &mut Context) -> JsValue {
+        context
+            .eval(Source::from_bytes("calls"))
+            .expect("calls must be defined")
+    }
+
+    #[test]
+    fn builtin_members() {
+        let context = &mut context();
+        let (start, candidates) = complete("JSON.", context);
+        assert_eq!(start, 5);
+        for name in ["isRawJSON", "parse", "rawJSON", "stringify"] {
+            assert!(candidates.iter().any(|c| c == name), "{name}");
+        }
+
+        let (start, candidates) = complete("const text = JSON.str", context);
+        assert_eq!(start, 18);
+        assert_eq!(candidates, ["stringify"]);
+    }
+
+    #[test]
+    fn property_chains() {
+        let context = &mut context();
+        let (_, candidates) = complete("config.nested.de", context);
+        assert_eq!(candidates, ["deeper", "depth"]);
+
+        let (_, candidates) = complete("config?.nested.deeper.va", context);
+        assert_eq!(candidates, ["value", "valueOf"]);
+
+        // Own and inherited properties, but not the ones that can't follow a dot.
+        let (_, candidates) = complete("config.", context);
+        assert!(candidates.iter().any(|c| c == "name"));
+        assert!(candidates.iter().any(|c| c == "hasOwnProperty"));
+        assert!(!candidates.iter().any(|c| c.contains(' ')));
+
+        // Primitives complete with the properties of their prototype.
+        let (_, candidates) = complete("config.name.toUpper", context);
+        assert_eq!(candidates, ["toUpperCase"]);
+    }
+
+    #[test]
+    fn globals_and_keywords() {
+        let context = &mut context();
+        let (start, candidates) = complete("let x = con", context);
+        assert_eq!(start, 8);
+        // The global object inherits from `Object.prototype`.
+        assert_eq!(candidates, ["config", "const", "constructor", "continue"]);
+
+        let (_, candidates) = complete("Obj", context);
+        assert_eq!(candidates, ["Object"]);
+    }
+
+    #[test]
+    fn no_side_effects() {
+        let context = &mut context();
+        assert!(complete("config.lazy.", context).1.is_empty());
+        assert!(complete("proxy.", context).1.is_empty());
+        assert!(complete("proxy.target.", context).1.is_empty());
+        assert_eq!(calls(context), JsValue::new(0));
+
+        // Calls and other expressions are never evaluated.
+        assert!(complete("JSON.parse('{}').", context).1.is_empty());
+        assert!(complete("undefinedName.", context).1.is_empty());
+        assert!(complete("1.", context).1.is_empty());
+    }
+}
@@ -21,6 +21,21 @@ fn set_constant_folding(_: &JsValue, args: &[JsValue], context: &mut Context) ->
     Ok(JsValue::undefined())
 }
 
+fn get_peephole(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    Ok(context
+        .optimizer_options()
+        .contains(OptimizerOptions::PEEPHOLE)
+        .into())
+}
+
+fn set_peephole(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0).to_boolean();
+    let mut options = context.optimizer_options();
+    options.set(OptimizerOptions::PEEPHOLE, value);
+    context.set_optimizer_options(options);
+    Ok(JsValue::undefined())
+}
+
 fn get_statistics(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
     Ok(context
         .optimizer_options()
@@ -52,6 +67,17 @@ pub(super) fn create_object(context: &mut Context) -> JsObject {
     .length(1)
     .build();
 
+    let get_peephole =
+        FunctionObjectBuilder::new(context.realm(), NativeFunction::from_fn_ptr(get_peephole))
+            .name("get peephole")
+            .length(0)
+            .build();
+    let set_peephole =
+        FunctionObjectBuilder::new(context.realm(), NativeFunction::from_fn_ptr(set_peephole))
+            .name("set peephole")
+            .length(1)
+            .build();
+
     let get_statistics =
         FunctionObjectBuilder::new(context.realm(), NativeFunction::from_fn_ptr(get_statistics))
             .name("get statistics")
@@ -69,6 +95,12 @@ pub(super) fn create_object(context: &mut Context) -> JsObject {
             Some(set_constant_folding),
             Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
         )
+        .accessor(
+            js_string!("peephole"),
+            Some(get_peephole),
+            Some(set_peephole),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .accessor(
             js_string!("statistics"),
             Some(get_statistics),
@@ -0,0 +1,97 @@
+use boa_engine::{
+    vm::{DebugAction, DebugHandler, PauseReason, PausedFrame},
+    Source,
+};
+use colored::Colorize;
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "\
+Commands:
+  c, continue       Run until the next breakpoint or `debugger` statement
+  s, step           Pause at the next statement, entering called functions
+  n, next           Pause at the next statement of the current function
+  o, out            Pause at the next statement of the calling function
+  p, print NAME     Print the value of the binding NAME
+  set NAME EXPR     Set the binding NAME to the value of the global expression EXPR
+  h, help           Show this help";
+
+/// A debugger that reads its commands from `stdin` when the execution pauses.
+#[derive(Debug, Default)]
+pub(crate) struct StdinDebugger;
+
+impl DebugHandler for StdinDebugger {
+    fn on_pause(&mut self, frame: &mut PausedFrame<'_>) -> DebugAction {
+        let reason = match frame.reason() {
+            PauseReason::Breakpoint => "breakpoint",
+            PauseReason::DebuggerStatement => "debugger statement",
+            PauseReason::Step => "step",
+        };
+        let location = match (frame.source_path(), frame.line()) {
+            (Some(path), Some(line)) => format!("{}:{line}", path.display()),
+            (None, Some(line)) => format!("line {line}"),
+            _ => "unknown location".to_owned(),
+        };
+        println!(
+            "{}",
+            format!(
+                "Paused at {location} in {} ({reason})",
+                frame.function_name().to_std_string_escaped()
+            )
+            .yellow()
+        );
+
+        let stdin = io::stdin();
+        let mut line = String::new();
+        loop {
+            print!("(debug) ");
+            io::stdout().flush().ok();
+
+            line.clear();
+            match stdin.lock().read_line(&mut line) {
+                // Nothing else can be read, so let the execution finish.
+                Ok(0) | Err(_) => return DebugAction::Continue,
+                Ok(_) => {}
+            }
+
+            let (command, rest) = line
+                .trim()
+                .split_once(' ')
+                .map_or((line.trim(), ""), |(command, rest)| (command, rest.trim()));
+            match command {
+                "c" | "continue" => return DebugAction::Continue,
+                "s" | "step" => return DebugAction::StepInto,
+                "n" | "next" => return DebugAction::StepOver,
+                "o" | "out" => return DebugAction::StepOut,
+                "p" | "print" => match frame.local(rest) {
+                    Ok(Some(value)) => println!("{}", value.display()),
+                    Ok(None) => eprintln!("{}", format!("no binding named `{rest}`").red()),
+                    Err(err) => eprintln!("{}", format!("Uncaught {err}").red()),
+                },
+                "set" => {
+                    let Some((name, expression)) = rest.split_once(' ') else {
+                        eprintln!("{}", "usage: set NAME EXPR".red());
+                        continue;
+                    };
+                    let value = match frame.context().eval(Source::from_bytes(expression.trim())) {
+                        Ok(value) => value,
+                        Err(err) => {
+                            eprintln!("{}", format!("Uncaught {err}").red());
+                            continue;
+                        }
+                    };
+                    match frame.set_local(name, value) {
+                        Ok(true) => {}
+                        Ok(false) => eprintln!("{}", format!("no binding named `{name}`").red()),
+                        Err(err) => eprintln!("{}", format!("Uncaught {err}").red()),
+                    }
+                }
+                "h" | "help" => println!("{HELP}"),
+                "" => {}
+                _ => eprintln!(
+                    "{}",
+                    format!("unknown command `{command}`, try `help`").red()
+                ),
+            }
+        }
+    }
+}
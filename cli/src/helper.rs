@@ -1,13 +1,20 @@
-use colored::{Color, Colorize};
+use crate::completion;
+use boa_ast::{Keyword, Position, Punctuator, Span};
+use boa_engine::Context;
+use boa_interner::Interner;
+use boa_parser::lexer::{InputElement, Lexer, Token, TokenKind};
+use colored::{Color, ColoredString, Colorize};
 use phf::{phf_set, Set};
-use regex::{Captures, Regex, Replacer};
 use rustyline::{
-    error::ReadlineError,
-    highlight::Highlighter,
-    validate::{MatchingBracketValidator, ValidationContext, ValidationResult, Validator},
-    Completer, Helper, Hinter,
+    completion::Completer, highlight::Highlighter, validate::Validator, Helper, Hinter,
+};
+use std::{
+    borrow::Cow::{self, Borrowed, Owned},
+    cell::RefCell,
+    fmt::Write,
+    ops::Range,
+    rc::Rc,
 };
-use std::borrow::Cow::{self, Borrowed};
 
 const STRING_COLOR: Color = Color::Green;
 const KEYWORD_COLOR: Color = Color::Yellow;
@@ -32,43 +39,56 @@ const IDENTIFIER_COLOR: Color = Color::TrueColor {
     g: 160,
     b: 214,
 };
+const COMMENT_COLOR: Color = Color::TrueColor {
+    r: 120,
+    g: 120,
+    b: 120,
+};
+const BRACKET_COLOR: Color = Color::Blue;
 
 const READLINE_COLOR: Color = Color::Cyan;
 
 #[allow(clippy::upper_case_acronyms, clippy::redundant_pub_crate)]
-#[derive(Completer, Helper, Hinter)]
+#[derive(Helper, Hinter)]
 pub(crate) struct RLHelper {
-    highlighter: LineHighlighter,
-    validator: MatchingBracketValidator,
-    colored_prompt: String,
+    context: Rc<RefCell<Context>>,
+    highlight: bool,
 }
 
 impl RLHelper {
-    pub(crate) fn new(prompt: &str) -> Self {
-        Self {
-            highlighter: LineHighlighter::new(),
-            validator: MatchingBracketValidator::new(),
-            colored_prompt: prompt.color(READLINE_COLOR).bold().to_string(),
-        }
+    /// Creates the helper of the REPL, which colors the input if `highlight` is `true`.
+    pub(crate) fn new(context: Rc<RefCell<Context>>, highlight: bool) -> Self {
+        Self { context, highlight }
     }
 }
 
-impl Validator for RLHelper {
-    fn validate(
-        &self,
-        context: &mut ValidationContext<'_>,
-    ) -> Result<ValidationResult, ReadlineError> {
-        self.validator.validate(context)
-    }
+impl Completer for RLHelper {
+    type Candidate = String;
 
-    fn validate_while_typing(&self) -> bool {
-        self.validator.validate_while_typing()
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let Ok(mut context) = self.context.try_borrow_mut() else {
+            return Ok((pos, Vec::new()));
+        };
+        Ok(completion::complete(&line[..pos], &mut context))
     }
 }
 
+// Every line is accepted, since incomplete inputs are buffered by the REPL, which switches to a
+// continuation prompt.
+impl Validator for RLHelper {}
+
 impl Highlighter for RLHelper {
     fn highlight<'l>(&self, line: &'l str, pos: usize) -> Cow<'l, str> {
-        self.highlighter.highlight(line, pos)
+        if self.highlight {
+            Owned(highlight(line, Some(pos)))
+        } else {
+            Borrowed(line)
+        }
     }
 
     // Must match signature of Highlighter::highlight_prompt, can't elide lifetimes.
@@ -78,8 +98,8 @@ impl Highlighter for RLHelper {
         prompt: &'p str,
         default: bool,
     ) -> Cow<'b, str> {
-        if default {
-            Borrowed(&self.colored_prompt)
+        if default && self.highlight {
+            Owned(prompt.color(READLINE_COLOR).bold().to_string())
         } else {
             Borrowed(prompt)
         }
@@ -94,15 +114,21 @@ impl Highlighter for RLHelper {
         candidate: &'c str,
         _completion: rustyline::CompletionType,
     ) -> Cow<'c, str> {
-        self.highlighter.highlight(candidate, 0)
+        if self.highlight {
+            Owned(highlight(candidate, None))
+        } else {
+            Borrowed(candidate)
+        }
     }
 
+    // Moving the cursor can change the highlighted brackets, so the line is always highlighted
+    // again.
     fn highlight_char(&self, line: &str, _: usize, _: bool) -> bool {
-        !line.is_empty()
+        self.highlight && !line.is_empty()
     }
 }
 
-static KEYWORDS: Set<&'static str> = phf_set! {
+pub(crate) static KEYWORDS: Set<&'static str> = phf_set! {
     "break",
     "case",
     "catch",
@@ -139,73 +165,362 @@ static KEYWORDS: Set<&'static str> = phf_set! {
     "let",
 };
 
-struct LineHighlighter {
-    regex: Regex,
+/// The kind of a highlighted part of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Keyword,
+    Literal,
+    Undefined,
+    Identifier,
+    Number,
+    String,
+    Operator,
+    Comment,
+    Bracket,
+    MatchingBracket,
 }
 
-impl LineHighlighter {
-    fn new() -> Self {
-        // Precompiles the regex to avoid creating it again after every highlight
-        let regex = Regex::new(
-            r#"(?x)
-            (?P<identifier>\b[$_\p{ID_Start}][$_\p{ID_Continue}\u{200C}\u{200D}]*\b) |
-            (?P<string_double_quote>"([^"\\]|\\.)*") |
-            (?P<string_single_quote>'([^'\\]|\\.)*') |
-            (?P<template_literal>`([^`\\]|\\.)*`) |
-            (?P<op>[+\-/*%~^!&|=<>;:]) |
-            (?P<number>0[bB][01](_?[01])*n?|0[oO][0-7](_?[0-7])*n?|0[xX][0-9a-fA-F](_?[0-9a-fA-F])*n?|(([0-9](_?[0-9])*\.([0-9](_?[0-9])*)?)|(([0-9](_?[0-9])*)?\.[0-9](_?[0-9])*)|([0-9](_?[0-9])*))([eE][+-]?[0-9](_?[0-9])*)?n?)"#,
-        ).expect("could not compile regular expression");
-
-        Self { regex }
+impl Class {
+    fn paint(self, text: &str) -> ColoredString {
+        match self {
+            Self::Keyword => text.color(KEYWORD_COLOR).bold(),
+            Self::Literal => text.color(PROPERTY_COLOR),
+            Self::Undefined => text.color(UNDEFINED_COLOR),
+            Self::Identifier => text.color(IDENTIFIER_COLOR),
+            Self::Number => text.color(NUMBER_COLOR),
+            Self::String => text.color(STRING_COLOR),
+            Self::Operator => text.color(OPERATOR_COLOR),
+            Self::Comment => text.color(COMMENT_COLOR).italic(),
+            Self::Bracket => text.normal(),
+            Self::MatchingBracket => text.color(BRACKET_COLOR).bold(),
+        }
     }
 }
 
-impl Highlighter for LineHighlighter {
-    fn highlight<'l>(&self, line: &'l str, _: usize) -> Cow<'l, str> {
-        use std::fmt::Write;
-
-        struct Colorizer;
-
-        impl Replacer for Colorizer {
-            // Changing to map_or_else moves the handling of "identifier" after all other kinds,
-            // which reads worse than this version.
-            #[allow(clippy::option_if_let_else)]
-            fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut String) {
-                let colored = if let Some(cap) = caps.name("identifier") {
-                    let cap = cap.as_str();
-
-                    let colored = match cap {
-                        "true" | "false" | "null" | "Infinity" | "globalThis" => {
-                            cap.color(PROPERTY_COLOR)
-                        }
-                        "undefined" => cap.color(UNDEFINED_COLOR),
-                        identifier if KEYWORDS.contains(identifier) => {
-                            cap.color(KEYWORD_COLOR).bold()
-                        }
-                        _ => cap.color(IDENTIFIER_COLOR),
-                    };
-
-                    Some(colored)
-                } else if let Some(cap) = caps
-                    .name("string_double_quote")
-                    .or_else(|| caps.name("string_single_quote"))
-                    .or_else(|| caps.name("template_literal"))
-                {
-                    Some(cap.as_str().color(STRING_COLOR))
-                } else if let Some(cap) = caps.name("op") {
-                    Some(cap.as_str().color(OPERATOR_COLOR))
-                } else {
-                    caps.name("number")
-                        .map(|cap| cap.as_str().color(NUMBER_COLOR))
-                };
+/// Colors the JavaScript code in `line`.
+///
+/// If `cursor` is next to a bracket, the bracket and the one that matches it are highlighted.
+pub(crate) fn highlight(line: &str, cursor: Option<usize>) -> String {
+    let mut highlighted = String::with_capacity(line.len());
+    let mut end = 0;
+    for (range, class) in classify(line, cursor) {
+        highlighted.push_str(&line[end..range.start]);
+        write!(highlighted, "{}", class.paint(&line[range.clone()]))
+            .expect("could not append data to the highlighted line");
+        end = range.end;
+    }
+    highlighted.push_str(&line[end..]);
+    highlighted
+}
 
-                if let Some(colored) = colored {
-                    write!(dst, "{colored}").expect("could not append data to dst");
-                } else {
-                    dst.push_str(&caps[0]);
+/// Splits `line` into the parts to highlight, using the lexer of the parser.
+///
+/// Lexing stops at the first error, so the rest of an invalid input is not highlighted.
+fn classify(line: &str, cursor: Option<usize>) -> Vec<(Range<usize>, Class)> {
+    let offsets = Offsets::new(line);
+    let mut interner = Interner::default();
+    let mut lexer = Lexer::from(line.as_bytes());
+
+    let mut parts = Vec::new();
+    // The depths of the blocks where the substitutions of template literals were opened.
+    let mut substitutions = Vec::new();
+    let mut depth: usize = 0;
+    let mut open_brackets = Vec::new();
+    let mut bracket_pairs = Vec::new();
+
+    while let Ok(Some(mut token)) = lexer.next_no_skip(&mut interner) {
+        if token.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock)
+            && substitutions.last() == Some(&depth)
+        {
+            substitutions.pop();
+            let Ok(rest) = lexer.lex_template(token.span().end(), &mut interner) else {
+                break;
+            };
+            let span = Span::new(token.span().start(), rest.span().end());
+            token = Token::new(rest.kind().clone(), span);
+        }
+
+        let range = offsets.get(token.span().start())..offsets.get(token.span().end());
+        if let Some(goal) = next_goal(token.kind()) {
+            lexer.set_goal(goal);
+        }
+        let class = match token.kind() {
+            TokenKind::Keyword(_) => Class::Keyword,
+            TokenKind::BooleanLiteral(_) | TokenKind::NullLiteral(_) => Class::Literal,
+            TokenKind::IdentifierName(_) => match &line[range.clone()] {
+                "undefined" => Class::Undefined,
+                "Infinity" | "NaN" | "globalThis" => Class::Literal,
+                name if KEYWORDS.contains(name) => Class::Keyword,
+                _ => Class::Identifier,
+            },
+            TokenKind::PrivateIdentifier(_) => Class::Identifier,
+            TokenKind::NumericLiteral(_) => Class::Number,
+            TokenKind::StringLiteral(_) | TokenKind::RegularExpressionLiteral(_, _) => {
+                Class::String
+            }
+            TokenKind::TemplateMiddle(_) => {
+                substitutions.push(depth);
+                Class::String
+            }
+            TokenKind::TemplateNoSubstitution(_) => Class::String,
+            TokenKind::Comment => Class::Comment,
+            // Block comments that span multiple lines are line terminators.
+            TokenKind::LineTerminator if line[range.clone()].starts_with("/*") => Class::Comment,
+            TokenKind::Punctuator(
+                punctuator @ (Punctuator::OpenParen
+                | Punctuator::OpenBracket
+                | Punctuator::OpenBlock),
+            ) => {
+                if *punctuator == Punctuator::OpenBlock {
+                    depth += 1;
                 }
+                open_brackets.push((*punctuator, parts.len()));
+                Class::Bracket
+            }
+            TokenKind::Punctuator(
+                punctuator @ (Punctuator::CloseParen
+                | Punctuator::CloseBracket
+                | Punctuator::CloseBlock),
+            ) => {
+                if *punctuator == Punctuator::CloseBlock {
+                    depth = depth.saturating_sub(1);
+                }
+                let opening = match punctuator {
+                    Punctuator::CloseParen => Punctuator::OpenParen,
+                    Punctuator::CloseBracket => Punctuator::OpenBracket,
+                    _ => Punctuator::OpenBlock,
+                };
+                if let Some((open, index)) = open_brackets.pop() {
+                    if open == opening {
+                        bracket_pairs.push((index, parts.len()));
+                    }
+                }
+                Class::Bracket
+            }
+            TokenKind::Punctuator(Punctuator::Comma | Punctuator::Dot | Punctuator::Spread)
+            | TokenKind::LineTerminator
+            | TokenKind::EOF => continue,
+            TokenKind::Punctuator(_) => Class::Operator,
+        };
+        parts.push((range, class));
+    }
+
+    if let Some(cursor) = cursor {
+        let bracket = |range: &Range<usize>, class: Class| {
+            class == Class::Bracket && (range.end == cursor || range.start == cursor)
+        };
+        // The bracket before the cursor takes precedence over the one after it.
+        let at_cursor = parts
+            .iter()
+            .rposition(|(range, class)| bracket(range, *class) && range.end == cursor)
+            .or_else(|| {
+                parts
+                    .iter()
+                    .position(|(range, class)| bracket(range, *class))
+            });
+        let pair = at_cursor.and_then(|index| {
+            bracket_pairs
+                .iter()
+                .find(|(open, close)| *open == index || *close == index)
+        });
+        if let Some(&(open, close)) = pair {
+            parts[open].1 = Class::MatchingBracket;
+            parts[close].1 = Class::MatchingBracket;
+        }
+    }
+
+    parts
+}
+
+/// Gets the goal symbol to lex the token after `kind`, which decides if a `/` starts a regular
+/// expression, or `None` if it doesn't change.
+fn next_goal(kind: &TokenKind) -> Option<InputElement> {
+    match kind {
+        TokenKind::Comment | TokenKind::LineTerminator => None,
+        TokenKind::IdentifierName(_)
+        | TokenKind::PrivateIdentifier(_)
+        | TokenKind::BooleanLiteral(_)
+        | TokenKind::NullLiteral(_)
+        | TokenKind::NumericLiteral(_)
+        | TokenKind::StringLiteral(_)
+        | TokenKind::TemplateNoSubstitution(_)
+        | TokenKind::RegularExpressionLiteral(_, _)
+        | TokenKind::Keyword((Keyword::This | Keyword::Super, _))
+        | TokenKind::Punctuator(
+            Punctuator::CloseParen | Punctuator::CloseBracket | Punctuator::Inc | Punctuator::Dec,
+        ) => Some(InputElement::Div),
+        _ => Some(InputElement::RegExp),
+    }
+}
+
+/// Converts the positions of the lexer to byte offsets in the input.
+struct Offsets<'a> {
+    input: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> Offsets<'a> {
+    fn new(input: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        let mut chars = input.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            // A `\r\n` sequence is a single line terminator for the lexer.
+            let terminator = match c {
+                '\r' => !matches!(chars.peek(), Some((_, '\n'))),
+                '\n' | '\u{2028}' | '\u{2029}' => true,
+                _ => false,
+            };
+            if terminator {
+                line_starts.push(index + c.len_utf8());
             }
         }
-        self.regex.replace_all(line, Colorizer)
+
+        Self { input, line_starts }
+    }
+
+    fn get(&self, position: Position) -> usize {
+        let line = position.line_number() as usize - 1;
+        let Some(&start) = self.line_starts.get(line) else {
+            return self.input.len();
+        };
+        // Columns count code points, starting at 1.
+        self.input[start..]
+            .char_indices()
+            .nth(position.column_number() as usize - 1)
+            .map_or(self.input.len(), |(index, _)| start + index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify, Class};
+
+    fn classes(line: &str, cursor: Option<usize>) -> Vec<(&str, Class)> {
+        classify(line, cursor)
+            .into_iter()
+            .map(|(range, class)| (&line[range], class))
+            .collect()
+    }
+
+    #[test]
+    fn tokens() {
+        assert_eq!(
+            classes("let x = 0x1f + 'a'; // done", None),
+            [
+                ("let", Class::Keyword),
+                ("x", Class::Identifier),
+                ("=", Class::Operator),
+                ("0x1f", Class::Number),
+                ("+", Class::Operator),
+                ("'a'", Class::String),
+                (";", Class::Operator),
+                ("// done", Class::Comment),
+            ]
+        );
+        assert_eq!(
+            classes("if (undefined === null) /* ü */ return true", None),
+            [
+                ("if", Class::Keyword),
+                ("(", Class::Bracket),
+                ("undefined", Class::Undefined),
+                ("===", Class::Operator),
+                ("null", Class::Literal),
+                (")", Class::Bracket),
+                ("/* ü */", Class::Comment),
+                ("return", Class::Keyword),
+                ("true", Class::Literal),
+            ]
+        );
+    }
+
+    #[test]
+    fn division_and_regular_expressions() {
+        assert_eq!(
+            classes("a / 2 /b/g", None),
+            [
+                ("a", Class::Identifier),
+                ("/", Class::Operator),
+                ("2", Class::Number),
+                ("/", Class::Operator),
+                ("b", Class::Identifier),
+                ("/", Class::Operator),
+                ("g", Class::Identifier),
+            ]
+        );
+        assert_eq!(
+            classes("x = /a+/g.test(y)", None),
+            [
+                ("x", Class::Identifier),
+                ("=", Class::Operator),
+                ("/a+/g", Class::String),
+                ("test", Class::Identifier),
+                ("(", Class::Bracket),
+                ("y", Class::Identifier),
+                (")", Class::Bracket),
+            ]
+        );
+        assert_eq!(
+            classes("/* a\nb */ 1", None),
+            [("/* a\nb */", Class::Comment), ("1", Class::Number)]
+        );
+    }
+
+    #[test]
+    fn template_substitutions() {
+        assert_eq!(
+            classes("`a${ {b: 1}.b }c${d}`", None),
+            [
+                ("`a${", Class::String),
+                ("{", Class::Bracket),
+                ("b", Class::Identifier),
+                (":", Class::Operator),
+                ("1", Class::Number),
+                ("}", Class::Bracket),
+                ("b", Class::Identifier),
+                ("}c${", Class::String),
+                ("d", Class::Identifier),
+                ("}`", Class::String),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        // Lexer errors end the highlighting, without panicking.
+        assert_eq!(
+            classes("x = 'unterminated", None),
+            [("x", Class::Identifier), ("=", Class::Operator)]
+        );
+        assert_eq!(classes("a @ b", None), [("a", Class::Identifier)]);
+        assert_eq!(
+            classes("`${x", None),
+            [("`${", Class::String), ("x", Class::Identifier)]
+        );
+        assert_eq!(super::highlight("/* open", Some(3)), "/* open");
+    }
+
+    #[test]
+    fn matching_brackets() {
+        let line = "f([1, (2)], {})";
+        let matching = |cursor| {
+            classify(line, Some(cursor))
+                .into_iter()
+                .filter(|(_, class)| *class == Class::MatchingBracket)
+                .map(|(range, _)| range.start)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(matching(0).is_empty());
+        assert_eq!(matching(1), [1, 14]);
+        assert_eq!(matching(2), [1, 14]);
+        assert_eq!(matching(3), [2, 9]);
+        assert_eq!(matching(7), [6, 8]);
+        assert_eq!(matching(9), [6, 8]);
+        assert_eq!(matching(13), [12, 13]);
+
+        // Unbalanced brackets are not matched.
+        assert!(classify("(]", Some(1))
+            .iter()
+            .all(|(_, class)| *class == Class::Bracket));
     }
 }
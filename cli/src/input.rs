@@ -0,0 +1,116 @@
+//! Buffering of the lines typed in the REPL, to allow entering code that spans multiple lines.
+
+use boa_engine::Source;
+use boa_interner::Interner;
+use boa_parser::Parser;
+
+/// The prompt shown when the REPL waits for a new input.
+pub(crate) const PROMPT: &str = ">> ";
+
+/// The prompt shown when the REPL waits for the rest of an incomplete input.
+pub(crate) const CONTINUATION_PROMPT: &str = ".. ";
+
+/// Accumulates the lines typed in the REPL until they form a complete script.
+#[derive(Debug, Default)]
+pub(crate) struct InputBuffer {
+    buffer: String,
+}
+
+impl InputBuffer {
+    /// Adds a line to the input, returning the whole input if it is complete.
+    ///
+    /// An input is complete if it parses, or if it has a syntax error that more lines couldn't
+    /// fix, which is reported when evaluating it.
+    pub(crate) fn push_line(&mut self, line: &str) -> Option<String> {
+        if !self.buffer.is_empty() {
+            self.buffer.push('\n');
+        }
+        self.buffer.push_str(line);
+
+        if Self::is_incomplete(&self.buffer) {
+            return None;
+        }
+        Some(std::mem::take(&mut self.buffer))
+    }
+
+    /// Discards the lines of an incomplete input.
+    pub(crate) fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Returns `true` if some lines of an incomplete input were buffered.
+    pub(crate) fn is_pending(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+
+    /// Gets the prompt for the next line.
+    pub(crate) fn prompt(&self) -> &'static str {
+        if self.is_pending() {
+            CONTINUATION_PROMPT
+        } else {
+            PROMPT
+        }
+    }
+
+    fn is_incomplete(input: &str) -> bool {
+        Parser::new(Source::from_bytes(input))
+            .parse_script(&mut Interner::default())
+            .is_err_and(|err| err.is_abrupt_end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputBuffer;
+
+    #[test]
+    fn single_line() {
+        let mut input = InputBuffer::default();
+        assert_eq!(input.push_line("1 + 2").as_deref(), Some("1 + 2"));
+        assert!(!input.is_pending());
+
+        // Syntax errors that can't be fixed by more lines are returned to be reported.
+        assert_eq!(input.push_line("1 +* 2").as_deref(), Some("1 +* 2"));
+        assert!(!input.is_pending());
+    }
+
+    #[test]
+    fn multiple_lines() {
+        let mut input = InputBuffer::default();
+        assert_eq!(input.push_line("function add(a, b) {"), None);
+        assert_eq!(input.prompt(), super::CONTINUATION_PROMPT);
+        assert_eq!(input.push_line("  return a + b;"), None);
+        assert_eq!(
+            input.push_line("}").as_deref(),
+            Some("function add(a, b) {\n  return a + b;\n}")
+        );
+        assert_eq!(input.prompt(), super::PROMPT);
+
+        assert_eq!(input.push_line("const point = {"), None);
+        assert_eq!(input.push_line("  x: `multi"), None);
+        assert_eq!(input.push_line("line`,"), None);
+        assert_eq!(
+            input.push_line("};").as_deref(),
+            Some("const point = {\n  x: `multi\nline`,\n};")
+        );
+    }
+
+    #[test]
+    fn invalid_continuation() {
+        let mut input = InputBuffer::default();
+        assert_eq!(input.push_line("let list = ["), None);
+        assert_eq!(
+            input.push_line("1, 2 }").as_deref(),
+            Some("let list = [\n1, 2 }")
+        );
+    }
+
+    #[test]
+    fn clear() {
+        let mut input = InputBuffer::default();
+        assert_eq!(input.push_line("if (true) {"), None);
+        input.clear();
+        assert!(!input.is_pending());
+        assert_eq!(input.push_line("1").as_deref(), Some("1"));
+    }
+}
@@ -0,0 +1,274 @@
+//! Hard limits on the time, memory and call stack used by the evaluated files, to run untrusted
+//! code.
+//!
+//! When a limit is exceeded, the CLI exits with a one-line reason and an exit code specific to
+//! the limit, instead of reporting the error like any other uncaught exception.
+
+use std::{
+    fmt, panic, process,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use boa_engine::{Context, JsError, JsNativeErrorKind};
+use boa_gc::{AllocationAction, AllocationHook, GcStats};
+
+/// The time given to the script to observe the interruption after a timeout, before exiting
+/// anyway. Scripts that are blocked, e.g. waiting for a timer, never observe it.
+const TIMEOUT_GRACE_PERIOD: Duration = Duration::from_millis(500);
+
+/// A limit that was exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Exceeded {
+    /// The execution took longer than the given time.
+    Timeout(Duration),
+    /// The memory tracked by the garbage collector reached the given number of bytes.
+    Memory(usize),
+    /// The recursion limit was reached.
+    Stack,
+}
+
+impl Exceeded {
+    /// Gets the exit code of the CLI when this limit is exceeded.
+    pub(crate) const fn exit_code(self) -> i32 {
+        match self {
+            Self::Timeout(_) => 3,
+            Self::Memory(_) => 4,
+            Self::Stack => 5,
+        }
+    }
+
+    /// Prints the reason and exits the process.
+    pub(crate) fn exit(self) -> ! {
+        eprintln!("{self}");
+        process::exit(self.exit_code())
+    }
+}
+
+impl fmt::Display for Exceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Timeout(timeout) => write!(f, "Execution timed out after {timeout:?}"),
+            Self::Memory(bytes) => write!(f, "Memory limit of {} exceeded", format_size(*bytes)),
+            Self::Stack => write!(f, "Maximum call stack size exceeded"),
+        }
+    }
+}
+
+/// The limits enforced while evaluating the files.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ResourceLimits {
+    timeout: Option<(Duration, Arc<AtomicBool>)>,
+    memory: Option<(usize, Arc<AtomicBool>)>,
+    stack: bool,
+}
+
+impl ResourceLimits {
+    /// Limits the memory tracked by the garbage collector of the current thread, which includes
+    /// the builtin objects.
+    pub(crate) fn set_max_memory(&mut self, bytes: usize) {
+        let exceeded = Arc::new(AtomicBool::new(false));
+        boa_gc::set_allocation_hook(Some(Rc::new(MemoryLimit {
+            bytes,
+            exceeded: exceeded.clone(),
+        })));
+        self.memory = Some((bytes, exceeded));
+    }
+
+    /// Treats the uncaught errors thrown when reaching the recursion limit as an exceeded limit.
+    pub(crate) fn set_max_stack(&mut self) {
+        self.stack = true;
+    }
+
+    /// Starts the countdown to interrupt `context` after `timeout`.
+    ///
+    /// If the context doesn't stop shortly after the interruption, the process exits anyway.
+    pub(crate) fn start_timeout(&mut self, timeout: Duration, context: &Context) {
+        let expired = Arc::new(AtomicBool::new(false));
+        let handle = context.interrupt_handle();
+        thread::spawn({
+            let expired = expired.clone();
+            move || {
+                thread::sleep(timeout);
+                expired.store(true, Ordering::Relaxed);
+                handle.interrupt();
+
+                thread::sleep(TIMEOUT_GRACE_PERIOD);
+                Exceeded::Timeout(timeout).exit();
+            }
+        });
+        self.timeout = Some((timeout, expired));
+    }
+
+    /// Exits with the reason of the exceeded limit instead of panicking after exceeding a limit.
+    ///
+    /// The engine panics when the errors thrown by the limits, which cannot be caught, end up
+    /// rejecting a promise, e.g. in async functions and modules.
+    pub(crate) fn exit_on_panic(&self) {
+        let limits = self.clone();
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if let Some(exceeded) = limits.expired() {
+                exceeded.exit();
+            }
+            default(info);
+        }));
+    }
+
+    /// Checks if the timeout expired or the memory limit was exceeded.
+    fn expired(&self) -> Option<Exceeded> {
+        if let Some((timeout, expired)) = &self.timeout {
+            if expired.load(Ordering::Relaxed) {
+                return Some(Exceeded::Timeout(*timeout));
+            }
+        }
+        match &self.memory {
+            Some((bytes, exceeded)) if exceeded.load(Ordering::Relaxed) => {
+                Some(Exceeded::Memory(*bytes))
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks if a limit was exceeded, given the error that stopped the execution, if any.
+    pub(crate) fn exceeded(
+        &self,
+        error: Option<&JsError>,
+        context: &mut Context,
+    ) -> Option<Exceeded> {
+        if let Some(exceeded) = self.expired() {
+            return Some(exceeded);
+        }
+
+        // The recursion limit throws a `RangeError`, which can be caught by scripts, so only the
+        // uncaught ones stop the execution.
+        let error = error?.try_native(context).ok()?;
+        (self.stack
+            && matches!(error.kind, JsNativeErrorKind::Range)
+            && error.message() == "Maximum call stack size exceeded")
+            .then_some(Exceeded::Stack)
+    }
+
+    /// Exits the process if a limit was exceeded, given the error that stopped the execution,
+    /// if any.
+    pub(crate) fn check(&self, error: Option<&JsError>, context: &mut Context) {
+        if let Some(exceeded) = self.exceeded(error, context) {
+            exceeded.exit();
+        }
+    }
+}
+
+/// An allocation hook that denies the allocations over a number of bytes.
+#[derive(Debug)]
+struct MemoryLimit {
+    bytes: usize,
+    exceeded: Arc<AtomicBool>,
+}
+
+impl AllocationHook for MemoryLimit {
+    fn on_allocation(&self, stats: &GcStats, size: usize) -> AllocationAction {
+        if stats.bytes_allocated + size <= self.bytes {
+            return AllocationAction::Continue;
+        }
+
+        // Collecting right after a collection that left most of the memory in use would not free
+        // enough memory, and would slow down every following allocation.
+        if stats.live_bytes + size <= self.bytes / 4 * 3 {
+            return AllocationAction::Collect;
+        }
+
+        self.exceeded.store(true, Ordering::Relaxed);
+        AllocationAction::Deny
+    }
+}
+
+/// Parses a duration like `5s`, `500ms` or `2m`. Numbers without a unit are seconds.
+pub(crate) fn parse_duration(value: &str) -> Result<Duration, String> {
+    let (number, unit) = value.split_at(value.trim_end_matches(char::is_alphabetic).len());
+    let scale = match unit {
+        "ms" => 0.001,
+        "" | "s" => 1.0,
+        "m" => 60.0,
+        "h" => 3600.0,
+        _ => {
+            return Err(format!(
+                "unknown time unit `{unit}`, expected `ms`, `s`, `m` or `h`"
+            ))
+        }
+    };
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration `{value}`"))?;
+    Duration::try_from_secs_f64(number * scale).map_err(|e| e.to_string())
+}
+
+/// Parses a number of bytes like `64M`, `512K` or `1G`, with binary units. Numbers without a
+/// unit are bytes.
+pub(crate) fn parse_size(value: &str) -> Result<usize, String> {
+    let (number, unit) = value.split_at(value.trim_end_matches(char::is_alphabetic).len());
+    let shift = match unit.to_ascii_uppercase().trim_end_matches('B') {
+        "" => 0,
+        "K" | "KI" => 10,
+        "M" | "MI" => 20,
+        "G" | "GI" => 30,
+        _ => {
+            return Err(format!(
+                "unknown size unit `{unit}`, expected `K`, `M` or `G`"
+            ))
+        }
+    };
+    let number: usize = number
+        .parse()
+        .map_err(|_| format!("invalid size `{value}`"))?;
+    number
+        .checked_mul(1 << shift)
+        .ok_or_else(|| format!("the size `{value}` is too big"))
+}
+
+/// Formats a number of bytes with the biggest binary unit that represents it exactly.
+fn format_size(bytes: usize) -> String {
+    [("GiB", 30), ("MiB", 20), ("KiB", 10)]
+        .into_iter()
+        .find(|(_, shift)| bytes != 0 && bytes % (1 << shift) == 0)
+        .map_or_else(
+            || format!("{bytes} bytes"),
+            |(unit, shift)| format!("{} {unit}", bytes >> shift),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_size, parse_duration, parse_size};
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("5s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_duration("5"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("1.5m"), Ok(Duration::from_secs(90)));
+        assert!(parse_duration("5 parsecs").is_err());
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("s").is_err());
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(parse_size("64M"), Ok(64 << 20));
+        assert_eq!(parse_size("64MiB"), Ok(64 << 20));
+        assert_eq!(parse_size("512kb"), Ok(512 << 10));
+        assert_eq!(parse_size("1000"), Ok(1000));
+        assert!(parse_size("1.5G").is_err());
+        assert!(parse_size("12T").is_err());
+
+        assert_eq!(format_size(64 << 20), "64 MiB");
+        assert_eq!(format_size(3 << 10), "3 KiB");
+        assert_eq!(format_size(1536), "1536 bytes");
+    }
+}
@@ -6,30 +6,55 @@
 )]
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
 
+mod completion;
 mod debug;
+mod debugger;
 mod helper;
-
+mod input;
+mod limits;
+mod profile;
+mod tracer;
+
+use boa_ast::{
+    pretty::{PrettyPrinter, PrettyPrinterOptions, QuoteStyle},
+    serialization::AstSerializer,
+};
 use boa_engine::{
-    builtins::promise::PromiseState,
-    context::ContextBuilder,
+    builtins::promise::{OperationType, PromiseState},
+    context::{ContextBuilder, HostHooks},
     job::{FutureJob, JobQueue, NativeJob},
     js_string,
     module::{Module, SimpleModuleLoader},
+    object::builtins::JsPromise,
     optimizer::OptimizerOptions,
     property::Attribute,
     script::Script,
     vm::flowgraph::{Direction, Graph},
-    Context, JsError, JsNativeError, JsResult, Source,
+    Context, JsError, JsObject, JsResult, Source,
 };
-use boa_runtime::Console;
+use boa_runtime::{Base64, Console, Crypto, Performance, Timers, Url};
 use clap::{Parser, ValueEnum, ValueHint};
 use colored::Colorize;
 use debug::init_boa_debug_object;
+use debugger::StdinDebugger;
+use input::InputBuffer;
+use limits::ResourceLimits;
+use profile::print_profile;
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
 use std::{
-    cell::RefCell, collections::VecDeque, eprintln, fs::read, fs::OpenOptions, io, path::PathBuf,
-    println, rc::Rc,
+    cell::RefCell,
+    collections::VecDeque,
+    env, eprintln,
+    ffi::OsStr,
+    fs::read,
+    fs::{File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    print, println,
+    rc::Rc,
+    time::{Duration, Instant},
 };
+use tracer::StdoutTracer;
 
 #[cfg(all(
     target_arch = "x86_64",
@@ -52,7 +77,7 @@ static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
-/// CLI configuration for Boa.
+/// The name of the file in the home directory where the history of the REPL is kept.
 static CLI_HISTORY: &str = ".boa_history";
 
 // Added #[allow(clippy::option_option)] because to StructOpt an Option<Option<T>>
@@ -82,6 +107,37 @@ struct Opt {
     #[allow(clippy::option_option)]
     dump_ast: Option<Option<DumpFormat>>,
 
+    /// Dump the compiled bytecode of the scripts to stdout with the given format.
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        ignore_case = true,
+        value_enum,
+        conflicts_with_all = ["dump_ast", "pretty", "graph"]
+    )]
+    #[allow(clippy::option_option)]
+    dump_bytecode: Option<Option<BytecodeFormat>>,
+
+    /// Print the source code back to stdout, formatted by the AST pretty-printer.
+    #[arg(long, group = "pretty", conflicts_with_all = ["dump_ast", "graph"])]
+    format: bool,
+
+    /// Number of spaces of each indentation level when formatting. Default is 4.
+    #[arg(long, value_name = "WIDTH", requires = "pretty")]
+    indent_width: Option<usize>,
+
+    /// Maximum line width when formatting. Default is 80.
+    #[arg(long, value_name = "WIDTH", requires = "pretty")]
+    line_width: Option<usize>,
+
+    /// Use single quotes for string literals when formatting.
+    #[arg(long, requires = "pretty")]
+    single_quotes: bool,
+
+    /// Omit the semicolons at the end of statements when formatting.
+    #[arg(long, requires = "pretty")]
+    no_semicolons: bool,
+
     /// Dump the AST to stdout with the given format.
     #[arg(long, short, conflicts_with = "graph")]
     trace: bool,
@@ -90,12 +146,25 @@ struct Opt {
     #[arg(long = "vi")]
     vi_mode: bool,
 
+    /// Don't load or save the history of the REPL, which is kept in `~/.boa_history`.
+    #[arg(long)]
+    no_history: bool,
+
+    /// Don't color the input and the results in the REPL. Colors are also disabled if the
+    /// `NO_COLOR` environment variable is set.
+    #[arg(long)]
+    no_highlight: bool,
+
     #[arg(long, short = 'O', group = "optimizer")]
     optimize: bool,
 
     #[arg(long, requires = "optimizer")]
     optimizer_statistics: bool,
 
+    /// Disable the peephole optimizations over the generated bytecode.
+    #[arg(long)]
+    no_peephole: bool,
+
     /// Generate instruction flowgraph. Default is Graphviz.
     #[arg(
         long,
@@ -121,19 +190,94 @@ struct Opt {
     #[arg(long)]
     debug_object: bool,
 
-    /// Treats the input files as modules.
-    #[arg(long, short = 'm', group = "mod")]
+    /// Pause at `debugger` statements in an interactive debugger that reads commands from stdin.
+    #[arg(long)]
+    debugger: bool,
+
+    /// Pause the interactive debugger at the given line of the evaluated files. Implies
+    /// `--debugger`.
+    #[arg(long = "break", value_name = "LINE")]
+    breakpoints: Vec<u32>,
+
+    /// Print the number of calls and the execution time of each function after the execution.
+    #[arg(long)]
+    profile: bool,
+
+    /// Write a snapshot of the heap to the given file after the execution, in the format of the
+    /// Chrome developer tools if the file has the `.heapsnapshot` extension, or as JSON otherwise.
+    #[arg(long, value_name = "PATH", value_hint = ValueHint::FilePath)]
+    heap_snapshot: Option<PathBuf>,
+
+    /// Treats the input files as modules. Files with the `.mjs` extension are always modules.
+    #[arg(long, short = 'm')]
     module: bool,
 
-    /// Root path from where the module resolver will try to load the modules.
-    #[arg(long, short = 'r', default_value_os_t = PathBuf::from("."), requires = "mod")]
-    root: PathBuf,
+    /// Root path from where the module resolver will try to load the modules. Defaults to the
+    /// directory of the first input file, or to the current directory.
+    #[arg(long, short = 'r', value_hint = ValueHint::DirPath)]
+    root: Option<PathBuf>,
+
+    /// Maximum number of nested function calls before a `RangeError` is thrown. If the error is
+    /// not caught, the CLI exits with code 5.
+    #[arg(long, visible_alias = "max-stack")]
+    recursion_limit: Option<usize>,
+
+    /// Maximum size of the VM stack before a `RangeError` is thrown.
+    #[arg(long)]
+    stack_size_limit: Option<usize>,
+
+    /// Maximum nesting depth of statements and expressions before a `SyntaxError` is thrown.
+    #[arg(long)]
+    nesting_limit: Option<usize>,
+
+    /// Interrupt the evaluation of the files after the given time, like `5s` or `500ms`, and exit
+    /// with code 3.
+    #[arg(long, value_name = "DURATION", value_parser = limits::parse_duration, requires = "FILE")]
+    timeout: Option<Duration>,
+
+    /// Maximum memory tracked by the garbage collector, like `64M` or `1G`, including the builtin
+    /// objects. The CLI exits with code 4 when it is exceeded.
+    #[arg(long, value_name = "SIZE", value_parser = limits::parse_size)]
+    max_memory: Option<usize>,
 }
 
 impl Opt {
     /// Returns whether a dump flag has been used.
     const fn has_dump_flag(&self) -> bool {
-        self.dump_ast.is_some()
+        self.dump_ast.is_some() || self.dump_bytecode.is_some() || self.format
+    }
+
+    /// Returns whether `file` is a module, which is the case for every file with `--module`, and
+    /// for files with the `.mjs` extension.
+    fn is_module(&self, file: Option<&Path>) -> bool {
+        self.module || file.is_some_and(|file| file.extension() == Some(OsStr::new("mjs")))
+    }
+
+    /// Gets the root path of the module loader.
+    fn module_root(&self) -> PathBuf {
+        if let Some(root) = &self.root {
+            return root.clone();
+        }
+        self.files
+            .first()
+            .and_then(|file| file.parent())
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map_or_else(|| PathBuf::from("."), Path::to_path_buf)
+    }
+
+    /// Returns the options of the pretty-printer used by `--format`.
+    fn pretty_printer_options(&self) -> PrettyPrinterOptions {
+        let default = PrettyPrinterOptions::default();
+        PrettyPrinterOptions {
+            indent_width: self.indent_width.unwrap_or(default.indent_width),
+            line_width: self.line_width.unwrap_or(default.line_width),
+            quote_style: if self.single_quotes {
+                QuoteStyle::Single
+            } else {
+                QuoteStyle::Double
+            },
+            semicolons: !self.no_semicolons,
+        }
     }
 }
 
@@ -151,10 +295,22 @@ enum DumpFormat {
     #[default]
     Debug,
 
-    // This is a minified json format.
+    // This is a minified json format, with identifiers and strings inlined.
     Json,
 
-    // This is a pretty printed json format.
+    // This is a pretty printed json format, with identifiers and strings inlined.
+    JsonPretty,
+}
+
+/// Represents the format of the bytecode dumped by `--dump-bytecode`.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+enum BytecodeFormat {
+    /// A disassembly with a line per instruction, and the nested functions indented.
+    #[default]
+    Text,
+    /// A minified json format.
+    Json,
+    /// A pretty printed json format.
     JsonPretty,
 }
 
@@ -176,49 +332,95 @@ enum FlowgraphDirection {
     RightToLeft,
 }
 
-/// Dumps the AST to stdout with format controlled by the given arguments.
+/// Dumps the AST, the formatted source code, or the bytecode to stdout with format controlled by
+/// the given arguments.
 ///
 /// Returns a error of type String with a error message,
 /// if the source has a syntax or parsing error.
-fn dump<S>(src: &S, args: &Opt, context: &mut Context) -> Result<(), String>
+fn dump<S>(src: &S, path: Option<&Path>, args: &Opt, context: &mut Context) -> Result<(), String>
 where
     S: AsRef<[u8]> + ?Sized,
 {
+    let mut parser = boa_parser::Parser::new(Source::from_reader(src.as_ref(), path));
+    let syntax_error = |e: boa_parser::Error| match path {
+        Some(path) => format!("Uncaught SyntaxError: {}", e.display_with_path(path)),
+        None => format!("Uncaught SyntaxError: {e}"),
+    };
+
     if let Some(arg) = args.dump_ast {
         let arg = arg.unwrap_or_default();
-        let mut parser = boa_parser::Parser::new(Source::from_bytes(src));
-        let dump =
-            if args.module {
-                let module = parser
-                    .parse_module(context.interner_mut())
-                    .map_err(|e| format!("Uncaught SyntaxError: {e}"))?;
-
-                match arg {
-                    DumpFormat::Json => serde_json::to_string(&module)
-                        .expect("could not convert AST to a JSON string"),
-                    DumpFormat::JsonPretty => serde_json::to_string_pretty(&module)
-                        .expect("could not convert AST to a pretty JSON string"),
-                    DumpFormat::Debug => format!("{module:#?}"),
+        let dump = if args.is_module(path) {
+            let module = parser
+                .parse_module(context.interner_mut())
+                .map_err(syntax_error)?;
+
+            let ast = AstSerializer::new(context.interner(), &module);
+            match arg {
+                DumpFormat::Json => {
+                    serde_json::to_string(&ast).expect("could not convert AST to a JSON string")
                 }
-            } else {
-                let mut script = parser
-                    .parse_script(context.interner_mut())
-                    .map_err(|e| format!("Uncaught SyntaxError: {e}"))?;
+                DumpFormat::JsonPretty => serde_json::to_string_pretty(&ast)
+                    .expect("could not convert AST to a pretty JSON string"),
+                DumpFormat::Debug => format!("{module:#?}"),
+            }
+        } else {
+            let mut script = parser
+                .parse_script(context.interner_mut())
+                .map_err(syntax_error)?;
 
-                if args.optimize {
-                    context.optimize_statement_list(script.statements_mut());
-                }
+            if args.optimize {
+                context.optimize_statement_list(script.statements_mut());
+            }
 
-                match arg {
-                    DumpFormat::Json => serde_json::to_string(&script)
-                        .expect("could not convert AST to a JSON string"),
-                    DumpFormat::JsonPretty => serde_json::to_string_pretty(&script)
-                        .expect("could not convert AST to a pretty JSON string"),
-                    DumpFormat::Debug => format!("{script:#?}"),
+            let ast = AstSerializer::new(context.interner(), &script);
+            match arg {
+                DumpFormat::Json => {
+                    serde_json::to_string(&ast).expect("could not convert AST to a JSON string")
                 }
-            };
+                DumpFormat::JsonPretty => serde_json::to_string_pretty(&ast)
+                    .expect("could not convert AST to a pretty JSON string"),
+                DumpFormat::Debug => format!("{script:#?}"),
+            }
+        };
 
         println!("{dump}");
+    } else if args.format {
+        let options = args.pretty_printer_options();
+        let formatted = if args.is_module(path) {
+            let module = parser
+                .parse_module(context.interner_mut())
+                .map_err(syntax_error)?;
+            PrettyPrinter::with_options(context.interner(), options).print_module(&module)
+        } else {
+            let script = parser
+                .parse_script(context.interner_mut())
+                .map_err(syntax_error)?;
+            PrettyPrinter::with_options(context.interner(), options).print_script(&script)
+        };
+
+        print!("{formatted}");
+    } else if let Some(format) = args.dump_bytecode {
+        if args.is_module(path) {
+            return Err("Uncaught Error: the bytecode of modules cannot be dumped".to_owned());
+        }
+
+        let disassembly = Script::parse(Source::from_reader(src.as_ref(), path), None, context)
+            .and_then(|script| script.codeblock(context))
+            .map_err(|e| format!("Uncaught {e}"))?
+            .disassemble();
+        match format.unwrap_or_default() {
+            BytecodeFormat::Text => print!("{disassembly}"),
+            BytecodeFormat::Json => println!(
+                "{}",
+                serde_json::to_string(&disassembly)
+                    .expect("could not convert the bytecode to a JSON string")
+            ),
+            BytecodeFormat::JsonPretty => println!(
+                "{}",
+                serde_json::to_string_pretty(&disassembly)
+                    .expect("could not convert the bytecode to a pretty JSON string")
+            ),
+        }
     }
 
     Ok(())
@@ -253,12 +455,16 @@ fn evaluate_files(
     args: &Opt,
     context: &mut Context,
     loader: &SimpleModuleLoader,
+    hooks: &RejectionTracker,
+    limits: &ResourceLimits,
 ) -> Result<(), io::Error> {
     for file in &args.files {
         let buffer = read(file)?;
+        // Relative imports are resolved from the path of the importing file.
+        let path = file.canonicalize()?;
 
         if args.has_dump_flag() {
-            if let Err(e) = dump(&buffer, args, context) {
+            if let Err(e) = dump(&buffer, Some(file), args, context) {
                 eprintln!("{e}");
             }
         } else if let Some(flowgraph) = args.flowgraph {
@@ -271,19 +477,18 @@ fn evaluate_files(
                 Ok(v) => println!("{v}"),
                 Err(v) => eprintln!("Uncaught {v}"),
             }
-        } else if args.module {
+        } else if args.is_module(Some(file)) {
             let result: JsResult<PromiseState> = (|| {
-                let module = Module::parse(Source::from_bytes(&buffer), None, context)?;
-
-                loader.insert(
-                    file.canonicalize()
-                        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?,
-                    module.clone(),
-                );
+                let module =
+                    Module::parse(Source::from_reader(&*buffer, Some(&path)), None, context)?;
+                loader.insert(path.clone(), module.clone());
 
                 let promise = module.load_link_evaluate(context);
 
-                context.run_jobs();
+                run_event_loop(context, limits);
+                // The rejection of the module itself is reported below.
+                hooks.handle(&promise);
+                hooks.report();
                 Ok(promise.state())
             })();
 
@@ -293,16 +498,19 @@ fn evaluate_files(
                 }
                 Ok(PromiseState::Fulfilled(_)) => {}
                 Ok(PromiseState::Rejected(err)) => {
+                    let error = JsError::from_opaque(err.clone());
+                    limits.check(Some(&error), context);
                     eprintln!("Uncaught {}", err.display());
 
-                    if let Ok(err) = JsError::from_opaque(err).try_native(context) {
+                    if let Ok(err) = error.try_native(context) {
                         if let Some(cause) = err.cause() {
                             eprintln!("\tCaused by: {cause}");
                         }
                     }
                 }
                 Err(err) => {
-                    eprintln!("Uncaught {err}");
+                    limits.check(Some(&err), context);
+                    eprintln!("{}", uncaught(&err));
 
                     if let Ok(err) = err.try_native(context) {
                         if let Some(cause) = err.cause() {
@@ -312,12 +520,19 @@ fn evaluate_files(
                 }
             }
         } else {
-            match context.eval(Source::from_bytes(&buffer)) {
+            match context.eval(Source::from_reader(&*buffer, Some(&path))) {
                 Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {v}"),
+                Err(v) => {
+                    limits.check(Some(&v), context);
+                    eprintln!("{}", uncaught(&v));
+                }
             }
-            context.run_jobs();
+            run_event_loop(context, limits);
+            hooks.report();
         }
+
+        // The errors thrown by the limits in promise jobs are not reported.
+        limits.check(None, context);
     }
 
     Ok(())
@@ -329,12 +544,15 @@ fn main() -> Result<(), io::Error> {
 
     let args = Opt::parse();
 
+    // The context requires `'static` hooks, and the CLI only ever creates one context.
+    let hooks: &'static RejectionTracker = Box::leak(Box::default());
     let queue = Rc::new(Jobs::default());
     let loader = Rc::new(
-        SimpleModuleLoader::new(&args.root)
+        SimpleModuleLoader::new(args.module_root())
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?,
     );
     let mut context = ContextBuilder::new()
+        .host_hooks(hooks)
         .job_queue(queue)
         .module_loader(loader.clone())
         .build()
@@ -343,99 +561,217 @@ fn main() -> Result<(), io::Error> {
     // Strict mode
     context.strict(args.strict);
 
+    // Runtime limits
+    set_runtime_limits(&args, &mut context);
+    let mut limits = ResourceLimits::default();
+    if let Some(bytes) = args.max_memory {
+        limits.set_max_memory(bytes);
+    }
+    if args.recursion_limit.is_some() {
+        limits.set_max_stack();
+    }
+
     // Add `console`.
     add_runtime(&mut context);
 
     // Trace Output
-    context.set_trace(args.trace);
+    //
+    // The tracing functions of the debug object only mark the functions to trace, so they also
+    // need the tracer to be installed.
+    if args.trace || args.debug_object {
+        context.set_tracer(Box::new(StdoutTracer::new(args.trace)));
+    }
 
     if args.debug_object {
         init_boa_debug_object(&mut context);
     }
 
+    // Interactive debugger
+    if args.debugger || !args.breakpoints.is_empty() {
+        context.set_debug_handler(Box::new(StdinDebugger));
+        for file in &args.files {
+            // The files are evaluated with their canonical paths.
+            let file = file.canonicalize().unwrap_or_else(|_| file.clone());
+            for line in &args.breakpoints {
+                context.set_breakpoint(&file, *line);
+            }
+        }
+    }
+
     // Configure optimizer options
     let mut optimizer_options = OptimizerOptions::empty();
     optimizer_options.set(OptimizerOptions::STATISTICS, args.optimizer_statistics);
     optimizer_options.set(OptimizerOptions::OPTIMIZE_ALL, args.optimize);
+    optimizer_options.set(OptimizerOptions::PEEPHOLE, !args.no_peephole);
     context.set_optimizer_options(optimizer_options);
 
-    if args.files.is_empty() {
-        let config = Config::builder()
-            .keyseq_timeout(1)
-            .edit_mode(if args.vi_mode {
-                EditMode::Vi
-            } else {
-                EditMode::Emacs
-            })
-            .build();
+    if args.profile {
+        context.start_profiling();
+    }
+
+    let mut context = if args.files.is_empty() {
+        run_repl(&args, context, hooks)?
+    } else {
+        if let Some(timeout) = args.timeout {
+            limits.start_timeout(timeout, &context);
+        }
+        limits.exit_on_panic();
+        evaluate_files(&args, &mut context, &loader, hooks, &limits)?;
+        context
+    };
+
+    if let Some(profile) = context.take_profile() {
+        print_profile(&profile);
+    }
+
+    if let Some(path) = &args.heap_snapshot {
+        write_heap_snapshot(path)?;
+    }
 
-        let mut editor =
-            Editor::with_config(config).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    Ok(())
+}
+
+/// Runs the REPL until the user exits, returning the context back.
+fn run_repl(args: &Opt, context: Context, hooks: &RejectionTracker) -> io::Result<Context> {
+    let config = Config::builder()
+        .keyseq_timeout(1)
+        .edit_mode(if args.vi_mode {
+            EditMode::Vi
+        } else {
+            EditMode::Emacs
+        })
+        .build();
+
+    let mut editor =
+        Editor::with_config(config).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    let history = (!args.no_history).then(history_path);
+    if let Some(history) = &history {
         // Check if the history file exists. If it does, create it.
         OpenOptions::new()
             .read(true)
             .write(true)
             .create(true)
-            .open(CLI_HISTORY)?;
-        editor.load_history(CLI_HISTORY).map_err(|err| match err {
+            .open(history)?;
+        editor.load_history(history).map_err(|err| match err {
             ReadlineError::Io(e) => e,
             e => io::Error::new(io::ErrorKind::Other, e),
         })?;
-        let readline = ">> ";
-        editor.set_helper(Some(helper::RLHelper::new(readline)));
-
-        loop {
-            match editor.readline(readline) {
-                Ok(line) if line == ".exit" => break,
-                Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
-
-                Ok(line) => {
-                    editor
-                        .add_history_entry(&line)
-                        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-
-                    if args.has_dump_flag() {
-                        if let Err(e) = dump(&line, &args, &mut context) {
-                            eprintln!("{e}");
+    }
+    // Colors are disabled by any non-empty value of `NO_COLOR`.
+    let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    let highlight = !args.no_highlight && !no_color;
+    let context = Rc::new(RefCell::new(context));
+    editor.set_helper(Some(helper::RLHelper::new(context.clone(), highlight)));
+
+    let mut input = InputBuffer::default();
+    loop {
+        match editor.readline(input.prompt()) {
+            Ok(line) if line == ".exit" && !input.is_pending() => break,
+            // Ctrl-C discards an incomplete input, and exits otherwise.
+            Err(ReadlineError::Interrupted) if input.is_pending() => input.clear(),
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+
+            Ok(line) => {
+                editor
+                    .add_history_entry(&line)
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+                let Some(line) = input.push_line(&line) else {
+                    continue;
+                };
+                let context = &mut *context.borrow_mut();
+
+                if args.has_dump_flag() {
+                    if let Err(e) = dump(&line, None, args, context) {
+                        eprintln!("{e}");
+                    }
+                } else if let Some(flowgraph) = args.flowgraph {
+                    match generate_flowgraph(
+                        context,
+                        line.trim_end().as_bytes(),
+                        flowgraph.unwrap_or(FlowgraphFormat::Graphviz),
+                        args.flowgraph_direction,
+                    ) {
+                        Ok(v) => println!("{v}"),
+                        Err(v) => eprintln!("Uncaught {v}"),
+                    }
+                } else {
+                    match context.eval(Source::from_bytes(line.trim_end())) {
+                        Ok(v) if highlight => {
+                            println!("{}", helper::highlight(&v.display().to_string(), None));
                         }
-                    } else if let Some(flowgraph) = args.flowgraph {
-                        match generate_flowgraph(
-                            &mut context,
-                            line.trim_end().as_bytes(),
-                            flowgraph.unwrap_or(FlowgraphFormat::Graphviz),
-                            args.flowgraph_direction,
-                        ) {
-                            Ok(v) => println!("{v}"),
-                            Err(v) => eprintln!("Uncaught {v}"),
+                        Ok(v) => {
+                            println!("{}", v.display());
                         }
-                    } else {
-                        match context.eval(Source::from_bytes(line.trim_end())) {
-                            Ok(v) => {
-                                println!("{}", v.display());
-                            }
-                            Err(v) => {
-                                eprintln!("{}: {}", "Uncaught".red(), v.to_string().red());
-                            }
+                        Err(v) => {
+                            eprintln!("{}: {}", "Uncaught".red(), v.to_string().red());
                         }
-                        context.run_jobs();
                     }
+                    context.run_jobs();
+                    run_due_timers(context);
+                    hooks.report();
                 }
+            }
 
-                Err(err) => {
-                    eprintln!("Unknown error: {err:?}");
-                    break;
-                }
+            Err(err) => {
+                eprintln!("Unknown error: {err:?}");
+                break;
             }
         }
+    }
 
+    if let Some(history) = &history {
         editor
-            .save_history(CLI_HISTORY)
+            .save_history(history)
             .expect("could not save CLI history");
+    }
+
+    // The helper of the editor is the only other owner of the context.
+    drop(editor);
+    let Ok(context) = Rc::try_unwrap(context) else {
+        unreachable!("the context must not be shared after the REPL ends");
+    };
+    Ok(context.into_inner())
+}
+
+/// Gets the path of the history file of the REPL, which is in the home directory of the user, or
+/// in the current directory if it is unknown.
+fn history_path() -> PathBuf {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map_or_else(
+            || PathBuf::from(CLI_HISTORY),
+            |home| PathBuf::from(home).join(CLI_HISTORY),
+        )
+}
+
+/// Writes a snapshot of the heap to `path`, in the format given by its extension.
+fn write_heap_snapshot(path: &Path) -> io::Result<()> {
+    let snapshot = boa_gc::heap_snapshot();
+    let mut file = BufWriter::new(File::create(path)?);
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "heapsnapshot")
+    {
+        snapshot.write_heapsnapshot(&mut file)?;
     } else {
-        evaluate_files(&args, &mut context, &loader)?;
+        snapshot.write_json(&mut file)?;
     }
+    file.flush()
+}
 
-    Ok(())
+/// Applies the runtime limits passed as arguments to the context.
+fn set_runtime_limits(args: &Opt, context: &mut Context) {
+    let limits = context.runtime_limits_mut();
+    if let Some(limit) = args.recursion_limit {
+        limits.set_recursion_limit(limit);
+    }
+    if let Some(limit) = args.stack_size_limit {
+        limits.set_stack_size_limit(limit);
+    }
+    if let Some(limit) = args.nesting_limit {
+        limits.set_nesting_limit(limit);
+    }
 }
 
 /// Adds the CLI runtime to the context.
@@ -444,6 +780,88 @@ fn add_runtime(context: &mut Context) {
     context
         .register_global_property(js_string!(Console::NAME), console, Attribute::all())
         .expect("the console object shouldn't exist");
+    Timers::register(context).expect("the timer functions shouldn't exist");
+    Base64::register(context).expect("the base64 functions shouldn't exist");
+    Url::register(context).expect("the URL classes shouldn't exist");
+    Performance::register(context).expect("the performance object shouldn't exist");
+    Crypto::register(context).expect("the crypto object shouldn't exist");
+}
+
+/// Runs the pending jobs and timers until there is nothing left to run, reporting the errors
+/// thrown by the timer callbacks.
+fn run_event_loop(context: &mut Context, limits: &ResourceLimits) {
+    while let Err(err) = Timers::run_event_loop(context) {
+        limits.check(Some(&err), context);
+        eprintln!("{}", uncaught(&err));
+    }
+}
+
+/// Formats an uncaught error, followed by the `file:line:col` location of the innermost frame
+/// of its stack if it is known, the same way as syntax errors.
+fn uncaught(err: &JsError) -> String {
+    let location = err.stack_frames().and_then(<[_]>::first).and_then(|frame| {
+        let path = frame.source_path()?;
+        let line = frame.line()?;
+        Some(match frame.column() {
+            Some(column) => format!("{}:{line}:{column}", path.display()),
+            None => format!("{}:{line}", path.display()),
+        })
+    });
+    match location {
+        Some(location) => format!("Uncaught {err} at {location}"),
+        None => format!("Uncaught {err}"),
+    }
+}
+
+/// Runs the timers that are already due, without waiting for the pending ones so the REPL
+/// doesn't block on long timers or intervals.
+fn run_due_timers(context: &mut Context) {
+    let now = Instant::now();
+    while Timers::next_deadline(context).is_some_and(|deadline| deadline <= now) {
+        if let Err(err) = Timers::run_next_timer(context) {
+            eprintln!("{}: {}", "Uncaught".red(), err.to_string().red());
+        }
+    }
+}
+
+/// Host hooks that keep track of the promises rejected without any handler.
+#[derive(Default)]
+struct RejectionTracker {
+    unhandled: RefCell<Vec<JsObject>>,
+}
+
+impl RejectionTracker {
+    /// Stops tracking `promise`, as if a handler was attached to it.
+    fn handle(&self, promise: &JsObject) {
+        self.unhandled
+            .borrow_mut()
+            .retain(|p| !JsObject::equals(p, promise));
+    }
+
+    /// Prints all the promises that are still unhandled and stops tracking them.
+    fn report(&self) {
+        for promise in std::mem::take(&mut *self.unhandled.borrow_mut()) {
+            if let Ok(PromiseState::Rejected(value)) =
+                JsPromise::from_object(promise).map(|p| p.state())
+            {
+                eprintln!("Unhandled promise rejection: {}", value.display());
+            }
+        }
+    }
+}
+
+impl HostHooks for RejectionTracker {
+    fn promise_rejection_tracker(
+        &self,
+        promise: &JsObject,
+        operation: OperationType,
+        _context: &mut Context,
+    ) {
+        match operation {
+            OperationType::Reject => self.unhandled.borrow_mut().push(promise.clone()),
+            OperationType::Handle => self.handle(promise),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -462,7 +880,7 @@ impl JobQueue for Jobs {
             }
             for job in jobs {
                 if let Err(e) = job.call(context) {
-                    eprintln!("Uncaught {e}");
+                    eprintln!("{}", uncaught(&e));
                 }
             }
         }
@@ -0,0 +1,29 @@
+//! Prints the profile of the executed functions.
+
+use boa_engine::vm::Profile;
+
+/// Prints a table with the calls and times of the functions in `profile` to `stderr`, sorted by
+/// their self time.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn print_profile(profile: &Profile) {
+    eprintln!(
+        "{:>12} {:>12} {:>10}  Function",
+        "Self (ms)", "Total (ms)", "Calls"
+    );
+    for function in profile.functions() {
+        let mut name = match function.name().to_std_string_escaped() {
+            name if name.is_empty() => "<anonymous>".to_owned(),
+            name => name,
+        };
+        if let Some(path) = function.source_path() {
+            name = format!("{name} ({})", path.display());
+        }
+
+        eprintln!(
+            "{:>12.3} {:>12.3} {:>10}  {name}",
+            function.self_nanos() as f64 / 1e6,
+            function.total_nanos() as f64 / 1e6,
+            function.calls(),
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Prints the execution of the VM to `stdout`.
+
+use boa_engine::vm::{CodeBlock, TracedInstruction, Tracer};
+
+const COLUMN_WIDTH: usize = 26;
+const TIME_COLUMN_WIDTH: usize = COLUMN_WIDTH / 2;
+const OPCODE_COLUMN_WIDTH: usize = COLUMN_WIDTH;
+const OPERAND_COLUMN_WIDTH: usize = COLUMN_WIDTH;
+const NUMBER_OF_COLUMNS: usize = 4;
+
+/// A [`Tracer`] that prints the bytecode of the frames that are entered, followed by the
+/// instructions they execute.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StdoutTracer {
+    /// Trace all the code blocks, instead of only the ones marked as traceable.
+    all: bool,
+}
+
+impl StdoutTracer {
+    pub(crate) const fn new(all: bool) -> Self {
+        Self { all }
+    }
+}
+
+impl Tracer for StdoutTracer {
+    fn should_trace(&self, code_block: &CodeBlock) -> bool {
+        self.all || code_block.traceable()
+    }
+
+    fn on_frame_push(&mut self, code_block: &CodeBlock, _depth: usize) {
+        let msg = format!(
+            " Call Frame -- {} ",
+            code_block.name().to_std_string_escaped()
+        );
+
+        println!("{code_block}");
+        println!(
+            "{msg:-^width$}",
+            width = COLUMN_WIDTH * NUMBER_OF_COLUMNS - 10
+        );
+        println!(
+            "{:<TIME_COLUMN_WIDTH$} {:<OPCODE_COLUMN_WIDTH$} {:<OPERAND_COLUMN_WIDTH$} Stack\n",
+            "Time", "Opcode", "Operands",
+        );
+    }
+
+    fn on_frame_pop(&mut self, _code_block: &CodeBlock, _depth: usize) {
+        println!();
+    }
+
+    fn on_instruction(&mut self, instruction: &TracedInstruction<'_>) {
+        let stack = instruction.stack();
+        let mut values = Vec::with_capacity(stack.len());
+        for (i, value) in stack.iter().enumerate().rev() {
+            let mut value = match value {
+                value if value.is_callable() => "[function]".to_string(),
+                value if value.is_object() => "[object]".to_string(),
+                value => value.display().to_string(),
+            };
+            if instruction.frame_pointer() == Some(i) {
+                value.push_str(" |");
+                value.push_str(&(instruction.depth() - 1).to_string());
+                value.push('|');
+            }
+            values.push(value);
+        }
+
+        let width = match instruction.operand_width() {
+            2 => ".U16",
+            4 => ".U32",
+            _ => "",
+        };
+
+        println!(
+            "{:<TIME_COLUMN_WIDTH$} {:<OPCODE_COLUMN_WIDTH$} {:<OPERAND_COLUMN_WIDTH$} [ {} ]",
+            format!("{}μs", instruction.duration().as_micros()),
+            format!("{}{width}", instruction.opcode()),
+            instruction.operands(),
+            values.join(", "),
+        );
+    }
+}
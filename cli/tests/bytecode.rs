@@ -0,0 +1,85 @@
+//! Tests dumping the bytecode of scripts with `--dump-bytecode`.
+
+#![allow(unused_crate_dependencies)]
+
+use std::{env, fs, path::Path, process::Command};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bytecode");
+
+/// Runs `boa` on the fixture `name` with the given arguments, returning its output.
+fn boa(name: &str, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_boa"))
+        .arg(Path::new(FIXTURES).join(name))
+        .args(args)
+        .output()
+        .expect("could not run the boa binary");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+    String::from_utf8(output.stdout).expect("the output must be UTF-8")
+}
+
+/// Compares `actual` with the snapshot at `path`.
+///
+/// The snapshot is recorded instead if it doesn't exist yet, or if the `UPDATE_SNAPSHOTS`
+/// environment variable is set.
+fn assert_snapshot(path: &Path, actual: &str) {
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() || !path.exists() {
+        fs::write(path, actual).expect("could not write the snapshot");
+        return;
+    }
+    let expected = fs::read_to_string(path).expect("could not read the snapshot");
+    assert!(
+        expected == actual,
+        "the output differs from the snapshot at `{}`, run with `UPDATE_SNAPSHOTS=1` to update \
+         it\n\nexpected:\n{expected}\nactual:\n{actual}",
+        path.display()
+    );
+}
+
+#[test]
+fn text() {
+    let output = boa("fibonacci.js", &["--dump-bytecode"]);
+
+    assert!(output.starts_with("function <main> (length: 0)\n"));
+    assert!(output.contains("\n    function fibonacci (length: 1)\n"));
+    assert!(output.contains("  handlers:\n"));
+    for literal in ["\"failed:\"", "0n", "1n"] {
+        assert!(output.contains(literal), "{literal}");
+    }
+
+    assert_snapshot(&Path::new(FIXTURES).join("fibonacci.txt"), &output);
+}
+
+#[test]
+fn json() {
+    let output = boa("fibonacci.js", &["--dump-bytecode=json"]);
+    let json: serde_json::Value = serde_json::from_str(&output).expect("invalid JSON output");
+
+    assert_eq!(json["name"], "<main>");
+    assert_eq!(json["functions"][0]["name"], "fibonacci");
+    assert_eq!(json["functions"][0]["length"], 1);
+
+    // Every jump target is the label of an instruction.
+    let fibonacci = &json["functions"][0]["instructions"];
+    let instructions = fibonacci.as_array().expect("instructions must be an array");
+    let labels: Vec<_> = instructions
+        .iter()
+        .filter_map(|instruction| instruction["label"].as_str())
+        .collect();
+    assert!(!labels.is_empty());
+    let jumps = ["Jump", "JumpIfTrue", "JumpIfFalse", "JumpIfNotUndefined"];
+    for instruction in instructions {
+        if instruction["opcode"]
+            .as_str()
+            .is_some_and(|op| jumps.contains(&op))
+        {
+            let target = instruction["operands"].as_str().unwrap_or_default();
+            assert!(labels.contains(&target), "{instruction}");
+        }
+    }
+
+    let pretty = boa("fibonacci.js", &["--dump-bytecode=json-pretty"]);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&pretty).expect("invalid JSON output"),
+        json
+    );
+}
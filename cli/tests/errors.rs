@@ -0,0 +1,27 @@
+//! Tests how the `boa` binary reports uncaught errors.
+
+#![allow(unused_crate_dependencies)]
+
+use std::{path::Path, process::Command};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/errors");
+
+#[test]
+fn uncaught_error_location() {
+    let path = Path::new(FIXTURES)
+        .join("type_error.js")
+        .canonicalize()
+        .expect("the fixture should exist");
+    let output = Command::new(env!("CARGO_BIN_EXE_boa"))
+        .arg(&path)
+        .output()
+        .expect("could not run the boa binary");
+
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        format!(
+            "Uncaught TypeError: cannot convert 'null' or 'undefined' to object at {}:2:5\n",
+            path.display()
+        )
+    );
+}
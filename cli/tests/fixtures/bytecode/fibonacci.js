@@ -0,0 +1,13 @@
+function fibonacci(n) {
+  let [a, b] = [0n, 1n];
+  for (let i = 0; i < n; i++) {
+    [a, b] = [b, a + b];
+  }
+  return a;
+}
+
+try {
+  console.log(`${fibonacci(10)}`);
+} catch (e) {
+  console.log("failed:", e);
+}
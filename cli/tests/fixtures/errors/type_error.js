@@ -0,0 +1,5 @@
+function read(object) {
+    return object.value;
+}
+
+  read(null);
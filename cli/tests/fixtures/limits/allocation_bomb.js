@@ -0,0 +1,4 @@
+const hoard = [];
+while (true) {
+  hoard.push({ data: new Array(1000).fill(hoard.length) });
+}
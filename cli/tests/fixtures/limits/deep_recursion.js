@@ -0,0 +1,11 @@
+function descend(depth) {
+  return descend(depth + 1) + 1;
+}
+
+try {
+  descend(0);
+} catch (error) {
+  console.log(`caught ${error.name}`);
+}
+
+descend(0);
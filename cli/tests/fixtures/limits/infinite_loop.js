@@ -0,0 +1,4 @@
+let iterations = 0;
+while (true) {
+  iterations++;
+}
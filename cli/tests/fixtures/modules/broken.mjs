@@ -0,0 +1 @@
+import "./lib/broken_dep.mjs";
@@ -0,0 +1 @@
+import "./missing.mjs";
@@ -0,0 +1,3 @@
+import { add } from "./math.mjs";
+
+export const greet = (name) => `hello, ${name} ${add(2, 3)}`;
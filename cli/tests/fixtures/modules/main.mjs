@@ -0,0 +1,6 @@
+import { add } from "./lib/math.mjs";
+
+const { greet } = await import("./lib/greet.mjs");
+
+console.log(add(1, 2));
+console.log(greet("modules"));
@@ -0,0 +1,3 @@
+import { add } from "./lib/math.mjs";
+
+console.log(add(4, 5));
@@ -0,0 +1,69 @@
+//! Tests the resource limit flags of the `boa` binary.
+
+#![allow(unused_crate_dependencies)]
+
+use std::{
+    path::Path,
+    process::{Command, Output},
+    time::{Duration, Instant},
+};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/limits");
+
+/// Runs `boa` on the fixture `name` with the given arguments.
+fn boa(name: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_boa"))
+        .arg(Path::new(FIXTURES).join(name))
+        .args(args)
+        .output()
+        .expect("could not run the boa binary")
+}
+
+#[test]
+fn timeout() {
+    let start = Instant::now();
+    let output = boa("infinite_loop.js", &["--timeout", "200ms"]);
+    assert!(start.elapsed() < Duration::from_secs(10));
+
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Execution timed out after 200ms\n"
+    );
+}
+
+#[test]
+fn max_memory() {
+    let output = boa("allocation_bomb.js", &["--max-memory", "64M"]);
+
+    assert_eq!(output.status.code(), Some(4));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Memory limit of 64 MiB exceeded\n"
+    );
+}
+
+#[test]
+fn max_stack() {
+    let output = boa("deep_recursion.js", &["--max-stack", "100"]);
+
+    // Only the error that isn't caught stops the execution.
+    assert_eq!(output.status.code(), Some(5));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "caught RangeError\n"
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Maximum call stack size exceeded\n"
+    );
+}
+
+#[test]
+fn invalid_limits() {
+    let output = boa("infinite_loop.js", &["--timeout", "5 parsecs"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = boa("allocation_bomb.js", &["--max-memory", "64T"]);
+    assert_eq!(output.status.code(), Some(2));
+}
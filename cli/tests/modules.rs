@@ -0,0 +1,59 @@
+//! Tests running ES modules with the `boa` binary.
+
+#![allow(unused_crate_dependencies)]
+
+use std::{
+    path::Path,
+    process::{Command, Output},
+};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/modules");
+
+/// Runs `boa` with the given arguments, from the directory of the crate.
+fn boa(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_boa"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("could not run the boa binary")
+}
+
+fn fixture(name: &str) -> String {
+    Path::new(FIXTURES).join(name).display().to_string()
+}
+
+#[test]
+fn mjs_files_are_modules() {
+    // Runs from a relative path, so the module root must be the directory of the file.
+    let output = boa(&["tests/fixtures/modules/main.mjs"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert_eq!(stderr, "");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "3\nhello, modules 5\n"
+    );
+}
+
+#[test]
+fn module_flag() {
+    let output = boa(&["--module", &fixture("module_flag.js")]);
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "9\n");
+
+    // Without the flag, the file is a script, where imports are not allowed.
+    let output = boa(&[&fixture("module_flag.js")]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("SyntaxError"));
+}
+
+#[test]
+fn link_errors_report_the_import_chain() {
+    let output = boa(&[&fixture("broken.mjs")]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let chain = format!(
+        "`./missing.mjs`\n    imported by `{}`\n    imported by `broken.mjs`",
+        Path::new("lib").join("broken_dep.mjs").display()
+    );
+    assert!(stderr.starts_with("Uncaught"), "{stderr}");
+    assert!(stderr.contains(&chain), "{stderr}");
+}
@@ -23,6 +23,7 @@ mod import;
 mod variable;
 
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{Span, Spanned};
 pub use export::*;
 pub use import::*;
 pub use variable::*;
@@ -70,6 +71,30 @@ impl ToIndentedString for Declaration {
     }
 }
 
+impl Spanned for Declaration {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Function(node) => node.span(),
+            Self::Generator(node) => node.span(),
+            Self::AsyncFunction(node) => node.span(),
+            Self::AsyncGenerator(node) => node.span(),
+            Self::Class(node) => node.span(),
+            Self::Lexical(node) => node.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Function(node) => node.set_span(span),
+            Self::Generator(node) => node.set_span(span),
+            Self::AsyncFunction(node) => node.set_span(span),
+            Self::AsyncGenerator(node) => node.set_span(span),
+            Self::Class(node) => node.set_span(span),
+            Self::Lexical(node) => node.set_span(span),
+        }
+    }
+}
+
 impl VisitWith for Declaration {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -14,6 +14,8 @@ use crate::{
 use boa_interner::{Interner, ToInternedString};
 
 use super::Declaration;
+use crate::position::{impl_spanned, NodeSpan};
+use crate::{Span, Spanned};
 
 /// A [`var`][var] statement, also called [`VariableStatement`][varstmt] in the spec.
 ///
@@ -61,6 +63,16 @@ impl ToInternedString for VarDeclaration {
     }
 }
 
+impl Spanned for VarDeclaration {
+    fn span(&self) -> Option<Span> {
+        self.0.span()
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.0.set_span(span);
+    }
+}
+
 impl VisitWith for VarDeclaration {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -80,7 +92,11 @@ impl VisitWith for VarDeclaration {
 /// A **[lexical declaration]** defines variables that are scoped to the lexical environment of
 /// the variable declaration.
 ///
+/// This also includes the [`using` declarations][using] of the explicit resource management
+/// proposal.
+///
 /// [lexical declaration]: https://tc39.es/ecma262/#sec-let-and-const-declarations
+/// [using]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
@@ -105,6 +121,21 @@ pub enum LexicalDeclaration {
     ///
     /// [let]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/let
     Let(VariableList),
+
+    /// A <code>[using]</code> declaration creates constants holding resources, which are disposed
+    /// by calling their `[Symbol.dispose]()` method when the enclosing block is exited.
+    ///
+    /// Every binding must be an identifier with an initializer.
+    ///
+    /// [using]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/using
+    Using(VariableList),
+
+    /// An `await using` declaration is like a `using` declaration, but its resources are disposed
+    /// by calling and awaiting their `[Symbol.asyncDispose]()` method, falling back to
+    /// `[Symbol.dispose]()`.
+    ///
+    /// It can only be used where `await` expressions are allowed.
+    AwaitUsing(VariableList),
 }
 
 impl LexicalDeclaration {
@@ -112,7 +143,9 @@ impl LexicalDeclaration {
     #[must_use]
     pub const fn variable_list(&self) -> &VariableList {
         match self {
-            Self::Const(list) | Self::Let(list) => list,
+            Self::Const(list) | Self::Let(list) | Self::Using(list) | Self::AwaitUsing(list) => {
+                list
+            }
         }
     }
 
@@ -121,6 +154,23 @@ impl LexicalDeclaration {
     pub const fn is_const(&self) -> bool {
         matches!(self, Self::Const(_))
     }
+
+    /// Returns `true` if the declaration is a `using` or `await using` declaration.
+    #[must_use]
+    pub const fn is_using(&self) -> bool {
+        matches!(self, Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns the keywords that start the declaration.
+    #[must_use]
+    pub const fn keyword(&self) -> &'static str {
+        match self {
+            Self::Const(_) => "const",
+            Self::Let(_) => "let",
+            Self::Using(_) => "using",
+            Self::AwaitUsing(_) => "await using",
+        }
+    }
 }
 
 impl From<LexicalDeclaration> for Declaration {
@@ -133,22 +183,35 @@ impl ToInternedString for LexicalDeclaration {
     fn to_interned_string(&self, interner: &Interner) -> String {
         format!(
             "{} {}",
-            match &self {
-                Self::Let(_) => "let",
-                Self::Const(_) => "const",
-            },
+            self.keyword(),
             self.variable_list().to_interned_string(interner)
         )
     }
 }
 
+impl Spanned for LexicalDeclaration {
+    fn span(&self) -> Option<Span> {
+        self.variable_list().span()
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Const(node) | Self::Let(node) | Self::Using(node) | Self::AwaitUsing(node) => {
+                node.set_span(span);
+            }
+        }
+    }
+}
+
 impl VisitWith for LexicalDeclaration {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: Visitor<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list(vars)
+            }
         }
     }
 
@@ -157,7 +220,9 @@ impl VisitWith for LexicalDeclaration {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list_mut(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list_mut(vars)
+            }
         }
     }
 }
@@ -168,8 +233,11 @@ impl VisitWith for LexicalDeclaration {
 #[derive(Clone, Debug, PartialEq)]
 pub struct VariableList {
     list: Box<[Variable]>,
+    span: NodeSpan,
 }
 
+impl_spanned!(VariableList);
+
 impl VariableList {
     /// Creates a variable list if the provided list of [`Variable`] is not empty.
     #[must_use]
@@ -178,7 +246,10 @@ impl VariableList {
             return None;
         }
 
-        Some(Self { list })
+        Some(Self {
+            list,
+            span: NodeSpan::NONE,
+        })
     }
 }
 
@@ -16,8 +16,10 @@
 
 use crate::expression::Expression;
 use crate::function::PrivateName;
+use crate::position::{impl_spanned, NodeSpan};
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{Span, Spanned};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -103,6 +105,24 @@ impl From<PropertyAccess> for Expression {
     }
 }
 
+impl Spanned for PropertyAccess {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Simple(node) => node.span(),
+            Self::Private(node) => node.span(),
+            Self::Super(node) => node.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Simple(node) => node.set_span(span),
+            Self::Private(node) => node.set_span(span),
+            Self::Super(node) => node.set_span(span),
+        }
+    }
+}
+
 impl VisitWith for PropertyAccess {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -134,8 +154,11 @@ impl VisitWith for PropertyAccess {
 pub struct SimplePropertyAccess {
     target: Box<Expression>,
     field: PropertyAccessField,
+    span: NodeSpan,
 }
 
+impl_spanned!(SimplePropertyAccess);
+
 impl SimplePropertyAccess {
     /// Gets the target object of the property access.
     #[inline]
@@ -159,6 +182,7 @@ impl SimplePropertyAccess {
         Self {
             target: target.into(),
             field: field.into(),
+            span: NodeSpan::NONE,
         }
     }
 }
@@ -217,8 +241,11 @@ impl VisitWith for SimplePropertyAccess {
 pub struct PrivatePropertyAccess {
     target: Box<Expression>,
     field: PrivateName,
+    span: NodeSpan,
 }
 
+impl_spanned!(PrivatePropertyAccess);
+
 impl PrivatePropertyAccess {
     /// Creates a `GetPrivateField` AST Expression.
     #[inline]
@@ -227,6 +254,7 @@ impl PrivatePropertyAccess {
         Self {
             target: value.into(),
             field,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -293,13 +321,19 @@ impl VisitWith for PrivatePropertyAccess {
 #[derive(Clone, Debug, PartialEq)]
 pub struct SuperPropertyAccess {
     field: PropertyAccessField,
+    span: NodeSpan,
 }
 
+impl_spanned!(SuperPropertyAccess);
+
 impl SuperPropertyAccess {
     /// Creates a new property access field node.
     #[must_use]
     pub const fn new(field: PropertyAccessField) -> Self {
-        Self { field }
+        Self {
+            field,
+            span: NodeSpan::NONE,
+        }
     }
 
     /// Gets the name of the field to retrieve.
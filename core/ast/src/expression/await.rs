@@ -3,6 +3,7 @@
 use core::ops::ControlFlow;
 
 use super::Expression;
+use crate::position::{impl_spanned, NodeSpan};
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 
@@ -20,8 +21,11 @@ use boa_interner::{Interner, ToIndentedString, ToInternedString};
 #[derive(Clone, Debug, PartialEq)]
 pub struct Await {
     target: Box<Expression>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Await);
+
 impl Await {
     /// Return the target expression that should be awaited.
     #[inline]
@@ -36,7 +40,10 @@ where
     T: Into<Box<Expression>>,
 {
     fn from(e: T) -> Self {
-        Self { target: e.into() }
+        Self {
+            target: e.into(),
+            span: NodeSpan::NONE,
+        }
     }
 }
 
@@ -5,6 +5,7 @@ use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
 use super::Expression;
+use crate::position::{impl_spanned, NodeSpan};
 
 /// Calling the function actually performs the specified actions with the indicated parameters.
 ///
@@ -26,8 +27,11 @@ use super::Expression;
 pub struct Call {
     function: Box<Expression>,
     args: Box<[Expression]>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Call);
+
 impl Call {
     /// Creates a new `Call` AST Expression.
     #[inline]
@@ -36,6 +40,7 @@ impl Call {
         Self {
             function: function.into(),
             args,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -109,15 +114,21 @@ impl VisitWith for Call {
 #[derive(Clone, Debug, PartialEq)]
 pub struct SuperCall {
     args: Box<[Expression]>,
+    span: NodeSpan,
 }
 
+impl_spanned!(SuperCall);
+
 impl SuperCall {
     /// Creates a new `SuperCall` AST node.
     pub fn new<A>(args: A) -> Self
     where
         A: Into<Box<[Expression]>>,
     {
-        Self { args: args.into() }
+        Self {
+            args: args.into(),
+            span: NodeSpan::NONE,
+        }
     }
 
     /// Retrieves the arguments of the super call.
@@ -178,8 +189,11 @@ impl VisitWith for SuperCall {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ImportCall {
     arg: Box<Expression>,
+    span: NodeSpan,
 }
 
+impl_spanned!(ImportCall);
+
 impl ImportCall {
     /// Creates a new `ImportCall` AST node.
     pub fn new<A>(arg: A) -> Self
@@ -188,6 +202,7 @@ impl ImportCall {
     {
         Self {
             arg: Box::new(arg.into()),
+            span: NodeSpan::NONE,
         }
     }
 
@@ -8,6 +8,7 @@ use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
 use super::Expression;
+use crate::position::{impl_spanned, NodeSpan};
 
 /// List of reserved keywords exclusive to strict mode.
 pub const RESERVED_IDENTIFIERS_STRICT: [Sym; 9] = [
@@ -45,11 +46,14 @@ pub const RESERVED_IDENTIFIERS_STRICT: [Sym; 9] = [
 )]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(transparent)]
 pub struct Identifier {
     ident: Sym,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: NodeSpan,
 }
 
+impl_spanned!(Identifier);
+
 impl PartialEq<Sym> for Identifier {
     #[inline]
     fn eq(&self, other: &Sym) -> bool {
@@ -69,7 +73,10 @@ impl Identifier {
     #[inline]
     #[must_use]
     pub const fn new(ident: Sym) -> Self {
-        Self { ident }
+        Self {
+            ident,
+            span: NodeSpan::NONE,
+        }
     }
 
     /// Retrieves the identifier's string symbol in the interner.
@@ -94,7 +101,10 @@ impl ToInternedString for Identifier {
 impl From<Sym> for Identifier {
     #[inline]
     fn from(sym: Sym) -> Self {
-        Self { ident: sym }
+        Self {
+            ident: sym,
+            span: NodeSpan::NONE,
+        }
     }
 }
 
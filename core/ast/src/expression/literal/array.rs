@@ -3,6 +3,7 @@
 use crate::expression::operator::assign::AssignTarget;
 use crate::expression::Expression;
 use crate::pattern::{ArrayPattern, ArrayPatternElement, Pattern};
+use crate::position::{impl_spanned, NodeSpan};
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, Sym, ToInternedString};
@@ -30,8 +31,11 @@ use core::ops::ControlFlow;
 pub struct ArrayLiteral {
     arr: Box<[Option<Expression>]>,
     has_trailing_comma_spread: bool,
+    span: NodeSpan,
 }
 
+impl_spanned!(ArrayLiteral);
+
 impl ArrayLiteral {
     /// Creates a new array literal.
     pub fn new<A>(array: A, has_trailing_comma_spread: bool) -> Self
@@ -41,6 +45,7 @@ impl ArrayLiteral {
         Self {
             arr: array.into(),
             has_trailing_comma_spread,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -174,6 +179,7 @@ where
         Self {
             arr: decl.into(),
             has_trailing_comma_spread: false,
+            span: NodeSpan::NONE,
         }
     }
 }
@@ -193,6 +199,10 @@ impl ToInternedString for ArrayLiteral {
                 buf.push_str(&e.to_interned_string(interner));
             }
         }
+        // A trailing elision is only kept by the parser if it is followed by a comma.
+        if matches!(self.arr.last(), Some(None)) {
+            buf.push(',');
+        }
         buf.push(']');
         buf
     }
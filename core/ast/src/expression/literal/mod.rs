@@ -16,6 +16,7 @@ use core::ops::ControlFlow;
 pub use object::ObjectLiteral;
 pub use template::{TemplateElement, TemplateLiteral};
 
+use crate::position::{impl_spanned, NodeSpan};
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, Sym, ToInternedString};
 use num_bigint::BigInt;
@@ -163,10 +164,98 @@ impl From<bool> for Literal {
 impl From<Literal> for Expression {
     #[inline]
     fn from(lit: Literal) -> Self {
+        Self::Literal(lit.into())
+    }
+}
+
+/// A [`Literal`] used as an expression.
+///
+/// This wraps the literal value together with its source span, leaving [`Literal`] itself a
+/// plain value that can be matched on and compared regardless of where it was written.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiteralExpression {
+    literal: Literal,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: NodeSpan,
+}
+
+impl_spanned!(LiteralExpression);
+
+impl LiteralExpression {
+    /// Creates a new literal expression without a span.
+    #[inline]
+    #[must_use]
+    pub const fn new(literal: Literal) -> Self {
+        Self {
+            literal,
+            span: NodeSpan::NONE,
+        }
+    }
+
+    /// Gets the literal value of this expression.
+    #[inline]
+    #[must_use]
+    pub const fn literal(&self) -> &Literal {
+        &self.literal
+    }
+
+    /// Gets a mutable reference to the literal value of this expression.
+    #[inline]
+    pub fn literal_mut(&mut self) -> &mut Literal {
+        &mut self.literal
+    }
+
+    /// Consumes the expression, returning its literal value.
+    #[inline]
+    #[must_use]
+    pub fn into_literal(self) -> Literal {
+        self.literal
+    }
+}
+
+impl From<Literal> for LiteralExpression {
+    #[inline]
+    fn from(literal: Literal) -> Self {
+        Self::new(literal)
+    }
+}
+
+impl From<LiteralExpression> for Expression {
+    #[inline]
+    fn from(lit: LiteralExpression) -> Self {
         Self::Literal(lit)
     }
 }
 
+impl ToInternedString for LiteralExpression {
+    #[inline]
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        self.literal.to_interned_string(interner)
+    }
+}
+
+impl VisitWith for LiteralExpression {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_literal(&self.literal)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_literal_mut(&mut self.literal)
+    }
+}
+
 impl ToInternedString for Literal {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -1,5 +1,6 @@
 //! Object Expression.
 
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     block_to_string,
     expression::{operator::assign::AssignTarget, Expression, RESERVED_IDENTIFIERS_STRICT},
@@ -38,8 +39,12 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ObjectLiteral {
     properties: Box<[PropertyDefinition]>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: NodeSpan,
 }
 
+impl_spanned!(ObjectLiteral);
+
 impl ObjectLiteral {
     /// Gets the object literal properties
     #[inline]
@@ -308,6 +313,7 @@ where
     fn from(props: T) -> Self {
         Self {
             properties: props.into(),
+            span: NodeSpan::NONE,
         }
     }
 }
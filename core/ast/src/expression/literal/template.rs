@@ -5,6 +5,7 @@ use std::borrow::Cow;
 
 use boa_interner::{Interner, Sym, ToInternedString};
 
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     expression::Expression,
     try_break,
@@ -25,8 +26,11 @@ use crate::{
 #[derive(Clone, Debug, PartialEq)]
 pub struct TemplateLiteral {
     elements: Box<[TemplateElement]>,
+    span: NodeSpan,
 }
 
+impl_spanned!(TemplateLiteral);
+
 impl From<TemplateLiteral> for Expression {
     #[inline]
     fn from(tem: TemplateLiteral) -> Self {
@@ -55,7 +59,10 @@ impl TemplateLiteral {
     #[inline]
     #[must_use]
     pub fn new(elements: Box<[TemplateElement]>) -> Self {
-        Self { elements }
+        Self {
+            elements,
+            span: NodeSpan::NONE,
+        }
     }
 
     /// Gets the element list of this `TemplateLiteral`.
@@ -11,7 +11,7 @@
 
 use self::{
     access::PropertyAccess,
-    literal::{ArrayLiteral, Literal, ObjectLiteral, TemplateLiteral},
+    literal::{ArrayLiteral, LiteralExpression, ObjectLiteral, TemplateLiteral},
     operator::{Assign, Binary, BinaryInPrivate, Conditional, Unary, Update},
 };
 use super::{
@@ -34,6 +34,7 @@ mod tagged_template;
 mod r#yield;
 
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{Span, Spanned};
 pub use call::{Call, ImportCall, SuperCall};
 pub use identifier::{Identifier, RESERVED_IDENTIFIERS_STRICT};
 pub use new::New;
@@ -73,8 +74,8 @@ pub enum Expression {
     /// See [`Identifier`].
     Identifier(Identifier),
 
-    /// See [`Literal`].
-    Literal(Literal),
+    /// See [`LiteralExpression`].
+    Literal(LiteralExpression),
 
     /// See [`RegExpLiteral`].
     RegExpLiteral(RegExpLiteral),
@@ -289,6 +290,80 @@ impl ToIndentedString for Expression {
     }
 }
 
+impl Spanned for Expression {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::This | Self::NewTarget | Self::ImportMeta | Self::FormalParameterList(_) => None,
+            Self::Identifier(node) => node.span(),
+            Self::Literal(node) => node.span(),
+            Self::RegExpLiteral(node) => node.span(),
+            Self::ArrayLiteral(node) => node.span(),
+            Self::ObjectLiteral(node) => node.span(),
+            Self::Spread(node) => node.span(),
+            Self::Function(node) => node.span(),
+            Self::ArrowFunction(node) => node.span(),
+            Self::AsyncArrowFunction(node) => node.span(),
+            Self::Generator(node) => node.span(),
+            Self::AsyncFunction(node) => node.span(),
+            Self::AsyncGenerator(node) => node.span(),
+            Self::Class(node) => node.span(),
+            Self::TemplateLiteral(node) => node.span(),
+            Self::PropertyAccess(node) => node.span(),
+            Self::New(node) => node.span(),
+            Self::Call(node) => node.span(),
+            Self::SuperCall(node) => node.span(),
+            Self::ImportCall(node) => node.span(),
+            Self::Optional(node) => node.span(),
+            Self::TaggedTemplate(node) => node.span(),
+            Self::Assign(node) => node.span(),
+            Self::Unary(node) => node.span(),
+            Self::Update(node) => node.span(),
+            Self::Binary(node) => node.span(),
+            Self::BinaryInPrivate(node) => node.span(),
+            Self::Conditional(node) => node.span(),
+            Self::Await(node) => node.span(),
+            Self::Yield(node) => node.span(),
+            Self::Parenthesized(node) => node.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::This | Self::NewTarget | Self::ImportMeta | Self::FormalParameterList(_) => {}
+            Self::Identifier(node) => node.set_span(span),
+            Self::Literal(node) => node.set_span(span),
+            Self::RegExpLiteral(node) => node.set_span(span),
+            Self::ArrayLiteral(node) => node.set_span(span),
+            Self::ObjectLiteral(node) => node.set_span(span),
+            Self::Spread(node) => node.set_span(span),
+            Self::Function(node) => node.set_span(span),
+            Self::ArrowFunction(node) => node.set_span(span),
+            Self::AsyncArrowFunction(node) => node.set_span(span),
+            Self::Generator(node) => node.set_span(span),
+            Self::AsyncFunction(node) => node.set_span(span),
+            Self::AsyncGenerator(node) => node.set_span(span),
+            Self::Class(node) => node.set_span(span),
+            Self::TemplateLiteral(node) => node.set_span(span),
+            Self::PropertyAccess(node) => node.set_span(span),
+            Self::New(node) => node.set_span(span),
+            Self::Call(node) => node.set_span(span),
+            Self::SuperCall(node) => node.set_span(span),
+            Self::ImportCall(node) => node.set_span(span),
+            Self::Optional(node) => node.set_span(span),
+            Self::TaggedTemplate(node) => node.set_span(span),
+            Self::Assign(node) => node.set_span(span),
+            Self::Unary(node) => node.set_span(span),
+            Self::Update(node) => node.set_span(span),
+            Self::Binary(node) => node.set_span(span),
+            Self::BinaryInPrivate(node) => node.set_span(span),
+            Self::Conditional(node) => node.set_span(span),
+            Self::Await(node) => node.set_span(span),
+            Self::Yield(node) => node.set_span(span),
+            Self::Parenthesized(node) => node.set_span(span),
+        }
+    }
+}
+
 impl VisitWith for Expression {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -296,7 +371,7 @@ impl VisitWith for Expression {
     {
         match self {
             Self::Identifier(id) => visitor.visit_identifier(id),
-            Self::Literal(lit) => visitor.visit_literal(lit),
+            Self::Literal(lit) => visitor.visit_literal(lit.literal()),
             Self::RegExpLiteral(regexp) => visitor.visit_reg_exp_literal(regexp),
             Self::ArrayLiteral(arlit) => visitor.visit_array_literal(arlit),
             Self::ObjectLiteral(olit) => visitor.visit_object_literal(olit),
@@ -339,7 +414,7 @@ impl VisitWith for Expression {
     {
         match self {
             Self::Identifier(id) => visitor.visit_identifier_mut(id),
-            Self::Literal(lit) => visitor.visit_literal_mut(lit),
+            Self::Literal(lit) => visitor.visit_literal_mut(lit.literal_mut()),
             Self::RegExpLiteral(regexp) => visitor.visit_reg_exp_literal_mut(regexp),
             Self::ArrayLiteral(arlit) => visitor.visit_array_literal_mut(arlit),
             Self::ObjectLiteral(olit) => visitor.visit_object_literal_mut(olit),
@@ -4,6 +4,7 @@ use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
 use super::Expression;
+use crate::position::{impl_spanned, NodeSpan};
 
 /// The `new` operator lets developers create an instance of a user-defined object type or of
 /// one of the built-in object types that has a constructor function.
@@ -25,8 +26,11 @@ use super::Expression;
 #[derive(Clone, Debug, PartialEq)]
 pub struct New {
     call: Call,
+    span: NodeSpan,
 }
 
+impl_spanned!(New);
+
 impl New {
     /// Gets the constructor of the new expression.
     #[inline]
@@ -52,7 +56,10 @@ impl New {
 impl From<Call> for New {
     #[inline]
     fn from(call: Call) -> Self {
-        Self { call }
+        Self {
+            call,
+            span: NodeSpan::NONE,
+        }
     }
 }
 
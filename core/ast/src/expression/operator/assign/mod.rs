@@ -19,6 +19,7 @@ pub use op::*;
 
 use boa_interner::{Interner, Sym, ToInternedString};
 
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     expression::{access::PropertyAccess, identifier::Identifier, Expression},
     pattern::Pattern,
@@ -36,8 +37,11 @@ pub struct Assign {
     op: AssignOp,
     lhs: Box<AssignTarget>,
     rhs: Box<Expression>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Assign);
+
 impl Assign {
     /// Creates an `Assign` AST Expression.
     #[inline]
@@ -47,6 +51,7 @@ impl Assign {
             op,
             lhs: Box::new(lhs),
             rhs: Box::new(rhs),
+            span: NodeSpan::NONE,
         }
     }
 
@@ -25,6 +25,7 @@ use crate::{
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
+use crate::position::{impl_spanned, NodeSpan};
 pub use op::*;
 
 /// Binary operations require two operands, one before the operator and one after the operator.
@@ -37,8 +38,11 @@ pub struct Binary {
     op: BinaryOp,
     lhs: Box<Expression>,
     rhs: Box<Expression>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Binary);
+
 impl Binary {
     /// Creates a `BinOp` AST Expression.
     #[inline]
@@ -48,6 +52,7 @@ impl Binary {
             op,
             lhs: Box::new(lhs),
             rhs: Box::new(rhs),
+            span: NodeSpan::NONE,
         }
     }
 
@@ -135,8 +140,11 @@ impl VisitWith for Binary {
 pub struct BinaryInPrivate {
     lhs: PrivateName,
     rhs: Box<Expression>,
+    span: NodeSpan,
 }
 
+impl_spanned!(BinaryInPrivate);
+
 impl BinaryInPrivate {
     /// Creates a `BinaryInPrivate` AST Expression.
     #[inline]
@@ -145,6 +153,7 @@ impl BinaryInPrivate {
         Self {
             lhs,
             rhs: Box::new(rhs),
+            span: NodeSpan::NONE,
         }
     }
 
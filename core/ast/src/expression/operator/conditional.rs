@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     expression::Expression,
     try_break,
@@ -27,8 +28,11 @@ pub struct Conditional {
     condition: Box<Expression>,
     if_true: Box<Expression>,
     if_false: Box<Expression>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Conditional);
+
 impl Conditional {
     /// Gets the condition of the `Conditional` expression.
     #[inline]
@@ -59,6 +63,7 @@ impl Conditional {
             condition: Box::new(condition),
             if_true: Box::new(if_true),
             if_false: Box::new(if_false),
+            span: NodeSpan::NONE,
         }
     }
 }
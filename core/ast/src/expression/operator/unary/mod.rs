@@ -18,6 +18,7 @@ use crate::{
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
+use crate::position::{impl_spanned, NodeSpan};
 pub use op::*;
 
 /// A unary expression is an operation with only one operand.
@@ -34,8 +35,11 @@ pub use op::*;
 pub struct Unary {
     op: UnaryOp,
     target: Box<Expression>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Unary);
+
 impl Unary {
     /// Creates a new `UnaryOp` AST Expression.
     #[inline]
@@ -44,6 +48,7 @@ impl Unary {
         Self {
             op,
             target: Box::new(target),
+            span: NodeSpan::NONE,
         }
     }
 
@@ -17,6 +17,7 @@ use crate::{
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
+use crate::position::{impl_spanned, NodeSpan};
 pub use op::*;
 
 /// A update expression is an operation with only one operand.
@@ -33,8 +34,11 @@ pub use op::*;
 pub struct Update {
     op: UpdateOp,
     target: Box<UpdateTarget>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Update);
+
 impl Update {
     /// Creates a new `Update` AST expression.
     #[inline]
@@ -43,6 +47,7 @@ impl Update {
         Self {
             op,
             target: Box::new(target),
+            span: NodeSpan::NONE,
         }
     }
 
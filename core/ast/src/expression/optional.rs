@@ -1,4 +1,5 @@
 use super::{access::PropertyAccessField, Expression};
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     function::PrivateName,
     join_nodes, try_break,
@@ -178,8 +179,11 @@ impl VisitWith for OptionalOperation {
 pub struct Optional {
     target: Box<Expression>,
     chain: Box<[OptionalOperation]>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Optional);
+
 impl VisitWith for Optional {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -212,6 +216,7 @@ impl Optional {
         Self {
             target: Box::new(target),
             chain,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -1,4 +1,5 @@
 use super::Expression;
+use crate::position::{impl_spanned, NodeSpan};
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -16,8 +17,11 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Parenthesized {
     expression: Box<Expression>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Parenthesized);
+
 impl Parenthesized {
     /// Creates a parenthesized expression.
     #[inline]
@@ -25,6 +29,7 @@ impl Parenthesized {
     pub fn new(expression: Expression) -> Self {
         Self {
             expression: Box::new(expression),
+            span: NodeSpan::NONE,
         }
     }
 
@@ -17,6 +17,7 @@ use crate::{
 };
 
 use super::Expression;
+use crate::position::{impl_spanned, NodeSpan};
 
 /// Regular expressions in ECMAScript.
 ///
@@ -32,14 +33,21 @@ use super::Expression;
 pub struct RegExpLiteral {
     pattern: Sym,
     flags: Sym,
+    span: NodeSpan,
 }
 
+impl_spanned!(RegExpLiteral);
+
 impl RegExpLiteral {
     /// Create a new [`RegExpLiteral`].
     #[inline]
     #[must_use]
     pub const fn new(pattern: Sym, flags: Sym) -> Self {
-        Self { pattern, flags }
+        Self {
+            pattern,
+            flags,
+            span: NodeSpan::NONE,
+        }
     }
 
     /// Get the pattern part of the [`RegExpLiteral`].
@@ -4,6 +4,7 @@ use core::ops::ControlFlow;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 
 use super::Expression;
+use crate::position::{impl_spanned, NodeSpan};
 
 /// The `spread` operator allows an iterable such as an array expression or string to be
 /// expanded.
@@ -27,8 +28,12 @@ use super::Expression;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Spread {
     target: Box<Expression>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: NodeSpan,
 }
 
+impl_spanned!(Spread);
+
 impl Spread {
     /// Gets the target expression to be expanded by the spread operator.
     #[inline]
@@ -43,6 +48,7 @@ impl Spread {
     pub fn new(target: Expression) -> Self {
         Self {
             target: Box::new(target),
+            span: NodeSpan::NONE,
         }
     }
 }
@@ -5,6 +5,7 @@ use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 
 use super::Expression;
+use crate::position::{impl_spanned, NodeSpan};
 
 /// A [`TaggedTemplate`][moz] expression, as defined by the [spec].
 ///
@@ -22,8 +23,11 @@ pub struct TaggedTemplate {
     cookeds: Box<[Option<Sym>]>,
     exprs: Box<[Expression]>,
     identifier: u64,
+    span: NodeSpan,
 }
 
+impl_spanned!(TaggedTemplate);
+
 impl TaggedTemplate {
     /// Creates a new tagged template with a tag, the list of raw strings, the cooked strings and
     /// the expressions.
@@ -42,6 +46,7 @@ impl TaggedTemplate {
             cookeds,
             exprs,
             identifier,
+            span: NodeSpan::NONE,
         }
     }
 
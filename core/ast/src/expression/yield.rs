@@ -4,6 +4,7 @@ use core::ops::ControlFlow;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 
 use super::Expression;
+use crate::position::{impl_spanned, NodeSpan};
 
 /// The `yield` keyword is used to pause and resume a generator function
 ///
@@ -19,8 +20,11 @@ use super::Expression;
 pub struct Yield {
     target: Option<Box<Expression>>,
     delegate: bool,
+    span: NodeSpan,
 }
 
+impl_spanned!(Yield);
+
 impl Yield {
     /// Gets the target expression of this `Yield` statement.
     #[inline]
@@ -42,6 +46,7 @@ impl Yield {
         Self {
             target: expr.map(Box::new),
             delegate,
+            span: NodeSpan::NONE,
         }
     }
 }
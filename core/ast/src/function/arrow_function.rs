@@ -8,6 +8,7 @@ use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
 
 use super::{FormalParameterList, FunctionBody};
+use crate::position::{impl_spanned, NodeSpan};
 
 /// An arrow function expression, as defined by the [spec].
 ///
@@ -25,8 +26,11 @@ pub struct ArrowFunction {
     name: Option<Identifier>,
     parameters: FormalParameterList,
     body: FunctionBody,
+    span: NodeSpan,
 }
 
+impl_spanned!(ArrowFunction);
+
 impl ArrowFunction {
     /// Creates a new `ArrowFunctionDecl` AST Expression.
     #[inline]
@@ -40,6 +44,7 @@ impl ArrowFunction {
             name,
             parameters: params,
             body,
+            span: NodeSpan::NONE,
         }
     }
 
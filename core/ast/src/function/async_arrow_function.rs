@@ -1,6 +1,7 @@
 use std::ops::ControlFlow;
 
 use super::{FormalParameterList, FunctionBody};
+use crate::position::{impl_spanned, NodeSpan};
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
@@ -25,8 +26,11 @@ pub struct AsyncArrowFunction {
     name: Option<Identifier>,
     parameters: FormalParameterList,
     body: FunctionBody,
+    span: NodeSpan,
 }
 
+impl_spanned!(AsyncArrowFunction);
+
 impl AsyncArrowFunction {
     /// Creates a new `AsyncArrowFunction` AST Expression.
     #[inline]
@@ -40,6 +44,7 @@ impl AsyncArrowFunction {
             name,
             parameters,
             body,
+            span: NodeSpan::NONE,
         }
     }
 
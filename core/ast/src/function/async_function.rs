@@ -10,6 +10,7 @@ use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
 
 use super::{FormalParameterList, FunctionBody};
+use crate::position::{impl_spanned, NodeSpan};
 
 /// An async function definition, as defined by the [spec].
 ///
@@ -27,8 +28,11 @@ pub struct AsyncFunction {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
+impl_spanned!(AsyncFunction);
+
 impl AsyncFunction {
     /// Creates a new function expression
     #[inline]
@@ -44,6 +48,7 @@ impl AsyncFunction {
             parameters,
             body,
             has_binding_identifier,
+            span: NodeSpan::NONE,
         }
     }
 
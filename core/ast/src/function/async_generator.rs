@@ -10,6 +10,7 @@ use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
 
 use super::{FormalParameterList, FunctionBody};
+use crate::position::{impl_spanned, NodeSpan};
 
 /// An async generator definition, as defined by the [spec].
 ///
@@ -26,8 +27,11 @@ pub struct AsyncGenerator {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
+impl_spanned!(AsyncGenerator);
+
 impl AsyncGenerator {
     /// Creates a new async generator expression
     #[inline]
@@ -43,6 +47,7 @@ impl AsyncGenerator {
             parameters,
             body,
             has_binding_identifier,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -1,4 +1,5 @@
 use super::Function;
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     block_to_string,
     expression::{Expression, Identifier},
@@ -29,8 +30,11 @@ pub struct Class {
     pub(crate) constructor: Option<Function>,
     pub(crate) elements: Box<[ClassElement]>,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
+impl_spanned!(Class);
+
 impl Class {
     /// Creates a new class declaration.
     #[inline]
@@ -48,6 +52,7 @@ impl Class {
             constructor,
             elements,
             has_binding_identifier,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -10,6 +10,7 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, ToIndentedString};
 
 use super::{FormalParameterList, FunctionBody};
+use crate::position::{impl_spanned, NodeSpan};
 
 /// A generator definition, as defined by the [spec].
 ///
@@ -28,8 +29,11 @@ pub struct Generator {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
+impl_spanned!(Generator);
+
 impl Generator {
     /// Creates a new generator expression
     #[inline]
@@ -45,6 +49,7 @@ impl Generator {
             parameters,
             body,
             has_binding_identifier,
+            span: NodeSpan::NONE,
         }
     }
 
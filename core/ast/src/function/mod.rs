@@ -45,6 +45,7 @@ use boa_interner::{Interner, ToIndentedString};
 
 use super::expression::{Expression, Identifier};
 use super::Declaration;
+use crate::position::{impl_spanned, NodeSpan};
 
 /// A function definition, as defined by the [spec].
 ///
@@ -64,8 +65,11 @@ pub struct Function {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
+impl_spanned!(Function);
+
 impl Function {
     /// Creates a new function expression.
     #[inline]
@@ -80,6 +84,7 @@ impl Function {
             parameters,
             body,
             has_binding_identifier: false,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -97,6 +102,7 @@ impl Function {
             parameters,
             body,
             has_binding_identifier,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -36,7 +36,10 @@ pub mod function;
 pub mod keyword;
 pub mod operations;
 pub mod pattern;
+pub mod pretty;
 pub mod property;
+#[cfg(feature = "serde")]
+pub mod serialization;
 pub mod statement;
 pub mod visitor;
 
@@ -47,7 +50,7 @@ pub use self::{
     expression::Expression,
     keyword::Keyword,
     module_item_list::{ModuleItem, ModuleItemList},
-    position::{Position, Span},
+    position::{Position, Span, Spanned},
     punctuator::Punctuator,
     source::{Module, Script},
     statement::Statement,
@@ -15,7 +15,7 @@ use crate::{
     expression::{
         access::{PrivatePropertyAccess, SuperPropertyAccess},
         operator::BinaryInPrivate,
-        Await, Identifier, OptionalOperationKind, SuperCall, Yield,
+        Await, Call, Identifier, OptionalOperationKind, SuperCall, Yield,
     },
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunction, AsyncGenerator, Class, ClassElement,
@@ -45,7 +45,7 @@ pub enum ContainsSymbol {
     SuperCall,
     /// A yield expression (`yield 5`).
     YieldExpression,
-    /// An await expression (`await 4`).
+    /// An await expression (`await 4`), or an `await using` declaration.
     AwaitExpression,
     /// The new target expression (`new.target`).
     NewTarget,
@@ -211,6 +211,20 @@ where
             node.visit_with(self)
         }
 
+        fn visit_lexical_declaration(
+            &mut self,
+            node: &'ast LexicalDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            // `await using` declarations await the disposal of their resources.
+            if matches!(node, LexicalDeclaration::AwaitUsing(_))
+                && self.0 == ContainsSymbol::AwaitExpression
+            {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
         fn visit_expression(&mut self, node: &'ast Expression) -> ControlFlow<Self::BreakTy> {
             if node == &Expression::This && self.0 == ContainsSymbol::This {
                 return ControlFlow::Break(());
@@ -289,6 +303,59 @@ where
     node.visit_with(&mut ContainsArgsVisitor).is_break()
 }
 
+/// Returns `true` if the node contains a direct call to `eval`, outside of nested functions.
+///
+/// A direct `eval` call in non-strict code can add `var` bindings to the environment of the
+/// enclosing function at runtime, which can shadow the bindings of the outer environments.
+#[must_use]
+pub fn contains_direct_eval<N>(node: &N) -> bool
+where
+    N: VisitWith,
+{
+    /// Visitor used by the function to search for a direct `eval` call.
+    #[derive(Debug, Clone, Copy)]
+    struct ContainsDirectEvalVisitor;
+
+    impl<'ast> Visitor<'ast> for ContainsDirectEvalVisitor {
+        type BreakTy = ();
+
+        fn visit_call(&mut self, node: &'ast Call) -> ControlFlow<Self::BreakTy> {
+            if matches!(node.function(), Expression::Identifier(ident) if *ident == Sym::EVAL) {
+                return ControlFlow::Break(());
+            }
+            node.visit_with(self)
+        }
+
+        fn visit_function(&mut self, _: &'ast Function) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_async_function(&mut self, _: &'ast AsyncFunction) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_generator(&mut self, _: &'ast Generator) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_async_generator(&mut self, _: &'ast AsyncGenerator) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_arrow_function(&mut self, _: &'ast ArrowFunction) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_async_arrow_function(
+            &mut self,
+            _: &'ast AsyncArrowFunction,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+    }
+    node.visit_with(&mut ContainsDirectEvalVisitor).is_break()
+}
+
 /// Returns `true` if `method` has a super call in its parameters or body.
 ///
 /// This is equivalent to the [`HasDirectSuper`][spec] syntax operation in the spec.
@@ -629,6 +696,8 @@ impl<'ast> Visitor<'ast> for VarDeclaredNamesVisitor<'_> {
     fn visit_statement(&mut self, node: &'ast Statement) -> ControlFlow<Self::BreakTy> {
         match node {
             Statement::Empty
+            | Statement::Error(_)
+            | Statement::Debugger(_)
             | Statement::Expression(_)
             | Statement::Continue(_)
             | Statement::Break(_)
@@ -1084,6 +1153,8 @@ where
                 Statement::Block(node) => self.visit_block(node),
                 Statement::Var(_)
                 | Statement::Empty
+                | Statement::Error(_)
+                | Statement::Debugger(_)
                 | Statement::Expression(_)
                 | Statement::Return(_)
                 | Statement::Throw(_) => ControlFlow::Continue(()),
@@ -1381,6 +1452,30 @@ where
     node.visit_with(&mut visitor).is_break()
 }
 
+/// Returns `true` if the given node contains a [`Statement::Error`] placeholder, left by a parser
+/// recovering from syntax errors.
+#[must_use]
+pub fn contains_error_placeholder<N>(node: &N) -> bool
+where
+    N: VisitWith,
+{
+    #[derive(Debug, Clone, Copy)]
+    struct ContainsErrorPlaceholder;
+
+    impl<'ast> Visitor<'ast> for ContainsErrorPlaceholder {
+        type BreakTy = ();
+
+        fn visit_statement(&mut self, node: &'ast Statement) -> ControlFlow<Self::BreakTy> {
+            if let Statement::Error(_) = node {
+                return ControlFlow::Break(());
+            }
+            node.visit_with(self)
+        }
+    }
+
+    node.visit_with(&mut ContainsErrorPlaceholder).is_break()
+}
+
 /// The type of a lexically scoped declaration.
 #[derive(Copy, Clone, Debug)]
 pub enum LexicallyScopedDeclaration<'a> {
@@ -1693,6 +1788,8 @@ impl<'ast> Visitor<'ast> for VarScopedDeclarationsVisitor<'_> {
             Statement::Try(s) => self.visit(s),
             Statement::With(s) => self.visit(s),
             Statement::Empty
+            | Statement::Error(_)
+            | Statement::Debugger(_)
             | Statement::Expression(_)
             | Statement::Continue(_)
             | Statement::Break(_)
@@ -2127,7 +2224,7 @@ impl<'ast> Visitor<'ast> for ReturnsValueVisitor {
 
     fn visit_statement(&mut self, node: &'ast Statement) -> ControlFlow<Self::BreakTy> {
         match node {
-            Statement::Empty | Statement::Var(_) => {}
+            Statement::Empty | Statement::Error(_) | Statement::Var(_) => {}
             Statement::Block(node) => try_break!(self.visit(node)),
             Statement::Labelled(node) => try_break!(self.visit(node)),
             _ => return ControlFlow::Break(()),
@@ -133,6 +133,84 @@ impl fmt::Display for Span {
     }
 }
 
+/// An AST node that knows where it appears in the source code.
+///
+/// Nodes produced by the parser always have a span, while nodes created programmatically don't
+/// have one until [`Spanned::set_span`] is called.
+pub trait Spanned {
+    /// Gets the span of the node in the source code, if any.
+    fn span(&self) -> Option<Span>;
+
+    /// Sets the span of the node in the source code.
+    ///
+    /// Nodes that don't have any payload, like [`Expression::This`][crate::Expression::This],
+    /// don't store a span and ignore this.
+    fn set_span(&mut self, span: Span);
+}
+
+/// The span stored inside an AST node.
+///
+/// Spans only locate a node in its source code, so they are ignored when comparing or hashing
+/// nodes: two nodes are equal if they represent the same syntax, wherever they appear.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct NodeSpan(Option<Span>);
+
+impl NodeSpan {
+    /// A node without span.
+    pub(crate) const NONE: Self = Self(None);
+
+    /// Gets the inner span, if any.
+    pub(crate) const fn get(self) -> Option<Span> {
+        self.0
+    }
+
+    /// Sets the inner span.
+    pub(crate) fn set(&mut self, span: Span) {
+        self.0 = Some(span);
+    }
+}
+
+impl PartialEq for NodeSpan {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NodeSpan {}
+
+impl std::hash::Hash for NodeSpan {
+    fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NodeSpan {
+    fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::NONE)
+    }
+}
+
+/// Implements [`Spanned`] for nodes storing their span in a `span` field.
+macro_rules! impl_spanned {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl $crate::Spanned for $ty {
+                #[inline]
+                fn span(&self) -> Option<$crate::Span> {
+                    self.span.get()
+                }
+
+                #[inline]
+                fn set_span(&mut self, span: $crate::Span) {
+                    self.span.set(span);
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_spanned;
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::similar_names)]
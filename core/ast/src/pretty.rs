@@ -0,0 +1,1714 @@
+//! A configurable pretty-printer for the AST.
+//!
+//! The [`ToInternedString`] and [`ToIndentedString`] implementations of the AST nodes always
+//! print with four spaces of indentation, double quoted strings and a semicolon after every
+//! statement, and never break long lines. [`PrettyPrinter`] produces the same kind of output,
+//! but lets the caller choose those settings through [`PrettyPrinterOptions`].
+//!
+//! [`ToInternedString`]: boa_interner::ToInternedString
+//! [`ToIndentedString`]: boa_interner::ToIndentedString
+
+use std::{convert::Infallible, fmt::Write, ops::ControlFlow};
+
+use boa_interner::{Interner, Sym, ToInternedString};
+
+use crate::{
+    declaration::{
+        Binding, ExportDeclaration, ExportSpecifier, ImportDeclaration, ImportKind,
+        ImportSpecifier, LexicalDeclaration, ReExportKind, VarDeclaration, Variable, VariableList,
+    },
+    expression::{
+        access::{
+            PrivatePropertyAccess, PropertyAccess, PropertyAccessField, SimplePropertyAccess,
+            SuperPropertyAccess,
+        },
+        literal::{ArrayLiteral, Literal, ObjectLiteral, TemplateElement, TemplateLiteral},
+        operator::{
+            assign::{Assign, AssignTarget},
+            binary::BinaryOp,
+            unary::UnaryOp,
+            update::{UpdateOp, UpdateTarget},
+            Binary, BinaryInPrivate, Conditional, Unary, Update,
+        },
+        Await, Call, Expression, Identifier, ImportCall, New, Optional, OptionalOperation,
+        OptionalOperationKind, Parenthesized, RegExpLiteral, Spread, SuperCall, TaggedTemplate,
+        Yield,
+    },
+    function::{
+        ArrowFunction, AsyncArrowFunction, AsyncFunction, AsyncGenerator, Class, ClassElement,
+        FormalParameter, FormalParameterList, Function, FunctionBody, Generator,
+    },
+    pattern::{ArrayPattern, ArrayPatternElement, ObjectPattern, ObjectPatternElement, Pattern},
+    property::{MethodDefinition, PropertyDefinition, PropertyName},
+    statement::{
+        iteration::{
+            Break, Continue, DoWhileLoop, ForInLoop, ForLoop, ForLoopInitializer, ForOfLoop,
+            IterableLoopInitializer, WhileLoop,
+        },
+        Block, Debugger, Labelled, LabelledItem, Return, Statement, Switch, Throw, Try, With,
+    },
+    try_break,
+    visitor::{VisitWith, Visitor},
+    Declaration, Module, ModuleItem, ModuleItemList, Script, StatementList, StatementListItem,
+    ToStringEscaped,
+};
+
+/// The quotes used to delimit string literals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Double quotes (`"`).
+    #[default]
+    Double,
+
+    /// Single quotes (`'`).
+    Single,
+}
+
+impl QuoteStyle {
+    const fn quote(self) -> char {
+        match self {
+            Self::Double => '"',
+            Self::Single => '\'',
+        }
+    }
+}
+
+/// The settings used by a [`PrettyPrinter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrettyPrinterOptions {
+    /// The number of spaces of each indentation level.
+    pub indent_width: usize,
+
+    /// The maximum width of a line.
+    ///
+    /// Array and object literals, argument lists and import or export lists that don't fit
+    /// are printed with one element per line.
+    pub line_width: usize,
+
+    /// The quotes used for string literals.
+    pub quote_style: QuoteStyle,
+
+    /// Whether statements are terminated with a semicolon.
+    ///
+    /// If disabled, a semicolon is still printed before any expression statement that would
+    /// otherwise continue the previous line, and after any class field that would otherwise
+    /// merge with the next class element. Empty statements in statement lists are dropped, so
+    /// that those leading semicolons don't print again as lone `;` lines when reformatting.
+    pub semicolons: bool,
+}
+
+impl Default for PrettyPrinterOptions {
+    fn default() -> Self {
+        Self {
+            indent_width: 4,
+            line_width: 80,
+            quote_style: QuoteStyle::default(),
+            semicolons: true,
+        }
+    }
+}
+
+/// Prints an AST back to source code, according to a set of [`PrettyPrinterOptions`].
+///
+/// The printer walks the tree with the [`Visitor`] infrastructure, and is configured with
+/// builder-style methods:
+///
+/// ```
+/// # use boa_ast::{pretty::{PrettyPrinter, QuoteStyle}, StatementList};
+/// # use boa_interner::Interner;
+/// let interner = Interner::default();
+/// let output = PrettyPrinter::new(&interner)
+///     .indent_width(2)
+///     .line_width(100)
+///     .quote_style(QuoteStyle::Single)
+///     .print_statement_list(&StatementList::default());
+/// assert!(output.is_empty());
+/// ```
+///
+/// Like the [`ToInternedString`] implementations, the printer doesn't insert parentheses on its
+/// own; the parser keeps them as [`Parenthesized`] expressions instead.
+#[derive(Debug)]
+pub struct PrettyPrinter<'a> {
+    interner: &'a Interner,
+    options: PrettyPrinterOptions,
+    buf: String,
+    indent: usize,
+    base_column: usize,
+}
+
+impl<'a> PrettyPrinter<'a> {
+    /// Creates a new `PrettyPrinter` with the default options.
+    #[must_use]
+    pub fn new(interner: &'a Interner) -> Self {
+        Self::with_options(interner, PrettyPrinterOptions::default())
+    }
+
+    /// Creates a new `PrettyPrinter` with the given options.
+    #[must_use]
+    pub const fn with_options(interner: &'a Interner, options: PrettyPrinterOptions) -> Self {
+        Self {
+            interner,
+            options,
+            buf: String::new(),
+            indent: 0,
+            base_column: 0,
+        }
+    }
+
+    /// Sets the number of spaces of each indentation level.
+    #[must_use]
+    pub const fn indent_width(mut self, indent_width: usize) -> Self {
+        self.options.indent_width = indent_width;
+        self
+    }
+
+    /// Sets the maximum width of a line.
+    #[must_use]
+    pub const fn line_width(mut self, line_width: usize) -> Self {
+        self.options.line_width = line_width;
+        self
+    }
+
+    /// Sets the quotes used for string literals.
+    #[must_use]
+    pub const fn quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.options.quote_style = quote_style;
+        self
+    }
+
+    /// Sets whether statements are terminated with a semicolon.
+    #[must_use]
+    pub const fn semicolons(mut self, semicolons: bool) -> Self {
+        self.options.semicolons = semicolons;
+        self
+    }
+
+    /// Gets the options of this printer.
+    #[must_use]
+    pub const fn options(&self) -> &PrettyPrinterOptions {
+        &self.options
+    }
+
+    /// Prints a script, with every top level statement ending in a newline.
+    pub fn print_script(&mut self, script: &Script) -> String {
+        let flow = self.visit_script(script);
+        self.finish(flow)
+    }
+
+    /// Prints a module, with every top level item ending in a newline.
+    pub fn print_module(&mut self, module: &Module) -> String {
+        let flow = self.visit_module(module);
+        self.finish(flow)
+    }
+
+    /// Prints a statement list, with every item ending in a newline.
+    pub fn print_statement_list(&mut self, list: &StatementList) -> String {
+        let flow = self.visit_statement_list(list);
+        self.finish(flow)
+    }
+
+    /// Prints a single expression.
+    pub fn print_expression(&mut self, expr: &Expression) -> String {
+        let flow = self.visit_expression(expr);
+        self.finish(flow)
+    }
+
+    fn finish(&mut self, flow: ControlFlow<Infallible>) -> String {
+        match flow {
+            ControlFlow::Continue(()) => std::mem::take(&mut self.buf),
+            ControlFlow::Break(never) => match never {},
+        }
+    }
+
+    fn push(&mut self, s: &str) {
+        self.buf.push_str(s);
+    }
+
+    fn push_escape(&mut self, kind: char, value: u32, digits: usize) {
+        write!(self.buf, "\\{kind}{value:0digits$X}").expect("writing to a `String` cannot fail");
+    }
+
+    fn newline(&mut self) {
+        self.buf.push('\n');
+        self.buf
+            .extend(std::iter::repeat(' ').take(self.indent * self.options.indent_width));
+    }
+
+    fn semicolon(&mut self) {
+        if self.options.semicolons {
+            self.buf.push(';');
+        }
+    }
+
+    /// The column where the next character will be printed.
+    fn column(&self) -> usize {
+        match self.buf.rfind('\n') {
+            Some(i) => self.buf[i + 1..].chars().count(),
+            None => self.base_column + self.buf.chars().count(),
+        }
+    }
+
+    /// Prints something into a new buffer, as if it was one indentation level deeper.
+    fn render<F>(&self, f: F) -> String
+    where
+        F: FnOnce(&mut Self) -> ControlFlow<Infallible>,
+    {
+        let mut printer = Self {
+            interner: self.interner,
+            options: self.options,
+            buf: String::new(),
+            indent: self.indent + 1,
+            base_column: (self.indent + 1) * self.options.indent_width,
+        };
+        let flow = f(&mut printer);
+        printer.finish(flow)
+    }
+
+    /// Prints a comma separated list between `open` and `close`.
+    ///
+    /// The list is kept on the current line if it fits, and gets an element per line (with a
+    /// trailing comma) otherwise. `padded` adds a space inside the delimiters of a single line
+    /// list, as in `{ a, b }`.
+    fn list<'ast, T, F>(
+        &mut self,
+        [open, close]: [&str; 2],
+        padded: bool,
+        items: &'ast [T],
+        mut f: F,
+    ) -> ControlFlow<Infallible>
+    where
+        F: FnMut(&mut Self, &'ast T) -> ControlFlow<Infallible>,
+    {
+        if items.is_empty() {
+            self.push(open);
+            self.push(close);
+            return ControlFlow::Continue(());
+        }
+
+        let items = items
+            .iter()
+            .map(|item| self.render(|p| f(p, item)))
+            .collect::<Vec<_>>();
+
+        let mut flat = items.join(", ");
+        // An empty last element is an elision, which needs its own comma.
+        if items.last().is_some_and(String::is_empty) {
+            flat.push(',');
+        }
+        let padding = if padded { " " } else { "" };
+        let width = open.len() + 2 * padding.len() + flat.chars().count() + close.len();
+
+        if !flat.contains('\n') && self.column() + width <= self.options.line_width {
+            self.push(open);
+            self.push(padding);
+            self.push(&flat);
+            self.push(padding);
+            self.push(close);
+        } else {
+            self.push(open);
+            self.indent += 1;
+            for item in &items {
+                self.newline();
+                self.push(item);
+                self.push(",");
+            }
+            self.indent -= 1;
+            self.newline();
+            self.push(close);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Prints a comma separated list on a single line.
+    fn join<'ast, T, F>(&mut self, items: &'ast [T], mut f: F) -> ControlFlow<Infallible>
+    where
+        F: FnMut(&mut Self, &'ast T) -> ControlFlow<Infallible>,
+    {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            try_break!(f(self, item));
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn sym(&mut self, sym: Sym) {
+        let s = self.interner.resolve_expect(sym).join(
+            String::from,
+            ToStringEscaped::to_string_escaped,
+            true,
+        );
+        self.push(&s);
+    }
+
+    fn string_literal(&mut self, sym: Sym) {
+        let quote = self.options.quote_style.quote();
+        self.buf.push(quote);
+        for c in char::decode_utf16(self.interner.resolve_expect(sym).utf16().iter().copied()) {
+            match c {
+                Ok('\\') => self.buf.push_str("\\\\"),
+                Ok('\n') => self.buf.push_str("\\n"),
+                Ok('\r') => self.buf.push_str("\\r"),
+                Ok('\t') => self.buf.push_str("\\t"),
+                Ok(c) if c == quote => {
+                    self.buf.push('\\');
+                    self.buf.push(c);
+                }
+                Ok(c @ ('\u{2028}' | '\u{2029}')) => {
+                    self.push_escape('u', u32::from(c), 4);
+                }
+                Ok(c) if c.is_control() => {
+                    self.push_escape('x', u32::from(c), 2);
+                }
+                Ok(c) => self.buf.push(c),
+                Err(e) => {
+                    self.push_escape('u', u32::from(e.unpaired_surrogate()), 4);
+                }
+            }
+        }
+        self.buf.push(quote);
+    }
+
+    /// Prints a name that can also be written as a string literal, like property keys or
+    /// the names of module exports.
+    fn name_or_string(&mut self, sym: Sym) {
+        let name = self.interner.resolve_expect(sym).to_string();
+        if is_identifier_name(&name) {
+            self.push(&name);
+        } else {
+            self.string_literal(sym);
+        }
+    }
+
+    fn property_key(&mut self, sym: Sym) {
+        let name = self.interner.resolve_expect(sym).to_string();
+        if is_identifier_name(&name) || is_array_index(&name) {
+            self.push(&name);
+        } else {
+            self.string_literal(sym);
+        }
+    }
+
+    /// Prints the items of a statement list, each one on its own line.
+    /// Whether a statement list item is left out of the output.
+    ///
+    /// Without semicolons, the semicolon guarding an expression statement parses back as an
+    /// empty statement, which must not be printed again.
+    fn skipped(&self, item: &StatementListItem) -> bool {
+        !self.options.semicolons && matches!(item, StatementListItem::Statement(Statement::Empty))
+    }
+
+    fn statements(&mut self, list: &StatementList) -> ControlFlow<Infallible> {
+        let mut first = true;
+        for item in list.statements() {
+            if self.skipped(item) {
+                continue;
+            }
+            if !first {
+                self.newline();
+            }
+            first = false;
+            try_break!(self.visit_statement_list_item(item));
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn block(&mut self, list: &StatementList) -> ControlFlow<Infallible> {
+        if list.statements().iter().all(|item| self.skipped(item)) {
+            self.push("{}");
+            return ControlFlow::Continue(());
+        }
+        self.push("{");
+        self.indent += 1;
+        self.newline();
+        try_break!(self.statements(list));
+        self.indent -= 1;
+        self.newline();
+        self.push("}");
+        ControlFlow::Continue(())
+    }
+
+    /// Prints the body of a control flow statement.
+    ///
+    /// Blocks stay on the same line, while other statements go on the next line, one level
+    /// deeper.
+    fn body(&mut self, body: &Statement) -> ControlFlow<Infallible> {
+        match body {
+            Statement::Block(block) => {
+                self.push(" ");
+                self.visit_block(block)
+            }
+            Statement::Empty => {
+                self.push(";");
+                ControlFlow::Continue(())
+            }
+            _ => {
+                self.indent += 1;
+                self.newline();
+                try_break!(self.visit_statement(body));
+                self.indent -= 1;
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    /// Prints the separator between the body of a statement and a following keyword, like
+    /// `else` or the `while` of a `do-while` loop.
+    fn after_body(&mut self, body: &Statement) {
+        if matches!(body, Statement::Block(_)) {
+            self.push(" ");
+        } else {
+            self.newline();
+        }
+    }
+
+    fn function<'ast>(
+        &mut self,
+        keyword: &str,
+        name: Option<Identifier>,
+        parameters: &'ast FormalParameterList,
+        body: &'ast FunctionBody,
+    ) -> ControlFlow<Infallible> {
+        self.push(keyword);
+        if let Some(name) = name {
+            self.push(" ");
+            self.sym(name.sym());
+        }
+        try_break!(self.visit_formal_parameter_list(parameters));
+        self.push(" ");
+        self.block(body.statements())
+    }
+
+    fn arrow_function<'ast>(
+        &mut self,
+        keyword: &str,
+        parameters: &'ast FormalParameterList,
+        body: &'ast FunctionBody,
+    ) -> ControlFlow<Infallible> {
+        self.push(keyword);
+        try_break!(self.visit_formal_parameter_list(parameters));
+        self.push(" => ");
+
+        // The parser turns concise bodies into a single return statement, so print those back
+        // as an expression.
+        if let [StatementListItem::Statement(Statement::Return(ret))] =
+            body.statements().statements()
+        {
+            if let Some(expr) = ret.target() {
+                let parenthesize = match expr {
+                    Expression::ObjectLiteral(_) => true,
+                    Expression::Binary(binary) => binary.op() == BinaryOp::Comma,
+                    _ => false,
+                };
+                if parenthesize {
+                    self.push("(");
+                }
+                try_break!(self.visit_expression(expr));
+                if parenthesize {
+                    self.push(")");
+                }
+                return ControlFlow::Continue(());
+            }
+        }
+
+        self.block(body.statements())
+    }
+
+    fn class(&mut self, class: &Class, name: Option<Identifier>) -> ControlFlow<Infallible> {
+        self.push("class");
+        if let Some(name) = name {
+            self.push(" ");
+            self.sym(name.sym());
+        }
+        if let Some(super_ref) = class.super_ref() {
+            self.push(" extends ");
+            try_break!(self.visit_expression(super_ref));
+        }
+        if class.constructor().is_none() && class.elements().is_empty() {
+            self.push(" {}");
+            return ControlFlow::Continue(());
+        }
+
+        self.push(" {");
+        self.indent += 1;
+        if let Some(constructor) = class.constructor() {
+            self.newline();
+            try_break!(self.function(
+                "constructor",
+                None,
+                constructor.parameters(),
+                constructor.body()
+            ));
+        }
+        let mut field_end = None;
+        for element in class.elements() {
+            self.newline();
+            let start = self.buf.len();
+            try_break!(self.visit_class_element(element));
+            let text = &self.buf[start..];
+            let word = text
+                .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '$')
+                .next();
+            let merges = text.starts_with(['[', '*']) || matches!(word, Some("in" | "instanceof"));
+            // A field named like a modifier would apply to the next element.
+            let modifier = matches!(text.rsplit(' ').next(), Some("get" | "set" | "static"));
+            if let Some(end) = field_end.take() {
+                if merges {
+                    self.buf.insert(end, ';');
+                }
+            }
+            if !self.options.semicolons {
+                match element {
+                    ClassElement::FieldDefinition(_, init)
+                    | ClassElement::StaticFieldDefinition(_, init)
+                    | ClassElement::PrivateFieldDefinition(_, init)
+                    | ClassElement::PrivateStaticFieldDefinition(_, init) => {
+                        if init.is_none() && modifier {
+                            self.push(";");
+                        } else {
+                            field_end = Some(self.buf.len());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.indent -= 1;
+        self.newline();
+        self.push("}");
+        ControlFlow::Continue(())
+    }
+
+    fn method<F>(&mut self, name: F, method: &MethodDefinition) -> ControlFlow<Infallible>
+    where
+        F: FnOnce(&mut Self) -> ControlFlow<Infallible>,
+    {
+        let (prefix, parameters, body) = match method {
+            MethodDefinition::Get(f) => ("get ", f.parameters(), f.body()),
+            MethodDefinition::Set(f) => ("set ", f.parameters(), f.body()),
+            MethodDefinition::Ordinary(f) => ("", f.parameters(), f.body()),
+            MethodDefinition::Generator(f) => ("*", f.parameters(), f.body()),
+            MethodDefinition::AsyncGenerator(f) => ("async *", f.parameters(), f.body()),
+            MethodDefinition::Async(f) => ("async ", f.parameters(), f.body()),
+        };
+        self.push(prefix);
+        try_break!(name(self));
+        try_break!(self.visit_formal_parameter_list(parameters));
+        self.push(" ");
+        self.block(body.statements())
+    }
+
+    fn field<F>(&mut self, name: F, init: Option<&Expression>) -> ControlFlow<Infallible>
+    where
+        F: FnOnce(&mut Self) -> ControlFlow<Infallible>,
+    {
+        try_break!(name(self));
+        if let Some(init) = init {
+            self.push(" = ");
+            try_break!(self.visit_expression(init));
+        }
+        // Without semicolons, `class` only terminates the fields that automatic semicolon
+        // insertion would merge with the next element.
+        self.semicolon();
+        ControlFlow::Continue(())
+    }
+
+    fn default_init(&mut self, init: Option<&Expression>) -> ControlFlow<Infallible> {
+        if let Some(init) = init {
+            self.push(" = ");
+            try_break!(self.visit_expression(init));
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn arguments(&mut self, args: &[Expression]) -> ControlFlow<Infallible> {
+        self.list(["(", ")"], false, args, Self::visit_expression)
+    }
+
+    fn update_target(&mut self, target: &UpdateTarget) -> ControlFlow<Infallible> {
+        match target {
+            UpdateTarget::Identifier(ident) => self.visit_identifier(ident),
+            UpdateTarget::PropertyAccess(access) => self.visit_property_access(access),
+        }
+    }
+
+    fn module_specifier(&mut self, specifier: Sym) {
+        self.push(" from ");
+        self.string_literal(specifier);
+        self.semicolon();
+    }
+
+    /// Gets the name of a default export, which is unset if the declaration was anonymous.
+    fn default_export_name(name: Option<Identifier>) -> Option<Identifier> {
+        name.filter(|name| name.sym() != Sym::DEFAULT)
+    }
+}
+
+impl<'ast> Visitor<'ast> for PrettyPrinter<'_> {
+    type BreakTy = Infallible;
+
+    fn visit_script(&mut self, node: &'ast Script) -> ControlFlow<Self::BreakTy> {
+        self.visit_statement_list(node.statements())
+    }
+
+    fn visit_module(&mut self, node: &'ast Module) -> ControlFlow<Self::BreakTy> {
+        self.visit_module_item_list(node.items())
+    }
+
+    fn visit_statement_list(&mut self, node: &'ast StatementList) -> ControlFlow<Self::BreakTy> {
+        for item in node.statements() {
+            if self.skipped(item) {
+                continue;
+            }
+            try_break!(self.visit_statement_list_item(item));
+            self.push("\n");
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_module_item_list(&mut self, node: &'ast ModuleItemList) -> ControlFlow<Self::BreakTy> {
+        for item in node.items() {
+            if matches!(item, ModuleItem::StatementListItem(item) if self.skipped(item)) {
+                continue;
+            }
+            try_break!(self.visit_module_item(item));
+            self.push("\n");
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_statement(&mut self, node: &'ast Statement) -> ControlFlow<Self::BreakTy> {
+        match node {
+            Statement::Empty => self.push(";"),
+            Statement::Error(_) => self.push("/* syntax error */"),
+            Statement::Expression(expr) => {
+                let start = self.buf.len();
+                try_break!(self.visit_expression(expr));
+                if !self.options.semicolons
+                    && self.buf[start..].starts_with(['(', '[', '`', '+', '-', '/'])
+                {
+                    self.buf.insert(start, ';');
+                }
+                self.semicolon();
+            }
+            Statement::Var(var) => {
+                try_break!(self.visit_var_declaration(var));
+                self.semicolon();
+            }
+            _ => return node.visit_with(self),
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_declaration(&mut self, node: &'ast Declaration) -> ControlFlow<Self::BreakTy> {
+        match node {
+            Declaration::Function(f) => {
+                self.function("function", f.name(), f.parameters(), f.body())
+            }
+            Declaration::Generator(f) => {
+                self.function("function*", f.name(), f.parameters(), f.body())
+            }
+            Declaration::AsyncFunction(f) => {
+                self.function("async function", f.name(), f.parameters(), f.body())
+            }
+            Declaration::AsyncGenerator(f) => {
+                self.function("async function*", f.name(), f.parameters(), f.body())
+            }
+            Declaration::Class(class) => self.class(class, class.name()),
+            Declaration::Lexical(lexical) => {
+                try_break!(self.visit_lexical_declaration(lexical));
+                self.semicolon();
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    fn visit_var_declaration(&mut self, node: &'ast VarDeclaration) -> ControlFlow<Self::BreakTy> {
+        self.push("var ");
+        self.visit_variable_list(&node.0)
+    }
+
+    fn visit_lexical_declaration(
+        &mut self,
+        node: &'ast LexicalDeclaration,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.push(node.keyword());
+        self.push(" ");
+        self.visit_variable_list(node.variable_list())
+    }
+
+    fn visit_variable_list(&mut self, node: &'ast VariableList) -> ControlFlow<Self::BreakTy> {
+        self.join(node.as_ref(), Self::visit_variable)
+    }
+
+    fn visit_variable(&mut self, node: &'ast Variable) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_binding(node.binding()));
+        self.default_init(node.init())
+    }
+
+    fn visit_binding(&mut self, node: &'ast Binding) -> ControlFlow<Self::BreakTy> {
+        match node {
+            Binding::Identifier(ident) => self.visit_identifier(ident),
+            Binding::Pattern(pattern) => self.visit_pattern(pattern),
+        }
+    }
+
+    fn visit_block(&mut self, node: &'ast Block) -> ControlFlow<Self::BreakTy> {
+        self.block(node.statement_list())
+    }
+
+    fn visit_if(&mut self, node: &'ast crate::statement::If) -> ControlFlow<Self::BreakTy> {
+        self.push("if (");
+        try_break!(self.visit_expression(node.cond()));
+        self.push(")");
+        try_break!(self.body(node.body()));
+        if let Some(else_node) = node.else_node() {
+            self.after_body(node.body());
+            self.push("else");
+            if matches!(else_node, Statement::If(_)) {
+                self.push(" ");
+                try_break!(self.visit_statement(else_node));
+            } else {
+                try_break!(self.body(else_node));
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_do_while_loop(&mut self, node: &'ast DoWhileLoop) -> ControlFlow<Self::BreakTy> {
+        self.push("do");
+        try_break!(self.body(node.body()));
+        self.after_body(node.body());
+        self.push("while (");
+        try_break!(self.visit_expression(node.cond()));
+        self.push(")");
+        self.semicolon();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_while_loop(&mut self, node: &'ast WhileLoop) -> ControlFlow<Self::BreakTy> {
+        self.push("while (");
+        try_break!(self.visit_expression(node.condition()));
+        self.push(")");
+        self.body(node.body())
+    }
+
+    fn visit_for_loop(&mut self, node: &'ast ForLoop) -> ControlFlow<Self::BreakTy> {
+        self.push("for (");
+        if let Some(init) = node.init() {
+            try_break!(self.visit_for_loop_initializer(init));
+        }
+        self.push(";");
+        if let Some(condition) = node.condition() {
+            self.push(" ");
+            try_break!(self.visit_expression(condition));
+        }
+        self.push(";");
+        if let Some(final_expr) = node.final_expr() {
+            self.push(" ");
+            try_break!(self.visit_expression(final_expr));
+        }
+        self.push(")");
+        self.body(node.body())
+    }
+
+    fn visit_for_loop_initializer(
+        &mut self,
+        node: &'ast ForLoopInitializer,
+    ) -> ControlFlow<Self::BreakTy> {
+        match node {
+            ForLoopInitializer::Expression(expr) => self.visit_expression(expr),
+            ForLoopInitializer::Var(var) => self.visit_var_declaration(var),
+            ForLoopInitializer::Lexical(lexical) => self.visit_lexical_declaration(lexical),
+        }
+    }
+
+    fn visit_for_in_loop(&mut self, node: &'ast ForInLoop) -> ControlFlow<Self::BreakTy> {
+        self.push("for (");
+        try_break!(self.visit_iterable_loop_initializer(node.initializer()));
+        self.push(" in ");
+        try_break!(self.visit_expression(node.target()));
+        self.push(")");
+        self.body(node.body())
+    }
+
+    fn visit_for_of_loop(&mut self, node: &'ast ForOfLoop) -> ControlFlow<Self::BreakTy> {
+        self.push(if node.r#await() {
+            "for await ("
+        } else {
+            "for ("
+        });
+        try_break!(self.visit_iterable_loop_initializer(node.initializer()));
+        self.push(" of ");
+        try_break!(self.visit_expression(node.iterable()));
+        self.push(")");
+        self.body(node.body())
+    }
+
+    fn visit_iterable_loop_initializer(
+        &mut self,
+        node: &'ast IterableLoopInitializer,
+    ) -> ControlFlow<Self::BreakTy> {
+        match node {
+            IterableLoopInitializer::Identifier(ident) => self.visit_identifier(ident),
+            IterableLoopInitializer::Access(access) => self.visit_property_access(access),
+            IterableLoopInitializer::Var(var) => {
+                self.push("var ");
+                self.visit_variable(var)
+            }
+            IterableLoopInitializer::Let(binding) => {
+                self.push("let ");
+                self.visit_binding(binding)
+            }
+            IterableLoopInitializer::Const(binding) => {
+                self.push("const ");
+                self.visit_binding(binding)
+            }
+            IterableLoopInitializer::Pattern(pattern) => self.visit_pattern(pattern),
+        }
+    }
+
+    fn visit_switch(&mut self, node: &'ast Switch) -> ControlFlow<Self::BreakTy> {
+        self.push("switch (");
+        try_break!(self.visit_expression(node.val()));
+        self.push(")");
+        if node.cases().is_empty() {
+            self.push(" {}");
+            return ControlFlow::Continue(());
+        }
+        self.push(" {");
+        self.indent += 1;
+        for case in node.cases() {
+            self.newline();
+            if let Some(condition) = case.condition() {
+                self.push("case ");
+                try_break!(self.visit_expression(condition));
+                self.push(":");
+            } else {
+                self.push("default:");
+            }
+            self.indent += 1;
+            for item in case.body().statements() {
+                if self.skipped(item) {
+                    continue;
+                }
+                self.newline();
+                try_break!(self.visit_statement_list_item(item));
+            }
+            self.indent -= 1;
+        }
+        self.indent -= 1;
+        self.newline();
+        self.push("}");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_continue(&mut self, node: &'ast Continue) -> ControlFlow<Self::BreakTy> {
+        self.push("continue");
+        if let Some(label) = node.label() {
+            self.push(" ");
+            self.sym(label);
+        }
+        self.semicolon();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_break(&mut self, node: &'ast Break) -> ControlFlow<Self::BreakTy> {
+        self.push("break");
+        if let Some(label) = node.label() {
+            self.push(" ");
+            self.sym(label);
+        }
+        self.semicolon();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_return(&mut self, node: &'ast Return) -> ControlFlow<Self::BreakTy> {
+        self.push("return");
+        if let Some(target) = node.target() {
+            self.push(" ");
+            try_break!(self.visit_expression(target));
+        }
+        self.semicolon();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_labelled(&mut self, node: &'ast Labelled) -> ControlFlow<Self::BreakTy> {
+        self.sym(node.label());
+        self.push(": ");
+        match node.item() {
+            LabelledItem::Function(f) => {
+                self.function("function", f.name(), f.parameters(), f.body())
+            }
+            LabelledItem::Statement(statement) => self.visit_statement(statement),
+        }
+    }
+
+    fn visit_throw(&mut self, node: &'ast Throw) -> ControlFlow<Self::BreakTy> {
+        self.push("throw ");
+        try_break!(self.visit_expression(node.target()));
+        self.semicolon();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_try(&mut self, node: &'ast Try) -> ControlFlow<Self::BreakTy> {
+        self.push("try ");
+        try_break!(self.visit_block(node.block()));
+        if let Some(catch) = node.catch() {
+            self.push(" catch ");
+            if let Some(parameter) = catch.parameter() {
+                self.push("(");
+                try_break!(self.visit_binding(parameter));
+                self.push(") ");
+            }
+            try_break!(self.visit_block(catch.block()));
+        }
+        if let Some(finally) = node.finally() {
+            self.push(" finally ");
+            try_break!(self.visit_block(finally.block()));
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_with(&mut self, node: &'ast With) -> ControlFlow<Self::BreakTy> {
+        self.push("with (");
+        try_break!(self.visit_expression(node.expression()));
+        self.push(")");
+        self.body(node.statement())
+    }
+
+    fn visit_debugger(&mut self, _: &'ast Debugger) -> ControlFlow<Self::BreakTy> {
+        self.push("debugger");
+        self.semicolon();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_import_declaration(
+        &mut self,
+        node: &'ast ImportDeclaration,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.push("import ");
+        let default = node.default();
+        if let Some(default) = default {
+            self.sym(default.sym());
+        }
+        match node.kind() {
+            ImportKind::DefaultOrUnnamed => {
+                if default.is_none() {
+                    self.string_literal(node.specifier().sym());
+                    self.semicolon();
+                    return ControlFlow::Continue(());
+                }
+            }
+            ImportKind::Namespaced { binding } => {
+                if default.is_some() {
+                    self.push(", ");
+                }
+                self.push("* as ");
+                self.sym(binding.sym());
+            }
+            ImportKind::Named { names } => {
+                if default.is_some() {
+                    self.push(", ");
+                }
+                try_break!(self.list(["{", "}"], true, names, Self::visit_import_specifier));
+            }
+        }
+        self.module_specifier(node.specifier().sym());
+        ControlFlow::Continue(())
+    }
+
+    fn visit_import_specifier(
+        &mut self,
+        node: &'ast ImportSpecifier,
+    ) -> ControlFlow<Self::BreakTy> {
+        if node.binding() != node.export_name() {
+            self.name_or_string(node.export_name());
+            self.push(" as ");
+        }
+        self.sym(node.binding().sym());
+        ControlFlow::Continue(())
+    }
+
+    fn visit_export_declaration(
+        &mut self,
+        node: &'ast ExportDeclaration,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.push("export ");
+        match node {
+            ExportDeclaration::ReExport { kind, specifier } => {
+                match kind {
+                    ReExportKind::Namespaced { name } => {
+                        self.push("*");
+                        if let Some(name) = name {
+                            self.push(" as ");
+                            self.name_or_string(*name);
+                        }
+                    }
+                    ReExportKind::Named { names } => {
+                        try_break!(self.list(
+                            ["{", "}"],
+                            true,
+                            names,
+                            Self::visit_export_specifier
+                        ));
+                    }
+                }
+                self.module_specifier(specifier.sym());
+            }
+            ExportDeclaration::List(names) => {
+                try_break!(self.list(["{", "}"], true, names, Self::visit_export_specifier));
+                self.semicolon();
+            }
+            ExportDeclaration::VarStatement(var) => {
+                try_break!(self.visit_var_declaration(var));
+                self.semicolon();
+            }
+            ExportDeclaration::Declaration(declaration) => {
+                try_break!(self.visit_declaration(declaration));
+            }
+            ExportDeclaration::DefaultFunction(f) => {
+                let name = Self::default_export_name(f.name());
+                try_break!(self.function("default function", name, f.parameters(), f.body()));
+            }
+            ExportDeclaration::DefaultGenerator(f) => {
+                let name = Self::default_export_name(f.name());
+                try_break!(self.function("default function*", name, f.parameters(), f.body()));
+            }
+            ExportDeclaration::DefaultAsyncFunction(f) => {
+                let name = Self::default_export_name(f.name());
+                try_break!(self.function("default async function", name, f.parameters(), f.body()));
+            }
+            ExportDeclaration::DefaultAsyncGenerator(f) => {
+                let name = Self::default_export_name(f.name());
+                try_break!(self.function(
+                    "default async function*",
+                    name,
+                    f.parameters(),
+                    f.body()
+                ));
+            }
+            ExportDeclaration::DefaultClassDeclaration(class) => {
+                self.push("default ");
+                try_break!(self.class(class, Self::default_export_name(class.name())));
+            }
+            ExportDeclaration::DefaultAssignmentExpression(expr) => {
+                self.push("default ");
+                try_break!(self.visit_expression(expr));
+                self.semicolon();
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_export_specifier(
+        &mut self,
+        node: &'ast ExportSpecifier,
+    ) -> ControlFlow<Self::BreakTy> {
+        if node.string_literal() {
+            self.string_literal(node.private_name());
+        } else {
+            self.sym(node.private_name());
+        }
+        if node.alias() != node.private_name() {
+            self.push(" as ");
+            self.name_or_string(node.alias());
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_expression(&mut self, node: &'ast Expression) -> ControlFlow<Self::BreakTy> {
+        match node {
+            Expression::This => self.push("this"),
+            Expression::NewTarget => self.push("new.target"),
+            Expression::ImportMeta => self.push("import.meta"),
+            _ => return node.visit_with(self),
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_identifier(&mut self, node: &'ast Identifier) -> ControlFlow<Self::BreakTy> {
+        self.sym(node.sym());
+        ControlFlow::Continue(())
+    }
+
+    fn visit_literal(&mut self, node: &'ast Literal) -> ControlFlow<Self::BreakTy> {
+        if let Literal::String(sym) = node {
+            self.string_literal(*sym);
+        } else {
+            let literal = node.to_interned_string(self.interner);
+            self.push(&literal);
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_reg_exp_literal(&mut self, node: &'ast RegExpLiteral) -> ControlFlow<Self::BreakTy> {
+        let literal = node.to_interned_string(self.interner);
+        self.push(&literal);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_array_literal(&mut self, node: &'ast ArrayLiteral) -> ControlFlow<Self::BreakTy> {
+        self.list(
+            ["[", "]"],
+            false,
+            node.as_ref(),
+            |p, element| match element {
+                Some(element) => p.visit_expression(element),
+                None => ControlFlow::Continue(()),
+            },
+        )
+    }
+
+    fn visit_object_literal(&mut self, node: &'ast ObjectLiteral) -> ControlFlow<Self::BreakTy> {
+        self.list(
+            ["{", "}"],
+            true,
+            node.properties(),
+            Self::visit_property_definition,
+        )
+    }
+
+    fn visit_property_definition(
+        &mut self,
+        node: &'ast PropertyDefinition,
+    ) -> ControlFlow<Self::BreakTy> {
+        match node {
+            PropertyDefinition::IdentifierReference(ident) => self.visit_identifier(ident),
+            PropertyDefinition::Property(name, value) => {
+                try_break!(self.visit_property_name(name));
+                self.push(": ");
+                self.visit_expression(value)
+            }
+            PropertyDefinition::MethodDefinition(name, method) => {
+                self.method(|p| p.visit_property_name(name), method)
+            }
+            PropertyDefinition::SpreadObject(target) => {
+                self.push("...");
+                self.visit_expression(target)
+            }
+            PropertyDefinition::CoverInitializedName(ident, init) => {
+                try_break!(self.visit_identifier(ident));
+                self.default_init(Some(init))
+            }
+        }
+    }
+
+    fn visit_property_name(&mut self, node: &'ast PropertyName) -> ControlFlow<Self::BreakTy> {
+        match node {
+            PropertyName::Literal(sym) => self.property_key(*sym),
+            // Numeric keys are parsed as computed names.
+            PropertyName::Computed(expr @ Expression::Literal(lit))
+                if matches!(lit.literal(), Literal::Int(_) | Literal::Num(_)) =>
+            {
+                try_break!(self.visit_expression(expr));
+            }
+            PropertyName::Computed(expr) => {
+                self.push("[");
+                try_break!(self.visit_expression(expr));
+                self.push("]");
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_spread(&mut self, node: &'ast Spread) -> ControlFlow<Self::BreakTy> {
+        self.push("...");
+        self.visit_expression(node.target())
+    }
+
+    fn visit_function(&mut self, node: &'ast Function) -> ControlFlow<Self::BreakTy> {
+        let name = node.name().filter(|_| node.has_binding_identifier());
+        self.function("function", name, node.parameters(), node.body())
+    }
+
+    fn visit_generator(&mut self, node: &'ast Generator) -> ControlFlow<Self::BreakTy> {
+        let name = node.name().filter(|_| node.has_binding_identifier());
+        self.function("function*", name, node.parameters(), node.body())
+    }
+
+    fn visit_async_function(&mut self, node: &'ast AsyncFunction) -> ControlFlow<Self::BreakTy> {
+        let name = node.name().filter(|_| node.has_binding_identifier());
+        self.function("async function", name, node.parameters(), node.body())
+    }
+
+    fn visit_async_generator(&mut self, node: &'ast AsyncGenerator) -> ControlFlow<Self::BreakTy> {
+        let name = node.name().filter(|_| node.has_binding_identifier());
+        self.function("async function*", name, node.parameters(), node.body())
+    }
+
+    fn visit_arrow_function(&mut self, node: &'ast ArrowFunction) -> ControlFlow<Self::BreakTy> {
+        self.arrow_function("", node.parameters(), node.body())
+    }
+
+    fn visit_async_arrow_function(
+        &mut self,
+        node: &'ast AsyncArrowFunction,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.arrow_function("async ", node.parameters(), node.body())
+    }
+
+    fn visit_class(&mut self, node: &'ast Class) -> ControlFlow<Self::BreakTy> {
+        let name = node.name().filter(|_| node.has_binding_identifier());
+        self.class(node, name)
+    }
+
+    fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
+        match node {
+            ClassElement::MethodDefinition(name, method) => {
+                self.method(|p| p.visit_property_name(name), method)
+            }
+            ClassElement::StaticMethodDefinition(name, method) => {
+                self.push("static ");
+                self.method(|p| p.visit_property_name(name), method)
+            }
+            ClassElement::FieldDefinition(name, init) => {
+                self.field(|p| p.visit_property_name(name), init.as_ref())
+            }
+            ClassElement::StaticFieldDefinition(name, init) => {
+                self.push("static ");
+                self.field(|p| p.visit_property_name(name), init.as_ref())
+            }
+            ClassElement::PrivateMethodDefinition(name, method) => {
+                self.method(|p| p.visit_private_name(name), method)
+            }
+            ClassElement::PrivateStaticMethodDefinition(name, method) => {
+                self.push("static ");
+                self.method(|p| p.visit_private_name(name), method)
+            }
+            ClassElement::PrivateFieldDefinition(name, init) => {
+                self.field(|p| p.visit_private_name(name), init.as_ref())
+            }
+            ClassElement::PrivateStaticFieldDefinition(name, init) => {
+                self.push("static ");
+                self.field(|p| p.visit_private_name(name), init.as_ref())
+            }
+            ClassElement::StaticBlock(body) => {
+                self.push("static ");
+                self.block(body.statements())
+            }
+        }
+    }
+
+    fn visit_private_name(
+        &mut self,
+        node: &'ast crate::function::PrivateName,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.push("#");
+        self.sym(node.description());
+        ControlFlow::Continue(())
+    }
+
+    fn visit_formal_parameter_list(
+        &mut self,
+        node: &'ast FormalParameterList,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.push("(");
+        try_break!(self.join(node.as_ref(), Self::visit_formal_parameter));
+        self.push(")");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_formal_parameter(
+        &mut self,
+        node: &'ast FormalParameter,
+    ) -> ControlFlow<Self::BreakTy> {
+        if node.is_rest_param() {
+            self.push("...");
+        }
+        self.visit_variable(node.variable())
+    }
+
+    fn visit_template_literal(
+        &mut self,
+        node: &'ast TemplateLiteral,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.push("`");
+        for element in node.elements() {
+            match element {
+                TemplateElement::String(sym) => {
+                    let units = self.interner.resolve_expect(*sym).utf16();
+                    let mut chars = char::decode_utf16(units.iter().copied()).peekable();
+                    while let Some(c) = chars.next() {
+                        match c {
+                            Ok(c @ ('\\' | '`')) => {
+                                self.buf.push('\\');
+                                self.buf.push(c);
+                            }
+                            Ok('$') if matches!(chars.peek(), Some(Ok('{'))) => {
+                                self.buf.push_str("\\$");
+                            }
+                            Ok('\r') => self.buf.push_str("\\r"),
+                            Ok(c) => self.buf.push(c),
+                            Err(e) => {
+                                self.push_escape('u', u32::from(e.unpaired_surrogate()), 4);
+                            }
+                        }
+                    }
+                }
+                TemplateElement::Expr(expr) => {
+                    self.push("${");
+                    try_break!(self.visit_expression(expr));
+                    self.push("}");
+                }
+            }
+        }
+        self.push("`");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_tagged_template(&mut self, node: &'ast TaggedTemplate) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_expression(node.tag()));
+        self.push("`");
+        for (i, raw) in node.raws().iter().enumerate() {
+            if i > 0 {
+                self.push("${");
+                try_break!(self.visit_expression(&node.exprs()[i - 1]));
+                self.push("}");
+            }
+            self.sym(*raw);
+        }
+        self.push("`");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_property_access(&mut self, node: &'ast PropertyAccess) -> ControlFlow<Self::BreakTy> {
+        match node {
+            PropertyAccess::Simple(access) => self.visit_simple_property_access(access),
+            PropertyAccess::Private(access) => self.visit_private_property_access(access),
+            PropertyAccess::Super(access) => self.visit_super_property_access(access),
+        }
+    }
+
+    fn visit_simple_property_access(
+        &mut self,
+        node: &'ast SimplePropertyAccess,
+    ) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_expression(node.target()));
+        self.visit_property_access_field(node.field())
+    }
+
+    fn visit_private_property_access(
+        &mut self,
+        node: &'ast PrivatePropertyAccess,
+    ) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_expression(node.target()));
+        self.push(".#");
+        self.sym(node.field().description());
+        ControlFlow::Continue(())
+    }
+
+    fn visit_super_property_access(
+        &mut self,
+        node: &'ast SuperPropertyAccess,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.push("super");
+        self.visit_property_access_field(node.field())
+    }
+
+    fn visit_property_access_field(
+        &mut self,
+        node: &'ast PropertyAccessField,
+    ) -> ControlFlow<Self::BreakTy> {
+        match node {
+            PropertyAccessField::Const(sym) => {
+                self.push(".");
+                self.sym(*sym);
+            }
+            PropertyAccessField::Expr(expr) => {
+                self.push("[");
+                try_break!(self.visit_expression(expr));
+                self.push("]");
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_new(&mut self, node: &'ast New) -> ControlFlow<Self::BreakTy> {
+        self.push("new ");
+        try_break!(self.visit_expression(node.constructor()));
+        self.arguments(node.arguments())
+    }
+
+    fn visit_call(&mut self, node: &'ast Call) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_expression(node.function()));
+        self.arguments(node.args())
+    }
+
+    fn visit_super_call(&mut self, node: &'ast SuperCall) -> ControlFlow<Self::BreakTy> {
+        self.push("super");
+        self.arguments(node.arguments())
+    }
+
+    fn visit_import_call(&mut self, node: &'ast ImportCall) -> ControlFlow<Self::BreakTy> {
+        self.push("import(");
+        try_break!(self.visit_expression(node.argument()));
+        self.push(")");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_optional(&mut self, node: &'ast Optional) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_expression(node.target()));
+        for operation in node.chain() {
+            try_break!(self.visit_optional_operation(operation));
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_optional_operation(
+        &mut self,
+        node: &'ast OptionalOperation,
+    ) -> ControlFlow<Self::BreakTy> {
+        if node.shorted() {
+            self.push("?.");
+        }
+        match node.kind() {
+            OptionalOperationKind::SimplePropertyAccess { field } => match field {
+                PropertyAccessField::Const(sym) => {
+                    if !node.shorted() {
+                        self.push(".");
+                    }
+                    self.sym(*sym);
+                }
+                PropertyAccessField::Expr(expr) => {
+                    self.push("[");
+                    try_break!(self.visit_expression(expr));
+                    self.push("]");
+                }
+            },
+            OptionalOperationKind::PrivatePropertyAccess { field } => {
+                if !node.shorted() {
+                    self.push(".");
+                }
+                try_break!(self.visit_private_name(field));
+            }
+            OptionalOperationKind::Call { args } => try_break!(self.arguments(args)),
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_assign(&mut self, node: &'ast Assign) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_assign_target(node.lhs()));
+        self.push(&format!(" {} ", node.op()));
+        self.visit_expression(node.rhs())
+    }
+
+    fn visit_assign_target(&mut self, node: &'ast AssignTarget) -> ControlFlow<Self::BreakTy> {
+        match node {
+            AssignTarget::Identifier(ident) => self.visit_identifier(ident),
+            AssignTarget::Access(access) => self.visit_property_access(access),
+            AssignTarget::Pattern(pattern) => self.visit_pattern(pattern),
+        }
+    }
+
+    fn visit_unary(&mut self, node: &'ast Unary) -> ControlFlow<Self::BreakTy> {
+        let op = node.op();
+        self.push(&op.to_string());
+        if matches!(op, UnaryOp::TypeOf | UnaryOp::Delete | UnaryOp::Void) {
+            self.push(" ");
+        }
+        let start = self.buf.len();
+        try_break!(self.visit_expression(node.target()));
+        // Keep `- -a` and `+ +a` from being printed as a decrement or increment.
+        let sign = match op {
+            UnaryOp::Minus => Some('-'),
+            UnaryOp::Plus => Some('+'),
+            _ => None,
+        };
+        if sign.is_some_and(|sign| self.buf[start..].starts_with(sign)) {
+            self.buf.insert(start, ' ');
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_update(&mut self, node: &'ast Update) -> ControlFlow<Self::BreakTy> {
+        match node.op() {
+            UpdateOp::IncrementPre | UpdateOp::DecrementPre => {
+                self.push(&node.op().to_string());
+                self.update_target(node.target())
+            }
+            UpdateOp::IncrementPost | UpdateOp::DecrementPost => {
+                try_break!(self.update_target(node.target()));
+                self.push(&node.op().to_string());
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    fn visit_binary(&mut self, node: &'ast Binary) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_expression(node.lhs()));
+        if node.op() == BinaryOp::Comma {
+            self.push(", ");
+        } else {
+            self.push(&format!(" {} ", node.op()));
+        }
+        self.visit_expression(node.rhs())
+    }
+
+    fn visit_binary_in_private(
+        &mut self,
+        node: &'ast BinaryInPrivate,
+    ) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_private_name(node.lhs()));
+        self.push(" in ");
+        self.visit_expression(node.rhs())
+    }
+
+    fn visit_conditional(&mut self, node: &'ast Conditional) -> ControlFlow<Self::BreakTy> {
+        try_break!(self.visit_expression(node.condition()));
+        self.push(" ? ");
+        try_break!(self.visit_expression(node.if_true()));
+        self.push(" : ");
+        self.visit_expression(node.if_false())
+    }
+
+    fn visit_await(&mut self, node: &'ast Await) -> ControlFlow<Self::BreakTy> {
+        self.push("await ");
+        self.visit_expression(node.target())
+    }
+
+    fn visit_yield(&mut self, node: &'ast Yield) -> ControlFlow<Self::BreakTy> {
+        self.push(if node.delegate() { "yield*" } else { "yield" });
+        if let Some(target) = node.target() {
+            self.push(" ");
+            try_break!(self.visit_expression(target));
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_parenthesized(&mut self, node: &'ast Parenthesized) -> ControlFlow<Self::BreakTy> {
+        self.push("(");
+        try_break!(self.visit_expression(node.expression()));
+        self.push(")");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_pattern(&mut self, node: &'ast Pattern) -> ControlFlow<Self::BreakTy> {
+        match node {
+            Pattern::Object(pattern) => self.visit_object_pattern(pattern),
+            Pattern::Array(pattern) => self.visit_array_pattern(pattern),
+        }
+    }
+
+    fn visit_object_pattern(&mut self, node: &'ast ObjectPattern) -> ControlFlow<Self::BreakTy> {
+        if node.bindings().is_empty() {
+            self.push("{}");
+            return ControlFlow::Continue(());
+        }
+        self.push("{ ");
+        try_break!(self.join(node.bindings(), Self::visit_object_pattern_element));
+        self.push(" }");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_object_pattern_element(
+        &mut self,
+        node: &'ast ObjectPatternElement,
+    ) -> ControlFlow<Self::BreakTy> {
+        match node {
+            ObjectPatternElement::SingleName {
+                name,
+                ident,
+                default_init,
+            } => {
+                if !matches!(name, PropertyName::Literal(name) if name == ident) {
+                    try_break!(self.visit_property_name(name));
+                    self.push(": ");
+                }
+                try_break!(self.visit_identifier(ident));
+                self.default_init(default_init.as_ref())
+            }
+            ObjectPatternElement::RestProperty { ident, .. } => {
+                self.push("...");
+                self.visit_identifier(ident)
+            }
+            ObjectPatternElement::AssignmentPropertyAccess {
+                name,
+                access,
+                default_init,
+            } => {
+                try_break!(self.visit_property_name(name));
+                self.push(": ");
+                try_break!(self.visit_property_access(access));
+                self.default_init(default_init.as_ref())
+            }
+            ObjectPatternElement::AssignmentRestPropertyAccess { access, .. } => {
+                self.push("...");
+                self.visit_property_access(access)
+            }
+            ObjectPatternElement::Pattern {
+                name,
+                pattern,
+                default_init,
+            } => {
+                try_break!(self.visit_property_name(name));
+                self.push(": ");
+                try_break!(self.visit_pattern(pattern));
+                self.default_init(default_init.as_ref())
+            }
+        }
+    }
+
+    fn visit_array_pattern(&mut self, node: &'ast ArrayPattern) -> ControlFlow<Self::BreakTy> {
+        self.push("[");
+        try_break!(self.join(node.bindings(), Self::visit_array_pattern_element));
+        if matches!(node.bindings().last(), Some(ArrayPatternElement::Elision)) {
+            self.push(",");
+        }
+        self.push("]");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_array_pattern_element(
+        &mut self,
+        node: &'ast ArrayPatternElement,
+    ) -> ControlFlow<Self::BreakTy> {
+        match node {
+            ArrayPatternElement::Elision => ControlFlow::Continue(()),
+            ArrayPatternElement::SingleName {
+                ident,
+                default_init,
+            } => {
+                try_break!(self.visit_identifier(ident));
+                self.default_init(default_init.as_ref())
+            }
+            ArrayPatternElement::PropertyAccess { access } => self.visit_property_access(access),
+            ArrayPatternElement::Pattern {
+                pattern,
+                default_init,
+            } => {
+                try_break!(self.visit_pattern(pattern));
+                self.default_init(default_init.as_ref())
+            }
+            ArrayPatternElement::SingleNameRest { ident } => {
+                self.push("...");
+                self.visit_identifier(ident)
+            }
+            ArrayPatternElement::PropertyAccessRest { access } => {
+                self.push("...");
+                self.visit_property_access(access)
+            }
+            ArrayPatternElement::PatternRest { pattern } => {
+                self.push("...");
+                self.visit_pattern(pattern)
+            }
+        }
+    }
+}
+
+/// Checks if `name` can be written as an identifier name instead of a string literal.
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '$' || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || matches!(c, '$' | '_' | '\u{200C}' | '\u{200D}'))
+}
+
+/// Checks if `name` can be written as a numeric property key without changing its value.
+fn is_array_index(name: &str) -> bool {
+    name == "0"
+        || (!name.starts_with('0') && !name.is_empty() && name.bytes().all(|b| b.is_ascii_digit()))
+}
@@ -3,6 +3,7 @@
 use crate::function::PrivateName;
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{Span, Spanned};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -214,6 +215,26 @@ pub enum MethodDefinition {
     Async(AsyncFunction),
 }
 
+impl Spanned for MethodDefinition {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Get(f) | Self::Set(f) | Self::Ordinary(f) => f.span(),
+            Self::Generator(g) => g.span(),
+            Self::AsyncGenerator(ag) => ag.span(),
+            Self::Async(af) => af.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Get(f) | Self::Set(f) | Self::Ordinary(f) => f.set_span(span),
+            Self::Generator(g) => g.set_span(span),
+            Self::AsyncGenerator(ag) => ag.set_span(span),
+            Self::Async(af) => af.set_span(span),
+        }
+    }
+}
+
 impl VisitWith for MethodDefinition {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -292,9 +313,11 @@ impl PropertyName {
     #[must_use]
     pub const fn prop_name(&self) -> Option<Sym> {
         match self {
-            Self::Literal(sym) | Self::Computed(Expression::Literal(Literal::String(sym))) => {
-                Some(*sym)
-            }
+            Self::Literal(sym) => Some(*sym),
+            Self::Computed(Expression::Literal(lit)) => match lit.literal() {
+                Literal::String(sym) => Some(*sym),
+                _ => None,
+            },
             Self::Computed(_) => None,
         }
     }
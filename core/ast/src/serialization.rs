@@ -0,0 +1,328 @@
+//! Interner-independent serialization of AST nodes.
+//!
+//! The derived `serde` implementations of the AST store every [`Sym`] as its raw index, which can
+//! only be read back with the exact [`Interner`] used while parsing. The wrappers in this module
+//! store the text of every identifier and string next to the node instead, so the output is
+//! self-contained and can be deserialized into any [`Interner`].
+
+use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use boa_interner::{Interner, ResolvedSym, Sym, SymRemap, SymSeed};
+use core::{fmt, marker::PhantomData, ops::ControlFlow};
+use rustc_hash::FxHashMap;
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, SeqAccess},
+    ser::{SerializeSeq, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::collections::BTreeSet;
+
+/// The name of the struct that contains the symbol table and the serialized node.
+const AST: &str = "Ast";
+
+/// The names of the fields of the [`AST`] struct.
+const FIELDS: &[&str] = &["symbols", "node"];
+
+/// Serializes an AST node, storing the text of every symbol it uses using an [`Interner`].
+///
+/// # Examples
+///
+/// ```ignore
+/// let json = serde_json::to_string(&AstSerializer::new(interner, &script))?;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AstSerializer<'a, T: ?Sized> {
+    interner: &'a Interner,
+    node: &'a T,
+}
+
+impl<'a, T: ?Sized> AstSerializer<'a, T> {
+    /// Creates a new `AstSerializer` for `node`, resolving its symbols with `interner`.
+    #[must_use]
+    pub const fn new(interner: &'a Interner, node: &'a T) -> Self {
+        Self { interner, node }
+    }
+}
+
+impl<T> Serialize for AstSerializer<'_, T>
+where
+    T: Serialize + VisitWith + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut syms = SymCollector::default();
+        let _ = self.node.visit_with(&mut syms);
+
+        let mut ast = serializer.serialize_struct(AST, FIELDS.len())?;
+        ast.serialize_field(
+            FIELDS[0],
+            &SymbolTable {
+                interner: self.interner,
+                syms: &syms.0,
+            },
+        )?;
+        ast.serialize_field(FIELDS[1], self.node)?;
+        ast.end()
+    }
+}
+
+/// Collects the symbols used by a node.
+#[derive(Debug, Default)]
+struct SymCollector(BTreeSet<Sym>);
+
+impl<'ast> Visitor<'ast> for SymCollector {
+    type BreakTy = ();
+
+    fn visit_sym(&mut self, node: &'ast Sym) -> ControlFlow<Self::BreakTy> {
+        self.0.insert(*node);
+        ControlFlow::Continue(())
+    }
+}
+
+/// Serializes symbols as pairs of their raw index and their text.
+struct SymbolTable<'a> {
+    interner: &'a Interner,
+    syms: &'a BTreeSet<Sym>,
+}
+
+impl Serialize for SymbolTable<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut table = serializer.serialize_seq(Some(self.syms.len()))?;
+        for &sym in self.syms {
+            table.serialize_element(&(sym, ResolvedSym::new(self.interner, sym)))?;
+        }
+        table.end()
+    }
+}
+
+/// Deserializes an AST node serialized by [`AstSerializer`], interning every symbol into an
+/// [`Interner`].
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut deserializer = serde_json::Deserializer::from_str(&json);
+/// let script: Script = AstDeserializer::new(interner).deserialize(&mut deserializer)?;
+/// ```
+#[derive(Debug)]
+pub struct AstDeserializer<'a, T> {
+    interner: &'a mut Interner,
+    node: PhantomData<T>,
+}
+
+impl<'a, T> AstDeserializer<'a, T> {
+    /// Creates a new `AstDeserializer`, interning the symbols of the node into `interner`.
+    pub fn new(interner: &'a mut Interner) -> Self {
+        Self {
+            interner,
+            node: PhantomData,
+        }
+    }
+
+    /// Maps the symbols of `node` through the symbol table that was serialized with it.
+    fn finish<E: de::Error>(table: Option<FxHashMap<Sym, Sym>>, node: Option<T>) -> Result<T, E>
+    where
+        T: VisitWith,
+    {
+        let table = table.ok_or_else(|| de::Error::missing_field(FIELDS[0]))?;
+        let mut node = node.ok_or_else(|| de::Error::missing_field(FIELDS[1]))?;
+        remap(&mut node, |sym| table.get(&sym).copied()).map_err(|sym| {
+            de::Error::custom(format_args!(
+                "symbol {} is not in the symbol table",
+                sym.get()
+            ))
+        })?;
+        Ok(node)
+    }
+}
+
+impl<'de, T> DeserializeSeed<'de> for AstDeserializer<'_, T>
+where
+    T: Deserialize<'de> + VisitWith,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(AST, FIELDS, self)
+    }
+}
+
+impl<'de, T> de::Visitor<'de> for AstDeserializer<'_, T>
+where
+    T: Deserialize<'de> + VisitWith,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an AST node with its symbol table")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let table = seq.next_element_seed(SymbolTableSeed {
+            interner: self.interner,
+        })?;
+        let node = seq.next_element()?;
+        Self::finish(table, node)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut table = None;
+        let mut node = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Symbols if table.is_some() => {
+                    return Err(de::Error::duplicate_field(FIELDS[0]));
+                }
+                Field::Symbols => {
+                    table = Some(map.next_value_seed(SymbolTableSeed {
+                        interner: &mut *self.interner,
+                    })?);
+                }
+                Field::Node if node.is_some() => return Err(de::Error::duplicate_field(FIELDS[1])),
+                Field::Node => node = Some(map.next_value()?),
+            }
+        }
+        Self::finish(table, node)
+    }
+}
+
+/// The fields of the [`AST`] struct.
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Symbols,
+    Node,
+}
+
+/// Interns the symbols of a symbol table, mapping their serialized index to their new symbol.
+struct SymbolTableSeed<'a> {
+    interner: &'a mut Interner,
+}
+
+impl<'de> DeserializeSeed<'de> for SymbolTableSeed<'_> {
+    type Value = FxHashMap<Sym, Sym>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for SymbolTableSeed<'_> {
+    type Value = FxHashMap<Sym, Sym>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence of symbols and their text")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut table = FxHashMap::default();
+        while let Some((old, new)) = seq.next_element_seed(SymbolSeed {
+            interner: &mut *self.interner,
+        })? {
+            table.insert(old, new);
+        }
+        Ok(table)
+    }
+}
+
+/// Deserializes a pair of a serialized symbol and its text, interning the text.
+struct SymbolSeed<'a> {
+    interner: &'a mut Interner,
+}
+
+impl<'de> DeserializeSeed<'de> for SymbolSeed<'_> {
+    type Value = (Sym, Sym);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for SymbolSeed<'_> {
+    type Value = (Sym, Sym);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a symbol and its text")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let old = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let new = seq
+            .next_element_seed(SymSeed::new(self.interner))?
+            .ok_or_else(|| de::Error::invalid_length(1, &"a symbol and its text"))?;
+        Ok((old, new))
+    }
+}
+
+/// Maps the symbols of `node`, deserialized from the raw symbols of another [`Interner`], to the
+/// symbols they have in the [`Interner`] that `remap` was restored into.
+///
+/// # Errors
+///
+/// Returns the first symbol of `node` that is not part of the snapshot `remap` was created from.
+pub fn remap_syms<T>(node: &mut T, remap: &SymRemap) -> Result<(), Sym>
+where
+    T: VisitWith + ?Sized,
+{
+    self::remap(node, |sym| remap.get(sym))
+}
+
+/// Replaces every symbol of `node` with the result of `f`, or returns the first symbol for which
+/// `f` returns `None`.
+fn remap<T, F>(node: &mut T, f: F) -> Result<(), Sym>
+where
+    T: VisitWith + ?Sized,
+    F: Fn(Sym) -> Option<Sym>,
+{
+    /// Replaces the symbols it visits.
+    struct Remapper<F>(F);
+
+    impl<'ast, F> VisitorMut<'ast> for Remapper<F>
+    where
+        F: Fn(Sym) -> Option<Sym>,
+    {
+        type BreakTy = Sym;
+
+        fn visit_sym_mut(&mut self, node: &'ast mut Sym) -> ControlFlow<Self::BreakTy> {
+            match (self.0)(*node) {
+                Some(sym) => {
+                    *node = sym;
+                    ControlFlow::Continue(())
+                }
+                None => ControlFlow::Break(*node),
+            }
+        }
+    }
+
+    match node.visit_with_mut(&mut Remapper(f)) {
+        ControlFlow::Continue(()) => Ok(()),
+        ControlFlow::Break(sym) => Err(sym),
+    }
+}
@@ -1,5 +1,6 @@
 //! Block AST node.
 
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     visitor::{VisitWith, Visitor, VisitorMut},
     Statement, StatementList,
@@ -28,8 +29,11 @@ use core::ops::ControlFlow;
 pub struct Block {
     #[cfg_attr(feature = "serde", serde(flatten))]
     statements: StatementList,
+    span: NodeSpan,
 }
 
+impl_spanned!(Block);
+
 impl Block {
     /// Gets the list of statements and declarations in this block.
     #[inline]
@@ -46,6 +50,7 @@ where
     fn from(list: T) -> Self {
         Self {
             statements: list.into(),
+            span: NodeSpan::NONE,
         }
     }
 }
@@ -0,0 +1,67 @@
+use crate::position::{impl_spanned, NodeSpan};
+use crate::{
+    statement::Statement,
+    visitor::{VisitWith, Visitor, VisitorMut},
+};
+use boa_interner::{Interner, ToInternedString};
+use core::ops::ControlFlow;
+
+/// The `debugger` statement invokes any available debugging functionality, such as setting a
+/// breakpoint.
+///
+/// Syntax: `debugger;`
+///
+/// If no debugging functionality is available, this statement has no effect.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-DebuggerStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/debugger
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Debugger {
+    span: NodeSpan,
+}
+
+impl_spanned!(Debugger);
+
+impl Debugger {
+    /// Creates a `Debugger` AST node.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            span: NodeSpan::NONE,
+        }
+    }
+}
+
+impl ToInternedString for Debugger {
+    fn to_interned_string(&self, _interner: &Interner) -> String {
+        "debugger".to_owned()
+    }
+}
+
+impl From<Debugger> for Statement {
+    fn from(debugger: Debugger) -> Self {
+        Self::Debugger(debugger)
+    }
+}
+
+impl VisitWith for Debugger {
+    fn visit_with<'a, V>(&'a self, _visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, _visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        ControlFlow::Continue(())
+    }
+}
@@ -1,5 +1,6 @@
 //! If statement
 
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     expression::Expression,
     statement::Statement,
@@ -32,8 +33,11 @@ pub struct If {
     condition: Expression,
     body: Box<Statement>,
     else_node: Option<Box<Statement>>,
+    span: NodeSpan,
 }
 
+impl_spanned!(If);
+
 impl If {
     /// Gets the condition of the if statement.
     #[inline]
@@ -63,6 +67,7 @@ impl If {
             condition,
             body: body.into(),
             else_node: else_node.map(Box::new),
+            span: NodeSpan::NONE,
         }
     }
 }
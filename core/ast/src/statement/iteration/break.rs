@@ -1,6 +1,7 @@
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
+use crate::position::{impl_spanned, NodeSpan};
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::Statement;
 
@@ -23,13 +24,19 @@ use crate::Statement;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Break {
     label: Option<Sym>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Break);
+
 impl Break {
     /// Creates a `Break` AST node.
     #[must_use]
     pub const fn new(label: Option<Sym>) -> Self {
-        Self { label }
+        Self {
+            label,
+            span: NodeSpan::NONE,
+        }
     }
 
     /// Gets the label of the break statement, if any.
@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::statement::Statement;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, Sym, ToInternedString};
@@ -21,13 +22,19 @@ use core::ops::ControlFlow;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Continue {
     label: Option<Sym>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Continue);
+
 impl Continue {
     /// Creates a `Continue` AST node.
     #[must_use]
     pub const fn new(label: Option<Sym>) -> Self {
-        Self { label }
+        Self {
+            label,
+            span: NodeSpan::NONE,
+        }
     }
 
     /// Gets the label of this `Continue` statement.
@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     expression::Expression,
     statement::Statement,
@@ -25,8 +26,11 @@ use core::ops::ControlFlow;
 pub struct DoWhileLoop {
     body: Box<Statement>,
     condition: Expression,
+    span: NodeSpan,
 }
 
+impl_spanned!(DoWhileLoop);
+
 impl DoWhileLoop {
     /// Gets the body of the do-while loop.
     #[inline]
@@ -48,6 +52,7 @@ impl DoWhileLoop {
         Self {
             body: body.into(),
             condition,
+            span: NodeSpan::NONE,
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
@@ -21,8 +22,11 @@ pub struct ForInLoop {
     initializer: IterableLoopInitializer,
     target: Expression,
     body: Box<Statement>,
+    span: NodeSpan,
 }
 
+impl_spanned!(ForInLoop);
+
 impl ForInLoop {
     /// Creates a new `ForInLoop`.
     #[inline]
@@ -32,6 +36,7 @@ impl ForInLoop {
             initializer,
             target,
             body: body.into(),
+            span: NodeSpan::NONE,
         }
     }
 
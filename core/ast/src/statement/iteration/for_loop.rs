@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
@@ -24,8 +25,11 @@ use core::ops::ControlFlow;
 pub struct ForLoop {
     #[cfg_attr(feature = "serde", serde(flatten))]
     inner: Box<InnerForLoop>,
+    span: NodeSpan,
 }
 
+impl_spanned!(ForLoop);
+
 impl ForLoop {
     /// Creates a new for loop AST node.
     #[inline]
@@ -38,6 +42,7 @@ impl ForLoop {
     ) -> Self {
         Self {
             inner: Box::new(InnerForLoop::new(init, condition, final_expr, body)),
+            span: NodeSpan::NONE,
         }
     }
 
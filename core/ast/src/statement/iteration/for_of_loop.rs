@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
@@ -27,8 +28,11 @@ pub struct ForOfLoop {
     iterable: Expression,
     body: Box<Statement>,
     r#await: bool,
+    span: NodeSpan,
 }
 
+impl_spanned!(ForOfLoop);
+
 impl ForOfLoop {
     /// Creates a new "for of" loop AST node.
     #[inline]
@@ -44,6 +48,7 @@ impl ForOfLoop {
             iterable,
             body: body.into(),
             r#await,
+            span: NodeSpan::NONE,
         }
     }
 
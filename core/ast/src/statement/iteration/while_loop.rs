@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     expression::Expression,
     statement::Statement,
@@ -24,8 +25,11 @@ use core::ops::ControlFlow;
 pub struct WhileLoop {
     condition: Expression,
     body: Box<Statement>,
+    span: NodeSpan,
 }
 
+impl_spanned!(WhileLoop);
+
 impl WhileLoop {
     /// Creates a `WhileLoop` AST node.
     #[inline]
@@ -34,6 +38,7 @@ impl WhileLoop {
         Self {
             condition,
             body: body.into(),
+            span: NodeSpan::NONE,
         }
     }
 
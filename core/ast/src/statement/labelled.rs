@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     function::Function,
     try_break,
@@ -87,8 +88,11 @@ impl VisitWith for LabelledItem {
 pub struct Labelled {
     item: Box<LabelledItem>,
     label: Sym,
+    span: NodeSpan,
 }
 
+impl_spanned!(Labelled);
+
 impl Labelled {
     /// Creates a new `Labelled` statement.
     #[inline]
@@ -97,6 +101,7 @@ impl Labelled {
         Self {
             item: Box::new(item),
             label,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -8,6 +8,7 @@
 //! [statements]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 
 mod block;
+mod debugger;
 mod r#if;
 mod labelled;
 mod r#return;
@@ -20,6 +21,7 @@ pub mod iteration;
 
 pub use self::{
     block::Block,
+    debugger::Debugger,
     iteration::{Break, Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
     labelled::{Labelled, LabelledItem},
     r#if::If,
@@ -32,6 +34,7 @@ pub use self::{
 use core::ops::ControlFlow;
 
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{Span, Spanned};
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 
 use super::{declaration::VarDeclaration, expression::Expression};
@@ -105,6 +108,15 @@ pub enum Statement {
 
     /// See [`With`].
     With(With),
+
+    /// See [`Debugger`].
+    Debugger(Debugger),
+
+    /// A placeholder for a statement that could not be parsed.
+    ///
+    /// This is only produced by the parser when recovering from syntax errors. An AST containing
+    /// this statement is not valid, and must never be compiled or executed.
+    Error(Span),
 }
 
 impl Statement {
@@ -134,6 +146,8 @@ impl Statement {
             Self::Throw(throw) => throw.to_interned_string(interner),
             Self::Try(try_catch) => return try_catch.to_indented_string(interner, indentation),
             Self::With(with) => return with.to_interned_string(interner),
+            Self::Debugger(debugger) => debugger.to_interned_string(interner),
+            Self::Error(_) => return "/* syntax error */".to_owned(),
         };
         s.push(';');
         s
@@ -187,6 +201,58 @@ impl ToIndentedString for Statement {
     }
 }
 
+impl Spanned for Statement {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Empty => None,
+            Self::Block(node) => node.span(),
+            Self::Var(node) => node.span(),
+            Self::Expression(node) => node.span(),
+            Self::If(node) => node.span(),
+            Self::DoWhileLoop(node) => node.span(),
+            Self::WhileLoop(node) => node.span(),
+            Self::ForLoop(node) => node.span(),
+            Self::ForInLoop(node) => node.span(),
+            Self::ForOfLoop(node) => node.span(),
+            Self::Switch(node) => node.span(),
+            Self::Continue(node) => node.span(),
+            Self::Break(node) => node.span(),
+            Self::Return(node) => node.span(),
+            Self::Labelled(node) => node.span(),
+            Self::Throw(node) => node.span(),
+            Self::Try(node) => node.span(),
+            Self::With(node) => node.span(),
+            Self::Debugger(node) => node.span(),
+            Self::Error(span) => Some(*span),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Empty => {}
+            Self::Block(node) => node.set_span(span),
+            Self::Var(node) => node.set_span(span),
+            Self::Expression(node) => node.set_span(span),
+            Self::If(node) => node.set_span(span),
+            Self::DoWhileLoop(node) => node.set_span(span),
+            Self::WhileLoop(node) => node.set_span(span),
+            Self::ForLoop(node) => node.set_span(span),
+            Self::ForInLoop(node) => node.set_span(span),
+            Self::ForOfLoop(node) => node.set_span(span),
+            Self::Switch(node) => node.set_span(span),
+            Self::Continue(node) => node.set_span(span),
+            Self::Break(node) => node.set_span(span),
+            Self::Return(node) => node.set_span(span),
+            Self::Labelled(node) => node.set_span(span),
+            Self::Throw(node) => node.set_span(span),
+            Self::Try(node) => node.set_span(span),
+            Self::With(node) => node.set_span(span),
+            Self::Debugger(node) => node.set_span(span),
+            Self::Error(error) => *error = span,
+        }
+    }
+}
+
 impl VisitWith for Statement {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -195,7 +261,7 @@ impl VisitWith for Statement {
         match self {
             Self::Block(b) => visitor.visit_block(b),
             Self::Var(v) => visitor.visit_var_declaration(v),
-            Self::Empty => {
+            Self::Empty | Self::Error(_) => {
                 // do nothing; there is nothing to visit here
                 ControlFlow::Continue(())
             }
@@ -214,6 +280,7 @@ impl VisitWith for Statement {
             Self::Throw(th) => visitor.visit_throw(th),
             Self::Try(tr) => visitor.visit_try(tr),
             Self::With(with) => visitor.visit_with(with),
+            Self::Debugger(debugger) => visitor.visit_debugger(debugger),
         }
     }
 
@@ -224,7 +291,7 @@ impl VisitWith for Statement {
         match self {
             Self::Block(b) => visitor.visit_block_mut(b),
             Self::Var(v) => visitor.visit_var_declaration_mut(v),
-            Self::Empty => {
+            Self::Empty | Self::Error(_) => {
                 // do nothing; there is nothing to visit here
                 ControlFlow::Continue(())
             }
@@ -243,6 +310,7 @@ impl VisitWith for Statement {
             Self::Throw(th) => visitor.visit_throw_mut(th),
             Self::Try(tr) => visitor.visit_try_mut(tr),
             Self::With(with) => visitor.visit_with_mut(with),
+            Self::Debugger(debugger) => visitor.visit_debugger_mut(debugger),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     expression::Expression,
     statement::Statement,
@@ -28,8 +29,11 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Return {
     target: Option<Expression>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Return);
+
 impl Return {
     /// Gets the target expression value of this `Return` statement.
     #[must_use]
@@ -40,7 +44,10 @@ impl Return {
     /// Creates a `Return` AST node.
     #[must_use]
     pub const fn new(expression: Option<Expression>) -> Self {
-        Self { target: expression }
+        Self {
+            target: expression,
+            span: NodeSpan::NONE,
+        }
     }
 }
 
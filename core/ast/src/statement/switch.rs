@@ -1,4 +1,5 @@
 //! Switch node.
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     expression::Expression,
     statement::Statement,
@@ -116,14 +117,21 @@ impl VisitWith for Case {
 pub struct Switch {
     val: Expression,
     cases: Box<[Case]>,
+    span: NodeSpan,
 }
 
+impl_spanned!(Switch);
+
 impl Switch {
     /// Creates a `Switch` AST node.
     #[inline]
     #[must_use]
     pub fn new(val: Expression, cases: Box<[Case]>) -> Self {
-        Self { val, cases }
+        Self {
+            val,
+            cases,
+            span: NodeSpan::NONE,
+        }
     }
 
     /// Gets the value to switch.
@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -25,8 +26,11 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Throw {
     target: Expression,
+    span: NodeSpan,
 }
 
+impl_spanned!(Throw);
+
 impl Throw {
     /// Gets the target expression of this `Throw` statement.
     #[must_use]
@@ -37,7 +41,10 @@ impl Throw {
     /// Creates a `Throw` AST node.
     #[must_use]
     pub const fn new(target: Expression) -> Self {
-        Self { target }
+        Self {
+            target,
+            span: NodeSpan::NONE,
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 //! Error handling statements
 
+use crate::position::{impl_spanned, NodeSpan};
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
@@ -28,8 +29,11 @@ use core::ops::ControlFlow;
 pub struct Try {
     block: Block,
     handler: ErrorHandler,
+    span: NodeSpan,
 }
 
+impl_spanned!(Try);
+
 /// The type of error handler in a [`Try`] statement.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -48,7 +52,11 @@ impl Try {
     #[inline]
     #[must_use]
     pub const fn new(block: Block, handler: ErrorHandler) -> Self {
-        Self { block, handler }
+        Self {
+            block,
+            handler,
+            span: NodeSpan::NONE,
+        }
     }
 
     /// Gets the `try` block.
@@ -1,3 +1,4 @@
+use crate::position::{impl_spanned, NodeSpan};
 use crate::{
     expression::Expression,
     statement::Statement,
@@ -21,8 +22,11 @@ use core::ops::ControlFlow;
 pub struct With {
     expression: Expression,
     statement: Box<Statement>,
+    span: NodeSpan,
 }
 
+impl_spanned!(With);
+
 impl With {
     /// Creates a `With` AST node.
     #[must_use]
@@ -30,6 +34,7 @@ impl With {
         Self {
             expression,
             statement: Box::new(statement),
+            span: NodeSpan::NONE,
         }
     }
 
@@ -6,6 +6,7 @@ use crate::{
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
+use crate::{Span, Spanned};
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
 use std::ops::Deref;
@@ -67,6 +68,22 @@ impl From<Declaration> for StatementListItem {
     }
 }
 
+impl Spanned for StatementListItem {
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Statement(node) => node.span(),
+            Self::Declaration(node) => node.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Statement(node) => node.set_span(span),
+            Self::Declaration(node) => node.set_span(span),
+        }
+    }
+}
+
 impl VisitWith for StatementListItem {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -3,7 +3,7 @@
 //! This module contains visitors which can be used to inspect or modify AST nodes. This allows for
 //! fine-grained manipulation of ASTs for analysis, rewriting, or instrumentation.
 
-use std::ops::ControlFlow;
+use std::{convert::Infallible, ops::ControlFlow};
 
 use crate::{
     declaration::{
@@ -36,10 +36,10 @@ use crate::{
             Break, Continue, DoWhileLoop, ForInLoop, ForLoop, ForLoopInitializer, ForOfLoop,
             IterableLoopInitializer, WhileLoop,
         },
-        Block, Case, Catch, Finally, If, Labelled, LabelledItem, Return, Statement, Switch, Throw,
-        Try, With,
+        Block, Case, Catch, Debugger, Finally, If, Labelled, LabelledItem, Return, Statement,
+        Switch, Throw, Try, With,
     },
-    Module, ModuleItem, ModuleItemList, Script, StatementList, StatementListItem,
+    Module, ModuleItem, ModuleItemList, Script, Spanned, StatementList, StatementListItem,
 };
 use boa_interner::Sym;
 
@@ -148,6 +148,7 @@ node_ref! {
     With,
     Throw,
     Try,
+    Debugger,
     Identifier,
     FormalParameterList,
     ClassElement,
@@ -251,6 +252,7 @@ pub trait Visitor<'ast>: Sized {
     define_visit!(visit_throw, Throw);
     define_visit!(visit_try, Try);
     define_visit!(visit_with, With);
+    define_visit!(visit_debugger, Debugger);
     define_visit!(visit_identifier, Identifier);
     define_visit!(visit_formal_parameter_list, FormalParameterList);
     define_visit!(visit_class_element, ClassElement);
@@ -351,6 +353,7 @@ pub trait Visitor<'ast>: Sized {
             NodeRef::With(n) => self.visit_with(n),
             NodeRef::Throw(n) => self.visit_throw(n),
             NodeRef::Try(n) => self.visit_try(n),
+            NodeRef::Debugger(n) => self.visit_debugger(n),
             NodeRef::Identifier(n) => self.visit_identifier(n),
             NodeRef::FormalParameterList(n) => self.visit_formal_parameter_list(n),
             NodeRef::ClassElement(n) => self.visit_class_element(n),
@@ -456,6 +459,7 @@ pub trait VisitorMut<'ast>: Sized {
     define_visit_mut!(visit_throw_mut, Throw);
     define_visit_mut!(visit_try_mut, Try);
     define_visit_mut!(visit_with_mut, With);
+    define_visit_mut!(visit_debugger_mut, Debugger);
     define_visit_mut!(visit_identifier_mut, Identifier);
     define_visit_mut!(visit_formal_parameter_list_mut, FormalParameterList);
     define_visit_mut!(visit_class_element_mut, ClassElement);
@@ -556,6 +560,7 @@ pub trait VisitorMut<'ast>: Sized {
             NodeRefMut::With(n) => self.visit_with_mut(n),
             NodeRefMut::Throw(n) => self.visit_throw_mut(n),
             NodeRefMut::Try(n) => self.visit_try_mut(n),
+            NodeRefMut::Debugger(n) => self.visit_debugger_mut(n),
             NodeRefMut::Identifier(n) => self.visit_identifier_mut(n),
             NodeRefMut::FormalParameterList(n) => self.visit_formal_parameter_list_mut(n),
             NodeRefMut::ClassElement(n) => self.visit_class_element_mut(n),
@@ -655,3 +660,64 @@ impl VisitWith for Sym {
         core::ops::ControlFlow::Continue(())
     }
 }
+
+/// Represents an AST pass which can replace expressions and statements with different nodes.
+///
+/// Unlike a [`VisitorMut`], which can only modify the fields of the node it is visiting, a `Fold`
+/// takes each node by value and returns the node that will take its place, which may be a
+/// completely different variant (e.g. replacing `1 + 2` with `3`).
+///
+/// The tree is traversed bottom-up: the children of a node are folded before the node itself.
+/// If the replacement doesn't have a span, it inherits the span of the node it replaces.
+pub trait Fold: Sized {
+    /// Folds an `Expression`, after all its children have been folded.
+    fn fold_expression(&mut self, node: Expression) -> Expression {
+        node
+    }
+
+    /// Folds a `Statement`, after all its children have been folded.
+    fn fold_statement(&mut self, node: Statement) -> Statement {
+        node
+    }
+
+    /// Folds all expressions and statements of an AST node, including the node itself.
+    fn fold<'a, N: Into<NodeRefMut<'a>>>(&mut self, node: N) {
+        match Folder(self).visit(node) {
+            ControlFlow::Continue(()) => {}
+            ControlFlow::Break(never) => match never {},
+        }
+    }
+}
+
+/// Adapts a [`Fold`] to the [`VisitorMut`] traversal.
+struct Folder<'f, F>(&'f mut F);
+
+impl<'ast, F: Fold> VisitorMut<'ast> for Folder<'_, F> {
+    type BreakTy = Infallible;
+
+    fn visit_expression_mut(&mut self, node: &'ast mut Expression) -> ControlFlow<Self::BreakTy> {
+        try_break!(node.visit_with_mut(self));
+        let span = node.span();
+        let mut new = self
+            .0
+            .fold_expression(std::mem::replace(node, Expression::This));
+        if let (Some(span), None) = (span, new.span()) {
+            new.set_span(span);
+        }
+        *node = new;
+        ControlFlow::Continue(())
+    }
+
+    fn visit_statement_mut(&mut self, node: &'ast mut Statement) -> ControlFlow<Self::BreakTy> {
+        try_break!(node.visit_with_mut(self));
+        let span = node.span();
+        let mut new = self
+            .0
+            .fold_statement(std::mem::replace(node, Statement::Empty));
+        if let (Some(span), None) = (span, new.span()) {
+            new.set_span(span);
+        }
+        *node = new;
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,13 @@
+(function () {
+  let testArr = [];
+  for (let a = 0; a < 500; a++) {
+    testArr.push(a);
+  }
+
+  let sum = 0;
+  for (const value of testArr) {
+    sum += value;
+  }
+
+  return sum;
+})();
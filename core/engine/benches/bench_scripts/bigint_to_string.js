@@ -0,0 +1,6 @@
+(function () {
+  const x = ((1n << 1000000n) - 1n) / 7n;
+  const decimal = x.toString();
+
+  return BigInt(decimal) === x;
+})();
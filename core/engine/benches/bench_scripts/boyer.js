@@ -0,0 +1,93 @@
+(function () {
+  // Term rewriting over cons cells, in the style of the `boyer` half of the V8 `earley-boyer`
+  // benchmark. Every rewrite allocates new cells, and almost none of them survive.
+  function cons(car, cdr) {
+    return { car, cdr };
+  }
+
+  function list(...items) {
+    let result = null;
+    for (let i = items.length - 1; i >= 0; i--) {
+      result = cons(items[i], result);
+    }
+    return result;
+  }
+
+  function isCons(term) {
+    return term !== null && typeof term === "object";
+  }
+
+  function firstArg(term) {
+    return term.cdr.car;
+  }
+
+  function secondArg(term) {
+    return term.cdr.cdr.car;
+  }
+
+  // Every rule takes the rewritten arguments of a term and returns its replacement, or `null`.
+  const rules = new Map([
+    ["plus", (a, b) => (b === "zero" ? a : null)],
+    [
+      "times",
+      (a, b) =>
+        isCons(b) && b.car === "plus"
+          ? list("plus", list("times", a, firstArg(b)), list("times", a, secondArg(b)))
+          : null,
+    ],
+    ["difference", (a, b) => (a === b ? "zero" : null)],
+    ["equal", (a, b) => (a === b ? "t" : null)],
+    [
+      "append",
+      (a, b) =>
+        isCons(a) && a.car === "append"
+          ? list("append", firstArg(a), list("append", secondArg(a), b))
+          : null,
+    ],
+    [
+      "reverse",
+      (a) =>
+        isCons(a) && a.car === "append"
+          ? list("append", list("reverse", secondArg(a)), list("reverse", firstArg(a)))
+          : null,
+    ],
+  ]);
+
+  function rewriteArgs(args) {
+    return args === null ? null : cons(rewrite(args.car), rewriteArgs(args.cdr));
+  }
+
+  function rewrite(term) {
+    if (!isCons(term)) {
+      return term;
+    }
+    const op = term.car;
+    const args = rewriteArgs(term.cdr);
+    const rule = rules.get(op);
+    if (rule !== undefined) {
+      const result = rule(args.car, args.cdr === null ? null : args.cdr.car);
+      if (result !== null) {
+        return rewrite(result);
+      }
+    }
+    return cons(op, args);
+  }
+
+  function build(depth) {
+    if (depth === 0) {
+      return list("plus", "x", "zero");
+    }
+    return list(
+      depth % 2 === 0 ? "times" : "append",
+      build(depth - 1),
+      list("plus", list("reverse", build(Math.max(depth - 2, 0))), "y"),
+    );
+  }
+
+  let result;
+  for (let i = 0; i < 20; i++) {
+    result = rewrite(build(8));
+  }
+
+  return result.car;
+})();
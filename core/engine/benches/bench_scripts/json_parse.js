@@ -0,0 +1,14 @@
+(function () {
+  let items = [];
+  for (let i = 0; i < 1000; i++) {
+    items.push({
+      id: i,
+      name: "item" + i,
+      tags: ["alpha", "beta", "gamma"],
+      description: "A moderately long ASCII description for item number " + i,
+    });
+  }
+  let json = JSON.stringify({ items: items });
+
+  return JSON.parse(json).items.length;
+})();
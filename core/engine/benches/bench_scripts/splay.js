@@ -0,0 +1,121 @@
+(function () {
+  // A splay tree whose nodes carry freshly allocated payload trees, in the style of the V8
+  // `splay` benchmark. Most payloads die young, while the tree itself stays alive.
+  function SplayTree() {
+    this.root = null;
+  }
+
+  SplayTree.prototype.splay = function (key) {
+    if (this.root === null) {
+      return;
+    }
+    let dummy = { left: null, right: null };
+    let left = dummy;
+    let right = dummy;
+    let current = this.root;
+    while (true) {
+      if (key < current.key) {
+        if (current.left === null) break;
+        if (key < current.left.key) {
+          const tmp = current.left;
+          current.left = tmp.right;
+          tmp.right = current;
+          current = tmp;
+          if (current.left === null) break;
+        }
+        right.left = current;
+        right = current;
+        current = current.left;
+      } else if (key > current.key) {
+        if (current.right === null) break;
+        if (key > current.right.key) {
+          const tmp = current.right;
+          current.right = tmp.left;
+          tmp.left = current;
+          current = tmp;
+          if (current.right === null) break;
+        }
+        left.right = current;
+        left = current;
+        current = current.right;
+      } else {
+        break;
+      }
+    }
+    left.right = current.left;
+    right.left = current.right;
+    current.left = dummy.right;
+    current.right = dummy.left;
+    this.root = current;
+  };
+
+  SplayTree.prototype.insert = function (key, value) {
+    if (this.root === null) {
+      this.root = { key, value, left: null, right: null };
+      return;
+    }
+    this.splay(key);
+    if (this.root.key === key) {
+      return;
+    }
+    const node = { key, value, left: null, right: null };
+    if (key > this.root.key) {
+      node.left = this.root;
+      node.right = this.root.right;
+      this.root.right = null;
+    } else {
+      node.right = this.root;
+      node.left = this.root.left;
+      this.root.left = null;
+    }
+    this.root = node;
+  };
+
+  SplayTree.prototype.remove = function (key) {
+    this.splay(key);
+    const removed = this.root;
+    if (removed.left === null) {
+      this.root = removed.right;
+    } else {
+      const right = removed.right;
+      this.root = removed.left;
+      this.splay(key);
+      this.root.right = right;
+    }
+    return removed;
+  };
+
+  SplayTree.prototype.findMax = function () {
+    let current = this.root;
+    while (current.right !== null) {
+      current = current.right;
+    }
+    return current;
+  };
+
+  function payload(depth, tag) {
+    if (depth === 0) {
+      return { array: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9], string: "String for key " + tag };
+    }
+    return { left: payload(depth - 1, tag), right: payload(depth - 1, tag) };
+  }
+
+  let seed = 49734321;
+  function random() {
+    seed = (seed * 1103515245 + 12345) % 2147483648;
+    return seed / 2147483648;
+  }
+
+  const tree = new SplayTree();
+  for (let i = 0; i < 2000; i++) {
+    const key = random();
+    tree.insert(key, payload(3, key));
+  }
+  for (let i = 0; i < 2000; i++) {
+    const key = random();
+    tree.insert(key, payload(3, key));
+    tree.remove(tree.findMax().key);
+  }
+
+  return tree.findMax().key;
+})();
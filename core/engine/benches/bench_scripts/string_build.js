@@ -0,0 +1,8 @@
+(function () {
+  let result = "";
+  for (let i = 0; i < 100000; i++) {
+    result += "chunk";
+  }
+
+  return result.length;
+})();
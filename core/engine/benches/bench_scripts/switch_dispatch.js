@@ -0,0 +1,317 @@
+(function () {
+  function dispatch(value) {
+    let sum = 0;
+    switch (value) {
+      case 0:
+        sum += 0;
+        break;
+      case 1:
+        sum += 1;
+        break;
+      case 2:
+        sum += 2;
+        break;
+      case 3:
+        sum += 3;
+        break;
+      case 4:
+        sum += 4;
+        break;
+      case 5:
+        sum += 5;
+        break;
+      case 6:
+        sum += 6;
+        break;
+      case 7:
+        sum += 0;
+        break;
+      case 8:
+        sum += 1;
+        break;
+      case 9:
+        sum += 2;
+        break;
+      case 10:
+        sum += 3;
+        break;
+      case 11:
+        sum += 4;
+        break;
+      case 12:
+        sum += 5;
+        break;
+      case 13:
+        sum += 6;
+        break;
+      case 14:
+        sum += 0;
+        break;
+      case 15:
+        sum += 1;
+        break;
+      case 16:
+        sum += 2;
+        break;
+      case 17:
+        sum += 3;
+        break;
+      case 18:
+        sum += 4;
+        break;
+      case 19:
+        sum += 5;
+        break;
+      case 20:
+        sum += 6;
+        break;
+      case 21:
+        sum += 0;
+        break;
+      case 22:
+        sum += 1;
+        break;
+      case 23:
+        sum += 2;
+        break;
+      case 24:
+        sum += 3;
+        break;
+      case 25:
+        sum += 4;
+        break;
+      case 26:
+        sum += 5;
+        break;
+      case 27:
+        sum += 6;
+        break;
+      case 28:
+        sum += 0;
+        break;
+      case 29:
+        sum += 1;
+        break;
+      case 30:
+        sum += 2;
+        break;
+      case 31:
+        sum += 3;
+        break;
+      case 32:
+        sum += 4;
+        break;
+      case 33:
+        sum += 5;
+        break;
+      case 34:
+        sum += 6;
+        break;
+      case 35:
+        sum += 0;
+        break;
+      case 36:
+        sum += 1;
+        break;
+      case 37:
+        sum += 2;
+        break;
+      case 38:
+        sum += 3;
+        break;
+      case 39:
+        sum += 4;
+        break;
+      case 40:
+        sum += 5;
+        break;
+      case 41:
+        sum += 6;
+        break;
+      case 42:
+        sum += 0;
+        break;
+      case 43:
+        sum += 1;
+        break;
+      case 44:
+        sum += 2;
+        break;
+      case 45:
+        sum += 3;
+        break;
+      case 46:
+        sum += 4;
+        break;
+      case 47:
+        sum += 5;
+        break;
+      case 48:
+        sum += 6;
+        break;
+      case 49:
+        sum += 0;
+        break;
+      case 50:
+        sum += 1;
+        break;
+      case 51:
+        sum += 2;
+        break;
+      case 52:
+        sum += 3;
+        break;
+      case 53:
+        sum += 4;
+        break;
+      case 54:
+        sum += 5;
+        break;
+      case 55:
+        sum += 6;
+        break;
+      case 56:
+        sum += 0;
+        break;
+      case 57:
+        sum += 1;
+        break;
+      case 58:
+        sum += 2;
+        break;
+      case 59:
+        sum += 3;
+        break;
+      case 60:
+        sum += 4;
+        break;
+      case 61:
+        sum += 5;
+        break;
+      case 62:
+        sum += 6;
+        break;
+      case 63:
+        sum += 0;
+        break;
+      case 64:
+        sum += 1;
+        break;
+      case 65:
+        sum += 2;
+        break;
+      case 66:
+        sum += 3;
+        break;
+      case 67:
+        sum += 4;
+        break;
+      case 68:
+        sum += 5;
+        break;
+      case 69:
+        sum += 6;
+        break;
+      case 70:
+        sum += 0;
+        break;
+      case 71:
+        sum += 1;
+        break;
+      case 72:
+        sum += 2;
+        break;
+      case 73:
+        sum += 3;
+        break;
+      case 74:
+        sum += 4;
+        break;
+      case 75:
+        sum += 5;
+        break;
+      case 76:
+        sum += 6;
+        break;
+      case 77:
+        sum += 0;
+        break;
+      case 78:
+        sum += 1;
+        break;
+      case 79:
+        sum += 2;
+        break;
+      case 80:
+        sum += 3;
+        break;
+      case 81:
+        sum += 4;
+        break;
+      case 82:
+        sum += 5;
+        break;
+      case 83:
+        sum += 6;
+        break;
+      case 84:
+        sum += 0;
+        break;
+      case 85:
+        sum += 1;
+        break;
+      case 86:
+        sum += 2;
+        break;
+      case 87:
+        sum += 3;
+        break;
+      case 88:
+        sum += 4;
+        break;
+      case 89:
+        sum += 5;
+        break;
+      case 90:
+        sum += 6;
+        break;
+      case 91:
+        sum += 0;
+        break;
+      case 92:
+        sum += 1;
+        break;
+      case 93:
+        sum += 2;
+        break;
+      case 94:
+        sum += 3;
+        break;
+      case 95:
+        sum += 4;
+        break;
+      case 96:
+        sum += 5;
+        break;
+      case 97:
+        sum += 6;
+        break;
+      case 98:
+        sum += 0;
+        break;
+      case 99:
+        sum += 1;
+        break;
+      default:
+        sum -= 1;
+    }
+    return sum;
+  }
+
+  let sum = 0;
+  for (let i = 0; i < 1000; i++) {
+    sum += dispatch(i % 101);
+  }
+
+  return sum;
+})();
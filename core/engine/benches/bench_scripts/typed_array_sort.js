@@ -0,0 +1,10 @@
+(function () {
+  const testArr = new Float64Array(1000000);
+  let seed = 1;
+  for (let i = 0; i < testArr.length; i++) {
+    seed = (seed * 16807) % 2147483647;
+    testArr[i] = seed / 2147483647 - 0.5;
+  }
+
+  return testArr.sort();
+})();
@@ -91,6 +91,50 @@ macro_rules! full_benchmarks {
                 }
             )*
         }
+        #[cfg(feature = "bytecode-cache")]
+        fn bench_cached(c: &mut Criterion) {
+            $(
+                {
+                    static CODE: &str = include_str!(concat!("bench_scripts/", stringify!($name), ".js"));
+
+                    // Each iteration needs a fresh realm to declare the globals of the script.
+                    let new_context = || {
+                        let mut context = Context::default();
+                        context.set_optimizer_options(OptimizerOptions::empty());
+                        context
+                    };
+
+                    c.bench_function(concat!($id, " (Parse and compile)"), |b| {
+                        b.iter_batched(
+                            new_context,
+                            |mut context| {
+                                let script = Script::parse(
+                                    black_box(Source::from_bytes(CODE)),
+                                    None,
+                                    &mut context,
+                                ).unwrap();
+                                script.codeblock(&mut context).unwrap();
+                                (script, context)
+                            },
+                            criterion::BatchSize::SmallInput,
+                        )
+                    });
+
+                    let mut cache = Vec::new();
+                    new_context().compile_cached(CODE, &mut cache).unwrap();
+                    c.bench_function(concat!($id, " (Cached load)"), |b| {
+                        b.iter_batched(
+                            new_context,
+                            |mut context| {
+                                let script = context.compile_cached(black_box(CODE), &mut cache).unwrap();
+                                (script, context)
+                            },
+                            criterion::BatchSize::SmallInput,
+                        )
+                    });
+                }
+            )*
+        }
     };
 }
 
@@ -108,15 +152,23 @@ full_benchmarks!(
     {"Array access", array_access},
     {"Array creation", array_create},
     {"Array pop", array_pop},
+    {"Array for-of", array_for_of},
+    {"TypedArray sort", typed_array_sort},
+    {"BigInt to string", bigint_to_string},
+    {"Switch dispatch", switch_dispatch},
     {"String concatenation", string_concat},
+    {"String building", string_build},
     {"String comparison", string_compare},
     {"String copy", string_copy},
     {"Number Object Access", number_object_access},
     {"Boolean Object Access", boolean_object_access},
     {"String Object Access", string_object_access},
+    {"JSON parse", json_parse},
     {"Arithmetic operations", arithmetic_operations},
     {"Clean js", clean_js},
-    {"Mini js", mini_js}
+    {"Mini js", mini_js},
+    {"Splay tree", splay},
+    {"Boyer term rewriting", boyer}
 );
 
 criterion_group!(
@@ -126,4 +178,11 @@ criterion_group!(
     bench_compile,
     bench_execution,
 );
+
+#[cfg(feature = "bytecode-cache")]
+criterion_group!(cached, bench_cached);
+
+#[cfg(not(feature = "bytecode-cache"))]
 criterion_main!(benches);
+#[cfg(feature = "bytecode-cache")]
+criterion_main!(benches, cached);
@@ -2,6 +2,7 @@
 
 use crate::{builtins::Number, error::JsNativeError, JsData, JsResult};
 use boa_gc::{Finalize, Trace};
+use num_bigint::Sign;
 use num_integer::Integer;
 use num_traits::{pow::Pow, FromPrimitive, One, ToPrimitive, Zero};
 use std::{
@@ -16,6 +17,8 @@ pub type RawBigInt = num_bigint::BigInt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+mod radix;
+
 /// JavaScript bigint primitive rust type.
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Trace, Finalize, JsData)]
@@ -68,7 +71,7 @@ impl JsBigInt {
     #[inline]
     #[must_use]
     pub fn to_string_radix(&self, radix: u32) -> String {
-        self.inner.to_str_radix(radix)
+        radix::to_str_radix(&self.inner, radix)
     }
 
     /// Converts the `BigInt` to a f64 type.
@@ -85,7 +88,7 @@ impl JsBigInt {
     #[must_use]
     pub fn from_string_radix(buf: &str, radix: u32) -> Option<Self> {
         Some(Self {
-            inner: Rc::new(RawBigInt::parse_bytes(buf.as_bytes(), radix)?),
+            inner: Rc::new(radix::parse_bytes(buf.as_bytes(), radix)?),
         })
     }
 
@@ -299,7 +302,12 @@ impl JsBigInt {
 impl Display for JsBigInt {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Display::fmt(&self.inner, f)
+        let digits = radix::to_str_radix(&self.inner, 10);
+        f.pad_integral(
+            self.inner.sign() != Sign::Minus,
+            "",
+            digits.trim_start_matches('-'),
+        )
     }
 }
 
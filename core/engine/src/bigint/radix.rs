@@ -0,0 +1,265 @@
+//! Conversions between big integers and their digits in a given radix.
+//!
+//! `num_bigint` converts to and from radices that aren't powers of two one machine word of digits
+//! at a time, which takes quadratic time in the size of the number. Instead, big numbers are split
+//! recursively at precomputed powers of the radix, so that most of the work is done by big
+//! multiplications, which `num_bigint` implements with sub-quadratic algorithms.
+
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::One;
+
+/// Numbers with fewer bits than this are converted by `num_bigint` directly.
+const THRESHOLD_BITS: u64 = 4096;
+
+/// Converts a big integer to its digits in the given radix.
+pub(super) fn to_str_radix(n: &BigInt, radix: u32) -> String {
+    let magnitude = n.magnitude();
+
+    // `num_bigint` converts power of two radices by just regrouping the bits of the number.
+    if radix.is_power_of_two() || magnitude.bits() < 2 * THRESHOLD_BITS {
+        return n.to_str_radix(radix);
+    }
+
+    let mut string = String::new();
+    if n.sign() == Sign::Minus {
+        string.push('-');
+    }
+
+    // The powers `radix^(d × 2^i)`, where `d` is chosen so that the smallest power has around
+    // `THRESHOLD_BITS` bits, up to a power whose square is bigger than the number.
+    let mut powers = vec![Power::new(small_power(radix))];
+    loop {
+        let last = powers.last().expect("there is at least one power");
+        if 2 * last.value.bits() - 1 > magnitude.bits() {
+            break;
+        }
+        let next = Power::new((&last.value * &last.value, last.digits * 2));
+        powers.push(next);
+    }
+
+    write_digits(magnitude, radix, &powers, 0, &mut string);
+    string
+}
+
+/// Appends the digits of `n` to `string`, padded with zeros to `width` digits.
+///
+/// `n` must be less than the square of the last power.
+fn write_digits(n: &BigUint, radix: u32, powers: &[Power], width: usize, string: &mut String) {
+    let Some((power, powers)) = powers.split_last() else {
+        let digits = n.to_str_radix(radix);
+        string.extend(std::iter::repeat('0').take(width.saturating_sub(digits.len())));
+        string.push_str(&digits);
+        return;
+    };
+
+    if width == 0 && *n < power.value {
+        write_digits(n, radix, powers, 0, string);
+        return;
+    }
+
+    let (high, low) = power.div_rem(n);
+    write_digits(
+        &high,
+        radix,
+        powers,
+        width.saturating_sub(power.digits),
+        string,
+    );
+    write_digits(&low, radix, powers, power.digits, string);
+}
+
+/// Parses the digits of a big integer in the given radix, with an optional leading sign.
+///
+/// Returns `None` if the string is empty or contains anything other than digits of the radix.
+pub(super) fn parse_bytes(bytes: &[u8], radix: u32) -> Option<BigInt> {
+    let (sign, digits) = match bytes {
+        [b'-', digits @ ..] => (Sign::Minus, digits),
+        [b'+', digits @ ..] | digits => (Sign::Plus, digits),
+    };
+
+    if digits.is_empty()
+        || !digits
+            .iter()
+            .all(|&digit| char::from(digit).is_digit(radix))
+    {
+        return None;
+    }
+
+    let magnitude = parse_digits(digits, radix);
+    Some(BigInt::from_biguint(sign, magnitude))
+}
+
+/// Parses a non-empty string of valid digits in the given radix.
+fn parse_digits(digits: &[u8], radix: u32) -> BigUint {
+    let (small_power, small_digits) = small_power(radix);
+
+    // `num_bigint` parses power of two radices by just regrouping the bits of the digits.
+    if radix.is_power_of_two() || digits.len() < 2 * small_digits {
+        return BigUint::parse_bytes(digits, radix).expect("digits were already validated");
+    }
+
+    // The powers `radix^(d × 2^i)`, up to the biggest one with less digits than the string.
+    let mut powers = vec![(small_power, small_digits)];
+    loop {
+        let (value, count) = powers.last().expect("there is at least one power");
+        if count * 2 >= digits.len() {
+            break;
+        }
+        let next = (value * value, count * 2);
+        powers.push(next);
+    }
+
+    parse_split(digits, radix, &powers)
+}
+
+/// Parses `digits` by splitting them at the biggest power with less digits than them.
+fn parse_split(digits: &[u8], radix: u32, powers: &[(BigUint, usize)]) -> BigUint {
+    let Some((power, count)) = powers.iter().rev().find(|(_, count)| *count < digits.len()) else {
+        return BigUint::parse_bytes(digits, radix).expect("digits were already validated");
+    };
+
+    let (high, low) = digits.split_at(digits.len() - count);
+    parse_split(high, radix, powers) * power + parse_split(low, radix, powers)
+}
+
+/// Gets the biggest power of the radix with at most `THRESHOLD_BITS` bits, and its number of
+/// zeros in the radix.
+fn small_power(radix: u32) -> (BigUint, usize) {
+    let digits = (THRESHOLD_BITS as f64 / f64::from(radix).log2()) as usize;
+    (BigUint::from(radix).pow(digits as u32), digits)
+}
+
+/// A power of the radix, with a precomputed reciprocal to divide by it using multiplications.
+struct Power {
+    value: BigUint,
+    /// The number of zeros of the power in the radix.
+    digits: usize,
+    /// `floor(2^shift / value)`.
+    reciprocal: BigUint,
+    /// Twice the number of bits of the power.
+    shift: u64,
+}
+
+impl Power {
+    fn new((value, digits): (BigUint, usize)) -> Self {
+        let shift = 2 * value.bits();
+        let reciprocal = reciprocal(&value);
+        Self {
+            value,
+            digits,
+            reciprocal,
+            shift,
+        }
+    }
+
+    /// Divides `n`, which must be less than the square of the power, using Barrett reduction.
+    fn div_rem(&self, n: &BigUint) -> (BigUint, BigUint) {
+        // The estimated quotient is at most 2 less than the real one.
+        let mut quotient = (n * &self.reciprocal) >> self.shift;
+        let mut remainder = n - &quotient * &self.value;
+        while remainder >= self.value {
+            remainder -= &self.value;
+            quotient += 1u8;
+        }
+        (quotient, remainder)
+    }
+}
+
+/// Computes `floor(2^(2 × bits) / n)`, where `bits` is the number of bits of `n`.
+///
+/// Big numbers use Newton's iteration `x' = x + x × (2^(2 × bits) - n × x) / 2^(2 × bits)` from
+/// the reciprocal of the upper half of `n`, which doubles the number of correct bits.
+fn reciprocal(n: &BigUint) -> BigUint {
+    let bits = n.bits();
+    if bits <= THRESHOLD_BITS {
+        return (BigUint::one() << (2 * bits)) / n;
+    }
+
+    // `2^(2 × bits) / n ≈ 2^(bits + half) / (n >> (bits - half))`.
+    let half = bits.div_ceil(2);
+    let approximation = BigInt::from(reciprocal(&(n >> (bits - half))) << (bits - half));
+
+    let one = BigInt::one() << (2 * bits);
+    let n = BigInt::from(n.clone());
+    let error = &one - &n * &approximation;
+    let mut x = &approximation + ((&approximation * error) >> (2 * bits));
+
+    // Fix the last few bits, lost in the truncations.
+    let mut remainder = one - &n * &x;
+    while remainder.sign() == Sign::Minus {
+        x -= 1u8;
+        remainder += &n;
+    }
+    while remainder >= n {
+        x += 1u8;
+        remainder -= &n;
+    }
+
+    x.into_parts().1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_bytes, reciprocal, to_str_radix};
+    use num_bigint::{BigInt, BigUint};
+    use num_traits::One;
+
+    /// A number with `bits` bits that has runs of ones and zeros of many lengths.
+    fn test_number(bits: u64) -> BigUint {
+        let mut n = BigUint::one();
+        let mut run = 1;
+        while n.bits() < bits {
+            n = ((n << run) + 1u8) << (run % 7);
+            run = run % 61 + 1;
+        }
+        let extra = n.bits() - bits;
+        n >> extra
+    }
+
+    #[test]
+    fn reciprocals() {
+        for bits in [1, 100, 4096, 4097, 10_000, 25_000] {
+            let n = test_number(bits);
+            let one = BigUint::one() << (2 * n.bits());
+            assert_eq!(
+                reciprocal(&n),
+                &one / &n,
+                "reciprocal of a {bits} bit number"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_small_chunk_conversion() {
+        for radix in [3, 7, 10, 16, 36] {
+            let mut numbers = vec![
+                BigInt::from(BigUint::from(radix).pow(5000) - 1u8),
+                BigInt::from(BigUint::from(radix).pow(5000)),
+            ];
+            for bits in [8191, 8192, 20_000, 50_000] {
+                numbers.push(BigInt::from(test_number(bits)));
+                numbers.push(-BigInt::from(test_number(bits)));
+            }
+
+            for n in numbers {
+                let string = n.to_str_radix(radix);
+                assert_eq!(to_str_radix(&n, radix), string, "radix {radix}");
+                assert_eq!(
+                    parse_bytes(string.as_bytes(), radix).as_ref(),
+                    Some(&n),
+                    "radix {radix}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_digits() {
+        assert_eq!(parse_bytes(b"", 10), None);
+        assert_eq!(parse_bytes(b"-", 10), None);
+        assert_eq!(parse_bytes(b"1_000", 10), None);
+        assert_eq!(parse_bytes(b"12a", 10), None);
+        assert_eq!(parse_bytes(b"+12a", 16), Some(BigInt::from(0x12a)));
+        assert_eq!(parse_bytes(b"-z", 36), Some(BigInt::from(-35)));
+    }
+}
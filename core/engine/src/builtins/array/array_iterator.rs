@@ -41,6 +41,18 @@ impl IntrinsicObject for ArrayIterator {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
+        let next_function = BuiltInBuilder::callable_with_object(
+            realm,
+            realm
+                .intrinsics()
+                .objects()
+                .array_iterator_prototype_next()
+                .into(),
+            Self::next,
+        )
+        .name(js_string!("next"))
+        .build();
+
         BuiltInBuilder::with_intrinsic::<Self>(realm)
             .prototype(
                 realm
@@ -49,7 +61,11 @@ impl IntrinsicObject for ArrayIterator {
                     .iterator_prototypes()
                     .iterator(),
             )
-            .static_method(Self::next, js_string!("next"), 0)
+            .static_property(
+                js_string!("next"),
+                next_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .static_property(
                 JsSymbol::to_string_tag(),
                 js_string!("Array Iterator"),
@@ -103,17 +119,27 @@ impl ArrayIterator {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next
     pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let value = Self::next_value(this, context)?;
+        let done = value.is_none();
+        Ok(create_iter_result_object(
+            value.unwrap_or_default(),
+            done,
+            context,
+        ))
+    }
+
+    /// Gets the next value of the array iterator, or `None` if the iterator is done.
+    ///
+    /// This has the same behaviour as `%ArrayIteratorPrototype%.next`, without allocating an
+    /// iterator result object.
+    pub(crate) fn next_value(this: &JsValue, context: &mut Context) -> JsResult<Option<JsValue>> {
         let mut array_iterator = this
             .as_object()
             .and_then(JsObject::downcast_mut::<Self>)
             .ok_or_else(|| JsNativeError::typ().with_message("`this` is not an ArrayIterator"))?;
         let index = array_iterator.next_index;
         if array_iterator.done {
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
+            return Ok(None);
         }
 
         let len = if let Some(f) = array_iterator.array.downcast_ref::<TypedArray>() {
@@ -134,23 +160,43 @@ impl ArrayIterator {
 
         if index >= len {
             array_iterator.done = true;
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
+            return Ok(None);
         }
         array_iterator.next_index = index + 1;
+
+        // Fast path: the dense elements of an array are plain data properties, so reading them
+        // directly is the same as calling `[[Get]]`. Holes are not stored in the dense elements,
+        // so they still go through the prototype chain.
+        let array = &array_iterator.array;
+        let dense_element = || {
+            if !array.is_array() {
+                return None;
+            }
+            let index = usize::try_from(index).ok()?;
+            array
+                .borrow()
+                .properties()
+                .dense_indexed_properties()?
+                .get(index)
+                .cloned()
+        };
+
         match array_iterator.kind {
-            PropertyNameKind::Key => Ok(create_iter_result_object(index.into(), false, context)),
+            PropertyNameKind::Key => Ok(Some(index.into())),
             PropertyNameKind::Value => {
-                let element_value = array_iterator.array.get(index, context)?;
-                Ok(create_iter_result_object(element_value, false, context))
+                let element_value = match dense_element() {
+                    Some(value) => value,
+                    None => array.get(index, context)?,
+                };
+                Ok(Some(element_value))
             }
             PropertyNameKind::KeyAndValue => {
-                let element_value = array_iterator.array.get(index, context)?;
+                let element_value = match dense_element() {
+                    Some(value) => value,
+                    None => array.get(index, context)?,
+                };
                 let result = Array::create_array_from_list([index.into(), element_value], context);
-                Ok(create_iter_result_object(result.into(), false, context))
+                Ok(Some(result.into()))
             }
         }
     }
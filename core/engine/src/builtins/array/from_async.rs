@@ -0,0 +1,386 @@
+//! Boa's implementation of the `Array.fromAsync` function.
+//!
+//! `Array.fromAsync` is specified as an async function, so its steps are split at every `Await`
+//! into callbacks of the awaited promises, which carry the state of the iteration with them.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::utf16;
+
+use crate::{
+    builtins::{
+        iterable::{AsyncFromSyncIterator, IteratorHint, IteratorRecord, IteratorResult},
+        promise::{await_value, ResolvingFunctions},
+        Array,
+    },
+    error::JsNativeError,
+    js_string,
+    object::{builtins::JsPromise, JsObject},
+    symbol::JsSymbol,
+    Context, JsArgs, JsError, JsResult, JsValue,
+};
+
+/// The maximum number of elements an iterator can produce, `2^53 - 1`.
+const MAX_LENGTH: u64 = 9_007_199_254_740_991;
+
+/// Where the elements of an `Array.fromAsync` call are taken from.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum Source {
+    /// An async iterator, or a sync iterator wrapped by `CreateAsyncFromSyncIterator`.
+    Iterator(IteratorRecord),
+
+    /// An array-like object, with the length it had when the call started.
+    ArrayLike { object: JsObject, length: u64 },
+}
+
+/// The state of an `Array.fromAsync` call, captured by the callbacks of the awaited promises.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct FromAsync {
+    /// The functions that settle the promise returned by `Array.fromAsync`.
+    resolvers: ResolvingFunctions,
+
+    /// `A`
+    target: JsObject,
+
+    /// `mapfn`, if `mapping` is true.
+    mapper: Option<JsObject>,
+    this_arg: JsValue,
+    source: Source,
+
+    /// `k`
+    index: u64,
+}
+
+impl Array {
+    /// [`Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let (promise, resolvers) = JsPromise::new_pending(context);
+
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters that captures C,
+        //    mapfn, and thisArg and performs the following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        if let Err(error) = Self::start_from_async(this, args, resolvers.clone(), context) {
+            resolvers
+                .reject
+                .call(&JsValue::undefined(), &[error.to_opaque(context)], context)
+                .expect("default `reject` function cannot throw");
+        }
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(promise.into())
+    }
+
+    /// Runs the steps of the `fromAsyncClosure` of [`Array::from_async`] until the first
+    /// `Await`.
+    fn start_from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        resolvers: ResolvingFunctions,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let items = args.get_or_undefined(0);
+        let mapfn = args.get_or_undefined(1);
+        let this_arg = args.get_or_undefined(2).clone();
+
+        // a. If mapfn is undefined, let mapping be false.
+        // b. Else,
+        //     i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        //     ii. Let mapping be true.
+        let mapper = match mapfn {
+            JsValue::Undefined => None,
+            JsValue::Object(o) if o.is_callable() => Some(o.clone()),
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("`{}` is not callable", mapfn.type_of()))
+                    .into())
+            }
+        };
+
+        // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+        let using_async_iterator = items.get_method(JsSymbol::async_iterator(), context)?;
+
+        // d. If usingAsyncIterator is undefined, then
+        //     i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+        let using_iterator = if using_async_iterator.is_none() {
+            items.get_method(JsSymbol::iterator(), context)?
+        } else {
+            None
+        };
+
+        // e. Let iteratorRecord be undefined.
+        let (target, source) = if using_async_iterator.is_some() || using_iterator.is_some() {
+            // f. If usingAsyncIterator is not undefined, then
+            //     i. Set iteratorRecord to ? GetIteratorFromMethod(asyncItems, usingAsyncIterator).
+            // g. Else if usingSyncIterator is not undefined, then
+            //     i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(GetIteratorFromMethod(asyncItems, usingSyncIterator)).
+            // h. If iteratorRecord is not undefined, then
+            //     i. If IsConstructor(C) is true, then
+            //         1. Let A be ? Construct(C).
+            //     ii. Else,
+            //         1. Let A be ! ArrayCreate(0).
+            let target = match this.as_constructor() {
+                Some(constructor) => constructor.construct(&[], None, context)?,
+                _ => Self::array_create(0, None, context)?,
+            };
+            let iterator_record = if let Some(method) = using_async_iterator {
+                items.get_iterator(context, Some(IteratorHint::Async), Some(method))?
+            } else {
+                let sync_iterator_record =
+                    items.get_iterator(context, Some(IteratorHint::Sync), using_iterator)?;
+                AsyncFromSyncIterator::create(sync_iterator_record, context)
+            };
+
+            (target, Source::Iterator(iterator_record))
+        } else {
+            // i. Else,
+            //     i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so assume it is
+            //        an array-like object.
+            //     ii. Let arrayLike be ! ToObject(asyncItems).
+            let array_like = items
+                .to_object(context)
+                .expect("should not fail according to spec");
+
+            //     iii. Let len be ? LengthOfArrayLike(arrayLike).
+            let length = array_like.length_of_array_like(context)?;
+
+            //     iv. If IsConstructor(C) is true, then
+            //         1. Let A be ? Construct(C, « 𝔽(len) »).
+            //     v. Else,
+            //         1. Let A be ? ArrayCreate(len).
+            let target = match this.as_constructor() {
+                Some(constructor) => constructor.construct(&[length.into()], None, context)?,
+                _ => Self::array_create(length, None, context)?,
+            };
+
+            (
+                target,
+                Source::ArrayLike {
+                    object: array_like,
+                    length,
+                },
+            )
+        };
+
+        FromAsync {
+            resolvers,
+            target,
+            mapper,
+            this_arg,
+            source,
+            index: 0,
+        }
+        .next_element(context);
+
+        Ok(())
+    }
+}
+
+impl FromAsync {
+    /// Requests the element at `k`, or fulfills the promise if there are no more elements.
+    fn next_element(self, context: &mut Context) {
+        match &self.source {
+            Source::Iterator(iterator_record) => {
+                // iv. Repeat,
+                //     1. If k ≥ 2^53 - 1, then
+                if self.index >= MAX_LENGTH {
+                    //    a. Let error be ThrowCompletion(a newly created TypeError object).
+                    //    b. Return ? AsyncIteratorClose(iteratorRecord, error).
+                    let error = JsNativeError::typ()
+                        .with_message("Array.fromAsync: iterator exceeded the max array length")
+                        .into();
+                    return self.close_iterator(error, context);
+                }
+
+                //     3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+                let next_result = iterator_record.next_method().call(
+                    &iterator_record.iterator().clone().into(),
+                    &[],
+                    context,
+                );
+
+                //     4. Set nextResult to ? Await(nextResult).
+                match next_result {
+                    Ok(next_result) => await_value(
+                        next_result,
+                        self,
+                        Self::on_next_result,
+                        |state, error, context| state.reject(&error, context),
+                        context,
+                    ),
+                    Err(error) => self.reject(&error, context),
+                }
+            }
+            Source::ArrayLike { object, length } => {
+                // vii. Repeat, while k < len,
+                if self.index >= *length {
+                    // viii. Perform ? Set(A, "length", 𝔽(len), true).
+                    // ix. Return A.
+                    let length = *length;
+                    return self.finish(length, context);
+                }
+
+                //     1. Let Pk be ! ToString(𝔽(k)).
+                //     2. Let kValue be ? Get(arrayLike, Pk).
+                //     3. Set kValue to ? Await(kValue).
+                match object.get(self.index, context) {
+                    Ok(value) => await_value(
+                        value,
+                        self,
+                        Self::on_value,
+                        |state, error, context| state.reject(&error, context),
+                        context,
+                    ),
+                    Err(error) => self.reject(&error, context),
+                }
+            }
+        }
+    }
+
+    /// Continues with the awaited result of calling the `next` method of the iterator.
+    fn on_next_result(self, next_result: JsValue, context: &mut Context) {
+        // 5. If nextResult is not an Object, throw a TypeError exception.
+        let next_result = match IteratorResult::from_value(next_result) {
+            Ok(next_result) => next_result,
+            Err(error) => return self.reject(&error, context),
+        };
+
+        // 6. Let done be ? IteratorComplete(nextResult).
+        match next_result.complete(context) {
+            // 7. If done is true,
+            //     a. Perform ? Set(A, "length", 𝔽(k), true).
+            //     b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+            Ok(true) => {
+                let length = self.index;
+                self.finish(length, context);
+            }
+            // 8. Let nextValue be ? IteratorValue(nextResult).
+            Ok(false) => match next_result.value(context) {
+                Ok(value) => self.on_value(value, context),
+                Err(error) => self.reject(&error, context),
+            },
+            Err(error) => self.reject(&error, context),
+        }
+    }
+
+    /// Continues with the value of the element at `k`, mapping it if needed.
+    fn on_value(self, value: JsValue, context: &mut Context) {
+        let Some(mapper) = &self.mapper else {
+            // Else, let mappedValue be nextValue.
+            return self.on_mapped_value(value, context);
+        };
+
+        // If mapping is true, then
+        //     a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+        //     b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        //     c. Set mappedValue to Await(mappedValue).
+        //     d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        match mapper.call(&self.this_arg, &[value, self.index.into()], context) {
+            Ok(mapped_value) => await_value(
+                mapped_value,
+                self,
+                Self::on_mapped_value,
+                Self::abrupt,
+                context,
+            ),
+            Err(error) => self.abrupt(error, context),
+        }
+    }
+
+    /// Defines the mapped value of the element at `k`, and continues with the next element.
+    fn on_mapped_value(mut self, mapped_value: JsValue, context: &mut Context) {
+        // Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+        // If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+        if let Err(error) =
+            self.target
+                .create_data_property_or_throw(self.index, mapped_value, context)
+        {
+            return self.abrupt(error, context);
+        }
+
+        // Set k to k + 1.
+        self.index += 1;
+        self.next_element(context);
+    }
+
+    /// Sets the length of `A` and fulfills the promise with it.
+    fn finish(self, length: u64, context: &mut Context) {
+        match self.target.set(utf16!("length"), length, true, context) {
+            Ok(_) => {
+                self.resolvers
+                    .resolve
+                    .call(
+                        &JsValue::undefined(),
+                        &[self.target.clone().into()],
+                        context,
+                    )
+                    .expect("default `resolve` function cannot throw");
+            }
+            Err(error) => self.reject(&error, context),
+        }
+    }
+
+    /// Handles an abrupt completion of the steps that close the iterator when they fail.
+    fn abrupt(self, error: JsError, context: &mut Context) {
+        match self.source {
+            Source::Iterator(_) => self.close_iterator(error, context),
+            Source::ArrayLike { .. } => self.reject(&error, context),
+        }
+    }
+
+    /// `AsyncIteratorClose ( iteratorRecord, completion )`, with a throw completion of `error`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asynciteratorclose
+    fn close_iterator(self, error: JsError, context: &mut Context) {
+        let Source::Iterator(iterator_record) = &self.source else {
+            return self.reject(&error, context);
+        };
+        let iterator = iterator_record.iterator().clone();
+
+        // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+        // 4. If innerResult.[[Type]] is normal, then
+        //     a. Let return be innerResult.[[Value]].
+        //     b. If return is undefined, return ? completion.
+        //     c. Set innerResult to Completion(Call(return, iterator)).
+        //     d. If innerResult.[[Type]] is normal, set innerResult to Completion(Await(innerResult.[[Value]])).
+        // 5. If completion.[[Type]] is throw, return ? completion.
+        let inner_result = match iterator.get_method(js_string!("return"), context) {
+            Ok(Some(r#return)) => r#return.call(&iterator.into(), &[], context),
+            Ok(None) | Err(_) => return self.reject(&error, context),
+        };
+        match inner_result {
+            Ok(inner_result) => await_value(
+                inner_result,
+                (self, error),
+                |(state, error), _, context| state.reject(&error, context),
+                |(state, error), _, context| state.reject(&error, context),
+                context,
+            ),
+            Err(_) => self.reject(&error, context),
+        }
+    }
+
+    /// Rejects the promise with `error`.
+    fn reject(&self, error: &JsError, context: &mut Context) {
+        let error = error.to_opaque(context);
+        self.resolvers
+            .reject
+            .call(&JsValue::undefined(), &[error], context)
+            .expect("default `reject` function cannot throw");
+    }
+}
@@ -41,6 +41,7 @@ use std::cmp::{min, Ordering};
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
 mod array_iterator;
+mod from_async;
 pub(crate) use array_iterator::ArrayIterator;
 #[cfg(test)]
 mod tests;
@@ -110,6 +111,7 @@ impl IntrinsicObject for Array {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             // Static Methods
             .static_method(Self::from, js_string!("from"), 1)
+            .static_method(Self::from_async, js_string!("fromAsync"), 1)
             .static_method(Self::is_array, js_string!("isArray"), 1)
             .static_method(Self::of, js_string!("of"), 0)
             .static_accessor(
@@ -1001,13 +1003,15 @@ impl Array {
         };
 
         // 5. Let R be the empty String.
+        // The strings are concatenated at the end, so the result can reference them instead of
+        // copying them.
         let mut r = Vec::new();
         // 6. Let k be 0.
         // 7. Repeat, while k < len,
         for k in 0..len {
             // a. If k > 0, set R to the string-concatenation of R and sep.
             if k > 0 {
-                r.extend_from_slice(&separator);
+                r.push(separator.clone());
             }
             // b. Let element be ? Get(O, ! ToString(𝔽(k))).
             let element = o.get(k, context)?;
@@ -1018,11 +1022,11 @@ impl Array {
                 element.to_string(context)?
             };
             // d. Set R to the string-concatenation of R and next.
-            r.extend_from_slice(&next);
+            r.push(next);
             // e. Set k to k + 1.
         }
         // 8. Return R.
-        Ok(js_string!(&r[..]).into())
+        Ok(JsString::concat_lazy_array(&r).into())
     }
 
     /// `Array.prototype.toString( separator )`
@@ -2210,7 +2214,7 @@ impl Array {
                     .to_string(context)?;
 
                 // ii. Set R to the string-concatenation of R and S.
-                r.extend_from_slice(&s);
+                r.extend(s.iter());
             }
             //     d. Increase k by 1.
         }
@@ -3163,7 +3167,7 @@ impl Array {
             args.get_or_undefined(0).to_integer_or_infinity(context)?
         else {
             return Err(JsNativeError::range()
-                .with_message("invalid integer index for TypedArray operation")
+                .with_message("index out of range for Array.prototype.with")
                 .into());
         };
 
@@ -3177,8 +3181,7 @@ impl Array {
             .filter(|&rel| rel < len)
             .ok_or_else(|| {
                 // 6. If actualIndex ≥ len or actualIndex < 0, throw a RangeError exception.
-                JsNativeError::range()
-                    .with_message("invalid integer index for TypedArray operation")
+                JsNativeError::range().with_message("index out of range for Array.prototype.with")
             })?;
 
         // 7. Let A be ? ArrayCreate(len).
@@ -950,3 +950,114 @@ fn array_sort() {
             "#}),
     ]);
 }
+
+#[test]
+fn array_change_by_copy() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("var arr = [3, 1, 2];"),
+        TestAction::assert("arrayEquals(arr.toSorted(), [1, 2, 3])"),
+        TestAction::assert("arrayEquals(arr.toReversed(), [2, 1, 3])"),
+        TestAction::assert("arrayEquals(arr.toSpliced(1, 1, 'a', 'b'), [3, 'a', 'b', 2])"),
+        TestAction::assert("arrayEquals(arr.with(0, 9), [9, 1, 2])"),
+        TestAction::assert("arrayEquals(arr.with(-1, 9), [3, 1, 9])"),
+        TestAction::assert("arrayEquals(arr, [3, 1, 2])"),
+        // Holes are read as `undefined`, so the new arrays don't have holes.
+        TestAction::assert("arrayEquals([, 1].toReversed(), [1, undefined])"),
+        TestAction::assert("Object.hasOwn([, 1].with(1, 2), 0)"),
+        TestAction::assert_native_error(
+            "arr.with(3, 0)",
+            JsNativeErrorKind::Range,
+            "index out of range for Array.prototype.with",
+        ),
+        TestAction::assert_native_error(
+            "arr.with(-4, 0)",
+            JsNativeErrorKind::Range,
+            "index out of range for Array.prototype.with",
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                try {
+                    arr.toSorted(() => { throw 'comparator'; });
+                } catch (e) {
+                    e + ' ' + arr.join();
+                }
+            "#},
+            js_string!("comparator 3,1,2"),
+        ),
+        TestAction::assert_native_error(
+            "Array.prototype.toReversed.call({ length: 2 ** 32 })",
+            JsNativeErrorKind::Range,
+            "array exceeded max size",
+        ),
+        TestAction::assert_native_error(
+            "Array.prototype.toSpliced.call({ length: 2 ** 53 - 1 }, 0, 0, 1)",
+            JsNativeErrorKind::Type,
+            "Target splice exceeded max safe integer value",
+        ),
+    ]);
+}
+
+#[test]
+fn typed_array_change_by_copy() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("var ta = new Int8Array([3, 1, 2]);"),
+        TestAction::assert("arrayEquals(Array.from(ta.toSorted()), [1, 2, 3])"),
+        TestAction::assert("arrayEquals(Array.from(ta.toReversed()), [2, 1, 3])"),
+        TestAction::assert("arrayEquals(Array.from(ta.with(-1, 7)), [3, 1, 7])"),
+        TestAction::assert("ta.toSorted() instanceof Int8Array && ta.toSorted() !== ta"),
+        TestAction::assert("arrayEquals(Array.from(ta), [3, 1, 2])"),
+        TestAction::assert_native_error(
+            "ta.with(3, 0)",
+            JsNativeErrorKind::Range,
+            "invalid integer index for TypedArray operation",
+        ),
+    ]);
+}
+
+#[test]
+fn array_from_async() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            async function* generate() {
+                yield 1;
+                yield Promise.resolve(2);
+                yield 3;
+            }
+            var results = {};
+            Array.fromAsync(generate(), (x, i) => Promise.resolve(x * 10 + i))
+                .then(a => { results.generator = a; });
+
+            // The elements of sync iterables are awaited.
+            Array.fromAsync([Promise.resolve('a'), 'b', Promise.resolve('c')])
+                .then(a => { results.sync = a; });
+
+            Array.fromAsync({ length: 2, 0: Promise.resolve(5), 1: 6 })
+                .then(a => { results.arrayLike = a; });
+
+            // A rejecting `mapfn` closes the iterator.
+            var closed = false;
+            var iterable = {
+                [Symbol.asyncIterator]() {
+                    return {
+                        next() { return Promise.resolve({ value: 1, done: false }); },
+                        return() { closed = true; return Promise.resolve({ done: true }); },
+                    };
+                },
+            };
+            Array.fromAsync(iterable, () => Promise.reject('mapper'))
+                .catch(e => { results.rejected = e; });
+
+            Array.fromAsync(1, 'not callable').catch(e => { results.notCallable = e; });
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("arrayEquals(results.generator, [10, 21, 32])"),
+        TestAction::assert("arrayEquals(results.sync, ['a', 'b', 'c'])"),
+        TestAction::assert("arrayEquals(results.arrayLike, [5, 6])"),
+        TestAction::assert("results.rejected === 'mapper' && closed"),
+        TestAction::assert("results.notCallable instanceof TypeError"),
+        TestAction::assert("Array.fromAsync([]) instanceof Promise"),
+    ]);
+}
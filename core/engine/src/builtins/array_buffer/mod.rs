@@ -16,7 +16,10 @@ pub(crate) mod utils;
 #[cfg(test)]
 mod tests;
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    any::{Any, TypeId},
+    ops::{Deref, DerefMut},
+};
 
 pub use shared::SharedArrayBuffer;
 use std::sync::atomic::Ordering;
@@ -194,11 +197,87 @@ impl BufferObject {
     }
 }
 
+/// Memory owned by the host that backs an `ArrayBuffer` without being copied.
+pub(crate) trait ExternalMemory: Any {
+    fn bytes(&self) -> &[u8];
+    fn bytes_mut(&mut self) -> &mut [u8];
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]> + 'static> ExternalMemory for T {
+    fn bytes(&self) -> &[u8] {
+        self.as_ref()
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        self.as_mut()
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// The data block of an `ArrayBuffer`.
+pub(crate) enum DataBlock {
+    /// A block allocated and owned by the engine.
+    Owned(Vec<u8>),
+
+    /// A block owned by the host, which cannot be resized.
+    External(Box<dyn ExternalMemory>),
+}
+
+impl DataBlock {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Self::Owned(vec) => vec,
+            Self::External(ext) => ext.bytes(),
+        }
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        match self {
+            Self::Owned(vec) => vec,
+            Self::External(ext) => ext.bytes_mut(),
+        }
+    }
+
+    /// Converts the block into an owned `Vec`, copying the bytes of external blocks.
+    fn into_vec(self) -> Vec<u8> {
+        match self {
+            Self::Owned(vec) => vec,
+            Self::External(ext) => ext.bytes().to_vec(),
+        }
+    }
+}
+
+/// Cloning an external block copies its bytes into a block owned by the engine, since the host
+/// memory cannot be shared.
+impl Clone for DataBlock {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Owned(vec) => Self::Owned(vec.clone()),
+            Self::External(ext) => Self::Owned(ext.bytes().to_vec()),
+        }
+    }
+}
+
+impl std::fmt::Debug for DataBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Owned(vec) => f.debug_tuple("Owned").field(vec).finish(),
+            Self::External(ext) => f.debug_tuple("External").field(&ext.bytes()).finish(),
+        }
+    }
+}
+
 /// The internal representation of an `ArrayBuffer` object.
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
 pub struct ArrayBuffer {
     /// The `[[ArrayBufferData]]` internal slot.
-    data: Option<Vec<u8>>,
+    // Safety: Neither owned nor external blocks store any traceable types.
+    #[unsafe_ignore_trace]
+    data: Option<DataBlock>,
 
     /// The `[[ArrayBufferMaxByteLength]]` internal slot.
     max_byte_len: Option<u64>,
@@ -210,62 +289,102 @@ pub struct ArrayBuffer {
 impl ArrayBuffer {
     pub(crate) fn from_data(data: Vec<u8>, detach_key: JsValue) -> Self {
         Self {
-            data: Some(data),
+            data: Some(DataBlock::Owned(data)),
+            max_byte_len: None,
+            detach_key,
+        }
+    }
+
+    pub(crate) fn from_external<T>(data: T, detach_key: JsValue) -> Self
+    where
+        T: AsRef<[u8]> + AsMut<[u8]> + 'static,
+    {
+        Self {
+            data: Some(DataBlock::External(Box::new(data))),
             max_byte_len: None,
             detach_key,
         }
     }
 
     pub(crate) fn len(&self) -> usize {
-        self.data.as_ref().map_or(0, Vec::len)
+        self.data.as_ref().map_or(0, |data| data.bytes().len())
     }
 
     pub(crate) fn bytes(&self) -> Option<&[u8]> {
-        self.data.as_deref()
+        self.data.as_ref().map(DataBlock::bytes)
     }
 
     pub(crate) fn bytes_mut(&mut self) -> Option<&mut [u8]> {
-        self.data.as_deref_mut()
+        self.data.as_mut().map(DataBlock::bytes_mut)
     }
 
+    /// Gets the inner `Vec` of the buffer, or `None` if it is detached or backed by external
+    /// memory.
     pub(crate) fn vec_mut(&mut self) -> Option<&mut Vec<u8>> {
-        self.data.as_mut()
+        match self.data.as_mut()? {
+            DataBlock::Owned(vec) => Some(vec),
+            DataBlock::External(_) => None,
+        }
     }
 
     /// Gets the inner bytes of the buffer without accessing the current atomic length.
     #[track_caller]
     pub(crate) fn bytes_with_len(&self, len: usize) -> Option<&[u8]> {
-        if let Some(s) = self.data.as_deref() {
-            Some(&s[..len])
-        } else {
-            None
-        }
+        self.bytes().map(|s| &s[..len])
     }
 
     /// Gets the mutable inner bytes of the buffer without accessing the current atomic length.
     #[track_caller]
     pub(crate) fn bytes_with_len_mut(&mut self, len: usize) -> Option<&mut [u8]> {
-        if let Some(s) = self.data.as_deref_mut() {
-            Some(&mut s[..len])
-        } else {
-            None
+        self.bytes_mut().map(|s| &mut s[..len])
+    }
+
+    /// Checks that `key` is the detach key of this `ArrayBuffer`.
+    pub(crate) fn check_detach_key(&self, key: &JsValue) -> JsResult<()> {
+        if !JsValue::same_value(&self.detach_key, key) {
+            return Err(JsNativeError::typ()
+                .with_message("Cannot detach array buffer with different key")
+                .into());
         }
+        Ok(())
     }
 
     /// Detaches the inner data of this `ArrayBuffer`, returning the original buffer if still
     /// present.
     ///
+    /// Buffers backed by external memory are copied into a new `Vec` before being detached;
+    /// use [`ArrayBuffer::detach_external`] to get the original memory back.
+    ///
     /// # Errors
     ///
     /// Throws an error if the provided detach key is invalid.
     pub fn detach(&mut self, key: &JsValue) -> JsResult<Option<Vec<u8>>> {
-        if !JsValue::same_value(&self.detach_key, key) {
-            return Err(JsNativeError::typ()
-                .with_message("Cannot detach array buffer with different key")
-                .into());
-        }
+        self.check_detach_key(key)?;
 
-        Ok(self.data.take())
+        Ok(self.data.take().map(DataBlock::into_vec))
+    }
+
+    /// Detaches the external memory of this `ArrayBuffer`, returning ownership of it to the host.
+    ///
+    /// Returns `Ok(None)` without detaching the buffer if it is already detached, is not backed
+    /// by external memory, or its external memory is not of type `T`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an error if the provided detach key is invalid.
+    pub fn detach_external<T: 'static>(&mut self, key: &JsValue) -> JsResult<Option<T>> {
+        self.check_detach_key(key)?;
+
+        let Some(DataBlock::External(ext)) = &self.data else {
+            return Ok(None);
+        };
+        if (**ext).type_id() != TypeId::of::<T>() {
+            return Ok(None);
+        }
+        let Some(DataBlock::External(ext)) = self.data.take() else {
+            unreachable!("checked above")
+        };
+        Ok(ext.into_any().downcast::<T>().ok().map(|data| *data))
     }
 
     /// `IsDetachedBuffer ( arrayBuffer )`
@@ -283,6 +402,22 @@ impl ArrayBuffer {
     pub(crate) fn is_fixed_len(&self) -> bool {
         self.max_byte_len.is_none()
     }
+
+    /// Gets the maximum byte length of the buffer, or `None` if it is not resizable.
+    pub(crate) const fn max_byte_len(&self) -> Option<u64> {
+        self.max_byte_len
+    }
+
+    /// Moves the data of this `ArrayBuffer` into a new `ArrayBuffer`, detaching this buffer.
+    ///
+    /// Returns `None` if the buffer is already detached.
+    pub(crate) fn take(&mut self) -> Option<Self> {
+        Some(Self {
+            data: Some(self.data.take()?),
+            max_byte_len: self.max_byte_len,
+            detach_key: JsValue::undefined(),
+        })
+    }
 }
 
 impl IntrinsicObject for ArrayBuffer {
@@ -307,12 +442,11 @@ impl IntrinsicObject for ArrayBuffer {
             .name(js_string!("get maxByteLength"))
             .build();
 
-        #[cfg(feature = "experimental")]
         let get_detached = BuiltInBuilder::callable(realm, Self::get_detached)
             .name(js_string!("get detached"))
             .build();
 
-        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_accessor(
                 JsSymbol::species(),
                 Some(get_species),
@@ -338,30 +472,26 @@ impl IntrinsicObject for ArrayBuffer {
                 None,
                 flag_attributes,
             )
-            .method(Self::resize, js_string!("resize"), 1)
-            .method(Self::slice, js_string!("slice"), 2)
-            .property(
-                JsSymbol::to_string_tag(),
-                Self::NAME,
-                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            );
-
-        #[cfg(feature = "experimental")]
-        let builder = builder
             .accessor(
                 js_string!("detached"),
                 Some(get_detached),
                 None,
                 flag_attributes,
             )
+            .method(Self::resize, js_string!("resize"), 1)
+            .method(Self::slice, js_string!("slice"), 2)
             .method(Self::transfer::<false>, js_string!("transfer"), 0)
             .method(
                 Self::transfer::<true>,
                 js_string!("transferToFixedLength"),
                 0,
-            );
-
-        builder.build();
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -528,8 +658,7 @@ impl ArrayBuffer {
 
     /// [`get ArrayBuffer.prototype.detached`][spec].
     ///
-    /// [spec]: https://tc39.es/proposal-arraybuffer-transfer/#sec-get-arraybuffer.prototype.detached
-    #[cfg(feature = "experimental")]
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.detached
     fn get_detached(
         this: &JsValue,
         _args: &[JsValue],
@@ -723,9 +852,8 @@ impl ArrayBuffer {
     /// [`ArrayBuffer.prototype.transfer ( [ newLength ] )`][transfer] and
     /// [`ArrayBuffer.prototype.transferToFixedLength ( [ newLength ] )`][transferFL]
     ///
-    /// [transfer]: https://tc39.es/proposal-arraybuffer-transfer/#sec-arraybuffer.prototype.transfer
-    /// [transferFL]: https://tc39.es/proposal-arraybuffer-transfer/#sec-arraybuffer.prototype.transfertofixedlength
-    #[cfg(feature = "experimental")]
+    /// [transfer]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfer
+    /// [transferFL]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfertofixedlength
     fn transfer<const TO_FIXED_LENGTH: bool>(
         this: &JsValue,
         args: &[JsValue],
@@ -735,7 +863,7 @@ impl ArrayBuffer {
         // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, preserve-resizability).
 
         // Abstract operation `ArrayBufferCopyAndDetach ( arrayBuffer, newLength, preserveResizability )`
-        // https://tc39.es/proposal-arraybuffer-transfer/#sec-arraybuffercopyanddetach
+        // https://tc39.es/ecma262/#sec-arraybuffercopyanddetach
 
         let new_length = args.get_or_undefined(0);
 
@@ -763,7 +891,7 @@ impl ArrayBuffer {
         };
 
         // 5. If IsDetachedBuffer(arrayBuffer) is true, throw a TypeError exception.
-        let Some(mut bytes) = buf.borrow_mut().data.data.take() else {
+        let Some(mut bytes) = buf.borrow_mut().data.data.take().map(DataBlock::into_vec) else {
             return Err(JsNativeError::typ()
                 .with_message("cannot transfer a detached buffer")
                 .into());
@@ -778,7 +906,7 @@ impl ArrayBuffer {
 
         // 8. If arrayBuffer.[[ArrayBufferDetachKey]] is not undefined, throw a TypeError exception.
         if !buf.borrow().data.detach_key.is_undefined() {
-            buf.borrow_mut().data.data = Some(bytes);
+            buf.borrow_mut().data.data = Some(DataBlock::Owned(bytes));
             return Err(JsNativeError::typ()
                 .with_message("cannot transfer a buffer with a detach key")
                 .into());
@@ -798,7 +926,7 @@ impl ArrayBuffer {
         // 16. Return newBuffer.
         if let Some(new_max_len) = new_max_len {
             if new_len > new_max_len {
-                buf.borrow_mut().data.data = Some(bytes);
+                buf.borrow_mut().data.data = Some(DataBlock::Owned(bytes));
                 return Err(JsNativeError::range()
                     .with_message("`length` cannot be bigger than `maxByteLength`")
                     .into());
@@ -822,7 +950,7 @@ impl ArrayBuffer {
             context.root_shape(),
             prototype,
             ArrayBuffer {
-                data: Some(bytes),
+                data: Some(DataBlock::Owned(bytes)),
                 max_byte_len: new_max_len,
                 detach_key: JsValue::undefined(),
             },
@@ -875,7 +1003,7 @@ impl ArrayBuffer {
             Self {
                 // 6. Set obj.[[ArrayBufferData]] to block.
                 // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
-                data: Some(block),
+                data: Some(DataBlock::Owned(block)),
                 // 8. If allocatingResizableBuffer is true, then
                 //    c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
                 max_byte_len,
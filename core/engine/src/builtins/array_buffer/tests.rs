@@ -19,3 +19,145 @@ fn create_shared_byte_data_block() {
     // Rainy day
     assert!(super::shared::create_shared_byte_data_block(u64::MAX, context).is_err());
 }
+
+#[test]
+fn external_memory() {
+    use crate::{
+        js_string, object::builtins::JsArrayBuffer, property::Attribute, JsNativeErrorKind,
+        JsValue, Source,
+    };
+
+    /// Host memory that the test keeps a raw pointer to.
+    ///
+    /// The memory is owned through the pointer returned by `Box::into_raw`, since moving a `Box`
+    /// would invalidate the pointers previously derived from it.
+    struct HostMemory(*mut [u8]);
+
+    impl AsRef<[u8]> for HostMemory {
+        fn as_ref(&self) -> &[u8] {
+            // SAFETY: The pointer is valid until `HostMemory` is dropped.
+            unsafe { &*self.0 }
+        }
+    }
+
+    impl AsMut<[u8]> for HostMemory {
+        fn as_mut(&mut self) -> &mut [u8] {
+            // SAFETY: The pointer is valid until `HostMemory` is dropped.
+            unsafe { &mut *self.0 }
+        }
+    }
+
+    impl Drop for HostMemory {
+        fn drop(&mut self) {
+            // SAFETY: The pointer was returned by `Box::into_raw` and is only freed here.
+            drop(unsafe { Box::from_raw(self.0) });
+        }
+    }
+
+    let context = &mut Context::default();
+    let memory = HostMemory(Box::into_raw(vec![0; 4].into_boxed_slice()));
+    let host_ptr = memory.0.cast::<u8>();
+    let buffer = JsArrayBuffer::from_external(memory, context).unwrap();
+    context
+        .register_global_property(js_string!("buf"), buffer.clone(), Attribute::all())
+        .unwrap();
+
+    context
+        .eval(Source::from_bytes(
+            "var view = new Uint8Array(buf); view[1] = 7;",
+        ))
+        .unwrap();
+
+    // SAFETY: No script is running and no borrow of the buffer data is alive.
+    unsafe {
+        assert_eq!(*host_ptr.add(1), 7);
+        *host_ptr.add(2) = 42;
+    }
+    assert_eq!(
+        context.eval(Source::from_bytes("view[2]")).unwrap(),
+        JsValue::new(42)
+    );
+
+    assert!(buffer
+        .detach_external::<Vec<u8>>(&JsValue::undefined())
+        .unwrap()
+        .is_none());
+    let memory = buffer
+        .detach_external::<HostMemory>(&JsValue::undefined())
+        .unwrap()
+        .unwrap();
+    assert_eq!(memory.as_ref(), &[0, 7, 42, 0]);
+
+    assert_eq!(
+        context.eval(Source::from_bytes("view[1]")).unwrap(),
+        JsValue::undefined()
+    );
+    let err = context
+        .eval(Source::from_bytes("new Uint8Array(buf)"))
+        .unwrap_err()
+        .try_native(context)
+        .unwrap();
+    assert_eq!(err.kind, JsNativeErrorKind::Type);
+}
+
+#[test]
+fn resizable_buffer_views() {
+    use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+    use indoc::indoc;
+
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+            var tracking = new Uint8Array(buffer);
+            var fixed = new Uint8Array(buffer, 2, 2);
+            tracking.set([1, 2, 3, 4]);
+            buffer.resize(2);
+        "#}),
+        TestAction::assert("buffer.resizable"),
+        TestAction::assert_eq("buffer.maxByteLength", 8),
+        TestAction::assert_eq("tracking.length", 2),
+        TestAction::assert_eq("fixed.length", 0),
+        TestAction::assert_eq("fixed.byteOffset", 0),
+        TestAction::assert("fixed[0] === undefined"),
+        TestAction::assert("!Reflect.defineProperty(tracking, 3, { value: 9 })"),
+        TestAction::assert_native_error(
+            "Object.defineProperty(tracking, 3, { value: 9 })",
+            JsNativeErrorKind::Type,
+            "cannot redefine property: 3",
+        ),
+        TestAction::run("buffer.resize(6)"),
+        TestAction::assert_eq("tracking.length", 6),
+        TestAction::assert_eq("tracking.join()", js_string!("1,2,0,0,0,0")),
+        TestAction::assert_eq("fixed.join()", js_string!("0,0")),
+        TestAction::assert_native_error(
+            "buffer.resize(9)",
+            JsNativeErrorKind::Range,
+            "ArrayBuffer.resize: new byte length exceeds buffer's maximum byte length",
+        ),
+    ]);
+}
+
+#[test]
+fn transfer() {
+    use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+    use indoc::indoc;
+
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+            var bytes = new Uint8Array(buffer);
+            bytes[0] = 1;
+            var transferred = buffer.transfer();
+            var fixed = transferred.transferToFixedLength(2);
+        "#}),
+        TestAction::assert("buffer.detached && bytes.length === 0"),
+        TestAction::assert("transferred.detached && transferred.resizable"),
+        TestAction::assert("!fixed.resizable && fixed.byteLength === 2"),
+        TestAction::assert_eq("new Uint8Array(fixed)[0]", 1),
+        TestAction::assert_native_error(
+            "buffer.transfer()",
+            JsNativeErrorKind::Type,
+            "cannot transfer a detached buffer",
+        ),
+    ]);
+}
@@ -0,0 +1,432 @@
+//! Boa's implementation of the `AsyncDisposableStack` builtin object.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack
+
+use crate::{
+    builtins::{
+        disposable_stack::{
+            adopt_closure, callable_on_dispose, suppress_error, DisposableResource,
+            DisposeCapability, DisposeHint,
+        },
+        promise::{await_value, ResolvingFunctions},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    js_string,
+    object::{builtins::JsPromise, internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::common::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsError, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The `AsyncDisposableStack` builtin object.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct AsyncDisposableStack {
+    /// The `[[DisposeCapability]]` internal slot, or `None` if the `[[AsyncDisposableState]]` of
+    /// the stack is `disposed`.
+    capability: Option<DisposeCapability>,
+}
+
+impl IntrinsicObject for AsyncDisposableStack {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_async = BuiltInBuilder::callable(realm, Self::dispose_async)
+            .name(js_string!("disposeAsync"))
+            .length(0)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("disposeAsync"),
+                dispose_async.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::async_dispose(),
+                dispose_async,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for AsyncDisposableStack {
+    const NAME: JsString = StaticJsStrings::ASYNC_DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for AsyncDisposableStack {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_disposable_stack;
+
+    /// `AsyncDisposableStack ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("AsyncDisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return asyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                capability: Some(DisposeCapability::default()),
+            },
+        )
+        .into())
+    }
+}
+
+impl AsyncDisposableStack {
+    /// Gets the `this` object of the `method` of `AsyncDisposableStack.prototype`, as
+    /// `RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]])`.
+    fn this_object(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "AsyncDisposableStack.prototype.{method}: `this` is not an AsyncDisposableStack"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Throws a `ReferenceError` if the `[[AsyncDisposableState]]` of `stack` is `disposed`.
+    fn require_pending(stack: &JsObject, method: &str) -> JsResult<()> {
+        let disposed = stack
+            .downcast_ref::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .capability
+            .is_none();
+        if disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "AsyncDisposableStack.prototype.{method}: the stack is already disposed"
+                ))
+                .into());
+        }
+        Ok(())
+    }
+
+    /// Appends `resource` to the `[[DisposeCapability]]` of `stack`.
+    fn push(stack: &JsObject, resource: DisposableResource) {
+        if let Some(capability) = &mut stack
+            .downcast_mut::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .capability
+        {
+            capability.push(resource);
+        }
+    }
+
+    /// `get AsyncDisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_object(this, "disposed")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        let stack = stack
+            .downcast_ref::<Self>()
+            .expect("must be an AsyncDisposableStack");
+        Ok(stack.capability.is_none().into())
+    }
+
+    /// `AsyncDisposableStack.prototype.disposeAsync ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    #[allow(clippy::unnecessary_wraps)]
+    fn dispose_async(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let (promise, resolvers) = JsPromise::new_pending(context);
+
+        // 1. Let asyncDisposableStack be the this value.
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+        //     b. Return promiseCapability.[[Promise]].
+        let stack = match Self::this_object(this, "disposeAsync") {
+            Ok(stack) => stack,
+            Err(error) => {
+                resolvers
+                    .reject
+                    .call(&JsValue::undefined(), &[error.to_opaque(context)], context)
+                    .expect("cannot fail per the spec");
+                return Ok(promise.into());
+            }
+        };
+
+        // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+        //     a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+        //     b. Return promiseCapability.[[Promise]].
+        // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        let capability = stack
+            .downcast_mut::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .capability
+            .take();
+
+        // 6. Let result be DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        // 9. Return promiseCapability.[[Promise]].
+        AsyncDispose {
+            capability: capability.unwrap_or_default(),
+            error: None,
+            resolvers,
+        }
+        .next_resource(context);
+
+        Ok(promise.into())
+    }
+
+    /// `AsyncDisposableStack.prototype.use ( value )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_object(this, "use")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], value, async-dispose).
+        let value = args.get_or_undefined(0);
+        if let Some(resource) = DisposableResource::from_value(value, DisposeHint::Async, context)?
+        {
+            Self::push(&stack, resource);
+        }
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// `AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_object(this, "adopt")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "adopt")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let on_dispose =
+            callable_on_dispose(args.get_or_undefined(1), "AsyncDisposableStack", "adopt")?;
+
+        // 5-6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let closure = adopt_closure(value.clone(), on_dispose, context);
+
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, F).
+        Self::push(
+            &stack,
+            DisposableResource::from_method(closure, DisposeHint::Async),
+        );
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// `AsyncDisposableStack.prototype.defer ( onDisposeAsync )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_object(this, "defer")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose =
+            callable_on_dispose(args.get_or_undefined(0), "AsyncDisposableStack", "defer")?;
+
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, onDisposeAsync).
+        Self::push(
+            &stack,
+            DisposableResource::from_method(on_dispose, DisposeHint::Async),
+        );
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `AsyncDisposableStack.prototype.move ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_object(this, "move")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "move")?;
+
+        // 7. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        let capability = stack
+            .downcast_mut::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .capability
+            .take();
+
+        // 4. Let newAsyncDisposableStack be ? OrdinaryCreateFromConstructor(%AsyncDisposableStack%, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to asyncDisposableStack.[[DisposeCapability]].
+        // 9. Return newAsyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .async_disposable_stack()
+                .prototype(),
+            Self { capability },
+        )
+        .into())
+    }
+}
+
+/// The state of a running `AsyncDisposableStack.prototype.disposeAsync` call, which awaits the
+/// result of disposing each resource before disposing the next one.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct AsyncDispose {
+    /// The resources that are left to dispose.
+    capability: DisposeCapability,
+
+    /// The error thrown while disposing the resources, if any.
+    error: Option<JsError>,
+
+    /// The resolving functions of the promise returned by `disposeAsync`.
+    resolvers: ResolvingFunctions,
+}
+
+impl AsyncDispose {
+    /// Disposes the next resource of the capability, or settles the promise if there are no more
+    /// resources.
+    fn next_resource(mut self, context: &mut Context) {
+        // DisposeResources ( disposeCapability, completion )
+        // 1. For each element resource of disposeCapability.[[DisposableResourceStack]], in
+        //    reverse list order, do
+        while let Some(resource) = self.capability.pop() {
+            // a. Let result be Dispose(resource.[[ResourceValue]], resource.[[Hint]], resource.[[DisposeMethod]]).
+            match resource.dispose(context) {
+                // Dispose ( V, hint, method )
+                // 3. If hint is async-dispose, then
+                //     a. Perform ? Await(result).
+                Ok(result) if resource.hint() == DisposeHint::Async => {
+                    return await_value(
+                        result,
+                        self,
+                        |state, _, context| state.next_resource(context),
+                        |mut state, error, context| {
+                            state.error = Some(suppress_error(error, state.error.take()));
+                            state.next_resource(context);
+                        },
+                        context,
+                    );
+                }
+                Ok(_) => {}
+                // b. If result.[[Type]] is throw, then
+                Err(error) => self.error = Some(suppress_error(error, self.error.take())),
+            }
+        }
+
+        // 4. Return ? completion.
+        let (function, argument) = match self.error.take() {
+            Some(error) => (&self.resolvers.reject, error.to_opaque(context)),
+            None => (&self.resolvers.resolve, JsValue::undefined()),
+        };
+        function
+            .call(&JsValue::undefined(), &[argument], context)
+            .expect("cannot fail per the spec");
+    }
+}
@@ -133,6 +133,13 @@
 // In a future point in time, "Thread 1" will be notified, which will proceed with the
 // exact same steps as "Thread 2", emptying the wait queue and finishing the execution of our
 // program.
+//
+// `Atomics.waitAsync` shares the same wait queues, but its `FutexWaiter` is allocated on the heap
+// and owned by an `AsyncWaiter` future instead of living on the stack of a sleeping thread.
+// Notifying an async waiter also wakes the `Waker` stored in its `FutexWaiter`, which lets the
+// job queue of the waiting context poll the future again and resolve the corresponding promise.
+// Dropping an `AsyncWaiter` (e.g. when its `Context` is dropped before the wait finishes) removes
+// its `FutexWaiter` from the wait queue before deallocating it.
 
 #![deny(unsafe_op_in_unsafe_fn)]
 #![deny(clippy::undocumented_unsafe_blocks)]
@@ -141,7 +148,11 @@
 
 use std::{
     cell::UnsafeCell,
-    sync::{atomic::Ordering, Condvar, Mutex},
+    future::Future,
+    pin::Pin,
+    ptr::NonNull,
+    sync::{atomic::Ordering, Condvar, Mutex, PoisonError},
+    task::{self, Poll, Waker},
 };
 
 use intrusive_collections::{intrusive_adapter, LinkedList, LinkedListLink, UnsafeRef};
@@ -168,6 +179,8 @@ pub(crate) struct FutexWaiter {
     pub(super) link: LinkedListLink,
     pub(super) cond_var: Condvar,
     pub(super) waiting: bool,
+    /// The waker of the task polling an async waiter, if any.
+    waker: Option<Waker>,
     addr: usize,
 }
 
@@ -202,7 +215,11 @@ impl FutexWaiters {
 
             // SAFETY: all elements of the waiters list are guaranteed to be valid.
             unsafe {
-                (*UnsafeRef::into_raw(elem)).waiting = false;
+                let elem = UnsafeRef::into_raw(elem);
+                (*elem).waiting = false;
+                if let Some(waker) = (*elem).waker.take() {
+                    waker.wake();
+                }
             }
         }
 
@@ -379,6 +396,177 @@ pub(super) unsafe fn wait<E: Element + PartialEq>(
     Ok(result)
 }
 
+/// The result of calling [`wait_async`].
+pub(super) enum AsyncWaitResult {
+    /// The wait finished synchronously.
+    Sync(AtomicsWaitResult),
+    /// The agent was added to the wait queue, and will be notified when the future completes.
+    Async(AsyncWaiter),
+}
+
+/// Adds a new async waiter to the wait queue for the address pointed to by `buffer[offset..]`.
+///
+/// Returns [`AsyncWaitResult::Sync`] if the value at the address is not equal to `check` or if
+/// `timeout` is zero, since those cases don't need to wait.
+///
+/// # Safety
+///
+/// - `addr` must be a multiple of `std::mem::size_of::<E>()`.
+/// - `buffer` must contain at least `std::mem::size_of::<E>()` bytes to read starting from `usize`.
+pub(super) unsafe fn wait_async<E: Element + PartialEq>(
+    buffer: &SharedArrayBuffer,
+    buf_len: usize,
+    offset: usize,
+    check: E,
+    timeout: Option<Duration>,
+) -> JsResult<AsyncWaitResult> {
+    // 11. Let block be buffer.[[ArrayBufferData]].
+    // 14. Let WL be GetWaiterList(block, byteIndexInBuffer).
+    // 17. Perform EnterCriticalSection(WL).
+    let mut waiters = CRITICAL_SECTION.lock().map_err(|_| {
+        // avoids exposing internals of our implementation.
+        JsNativeError::typ().with_message("failed to synchronize with the agent cluster")
+    })?;
+
+    let buffer = &buffer.bytes_with_len(buf_len)[offset..];
+
+    // 18. Let elementType be TypedArrayElementType(typedArray).
+    // 19. Let w be GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, seq-cst).
+
+    // SAFETY: The safety of this operation is guaranteed by the caller.
+    let value = unsafe { E::read(SliceRef::AtomicSlice(buffer)).load(Ordering::SeqCst) };
+
+    // 20. If v ≠ w, then
+    //     a. Perform LeaveCriticalSection(WL).
+    //     ...
+    //     d. Perform ! CreateDataPropertyOrThrow(resultObject, "value", "not-equal").
+    if check != value {
+        return Ok(AsyncWaitResult::Sync(AtomicsWaitResult::NotEqual));
+    }
+
+    // 21. If t = 0 and mode is async, then
+    //     ...
+    //     b. Perform LeaveCriticalSection(WL).
+    //     ...
+    //     d. Perform ! CreateDataPropertyOrThrow(resultObject, "value", "timed-out").
+    if timeout == Some(Duration::ZERO) {
+        return Ok(AsyncWaitResult::Sync(AtomicsWaitResult::TimedOut));
+    }
+
+    // 22. Let thisAgent be AgentSignifier().
+    // 23. Let now be the time value (UTC) identifying the current time.
+    // 24. Let additionalTimeout be an implementation-defined non-negative mathematical value.
+    // 25. Let timeoutTime be ℝ(now) + t + additionalTimeout.
+    // 26. NOTE: When t is +∞, timeoutTime is also +∞.
+    // 27. Let waiterRecord be a new Waiter Record { ... }.
+    // 28. Perform AddWaiter(WL, waiterRecord).
+    let waiter = NonNull::from(Box::leak(Box::<FutexWaiter>::default()));
+
+    // SAFETY: `waiter` was just allocated, and is only deallocated by `AsyncWaiter::drop`, which
+    // removes it from its waiter list first.
+    unsafe {
+        waiters.add_waiter(waiter.as_ptr(), buffer.as_ptr().addr());
+    }
+
+    // 29. If mode is sync, then
+    //     ...
+    // 30. Else if timeoutTime is finite, then
+    //     a. Perform EnqueueAtomicsWaitAsyncTimeoutJob(WL, waiterRecord).
+    //        Handled by the timeout of `AsyncWaiter`.
+    // 31. Perform LeaveCriticalSection(WL).
+    drop(waiters);
+
+    Ok(AsyncWaitResult::Async(AsyncWaiter {
+        waiter,
+        deadline: timeout.and_then(|timeout| Instant::now().checked_add(timeout)),
+        timer_started: false,
+    }))
+}
+
+/// A future that completes when its waiter is notified or its timeout has passed.
+pub(super) struct AsyncWaiter {
+    /// The heap allocated waiter, owned by this future.
+    waiter: NonNull<FutexWaiter>,
+    deadline: Option<Instant>,
+    timer_started: bool,
+}
+
+impl Future for AsyncWaiter {
+    type Output = JsResult<AtomicsWaitResult>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let waiter = this.waiter.as_ptr();
+
+        let mut waiters = CRITICAL_SECTION.lock().map_err(|_| {
+            JsNativeError::typ().with_message("failed to synchronize with the agent cluster")
+        })?;
+
+        // SAFETY: `waiter` is valid until this future is dropped, and the critical section is
+        // locked, so no other agent can access it.
+        unsafe {
+            if !(*waiter).waiting {
+                return Poll::Ready(Ok(AtomicsWaitResult::Ok));
+            }
+
+            let remaining = match this.deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) if !remaining.is_zero() => Some(remaining),
+                    _ => {
+                        waiters.remove_waiter(waiter);
+                        (*waiter).waiting = false;
+                        return Poll::Ready(Ok(AtomicsWaitResult::TimedOut));
+                    }
+                },
+                None => None,
+            };
+
+            (*waiter).waker = Some(cx.waker().clone());
+
+            // Nothing else will wake the task when the timeout expires, so spawn a thread that
+            // does it. If the platform cannot spawn threads, the timeout will only be checked
+            // after a notification.
+            if let Some(remaining) = remaining.filter(|_| !this.timer_started) {
+                let waker = cx.waker().clone();
+                this.timer_started = std::thread::Builder::new()
+                    .name("Atomics.waitAsync timer".into())
+                    .spawn(move || {
+                        std::thread::sleep(remaining);
+                        waker.wake();
+                    })
+                    .is_ok();
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for AsyncWaiter {
+    fn drop(&mut self) {
+        let waiter = self.waiter.as_ptr();
+
+        // The waiter must be unlinked even if another agent panicked while holding the lock,
+        // since it is about to be deallocated.
+        let mut waiters = CRITICAL_SECTION
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // SAFETY: `waiter` is valid and contained in its waiter list if `waiting == true`.
+        unsafe {
+            if (*waiter).waiting {
+                waiters.remove_waiter(waiter);
+            }
+        }
+
+        drop(waiters);
+
+        // SAFETY: `waiter` was allocated with `Box` by `wait_async`, and it isn't linked to any
+        // waiter list anymore.
+        drop(unsafe { Box::from_raw(waiter) });
+    }
+}
+
 /// Notifies at most `count` agents waiting on the memory address pointed to by `buffer[offset..]`.
 pub(super) fn notify(buffer: &SharedArrayBuffer, offset: usize, count: u64) -> JsResult<u64> {
     let addr = buffer.as_ptr().addr() + offset;
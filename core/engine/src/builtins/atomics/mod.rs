@@ -12,13 +12,24 @@
 
 mod futex;
 
+#[cfg(test)]
+mod tests;
+
 use std::sync::atomic::Ordering;
 
 use crate::{
-    builtins::BuiltInObject, context::intrinsics::Intrinsics, js_string, object::JsObject,
-    property::Attribute, realm::Realm, string::common::StaticJsStrings, symbol::JsSymbol,
-    sys::time::Duration, value::IntegerOrInfinity, Context, JsArgs, JsNativeError, JsResult,
-    JsString, JsValue,
+    builtins::BuiltInObject,
+    context::intrinsics::Intrinsics,
+    job::NativeJob,
+    js_string,
+    object::{builtins::JsPromise, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::common::StaticJsStrings,
+    symbol::JsSymbol,
+    sys::time::Duration,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
 };
 
 use boa_profiler::Profiler;
@@ -53,6 +64,7 @@ impl IntrinsicObject for Atomics {
             .static_method(Atomics::store, js_string!("store"), 3)
             .static_method(Atomics::sub, js_string!("sub"), 3)
             .static_method(Atomics::wait, js_string!("wait"), 4)
+            .static_method(Atomics::wait_async, js_string!("waitAsync"), 4)
             .static_method(Atomics::notify, js_string!("notify"), 3)
             .static_method(Atomics::bit_xor, js_string!("xor"), 3)
             .build();
@@ -386,54 +398,119 @@ impl Atomics {
     /// [`Atomics.wait ( typedArray, index, value, timeout )`][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-atomics.wait
-    // TODO: rewrite this to support Atomics.waitAsync
     fn wait(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? DoWait(sync, typedArray, index, value, timeout).
+        do_wait(WaitMode::Sync, args, context)
+    }
+
+    /// [`Atomics.waitAsync ( typedArray, index, value, timeout )`][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.waitasync
+    fn wait_async(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? DoWait(async, typedArray, index, value, timeout).
+        do_wait(WaitMode::Async, args, context)
+    }
+
+    /// [`Atomics.notify ( typedArray, index, count )`][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.notify
+    fn notify(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let array = args.get_or_undefined(0);
         let index = args.get_or_undefined(1);
-        let value = args.get_or_undefined(2);
-        let timeout = args.get_or_undefined(3);
+        let count = args.get_or_undefined(2);
 
-        // 1. Let taRecord be ? ValidateIntegerTypedArray(typedArray, true).
+        // 1. Let indexedPosition be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index, true).
         let (ta, buf_len) = validate_integer_typed_array(array, true)?;
+        let access = validate_atomic_access(&ta, buf_len, index, context)?;
 
-        // 2. Let buffer be taRecord.[[Object]].[[ViewedArrayBuffer]].
-        // 2. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
-        let buffer = match ta.borrow().data.viewed_array_buffer() {
-            BufferObject::SharedBuffer(buf) => buf.clone(),
-            BufferObject::Buffer(_) => {
-                return Err(JsNativeError::typ()
-                    .with_message("cannot use `ArrayBuffer` for an atomic wait")
-                    .into())
+        // 2. If count is undefined, then
+        let count = if count.is_undefined() {
+            // a. Let c be +∞.
+            u64::MAX
+        } else {
+            // 3. Else,
+            //     a. Let intCount be ? ToIntegerOrInfinity(count).
+            //     b. Let c be max(intCount, 0).
+            match count.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::PositiveInfinity => u64::MAX,
+                IntegerOrInfinity::Integer(i) => i64::max(i, 0) as u64,
+                IntegerOrInfinity::NegativeInfinity => 0,
             }
         };
 
-        // 3. Let indexedPosition be ? ValidateAtomicAccess(typedArray, index).
-        let access = validate_atomic_access(&ta, buf_len, index, context)?;
-
-        // spec expects the evaluation of this first, then the timeout.
-        let value = if access.kind == TypedArrayKind::BigInt64 {
-            // 4. If typedArray.[[TypedArrayName]] is "BigInt64Array", let v be ? ToBigInt64(value).
-            value.to_big_int64(context)?
-        } else {
-            // 5. Otherwise, let v be ? ToInt32(value).
-            i64::from(value.to_i32(context)?)
+        // 4. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 5. Let block be buffer.[[ArrayBufferData]].
+        // 6. If IsSharedArrayBuffer(buffer) is false, return +0𝔽.
+        let ta = ta.borrow();
+        let BufferRef::SharedBuffer(shared) = ta.data.viewed_array_buffer().as_buffer() else {
+            return Ok(0.into());
         };
 
-        // moving above since we need to make a generic call next.
+        let count = futex::notify(&shared, access.byte_offset, count)?;
 
-        // 6. Let q be ? ToNumber(timeout).
-        // 7. If q is either NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
-        let mut timeout = timeout.to_number(context)?;
-        // convert to nanoseconds to discard any excessively big timeouts.
-        timeout = timeout.clamp(0.0, f64::INFINITY) * 1000.0 * 1000.0;
-        let timeout = if timeout.is_nan() || timeout.is_infinite() || timeout > u64::MAX as f64 {
-            None
-        } else {
-            Some(Duration::from_nanos(timeout as u64))
-        };
+        // 12. Let n be the number of elements in S.
+        // 13. Return 𝔽(n).
+        Ok(count.into())
+    }
+}
+
+/// The mode of a [`DoWait`][spec] operation.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dowait
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WaitMode {
+    Sync,
+    Async,
+}
+
+/// [`DoWait ( mode, typedArray, index, value, timeout )`][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dowait
+fn do_wait(mode: WaitMode, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let array = args.get_or_undefined(0);
+    let index = args.get_or_undefined(1);
+    let value = args.get_or_undefined(2);
+    let timeout = args.get_or_undefined(3);
+
+    // 1. Let taRecord be ? ValidateIntegerTypedArray(typedArray, true).
+    let (ta, buf_len) = validate_integer_typed_array(array, true)?;
+
+    // 2. Let buffer be taRecord.[[Object]].[[ViewedArrayBuffer]].
+    // 3. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
+    let buffer = match ta.borrow().data.viewed_array_buffer() {
+        BufferObject::SharedBuffer(buf) => buf.clone(),
+        BufferObject::Buffer(_) => {
+            return Err(JsNativeError::typ()
+                .with_message("cannot use `ArrayBuffer` for an atomic wait")
+                .into())
+        }
+    };
+
+    // 4. Let i be ? ValidateAtomicAccess(taRecord, index).
+    let access = validate_atomic_access(&ta, buf_len, index, context)?;
 
-        // 8. Let B be AgentCanSuspend().
-        // 9. If B is false, throw a TypeError exception.
+    // spec expects the evaluation of this first, then the timeout.
+    let value = if access.kind == TypedArrayKind::BigInt64 {
+        // 6. If arrayTypeName is "BigInt64Array", let v be ? ToBigInt64(value).
+        value.to_big_int64(context)?
+    } else {
+        // 7. Else, let v be ? ToInt32(value).
+        i64::from(value.to_i32(context)?)
+    };
+
+    // 8. Let q be ? ToNumber(timeout).
+    // 9. If q is either NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
+    let mut timeout = timeout.to_number(context)?;
+    // convert to nanoseconds to discard any excessively big timeouts.
+    timeout = timeout.clamp(0.0, f64::INFINITY) * 1000.0 * 1000.0;
+    let timeout = if timeout.is_nan() || timeout.is_infinite() || timeout > u64::MAX as f64 {
+        None
+    } else {
+        Some(Duration::from_nanos(timeout as u64))
+    };
+
+    if mode == WaitMode::Sync {
+        // 10. If mode is sync and AgentCanSuspend() is false, throw a TypeError exception.
         if !context.can_block() {
             return Err(JsNativeError::typ()
                 .with_message("agent cannot be suspended")
@@ -462,54 +539,88 @@ impl Atomics {
             }
         };
 
-        Ok(match result {
-            futex::AtomicsWaitResult::NotEqual => js_string!("not-equal"),
-            futex::AtomicsWaitResult::TimedOut => js_string!("timed-out"),
-            futex::AtomicsWaitResult::Ok => js_string!("ok"),
-        }
-        .into())
+        return Ok(wait_result_string(result).into());
     }
 
-    /// [`Atomics.notify ( typedArray, index, count )`][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-atomics.notify
-    fn notify(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let array = args.get_or_undefined(0);
-        let index = args.get_or_undefined(1);
-        let count = args.get_or_undefined(2);
+    // SAFETY: the validity of `addr` is verified by our call to `validate_atomic_access`.
+    let result = unsafe {
+        if access.kind == TypedArrayKind::BigInt64 {
+            futex::wait_async(
+                &buffer.borrow().data,
+                buf_len,
+                access.byte_offset,
+                value,
+                timeout,
+            )?
+        } else {
+            // value must fit into `i32` since it came from an `i32` above.
+            futex::wait_async(
+                &buffer.borrow().data,
+                buf_len,
+                access.byte_offset,
+                value as i32,
+                timeout,
+            )?
+        }
+    };
 
-        // 1. Let indexedPosition be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index, true).
-        let (ta, buf_len) = validate_integer_typed_array(array, true)?;
-        let access = validate_atomic_access(&ta, buf_len, index, context)?;
+    let (is_async, value): (bool, JsValue) = match result {
+        futex::AsyncWaitResult::Sync(result) => (false, wait_result_string(result).into()),
+        futex::AsyncWaitResult::Async(waiter) => {
+            // 16. Else,
+            //     a. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+            let (promise, resolvers) = JsPromise::new_pending(context);
+
+            // The promise is resolved by a job enqueued once the waiter is notified or times out.
+            let future = async move {
+                let result = waiter.await;
+
+                NativeJob::new(move |context| match result {
+                    Ok(result) => resolvers.resolve.call(
+                        &JsValue::undefined(),
+                        &[wait_result_string(result).into()],
+                        context,
+                    ),
+                    Err(e) => {
+                        let e = e.to_opaque(context);
+                        resolvers.reject.call(&JsValue::undefined(), &[e], context)
+                    }
+                })
+            };
 
-        // 2. If count is undefined, then
-        let count = if count.is_undefined() {
-            // a. Let c be +∞.
-            u64::MAX
-        } else {
-            // 3. Else,
-            //     a. Let intCount be ? ToIntegerOrInfinity(count).
-            //     b. Let c be max(intCount, 0).
-            match count.to_integer_or_infinity(context)? {
-                IntegerOrInfinity::PositiveInfinity => u64::MAX,
-                IntegerOrInfinity::Integer(i) => i64::max(i, 0) as u64,
-                IntegerOrInfinity::NegativeInfinity => 0,
-            }
-        };
+            context
+                .job_queue()
+                .enqueue_future_job(Box::pin(future), context);
 
-        // 4. Let buffer be typedArray.[[ViewedArrayBuffer]].
-        // 5. Let block be buffer.[[ArrayBufferData]].
-        // 6. If IsSharedArrayBuffer(buffer) is false, return +0𝔽.
-        let ta = ta.borrow();
-        let BufferRef::SharedBuffer(shared) = ta.data.viewed_array_buffer().as_buffer() else {
-            return Ok(0.into());
-        };
+            (true, promise.into())
+        }
+    };
 
-        let count = futex::notify(&shared, access.byte_offset, count)?;
+    // 16. b. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+    let result_object = JsObject::with_object_proto(context.intrinsics());
 
-        // 12. Let n be the number of elements in S.
-        // 13. Return 𝔽(n).
-        Ok(count.into())
+    // 33. Perform ! CreateDataPropertyOrThrow(resultObject, "async", true).
+    //     Or `false` if the wait finished synchronously.
+    result_object
+        .create_data_property_or_throw(js_string!("async"), is_async, context)
+        .expect("this CreateDataPropertyOrThrow call must not fail");
+
+    // 34. Perform ! CreateDataPropertyOrThrow(resultObject, "value", promiseCapability.[[Promise]]).
+    //     Or the result of the wait if it finished synchronously.
+    result_object
+        .create_data_property_or_throw(js_string!("value"), value, context)
+        .expect("this CreateDataPropertyOrThrow call must not fail");
+
+    // 35. Return resultObject.
+    Ok(result_object.into())
+}
+
+/// Converts the result of a wait to the string returned to ECMAScript code.
+fn wait_result_string(result: futex::AtomicsWaitResult) -> JsString {
+    match result {
+        futex::AtomicsWaitResult::NotEqual => js_string!("not-equal"),
+        futex::AtomicsWaitResult::TimedOut => js_string!("timed-out"),
+        futex::AtomicsWaitResult::Ok => js_string!("ok"),
     }
 }
 
@@ -0,0 +1,221 @@
+use std::{thread, time::Duration};
+
+use crate::{
+    builtins::array_buffer::SharedArrayBuffer, js_string, object::builtins::JsSharedArrayBuffer,
+    property::Attribute, run_test_actions, Context, JsNativeErrorKind, JsValue, Source, TestAction,
+};
+
+/// Creates a context that views `buffer` through an `Int32Array` in the global `view`.
+fn context_with_buffer(buffer: SharedArrayBuffer, can_block: bool) -> Context {
+    let mut context = Context::builder()
+        .can_block(can_block)
+        .build()
+        .expect("the context must be the only one in its thread");
+    let buffer = JsSharedArrayBuffer::from_buffer(buffer, &mut context);
+    context
+        .register_global_property(js_string!("buffer"), buffer, Attribute::all())
+        .expect("the `buffer` property shouldn't exist yet");
+    context
+        .eval(Source::from_bytes("var view = new Int32Array(buffer);"))
+        .expect("the view must be created");
+    context
+}
+
+#[test]
+fn wait_and_notify_across_threads() {
+    let mut notifier = Context::default();
+    let buffer = JsSharedArrayBuffer::new(8, &mut notifier)
+        .expect("the buffer must be allocated")
+        .inner();
+
+    let waiter = thread::spawn({
+        let buffer = buffer.clone();
+        move || {
+            let mut context = context_with_buffer(buffer, true);
+            context
+                .eval(Source::from_bytes(
+                    "Atomics.wait(view, 0, 0) + ',' + Atomics.load(view, 1)",
+                ))
+                .expect("the wait must succeed")
+                .to_string(&mut context)
+                .expect("the result must be a string")
+                .to_std_string_escaped()
+        }
+    });
+
+    let buffer = JsSharedArrayBuffer::from_buffer(buffer, &mut notifier);
+    notifier
+        .register_global_property(js_string!("buffer"), buffer, Attribute::all())
+        .expect("the `buffer` property shouldn't exist yet");
+    notifier
+        .eval(Source::from_bytes(
+            "var view = new Int32Array(buffer); Atomics.store(view, 1, 42);",
+        ))
+        .expect("the store must succeed");
+
+    // The waiter may not be suspended yet, so keep notifying until it is woken up.
+    let mut woken = false;
+    for _ in 0..5000 {
+        let count = notifier
+            .eval(Source::from_bytes("Atomics.notify(view, 0, 1)"))
+            .expect("the notify must succeed");
+        if count == JsValue::new(1) {
+            woken = true;
+            break;
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    assert!(woken, "the waiting thread was never suspended");
+    assert_eq!(waiter.join().expect("the waiter must not panic"), "ok,42");
+}
+
+#[test]
+fn atomics_on_shared_memory() {
+    run_test_actions([
+        TestAction::run(indoc::indoc! {r#"
+            var view = new Int32Array(new SharedArrayBuffer(8));
+            Atomics.store(view, 0, 5);
+        "#}),
+        TestAction::assert_eq("Atomics.add(view, 0, 2)", 5),
+        TestAction::assert_eq("Atomics.sub(view, 0, 1)", 7),
+        TestAction::assert_eq("Atomics.compareExchange(view, 0, 6, 1)", 6),
+        TestAction::assert_eq("Atomics.exchange(view, 0, 3)", 1),
+        TestAction::assert_eq("Atomics.load(view, 0)", 3),
+        TestAction::assert_eq("Atomics.notify(view, 0)", 0),
+        TestAction::assert("Atomics.isLockFree(4)"),
+        TestAction::assert_native_error(
+            "Atomics.wait(view, 0, 3, 0)",
+            JsNativeErrorKind::Type,
+            "agent cannot be suspended",
+        ),
+        TestAction::assert_native_error(
+            "Atomics.wait(new Int32Array(4), 0, 0, 0)",
+            JsNativeErrorKind::Type,
+            "cannot use `ArrayBuffer` for an atomic wait",
+        ),
+    ]);
+}
+
+#[test]
+fn wait_async_synchronous_results() {
+    run_test_actions([
+        TestAction::run("var view = new Int32Array(new SharedArrayBuffer(8));"),
+        TestAction::run("var result = Atomics.waitAsync(view, 0, 1);"),
+        TestAction::assert_eq("result.async", false),
+        TestAction::assert_eq("result.value", js_string!("not-equal")),
+        TestAction::run("result = Atomics.waitAsync(view, 0, 0, 0);"),
+        TestAction::assert_eq("result.async", false),
+        TestAction::assert_eq("result.value", js_string!("timed-out")),
+        TestAction::assert("Object.getPrototypeOf(result) === Object.prototype"),
+        TestAction::assert_native_error(
+            "Atomics.waitAsync(new Int32Array(4), 0, 0)",
+            JsNativeErrorKind::Type,
+            "cannot use `ArrayBuffer` for an atomic wait",
+        ),
+        TestAction::assert_native_error(
+            "Atomics.waitAsync(new Int8Array(new SharedArrayBuffer(4)), 0, 0)",
+            JsNativeErrorKind::Type,
+            "can only atomically wait using Int32 or BigInt64 arrays",
+        ),
+    ]);
+}
+
+#[test]
+fn wait_async_in_the_same_agent() {
+    run_test_actions([
+        TestAction::run(indoc::indoc! {r#"
+            var view = new BigInt64Array(new SharedArrayBuffer(16));
+            var results = [];
+            var first = Atomics.waitAsync(view, 1, 0n);
+            var second = Atomics.waitAsync(view, 1, 0n, 10);
+            first.value.then(value => results.push('first: ' + value));
+            second.value.then(value => results.push('second: ' + value));
+            var notified = Atomics.notify(view, 1, 1);
+        "#}),
+        TestAction::assert("first.async && first.value instanceof Promise"),
+        TestAction::assert_eq("notified", 1),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("results.join()", js_string!("first: ok,second: timed-out")),
+        TestAction::assert_eq("Atomics.notify(view, 1)", 0),
+    ]);
+}
+
+#[test]
+fn wait_async_and_notify_across_threads() {
+    let mut notifier = Context::default();
+    let buffer = JsSharedArrayBuffer::new(8, &mut notifier)
+        .expect("the buffer must be allocated")
+        .inner();
+
+    let waiter = thread::spawn({
+        let buffer = buffer.clone();
+        move || {
+            let mut context = context_with_buffer(buffer, false);
+            context
+                .eval(Source::from_bytes(indoc::indoc! {r#"
+                    var result;
+                    var wait = Atomics.waitAsync(view, 0, 0);
+                    wait.value.then(value => result = wait.async + ',' + value);
+                    Atomics.store(view, 1, 1);
+                "#}))
+                .expect("the wait must succeed");
+            context.run_jobs();
+            context
+                .eval(Source::from_bytes("result"))
+                .expect("the result must be set")
+                .to_string(&mut context)
+                .expect("the result must be a string")
+                .to_std_string_escaped()
+        }
+    });
+
+    let buffer = JsSharedArrayBuffer::from_buffer(buffer, &mut notifier);
+    notifier
+        .register_global_property(js_string!("buffer"), buffer, Attribute::all())
+        .expect("the `buffer` property shouldn't exist yet");
+    notifier
+        .eval(Source::from_bytes("var view = new Int32Array(buffer);"))
+        .expect("the view must be created");
+
+    // The waiter may not be registered yet, so keep notifying until it is woken up.
+    let mut woken = false;
+    for _ in 0..5000 {
+        let count = notifier
+            .eval(Source::from_bytes(
+                "Atomics.load(view, 1) === 1 ? Atomics.notify(view, 0, 1) : 0",
+            ))
+            .expect("the notify must succeed");
+        if count == JsValue::new(1) {
+            woken = true;
+            break;
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    assert!(woken, "the async waiter was never registered");
+    assert_eq!(waiter.join().expect("the waiter must not panic"), "true,ok");
+}
+
+#[test]
+fn wait_async_cleanup_on_context_drop() {
+    let mut context = Context::default();
+    let buffer = JsSharedArrayBuffer::new(8, &mut context)
+        .expect("the buffer must be allocated")
+        .inner();
+
+    let mut waiter = context_with_buffer(buffer.clone(), false);
+    waiter
+        .eval(Source::from_bytes("Atomics.waitAsync(view, 0, 0).async"))
+        .expect("the wait must succeed");
+    drop(waiter);
+
+    // The pending waiter was removed from the wait queue when its context was dropped.
+    let mut notifier = context_with_buffer(buffer, false);
+    assert_eq!(
+        notifier
+            .eval(Source::from_bytes("Atomics.notify(view, 0)"))
+            .expect("the notify must succeed"),
+        JsValue::new(0)
+    );
+}
@@ -320,3 +320,16 @@ fn as_uint_n_errors() {
         ),
     ]);
 }
+
+#[cfg(feature = "intl")]
+#[test]
+fn to_locale_string() {
+    run_test_actions([
+        TestAction::assert_eq("1234567n.toLocaleString('en-US')", js_string!("1,234,567")),
+        TestAction::assert_eq("1234567n.toLocaleString('de-DE')", js_string!("1.234.567")),
+        TestAction::assert_eq(
+            "1234567n.toLocaleString('en-US', { useGrouping: false })",
+            js_string!("1234567"),
+        ),
+    ]);
+}
@@ -366,9 +366,17 @@ impl BuiltInConstructorWithPrototype<'_> {
         self = self.static_property(js_string!("name"), name, Attribute::CONFIGURABLE);
         self = self.static_property(PROTOTYPE, prototype, Attribute::empty());
 
-        let attributes = self.attributes;
-        let object = self.object.clone();
-        self = self.property(CONSTRUCTOR, object, attributes);
+        // The prototype can define its own "constructor" property, like the accessor of
+        // `Iterator.prototype`.
+        if !self
+            .prototype_property_table
+            .map
+            .contains_key(&PropertyKey::from(CONSTRUCTOR))
+        {
+            let attributes = self.attributes;
+            let object = self.object.clone();
+            self = self.property(CONSTRUCTOR, object, attributes);
+        }
 
         {
             let mut prototype = self.prototype.borrow_mut();
@@ -383,6 +391,7 @@ impl BuiltInConstructorWithPrototype<'_> {
                 &mut prototype.properties_mut().storage,
                 self.prototype_storage,
             );
+            prototype.properties_mut().track_memory();
 
             debug_assert_eq!(prototype_old_storage.len(), 0);
         }
@@ -403,6 +412,7 @@ impl BuiltInConstructorWithPrototype<'_> {
 
         let object_old_storage =
             std::mem::replace(&mut object.properties_mut().storage, self.object_storage);
+        object.properties_mut().track_memory();
 
         debug_assert_eq!(object_old_storage.len(), 0);
     }
@@ -429,6 +439,7 @@ impl BuiltInConstructorWithPrototype<'_> {
 
         let object_old_storage =
             std::mem::replace(&mut object.properties_mut().storage, self.object_storage);
+        object.properties_mut().track_memory();
 
         debug_assert_eq!(object_old_storage.len(), 0);
     }
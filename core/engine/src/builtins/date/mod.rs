@@ -30,7 +30,7 @@ use crate::{
     string::{common::StaticJsStrings, utf16},
     symbol::JsSymbol,
     value::{JsValue, PreferredType},
-    Context, JsArgs, JsData, JsError, JsResult, JsString,
+    Context, JsArgs, JsData, JsResult, JsString,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -51,6 +51,11 @@ impl Date {
         Self(dt)
     }
 
+    /// Gets the time value of the `Date`.
+    pub(crate) const fn time_value(self) -> f64 {
+        self.0
+    }
+
     /// Creates a new `Date` from the current UTC time of the host.
     pub(crate) fn utc_now(hooks: &dyn HostHooks) -> Self {
         Self(hooks.utc_now() as f64)
@@ -1485,16 +1490,33 @@ impl Date {
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaledatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
     pub(crate) fn to_locale_date_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::DateTimeReqs;
+
+            Self::to_locale_string_impl(
+                this,
+                args,
+                &DateTimeReqs::Date,
+                &DateTimeReqs::Date,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (this, args, context);
+            Err(crate::JsError::from_opaque(JsValue::new(js_string!(
+                "Function Unimplemented"
+            ))))
+        }
     }
 
     /// [`Date.prototype.toLocaleString()`][spec].
@@ -1504,36 +1526,110 @@ impl Date {
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocalestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
     pub(crate) fn to_locale_string(
-        _this: &JsValue,
-        _: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::DateTimeReqs;
+
+            Self::to_locale_string_impl(
+                this,
+                args,
+                &DateTimeReqs::AnyAll,
+                &DateTimeReqs::AnyAll,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (this, args, context);
+            Err(crate::JsError::from_opaque(JsValue::new(js_string!(
+                "Function Unimplemented]"
+            ))))
+        }
     }
 
     /// [`Date.prototype.toLocaleTimeString()`][spec].
     ///
     /// The `toLocaleTimeString()` method returns the time portion of a Date object in human readable
-    /// form in American English.
+    /// form according to language-specific conventions.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaletimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
     pub(crate) fn to_locale_time_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::DateTimeReqs;
+
+            Self::to_locale_string_impl(
+                this,
+                args,
+                &DateTimeReqs::Time,
+                &DateTimeReqs::Time,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (this, args, context);
+            Err(crate::JsError::from_opaque(JsValue::new(js_string!(
+                "Function Unimplemented]"
+            ))))
+        }
+    }
+
+    /// Common steps of the `toLocaleString`, `toLocaleDateString` and `toLocaleTimeString`
+    /// methods, which only differ in the `required` and `defaults` arguments passed to
+    /// `CreateDateTimeFormat`.
+    #[cfg(feature = "intl")]
+    fn to_locale_string_impl(
+        this: &JsValue,
+        args: &[JsValue],
+        required: &crate::builtins::intl::date_time_format::DateTimeReqs,
+        defaults: &crate::builtins::intl::date_time_format::DateTimeReqs,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use crate::builtins::intl::DateTimeFormat;
+
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let x be dateObject.[[DateValue]].
+        let x = this
+            .as_object()
+            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+            .0;
+
+        // 4. If x is NaN, return "Invalid Date".
+        if x.is_nan() {
+            return Ok(js_string!("Invalid Date").into());
+        }
+
+        // 5. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, required, defaults).
+        let date_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            required,
+            defaults,
+            context,
+        )?;
+
+        // 6. Return ! FormatDateTime(dateFormat, x).
+        Ok(date_format.format(x, context.host_hooks())?.into())
     }
 
     /// [`Date.prototype.toString()`][spec].
@@ -174,6 +174,129 @@ fn date_ctor_parse_call() {
     )]);
 }
 
+#[test]
+fn date_parse_legacy_formats() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Date.parse('Tue, 01 Aug 2023 14:00:00 GMT')",
+            timestamp_from_utc(2023, 8, 1, 14, 0, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('Tue, 01 Aug 2023 14:00:00.5 UTC')",
+            timestamp_from_utc(2023, 8, 1, 14, 0, 0, 500),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('Tue, 01 Aug 2023 14:00:00 +0200')",
+            timestamp_from_utc(2023, 8, 1, 12, 0, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('Tue, 1 Aug 2023 10:00 EDT')",
+            timestamp_from_utc(2023, 8, 1, 14, 0, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('Aug 1 2023 2:30 PM GMT-02:00')",
+            timestamp_from_utc(2023, 8, 1, 16, 30, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('Aug 1 2023 12:15 am Z')",
+            timestamp_from_utc(2023, 8, 1, 0, 15, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('Thu Jan 01 1970 00:00:00 GMT+0000 (Coordinated Universal Time)')",
+            0,
+        ),
+        TestAction::assert_eq(
+            "Date.parse('August 1, 2023')",
+            timestamp_from_local(2023, 8, 1, 0, 0, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('8/1/2023 14:00')",
+            timestamp_from_local(2023, 8, 1, 14, 0, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('2023/08/01')",
+            timestamp_from_local(2023, 8, 1, 0, 0, 0, 0),
+        ),
+        // The Date Time String Format takes precedence, so this is in UTC.
+        TestAction::assert_eq(
+            "Date.parse('2023-08-01')",
+            timestamp_from_utc(2023, 8, 1, 0, 0, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "new Date('Tue, 01 Aug 2023 14:00:00 GMT').toISOString()",
+            js_string!("2023-08-01T14:00:00.000Z"),
+        ),
+    ]);
+}
+
+#[test]
+fn date_parse_legacy_round_trips() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var dates = [
+                new Date(2020, 6, 8, 9, 16, 15),
+                new Date(Date.UTC(-1, 0, 1)),
+                new Date(Date.UTC(12345, 11, 31, 23, 59, 59)),
+            ];
+        "#}),
+        TestAction::assert("dates.every(d => Date.parse(d.toString()) === d.getTime())"),
+        TestAction::assert("dates.every(d => Date.parse(d.toUTCString()) === d.getTime())"),
+    ]);
+}
+
+#[test]
+fn date_parse_legacy_two_digit_years() {
+    run_test_actions([
+        TestAction::assert_eq("Date.parse('Thu, 01 Jan 70 00:00:00 GMT')", 0),
+        TestAction::assert_eq(
+            "Date.parse('1/2/49')",
+            timestamp_from_local(2049, 1, 2, 0, 0, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('1/2/50')",
+            timestamp_from_local(1950, 1, 2, 0, 0, 0, 0),
+        ),
+        TestAction::assert_eq(
+            "Date.parse('Jan 2 00 GMT')",
+            timestamp_from_utc(2000, 1, 2, 0, 0, 0, 0),
+        ),
+    ]);
+}
+
+#[test]
+fn date_parse_legacy_dst_boundaries() {
+    run_test_actions([
+        // Clocks jump forward from 2:00 EST to 3:00 EDT.
+        TestAction::assert_eq(
+            "Date.parse('Sun, 12 Mar 2023 03:00:00 EDT') - Date.parse('Sun, 12 Mar 2023 01:59:59 EST')",
+            1000,
+        ),
+        // Clocks fall back from 2:00 EDT to 1:00 EST, so 1:30 happens twice.
+        TestAction::assert_eq(
+            "Date.parse('Sun, 05 Nov 2023 01:30:00 EST') - Date.parse('Sun, 05 Nov 2023 01:30:00 EDT')",
+            3_600_000,
+        ),
+        TestAction::assert_eq(
+            "Date.parse('Sun, 05 Nov 2023 01:30:00 PST')",
+            timestamp_from_utc(2023, 11, 5, 9, 30, 0, 0),
+        ),
+    ]);
+}
+
+#[test]
+fn date_parse_legacy_invalid() {
+    run_test_actions([
+        TestAction::assert_eq("Date.parse('Aug 2023')", f64::NAN),
+        TestAction::assert_eq("Date.parse('13/1/2023')", f64::NAN),
+        TestAction::assert_eq("Date.parse('1 2 2023')", f64::NAN),
+        TestAction::assert_eq("Date.parse('Aug 1 2023 25:00')", f64::NAN),
+        TestAction::assert_eq("Date.parse('Aug 1 2023 13:00 PM')", f64::NAN),
+        TestAction::assert_eq("Date.parse('Aug 1 2023 10:00 GMT EST')", f64::NAN),
+        TestAction::assert_eq("Date.parse('Aug 1 2023 foo')", f64::NAN),
+        TestAction::assert_eq("Date.parse('Aug 1 2023 (comment')", f64::NAN),
+    ]);
+}
+
 #[test]
 fn date_ctor_utc_call() {
     run_test_actions([TestAction::assert_eq(
@@ -876,3 +999,37 @@ fn date_json() {
         js_string!(r#"{"date":"2020-07-08T09:16:15.779Z"}"#),
     )]);
 }
+
+#[cfg(feature = "intl")]
+#[test]
+fn date_proto_to_locale_string() {
+    run_test_actions([
+        TestAction::run("var date = new Date(Date.UTC(2020, 6, 8, 9, 16, 15, 779));"),
+        TestAction::assert_eq(
+            "date.toLocaleString('en-US', { timeZone: 'UTC' })",
+            js_string!("7/8/2020, 9:16:15\u{202f}AM"),
+        ),
+        TestAction::assert_eq(
+            "date.toLocaleDateString('en-US', { timeZone: 'UTC' })",
+            js_string!("7/8/2020"),
+        ),
+        TestAction::assert_eq(
+            "date.toLocaleDateString('de-DE', { timeZone: 'UTC' })",
+            js_string!("8.7.2020"),
+        ),
+        TestAction::assert_eq(
+            "date.toLocaleDateString('en-US', { month: 'long', day: 'numeric', timeZone: 'UTC' })",
+            js_string!("July 8"),
+        ),
+        TestAction::assert_eq(
+            "date.toLocaleTimeString('en-US', { hour12: false, timeZone: 'UTC' })",
+            js_string!("09:16:15"),
+        ),
+        TestAction::assert_eq("new Date(NaN).toLocaleString()", js_string!("Invalid Date")),
+        TestAction::assert_native_error(
+            "date.toLocaleTimeString('en-US', { dateStyle: 'short' })",
+            JsNativeErrorKind::Type,
+            "'time' is required, but dateStyle was defined",
+        ),
+    ]);
+}
@@ -1,7 +1,6 @@
 use crate::{context::HostHooks, js_string, value::IntegerOrInfinity, JsString};
 use boa_macros::utf16;
 use std::{iter::Peekable, str::Chars};
-use time::{macros::format_description, OffsetDateTime, PrimitiveDateTime};
 
 // Time-related Constants
 //
@@ -127,7 +126,7 @@ fn time_from_year(y: f64) -> f64 {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-yearfromtime
-pub(super) fn year_from_time(t: f64) -> i32 {
+pub(crate) fn year_from_time(t: f64) -> i32 {
     const MS_PER_AVERAGE_YEAR: f64 = 12.0 * 30.436_875 * MS_PER_DAY;
 
     // 1. Return the largest integral Number y (closest to +∞) such that TimeFromYear(y) ≤ t.
@@ -166,7 +165,7 @@ fn in_leap_year(t: f64) -> u16 {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-monthfromtime
-pub(super) fn month_from_time(t: f64) -> u8 {
+pub(crate) fn month_from_time(t: f64) -> u8 {
     // 1. Let inLeapYear be InLeapYear(t).
     let in_leap_year = in_leap_year(t);
 
@@ -208,7 +207,7 @@ pub(super) fn month_from_time(t: f64) -> u8 {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-datefromtime
-pub(super) fn date_from_time(t: f64) -> u8 {
+pub(crate) fn date_from_time(t: f64) -> u8 {
     // 1. Let inLeapYear be InLeapYear(t).
     let in_leap_year = in_leap_year(t);
 
@@ -265,7 +264,7 @@ pub(super) fn week_day(t: f64) -> u8 {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-hourfromtime
-pub(super) fn hour_from_time(t: f64) -> u8 {
+pub(crate) fn hour_from_time(t: f64) -> u8 {
     // 1. Return 𝔽(floor(ℝ(t / msPerHour)) modulo HoursPerDay).
     ((t / MS_PER_HOUR).floor()).rem_euclid(HOURS_PER_DAY) as u8
 }
@@ -276,7 +275,7 @@ pub(super) fn hour_from_time(t: f64) -> u8 {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-minfromtime
-pub(super) fn min_from_time(t: f64) -> u8 {
+pub(crate) fn min_from_time(t: f64) -> u8 {
     // 1. Return 𝔽(floor(ℝ(t / msPerMinute)) modulo MinutesPerHour).
     ((t / MS_PER_MINUTE).floor()).rem_euclid(MINUTES_PER_HOUR) as u8
 }
@@ -287,7 +286,7 @@ pub(super) fn min_from_time(t: f64) -> u8 {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-secfromtime
-pub(super) fn sec_from_time(t: f64) -> u8 {
+pub(crate) fn sec_from_time(t: f64) -> u8 {
     // 1. Return 𝔽(floor(ℝ(t / msPerSecond)) modulo SecondsPerMinute).
     ((t / MS_PER_SECOND).floor()).rem_euclid(SECONDS_PER_MINUTE) as u8
 }
@@ -298,7 +297,7 @@ pub(super) fn sec_from_time(t: f64) -> u8 {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-msfromtime
-pub(super) fn ms_from_time(t: f64) -> u16 {
+pub(crate) fn ms_from_time(t: f64) -> u16 {
     // 1. Return 𝔽(ℝ(t) modulo ℝ(msPerSecond)).
     t.rem_euclid(MS_PER_SECOND) as u16
 }
@@ -670,7 +669,7 @@ pub(super) fn to_date_string_t(tv: f64, hooks: &dyn HostHooks) -> JsString {
     )
 }
 
-fn local_timezone_offset_seconds(t: f64, hooks: &dyn HostHooks) -> i32 {
+pub(crate) fn local_timezone_offset_seconds(t: f64, hooks: &dyn HostHooks) -> i32 {
     let millis = t.rem_euclid(MS_PER_SECOND);
     let seconds = ((t - millis) / MS_PER_SECOND) as i64;
     hooks.local_timezone_offset_seconds(seconds)
@@ -720,10 +719,13 @@ pub(super) fn pad_six(t: u32) -> [u16; 6] {
 
 /// Parse a date string according to the steps specified in [`Date.parse`][spec].
 ///
-/// We parse three different formats:
+/// We parse these formats:
 /// - The [`Date Time String Format`][spec-format] specified in the spec: `YYYY-MM-DDTHH:mm:ss.sssZ`
 /// - The `toString` format: `Thu Jan 01 1970 00:00:00 GMT+0000`
 /// - The `toUTCString` format: `Thu, 01 Jan 1970 00:00:00 GMT`
+/// - Other formats accepted by every major engine, described in [`parse_legacy_date`].
+///
+/// The Date Time String Format takes precedence over the other formats.
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-date.parse
 /// [spec-format]: https://tc39.es/ecma262/#sec-date-time-string-format
@@ -738,18 +740,316 @@ pub(super) fn parse_date(date: &JsString, hooks: &dyn HostHooks) -> Option<i64>
         return Some(dt);
     }
 
-    // `toString` format: `Thu Jan 01 1970 00:00:00 GMT+0000`
-    if let Ok(t) = OffsetDateTime::parse(&date, &format_description!("[weekday repr:short] [month repr:short] [day] [year] [hour]:[minute]:[second] GMT[offset_hour sign:mandatory][offset_minute][end]")) {
-        return Some(t.unix_timestamp() * 1000 + i64::from(t.millisecond()));
+    // `toString`, `toUTCString` and other formats accepted by every major engine.
+    parse_legacy_date(&date, hooks)
+}
+
+/// The tokens of the date formats parsed by [`parse_legacy_date`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LegacyToken<'a> {
+    Number(&'a str),
+    Word(&'a str),
+    Punctuator(char),
+}
+
+/// Splits a date string into numbers, words and punctuators, skipping whitespace, commas and
+/// comments in parentheses.
+fn legacy_tokens(mut date: &str) -> Option<Vec<LegacyToken<'_>>> {
+    let mut tokens = Vec::new();
+    while let Some(c) = date.chars().next() {
+        let len = match c {
+            c if c.is_ascii_whitespace() || c == ',' => {
+                date = &date[1..];
+                continue;
+            }
+            '(' => {
+                // Comments can be nested.
+                let mut depth = 0;
+                let (end, _) = date.char_indices().find(|&(_, c)| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                })?;
+                date = &date[end + 1..];
+                continue;
+            }
+            c if c.is_ascii_digit() => {
+                let len = date
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(date.len());
+                tokens.push(LegacyToken::Number(&date[..len]));
+                len
+            }
+            c if c.is_ascii_alphabetic() => {
+                let len = date
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .unwrap_or(date.len());
+                tokens.push(LegacyToken::Word(&date[..len]));
+                len
+            }
+            ':' | '/' | '.' | '+' | '-' => {
+                tokens.push(LegacyToken::Punctuator(c));
+                1
+            }
+            _ => return None,
+        };
+        date = &date[len..];
     }
+    Some(tokens)
+}
 
-    // `toUTCString` format: `Thu, 01 Jan 1970 00:00:00 GMT`
-    if let Ok(t) = PrimitiveDateTime::parse(&date, &format_description!("[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT[end]")) {
-        let t = t.assume_utc();
-        return Some(t.unix_timestamp() * 1000 + i64::from(t.millisecond()));
+/// Checks if `word` is `name`, or an abbreviation of it with at least three letters.
+fn is_abbreviation_of(word: &str, name: &str) -> bool {
+    word.len() >= 3
+        && name
+            .get(..word.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(word))
+}
+
+/// Parses the date formats that aren't specified, but are accepted by every major engine.
+///
+/// The date must consist of a month, a day and a year, in one of these forms:
+/// - `Tue, 01 Aug 2023`, as in RFC 2822 and the `toUTCString` format.
+/// - `Tue Aug 01 2023` or `August 1, 2023`, as in the `toString` format.
+/// - `08/01/2023` in the month/day/year order, or `2023/08/01` if the year has more than two
+///   digits.
+///
+/// It can be followed by a time, `HH:mm`, `HH:mm:ss` or `HH:mm:ss.sss` with an optional `AM` or
+/// `PM`, and a time zone: `Z`, `UT`, `UTC` or `GMT` with an optional offset like `+0200` or
+/// `+02:00`, an offset after the time, or one of the North American zones of RFC 2822. Dates
+/// without a time zone are in local time, and missing time components are zero.
+///
+/// Month and weekday names are case insensitive and can be abbreviated to three letters, years
+/// from 0 to 49 are in the 2000s and years from 50 to 99 are in the 1900s, and any words before
+/// the first number are ignored.
+fn parse_legacy_date(date: &str, hooks: &dyn HostHooks) -> Option<i64> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    const WEEKDAYS: [&str; 7] = [
+        "sunday",
+        "monday",
+        "tuesday",
+        "wednesday",
+        "thursday",
+        "friday",
+        "saturday",
+    ];
+    // The offsets in minutes of the North American time zones of RFC 2822.
+    const ZONES: [(&str, i64); 8] = [
+        ("est", -5 * 60),
+        ("edt", -4 * 60),
+        ("cst", -6 * 60),
+        ("cdt", -5 * 60),
+        ("mst", -7 * 60),
+        ("mdt", -6 * 60),
+        ("pst", -8 * 60),
+        ("pdt", -7 * 60),
+    ];
+
+    fn next_number(tokens: &mut dyn Iterator<Item = LegacyToken<'_>>) -> Option<u32> {
+        match tokens.next()? {
+            LegacyToken::Number(digits) => digits.parse().ok(),
+            _ => None,
+        }
     }
 
-    None
+    let mut tokens = legacy_tokens(date)?.into_iter().peekable();
+
+    // The numbers of the date, with their digits.
+    let mut numbers: Vec<(i64, usize)> = Vec::new();
+    let mut month = None;
+    let mut slashes = false;
+    let mut time: Option<[u32; 4]> = None;
+    let mut pm = None;
+    // The offset of the time zone, in minutes east of UTC.
+    let mut offset = None;
+    let mut after_utc = false;
+
+    while let Some(token) = tokens.next() {
+        let is_utc = matches!(
+            token,
+            LegacyToken::Word(word) if ["z", "ut", "utc", "gmt"]
+                .iter()
+                .any(|utc| word.eq_ignore_ascii_case(utc))
+        );
+
+        match token {
+            LegacyToken::Number(digits) if tokens.peek() == Some(&LegacyToken::Punctuator(':')) => {
+                if time.is_some() {
+                    return None;
+                }
+                tokens.next();
+                let hour = digits.parse::<u32>().ok()?;
+                let minute = next_number(&mut tokens)?;
+                let mut second = 0;
+                let mut millisecond = 0;
+                if tokens.next_if_eq(&LegacyToken::Punctuator(':')).is_some() {
+                    second = next_number(&mut tokens)?;
+                    if tokens.next_if_eq(&LegacyToken::Punctuator('.')).is_some() {
+                        // Only the first three digits of the fraction are significant.
+                        let Some(LegacyToken::Number(fraction)) = tokens.next() else {
+                            return None;
+                        };
+                        let fraction = format!("{fraction:0<3}");
+                        millisecond = fraction[..3].parse().ok()?;
+                    }
+                }
+                if minute > 59 || second > 59 {
+                    return None;
+                }
+                time = Some([hour, minute, second, millisecond]);
+            }
+            LegacyToken::Number(digits) => {
+                if numbers.len() == 3 {
+                    return None;
+                }
+                numbers.push((digits.parse().ok()?, digits.len()));
+            }
+            LegacyToken::Punctuator(sign @ ('+' | '-')) if time.is_some() || after_utc => {
+                // Offsets can only follow a time or a UTC time zone.
+                if offset.is_some() && !after_utc {
+                    return None;
+                }
+                let Some(LegacyToken::Number(digits)) = tokens.next() else {
+                    return None;
+                };
+                let (hours, minutes) = match digits.len() {
+                    1 | 2 if tokens.next_if_eq(&LegacyToken::Punctuator(':')).is_some() => (
+                        digits.parse::<i64>().ok()?,
+                        i64::from(next_number(&mut tokens)?),
+                    ),
+                    1 | 2 => (digits.parse::<i64>().ok()?, 0),
+                    4 => (digits[..2].parse().ok()?, digits[2..].parse().ok()?),
+                    _ => return None,
+                };
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+                let minutes = hours * 60 + minutes;
+                offset = Some(if sign == '-' { -minutes } else { minutes });
+            }
+            // A negative year, as in the `toString` format of years before 1 BC.
+            LegacyToken::Punctuator('-') => {
+                let Some(LegacyToken::Number(digits)) = tokens.next() else {
+                    return None;
+                };
+                if numbers.len() == 3 {
+                    return None;
+                }
+                numbers.push((-digits.parse::<i64>().ok()?, digits.len()));
+            }
+            LegacyToken::Punctuator('/') if !numbers.is_empty() && month.is_none() => {
+                slashes = true;
+            }
+            LegacyToken::Punctuator(_) => return None,
+            LegacyToken::Word(_) if is_utc => {
+                if offset.is_some() {
+                    return None;
+                }
+                offset = Some(0);
+            }
+            LegacyToken::Word(word) => {
+                if let Some(index) = MONTHS
+                    .iter()
+                    .position(|name| is_abbreviation_of(word, name))
+                {
+                    if month.is_some() {
+                        return None;
+                    }
+                    month = Some(index as i64);
+                } else if WEEKDAYS.iter().any(|name| is_abbreviation_of(word, name)) {
+                    // Weekdays are ignored, even if they don't match the date.
+                } else if word.eq_ignore_ascii_case("am") || word.eq_ignore_ascii_case("pm") {
+                    if time.is_none() || pm.is_some() {
+                        return None;
+                    }
+                    pm = Some(word.eq_ignore_ascii_case("pm"));
+                } else if let Some(&(_, zone_offset)) = ZONES
+                    .iter()
+                    .find(|(name, _)| word.eq_ignore_ascii_case(name))
+                {
+                    if offset.is_some() {
+                        return None;
+                    }
+                    offset = Some(zone_offset);
+                } else if !numbers.is_empty() || time.is_some() {
+                    return None;
+                }
+            }
+        }
+
+        after_utc = is_utc;
+    }
+
+    let (year, month, day) = match (month, numbers.as_slice()) {
+        (Some(month), &[(first, digits), (second, _)]) => {
+            if digits > 2 || first < 0 {
+                (first, month, second)
+            } else {
+                (second, month, first)
+            }
+        }
+        (None, &[(first, digits), (second, _), (third, _)]) if slashes => {
+            if digits > 2 {
+                (first, second - 1, third)
+            } else {
+                (third, first - 1, second)
+            }
+        }
+        _ => return None,
+    };
+    if !(0..12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let year = match year {
+        0..=49 => year + 2000,
+        50..=99 => year + 1900,
+        _ => year,
+    };
+
+    let [mut hour, minute, second, millisecond] = time.unwrap_or_default();
+    match pm {
+        Some(pm) if (1..=12).contains(&hour) => hour = hour % 12 + if pm { 12 } else { 0 },
+        Some(_) => return None,
+        None if hour > 23 => return None,
+        None => {}
+    }
+
+    let date = make_date(
+        make_day(year as f64, month as f64, day as f64),
+        make_time(
+            hour.into(),
+            minute.into(),
+            second.into(),
+            millisecond.into(),
+        ),
+    );
+    let date = match offset {
+        Some(offset) => date - offset as f64 * MS_PER_MINUTE,
+        None => utc_t(date, hooks),
+    };
+
+    let t = time_clip(date);
+    if t.is_finite() {
+        Some(t as i64)
+    } else {
+        None
+    }
 }
 
 /// Parses a date string according to the [`Date Time String Format`][spec].
@@ -0,0 +1,674 @@
+//! Boa's implementation of the `DisposableStack` builtin object, and of the resource disposal
+//! operations shared with `using` declarations and `AsyncDisposableStack`.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        builtins::JsPromise, internal_methods::get_prototype_from_constructor,
+        FunctionObjectBuilder, JsObject,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::common::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsError, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The hint of a disposable resource, which selects the method used to dispose it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub(crate) enum DisposeHint {
+    /// `sync-dispose`, for `using` declarations and `DisposableStack`s.
+    Sync,
+
+    /// `async-dispose`, for `await using` declarations and `AsyncDisposableStack`s.
+    Async,
+}
+
+/// A `DisposableResource` Record.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct DisposableResource {
+    /// The `[[ResourceValue]]` field, `undefined` if the resource was `null` or `undefined`.
+    value: JsValue,
+
+    /// The `[[Hint]]` field.
+    hint: DisposeHint,
+
+    /// The `[[DisposeMethod]]` field.
+    method: Option<JsObject>,
+}
+
+impl DisposableResource {
+    /// Abstract operation `CreateDisposableResource ( V, hint )`, when `method` is not present.
+    ///
+    /// Returns `None` if `value` is `null` or `undefined` and `hint` is
+    /// [`DisposeHint::Sync`], since there is nothing to dispose.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    pub(crate) fn from_value(
+        value: &JsValue,
+        hint: DisposeHint,
+        context: &mut Context,
+    ) -> JsResult<Option<Self>> {
+        // AddDisposableResource ( disposeCapability, V, hint [ , method ] )
+        // 1. If method is not present, then
+        //     a. If V is either null or undefined and hint is sync-dispose, return unused.
+        //     b. NOTE: When V is either null or undefined and hint is async-dispose, we record that
+        //        the resource was evaluated to ensure we will still perform an Await when resources
+        //        are later disposed.
+        // 1. If method is not present, then
+        //     a. If V is either null or undefined, then
+        if value.is_null_or_undefined() {
+            if hint == DisposeHint::Sync {
+                return Ok(None);
+            }
+
+            // i. Set V to undefined.
+            // ii. Set method to undefined.
+            return Ok(Some(Self {
+                value: JsValue::undefined(),
+                hint,
+                method: None,
+            }));
+        }
+
+        // b. Else,
+        //     i. If V is not an Object, throw a TypeError exception.
+        if !value.is_object() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "value with type `{}` cannot be disposed",
+                    value.type_of()
+                ))
+                .into());
+        }
+
+        //     ii. Set method to ? GetDisposeMethod(V, hint).
+        //     iii. If method is undefined, throw a TypeError exception.
+        let method = get_dispose_method(value, hint, context)?.ok_or_else(|| {
+            JsNativeError::typ().with_message(match hint {
+                DisposeHint::Sync => "value is not disposable, it has no `Symbol.dispose` method",
+                DisposeHint::Async => {
+                    "value is not disposable, it has no `Symbol.asyncDispose` or `Symbol.dispose` method"
+                }
+            })
+        })?;
+
+        // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+        Ok(Some(Self {
+            value: value.clone(),
+            hint,
+            method: Some(method),
+        }))
+    }
+
+    /// Abstract operation `CreateDisposableResource ( undefined, hint, method )`.
+    pub(crate) const fn from_method(method: JsObject, hint: DisposeHint) -> Self {
+        Self {
+            value: JsValue::undefined(),
+            hint,
+            method: Some(method),
+        }
+    }
+
+    /// Gets the `[[Hint]]` field of the resource.
+    pub(crate) const fn hint(&self) -> DisposeHint {
+        self.hint
+    }
+
+    /// Abstract operation `Dispose ( V, hint, method )`.
+    ///
+    /// The result is returned without awaiting it, so resources with the
+    /// [`DisposeHint::Async`] hint must be awaited by the caller.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-dispose
+    pub(crate) fn dispose(&self, context: &mut Context) -> JsResult<JsValue> {
+        // 1. If method is undefined, let result be undefined.
+        let Some(method) = &self.method else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 2. Else, let result be ? Call(method, V).
+        method.call(&self.value, &[], context)
+    }
+}
+
+/// Abstract operation `GetDisposeMethod ( V, hint )`
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+fn get_dispose_method(
+    value: &JsValue,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    // 2. Else,
+    //     a. Let method be ? GetMethod(V, @@dispose).
+    if hint == DisposeHint::Sync {
+        return value.get_method(JsSymbol::dispose(), context);
+    }
+
+    // 1. If hint is async-dispose, then
+    //     a. Let method be ? GetMethod(V, @@asyncDispose).
+    if let Some(method) = value.get_method(JsSymbol::async_dispose(), context)? {
+        return Ok(Some(method));
+    }
+
+    // b. If method is undefined, then
+    //     i. Set method to ? GetMethod(V, @@dispose).
+    let Some(method) = value.get_method(JsSymbol::dispose(), context)? else {
+        return Ok(None);
+    };
+
+    // ii. If method is not undefined, then
+    //     1. Let closure be a new Abstract Closure with no parameters that captures method and
+    //        performs the following steps when called:
+    //     2. NOTE: This function is not observable to user code. It is used to ensure that a
+    //        Promise returned from a synchronous @@dispose method will not be awaited and that
+    //        any exception thrown will not be thrown synchronously.
+    //     3. Return CreateBuiltinFunction(closure, 0, "", « »).
+    let closure = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |this, _, method, context| {
+                // a. Let O be the this value.
+                // b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+                // c. Let result be Completion(Call(method, O)).
+                // d. IfAbruptRejectPromise(result, promiseCapability).
+                // e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                // f. Return promiseCapability.[[Promise]].
+                let promise = match method.call(this, &[], context) {
+                    Ok(_) => JsPromise::resolve(JsValue::undefined(), context),
+                    Err(error) => JsPromise::reject(error, context),
+                };
+                Ok(promise.into())
+            },
+            method,
+        ),
+    )
+    .name("")
+    .length(0)
+    .build();
+
+    Ok(Some(closure.into()))
+}
+
+/// Combines the `error` thrown while disposing a resource with the `suppressed` error that was
+/// already being thrown, if any, into a `SuppressedError`.
+///
+/// This implements the steps of `DisposeResources` that handle a throw completion of `Dispose`.
+pub(crate) fn suppress_error(error: JsError, suppressed: Option<JsError>) -> JsError {
+    match suppressed {
+        // i. If completion.[[Type]] is throw, then
+        //     1. Set result to result.[[Value]].
+        //     2. Let suppressed be completion.[[Value]].
+        //     3. Let error be a newly created SuppressedError object.
+        //     4. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+        //     5. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+        //     6. Set completion to ThrowCompletion(error).
+        //
+        // NOTE: Errors that cannot be caught by user code are never wrapped.
+        Some(suppressed) if !suppressed.is_catchable() => suppressed,
+        Some(suppressed) if error.is_catchable() => {
+            JsNativeError::suppressed(error, suppressed).into()
+        }
+        // ii. Else,
+        //     1. Set completion to result.
+        _ => error,
+    }
+}
+
+/// A `DisposeCapability` Record, which holds the resources that must be disposed when a scope
+/// or a disposable stack is disposed.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub(crate) struct DisposeCapability {
+    /// The `[[DisposableResourceStack]]` field.
+    resources: Vec<DisposableResource>,
+}
+
+impl DisposeCapability {
+    /// Appends `resource` to the `[[DisposableResourceStack]]`, as the last step of
+    /// `AddDisposableResource`.
+    pub(crate) fn push(&mut self, resource: DisposableResource) {
+        self.resources.push(resource);
+    }
+
+    /// Removes the most recently added resource, which is the next one to dispose.
+    pub(crate) fn pop(&mut self) -> Option<DisposableResource> {
+        self.resources.pop()
+    }
+
+    /// Abstract operation `DisposeResources ( disposeCapability, completion )`, for capabilities
+    /// that only contain resources with the [`DisposeHint::Sync`] hint.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_resources(
+        mut self,
+        completion: JsResult<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let (value, mut error) = match completion {
+            Ok(value) => (value, None),
+            Err(error) => (JsValue::undefined(), Some(error)),
+        };
+
+        // 1. For each element resource of disposeCapability.[[DisposableResourceStack]], in
+        //    reverse list order, do
+        while let Some(resource) = self.pop() {
+            debug_assert_eq!(resource.hint(), DisposeHint::Sync);
+
+            // a. Let result be Dispose(resource.[[ResourceValue]], resource.[[Hint]], resource.[[DisposeMethod]]).
+            // b. If result.[[Type]] is throw, then
+            if let Err(err) = resource.dispose(context) {
+                error = Some(suppress_error(err, error));
+            }
+        }
+
+        // 2. NOTE: After disposeCapability has been disposed, it will never be used again. The
+        //    contents of disposeCapability.[[DisposableResourceStack]] can be discarded in
+        //    implementations, such as by garbage collection, at this point.
+        // 3. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        // 4. Return ? completion.
+        error.map_or(Ok(value), Err)
+    }
+}
+
+/// The `DisposableStack` builtin object.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DisposableStack {
+    /// The `[[DisposeCapability]]` internal slot, or `None` if the `[[DisposableState]]` of the
+    /// stack is `disposed`.
+    capability: Option<DisposeCapability>,
+}
+
+impl IntrinsicObject for DisposableStack {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose = BuiltInBuilder::callable(realm, Self::dispose)
+            .name(js_string!("dispose"))
+            .length(0)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("dispose"),
+                dispose.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::dispose(),
+                dispose,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisposableStack {
+    const NAME: JsString = StaticJsStrings::DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for DisposableStack {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::disposable_stack;
+
+    /// `DisposableStack ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return disposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                capability: Some(DisposeCapability::default()),
+            },
+        )
+        .into())
+    }
+}
+
+impl DisposableStack {
+    /// Gets the `this` object of the `method` of `DisposableStack.prototype`, as
+    /// `RequireInternalSlot(disposableStack, [[DisposableState]])`.
+    fn this_object(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "DisposableStack.prototype.{method}: `this` is not a DisposableStack"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Throws a `ReferenceError` if the `[[DisposableState]]` of `stack` is `disposed`.
+    fn require_pending(stack: &JsObject, method: &str) -> JsResult<()> {
+        let disposed = stack
+            .downcast_ref::<Self>()
+            .expect("must be a DisposableStack")
+            .capability
+            .is_none();
+        if disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "DisposableStack.prototype.{method}: the stack is already disposed"
+                ))
+                .into());
+        }
+        Ok(())
+    }
+
+    /// Appends `resource` to the `[[DisposeCapability]]` of `stack`.
+    fn push(stack: &JsObject, resource: DisposableResource) {
+        // NOTE: The stack could have been disposed while getting the dispose method of the
+        //       resource, in which case the resource is added to a capability that will never
+        //       be disposed again.
+        if let Some(capability) = &mut stack
+            .downcast_mut::<Self>()
+            .expect("must be a DisposableStack")
+            .capability
+        {
+            capability.push(resource);
+        }
+    }
+
+    /// `get DisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_object(this, "disposed")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        let stack = stack
+            .downcast_ref::<Self>()
+            .expect("must be a DisposableStack");
+        Ok(stack.capability.is_none().into())
+    }
+
+    /// `DisposableStack.prototype.dispose ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    fn dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_object(this, "dispose")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+        // 4. Set disposableStack.[[DisposableState]] to disposed.
+        let capability = stack
+            .downcast_mut::<Self>()
+            .expect("must be a DisposableStack")
+            .capability
+            .take();
+        let Some(capability) = capability else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 5. Return DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        capability.dispose_resources(Ok(JsValue::undefined()), context)
+    }
+
+    /// `DisposableStack.prototype.use ( value )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_object(this, "use")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let value = args.get_or_undefined(0);
+        if let Some(resource) = DisposableResource::from_value(value, DisposeHint::Sync, context)? {
+            Self::push(&stack, resource);
+        }
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// `DisposableStack.prototype.adopt ( value, onDispose )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_object(this, "adopt")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "adopt")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let on_dispose = callable_on_dispose(args.get_or_undefined(1), "DisposableStack", "adopt")?;
+
+        // 5-6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let closure = adopt_closure(value.clone(), on_dispose, context);
+
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        Self::push(
+            &stack,
+            DisposableResource::from_method(closure, DisposeHint::Sync),
+        );
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// `DisposableStack.prototype.defer ( onDispose )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_object(this, "defer")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = callable_on_dispose(args.get_or_undefined(0), "DisposableStack", "defer")?;
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        Self::push(
+            &stack,
+            DisposableResource::from_method(on_dispose, DisposeHint::Sync),
+        );
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `DisposableStack.prototype.move ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_object(this, "move")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_pending(&stack, "move")?;
+
+        // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set disposableStack.[[DisposableState]] to disposed.
+        let capability = stack
+            .downcast_mut::<Self>()
+            .expect("must be a DisposableStack")
+            .capability
+            .take();
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        // 9. Return newDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .disposable_stack()
+                .prototype(),
+            Self { capability },
+        )
+        .into())
+    }
+}
+
+/// Checks that the `onDispose` argument of `stack.method` is callable.
+pub(crate) fn callable_on_dispose(
+    on_dispose: &JsValue,
+    stack: &str,
+    method: &str,
+) -> JsResult<JsObject> {
+    on_dispose.as_callable().cloned().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message(format!(
+                "{stack}.prototype.{method}: `onDispose` is not callable"
+            ))
+            .into()
+    })
+}
+
+/// Creates the function that disposes a `value` adopted by a disposable stack, by calling
+/// `onDispose` with it.
+pub(crate) fn adopt_closure(
+    value: JsValue,
+    on_dispose: JsObject,
+    context: &mut Context,
+) -> JsObject {
+    // 5. Let closure be a new Abstract Closure with no parameters that captures value and
+    //    onDispose and performs the following steps when called:
+    //     a. Return ? Call(onDispose, undefined, « value »).
+    // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, _, (value, on_dispose), context| {
+                on_dispose.call(&JsValue::undefined(), std::slice::from_ref(value), context)
+            },
+            (value, on_dispose),
+        ),
+    )
+    .name("")
+    .length(0)
+    .build()
+    .into()
+}
@@ -0,0 +1,263 @@
+use crate::{js_string, run_test_actions, Context, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+const RESOURCES: &str = indoc! {r#"
+    var log = [];
+    function resource(name) {
+        return {
+            [Symbol.dispose]() {
+                log.push(name);
+            },
+        };
+    }
+"#};
+
+#[test]
+fn using_declarations() {
+    run_test_actions([
+        TestAction::run(RESOURCES),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                {
+                    using a = resource("a"), b = resource("b");
+                    using c = null;
+                    log.push("body");
+                }
+                log.join()
+            "#},
+            js_string!("body,b,a"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                function f() {
+                    using a = resource("a");
+                    return log.concat("return").join();
+                }
+                f() + ";" + log.join()
+            "#},
+            js_string!("return;a"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                for (let i = 0; i < 3; i++) {
+                    using a = resource(i);
+                    if (i === 1) continue;
+                    if (i === 2) break;
+                    log.push("end");
+                }
+                log.join()
+            "#},
+            js_string!("end,0,1,2"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                switch (0) {
+                    case 0:
+                        using a = resource("a");
+                    case 1:
+                        log.push("fallthrough");
+                }
+                log.join()
+            "#},
+            js_string!("fallthrough,a"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                function* g() {
+                    using a = resource("a");
+                    yield 1;
+                    yield 2;
+                }
+                var it = g();
+                it.next();
+                it.return(3).value + ";" + log.join()
+            "#},
+            js_string!("3;a"),
+        ),
+        TestAction::assert_native_error(
+            "{ using a = 1; }",
+            JsNativeErrorKind::Type,
+            "value with type `number` cannot be disposed",
+        ),
+        TestAction::assert_native_error(
+            "{ using a = {}; }",
+            JsNativeErrorKind::Type,
+            "value is not disposable, it has no `Symbol.dispose` method",
+        ),
+        TestAction::assert_native_error(
+            "{ using a = resource('a'); a = resource('b'); }",
+            JsNativeErrorKind::Type,
+            "cannot mutate an immutable binding 'a'",
+        ),
+    ]);
+}
+
+#[test]
+fn suppressed_errors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function throwing(message) {
+                return {
+                    [Symbol.dispose]() {
+                        throw new Error(message);
+                    },
+                };
+            }
+            var error;
+            try {
+                using a = throwing("a"), b = throwing("b");
+                throw new Error("body");
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error.message", js_string!("a")),
+        TestAction::assert("error.suppressed instanceof SuppressedError"),
+        TestAction::assert_eq("error.suppressed.error.message", js_string!("b")),
+        TestAction::assert_eq("error.suppressed.suppressed.message", js_string!("body")),
+        TestAction::assert("!Object.keys(error).includes('error')"),
+        TestAction::assert_eq(
+            indoc! {r#"
+                try {
+                    using a = { [Symbol.dispose]() { throw 1; } };
+                } catch (e) {
+                    error = e;
+                }
+                error
+            "#},
+            1,
+        ),
+        TestAction::assert_eq("new SuppressedError(1, 2, 'message').suppressed", 2),
+    ]);
+}
+
+#[test]
+fn disposable_stack() {
+    run_test_actions([
+        TestAction::run(RESOURCES),
+        TestAction::run(indoc! {r#"
+            var stack = new DisposableStack();
+            stack.use(resource("use"));
+            stack.use(undefined);
+            stack.defer(() => log.push("defer"));
+            var adopted = stack.adopt(1, value => log.push("adopt " + value));
+            log = [];
+            stack.dispose();
+        "#}),
+        TestAction::assert_eq("log.join()", js_string!("adopt 1,defer,use")),
+        TestAction::assert_eq("adopted", 1),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert("stack.dispose() === undefined"),
+        TestAction::assert(
+            "DisposableStack.prototype[Symbol.dispose] === DisposableStack.prototype.dispose",
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(stack)",
+            js_string!("[object DisposableStack]"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                var moved;
+                {
+                    using stack = new DisposableStack();
+                    stack.use(resource("a"));
+                    moved = stack.move();
+                }
+                log.push(moved.disposed);
+                moved.dispose();
+                log.join()
+            "#},
+            js_string!("false,a"),
+        ),
+        TestAction::assert_native_error(
+            "stack.use(resource('a'))",
+            JsNativeErrorKind::Reference,
+            "DisposableStack.prototype.use: the stack is already disposed",
+        ),
+        TestAction::assert_native_error(
+            "new DisposableStack().defer(1)",
+            JsNativeErrorKind::Type,
+            "DisposableStack.prototype.defer: `onDispose` is not callable",
+        ),
+        TestAction::assert_native_error(
+            "DisposableStack()",
+            JsNativeErrorKind::Type,
+            "DisposableStack: cannot call constructor without `new`",
+        ),
+        TestAction::assert_native_error(
+            "DisposableStack.prototype.dispose.call({})",
+            JsNativeErrorKind::Type,
+            "DisposableStack.prototype.dispose: `this` is not a DisposableStack",
+        ),
+    ]);
+}
+
+#[test]
+fn await_using_declarations() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            function resource(name) {
+                return {
+                    async [Symbol.asyncDispose]() {
+                        await null;
+                        log.push(name);
+                    },
+                };
+            }
+            async function f() {
+                await using a = resource("a");
+                await using b = { [Symbol.dispose]() { log.push("b"); } };
+                await using c = null;
+                log.push("body");
+                return "done";
+            }
+            async function rejects() {
+                await using a = { [Symbol.asyncDispose]: () => Promise.reject("rejected") };
+            }
+            var result, rejection;
+            f().then(value => { result = value; });
+            rejects().catch(reason => { rejection = reason; });
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("result", js_string!("done")),
+        TestAction::assert_eq("log.join()", js_string!("body,b,a")),
+        TestAction::assert_eq("rejection", js_string!("rejected")),
+    ]);
+}
+
+#[test]
+fn async_disposable_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new AsyncDisposableStack();
+            stack.use({ [Symbol.dispose]() { log.push("sync"); } });
+            stack.defer(async () => { await null; log.push("defer"); });
+            stack.adopt(1, async value => { log.push("adopt " + value); });
+            var promise = stack.disposeAsync();
+            var rejection;
+            AsyncDisposableStack.prototype.disposeAsync.call({}).catch(e => { rejection = e; });
+        "#}),
+        TestAction::assert("promise instanceof Promise"),
+        TestAction::assert("stack.disposed"),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("log.join()", js_string!("adopt 1,defer,sync")),
+        TestAction::assert("rejection instanceof TypeError"),
+        TestAction::assert(
+            "AsyncDisposableStack.prototype[Symbol.asyncDispose] === AsyncDisposableStack.prototype.disposeAsync",
+        ),
+        TestAction::assert_native_error(
+            "stack.defer(() => {})",
+            JsNativeErrorKind::Reference,
+            "AsyncDisposableStack.prototype.defer: the stack is already disposed",
+        ),
+    ]);
+}
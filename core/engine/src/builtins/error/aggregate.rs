@@ -101,6 +101,9 @@ impl BuiltInConstructor for AggregateError {
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(2), context)?;
 
+        // Non-standard: capture the current stack trace.
+        Error::capture_error_stack(&o, &Self::NAME, context);
+
         // 5. Let errorsList be ? IterableToList(errors).
         let errors = args.get_or_undefined(0);
         let errors_list = iterable_to_list(context, errors, None)?;
@@ -13,21 +13,24 @@
 use crate::{
     builtins::BuiltInObject,
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
-    error::JsNativeError,
+    error::{CapturedStack, JsNativeError},
     js_string,
-    object::{internal_methods::get_prototype_from_constructor, JsObject},
-    property::Attribute,
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::{Attribute, PropertyDescriptor},
     realm::Realm,
     string::{common::StaticJsStrings, utf16},
     Context, JsArgs, JsData, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
+use std::{cell::OnceCell, rc::Rc};
 
 pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
+pub(crate) mod suppressed;
 pub(crate) mod syntax;
 pub(crate) mod r#type;
 pub(crate) mod uri;
@@ -40,6 +43,7 @@ pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
 pub(crate) use self::reference::ReferenceError;
+pub(crate) use self::suppressed::SuppressedError;
 pub(crate) use self::syntax::SyntaxError;
 pub(crate) use self::uri::UriError;
 
@@ -109,6 +113,14 @@ pub enum ErrorObject {
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-referenceerror
     Reference,
 
+    /// The `SuppressedError` object type.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed,
+
     /// The `SyntaxError` type.
     ///
     /// More information:
@@ -139,6 +151,12 @@ impl IntrinsicObject for Error {
             .property(utf16!("name"), Self::NAME, attribute)
             .property(utf16!("message"), js_string!(), attribute)
             .method(Self::to_string, js_string!("toString"), 0)
+            .static_method(
+                Self::capture_stack_trace,
+                js_string!("captureStackTrace"),
+                2,
+            )
+            .static_property(js_string!("stackTraceLimit"), 10, Attribute::all())
             .build();
     }
 
@@ -197,12 +215,167 @@ impl BuiltInConstructor for Error {
         // 4. Perform ? InstallErrorCause(O, options).
         Self::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        // Non-standard: capture the current stack trace.
+        Self::capture_error_stack(&o, &Self::NAME, context);
+
         // 5. Return O.
         Ok(o.into())
     }
 }
 
 impl Error {
+    /// Installs a non-standard `stack` property on the error object `o`, containing the current
+    /// stack trace of `context`.
+    pub(crate) fn capture_error_stack(o: &JsObject, name: &JsString, context: &mut Context) {
+        let stack = CapturedStack::capture(context);
+        Self::install_error_stack(o, &name.to_std_string_escaped(), stack, context);
+    }
+
+    /// Installs a non-standard `stack` property on the error object `o`, with the format used
+    /// by V8:
+    ///
+    /// ```text
+    /// TypeError: message
+    ///     at inner (script.js:2:5)
+    ///     at <main> (script.js:5:1)
+    /// ```
+    pub(crate) fn install_error_stack(
+        o: &JsObject,
+        name: &str,
+        stack: Rc<CapturedStack>,
+        context: &mut Context,
+    ) {
+        let message = o
+            .borrow()
+            .properties()
+            .get(&utf16!("message").into())
+            .and_then(|desc| desc.value().and_then(JsValue::as_string).cloned())
+            .unwrap_or_default();
+
+        let mut header = name.to_owned();
+        if !message.is_empty() {
+            header.push_str(": ");
+            header.push_str(&message.to_std_string_escaped());
+        }
+
+        o.define_property_or_throw(
+            utf16!("stack"),
+            Self::stack_property(header, stack, context),
+            context,
+        )
+        .expect("the error object must be an ordinary, extensible object");
+    }
+
+    /// Creates the descriptor of a `stack` property, starting with the `header` line.
+    ///
+    /// The trace is only formatted the first time the property is read, since the stack of most
+    /// errors is never inspected. Assigning to the property replaces it with a data property.
+    fn stack_property(
+        header: String,
+        stack: Rc<CapturedStack>,
+        context: &mut Context,
+    ) -> PropertyDescriptor {
+        /// The captures of the `stack` getter.
+        #[derive(Trace, Finalize)]
+        // SAFETY: `CapturedStack` roots the code blocks of its frames, so they don't need to be
+        // traced.
+        #[boa_gc(unsafe_empty_trace)]
+        struct LazyStack {
+            header: String,
+            stack: Rc<CapturedStack>,
+            formatted: OnceCell<JsString>,
+        }
+
+        let get = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, lazy: &LazyStack, _| {
+                    let formatted = lazy.formatted.get_or_init(|| {
+                        let mut trace = lazy.header.clone();
+                        for frame in lazy.stack.frames() {
+                            trace.push_str("\n    at ");
+                            trace.push_str(&frame.to_string());
+                        }
+                        js_string!(trace)
+                    });
+                    Ok(formatted.clone().into())
+                },
+                LazyStack {
+                    header,
+                    stack,
+                    formatted: OnceCell::new(),
+                },
+            ),
+        )
+        .name(js_string!("get stack"))
+        .build();
+
+        let set = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_fn_ptr(|this, args, context| {
+                if let Some(this) = this.as_object() {
+                    this.define_property_or_throw(
+                        utf16!("stack"),
+                        PropertyDescriptor::builder()
+                            .value(args.get_or_undefined(0).clone())
+                            .writable(true)
+                            .enumerable(false)
+                            .configurable(true),
+                        context,
+                    )?;
+                }
+                Ok(JsValue::undefined())
+            }),
+        )
+        .name(js_string!("set stack"))
+        .length(1)
+        .build();
+
+        PropertyDescriptor::builder()
+            .get(get)
+            .set(set)
+            .enumerable(false)
+            .configurable(true)
+            .build()
+    }
+
+    /// `Error.captureStackTrace ( targetObject [ , constructorOpt ] )`
+    ///
+    /// Non-standard V8 extension that installs a `stack` property on `targetObject`, containing
+    /// the current stack trace. If `constructorOpt` is given, the frames above the innermost
+    /// call to it, including that call, are omitted from the trace.
+    ///
+    /// More information:
+    ///  - [V8 documentation][v8]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [v8]: https://v8.dev/docs/stack-trace-api#stack-trace-collection-for-custom-exceptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/captureStackTrace
+    fn capture_stack_trace(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let Some(target) = args.get_or_undefined(0).as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("Error.captureStackTrace: `targetObject` is not an object")
+                .into());
+        };
+
+        let stack = CapturedStack::capture_below(args.get_or_undefined(1).as_object(), context);
+
+        // The first line is the same as the result of calling `Error.prototype.toString` on
+        // the target object.
+        let header = Self::to_string(&target.clone().into(), &[], context)?
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        let stack = Self::stack_property(header, stack, context);
+        target.define_property_or_throw(utf16!("stack"), stack, context)?;
+
+        Ok(JsValue::undefined())
+    }
+
     pub(crate) fn install_error_cause(
         o: &JsObject,
         options: &JsValue,
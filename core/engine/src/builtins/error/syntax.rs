@@ -103,6 +103,9 @@ impl BuiltInConstructor for SyntaxError {
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        // Non-standard: capture the current stack trace.
+        Error::capture_error_stack(&o, &Self::NAME, context);
+
         // 5. Return O.
         Ok(o.into())
     }
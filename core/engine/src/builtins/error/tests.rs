@@ -1,4 +1,6 @@
-use crate::{js_string, run_test_actions, TestAction};
+use std::path::Path;
+
+use crate::{js_string, run_test_actions, Context, JsNativeErrorKind, Source, TestAction};
 use indoc::indoc;
 
 #[test]
@@ -76,3 +78,143 @@ fn error_lengths() {
         TestAction::assert_eq("AggregateError.length", 2),
     ]);
 }
+
+#[test]
+fn error_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function inner() { return new RangeError("boom"); }
+            function outer() { return inner(); }
+        "#}),
+        TestAction::assert_eq(
+            "outer().stack",
+            js_string!("RangeError: boom\n    at inner\n    at outer\n    at <main>"),
+        ),
+        TestAction::assert_eq(
+            "try { null.x } catch (e) { e.stack }",
+            js_string!("TypeError: cannot convert 'null' or 'undefined' to object\n    at <main>"),
+        ),
+        TestAction::assert_eq(
+            "(() => new Error())().stack",
+            js_string!("Error\n    at <anonymous>\n    at <main>"),
+        ),
+        TestAction::assert("!Object.keys(new Error('x')).includes('stack')"),
+        TestAction::assert_eq("Error.stackTraceLimit", 10),
+        TestAction::run("Error.stackTraceLimit = 1"),
+        TestAction::assert_eq(
+            "outer().stack",
+            js_string!("RangeError: boom\n    at inner"),
+        ),
+        TestAction::run("Error.stackTraceLimit = undefined"),
+        TestAction::assert_eq("outer().stack", js_string!("RangeError: boom")),
+    ]);
+}
+
+#[test]
+fn error_stack_assignment() {
+    run_test_actions([
+        TestAction::run("var e = new Error('x');"),
+        TestAction::assert("e.stack === e.stack"),
+        TestAction::run("e.stack = 'custom'"),
+        TestAction::assert_eq("e.stack", js_string!("custom")),
+        TestAction::assert("Object.getOwnPropertyDescriptor(e, 'stack').writable"),
+        TestAction::assert("!Object.keys(e).includes('stack')"),
+    ]);
+}
+
+#[test]
+fn error_stack_frames() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let source = Source::from_reader(
+            indoc! {r#"
+                function thrower() { throw 5; }
+                function caller() { thrower(); }
+                caller();
+            "#}
+            .as_bytes(),
+            Some(Path::new("script.js")),
+        );
+        let error = context.eval(source).unwrap_err();
+
+        let frames = error.stack_frames().unwrap();
+        let names = frames
+            .iter()
+            .map(|frame| frame.function_name().to_std_string_escaped())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["thrower", "caller", "<main>"]);
+        assert!(frames
+            .iter()
+            .all(|frame| frame.source_path() == Some(Path::new("script.js"))));
+        assert_eq!(frames[0].to_string(), "thrower (script.js:1:22)");
+        assert_eq!(frames[1].to_string(), "caller (script.js:2:21)");
+        assert_eq!(frames[2].line(), Some(3));
+        assert_eq!(frames[2].column(), Some(1));
+
+        let error = context
+            .eval(Source::from_bytes("[1].map(() => null.x)"))
+            .unwrap_err();
+        let names = error
+            .stack_frames()
+            .unwrap()
+            .iter()
+            .map(|frame| frame.function_name().to_std_string_escaped())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["<anonymous>", "<main>"]);
+    })]);
+}
+
+#[test]
+fn capture_stack_trace() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function MyError(message) {
+                this.message = message;
+                Error.captureStackTrace(this, MyError);
+            }
+            MyError.prototype.name = 'MyError';
+            function make() { return new MyError('custom'); }
+            function capture() {
+                var o = {};
+                Error.captureStackTrace(o);
+                return o;
+            }
+        "#}),
+        TestAction::assert_eq(
+            "make().stack",
+            js_string!("MyError: custom\n    at make\n    at <main>"),
+        ),
+        TestAction::assert_eq(
+            "capture().stack",
+            js_string!("Error\n    at capture\n    at <main>"),
+        ),
+        TestAction::assert("!Object.keys(capture()).includes('stack')"),
+        TestAction::assert_eq(
+            "var o = {}; Error.captureStackTrace(o, function absent() {}); o.stack",
+            js_string!("Error"),
+        ),
+        TestAction::run("Error.stackTraceLimit = 1"),
+        TestAction::assert_eq("make().stack", js_string!("MyError: custom\n    at make")),
+        TestAction::assert_native_error(
+            "Error.captureStackTrace(1)",
+            JsNativeErrorKind::Type,
+            "Error.captureStackTrace: `targetObject` is not an object",
+        ),
+    ]);
+}
+
+#[test]
+fn error_stack_after_await() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var stack;
+            async function late() {
+                await null;
+                stack = new Error('late').stack;
+            }
+            late();
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("stack.split('\\n')[0]", js_string!("Error: late")),
+        TestAction::assert_eq("stack.split('\\n')[1]", js_string!("    at late")),
+    ]);
+}
@@ -17,6 +17,9 @@ use crate::{
 
 use super::{BuiltInBuilder, BuiltInObject, IntrinsicObject};
 
+#[cfg(test)]
+mod tests;
+
 /// The `escape` function
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Escape;
@@ -59,7 +62,7 @@ fn escape(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsVa
     // 5. Let k be 0.
     // 6. Repeat, while k < len,
     //     a. Let C be the code unit at index k within string.
-    for &cp in &*string {
+    for cp in &string {
         // b. If unescapedSet contains C, then
         if is_unescaped(cp) {
             // i. Let S be C.
@@ -124,7 +127,7 @@ fn unescape(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Js
     // 3. Let R be the empty String.
     let mut vec = Vec::with_capacity(string.len());
 
-    let mut codepoints = <PeekableN<_, 6>>::new(string.iter().copied());
+    let mut codepoints = <PeekableN<_, 6>>::new(string.iter());
 
     // 2. Let len be the length of string.
     // 4. Let k be 0.
@@ -0,0 +1,43 @@
+use crate::{js_string, run_test_actions, TestAction};
+
+#[test]
+fn escape() {
+    run_test_actions([
+        TestAction::assert_eq("escape('AZaz09@*_+-./')", js_string!("AZaz09@*_+-./")),
+        TestAction::assert_eq(
+            "escape(' !\"#$%&\\'(),:;<=>?[\\\\]^`{|}~')",
+            js_string!(
+                "%20%21%22%23%24%25%26%27%28%29%2C%3A%3B%3C%3D%3E%3F%5B%5C%5D%5E%60%7B%7C%7D%7E"
+            ),
+        ),
+        TestAction::assert_eq("escape('\\u00e9\\u00ff')", js_string!("%E9%FF")),
+        TestAction::assert_eq("escape('\\u0100\\u20ac')", js_string!("%u0100%u20AC")),
+        TestAction::assert_eq("escape('\\ud83d\\ude00')", js_string!("%uD83D%uDE00")),
+        TestAction::assert_eq("escape()", js_string!("undefined")),
+    ]);
+}
+
+#[test]
+fn unescape() {
+    run_test_actions([
+        TestAction::assert_eq("unescape('%E9%u20AC%uD83D%uDE00')", js_string!("é€😀")),
+        TestAction::assert_eq("unescape('%e9%u20ac')", js_string!("é€")),
+        TestAction::assert_eq("unescape('%')", js_string!("%")),
+        TestAction::assert_eq("unescape('%4')", js_string!("%4")),
+        TestAction::assert_eq("unescape('%zz%4G')", js_string!("%zz%4G")),
+        TestAction::assert_eq("unescape('%u12')", js_string!("%u12")),
+        TestAction::assert_eq("unescape('%u12G4')", js_string!("%u12G4")),
+        TestAction::assert_eq("unescape('%u0041%41')", js_string!("AA")),
+    ]);
+}
+
+#[test]
+fn escape_unescape_round_trip() {
+    run_test_actions([
+        TestAction::run(
+            "var text = 'caf\\u00e9 \\u00bd \\u20ac \\ud83d\\ude00 \\udc00 \\u0000 100% ok';",
+        ),
+        TestAction::assert_eq("unescape(escape(text)) === text", true),
+        TestAction::assert_eq("escape(unescape(escape(text))) === escape(text)", true),
+    ]);
+}
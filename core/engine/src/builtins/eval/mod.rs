@@ -26,6 +26,7 @@ use boa_ast::operations::{contains, contains_arguments, ContainsSymbol};
 use boa_gc::Gc;
 use boa_parser::{Parser, Source};
 use boa_profiler::Profiler;
+use std::rc::Rc;
 
 use super::{BuiltInBuilder, IntrinsicObject};
 
@@ -118,12 +119,15 @@ impl Eval {
         //     b. If script is a List of errors, throw a SyntaxError exception.
         //     c. If script Contains ScriptBody is false, return undefined.
         //     d. Let body be the ScriptBody of script.
-        let mut parser = Parser::new(Source::from_utf16(x));
+        let source = x.to_vec();
+        let mut parser = Parser::new(Source::from_utf16(&source));
         parser.set_identifier(context.next_parser_identifier());
+        parser.set_nesting_limit(context.runtime_limits().nesting_limit());
         if strict {
             parser.set_strict();
         }
         let body = parser.parse_eval(direct, context.interner_mut())?;
+        let source_text = Rc::new(parser.take_source_text());
 
         // 6. Let inFunction be false.
         // 7. Let inMethod be false.
@@ -235,8 +239,12 @@ impl Eval {
             context.vm.environments.current_compile_environment(),
             context,
         );
+        compiler.source_text = Some(source_text);
 
         let env_index = compiler.push_compile_environment(strict);
+        // The environments of the caller may be poisoned or inside a `with` statement, which
+        // isn't visible from the compile time environments.
+        compiler.lexical_environment.set_dynamic();
         compiler.emit_with_varying_operand(Opcode::PushDeclarativeEnvironment, env_index);
         let lex_env = compiler.lexical_environment.clone();
         if strict {
@@ -8,9 +8,8 @@ use crate::{
         JsObject,
     },
     property::{DescriptorKind, PropertyDescriptor, PropertyKey},
-    Context, JsData, JsResult, JsValue,
+    Context, JsData, JsResult, JsString, JsValue,
 };
-use boa_ast::{function::FormalParameterList, operations::bound_names};
 use boa_gc::{Finalize, Gc, Trace};
 use rustc_hash::FxHashMap;
 
@@ -143,7 +142,7 @@ impl MappedArguments {
     #[allow(clippy::new_ret_no_self)]
     pub(crate) fn new(
         func: &JsObject,
-        formals: &FormalParameterList,
+        formals: &[JsString],
         arguments_list: &[JsValue],
         env: &Gc<DeclarativeEnvironment>,
         context: &mut Context,
@@ -199,7 +198,7 @@ impl MappedArguments {
 
         let mut bindings = FxHashMap::default();
         let mut property_index = 0;
-        for name in bound_names(formals) {
+        for name in formals {
             if property_index >= len {
                 break;
             }
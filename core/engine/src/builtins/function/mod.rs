@@ -43,13 +43,11 @@ use boa_ast::{
 };
 use boa_gc::{self, custom_trace, Finalize, Gc, Trace};
 use boa_interner::Sym;
-use boa_parser::{Parser, Source};
+use boa_parser::{source::SourceText, Parser, Source};
 use boa_profiler::Profiler;
-use std::io::Read;
+use std::{io::Read, rc::Rc};
 use thin_vec::ThinVec;
 
-use super::Proxy;
-
 pub(crate) mod arguments;
 mod bound;
 
@@ -441,20 +439,26 @@ impl BuiltInFunctionObject {
         // 22. Let proto be ? GetPrototypeFromConstructor(newTarget, fallbackProto).
         let prototype = get_prototype_from_constructor(&new_target, default, context)?;
 
+        // The source text of the parameters and body, used to build the sourceString in step 16.
+        let mut parameters_text = Vec::new();
+        let mut body_text = Vec::new();
+
         let (parameters, body) = if let Some((body_arg, args)) = args.split_last() {
             let parameters = if args.is_empty() {
                 FormalParameterList::default()
             } else {
                 let mut parameters = Vec::with_capacity(args.len());
                 for arg in args {
-                    parameters.push(arg.to_string(context)?);
+                    parameters.push(arg.to_string(context)?.to_vec());
                 }
                 let parameters = parameters.join(utf16!(","));
+                parameters_text.clone_from(&parameters);
 
                 // TODO: make parser generic to u32 iterators
                 let parameters = String::from_utf16_lossy(&parameters);
                 let mut parser = Parser::new(Source::from_bytes(&parameters));
                 parser.set_identifier(context.next_parser_identifier());
+                parser.set_nesting_limit(context.runtime_limits().nesting_limit());
 
                 let parameters = match parser.parse_formal_parameters(
                     context.interner_mut(),
@@ -493,12 +497,15 @@ impl BuiltInFunctionObject {
             }
 
             // 11. Let bodyString be the string-concatenation of 0x000A (LINE FEED), ? ToString(bodyArg), and 0x000A (LINE FEED).
-            let body_arg = body_arg.to_string(context)?.to_std_string_escaped();
+            let body_arg = body_arg.to_string(context)?;
+            body_text = body_arg.to_vec();
+            let body_arg = body_arg.to_std_string_escaped();
             let body = b"\n".chain(body_arg.as_bytes()).chain(b"\n".as_slice());
 
             // TODO: make parser generic to u32 iterators
             let mut parser = Parser::new(Source::from_reader(body, None));
             parser.set_identifier(context.next_parser_identifier());
+            parser.set_nesting_limit(context.runtime_limits().nesting_limit());
 
             let body = match parser.parse_function_body(context.interner_mut(), generator, r#async)
             {
@@ -588,10 +595,33 @@ impl BuiltInFunctionObject {
             (FormalParameterList::default(), FunctionBody::default())
         };
 
+        let prefix = match (r#async, generator) {
+            (true, true) => utf16!("async function*"),
+            (true, false) => utf16!("async function"),
+            (false, true) => utf16!("function*"),
+            (false, false) => utf16!("function"),
+        };
+
+        // 16. Let sourceString be the string-concatenation of prefix, " anonymous(", P, 0x000A (LINE FEED), ") {", bodyString, and "}".
+        // 17. Let sourceText be StringToCodePoints(sourceString).
+        let source_text = SourceText::from_utf16(
+            &[
+                prefix,
+                utf16!(" anonymous("),
+                &parameters_text,
+                utf16!("\n) {\n"),
+                &body_text,
+                utf16!("\n}"),
+            ]
+            .concat(),
+        );
+        let span = source_text.span();
+
         let code = FunctionCompiler::new()
             .name(js_string!("anonymous"))
             .generator(generator)
             .r#async(r#async)
+            .source_text(Some(Rc::new(source_text)), Some(span))
             .compile(
                 &parameters,
                 &body,
@@ -768,24 +798,42 @@ impl BuiltInFunctionObject {
         // 1. Let func be the this value.
         let func = this;
 
-        // TODO:
-        //    2. If func is an Object, func has a [[SourceText]] internal slot, func.[[SourceText]] is a sequence of Unicode code points,and HostHasSourceTextAvailable(func) is true, then
-        //        a. Return CodePointsToString(func.[[SourceText]]).
-
-        // 3. If func is a built-in function object, return an implementation-defined String source code representation of func.
-        //    The representation must have the syntax of a NativeFunction. Additionally, if func has an [[InitialName]] internal slot and
-        //    func.[[InitialName]] is a String, the portion of the returned String that would be matched by
-        //    NativeFunctionAccessor_opt PropertyName must be the value of func.[[InitialName]].
-
-        // 4. If func is an Object and IsCallable(func) is true, return an implementation-defined String source code representation of func.
-        //    The representation must have the syntax of a NativeFunction.
         // 5. Throw a TypeError exception.
         let Some(object) = func.as_callable() else {
             return Err(JsNativeError::typ().with_message("not a function").into());
         };
 
-        let object_borrow = object.borrow();
-        if object_borrow.is::<NativeFunctionObject>() {
+        // 2. If func is an Object, func has a [[SourceText]] internal slot, func.[[SourceText]] is a sequence of Unicode code points,and HostHasSourceTextAvailable(func) is true, then
+        let code = object
+            .downcast_ref::<OrdinaryFunction>()
+            .map(|function| function.code.clone());
+        if let Some(code) = code {
+            if let Some(source_text) = code.source_text_slice() {
+                let function = JsFunction::from_object_unchecked(object.clone());
+                if context
+                    .host_hooks()
+                    .has_source_text_available(&function, context)
+                {
+                    // a. Return CodePointsToString(func.[[SourceText]]).
+                    return Ok(JsString::from(source_text).into());
+                }
+            }
+
+            // 4. If func is an Object and IsCallable(func) is true, return an implementation-defined String source code representation of func.
+            //    The representation must have the syntax of a NativeFunction.
+            return Ok(js_string!(
+                utf16!("function "),
+                code.name(),
+                utf16!("() { [native code] }")
+            )
+            .into());
+        }
+
+        // 3. If func is a built-in function object, return an implementation-defined String source code representation of func.
+        //    The representation must have the syntax of a NativeFunction. Additionally, if func has an [[InitialName]] internal slot and
+        //    func.[[InitialName]] is a String, the portion of the returned String that would be matched by
+        //    NativeFunctionAccessor_opt PropertyName must be the value of func.[[InitialName]].
+        if object.is::<NativeFunctionObject>() {
             let name = {
                 // Is there a case here where if there is no name field on a value
                 // name should default to None? Do all functions have names set?
@@ -799,22 +847,11 @@ impl BuiltInFunctionObject {
             return Ok(
                 js_string!(utf16!("function "), &name, utf16!("() { [native code] }")).into(),
             );
-        } else if object_borrow.is::<Proxy>() || object_borrow.is::<BoundFunction>() {
-            return Ok(js_string!(utf16!("function () { [native code] }")).into());
         }
 
-        let function = object_borrow
-            .downcast_ref::<OrdinaryFunction>()
-            .ok_or_else(|| JsNativeError::typ().with_message("not a function"))?;
-
-        let code = function.codeblock();
-
-        Ok(js_string!(
-            utf16!("function "),
-            code.name(),
-            utf16!("() { [native code] }")
-        )
-        .into())
+        // 4. If func is an Object and IsCallable(func) is true, return an implementation-defined String source code representation of func.
+        //    The representation must have the syntax of a NativeFunction.
+        Ok(js_string!(utf16!("function () { [native code] }")).into())
     }
 
     /// `Function.prototype [ @@hasInstance ] ( V )`
@@ -193,3 +193,100 @@ fn function_constructor_early_errors_super() {
         ),
     ]);
 }
+
+#[test]
+fn function_constructor_rejects_hashbang() {
+    run_test_actions([
+        TestAction::assert_eq("eval('#!\\n1')", 1),
+        TestAction::assert_native_error(
+            "Function('#!\\n')",
+            JsNativeErrorKind::Syntax,
+            "failed to parse function body: Abrupt end: Expecting private identifier at line 2, col 1",
+        ),
+    ]);
+}
+
+#[test]
+fn function_to_string_source_text() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function add(a, /* b */ b) {
+                // sum
+                return a + b;
+            }
+            var arrow = async (x) =>   x * 2;
+            var object = {
+                get  value() { return 1; },
+                *gen() {},
+                async method() {},
+            };
+            class Point extends Object {
+                static   create() {}
+                #secret() {}
+            }
+        "#}),
+        TestAction::assert_eq(
+            "add.toString()",
+            js_string!("function add(a, /* b */ b) {\n    // sum\n    return a + b;\n}"),
+        ),
+        TestAction::assert_eq("arrow.toString()", js_string!("async (x) =>   x * 2")),
+        TestAction::assert_eq(
+            "Object.getOwnPropertyDescriptor(object, 'value').get.toString()",
+            js_string!("get  value() { return 1; }"),
+        ),
+        TestAction::assert_eq("object.gen.toString()", js_string!("*gen() {}")),
+        TestAction::assert_eq("object.method.toString()", js_string!("async method() {}")),
+        TestAction::assert_eq("Point.create.toString()", js_string!("create() {}")),
+        TestAction::assert_eq(
+            "Point.toString()",
+            js_string!(
+                "class Point extends Object {\n    static   create() {}\n    #secret() {}\n}"
+            ),
+        ),
+        TestAction::assert_eq(
+            "(function () { return 'x'; }).toString()",
+            js_string!("function () { return 'x'; }"),
+        ),
+    ]);
+}
+
+#[test]
+fn function_to_string_dynamic_functions() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Function('a', 'b', 'return a').toString()",
+            js_string!("function anonymous(a,b\n) {\nreturn a\n}"),
+        ),
+        TestAction::assert_eq(
+            "new Function().toString()",
+            js_string!("function anonymous(\n) {\n\n}"),
+        ),
+        TestAction::assert_eq(
+            "eval('(function f() { /* eval */ })').toString()",
+            js_string!("function f() { /* eval */ }"),
+        ),
+    ]);
+}
+
+#[test]
+fn function_to_string_native_functions() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Math.max.toString()",
+            js_string!("function max() { [native code] }"),
+        ),
+        TestAction::assert_eq(
+            "(function f() {}).bind(null).toString()",
+            js_string!("function () { [native code] }"),
+        ),
+        TestAction::assert_eq(
+            "new Proxy(function f() {}, {}).toString()",
+            js_string!("function () { [native code] }"),
+        ),
+        TestAction::assert_native_error(
+            "Function.prototype.toString.call({})",
+            JsNativeErrorKind::Type,
+            "not a function",
+        ),
+    ]);
+}
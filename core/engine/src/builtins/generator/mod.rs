@@ -91,7 +91,8 @@ impl GeneratorContext {
         resume_kind: GeneratorResumeKind,
         context: &mut Context,
     ) -> CompletionRecord {
-        std::mem::swap(&mut context.vm.stack, &mut self.stack);
+        let stack = std::mem::replace(&mut context.vm.stack, std::mem::take(&mut self.stack));
+        context.vm.suspended_stacks.push(stack);
         let frame = self.call_frame.take().expect("should have a call frame");
         let rp = frame.rp;
         context.vm.push_frame(frame);
@@ -106,7 +107,12 @@ impl GeneratorContext {
 
         let result = context.run();
 
-        std::mem::swap(&mut context.vm.stack, &mut self.stack);
+        let stack = context
+            .vm
+            .suspended_stacks
+            .pop()
+            .expect("the stack should have been suspended when resuming");
+        self.stack = std::mem::replace(&mut context.vm.stack, stack);
         self.call_frame = context.vm.pop_frame();
         assert!(self.call_frame.is_some());
         result
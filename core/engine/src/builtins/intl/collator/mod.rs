@@ -42,6 +42,9 @@ use super::{
 mod options;
 pub(crate) use options::*;
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Finalize, JsData)]
 #[allow(clippy::struct_field_names)]
 pub(crate) struct Collator {
@@ -412,13 +415,11 @@ impl Collator {
         // 1. Let collator be the this value.
         // 2. Perform ? RequireInternalSlot(collator, [[InitializedCollator]]).
         let this = this.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("`resolvedOptions` can only be called on a `Collator` object")
+            JsNativeError::typ().with_message("`compare` can only be called on a `Collator` object")
         })?;
         let collator_obj = this.clone();
         let mut collator = this.downcast_mut::<Self>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("`resolvedOptions` can only be called on a `Collator` object")
+            JsNativeError::typ().with_message("`compare` can only be called on a `Collator` object")
         })?;
 
         // 3. If collator.[[BoundCompare]] is undefined, then
@@ -434,12 +435,6 @@ impl Collator {
                 // https://tc39.es/ecma402/#sec-collator-compare-functions
                 NativeFunction::from_copy_closure_with_captures(
                     |_, args, collator, context| {
-                        // 1. Let collator be F.[[Collator]].
-                        // 2. Assert: Type(collator) is Object and collator has an [[InitializedCollator]] internal slot.
-                        let collator = collator
-                            .downcast_ref::<Self>()
-                            .expect("checked above that the object was a collator object");
-
                         // 3. If x is not provided, let x be undefined.
                         // 5. Let X be ? ToString(x).
                         let x = args.get_or_undefined(0).to_string(context)?;
@@ -448,8 +443,17 @@ impl Collator {
                         // 6. Let Y be ? ToString(y).
                         let y = args.get_or_undefined(1).to_string(context)?;
 
+                        // 1. Let collator be F.[[Collator]].
+                        // 2. Assert: Type(collator) is Object and collator has an [[InitializedCollator]] internal slot.
+                        // NOTE: The collator is borrowed after the conversions, since those can run user
+                        // code that could access the collator.
+                        let collator = collator
+                            .downcast_ref::<Self>()
+                            .expect("checked above that the object was a collator object");
+
                         // 7. Return CompareStrings(collator, X, Y).
-                        let result = collator.collator.compare_utf16(&x, &y) as i32;
+                        let result =
+                            collator.collator.compare_utf16(&x.to_vec(), &y.to_vec()) as i32;
 
                         Ok(result.into())
                     },
@@ -0,0 +1,86 @@
+use crate::{js_string, run_test_actions, TestAction};
+use indoc::indoc;
+
+#[test]
+fn sort_with_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var items = ['item10', 'Item2', 'item9', 'item1', 'Item1'];
+            function sorted(options) {
+                return items.slice().sort(new Intl.Collator('en', options).compare).join();
+            }
+        "#}),
+        TestAction::assert_eq("sorted({})", js_string!("item1,Item1,item10,Item2,item9")),
+        TestAction::assert_eq(
+            "sorted({ numeric: true })",
+            js_string!("item1,Item1,Item2,item9,item10"),
+        ),
+        TestAction::assert_eq(
+            "sorted({ numeric: true, caseFirst: 'upper' })",
+            js_string!("Item1,item1,Item2,item9,item10"),
+        ),
+        TestAction::assert_eq(
+            "sorted({ numeric: true, sensitivity: 'base' })",
+            js_string!("item1,Item1,Item2,item9,item10"),
+        ),
+    ]);
+}
+
+#[test]
+fn sensitivity() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function compare(sensitivity, x, y) {
+                return new Intl.Collator('en', { sensitivity }).compare(x, y);
+            }
+        "#}),
+        TestAction::assert_eq("compare('base', 'a', 'A')", 0),
+        TestAction::assert_eq("compare('base', 'a', 'á')", 0),
+        TestAction::assert_eq("compare('accent', 'a', 'A')", 0),
+        TestAction::assert_eq("compare('accent', 'a', 'á')", -1),
+        TestAction::assert_eq("compare('case', 'a', 'A')", -1),
+        TestAction::assert_eq("compare('case', 'a', 'á')", 0),
+        TestAction::assert_eq("compare('variant', 'a', 'A')", -1),
+        TestAction::assert_eq(
+            "new Intl.Collator('en', { ignorePunctuation: true }).compare('ab', 'a-b')",
+            0,
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var options = new Intl.Collator('en', {
+                numeric: true,
+                caseFirst: 'upper',
+                sensitivity: 'base',
+            }).resolvedOptions();
+        "#}),
+        TestAction::assert_eq("options.usage", js_string!("sort")),
+        TestAction::assert_eq("options.sensitivity", js_string!("base")),
+        TestAction::assert_eq("options.numeric", true),
+        TestAction::assert_eq("options.caseFirst", js_string!("upper")),
+        TestAction::assert_eq("options.ignorePunctuation", false),
+    ]);
+}
+
+#[test]
+fn locale_compare() {
+    run_test_actions([
+        TestAction::assert_eq("'item10'.localeCompare('item9', 'en')", -1),
+        TestAction::assert_eq(
+            "'item10'.localeCompare('item9', 'en', { numeric: true })",
+            1,
+        ),
+        TestAction::assert_eq(
+            "'a'.localeCompare('A', 'en', { sensitivity: 'base' })",
+            0,
+        ),
+        TestAction::assert_eq(
+            "['b', 'B', 'a', 'A'].sort((x, y) => x.localeCompare(y, 'en', { caseFirst: 'upper' })).join()",
+            js_string!("A,a,B,b"),
+        ),
+    ]);
+}
@@ -0,0 +1,1479 @@
+//! This module implements the global `Intl.DateTimeFormat` object.
+//!
+//! `Intl.DateTimeFormat` is a built-in object that has properties and methods for date and time i18n.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+
+use std::ops::Range;
+
+use boa_gc::{custom_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_calendar::{types::Time, AnyCalendar, AnyCalendarKind, Date, DateTime};
+use icu_datetime::{
+    options::{components, length, preferences::HourCycle},
+    pattern::CoarseHourCycle,
+    provider::calendar::TimeLengthsV1Marker,
+    DateTimeFormatter, DateTimeFormatterOptions,
+};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::Value, extensions_unicode_key as key, extensions_unicode_value as value,
+    Locale,
+};
+use icu_provider::{
+    DataLocale, DataPayload, DataProvider, DataRequest, DataRequestMetadata, DataResponse,
+};
+
+use crate::{
+    builtins::{
+        date::utils::{
+            date_from_time, hour_from_time, local_timezone_offset_seconds, min_from_time,
+            month_from_time, ms_from_time, sec_from_time, time_clip, year_from_time,
+        },
+        options::get_option,
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+        HostHooks,
+    },
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsFunction,
+        JsObject, ObjectInitializer,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::{common::StaticJsStrings, utf16},
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales, validate_extension},
+    options::IntlOptions,
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The separator used between the two sides of a formatted date range.
+// TODO: ICU4X doesn't provide date interval formats yet, so the CLDR fallback is used.
+const RANGE_SEPARATOR: &str = " – ";
+
+/// JavaScript `Intl.DateTimeFormat` object.
+#[derive(Debug, Finalize, JsData)]
+pub(crate) struct DateTimeFormat {
+    locale: Locale,
+    calendar: Value,
+    numbering_system: Value,
+    time_zone: TimeZone,
+    hour_cycle: Option<HourCycle>,
+    date_style: Option<Style>,
+    time_style: Option<Style>,
+    components: FormatComponents,
+    decimal_separator: String,
+    formatter: DateTimeFormatter,
+    text_formatters: Vec<(&'static str, DateTimeFormatter)>,
+    bound_format: Option<JsFunction>,
+}
+
+// SAFETY: only `bound_format` is a traceable object.
+unsafe impl Trace for DateTimeFormat {
+    custom_trace!(this, mark, mark(&this.bound_format));
+}
+
+/// A time value split into its fields, as seen from the time zone of a `DateTimeFormat`.
+#[derive(Debug, Clone, Copy)]
+struct LocalTime {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    millisecond: u16,
+    offset_seconds: i32,
+}
+
+impl LocalTime {
+    /// Converts the time into an ICU4X `DateTime`.
+    fn to_native(self) -> JsResult<DateTime<AnyCalendar>> {
+        let date = Date::try_new_iso_date(self.year, self.month, self.day)
+            .map_err(|e| JsNativeError::range().with_message(e.to_string()))?;
+        let time = Time::try_new(
+            self.hour,
+            self.minute,
+            self.second,
+            u32::from(self.millisecond) * 1_000_000,
+        )
+        .map_err(|e| JsNativeError::range().with_message(e.to_string()))?;
+
+        Ok(DateTime::new(date, time).to_any())
+    }
+}
+
+impl DateTimeFormat {
+    /// Abstract operation [`CreateDateTimeFormat ( newTarget, locales, options, required, defaults )`][spec],
+    /// without the object creation.
+    ///
+    /// This is also used by the `toLocaleString` family of methods of `Date`.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        required: &DateTimeReqs,
+        defaults: &DateTimeReqs,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Let options be ? ToDateTimeOptions(options, required, defaults).
+        let options = to_date_time_options(options, required, defaults, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[localeMatcher]] to matcher.
+        let matcher = get_option(&options, utf16!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let calendar be ? GetOption(options, "calendar", string, empty, undefined).
+        // 7. If calendar is not undefined, then
+        //     a. If calendar cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 8. Set opt.[[ca]] to calendar.
+        let calendar = get_option(&options, utf16!("calendar"), context)?;
+
+        // 9. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 10. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 11. Set opt.[[nu]] to numberingSystem.
+        let numbering_system = get_option(&options, utf16!("numberingSystem"), context)?;
+
+        // 12. Let hour12 be ? GetOption(options, "hour12", boolean, empty, undefined).
+        let hour12: Option<bool> = get_option(&options, utf16!("hour12"), context)?;
+
+        // 13. Let hourCycle be ? GetOption(options, "hourCycle", string, « "h11", "h12", "h23", "h24" », undefined).
+        let hour_cycle = get_option(&options, utf16!("hourCycle"), context)?;
+
+        // 14. If hour12 is not undefined, then
+        //     a. Set hourCycle to null.
+        let hour_cycle = if hour12.is_some() { None } else { hour_cycle };
+
+        // 15. Set opt.[[hc]] to hourCycle.
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: DateTimeFormatLocaleOptions {
+                calendar,
+                numbering_system,
+                hour_cycle,
+            },
+        };
+
+        // 16. Let localeData be %DateTimeFormat%.[[LocaleData]].
+        // 17. Let r be ResolveLocale(%DateTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %DateTimeFormat%.[[RelevantExtensionKeys]], localeData).
+        let mut locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        );
+
+        // `hour12` overrides any hour cycle requested by the locale.
+        if hour12.is_some() {
+            locale.extensions.unicode.keywords.remove(key!("hc"));
+        }
+
+        // 18. Set dateTimeFormat.[[Locale]] to r.[[locale]].
+        // 19. Let resolvedCalendar be r.[[ca]].
+        // 20. Set dateTimeFormat.[[Calendar]] to resolvedCalendar.
+        // TODO: report the default calendar of the locale instead of always using `gregory`.
+        let calendar = intl_options
+            .service_options
+            .calendar
+            .unwrap_or(value!("gregory"));
+
+        // 21. Set dateTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let numbering_system = intl_options
+            .service_options
+            .numbering_system
+            .unwrap_or(value!("latn"));
+
+        // 22. Let dataLocale be r.[[dataLocale]].
+        let mut data_locale = DataLocale::from(&locale);
+
+        // 23. Let dataLocaleData be localeData.[[<dataLocale>]].
+        // 24. Let timeZone be ? Get(options, "timeZone").
+        let time_zone = options.get(utf16!("timeZone"), context)?;
+
+        // 25. If timeZone is undefined, then
+        //     a. Set timeZone to DefaultTimeZone().
+        // 26. Else,
+        //     a. Set timeZone to ? ToString(timeZone).
+        //     b. If IsTimeZoneOffsetString(timeZone) is true, then ...
+        //     c. Else,
+        //         i. If IsValidTimeZoneName(timeZone) is false, throw a RangeError exception.
+        let time_zone = if time_zone.is_undefined() {
+            TimeZone::Local
+        } else {
+            let time_zone = time_zone.to_string(context)?.to_std_string_escaped();
+            TimeZone::parse(&time_zone).ok_or_else(|| {
+                JsNativeError::range().with_message(format!("unsupported time zone `{time_zone}`"))
+            })?
+        };
+
+        // 27. Set dateTimeFormat.[[TimeZone]] to timeZone.
+        // 28. Let formatOptions be a new Record.
+        // 29. Set formatOptions.[[hourCycle]] to hc.
+        // 30. Let hasExplicitFormatComponents be false.
+        // 31. For each row of Table 7, except the header row, in table order, do
+        //     ...
+        //     h. If value is not undefined, then
+        //         i. Set hasExplicitFormatComponents to true.
+        let components = FormatComponents::from_options(&options, context)?;
+
+        // 32. Let matcher be ? GetOption(options, "formatMatcher", string, « "basic", "best fit" », "best fit").
+        // NOTE: ICU4X only provides a "best fit" matcher, so the value is only validated.
+        let _: Option<FormatMatcher> = get_option(&options, utf16!("formatMatcher"), context)?;
+
+        // 33. Let dateStyle be ? GetOption(options, "dateStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 34. Set dateTimeFormat.[[DateStyle]] to dateStyle.
+        let date_style: Option<Style> = get_option(&options, utf16!("dateStyle"), context)?;
+
+        // 35. Let timeStyle be ? GetOption(options, "timeStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 36. Set dateTimeFormat.[[TimeStyle]] to timeStyle.
+        let time_style: Option<Style> = get_option(&options, utf16!("timeStyle"), context)?;
+
+        // 37. If dateStyle is not undefined or timeStyle is not undefined, then
+        //     a. If hasExplicitFormatComponents is true, then
+        //         i. Throw a TypeError exception.
+        if (date_style.is_some() || time_style.is_some()) && components.has_any() {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "`dateStyle` and `timeStyle` cannot be combined with explicit date-time components",
+                )
+                .into());
+        }
+
+        // 38. If hour12 is true, then
+        //     a. Let hc be dataLocaleData.[[hourCycle12]].
+        // 39. Else if hour12 is false, then
+        //     a. Let hc be dataLocaleData.[[hourCycle24]].
+        // 40. Else,
+        //     a. Assert: hour12 is undefined.
+        //     b. Let hc be r.[[hc]].
+        //     c. If hc is null, set hc to dataLocaleData.[[hourCycle]].
+        let hour_cycle = match hour12 {
+            Some(true) => HourCycle::H12,
+            Some(false) => HourCycle::H23,
+            None => match intl_options.service_options.hour_cycle {
+                Some(hc) => hc,
+                None => default_hour_cycle(&data_locale, context.intl_provider())?,
+            },
+        };
+        data_locale.set_unicode_ext(key!("hc"), hour_cycle_to_value(hour_cycle));
+
+        // 41. If dateStyle is not undefined or timeStyle is not undefined, then
+        //     a. Let styles be dataLocaleData.[[styles]].[[<resolvedCalendar>]].
+        //     b. Let bestFormat be DateTimeStyleFormat(dateStyle, timeStyle, styles).
+        // 42. Else,
+        //     a. Let formats be dataLocaleData.[[formats]].[[<resolvedCalendar>]].
+        //     b. If matcher is "basic", then
+        //         i. Let bestFormat be BasicFormatMatcher(formatOptions, formats).
+        //     c. Else,
+        //         i. Let bestFormat be BestFitFormatMatcher(formatOptions, formats).
+        let format_options = match (date_style, time_style) {
+            (Some(date), Some(time)) => DateTimeFormatterOptions::Length(
+                length::Bag::from_date_time_style(date.to_date(), time.to_time()),
+            ),
+            (Some(date), None) => {
+                DateTimeFormatterOptions::Length(length::Bag::from_date_style(date.to_date()))
+            }
+            (None, Some(time)) => {
+                DateTimeFormatterOptions::Length(length::Bag::from_time_style(time.to_time()))
+            }
+            (None, None) => {
+                let mut bag = components.to_native(Some(hour_cycle));
+
+                // ICU4X cannot format a pattern consisting only of a day period or a time zone
+                // name, so those patterns fall back to showing the hour.
+                let formattable = FormatComponents {
+                    day_period: None,
+                    time_zone_name: None,
+                    ..components
+                };
+                if !formattable.has_any() {
+                    bag.hour = Some(components::Numeric::Numeric);
+                }
+
+                DateTimeFormatterOptions::Components(bag)
+            }
+        };
+
+        let formatter = create_formatter(context.intl_provider(), &data_locale, format_options)?;
+
+        // 43. Set dateTimeFormat.[[DateTimeFormat]] to bestFormat.
+        // 44. If bestFormat has a field [[hour]], then
+        //     a. Set dateTimeFormat.[[HourCycle]] to hc.
+        let resolved = formatter.resolve_components();
+        let hour_cycle = resolved.hour.map(|_| {
+            resolved
+                .preferences
+                .as_ref()
+                .and_then(|preferences| preferences.hour_cycle)
+                .unwrap_or(hour_cycle)
+        });
+        let components = components.resolve(&resolved);
+        let decimal_separator = match components.fractional_second_digits {
+            Some(_) => decimal_separator(&data_locale, context.intl_provider())?,
+            None => String::new(),
+        };
+
+        // The textual fields are formatted on their own to be able to find them in the
+        // formatted output.
+        // TODO: ICU4X doesn't support formatting to parts yet. Remove this when it does.
+        let text_formatters = text_fields(&resolved)
+            .into_iter()
+            .filter_map(|(typ, bag)| {
+                create_formatter(
+                    context.intl_provider(),
+                    &data_locale,
+                    DateTimeFormatterOptions::Components(bag),
+                )
+                .ok()
+                .map(|formatter| (typ, formatter))
+            })
+            .collect();
+
+        // 45. Return dateTimeFormat.
+        Ok(Self {
+            locale,
+            calendar,
+            numbering_system,
+            time_zone,
+            hour_cycle,
+            date_style,
+            time_style,
+            components,
+            decimal_separator,
+            formatter,
+            text_formatters,
+            bound_format: None,
+        })
+    }
+
+    /// Splits the time value `x` into its fields in the time zone of this `DateTimeFormat`.
+    fn to_local_time(&self, x: f64, hooks: &dyn HostHooks) -> LocalTime {
+        let offset_seconds = match self.time_zone {
+            TimeZone::Utc => 0,
+            TimeZone::Offset(minutes) => i32::from(minutes) * 60,
+            TimeZone::Local => local_timezone_offset_seconds(x, hooks),
+        };
+        let t = x + f64::from(offset_seconds) * 1000.0;
+
+        LocalTime {
+            year: year_from_time(t),
+            month: month_from_time(t) + 1,
+            day: date_from_time(t),
+            hour: hour_from_time(t),
+            minute: min_from_time(t),
+            second: sec_from_time(t),
+            millisecond: ms_from_time(t),
+            offset_seconds,
+        }
+    }
+
+    /// Gets the values of the numeric fields of this format, in the order they usually appear
+    /// in a pattern.
+    fn numeric_fields(&self, time: &LocalTime) -> Vec<(&'static str, u32)> {
+        let components = &self.components;
+        let mut fields = Vec::new();
+
+        if let Some(year) = components.year {
+            let year = match year {
+                Numeric::Numeric => time.year.unsigned_abs(),
+                Numeric::TwoDigit => time.year.rem_euclid(100) as u32,
+            };
+            fields.push(("year", year));
+        }
+        if components.month.is_some_and(Month::is_numeric) {
+            fields.push(("month", u32::from(time.month)));
+        }
+        if components.day.is_some() {
+            fields.push(("day", u32::from(time.day)));
+        }
+        if components.hour.is_some() {
+            let hour = u32::from(time.hour);
+            let hour = match self.hour_cycle {
+                Some(HourCycle::H11) => hour % 12,
+                Some(HourCycle::H12) => (hour + 11) % 12 + 1,
+                Some(HourCycle::H24) if hour == 0 => 24,
+                _ => hour,
+            };
+            fields.push(("hour", hour));
+        }
+        if components.minute.is_some() {
+            fields.push(("minute", u32::from(time.minute)));
+        }
+        if components.second.is_some() {
+            fields.push(("second", u32::from(time.second)));
+        }
+
+        fields
+    }
+
+    /// Formats `time` and splits the result into its `(type, value)` parts.
+    ///
+    /// ICU4X cannot format to parts yet, so the formatted string is tagged by matching its runs
+    /// of characters against the expected values of each field.
+    // TODO: Use the parts API of ICU4X when it becomes available.
+    fn format_to_parts(&self, time: &LocalTime) -> JsResult<Vec<(&'static str, String)>> {
+        let datetime = time.to_native()?;
+        let chars: Vec<char> = format_native(&self.formatter, &datetime)?.chars().collect();
+        let mut types: Vec<Option<&'static str>> = vec![None; chars.len()];
+
+        // Textual fields: weekday, month and era.
+        for (typ, formatter) in &self.text_formatters {
+            let text: Vec<char> = format_native(formatter, &datetime)?.chars().collect();
+            if text.is_empty() || text.len() > chars.len() {
+                continue;
+            }
+            let found = (0..=chars.len() - text.len()).find(|&i| {
+                chars[i..i + text.len()] == text[..]
+                    && types[i..i + text.len()].iter().all(Option::is_none)
+            });
+            if let Some(start) = found {
+                types[start..start + text.len()].fill(Some(*typ));
+            }
+        }
+
+        // Numeric fields, matched by value first and by position otherwise.
+        let mut fields = self.numeric_fields(time);
+        for run in unassigned_runs(&chars, &types, char::is_numeric, char::is_numeric) {
+            if fields.is_empty() {
+                break;
+            }
+            let value = chars[run.clone()].iter().try_fold(0u32, |acc, c| {
+                acc.checked_mul(10)?.checked_add(c.to_digit(10)?)
+            });
+            let index = value
+                .and_then(|value| fields.iter().position(|&(_, field)| field == value))
+                .unwrap_or(0);
+            let (typ, _) = fields.remove(index);
+            types[run].fill(Some(typ));
+        }
+
+        // The day period is the word right after the hour, or right before it for locales that
+        // put it first.
+        // TODO: ICU4X doesn't support flexible day periods, so `dayPeriod` is only displayed
+        // as part of a 12-hour clock.
+        if matches!(self.hour_cycle, Some(HourCycle::H11 | HourCycle::H12)) {
+            if let Some(hour) = types.iter().position(|t| *t == Some("hour")) {
+                let words = unassigned_runs(&chars, &types, char::is_alphabetic, |c| {
+                    c.is_alphabetic() || c == '.'
+                });
+                let after = words.iter().find(|word| word.start > hour);
+                let before = words.iter().rev().find(|word| {
+                    word.end <= hour && chars[word.end..hour].iter().all(|c| c.is_whitespace())
+                });
+                if let Some(word) = after.or(before) {
+                    types[word.clone()].fill(Some("dayPeriod"));
+                }
+            }
+        }
+
+        let mut parts: Vec<(&'static str, String)> = Vec::new();
+        for (c, typ) in chars.into_iter().zip(types) {
+            let typ = typ.unwrap_or("literal");
+            match parts.last_mut() {
+                Some((last, value)) if *last == typ => value.push(c),
+                _ => parts.push((typ, c.to_string())),
+            }
+        }
+
+        // The fractional seconds are not part of the ICU4X pattern, so they are added after the
+        // seconds, or after the last time field if the seconds are not displayed.
+        if let Some(digits) = self.components.fractional_second_digits {
+            let mut fraction = format!("{:03}", time.millisecond);
+            fraction.truncate(usize::from(digits));
+            let position = parts
+                .iter()
+                .position(|(typ, _)| *typ == "second")
+                .or_else(|| {
+                    parts
+                        .iter()
+                        .rposition(|(typ, _)| matches!(*typ, "hour" | "minute"))
+                });
+            if let Some(index) = position {
+                parts.insert(index + 1, ("fractionalSecond", fraction));
+                parts.insert(index + 1, ("literal", self.decimal_separator.clone()));
+            } else {
+                if !parts.is_empty() {
+                    parts.push(("literal", " ".to_owned()));
+                }
+                parts.push(("fractionalSecond", fraction));
+            }
+        }
+
+        // TODO: ICU4X's `DateTimeFormatter` cannot format time zones, so the name is appended
+        // to the formatted output.
+        if let Some(name) = self.components.time_zone_name {
+            parts.push(("literal", " ".to_owned()));
+            parts.push((
+                "timeZoneName",
+                name.format(self.time_zone, time.offset_seconds),
+            ));
+        }
+
+        Ok(parts)
+    }
+
+    /// Abstract operation [`PartitionDateTimePattern ( dateTimeFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimepattern
+    fn partition_date_time_pattern(
+        &self,
+        x: f64,
+        hooks: &dyn HostHooks,
+    ) -> JsResult<Vec<(&'static str, String)>> {
+        // 1. Let x be TimeClip(x).
+        let x = time_clip(x);
+
+        // 2. If x is NaN, throw a RangeError exception.
+        if x.is_nan() {
+            return Err(JsNativeError::range()
+                .with_message("invalid time value")
+                .into());
+        }
+
+        // 3. Let epochNanoseconds be ℤ(ℝ(x) × 10^6).
+        // 4. Let timeZone be dateTimeFormat.[[TimeZone]].
+        // 5. Let tm be ToLocalTime(epochNanoseconds, dateTimeFormat.[[Calendar]], timeZone).
+        let time = self.to_local_time(x, hooks);
+
+        // 6. Let result be ? FormatDateTimePattern(dateTimeFormat, patternParts, x, undefined).
+        // 7. Return result.
+        self.format_to_parts(&time)
+    }
+
+    /// Abstract operation [`PartitionDateTimeRangePattern ( dateTimeFormat, x, y )`][spec].
+    ///
+    /// Returns the `(type, value, source)` triples of the formatted range.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimerangepattern
+    fn partition_date_time_range_pattern(
+        &self,
+        x: f64,
+        y: f64,
+        hooks: &dyn HostHooks,
+    ) -> JsResult<Vec<(&'static str, String, &'static str)>> {
+        // 1. Let x be TimeClip(x).
+        // 2. If x is NaN, throw a RangeError exception.
+        // 3. Let y be TimeClip(y).
+        // 4. If y is NaN, throw a RangeError exception.
+        // 5. Let tm1 be ToLocalTime(ℤ(ℝ(x) × 10^6), dateTimeFormat.[[Calendar]], dateTimeFormat.[[TimeZone]]).
+        // 6. Let tm2 be ToLocalTime(ℤ(ℝ(y) × 10^6), dateTimeFormat.[[Calendar]], dateTimeFormat.[[TimeZone]]).
+        let x_parts = self.partition_date_time_pattern(x, hooks)?;
+        let y_parts = self.partition_date_time_pattern(y, hooks)?;
+
+        // 7-12. If dateFieldsPracticallyEqual is true, then
+        //     a. Let resultParts be ! PartitionDateTimePattern(dateTimeFormat, x).
+        //     b. For each Record { [[Type]], [[Value]] } r of resultParts, do
+        //         i. Set r.[[Source]] to "shared".
+        //     c. Return resultParts.
+        if x_parts == y_parts {
+            return Ok(x_parts
+                .into_iter()
+                .map(|(typ, value)| (typ, value, "shared"))
+                .collect());
+        }
+
+        // 13-15. Let rangePattern be the range pattern for the largest differing field.
+        // When only the time fields differ, the date is shared between both sides of the
+        // range and only the time is repeated.
+        let is_time = |typ: &str| {
+            matches!(
+                typ,
+                "dayPeriod" | "hour" | "minute" | "second" | "fractionalSecond"
+            )
+        };
+        let time_range = |parts: &[(&'static str, String)]| {
+            let start = parts.iter().position(|(typ, _)| is_time(typ))?;
+            let end = parts.iter().rposition(|(typ, _)| is_time(typ))? + 1;
+            Some(start..end)
+        };
+
+        let mut result = Vec::new();
+        if let (Some(x_time), Some(y_time)) = (time_range(&x_parts), time_range(&y_parts)) {
+            if x_parts[..x_time.start] == y_parts[..y_time.start]
+                && x_parts[x_time.end..] == y_parts[y_time.end..]
+            {
+                let shared = |parts: &[(&'static str, String)]| {
+                    parts
+                        .iter()
+                        .map(|(typ, value)| (*typ, value.clone(), "shared"))
+                        .collect::<Vec<_>>()
+                };
+                result.extend(shared(&x_parts[..x_time.start]));
+                result.extend(
+                    x_parts[x_time.clone()]
+                        .iter()
+                        .map(|(typ, value)| (*typ, value.clone(), "startRange")),
+                );
+                result.push(("literal", RANGE_SEPARATOR.to_owned(), "shared"));
+                result.extend(
+                    y_parts[y_time]
+                        .iter()
+                        .map(|(typ, value)| (*typ, value.clone(), "endRange")),
+                );
+                result.extend(shared(&x_parts[x_time.end..]));
+                return Ok(result);
+            }
+        }
+
+        // 16. If rangePattern is undefined, then
+        //     a. Return FormatDateTimeRangeFallback(dateTimeFormat, tm1, tm2).
+        result.extend(
+            x_parts
+                .into_iter()
+                .map(|(typ, value)| (typ, value, "startRange")),
+        );
+        result.push(("literal", RANGE_SEPARATOR.to_owned(), "shared"));
+        result.extend(
+            y_parts
+                .into_iter()
+                .map(|(typ, value)| (typ, value, "endRange")),
+        );
+
+        Ok(result)
+    }
+
+    /// Abstract operation [`FormatDateTime ( dateTimeFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+    pub(crate) fn format(&self, x: f64, hooks: &dyn HostHooks) -> JsResult<JsString> {
+        // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+        let parts = self.partition_date_time_pattern(x, hooks)?;
+
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        Ok(js_string!(parts
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect::<String>()))
+    }
+}
+
+/// Creates an ICU4X `DateTimeFormatter` for `locale` with the provided options.
+fn create_formatter(
+    provider: &IntlProvider,
+    locale: &DataLocale,
+    options: DateTimeFormatterOptions,
+) -> JsResult<DateTimeFormatter> {
+    DateTimeFormatter::try_new_experimental_unstable(provider, locale, options)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()).into())
+}
+
+/// Formats `datetime` using `formatter`.
+fn format_native(
+    formatter: &DateTimeFormatter,
+    datetime: &DateTime<AnyCalendar>,
+) -> JsResult<String> {
+    formatter
+        .format_to_string(datetime)
+        .map_err(|e| JsNativeError::range().with_message(e.to_string()).into())
+}
+
+/// Gets the components bags needed to format each of the textual fields of `bag` on its own.
+fn text_fields(bag: &components::Bag) -> Vec<(&'static str, components::Bag)> {
+    let mut fields = Vec::new();
+
+    if let Some(weekday) = bag.weekday {
+        let mut field = components::Bag::default();
+        field.weekday = Some(weekday);
+        fields.push(("weekday", field));
+    }
+    if let Some(month) = bag.month.filter(|month| {
+        !matches!(
+            month,
+            components::Month::Numeric | components::Month::TwoDigit
+        )
+    }) {
+        let mut field = components::Bag::default();
+        field.month = Some(month);
+        fields.push(("month", field));
+    }
+    if let Some(era) = bag.era {
+        let mut field = components::Bag::default();
+        field.era = Some(era);
+        fields.push(("era", field));
+    }
+
+    fields
+}
+
+/// Gets the ranges of the runs of characters that weren't assigned a type yet, starting with a
+/// character matching `start` and continuing with characters matching `rest`.
+fn unassigned_runs(
+    chars: &[char],
+    types: &[Option<&'static str>],
+    start: impl Fn(char) -> bool,
+    rest: impl Fn(char) -> bool,
+) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if types[i].is_some() || !start(chars[i]) {
+            i += 1;
+            continue;
+        }
+        let begin = i;
+        i += 1;
+        while i < chars.len() && types[i].is_none() && rest(chars[i]) {
+            i += 1;
+        }
+        runs.push(begin..i);
+    }
+    runs
+}
+
+/// Gets the default hour cycle of `locale`.
+fn default_hour_cycle(locale: &DataLocale, provider: &IntlProvider) -> JsResult<HourCycle> {
+    let lengths: DataPayload<TimeLengthsV1Marker> = provider
+        .load(DataRequest {
+            locale,
+            metadata: DataRequestMetadata::default(),
+        })
+        .and_then(DataResponse::take_payload)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    Ok(match lengths.get().preferred_hour_cycle {
+        CoarseHourCycle::H11H12 => HourCycle::H12,
+        CoarseHourCycle::H23H24 => HourCycle::H23,
+    })
+}
+
+/// Gets the decimal separator of `locale`, used between the seconds and the fractional seconds.
+fn decimal_separator(locale: &DataLocale, provider: &IntlProvider) -> JsResult<String> {
+    let symbols: DataPayload<DecimalSymbolsV1Marker> = provider
+        .load(DataRequest {
+            locale,
+            metadata: DataRequestMetadata::default(),
+        })
+        .and_then(DataResponse::take_payload)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    Ok(symbols.get().decimal_separator.to_string())
+}
+
+/// Converts an hour cycle into its Unicode extension value.
+fn hour_cycle_to_value(hc: HourCycle) -> Value {
+    match hc {
+        HourCycle::H11 => value!("h11"),
+        HourCycle::H12 => value!("h12"),
+        HourCycle::H23 => value!("h23"),
+        HourCycle::H24 => value!("h24"),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct DateTimeFormatLocaleOptions {
+    calendar: Option<Value>,
+    numbering_system: Option<Value>,
+    hour_cycle: Option<HourCycle>,
+}
+
+impl Service for DateTimeFormat {
+    type LangMarker = TimeLengthsV1Marker;
+
+    type LocaleOptions = DateTimeFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        let calendar = options
+            .calendar
+            .take()
+            .filter(|ca| AnyCalendarKind::get_for_bcp47_value(ca).is_some())
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("ca"))
+                    .cloned()
+                    .filter(|ca| AnyCalendarKind::get_for_bcp47_value(ca).is_some())
+            });
+
+        let numbering_system = options
+            .numbering_system
+            .take()
+            .filter(|nu| {
+                validate_extension::<DecimalSymbolsV1Marker>(
+                    locale.id.clone(),
+                    key!("nu"),
+                    nu,
+                    provider,
+                )
+            })
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("nu"))
+                    .cloned()
+                    .filter(|nu| {
+                        validate_extension::<DecimalSymbolsV1Marker>(
+                            locale.id.clone(),
+                            key!("nu"),
+                            nu,
+                            provider,
+                        )
+                    })
+            });
+
+        let hour_cycle = options.hour_cycle.or_else(|| {
+            match locale.extensions.unicode.keywords.get(&key!("hc")) {
+                Some(hc) if hc == &value!("h11") => Some(HourCycle::H11),
+                Some(hc) if hc == &value!("h12") => Some(HourCycle::H12),
+                Some(hc) if hc == &value!("h23") => Some(HourCycle::H23),
+                Some(hc) if hc == &value!("h24") => Some(HourCycle::H24),
+                _ => None,
+            }
+        });
+
+        locale.extensions.unicode.clear();
+
+        if let Some(ca) = calendar.clone() {
+            locale.extensions.unicode.keywords.set(key!("ca"), ca);
+        }
+
+        if let Some(hc) = hour_cycle {
+            locale
+                .extensions
+                .unicode
+                .keywords
+                .set(key!("hc"), hour_cycle_to_value(hc));
+        }
+
+        if let Some(nu) = numbering_system.clone() {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.calendar = calendar;
+        options.numbering_system = numbering_system;
+        options.hour_cycle = hour_cycle;
+    }
+}
+
+impl IntrinsicObject for DateTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_format = BuiltInBuilder::callable(realm, Self::get_format)
+            .name(js_string!("get format"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DateTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("format"),
+                Some(get_format),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format_to_parts_js, js_string!("formatToParts"), 1)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(
+                Self::format_range_to_parts,
+                js_string!("formatRangeToParts"),
+                2,
+            )
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DateTimeFormat {
+    const NAME: JsString = StaticJsStrings::DATE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for DateTimeFormat {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::date_time_format;
+    /// The `Intl.DateTimeFormat` constructor is the `%DateTimeFormat%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target = &if new_target.is_undefined() {
+            context
+                .active_function_object()
+                .unwrap_or_else(|| {
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .date_time_format()
+                        .constructor()
+                })
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales, options, any, date).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::date_time_format,
+            context,
+        )?;
+        let date_time_format = Self::new(
+            locales,
+            options,
+            &DateTimeReqs::AnyAll,
+            &DateTimeReqs::Date,
+            context,
+        )?;
+        let date_time_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            date_time_format,
+        );
+
+        // TODO 3. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        // TODO a. Let this be the this value.
+        // TODO b. Return ? ChainDateTimeFormat(dateTimeFormat, NewTarget, this).
+
+        // 4. Return dateTimeFormat.
+        Ok(date_time_format.into())
+    }
+}
+
+impl DateTimeFormat {
+    /// [`Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in date and
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`get Intl.DateTimeFormat.prototype.format`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, "format")?;
+        let dtf_clone = dtf.clone();
+        let mut dtf = dtf.borrow_mut();
+
+        let bound_format = if let Some(f) = dtf.data.bound_format.clone() {
+            f
+        } else {
+            // 4. If dtf.[[BoundFormat]] is undefined, then
+            //     a. Let F be a new built-in function object as defined in DateTime Format Functions (11.5.4.1).
+            //     b. Set F.[[DateTimeFormat]] to dtf.
+            //     c. Set dtf.[[BoundFormat]] to F.
+            let bound_format = FunctionObjectBuilder::new(
+                context.realm(),
+                // DateTime Format Functions
+                // <https://tc39.es/ecma402/#sec-datetime-format-functions>
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, dtf, context| {
+                        // 1. Let dtf be F.[[DateTimeFormat]].
+                        // 2. Assert: Type(dtf) is Object and dtf has an [[InitializedDateTimeFormat]] internal slot.
+                        let date = args.get_or_undefined(0);
+
+                        // 3. If date is not provided or is undefined, then
+                        //     a. Let x be ! Call(%Date.now%, undefined).
+                        // 4. Else,
+                        //     a. Let x be ? ToNumber(date).
+                        let x = if date.is_undefined() {
+                            context.host_hooks().utc_now() as f64
+                        } else {
+                            date.to_number(context)?
+                        };
+
+                        // 5. Return ? FormatDateTime(dtf, x).
+                        Ok(dtf.borrow().data.format(x, context.host_hooks())?.into())
+                    },
+                    dtf_clone,
+                ),
+            )
+            .length(1)
+            .build();
+
+            dtf.data.bound_format = Some(bound_format.clone());
+            bound_format
+        };
+
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatToParts ( date )`][spec].
+    ///
+    /// Returns an array of objects representing the parts of the formatted date.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts_js(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, "formatToParts")?;
+        let date = args.get_or_undefined(0);
+
+        // 3. If date is undefined, then
+        //     a. Let x be ! Call(%Date.now%, undefined).
+        // 4. Else,
+        //     a. Let x be ? ToNumber(date).
+        let x = if date.is_undefined() {
+            context.host_hooks().utc_now() as f64
+        } else {
+            date.to_number(context)?
+        };
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+        let parts = dtf
+            .borrow()
+            .data
+            .partition_date_time_pattern(x, context.host_hooks())?;
+        let parts = parts
+            .into_iter()
+            .map(|(typ, value)| {
+                let mut part = ObjectInitializer::new(context);
+                part.property(js_string!("type"), js_string!(typ), Attribute::all())
+                    .property(js_string!("value"), js_string!(value), Attribute::all());
+                part.build().into()
+            })
+            .collect::<Vec<JsValue>>();
+
+        Ok(Array::create_array_from_list(parts, context).into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRange ( startDate, endDate )`][spec].
+    ///
+    /// Returns a language-specific string representing the range between two dates.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let parts = Self::format_range_impl("formatRange", this, args, context)?;
+
+        // 6. Return ? FormatDateTimeRange(dtf, x, y).
+        Ok(js_string!(parts
+            .into_iter()
+            .map(|(_, value, _)| value)
+            .collect::<String>())
+        .into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRangeToParts ( startDate, endDate )`][spec].
+    ///
+    /// Returns an array of objects representing the range between two dates.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatRangeToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let parts = Self::format_range_impl("formatRangeToParts", this, args, context)?;
+
+        // 6. Return ? FormatDateTimeRangeToParts(dtf, x, y).
+        let parts = parts
+            .into_iter()
+            .map(|(typ, value, source)| {
+                let mut part = ObjectInitializer::new(context);
+                part.property(js_string!("type"), js_string!(typ), Attribute::all())
+                    .property(js_string!("value"), js_string!(value), Attribute::all())
+                    .property(js_string!("source"), js_string!(source), Attribute::all());
+                part.build().into()
+            })
+            .collect::<Vec<JsValue>>();
+
+        Ok(Array::create_array_from_list(parts, context).into())
+    }
+
+    /// Common steps of `formatRange` and `formatRangeToParts`.
+    fn format_range_impl(
+        method: &str,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<Vec<(&'static str, String, &'static str)>> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, method)?;
+
+        let start = args.get_or_undefined(0);
+        let end = args.get_or_undefined(1);
+
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        if start.is_undefined() || end.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "`{method}` requires both `startDate` and `endDate` values"
+                ))
+                .into());
+        }
+
+        // 4. Let x be ? ToNumber(startDate).
+        let x = start.to_number(context)?;
+
+        // 5. Let y be ? ToNumber(endDate).
+        let y = end.to_number(context)?;
+
+        let dtf = dtf.borrow();
+        dtf.data
+            .partition_date_time_range_pattern(x, y, context.host_hooks())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, "resolvedOptions")?;
+        let dtf = dtf.borrow();
+        let dtf = &dtf.data;
+
+        let hooks = context.host_hooks();
+        let local_offset = local_timezone_offset_seconds(hooks.utc_now() as f64, hooks);
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 8, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. If p is "hour12", then
+        //         i. Let hc be dtf.[[HourCycle]].
+        //         ii. If hc is "h11" or "h12", let v be true.
+        //         iii. Else if, hc is "h23" or "h24", let v be false.
+        //         iv. Else, let v be undefined.
+        //     c. Else,
+        //         i. Let v be the value of dtf's internal slot whose name is the Internal Slot value of the current row.
+        //     d. If the Internal Slot value of the current row is an Internal Slot value in Table 7, then
+        //         i. If dtf.[[DateStyle]] is not undefined or dtf.[[TimeStyle]] is not undefined, then
+        //             1. Let v be undefined.
+        //     e. If v is not undefined, then
+        //         i. If there is a Conversion value in the current row, let v be 𝔽(v).
+        //         ii. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(dtf.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("calendar"),
+                js_string!(dtf.calendar.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numberingSystem"),
+                js_string!(dtf.numbering_system.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("timeZone"),
+                dtf.time_zone.to_js_string(local_offset),
+                Attribute::all(),
+            );
+
+        if let Some(hc) = dtf.hour_cycle {
+            options
+                .property(
+                    js_string!("hourCycle"),
+                    hour_cycle_to_js_string(hc),
+                    Attribute::all(),
+                )
+                .property(
+                    js_string!("hour12"),
+                    matches!(hc, HourCycle::H11 | HourCycle::H12),
+                    Attribute::all(),
+                );
+        }
+
+        if dtf.date_style.is_none() && dtf.time_style.is_none() {
+            let components = &dtf.components;
+            let text = [
+                ("weekday", components.weekday.map(Text::to_js_string)),
+                ("era", components.era.map(Text::to_js_string)),
+                ("year", components.year.map(Numeric::to_js_string)),
+                ("month", components.month.map(Month::to_js_string)),
+                ("day", components.day.map(Numeric::to_js_string)),
+                ("dayPeriod", components.day_period.map(Text::to_js_string)),
+                ("hour", components.hour.map(Numeric::to_js_string)),
+                ("minute", components.minute.map(Numeric::to_js_string)),
+                ("second", components.second.map(Numeric::to_js_string)),
+            ];
+            for (property, value) in text {
+                if let Some(value) = value {
+                    options.property(js_string!(property), value, Attribute::all());
+                }
+            }
+
+            if let Some(digits) = components.fractional_second_digits {
+                options.property(
+                    js_string!("fractionalSecondDigits"),
+                    digits,
+                    Attribute::all(),
+                );
+            }
+
+            if let Some(name) = components.time_zone_name {
+                options.property(
+                    js_string!("timeZoneName"),
+                    name.to_js_string(),
+                    Attribute::all(),
+                );
+            }
+        }
+
+        if let Some(style) = dtf.date_style {
+            options.property(
+                js_string!("dateStyle"),
+                style.to_js_string(),
+                Attribute::all(),
+            );
+        }
+
+        if let Some(style) = dtf.time_style {
+            options.property(
+                js_string!("timeStyle"),
+                style.to_js_string(),
+                Attribute::all(),
+            );
+        }
+
+        // 6. Return options.
+        Ok(options.build().into())
+    }
+}
+
+/// Checks that `dtf` is an `Intl.DateTimeFormat` object, returning it downcasted.
+fn unwrap_date_time_format(dtf: &JsValue, method: &str) -> JsResult<JsObject<DateTimeFormat>> {
+    dtf.as_object()
+        .and_then(|o| o.clone().downcast::<DateTimeFormat>().ok())
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "`{method}` can only be called on an `Intl.DateTimeFormat` object"
+                ))
+                .into()
+        })
+}
+
+/// Represents the `required` and `defaults` arguments in the abstract operation
+/// `toDateTimeOptions`.
+///
+/// Since `required` and `defaults` differ only in the `any` and `all` variants,
+/// we combine both in a single variant `AnyAll`.
+#[derive(Debug, PartialEq)]
+pub(crate) enum DateTimeReqs {
+    Date,
+    Time,
+    AnyAll,
+}
+
+/// The abstract operation `toDateTimeOptions` is called with arguments `options`, `required` and
+/// `defaults`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-todatetimeoptions
+pub(crate) fn to_date_time_options(
+    options: &JsValue,
+    required: &DateTimeReqs,
+    defaults: &DateTimeReqs,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If options is undefined, let options be null;
+    // otherwise let options be ? ToObject(options).
+    // 2. Let options be ! OrdinaryObjectCreate(options).
+    let options = if options.is_undefined() {
+        None
+    } else {
+        Some(options.to_object(context)?)
+    };
+    let options = JsObject::from_proto_and_data_with_shared_shape(
+        context.root_shape(),
+        options,
+        OrdinaryObject,
+    );
+
+    // 3. Let needDefaults be true.
+    let mut need_defaults = true;
+
+    // 4. If required is "date" or "any", then
+    if [DateTimeReqs::Date, DateTimeReqs::AnyAll].contains(required) {
+        // a. For each property name prop of « "weekday", "year", "month", "day" », do
+        for property in [
+            utf16!("weekday"),
+            utf16!("year"),
+            utf16!("month"),
+            utf16!("day"),
+        ] {
+            // i. Let value be ? Get(options, prop).
+            let value = options.get(property, context)?;
+
+            // ii. If value is not undefined, let needDefaults be false.
+            if !value.is_undefined() {
+                need_defaults = false;
+            }
+        }
+    }
+
+    // 5. If required is "time" or "any", then
+    if [DateTimeReqs::Time, DateTimeReqs::AnyAll].contains(required) {
+        // a. For each property name prop of « "dayPeriod", "hour", "minute", "second",
+        // "fractionalSecondDigits" », do
+        for property in [
+            utf16!("dayPeriod"),
+            utf16!("hour"),
+            utf16!("minute"),
+            utf16!("second"),
+            utf16!("fractionalSecondDigits"),
+        ] {
+            // i. Let value be ? Get(options, prop).
+            let value = options.get(property, context)?;
+
+            // ii. If value is not undefined, let needDefaults be false.
+            if !value.is_undefined() {
+                need_defaults = false;
+            }
+        }
+    }
+
+    // 6. Let dateStyle be ? Get(options, "dateStyle").
+    let date_style = options.get(utf16!("dateStyle"), context)?;
+
+    // 7. Let timeStyle be ? Get(options, "timeStyle").
+    let time_style = options.get(utf16!("timeStyle"), context)?;
+
+    // 8. If dateStyle is not undefined or timeStyle is not undefined, let needDefaults be false.
+    if !date_style.is_undefined() || !time_style.is_undefined() {
+        need_defaults = false;
+    }
+
+    // 9. If required is "date" and timeStyle is not undefined, then
+    if required == &DateTimeReqs::Date && !time_style.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("'date' is required, but timeStyle was defined")
+            .into());
+    }
+
+    // 10. If required is "time" and dateStyle is not undefined, then
+    if required == &DateTimeReqs::Time && !date_style.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("'time' is required, but dateStyle was defined")
+            .into());
+    }
+
+    // 11. If needDefaults is true and defaults is either "date" or "all", then
+    if need_defaults && [DateTimeReqs::Date, DateTimeReqs::AnyAll].contains(defaults) {
+        // a. For each property name prop of « "year", "month", "day" », do
+        for property in [utf16!("year"), utf16!("month"), utf16!("day")] {
+            // i. Perform ? CreateDataPropertyOrThrow(options, prop, "numeric").
+            options.create_data_property_or_throw(property, js_string!("numeric"), context)?;
+        }
+    }
+
+    // 12. If needDefaults is true and defaults is either "time" or "all", then
+    if need_defaults && [DateTimeReqs::Time, DateTimeReqs::AnyAll].contains(defaults) {
+        // a. For each property name prop of « "hour", "minute", "second" », do
+        for property in [utf16!("hour"), utf16!("minute"), utf16!("second")] {
+            // i. Perform ? CreateDataPropertyOrThrow(options, prop, "numeric").
+            options.create_data_property_or_throw(property, js_string!("numeric"), context)?;
+        }
+    }
+
+    // 13. Return options.
+    Ok(options)
+}
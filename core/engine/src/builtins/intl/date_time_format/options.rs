@@ -0,0 +1,570 @@
+use std::{fmt::Display, str::FromStr};
+
+use icu_datetime::options::{components, length, preferences::HourCycle};
+
+use crate::{
+    builtins::{
+        intl::options::get_number_option,
+        options::{get_option, OptionType, ParsableOptionType},
+    },
+    js_string,
+    string::utf16,
+    Context, JsNativeError, JsObject, JsResult, JsString, JsValue,
+};
+
+impl OptionType for HourCycle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "h11" => Ok(Self::H11),
+            "h12" => Ok(Self::H12),
+            "h23" => Ok(Self::H23),
+            "h24" => Ok(Self::H24),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `h11`, `h12`, `h23` or `h24`")
+                .into()),
+        }
+    }
+}
+
+pub(crate) fn hour_cycle_to_js_string(hc: HourCycle) -> JsString {
+    match hc {
+        HourCycle::H11 => js_string!("h11"),
+        HourCycle::H12 => js_string!("h12"),
+        HourCycle::H23 => js_string!("h23"),
+        HourCycle::H24 => js_string!("h24"),
+    }
+}
+
+/// The `weekday`, `era` and `dayPeriod` options of `Intl.DateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Text {
+    Narrow,
+    Short,
+    Long,
+}
+
+impl Text {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Narrow => js_string!("narrow"),
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+        }
+    }
+
+    const fn to_native(self) -> components::Text {
+        match self {
+            Self::Narrow => components::Text::Narrow,
+            Self::Short => components::Text::Short,
+            Self::Long => components::Text::Long,
+        }
+    }
+
+    fn from_native(text: components::Text) -> Self {
+        match text {
+            components::Text::Narrow => Self::Narrow,
+            components::Text::Short => Self::Short,
+            _ => Self::Long,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTextError;
+
+impl Display for ParseTextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for Text {
+    type Err = ParseTextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseTextError),
+        }
+    }
+}
+
+impl ParsableOptionType for Text {}
+
+/// The `year`, `day`, `hour`, `minute` and `second` options of `Intl.DateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Numeric {
+    Numeric,
+    TwoDigit,
+}
+
+impl Numeric {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+        }
+    }
+
+    const fn to_native(self) -> components::Numeric {
+        match self {
+            Self::Numeric => components::Numeric::Numeric,
+            Self::TwoDigit => components::Numeric::TwoDigit,
+        }
+    }
+
+    fn from_native(numeric: components::Numeric) -> Self {
+        match numeric {
+            components::Numeric::TwoDigit => Self::TwoDigit,
+            _ => Self::Numeric,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseNumericError;
+
+impl Display for ParseNumericError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `numeric` or `2-digit`")
+    }
+}
+
+impl FromStr for Numeric {
+    type Err = ParseNumericError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseNumericError),
+        }
+    }
+}
+
+impl ParsableOptionType for Numeric {}
+
+/// The `month` option of `Intl.DateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Month {
+    Numeric,
+    TwoDigit,
+    Narrow,
+    Short,
+    Long,
+}
+
+impl Month {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+        }
+    }
+
+    pub(crate) const fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit)
+    }
+
+    const fn to_native(self) -> components::Month {
+        match self {
+            Self::Numeric => components::Month::Numeric,
+            Self::TwoDigit => components::Month::TwoDigit,
+            Self::Narrow => components::Month::Narrow,
+            Self::Short => components::Month::Short,
+            Self::Long => components::Month::Long,
+        }
+    }
+
+    fn from_native(month: components::Month) -> Self {
+        match month {
+            components::Month::Numeric => Self::Numeric,
+            components::Month::TwoDigit => Self::TwoDigit,
+            components::Month::Narrow => Self::Narrow,
+            components::Month::Short => Self::Short,
+            _ => Self::Long,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseMonthError;
+
+impl Display for ParseMonthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `numeric`, `2-digit`, `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for Month {
+    type Err = ParseMonthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseMonthError),
+        }
+    }
+}
+
+impl ParsableOptionType for Month {}
+
+/// The `timeZoneName` option of `Intl.DateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeZoneName {
+    Short,
+    Long,
+    ShortOffset,
+    LongOffset,
+    ShortGeneric,
+    LongGeneric,
+}
+
+impl TimeZoneName {
+    /// Formats the name of a time zone with the provided offset from UTC.
+    ///
+    /// ICU4X's `DateTimeFormatter` cannot format time zones, so this uses the root locale
+    /// `GMT` formats for all locales.
+    pub(crate) fn format(self, time_zone: TimeZone, offset_seconds: i32) -> String {
+        let long = matches!(self, Self::Long | Self::LongOffset | Self::LongGeneric);
+        if time_zone == TimeZone::Utc && !matches!(self, Self::ShortOffset | Self::LongOffset) {
+            return if long {
+                "Coordinated Universal Time".to_owned()
+            } else {
+                "UTC".to_owned()
+            };
+        }
+
+        let minutes = offset_seconds / 60;
+        if minutes == 0 {
+            return "GMT".to_owned();
+        }
+        let sign = if minutes < 0 { '-' } else { '+' };
+        let (hours, minutes) = (minutes.abs() / 60, minutes.abs() % 60);
+        if long {
+            format!("GMT{sign}{hours:02}:{minutes:02}")
+        } else if minutes == 0 {
+            format!("GMT{sign}{hours}")
+        } else {
+            format!("GMT{sign}{hours}:{minutes:02}")
+        }
+    }
+
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+            Self::ShortOffset => js_string!("shortOffset"),
+            Self::LongOffset => js_string!("longOffset"),
+            Self::ShortGeneric => js_string!("shortGeneric"),
+            Self::LongGeneric => js_string!("longGeneric"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTimeZoneNameError;
+
+impl Display for ParseTimeZoneNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "provided string was not `short`, `long`, `shortOffset`, `longOffset`, \
+             `shortGeneric` or `longGeneric`",
+        )
+    }
+}
+
+impl FromStr for TimeZoneName {
+    type Err = ParseTimeZoneNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            "shortOffset" => Ok(Self::ShortOffset),
+            "longOffset" => Ok(Self::LongOffset),
+            "shortGeneric" => Ok(Self::ShortGeneric),
+            "longGeneric" => Ok(Self::LongGeneric),
+            _ => Err(ParseTimeZoneNameError),
+        }
+    }
+}
+
+impl ParsableOptionType for TimeZoneName {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum FormatMatcher {
+    Basic,
+    #[default]
+    BestFit,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseFormatMatcherError;
+
+impl Display for ParseFormatMatcherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `basic` or `best fit`")
+    }
+}
+
+impl FromStr for FormatMatcher {
+    type Err = ParseFormatMatcherError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "basic" => Ok(Self::Basic),
+            "best fit" => Ok(Self::BestFit),
+            _ => Err(ParseFormatMatcherError),
+        }
+    }
+}
+
+impl ParsableOptionType for FormatMatcher {}
+
+/// The `dateStyle` and `timeStyle` options of `Intl.DateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Style {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl Style {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Full => js_string!("full"),
+            Self::Long => js_string!("long"),
+            Self::Medium => js_string!("medium"),
+            Self::Short => js_string!("short"),
+        }
+    }
+
+    pub(crate) const fn to_date(self) -> length::Date {
+        match self {
+            Self::Full => length::Date::Full,
+            Self::Long => length::Date::Long,
+            Self::Medium => length::Date::Medium,
+            Self::Short => length::Date::Short,
+        }
+    }
+
+    pub(crate) const fn to_time(self) -> length::Time {
+        match self {
+            Self::Full => length::Time::Full,
+            Self::Long => length::Time::Long,
+            Self::Medium => length::Time::Medium,
+            Self::Short => length::Time::Short,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseStyleError;
+
+impl Display for ParseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `full`, `long`, `medium` or `short`")
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(Self::Full),
+            "long" => Ok(Self::Long),
+            "medium" => Ok(Self::Medium),
+            "short" => Ok(Self::Short),
+            _ => Err(ParseStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for Style {}
+
+/// The date-time format components of an `Intl.DateTimeFormat` (Table 7 of ECMA-402).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct FormatComponents {
+    pub(crate) weekday: Option<Text>,
+    pub(crate) era: Option<Text>,
+    pub(crate) year: Option<Numeric>,
+    pub(crate) month: Option<Month>,
+    pub(crate) day: Option<Numeric>,
+    pub(crate) day_period: Option<Text>,
+    pub(crate) hour: Option<Numeric>,
+    pub(crate) minute: Option<Numeric>,
+    pub(crate) second: Option<Numeric>,
+    pub(crate) fractional_second_digits: Option<u8>,
+    pub(crate) time_zone_name: Option<TimeZoneName>,
+}
+
+impl FormatComponents {
+    /// Reads the format components from `options`, in the order mandated by
+    /// [`CreateDateTimeFormat`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+    pub(crate) fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            weekday: get_option(options, utf16!("weekday"), context)?,
+            era: get_option(options, utf16!("era"), context)?,
+            year: get_option(options, utf16!("year"), context)?,
+            month: get_option(options, utf16!("month"), context)?,
+            day: get_option(options, utf16!("day"), context)?,
+            day_period: get_option(options, utf16!("dayPeriod"), context)?,
+            hour: get_option(options, utf16!("hour"), context)?,
+            minute: get_option(options, utf16!("minute"), context)?,
+            second: get_option(options, utf16!("second"), context)?,
+            fractional_second_digits: get_number_option(
+                options,
+                utf16!("fractionalSecondDigits"),
+                1,
+                3,
+                context,
+            )?,
+            time_zone_name: get_option(options, utf16!("timeZoneName"), context)?,
+        })
+    }
+
+    /// Returns `true` if any of the components was provided.
+    pub(crate) fn has_any(&self) -> bool {
+        *self != Self::default()
+    }
+
+    /// Converts the components into an ICU4X components bag.
+    ///
+    /// ICU4X doesn't support flexible day periods nor time zones in `DateTimeFormatter`, so
+    /// `dayPeriod` and `timeZoneName` are not part of the returned bag. Its skeleton matcher
+    /// also adds the minutes to patterns with fractional seconds and ignores their number of
+    /// digits, so `fractionalSecondDigits` is left out as well.
+    pub(crate) fn to_native(self, hour_cycle: Option<HourCycle>) -> components::Bag {
+        let mut bag = components::Bag::default();
+        bag.weekday = self.weekday.map(Text::to_native);
+        bag.era = self.era.map(Text::to_native);
+        bag.year = self.year.map(|year| match year {
+            Numeric::Numeric => components::Year::Numeric,
+            Numeric::TwoDigit => components::Year::TwoDigit,
+        });
+        bag.month = self.month.map(Month::to_native);
+        bag.day = self.day.map(|day| match day {
+            Numeric::Numeric => components::Day::NumericDayOfMonth,
+            Numeric::TwoDigit => components::Day::TwoDigitDayOfMonth,
+        });
+        bag.hour = self.hour.map(Numeric::to_native);
+        bag.minute = self.minute.map(Numeric::to_native);
+        bag.second = self.second.map(Numeric::to_native);
+        bag.preferences = hour_cycle.map(icu_datetime::options::preferences::Bag::from_hour_cycle);
+        bag
+    }
+
+    /// Gets the components that were actually selected by the ICU4X skeleton matcher.
+    ///
+    /// `dayPeriod`, `fractionalSecondDigits` and `timeZoneName` are kept from `self`, since
+    /// those are handled by `DateTimeFormat` itself.
+    pub(crate) fn resolve(self, bag: &components::Bag) -> Self {
+        Self {
+            weekday: bag.weekday.map(Text::from_native),
+            era: bag.era.map(Text::from_native),
+            year: bag.year.map(|year| match year {
+                components::Year::TwoDigit | components::Year::TwoDigitWeekOf => Numeric::TwoDigit,
+                _ => Numeric::Numeric,
+            }),
+            month: bag.month.map(Month::from_native),
+            day: bag.day.map(|day| match day {
+                components::Day::TwoDigitDayOfMonth => Numeric::TwoDigit,
+                _ => Numeric::Numeric,
+            }),
+            day_period: self.day_period,
+            hour: bag.hour.map(Numeric::from_native),
+            minute: bag.minute.map(Numeric::from_native),
+            second: bag.second.map(Numeric::from_native),
+            fractional_second_digits: self.fractional_second_digits,
+            time_zone_name: self.time_zone_name,
+        }
+    }
+}
+
+/// The time zone of an `Intl.DateTimeFormat`.
+///
+/// ICU4X doesn't ship the IANA time zone database yet, so only the host time zone, UTC and
+/// fixed offsets are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeZone {
+    /// The time zone of the host, as reported by [`HostHooks::local_timezone_offset_seconds`].
+    ///
+    /// [`HostHooks::local_timezone_offset_seconds`]: crate::context::HostHooks::local_timezone_offset_seconds
+    Local,
+    Utc,
+    /// A fixed offset from UTC, in minutes.
+    Offset(i16),
+}
+
+impl TimeZone {
+    /// Abstract operations [`IsTimeZoneOffsetString ( offsetString )`][offset] and
+    /// [`IsValidTimeZoneName ( timeZone )`][valid].
+    ///
+    /// Returns `None` if `time_zone` is not a supported time zone identifier.
+    ///
+    /// [offset]: https://tc39.es/ecma402/#sec-istimezoneoffsetstring
+    /// [valid]: https://tc39.es/ecma402/#sec-isvalidtimezonename
+    pub(crate) fn parse(time_zone: &str) -> Option<Self> {
+        if ["utc", "etc/utc", "gmt", "etc/gmt"]
+            .iter()
+            .any(|utc| time_zone.eq_ignore_ascii_case(utc))
+        {
+            return Some(Self::Utc);
+        }
+
+        let (sign, rest) = match time_zone.as_bytes().first()? {
+            b'+' => (1, &time_zone[1..]),
+            b'-' => (-1, &time_zone[1..]),
+            _ => return None,
+        };
+        let (hours, minutes) = match rest.len() {
+            2 => (rest, "00"),
+            4 => rest.split_at(2),
+            5 if rest.as_bytes()[2] == b':' => (&rest[..2], &rest[3..]),
+            _ => return None,
+        };
+        if !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let hours: i16 = hours.parse().ok()?;
+        let minutes: i16 = minutes.parse().ok()?;
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+
+        Some(Self::Offset(sign * (hours * 60 + minutes)))
+    }
+
+    /// Gets the canonical name of the time zone, as reported by `resolvedOptions`.
+    ///
+    /// The host time zone has no IANA name available, so it is reported as its current offset.
+    pub(crate) fn to_js_string(self, local_offset_seconds: i32) -> JsString {
+        let minutes = match self {
+            Self::Utc => return js_string!("UTC"),
+            Self::Local if local_offset_seconds == 0 => return js_string!("UTC"),
+            Self::Local => local_offset_seconds / 60,
+            Self::Offset(minutes) => i32::from(minutes),
+        };
+        let sign = if minutes < 0 { '-' } else { '+' };
+        let minutes = minutes.abs();
+        js_string!(format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60))
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn date_and_time_style() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var dtf = new Intl.DateTimeFormat('en', {
+                dateStyle: 'full',
+                timeStyle: 'short',
+                timeZone: 'UTC',
+            });
+        "#}),
+        TestAction::assert_eq(
+            "dtf.format(Date.UTC(2024, 0, 1, 10, 30))",
+            js_string!("Monday, January 1, 2024, 10:30\u{202f}AM"),
+        ),
+        TestAction::assert_eq(
+            "Object.keys(dtf.resolvedOptions()).slice(4).join()",
+            js_string!("hourCycle,hour12,dateStyle,timeStyle"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en', { dateStyle: 'full', hour: 'numeric' })",
+            JsNativeErrorKind::Type,
+            "`dateStyle` and `timeStyle` cannot be combined with explicit date-time components",
+        ),
+    ]);
+}
+
+#[test]
+fn format_range_same_day() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var dtf = new Intl.DateTimeFormat('en', {
+                month: 'short',
+                day: 'numeric',
+                hour: 'numeric',
+                minute: 'numeric',
+                timeZone: 'UTC',
+            });
+            var start = Date.UTC(2024, 0, 1, 10);
+            var end = Date.UTC(2024, 0, 1, 11);
+        "#}),
+        TestAction::assert_eq(
+            "dtf.formatRange(start, end)",
+            js_string!("Jan 1, 10:00\u{202f}AM – 11:00\u{202f}AM"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                dtf.formatRangeToParts(start, end)
+                    .filter(p => p.type !== 'literal')
+                    .map(p => `${p.type}:${p.source}`)
+                    .join()
+            "#},
+            js_string!(
+                "month:shared,day:shared,\
+                 hour:startRange,minute:startRange,dayPeriod:startRange,\
+                 hour:endRange,minute:endRange,dayPeriod:endRange"
+            ),
+        ),
+        TestAction::assert_eq(
+            "dtf.formatRange(start, start)",
+            js_string!("Jan 1, 10:00\u{202f}AM"),
+        ),
+        TestAction::assert_native_error(
+            "dtf.formatRange(start)",
+            JsNativeErrorKind::Type,
+            "`formatRange` requires both `startDate` and `endDate` values",
+        ),
+    ]);
+}
+
+#[test]
+fn fractional_second_digits() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var dtf = new Intl.DateTimeFormat('en', {
+                minute: '2-digit',
+                second: '2-digit',
+                fractionalSecondDigits: 3,
+                timeZone: 'UTC',
+            });
+        "#}),
+        TestAction::assert_eq(
+            "dtf.format(Date.UTC(2024, 0, 1, 0, 1, 2, 123))",
+            js_string!("01:02.123"),
+        ),
+        TestAction::assert_eq("dtf.resolvedOptions().fractionalSecondDigits", 3),
+        TestAction::run(indoc! {r#"
+            var seconds = new Intl.DateTimeFormat('en', {
+                second: 'numeric',
+                fractionalSecondDigits: 3,
+                timeZone: 'UTC',
+            });
+        "#}),
+        TestAction::assert_eq(
+            "seconds.format(Date.UTC(2024, 0, 1, 0, 5, 6, 789))",
+            js_string!("6.789"),
+        ),
+        TestAction::assert("!('minute' in seconds.resolvedOptions())"),
+        TestAction::assert_eq(
+            indoc! {r#"
+                new Intl.DateTimeFormat('en', {
+                    second: 'numeric',
+                    fractionalSecondDigits: 1,
+                    timeZone: 'UTC',
+                }).format(Date.UTC(2024, 0, 1, 0, 5, 6, 789))
+            "#},
+            js_string!("6.7"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en', { fractionalSecondDigits: 4 })",
+            JsNativeErrorKind::Range,
+            "DefaultNumberOption: value is out of range.",
+        ),
+    ]);
+}
+
+#[test]
+fn hour12_overrides_hour_cycle() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var options = new Intl.DateTimeFormat('en', {
+                hour: 'numeric',
+                hour12: false,
+                hourCycle: 'h11',
+            }).resolvedOptions();
+        "#}),
+        TestAction::assert_eq("options.hourCycle", js_string!("h23")),
+        TestAction::assert_eq("options.hour12", false),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en-u-hc-h23', { hour: 'numeric', hour12: true }).resolvedOptions().locale",
+            js_string!("en"),
+        ),
+    ]);
+}
@@ -0,0 +1,505 @@
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_displaynames::{
+    provider::RegionDisplayNamesV1Marker, DisplayNamesOptions, LanguageDisplayNames,
+    LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames,
+};
+use icu_locid::{
+    subtags::{Region, Script},
+    LanguageIdentifier, Locale,
+};
+use icu_provider::DataLocale;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::{common::StaticJsStrings, utf16},
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales},
+    number_format::Currency,
+    options::IntlOptions,
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The ICU4X data backing a `DisplayNames` object.
+///
+/// ICU4X doesn't provide display names for currencies, calendars or date-time fields yet, so
+/// those types always resolve to the configured fallback.
+enum NativeDisplayNames {
+    Language {
+        names: LanguageDisplayNames,
+        formatter: Box<LocaleDisplayNamesFormatter>,
+    },
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    Unsupported,
+}
+
+impl std::fmt::Debug for NativeDisplayNames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            Self::Language { .. } => "Language",
+            Self::Region(_) => "Region",
+            Self::Script(_) => "Script",
+            Self::Unsupported => "Unsupported",
+        };
+        f.debug_struct("NativeDisplayNames")
+            .field("kind", &kind)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DisplayNames` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DisplayNames {
+    locale: Locale,
+    style: Style,
+    typ: DisplayNamesType,
+    fallback: Fallback,
+    language_display: LanguageDisplay,
+    native: NativeDisplayNames,
+}
+
+impl Service for DisplayNames {
+    type LangMarker = RegionDisplayNamesV1Marker;
+
+    type LocaleOptions = ();
+}
+
+impl IntrinsicObject for DisplayNames {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DisplayNames"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::of, js_string!("of"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisplayNames {
+    const NAME: JsString = StaticJsStrings::DISPLAY_NAMES;
+}
+
+impl BuiltInConstructor for DisplayNames {
+    const LENGTH: usize = 2;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::display_names;
+
+    /// Constructor [`Intl.DisplayNames ( locales, options )`][spec].
+    ///
+    /// Constructor for `DisplayNames` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DisplayNames` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. If options is undefined, throw a TypeError exception.
+        if options.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`Intl.DisplayNames` requires an options object")
+                .into());
+        }
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 6. Let opt be a new Record.
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = get_option(&options, utf16!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 8. Set opt.[[localeMatcher]] to matcher.
+        // 9. Let localeData be %DisplayNames%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%DisplayNames%.[[AvailableLocales]], requestedLocales, opt, %DisplayNames%.[[RelevantExtensionKeys]]).
+        let locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut IntlOptions {
+                matcher,
+                ..Default::default()
+            },
+            context.intl_provider(),
+        );
+
+        // 11. Let style be ? GetOption(options, "style", string, « "narrow", "short", "long" », "long").
+        // 12. Set displayNames.[[Style]] to style.
+        let style: Style = get_option(&options, utf16!("style"), context)?.unwrap_or_default();
+
+        // 13. Let type be ? GetOption(options, "type", string, « "language", "region", "script", "currency", "calendar", "dateTimeField" », undefined).
+        // 14. If type is undefined, throw a TypeError exception.
+        // 15. Set displayNames.[[Type]] to type.
+        let Some(typ) = get_option::<DisplayNamesType>(&options, utf16!("type"), context)? else {
+            return Err(JsNativeError::typ()
+                .with_message("`type` option is required by `Intl.DisplayNames`")
+                .into());
+        };
+
+        // 16. Let fallback be ? GetOption(options, "fallback", string, « "code", "none" », "code").
+        // 17. Set displayNames.[[Fallback]] to fallback.
+        let fallback: Fallback =
+            get_option(&options, utf16!("fallback"), context)?.unwrap_or_default();
+
+        // 18. Set displayNames.[[Locale]] to r.[[locale]].
+        // 19. Let dataLocale be r.[[dataLocale]].
+        // 20. Let dataLocaleData be localeData.[[<dataLocale>]].
+        // 21. Let types be dataLocaleData.[[types]].
+        // 22. Assert: types is a Record (see 12.4.3).
+        // 23. Let languageDisplay be ? GetOption(options, "languageDisplay", string, « "dialect", "standard" », "dialect").
+        let language_display: LanguageDisplay =
+            get_option(&options, utf16!("languageDisplay"), context)?.unwrap_or_default();
+
+        // 24. Let typeFields be types.[[<type>]].
+        // 25. Assert: typeFields is a Record (see 12.4.3).
+        // 26. If type is "language", then
+        //     a. Set displayNames.[[LanguageDisplay]] to languageDisplay.
+        //     b. Let styleFields be typeFields.[[<languageDisplay>]].
+        //     c. Assert: styleFields is a Record (see 12.4.3).
+        // 27. Else,
+        //     a. Let styleFields be typeFields.
+        // 28. Let fields be styleFields.[[<style>]].
+        // 29. Assert: fields is a Record (see 12.4.3).
+        // 30. Set displayNames.[[Fields]] to fields.
+        let data_locale = DataLocale::from(&locale);
+        let mut native_options = DisplayNamesOptions::default();
+        native_options.style = Some(style.to_native());
+        native_options.language_display = language_display.to_native();
+        let provider = context.intl_provider();
+        let native = match typ {
+            DisplayNamesType::Language => LanguageDisplayNames::try_new_unstable(
+                provider,
+                &data_locale,
+                native_options.clone(),
+            )
+            .and_then(|names| {
+                LocaleDisplayNamesFormatter::try_new_unstable(
+                    provider,
+                    &data_locale,
+                    native_options,
+                )
+                .map(|formatter| NativeDisplayNames::Language {
+                    names,
+                    formatter: Box::new(formatter),
+                })
+            }),
+            DisplayNamesType::Region => {
+                RegionDisplayNames::try_new_unstable(provider, &data_locale, native_options)
+                    .map(NativeDisplayNames::Region)
+            }
+            DisplayNamesType::Script => {
+                ScriptDisplayNames::try_new_unstable(provider, &data_locale, native_options)
+                    .map(NativeDisplayNames::Script)
+            }
+            DisplayNamesType::Currency
+            | DisplayNamesType::Calendar
+            | DisplayNamesType::DateTimeField => Ok(NativeDisplayNames::Unsupported),
+        }
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%DisplayNames.prototype%", « [[InitializedDisplayNames]], [[Locale]], [[Style]], [[Type]], [[Fallback]], [[LanguageDisplay]], [[Fields]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::display_names,
+            context,
+        )?;
+        let display_names = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                style,
+                typ,
+                fallback,
+                language_display,
+                native,
+            },
+        );
+
+        // 31. Return displayNames.
+        Ok(display_names.into())
+    }
+}
+
+impl DisplayNames {
+    /// [`Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in display
+    /// names without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DisplayNames.prototype.of ( code )`][spec].
+    ///
+    /// Returns the localized display name of the provided code.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message("`of` can only be called on a `DisplayNames` object")
+        })?;
+        let display_names = display_names.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message("`of` can only be called on a `DisplayNames` object")
+        })?;
+
+        // 3. Let code be ? ToString(code).
+        let code = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        let (code, name) = match (display_names.typ, &display_names.native) {
+            (DisplayNamesType::Language, NativeDisplayNames::Language { names, formatter }) => {
+                let mut locale = Locale::from(canonical_language_code(&code)?);
+                context
+                    .intl_provider()
+                    .locale_canonicalizer()
+                    .canonicalize(&mut locale);
+                let name = names
+                    .of(locale.id.language)
+                    .is_some()
+                    .then(|| formatter.of(&locale).into_owned());
+                (locale.to_string(), name)
+            }
+            (DisplayNamesType::Region, NativeDisplayNames::Region(names)) => {
+                let region = Region::try_from_bytes(code.as_bytes()).map_err(|_| {
+                    JsNativeError::range().with_message(format!("invalid region code `{code}`"))
+                })?;
+                (region.to_string(), names.of(region).map(str::to_owned))
+            }
+            (DisplayNamesType::Script, NativeDisplayNames::Script(names)) => {
+                let script = Script::try_from_bytes(code.as_bytes()).map_err(|_| {
+                    JsNativeError::range().with_message(format!("invalid script code `{code}`"))
+                })?;
+                (script.to_string(), names.of(script).map(str::to_owned))
+            }
+            (DisplayNamesType::Currency, _) => {
+                let currency = code.parse::<Currency>().map_err(|_| {
+                    JsNativeError::range().with_message(format!("invalid currency code `{code}`"))
+                })?;
+                (currency.to_js_string().to_std_string_escaped(), None)
+            }
+            (DisplayNamesType::Calendar, _) => (canonical_calendar_code(&code)?, None),
+            (DisplayNamesType::DateTimeField, _) => (canonical_date_time_field(&code)?, None),
+            _ => unreachable!("the native data always matches the display names type"),
+        };
+
+        if let Some(name) = name {
+            return Ok(js_string!(name).into());
+        }
+
+        // 7. If displayNames.[[Fallback]] is "code", return code.
+        // 8. Return undefined.
+        match display_names.fallback {
+            Fallback::Code => Ok(js_string!(code).into()),
+            Fallback::None => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// [`Intl.DisplayNames.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DisplayNames` object")
+        })?;
+        let display_names = display_names.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DisplayNames` object")
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 16, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut properties = vec![
+            (
+                js_string!("locale"),
+                js_string!(display_names.locale.to_string()),
+            ),
+            (js_string!("style"), display_names.style.to_js_string()),
+            (js_string!("type"), display_names.typ.to_js_string()),
+            (
+                js_string!("fallback"),
+                display_names.fallback.to_js_string(),
+            ),
+        ];
+        if display_names.typ == DisplayNamesType::Language {
+            properties.push((
+                js_string!("languageDisplay"),
+                display_names.language_display.to_js_string(),
+            ));
+        }
+        for (key, value) in properties {
+            options
+                .create_data_property_or_throw(key, value, context)
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Validates a language code according to step 1 of
+/// [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_language_code(code: &str) -> JsResult<LanguageIdentifier> {
+    // a. If code cannot be matched by the unicode_language_id Unicode locale nonterminal, throw a RangeError exception.
+    // b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+    code.parse::<LanguageIdentifier>().map_err(|_| {
+        JsNativeError::range()
+            .with_message(format!("invalid language code `{code}`"))
+            .into()
+    })
+}
+
+/// Validates and canonicalizes a calendar code according to step 5 of
+/// [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_calendar_code(code: &str) -> JsResult<String> {
+    // a. If code cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+    // b. If code uses any of the backwards compatibility syntax described in Unicode Technical
+    //    Standard #35 LDML § 3.3 BCP 47 Conformance, throw a RangeError exception.
+    let is_type = !code.is_empty()
+        && code.split('-').all(|subtag| {
+            (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+        });
+    if !is_type {
+        return Err(JsNativeError::range()
+            .with_message(format!("invalid calendar code `{code}`"))
+            .into());
+    }
+
+    // c. Return the ASCII-lowercase of code.
+    Ok(code.to_ascii_lowercase())
+}
+
+/// Validates a date-time field code according to step 6 of
+/// [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_date_time_field(code: &str) -> JsResult<String> {
+    // a. Assert: type is "dateTimeField".
+    // b. If the result of IsValidDateTimeFieldCode(code) is false, throw a RangeError exception.
+    const FIELDS: [&str; 12] = [
+        "era",
+        "year",
+        "quarter",
+        "month",
+        "weekOfYear",
+        "weekday",
+        "day",
+        "dayPeriod",
+        "hour",
+        "minute",
+        "second",
+        "timeZoneName",
+    ];
+    if !FIELDS.contains(&code) {
+        return Err(JsNativeError::range()
+            .with_message(format!("invalid date-time field code `{code}`"))
+            .into());
+    }
+
+    // c. Return code.
+    Ok(code.to_owned())
+}
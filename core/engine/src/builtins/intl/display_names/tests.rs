@@ -0,0 +1,100 @@
+use indoc::indoc;
+
+use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+#[test]
+#[ignore = "not exposed until the bundled ICU4X data includes the display names keys"]
+fn region() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('US')",
+            js_string!("United States"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('de', { type: 'region' }).of('US')",
+            js_string!("Vereinigte Staaten"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('us')",
+            js_string!("United States"),
+        ),
+    ]);
+}
+
+#[test]
+#[ignore = "not exposed until the bundled ICU4X data includes the display names keys"]
+fn fallback() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('aa')",
+            js_string!("AA"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region', fallback: 'none' }).of('AA')",
+            JsValue::undefined(),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'currency' }).of('usd')",
+            js_string!("USD"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'calendar' }).of('Gregory')",
+            js_string!("gregory"),
+        ),
+    ]);
+}
+
+#[test]
+#[ignore = "not exposed until the bundled ICU4X data includes the display names keys"]
+fn invalid_codes() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'currency' }).of('US$')",
+            JsNativeErrorKind::Range,
+            "invalid currency code `US$`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('USA')",
+            JsNativeErrorKind::Range,
+            "invalid region code `USA`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'script' }).of('Lat')",
+            JsNativeErrorKind::Range,
+            "invalid script code `Lat`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'dateTimeField' }).of('millisecond')",
+            JsNativeErrorKind::Range,
+            "invalid date-time field code `millisecond`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en')",
+            JsNativeErrorKind::Type,
+            "`Intl.DisplayNames` requires an options object",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', {})",
+            JsNativeErrorKind::Type,
+            "`type` option is required by `Intl.DisplayNames`",
+        ),
+    ]);
+}
+
+#[test]
+#[ignore = "not exposed until the bundled ICU4X data includes the display names keys"]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var options = new Intl.DisplayNames('en', { type: 'language' }).resolvedOptions();
+        "#}),
+        TestAction::assert_eq("options.locale", js_string!("en")),
+        TestAction::assert_eq("options.style", js_string!("long")),
+        TestAction::assert_eq("options.type", js_string!("language")),
+        TestAction::assert_eq("options.fallback", js_string!("code")),
+        TestAction::assert_eq("options.languageDisplay", js_string!("dialect")),
+        TestAction::assert(
+            "!('languageDisplay' in new Intl.DisplayNames('en', { type: 'region' }).resolvedOptions())",
+        ),
+    ]);
+}
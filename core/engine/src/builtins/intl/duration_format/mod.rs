@@ -0,0 +1,799 @@
+use boa_gc::{Finalize, Trace};
+use boa_macros::utf16;
+use boa_profiler::Profiler;
+use fixed_decimal::{FixedDecimal, FloatPrecision, Sign};
+use icu_decimal::{
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV1Marker, FixedDecimalFormatter,
+};
+use icu_list::ListFormatter;
+use icu_locid::{extensions::unicode::Value, Locale};
+use icu_provider::DataLocale;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, Number,
+        OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, ObjectInitializer},
+    property::Attribute,
+    realm::Realm,
+    string::common::StaticJsStrings,
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales},
+    number_format::{partition_fixed_decimal, NumberFormat, NumberFormatLocaleOptions},
+    options::{get_number_option, IntlOptions},
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+/// A part of a formatted duration.
+#[derive(Debug)]
+struct Part {
+    typ: &'static str,
+    value: String,
+    unit: Option<DurationUnit>,
+}
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DurationFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DurationFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: BaseStyle,
+    units: [UnitOptions; 10],
+    fractional_digits: Option<u8>,
+}
+
+impl Service for DurationFormat {
+    type LangMarker = DecimalSymbolsV1Marker;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        // The only relevant extension key is "nu", which is resolved in the same way as for
+        // `Intl.NumberFormat`.
+        <NumberFormat as Service>::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for DurationFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DurationFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 1)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DurationFormat {
+    const NAME: JsString = StaticJsStrings::DURATION_FORMAT;
+}
+
+impl BuiltInConstructor for DurationFormat {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::duration_format;
+
+    /// Constructor [`Intl.DurationFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `DurationFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/DurationFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DurationFormat` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let durationFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%DurationFormatPrototype%", « [[InitializedDurationFormat]], [[Locale]], [[DataLocale]], [[NumberingSystem]], [[Style]], [[YearsStyle]], [[YearsDisplay]], [[MonthsStyle]], [[MonthsDisplay]], [[WeeksStyle]], [[WeeksDisplay]], [[DaysStyle]], [[DaysDisplay]], [[HoursStyle]], [[HoursDisplay]], [[MinutesStyle]], [[MinutesDisplay]], [[SecondsStyle]], [[SecondsDisplay]], [[MillisecondsStyle]], [[MillisecondsDisplay]], [[MicrosecondsStyle]], [[MicrosecondsDisplay]], [[NanosecondsStyle]], [[NanosecondsDisplay]], [[FractionalDigits]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::duration_format,
+            context,
+        )?;
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Let options be ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = get_option(&options, utf16!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, undefined, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        let numbering_system = get_option(&options, utf16!("numberingSystem"), context)?;
+
+        // 8. Let opt be the Record { [[localeMatcher]]: matcher, [[nu]]: numberingSystem }.
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions { numbering_system },
+        };
+
+        // 9. Let r be ResolveLocale(%DurationFormat%.[[AvailableLocales]], requestedLocales, opt, %DurationFormat%.[[RelevantExtensionKeys]], %DurationFormat%.[[LocaleData]]).
+        // 10. Let locale be r.[[locale]].
+        // 11. Set durationFormat.[[Locale]] to locale.
+        // 12. Set durationFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        );
+
+        // 13. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow", "digital" », "short").
+        // 14. Set durationFormat.[[Style]] to style.
+        let style = get_option(&options, utf16!("style"), context)?.unwrap_or_default();
+
+        // 15. Set durationFormat.[[DataLocale]] to r.[[dataLocale]].
+        // 16. Let prevStyle be the empty String.
+        let mut prev_style = None;
+
+        // 17. For each row of Table 1, except the header row, in table order, do
+        let mut units = [UnitOptions {
+            style: UnitStyle::Short,
+            display: UnitDisplay::Auto,
+        }; 10];
+        for (unit, unit_options) in DurationUnit::ALL.into_iter().zip(&mut units) {
+            // a. Let styleSlot be the Style Slot value of the current row.
+            // b. Let displaySlot be the Display Slot value of the current row.
+            // c. Let unit be the Unit value of the current row.
+            // d. Let valueList be the Values value of the current row.
+            // e. Let digitalBase be the Digital Default value of the current row.
+            // f. Let unitOptions be ? GetDurationUnitOptions(unit, options, style, valueList, digitalBase, prevStyle).
+            *unit_options = get_duration_unit_options(unit, &options, style, prev_style, context)?;
+
+            // g. Set the value of the styleSlot slot of durationFormat to unitOptions.[[Style]].
+            // h. Set the value of the displaySlot slot of durationFormat to unitOptions.[[Display]].
+            // i. If unit is one of "hours", "minutes", "seconds", "milliseconds", or "microseconds", then
+            //     i. Set prevStyle to unitOptions.[[Style]].
+            prev_style = Some(unit_options.style);
+        }
+
+        // 18. Set durationFormat.[[FractionalDigits]] to ? GetNumberOption(options, "fractionalDigits", 0, 9, undefined).
+        let fractional_digits =
+            get_number_option(&options, utf16!("fractionalDigits"), 0, 9, context)?;
+
+        let duration_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                numbering_system: intl_options.service_options.numbering_system,
+                style,
+                units,
+                fractional_digits,
+            },
+        );
+
+        // 19. Return durationFormat.
+        Ok(duration_format.into())
+    }
+}
+
+impl DurationFormat {
+    /// [`Intl.DurationFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in duration
+    /// formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DurationFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DurationFormat.prototype.format ( duration )`][spec].
+    ///
+    /// Formats a duration according to the locale and formatting options of the
+    /// `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/format
+    pub(crate) fn format(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`format` can only be called on an `Intl.DurationFormat` object")
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = to_duration_record(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        let parts = df.partition_duration_format_pattern(&record, context.intl_provider())?;
+
+        // 5. Let result be the empty String.
+        // 6. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result = parts.into_iter().map(|part| part.value).collect::<String>();
+
+        // 7. Return result.
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.formatToParts ( duration )`][spec].
+    ///
+    /// Returns an array of objects representing the formatted duration in parts.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.DurationFormat` object",
+                )
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = to_duration_record(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        let parts = df.partition_duration_format_pattern(&record, context.intl_provider())?;
+
+        // 5. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 6. Let n be 0.
+        // 7. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, part) in parts.into_iter().enumerate() {
+            // a. Let obj be OrdinaryObjectCreate(%ObjectPrototype%).
+            let obj = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, "type", part.[[Type]]).
+            obj.create_data_property_or_throw(utf16!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(obj, "value", part.[[Value]]).
+            obj.create_data_property_or_throw(utf16!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, perform ! CreateDataPropertyOrThrow(obj, "unit", part.[[Unit]]).
+            if let Some(unit) = part.unit {
+                obj.create_data_property_or_throw(utf16!("unit"), unit.singular(), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), obj).
+            result
+                .create_data_property_or_throw(n, obj, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 8. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and formatting options
+    /// computed during the construction of the current `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be the this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`resolvedOptions` can only be called on an `Intl.DurationFormat` object",
+                )
+            })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 2, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of df's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If p is "fractionalDigits", then
+        //         i. If v is not undefined, set v to 𝔽(v).
+        //     d. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(df.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numberingSystem"),
+                df.numbering_system
+                    .as_ref()
+                    .map_or_else(|| js_string!("latn"), |nu| js_string!(nu.to_string())),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("style"),
+                df.style.to_js_string(),
+                Attribute::all(),
+            );
+
+        for (unit, unit_options) in DurationUnit::ALL.into_iter().zip(df.units) {
+            options
+                .property(
+                    JsString::from(unit.field()),
+                    unit_options.style.to_js_string(),
+                    Attribute::all(),
+                )
+                .property(
+                    JsString::from(unit.display_field()),
+                    unit_options.display.to_js_string(),
+                    Attribute::all(),
+                );
+        }
+
+        if let Some(fractional_digits) = df.fractional_digits {
+            options.property(
+                js_string!("fractionalDigits"),
+                fractional_digits,
+                Attribute::all(),
+            );
+        }
+
+        // 5. Return options.
+        Ok(options.build().into())
+    }
+
+    /// Abstract operation [`PartitionDurationFormatPattern ( durationFormat, duration )`][spec].
+    ///
+    /// Unit names are not localized yet, since ICU4X doesn't provide unit display name data; the
+    /// numbers and the list joining the units are localized.
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-partitiondurationformatpattern
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn partition_duration_format_pattern(
+        &self,
+        duration: &[f64; 10],
+        provider: &IntlProvider,
+    ) -> JsResult<Vec<Part>> {
+        let data_locale = DataLocale::from(&self.locale);
+        let number_formatter = FixedDecimalFormatter::try_new_unstable(
+            provider,
+            &data_locale,
+            FixedDecimalFormatterOptions::default(),
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        let negative = duration.iter().any(|v| *v < 0.0);
+        let mut sign_displayed = false;
+
+        // Each element of the list is a group of parts; consecutive numeric units are merged
+        // into a single element separated by the time separator.
+        let mut elements: Vec<Vec<Part>> = Vec::new();
+        let mut numeric_group_open = false;
+
+        for (i, unit) in DurationUnit::ALL.into_iter().enumerate() {
+            let UnitOptions { style, display } = self.units[i];
+
+            // Fractional units are formatted as the fraction digits of the previous unit.
+            if style == UnitStyle::Fractional {
+                continue;
+            }
+
+            let fractional_units = self.units[i + 1..]
+                .iter()
+                .take_while(|options| options.style == UnitStyle::Fractional)
+                .count();
+
+            let mut value = if let (true, Some(exponent)) =
+                (fractional_units > 0, unit.nanoseconds_exponent())
+            {
+                // Sum this unit with all the following fractional units, in nanoseconds, then
+                // scale back to this unit.
+                let nanoseconds = DurationUnit::ALL[i..=i + fractional_units]
+                    .iter()
+                    .zip(&duration[i..=i + fractional_units])
+                    .map(|(unit, value)| {
+                        (value.abs() as i128)
+                            * 10i128.pow(
+                                unit.nanoseconds_exponent()
+                                    .expect("fractional units must have an exponent")
+                                    as u32,
+                            )
+                    })
+                    .sum::<i128>();
+                let mut value = FixedDecimal::from(nanoseconds);
+                value.multiply_pow10(-exponent);
+
+                if let Some(digits) = self.fractional_digits {
+                    value.trunc(-i16::from(digits));
+                    value.pad_end(-i16::from(digits));
+                } else {
+                    value.trim_end();
+                }
+                value
+            } else {
+                FixedDecimal::try_from_f64(duration[i].abs(), FloatPrecision::Integer)
+                    .expect("duration records only contain finite integers")
+            };
+
+            let is_zero = duration[i..=i + fractional_units]
+                .iter()
+                .all(|value| *value == 0.0);
+
+            if is_zero && display == UnitDisplay::Auto {
+                numeric_group_open = false;
+                continue;
+            }
+
+            if style == UnitStyle::TwoDigit {
+                value.pad_start(2);
+            }
+
+            let one = !negative && value == FixedDecimal::from(1);
+            if negative && !sign_displayed {
+                value.set_sign(Sign::Negative);
+                sign_displayed = true;
+            }
+
+            let mut parts = partition_fixed_decimal(&number_formatter, &value)
+                .into_iter()
+                .map(|(typ, value)| Part {
+                    typ,
+                    value,
+                    unit: Some(unit),
+                })
+                .collect::<Vec<_>>();
+
+            if style.is_numeric() {
+                if numeric_group_open {
+                    let element = elements
+                        .last_mut()
+                        .expect("an open numeric group must have an element");
+                    element.push(Part {
+                        typ: "literal",
+                        value: String::from(":"),
+                        unit: None,
+                    });
+                    element.append(&mut parts);
+                } else {
+                    elements.push(parts);
+                }
+                numeric_group_open = true;
+            } else {
+                let label = unit.label(style, one);
+                if let Some(label) = label.strip_prefix(' ') {
+                    parts.push(Part {
+                        typ: "literal",
+                        value: String::from(" "),
+                        unit: Some(unit),
+                    });
+                    parts.push(Part {
+                        typ: "unit",
+                        value: label.to_owned(),
+                        unit: Some(unit),
+                    });
+                } else {
+                    parts.push(Part {
+                        typ: "unit",
+                        value: label.to_owned(),
+                        unit: Some(unit),
+                    });
+                }
+                elements.push(parts);
+                numeric_group_open = false;
+            }
+        }
+
+        // Join the elements using the localized list pattern of type "unit".
+        let list_formatter = ListFormatter::try_new_unit_with_length_unstable(
+            provider,
+            &data_locale,
+            self.style.list_length(),
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        let strings = elements
+            .iter()
+            .map(|parts| {
+                parts
+                    .iter()
+                    .map(|part| part.value.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        let formatted = list_formatter.format_to_string(strings.iter());
+
+        // Recover the literals inserted by the list formatter between the elements.
+        let mut result = Vec::new();
+        let mut rest = formatted.as_str();
+        for (string, parts) in strings.iter().zip(elements) {
+            let start = rest.find(string.as_str()).unwrap_or_default();
+            if start > 0 {
+                result.push(Part {
+                    typ: "literal",
+                    value: rest[..start].to_owned(),
+                    unit: None,
+                });
+            }
+            result.extend(parts);
+            rest = &rest[(start + string.len()).min(rest.len())..];
+        }
+        if !rest.is_empty() {
+            result.push(Part {
+                typ: "literal",
+                value: rest.to_owned(),
+                unit: None,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+/// Abstract operation [`GetDurationUnitOptions ( unit, options, baseStyle, stylesList, digitalBase, prevStyle )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-getdurationunitoptions
+fn get_duration_unit_options(
+    unit: DurationUnit,
+    options: &JsObject,
+    base_style: BaseStyle,
+    prev_style: Option<UnitStyle>,
+    context: &mut Context,
+) -> JsResult<UnitOptions> {
+    let (styles_list, digital_base) = unit.styles();
+
+    // 1. Let style be ? GetOption(options, unit, string, stylesList, undefined).
+    let style = get_option::<UnitStyle>(options, unit.field(), context)?;
+    if let Some(style) = style {
+        if !styles_list.contains(&style) {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "`{}` is not a valid style for the `{}` unit",
+                    style.to_js_string().to_std_string_escaped(),
+                    JsString::from(unit.field()).to_std_string_escaped()
+                ))
+                .into());
+        }
+    }
+
+    // 2. Let displayDefault be "always".
+    let mut display_default = UnitDisplay::Always;
+
+    let prev_numeric = prev_style.is_some_and(UnitStyle::is_numeric);
+
+    // 3. If style is undefined, then
+    let mut style = style.unwrap_or_else(|| {
+        if base_style == BaseStyle::Digital {
+            // a. If baseStyle is "digital", then
+            //     i. If unit is not one of "hours", "minutes", or "seconds", then
+            //         1. Set displayDefault to "auto".
+            if !matches!(
+                unit,
+                DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+            ) {
+                display_default = UnitDisplay::Auto;
+            }
+            //     ii. Set style to digitalBase.
+            digital_base
+        } else if prev_numeric {
+            // b. Else if prevStyle is "fractional", "numeric" or "2-digit", then
+            //     i. If unit is not one of "minutes" or "seconds", then
+            //         1. Set displayDefault to "auto".
+            if !matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+                display_default = UnitDisplay::Auto;
+            }
+            //     ii. Set style to "numeric".
+            UnitStyle::Numeric
+        } else {
+            // c. Else,
+            //     i. Set displayDefault to "auto".
+            display_default = UnitDisplay::Auto;
+            //     ii. Set style to baseStyle.
+            match base_style {
+                BaseStyle::Long => UnitStyle::Long,
+                BaseStyle::Short | BaseStyle::Digital => UnitStyle::Short,
+                BaseStyle::Narrow => UnitStyle::Narrow,
+            }
+        }
+    });
+
+    // 4. If style is "numeric" and unit is one of "milliseconds", "microseconds" or "nanoseconds", then
+    if style == UnitStyle::Numeric
+        && matches!(
+            unit,
+            DurationUnit::Milliseconds | DurationUnit::Microseconds | DurationUnit::Nanoseconds
+        )
+    {
+        // a. Set style to "fractional".
+        style = UnitStyle::Fractional;
+        // b. Set displayDefault to "auto".
+        display_default = UnitDisplay::Auto;
+    }
+
+    // 5. Let displayField be the string-concatenation of unit and "Display".
+    // 6. Let display be ? GetOption(options, displayField, string, « "auto", "always" », displayDefault).
+    let display = get_option(options, unit.display_field(), context)?.unwrap_or(display_default);
+
+    // 7. If prevStyle is "fractional", "numeric" or "2-digit", then
+    if prev_numeric {
+        // a. If style is not "fractional", "numeric" or "2-digit", then
+        if !style.is_numeric() {
+            // i. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message("units smaller than a numeric unit must also be numeric")
+                .into());
+        }
+        // b. If unit is "minutes" or "seconds", then
+        if matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+            // i. Set style to "2-digit".
+            style = UnitStyle::TwoDigit;
+        }
+    }
+
+    // 8. Return the Record { [[Style]]: style, [[Display]]: display }.
+    Ok(UnitOptions { style, display })
+}
+
+/// Abstract operation [`ToDurationRecord ( input )`][spec].
+///
+/// Reads the fields of a `Temporal.Duration` or a duration-like object.
+///
+/// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-todurationrecord
+fn to_duration_record(input: &JsValue, context: &mut Context) -> JsResult<[f64; 10]> {
+    // 1. If Type(input) is not Object, then
+    let Some(input) = input.as_object() else {
+        // a. If Type(input) is String, throw a RangeError exception.
+        if input.is_string() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format a string as a duration")
+                .into());
+        }
+        // b. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("can only format objects as durations")
+            .into());
+    };
+
+    // 2. Let result be a new Duration Record with each field set to 0.
+    let mut result = [0.0; 10];
+    let mut any_defined = false;
+
+    // 3. For each field of the duration record, in alphabetical order, do
+    //     a. Let value be ? Get(input, field).
+    //     b. If value is not undefined, then
+    //         i. Set any to true.
+    //         ii. Set value to ? ToIntegerIfIntegral(value).
+    //         iii. Set the field of result to value.
+    let mut fields = DurationUnit::ALL
+        .into_iter()
+        .enumerate()
+        .collect::<Vec<_>>();
+    fields.sort_by_key(|(_, unit)| unit.field());
+    for (index, unit) in fields {
+        let value = input.get(unit.field(), context)?;
+        if value.is_undefined() {
+            continue;
+        }
+        any_defined = true;
+
+        let number = value.to_number(context)?;
+        if !Number::is_float_integer(number) {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "the `{}` of a duration must be an integer",
+                    JsString::from(unit.field()).to_std_string_escaped()
+                ))
+                .into());
+        }
+        result[index] = number + 0.0;
+    }
+
+    // 4. If any is false, throw a TypeError exception.
+    if !any_defined {
+        return Err(JsNativeError::typ()
+            .with_message("a duration-like object must have at least one duration field")
+            .into());
+    }
+
+    // 5. If IsValidDurationRecord(result) is false, throw a RangeError exception.
+    let positive = result.iter().any(|v| *v > 0.0);
+    let negative = result.iter().any(|v| *v < 0.0);
+    if positive && negative {
+        return Err(JsNativeError::range()
+            .with_message("the fields of a duration cannot have mixed signs")
+            .into());
+    }
+
+    // 6. Return result.
+    Ok(result)
+}
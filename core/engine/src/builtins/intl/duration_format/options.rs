@@ -0,0 +1,320 @@
+use std::{fmt::Display, str::FromStr};
+
+use boa_macros::utf16;
+use icu_list::ListLength;
+
+use crate::{builtins::options::ParsableOptionType, js_string, JsString};
+
+/// The base style of an `Intl.DurationFormat`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum BaseStyle {
+    Long,
+    #[default]
+    Short,
+    Narrow,
+    Digital,
+}
+
+impl BaseStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Digital => js_string!("digital"),
+        }
+    }
+
+    /// Gets the length of the list used to join the formatted units.
+    pub(crate) const fn list_length(self) -> ListLength {
+        match self {
+            Self::Long => ListLength::Wide,
+            Self::Short | Self::Digital => ListLength::Short,
+            Self::Narrow => ListLength::Narrow,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseBaseStyleError;
+
+impl Display for ParseBaseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow` or `digital`")
+    }
+}
+
+impl FromStr for BaseStyle {
+    type Err = ParseBaseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "digital" => Ok(Self::Digital),
+            _ => Err(ParseBaseStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for BaseStyle {}
+
+/// The style used to format a single unit of a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitStyle {
+    Long,
+    Short,
+    Narrow,
+    Numeric,
+    TwoDigit,
+    Fractional,
+}
+
+impl UnitStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+            Self::Fractional => js_string!("fractional"),
+        }
+    }
+
+    /// Returns `true` if this style formats the unit as a plain number.
+    pub(crate) const fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit | Self::Fractional)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitStyleError;
+
+impl Display for ParseUnitStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not a valid duration unit style")
+    }
+}
+
+impl FromStr for UnitStyle {
+    type Err = ParseUnitStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseUnitStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for UnitStyle {}
+
+/// Whether a unit of a duration should be displayed if its value is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitDisplay {
+    Auto,
+    Always,
+}
+
+impl UnitDisplay {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Auto => js_string!("auto"),
+            Self::Always => js_string!("always"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitDisplayError;
+
+impl Display for ParseUnitDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto` or `always`")
+    }
+}
+
+impl FromStr for UnitDisplay {
+    type Err = ParseUnitDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            _ => Err(ParseUnitDisplayError),
+        }
+    }
+}
+
+impl ParsableOptionType for UnitDisplay {}
+
+/// The style and display options of a single unit.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct UnitOptions {
+    pub(crate) style: UnitStyle,
+    pub(crate) display: UnitDisplay,
+}
+
+/// The units of a duration record, in the order in which they are formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DurationUnit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl DurationUnit {
+    pub(crate) const ALL: [Self; 10] = [
+        Self::Years,
+        Self::Months,
+        Self::Weeks,
+        Self::Days,
+        Self::Hours,
+        Self::Minutes,
+        Self::Seconds,
+        Self::Milliseconds,
+        Self::Microseconds,
+        Self::Nanoseconds,
+    ];
+
+    /// The name of the field of a duration-like object holding this unit.
+    pub(crate) const fn field(self) -> &'static [u16] {
+        match self {
+            Self::Years => utf16!("years"),
+            Self::Months => utf16!("months"),
+            Self::Weeks => utf16!("weeks"),
+            Self::Days => utf16!("days"),
+            Self::Hours => utf16!("hours"),
+            Self::Minutes => utf16!("minutes"),
+            Self::Seconds => utf16!("seconds"),
+            Self::Milliseconds => utf16!("milliseconds"),
+            Self::Microseconds => utf16!("microseconds"),
+            Self::Nanoseconds => utf16!("nanoseconds"),
+        }
+    }
+
+    /// The name of the option holding the display of this unit.
+    pub(crate) const fn display_field(self) -> &'static [u16] {
+        match self {
+            Self::Years => utf16!("yearsDisplay"),
+            Self::Months => utf16!("monthsDisplay"),
+            Self::Weeks => utf16!("weeksDisplay"),
+            Self::Days => utf16!("daysDisplay"),
+            Self::Hours => utf16!("hoursDisplay"),
+            Self::Minutes => utf16!("minutesDisplay"),
+            Self::Seconds => utf16!("secondsDisplay"),
+            Self::Milliseconds => utf16!("millisecondsDisplay"),
+            Self::Microseconds => utf16!("microsecondsDisplay"),
+            Self::Nanoseconds => utf16!("nanosecondsDisplay"),
+        }
+    }
+
+    /// The singular name of the unit, used as the `unit` of the formatted parts.
+    pub(crate) fn singular(self) -> JsString {
+        match self {
+            Self::Years => js_string!("year"),
+            Self::Months => js_string!("month"),
+            Self::Weeks => js_string!("week"),
+            Self::Days => js_string!("day"),
+            Self::Hours => js_string!("hour"),
+            Self::Minutes => js_string!("minute"),
+            Self::Seconds => js_string!("second"),
+            Self::Milliseconds => js_string!("millisecond"),
+            Self::Microseconds => js_string!("microsecond"),
+            Self::Nanoseconds => js_string!("nanosecond"),
+        }
+    }
+
+    /// The styles accepted for this unit, and the style used by default with the `"digital"`
+    /// base style.
+    pub(crate) const fn styles(self) -> (&'static [UnitStyle], UnitStyle) {
+        use UnitStyle::{Long, Narrow, Numeric, Short, TwoDigit};
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => {
+                (&[Long, Short, Narrow], Short)
+            }
+            Self::Hours | Self::Minutes | Self::Seconds => {
+                (&[Long, Short, Narrow, Numeric, TwoDigit], Numeric)
+            }
+            Self::Milliseconds | Self::Microseconds | Self::Nanoseconds => {
+                (&[Long, Short, Narrow, Numeric], Numeric)
+            }
+        }
+    }
+
+    /// The power of ten of a nanosecond in this unit, for the units that can be formatted as
+    /// fractions of a bigger unit.
+    pub(crate) const fn nanoseconds_exponent(self) -> Option<i16> {
+        match self {
+            Self::Seconds => Some(9),
+            Self::Milliseconds => Some(6),
+            Self::Microseconds => Some(3),
+            Self::Nanoseconds => Some(0),
+            _ => None,
+        }
+    }
+
+    /// The English label of the unit for the provided style and plurality.
+    ///
+    /// ICU4X doesn't provide unit display names yet, so units are always labeled in English.
+    #[allow(clippy::match_same_arms)]
+    pub(crate) const fn label(self, style: UnitStyle, one: bool) -> &'static str {
+        match (self, style) {
+            (Self::Years, UnitStyle::Long) if one => " year",
+            (Self::Years, UnitStyle::Long) => " years",
+            (Self::Years, UnitStyle::Narrow) => "y",
+            (Self::Years, _) if one => " yr",
+            (Self::Years, _) => " yrs",
+            (Self::Months, UnitStyle::Long) if one => " month",
+            (Self::Months, UnitStyle::Long) => " months",
+            (Self::Months, UnitStyle::Narrow) => "m",
+            (Self::Months, _) if one => " mth",
+            (Self::Months, _) => " mths",
+            (Self::Weeks, UnitStyle::Long) if one => " week",
+            (Self::Weeks, UnitStyle::Long) => " weeks",
+            (Self::Weeks, UnitStyle::Narrow) => "w",
+            (Self::Weeks, _) if one => " wk",
+            (Self::Weeks, _) => " wks",
+            (Self::Days, UnitStyle::Narrow) => "d",
+            (Self::Days, _) if one => " day",
+            (Self::Days, _) => " days",
+            (Self::Hours, UnitStyle::Long) if one => " hour",
+            (Self::Hours, UnitStyle::Long) => " hours",
+            (Self::Hours, UnitStyle::Narrow) => "h",
+            (Self::Hours, _) => " hr",
+            (Self::Minutes, UnitStyle::Long) if one => " minute",
+            (Self::Minutes, UnitStyle::Long) => " minutes",
+            (Self::Minutes, UnitStyle::Narrow) => "m",
+            (Self::Minutes, _) => " min",
+            (Self::Seconds, UnitStyle::Long) if one => " second",
+            (Self::Seconds, UnitStyle::Long) => " seconds",
+            (Self::Seconds, UnitStyle::Narrow) => "s",
+            (Self::Seconds, _) => " sec",
+            (Self::Milliseconds, UnitStyle::Long) if one => " millisecond",
+            (Self::Milliseconds, UnitStyle::Long) => " milliseconds",
+            (Self::Milliseconds, UnitStyle::Narrow) => "ms",
+            (Self::Milliseconds, _) => " ms",
+            (Self::Microseconds, UnitStyle::Long) if one => " microsecond",
+            (Self::Microseconds, UnitStyle::Long) => " microseconds",
+            (Self::Microseconds, UnitStyle::Narrow) => "μs",
+            (Self::Microseconds, _) => " μs",
+            (Self::Nanoseconds, UnitStyle::Long) if one => " nanosecond",
+            (Self::Nanoseconds, UnitStyle::Long) => " nanoseconds",
+            (Self::Nanoseconds, UnitStyle::Narrow) => "ns",
+            (Self::Nanoseconds, _) => " ns",
+        }
+    }
+}
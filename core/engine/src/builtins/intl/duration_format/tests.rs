@@ -0,0 +1,163 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn format_styles() {
+    run_test_actions([
+        TestAction::run(
+            "function format(style, duration) {
+                return new Intl.DurationFormat('en', { style }).format(duration);
+            }
+            var duration = { hours: 1, minutes: 46, seconds: 40 };",
+        ),
+        TestAction::assert_eq(
+            "format('long', duration)",
+            js_string!("1 hour, 46 minutes, 40 seconds"),
+        ),
+        TestAction::assert_eq(
+            "format('short', duration)",
+            js_string!("1 hr, 46 min, 40 sec"),
+        ),
+        TestAction::assert_eq("format('narrow', duration)", js_string!("1h 46m 40s")),
+        TestAction::assert_eq("format('digital', duration)", js_string!("1:46:40")),
+        TestAction::assert_eq(
+            "format('long', { years: 1, days: 2 })",
+            js_string!("1 year, 2 days"),
+        ),
+    ]);
+}
+
+#[test]
+fn format_zero_units_and_sign() {
+    run_test_actions([
+        TestAction::run("var df = new Intl.DurationFormat('en');"),
+        TestAction::assert_eq("df.format({ hours: 0, minutes: 5 })", js_string!("5 min")),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { hoursDisplay: 'always' }).format({ minutes: 5 })",
+            js_string!("0 hr, 5 min"),
+        ),
+        TestAction::assert_eq(
+            "df.format({ hours: -1, minutes: -5 })",
+            js_string!("-1 hr, 5 min"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format({ seconds: 5 })",
+            js_string!("0:00:05"),
+        ),
+    ]);
+}
+
+#[test]
+fn format_fractional_seconds() {
+    run_test_actions([
+        TestAction::run(
+            "var duration = { hours: 1, minutes: 2, seconds: 3, milliseconds: 400, nanoseconds: 7 };",
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format(duration)",
+            js_string!("1:02:03.400000007"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital', fractionalDigits: 2 }).format(duration)",
+            js_string!("1:02:03.40"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { seconds: 'numeric' }).format(duration)",
+            js_string!("1 hr, 2 min, 3.400000007"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'narrow' }).format(duration)",
+            js_string!("1h 2m 3s 400ms 7ns"),
+        ),
+    ]);
+}
+
+#[test]
+fn format_localized_numbers() {
+    run_test_actions([TestAction::assert_eq(
+        "new Intl.DurationFormat('de', { style: 'digital' }).format({ hours: 1234, minutes: 5 })",
+        js_string!("1.234:05:00"),
+    )]);
+}
+
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(
+            "var parts = new Intl.DurationFormat('en', { style: 'long' })
+                .formatToParts({ hours: 2, minutes: 1 })
+                .map((part) => part.type + ':' + part.value + ('unit' in part ? ':' + part.unit : ''))
+                .join('|');",
+        ),
+        TestAction::assert_eq(
+            "parts",
+            js_string!(
+                "integer:2:hour|literal: :hour|unit:hours:hour|literal:, \
+                 |integer:1:minute|literal: :minute|unit:minute:minute"
+            ),
+        ),
+    ]);
+}
+
+#[test]
+fn invalid_input() {
+    run_test_actions([
+        TestAction::run("var df = new Intl.DurationFormat('en');"),
+        TestAction::assert_native_error(
+            "df.format('PT1H')",
+            JsNativeErrorKind::Range,
+            "cannot format a string as a duration",
+        ),
+        TestAction::assert_native_error(
+            "df.format({})",
+            JsNativeErrorKind::Type,
+            "a duration-like object must have at least one duration field",
+        ),
+        TestAction::assert_native_error(
+            "df.format({ hours: 1, minutes: -1 })",
+            JsNativeErrorKind::Range,
+            "the fields of a duration cannot have mixed signs",
+        ),
+        TestAction::assert_native_error(
+            "df.format({ hours: 1.5 })",
+            JsNativeErrorKind::Range,
+            "the `hours` of a duration must be an integer",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { years: 'numeric' })",
+            JsNativeErrorKind::Range,
+            "`numeric` is not a valid style for the `years` unit",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { hours: 'numeric', minutes: 'long' })",
+            JsNativeErrorKind::Range,
+            "units smaller than a numeric unit must also be numeric",
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(
+            "var options = new Intl.DurationFormat('en', { style: 'digital', fractionalDigits: 3 })
+                .resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.style", js_string!("digital")),
+        TestAction::assert_eq("options.years", js_string!("short")),
+        TestAction::assert_eq("options.yearsDisplay", js_string!("auto")),
+        TestAction::assert_eq("options.hours", js_string!("numeric")),
+        TestAction::assert_eq("options.hoursDisplay", js_string!("always")),
+        TestAction::assert_eq("options.minutes", js_string!("2-digit")),
+        TestAction::assert_eq("options.milliseconds", js_string!("fractional")),
+        TestAction::assert_eq("options.fractionalDigits", 3),
+    ]);
+}
+
+#[cfg(feature = "temporal")]
+#[test]
+fn temporal_duration_to_locale_string() {
+    run_test_actions([TestAction::assert_eq(
+        "new Temporal.Duration(0, 0, 0, 1, 2).toLocaleString('en', { style: 'long' })",
+        js_string!("1 day, 2 hours"),
+    )]);
+}
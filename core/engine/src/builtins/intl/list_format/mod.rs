@@ -30,6 +30,9 @@ use super::{
 mod options;
 pub(crate) use options::*;
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Trace, Finalize, JsData)]
 // Safety: `ListFormat` only contains non-traceable types.
 #[boa_gc(unsafe_empty_trace)]
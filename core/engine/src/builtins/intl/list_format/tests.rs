@@ -0,0 +1,106 @@
+use indoc::indoc;
+
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var list = ["Motorcycle", "Bus", "Car"];
+            function format(locale, type, style) {
+                return new Intl.ListFormat(locale, { type, style }).format(list);
+            }
+        "#}),
+        TestAction::assert_eq(
+            "format('en', 'conjunction', 'long')",
+            js_string!("Motorcycle, Bus, and Car"),
+        ),
+        TestAction::assert_eq(
+            "format('en', 'disjunction', 'long')",
+            js_string!("Motorcycle, Bus, or Car"),
+        ),
+        TestAction::assert_eq(
+            "format('en', 'conjunction', 'short')",
+            js_string!("Motorcycle, Bus, & Car"),
+        ),
+        TestAction::assert_eq(
+            "format('en', 'unit', 'narrow')",
+            js_string!("Motorcycle Bus Car"),
+        ),
+        TestAction::assert_eq(
+            "format('es', 'conjunction', 'long')",
+            js_string!("Motorcycle, Bus y Car"),
+        ),
+        TestAction::assert_eq(
+            "format('es', 'disjunction', 'long')",
+            js_string!("Motorcycle, Bus o Car"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.ListFormat('en').format(new Set(['a', 'b']))",
+            js_string!("a and b"),
+        ),
+        TestAction::assert_eq("new Intl.ListFormat('en').format()", js_string!("")),
+    ]);
+}
+
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var parts = new Intl.ListFormat("en")
+                .formatToParts(["Motorcycle", "Bus", "Car"])
+                .map((part) => part.type + ":" + part.value)
+                .join("|");
+        "#}),
+        TestAction::assert_eq(
+            "parts",
+            js_string!("element:Motorcycle|literal:, |element:Bus|literal:, and |element:Car"),
+        ),
+    ]);
+}
+
+#[test]
+fn non_string_elements() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var closed = false;
+            var iterable = {
+                [Symbol.iterator]() {
+                    var values = ["a", 1, "c"];
+                    return {
+                        next() {
+                            return { value: values.shift(), done: values.length === 0 };
+                        },
+                        return() {
+                            closed = true;
+                            return {};
+                        },
+                    };
+                },
+            };
+        "#}),
+        TestAction::assert_native_error(
+            "new Intl.ListFormat('en').format(iterable)",
+            JsNativeErrorKind::Type,
+            "StringListFromIterable: can only format strings into a list",
+        ),
+        TestAction::assert("closed"),
+        TestAction::assert_native_error(
+            "new Intl.ListFormat('en', { type: 'list' })",
+            JsNativeErrorKind::Range,
+            "provided string was not `conjunction`, `disjunction` or `unit`",
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(
+            "var options = new Intl.ListFormat('en', { type: 'unit', style: 'short' }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.locale", js_string!("en")),
+        TestAction::assert_eq("options.type", js_string!("unit")),
+        TestAction::assert_eq("options.style", js_string!("short")),
+    ]);
+}
@@ -31,15 +31,20 @@ use icu_provider::KeyedDataMarker;
 
 pub(crate) mod collator;
 pub(crate) mod date_time_format;
+pub(crate) mod display_names;
+pub(crate) mod duration_format;
 pub(crate) mod list_format;
 pub(crate) mod locale;
 pub(crate) mod number_format;
 pub(crate) mod plural_rules;
+pub(crate) mod relative_time_format;
 pub(crate) mod segmenter;
 
 pub(crate) use self::{
-    collator::Collator, date_time_format::DateTimeFormat, list_format::ListFormat, locale::Locale,
-    number_format::NumberFormat, plural_rules::PluralRules, segmenter::Segmenter,
+    collator::Collator, date_time_format::DateTimeFormat, display_names::DisplayNames,
+    duration_format::DurationFormat, list_format::ListFormat, locale::Locale,
+    number_format::NumberFormat, plural_rules::PluralRules,
+    relative_time_format::RelativeTimeFormat, segmenter::Segmenter,
 };
 
 mod options;
@@ -107,6 +112,8 @@ impl IntrinsicObject for Intl {
                     .constructor(),
                 PluralRules::ATTRIBUTE,
             )
+            // TODO: Expose `Intl.RelativeTimeFormat` and `Intl.DisplayNames` once the bundled
+            // ICU4X data includes the `relativetime` and `displaynames` keys.
             .static_property(
                 DateTimeFormat::NAME,
                 realm
@@ -116,6 +123,15 @@ impl IntrinsicObject for Intl {
                     .constructor(),
                 DateTimeFormat::ATTRIBUTE,
             )
+            .static_property(
+                DurationFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .duration_format()
+                    .constructor(),
+                DurationFormat::ATTRIBUTE,
+            )
             .static_property(
                 NumberFormat::NAME,
                 realm
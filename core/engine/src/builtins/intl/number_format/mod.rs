@@ -12,7 +12,7 @@ use icu_decimal::{
 
 mod options;
 use icu_locid::{
-    extensions::unicode::{key, Value},
+    extensions::unicode::{key, value, Value},
     Locale,
 };
 use num_bigint::BigInt;
@@ -21,7 +21,7 @@ pub(crate) use options::*;
 
 use crate::{
     builtins::{
-        builder::BuiltInBuilder, options::get_option, string::is_trimmable_whitespace,
+        builder::BuiltInBuilder, options::get_option, string::is_trimmable_whitespace, Array,
         BuiltInConstructor, BuiltInObject, IntrinsicObject,
     },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
@@ -53,7 +53,7 @@ mod tests;
 pub(crate) struct NumberFormat {
     locale: Locale,
     formatter: FixedDecimalFormatter,
-    numbering_system: Option<Value>,
+    numbering_system: Value,
     unit_options: UnitFormatOptions,
     digit_options: DigitFormatOptions,
     notation: Notation,
@@ -70,20 +70,85 @@ impl NumberFormat {
     ///
     /// [full]: https://tc39.es/ecma402/#sec-formatnumber
     /// [parts]: https://tc39.es/ecma402/#sec-formatnumbertoparts
-    fn format<'a>(&'a self, value: &'a mut FixedDecimal) -> FormattedFixedDecimal<'a> {
+    pub(crate) fn format<'a>(&'a self, value: &'a mut FixedDecimal) -> FormattedFixedDecimal<'a> {
         // TODO: Missing support from ICU4X for Percent/Currency/Unit formatting.
         // TODO: Missing support from ICU4X for Scientific/Engineering/Compact notation.
 
+        self.round(value);
+
+        self.formatter.format(value)
+    }
+
+    /// Applies the digit options and the sign display of this formatter to `value`.
+    fn round(&self, value: &mut FixedDecimal) {
         self.digit_options.format_fixed_decimal(value);
         value.apply_sign_display(self.sign_display);
+    }
 
-        self.formatter.format(value)
+    /// Abstract operation [`PartitionNumberRangePattern ( numberFormat, x, y )`][spec].
+    ///
+    /// Returns the `(type, value, source)` triples of the formatted range.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberrangepattern
+    fn partition_number_range_pattern(
+        &self,
+        mut x: FixedDecimal,
+        mut y: FixedDecimal,
+    ) -> Vec<(&'static str, String, &'static str)> {
+        // 1. If x is NaN or y is NaN, throw a RangeError exception.
+        // Already handled by `to_intl_mathematical_value`.
+
+        // 2. Let xResult be ? PartitionNumberPattern(numberFormat, x).
+        self.round(&mut x);
+        let x_result = partition_fixed_decimal(&self.formatter, &x);
+
+        // 3. Let yResult be ? PartitionNumberPattern(numberFormat, y).
+        self.round(&mut y);
+        let y_result = partition_fixed_decimal(&self.formatter, &y);
+
+        // 4. If ! FormatNumeric(numberFormat, x) is equal to ! FormatNumeric(numberFormat, y), then
+        if x_result == y_result {
+            // a. Let appxResult be ? FormatApproximately(numberFormat, xResult).
+            // b. For each r in appxResult, do
+            //     i. Set r.[[Source]] to "shared".
+            // c. Return appxResult.
+            // TODO: ICU4X doesn't expose the approximately sign of a locale yet, so the CLDR
+            // default "~" is used.
+            return std::iter::once(("approximatelySign", "~".to_owned()))
+                .chain(x_result)
+                .map(|(typ, value)| (typ, value, "shared"))
+                .collect();
+        }
+
+        // 5. Let result be a new empty List.
+        // 6. For each element r in xResult, do
+        //     a. Append a new Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "startRange" } as the last element of result.
+        let mut result: Vec<_> = x_result
+            .into_iter()
+            .map(|(typ, value)| (typ, value, "startRange"))
+            .collect();
+
+        // 7. Let rangeSeparator be an ILND String value used to separate two numbers.
+        // 8. Append a new Record { [[Type]]: "literal", [[Value]]: rangeSeparator, [[Source]]: "shared" } element to result.
+        // TODO: ICU4X doesn't provide number range patterns yet, so the CLDR default is used.
+        result.push(("literal", "–".to_owned(), "shared"));
+
+        // 9. For each element r in yResult, do
+        //     a. Append a new Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "endRange" } as the last element of result.
+        result.extend(
+            y_result
+                .into_iter()
+                .map(|(typ, value)| (typ, value, "endRange")),
+        );
+
+        // 10. Return ! CollapseNumberRange(numberFormat, result).
+        result
     }
 }
 
 #[derive(Debug, Clone)]
 pub(super) struct NumberFormatLocaleOptions {
-    numbering_system: Option<Value>,
+    pub(super) numbering_system: Option<Value>,
 }
 
 impl Service for NumberFormat {
@@ -154,6 +219,12 @@ impl IntrinsicObject for NumberFormat {
                 None,
                 Attribute::CONFIGURABLE,
             )
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(
+                Self::format_range_to_parts,
+                js_string!("formatRangeToParts"),
+                2,
+            )
             .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
             .build();
     }
@@ -381,7 +452,10 @@ impl BuiltInConstructor for NumberFormat {
             prototype,
             NumberFormat {
                 locale,
-                numbering_system: intl_options.service_options.numbering_system,
+                numbering_system: intl_options
+                    .service_options
+                    .numbering_system
+                    .unwrap_or(value!("latn")),
                 formatter,
                 unit_options,
                 digit_options,
@@ -443,6 +517,23 @@ impl BuiltInConstructor for NumberFormat {
 }
 
 impl NumberFormat {
+    /// Creates a new `NumberFormat` as if calling `Construct(%Intl.NumberFormat%, « locales, options »)`.
+    ///
+    /// This is used by the `toLocaleString` methods of `Number` and `BigInt`.
+    pub(crate) fn construct(args: &[JsValue], context: &mut Context) -> JsResult<JsObject<Self>> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .number_format()
+            .constructor();
+        let number_format = Self::constructor(&constructor.into(), args, context)?;
+
+        Ok(number_format
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .expect("constructor must return a `NumberFormat` object"))
+    }
+
     /// [`Intl.NumberFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
     ///
     /// Returns an array containing those of the provided locales that are supported in number format
@@ -521,6 +612,95 @@ impl NumberFormat {
         Ok(bound_format.into())
     }
 
+    /// [`Intl.NumberFormat.prototype.formatRange ( start, end )`][spec].
+    ///
+    /// Returns a language-specific string representing the range between two numbers.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let parts = Self::format_range_impl("formatRange", this, args, context)?;
+
+        // 6. Return ! FormatNumericRange(nf, x, y).
+        Ok(js_string!(parts
+            .into_iter()
+            .map(|(_, value, _)| value)
+            .collect::<String>())
+        .into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRangeToParts ( start, end )`][spec].
+    ///
+    /// Returns an array of objects representing the range between two numbers.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrangetoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let parts = Self::format_range_impl("formatRangeToParts", this, args, context)?;
+
+        // 6. Return ! FormatNumericRangeToParts(nf, x, y).
+        let parts = parts
+            .into_iter()
+            .map(|(typ, value, source)| {
+                let mut part = ObjectInitializer::new(context);
+                part.property(js_string!("type"), js_string!(typ), Attribute::all())
+                    .property(js_string!("value"), js_string!(value), Attribute::all())
+                    .property(js_string!("source"), js_string!(source), Attribute::all());
+                part.build().into()
+            })
+            .collect::<Vec<JsValue>>();
+
+        Ok(Array::create_array_from_list(parts, context).into())
+    }
+
+    /// Common steps of `formatRange` and `formatRangeToParts`.
+    fn format_range_impl(
+        method: &str,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<Vec<(&'static str, String, &'static str)>> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "`{method}` can only be called on an `Intl.NumberFormat` object"
+                ))
+            })?;
+
+        let start = args.get_or_undefined(0);
+        let end = args.get_or_undefined(1);
+
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        if start.is_undefined() || end.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!("`{method}` requires both `start` and `end` values"))
+                .into());
+        }
+
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        let x = to_intl_mathematical_value(start, context)?;
+
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        let y = to_intl_mathematical_value(end, context)?;
+
+        let nf = nf.borrow();
+        Ok(nf.data.partition_number_range_pattern(x, y))
+    }
+
     /// [`Intl.NumberFormat.prototype.resolvedOptions ( )`][spec].
     ///
     /// Returns a new object with properties reflecting the locale and options computed during the
@@ -557,13 +737,11 @@ impl NumberFormat {
             js_string!(nf.locale.to_string()),
             Attribute::all(),
         );
-        if let Some(nu) = &nf.numbering_system {
-            options.property(
-                js_string!("numberingSystem"),
-                js_string!(nu.to_string()),
-                Attribute::all(),
-            );
-        }
+        options.property(
+            js_string!("numberingSystem"),
+            js_string!(nf.numbering_system.to_string()),
+            Attribute::all(),
+        );
 
         options.property(
             js_string!("style"),
@@ -689,6 +867,11 @@ impl NumberFormat {
                 nf.digit_options.rounding_increment.to_u16(),
                 Attribute::all(),
             )
+            .property(
+                js_string!("roundingMode"),
+                nf.digit_options.rounding_mode.to_js_string(),
+                Attribute::all(),
+            )
             .property(
                 js_string!("roundingPriority"),
                 nf.digit_options.rounding_priority.to_js_string(),
@@ -755,7 +938,10 @@ fn unwrap_number_format(nf: &JsValue, context: &mut Context) -> JsResult<JsObjec
 /// Abstract operation [`ToIntlMathematicalValue ( value )`][spec].
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-tointlmathematicalvalue
-fn to_intl_mathematical_value(value: &JsValue, context: &mut Context) -> JsResult<FixedDecimal> {
+pub(crate) fn to_intl_mathematical_value(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<FixedDecimal> {
     // 1. Let primValue be ? ToPrimitive(value, number).
     let prim_value = value.to_primitive(context, PreferredType::Number)?;
 
@@ -849,3 +1035,61 @@ pub(crate) fn js_string_to_fixed_decimal(string: &JsString) -> Option<FixedDecim
 
     FixedDecimal::try_from(s.as_bytes()).ok()
 }
+
+/// Splits the string produced by `formatter` for `number` into its `minusSign` or `plusSign`,
+/// `integer`, `group`, `decimal` and `fraction` parts.
+///
+/// `FixedDecimalFormatter` doesn't annotate its output yet, so the parts are recovered by
+/// comparing the formatted number with its formatted absolute value and integer part.
+pub(crate) fn partition_fixed_decimal(
+    formatter: &FixedDecimalFormatter,
+    number: &FixedDecimal,
+) -> Vec<(&'static str, String)> {
+    let string = formatter.format_to_string(number);
+
+    let mut abs = number.clone();
+    abs.set_sign(fixed_decimal::Sign::None);
+    let abs_string = formatter.format_to_string(&abs);
+
+    let mut integer = abs.clone();
+    integer.trunc(0);
+    let integer_string = formatter.format_to_string(&integer);
+
+    let mut parts = Vec::new();
+
+    let start = string.find(&abs_string).unwrap_or_default();
+    if start > 0 {
+        let typ = if number.sign() == fixed_decimal::Sign::Positive {
+            "plusSign"
+        } else {
+            "minusSign"
+        };
+        parts.push((typ, string[..start].to_owned()));
+    }
+
+    let (mut rest, fraction) = abs_string.split_at(integer_string.len().min(abs_string.len()));
+    while let Some(c) = rest.chars().next() {
+        let is_digit = c.is_numeric();
+        let len = rest
+            .char_indices()
+            .find(|(_, c)| c.is_numeric() != is_digit)
+            .map_or(rest.len(), |(i, _)| i);
+        let (group, tail) = rest.split_at(len);
+        parts.push((if is_digit { "integer" } else { "group" }, group.to_owned()));
+        rest = tail;
+    }
+
+    if !fraction.is_empty() {
+        let decimal_len = fraction
+            .char_indices()
+            .find(|(_, c)| c.is_numeric())
+            .map_or(fraction.len(), |(i, _)| i);
+        let (decimal, fraction) = fraction.split_at(decimal_len);
+        parts.push(("decimal", decimal.to_owned()));
+        if !fraction.is_empty() {
+            parts.push(("fraction", fraction.to_owned()));
+        }
+    }
+
+    parts
+}
@@ -10,7 +10,9 @@ use crate::{
         intl::options::{default_number_option, get_number_option},
         options::{get_option, OptionType, ParsableOptionType, RoundingMode},
     },
-    js_string, Context, JsNativeError, JsObject, JsResult, JsString,
+    js_string,
+    string::JsStr,
+    Context, JsNativeError, JsObject, JsResult, JsString,
 };
 
 #[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
@@ -256,11 +258,12 @@ impl Unit {
         if self.denominator.is_empty() {
             js_string!(self.numerator)
         } else {
-            // TODO: this is not optimal for now, but the new JS strings should
-            // allow us to optimize this to simple casts from ASCII to JsString.
-            let numerator: Vec<u16> = self.numerator.encode_utf16().collect();
-            let denominator: Vec<u16> = self.denominator.encode_utf16().collect();
-            js_string!(&numerator, utf16!("-per-"), &denominator)
+            // Unit identifiers are ASCII, so they can be used as Latin-1 strings directly.
+            js_string!(
+                JsStr::Latin1(self.numerator.as_bytes()),
+                JsStr::Latin1(b"-per-"),
+                JsStr::Latin1(self.denominator.as_bytes())
+            )
         }
     }
 }
@@ -1,5 +1,9 @@
-use crate::builtins::intl::number_format::RoundingIncrement;
+use crate::{
+    builtins::intl::number_format::RoundingIncrement, js_string, run_test_actions,
+    JsNativeErrorKind, TestAction,
+};
 use fixed_decimal::RoundingIncrement::*;
+use indoc::indoc;
 
 #[test]
 fn u16_to_rounding_increment_sunny_day() {
@@ -39,3 +43,119 @@ fn u16_to_rounding_increment_rainy_day() {
         assert!(RoundingIncrement::from_u16(num).is_none());
     }
 }
+
+#[test]
+fn format_range() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en').formatRange(3, 5)",
+            js_string!("3–5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { maximumFractionDigits: 0 }).formatRange(2.9, 3.1)",
+            js_string!("~3"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.NumberFormat('en').formatRange(3)",
+            JsNativeErrorKind::Type,
+            "`formatRange` requires both `start` and `end` values",
+        ),
+    ]);
+}
+
+#[test]
+fn format_range_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function parts(start, end) {
+                return new Intl.NumberFormat('en', { maximumFractionDigits: 0 })
+                    .formatRangeToParts(start, end)
+                    .map(p => `${p.type}:${p.value}:${p.source}`)
+                    .join();
+            }
+        "#}),
+        TestAction::assert_eq(
+            "parts(1000, 2000)",
+            js_string!(
+                "integer:1:startRange,group:,:startRange,integer:000:startRange,\
+                 literal:–:shared,\
+                 integer:2:endRange,group:,:endRange,integer:000:endRange"
+            ),
+        ),
+        TestAction::assert_eq(
+            "parts(2.9, 3.1)",
+            js_string!("approximatelySign:~:shared,integer:3:shared"),
+        ),
+    ]);
+}
+
+#[test]
+fn rounding_increment() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function format(value, roundingMode) {
+                return new Intl.NumberFormat('en', {
+                    roundingIncrement: 5,
+                    minimumFractionDigits: 2,
+                    maximumFractionDigits: 2,
+                    roundingMode,
+                }).format(value);
+            }
+        "#}),
+        TestAction::assert_eq("format(1.23)", js_string!("1.25")),
+        TestAction::assert_eq("format(1.22)", js_string!("1.20")),
+        TestAction::assert_eq("format(1.24, 'floor')", js_string!("1.20")),
+        TestAction::assert_eq("format(1.21, 'ceil')", js_string!("1.25")),
+        TestAction::assert_native_error(
+            "new Intl.NumberFormat('en', { roundingIncrement: 5, maximumFractionDigits: 2 })",
+            JsNativeErrorKind::Range,
+            "option `roundingIncrement` invalid for the current set of options",
+        ),
+    ]);
+}
+
+#[test]
+fn use_grouping() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function format(value, useGrouping) {
+                return new Intl.NumberFormat('en', { useGrouping }).format(value);
+            }
+        "#}),
+        TestAction::assert_eq("format(1000, 'min2')", js_string!("1000")),
+        TestAction::assert_eq("format(10000, 'min2')", js_string!("10,000")),
+        TestAction::assert_eq("format(1000, 'always')", js_string!("1,000")),
+        TestAction::assert_eq("format(1000, false)", js_string!("1000")),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { useGrouping: 'min2' }).resolvedOptions().useGrouping",
+            js_string!("min2"),
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options_order() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var keys = Object.keys(
+                new Intl.NumberFormat('en', { roundingMode: 'halfEven' }).resolvedOptions()
+            );
+        "#}),
+        TestAction::assert_eq(
+            "keys.join()",
+            js_string!(
+                "locale,numberingSystem,style,minimumIntegerDigits,minimumFractionDigits,\
+                 maximumFractionDigits,useGrouping,notation,signDisplay,roundingIncrement,\
+                 roundingMode,roundingPriority,trailingZeroDisplay"
+            ),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en').resolvedOptions().numberingSystem",
+            js_string!("latn"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { roundingMode: 'halfEven' }).resolvedOptions().roundingMode",
+            js_string!("halfEven"),
+        ),
+    ]);
+}
@@ -1,4 +1,6 @@
 mod options;
+#[cfg(test)]
+mod tests;
 
 use boa_gc::{Finalize, Trace};
 use boa_macros::utf16;
@@ -218,11 +220,11 @@ impl PluralRules {
         // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
         let plural_rules = this.as_object().map(JsObject::borrow).ok_or_else(|| {
             JsNativeError::typ()
-                .with_message("`select_range` can only be called on an `Intl.PluralRules` object")
+                .with_message("`selectRange` can only be called on an `Intl.PluralRules` object")
         })?;
         let plural_rules = plural_rules.downcast_ref::<Self>().ok_or_else(|| {
             JsNativeError::typ()
-                .with_message("`select_range` can only be called on an `Intl.PluralRules` object")
+                .with_message("`selectRange` can only be called on an `Intl.PluralRules` object")
         })?;
 
         // 3. If start is undefined or end is undefined, throw a TypeError exception.
@@ -245,7 +247,7 @@ impl PluralRules {
 
         // 1. If x is NaN or y is NaN, throw a RangeError exception.
         if x.is_nan() || y.is_nan() {
-            return Err(JsNativeError::typ()
+            return Err(JsNativeError::range()
                 .with_message("extremum of range cannot be NaN")
                 .into());
         }
@@ -439,7 +441,6 @@ impl PluralRules {
 }
 
 #[derive(Debug)]
-#[allow(unused)] // Will be used when we implement `selectRange`
 struct ResolvedPlural {
     category: PluralCategory,
     formatted: Option<FixedDecimal>,
@@ -0,0 +1,94 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn select_cardinal() {
+    run_test_actions([
+        TestAction::run("var en = new Intl.PluralRules('en');"),
+        TestAction::assert_eq("en.select(0)", js_string!("other")),
+        TestAction::assert_eq("en.select(1)", js_string!("one")),
+        TestAction::assert_eq("en.select(1.0)", js_string!("one")),
+        TestAction::assert_eq("en.select(2)", js_string!("other")),
+        TestAction::assert_eq("en.select(NaN)", js_string!("other")),
+        TestAction::assert_eq("en.select(Infinity)", js_string!("other")),
+        TestAction::assert_eq("new Intl.PluralRules('ar').select(3)", js_string!("few")),
+    ]);
+}
+
+#[test]
+fn select_visible_fraction_digits() {
+    // In English, "1.0" has a visible fraction digit and is therefore `other`, whereas "1" is
+    // `one`. In French, both are `one`.
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.PluralRules('en', { minimumFractionDigits: 1 }).select(1)",
+            js_string!("other"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.PluralRules('fr', { minimumFractionDigits: 1 }).select(1)",
+            js_string!("one"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.PluralRules('en', { maximumFractionDigits: 0 }).select(1.2)",
+            js_string!("one"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.PluralRules('en', { maximumSignificantDigits: 1 }).select(1.4)",
+            js_string!("one"),
+        ),
+    ]);
+}
+
+#[test]
+fn select_ordinal() {
+    run_test_actions([
+        TestAction::run(
+            "var ordinal = new Intl.PluralRules('en', { type: 'ordinal' });
+             var categories = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 101].map((n) => ordinal.select(n));",
+        ),
+        TestAction::assert_eq(
+            "categories.join()",
+            js_string!("one,two,few,other,other,other,other,one,two,few,one"),
+        ),
+        TestAction::assert_eq(
+            "ordinal.resolvedOptions().pluralCategories.join()",
+            js_string!("one,two,few,other"),
+        ),
+    ]);
+}
+
+#[test]
+fn select_range() {
+    run_test_actions([
+        TestAction::run("var en = new Intl.PluralRules('en');"),
+        TestAction::assert_eq("en.selectRange(1, 2)", js_string!("other")),
+        TestAction::assert_eq("en.selectRange(1, 1)", js_string!("one")),
+        TestAction::assert_native_error(
+            "en.selectRange(1)",
+            JsNativeErrorKind::Type,
+            "extremum of range cannot be `undefined`",
+        ),
+        TestAction::assert_native_error(
+            "en.selectRange(NaN, 1)",
+            JsNativeErrorKind::Range,
+            "extremum of range cannot be NaN",
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(
+            "var options = new Intl.PluralRules('en', {
+                minimumFractionDigits: 2,
+                maximumFractionDigits: 3,
+            }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.locale", js_string!("en")),
+        TestAction::assert_eq("options.type", js_string!("cardinal")),
+        TestAction::assert_eq("options.minimumIntegerDigits", 1),
+        TestAction::assert_eq("options.minimumFractionDigits", 2),
+        TestAction::assert_eq("options.maximumFractionDigits", 3),
+        TestAction::assert_eq("options.pluralCategories.join()", js_string!("one,other")),
+    ]);
+}
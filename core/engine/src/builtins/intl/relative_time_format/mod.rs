@@ -0,0 +1,525 @@
+use boa_gc::{Finalize, Trace};
+use boa_macros::utf16;
+use boa_profiler::Profiler;
+use fixed_decimal::{FixedDecimal, FloatPrecision, Sign};
+use icu_decimal::{options::FixedDecimalFormatterOptions, FixedDecimalFormatter};
+use icu_locid::{extensions::unicode::Value, Locale};
+use icu_provider::DataLocale;
+use icu_relativetime::{
+    options::Numeric, provider::LongDayRelativeTimeFormatDataV1Marker, RelativeTimeFormatter,
+    RelativeTimeFormatterOptions,
+};
+use writeable::Writeable;
+
+use crate::{
+    builtins::{
+        options::{get_option, RoundingMode},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, ObjectInitializer},
+    property::Attribute,
+    realm::Realm,
+    string::common::StaticJsStrings,
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales},
+    number_format::{
+        partition_fixed_decimal, DigitFormatOptions, Extrema, NumberFormat,
+        NumberFormatLocaleOptions, RoundingIncrement, RoundingPriority, RoundingType,
+        TrailingZeroDisplay,
+    },
+    options::{coerce_options_to_object, IntlOptions},
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `RelativeTimeFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct RelativeTimeFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: Style,
+    numeric: Numeric,
+}
+
+impl Service for RelativeTimeFormat {
+    type LangMarker = LongDayRelativeTimeFormatDataV1Marker;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        // The only relevant extension key is "nu", which is resolved in the same way as for
+        // `Intl.NumberFormat`.
+        <NumberFormat as Service>::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for RelativeTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.RelativeTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 2)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 2)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for RelativeTimeFormat {
+    const NAME: JsString = StaticJsStrings::RELATIVE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for RelativeTimeFormat {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::relative_time_format;
+
+    /// Constructor [`Intl.RelativeTimeFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `RelativeTimeFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.RelativeTimeFormat` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let relativeTimeFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.RelativeTimeFormat.prototype%", « [[InitializedRelativeTimeFormat]], [[Locale]], [[LocaleData]], [[Style]], [[Numeric]], [[NumberFormat]], [[NumberingSystem]], [[PluralRules]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::relative_time_format,
+            context,
+        )?;
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 5. Let opt be a new Record.
+        // 6. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 7. Set opt.[[LocaleMatcher]] to matcher.
+        let matcher = get_option(&options, utf16!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 8. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 9. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 10. Set opt.[[nu]] to numberingSystem.
+        let numbering_system = get_option(&options, utf16!("numberingSystem"), context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions { numbering_system },
+        };
+
+        // 11. Let r be ResolveLocale(%Intl.RelativeTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.RelativeTimeFormat%.[[RelevantExtensionKeys]], %Intl.RelativeTimeFormat%.[[LocaleData]]).
+        // 12. Let locale be r.[[locale]].
+        // 13. Set relativeTimeFormat.[[Locale]] to locale.
+        // 14. Set relativeTimeFormat.[[LocaleData]] to r.[[LocaleData]].
+        // 15. Set relativeTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        );
+
+        // 16. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow" », "long").
+        // 17. Set relativeTimeFormat.[[Style]] to style.
+        let style = get_option(&options, utf16!("style"), context)?.unwrap_or_default();
+
+        // 18. Let numeric be ? GetOption(options, "numeric", string, « "always", "auto" », "always").
+        // 19. Set relativeTimeFormat.[[Numeric]] to numeric.
+        let numeric = get_option(&options, utf16!("numeric"), context)?.unwrap_or(Numeric::Always);
+
+        // 20. Let relativeTimeFormat.[[NumberFormat]] be ! Construct(%Intl.NumberFormat%, « locale »).
+        // 21. Let relativeTimeFormat.[[PluralRules]] be ! Construct(%Intl.PluralRules%, « locale »).
+        // Both are handled by the ICU4X formatter, which is created on demand for each unit.
+        let relative_time_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                numbering_system: intl_options.service_options.numbering_system,
+                style,
+                numeric,
+            },
+        );
+
+        // 22. Return relativeTimeFormat.
+        Ok(relative_time_format.into())
+    }
+}
+
+impl RelativeTimeFormat {
+    /// [`Intl.RelativeTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in relative
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.RelativeTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.format ( value, unit )`][spec].
+    ///
+    /// Formats a value according to the locale and formatting options of the
+    /// `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`format` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTime(relativeTimeFormat, value, unit).
+        let (value, unit) = validate_relative_time(value, &unit)?;
+        let formatter = rtf.native_formatter(unit, context.intl_provider())?;
+
+        Ok(js_string!(formatter.format(value).write_to_string().into_owned()).into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.formatToParts ( value, unit )`][spec].
+    ///
+    /// Returns an array of objects representing the relative time format in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTimeToParts(relativeTimeFormat, value, unit).
+
+        // Abstract operation `FormatRelativeTimeToParts ( relativeTimeFormat, value, unit )`
+        // https://tc39.es/ecma402/#sec-FormatRelativeTimeToParts
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let (value, unit) = validate_relative_time(value, &unit)?;
+        let formatted = rtf
+            .native_formatter(unit, context.intl_provider())?
+            .format(value.clone())
+            .write_to_string()
+            .into_owned();
+
+        // The ICU4X formatter doesn't expose the boundaries of the formatted number, so we find
+        // them by formatting the absolute value of the number on its own. Outputs such as
+        // "yesterday" won't contain the number at all, and are returned as a single literal.
+        let mut number = value;
+        number.set_sign(Sign::None);
+        let number_formatter = FixedDecimalFormatter::try_new_unstable(
+            context.intl_provider(),
+            &DataLocale::from(&rtf.locale),
+            FixedDecimalFormatterOptions::default(),
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        let number_string = number_formatter.format_to_string(&number);
+
+        let mut parts = Vec::new();
+        if let Some(start) = formatted.find(&number_string) {
+            let end = start + number_string.len();
+            if start > 0 {
+                parts.push(("literal", formatted[..start].to_owned(), false));
+            }
+            parts.extend(
+                partition_fixed_decimal(&number_formatter, &number)
+                    .into_iter()
+                    .map(|(typ, value)| (typ, value, true)),
+            );
+            if end < formatted.len() {
+                parts.push(("literal", formatted[end..].to_owned(), false));
+            }
+        } else {
+            parts.push(("literal", formatted, false));
+        }
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, (typ, value, has_unit)) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(utf16!("type"), js_string!(typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(utf16!("value"), js_string!(value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, then
+            if has_unit {
+                // i. Perform ! CreateDataPropertyOrThrow(O, "unit", part.[[Unit]]).
+                o.create_data_property_or_throw(utf16!("unit"), unit.to_js_string(), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and formatting options
+    /// computed during the construction of the current `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`resolvedOptions` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 16, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of relativeTimeFormat's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(rtf.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("style"),
+                rtf.style.to_js_string(),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numeric"),
+                if rtf.numeric == Numeric::Auto {
+                    js_string!("auto")
+                } else {
+                    js_string!("always")
+                },
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numberingSystem"),
+                rtf.numbering_system
+                    .as_ref()
+                    .map_or_else(|| js_string!("latn"), |nu| js_string!(nu.to_string())),
+                Attribute::all(),
+            );
+
+        // 5. Return options.
+        Ok(options.build().into())
+    }
+
+    /// Creates the ICU4X formatter for the provided `unit`, using the locale, style and numeric
+    /// options of this `Intl.RelativeTimeFormat`.
+    fn native_formatter(
+        &self,
+        unit: Unit,
+        provider: &IntlProvider,
+    ) -> JsResult<RelativeTimeFormatter> {
+        let locale = DataLocale::from(&self.locale);
+        let options = RelativeTimeFormatterOptions {
+            numeric: self.numeric,
+        };
+
+        macro_rules! formatter {
+            ($($style:ident, $unit:ident => $ctor:ident;)*) => {
+                match (self.style, unit) {
+                    $(
+                        (Style::$style, Unit::$unit) => {
+                            RelativeTimeFormatter::$ctor(provider, &locale, options)
+                        }
+                    )*
+                }
+            };
+        }
+
+        formatter! {
+            Long, Second => try_new_long_second_unstable;
+            Long, Minute => try_new_long_minute_unstable;
+            Long, Hour => try_new_long_hour_unstable;
+            Long, Day => try_new_long_day_unstable;
+            Long, Week => try_new_long_week_unstable;
+            Long, Month => try_new_long_month_unstable;
+            Long, Quarter => try_new_long_quarter_unstable;
+            Long, Year => try_new_long_year_unstable;
+            Short, Second => try_new_short_second_unstable;
+            Short, Minute => try_new_short_minute_unstable;
+            Short, Hour => try_new_short_hour_unstable;
+            Short, Day => try_new_short_day_unstable;
+            Short, Week => try_new_short_week_unstable;
+            Short, Month => try_new_short_month_unstable;
+            Short, Quarter => try_new_short_quarter_unstable;
+            Short, Year => try_new_short_year_unstable;
+            Narrow, Second => try_new_narrow_second_unstable;
+            Narrow, Minute => try_new_narrow_minute_unstable;
+            Narrow, Hour => try_new_narrow_hour_unstable;
+            Narrow, Day => try_new_narrow_day_unstable;
+            Narrow, Week => try_new_narrow_week_unstable;
+            Narrow, Month => try_new_narrow_month_unstable;
+            Narrow, Quarter => try_new_narrow_quarter_unstable;
+            Narrow, Year => try_new_narrow_year_unstable;
+        }
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()).into())
+    }
+}
+
+/// The first steps of the abstract operation [`PartitionRelativeTimePattern ( relativeTimeFormat, value, unit )`][spec].
+///
+/// Validates the value and unit to format, rounding the value with the default digit options
+/// of `Intl.NumberFormat`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern
+fn validate_relative_time(value: f64, unit: &JsString) -> JsResult<(FixedDecimal, Unit)> {
+    // 1. If value is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+    if !value.is_finite() {
+        return Err(JsNativeError::range()
+            .with_message("cannot format a non-finite relative time")
+            .into());
+    }
+
+    // 2. Let unit be ? SingularRelativeTimeUnit(unit).
+    let unit = Unit::from_js_string(unit)?;
+
+    let digit_options = DigitFormatOptions {
+        minimum_integer_digits: 1,
+        rounding_increment: RoundingIncrement::from_u16(1)
+            .expect("1 must be a valid rounding increment"),
+        rounding_mode: RoundingMode::default(),
+        trailing_zero_display: TrailingZeroDisplay::default(),
+        rounding_type: RoundingType::FractionDigits(Extrema {
+            minimum: 0,
+            maximum: 3,
+        }),
+        rounding_priority: RoundingPriority::default(),
+    };
+
+    let mut value = FixedDecimal::try_from_f64(value, FloatPrecision::Floating)
+        .expect("`value` must be finite");
+    digit_options.format_fixed_decimal(&mut value);
+
+    Ok((value, unit))
+}
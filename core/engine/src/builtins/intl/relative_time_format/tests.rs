@@ -0,0 +1,95 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+#[ignore = "not exposed until the bundled ICU4X data includes the relative time keys"]
+fn format_numeric_auto() {
+    run_test_actions([
+        TestAction::run(
+            "function format(locale, numeric, value, unit) {
+                return new Intl.RelativeTimeFormat(locale, { numeric }).format(value, unit);
+            }",
+        ),
+        TestAction::assert_eq("format('en', 'auto', -1, 'day')", js_string!("yesterday")),
+        TestAction::assert_eq("format('en', 'always', -1, 'day')", js_string!("1 day ago")),
+        TestAction::assert_eq("format('en', 'auto', 1, 'days')", js_string!("tomorrow")),
+        TestAction::assert_eq("format('en', 'auto', 3, 'days')", js_string!("in 3 days")),
+        TestAction::assert_eq("format('es', 'auto', -1, 'day')", js_string!("ayer")),
+        TestAction::assert_eq(
+            "format('es', 'always', -1, 'day')",
+            js_string!("hace 1 día"),
+        ),
+        TestAction::assert_eq("format('de', 'auto', -1, 'day')", js_string!("gestern")),
+        TestAction::assert_eq("format('de', 'always', -1, 'day')", js_string!("vor 1 Tag")),
+    ]);
+}
+
+#[test]
+#[ignore = "not exposed until the bundled ICU4X data includes the relative time keys"]
+fn format_units() {
+    run_test_actions([
+        TestAction::run("var rtf = new Intl.RelativeTimeFormat('en');"),
+        TestAction::assert_eq("rtf.format(-5, 'seconds')", js_string!("5 seconds ago")),
+        TestAction::assert_eq("rtf.format(1, 'minute')", js_string!("in 1 minute")),
+        TestAction::assert_eq("rtf.format(-2, 'hours')", js_string!("2 hours ago")),
+        TestAction::assert_eq("rtf.format(2, 'weeks')", js_string!("in 2 weeks")),
+        TestAction::assert_eq("rtf.format(-1.5, 'month')", js_string!("1.5 months ago")),
+        TestAction::assert_eq("rtf.format(3, 'quarters')", js_string!("in 3 quarters")),
+        TestAction::assert_eq("rtf.format(10, 'year')", js_string!("in 10 years")),
+        TestAction::assert_native_error(
+            "rtf.format(1, 'decade')",
+            JsNativeErrorKind::Range,
+            "`decade` is not a valid relative time unit",
+        ),
+        TestAction::assert_native_error(
+            "rtf.format(NaN, 'day')",
+            JsNativeErrorKind::Range,
+            "cannot format a non-finite relative time",
+        ),
+    ]);
+}
+
+#[test]
+#[ignore = "not exposed until the bundled ICU4X data includes the relative time keys"]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(
+            "var rtf = new Intl.RelativeTimeFormat('en', { numeric: 'auto' });
+             function parts(value, unit) {
+                return rtf.formatToParts(value, unit).map((part) =>
+                    part.type + ':' + part.value + ('unit' in part ? ':' + part.unit : '')
+                ).join('|');
+             }",
+        ),
+        TestAction::assert_eq("parts(-1, 'day')", js_string!("literal:yesterday")),
+        TestAction::assert_eq(
+            "parts(1000, 'days')",
+            js_string!("literal:in |integer:1:day|group:,:day|integer:000:day|literal: days"),
+        ),
+        TestAction::assert_eq(
+            "parts(-2.5, 'hour')",
+            js_string!("integer:2:hour|decimal:.:hour|fraction:5:hour|literal: hours ago"),
+        ),
+    ]);
+}
+
+#[test]
+#[ignore = "not exposed until the bundled ICU4X data includes the relative time keys"]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(
+            "var options = new Intl.RelativeTimeFormat('en', {
+                style: 'narrow',
+                numeric: 'auto',
+            }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.locale", js_string!("en")),
+        TestAction::assert_eq("options.style", js_string!("narrow")),
+        TestAction::assert_eq("options.numeric", js_string!("auto")),
+        TestAction::assert_eq("options.numberingSystem", js_string!("latn")),
+        TestAction::assert_native_error(
+            "new Intl.RelativeTimeFormat('en', { numeric: 'sometimes' })",
+            JsNativeErrorKind::Range,
+            "provided string was not `always` or `auto`",
+        ),
+    ]);
+}
@@ -121,7 +121,8 @@ impl SegmentIterator {
             let segmenter = segmenter
                 .downcast_ref::<Segmenter>()
                 .expect("segment iterator object should contain a segmenter");
-            let mut segments = segmenter.native.segment(string);
+            let string = string.to_vec();
+            let mut segments = segmenter.native.segment(&string);
             // the first elem is always 0.
             segments.next();
             segments
@@ -25,6 +25,8 @@ use crate::{
 mod iterator;
 mod options;
 mod segments;
+#[cfg(test)]
+mod tests;
 pub(crate) use iterator::*;
 pub(crate) use options::*;
 pub(crate) use segments::*;
@@ -301,7 +303,7 @@ fn create_segment_data_object(
     let start = range.start;
 
     // 6. Let segment be the substring of string from startIndex to endIndex.
-    let segment = js_string!(&string[range]);
+    let segment = js_string!(string.get_expect(range));
 
     // 5. Let result be OrdinaryObjectCreate(%Object.prototype%).
     let object = &mut ObjectInitializer::new(context);
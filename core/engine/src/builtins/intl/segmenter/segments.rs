@@ -89,7 +89,8 @@ impl Segments {
         // 8. Let startIndex be ! FindBoundary(segmenter, string, n, before).
         // 9. Let endIndex be ! FindBoundary(segmenter, string, n, after).
         let (range, is_word_like) = {
-            let mut segments = segmenter.native.segment(&segments.string);
+            let string = segments.string.to_vec();
+            let mut segments = segmenter.native.segment(&string);
             std::iter::from_fn(|| segments.next().map(|i| (i, segments.is_word_like())))
                 .tuple_windows()
                 .find(|((i, _), (j, _))| (*i..*j).contains(&n))
@@ -110,14 +111,13 @@ impl Segments {
     fn iterator(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let segments be the this value.
         // 2. Perform ? RequireInternalSlot(segments, [[SegmentsSegmenter]]).
-        let segments = this.as_object().map(JsObject::borrow).ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("`containing` can only be called on a `Segments` object")
-        })?;
-        let segments = segments.downcast_ref::<Self>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("`containing` can only be called on a `Segments` object")
-        })?;
+        let segments = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`@@iterator` can only be called on a `Segments` object")
+            })?;
 
         // 3. Let segmenter be segments.[[SegmentsSegmenter]].
         // 4. Let string be segments.[[SegmentsString]].
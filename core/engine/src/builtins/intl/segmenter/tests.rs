@@ -0,0 +1,71 @@
+use indoc::indoc;
+
+use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+const INPUT: &str = indoc! {r#"
+    var input = "किताब 👨‍👩‍👧 hello world! Bye?";
+    function segment(granularity) {
+        var segmenter = new Intl.Segmenter("en", { granularity });
+        return Array.from(segmenter.segment(input), (data) =>
+            data.segment + "@" + data.index + ("isWordLike" in data ? ":" + data.isWordLike : "")
+        ).join("|");
+    }
+"#};
+
+#[test]
+fn segment_graphemes() {
+    run_test_actions([
+        TestAction::run(INPUT),
+        TestAction::assert_eq(
+            "segment('grapheme')",
+            js_string!(
+                "कि@0|ता@2|ब@4| @5|👨‍👩‍👧@6| @14|h@15|e@16|l@17|l@18|o@19| @20\
+                 |w@21|o@22|r@23|l@24|d@25|!@26| @27|B@28|y@29|e@30|?@31"
+            ),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Segmenter().resolvedOptions().granularity",
+            js_string!("grapheme"),
+        ),
+    ]);
+}
+
+#[test]
+fn segment_words() {
+    run_test_actions([
+        TestAction::run(INPUT),
+        TestAction::assert_eq(
+            "segment('word')",
+            js_string!(
+                "किताब@0:true| @5:false|👨‍👩‍👧@6:false| @14:false|hello@15:true| @20:false\
+                 |world@21:true|!@26:false| @27:false|Bye@28:true|?@31:false"
+            ),
+        ),
+        TestAction::assert_eq(
+            "segment('sentence')",
+            js_string!("किताब 👨‍👩‍👧 hello world! @0|Bye?@28"),
+        ),
+    ]);
+}
+
+#[test]
+fn segments_containing() {
+    run_test_actions([
+        TestAction::run(INPUT),
+        TestAction::run(
+            "var segments = new Intl.Segmenter('en', { granularity: 'word' }).segment(input);",
+        ),
+        TestAction::assert_eq("segments.containing(3).segment", js_string!("किताब")),
+        TestAction::assert_eq("segments.containing(10).segment", js_string!("👨‍👩‍👧")),
+        TestAction::assert_eq("segments.containing(10).index", 6),
+        TestAction::assert("segments.containing(17).isWordLike"),
+        TestAction::assert_eq("segments.containing(-1)", JsValue::undefined()),
+        TestAction::assert_eq("segments.containing(input.length)", JsValue::undefined()),
+        TestAction::assert_eq("[...segments].length", 11),
+        TestAction::assert_native_error(
+            "new Intl.Segmenter('en', { granularity: 'line' })",
+            JsNativeErrorKind::Range,
+            "provided string was not `grapheme`, `word` or `sentence`",
+        ),
+    ]);
+}
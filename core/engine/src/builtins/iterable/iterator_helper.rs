@@ -0,0 +1,449 @@
+//! Boa's implementation of the `%IteratorHelperPrototype%` object and the iterator helper objects
+//! returned by the lazy methods of `%Iterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator-helper-objects
+
+use crate::{
+    builtins::{
+        iterable::{
+            create_iter_result_object, get_iterator_flattenable, IteratorRecord, PrimitiveHandling,
+        },
+        BuiltInBuilder, IntrinsicObject,
+    },
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsData, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The state of the abstract closure of an iterator helper, which behaves like the state of a
+/// generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The operation performed by an iterator helper, with the variables of its abstract closure.
+#[derive(Debug, Clone, Finalize, Trace)]
+pub(crate) enum HelperKind {
+    /// `Iterator.prototype.map ( mapper )`
+    Map { mapper: JsObject, counter: u64 },
+
+    /// `Iterator.prototype.filter ( predicate )`
+    Filter { predicate: JsObject, counter: u64 },
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// A `remaining` count of `None` represents `+∞`.
+    Take { remaining: Option<u64> },
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// A `remaining` count of `None` represents `+∞`.
+    Drop { remaining: Option<u64> },
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    FlatMap {
+        mapper: JsObject,
+        counter: u64,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+/// An iterator helper object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator-helper-objects
+#[derive(Debug, Finalize, Trace, JsData)]
+pub(crate) struct IteratorHelper {
+    /// The `[[UnderlyingIterator]]` internal slot.
+    underlying: IteratorRecord,
+    kind: HelperKind,
+    #[unsafe_ignore_trace]
+    state: HelperState,
+}
+
+impl IntrinsicObject for IteratorHelper {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(
+                realm
+                    .intrinsics()
+                    .objects()
+                    .iterator_prototypes()
+                    .iterator(),
+            )
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                js_string!("Iterator Helper"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().iterator_prototypes().iterator_helper()
+    }
+}
+
+impl IteratorHelper {
+    /// `CreateIteratorFromClosure ( closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] » )`
+    ///
+    /// Creates an iterator helper that performs `kind` on the values of `underlying`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createiteratorfromclosure
+    pub(crate) fn create(
+        underlying: IteratorRecord,
+        kind: HelperKind,
+        context: &mut Context,
+    ) -> JsValue {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .iterator_helper(),
+            Self {
+                underlying,
+                kind,
+                state: HelperState::SuspendedStart,
+            },
+        )
+        .into()
+    }
+
+    /// `GeneratorValidate ( generator, "Iterator Helper" )`
+    ///
+    /// Returns the iterator helper object and its state, or throws if it is already running.
+    fn validate(this: &JsValue) -> JsResult<(JsObject<Self>, HelperState)> {
+        let object = this
+            .as_object()
+            .and_then(|object| object.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("Iterator Helper method called on non helper")
+            })?;
+
+        let state = object.borrow().data.state;
+        if state == HelperState::Executing {
+            return Err(JsNativeError::typ()
+                .with_message("Iterator Helper is already running")
+                .into());
+        }
+
+        Ok((object, state))
+    }
+
+    /// `%IteratorHelperPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-%iteratorhelperprototype%.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+        let (object, state) = Self::validate(this)?;
+        if state == HelperState::Completed {
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        }
+
+        // The object can't stay borrowed while the closure runs, since the callbacks may access
+        // it, so the closure works on a copy of its variables that is stored back afterwards.
+        let (mut underlying, mut kind) = {
+            let mut helper = object.borrow_mut();
+            helper.data.state = HelperState::Executing;
+            (helper.data.underlying.clone(), helper.data.kind.clone())
+        };
+
+        let result = Self::resume(&mut underlying, &mut kind, context);
+
+        let mut helper = object.borrow_mut();
+        helper.data.underlying = underlying;
+        helper.data.kind = kind;
+        match result {
+            Ok(Some(value)) => {
+                helper.data.state = HelperState::SuspendedYield;
+                drop(helper);
+                Ok(create_iter_result_object(value, false, context))
+            }
+            Ok(None) => {
+                helper.data.state = HelperState::Completed;
+                drop(helper);
+                Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                ))
+            }
+            Err(err) => {
+                helper.data.state = HelperState::Completed;
+                Err(err)
+            }
+        }
+    }
+
+    /// `%IteratorHelperPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-%iteratorhelperprototype%.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        // 3. Assert: O has a [[GeneratorState]] slot.
+        let (object, state) = Self::validate(this)?;
+
+        let (underlying, kind) = {
+            let mut helper = object.borrow_mut();
+            match state {
+                // 4. If O.[[GeneratorState]] is suspended-start, then
+                //     a. Set O.[[GeneratorState]] to completed.
+                HelperState::SuspendedStart => helper.data.state = HelperState::Completed,
+                HelperState::SuspendedYield => helper.data.state = HelperState::Executing,
+                HelperState::Completed => {
+                    drop(helper);
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ));
+                }
+                HelperState::Executing => unreachable!("the state was validated above"),
+            }
+            (helper.data.underlying.clone(), helper.data.kind.clone())
+        };
+
+        let result = if state == HelperState::SuspendedStart {
+            //     b. NOTE: Once a generator enters the completed state it never leaves it and its
+            //        associated execution context is never resumed. Any execution state associated
+            //        with O can be discarded at this point.
+            //     c. Perform ? IteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused)).
+            underlying.close(Ok(JsValue::undefined()), context)
+        } else {
+            // 5. Let C be Completion { [[Type]]: return, [[Value]]: undefined, [[Target]]: empty }.
+            // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+            //
+            // The return completion is received by the `Yield` of the closure, which closes
+            // the inner iterator of `flatMap` first and then the underlying iterator.
+            let result = match &kind {
+                HelperKind::FlatMap {
+                    inner: Some(inner), ..
+                } => close_if_abrupt(
+                    inner.close(Ok(JsValue::undefined()), context),
+                    &underlying,
+                    context,
+                ),
+                _ => Ok(JsValue::undefined()),
+            };
+            let result = result.and_then(|_| underlying.close(Ok(JsValue::undefined()), context));
+            object.borrow_mut().data.state = HelperState::Completed;
+            result
+        };
+        result?;
+
+        //     d. Return CreateIterResultObject(undefined, true).
+        Ok(create_iter_result_object(
+            JsValue::undefined(),
+            true,
+            context,
+        ))
+    }
+
+    /// Resumes the abstract closure of the iterator helper until it yields a value, returning
+    /// `None` if it completed instead.
+    fn resume(
+        underlying: &mut IteratorRecord,
+        kind: &mut HelperKind,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        match kind {
+            HelperKind::Map { mapper, counter } => {
+                // a. Let value be ? IteratorStepValue(iterated).
+                // b. If value is done, return ReturnCompletion(undefined).
+                if underlying.step(context)? {
+                    return Ok(None);
+                }
+                let value = underlying.value(context)?;
+
+                // c. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                // d. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = close_if_abrupt(mapped, underlying, context)?;
+
+                // g. Set counter to counter + 1.
+                *counter += 1;
+
+                // e. Let completion be Completion(Yield(mapped)).
+                Ok(Some(mapped))
+            }
+            HelperKind::Filter { predicate, counter } => loop {
+                // a. Let value be ? IteratorStepValue(iterated).
+                // b. If value is done, return ReturnCompletion(undefined).
+                if underlying.step(context)? {
+                    return Ok(None);
+                }
+                let value = underlying.value(context)?;
+
+                // c. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                let selected = predicate.call(
+                    &JsValue::undefined(),
+                    &[value.clone(), (*counter).into()],
+                    context,
+                );
+
+                // d. IfAbruptCloseIterator(selected, iterated).
+                let selected = close_if_abrupt(selected, underlying, context)?;
+
+                // f. Set counter to counter + 1.
+                *counter += 1;
+
+                // e. If ToBoolean(selected) is true, then
+                //     i. Let completion be Completion(Yield(value)).
+                if selected.to_boolean() {
+                    return Ok(Some(value));
+                }
+            },
+            HelperKind::Take { remaining } => {
+                // a. If remaining = 0, then
+                if *remaining == Some(0) {
+                    // i. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                    underlying.close(Ok(JsValue::undefined()), context)?;
+                    return Ok(None);
+                }
+
+                // b. If remaining ≠ +∞, then
+                if let Some(remaining) = remaining {
+                    // i. Set remaining to remaining - 1.
+                    *remaining -= 1;
+                }
+
+                // c. Let value be ? IteratorStepValue(iterated).
+                // d. If value is done, return ReturnCompletion(undefined).
+                if underlying.step(context)? {
+                    return Ok(None);
+                }
+
+                // e. Let completion be Completion(Yield(value)).
+                underlying.value(context).map(Some)
+            }
+            HelperKind::Drop { remaining } => {
+                // b. Repeat, while remaining > 0,
+                while *remaining != Some(0) {
+                    // i. If remaining ≠ +∞, then
+                    if let Some(remaining) = remaining {
+                        // 1. Set remaining to remaining - 1.
+                        *remaining -= 1;
+                    }
+
+                    // ii. Let next be ? IteratorStep(iterated).
+                    // iii. If next is done, return ReturnCompletion(undefined).
+                    if underlying.step(context)? {
+                        return Ok(None);
+                    }
+                }
+
+                // c. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                if underlying.step(context)? {
+                    return Ok(None);
+                }
+
+                //     iii. Let completion be Completion(Yield(value)).
+                underlying.value(context).map(Some)
+            }
+            HelperKind::FlatMap {
+                mapper,
+                counter,
+                inner,
+            } => loop {
+                // h. Repeat, while innerAlive is true,
+                if let Some(inner_iterator) = inner {
+                    // i. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+                    // ii. IfAbruptCloseIterator(innerValue, iterated).
+                    let done = close_if_abrupt(inner_iterator.step(context), underlying, context)?;
+
+                    // iv. Else,
+                    if !done {
+                        let value =
+                            close_if_abrupt(inner_iterator.value(context), underlying, context)?;
+
+                        // 1. Let completion be Completion(Yield(innerValue)).
+                        return Ok(Some(value));
+                    }
+
+                    // iii. If innerValue is done, then
+                    //     1. Set innerAlive to false.
+                    *inner = None;
+
+                    // i. Set counter to counter + 1.
+                    *counter += 1;
+                }
+
+                // a. Let value be ? IteratorStepValue(iterated).
+                // b. If value is done, return ReturnCompletion(undefined).
+                if underlying.step(context)? {
+                    return Ok(None);
+                }
+                let value = underlying.value(context)?;
+
+                // c. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                // d. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = close_if_abrupt(mapped, underlying, context)?;
+
+                // e. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+                let inner_iterator =
+                    get_iterator_flattenable(&mapped, PrimitiveHandling::RejectPrimitives, context);
+
+                // f. IfAbruptCloseIterator(innerIterator, iterated).
+                // g. Let innerAlive be true.
+                *inner = Some(close_if_abrupt(inner_iterator, underlying, context)?);
+            },
+        }
+    }
+}
+
+/// `IfAbruptCloseIterator ( value, iteratorRecord )` for an abstract closure, which can't return
+/// the result of the closing from the function.
+fn close_if_abrupt<T>(
+    value: JsResult<T>,
+    iterator_record: &IteratorRecord,
+    context: &mut Context,
+) -> JsResult<T> {
+    value.map_err(|err| {
+        iterator_record
+            .close(Err(err), context)
+            .expect_err("closing an iterator with a throw completion always throws")
+    })
+}
@@ -1,20 +1,39 @@
 //! Boa's implementation of ECMAScript's `IteratorRecord` and iterator prototype objects.
 
 use crate::{
-    builtins::{BuiltInBuilder, IntrinsicObject},
-    context::intrinsics::Intrinsics,
+    builtins::{
+        array::ArrayIterator, Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::JsObject,
+    object::{
+        internal_methods::{get_prototype_from_constructor, InternalMethodContext},
+        JsObject, CONSTRUCTOR,
+    },
+    property::{Attribute, PropertyKey},
     realm::Realm,
+    string::common::StaticJsStrings,
     symbol::JsSymbol,
-    Context, JsResult, JsValue,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
 mod async_from_sync_iterator;
+mod iterator_helper;
+mod wrap_for_valid_iterator;
+
+#[cfg(test)]
+mod tests;
+
 pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
+pub(crate) use iterator_helper::IteratorHelper;
+pub(crate) use wrap_for_valid_iterator::WrapForValidIterator;
+
+use iterator_helper::HelperKind;
 
 /// `IfAbruptCloseIterator ( value, iteratorRecord )`
 ///
@@ -71,12 +90,37 @@ pub struct IteratorPrototypes {
     /// The `ForInIteratorPrototype` prototype object.
     for_in: JsObject,
 
+    /// The `%IteratorHelperPrototype%` prototype object.
+    iterator_helper: JsObject,
+
+    /// The `%WrapForValidIteratorPrototype%` prototype object.
+    wrap_for_valid_iterator: JsObject,
+
     /// The `%SegmentIteratorPrototype%` prototype object.
     #[cfg(feature = "intl")]
     segment: JsObject,
 }
 
 impl IteratorPrototypes {
+    /// Creates the uninitialized iterator prototypes, using `iterator` as `%Iterator.prototype%`.
+    pub(crate) fn new(iterator: JsObject) -> Self {
+        Self {
+            iterator,
+            async_iterator: JsObject::default(),
+            async_from_sync_iterator: JsObject::default(),
+            array: JsObject::default(),
+            set: JsObject::default(),
+            string: JsObject::default(),
+            regexp_string: JsObject::default(),
+            map: JsObject::default(),
+            for_in: JsObject::default(),
+            iterator_helper: JsObject::default(),
+            wrap_for_valid_iterator: JsObject::default(),
+            #[cfg(feature = "intl")]
+            segment: JsObject::default(),
+        }
+    }
+
     /// Returns the `ArrayIteratorPrototype` object.
     #[inline]
     #[must_use]
@@ -140,6 +184,20 @@ impl IteratorPrototypes {
         self.for_in.clone()
     }
 
+    /// Returns the `%IteratorHelperPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn iterator_helper(&self) -> JsObject {
+        self.iterator_helper.clone()
+    }
+
+    /// Returns the `%WrapForValidIteratorPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn wrap_for_valid_iterator(&self) -> JsObject {
+        self.wrap_for_valid_iterator.clone()
+    }
+
     /// Returns the `%SegmentIteratorPrototype%` object.
     #[inline]
     #[must_use]
@@ -149,27 +207,740 @@ impl IteratorPrototypes {
     }
 }
 
-/// `%IteratorPrototype%` object
+/// The `Iterator` constructor and the `%Iterator.prototype%` object.
 ///
 /// More information:
 ///  - [ECMA reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
-
 pub(crate) struct Iterator;
 
 impl IntrinsicObject for Iterator {
     fn init(realm: &Realm) {
-        let _timer = Profiler::global().start_event("Iterator Prototype", "init");
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .static_method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+        let get_constructor = BuiltInBuilder::callable(realm, Self::get_constructor)
+            .name(js_string!("get constructor"))
+            .build();
+        let set_constructor = BuiltInBuilder::callable(realm, Self::set_constructor)
+            .name(js_string!("set constructor"))
+            .length(1)
+            .build();
+        let get_to_string_tag = BuiltInBuilder::callable(realm, Self::get_to_string_tag)
+            .name(js_string!("get [Symbol.toStringTag]"))
+            .build();
+        let set_to_string_tag = BuiltInBuilder::callable(realm, Self::set_to_string_tag)
+            .name(js_string!("set [Symbol.toStringTag]"))
+            .length(1)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, js_string!("from"), 1)
+            .method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+            .accessor(
+                CONSTRUCTOR,
+                Some(get_constructor),
+                Some(set_constructor),
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .accessor(
+                JsSymbol::to_string_tag(),
+                Some(get_to_string_tag),
+                Some(set_to_string_tag),
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .method(Self::map, js_string!("map"), 1)
+            .method(Self::filter, js_string!("filter"), 1)
+            .method(Self::take, js_string!("take"), 1)
+            .method(Self::drop, js_string!("drop"), 1)
+            .method(Self::flat_map, js_string!("flatMap"), 1)
+            .method(Self::reduce, js_string!("reduce"), 1)
+            .method(Self::to_array, js_string!("toArray"), 0)
+            .method(Self::for_each, js_string!("forEach"), 1)
+            .method(Self::some, js_string!("some"), 1)
+            .method(Self::every, js_string!("every"), 1)
+            .method(Self::find, js_string!("find"), 1)
             .build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
-        intrinsics.objects().iterator_prototypes().iterator()
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Iterator {
+    const NAME: JsString = StaticJsStrings::ITERATOR;
+}
+
+impl BuiltInConstructor for Iterator {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::iterator;
+
+    /// `Iterator ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator-constructor
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is either undefined or the active function object, throw a TypeError exception.
+        let is_active_function = match (new_target.as_object(), context.active_function_object()) {
+            (Some(new_target), Some(active_function)) => {
+                JsObject::equals(new_target, &active_function)
+            }
+            _ => false,
+        };
+        if new_target.is_undefined() || is_active_function {
+            return Err(JsNativeError::typ()
+                .with_message("the Iterator constructor is abstract and can only be subclassed")
+                .into());
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Iterator.prototype%").
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::iterator, context)?;
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            OrdinaryObject,
+        )
+        .into())
+    }
+}
+
+impl Iterator {
+    /// `Iterator.from ( O )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-string-primitives).
+        let iterator_record = get_iterator_flattenable(
+            args.get_or_undefined(0),
+            PrimitiveHandling::IterateStringPrimitives,
+            context,
+        )?;
+
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let has_instance = JsValue::ordinary_has_instance(
+            &context
+                .intrinsics()
+                .constructors()
+                .iterator()
+                .constructor()
+                .into(),
+            &iterator_record.iterator().clone().into(),
+            context,
+        )?;
+
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator_record.iterator().clone().into());
+        }
+
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(WrapForValidIterator::create(iterator_record, context))
+    }
+
+    /// `get Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-get-iteratorprototype-constructor
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return %Iterator%.
+        Ok(context
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .constructor()
+            .into())
+    }
+
+    /// `set Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-set-iteratorprototype-constructor
+    fn set_constructor(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, "constructor", v).
+        setter_that_ignores_prototype_properties(
+            this,
+            CONSTRUCTOR.into(),
+            args.get_or_undefined(0),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `get Iterator.prototype [ @@toStringTag ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-get-iteratorprototype-@@tostringtag
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_to_string_tag(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return "Iterator".
+        Ok(Self::NAME.into())
+    }
+
+    /// `set Iterator.prototype [ @@toStringTag ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-set-iteratorprototype-@@tostringtag
+    fn set_to_string_tag(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, @@toStringTag, v).
+        setter_that_ignores_prototype_properties(
+            this,
+            JsSymbol::to_string_tag().into(),
+            args.get_or_undefined(0),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Gets the `this` object of an `%Iterator.prototype%` method, throwing if it isn't an object.
+    fn this_object<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("Iterator.prototype.{method} called on non-object"))
+                .into()
+        })
+    }
+
+    /// Gets the callback argument of an `%Iterator.prototype%` method, throwing if it isn't
+    /// callable.
+    fn callback<'a>(callback: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        // 3. If IsCallable(callback) is false, throw a TypeError exception.
+        callback.as_callable().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "Iterator.prototype.{method}: callback is not a function"
+                ))
+                .into()
+        })
+    }
+
+    /// Validates the `limit` argument of `Iterator.prototype.take` and `Iterator.prototype.drop`,
+    /// returning `None` if it is `+∞`.
+    fn limit(limit: &JsValue, method: &str, context: &mut Context) -> JsResult<Option<u64>> {
+        // 3. Let numLimit be ? ToNumber(limit).
+        let num_limit = limit.to_number(context)?;
+
+        // 4. If numLimit is NaN, throw a RangeError exception.
+        if num_limit.is_nan() {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "Iterator.prototype.{method}: limit must be a number"
+                ))
+                .into());
+        }
+
+        // 5. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 6. If integerLimit < 0, throw a RangeError exception.
+        match IntegerOrInfinity::from(num_limit) {
+            IntegerOrInfinity::PositiveInfinity => Ok(None),
+            IntegerOrInfinity::Integer(limit) if limit >= 0 => Ok(Some(limit as u64)),
+            _ => Err(JsNativeError::range()
+                .with_message(format!(
+                    "Iterator.prototype.{method}: limit must not be negative"
+                ))
+                .into()),
+        }
+    }
+
+    /// `Iterator.prototype.map ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.map
+    fn map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "map")?;
+
+        // 3. If IsCallable(mapper) is false, throw a TypeError exception.
+        let mapper = Self::callback(args.get_or_undefined(0), "map")?.clone();
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper and performs the following steps when called:
+        //     a. Let counter be 0.
+        // 6. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 7. Set result.[[UnderlyingIterator]] to iterated.
+        // 8. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::Map { mapper, counter: 0 },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.filter ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.filter
+    fn filter(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "filter")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = Self::callback(args.get_or_undefined(0), "filter")?.clone();
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate and performs the following steps when called:
+        //     a. Let counter be 0.
+        // 6. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 7. Set result.[[UnderlyingIterator]] to iterated.
+        // 8. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::Filter {
+                predicate,
+                counter: 0,
+            },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.take
+    fn take(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "take")?;
+
+        // 3-6.
+        let remaining = Self::limit(args.get_or_undefined(0), "take", context)?;
+
+        // 7. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 8. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit and performs the following steps when called:
+        //     a. Let remaining be integerLimit.
+        // 9. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 10. Set result.[[UnderlyingIterator]] to iterated.
+        // 11. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::Take { remaining },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.drop
+    fn drop(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "drop")?;
+
+        // 3-6.
+        let remaining = Self::limit(args.get_or_undefined(0), "drop", context)?;
+
+        // 7. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 8. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit and performs the following steps when called:
+        //     a. Let remaining be integerLimit.
+        // 9. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 10. Set result.[[UnderlyingIterator]] to iterated.
+        // 11. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::Drop { remaining },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.flatmap
+    fn flat_map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "flatMap")?;
+
+        // 3. If IsCallable(mapper) is false, throw a TypeError exception.
+        let mapper = Self::callback(args.get_or_undefined(0), "flatMap")?.clone();
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper and performs the following steps when called:
+        //     a. Let counter be 0.
+        // 6. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 7. Set result.[[UnderlyingIterator]] to iterated.
+        // 8. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::FlatMap {
+                mapper,
+                counter: 0,
+                inner: None,
+            },
+            context,
+        ))
+    }
+
+    /// `Iterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.reduce
+    fn reduce(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "reduce")?;
+
+        // 3. If IsCallable(reducer) is false, throw a TypeError exception.
+        let reducer = Self::callback(args.get_or_undefined(0), "reduce")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        let (mut accumulator, mut counter) = if let Some(initial_value) = args.get(1) {
+            // 6. Else,
+            //     a. Let accumulator be initialValue.
+            //     b. Let counter be 0.
+            (initial_value.clone(), 0u64)
+        } else {
+            // 5. If initialValue is not present, then
+            //     a. Let accumulator be ? IteratorStepValue(iterated).
+            //     b. If accumulator is done, throw a TypeError exception.
+            if iterated.step(context)? {
+                return Err(JsNativeError::typ()
+                    .with_message("Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value")
+                    .into());
+            }
+
+            //     c. Let counter be 1.
+            (iterated.value(context)?, 1)
+        };
+
+        // 7. Repeat,
+        loop {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return accumulator.
+            if iterated.step(context)? {
+                return Ok(accumulator);
+            }
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            let result = reducer.call(
+                &JsValue::undefined(),
+                &[accumulator, value, counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            // e. Set accumulator to result.
+            accumulator = if_abrupt_close_iterator!(result, iterated, context);
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+    }
+
+    /// `Iterator.prototype.toArray ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.toarray
+    fn to_array(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "toArray")?;
+
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 4. Let items be a new empty List.
+        let mut items = Vec::new();
+
+        // 5. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return CreateArrayFromList(items).
+        //     c. Append value to items.
+        while !iterated.step(context)? {
+            items.push(iterated.value(context)?);
+        }
+
+        Ok(Array::create_array_from_list(items, context).into())
+    }
+
+    /// `Iterator.prototype.forEach ( fn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.foreach
+    fn for_each(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "forEach")?;
+
+        // 3. If IsCallable(fn) is false, throw a TypeError exception.
+        let function = Self::callback(args.get_or_undefined(0), "forEach")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 6. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return undefined.
+        while !iterated.step(context)? {
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(fn, undefined, « value, 𝔽(counter) »)).
+            let result = function.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.some ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.some
+    fn some(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "some")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = Self::callback(args.get_or_undefined(0), "some")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 6. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return false.
+        while !iterated.step(context)? {
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(true)).
+            if result.to_boolean() {
+                return iterated.close(Ok(true.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(false.into())
+    }
+
+    /// `Iterator.prototype.every ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.every
+    fn every(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "every")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = Self::callback(args.get_or_undefined(0), "every")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 6. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return true.
+        while !iterated.step(context)? {
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is false, return ? IteratorClose(iterated, NormalCompletion(false)).
+            if !result.to_boolean() {
+                return iterated.close(Ok(false.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(true.into())
     }
+
+    /// `Iterator.prototype.find ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.find
+    fn find(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "find")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = Self::callback(args.get_or_undefined(0), "find")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 6. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return undefined.
+        while !iterated.step(context)? {
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(value)).
+            if result.to_boolean() {
+                return iterated.close(Ok(value), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(JsValue::undefined())
+    }
+}
+
+/// `SetterThatIgnoresPrototypeProperties ( thisValue, home, p, v )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-SetterThatIgnoresPrototypeProperties
+fn setter_that_ignores_prototype_properties(
+    this: &JsValue,
+    key: PropertyKey,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If thisValue is not an Object, throw a TypeError exception.
+    let Some(this) = this.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message("cannot set a property of a non-object")
+            .into());
+    };
+
+    // 2. If SameValue(thisValue, home) is true, then
+    let home = context.intrinsics().constructors().iterator().prototype();
+    if JsObject::equals(this, &home) {
+        // a. NOTE: Throwing here emulates assignment to a non-writable data property on the home object in strict mode code.
+        // b. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("cannot set a property of %Iterator.prototype%")
+            .into());
+    }
+
+    // 3. Let desc be ? thisValue.[[GetOwnProperty]](p).
+    let desc = this.__get_own_property__(&key, &mut InternalMethodContext::new(context))?;
+
+    // 4. If desc is undefined, then
+    if desc.is_none() {
+        // a. Perform ? CreateDataPropertyOrThrow(thisValue, p, v).
+        this.create_data_property_or_throw(key, value.clone(), context)?;
+    } else {
+        // 5. Else,
+        //     a. Perform ? Set(thisValue, p, v, true).
+        this.set(key, value.clone(), true, context)?;
+    }
+
+    // 6. Return unused.
+    Ok(())
 }
 
 /// `%AsyncIteratorPrototype%` object
@@ -373,7 +1144,18 @@ pub struct IteratorRecord {
     done: bool,
 
     /// The result of the last call to `next`.
-    last_result: IteratorResult,
+    last_result: LastResult,
+}
+
+/// The result of the last call to the `next` method of an [`IteratorRecord`].
+#[derive(Clone, Debug, Finalize, Trace)]
+enum LastResult {
+    /// The iterator result object returned by `next`.
+    Object(IteratorResult),
+
+    /// The value produced by `%ArrayIteratorPrototype%.next`, which doesn't need an iterator
+    /// result object to be allocated until it is requested.
+    Value(JsValue),
 }
 
 impl IteratorRecord {
@@ -385,9 +1167,9 @@ impl IteratorRecord {
             iterator,
             next_method,
             done: false,
-            last_result: IteratorResult {
+            last_result: LastResult::Object(IteratorResult {
                 object: JsObject::with_null_proto(),
-            },
+            }),
         }
     }
 
@@ -402,8 +1184,19 @@ impl IteratorRecord {
     }
 
     /// Gets the last result object of the iterator record.
-    pub(crate) const fn last_result(&self) -> &IteratorResult {
-        &self.last_result
+    ///
+    /// If the last step didn't allocate an iterator result object, it is created now.
+    pub(crate) fn last_result(&mut self, context: &mut Context) -> &IteratorResult {
+        if let LastResult::Value(value) = &self.last_result {
+            let object = create_iter_result_object(value.clone(), self.done, context);
+            self.last_result = LastResult::Object(
+                IteratorResult::from_value(object).expect("should be an iterator result object"),
+            );
+        }
+        match &self.last_result {
+            LastResult::Object(result) => result,
+            LastResult::Value(_) => unreachable!("the result object was created above"),
+        }
     }
 
     /// Runs `f`, setting the `done` field of this `IteratorRecord` to `true` if `f` returns
@@ -421,7 +1214,10 @@ impl IteratorRecord {
 
     /// Gets the current value of the `IteratorRecord`.
     pub(crate) fn value(&mut self, context: &mut Context) -> JsResult<JsValue> {
-        self.set_done_on_err(|iter| iter.last_result.value(context))
+        self.set_done_on_err(|iter| match &iter.last_result {
+            LastResult::Object(result) => result.value(context),
+            LastResult::Value(value) => Ok(value.clone()),
+        })
     }
 
     /// Get the `[[Done]]` field of the `IteratorRecord`.
@@ -445,7 +1241,7 @@ impl IteratorRecord {
             // 3. If done is true, return false.
             iter.done = result.complete(context)?;
 
-            iter.last_result = result;
+            iter.last_result = LastResult::Object(result);
 
             Ok(())
         })
@@ -469,6 +1265,24 @@ impl IteratorRecord {
         let _timer = Profiler::global().start_event("IteratorRecord::step_with", "iterator");
 
         self.set_done_on_err(|iter| {
+            // Fast path: the result of `%ArrayIteratorPrototype%.next` is an ordinary object with
+            // `value` and `done` data properties, so reading them back is not observable and the
+            // object doesn't need to be allocated. It also ignores its arguments.
+            let array_iterator_next = context
+                .intrinsics()
+                .objects()
+                .array_iterator_prototype_next();
+            if iter
+                .next_method
+                .as_object()
+                .is_some_and(|next| JsObject::equals(next, &array_iterator_next))
+            {
+                let value = ArrayIterator::next_value(&iter.iterator.clone().into(), context)?;
+                iter.done = value.is_none();
+                iter.last_result = LastResult::Value(value.unwrap_or_default());
+                return Ok(iter.done);
+            }
+
             // 1. If value is not present, then
             //     a. Let result be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
             // 2. Else,
@@ -602,3 +1416,76 @@ pub(crate) fn iterable_to_list(
     // 6. Return values.
     Ok(values)
 }
+
+/// The handling of primitive values in [`get_iterator_flattenable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveHandling {
+    /// Iterates over the code points of strings, rejecting the other primitives.
+    IterateStringPrimitives,
+
+    /// Rejects every primitive value.
+    RejectPrimitives,
+}
+
+/// `GetIteratorDirect ( obj )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+///  [spec]: https://tc39.es/proposal-iterator-helpers/#sec-getiteratordirect
+pub(crate) fn get_iterator_direct(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = object.get(js_string!("next"), context)?;
+
+    // 2. Let iteratorRecord be the Iterator Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return iteratorRecord.
+    Ok(IteratorRecord::new(object.clone(), next_method))
+}
+
+/// `GetIteratorFlattenable ( obj, primitiveHandling )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+///  [spec]: https://tc39.es/proposal-iterator-helpers/#sec-getiteratorflattenable
+pub(crate) fn get_iterator_flattenable(
+    object: &JsValue,
+    primitive_handling: PrimitiveHandling,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. If obj is not an Object, then
+    if !object.is_object() {
+        // a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+        // b. Assert: primitiveHandling is iterate-string-primitives.
+        // c. If obj is not a String, throw a TypeError exception.
+        if primitive_handling == PrimitiveHandling::RejectPrimitives || !object.is_string() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "value with type `{}` is not an iterator or iterable",
+                    object.type_of()
+                ))
+                .into());
+        }
+    }
+
+    // 2. Let method be ? GetMethod(obj, @@iterator).
+    let iterator = match object.get_method(JsSymbol::iterator(), context)? {
+        // 3. If method is undefined, then
+        //     a. Let iterator be obj.
+        None => object.clone(),
+        // 4. Else,
+        //     a. Let iterator be ? Call(method, obj).
+        Some(method) => method.call(object, &[], context)?,
+    };
+
+    // 5. If iterator is not an Object, throw a TypeError exception.
+    let iterator = iterator
+        .as_object()
+        .ok_or_else(|| JsNativeError::typ().with_message("returned iterator is not an object"))?;
+
+    // 6. Return ? GetIteratorDirect(iterator).
+    get_iterator_direct(iterator, context)
+}
@@ -0,0 +1,240 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn iterator_constructor() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Iterator()",
+            JsNativeErrorKind::Type,
+            "the Iterator constructor is abstract and can only be subclassed",
+        ),
+        TestAction::assert_native_error(
+            "Iterator()",
+            JsNativeErrorKind::Type,
+            "the Iterator constructor is abstract and can only be subclassed",
+        ),
+        TestAction::run(indoc! {r#"
+            class Empty extends Iterator {
+                next() {
+                    return { value: undefined, done: true };
+                }
+            }
+        "#}),
+        TestAction::assert("new Empty() instanceof Iterator"),
+        TestAction::assert_eq("new Empty().toArray().length", 0),
+        TestAction::assert(
+            "Object.getPrototypeOf(Object.getPrototypeOf([].values())) === Iterator.prototype",
+        ),
+        TestAction::assert("Iterator.prototype.constructor === Iterator"),
+        TestAction::assert_eq(
+            "Iterator.prototype[Symbol.toStringTag]",
+            js_string!("Iterator"),
+        ),
+        TestAction::assert_native_error(
+            "Iterator.prototype.constructor = Object",
+            JsNativeErrorKind::Type,
+            "cannot set a property of %Iterator.prototype%",
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var empty = new Empty();
+                empty[Symbol.toStringTag] = "Empty";
+                Object.prototype.toString.call(empty)
+            "#},
+            js_string!("[object Empty]"),
+        ),
+    ]);
+}
+
+#[test]
+fn iterator_from() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var sevens = { next() { return { value: 7, done: false }; } };
+            var wrapper = Iterator.from(sevens);
+        "#}),
+        TestAction::assert("wrapper instanceof Iterator"),
+        TestAction::assert("Object.getPrototypeOf(wrapper) !== Iterator.prototype"),
+        TestAction::assert_eq("wrapper.take(2).toArray().join()", js_string!("7,7")),
+        TestAction::assert_eq("wrapper.return().done", true),
+        TestAction::assert_eq("Iterator.from('ab').toArray().join()", js_string!("a,b")),
+        TestAction::assert(indoc! {r#"
+            var values = [1].values();
+            Iterator.from(values) === values
+        "#}),
+        TestAction::assert_native_error(
+            "Iterator.from(1)",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterator or iterable",
+        ),
+    ]);
+}
+
+#[test]
+fn lazy_helpers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var calls = 0;
+            function* naturals() {
+                let i = 0;
+                while (true) {
+                    calls++;
+                    yield i++;
+                }
+            }
+        "#}),
+        TestAction::assert_eq(
+            "naturals().map(x => x * 2).take(3).toArray().join()",
+            js_string!("0,2,4"),
+        ),
+        TestAction::assert_eq("calls", 3),
+        TestAction::assert_eq(
+            "naturals().filter(x => x % 2).drop(1).take(2).toArray().join()",
+            js_string!("3,5"),
+        ),
+        TestAction::assert_eq(
+            "[1, 2].values().flatMap(x => [x, x * 10]).toArray().join()",
+            js_string!("1,10,2,20"),
+        ),
+        TestAction::assert_eq(
+            "[1, 2, 3].values().map((x, i) => x * i).toArray().join()",
+            js_string!("0,2,6"),
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call([].values().map(x => x))",
+            js_string!("[object Iterator Helper]"),
+        ),
+        TestAction::assert_native_error(
+            "naturals().take(NaN)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.take: limit must be a number",
+        ),
+        TestAction::assert_native_error(
+            "naturals().drop(-1)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.drop: limit must not be negative",
+        ),
+        TestAction::assert_native_error(
+            "[1].values().flatMap(x => x).next()",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterator or iterable",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                var helper = [1].values().map(() => helper.next());
+                helper.next()
+            "#},
+            JsNativeErrorKind::Type,
+            "Iterator Helper is already running",
+        ),
+    ]);
+}
+
+#[test]
+fn eager_helpers() {
+    run_test_actions([
+        TestAction::assert_eq("[1, 2, 3].values().reduce((a, b) => a + b)", 6),
+        TestAction::assert_eq("[1, 2, 3].values().reduce((a, b) => a + b, 10)", 16),
+        TestAction::assert_native_error(
+            "[].values().reduce((a, b) => a + b)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+        ),
+        TestAction::assert("[1, 2, 3].values().some(x => x > 2)"),
+        TestAction::assert("![1, 2, 3].values().every(x => x > 2)"),
+        TestAction::assert_eq("[1, 2, 3].values().find(x => x > 1)", 2),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var sum = 0;
+                [1, 2, 3].values().forEach((x, i) => { sum += x * i; });
+                sum
+            "#},
+            8,
+        ),
+        TestAction::assert_native_error(
+            "[].values().map(1)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.map: callback is not a function",
+        ),
+    ]);
+}
+
+#[test]
+fn closing_underlying_iterators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            function counter(name) {
+                return {
+                    __proto__: Iterator.prototype,
+                    next() {
+                        log.push(name + ".next");
+                        return { value: 1, done: false };
+                    },
+                    return() {
+                        log.push(name + ".return");
+                        return {};
+                    },
+                };
+            }
+        "#}),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                try {
+                    counter("it").map(() => { throw new Error("boom"); }).next();
+                } catch (e) {
+                    log.push(e.message);
+                }
+                log.join()
+            "#},
+            js_string!("it.next,it.return,boom"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                counter("it").find(x => x === 1);
+                log.join()
+            "#},
+            js_string!("it.next,it.return"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                var helper = counter("it").filter(x => x);
+                helper.next();
+                helper.return();
+                helper.next();
+                log.join()
+            "#},
+            js_string!("it.next,it.return"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                var helper = counter("outer").flatMap(() => counter("inner"));
+                helper.next();
+                helper.return();
+                log.join()
+            "#},
+            js_string!("outer.next,inner.next,inner.return,outer.return"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                counter("it").take(0).next();
+                log.join()
+            "#},
+            js_string!("it.return"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                log = [];
+                counter("it").map(x => x).return();
+                log.join()
+            "#},
+            js_string!("it.return"),
+        ),
+    ]);
+}
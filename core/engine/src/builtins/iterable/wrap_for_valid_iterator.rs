@@ -0,0 +1,133 @@
+//! Boa's implementation of the `%WrapForValidIteratorPrototype%` object, used by `Iterator.from`
+//! to wrap the iterators that don't inherit from `%Iterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-iterator-helpers/#sec-wrapforvaliditeratorprototype-object
+
+use crate::{
+    builtins::{
+        iterable::{create_iter_result_object, IteratorRecord},
+        BuiltInBuilder, IntrinsicObject,
+    },
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    realm::Realm,
+    Context, JsData, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// An iterator created by `Iterator.from`, which forwards its methods to the wrapped iterator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-wrapforvaliditeratorprototype-object
+#[derive(Debug, Clone, Finalize, Trace, JsData)]
+pub(crate) struct WrapForValidIterator {
+    /// The `[[Iterated]]` internal slot.
+    iterated: IteratorRecord,
+}
+
+impl IntrinsicObject for WrapForValidIterator {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(
+                realm
+                    .intrinsics()
+                    .objects()
+                    .iterator_prototypes()
+                    .iterator(),
+            )
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics
+            .objects()
+            .iterator_prototypes()
+            .wrap_for_valid_iterator()
+    }
+}
+
+impl WrapForValidIterator {
+    /// Creates a new wrapper for the iterator of `iterated`.
+    pub(crate) fn create(iterated: IteratorRecord, context: &mut Context) -> JsValue {
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .wrap_for_valid_iterator(),
+            Self { iterated },
+        )
+        .into()
+    }
+
+    /// Gets the `[[Iterated]]` internal slot of the `this` value.
+    fn iterated(this: &JsValue) -> JsResult<IteratorRecord> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        this.as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .map(|wrapper| wrapper.iterated.clone())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("WrapForValidIterator method called on incompatible object")
+                    .into()
+            })
+    }
+
+    /// `%WrapForValidIteratorPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-wrapforvaliditeratorprototype.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Let iteratorRecord be O.[[Iterated]].
+        let iterator_record = Self::iterated(this)?;
+
+        // 4. Return ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        iterator_record
+            .next_method()
+            .call(&iterator_record.iterator().clone().into(), &[], context)
+    }
+
+    /// `%WrapForValidIteratorPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-wrapforvaliditeratorprototype.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 3. Let iterator be O.[[Iterated]].[[Iterator]].
+        // 4. Assert: iterator is an Object.
+        let iterator = Self::iterated(this)?.iterator().clone();
+
+        // 5. Let returnMethod be ? GetMethod(iterator, "return").
+        let Some(return_method) = iterator.get_method(js_string!("return"), context)? else {
+            // 6. If returnMethod is undefined, then
+            //     a. Return CreateIterResultObject(undefined, true).
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        };
+
+        // 7. Return ? Call(returnMethod, iterator).
+        return_method.call(&iterator.into(), &[], context)
+    }
+}
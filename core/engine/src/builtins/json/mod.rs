@@ -13,38 +13,47 @@
 //! [json]: https://www.json.org/json-en.html
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
-use std::{borrow::Cow, iter::once};
+use std::iter::once;
 
 use itertools::Itertools;
 
 use crate::{
-    builtins::BuiltInObject,
+    builtins::{BuiltInObject, Number},
     bytecompiler::ByteCompiler,
     context::intrinsics::Intrinsics,
     error::JsNativeError,
     js_string,
-    object::{internal_methods::InternalMethodContext, JsObject},
+    object::{internal_methods::InternalMethodContext, IntegrityLevel, JsObject},
     property::{Attribute, PropertyNameKind},
     realm::Realm,
     string::{common::StaticJsStrings, utf16, CodePoint},
     symbol::JsSymbol,
     value::IntegerOrInfinity,
     vm::{CallFrame, CallFrameFlags},
-    Context, JsArgs, JsBigInt, JsResult, JsString, JsValue,
+    Context, JsArgs, JsBigInt, JsData, JsResult, JsString, JsValue,
 };
-use boa_gc::Gc;
+use boa_gc::{Finalize, Gc, Trace};
 use boa_parser::{Parser, Source};
 use boa_profiler::Profiler;
 
 use super::{BuiltInBuilder, IntrinsicObject};
 
+mod parse_record;
+
 #[cfg(test)]
 mod tests;
 
+use parse_record::JsonParseRecord;
+
 /// JavaScript `JSON` global object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct Json;
 
+/// The `[[IsRawJSON]]` internal slot of the objects created by `JSON.rawJSON`.
+#[derive(Debug, Clone, Copy, Trace, Finalize, JsData)]
+#[boa_gc(empty_trace)]
+pub(crate) struct RawJson;
+
 impl IntrinsicObject for Json {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
@@ -55,6 +64,8 @@ impl IntrinsicObject for Json {
         BuiltInBuilder::with_intrinsic::<Self>(realm)
             .static_method(Self::parse, js_string!("parse"), 2)
             .static_method(Self::stringify, js_string!("stringify"), 3)
+            .static_method(Self::raw_json, js_string!("rawJSON"), 1)
+            .static_method(Self::is_raw_json, js_string!("isRawJSON"), 1)
             .static_property(to_string_tag, Self::NAME, attribute)
             .build();
     }
@@ -110,6 +121,7 @@ impl Json {
         // 10. Assert: unfiltered is either a String, Number, Boolean, Null, or an Object that is defined by either an ArrayLiteral or an ObjectLiteral.
         let mut parser = Parser::new(Source::from_bytes(&script_string));
         parser.set_json_parse();
+        parser.set_nesting_limit(context.runtime_limits().nesting_limit());
         let script = parser.parse_script(context.interner_mut())?;
         let code_block = {
             let mut compiler = ByteCompiler::new(
@@ -148,11 +160,14 @@ impl Json {
 
             // b. Let rootName be the empty String.
             // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
-            root.create_data_property_or_throw(utf16!(""), unfiltered, context)
+            root.create_data_property_or_throw(utf16!(""), unfiltered.clone(), context)
                 .expect("CreateDataPropertyOrThrow should never throw here");
 
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
-            Self::internalize_json_property(&root, "".into(), obj, context)
+            // d. Let snapshot be CreateJSONParseRecord(script, rootName, unfiltered).
+            let snapshot = JsonParseRecord::new(&json_string, unfiltered, context);
+
+            // e. Return ? InternalizeJSONProperty(root, rootName, reviver, snapshot).
+            Self::internalize_json_property(&root, "".into(), obj, Some(&snapshot), context)
         } else {
             // 12. Else,
             // a. Return unfiltered.
@@ -160,22 +175,47 @@ impl Json {
         }
     }
 
-    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
+    /// `InternalizeJSONProperty ( holder, name, reviver, parseRecord )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
+    ///  - [Proposal][proposal]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-internalizejsonproperty
     fn internalize_json_property(
         holder: &JsObject,
         name: JsString,
         reviver: &JsObject,
+        parse_record: Option<&JsonParseRecord>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let val be ? Get(holder, name).
         let val = holder.get(name.clone(), context)?;
 
-        // 2. If Type(val) is Object, then
+        // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+        let reviver_context = JsObject::with_object_proto(context.intrinsics());
+
+        // 3. If parseRecord is a JSON Parse Record and SameValue(parseRecord.[[Value]], val) is true, then
+        //     b. Let elementRecords be parseRecord.[[Elements]].
+        //     c. Let entryRecords be parseRecord.[[Entries]].
+        // 4. Else,
+        //     a. Let elementRecords be a new empty List.
+        //     b. Let entryRecords be a new empty List.
+        let parse_record = parse_record.filter(|record| JsValue::same_value(&record.value, &val));
+
+        // 3.a. If val is not an Object, then
+        if let Some(source) = parse_record.and_then(|record| record.source.clone()) {
+            // i. Let parseNode be parseRecord.[[ParseNode]].
+            // ii. Assert: parseNode is not an ArrayLiteral Parse Node and not an ObjectLiteral Parse Node.
+            // iii. Let sourceText be the source text matched by parseNode.
+            // iv. Perform ! CreateDataPropertyOrThrow(context, "source", CodePointsToString(sourceText)).
+            reviver_context
+                .create_data_property_or_throw(js_string!("source"), source, context)
+                .expect("CreateDataPropertyOrThrow should never throw here");
+        }
+
+        // 5. If val is an Object, then
         if let Some(obj) = val.as_object() {
             // a. Let isArray be ? IsArray(val).
             // b. If isArray is true, then
@@ -186,11 +226,16 @@ impl Json {
                 let len = obj.length_of_array_like(context)? as i64;
                 for i in 0..len {
                     // 1. Let prop be ! ToString(𝔽(I)).
-                    // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
+                    // 2. If I < elementRecordsLen, let elementRecord be elementRecords[I]. Otherwise, let elementRecord be empty.
+                    let element_record =
+                        parse_record.and_then(|record| record.elements.get(i as usize));
+
+                    // 3. Let newElement be ? InternalizeJSONProperty(val, prop, reviver, elementRecord).
                     let new_element = Self::internalize_json_property(
                         obj,
                         i.to_string().into(),
                         reviver,
+                        element_record,
                         context,
                     )?;
 
@@ -219,9 +264,17 @@ impl Json {
                         .expect("EnumerableOwnPropertyNames only returns strings")
                         .clone();
 
-                    // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, p.clone(), reviver, context)?;
+                    // 1. Let entryRecord be the element of entryRecords whose [[Key]] field is P. If there is no such element, let entryRecord be empty.
+                    let entry_record = parse_record.and_then(|record| record.entries.get(&p));
+
+                    // 2. Let newElement be ? InternalizeJSONProperty(val, P, reviver, entryRecord).
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        p.clone(),
+                        reviver,
+                        entry_record,
+                        context,
+                    )?;
 
                     // 2. If newElement is undefined, then
                     if new_element.is_undefined() {
@@ -237,8 +290,90 @@ impl Json {
             }
         }
 
-        // 3. Return ? Call(reviver, holder, « name, val »).
-        reviver.call(&holder.clone().into(), &[name.into(), val], context)
+        // 6. Return ? Call(reviver, holder, « name, val, context »).
+        reviver.call(
+            &holder.clone().into(),
+            &[name.into(), val, reviver_context.into()],
+            context,
+        )
+    }
+
+    /// `JSON.rawJSON ( text )`
+    ///
+    /// Creates a frozen object containing a piece of JSON text, which `JSON.stringify` embeds
+    /// verbatim in its output.
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson
+    pub(crate) fn raw_json(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let jsonString be ? ToString(text).
+        let json_string = args.get_or_undefined(0).to_string(context)?;
+        let text = json_string
+            .to_std_string()
+            .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
+
+        // 2. Throw a SyntaxError exception if jsonString is the empty String, or if either the first or last code unit of jsonString is any of 0x0009 (CHARACTER TABULATION), 0x000A (LINE FEED), 0x000D (CARRIAGE RETURN), or 0x0020 (SPACE).
+        let is_whitespace = |byte: Option<&u8>| matches!(byte, Some(b'\t' | b'\n' | b'\r' | b' '));
+        if text.is_empty()
+            || is_whitespace(text.as_bytes().first())
+            || is_whitespace(text.as_bytes().last())
+        {
+            return Err(JsNativeError::syntax()
+                .with_message("JSON.rawJSON: invalid raw JSON text")
+                .into());
+        }
+
+        // 3. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification, or if its outermost value is an object or array as defined in that specification.
+        match serde_json::from_str::<serde_json::Value>(&text) {
+            Err(e) => return Err(JsNativeError::syntax().with_message(e.to_string()).into()),
+            Ok(serde_json::Value::Array(_) | serde_json::Value::Object(_)) => {
+                return Err(JsNativeError::syntax()
+                    .with_message("JSON.rawJSON: raw JSON text cannot be an object or array")
+                    .into());
+            }
+            Ok(_) => {}
+        }
+
+        // 4. Let internalSlotsList be « [[IsRawJSON]] ».
+        // 5. Let obj be OrdinaryObjectCreate(null, internalSlotsList).
+        let obj =
+            JsObject::from_proto_and_data_with_shared_shape(context.root_shape(), None, RawJson);
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "rawJSON", jsonString).
+        obj.create_data_property_or_throw(js_string!("rawJSON"), json_string, context)
+            .expect("CreateDataPropertyOrThrow should never throw here");
+
+        // 7. Perform ! SetIntegrityLevel(obj, frozen).
+        obj.set_integrity_level(IntegrityLevel::Frozen, context)
+            .expect("SetIntegrityLevel should never throw here");
+
+        // 8. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `JSON.isRawJSON ( O )`
+    ///
+    /// Returns whether a value was created by `JSON.rawJSON`.
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json.israwjson
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn is_raw_json(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. If O is an Object and O has an [[IsRawJSON]] internal slot, return true.
+        // 2. Return false.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .is_some_and(JsObject::is::<RawJson>)
+            .into())
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -368,7 +503,7 @@ impl Json {
         // 7. Else if Type(space) is String, then
         } else if let Some(s) = space.as_string() {
             // a. If the length of space is 10 or less, let gap be space; otherwise let gap be the substring of space from 0 to 10.
-            js_string!(s.get(..10).unwrap_or(s))
+            js_string!(s.get(..10).unwrap_or(s.as_str()))
         // 8. Else,
         } else {
             // a. Let gap be the empty String.
@@ -437,22 +572,30 @@ impl Json {
 
         // 4. If Type(value) is Object, then
         if let Some(obj) = value.as_object().cloned() {
-            // a. If value has a [[NumberData]] internal slot, then
+            // a. If value has an [[IsRawJSON]] internal slot, then
+            if obj.is::<RawJson>() {
+                // i. Return ! Get(value, "rawJSON").
+                let raw_json = obj
+                    .get(js_string!("rawJSON"), context)
+                    .expect("the rawJSON property of raw JSON objects is a data property");
+                return Ok(raw_json.as_string().cloned());
+            }
+            // b. If value has a [[NumberData]] internal slot, then
             if obj.is::<f64>() {
                 // i. Set value to ? ToNumber(value).
                 value = value.to_number(context)?.into();
             }
-            // b. Else if value has a [[StringData]] internal slot, then
+            // c. Else if value has a [[StringData]] internal slot, then
             else if obj.is::<JsString>() {
                 // i. Set value to ? ToString(value).
                 value = value.to_string(context)?.into();
             }
-            // c. Else if value has a [[BooleanData]] internal slot, then
+            // d. Else if value has a [[BooleanData]] internal slot, then
             else if let Some(boolean) = obj.downcast_ref::<bool>() {
                 // i. Set value to value.[[BooleanData]].
                 value = (*boolean).into();
             }
-            // d. Else if value has a [[BigIntData]] internal slot, then
+            // e. Else if value has a [[BigIntData]] internal slot, then
             else if let Some(bigint) = obj.downcast_ref::<JsBigInt>() {
                 // i. Set value to value.[[BigIntData]].
                 value = bigint.clone().into();
@@ -627,22 +770,22 @@ impl Json {
             // b. If strP is not undefined, then
             if let Some(str_p) = str_p {
                 // i. Let member be QuoteJSONString(P).
-                let mut member = Self::quote_json_string(p).to_vec();
+                let mut member = vec![Self::quote_json_string(p)];
 
                 // ii. Set member to the string-concatenation of member and ":".
-                member.push(':' as u16);
+                member.push(js_string!(":"));
 
                 // iii. If state.[[Gap]] is not the empty String, then
                 if !state.gap.is_empty() {
                     // 1. Set member to the string-concatenation of member and the code unit 0x0020 (SPACE).
-                    member.push(' ' as u16);
+                    member.push(js_string!(" "));
                 }
 
                 // iv. Set member to the string-concatenation of member and strP.
-                member.extend_from_slice(&str_p);
+                member.push(str_p);
 
                 // v. Append member to partial.
-                partial.push(member);
+                partial.push(JsString::concat_lazy_array(&member));
             }
         }
 
@@ -658,40 +801,29 @@ impl Json {
                 //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
                 //    A comma is not inserted either before the first String or after the last String.
                 // ii. Let final be the string-concatenation of "{", properties, and "}".
-                let separator = utf16!(",");
-                let result = once(utf16!("{"))
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Vec::as_slice),
-                        separator,
-                    ))
-                    .chain(once(utf16!("}")))
-                    .flatten()
-                    .copied()
+                let separator = js_string!(",");
+                let result = once(js_string!("{"))
+                    .chain(Itertools::intersperse(partial.into_iter(), separator))
+                    .chain(once(js_string!("}")))
                     .collect::<Vec<_>>();
-                js_string!(&result[..])
+                JsString::concat_lazy_array(&result)
             // b. Else,
             } else {
                 // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
                 //    the code unit 0x000A (LINE FEED), and state.[[Indent]].
-                let mut separator = utf16!(",\n").to_vec();
-                separator.extend_from_slice(&state.indent);
+                let separator = js_string!(utf16!(",\n"), &state.indent);
                 // ii. Let properties be the String value formed by concatenating all the element Strings of partial
                 //     with each adjacent pair of Strings separated with separator.
                 //     The separator String is not inserted either before the first String or after the last String.
                 // iii. Let final be the string-concatenation of "{", the code
                 //      unit 0x000A (LINE FEED), state.[[Indent]], properties,
                 //      the code unit 0x000A (LINE FEED), stepback, and "}".
-                let result = [utf16!("{\n"), &state.indent[..]]
+                let result = [js_string!("{\n"), state.indent.clone()]
                     .into_iter()
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Vec::as_slice),
-                        &separator,
-                    ))
-                    .chain([utf16!("\n"), &stepback[..], utf16!("}")])
-                    .flatten()
-                    .copied()
+                    .chain(Itertools::intersperse(partial.into_iter(), separator))
+                    .chain([js_string!("\n"), stepback.clone(), js_string!("}")])
                     .collect::<Vec<_>>();
-                js_string!(&result[..])
+                JsString::concat_lazy_array(&result)
             }
         };
 
@@ -744,17 +876,19 @@ impl Json {
         // 8. Repeat, while index < len,
         while index < len {
             // a. Let strP be ? SerializeJSONProperty(state, ! ToString(𝔽(index)), value).
-            let str_p =
-                Self::serialize_json_property(state, index.to_string().into(), value, context)?;
+            // Array lengths are at most 2^53 - 1, so the index never wraps.
+            #[allow(clippy::cast_possible_wrap)]
+            let key = Number::integer_to_js_string(index as i64);
+            let str_p = Self::serialize_json_property(state, key, value, context)?;
 
             // b. If strP is undefined, then
             if let Some(str_p) = str_p {
                 // i. Append strP to partial.
-                partial.push(Cow::Owned(str_p.to_vec()));
+                partial.push(str_p);
             // c. Else,
             } else {
                 // i. Append "null" to partial.
-                partial.push(Cow::Borrowed(utf16!("null")));
+                partial.push(js_string!("null"));
             }
 
             // d. Set index to index + 1.
@@ -773,38 +907,27 @@ impl Json {
                 //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
                 //    A comma is not inserted either before the first String or after the last String.
                 // ii. Let final be the string-concatenation of "[", properties, and "]".
-                let separator = utf16!(",");
-                let result = once(utf16!("["))
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Cow::as_ref),
-                        separator,
-                    ))
-                    .chain(once(utf16!("]")))
-                    .flatten()
-                    .copied()
+                let separator = js_string!(",");
+                let result = once(js_string!("["))
+                    .chain(Itertools::intersperse(partial.into_iter(), separator))
+                    .chain(once(js_string!("]")))
                     .collect::<Vec<_>>();
-                js_string!(&result[..])
+                JsString::concat_lazy_array(&result)
             // b. Else,
             } else {
                 // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
                 //    the code unit 0x000A (LINE FEED), and state.[[Indent]].
-                let mut separator = utf16!(",\n").to_vec();
-                separator.extend_from_slice(&state.indent);
+                let separator = js_string!(utf16!(",\n"), &state.indent);
                 // ii. Let properties be the String value formed by concatenating all the element Strings of partial
                 //     with each adjacent pair of Strings separated with separator.
                 //     The separator String is not inserted either before the first String or after the last String.
                 // iii. Let final be the string-concatenation of "[", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "]".
-                let result = [utf16!("[\n"), &state.indent[..]]
+                let result = [js_string!("[\n"), state.indent.clone()]
                     .into_iter()
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Cow::as_ref),
-                        &separator,
-                    ))
-                    .chain([utf16!("\n"), &stepback[..], utf16!("]")])
-                    .flatten()
-                    .copied()
+                    .chain(Itertools::intersperse(partial.into_iter(), separator))
+                    .chain([js_string!("\n"), stepback.clone(), js_string!("]")])
                     .collect::<Vec<_>>();
-                js_string!(&result[..])
+                JsString::concat_lazy_array(&result)
             }
         };
 
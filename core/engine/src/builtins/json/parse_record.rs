@@ -0,0 +1,195 @@
+//! JSON Parse Records, used to give revivers access to the source text of parsed values.
+//!
+//! More information:
+//!  - [Proposal][proposal]
+//!
+//! [proposal]: https://tc39.es/proposal-json-parse-with-source/
+
+use rustc_hash::FxHashMap;
+
+use crate::{Context, JsString, JsValue};
+
+/// A `JSON Parse Record`.
+///
+/// Associates a value produced by `JSON.parse` with the parse node it was created from.
+///
+/// More information:
+///  - [Proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+#[derive(Debug)]
+pub(super) struct JsonParseRecord {
+    /// `[[Value]]`
+    pub(super) value: JsValue,
+
+    /// The source text matched by `[[ParseNode]]`, if the value is a primitive.
+    pub(super) source: Option<JsString>,
+
+    /// `[[Elements]]`
+    pub(super) elements: Vec<JsonParseRecord>,
+
+    /// `[[Entries]]`
+    pub(super) entries: FxHashMap<JsString, JsonParseRecord>,
+}
+
+impl JsonParseRecord {
+    /// Creates the parse records of `value`, which must be the value parsed from `text`.
+    ///
+    /// `text` must be a valid JSON text.
+    pub(super) fn new(text: &str, value: JsValue, context: &mut Context) -> Self {
+        let node = Scanner { text, position: 0 }.value();
+        Self::from_node(node, value, context)
+    }
+
+    /// `CreateJSONParseRecord ( parseNode, key, val )`
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-createjsonparserecord
+    fn from_node(node: JsonNode<'_>, value: JsValue, context: &mut Context) -> Self {
+        let mut record = Self {
+            value,
+            source: None,
+            elements: Vec::new(),
+            entries: FxHashMap::default(),
+        };
+
+        match node {
+            JsonNode::Primitive(source) => record.source = Some(source.into()),
+            JsonNode::Array(elements) => {
+                let array = record
+                    .value
+                    .as_object()
+                    .expect("an array literal must evaluate to an object")
+                    .clone();
+                for (index, element) in elements.into_iter().enumerate() {
+                    let value = array
+                        .get(index, context)
+                        .expect("the elements of a parsed array are data properties");
+                    record
+                        .elements
+                        .push(Self::from_node(element, value, context));
+                }
+            }
+            JsonNode::Object(entries) => {
+                let object = record
+                    .value
+                    .as_object()
+                    .expect("an object literal must evaluate to an object")
+                    .clone();
+                for (key, entry) in entries {
+                    let value = object
+                        .get(key.clone(), context)
+                        .expect("the properties of a parsed object are data properties");
+
+                    // Duplicate keys are overwritten, so only the last entry matches the value.
+                    record
+                        .entries
+                        .insert(key, Self::from_node(entry, value, context));
+                }
+            }
+        }
+
+        record
+    }
+}
+
+/// The structure of a JSON text, keeping the source text of every primitive value.
+#[derive(Debug)]
+enum JsonNode<'a> {
+    Primitive(&'a str),
+    Array(Vec<JsonNode<'a>>),
+    Object(Vec<(JsString, JsonNode<'a>)>),
+}
+
+/// A scanner over an already validated JSON text.
+#[derive(Debug)]
+struct Scanner<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn peek(&self) -> u8 {
+        self.text.as_bytes()[self.position]
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(
+            self.text.as_bytes().get(self.position),
+            Some(b' ' | b'\t' | b'\n' | b'\r')
+        ) {
+            self.position += 1;
+        }
+    }
+
+    /// Skips the whitespace around the next structural character.
+    fn expect(&mut self, byte: u8) {
+        self.skip_whitespace();
+        debug_assert_eq!(self.peek(), byte);
+        self.position += 1;
+        self.skip_whitespace();
+    }
+
+    fn value(&mut self) -> JsonNode<'a> {
+        self.skip_whitespace();
+        match self.peek() {
+            b'[' => {
+                self.expect(b'[');
+                let mut elements = Vec::new();
+                while self.peek() != b']' {
+                    if !elements.is_empty() {
+                        self.expect(b',');
+                    }
+                    elements.push(self.value());
+                    self.skip_whitespace();
+                }
+                self.expect(b']');
+                JsonNode::Array(elements)
+            }
+            b'{' => {
+                self.expect(b'{');
+                let mut entries = Vec::new();
+                while self.peek() != b'}' {
+                    if !entries.is_empty() {
+                        self.expect(b',');
+                    }
+                    let key = serde_json::from_str::<String>(self.string())
+                        .expect("the JSON text must be valid");
+                    self.expect(b':');
+                    entries.push((key.into(), self.value()));
+                    self.skip_whitespace();
+                }
+                self.expect(b'}');
+                JsonNode::Object(entries)
+            }
+            b'"' => JsonNode::Primitive(self.string()),
+            _ => {
+                let start = self.position;
+                while !matches!(
+                    self.text.as_bytes().get(self.position),
+                    None | Some(b' ' | b'\t' | b'\n' | b'\r' | b',' | b']' | b'}')
+                ) {
+                    self.position += 1;
+                }
+                JsonNode::Primitive(&self.text[start..self.position])
+            }
+        }
+    }
+
+    /// Scans a string literal, including its quotes.
+    fn string(&mut self) -> &'a str {
+        let start = self.position;
+        self.position += 1;
+        loop {
+            match self.peek() {
+                b'"' => break,
+                b'\\' => self.position += 2,
+                _ => self.position += 1,
+            }
+        }
+        self.position += 1;
+        &self.text[start..self.position]
+    }
+}
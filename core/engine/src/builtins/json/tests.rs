@@ -317,3 +317,67 @@ fn json_parse_with_no_args_throws_syntax_error() {
         "expected value at line 1 column 1",
     )]);
 }
+
+#[test]
+fn json_parse_reviver_source() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                var big = JSON.parse('{"big":12345678901234567890}', (key, value, { source }) =>
+                    key === "big" ? BigInt(source) : value
+                ).big;
+                big.toString()
+            "#},
+            js_string!("12345678901234567890"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var sources = [];
+                JSON.parse(' [1.0, "a\\u0062", {"x": -0, "x": null}, true] ', (key, value, context) => {
+                    sources.push(key + "=" + context.source);
+                    return value;
+                });
+                sources.join()
+            "#},
+            js_string!(r#"0=1.0,1="a\u0062",x=null,2=undefined,3=true,=undefined"#),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                JSON.parse('[1, 2]', function (key, value, context) {
+                    if (key === "0") this[1] = 3;
+                    return key === "1" ? context.source : value;
+                })[1]
+            "#},
+            JsValue::undefined(),
+        ),
+    ]);
+}
+
+#[test]
+fn json_raw_json() {
+    run_test_actions([
+        TestAction::run("var raw = JSON.rawJSON('12345678901234567890');"),
+        TestAction::assert("JSON.isRawJSON(raw)"),
+        TestAction::assert("!JSON.isRawJSON({ rawJSON: '1' })"),
+        TestAction::assert("Object.isFrozen(raw)"),
+        TestAction::assert("Object.getPrototypeOf(raw) === null"),
+        TestAction::assert_eq(
+            "JSON.stringify({ big: raw, list: [JSON.rawJSON('\"a\"')] })",
+            js_string!(r#"{"big":12345678901234567890,"list":["a"]}"#),
+        ),
+        TestAction::assert_eq(
+            "JSON.stringify(BigInt(2) ** 64n, (key, value) => JSON.rawJSON(value.toString()))",
+            js_string!("18446744073709551616"),
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON(' 1')",
+            JsNativeErrorKind::Syntax,
+            "JSON.rawJSON: invalid raw JSON text",
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON('{}')",
+            JsNativeErrorKind::Syntax,
+            "JSON.rawJSON: raw JSON text cannot be an object or array",
+        ),
+    ]);
+}
@@ -2,6 +2,7 @@
 
 pub mod array;
 pub mod array_buffer;
+pub mod async_disposable_stack;
 pub mod async_function;
 pub mod async_generator;
 pub mod async_generator_function;
@@ -10,6 +11,7 @@ pub mod bigint;
 pub mod boolean;
 pub mod dataview;
 pub mod date;
+pub mod disposable_stack;
 pub mod error;
 pub mod eval;
 pub mod function;
@@ -26,7 +28,9 @@ pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
+pub mod shadow_realm;
 pub mod string;
+pub mod structured_clone;
 pub mod symbol;
 pub mod typed_array;
 pub mod uri;
@@ -59,8 +63,8 @@ pub(crate) use self::{
     dataview::DataView,
     date::Date,
     error::{
-        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
-        UriError,
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SuppressedError, SyntaxError,
+        TypeError, UriError,
     },
     eval::Eval,
     function::BuiltInFunctionObject,
@@ -86,18 +90,24 @@ use crate::{
     builtins::{
         array::ArrayIterator,
         array_buffer::{ArrayBuffer, SharedArrayBuffer},
+        async_disposable_stack::AsyncDisposableStack,
         async_generator::AsyncGenerator,
         async_generator_function::AsyncGeneratorFunction,
         atomics::Atomics,
+        disposable_stack::DisposableStack,
         error::r#type::ThrowTypeError,
         generator::Generator,
         generator_function::GeneratorFunction,
-        iterable::{AsyncFromSyncIterator, AsyncIterator, Iterator},
+        iterable::{
+            AsyncFromSyncIterator, AsyncIterator, Iterator, IteratorHelper, WrapForValidIterator,
+        },
         map::MapIterator,
         object::for_in_iterator::ForInIterator,
         regexp::RegExpStringIterator,
         set::SetIterator,
+        shadow_realm::ShadowRealm,
         string::StringIterator,
+        structured_clone::StructuredClone,
         typed_array::BuiltinTypedArray,
         uri::{DecodeUri, DecodeUriComponent, EncodeUri, EncodeUriComponent},
         weak::WeakRef,
@@ -196,6 +206,8 @@ impl Realm {
         BuiltInFunctionObject::init(self);
         OrdinaryObject::init(self);
         Iterator::init(self);
+        IteratorHelper::init(self);
+        WrapForValidIterator::init(self);
         AsyncIterator::init(self);
         AsyncFromSyncIterator::init(self);
         ForInIterator::init(self);
@@ -246,6 +258,7 @@ impl Realm {
         EvalError::init(self);
         UriError::init(self);
         AggregateError::init(self);
+        SuppressedError::init(self);
         Reflect::init(self);
         Generator::init(self);
         GeneratorFunction::init(self);
@@ -261,6 +274,10 @@ impl Realm {
         WeakMap::init(self);
         WeakSet::init(self);
         Atomics::init(self);
+        DisposableStack::init(self);
+        AsyncDisposableStack::init(self);
+        ShadowRealm::init(self);
+        StructuredClone::init(self);
 
         #[cfg(feature = "annex-b")]
         {
@@ -279,7 +296,10 @@ impl Realm {
             intl::segmenter::Segments::init(self);
             intl::segmenter::SegmentIterator::init(self);
             intl::PluralRules::init(self);
+            intl::RelativeTimeFormat::init(self);
             intl::NumberFormat::init(self);
+            intl::DurationFormat::init(self);
+            intl::DisplayNames::init(self);
         }
 
         #[cfg(feature = "temporal")]
@@ -340,6 +360,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<Math>(context)?;
     global_binding::<Json>(context)?;
     global_binding::<Array>(context)?;
+    global_binding::<Iterator>(context)?;
     global_binding::<Proxy>(context)?;
     global_binding::<ArrayBuffer>(context)?;
     global_binding::<SharedArrayBuffer>(context)?;
@@ -378,6 +399,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<EvalError>(context)?;
     global_binding::<UriError>(context)?;
     global_binding::<AggregateError>(context)?;
+    global_binding::<SuppressedError>(context)?;
     global_binding::<Reflect>(context)?;
     global_binding::<Promise>(context)?;
     global_binding::<EncodeUri>(context)?;
@@ -388,6 +410,10 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<WeakMap>(context)?;
     global_binding::<WeakSet>(context)?;
     global_binding::<Atomics>(context)?;
+    global_binding::<DisposableStack>(context)?;
+    global_binding::<AsyncDisposableStack>(context)?;
+    global_binding::<ShadowRealm>(context)?;
+    global_binding::<StructuredClone>(context)?;
 
     #[cfg(feature = "annex-b")]
     {
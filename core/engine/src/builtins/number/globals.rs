@@ -5,7 +5,7 @@ use crate::{
     context::intrinsics::Intrinsics,
     object::JsObject,
     realm::Realm,
-    string::{common::StaticJsStrings, Utf16Trim},
+    string::common::StaticJsStrings,
     Context, JsArgs, JsResult, JsString, JsValue,
 };
 
@@ -115,12 +115,12 @@ pub(crate) fn parse_int(_: &JsValue, args: &[JsValue], context: &mut Context) ->
         let input_string = val.to_string(context)?;
 
         // 2. Let S be ! TrimString(inputString, start).
-        let mut var_s = input_string.trim_start();
+        let mut var_s = input_string.as_str().trim_start();
 
         // 3. Let sign be 1.
         // 4. If S is not empty and the first code unit of S is the code unit 0x002D (HYPHEN-MINUS),
         //    set sign to -1.
-        let sign = if !var_s.is_empty() && var_s.starts_with(utf16!("-")) {
+        let sign = if !var_s.is_empty() && var_s.starts_with(utf16!("-").into()) {
             -1
         } else {
             1
@@ -128,8 +128,10 @@ pub(crate) fn parse_int(_: &JsValue, args: &[JsValue], context: &mut Context) ->
 
         // 5. If S is not empty and the first code unit of S is the code unit 0x002B (PLUS SIGN) or
         //    the code unit 0x002D (HYPHEN-MINUS), remove the first code unit from S.
-        if !var_s.is_empty() && (var_s.starts_with(utf16!("+")) || var_s.starts_with(utf16!("-"))) {
-            var_s = &var_s[1..];
+        if !var_s.is_empty()
+            && (var_s.starts_with(utf16!("+").into()) || var_s.starts_with(utf16!("-").into()))
+        {
+            var_s = var_s.get_expect(1..);
         }
 
         // 6. Let R be ℝ(? ToInt32(radix)).
@@ -162,21 +164,21 @@ pub(crate) fn parse_int(_: &JsValue, args: &[JsValue], context: &mut Context) ->
         //         ii. Set R to 16.
         if strip_prefix
             && var_s.len() >= 2
-            && (var_s.starts_with(utf16!("0x")) || var_s.starts_with(utf16!("0X")))
+            && (var_s.starts_with(utf16!("0x").into()) || var_s.starts_with(utf16!("0X").into()))
         {
-            var_s = &var_s[2..];
+            var_s = var_s.get_expect(2..);
 
             var_r = 16;
         }
 
         // 11. If S contains a code unit that is not a radix-R digit, let end be the index within S of the
         //     first such code unit; otherwise, let end be the length of S.
-        let end = char::decode_utf16(var_s.iter().copied())
+        let end = char::decode_utf16(var_s.iter())
             .position(|code| !code.map(|c| c.is_digit(var_r as u32)).unwrap_or_default())
             .unwrap_or(var_s.len());
 
         // 12. Let Z be the substring of S from 0 to end.
-        let var_z = String::from_utf16_lossy(&var_s[..end]);
+        let var_z = String::from_utf16_lossy(&var_s.get_expect(..end).to_vec());
 
         // 13. If Z is empty, return NaN.
         if var_z.is_empty() {
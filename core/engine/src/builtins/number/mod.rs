@@ -21,12 +21,14 @@ use crate::{
     object::{internal_methods::get_prototype_from_constructor, JsObject},
     property::Attribute,
     realm::Realm,
-    string::common::StaticJsStrings,
+    string::{common::StaticJsStrings, JsStr},
     value::{AbstractRelation, IntegerOrInfinity, JsValue},
     Context, JsArgs, JsResult, JsString,
 };
 use boa_profiler::Profiler;
-use num_traits::float::FloatCore;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::One;
 
 mod globals;
 pub(crate) use globals::{IsFinite, IsNaN, ParseFloat, ParseInt};
@@ -40,8 +42,6 @@ use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 #[cfg(test)]
 mod tests;
 
-const BUF_SIZE: usize = 2200;
-
 /// `Number` implementation.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Number;
@@ -280,120 +280,94 @@ impl Number {
                     .with_message("toFixed() digits argument must be between 0 and 100")
             })? as u8;
 
-        let mut buffer = ryu_js::Buffer::new();
-        let string = buffer.format_to_fixed(this_num, precision);
+        // 6. If x is not finite, return Number::toString(x, 10).
+        // 10. If x ≥ 10^21, then
+        //     a. Let m be ! ToString(𝔽(x)).
+        if !this_num.is_finite() || this_num.abs() >= 1e21 {
+            return Ok(Self::to_js_string(this_num).into());
+        }
 
-        Ok(js_string!(string).into())
+        // 7. Set x to ℝ(x).
+        // 8. Let s be the empty String.
+        // 9. If x < 0, then
+        //     a. Set s to "-".
+        //     b. Set x to -x.
+        let prefix = if this_num < 0.0 { "-" } else { "" };
+        let x = this_num.abs();
+
+        // 11. Else,
+        //     a. Let n be an integer for which n / 10^f - x is as close to zero as possible. If there are two such n, pick the larger n.
+        //     b. If n = 0, let m be "0". Otherwise, let m be the String value consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+        let mut m = round_scaled(x, i32::from(precision)).to_string();
+        let precision = usize::from(precision);
+
+        //     c. If f ≠ 0, then
+        if precision != 0 {
+            // i. Let k be the length of m.
+            let k = m.len();
+
+            // ii. If k ≤ f, then
+            if k <= precision {
+                // 1. Let z be the String value consisting of f + 1 - k occurrences of the code unit 0x0030 (DIGIT ZERO).
+                // 2. Set m to the string-concatenation of z and m.
+                // 3. Set k to f + 1.
+                m.insert_str(0, &"0".repeat(precision + 1 - k));
+            }
+
+            // iii. Let a be the first k - f code units of m.
+            // iv. Let b be the other f code units of m.
+            // v. Set m to the string-concatenation of a, ".", and b.
+            m.insert(m.len() - precision, '.');
+        }
+
+        // 12. Return the string-concatenation of s and m.
+        Ok(js_string!(prefix.to_owned() + &m).into())
     }
 
     /// `Number.prototype.toLocaleString( [locales [, options]] )`
     ///
     /// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
     ///
-    /// Note that while this technically conforms to the Ecma standard, it does no actual
-    /// internationalization logic.
+    /// Without the `intl` feature, this technically conforms to the Ecma standard, but it does no
+    /// actual internationalization logic.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tolocalestring
+    /// [spec]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
-        _: &mut Context,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        let this_num = Self::this_number_value(this)?;
-        let this_str_num = this_num.to_string();
-        Ok(JsValue::new(js_string!(this_str_num)))
-    }
+        // 1. Let x be ? ThisNumberValue(this value).
+        let x = Self::this_number_value(this)?;
 
-    /// `flt_str_to_exp` - used in `to_precision`
-    ///
-    /// This function traverses a string representing a number,
-    /// returning the floored log10 of this number.
-    fn flt_str_to_exp(flt: &str) -> i32 {
-        let mut non_zero_encountered = false;
-        let mut dot_encountered = false;
-        for (i, c) in flt.chars().enumerate() {
-            if c == '.' {
-                if non_zero_encountered {
-                    return (i as i32) - 1;
-                }
-                dot_encountered = true;
-            } else if c != '0' {
-                if dot_encountered {
-                    return 1 - (i as i32);
-                }
-                non_zero_encountered = true;
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::number_format::{to_intl_mathematical_value, NumberFormat};
+
+            // TODO: `FixedDecimal` cannot represent NaN nor infinities yet.
+            if !x.is_finite() {
+                return Ok(Self::to_js_string(x).into());
             }
+
+            // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%, « locales, options »).
+            let number_format = NumberFormat::construct(args, context)?;
+
+            // 3. Return FormatNumeric(numberFormat, ! ToIntlMathematicalValue(x)).
+            let mut x = to_intl_mathematical_value(&x.into(), context)?;
+            let formatted = number_format.borrow().data.format(&mut x).to_string();
+            Ok(js_string!(formatted).into())
         }
-        (flt.len() as i32) - 1
-    }
-
-    /// `round_to_precision` - used in `to_precision`
-    ///
-    /// This procedure has two roles:
-    /// - If there are enough or more than enough digits in the
-    ///   string to show the required precision, the number
-    ///   represented by these digits is rounded using string
-    ///   manipulation.
-    /// - Else, zeroes are appended to the string.
-    /// - Additionally, sometimes the exponent was wrongly computed and
-    ///   while up-rounding we find that we need an extra digit. When this
-    ///   happens, we return true so that the calling context can adjust
-    ///   the exponent. The string is kept at an exact length of `precision`.
-    ///
-    /// When this procedure returns, `digits` is exactly `precision` long.
-    fn round_to_precision(digits: &mut String, precision: usize) -> bool {
-        if digits.len() > precision {
-            let to_round = digits.split_off(precision);
-            let mut digit = digits
-                .pop()
-                .expect("already checked that length is bigger than precision")
-                as u8;
-            if let Some(first) = to_round.chars().next() {
-                if first > '4' {
-                    digit += 1;
-                }
-            }
 
-            if digit as char == ':' {
-                // ':' is '9' + 1
-                // need to propagate the increment backward
-                let mut replacement = String::from("0");
-                let mut propagated = false;
-                for c in digits.chars().rev() {
-                    let d = match (c, propagated) {
-                        ('0'..='8', false) => (c as u8 + 1) as char,
-                        (_, false) => '0',
-                        (_, true) => c,
-                    };
-                    replacement.push(d);
-                    if d != '0' {
-                        propagated = true;
-                    }
-                }
-                digits.clear();
-                let replacement = if propagated {
-                    replacement.as_str()
-                } else {
-                    digits.push('1');
-                    &replacement.as_str()[1..]
-                };
-                for c in replacement.chars().rev() {
-                    digits.push(c);
-                }
-                !propagated
-            } else {
-                digits.push(digit as char);
-                false
-            }
-        } else {
-            digits.push_str(&"0".repeat(precision - digits.len()));
-            false
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (args, context);
+            Ok(JsValue::new(js_string!(x.to_string())))
         }
     }
 
@@ -444,7 +418,7 @@ impl Number {
         // 7
         let mut prefix = String::new(); // spec: 's'
         let mut suffix: String; // spec: 'm'
-        let mut exponent: i32; // spec: 'e'
+        let exponent: i32; // spec: 'e'
 
         // 8
         if this_num < 0.0 {
@@ -458,23 +432,10 @@ impl Number {
             exponent = 0;
         // 10
         } else {
-            // Due to f64 limitations, this part differs a bit from the spec,
-            // but has the same effect. It manipulates the string constructed
-            // by `format`: digits with an optional dot between two of them.
-            suffix = format!("{this_num:.100}");
-
-            // a: getting an exponent
-            exponent = Self::flt_str_to_exp(&suffix);
-            // b: getting relevant digits only
-            if exponent < 0 {
-                suffix = suffix.split_off((1 - exponent) as usize);
-            } else if let Some(n) = suffix.find('.') {
-                suffix.remove(n);
-            }
-            // impl: having exactly `precision` digits in `suffix`
-            if Self::round_to_precision(&mut suffix, precision) {
-                exponent += 1;
-            }
+            // a. Let e and n be integers such that 10^(p-1) ≤ n < 10^p and for which n × 10^(e-p+1) - x is as close to zero as possible.
+            //    If there are two such sets of e and n, pick the e and n for which n × 10^(e-p+1) is larger.
+            // b. Let m be the String value consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+            (suffix, exponent) = significant_digits(this_num, precision);
 
             // c: switching to scientific notation
             let great_exp = exponent >= precision_i32;
@@ -516,136 +477,95 @@ impl Number {
         Ok(JsValue::new(js_string!(prefix + &suffix)))
     }
 
-    // https://golang.org/src/math/nextafter.go
-    fn next_after(x: f64, y: f64) -> f64 {
-        if x.is_nan() || y.is_nan() {
-            f64::NAN
-        } else if (x - y) == 0. {
-            x
-        } else if x == 0.0 {
-            f64::from_bits(1).copysign(y)
-        } else if y > x || x > 0.0 {
-            f64::from_bits(x.to_bits() + 1)
-        } else {
-            f64::from_bits(x.to_bits() - 1)
-        }
-    }
-
-    // https://chromium.googlesource.com/v8/v8/+/refs/heads/master/src/numbers/conversions.cc#1230
+    /// Converts a finite number to its shortest representation in the given radix, as in
+    /// `Number::toString`.
     #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn to_js_string_radix(mut value: f64, radix: u8) -> JsString {
-        assert!(radix >= 2);
-        assert!(radix <= 36);
+    pub(crate) fn to_js_string_radix(value: f64, radix: u8) -> JsString {
+        assert!((2..=36).contains(&radix));
         assert!(value.is_finite());
-        // assert_ne!(0.0, value);
-
-        // Character array used for conversion.
-        // Temporary buffer for the result. We start with the decimal point in the
-        // middle and write to the left for the integer part and to the right for the
-        // fractional part. 1024 characters for the exponent and 52 for the mantissa
-        // either way, with additional space for sign, decimal point and string
-        // termination should be sufficient.
-        let mut buffer: [u8; BUF_SIZE] = [0; BUF_SIZE];
-        let (int_buf, frac_buf) = buffer.split_at_mut(BUF_SIZE / 2);
-        let mut fraction_cursor = 0;
-        let negative = value.is_sign_negative();
-        if negative {
-            value = -value;
-        }
-        // Split the value into an integer part and a fractional part.
-        // let mut integer = value.trunc();
-        // let mut fraction = value.fract();
-        let mut integer = value.floor();
-        let mut fraction = value - integer;
-
-        // We only compute fractional digits up to the input double's precision.
-        let mut delta = 0.5 * (Self::next_after(value, f64::MAX) - value);
-        delta = Self::next_after(0.0, f64::MAX).max(delta);
-        assert!(delta > 0.0);
-        if fraction >= delta {
-            // Insert decimal point.
-            frac_buf[fraction_cursor] = b'.';
-            fraction_cursor += 1;
+
+        let mut string = String::new();
+        if value < 0.0 {
+            string.push('-');
+        }
+        let value = value.abs();
+
+        // Integers below 2^53 are exactly represented by all their digits, which are also the
+        // shortest digits that round to them.
+        if value.fract() == 0.0 && value < 9_007_199_254_740_992.0 {
+            // Enough for the 53 binary digits of the biggest integer.
+            let mut buffer = [0; 53];
+            let mut start = buffer.len();
+            let mut integer = value as u64;
             loop {
-                // Shift up by one digit.
-                fraction *= f64::from(radix);
-                delta *= f64::from(radix);
-                // Write digit.
-                let digit = fraction as u32;
-                frac_buf[fraction_cursor] = std::char::from_digit(digit, u32::from(radix))
-                    .expect("radix already checked")
-                    as u8;
-                fraction_cursor += 1;
-                // Calculate remainder.
-                fraction -= f64::from(digit);
-                // Round to even.
-                if fraction + delta > 1.0
-                    && (fraction > 0.5 || (fraction - 0.5).abs() < f64::EPSILON && digit & 1 != 0)
-                {
-                    loop {
-                        // We need to back trace already written digits in case of carry-over.
-                        fraction_cursor -= 1;
-                        if fraction_cursor == 0 {
-                            //              CHECK_EQ('.', buffer[fraction_cursor]);
-                            // Carry over to the integer part.
-                            integer += 1.;
-                        } else {
-                            let c: u8 = frac_buf[fraction_cursor];
-                            // Reconstruct digit.
-                            let digit = if c > b'9' { c - b'a' + 10 } else { c - b'0' };
-                            if digit + 1 >= radix {
-                                continue;
-                            }
-                            frac_buf[fraction_cursor] =
-                                std::char::from_digit(u32::from(digit + 1), u32::from(radix))
-                                    .expect("digit was not a valid number in the given radix")
-                                    as u8;
-                            fraction_cursor += 1;
-                        }
-                        break;
-                    }
-                    break;
-                }
-                if fraction < delta {
+                start -= 1;
+                buffer[start] = radix_digit((integer % u64::from(radix)) as u8, radix);
+                integer /= u64::from(radix);
+                if integer == 0 {
                     break;
                 }
             }
+            string.push_str(std::str::from_utf8(&buffer[start..]).expect("digits are ASCII"));
+            return js_string!(string);
         }
 
-        // Compute integer digits. Fill unrepresented digits with zero.
-        let mut int_iter = int_buf.iter_mut().enumerate().rev();
-        while FloatCore::integer_decode(integer / f64::from(radix)).1 > 0 {
-            integer /= f64::from(radix);
-            *int_iter.next().expect("integer buffer exhausted").1 = b'0';
+        let (digits, exponent) = shortest_radix_digits(value, radix);
+        let digit_count = digits.len() as i32;
+        if exponent <= 0 {
+            string.push_str("0.");
+            string.push_str(&"0".repeat(exponent.unsigned_abs() as usize));
+            string.push_str(&digits);
+        } else if exponent >= digit_count {
+            string.push_str(&digits);
+            string.push_str(&"0".repeat((exponent - digit_count) as usize));
+        } else {
+            let (integer, fraction) = digits.split_at(exponent as usize);
+            string.push_str(integer);
+            string.push('.');
+            string.push_str(fraction);
         }
 
+        js_string!(string)
+    }
+
+    /// Converts a number to its shortest decimal representation, as in `Number::toString`.
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_js_string(x: f64) -> JsString {
+        // Integers below 2^53 are represented by all their digits, while bigger ones can have
+        // trailing zeros instead.
+        if x.fract() == 0.0 && x.abs() < 9_007_199_254_740_992.0 {
+            return Self::integer_to_js_string(x as i64);
+        }
+
+        let mut buffer = ryu_js::Buffer::new();
+        JsStr::Latin1(buffer.format(x).as_bytes()).into()
+    }
+
+    /// Converts an integer to its decimal representation, reusing the static strings of small
+    /// integers.
+    pub(crate) fn integer_to_js_string(x: i64) -> JsString {
+        if let Some(string) = StaticJsStrings::get_integer(x) {
+            return string;
+        }
+
+        // Enough for the sign and the 19 digits of `i64::MIN`.
+        let mut buffer = [0; 20];
+        let mut start = buffer.len();
+        let mut value = x.unsigned_abs();
         loop {
-            let remainder = integer % f64::from(radix);
-            *int_iter.next().expect("integer buffer exhausted").1 =
-                std::char::from_digit(remainder as u32, u32::from(radix))
-                    .expect("remainder not a digit in the given number") as u8;
-            integer = (integer - remainder) / f64::from(radix);
-            if integer <= 0f64 {
+            start -= 1;
+            buffer[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
                 break;
             }
         }
-        // Add sign and terminate string.
-        if negative {
-            *int_iter.next().expect("integer buffer exhausted").1 = b'-';
+        if x < 0 {
+            start -= 1;
+            buffer[start] = b'-';
         }
-        assert!(fraction_cursor < BUF_SIZE);
 
-        let integer_cursor = int_iter.next().expect("integer buffer exhausted").0 + 1;
-        let fraction_cursor = fraction_cursor + BUF_SIZE / 2;
-        js_string!(&*String::from_utf8_lossy(
-            &buffer[integer_cursor..fraction_cursor]
-        ))
-    }
-
-    #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn to_js_string(x: f64) -> JsString {
-        let mut buffer = ryu_js::Buffer::new();
-        js_string!(buffer.format(x).to_string())
+        JsStr::Latin1(&buffer[start..]).into()
     }
 
     /// `Number.prototype.toString( [radix] )`
@@ -699,14 +619,6 @@ impl Number {
             return Ok(JsValue::new(js_string!("-Infinity")));
         }
 
-        // This is a Optimization from the v8 source code to print values that can fit in a single character
-        // Since the actual num_to_string allocates a 2200 bytes buffer for actual conversion
-        // I am not sure if this part is effective as the v8 equivalent https://chromium.googlesource.com/v8/v8/+/refs/heads/master/src/builtins/number.tq#53
-        // // Fast case where the result is a one character string.
-        // if x.is_sign_positive() && x.fract() == 0.0 && x < radix_number as f64 {
-        //     return Ok(std::char::from_digit(x as u32, radix_number as u32).unwrap().to_string().into())
-        // }
-
         // 6. Return the String representation of this Number value using the radix specified by radixNumber.
         Ok(JsValue::new(Self::to_js_string_radix(x, radix_number)))
     }
@@ -924,14 +836,226 @@ fn f64_to_exponential(n: f64) -> JsString {
 }
 
 /// Helper function that formats a float as a ES6-style exponential number string with a given precision.
-// We can't use the same approach as in `f64_to_exponential`
-// because in cases like (0.999).toExponential(0) the result will be 1e0.
-// Instead we get the index of 'e', and if the next character is not '-' we insert the plus sign
 fn f64_to_exponential_with_precision(n: f64, prec: usize) -> JsString {
-    let mut res = format!("{n:.prec$e}");
-    let idx = res.find('e').expect("'e' not found in exponential string");
-    if res.as_bytes()[idx + 1] != b'-' {
-        res.insert(idx + 1, '+');
+    let prefix = if n < 0.0 { "-" } else { "" };
+    let n = n.abs();
+
+    // 8. If x = 0, then
+    let (mut digits, exponent) = if n == 0.0 {
+        // a. Let m be the String value consisting of f + 1 occurrences of the code unit 0x0030 (DIGIT ZERO).
+        // b. Let e be 0.
+        ("0".repeat(prec + 1), 0)
+    } else {
+        // 9. Else,
+        //     a. If fractionDigits is not undefined, then
+        //         i. Let e and n be integers such that 10^f ≤ n < 10^(f+1) and for which n × 10^(e-f) - x is as close to zero as possible.
+        //            If there are two such sets of e and n, pick the e and n for which n × 10^(e-f) is larger.
+        //     c. Let m be the String value consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+        significant_digits(n, prec + 1)
+    };
+
+    // 10. If f ≠ 0, then
+    if prec != 0 {
+        // a. Let a be the first code unit of m.
+        // b. Let b be the remaining f code units of m.
+        // c. Set m to the string-concatenation of a, ".", and b.
+        digits.insert(1, '.');
+    }
+
+    // 11. If e = 0, then
+    //     a. Let c be "+".
+    //     b. Let d be "0".
+    // 12. Else,
+    //     a. If e > 0, let c be "+".
+    //     b. Else,
+    //         i. Assert: e < 0.
+    //         ii. Let c be "-".
+    //         iii. Set e to -e.
+    //     c. Let d be the String value consisting of the digits of the decimal representation of e (in order, with no leading zeroes).
+    // 13. Set m to the string-concatenation of m, "e", c, and d.
+    let sign = if exponent < 0 { '-' } else { '+' };
+    let exponent = exponent.unsigned_abs();
+
+    // 14. Return the string-concatenation of s and m.
+    js_string!(format!("{prefix}{digits}e{sign}{exponent}"))
+}
+
+/// Decomposes a finite, non-negative float into its binary significand and exponent,
+/// such that `x = significand × 2^exponent`.
+fn decompose(x: f64) -> (u64, i32) {
+    let bits = x.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+
+    if biased_exponent == 0 {
+        // Subnormal numbers don't have the implicit leading bit.
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    }
+}
+
+/// Computes the integer closest to `x × 10^scale` for a finite, non-negative `x`, picking the
+/// larger integer if there are two such integers.
+///
+/// This uses big integer arithmetic over the binary significand of `x`, so the result is exact
+/// for all values of `x` and `scale`.
+fn round_scaled(x: f64, scale: i32) -> BigUint {
+    let (significand, exponent) = decompose(x);
+
+    let mut numerator = BigUint::from(significand);
+    let mut denominator = BigUint::one();
+
+    if exponent >= 0 {
+        numerator <<= exponent.unsigned_abs();
+    } else {
+        denominator <<= exponent.unsigned_abs();
+    }
+
+    let power = BigUint::from(10u8).pow(scale.unsigned_abs());
+    if scale >= 0 {
+        numerator *= power;
+    } else {
+        denominator *= power;
+    }
+
+    // floor(numerator / denominator + 1/2)
+    (numerator * 2u8 + &denominator) / (denominator * 2u8)
+}
+
+/// Gets the decimal digits `n` and the exponent `e` such that `n` has exactly `precision` digits
+/// and `n × 10^(e - precision + 1)` is as close as possible to the finite, positive `x`, picking
+/// the larger `n` if there are two such values.
+fn significant_digits(x: f64, precision: usize) -> (String, i32) {
+    let precision_i32 = precision as i32;
+
+    // `log10` is only an estimate of the exponent, which is then corrected by checking the number
+    // of digits generated. This also handles rounding up to the next power of ten.
+    let mut exponent = x.log10().floor() as i32;
+    loop {
+        let digits = round_scaled(x, precision_i32 - 1 - exponent).to_string();
+        match digits.len().cmp(&precision) {
+            std::cmp::Ordering::Greater => exponent += 1,
+            std::cmp::Ordering::Less => exponent -= 1,
+            std::cmp::Ordering::Equal => return (digits, exponent),
+        }
+    }
+}
+
+/// Gets the ASCII character of a digit in the given radix.
+fn radix_digit(digit: u8, radix: u8) -> u8 {
+    char::from_digit(u32::from(digit), u32::from(radix)).expect("digit must be less than the radix")
+        as u8
+}
+
+/// Gets the shortest digits `d` and the exponent `k` such that `0.d × radix^k` rounds to the
+/// finite, positive `x`, picking the digits closest to `x` if there are several of them.
+///
+/// This is the free-format algorithm of Steele & White, as refined by Burger & Dybvig, over the
+/// exact binary value of `x`: the remaining value is `r / s`, and the distances to the halfway
+/// points with the neighbouring floats are `m_plus / s` and `m_minus / s`.
+fn shortest_radix_digits(x: f64, radix: u8) -> (String, i32) {
+    let (significand, exponent) = decompose(x);
+
+    // Halfway points round to `x` if its significand is even.
+    let inclusive = significand % 2 == 0;
+    // The previous float is closer than the next one for powers of two, except for the smallest
+    // normal number.
+    let unequal_gaps = significand == 1 << 52 && exponent > -1074;
+
+    let significand = BigUint::from(significand);
+    let shift = exponent.unsigned_abs();
+    let (mut r, mut s, mut m_plus, mut m_minus) = match (exponent >= 0, unequal_gaps) {
+        (true, false) => (
+            significand << (shift + 1),
+            BigUint::from(2u8),
+            BigUint::one() << shift,
+            BigUint::one() << shift,
+        ),
+        (true, true) => (
+            significand << (shift + 2),
+            BigUint::from(4u8),
+            BigUint::one() << (shift + 1),
+            BigUint::one() << shift,
+        ),
+        (false, false) => (
+            significand << 1,
+            BigUint::one() << (shift + 1),
+            BigUint::one(),
+            BigUint::one(),
+        ),
+        (false, true) => (
+            significand << 2,
+            BigUint::one() << (shift + 2),
+            BigUint::from(2u8),
+            BigUint::one(),
+        ),
+    };
+
+    let reaches_next_digit = |r: &BigUint, m_plus: &BigUint, s: &BigUint| {
+        if inclusive {
+            r + m_plus >= *s
+        } else {
+            r + m_plus > *s
+        }
+    };
+
+    // Scale by `radix^k`, using the logarithm as an estimate of `k` that is then corrected.
+    let mut k = (x.log2() / f64::from(radix).log2() - 1e-10).ceil() as i32;
+    let power = BigUint::from(radix).pow(k.unsigned_abs());
+    if k >= 0 {
+        s *= power;
+    } else {
+        r *= &power;
+        m_plus *= &power;
+        m_minus *= power;
+    }
+    while reaches_next_digit(&r, &m_plus, &s) {
+        s *= radix;
+        k += 1;
+    }
+    while !reaches_next_digit(&(&r * radix), &(&m_plus * radix), &s) {
+        r *= radix;
+        m_plus *= radix;
+        m_minus *= radix;
+        k -= 1;
+    }
+
+    // For radices that are powers of two `s` is also a power of two, so digits can be extracted
+    // with shifts and masks instead of big integer divisions.
+    let power_of_two = radix.is_power_of_two().then(|| {
+        let bits = s.bits() - 1;
+        (bits, (BigUint::one() << bits) - 1u8)
+    });
+
+    let mut digits = String::new();
+    loop {
+        r *= radix;
+        m_plus *= radix;
+        m_minus *= radix;
+
+        let (digit, rest) = match &power_of_two {
+            Some((bits, mask)) => (&r >> *bits, r & mask),
+            None => r.div_rem(&s),
+        };
+        r = rest;
+        let digit = u8::try_from(&digit).expect("digit must be less than the radix");
+
+        let low = if inclusive { r <= m_minus } else { r < m_minus };
+        let high = reaches_next_digit(&r, &m_plus, &s);
+        #[allow(clippy::match_same_arms)]
+        let digit = match (low, high) {
+            (false, false) => {
+                digits.push(char::from(radix_digit(digit, radix)));
+                continue;
+            }
+            (true, false) => digit,
+            (false, true) => digit + 1,
+            (true, true) if &r * 2u8 < s => digit,
+            (true, true) => digit + 1,
+        };
+        digits.push(char::from(radix_digit(digit, radix)));
+
+        return (digits, k);
     }
-    js_string!(res)
 }
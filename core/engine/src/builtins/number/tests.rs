@@ -2,6 +2,8 @@ use crate::{
     builtins::Number, js_string, run_test_actions, value::AbstractRelation, JsNativeErrorKind,
     TestAction,
 };
+use indoc::indoc;
+use num_bigint::BigUint;
 
 #[test]
 fn integer_number_primitive_to_number_object() {
@@ -167,18 +169,87 @@ fn issue_2609() {
     ]);
 }
 
+#[test]
+fn digit_generation_regressions() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "(1000000000000000128).toFixed(0)",
+            js_string!("1000000000000000128"),
+        ),
+        TestAction::assert_eq("(0.5).toFixed(20)", js_string!("0.50000000000000000000")),
+        TestAction::assert_eq("(2.5).toFixed(0)", js_string!("3")),
+        TestAction::assert_eq("(1.005).toFixed(2)", js_string!("1.00")),
+        TestAction::assert_eq("(1e21).toFixed(2)", js_string!("1e+21")),
+        TestAction::assert_eq("(-1e21).toFixed(2)", js_string!("-1e+21")),
+        TestAction::assert_eq(
+            "(0.1).toFixed(100)",
+            js_string!("0.1000000000000000055511151231257827021181583404541015625000000000000000000000000000000000000000000000"),
+        ),
+        TestAction::assert_eq("(12345).toExponential(3)", js_string!("1.235e+4")),
+        TestAction::assert_eq("(25).toExponential(0)", js_string!("3e+1")),
+        TestAction::assert_eq("(-0.00015).toExponential(1)", js_string!("-1.5e-4")),
+        TestAction::assert_eq("(0).toExponential(2)", js_string!("0.00e+0")),
+        TestAction::assert_eq("(5e-324).toExponential(2)", js_string!("4.94e-324")),
+        TestAction::assert_eq("(9.99).toExponential(1)", js_string!("1.0e+1")),
+        TestAction::assert_eq(
+            "(1/3).toExponential(100)",
+            js_string!("3.3333333333333331482961625624739099293947219848632812500000000000000000000000000000000000000000000000e-1"),
+        ),
+        TestAction::assert_eq(
+            "(1.7976931348623157e308).toPrecision(3)",
+            js_string!("1.80e+308"),
+        ),
+        TestAction::assert_eq("(0.000001).toPrecision(2)", js_string!("0.0000010")),
+        TestAction::assert_eq("(0.0000001).toPrecision(2)", js_string!("1.0e-7")),
+        TestAction::assert_eq("(99.99).toPrecision(3)", js_string!("100")),
+        TestAction::assert_eq("(999.9).toPrecision(3)", js_string!("1.00e+3")),
+        TestAction::assert_eq(
+            "(0.1).toPrecision(100)",
+            js_string!("0.1000000000000000055511151231257827021181583404541015625000000000000000000000000000000000000000000000"),
+        ),
+        TestAction::assert_native_error(
+            "(1).toFixed(101)",
+            JsNativeErrorKind::Range,
+            "toFixed() digits argument must be between 0 and 100",
+        ),
+        TestAction::assert_native_error(
+            "(1).toExponential(101)",
+            JsNativeErrorKind::Range,
+            "toExponential() argument must be between 0 and 100",
+        ),
+    ]);
+}
+
 #[test]
 fn to_locale_string() {
-    // TODO: We don't actually do any locale checking here
-    // To honor the spec we should print numbers according to user locale.
     run_test_actions([
         TestAction::assert_eq("Number().toLocaleString()", js_string!("0")),
         TestAction::assert_eq("Number(5).toLocaleString()", js_string!("5")),
-        TestAction::assert_eq("Number('345600').toLocaleString()", js_string!("345600")),
         TestAction::assert_eq("Number(-25).toLocaleString()", js_string!("-25")),
     ]);
 }
 
+#[cfg(feature = "intl")]
+#[test]
+fn to_locale_string_intl() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Number('345600').toLocaleString('en-US')",
+            js_string!("345,600"),
+        ),
+        TestAction::assert_eq("(1234.5).toLocaleString('en-US')", js_string!("1,234.5")),
+        TestAction::assert_eq("(1234.5).toLocaleString('de-DE')", js_string!("1.234,5")),
+        TestAction::assert_eq(
+            "(1234.5).toLocaleString('en-US', { maximumFractionDigits: 0 })",
+            js_string!("1,235"),
+        ),
+        TestAction::assert_eq(
+            "(1234.5).toLocaleString('de-DE', { useGrouping: false })",
+            js_string!("1234,5"),
+        ),
+    ]);
+}
+
 #[test]
 fn to_precision() {
     const ERROR: &str = "precision must be an integer at least 1 and no greater than 100";
@@ -365,6 +436,35 @@ fn num_to_string_exponential() {
     ]);
 }
 
+#[test]
+fn integer_to_string() {
+    run_test_actions([
+        TestAction::assert_eq("String(-1)", js_string!("-1")),
+        TestAction::assert_eq("String(-2)", js_string!("-2")),
+        TestAction::assert_eq("String(256)", js_string!("256")),
+        TestAction::assert_eq("String(257)", js_string!("257")),
+        TestAction::assert_eq("String(-0)", js_string!("0")),
+        TestAction::assert_eq("String(2.0)", js_string!("2")),
+        TestAction::assert_eq("String(-2147483648)", js_string!("-2147483648")),
+        TestAction::assert_eq("String(2 ** 53 - 1)", js_string!("9007199254740991")),
+        TestAction::assert_eq("String(-(2 ** 53))", js_string!("-9007199254740992")),
+        TestAction::assert_eq("String(2 ** 53 + 2)", js_string!("9007199254740994")),
+        TestAction::assert_eq("String(2 ** 60)", js_string!("1152921504606847000")),
+        TestAction::assert_eq("String(5e-324)", js_string!("5e-324")),
+        TestAction::assert_eq("String(-5e-324)", js_string!("-5e-324")),
+        TestAction::assert_eq("String(0.1 + 0.2)", js_string!("0.30000000000000004")),
+        TestAction::assert_eq("String(1e21)", js_string!("1e+21")),
+        TestAction::assert_eq(
+            "Object.keys({ 1: 0, 300: 0, [2 ** 32]: 0 }).join()",
+            js_string!("1,300,4294967296"),
+        ),
+        TestAction::assert_eq(
+            "[-1, 0, 7, 1000, 0.5].join()",
+            js_string!("-1,0,7,1000,0.5"),
+        ),
+    ]);
+}
+
 #[test]
 fn value_of() {
     // TODO: In addition to parsing numbers from strings, parse them bare As of October 2019
@@ -644,9 +744,127 @@ fn issue_2717() {
             "(0.1600057092765239).toString(36)",
             js_string!("0.5rd85dm1ixq"),
         ),
+        // Both `phh` and `phj` round to the number, but `phh` is closer to it.
         TestAction::assert_eq(
             "(0.23046743672210102).toString(36)",
-            js_string!("0.8aoosla2phj"),
+            js_string!("0.8aoosla2phh"),
+        ),
+    ]);
+}
+
+#[test]
+fn to_string_radix_shortest() {
+    run_test_actions([
+        TestAction::assert_eq("(0.5).toString(2)", js_string!("0.1")),
+        TestAction::assert_eq(
+            "(0.1).toString(2)",
+            js_string!("0.0001100110011001100110011001100110011001100110011001101"),
+        ),
+        TestAction::assert_eq(
+            "(0.1).toString(3)",
+            js_string!("0.0022002200220022002200220022002201"),
+        ),
+        TestAction::assert_eq("(1/3).toString(3)", js_string!("0.1")),
+        TestAction::assert_eq(
+            "(0.3).toString(5)",
+            js_string!("0.122222222222222222222222"),
+        ),
+        TestAction::assert_eq("(0.1).toString(7)", js_string!("0.04620462046204620463")),
+        TestAction::assert_eq("(123.456).toString(8)", js_string!("173.3513615237574734")),
+        TestAction::assert_eq("(-255.5).toString(16)", js_string!("-ff.8")),
+        TestAction::assert_eq("(0.1).toString(32)", js_string!("0.36cpj6cpj6d")),
+        TestAction::assert_eq("Math.PI.toString(36)", js_string!("3.53i5ab8p5f")),
+        TestAction::assert_eq("(1e21).toString(36)", js_string!("5v1j4f4ds7a000")),
+        TestAction::assert_eq(
+            "(2 ** 100).toString(3)",
+            js_string!("1002220101202122200001221110000111000000000000000000000000000000"),
+        ),
+        TestAction::assert_eq(
+            "(2 ** 53 + 2).toString(2)",
+            js_string!("100000000000000000000000000000000000000000000000000010"),
+        ),
+        TestAction::assert_eq(
+            "Number.MIN_VALUE.toString(2)",
+            js_string!(format!("0.{}1", "0".repeat(1073))),
         ),
     ]);
 }
+
+#[test]
+fn to_string_radix_integer_round_trip() {
+    run_test_actions([TestAction::assert(indoc! {r#"
+        var integers = [0, 1, 35, 36, 1295, 123456789, 2 ** 31, 2 ** 53 - 1, -(2 ** 53 - 1)];
+        var roundTrips = true;
+        for (var radix = 2; radix <= 36; radix++) {
+            for (var x of integers) {
+                roundTrips &&= parseInt(x.toString(radix), radix) === x;
+            }
+        }
+        roundTrips
+    "#})]);
+}
+
+/// Checks that `string`, read as a number in `radix`, rounds to the finite, positive `x`.
+fn rounds_to(string: &str, radix: u8, x: f64) -> bool {
+    let (integer, fraction) = string.split_once('.').unwrap_or((string, ""));
+    let digits = BigUint::parse_bytes(format!("{integer}{fraction}").as_bytes(), radix.into())
+        .expect("string must be a number in the given radix");
+    let denominator = BigUint::from(radix).pow(fraction.len() as u32);
+
+    // Every float is a multiple of 2^-1074, so scaling by 2^1074 makes all of them integers.
+    let (significand, exponent) = super::decompose(x);
+    let scale = (exponent + 1074) as usize;
+    let x = BigUint::from(significand) << scale;
+    let next = BigUint::from(significand + 1) << scale;
+    let previous = if significand == 1 << 52 && exponent > -1074 {
+        BigUint::from(2 * significand - 1) << (scale - 1)
+    } else {
+        BigUint::from(significand - 1) << scale
+    };
+
+    // Compare twice the value of the string with the sums of `x` and its neighbours.
+    let value = digits << 1075usize;
+    let lower = (&x + previous) * &denominator;
+    let upper = (&x + next) * &denominator;
+    if significand % 2 == 0 {
+        lower <= value && value <= upper
+    } else {
+        lower < value && value < upper
+    }
+}
+
+#[test]
+fn to_string_radix_round_trip() {
+    // A xorshift generator, to get numbers of all magnitudes from random bits.
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next_random = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut numbers = vec![
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        5e-324,
+        0.1,
+        1.0 / 3.0,
+        std::f64::consts::PI,
+        2f64.powi(-1000),
+        2f64.powi(100),
+        1e21,
+    ];
+    numbers.extend((0..100).map(|_| f64::from_bits(next_random() >> 1)));
+    numbers.extend((0..100).map(|_| (next_random() >> 11) as f64 / 2f64.powi(30)));
+
+    for x in numbers.into_iter().filter(|x| x.is_finite() && *x != 0.0) {
+        for radix in 2..=36 {
+            let string = Number::to_js_string_radix(x, radix).to_std_string_escaped();
+            assert!(
+                rounds_to(&string, radix, x),
+                "{string} in radix {radix} does not round to {x}"
+            );
+        }
+    }
+}
@@ -9,7 +9,7 @@
 // Opportunity to optimize this for iteration speed.
 
 use crate::{
-    builtins::{iterable::create_iter_result_object, BuiltInBuilder, IntrinsicObject},
+    builtins::{iterable::create_iter_result_object, BuiltInBuilder, IntrinsicObject, Number},
     context::intrinsics::Intrinsics,
     error::JsNativeError,
     js_string,
@@ -115,7 +115,7 @@ impl ForInIterator {
                         PropertyKey::Index(i) => {
                             iterator
                                 .remaining_keys
-                                .push_back(i.get().to_string().into());
+                                .push_back(Number::integer_to_js_string(i.get().into()));
                         }
                         PropertyKey::Symbol(_) => {}
                     }
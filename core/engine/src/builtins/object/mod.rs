@@ -13,13 +13,11 @@
 //! [spec]: https://tc39.es/ecma262/#sec-objects
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
-use std::ops::Deref;
-
 use super::{
     error::ErrorObject, Array, BuiltInBuilder, BuiltInConstructor, Date, IntrinsicObject, RegExp,
 };
 use crate::{
-    builtins::{map, BuiltInObject},
+    builtins::{map, BuiltInObject, Number},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
@@ -52,22 +50,8 @@ impl IntrinsicObject for OrdinaryObject {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
-        let legacy_proto_getter = BuiltInBuilder::callable(realm, Self::legacy_proto_getter)
-            .name(js_string!("get __proto__"))
-            .build();
-
-        let legacy_setter_proto = BuiltInBuilder::callable(realm, Self::legacy_proto_setter)
-            .name(js_string!("set __proto__"))
-            .build();
-
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .inherits(None)
-            .accessor(
-                utf16!("__proto__"),
-                Some(legacy_proto_getter),
-                Some(legacy_setter_proto),
-                Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            )
             .method(Self::has_own_property, js_string!("hasOwnProperty"), 1)
             .method(
                 Self::property_is_enumerable,
@@ -78,26 +62,6 @@ impl IntrinsicObject for OrdinaryObject {
             .method(Self::to_locale_string, js_string!("toLocaleString"), 0)
             .method(Self::value_of, js_string!("valueOf"), 0)
             .method(Self::is_prototype_of, js_string!("isPrototypeOf"), 1)
-            .method(
-                Self::legacy_define_getter,
-                js_string!("__defineGetter__"),
-                2,
-            )
-            .method(
-                Self::legacy_define_setter,
-                js_string!("__defineSetter__"),
-                2,
-            )
-            .method(
-                Self::legacy_lookup_getter,
-                js_string!("__lookupGetter__"),
-                1,
-            )
-            .method(
-                Self::legacy_lookup_setter,
-                js_string!("__lookupSetter__"),
-                1,
-            )
             .static_method(Self::create, js_string!("create"), 2)
             .static_method(Self::set_prototype_of, js_string!("setPrototypeOf"), 2)
             .static_method(Self::get_prototype_of, js_string!("getPrototypeOf"), 1)
@@ -136,8 +100,48 @@ impl IntrinsicObject for OrdinaryObject {
             )
             .static_method(Self::has_own, js_string!("hasOwn"), 2)
             .static_method(Self::from_entries, js_string!("fromEntries"), 1)
-            .static_method(Self::group_by, js_string!("groupBy"), 2)
-            .build();
+            .static_method(Self::group_by, js_string!("groupBy"), 2);
+
+        #[cfg(feature = "annex-b")]
+        let builder = {
+            let legacy_proto_getter = BuiltInBuilder::callable(realm, Self::legacy_proto_getter)
+                .name(js_string!("get __proto__"))
+                .build();
+
+            let legacy_setter_proto = BuiltInBuilder::callable(realm, Self::legacy_proto_setter)
+                .name(js_string!("set __proto__"))
+                .build();
+
+            builder
+                .accessor(
+                    utf16!("__proto__"),
+                    Some(legacy_proto_getter),
+                    Some(legacy_setter_proto),
+                    Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                )
+                .method(
+                    Self::legacy_define_getter,
+                    js_string!("__defineGetter__"),
+                    2,
+                )
+                .method(
+                    Self::legacy_define_setter,
+                    js_string!("__defineSetter__"),
+                    2,
+                )
+                .method(
+                    Self::legacy_lookup_getter,
+                    js_string!("__lookupGetter__"),
+                    1,
+                )
+                .method(
+                    Self::legacy_lookup_setter,
+                    js_string!("__lookupSetter__"),
+                    1,
+                )
+        };
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -182,264 +186,16 @@ impl BuiltInConstructor for OrdinaryObject {
         let value = args.get_or_undefined(0);
 
         // 2. If value is undefined or null, return OrdinaryObjectCreate(%Object.prototype%).
-        if value.is_null_or_undefined() {
-            Ok(JsObject::with_object_proto(context.intrinsics()).into())
-        } else {
-            // 3. Return ! ToObject(value).
-            value.to_object(context).map(JsValue::from)
-        }
-    }
-}
-
-impl OrdinaryObject {
-    /// `get Object.prototype.__proto__`
-    ///
-    /// The `__proto__` getter function exposes the value of the
-    /// internal `[[Prototype]]` of an object.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///  - [MDN documentation][mdn]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-get-object.prototype.__proto__
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/proto
-    pub fn legacy_proto_getter(
-        this: &JsValue,
-        _: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        // 1. Let O be ? ToObject(this value).
-        let obj = this.to_object(context)?;
-
-        // 2. Return ? O.[[GetPrototypeOf]]().
-        let proto = obj.__get_prototype_of__(&mut InternalMethodContext::new(context))?;
-
-        Ok(proto.map_or(JsValue::Null, JsValue::new))
-    }
-
-    /// `set Object.prototype.__proto__`
-    ///
-    /// The `__proto__` setter allows the `[[Prototype]]` of
-    /// an object to be mutated.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///  - [MDN documentation][mdn]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-set-object.prototype.__proto__
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/proto
-    pub fn legacy_proto_setter(
-        this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        // 1. Let O be ? RequireObjectCoercible(this value).
-        let this = this.require_object_coercible()?;
-
-        // 2. If Type(proto) is neither Object nor Null, return undefined.
-        let proto = match args.get_or_undefined(0) {
-            JsValue::Object(proto) => Some(proto.clone()),
-            JsValue::Null => None,
-            _ => return Ok(JsValue::undefined()),
-        };
-
-        // 3. If Type(O) is not Object, return undefined.
-        let JsValue::Object(object) = this else {
-            return Ok(JsValue::undefined());
-        };
-
-        // 4. Let status be ? O.[[SetPrototypeOf]](proto).
-        let status =
-            object.__set_prototype_of__(proto, &mut InternalMethodContext::new(context))?;
-
-        // 5. If status is false, throw a TypeError exception.
-        if !status {
-            return Err(JsNativeError::typ()
-                .with_message("__proto__ called on null or undefined")
-                .into());
-        }
-
-        // 6. Return undefined.
-        Ok(JsValue::undefined())
-    }
-
-    /// `Object.prototype.__defineGetter__(prop, func)`
-    ///
-    /// Binds an object's property to a function to be called when that property is looked up.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///  - [MDN documentation][mdn]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__defineGetter__
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__defineGetter__
-    pub fn legacy_define_getter(
-        this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        let getter = args.get_or_undefined(1);
-
-        // 1. Let O be ? ToObject(this value).
-        let obj = this.to_object(context)?;
-
-        // 2. If IsCallable(getter) is false, throw a TypeError exception.
-        if !getter.is_callable() {
-            return Err(JsNativeError::typ()
-                .with_message("Object.prototype.__defineGetter__: Expecting function")
-                .into());
-        }
-
-        // 3. Let desc be PropertyDescriptor { [[Get]]: getter, [[Enumerable]]: true, [[Configurable]]: true }.
-        let desc = PropertyDescriptor::builder()
-            .get(getter.clone())
-            .enumerable(true)
-            .configurable(true);
-
-        // 4. Let key be ? ToPropertyKey(P).
-        let key = args.get_or_undefined(0).to_property_key(context)?;
-
-        // 5. Perform ? DefinePropertyOrThrow(O, key, desc).
-        obj.define_property_or_throw(key, desc, context)?;
-
-        // 6. Return undefined.
-        Ok(JsValue::undefined())
-    }
-
-    /// `Object.prototype.__defineSetter__(prop, func)`
-    ///
-    /// Binds an object's property to a function to be called when an attempt is made to set that property.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///  - [MDN documentation][mdn]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__defineSetter__
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__defineSetter__
-    pub fn legacy_define_setter(
-        this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        let setter = args.get_or_undefined(1);
-
-        // 1. Let O be ? ToObject(this value).
-        let obj = this.to_object(context)?;
-
-        // 2. If IsCallable(setter) is false, throw a TypeError exception.
-        if !setter.is_callable() {
-            return Err(JsNativeError::typ()
-                .with_message("Object.prototype.__defineSetter__: Expecting function")
-                .into());
-        }
-
-        // 3. Let desc be PropertyDescriptor { [[Set]]: setter, [[Enumerable]]: true, [[Configurable]]: true }.
-        let desc = PropertyDescriptor::builder()
-            .set(setter.clone())
-            .enumerable(true)
-            .configurable(true);
-
-        // 4. Let key be ? ToPropertyKey(P).
-        let key = args.get_or_undefined(0).to_property_key(context)?;
-
-        // 5. Perform ? DefinePropertyOrThrow(O, key, desc).
-        obj.define_property_or_throw(key, desc, context)?;
-
-        // 6. Return undefined.
-        Ok(JsValue::undefined())
-    }
-
-    /// `Object.prototype.__lookupGetter__(prop)`
-    ///
-    /// Returns the function bound as a getter to the specified property.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///  - [MDN documentation][mdn]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__lookupGetter__
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__lookupGetter__
-    pub fn legacy_lookup_getter(
-        this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        // 1. Let O be ? ToObject(this value).
-        let mut obj = this.to_object(context)?;
-
-        // 2. Let key be ? ToPropertyKey(P).
-        let key = args.get_or_undefined(0).to_property_key(context)?;
-
-        // 3. Repeat
-        loop {
-            // a. Let desc be ? O.[[GetOwnProperty]](key).
-
-            let desc = obj.__get_own_property__(&key, &mut InternalMethodContext::new(context))?;
-
-            // b. If desc is not undefined, then
-            if let Some(current_desc) = desc {
-                // i. If IsAccessorDescriptor(desc) is true, return desc.[[Get]].
-                return if current_desc.is_accessor_descriptor() {
-                    Ok(current_desc.expect_get().clone())
-                } else {
-                    // ii. Return undefined.
-                    Ok(JsValue::undefined())
-                };
-            }
-            match obj.__get_prototype_of__(&mut InternalMethodContext::new(context))? {
-                // c. Set O to ? O.[[GetPrototypeOf]]().
-                Some(o) => obj = o,
-                // d. If O is null, return undefined.
-                None => return Ok(JsValue::undefined()),
-            }
-        }
-    }
-    /// `Object.prototype.__lookupSetter__(prop)`
-    ///
-    /// Returns the function bound as a getter to the specified property.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///  - [MDN documentation][mdn]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__lookupSetter__
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__lookupSetter__
-    pub fn legacy_lookup_setter(
-        this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        // 1. Let O be ? ToObject(this value).
-        let mut obj = this.to_object(context)?;
-
-        // 2. Let key be ? ToPropertyKey(P).
-        let key = args.get_or_undefined(0).to_property_key(context)?;
-
-        // 3. Repeat
-        loop {
-            // a. Let desc be ? O.[[GetOwnProperty]](key).
-
-            let desc = obj.__get_own_property__(&key, &mut InternalMethodContext::new(context))?;
-
-            // b. If desc is not undefined, then
-            if let Some(current_desc) = desc {
-                // i. If IsAccessorDescriptor(desc) is true, return desc.[[Set]].
-                return if current_desc.is_accessor_descriptor() {
-                    Ok(current_desc.expect_set().clone())
-                } else {
-                    // ii. Return undefined.
-                    Ok(JsValue::undefined())
-                };
-            }
-            match obj.__get_prototype_of__(&mut InternalMethodContext::new(context))? {
-                // c. Set O to ? O.[[GetPrototypeOf]]().
-                Some(o) => obj = o,
-                // d. If O is null, return undefined.
-                None => return Ok(JsValue::undefined()),
-            }
+        if value.is_null_or_undefined() {
+            Ok(JsObject::with_object_proto(context.intrinsics()).into())
+        } else {
+            // 3. Return ! ToObject(value).
+            value.to_object(context).map(JsValue::from)
         }
     }
+}
 
+impl OrdinaryObject {
     /// `Object.create( proto, [propertiesObject] )`
     ///
     /// Creates a new object from the provided prototype.
@@ -875,7 +631,7 @@ impl OrdinaryObject {
         let tag = o.get(JsSymbol::to_string_tag(), context)?;
 
         // 16. If Type(tag) is not String, set tag to builtinTag.
-        let tag_str = tag.as_string().map_or(builtin_tag, JsString::deref);
+        let tag_str = tag.as_string().map_or(builtin_tag.into(), JsString::as_str);
 
         // 17. Return the string-concatenation of "[object ", tag, and "]".
         Ok(js_string!(utf16!("[object "), tag_str, utf16!("]")).into())
@@ -1356,7 +1112,7 @@ impl OrdinaryObject {
         use indexmap::IndexMap;
         use rustc_hash::FxHasher;
 
-        use crate::builtins::{iterable::if_abrupt_close_iterator, Number};
+        use crate::builtins::iterable::if_abrupt_close_iterator;
 
         let items = args.get_or_undefined(0);
         let callback = args.get_or_undefined(1);
@@ -1447,6 +1203,257 @@ impl OrdinaryObject {
     }
 }
 
+#[cfg(feature = "annex-b")]
+impl OrdinaryObject {
+    /// `get Object.prototype.__proto__`
+    ///
+    /// The `__proto__` getter function exposes the value of the
+    /// internal `[[Prototype]]` of an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-object.prototype.__proto__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/proto
+    pub fn legacy_proto_getter(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let obj = this.to_object(context)?;
+
+        // 2. Return ? O.[[GetPrototypeOf]]().
+        let proto = obj.__get_prototype_of__(&mut InternalMethodContext::new(context))?;
+
+        Ok(proto.map_or(JsValue::Null, JsValue::new))
+    }
+
+    /// `set Object.prototype.__proto__`
+    ///
+    /// The `__proto__` setter allows the `[[Prototype]]` of
+    /// an object to be mutated.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-object.prototype.__proto__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/proto
+    pub fn legacy_proto_setter(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible()?;
+
+        // 2. If Type(proto) is neither Object nor Null, return undefined.
+        let proto = match args.get_or_undefined(0) {
+            JsValue::Object(proto) => Some(proto.clone()),
+            JsValue::Null => None,
+            _ => return Ok(JsValue::undefined()),
+        };
+
+        // 3. If Type(O) is not Object, return undefined.
+        let JsValue::Object(object) = this else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 4. Let status be ? O.[[SetPrototypeOf]](proto).
+        let status =
+            object.__set_prototype_of__(proto, &mut InternalMethodContext::new(context))?;
+
+        // 5. If status is false, throw a TypeError exception.
+        if !status {
+            return Err(JsNativeError::typ()
+                .with_message("Object.prototype.__proto__: cannot set the prototype of this object")
+                .into());
+        }
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Object.prototype.__defineGetter__(prop, func)`
+    ///
+    /// Binds an object's property to a function to be called when that property is looked up.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__defineGetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__defineGetter__
+    pub fn legacy_define_getter(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let getter = args.get_or_undefined(1);
+
+        // 1. Let O be ? ToObject(this value).
+        let obj = this.to_object(context)?;
+
+        // 2. If IsCallable(getter) is false, throw a TypeError exception.
+        if !getter.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("Object.prototype.__defineGetter__: Expecting function")
+                .into());
+        }
+
+        // 3. Let desc be PropertyDescriptor { [[Get]]: getter, [[Enumerable]]: true, [[Configurable]]: true }.
+        let desc = PropertyDescriptor::builder()
+            .get(getter.clone())
+            .enumerable(true)
+            .configurable(true);
+
+        // 4. Let key be ? ToPropertyKey(P).
+        let key = args.get_or_undefined(0).to_property_key(context)?;
+
+        // 5. Perform ? DefinePropertyOrThrow(O, key, desc).
+        obj.define_property_or_throw(key, desc, context)?;
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Object.prototype.__defineSetter__(prop, func)`
+    ///
+    /// Binds an object's property to a function to be called when an attempt is made to set that property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__defineSetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__defineSetter__
+    pub fn legacy_define_setter(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let setter = args.get_or_undefined(1);
+
+        // 1. Let O be ? ToObject(this value).
+        let obj = this.to_object(context)?;
+
+        // 2. If IsCallable(setter) is false, throw a TypeError exception.
+        if !setter.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("Object.prototype.__defineSetter__: Expecting function")
+                .into());
+        }
+
+        // 3. Let desc be PropertyDescriptor { [[Set]]: setter, [[Enumerable]]: true, [[Configurable]]: true }.
+        let desc = PropertyDescriptor::builder()
+            .set(setter.clone())
+            .enumerable(true)
+            .configurable(true);
+
+        // 4. Let key be ? ToPropertyKey(P).
+        let key = args.get_or_undefined(0).to_property_key(context)?;
+
+        // 5. Perform ? DefinePropertyOrThrow(O, key, desc).
+        obj.define_property_or_throw(key, desc, context)?;
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Object.prototype.__lookupGetter__(prop)`
+    ///
+    /// Returns the function bound as a getter to the specified property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__lookupGetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__lookupGetter__
+    pub fn legacy_lookup_getter(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let mut obj = this.to_object(context)?;
+
+        // 2. Let key be ? ToPropertyKey(P).
+        let key = args.get_or_undefined(0).to_property_key(context)?;
+
+        // 3. Repeat
+        loop {
+            // a. Let desc be ? O.[[GetOwnProperty]](key).
+
+            let desc = obj.__get_own_property__(&key, &mut InternalMethodContext::new(context))?;
+
+            // b. If desc is not undefined, then
+            if let Some(current_desc) = desc {
+                // i. If IsAccessorDescriptor(desc) is true, return desc.[[Get]].
+                return if current_desc.is_accessor_descriptor() {
+                    Ok(current_desc.expect_get().clone())
+                } else {
+                    // ii. Return undefined.
+                    Ok(JsValue::undefined())
+                };
+            }
+            match obj.__get_prototype_of__(&mut InternalMethodContext::new(context))? {
+                // c. Set O to ? O.[[GetPrototypeOf]]().
+                Some(o) => obj = o,
+                // d. If O is null, return undefined.
+                None => return Ok(JsValue::undefined()),
+            }
+        }
+    }
+    /// `Object.prototype.__lookupSetter__(prop)`
+    ///
+    /// Returns the function bound as a getter to the specified property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__lookupSetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__lookupSetter__
+    pub fn legacy_lookup_setter(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let mut obj = this.to_object(context)?;
+
+        // 2. Let key be ? ToPropertyKey(P).
+        let key = args.get_or_undefined(0).to_property_key(context)?;
+
+        // 3. Repeat
+        loop {
+            // a. Let desc be ? O.[[GetOwnProperty]](key).
+
+            let desc = obj.__get_own_property__(&key, &mut InternalMethodContext::new(context))?;
+
+            // b. If desc is not undefined, then
+            if let Some(current_desc) = desc {
+                // i. If IsAccessorDescriptor(desc) is true, return desc.[[Set]].
+                return if current_desc.is_accessor_descriptor() {
+                    Ok(current_desc.expect_set().clone())
+                } else {
+                    // ii. Return undefined.
+                    Ok(JsValue::undefined())
+                };
+            }
+            match obj.__get_prototype_of__(&mut InternalMethodContext::new(context))? {
+                // c. Set O to ? O.[[GetPrototypeOf]]().
+                Some(o) => obj = o,
+                // d. If O is null, return undefined.
+                None => return Ok(JsValue::undefined()),
+            }
+        }
+    }
+}
+
 /// The abstract operation `ObjectDefineProperties`
 ///
 /// More information:
@@ -1534,7 +1541,7 @@ fn get_own_property_keys(
             (PropertyKeyType::String, PropertyKey::String(_))
             | (PropertyKeyType::Symbol, PropertyKey::Symbol(_)) => Some(next_key.into()),
             (PropertyKeyType::String, PropertyKey::Index(index)) => {
-                Some(js_string!(index.get().to_string()).into())
+                Some(Number::integer_to_js_string(index.get().into()).into())
             }
             _ => None,
         }
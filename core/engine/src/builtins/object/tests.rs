@@ -34,7 +34,7 @@ fn object_create_with_function() {
     run_test_actions([TestAction::assert(indoc! {r#"
             const x = function (){};
             const bar = Object.create(x);
-            bar.__proto__ === x
+            Object.getPrototypeOf(bar) === x
         "#})]);
 }
 
@@ -425,3 +425,105 @@ fn object_from_entries() {
         TestAction::assert_eq("map[5]", 4),
     ]);
 }
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn legacy_proto_accessor() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var proto = { a: 1 };
+            var obj = {};
+            obj.__proto__ = proto;
+        "#}),
+        TestAction::assert("Object.getPrototypeOf(obj) === proto"),
+        TestAction::assert("obj.__proto__ === proto"),
+        TestAction::assert_eq("obj.a", 1),
+        TestAction::assert("!obj.hasOwnProperty('__proto__')"),
+        // Non-object, non-null values are ignored.
+        TestAction::run("obj.__proto__ = 5"),
+        TestAction::assert("Object.getPrototypeOf(obj) === proto"),
+        TestAction::run("obj.__proto__ = null"),
+        TestAction::assert("Object.getPrototypeOf(obj) === null"),
+        TestAction::assert_eq("(1).__proto__ === Number.prototype", true),
+        TestAction::assert_native_error(
+            "Object.preventExtensions({}).__proto__ = {}",
+            JsNativeErrorKind::Type,
+            "Object.prototype.__proto__: cannot set the prototype of this object",
+        ),
+        TestAction::assert_native_error(
+            "Object.getOwnPropertyDescriptor(Object.prototype, '__proto__').set.call(undefined, {})",
+            JsNativeErrorKind::Type,
+            "cannot convert null or undefined to Object",
+        ),
+    ]);
+}
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn legacy_proto_without_object_prototype() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var proto = {};
+            var obj = Object.create(null);
+            obj.__proto__ = proto;
+        "#}),
+        TestAction::assert("Object.getPrototypeOf(obj) === null"),
+        TestAction::assert("obj.hasOwnProperty === undefined"),
+        TestAction::assert("Object.getOwnPropertyDescriptor(obj, '__proto__').value === proto"),
+    ]);
+}
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn legacy_proto_proxy() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var proto = {};
+            var trapped;
+            var proxy = new Proxy({}, {
+                setPrototypeOf(target, value) {
+                    trapped = value;
+                    return Reflect.setPrototypeOf(target, value);
+                },
+            });
+            Object.prototype.__lookupSetter__('__proto__').call(proxy, proto);
+        "#}),
+        TestAction::assert("trapped === proto"),
+        TestAction::assert("Object.getPrototypeOf(proxy) === proto"),
+    ]);
+}
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn legacy_define_and_lookup_accessors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var value;
+            var getter = function () { return 42; };
+            var setter = function (v) { value = v; };
+            var obj = {};
+            obj.__defineGetter__('x', getter);
+            obj.__defineSetter__('x', setter);
+            obj.x = 5;
+            var child = Object.create(obj);
+            var desc = Object.getOwnPropertyDescriptor(obj, 'x');
+        "#}),
+        TestAction::assert_eq("obj.x", 42),
+        TestAction::assert_eq("value", 5),
+        TestAction::assert("desc.enumerable && desc.configurable"),
+        TestAction::assert("child.__lookupGetter__('x') === getter"),
+        TestAction::assert("child.__lookupSetter__('x') === setter"),
+        TestAction::assert_eq("({ x: 1 }).__lookupGetter__('x')", JsValue::undefined()),
+        TestAction::assert_eq("({}).__lookupSetter__('y')", JsValue::undefined()),
+        TestAction::assert_native_error(
+            "obj.__defineGetter__('y', 1)",
+            JsNativeErrorKind::Type,
+            "Object.prototype.__defineGetter__: Expecting function",
+        ),
+        TestAction::assert_native_error(
+            "obj.__defineSetter__('y', {})",
+            JsNativeErrorKind::Type,
+            "Object.prototype.__defineSetter__: Expecting function",
+        ),
+    ]);
+}
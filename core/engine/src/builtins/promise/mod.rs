@@ -5,7 +5,7 @@ mod tests;
 
 use super::{iterable::IteratorRecord, BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 use crate::{
-    builtins::{Array, BuiltInObject},
+    builtins::{Array, BuiltInObject, OrdinaryObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     job::{JobCallback, NativeJob},
@@ -474,10 +474,8 @@ impl Promise {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let C be the this value.
-
-        use super::OrdinaryObject;
         let c = this.as_object().ok_or_else(|| {
-            JsNativeError::typ().with_message("Promise.all() called on a non-object")
+            JsNativeError::typ().with_message("Promise.withResolvers() called on a non-object")
         })?;
 
         // 2. Let promiseCapability be ? NewPromiseCapability(C).
@@ -1901,15 +1899,15 @@ impl Promise {
 
                 //   e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
                 context.job_queue().enqueue_promise_job(reject_job, context);
-
-                // 12. Set promise.[[PromiseIsHandled]] to true.
-                promise
-                    .downcast_mut::<Self>()
-                    .expect("IsPromise(promise) is false")
-                    .handled = true;
             }
         }
 
+        // 12. Set promise.[[PromiseIsHandled]] to true.
+        promise
+            .downcast_mut::<Self>()
+            .expect("IsPromise(promise) is false")
+            .handled = true;
+
         // 13. If resultCapability is undefined, then
         //   a. Return undefined.
         // 14. Else,
@@ -2222,6 +2220,77 @@ impl Promise {
     }
 }
 
+/// `Await ( value )`, calling `on_fulfilled` or `on_rejected` with `captures` once `value` is
+/// settled.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#await
+pub(crate) fn await_value<T>(
+    value: JsValue,
+    captures: T,
+    on_fulfilled: fn(T, JsValue, &mut Context),
+    on_rejected: fn(T, JsError, &mut Context),
+    context: &mut Context,
+) where
+    T: Trace + Clone + 'static,
+{
+    // 2. Let promise be ? PromiseResolve(%Promise%, value).
+    let promise = match Promise::promise_resolve(
+        &context.intrinsics().constructors().promise().constructor(),
+        value,
+        context,
+    ) {
+        Ok(promise) => promise,
+        Err(error) => return on_rejected(captures, error, context),
+    };
+
+    // 3. Let fulfilledClosure be a new Abstract Closure with parameters (value) that captures
+    //    asyncContext and performs the following steps when called:
+    // 4. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
+    let on_fulfilled = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            move |_this, args, captures: &T, context| {
+                on_fulfilled(captures.clone(), args.get_or_undefined(0).clone(), context);
+                Ok(JsValue::undefined())
+            },
+            captures.clone(),
+        ),
+    )
+    .name("")
+    .length(1)
+    .build();
+
+    // 5. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures
+    //    asyncContext and performs the following steps when called:
+    // 6. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+    let on_rejected = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            move |_this, args, captures: &T, context| {
+                let error = JsError::from_opaque(args.get_or_undefined(0).clone());
+                on_rejected(captures.clone(), error, context);
+                Ok(JsValue::undefined())
+            },
+            captures,
+        ),
+    )
+    .name("")
+    .length(1)
+    .build();
+
+    // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+    Promise::perform_promise_then(
+        &promise,
+        Some(on_fulfilled),
+        Some(on_rejected),
+        None,
+        context,
+    );
+}
+
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///
@@ -1,4 +1,10 @@
-use crate::{run_test_actions, TestAction};
+use std::cell::RefCell;
+
+use super::OperationType;
+use crate::{
+    context::HostHooks, js_string, run_test_actions, Context, JsNativeErrorKind, JsObject, Source,
+    TestAction,
+};
 use indoc::indoc;
 
 #[test]
@@ -18,3 +24,93 @@ fn promise() {
         TestAction::assert_eq("count", 3),
     ]);
 }
+
+#[test]
+fn promise_rejection_tracker() {
+    #[derive(Default)]
+    struct Tracker(RefCell<Vec<OperationType>>);
+
+    impl HostHooks for Tracker {
+        fn promise_rejection_tracker(
+            &self,
+            _promise: &JsObject,
+            operation: OperationType,
+            _context: &mut Context,
+        ) {
+            self.0.borrow_mut().push(operation);
+        }
+    }
+
+    let tracker: &'static Tracker = Box::leak(Box::default());
+    let context = &mut Context::builder().host_hooks(tracker).build().unwrap();
+
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            const rejected = Promise.reject(1);
+            Promise.resolve(2).then(() => {});
+        "#}))
+        .unwrap();
+    assert_eq!(*tracker.0.borrow(), [OperationType::Reject]);
+
+    context
+        .eval(Source::from_bytes("rejected.catch(() => {});"))
+        .unwrap();
+    context.run_jobs();
+    assert_eq!(
+        *tracker.0.borrow(),
+        [OperationType::Reject, OperationType::Handle]
+    );
+
+    // Promises that already had a handler when they were rejected aren't tracked.
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            new Promise((_, reject) => Promise.resolve().then(() => reject(3)))
+                .catch(() => {});
+        "#}))
+        .unwrap();
+    context.run_jobs();
+    assert_eq!(
+        *tracker.0.borrow(),
+        [OperationType::Reject, OperationType::Handle]
+    );
+}
+
+#[test]
+fn promise_with_resolvers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var first = Promise.withResolvers();
+            var second = Promise.withResolvers();
+            var result;
+            first.promise.then(value => { result = value; });
+            first.resolve(1);
+            first.resolve(2);
+            second.reject(3);
+            second.promise.catch(() => {});
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("result", 1),
+        TestAction::assert("first.promise instanceof Promise"),
+        TestAction::assert("first.resolve !== second.resolve && first.reject !== second.reject"),
+        TestAction::assert_eq(
+            "Object.keys(first).join()",
+            js_string!("promise,resolve,reject"),
+        ),
+        TestAction::assert_eq("Promise.withResolvers.length", 0),
+        TestAction::assert(indoc! {r#"
+            class MyPromise extends Promise {}
+            var { promise } = MyPromise.withResolvers();
+            promise instanceof MyPromise
+        "#}),
+        TestAction::assert_native_error(
+            "Reflect.apply(Promise.withResolvers, {}, [])",
+            JsNativeErrorKind::Type,
+            "PromiseCapability: expected constructor",
+        ),
+        TestAction::assert_native_error(
+            "Reflect.apply(Promise.withResolvers, 1, [])",
+            JsNativeErrorKind::Type,
+            "Promise.withResolvers() called on a non-object",
+        ),
+    ]);
+}
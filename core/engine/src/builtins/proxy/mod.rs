@@ -35,6 +35,10 @@ use boa_profiler::Profiler;
 use rustc_hash::FxHashSet;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject, OrdinaryObject};
+
+#[cfg(test)]
+mod tests;
+
 /// Javascript `Proxy` object.
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct Proxy {
@@ -508,7 +512,7 @@ pub(crate) fn proxy_exotic_get_own_property(
             if !desc.expect_configurable() {
                 return Err(JsNativeError::typ()
                     .with_message(
-                        "Proxy trap result is undefined adn target result is not configurable",
+                        "Proxy trap result is undefined and target result is not configurable",
                     )
                     .into());
             }
@@ -558,9 +562,11 @@ pub(crate) fn proxy_exotic_get_own_property(
                 if result_desc.writable() == Some(false) {
                     // i. If targetDesc.[[Writable]] is true, throw a TypeError exception.
                     if desc.expect_writable() {
-                        return
-                            Err(JsNativeError::typ().with_message("Proxy trap result is writable and not configurable while target result is not configurable").into())
-                        ;
+                        return Err(JsNativeError::typ()
+                            .with_message(
+                                "Proxy trap reported a non-writable property that is writable on the target",
+                            )
+                            .into());
                     }
                 }
             }
@@ -640,14 +646,16 @@ pub(crate) fn proxy_exotic_define_own_property(
             // a. If extensibleTarget is false, throw a TypeError exception.
             if !extensible_target {
                 return Err(JsNativeError::typ()
-                    .with_message("Proxy trap failed to set property")
+                    .with_message("Proxy trap defined a new property on a non-extensible target")
                     .into());
             }
 
             // b. If settingConfigFalse is true, throw a TypeError exception.
             if setting_config_false {
                 return Err(JsNativeError::typ()
-                    .with_message("Proxy trap failed to set property")
+                    .with_message(
+                        "Proxy trap defined a non-configurable property missing from the target",
+                    )
                     .into());
             }
         }
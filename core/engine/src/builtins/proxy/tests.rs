@@ -0,0 +1,177 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use indoc::indoc;
+
+#[test]
+fn own_keys_duplicate_entries() {
+    run_test_actions([TestAction::assert_native_error(
+        "Object.keys(new Proxy({}, { ownKeys: () => ['a', 'a'] }))",
+        JsNativeErrorKind::Type,
+        "Proxy trap result contains duplicate string property keys",
+    )]);
+}
+
+#[test]
+fn own_keys_missing_non_configurable_key() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var target = {};
+            Object.defineProperty(target, 'a', { value: 1, configurable: false });
+        "#}),
+        TestAction::assert_native_error(
+            "Reflect.ownKeys(new Proxy(target, { ownKeys: () => [] }))",
+            JsNativeErrorKind::Type,
+            "Proxy trap failed to return all non-configurable property keys",
+        ),
+        TestAction::assert_eq(
+            "Reflect.ownKeys(new Proxy(target, { ownKeys: () => ['a', 'b'] })).join()",
+            js_string!("a,b"),
+        ),
+    ]);
+}
+
+#[test]
+fn own_keys_non_extensible_target() {
+    run_test_actions([
+        TestAction::run("var target = Object.seal({ a: 1, b: 2 });"),
+        TestAction::assert_native_error(
+            "Reflect.ownKeys(new Proxy(target, { ownKeys: () => ['a', 'b', 'c'] }))",
+            JsNativeErrorKind::Type,
+            "Proxy trap failed to return all property keys",
+        ),
+        TestAction::assert_native_error(
+            "Reflect.ownKeys(new Proxy(Object.preventExtensions({ a: 1 }), { ownKeys: () => [] }))",
+            JsNativeErrorKind::Type,
+            "Proxy trap failed to return all configurable property keys",
+        ),
+        TestAction::assert_eq(
+            "Reflect.ownKeys(new Proxy(target, { ownKeys: () => ['b', 'a'] })).join()",
+            js_string!("b,a"),
+        ),
+    ]);
+}
+
+#[test]
+fn get_own_property_descriptor_invariants() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var target = {};
+            Object.defineProperty(target, 'fixed', {
+                value: 1,
+                writable: true,
+                configurable: false,
+            });
+            Object.defineProperty(target, 'loose', { value: 1, configurable: true });
+            function describe(key, result) {
+                var proxy = new Proxy(target, {
+                    getOwnPropertyDescriptor: () => result,
+                });
+                return Object.getOwnPropertyDescriptor(proxy, key);
+            }
+        "#}),
+        TestAction::assert_native_error(
+            "describe('fixed', undefined)",
+            JsNativeErrorKind::Type,
+            "Proxy trap result is undefined and target result is not configurable",
+        ),
+        TestAction::assert_native_error(
+            "describe('loose', { value: 1, configurable: false })",
+            JsNativeErrorKind::Type,
+            "Proxy trap result is not configurable and target result is undefined",
+        ),
+        TestAction::assert_native_error(
+            "describe('missing', { value: 1, configurable: false })",
+            JsNativeErrorKind::Type,
+            "Proxy trap result is not configurable and target result is undefined",
+        ),
+        TestAction::assert_native_error(
+            "describe('fixed', { value: 1, writable: false, configurable: false })",
+            JsNativeErrorKind::Type,
+            "Proxy trap reported a non-writable property that is writable on the target",
+        ),
+        TestAction::assert_native_error(
+            "describe('missing', 42)",
+            JsNativeErrorKind::Type,
+            "Proxy trap result is neither object nor undefined",
+        ),
+        TestAction::assert_eq("describe('loose', undefined)", JsValue::undefined()),
+    ]);
+}
+
+#[test]
+fn get_own_property_descriptor_non_extensible_target() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var target = Object.preventExtensions({ a: 1 });
+            var proxy = new Proxy(target, { getOwnPropertyDescriptor: () => undefined });
+        "#}),
+        TestAction::assert_native_error(
+            "Object.getOwnPropertyDescriptor(proxy, 'a')",
+            JsNativeErrorKind::Type,
+            "Proxy trap result is undefined and target is not extensible",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                Object.getOwnPropertyDescriptor(
+                    new Proxy(target, {
+                        getOwnPropertyDescriptor: () => ({ value: 1, configurable: true }),
+                    }),
+                    'b',
+                )
+            "#},
+            JsNativeErrorKind::Type,
+            "Proxy trap returned unexpected property",
+        ),
+    ]);
+}
+
+#[test]
+fn define_property_invariants() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var target = { loose: 1 };
+            Object.defineProperty(target, 'fixed', {
+                value: 1,
+                writable: true,
+                configurable: false,
+            });
+            var proxy = new Proxy(target, { defineProperty: () => true });
+        "#}),
+        TestAction::assert_native_error(
+            "Object.defineProperty(proxy, 'missing', { value: 1, configurable: false })",
+            JsNativeErrorKind::Type,
+            "Proxy trap defined a non-configurable property missing from the target",
+        ),
+        TestAction::assert_native_error(
+            "Object.defineProperty(proxy, 'loose', { value: 1, configurable: false })",
+            JsNativeErrorKind::Type,
+            "Proxy trap set property with unexpected configurable field",
+        ),
+        TestAction::assert_native_error(
+            "Object.defineProperty(proxy, 'fixed', { value: 1, writable: false })",
+            JsNativeErrorKind::Type,
+            "Proxy trap set property with unexpected writable field",
+        ),
+        TestAction::assert_native_error(
+            "Object.defineProperty(proxy, 'fixed', { get() {} })",
+            JsNativeErrorKind::Type,
+            "Proxy trap set property to unexpected value",
+        ),
+        TestAction::assert_eq("Reflect.defineProperty(proxy, 'loose', { value: 2 })", true),
+    ]);
+}
+
+#[test]
+fn define_property_non_extensible_target() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var proxy = new Proxy(Object.preventExtensions({}), {
+                defineProperty: () => true,
+            });
+        "#}),
+        TestAction::assert_native_error(
+            "Object.defineProperty(proxy, 'a', { value: 1 })",
+            JsNativeErrorKind::Type,
+            "Proxy trap defined a new property on a non-extensible target",
+        ),
+    ]);
+}
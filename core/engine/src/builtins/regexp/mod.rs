@@ -17,16 +17,16 @@ use crate::{
     object::{internal_methods::get_prototype_from_constructor, JsObject, CONSTRUCTOR},
     property::Attribute,
     realm::Realm,
-    string::{common::StaticJsStrings, utf16, CodePoint},
+    string::{common::StaticJsStrings, utf16, CodePoint, JsStr},
     symbol::JsSymbol,
     value::JsValue,
     Context, JsArgs, JsData, JsResult, JsString,
 };
 use boa_gc::{Finalize, Trace};
-use boa_parser::lexer::regex::RegExpFlags;
+use boa_parser::lexer::regex::{compile_regexp, RegExpError, RegExpFlags};
 use boa_profiler::Profiler;
-use regress::{Flags, Range, Regex};
-use std::str::FromStr;
+use regress::{Range, Regex};
+use std::{cell::RefCell, ptr, rc::Rc};
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
@@ -45,8 +45,15 @@ pub struct RegExp {
     flags: RegExpFlags,
     original_source: JsString,
     original_flags: JsString,
+
+    /// The last Latin-1 input and its code units, shared by the clones of this `RegExp`, so
+    /// the repeated matches of a global search only widen the input once.
+    widened_input: Rc<RefCell<Option<WidenedInput>>>,
 }
 
+/// A Latin-1 string and its code units widened to UTF-16.
+type WidenedInput = (JsString, Rc<[u16]>);
+
 impl IntrinsicObject for RegExp {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
@@ -75,6 +82,9 @@ impl IntrinsicObject for RegExp {
         let get_unicode = BuiltInBuilder::callable(realm, Self::get_unicode)
             .name(js_string!("get unicode"))
             .build();
+        let get_unicode_sets = BuiltInBuilder::callable(realm, Self::get_unicode_sets)
+            .name(js_string!("get unicodeSets"))
+            .build();
         let get_sticky = BuiltInBuilder::callable(realm, Self::get_sticky)
             .name(js_string!("get sticky"))
             .build();
@@ -136,6 +146,12 @@ impl IntrinsicObject for RegExp {
                 None,
                 flag_attributes,
             )
+            .accessor(
+                js_string!("unicodeSets"),
+                Some(get_unicode_sets),
+                None,
+                flag_attributes,
+            )
             .accessor(
                 js_string!("sticky"),
                 Some(get_sticky),
@@ -259,6 +275,16 @@ impl BuiltInConstructor for RegExp {
 }
 
 impl RegExp {
+    /// Gets the `[[OriginalSource]]` internal slot of the `RegExp`.
+    pub(crate) const fn original_source(&self) -> &JsString {
+        &self.original_source
+    }
+
+    /// Gets the `[[OriginalFlags]]` internal slot of the `RegExp`.
+    pub(crate) const fn original_flags(&self) -> &JsString {
+        &self.original_flags
+    }
+
     /// `7.2.8 IsRegExp ( argument )`
     ///
     /// This modified to return the object if it's `true`, [`None`] otherwise.
@@ -319,22 +345,22 @@ impl RegExp {
             flags.to_string(context)?
         };
 
-        // 5. If F contains any code unit other than "g", "i", "m", "s", "u", or "y"
+        // 5. If F contains any code unit other than "d", "g", "i", "m", "s", "u", "v", or "y"
         //    or if it contains the same code unit more than once, throw a SyntaxError exception.
-        // TODO: Should directly parse the JsString instead of converting to String
-        let flags = match RegExpFlags::from_str(&f.to_std_string_escaped()) {
-            Err(msg) => return Err(JsNativeError::syntax().with_message(msg).into()),
-            Ok(result) => result,
-        };
-
+        // 10. If u is true and v is true, throw a SyntaxError exception.
         // 13. Let parseResult be ParsePattern(patternText, u, v).
         // 14. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
-        let matcher =
-            Regex::from_unicode(p.code_points().map(CodePoint::as_u32), Flags::from(flags))
-                .map_err(|error| {
-                    JsNativeError::syntax()
-                        .with_message(format!("failed to create matcher: {}", error.text))
-                })?;
+        // TODO: Should directly parse the JsString instead of converting to String
+        let (matcher, flags) = compile_regexp(
+            p.code_points().map(CodePoint::as_u32),
+            &f.to_std_string_escaped(),
+        )
+        .map_err(|error| match error {
+            RegExpError::Flags(message) => JsNativeError::syntax().with_message(message),
+            RegExpError::Pattern(message) => {
+                JsNativeError::syntax().with_message(format!("failed to create matcher: {message}"))
+            }
+        })?;
 
         // 15. Assert: parseResult is a Pattern Parse Node.
         // 16. Set obj.[[OriginalSource]] to P.
@@ -348,6 +374,7 @@ impl RegExp {
             flags,
             original_source: p,
             original_flags: f,
+            widened_input: Rc::default(),
         })
     }
 
@@ -427,6 +454,7 @@ impl RegExp {
                     b's' => regexp.flags.contains(RegExpFlags::DOT_ALL),
                     b'i' => regexp.flags.contains(RegExpFlags::IGNORE_CASE),
                     b'u' => regexp.flags.contains(RegExpFlags::UNICODE),
+                    b'v' => regexp.flags.contains(RegExpFlags::UNICODE_SETS),
                     b'y' => regexp.flags.contains(RegExpFlags::STICKY),
                     _ => unreachable!(),
                 }));
@@ -447,6 +475,7 @@ impl RegExp {
             b's' => "dotAll",
             b'i' => "ignoreCase",
             b'u' => "unicode",
+            b'v' => "unicodeSets",
             b'y' => "sticky",
             _ => unreachable!(),
         };
@@ -565,6 +594,25 @@ impl RegExp {
         Self::regexp_has_flag(this, b'u', context)
     }
 
+    /// `get RegExp.prototype.unicodeSets`
+    ///
+    /// The `unicodeSets` property indicates whether or not the "`v`" flag is used with a regular
+    /// expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.unicodesets
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicodeSets
+    pub(crate) fn get_unicode_sets(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::regexp_has_flag(this, b'v', context)
+    }
+
     /// `get RegExp.prototype.sticky`
     ///
     /// This flag indicates that it matches only from the index indicated by the `lastIndex` property
@@ -640,13 +688,19 @@ impl RegExp {
                 result.push('u');
             }
 
-            // 16. Let sticky be ! ToBoolean(? Get(R, "sticky")).
-            // 17. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
+            // 16. Let unicodeSets be ! ToBoolean(? Get(R, "unicodeSets")).
+            // 17. If unicodeSets is true, append the code unit 0x0076 (LATIN SMALL LETTER V) as the last code unit of result.
+            if object.get(utf16!("unicodeSets"), context)?.to_boolean() {
+                result.push('v');
+            }
+
+            // 18. Let sticky be ! ToBoolean(? Get(R, "sticky")).
+            // 19. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
             if object.get(utf16!("sticky"), context)?.to_boolean() {
                 result.push('y');
             }
 
-            // 18. Return result.
+            // 20. Return result.
             return Ok(js_string!(result).into());
         }
 
@@ -856,6 +910,22 @@ impl RegExp {
         Self::abstract_builtin_exec(this, &input, context)
     }
 
+    /// Gets the UTF-16 code units of a Latin-1 `input`, reusing the ones of the previous
+    /// match if it was on the same string.
+    fn widen(&self, input: &JsString) -> Rc<[u16]> {
+        let mut cache = self.widened_input.borrow_mut();
+        match &*cache {
+            Some((last, code_units)) if same_data(last.as_str(), input.as_str()) => {
+                code_units.clone()
+            }
+            _ => {
+                let code_units: Rc<[u16]> = input.to_vec().into();
+                *cache = Some((input.clone(), code_units.clone()));
+                code_units
+            }
+        }
+    }
+
     /// `22.2.7.2 RegExpBuiltinExec ( R, S )`
     ///
     /// More information:
@@ -885,13 +955,13 @@ impl RegExp {
         let flags = &rx.original_flags;
 
         // 4. If flags contains "g", let global be true; else let global be false.
-        let global = flags.contains(&('g' as u16));
+        let global = flags.as_str().contains('g' as u16);
 
         // 5. If flags contains "y", let sticky be true; else let sticky be false.
-        let sticky = flags.contains(&('y' as u16));
+        let sticky = flags.as_str().contains('y' as u16);
 
         // 6. If flags contains "d", let hasIndices be true; else let hasIndices be false.
-        let has_indices = flags.contains(&('d' as u16));
+        let has_indices = flags.as_str().contains('d' as u16);
 
         // 7. If global is false and sticky is false, set lastIndex to 0.
         if !global && !sticky {
@@ -902,10 +972,19 @@ impl RegExp {
         let matcher = &rx.matcher;
 
         // 9. If flags contains "u" or flags contains "v", let fullUnicode be true; else let fullUnicode be false.
-        let full_unicode = flags.contains(&('u' as u16)) || flags.contains(&('v' as u16));
+        let full_unicode =
+            flags.as_str().contains('u' as u16) || flags.as_str().contains('v' as u16);
 
         // 11. If fullUnicode is true, let input be StringToCodePoints(S). Otherwise, let input be a List whose elements are the code units that are the elements of S.
         // 12. NOTE: Each element of input is considered to be a character.
+        let widened;
+        let code_units = match input.as_str() {
+            JsStr::Latin1(_) => {
+                widened = rx.widen(input);
+                &widened[..]
+            }
+            JsStr::Utf16(code_units) => code_units,
+        };
 
         // 10. Let matchSucceeded be false.
         // 13. Repeat, while matchSucceeded is false,
@@ -925,9 +1004,13 @@ impl RegExp {
             // b. Let inputIndex be the index into input of the character that was obtained from element lastIndex of S.
             // c. Let r be matcher(input, inputIndex).
             let r: Option<regress::Match> = if full_unicode {
-                matcher.find_from_utf16(input, last_index as usize).next()
+                matcher
+                    .find_from_utf16(code_units, last_index as usize)
+                    .next()
             } else {
-                matcher.find_from_ucs2(input, last_index as usize).next()
+                matcher
+                    .find_from_ucs2(code_units, last_index as usize)
+                    .next()
             };
 
             match r {
@@ -1006,24 +1089,26 @@ impl RegExp {
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
         // 24. Let match be the Match Record { [[StartIndex]]: lastIndex, [[EndIndex]]: e }.
-        // Immediately convert it to an array according to 22.2.7.7 GetMatchIndexPair(S, match)
-        // 1. Assert: match.[[StartIndex]] ≤ match.[[EndIndex]] ≤ the length of S.
-        // 2. Return CreateArrayFromList(« 𝔽(match.[[StartIndex]]), 𝔽(match.[[EndIndex]]) »).
-        let match_record = Array::create_array_from_list(
-            [match_value.start().into(), match_value.end().into()],
-            context,
-        );
-
         // 25. Let indices be a new empty List.
-        let indices = Array::array_create(n + 1, None, context)?;
-
         // 27. Append match to indices.
-        indices
-            .create_data_property_or_throw(0, match_record, context)
-            .expect("this CreateDataPropertyOrThrow call must not fail");
+        // NOTE: The indices are only observable if hasIndices is true, so the array returned by
+        //       MakeMatchIndicesIndexPairArray is built directly in that case.
+        let indices = if has_indices {
+            let indices = Array::array_create(n + 1, None, context)?;
+            indices
+                .create_data_property_or_throw(
+                    0,
+                    get_match_index_pair(match_value.range(), context),
+                    context,
+                )
+                .expect("this CreateDataPropertyOrThrow call must not fail");
+            Some(indices)
+        } else {
+            None
+        };
 
         // 28. Let matchedSubstr be GetMatchString(S, match).
-        let matched_substr = js_string!(&input[(last_index as usize)..(e)]);
+        let matched_substr = js_string!(input.get_expect((last_index as usize)..(e)));
 
         // 29. Perform ! CreateDataPropertyOrThrow(A, "0", matchedSubstr).
         a.create_data_property_or_throw(0, matched_substr, context)
@@ -1041,10 +1126,10 @@ impl RegExp {
         // 31. Else,
         // 33. For each integer i such that 1 ≤ i ≤ n, in ascending order, do
         #[allow(clippy::if_not_else)]
-        let (groups, group_names) = if !named_groups.clone().is_empty() {
+        let (groups, group_names) = if !named_groups.is_empty() {
             // a. Let groups be OrdinaryObjectCreate(null).
             let groups = JsObject::with_null_proto();
-            let group_names = JsObject::with_null_proto();
+            let group_names = indices.as_ref().map(|_| JsObject::with_null_proto());
 
             // e. If the ith capture of R was defined with a GroupName, then
             // i. Let s be the CapturingGroupName of that GroupName.
@@ -1053,7 +1138,7 @@ impl RegExp {
             for (name, range) in named_groups {
                 let name = js_string!(name);
                 if let Some(range) = range {
-                    let value = js_string!(&input[range.clone()]);
+                    let value = js_string!(input.get_expect(range.clone()));
 
                     groups
                         .create_data_property_or_throw(name.clone(), value, context)
@@ -1063,17 +1148,17 @@ impl RegExp {
                     // a. Let matchIndices be indices[i].
                     // b. If matchIndices is not undefined, then
                     // i. Let matchIndexPair be GetMatchIndexPair(S, matchIndices).
-                    // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
-                    group_names
-                        .create_data_property_or_throw(
-                            name.clone(),
-                            Array::create_array_from_list(
-                                [range.start.into(), range.end.into()],
+                    // e. If i > 0 and groupNames[i - 1] is not undefined, then
+                    // i. Perform ! CreateDataPropertyOrThrow(groups, groupNames[i - 1], matchIndexPair).
+                    if let Some(group_names) = &group_names {
+                        group_names
+                            .create_data_property_or_throw(
+                                name.clone(),
+                                get_match_index_pair(range, context),
                                 context,
-                            ),
-                            context,
-                        )
-                        .expect("this CreateDataPropertyOrThrow call must not fail");
+                            )
+                            .expect("this CreateDataPropertyOrThrow call must not fail");
+                    }
                 } else {
                     groups
                         .create_data_property_or_throw(name.clone(), JsValue::undefined(), context)
@@ -1082,24 +1167,36 @@ impl RegExp {
                     // 22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )
                     // c. Else,
                     // i. Let matchIndexPair be undefined.
-                    // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
-                    group_names
-                        .create_data_property_or_throw(name, JsValue::undefined(), context)
-                        .expect("this CreateDataPropertyOrThrow call must not fail");
+                    // e. If i > 0 and groupNames[i - 1] is not undefined, then
+                    // i. Perform ! CreateDataPropertyOrThrow(groups, groupNames[i - 1], matchIndexPair).
+                    if let Some(group_names) = &group_names {
+                        group_names
+                            .create_data_property_or_throw(name, JsValue::undefined(), context)
+                            .expect("this CreateDataPropertyOrThrow call must not fail");
+                    }
                 }
             }
 
-            (groups.into(), group_names.into())
+            (
+                groups.into(),
+                group_names.map_or_else(JsValue::undefined, Into::into),
+            )
         } else {
             // a. Let groups be undefined.
             (JsValue::undefined(), JsValue::undefined())
         };
 
         // 22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )
-        // 8. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
-        indices
-            .create_data_property_or_throw(utf16!("groups"), group_names, context)
-            .expect("this CreateDataPropertyOrThrow call must not fail");
+        // 5. If hasGroups is true, then
+        // a. Let groups be OrdinaryObjectCreate(null).
+        // 6. Else,
+        // a. Let groups be undefined.
+        // 7. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
+        if let Some(indices) = &indices {
+            indices
+                .create_data_property_or_throw(utf16!("groups"), group_names, context)
+                .expect("this CreateDataPropertyOrThrow call must not fail");
+        }
 
         // 32. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
         a.create_data_property_or_throw(utf16!("groups"), groups, context)
@@ -1113,23 +1210,22 @@ impl RegExp {
             // b. If captureI is undefined, let capturedValue be undefined.
             // c. Else if fullUnicode is true, then
             // d. Else,
-            let captured_value = capture
-                .clone()
-                .map_or_else(JsValue::undefined, |range| js_string!(&input[range]).into());
+            let captured_value = capture.clone().map_or_else(JsValue::undefined, |range| {
+                js_string!(input.get_expect(range)).into()
+            });
 
             // e. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), capturedValue).
             a.create_data_property_or_throw(i, captured_value.clone(), context)
                 .expect("this CreateDataPropertyOrThrow call must not fail");
 
             // 22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )
-            if has_indices {
+            if let Some(indices) = &indices {
                 // b. If matchIndices is not undefined, then
                 // i. Let matchIndexPair be GetMatchIndexPair(S, matchIndices).
                 // c. Else,
                 // i. Let matchIndexPair be undefined.
                 let indices_range = capture.map_or_else(JsValue::undefined, |range| {
-                    Array::create_array_from_list([range.start.into(), range.end.into()], context)
-                        .into()
+                    get_match_index_pair(range, context).into()
                 });
 
                 // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
@@ -1142,7 +1238,7 @@ impl RegExp {
         // 34. If hasIndices is true, then
         // a. Let indicesArray be MakeMatchIndicesIndexPairArray(S, indices, groupNames, hasGroups).
         // b. Perform ! CreateDataPropertyOrThrow(A, "indices", indicesArray).
-        if has_indices {
+        if let Some(indices) = indices {
             a.create_data_property_or_throw(utf16!("indices"), indices, context)
                 .expect("this CreateDataPropertyOrThrow call must not fail");
         }
@@ -1181,7 +1277,7 @@ impl RegExp {
         let flags = rx.get(utf16!("flags"), context)?.to_string(context)?;
 
         // 5. If flags does not contain "g", then
-        if !flags.contains(&103) {
+        if !flags.as_str().contains(103) {
             // a. Return ? RegExpExec(rx, S).
             return (Self::abstract_exec(rx, arg_str, context)?)
                 .map_or_else(|| Ok(JsValue::null()), |v| Ok(v.into()));
@@ -1190,7 +1286,7 @@ impl RegExp {
         // 6. Else,
 
         // a. If flags contains "u" or flags contains "v", let fullUnicode be true. Otherwise, let fullUnicode be false.
-        let full_unicode = flags.contains(&117) || flags.contains(&118);
+        let full_unicode = flags.as_str().contains(117) || flags.as_str().contains(118);
 
         // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
         rx.set(utf16!("lastIndex"), 0, true, context)?;
@@ -1319,11 +1415,11 @@ impl RegExp {
 
         // 9. If flags contains "g", let global be true.
         // 10. Else, let global be false.
-        let global = flags.contains(&('g' as u16));
+        let global = flags.as_str().contains('g' as u16);
 
-        // 11. If flags contains "u", let fullUnicode be true.
+        // 11. If flags contains "u" or flags contains "v", let fullUnicode be true.
         // 12. Else, let fullUnicode be false.
-        let unicode = flags.contains(&('u' as u16));
+        let unicode = flags.as_str().contains('u' as u16) || flags.as_str().contains('v' as u16);
 
         // 13. Return ! CreateRegExpStringIterator(matcher, S, global, fullUnicode).
         Ok(RegExpStringIterator::create_regexp_string_iterator(
@@ -1389,12 +1485,13 @@ impl RegExp {
         let flags = rx.get(utf16!("flags"), context)?.to_string(context)?;
 
         // 8. If flags contains "g", let global be true. Otherwise, let global be false.
-        let global = flags.as_slice().contains(&u16::from(b'g'));
+        let global = flags.as_str().contains(u16::from(b'g'));
 
         // 9. If global is true, then
         let full_unicode = if global {
-            // a. If flags contains "u", let fullUnicode be true. Otherwise, let fullUnicode be false.
-            let full_unicode = flags.contains(&u16::from(b'u'));
+            // a. If flags contains "u" or flags contains "v", let fullUnicode be true. Otherwise, let fullUnicode be false.
+            let full_unicode = flags.as_str().contains(u16::from(b'u'))
+                || flags.as_str().contains(u16::from(b'v'));
 
             // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
             rx.set(utf16!("lastIndex"), 0, true, context)?;
@@ -1559,8 +1656,8 @@ impl RegExp {
                 //    In such cases, the corresponding substitution is ignored.
 
                 // ii. Set accumulatedResult to the string-concatenation of accumulatedResult, the substring of S from nextSourcePosition to position, and replacement.
-                accumulated_result.extend_from_slice(&s[next_source_position..position]);
-                accumulated_result.extend_from_slice(&replacement);
+                accumulated_result.extend(s.get_expect(next_source_position..position).iter());
+                accumulated_result.extend(replacement.iter());
 
                 // iii. Set nextSourcePosition to position + matchLength.
                 next_source_position = position + match_length;
@@ -1573,7 +1670,11 @@ impl RegExp {
         }
 
         // 17. Return the string-concatenation of accumulatedResult and the substring of S from nextSourcePosition.
-        Ok(js_string!(&accumulated_result[..], &s[next_source_position..]).into())
+        Ok(js_string!(
+            &accumulated_result[..],
+            s.get_expect(next_source_position..)
+        )
+        .into())
     }
 
     /// `RegExp.prototype[ @@search ]( string )`
@@ -1661,13 +1762,13 @@ impl RegExp {
         // 5. Let flags be ? ToString(? Get(rx, "flags")).
         let flags = rx.get(utf16!("flags"), context)?.to_string(context)?;
 
-        // 6. If flags contains "u", let unicodeMatching be true.
+        // 6. If flags contains "u" or flags contains "v", let unicodeMatching be true.
         // 7. Else, let unicodeMatching be false.
-        let unicode = flags.contains(&('u' as u16));
+        let unicode = flags.as_str().contains('u' as u16) || flags.as_str().contains('v' as u16);
 
         // 8. If flags contains "y", let newFlags be flags.
         // 9. Else, let newFlags be the string-concatenation of flags and "y".
-        let new_flags = if flags.contains(&('y' as u16)) {
+        let new_flags = if flags.as_str().contains('y' as u16) {
             flags
         } else {
             js_string!(&flags, utf16!("y"))
@@ -1750,7 +1851,7 @@ impl RegExp {
                     q = advance_string_index(&arg_str, q, unicode);
                 } else {
                     // 1. Let T be the substring of S from p to q.
-                    let arg_str_substring = js_string!(&arg_str[p as usize..q as usize]);
+                    let arg_str_substring = js_string!(arg_str.get_expect(p as usize..q as usize));
 
                     // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
                     a.create_data_property_or_throw(length_a, arg_str_substring, context)
@@ -1801,7 +1902,7 @@ impl RegExp {
         }
 
         // 20. Let T be the substring of S from p to size.
-        let arg_str_substring = js_string!(&arg_str[p as usize..size as usize]);
+        let arg_str_substring = js_string!(arg_str.get_expect(p as usize..size as usize));
 
         // 21. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
         a.create_data_property_or_throw(length_a, arg_str_substring, context)
@@ -1874,6 +1975,22 @@ impl RegExp {
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-advancestringindex
+/// `22.2.7.7 GetMatchIndexPair ( S, match )`
+///
+/// The indices are UTF-16 code unit offsets, even if the regular expression has the `u` flag.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getmatchindexpair
+fn get_match_index_pair(range: Range, context: &mut Context) -> JsObject {
+    // 1. Assert: match.[[StartIndex]] ≤ match.[[EndIndex]] ≤ the length of S.
+    debug_assert!(range.start <= range.end);
+
+    // 2. Return CreateArrayFromList(« 𝔽(match.[[StartIndex]]), 𝔽(match.[[EndIndex]]) »).
+    Array::create_array_from_list([range.start.into(), range.end.into()], context)
+}
+
 fn advance_string_index(s: &JsString, index: u64, unicode: bool) -> u64 {
     // Regress only works with utf8, so this function differs from the spec.
 
@@ -1897,3 +2014,11 @@ fn advance_string_index(s: &JsString, index: u64, unicode: bool) -> u64 {
 
     index + code_point.code_unit_count() as u64
 }
+
+/// Checks if two Latin-1 strings are views of the same bytes.
+fn same_data(x: JsStr<'_>, y: JsStr<'_>) -> bool {
+    match (x, y) {
+        (JsStr::Latin1(x), JsStr::Latin1(y)) => ptr::eq(x, y),
+        _ => false,
+    }
+}
@@ -85,6 +85,54 @@ fn flags() {
     ]);
 }
 
+#[test]
+fn unicode_sets() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var consonants = /^[\p{Lowercase_Letter}--[aeiou]]+$/v;
+                var greek = /^[\p{Script=Greek}&&\p{Uppercase_Letter}]$/v;
+                var emoji = /^\p{RGI_Emoji}$/v;
+            "#}),
+        TestAction::assert("consonants.unicodeSets"),
+        TestAction::assert("!consonants.unicode"),
+        TestAction::assert_eq("consonants.flags", js_string!("v")),
+        TestAction::assert_eq("/a/dgimsvy.flags", js_string!("dgimsvy")),
+        // set difference
+        TestAction::assert("consonants.test('rhythm')"),
+        TestAction::assert("!consonants.test('vowel')"),
+        // set intersection
+        TestAction::assert("greek.test('Ω')"),
+        TestAction::assert("!greek.test('ω')"),
+        TestAction::assert("!greek.test('A')"),
+        // properties of strings
+        TestAction::assert("emoji.test('👨‍👩‍👧‍👦')"),
+        TestAction::assert("emoji.test('🇯🇵')"),
+        TestAction::assert("emoji.test('👍🏽')"),
+        TestAction::assert("emoji.test('#️⃣')"),
+        TestAction::assert("!emoji.test('a')"),
+        TestAction::assert_eq("'🏳️‍🌈!'.match(/\\p{RGI_Emoji}/v)[0]", js_string!("🏳️‍🌈")),
+        // class strings, from the longest one
+        TestAction::assert_eq("'abcd'.match(/[\\q{a|abc|ab}]/v)[0]", js_string!("abc")),
+        TestAction::assert_eq(
+            "'xyz'.replace(/[\\q{xy|z}--\\q{z}]/gv, '-')",
+            js_string!("-z"),
+        ),
+        // case insensitive complements
+        TestAction::assert("!/[^a]/vi.test('A')"),
+        TestAction::assert("/[^a]/vi.test('b')"),
+        TestAction::assert_native_error(
+            r"/[^\p{RGI_Emoji}]/v",
+            JsNativeErrorKind::Syntax,
+            "Invalid regular expression literal: Invalid character class: negated character class may contain strings at line 1, col 1",
+        ),
+        TestAction::assert_native_error(
+            r"new RegExp('[a-z&&b]', 'v')",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: Invalid character class: set operations cannot be mixed with unions",
+        ),
+    ]);
+}
+
 #[test]
 fn last_index() {
     run_test_actions([
@@ -119,6 +167,40 @@ fn exec() {
     ]);
 }
 
+#[test]
+fn match_indices() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                var result = /(?<x>x)|(?<y>y)/d.exec("zy");
+                var indices = result.indices;
+            "#}),
+        TestAction::assert("/a/d.hasIndices"),
+        TestAction::assert("!/a/.hasIndices"),
+        TestAction::assert_eq("/a/ysmigd.flags", js_string!("dgimsy")),
+        TestAction::assert("!('indices' in /a/.exec('a'))"),
+        TestAction::assert_eq("indices.length", 3),
+        TestAction::assert("arrayEquals(indices[0], [1, 2])"),
+        // groups that didn't participate in the match
+        TestAction::assert_eq("indices[1]", JsValue::undefined()),
+        TestAction::assert("arrayEquals(indices[2], [1, 2])"),
+        TestAction::assert_eq("Object.getPrototypeOf(indices.groups)", JsValue::null()),
+        TestAction::assert("arrayEquals(Object.keys(indices.groups), ['x', 'y'])"),
+        TestAction::assert_eq("indices.groups.x", JsValue::undefined()),
+        TestAction::assert("arrayEquals(indices.groups.y, [1, 2])"),
+        TestAction::assert("'groups' in /a/d.exec('a').indices"),
+        TestAction::assert_eq("/a/d.exec('a').indices.groups", JsValue::undefined()),
+        TestAction::assert_eq("/(?:(a)|b)+/d.exec('ab').indices[1]", JsValue::undefined()),
+        // the indices are UTF-16 code units, with or without the `u` flag
+        TestAction::assert("arrayEquals(/😀(.)/du.exec('x😀é').indices[1], [3, 4])"),
+        TestAction::assert("arrayEquals(/😀(.)/d.exec('x😀é').indices[1], [3, 4])"),
+        TestAction::assert("arrayEquals(/.(.)/d.exec('😀é').indices[1], [1, 2])"),
+        TestAction::assert(
+            "arrayEquals([...'a1b2'.matchAll(/[a-z](?<n>\\d)/gd)].map(m => m.indices.groups.n[0]), [1, 3])",
+        ),
+    ]);
+}
+
 #[test]
 fn no_panic_on_parse_fail() {
     run_test_actions([
@@ -135,6 +217,28 @@ fn no_panic_on_parse_fail() {
     ]);
 }
 
+#[test]
+fn early_errors_only_for_literals() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "function f() { return /(?<dup>a)(?<dup>b)/; }",
+            JsNativeErrorKind::Syntax,
+            "Invalid regular expression literal: Duplicate capture group name at line 1, col 23",
+        ),
+        TestAction::run("function g() { return new RegExp('(?<dup>a)(?<dup>b)'); }"),
+        TestAction::assert_native_error(
+            "g()",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: Duplicate capture group name",
+        ),
+        TestAction::assert_native_error(
+            "new RegExp('a', 'uv')",
+            JsNativeErrorKind::Syntax,
+            "regular expression flags u and v cannot be used together",
+        ),
+    ]);
+}
+
 #[test]
 fn to_string() {
     run_test_actions([
@@ -215,6 +319,75 @@ fn search() {
     ]);
 }
 
+#[test]
+fn replace_with_crafted_exec() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class CraftedRegExp extends RegExp {
+                exec() {
+                    if (this.done) {
+                        return null;
+                    }
+                    this.done = true;
+                    return {
+                        0: "zz",
+                        1: 5,
+                        length: 2,
+                        index: 1,
+                        groups: { empty: "", missing: undefined },
+                    };
+                }
+            }
+        "#}),
+        TestAction::assert_eq(
+            r#""abc".replace(new CraftedRegExp("x"), "[$`|$'|$<empty>|$<missing>|$1|$&]")"#,
+            js_string!("a[a||||5|zz]"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var re = new CraftedRegExp("x");
+                re.exec = () => re.done ? null : (re.done = true, { 0: "z", length: 1, index: 10 });
+                "abc".replace(re, "[$'|$`]")
+            "#},
+            js_string!("abc[|abc]"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var args;
+                "abc".replace(new CraftedRegExp("x"), (...a) => { args = a; return "-"; })
+            "#},
+            js_string!("a-"),
+        ),
+        TestAction::assert_eq("args.length", 5),
+        TestAction::assert_eq("args[1]", js_string!("5")),
+        TestAction::assert_eq("args[4].empty", js_string!()),
+    ]);
+}
+
+#[test]
+fn replace_global_sticky() {
+    run_test_actions([
+        TestAction::run(r#"var re = /a/gy; var result = "aaba".replace(re, "x");"#),
+        TestAction::assert_eq("result", js_string!("xxba")),
+        TestAction::assert_eq("re.lastIndex", 0),
+    ]);
+}
+
+#[test]
+fn reused_on_latin1_strings() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var re = /\w+/g;
+            var first = "caf\u00e9 au lait".replace(re, "[$&]");
+            var second = "th\u00e9 noir".replace(re, "[$&]");
+            var third = "\u03b1\u03b2 gamma".replace(re, "[$&]");
+        "#}),
+        TestAction::assert_eq("first", js_string!("[caf]\u{e9} [au] [lait]")),
+        TestAction::assert_eq("second", js_string!("[th]\u{e9} [noir]")),
+        TestAction::assert_eq("third", js_string!("\u{3b1}\u{3b2} [gamma]")),
+    ]);
+}
+
 #[test]
 fn regular_expression_construction_independant_of_global_reg_exp() {
     let regex = "/abc/";
@@ -224,3 +397,27 @@ fn regular_expression_construction_independant_of_global_reg_exp() {
         TestAction::run(regex),
     ]);
 }
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn compile() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var re = /a/g;
+            re.lastIndex = 3;
+            var result = re.compile('b+', 'i');
+        "#}),
+        TestAction::assert("result === re"),
+        TestAction::assert_eq("re.source", js_string!("b+")),
+        TestAction::assert_eq("re.flags", js_string!("i")),
+        TestAction::assert_eq("re.lastIndex", 0),
+        TestAction::assert("re.test('BB')"),
+        TestAction::assert_eq("re.compile(/c/m).source", js_string!("c")),
+        TestAction::assert_eq("re.flags", js_string!("m")),
+        TestAction::assert_native_error(
+            "re.compile(/c/, 'g')",
+            JsNativeErrorKind::Type,
+            "`RegExp.prototype.compile` cannot be called with both a RegExp initializer and new flags",
+        ),
+    ]);
+}
@@ -0,0 +1,588 @@
+//! Boa's implementation of the `ShadowRealm` builtin object.
+//!
+//! A `ShadowRealm` is a distinct global environment, with its own global object and intrinsics,
+//! that can only exchange primitives and callables with the realm that created it.
+//!
+//! More information:
+//!  - [ShadowRealm proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{eval::Eval, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    js_string,
+    module::Referrer,
+    native_function::NativeFunction,
+    object::{
+        builtins::JsPromise, internal_methods::get_prototype_from_constructor,
+        FunctionObjectBuilder, JsObject,
+    },
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    string::common::StaticJsStrings,
+    symbol::JsSymbol,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsData, JsError, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_parser::{Parser, Source};
+use boa_profiler::Profiler;
+
+/// The `ShadowRealm` builtin object.
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct ShadowRealm {
+    /// The `[[ShadowRealm]]` internal slot.
+    realm: Realm,
+}
+
+impl IntrinsicObject for ShadowRealm {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::evaluate, js_string!("evaluate"), 1)
+            .method(Self::import_value, js_string!("importValue"), 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for ShadowRealm {
+    const NAME: JsString = StaticJsStrings::SHADOW_REALM;
+}
+
+impl BuiltInConstructor for ShadowRealm {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::shadow_realm;
+
+    /// `ShadowRealm ( )`
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let callerContext be the running execution context.
+        // 4. Perform ? InitializeHostDefinedRealm().
+        // 5. Let innerContext be the running execution context.
+        // 6. Remove innerContext from the execution context stack and restore callerContext as the running execution context.
+        // 7. Let realmRec be the Realm of innerContext.
+        // 8. Set O.[[ShadowRealm]] to realmRec.
+        // 9. Perform ? HostInitializeShadowRealm(realmRec).
+        let realm = context.create_realm()?;
+
+        // 10. Return O.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { realm },
+        )
+        .into())
+    }
+}
+
+impl ShadowRealm {
+    /// Abstract operation `ValidateShadowRealmObject ( O )`, returning the `[[ShadowRealm]]` of
+    /// the `this` object of `method`.
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn this_realm(this: &JsValue, method: &str) -> JsResult<Realm> {
+        // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+        this.as_object()
+            .and_then(|object| object.downcast_ref::<Self>().map(|this| this.realm.clone()))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "ShadowRealm.prototype.{method}: `this` is not a ShadowRealm"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `ShadowRealm.prototype.evaluate ( sourceText )`
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    fn evaluate(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::this_realm(this, "evaluate")?;
+
+        // 3. If sourceText is not a String, throw a TypeError exception.
+        let Some(source_text) = args.get_or_undefined(0).as_string() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.evaluate: `sourceText` is not a string")
+                .into());
+        };
+
+        // 4. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        perform_shadow_realm_eval(source_text, &caller_realm, eval_realm, context)
+    }
+
+    /// `ShadowRealm.prototype.importValue ( specifier, exportName )`
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    fn import_value(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::this_realm(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If exportName is not a String, throw a TypeError exception.
+        let Some(export_name) = args.get_or_undefined(1).as_string().cloned() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.importValue: `exportName` is not a string")
+                .into());
+        };
+
+        // 5. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Return ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm).
+        Ok(
+            shadow_realm_import_value(specifier, export_name, &caller_realm, eval_realm, context)
+                .into(),
+        )
+    }
+}
+
+/// Abstract operation `PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+fn perform_shadow_realm_eval(
+    source_text: &JsString,
+    caller_realm: &Realm,
+    eval_realm: Realm,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Perform ? HostEnsureCanCompileStrings(evalRealm, « », sourceText, false).
+    context
+        .host_hooks()
+        .ensure_can_compile_strings(eval_realm.clone(), context)?;
+
+    // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+    //     a. Let script be ParseText(StringToCodePoints(sourceText), Script).
+    //     b. If script is a List of errors, throw a SyntaxError exception.
+    //     c. If script Contains ScriptBody is false, return undefined.
+    //     d. Let body be the ScriptBody of script.
+    //     e. If body Contains NewTarget, throw a SyntaxError exception.
+    //     f. If body Contains SuperProperty, throw a SyntaxError exception.
+    //     g. If body Contains SuperCall, throw a SyntaxError exception.
+    // NOTE: Early errors must be reported as a `SyntaxError` of the caller realm, while every
+    // other error is turned into a `TypeError`, so the source is checked here before being
+    // evaluated as an indirect eval inside the shadow realm.
+    let source = source_text.to_vec();
+    let mut parser = Parser::new(Source::from_utf16(&source));
+    parser.set_nesting_limit(context.runtime_limits().nesting_limit());
+    parser.parse_eval(false, context.interner_mut())?;
+
+    // 3-14. Evaluate body as an indirect eval in the context of evalRealm.
+    let old_realm = context.enter_realm(eval_realm);
+    let result = Eval::perform_eval(&source_text.clone().into(), false, false, context);
+    context.enter_realm(old_realm);
+
+    match result {
+        // 16. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        Ok(value) => get_wrapped_value(caller_realm, &value, context),
+        // 15. If result is an abrupt completion, then
+        //     a. Let copiedError be CreateTypeErrorCopy(callerRealm, result.[[Value]]).
+        //     b. Return ThrowCompletion(copiedError).
+        Err(err) => Err(create_type_error_copy(err)),
+    }
+}
+
+/// Abstract operation `ShadowRealmImportValue ( specifierString, exportNameString, callerRealm, evalRealm )`.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+fn shadow_realm_import_value(
+    specifier: JsString,
+    export_name: JsString,
+    caller_realm: &Realm,
+    eval_realm: Realm,
+    context: &mut Context,
+) -> JsPromise {
+    // 1. Let evalContext be GetShadowRealmContext(evalRealm, true).
+    // 2. Let innerCapability be ! NewPromiseCapability(%Promise%).
+    let (inner_promise, resolvers) = JsPromise::new_pending(context);
+
+    // 3. Let runningContext be the running execution context.
+    // 4. If runningContext is not already suspended, suspend runningContext.
+    // 5. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+    let old_realm = context.enter_realm(eval_realm.clone());
+
+    // 6. Let referrer be the Realm component of evalContext.
+    // 7. Perform HostLoadImportedModule(referrer, specifierString, empty, innerCapability).
+    context.module_loader().load_imported_module(
+        Referrer::Realm(eval_realm.clone()),
+        specifier.clone(),
+        Box::new(move |completion, context| {
+            // `ContinueDynamicImport ( promiseCapability, moduleCompletion )`
+            let module = match completion {
+                Ok(module) => module,
+                Err(err) => {
+                    let err = err.to_opaque(context);
+                    resolvers
+                        .reject
+                        .call(&JsValue::undefined(), &[err], context)
+                        .expect("default `reject` function cannot throw");
+                    return;
+                }
+            };
+
+            eval_realm
+                .loaded_modules()
+                .borrow_mut()
+                .entry(specifier)
+                .or_insert_with(|| module.clone());
+
+            let namespace = module
+                .load(context)
+                .then(
+                    Some(
+                        NativeFunction::from_copy_closure_with_captures(
+                            |_, _, module, context| {
+                                module.link(context)?;
+                                Ok(module.evaluate(context).into())
+                            },
+                            module.clone(),
+                        )
+                        .to_js_function(context.realm()),
+                    ),
+                    None,
+                    context,
+                )
+                .then(
+                    Some(
+                        NativeFunction::from_copy_closure_with_captures(
+                            |_, _, module, context| Ok(module.namespace(context).into()),
+                            module,
+                        )
+                        .to_js_function(context.realm()),
+                    ),
+                    None,
+                    context,
+                );
+
+            resolvers
+                .resolve
+                .call(&JsValue::undefined(), &[namespace.into()], context)
+                .expect("default `resolve` function cannot throw");
+        }),
+        context,
+    );
+
+    // 8. Suspend evalContext and remove it from the execution context stack.
+    // 9. Resume the context that is now on the top of the execution context stack as the running execution context.
+    context.enter_realm(old_realm);
+
+    // 10. Let steps be the steps of an ExportGetter function as described below.
+    // 11. Let onFulfilled be CreateBuiltinFunction(steps, 1, "", « [[ExportNameString]] », callerRealm).
+    // 12. Set onFulfilled.[[ExportNameString]] to exportNameString.
+    let on_fulfilled = FunctionObjectBuilder::new(
+        caller_realm,
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, (export_name, realm), context| {
+                // 1. Assert: exports is a module namespace exotic object.
+                let exports = args
+                    .get_or_undefined(0)
+                    .as_object()
+                    .expect("the inner promise must resolve to a module namespace");
+
+                // 2. Let f be the active function object.
+                // 3. Let string be f.[[ExportNameString]].
+                // 4. Assert: string is a String.
+                // 5. Let hasOwn be ? HasOwnProperty(exports, string).
+                // 6. If hasOwn is false, throw a TypeError exception.
+                if !exports.has_own_property(export_name.clone(), context)? {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "ShadowRealm.prototype.importValue: module has no export named `{}`",
+                            export_name.to_std_string_escaped()
+                        ))
+                        .into());
+                }
+
+                // 7. Let value be ? Get(exports, string).
+                let value = exports.get(export_name.clone(), context)?;
+
+                // 8. Let realm be f.[[Realm]].
+                // 9. Return ? GetWrappedValue(realm, value).
+                get_wrapped_value(realm, &value, context)
+            },
+            (export_name, caller_realm.clone()),
+        ),
+    )
+    .length(1)
+    .build();
+
+    // 13. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+    // 14. Return PerformPromiseThen(innerCapability.[[Promise]], onFulfilled, callerRealm.[[Intrinsics]].[[%ThrowTypeError%]], promiseCapability).
+    // NOTE: `%ThrowTypeError%` would report an error about restricted function properties, so a
+    // function with a more helpful message is used in its place.
+    let on_rejected = FunctionObjectBuilder::new(
+        caller_realm,
+        NativeFunction::from_fn_ptr(|_, _, _| {
+            Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.importValue: failed to import the module")
+                .into())
+        }),
+    )
+    .length(1)
+    .build();
+
+    inner_promise.then(Some(on_fulfilled), Some(on_rejected), context)
+}
+
+/// Abstract operation `GetWrappedValue ( callerRealm, value )`.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+fn get_wrapped_value(
+    caller_realm: &Realm,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If value is an Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm: only primitives and callables can cross the boundary")
+                .into());
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return wrapped_function_create(caller_realm, object.clone(), context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value.clone())
+}
+
+/// Abstract operation `WrappedFunctionCreate ( callerRealm, Target )`.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+fn wrapped_function_create(
+    caller_realm: &Realm,
+    target: JsObject,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 7. Let result be Completion(CopyNameAndLength(wrapped, Target)).
+    // 8. If result is an abrupt completion, throw a TypeError exception.
+    let (name, length) = copy_name_and_length(&target, context).map_err(|err| {
+        if err.is_catchable() {
+            JsNativeError::typ()
+                .with_message("ShadowRealm: could not copy the name and length of a callable")
+                .into()
+        } else {
+            err
+        }
+    })?;
+
+    // 1. Let internalSlotsList be the internal slots listed in Table 2, plus [[Prototype]] and [[Extensible]].
+    // 2. Let wrapped be MakeBasicObject(internalSlotsList).
+    // 3. Set wrapped.[[Prototype]] to callerRealm.[[Intrinsics]].[[%Function.prototype%]].
+    // 4. Set wrapped.[[Call]] as described in 2.1.
+    // 5. Set wrapped.[[WrappedTargetFunction]] to Target.
+    // 6. Set wrapped.[[Realm]] to callerRealm.
+    let wrapped = FunctionObjectBuilder::new(
+        caller_realm,
+        NativeFunction::from_copy_closure_with_captures(
+            ordinary_wrapped_function_call,
+            (target, caller_realm.clone()),
+        ),
+    )
+    .name(name)
+    .build();
+
+    wrapped.define_property_or_throw(
+        js_string!("length"),
+        PropertyDescriptor::builder()
+            .value(length)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    // 9. Return wrapped.
+    Ok(wrapped.into())
+}
+
+/// Abstract operation `CopyNameAndLength ( F, Target [ , prefix [ , argCount ] ] )`, returning the
+/// name and length the wrapped function must be created with.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+fn copy_name_and_length(target: &JsObject, context: &mut Context) -> JsResult<(JsString, f64)> {
+    // 1. If argCount is undefined, then set argCount to 0.
+    // 2. Let L be 0.
+    let mut length = 0.0;
+
+    // 3. Let targetHasLength be ? HasOwnProperty(Target, "length").
+    // 4. If targetHasLength is true, then
+    if target.has_own_property(js_string!("length"), context)? {
+        // a. Let targetLen be ? Get(Target, "length").
+        // b. If targetLen is a Number, then
+        if let Some(target_len) = target.get(js_string!("length"), context)?.as_number() {
+            length = match IntegerOrInfinity::from(target_len) {
+                // i. If targetLen is +∞𝔽, then
+                //     1. Set L to +∞.
+                IntegerOrInfinity::PositiveInfinity => f64::INFINITY,
+                // ii. Else if targetLen is -∞𝔽, then
+                //     1. Set L to 0.
+                IntegerOrInfinity::NegativeInfinity => 0.0,
+                // iii. Else,
+                //     1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                //     2. Assert: targetLenAsInt is finite.
+                //     3. Set L to max(targetLenAsInt - argCount, 0).
+                IntegerOrInfinity::Integer(len) => len.max(0) as f64,
+            };
+        }
+    }
+
+    // 5. Perform SetFunctionLength(F, L).
+    // 6. Let targetName be ? Get(Target, "name").
+    // 7. If targetName is not a String, set targetName to the empty String.
+    // 8. If prefix is present, then
+    //     a. Perform SetFunctionName(F, targetName, prefix).
+    // 9. Else,
+    //     a. Perform SetFunctionName(F, targetName).
+    let name = target
+        .get(js_string!("name"), context)?
+        .as_string()
+        .cloned()
+        .unwrap_or_default();
+
+    Ok((name, length))
+}
+
+/// Abstract operation `OrdinaryWrappedFunctionCall ( F, thisArgument, argumentsList )`.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-ordinary-wrapped-function-call
+fn ordinary_wrapped_function_call(
+    this: &JsValue,
+    args: &[JsValue],
+    (target, caller_realm): &(JsObject, Realm),
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerRealm be F.[[Realm]].
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+    // 5. Let targetRealm be ? GetFunctionRealm(target).
+    let target_realm = target.get_function_realm(context)?;
+
+    // 6. Let wrappedArgs be a new empty List.
+    // 7. For each element arg of argumentsList, do
+    //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+    //     b. Append wrappedValue to wrappedArgs.
+    let wrapped_args = args
+        .iter()
+        .map(|arg| get_wrapped_value(&target_realm, arg, context))
+        .collect::<JsResult<Vec<_>>>()?;
+
+    // 8. Let wrappedThisArgument be ? GetWrappedValue(targetRealm, thisArgument).
+    let wrapped_this = get_wrapped_value(&target_realm, this, context)?;
+
+    // 9. Let result be Completion(Call(target, wrappedThisArgument, wrappedArgs)).
+    match target.call(&wrapped_this, &wrapped_args, context) {
+        // 10. If result.[[Type]] is normal or result.[[Type]] is return, then
+        //     a. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        Ok(value) => get_wrapped_value(caller_realm, &value, context),
+        // 11. Else,
+        //     a. Let copiedError be CreateTypeErrorCopy(callerRealm, result.[[Value]]).
+        //     b. Return ThrowCompletion(copiedError).
+        Err(err) => Err(create_type_error_copy(err)),
+    }
+}
+
+/// Abstract operation `CreateTypeErrorCopy ( realmRecord, originalError )`.
+///
+/// The message of the copy is implementation-defined; native errors keep their description, but
+/// thrown objects are never inspected, since that could run code from the other realm.
+/// Uncatchable errors are propagated unchanged.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-createtypeerrorcopy
+fn create_type_error_copy(error: JsError) -> JsError {
+    if !error.is_catchable() {
+        return error;
+    }
+
+    // 1. Let newError be a newly created TypeError object.
+    // 2. NOTE: newError is created in realmRecord.
+    // 3. Return newError.
+    let message = error.as_native().map_or_else(
+        || "ShadowRealm: an error was thrown across the realm boundary".to_owned(),
+        |native| format!("ShadowRealm: an error was thrown across the realm boundary ({native})"),
+    );
+
+    JsNativeError::typ().with_message(message).into()
+}
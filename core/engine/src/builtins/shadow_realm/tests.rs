@@ -0,0 +1,161 @@
+use std::rc::Rc;
+
+use indoc::indoc;
+
+use crate::{
+    js_string,
+    module::{ModuleLoader, Referrer},
+    run_test_actions, Context, JsNativeErrorKind, JsResult, JsString, JsValue, Module, Source,
+    TestAction,
+};
+
+#[test]
+fn evaluate_isolates_global_object() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var realm = new ShadowRealm();
+            realm.evaluate(`
+                globalThis.x = 1;
+                var y = 2;
+                Array.prototype.z = 3;
+            `);
+        "#}),
+        TestAction::assert_eq("typeof globalThis.x", js_string!("undefined")),
+        TestAction::assert_eq("typeof y", js_string!("undefined")),
+        TestAction::assert_eq("[].z", JsValue::undefined()),
+        TestAction::assert_eq("realm.evaluate('x + y + [].z')", 6),
+        TestAction::assert_eq(
+            "new ShadowRealm().evaluate('typeof x')",
+            js_string!("undefined"),
+        ),
+        TestAction::assert("realm.evaluate('Array') !== Array"),
+    ]);
+}
+
+#[test]
+fn evaluate_rejects_objects() {
+    run_test_actions([
+        TestAction::run("var realm = new ShadowRealm();"),
+        TestAction::assert_native_error(
+            "realm.evaluate('({})')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: only primitives and callables can cross the boundary",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('x => typeof x')({})",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: only primitives and callables can cross the boundary",
+        ),
+        TestAction::assert_eq("realm.evaluate('x => typeof x')(1n)", js_string!("bigint")),
+        TestAction::assert_native_error(
+            "realm.evaluate(1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: `sourceText` is not a string",
+        ),
+    ]);
+}
+
+#[test]
+fn wrapped_functions() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var realm = new ShadowRealm();
+            var add = realm.evaluate('function add(a, b) { return a + b; } add');
+        "#}),
+        TestAction::assert_eq("add(1, 2)", 3),
+        TestAction::assert_eq("add.name", js_string!("add")),
+        TestAction::assert_eq("add.length", 2),
+        TestAction::assert("Object.getPrototypeOf(add) === Function.prototype"),
+        TestAction::assert_eq("realm.evaluate('f => f(2)')(x => x * 3)", 6),
+        TestAction::assert_eq("realm.evaluate('f => f')(add) === add", false),
+    ]);
+}
+
+#[test]
+fn errors_become_type_errors() {
+    run_test_actions([
+        TestAction::run("var realm = new ShadowRealm();"),
+        TestAction::assert_native_error(
+            "realm.evaluate('throw new Error(\"boom\")')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: an error was thrown across the realm boundary",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('() => { throw 1; }')()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: an error was thrown across the realm boundary",
+        ),
+        TestAction::assert(indoc! {r#"
+            try {
+                realm.evaluate('1 +');
+                false;
+            } catch (e) {
+                e instanceof SyntaxError;
+            }
+        "#}),
+    ]);
+}
+
+/// A module loader that parses the modules in the list synchronously.
+struct StaticLoader(&'static [(&'static str, &'static str)]);
+
+impl ModuleLoader for StaticLoader {
+    fn load_imported_module(
+        &self,
+        _referrer: Referrer,
+        specifier: JsString,
+        finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+        context: &mut Context,
+    ) {
+        let specifier = specifier.to_std_string_escaped();
+        let (_, source) = self
+            .0
+            .iter()
+            .find(|(name, _)| *name == specifier)
+            .expect("test should only import known modules");
+        let module = Module::parse(Source::from_bytes(source), None, context);
+        finish_load(module, context);
+    }
+}
+
+#[test]
+fn import_value() {
+    let context = &mut Context::builder()
+        .module_loader(Rc::new(StaticLoader(&[(
+            "math",
+            indoc! {r#"
+                globalThis.imported = true;
+                export function double(x) { return x * 2; }
+                export const table = {};
+            "#},
+        )])))
+        .build()
+        .unwrap();
+
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            var realm = new ShadowRealm();
+            var results = {};
+            var promise = realm.importValue('math', 'double');
+            promise.then(double => results.double = double(21));
+            realm.importValue('math', 'missing').catch(e => results.missing = e instanceof TypeError);
+            realm.importValue('math', 'table').catch(e => results.table = e instanceof TypeError);
+        "#}))
+        .unwrap();
+    context.run_jobs();
+
+    assert_eq!(
+        context
+            .eval(Source::from_bytes(
+                "`${promise instanceof Promise},${results.double},${results.missing},${results.table},${typeof imported}`"
+            ))
+            .unwrap(),
+        js_string!("true,42,true,true,undefined").into()
+    );
+    assert_eq!(
+        context
+            .eval(Source::from_bytes("realm.evaluate('globalThis.imported')"))
+            .unwrap(),
+        JsValue::new(true)
+    );
+}
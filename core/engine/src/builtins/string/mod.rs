@@ -17,8 +17,8 @@ use crate::{
     object::{internal_methods::get_prototype_from_constructor, JsObject},
     property::{Attribute, PropertyDescriptor},
     realm::Realm,
+    string::CodePoint,
     string::{common::StaticJsStrings, utf16},
-    string::{CodePoint, Utf16Trim},
     symbol::JsSymbol,
     value::IntegerOrInfinity,
     Context, JsArgs, JsResult, JsString, JsValue,
@@ -32,6 +32,9 @@ use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 mod string_iterator;
 pub(crate) use string_iterator::StringIterator;
 
+mod substitution;
+pub(crate) use substitution::get_substitution;
+
 /// The set of normalizers required for the `String.prototype.normalize` function.
 #[derive(Debug)]
 pub(crate) struct StringNormalizers {
@@ -407,7 +410,7 @@ impl String {
             let next_seg = raw.get(next_key, context)?.to_string(context)?;
 
             // c. Append the code unit elements of nextSeg to the end of stringElements.
-            string_elements.extend(next_seg.iter().copied());
+            string_elements.extend(next_seg.iter());
 
             // d. If nextIndex + 1 = literalSegments, then
             if next_index + 1 == literal_segments {
@@ -429,7 +432,7 @@ impl String {
             let next_sub = next.to_string(context)?;
 
             // h. Append the code unit elements of nextSub to the end of stringElements.
-            string_elements.extend(next_sub.iter().copied());
+            string_elements.extend(next_sub.iter());
 
             // i. Set nextIndex to nextIndex + 1.
             next_index += 1;
@@ -511,7 +514,7 @@ impl String {
             // 6. Return the substring of S from position to position + 1.
             IntegerOrInfinity::Integer(i) if i >= 0 && i < string.len() as i64 => {
                 let i = i as usize;
-                Ok(js_string!(&string[i..=i]).into())
+                Ok(js_string!(string.get_expect(i..=i)).into())
             }
             // 5. If position < 0 or position ≥ size, return the empty String.
             _ => Ok(js_string!().into()),
@@ -553,7 +556,7 @@ impl String {
         };
 
         // 8. Return the substring of S from k to k + 1.
-        Ok(js_string!(&s[k..=k]).into())
+        Ok(js_string!(s.get_expect(k..=k)).into())
     }
 
     /// `String.prototype.codePointAt( index )`
@@ -631,7 +634,7 @@ impl String {
             // 4. Let size be the length of S.
             IntegerOrInfinity::Integer(i) if i >= 0 && i < string.len() as i64 => {
                 // 6. Return the Number value for the numeric value of the code unit at index position within the String S.
-                Ok(u32::from(string[i as usize]).into())
+                Ok(u32::from(string.get_expect(i as usize)).into())
             }
             // 5. If position < 0 or position ≥ size, return NaN.
             _ => Ok(JsValue::nan()),
@@ -668,7 +671,7 @@ impl String {
         for arg in args {
             // a. Let nextString be ? ToString(next).
             // b. Set R to the string-concatenation of R and nextString.
-            string = js_string!(&string, &arg.to_string(context)?);
+            string = JsString::concat_lazy(&string, &arg.to_string(context)?);
         }
 
         // 5. Return R.
@@ -710,9 +713,9 @@ impl String {
                 let n = n as usize;
                 let mut result = Vec::with_capacity(n * len);
 
-                std::iter::repeat(&string[..])
+                std::iter::repeat(string.as_str())
                     .take(n)
-                    .for_each(|s| result.extend_from_slice(s));
+                    .for_each(|s| result.extend(s.iter()));
 
                 // 6. Return the String value that is made from n copies of S appended together.
                 Ok(js_string!(result).into())
@@ -790,7 +793,7 @@ impl String {
             Ok(js_string!().into())
         } else {
             // 13. Return the substring of S from from to to.
-            Ok(js_string!(&string[from..to]).into())
+            Ok(js_string!(string.get_expect(from..to)).into())
         }
     }
 
@@ -859,7 +862,7 @@ impl String {
             // 14. Return ! SameValueNonNumeric(substring, searchStr).
             // `SameValueNonNumeric` forwards to `==`, so directly check
             // equality to avoid converting to `JsValue`
-            Ok(JsValue::new(search_string == string[start..end]))
+            Ok(JsValue::new(search_string == string.get_expect(start..end)))
         }
     }
 
@@ -922,7 +925,7 @@ impl String {
             // 14. Return ! SameValueNonNumeric(substring, searchStr).
             // `SameValueNonNumeric` forwards to `==`, so directly check
             // equality to avoid converting to `JsValue`
-            Ok(JsValue::new(search_str == string[start..end]))
+            Ok(JsValue::new(search_str == string.get_expect(start..end)))
         } else {
             // 12. If start < 0, return false.
             Ok(false.into())
@@ -973,7 +976,7 @@ impl String {
         // 10. Let index be ! StringIndexOf(S, searchStr, start).
         // 11. If index is not -1, return true.
         // 12. Return false.
-        Ok(string.index_of(&search_str, start).is_some().into())
+        Ok(string.index_of(search_str.as_str(), start).is_some().into())
     }
 
     /// `String.prototype.replace( regexp|substr, newSubstr|function )`
@@ -1042,12 +1045,12 @@ impl String {
 
         // 8. Let position be ! StringIndexOf(string, searchString, 0).
         // 9. If position is -1, return string.
-        let Some(position) = string.index_of(&search_string, 0) else {
+        let Some(position) = string.index_of(search_string.as_str(), 0) else {
             return Ok(string.into());
         };
 
         // 10. Let preserved be the substring of string from 0 to position.
-        let preserved = &string[..position];
+        let preserved = string.get_expect(..position);
 
         let replacement = match replace_value {
             // 11. If functionalReplace is true, then
@@ -1081,7 +1084,12 @@ impl String {
         };
 
         // 13. Return the string-concatenation of preserved, replacement, and the substring of string from position + searchLength.
-        Ok(js_string!(preserved, &replacement, &string[position + search_length..]).into())
+        Ok(js_string!(
+            preserved,
+            &replacement,
+            string.get_expect(position + search_length..)
+        )
+        .into())
     }
 
     /// `22.1.3.18 String.prototype.replaceAll ( searchValue, replaceValue )`
@@ -1123,7 +1131,7 @@ impl String {
                 flags.require_object_coercible()?;
 
                 // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
-                if !flags.to_string(context)?.contains(&u16::from(b'g')) {
+                if !flags.to_string(context)?.as_str().contains(u16::from(b'g')) {
                     return Err(JsNativeError::typ()
                         .with_message(
                             "String.prototype.replaceAll called with a non-global RegExp argument",
@@ -1167,7 +1175,7 @@ impl String {
         let mut match_positions = Vec::new();
 
         // 10. Let position be ! StringIndexOf(string, searchString, 0).
-        let mut position = string.index_of(&search_string, 0);
+        let mut position = string.index_of(search_string.as_str(), 0);
 
         // 11. Repeat, while position is not -1,
         while let Some(p) = position {
@@ -1175,7 +1183,7 @@ impl String {
             match_positions.push(p);
 
             // b. Set position to ! StringIndexOf(string, searchString, position + advanceBy).
-            position = string.index_of(&search_string, p + advance_by);
+            position = string.index_of(search_string.as_str(), p + advance_by);
         }
 
         // 12. Let endOfLastMatch be 0.
@@ -1187,7 +1195,7 @@ impl String {
         // 14. For each element p of matchPositions, do
         for p in match_positions {
             // a. Let preserved be the substring of string from endOfLastMatch to p.
-            let preserved = &string[end_of_last_match..p];
+            let preserved = string.get_expect(end_of_last_match..p);
 
             // c. Else,
             let replacement = match replace {
@@ -1222,8 +1230,8 @@ impl String {
             };
 
             // d. Set result to the string-concatenation of result, preserved, and replacement.
-            result.extend_from_slice(preserved);
-            result.extend_from_slice(&replacement);
+            result.extend(preserved.iter());
+            result.extend(replacement.iter());
 
             // e. Set endOfLastMatch to p + searchLength.
             end_of_last_match = p + search_length;
@@ -1232,7 +1240,7 @@ impl String {
         // 15. If endOfLastMatch < the length of string, then
         if end_of_last_match < string.len() {
             // a. Set result to the string-concatenation of result and the substring of string from endOfLastMatch.
-            result.extend_from_slice(&string[end_of_last_match..]);
+            result.extend(string.get_expect(end_of_last_match..).iter());
         }
 
         // 16. Return result.
@@ -1278,7 +1286,7 @@ impl String {
 
         // 8. Return 𝔽(! StringIndexOf(S, searchStr, start)).
         Ok(string
-            .index_of(&search_str, start)
+            .index_of(search_str.as_str(), start)
             .map_or(-1, |i| i as i64)
             .into())
     }
@@ -1368,10 +1376,10 @@ impl String {
             // 11. For each non-negative integer i starting with start such that i ≤ len - searchLen, in descending order, do
             for i in (0..=min(start, end)).rev() {
                 // a. Let candidate be the substring of S from i to i + searchLen.
-                let candidate = &string[i..i + search_len];
+                let candidate = string.get_expect(i..i + search_len);
 
                 // b. If candidate is the same sequence of code units as searchStr, return 𝔽(i).
-                if candidate == &search_str {
+                if candidate == search_str {
                     return Ok(i.into());
                 }
             }
@@ -1431,7 +1439,7 @@ impl String {
                     .expect("constructor must return a `Collator` object")
                     .collator();
 
-                collator.compare_utf16(&s, &that_value) as i8
+                collator.compare_utf16(&s.to_vec(), &that_value.to_vec()) as i8
             }
 
             // Default to common comparison if the user doesn't have `Intl` enabled.
@@ -1544,7 +1552,7 @@ impl String {
             }
         };
 
-        let truncated_string_filler = filler.repeat(repetitions as usize);
+        let truncated_string_filler = filler.to_vec().repeat(repetitions as usize);
         let truncated_string_filler = &truncated_string_filler[..fill_len as usize];
 
         // 10. If placement is start, return the string-concatenation of truncatedStringFiller and S.
@@ -1627,7 +1635,7 @@ impl String {
         // 2. Return ? TrimString(S, start+end).
         let object = this.require_object_coercible()?;
         let string = object.to_string(context)?;
-        Ok(js_string!(string.trim()).into())
+        Ok(js_string!(string.as_str().trim()).into())
     }
 
     /// `String.prototype.trimStart()`
@@ -1651,7 +1659,7 @@ impl String {
         // 2. Return ? TrimString(S, start).
         let object = this.require_object_coercible()?;
         let string = object.to_string(context)?;
-        Ok(js_string!(string.trim_start()).into())
+        Ok(js_string!(string.as_str().trim_start()).into())
     }
 
     /// `String.prototype.trimEnd()`
@@ -1675,7 +1683,7 @@ impl String {
         // 2. Return ? TrimString(S, end).
         let object = this.require_object_coercible()?;
         let string = object.to_string(context)?;
-        Ok(js_string!(string.trim_end()).into())
+        Ok(js_string!(string.as_str().trim_end()).into())
     }
 
     /// [`String.prototype.toUpperCase()`][upper] and [`String.prototype.toLowerCase()`][lower]
@@ -1876,7 +1884,7 @@ impl String {
         let to = max(final_start, final_end);
 
         // 10. Return the substring of S from from to to.
-        Ok(js_string!(&string[from..to]).into())
+        Ok(js_string!(string.get_expect(from..to)).into())
     }
 
     /// `String.prototype.split ( separator, limit )`
@@ -1946,9 +1954,9 @@ impl String {
             // b. Let codeUnits be a List consisting of the sequence of code units that are the elements of head.
             let head = this_str
                 .get(..lim)
-                .unwrap_or(&this_str[..])
+                .unwrap_or(this_str.as_str())
                 .iter()
-                .map(|code| js_string!(std::slice::from_ref(code)).into());
+                .map(|code| js_string!(&[code]).into());
             // c. Return ! CreateArrayFromList(codeUnits).
             return Ok(Array::create_array_from_list(head, context).into());
         }
@@ -1965,13 +1973,13 @@ impl String {
         let mut i = 0;
 
         // 13. Let j be ! StringIndexOf(S, R, 0).
-        let mut j = this_str.index_of(&separator_str, 0);
+        let mut j = this_str.index_of(separator_str.as_str(), 0);
 
         // 14. Repeat, while j is not -1
         while let Some(index) = j {
             // a. Let T be the substring of S from i to j.
             // b. Append T as the last element of substrings.
-            substrings.push(js_string!(&this_str[i..index]));
+            substrings.push(js_string!(this_str.get_expect(i..index)));
 
             // c. If the number of elements of substrings is lim, return ! CreateArrayFromList(substrings).
             if substrings.len() == lim {
@@ -1985,12 +1993,12 @@ impl String {
             i = index + separator_length;
 
             // e. Set j to ! StringIndexOf(S, R, i).
-            j = this_str.index_of(&separator_str, i);
+            j = this_str.index_of(separator_str.as_str(), i);
         }
 
         // 15. Let T be the substring of S from i.
         // 16. Append T to substrings.
-        substrings.push(js_string!(&this_str[i..]));
+        substrings.push(js_string!(this_str.get_expect(i..)));
 
         // 17. Return ! CreateArrayFromList(substrings).
         Ok(
@@ -2051,7 +2059,7 @@ impl String {
                 flags.require_object_coercible()?;
 
                 // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
-                if !flags.to_string(context)?.contains(&u16::from(b'g')) {
+                if !flags.to_string(context)?.as_str().contains(u16::from(b'g')) {
                     return Err(JsNativeError::typ()
                         .with_message(
                             "String.prototype.matchAll called with a non-global RegExp argument",
@@ -2148,10 +2156,10 @@ impl String {
         };
 
         let result = match normalization {
-            Normalization::Nfc => normalizers.nfc.normalize_utf16(&s),
-            Normalization::Nfd => normalizers.nfd.normalize_utf16(&s),
-            Normalization::Nfkc => normalizers.nfkc.normalize_utf16(&s),
-            Normalization::Nfkd => normalizers.nfkd.normalize_utf16(&s),
+            Normalization::Nfc => normalizers.nfc.normalize_utf16(&s.to_vec()),
+            Normalization::Nfd => normalizers.nfd.normalize_utf16(&s.to_vec()),
+            Normalization::Nfkc => normalizers.nfkc.normalize_utf16(&s.to_vec()),
+            Normalization::Nfkd => normalizers.nfkd.normalize_utf16(&s.to_vec()),
         };
 
         // 7. Return ns.
@@ -2301,7 +2309,7 @@ impl String {
         let s = str.to_string(context)?;
 
         // 3. Let p1 be the string-concatenation of "<" and tag.
-        let mut p1 = JsString::concat_array(&[utf16!("<"), tag]);
+        let mut p1 = JsString::concat_array(&[utf16!("<").into(), tag.into()]);
 
         // 4. If attribute is not the empty String, then
         if let Some((attribute, value)) = attribute_and_value {
@@ -2312,7 +2320,7 @@ impl String {
             //    of the code unit 0x0022 (QUOTATION MARK) in V has been replaced with the six
             //    code unit sequence "&quot;".
             let mut escaped_v = Vec::with_capacity(v.len());
-            for c in v.as_slice().iter().copied() {
+            for c in &v {
                 if c == 0x0022 {
                     escaped_v.extend(utf16!("&quot;"));
                     continue;
@@ -2329,12 +2337,12 @@ impl String {
             //    escapedV
             //    the code unit 0x0022 (QUOTATION MARK)
             p1 = JsString::concat_array(&[
-                p1.as_slice(),
-                utf16!(" "),
-                attribute,
-                utf16!("=\""),
-                escaped_v.as_slice(),
-                utf16!("\""),
+                p1.as_str(),
+                utf16!(" ").into(),
+                attribute.into(),
+                utf16!("=\"").into(),
+                escaped_v.as_slice().into(),
+                utf16!("\"").into(),
             ]);
         }
 
@@ -2342,12 +2350,12 @@ impl String {
         // 6. Let p3 be the string-concatenation of p2 and S.
         // 7. Let p4 be the string-concatenation of p3, "</", tag, and ">".
         let p4 = JsString::concat_array(&[
-            p1.as_slice(),
-            utf16!(">"),
-            s.as_slice(),
-            utf16!("</"),
-            tag,
-            utf16!(">"),
+            p1.as_str(),
+            utf16!(">").into(),
+            s.as_str(),
+            utf16!("</").into(),
+            tag.into(),
+            utf16!(">").into(),
         ]);
 
         // 8. Return p4.
@@ -2581,192 +2589,3 @@ impl String {
         Self::create_html(s, utf16!("sup"), None, context)
     }
 }
-
-/// Abstract operation `GetSubstitution ( matched, str, position, captures, namedCaptures, replacement )`
-///
-/// More information:
-///  - [ECMAScript reference][spec]
-///
-/// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
-pub(crate) fn get_substitution(
-    matched: &JsString,
-    str: &JsString,
-    position: usize,
-    captures: &[JsValue],
-    named_captures: &JsValue,
-    replacement: &JsString,
-    context: &mut Context,
-) -> JsResult<JsString> {
-    let mut buf = [0; 2];
-    // 1. Assert: Type(matched) is String.
-
-    // 2. Let matchLength be the number of code units in matched.
-    let match_length = matched.len();
-
-    // 3. Assert: Type(str) is String.
-
-    // 4. Let stringLength be the number of code units in str.
-    let str_length = str.len();
-
-    // 5. Assert: position ≤ stringLength.
-    // 6. Assert: captures is a possibly empty List of Strings.
-    // 7. Assert: Type(replacement) is String.
-
-    // 8. Let tailPos be position + matchLength.
-    let tail_pos = position + match_length;
-
-    // 10. Let result be the String value derived from replacement by copying code unit elements
-    //     from replacement to result while performing replacements as specified in Table 58.
-    //     These $ replacements are done left-to-right, and, once such a replacement is performed,
-    //     the new replacement text is not subject to further replacements.
-    let mut result = vec![];
-    let mut chars = replacement.code_points().peekable();
-
-    while let Some(first) = chars.next() {
-        if first == CodePoint::Unicode('$') {
-            let second = chars.next();
-            let second_is_digit = second
-                .and_then(CodePoint::as_char)
-                .as_ref()
-                .map_or(false, char::is_ascii_digit);
-
-            match second {
-                // $$
-                Some(CodePoint::Unicode('$')) => {
-                    // $
-                    result.push('$' as u16);
-                }
-                // $&
-                Some(CodePoint::Unicode('&')) => {
-                    // matched
-                    result.extend_from_slice(matched);
-                }
-                // $`
-                Some(CodePoint::Unicode('`')) => {
-                    // The replacement is the substring of str from 0 to position.
-                    result.extend_from_slice(&str[..position]);
-                }
-                // $'
-                Some(CodePoint::Unicode('\'')) => {
-                    // If tailPos ≥ stringLength, the replacement is the empty String.
-                    // Otherwise the replacement is the substring of str from tailPos.
-                    if tail_pos < str_length {
-                        result.extend_from_slice(&str[tail_pos..]);
-                    }
-                }
-                // $nn
-                // f. Else if templateRemainder starts with "$" followed by 1 or more decimal digits, then
-                Some(CodePoint::Unicode(second)) if second_is_digit => {
-                    // i. If templateRemainder starts with "$" followed by 2 or more decimal digits, let digitCount be 2. Otherwise, let digitCount be 1.
-                    // ii. Let ref be the substring of templateRemainder from 0 to 1 + digitCount.
-                    // iii. Let digits be the substring of templateRemainder from 1 to 1 + digitCount.
-                    // iv. Let index be ℝ(StringToNumber(digits)).
-                    let mut index = second
-                        .to_digit(10)
-                        .expect("could not convert character to digit after checking it")
-                        as usize;
-
-                    // vi. Let captureLen be the number of elements in captures.
-                    let capture_len = captures.len();
-
-                    // NOTE(HalidOdat): We deviate from the spec, because of a bug in GetSubstitutions
-                    //
-                    // See: https://github.com/tc39/ecma262/issues/1426
-                    if let Some(digit) = chars
-                        .peek()
-                        .copied()
-                        .and_then(CodePoint::as_char)
-                        .and_then(|n| n.to_digit(10))
-                    {
-                        // If there is two digits, and it's not in range fallback to one digit.
-                        let two_digit_index = index * 10 + digit as usize;
-                        if (1..=capture_len).contains(&two_digit_index) {
-                            index = two_digit_index;
-                            chars.next();
-                        }
-                    }
-
-                    // v. Assert: 0 ≤ index ≤ 99.
-                    debug_assert!((0..=99).contains(&index));
-
-                    // vii. If 1 ≤ index ≤ captureLen, then
-                    if (1..=capture_len).contains(&index) {
-                        // 1. Let capture be captures[index - 1].
-                        // 2. If capture is undefined, then
-                        //     a. Let refReplacement be the empty String.
-                        // 3. Else,
-                        //     a. Let refReplacement be capture.
-                        if let Some(capture) = captures.get(index - 1) {
-                            if let Some(s) = capture.as_string() {
-                                result.extend_from_slice(s);
-                            }
-                        }
-
-                    // viii. Else,
-                    } else {
-                        // 1. Let refReplacement be ref.
-                        result.extend_from_slice(&['$' as u16, second as u16]);
-                    }
-                }
-                // $<
-                Some(CodePoint::Unicode('<')) => {
-                    // 1. If namedCaptures is undefined, the replacement text is the String "$<".
-                    // 2. Else,
-                    if named_captures.is_undefined() {
-                        result.extend_from_slice(utf16!("$<"));
-                    } else {
-                        // a. Assert: Type(namedCaptures) is Object.
-                        let named_captures = named_captures
-                            .as_object()
-                            .expect("should be an object according to spec");
-
-                        // b. Scan until the next > U+003E (GREATER-THAN SIGN).
-                        let mut group_name = vec![];
-                        let mut found = false;
-                        loop {
-                            match chars.next() {
-                                Some(CodePoint::Unicode('>')) => {
-                                    found = true;
-                                    break;
-                                }
-                                Some(c) => group_name.extend_from_slice(c.encode_utf16(&mut buf)),
-                                None => break,
-                            }
-                        }
-
-                        // c. If none is found, the replacement text is the String "$<".
-                        #[allow(clippy::if_not_else)]
-                        if !found {
-                            result.extend_from_slice(utf16!("$<"));
-                            result.extend_from_slice(&group_name);
-                        // d. Else,
-                        } else {
-                            // i. Let groupName be the enclosed substring.
-                            let group_name = js_string!(group_name);
-                            // ii. Let capture be ? Get(namedCaptures, groupName).
-                            let capture = named_captures.get(group_name, context)?;
-
-                            // iii. If capture is undefined, replace the text through > with the empty String.
-                            // iv. Otherwise, replace the text through > with ? ToString(capture).
-                            if !capture.is_undefined() {
-                                result.extend_from_slice(&capture.to_string(context)?);
-                            }
-                        }
-                    }
-                }
-                // $?, ? is none of the above
-                _ => {
-                    result.push('$' as u16);
-                    if let Some(second) = second {
-                        result.extend_from_slice(second.encode_utf16(&mut buf));
-                    }
-                }
-            }
-        } else {
-            result.extend_from_slice(first.encode_utf16(&mut buf));
-        }
-    }
-
-    // 11. Return result.
-    Ok(js_string!(result))
-}
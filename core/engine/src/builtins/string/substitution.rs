@@ -0,0 +1,243 @@
+//! The `GetSubstitution` abstract operation, shared by the `replace` methods of strings and
+//! regular expressions.
+
+use crate::{js_string, Context, JsResult, JsString, JsValue};
+
+/// A part of a replacement template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TemplatePart<'a> {
+    /// Text that is copied as is, including the references that don't refer to anything.
+    Literal(&'a [u16]),
+
+    /// `$&`
+    Matched,
+
+    /// `` $` ``
+    Before,
+
+    /// `$'`
+    After,
+
+    /// `$n` or `$nn`, where the index is in the range of the captures.
+    Capture(usize),
+
+    /// `$<name>`, if there are named captures.
+    NamedCapture(&'a [u16]),
+}
+
+/// Splits a replacement template into the references and literal text that replace them, as
+/// defined by `GetSubstitution`.
+#[derive(Debug, Clone)]
+struct Template<'a> {
+    remainder: &'a [u16],
+    capture_count: usize,
+    has_named_captures: bool,
+}
+
+impl<'a> Iterator for Template<'a> {
+    type Item = TemplatePart<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        const DOLLAR: u16 = b'$' as u16;
+
+        let remainder = self.remainder;
+        if remainder.is_empty() {
+            return None;
+        }
+
+        let digit = |index: usize| {
+            remainder
+                .get(index)
+                .and_then(|c| char::from_u32(u32::from(*c)))
+                .and_then(|c| c.to_digit(10))
+                .map(|digit| digit as usize)
+        };
+
+        // a. NOTE: The following steps isolate ref (a prefix of templateRemainder), determine
+        //    refReplacement (its replacement), and then append that replacement to result.
+        let (length, part) = match remainder {
+            [DOLLAR, second, ..] => match u8::try_from(*second).map(char::from) {
+                // b. If templateRemainder starts with "$$", then
+                //     i. Let ref be "$$".
+                //     ii. Let refReplacement be "$".
+                Ok('$') => (2, TemplatePart::Literal(&remainder[..1])),
+
+                // c. Else if templateRemainder starts with "$`", then
+                Ok('`') => (2, TemplatePart::Before),
+
+                // d. Else if templateRemainder starts with "$&", then
+                Ok('&') => (2, TemplatePart::Matched),
+
+                // e. Else if templateRemainder starts with "$'", then
+                Ok('\'') => (2, TemplatePart::After),
+
+                // f. Else if templateRemainder starts with "$" followed by 1 or more decimal digits, then
+                Ok('0'..='9') => {
+                    let first = digit(1).expect("the second code unit must be a digit");
+
+                    // i. If templateRemainder starts with "$" followed by 2 or more decimal
+                    //    digits, let digitCount be 2. Otherwise, let digitCount be 1.
+                    // iii. Let index be ℝ(StringToNumber(digits)).
+                    // vi. If index > captureLen and digitCount = 2, then
+                    //     1. NOTE: When a two-digit replacement pattern specifies an index
+                    //        exceeding the count of capturing groups, it is reinterpreted as a
+                    //        one-digit replacement pattern followed by a literal digit.
+                    //     2. Set digitCount to 1.
+                    let (digit_count, index) = match digit(2) {
+                        Some(second) if first * 10 + second <= self.capture_count => {
+                            (2, first * 10 + second)
+                        }
+                        _ => (1, first),
+                    };
+
+                    // vii. Let ref be the substring of templateRemainder from 0 to 1 + digitCount.
+                    // viii. If 1 ≤ index ≤ captureLen, then
+                    // ix. Else,
+                    //     1. Let refReplacement be ref.
+                    let part = if (1..=self.capture_count).contains(&index) {
+                        TemplatePart::Capture(index)
+                    } else {
+                        TemplatePart::Literal(&remainder[..=digit_count])
+                    };
+                    (1 + digit_count, part)
+                }
+
+                // g. Else if templateRemainder starts with "$<", then
+                Ok('<') => {
+                    // i. Let gtPos be StringIndexOf(templateRemainder, ">", 0).
+                    let gt_pos = remainder.iter().position(|c| *c == u16::from(b'>'));
+                    match gt_pos {
+                        // iii. Else,
+                        //     1. Let ref be the substring of templateRemainder from 0 to gtPos + 1.
+                        //     2. Let groupName be the substring of templateRemainder from 2 to gtPos.
+                        Some(gt_pos) if self.has_named_captures => (
+                            gt_pos + 1,
+                            TemplatePart::NamedCapture(&remainder[2..gt_pos]),
+                        ),
+                        // ii. If gtPos = -1 or namedCaptures is undefined, then
+                        //     1. Let ref be "$<".
+                        //     2. Let refReplacement be ref.
+                        _ => (2, TemplatePart::Literal(&remainder[..2])),
+                    }
+                }
+
+                // h. Else,
+                //     i. Let ref be the substring of templateRemainder from 0 to 1.
+                //     ii. Let refReplacement be ref.
+                _ => (1, TemplatePart::Literal(&remainder[..1])),
+            },
+            [DOLLAR] => (1, TemplatePart::Literal(remainder)),
+
+            // NOTE: The text up to the next "$" can't contain any references.
+            _ => {
+                let end = remainder
+                    .iter()
+                    .position(|c| *c == DOLLAR)
+                    .unwrap_or(remainder.len());
+                (end, TemplatePart::Literal(&remainder[..end]))
+            }
+        };
+
+        // i. Let refLength be the length of ref.
+        // j. Set templateRemainder to the substring of templateRemainder from refLength.
+        self.remainder = &remainder[length..];
+        Some(part)
+    }
+}
+
+/// Abstract operation `GetSubstitution ( matched, str, position, captures, namedCaptures, replacementTemplate )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
+pub(crate) fn get_substitution(
+    matched: &JsString,
+    str: &JsString,
+    position: usize,
+    captures: &[JsValue],
+    named_captures: &JsValue,
+    replacement_template: &JsString,
+    context: &mut Context,
+) -> JsResult<JsString> {
+    // 1. Let stringLength be the length of str.
+    let string_length = str.len();
+
+    // 2. Assert: position ≤ stringLength.
+    debug_assert!(position <= string_length);
+
+    // 3. Let result be the empty String.
+    let mut result = Vec::with_capacity(replacement_template.len());
+
+    // 4. Let templateRemainder be replacementTemplate.
+    let replacement_template = replacement_template.to_vec();
+    let template = Template {
+        remainder: &replacement_template,
+        capture_count: captures.len(),
+        has_named_captures: !named_captures.is_undefined(),
+    };
+
+    // 5. Repeat, while templateRemainder is not the empty String,
+    for part in template {
+        // k. Set result to the string-concatenation of result and refReplacement.
+        match part {
+            TemplatePart::Literal(text) => result.extend_from_slice(text),
+
+            // d. Else if templateRemainder starts with "$&", then
+            //     ii. Let refReplacement be matched.
+            TemplatePart::Matched => result.extend(matched.iter()),
+
+            // c. Else if templateRemainder starts with "$`", then
+            //     ii. Let refReplacement be the substring of str from 0 to position.
+            TemplatePart::Before => result.extend(str.get_expect(..position).iter()),
+
+            // e. Else if templateRemainder starts with "$'", then
+            TemplatePart::After => {
+                // ii. Let matchLength be the length of matched.
+                // iii. Let tailPos be position + matchLength.
+                let tail_pos = position + matched.len();
+
+                // iv. Let refReplacement be the substring of str from min(tailPos, stringLength).
+                // v. NOTE: tailPos can exceed stringLength only if this abstract operation was
+                //    invoked by a call to the intrinsic @@replace method of %RegExp.prototype% on
+                //    an object whose "exec" property is not the intrinsic %RegExp.prototype.exec%.
+                result.extend(str.get_expect(tail_pos.min(string_length)..).iter());
+            }
+
+            // viii. If 1 ≤ index ≤ captureLen, then
+            TemplatePart::Capture(index) => {
+                // 1. Let capture be captures[index - 1].
+                // 2. If capture is undefined, then
+                //     a. Let refReplacement be the empty String.
+                // 3. Else,
+                //     a. Let refReplacement be capture.
+                if let Some(capture) = captures[index - 1].as_string() {
+                    result.extend(capture.iter());
+                }
+            }
+
+            // g. Else if templateRemainder starts with "$<", then
+            //     iii. Else,
+            TemplatePart::NamedCapture(group_name) => {
+                // 3. Assert: namedCaptures is an Object.
+                let named_captures = named_captures
+                    .as_object()
+                    .expect("should be an object according to spec");
+
+                // 4. Let capture be ? Get(namedCaptures, groupName).
+                let capture = named_captures.get(js_string!(group_name), context)?;
+
+                // 5. If capture is undefined, then
+                //     a. Let refReplacement be the empty String.
+                // 6. Else,
+                //     a. Let refReplacement be ? ToString(capture).
+                if !capture.is_undefined() {
+                    result.extend(capture.to_string(context)?.iter());
+                }
+            }
+        }
+    }
+
+    // 6. Return result.
+    Ok(js_string!(result))
+}
@@ -207,6 +207,37 @@ fn replace_substitutions() {
     ]);
 }
 
+#[test]
+fn replace_named_substitutions() {
+    run_test_actions([
+        TestAction::assert_eq(
+            r#""abc".replace(/(?<n>b)/, "[$<n>|$<missing>]")"#,
+            js_string!("a[b|]c"),
+        ),
+        TestAction::assert_eq(
+            r#""abc".replace(/(?<n>b)/, "[$<n|$1]")"#,
+            js_string!("a[$<n|b]c"),
+        ),
+        TestAction::assert_eq(r#""abc".replace(/(b)/, "[$<n>]")"#, js_string!("a[$<n>]c")),
+        TestAction::assert_eq(
+            r#""abc".replace("b", "[$<n>|$1|$$]")"#,
+            js_string!("a[$<n>|$1|$]c"),
+        ),
+    ]);
+}
+
+#[test]
+fn replace_numbered_substitutions() {
+    run_test_actions([
+        TestAction::assert_eq(
+            r#""abc".replace(/(b)/, "$01|$10|$2|$0")"#,
+            js_string!("ab|b0|$2|$0c"),
+        ),
+        TestAction::assert_eq(r#""abc".replace(/(b)|(x)/, "[$2]")"#, js_string!("a[]c")),
+        TestAction::assert_eq(r#""abc".replace(/b/, "$")"#, js_string!("a$c")),
+    ]);
+}
+
 #[test]
 fn replace_with_function() {
     run_test_actions([
@@ -918,3 +949,42 @@ fn from_code_point() {
         ),
     ]);
 }
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn html_methods() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "'x'.anchor('a\"b')",
+            js_string!("<a name=\"a&quot;b\">x</a>"),
+        ),
+        TestAction::assert_eq(
+            "'x'.fontcolor('\"')",
+            js_string!("<font color=\"&quot;\">x</font>"),
+        ),
+        TestAction::assert_eq("'x'.fontsize(7)", js_string!("<font size=\"7\">x</font>")),
+        TestAction::assert_eq("'x'.link('a.html')", js_string!("<a href=\"a.html\">x</a>")),
+        TestAction::assert_eq("'x'.big()", js_string!("<big>x</big>")),
+        TestAction::assert_eq("'x'.blink()", js_string!("<blink>x</blink>")),
+        TestAction::assert_eq("'x'.sup()", js_string!("<sup>x</sup>")),
+        TestAction::assert_eq("'\"x\"'.bold()", js_string!("<b>\"x\"</b>")),
+        TestAction::assert_native_error(
+            "String.prototype.big.call(undefined)",
+            JsNativeErrorKind::Type,
+            "cannot convert null or undefined to Object",
+        ),
+    ]);
+}
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn substr_and_trim_aliases() {
+    run_test_actions([
+        TestAction::assert_eq("'abcdef'.substr(1, 3)", js_string!("bcd")),
+        TestAction::assert_eq("'abcdef'.substr(-2)", js_string!("ef")),
+        TestAction::assert_eq("'abcdef'.substr(2, -1)", js_string!("")),
+        TestAction::assert("String.prototype.trimLeft === String.prototype.trimStart"),
+        TestAction::assert("String.prototype.trimRight === String.prototype.trimEnd"),
+        TestAction::assert_eq("String.prototype.trimLeft.name", js_string!("trimStart")),
+    ]);
+}
@@ -0,0 +1,632 @@
+//! Boa's implementation of the `structuredClone` global function.
+//!
+//! The `structuredClone()` function creates a deep clone of a value using the structured clone
+//! algorithm, optionally transferring the ownership of `ArrayBuffer`s to the clone.
+//!
+//! Serialization and deserialization are done in a single pass, since the clone is always
+//! created in the same realm as the original value.
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#safe-passing-of-structured-data
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        array_buffer::{ArrayBuffer, BufferObject, SharedArrayBuffer},
+        error::ErrorObject,
+        iterable::iterable_to_list,
+        map::ordered_map::OrderedMap,
+        set::ordered_set::OrderedSet,
+        typed_array::TypedArray,
+        Array, BuiltInBuilder, BuiltInObject, DataView, Date, IntrinsicObject, OrdinaryObject,
+        Proxy, RegExp,
+    },
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::internal_methods::InternalMethodContext,
+    property::{PropertyDescriptor, PropertyKey, PropertyNameKind},
+    realm::Realm,
+    string::common::StaticJsStrings,
+    Context, JsArgs, JsBigInt, JsError, JsObject, JsResult, JsString, JsValue,
+};
+use rustc_hash::FxHashMap;
+
+/// The `structuredClone` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StructuredClone;
+
+impl IntrinsicObject for StructuredClone {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::callable_with_intrinsic::<Self>(realm, structured_clone)
+            .name(Self::NAME)
+            .length(1)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().structured_clone().into()
+    }
+}
+
+impl BuiltInObject for StructuredClone {
+    const NAME: JsString = StaticJsStrings::STRUCTURED_CLONE;
+}
+
+/// Creates the error thrown when a value cannot be cloned.
+///
+/// HTML throws a `DataCloneError` `DOMException`, which is represented as a `TypeError` here.
+fn data_clone_error(message: &'static str) -> JsError {
+    JsNativeError::typ()
+        .with_message(format!("DataCloneError: {message}"))
+        .into()
+}
+
+/// `structuredClone ( value [, options] )`
+///
+/// More information:
+///  - [HTML reference][spec]
+///
+/// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+fn structured_clone(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0);
+    let options = args.get_or_undefined(1);
+
+    // `options.transfer` is a `sequence<object>` which defaults to the empty sequence.
+    let transfer_list = if options.is_null_or_undefined() {
+        Vec::new()
+    } else {
+        let Some(options) = options.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("structuredClone: `options` must be an object")
+                .into());
+        };
+        let transfer = options.get(js_string!("transfer"), context)?;
+        if transfer.is_undefined() {
+            Vec::new()
+        } else if transfer.is_object() {
+            iterable_to_list(context, &transfer, None)?
+        } else {
+            return Err(JsNativeError::typ()
+                .with_message("structuredClone: `options.transfer` must be an iterable object")
+                .into());
+        }
+    };
+
+    let mut cloner = Cloner::default();
+
+    // StructuredSerializeWithTransfer ( value, transferList )
+    // https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializewithtransfer
+
+    // 1. Let memory be an empty map.
+    // 2. For each transferable of transferList:
+    for transferable in transfer_list {
+        // a. If transferable has neither an [[ArrayBufferData]] internal slot nor a [[Detached]]
+        //    internal slot, then throw a "DataCloneError" DOMException.
+        // b. If transferable has an [[ArrayBufferData]] internal slot and IsSharedArrayBuffer(transferable)
+        //    is true, then throw a "DataCloneError" DOMException.
+        let Some(buffer) = transferable
+            .as_object()
+            .and_then(|o| o.clone().downcast::<ArrayBuffer>().ok())
+        else {
+            return Err(data_clone_error("only ArrayBuffers can be transferred"));
+        };
+
+        // c. If memory[transferable] exists, then throw a "DataCloneError" DOMException.
+        let source = buffer.clone().upcast();
+        if cloner.memory.contains_key(&source) {
+            return Err(data_clone_error(
+                "an ArrayBuffer cannot be transferred twice",
+            ));
+        }
+
+        // d. Set memory[transferable] to { [[Type]]: an uninitialized value }.
+        // Step 4.a of the serialization steps is done here, since it doesn't depend on the
+        // order of the transfers.
+        {
+            let buffer = buffer.borrow();
+            if buffer.data.is_detached() {
+                return Err(data_clone_error(
+                    "a detached ArrayBuffer cannot be transferred",
+                ));
+            }
+            if buffer.data.check_detach_key(&JsValue::undefined()).is_err() {
+                return Err(data_clone_error(
+                    "an ArrayBuffer with a detach key cannot be transferred",
+                ));
+            }
+        }
+        let target = ArrayBuffer::allocate(
+            &context
+                .intrinsics()
+                .constructors()
+                .array_buffer()
+                .constructor()
+                .into(),
+            0,
+            None,
+            context,
+        )?;
+        cloner.memory.insert(source, target.clone().upcast());
+        cloner.transferred.push((buffer, target));
+    }
+
+    // 3. Let serialized be ? StructuredSerializeInternal(value, false, memory).
+    let clone = cloner.clone_value(value, context)?;
+
+    // 4. For each transferable of transferList:
+    //     a. If transferable has an [[ArrayBufferData]] internal slot and IsDetachedBuffer(transferable)
+    //        is true, then throw a "DataCloneError" DOMException.
+    //     b. ...
+    //     c. Let dataHolder be memory[transferable].
+    //     d. If transferable has an [[ArrayBufferData]] internal slot, then:
+    //         i. ...
+    //         ii. Perform ? DetachArrayBuffer(transferable).
+    for (source, target) in cloner.transferred {
+        let data = source
+            .borrow_mut()
+            .data
+            .take()
+            .ok_or_else(|| data_clone_error("a detached ArrayBuffer cannot be transferred"))?;
+        target.borrow_mut().data = data;
+    }
+
+    Ok(clone)
+}
+
+/// The state of a structured clone.
+#[derive(Default)]
+struct Cloner {
+    /// The map from the original objects to their clones, used to preserve cycles and
+    /// shared references.
+    memory: FxHashMap<JsObject, JsObject>,
+
+    /// The source and target buffers of the transferred `ArrayBuffer`s.
+    transferred: Vec<(JsObject<ArrayBuffer>, JsObject<ArrayBuffer>)>,
+}
+
+impl Cloner {
+    /// Abstract operation [`StructuredSerializeInternal ( value, forStorage [, memory ] )`][spec],
+    /// immediately followed by its deserialization.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializeinternal
+    fn clone_value(&mut self, value: &JsValue, context: &mut Context) -> JsResult<JsValue> {
+        // 1. If memory was not supplied, let memory be an empty map.
+        // 2. If memory[value] exists, then return memory[value].
+        // 3. Let deep be false.
+        // 4. If value is undefined, null, a Boolean, a Number, a BigInt, or a String, then
+        //    return { [[Type]]: "primitive", [[Value]]: value }.
+        // 5. If value is a Symbol, then throw a "DataCloneError" DOMException.
+        let object = match value {
+            JsValue::Object(object) => object,
+            JsValue::Symbol(_) => return Err(data_clone_error("symbols cannot be cloned")),
+            _ => return Ok(value.clone()),
+        };
+
+        if let Some(clone) = self.memory.get(object) {
+            return Ok(clone.clone().into());
+        }
+
+        let clone = self.clone_object(object, context)?;
+        Ok(clone.into())
+    }
+
+    /// Clones an object which hasn't been cloned yet.
+    fn clone_object(&mut self, object: &JsObject, context: &mut Context) -> JsResult<JsObject> {
+        // 6. Let serialized be an uninitialized value.
+        // 7. If value has a [[BooleanData]] internal slot, then set serialized to
+        //    { [[Type]]: "Boolean", [[BooleanData]]: value.[[BooleanData]] }.
+        // 8. Otherwise, if value has a [[NumberData]] internal slot, then set serialized to
+        //    { [[Type]]: "Number", [[NumberData]]: value.[[NumberData]] }.
+        // 9. Otherwise, if value has a [[BigIntData]] internal slot, then set serialized to
+        //    { [[Type]]: "BigInt", [[BigIntData]]: value.[[BigIntData]] }.
+        // 10. Otherwise, if value has a [[StringData]] internal slot, then set serialized to
+        //     { [[Type]]: "String", [[StringData]]: value.[[StringData]] }.
+        let primitive = if let Some(boolean) = object.downcast_ref::<bool>() {
+            Some(JsValue::from(*boolean))
+        } else if let Some(number) = object.downcast_ref::<f64>() {
+            Some(JsValue::from(*number))
+        } else if let Some(bigint) = object.downcast_ref::<JsBigInt>() {
+            Some(JsValue::from(bigint.clone()))
+        } else {
+            object
+                .downcast_ref::<JsString>()
+                .map(|string| JsValue::from(string.clone()))
+        };
+        if let Some(primitive) = primitive {
+            let clone = primitive.to_object(context)?;
+            self.memory.insert(object.clone(), clone.clone());
+            return Ok(clone);
+        }
+
+        // 11. Otherwise, if value has a [[DateValue]] internal slot, then set serialized to
+        //     { [[Type]]: "Date", [[DateValue]]: value.[[DateValue]] }.
+        let time_value = object.downcast_ref::<Date>().map(|date| date.time_value());
+        if let Some(time_value) = time_value {
+            let prototype = context.intrinsics().constructors().date().prototype();
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                prototype,
+                Date::new(time_value),
+            );
+            self.memory.insert(object.clone(), clone.clone());
+            return Ok(clone);
+        }
+
+        // 12. Otherwise, if value has a [[RegExpMatcher]] internal slot, then set serialized to
+        //     { [[Type]]: "RegExp", [[RegExpMatcher]]: value.[[RegExpMatcher]],
+        //       [[OriginalSource]]: value.[[OriginalSource]],
+        //       [[OriginalFlags]]: value.[[OriginalFlags]] }.
+        let regexp = object.downcast_ref::<RegExp>().map(|regexp| {
+            (
+                regexp.original_source().clone(),
+                regexp.original_flags().clone(),
+            )
+        });
+        if let Some((source, flags)) = regexp {
+            let clone = RegExp::create(&source.into(), &flags.into(), context)?
+                .as_object()
+                .expect("RegExpCreate must return an object")
+                .clone();
+            self.memory.insert(object.clone(), clone.clone());
+            return Ok(clone);
+        }
+
+        // 13. Otherwise, if value has an [[ArrayBufferData]] internal slot, then:
+        if let Some(buffer) = object.downcast_ref::<SharedArrayBuffer>() {
+            // a. If IsSharedArrayBuffer(value) is true, then:
+            //     ...
+            //     v. Otherwise, set serialized to { [[Type]]: "SharedArrayBuffer",
+            //        [[ArrayBufferData]]: value.[[ArrayBufferData]], ... }.
+            let data = buffer.clone();
+            drop(buffer);
+            let prototype = context
+                .intrinsics()
+                .constructors()
+                .shared_array_buffer()
+                .prototype();
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                prototype,
+                data,
+            );
+            self.memory.insert(object.clone(), clone.clone());
+            return Ok(clone);
+        }
+        if let Some(buffer) = object.downcast_ref::<ArrayBuffer>() {
+            // b. Otherwise:
+            //     i. If IsDetachedBuffer(value) is true, then throw a "DataCloneError" DOMException.
+            let Some(bytes) = buffer.bytes().map(<[u8]>::to_vec) else {
+                return Err(data_clone_error("a detached ArrayBuffer cannot be cloned"));
+            };
+            let max_byte_len = buffer.max_byte_len();
+            drop(buffer);
+
+            //     ii. Let size be value.[[ArrayBufferByteLength]].
+            //     iii. Let dataCopy be ? CreateByteDataBlock(size).
+            //     iv. Perform CopyDataBlockBytes(dataCopy, 0, value.[[ArrayBufferData]], 0, size).
+            //     v. If value has an [[ArrayBufferMaxByteLength]] internal slot, then set serialized to
+            //        { [[Type]]: "ResizableArrayBuffer", [[ArrayBufferData]]: dataCopy,
+            //          [[ByteLength]]: size, [[ArrayBufferMaxByteLength]]: value.[[ArrayBufferMaxByteLength]] }.
+            //     vi. Otherwise, set serialized to { [[Type]]: "ArrayBuffer",
+            //         [[ArrayBufferData]]: dataCopy, [[ArrayBufferByteLength]]: size }.
+            let clone = ArrayBuffer::allocate(
+                &context
+                    .intrinsics()
+                    .constructors()
+                    .array_buffer()
+                    .constructor()
+                    .into(),
+                bytes.len() as u64,
+                max_byte_len,
+                context,
+            )?;
+            clone
+                .borrow_mut()
+                .data
+                .bytes_mut()
+                .expect("a new buffer cannot be detached")
+                .copy_from_slice(&bytes);
+            let clone = clone.upcast();
+            self.memory.insert(object.clone(), clone.clone());
+            return Ok(clone);
+        }
+
+        // 14. Otherwise, if value has a [[ViewedArrayBuffer]] internal slot, then:
+        //     ...
+        //     c. Let buffer be the value of value's [[ViewedArrayBuffer]] internal slot.
+        //     d. Let bufferSerialized be ? StructuredSerializeInternal(buffer, forStorage, memory).
+        //     e. Assert: bufferSerialized.[[Type]] is "ArrayBuffer", "ResizableArrayBuffer",
+        //        "SharedArrayBuffer", or "GrowableSharedArrayBuffer".
+        //     f. If value has a [[DataView]] internal slot, then set serialized to
+        //        { [[Type]]: "ArrayBufferView", [[Constructor]]: "DataView", ... }.
+        //     g. Otherwise:
+        //         i. Assert: value has a [[TypedArrayName]] internal slot.
+        //         ii. Set serialized to { [[Type]]: "ArrayBufferView",
+        //             [[Constructor]]: value.[[TypedArrayName]], ... }.
+        let view = object.downcast_ref::<DataView>().map(|view| view.clone());
+        if let Some(view) = view {
+            let buffer = self.clone_buffer(&view.viewed_array_buffer, context)?;
+            let prototype = context.intrinsics().constructors().data_view().prototype();
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                prototype,
+                DataView {
+                    viewed_array_buffer: buffer,
+                    ..view
+                },
+            );
+            self.memory.insert(object.clone(), clone.clone());
+            return Ok(clone);
+        }
+        let array = object
+            .downcast_ref::<TypedArray>()
+            .map(|array| array.clone());
+        if let Some(array) = array {
+            let buffer = self.clone_buffer(array.viewed_array_buffer(), context)?;
+            let prototype =
+                (array.kind().standard_constructor())(context.intrinsics().constructors())
+                    .prototype();
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                prototype,
+                array.with_viewed_array_buffer(buffer),
+            );
+            self.memory.insert(object.clone(), clone.clone());
+            return Ok(clone);
+        }
+
+        // 15. Otherwise, if value has [[MapData]] internal slot, then:
+        let entries = object.downcast_ref::<OrderedMap<JsValue>>().map(|map| {
+            map.iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Vec<_>>()
+        });
+        if let Some(entries) = entries {
+            // a. Set serialized to { [[Type]]: "Map", [[MapData]]: a new empty List }.
+            // b. Set deep to true.
+            let prototype = context.intrinsics().constructors().map().prototype();
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                prototype,
+                <OrderedMap<JsValue>>::new(),
+            );
+            self.memory.insert(object.clone(), clone.clone());
+
+            // 26. If deep is true, then:
+            //     a. If value has a [[MapData]] internal slot, then:
+            //         i. Let copiedList be a new empty List.
+            //         ii. For each Record { [[Key]], [[Value]] } entry of value.[[MapData]]:
+            //             1. Let copiedEntry be a new Record { [[Key]]: entry.[[Key]], [[Value]]: entry.[[Value]] }.
+            //             2. If copiedEntry.[[Key]] is not the special value empty, append copiedEntry to copiedList.
+            //         iii. For each Record { [[Key]], [[Value]] } entry of copiedList:
+            //             1. Let serializedKey be ? StructuredSerializeInternal(entry.[[Key]], forStorage, memory).
+            //             2. Let serializedValue be ? StructuredSerializeInternal(entry.[[Value]], forStorage, memory).
+            //             3. Append { [[Key]]: serializedKey, [[Value]]: serializedValue } to serialized.[[MapData]].
+            for (key, value) in entries {
+                let key = self.clone_value(&key, context)?;
+                let value = self.clone_value(&value, context)?;
+                clone
+                    .downcast_mut::<OrderedMap<JsValue>>()
+                    .expect("must be a map")
+                    .insert(key, value);
+            }
+            return Ok(clone);
+        }
+
+        // 16. Otherwise, if value has [[SetData]] internal slot, then:
+        let values = object
+            .downcast_ref::<OrderedSet>()
+            .map(|set| set.iter().cloned().collect::<Vec<_>>());
+        if let Some(values) = values {
+            // a. Set serialized to { [[Type]]: "Set", [[SetData]]: a new empty List }.
+            // b. Set deep to true.
+            let prototype = context.intrinsics().constructors().set().prototype();
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                prototype,
+                OrderedSet::new(),
+            );
+            self.memory.insert(object.clone(), clone.clone());
+
+            // 26. If deep is true, then:
+            //     b. Otherwise, if value has a [[SetData]] internal slot, then:
+            //         i. Let copiedList be a new empty List.
+            //         ii. For each entry of value.[[SetData]]:
+            //             1. If entry is not the special value empty, append entry to copiedList.
+            //         iii. For each entry of copiedList:
+            //             1. Let serializedEntry be ? StructuredSerializeInternal(entry, forStorage, memory).
+            //             2. Append serializedEntry to serialized.[[SetData]].
+            for value in values {
+                let value = self.clone_value(&value, context)?;
+                clone
+                    .downcast_mut::<OrderedSet>()
+                    .expect("must be a set")
+                    .add(value);
+            }
+            return Ok(clone);
+        }
+
+        // 17. Otherwise, if value has an [[ErrorData]] internal slot and value is not a platform
+        //     object, then:
+        if object.is::<ErrorObject>() {
+            return self.clone_error(object, context);
+        }
+
+        // 18. Otherwise, if value is an Array exotic object, then:
+        if object.is::<Array>() {
+            // a. Let valueLenDescriptor be ? OrdinaryGetOwnProperty(value, "length").
+            // b. Let valueLen be valueLenDescriptor.[[Value]].
+            let len = object.length_of_array_like(context)?;
+
+            // c. Set serialized to { [[Type]]: "Array", [[Length]]: valueLen, [[Properties]]: a new empty List }.
+            // d. Set deep to true.
+            let clone = Array::array_create(len, None, context)?;
+            self.memory.insert(object.clone(), clone.clone());
+            self.clone_properties(object, &clone, context)?;
+            return Ok(clone);
+        }
+
+        // 19. Otherwise, if value is a platform object that is a serializable object, then ...
+        // 20. Otherwise, if value is a platform object, then throw a "DataCloneError" DOMException.
+        // 21. Otherwise, if IsCallable(value) is true, then throw a "DataCloneError" DOMException.
+        if object.is_callable() {
+            return Err(data_clone_error("functions cannot be cloned"));
+        }
+
+        // 22. Otherwise, if value has any internal slot other than [[Prototype]], [[Extensible]], or
+        //     [[PrivateElements]], then throw a "DataCloneError" DOMException.
+        if object.is::<Proxy>() {
+            return Err(data_clone_error("proxies cannot be cloned"));
+        }
+        if !object.is::<OrdinaryObject>() {
+            return Err(data_clone_error("the object cannot be cloned"));
+        }
+
+        // 23. Otherwise, if value is an exotic object and value is not the %Object.prototype%
+        //     intrinsic object associated with any realm, then throw a "DataCloneError" DOMException.
+        // Every remaining exotic object has its own internal slots, and was handled above.
+
+        // 24. Otherwise:
+        //     a. Set serialized to { [[Type]]: "Object", [[Properties]]: a new empty List }.
+        //     b. Set deep to true.
+        let clone = JsObject::with_object_proto(context.intrinsics());
+        self.memory.insert(object.clone(), clone.clone());
+        self.clone_properties(object, &clone, context)?;
+        Ok(clone)
+    }
+
+    /// Clones the viewed buffer of an `ArrayBuffer` view.
+    fn clone_buffer(
+        &mut self,
+        buffer: &BufferObject,
+        context: &mut Context,
+    ) -> JsResult<BufferObject> {
+        let buffer = self
+            .clone_value(&buffer.clone().into(), context)?
+            .as_object()
+            .expect("a cloned buffer must be an object")
+            .clone();
+
+        Ok(match buffer.downcast::<ArrayBuffer>() {
+            Ok(buffer) => BufferObject::Buffer(buffer),
+            Err(buffer) => BufferObject::SharedBuffer(
+                buffer
+                    .downcast::<SharedArrayBuffer>()
+                    .expect("a cloned buffer must be a buffer"),
+            ),
+        })
+    }
+
+    /// Clones an object with an `[[ErrorData]]` internal slot.
+    fn clone_error(&mut self, object: &JsObject, context: &mut Context) -> JsResult<JsObject> {
+        // a. Let name be ? Get(value, "name").
+        let name = object.get(js_string!("name"), context)?;
+
+        // b. If name is not one of "Error", "EvalError", "RangeError", "ReferenceError",
+        //    "SyntaxError", "TypeError", or "URIError", then set name to "Error".
+        let constructors = context.intrinsics().constructors();
+        let (prototype, tag) = match name.as_string().map(JsString::to_std_string_escaped) {
+            Some(name) if name == "EvalError" => {
+                (constructors.eval_error().prototype(), ErrorObject::Eval)
+            }
+            Some(name) if name == "RangeError" => {
+                (constructors.range_error().prototype(), ErrorObject::Range)
+            }
+            Some(name) if name == "ReferenceError" => (
+                constructors.reference_error().prototype(),
+                ErrorObject::Reference,
+            ),
+            Some(name) if name == "SyntaxError" => {
+                (constructors.syntax_error().prototype(), ErrorObject::Syntax)
+            }
+            Some(name) if name == "TypeError" => {
+                (constructors.type_error().prototype(), ErrorObject::Type)
+            }
+            Some(name) if name == "URIError" => {
+                (constructors.uri_error().prototype(), ErrorObject::Uri)
+            }
+            _ => (constructors.error().prototype(), ErrorObject::Error),
+        };
+
+        // c. Let valueMessageDesc be ? value.[[GetOwnProperty]]("message").
+        let message = object.__get_own_property__(
+            &js_string!("message").into(),
+            &mut InternalMethodContext::new(context),
+        )?;
+
+        // d. Let message be undefined.
+        // e. If IsDataDescriptor(valueMessageDesc) is true, then set message to
+        //    ? ToString(valueMessageDesc.[[Value]]).
+        let message = match message {
+            Some(desc) if desc.is_data_descriptor() => {
+                Some(desc.expect_value().to_string(context)?)
+            }
+            _ => None,
+        };
+
+        // f. Set serialized to { [[Type]]: "Error", [[Name]]: name, [[Message]]: message }.
+        let clone =
+            JsObject::from_proto_and_data_with_shared_shape(context.root_shape(), prototype, tag);
+        self.memory.insert(object.clone(), clone.clone());
+
+        if let Some(message) = message {
+            clone.create_non_enumerable_data_property_or_throw(
+                js_string!("message"),
+                message,
+                context,
+            );
+        }
+
+        // The `cause` of the error is also cloned if it is an own data property.
+        let cause = object.__get_own_property__(
+            &js_string!("cause").into(),
+            &mut InternalMethodContext::new(context),
+        )?;
+        if let Some(cause) = cause.filter(PropertyDescriptor::is_data_descriptor) {
+            let cause = self.clone_value(cause.expect_value(), context)?;
+            clone.create_non_enumerable_data_property_or_throw(js_string!("cause"), cause, context);
+        }
+
+        Ok(clone)
+    }
+
+    /// Clones the enumerable own string-keyed properties of an array or ordinary object.
+    fn clone_properties(
+        &mut self,
+        object: &JsObject,
+        clone: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 26. If deep is true, then:
+        //     c. Otherwise, if value is a platform object that is a serializable object, ...
+        //     d. Otherwise, for each key in ! EnumerableOwnProperties(value, key):
+        let keys = object.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+        for key in keys {
+            let key = PropertyKey::from(
+                key.as_string()
+                    .expect("EnumerableOwnPropertyNames only returns strings")
+                    .clone(),
+            );
+
+            // i. If ! HasOwnProperty(value, key) is true, then:
+            if object.has_own_property(key.clone(), context)? {
+                // 1. Let inputValue be ? value.[[Get]](key, value).
+                let value = object.get(key.clone(), context)?;
+
+                // 2. Let outputValue be ? StructuredSerializeInternal(inputValue, forStorage, memory).
+                let value = self.clone_value(&value, context)?;
+
+                // 3. Append { [[Key]]: key, [[Value]]: outputValue } to serialized.[[Properties]].
+                clone.create_data_property_or_throw(key, value, context)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn clone_values() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var original = {
+                number: 1,
+                bigint: 2n,
+                string: "three",
+                array: [1, , 3],
+                date: new Date(0),
+                regexp: /a+/gi,
+                map: new Map([[1, "one"]]),
+                set: new Set(["a"]),
+                error: new RangeError("message", { cause: 4 }),
+                boxed: new String("boxed"),
+            };
+            original.self = original;
+            original.map.set("self", original);
+            var clone = structuredClone(original);
+        "#}),
+        TestAction::assert("clone !== original"),
+        TestAction::assert("clone.self === clone"),
+        TestAction::assert("clone.map.get('self') === clone"),
+        TestAction::assert_eq("clone.number", 1),
+        TestAction::assert("clone.bigint === 2n"),
+        TestAction::assert_eq("clone.string", js_string!("three")),
+        TestAction::assert("Array.isArray(clone.array) && clone.array !== original.array"),
+        TestAction::assert("clone.array.length === 3 && !(1 in clone.array)"),
+        TestAction::assert("clone.date instanceof Date && clone.date.getTime() === 0"),
+        TestAction::assert_eq(
+            "clone.regexp.source + clone.regexp.flags",
+            js_string!("a+gi"),
+        ),
+        TestAction::assert_eq("clone.map.get(1)", js_string!("one")),
+        TestAction::assert("clone.set instanceof Set && clone.set.has('a')"),
+        TestAction::assert("clone.error instanceof RangeError"),
+        TestAction::assert_eq("clone.error.message", js_string!("message")),
+        TestAction::assert_eq("clone.error.cause", 4),
+        TestAction::assert("clone.boxed instanceof String && clone.boxed.valueOf() === 'boxed'"),
+    ]);
+}
+
+#[test]
+fn clone_buffers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var buffer = new ArrayBuffer(8);
+            var bytes = new Uint8Array(buffer, 2, 4);
+            bytes[0] = 42;
+            var view = new DataView(buffer);
+            var clone = structuredClone({ buffer, bytes, view });
+        "#}),
+        TestAction::assert("clone.buffer !== buffer && clone.buffer.byteLength === 8"),
+        TestAction::assert(
+            "clone.bytes.buffer === clone.buffer && clone.view.buffer === clone.buffer",
+        ),
+        TestAction::assert("clone.bytes instanceof Uint8Array && clone.bytes.byteOffset === 2"),
+        TestAction::assert_eq("clone.bytes.length", 4),
+        TestAction::assert_eq("clone.view.getUint8(2)", 42),
+        TestAction::assert_eq("buffer.byteLength", 8),
+        TestAction::run(indoc! {r#"
+            var transferred = structuredClone(bytes, { transfer: [buffer] });
+        "#}),
+        TestAction::assert_eq("buffer.byteLength", 0),
+        TestAction::assert_eq("bytes.length", 0),
+        TestAction::assert_eq("transferred.buffer.byteLength", 8),
+        TestAction::assert_eq("transferred[0]", 42),
+        TestAction::assert_native_error(
+            "structuredClone(buffer)",
+            JsNativeErrorKind::Type,
+            "DataCloneError: a detached ArrayBuffer cannot be cloned",
+        ),
+        TestAction::assert_native_error(
+            "var other = new ArrayBuffer(1); structuredClone(other, { transfer: [other, other] })",
+            JsNativeErrorKind::Type,
+            "DataCloneError: an ArrayBuffer cannot be transferred twice",
+        ),
+    ]);
+}
+
+#[test]
+fn uncloneable_values() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "structuredClone({ f() {} })",
+            JsNativeErrorKind::Type,
+            "DataCloneError: functions cannot be cloned",
+        ),
+        TestAction::assert_native_error(
+            "structuredClone([Symbol()])",
+            JsNativeErrorKind::Type,
+            "DataCloneError: symbols cannot be cloned",
+        ),
+        TestAction::assert_native_error(
+            "structuredClone(new Proxy({}, {}))",
+            JsNativeErrorKind::Type,
+            "DataCloneError: proxies cannot be cloned",
+        ),
+        TestAction::assert_native_error(
+            "var buffer = new ArrayBuffer(1); structuredClone([buffer, () => {}], { transfer: [buffer] })",
+            JsNativeErrorKind::Type,
+            "DataCloneError: functions cannot be cloned",
+        ),
+        TestAction::assert_eq("buffer.byteLength", 1),
+    ]);
+}
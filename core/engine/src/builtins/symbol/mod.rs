@@ -64,8 +64,8 @@ impl GlobalSymbolRegistry {
     }
 
     fn get_or_create_symbol(&self, key: &JsString) -> JsResult<JsSymbol> {
-        let slice = &**key;
-        if let Some(symbol) = self.keys.get(slice) {
+        let slice = key.to_vec();
+        if let Some(symbol) = self.keys.get(slice.as_slice()) {
             return Ok(symbol.clone());
         }
 
@@ -73,7 +73,7 @@ impl GlobalSymbolRegistry {
             JsNativeError::range()
                 .with_message("reached the maximum number of symbols that can be created")
         })?;
-        self.keys.insert(slice.into(), symbol.clone());
+        self.keys.insert(slice.clone().into(), symbol.clone());
         self.symbols.insert(symbol.clone(), slice.into());
         Ok(symbol)
     }
@@ -95,7 +95,9 @@ impl IntrinsicObject for Symbol {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
+        let symbol_async_dispose = JsSymbol::async_dispose();
         let symbol_async_iterator = JsSymbol::async_iterator();
+        let symbol_dispose = JsSymbol::dispose();
         let symbol_has_instance = JsSymbol::has_instance();
         let symbol_is_concat_spreadable = JsSymbol::is_concat_spreadable();
         let symbol_iterator = JsSymbol::iterator();
@@ -123,11 +125,13 @@ impl IntrinsicObject for Symbol {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::for_, js_string!("for"), 1)
             .static_method(Self::key_for, js_string!("keyFor"), 1)
+            .static_property(js_string!("asyncDispose"), symbol_async_dispose, attribute)
             .static_property(
                 js_string!("asyncIterator"),
                 symbol_async_iterator,
                 attribute,
             )
+            .static_property(js_string!("dispose"), symbol_dispose, attribute)
             .static_property(js_string!("hasInstance"), symbol_has_instance, attribute)
             .static_property(
                 js_string!("isConcatSpreadable"),
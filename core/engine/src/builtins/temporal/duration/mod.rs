@@ -187,6 +187,7 @@ impl IntrinsicObject for Duration {
             .method(Self::total, js_string!("total"), 1)
             .method(Self::to_string, js_string!("toString"), 1)
             .method(Self::to_json, js_string!("toJSON"), 0)
+            .method(Self::to_locale_string, js_string!("toLocaleString"), 0)
             .build();
     }
 
@@ -900,6 +901,48 @@ impl Duration {
             .with_message("not yet implemented.")
             .into())
     }
+
+    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        if this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .is_none()
+        {
+            return Err(JsNativeError::typ()
+                .with_message("this value must be a Duration object.")
+                .into());
+        }
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::DurationFormat;
+
+            // 3. Let formatter be ? Construct(%Intl.DurationFormat%, « locales, options »).
+            let constructor = context
+                .intrinsics()
+                .constructors()
+                .duration_format()
+                .constructor();
+            let formatter = DurationFormat::constructor(&constructor.into(), args, context)?;
+
+            // 4. Return ? FormatDurationFormatPattern(formatter, duration).
+            DurationFormat::format(&formatter, std::slice::from_ref(this), context)
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            // 3. Return TemporalDurationToString(duration, "auto").
+            let _ = args;
+            Self::to_string(this, &[], context)
+        }
+    }
 }
 
 // -- Duration Abstract Operations --
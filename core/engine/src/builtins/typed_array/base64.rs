@@ -0,0 +1,856 @@
+//! Boa's implementation of the `Uint8Array` base64 and hex conversion methods.
+//!
+//! More information:
+//!  - [Uint8Array to/from base64 proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/
+
+use std::sync::atomic::Ordering;
+
+use crate::{
+    builtins::{
+        array_buffer::utils::{memcpy, SliceRef, SliceRefMut},
+        builder::BuiltInConstructorWithPrototype,
+    },
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    Context, JsArgs, JsResult, JsString, JsValue,
+};
+
+use super::{BuiltinTypedArray, TypedArray, TypedArrayKind, Uint8Array};
+
+/// The standard base64 alphabet.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The URL and filename safe base64 alphabet.
+const BASE64_URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The maximum length of the decoded bytes if no target length is provided, `2^53 - 1`.
+const MAX_LENGTH: usize = (1 << 53) - 1;
+
+/// Adds the base64 and hex conversion methods to the `Uint8Array` constructor and prototype.
+pub(super) fn add_methods(
+    builder: BuiltInConstructorWithPrototype<'_>,
+) -> BuiltInConstructorWithPrototype<'_> {
+    builder
+        .static_method(from_base64, js_string!("fromBase64"), 1)
+        .static_method(from_hex, js_string!("fromHex"), 1)
+        .method(set_from_base64, js_string!("setFromBase64"), 1)
+        .method(set_from_hex, js_string!("setFromHex"), 1)
+        .method(to_base64, js_string!("toBase64"), 0)
+        .method(to_hex, js_string!("toHex"), 0)
+}
+
+/// The `alphabet` option of the base64 methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Alphabet {
+    Base64,
+    Base64Url,
+}
+
+/// The `lastChunkHandling` option of the base64 decoding methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LastChunkHandling {
+    Loose,
+    Strict,
+    StopBeforePartial,
+}
+
+/// The result of decoding a base64 or hex string.
+struct Decoded {
+    /// The number of code units of the string that were read.
+    read: usize,
+    /// The decoded bytes.
+    bytes: Vec<u8>,
+    /// The error found while decoding, thrown after writing the decoded bytes.
+    error: Option<JsNativeError>,
+}
+
+/// Abstract operation `GetOptionsObject ( options )`.
+fn get_options_object(options: &JsValue) -> JsResult<Option<JsObject>> {
+    match options {
+        JsValue::Undefined => Ok(None),
+        JsValue::Object(options) => Ok(Some(options.clone())),
+        _ => Err(JsNativeError::typ()
+            .with_message("provided options is not an object")
+            .into()),
+    }
+}
+
+/// Gets the string value of the option `name`, throwing if it is not one of `values`.
+fn get_string_option<T: Copy>(
+    options: Option<&JsObject>,
+    name: &'static str,
+    values: &[(&str, T)],
+    default: T,
+    context: &mut Context,
+) -> JsResult<T> {
+    let Some(options) = options else {
+        return Ok(default);
+    };
+
+    let value = options.get(JsString::from(name), context)?;
+    if value.is_undefined() {
+        return Ok(default);
+    }
+
+    value
+        .as_string()
+        .and_then(|value| {
+            values
+                .iter()
+                .find(|(name, _)| value == &JsString::from(*name))
+                .map(|(_, value)| *value)
+        })
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("invalid `{name}` option"))
+                .into()
+        })
+}
+
+/// Gets the `alphabet` option of the base64 methods.
+fn get_alphabet(options: Option<&JsObject>, context: &mut Context) -> JsResult<Alphabet> {
+    get_string_option(
+        options,
+        "alphabet",
+        &[
+            ("base64", Alphabet::Base64),
+            ("base64url", Alphabet::Base64Url),
+        ],
+        Alphabet::Base64,
+        context,
+    )
+}
+
+/// Gets the `lastChunkHandling` option of the base64 decoding methods.
+fn get_last_chunk_handling(
+    options: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<LastChunkHandling> {
+    get_string_option(
+        options,
+        "lastChunkHandling",
+        &[
+            ("loose", LastChunkHandling::Loose),
+            ("strict", LastChunkHandling::Strict),
+            ("stop-before-partial", LastChunkHandling::StopBeforePartial),
+        ],
+        LastChunkHandling::Loose,
+        context,
+    )
+}
+
+/// Gets the string argument of the decoding methods.
+fn get_string_argument<'a>(args: &'a [JsValue], method: &str) -> JsResult<&'a JsString> {
+    args.get_or_undefined(0).as_string().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message(format!("{method}: the argument must be a string"))
+            .into()
+    })
+}
+
+/// Abstract operation `ValidateUint8Array ( ta )`.
+fn validate_uint8_array(this: &JsValue, method: &str) -> JsResult<JsObject<TypedArray>> {
+    this.as_object()
+        .and_then(|o| o.clone().downcast::<TypedArray>().ok())
+        .filter(|ta| ta.borrow().data.kind() == TypedArrayKind::Uint8)
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("{method}: `this` is not a Uint8Array"))
+                .into()
+        })
+}
+
+/// Abstract operation `GetUint8ArrayBytes ( ta )`.
+fn get_uint8_array_bytes(ta: &JsObject<TypedArray>) -> JsResult<Vec<u8>> {
+    let ta = ta.borrow();
+    let ta = &ta.data;
+
+    // 1. Let buffer be ta.[[ViewedArrayBuffer]].
+    // 2. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(ta, seq-cst).
+    // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    let buffer = ta.viewed_array_buffer().as_buffer();
+    let Some(buffer) = buffer
+        .bytes(Ordering::SeqCst)
+        .filter(|buffer| !ta.is_out_of_bounds(buffer.len()))
+    else {
+        return Err(JsNativeError::typ()
+            .with_message("typed array is outside the bounds of its inner buffer")
+            .into());
+    };
+
+    // 4. Let len be TypedArrayLength(taRecord).
+    // 5. Let byteOffset be ta.[[ByteOffset]].
+    // 6. Let bytes be a new empty List.
+    // 7. Let index be 0.
+    // 8. Repeat, while index < len,
+    //     a. Let byteIndex be byteOffset + index.
+    //     b. Let byte be ℝ(GetValueFromBuffer(buffer, byteIndex, uint8, true, unordered)).
+    //     c. Append byte to bytes.
+    //     d. Set index to index + 1.
+    let offset = ta.byte_offset() as usize;
+    let len = ta.array_length(buffer.len()) as usize;
+    let src = buffer.subslice(offset..offset + len);
+    let mut bytes = vec![0; len];
+
+    // SAFETY: both buffers have exactly `len` bytes, and `bytes` is a new buffer so they cannot
+    // overlap.
+    unsafe {
+        memcpy(
+            src.as_ptr(),
+            SliceRefMut::from(&mut bytes[..]).as_ptr(),
+            len,
+        );
+    };
+
+    // 9. Return bytes.
+    Ok(bytes)
+}
+
+/// Abstract operation `SetUint8ArrayBytes ( into, bytes )`.
+///
+/// The caller must ensure that `into` is still in bounds and that `bytes` fits in it.
+fn set_uint8_array_bytes(into: &JsObject<TypedArray>, bytes: &[u8]) {
+    let into = into.borrow();
+    let into = &into.data;
+
+    // 1. Let offset be into.[[ByteOffset]].
+    // 2. Let len be the length of bytes.
+    // 3. Let index be 0.
+    // 4. Repeat, while index < len,
+    //     a. Let byte be bytes[index].
+    //     b. Let byteIndexInBuffer be index + offset.
+    //     c. Perform SetValueInBuffer(into.[[ViewedArrayBuffer]], byteIndexInBuffer, uint8, 𝔽(byte), true, unordered).
+    //     d. Set index to index + 1.
+    let offset = into.byte_offset() as usize;
+    let mut buffer = into.viewed_array_buffer().as_buffer_mut();
+    let mut buffer = buffer
+        .bytes(Ordering::SeqCst)
+        .expect("the typed array must not be detached");
+    let mut dest = buffer.subslice_mut(offset..offset + bytes.len());
+
+    // SAFETY: both buffers have exactly `bytes.len()` bytes, and `bytes` is not part of the
+    // buffer so they cannot overlap.
+    unsafe {
+        memcpy(SliceRef::from(bytes).as_ptr(), dest.as_ptr(), bytes.len());
+    };
+}
+
+/// Allocates a new `Uint8Array` containing `bytes`.
+fn create_uint8_array(bytes: &[u8], context: &mut Context) -> JsResult<JsValue> {
+    let constructor = context
+        .intrinsics()
+        .constructors()
+        .typed_uint8_array()
+        .constructor();
+    let ta = BuiltinTypedArray::allocate::<Uint8Array>(
+        &constructor.into(),
+        bytes.len() as u64,
+        context,
+    )?
+    .downcast::<TypedArray>()
+    .expect("must be a typed array");
+    set_uint8_array_bytes(&ta, bytes);
+    Ok(ta.upcast().into())
+}
+
+/// Creates the `{ read, written }` result object of the `setFrom*` methods.
+fn create_result_object(read: usize, written: usize, context: &mut Context) -> JsValue {
+    // 1. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+    let result = JsObject::with_object_proto(context.intrinsics());
+
+    // 2. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(read)).
+    result
+        .create_data_property_or_throw(js_string!("read"), read, context)
+        .expect("this CreateDataPropertyOrThrow call must not fail");
+
+    // 3. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+    result
+        .create_data_property_or_throw(js_string!("written"), written, context)
+        .expect("this CreateDataPropertyOrThrow call must not fail");
+
+    // 4. Return resultObject.
+    result.into()
+}
+
+/// Abstract operation `SkipAsciiWhitespace ( string, index )`.
+fn skip_ascii_whitespace(string: &[u16], mut index: usize) -> usize {
+    // 1. Let length be the length of string.
+    // 2. Repeat, while index < length,
+    //     a. Let char be the code unit at index index of string.
+    //     b. If char is neither 0x0009 (TAB), 0x000A (LF), 0x000C (FF), 0x000D (CR), nor 0x0020 (SPACE), then
+    //         i. Return index.
+    //     c. Set index to index + 1.
+    while let Some(&char) = string.get(index) {
+        if !matches!(char, 0x09 | 0x0A | 0x0C | 0x0D | 0x20) {
+            break;
+        }
+        index += 1;
+    }
+
+    // 3. Return index.
+    index
+}
+
+/// Abstract operation `DecodeBase64Chunk ( chunk [ , throwOnExtraBits ] )`.
+///
+/// `chunk` contains the base64 values of the characters of the chunk, not the characters.
+fn decode_base64_chunk(chunk: &[u8], throw_on_extra_bits: bool) -> Result<Vec<u8>, JsNativeError> {
+    // 1. Let chunkLength be the length of chunk.
+    // 2. If chunkLength is 2, then
+    //     a. Set chunk to the string-concatenation of chunk and "AA".
+    // 3. Else if chunkLength is 3, then
+    //     a. Set chunk to the string-concatenation of chunk and "A".
+    // 4. Else,
+    //     a. Assert: chunkLength is 4.
+    // 5. Let byteSequence be the unique sequence of 3 bytes resulting from decoding chunk as base64.
+    let mut values = [0u8; 4];
+    values[..chunk.len()].copy_from_slice(chunk);
+    let bits = values
+        .iter()
+        .fold(0u32, |bits, &value| bits << 6 | u32::from(value));
+    let bytes = [(bits >> 16) as u8, (bits >> 8) as u8, bits as u8];
+
+    // 6. Let bytes be the List whose elements are the elements of byteSequence, in order.
+    // 7. If chunkLength is 2, then
+    //     a. Assert: throwOnExtraBits is present.
+    //     b. If throwOnExtraBits is true and bytes[1] ≠ 0, then
+    //         i. Throw a SyntaxError exception.
+    //     c. Return « bytes[0] ».
+    // 8. Else if chunkLength is 3, then
+    //     a. Assert: throwOnExtraBits is present.
+    //     b. If throwOnExtraBits is true and bytes[2] ≠ 0, then
+    //         i. Throw a SyntaxError exception.
+    //     c. Return « bytes[0], bytes[1] ».
+    // 9. Else,
+    //     a. Return bytes.
+    let len = chunk.len() - 1;
+    if throw_on_extra_bits && bytes.get(len).is_some_and(|&byte| byte != 0) {
+        return Err(JsNativeError::syntax().with_message("base64 string has non-zero padding bits"));
+    }
+    Ok(bytes[..len].to_vec())
+}
+
+/// Abstract operation `FromBase64 ( string, alphabet, lastChunkHandling [ , maxLength ] )`.
+fn decode_base64(
+    string: &[u16],
+    alphabet: Alphabet,
+    last_chunk_handling: LastChunkHandling,
+    max_length: usize,
+) -> Decoded {
+    fn syntax_error(message: &'static str) -> JsNativeError {
+        JsNativeError::syntax().with_message(message)
+    }
+
+    // 1. If maxLength is not present, then
+    //     a. Let maxLength be 2^53 - 1.
+    //     b. NOTE: Because the input is a string, the length of strings is limited to 2^53 - 1
+    //        characters, and the output requires no more bytes than the input has characters,
+    //        this limit can never be reached. However, it is editorially convenient to use a
+    //        finite value here.
+    // 2. NOTE: The order of validation and decoding in the algorithm below is not observable.
+    //    Implementations are encouraged to perform them in whatever order is most efficient,
+    //    possibly interleaving validation with decoding, as long as the behaviour is observably
+    //    equivalent.
+    // 3. If maxLength is 0, then
+    //     a. Return the Record { [[Read]]: 0, [[Bytes]]: « », [[Error]]: none }.
+    // 4. Let read be 0.
+    // 5. Let bytes be « ».
+    // 6. Let chunk be the empty String.
+    // 7. Let chunkLength be 0.
+    // 8. Let index be 0.
+    // 9. Let length be the length of string.
+    let mut decoded = Decoded {
+        read: 0,
+        bytes: Vec::new(),
+        error: None,
+    };
+    if max_length == 0 {
+        return decoded;
+    }
+    let mut chunk = Vec::with_capacity(4);
+    let mut index = 0;
+    let length = string.len();
+
+    // 10. Repeat,
+    loop {
+        // a. Set index to SkipAsciiWhitespace(string, index).
+        index = skip_ascii_whitespace(string, index);
+
+        // b. If index = length, then
+        if index == length {
+            // i. If chunkLength > 0, then
+            if !chunk.is_empty() {
+                match last_chunk_handling {
+                    // 1. If lastChunkHandling is "stop-before-partial", then
+                    //     a. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                    LastChunkHandling::StopBeforePartial => return decoded,
+                    // 2. Else if lastChunkHandling is "loose", then
+                    LastChunkHandling::Loose => {
+                        // a. If chunkLength = 1, then
+                        if chunk.len() == 1 {
+                            // i. Let error be a new SyntaxError exception.
+                            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                            decoded.error =
+                                Some(syntax_error("base64 string ends with a single character"));
+                            return decoded;
+                        }
+
+                        // b. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk, false).
+                        decoded.bytes.extend(
+                            decode_base64_chunk(&chunk, false)
+                                .expect("cannot fail if the extra bits are ignored"),
+                        );
+                    }
+                    // 3. Else,
+                    //     a. Assert: lastChunkHandling is "strict".
+                    LastChunkHandling::Strict => {
+                        // b. Let error be a new SyntaxError exception.
+                        // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                        decoded.error = Some(syntax_error("base64 string is missing its padding"));
+                        return decoded;
+                    }
+                }
+            }
+
+            // ii. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            decoded.read = length;
+            return decoded;
+        }
+
+        // c. Let char be the substring of string from index to index + 1.
+        let mut char = string[index];
+
+        // d. Set index to index + 1.
+        index += 1;
+
+        // e. If char is "=", then
+        if char == u16::from(b'=') {
+            // i. If chunkLength < 2, then
+            if chunk.len() < 2 {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                decoded.error = Some(syntax_error("unexpected padding in base64 string"));
+                return decoded;
+            }
+
+            // ii. Set index to SkipAsciiWhitespace(string, index).
+            index = skip_ascii_whitespace(string, index);
+
+            // iii. If chunkLength = 2, then
+            if chunk.len() == 2 {
+                // 1. If index = length, then
+                if index == length {
+                    // a. If lastChunkHandling is "stop-before-partial", then
+                    if last_chunk_handling == LastChunkHandling::StopBeforePartial {
+                        // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return decoded;
+                    }
+
+                    // b. Let error be a new SyntaxError exception.
+                    // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    decoded.error = Some(syntax_error("base64 string is missing its padding"));
+                    return decoded;
+                }
+
+                // 2. Set char to the substring of string from index to index + 1.
+                // 3. If char is "=", then
+                if string[index] == u16::from(b'=') {
+                    // a. Set index to SkipAsciiWhitespace(string, index + 1).
+                    index = skip_ascii_whitespace(string, index + 1);
+                }
+            }
+
+            // iv. If index < length, then
+            if index < length {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                decoded.error = Some(syntax_error("unexpected character after base64 padding"));
+                return decoded;
+            }
+
+            // v. If lastChunkHandling is "strict", let throwOnExtraBits be true.
+            // vi. Else, let throwOnExtraBits be false.
+            let throw_on_extra_bits = last_chunk_handling == LastChunkHandling::Strict;
+
+            // vii. Let decodeResult be DecodeBase64Chunk(chunk, throwOnExtraBits).
+            // viii. If decodeResult is an abrupt completion, then
+            //     1. Let error be decodeResult.[[Value]].
+            //     2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            // ix. Set bytes to the list-concatenation of bytes and ! decodeResult.
+            // x. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            match decode_base64_chunk(&chunk, throw_on_extra_bits) {
+                Ok(bytes) => {
+                    decoded.bytes.extend(bytes);
+                    decoded.read = length;
+                }
+                Err(error) => decoded.error = Some(error),
+            }
+            return decoded;
+        }
+
+        // f. If alphabet is "base64url", then
+        if alphabet == Alphabet::Base64Url {
+            // i. If char is either "+" or "/", then
+            if char == u16::from(b'+') || char == u16::from(b'/') {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                decoded.error = Some(syntax_error("invalid character in base64url string"));
+                return decoded;
+            }
+            // ii. Else if char is "-", then
+            //     1. Set char to "+".
+            // iii. Else if char is "_", then
+            //     1. Set char to "/".
+            if char == u16::from(b'-') {
+                char = u16::from(b'+');
+            } else if char == u16::from(b'_') {
+                char = u16::from(b'/');
+            }
+        }
+
+        // g. If the sole code unit of char is not an element of the standard base64 alphabet, then
+        let Some(value) = BASE64_ALPHABET.iter().position(|&c| u16::from(c) == char) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            decoded.error = Some(syntax_error("invalid character in base64 string"));
+            return decoded;
+        };
+
+        // h. Let remaining be maxLength - the length of bytes.
+        let remaining = max_length - decoded.bytes.len();
+
+        // i. If remaining = 1 and chunkLength = 2, or if remaining = 2 and chunkLength = 3, then
+        if (remaining == 1 && chunk.len() == 2) || (remaining == 2 && chunk.len() == 3) {
+            // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+            return decoded;
+        }
+
+        // j. Set chunk to the string-concatenation of chunk and char.
+        // k. Set chunkLength to the length of chunk.
+        chunk.push(value as u8);
+
+        // l. If chunkLength = 4, then
+        if chunk.len() == 4 {
+            // i. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk).
+            decoded.bytes.extend(
+                decode_base64_chunk(&chunk, false).expect("cannot fail for a complete chunk"),
+            );
+
+            // ii. Set chunk to the empty String.
+            // iii. Set chunkLength to 0.
+            chunk.clear();
+
+            // iv. Set read to index.
+            decoded.read = index;
+
+            // v. If the length of bytes = maxLength, then
+            if decoded.bytes.len() == max_length {
+                // 1. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                return decoded;
+            }
+        }
+    }
+}
+
+/// Abstract operation `FromHex ( string [ , maxLength ] )`.
+fn decode_hex(string: &[u16], max_length: usize) -> Decoded {
+    fn hex_value(char: u16) -> Option<u8> {
+        char::from_u32(u32::from(char))
+            .and_then(|char| char.to_digit(16))
+            .map(|digit| digit as u8)
+    }
+
+    // 1. If maxLength is not present, let maxLength be 2^53 - 1.
+    // 2. Let length be the length of string.
+    // 3. Let bytes be « ».
+    // 4. Let read be 0.
+    let length = string.len();
+    let mut decoded = Decoded {
+        read: 0,
+        bytes: Vec::new(),
+        error: None,
+    };
+
+    // 5. If length modulo 2 is not 0, then
+    if length % 2 != 0 {
+        // a. Let error be a new SyntaxError exception.
+        // b. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+        decoded.error =
+            Some(JsNativeError::syntax().with_message("hex string must have an even length"));
+        return decoded;
+    }
+
+    // 6. Repeat, while read < length and the length of bytes < maxLength,
+    while decoded.read < length && decoded.bytes.len() < max_length {
+        // a. Let hexits be the substring of string from read to read + 2.
+        // b. If hexits contains any code units which are not in "0123456789abcdefABCDEF", then
+        //     i. Let error be a new SyntaxError exception.
+        //     ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+        // c. Set read to read + 2.
+        // d. Let byte be the integer value represented by hexits in base-16 notation, using the
+        //    letters A-F and a-f for digits with values 10 through 15.
+        // e. Append byte to bytes.
+        let (Some(high), Some(low)) = (
+            hex_value(string[decoded.read]),
+            hex_value(string[decoded.read + 1]),
+        ) else {
+            decoded.error =
+                Some(JsNativeError::syntax().with_message("invalid character in hex string"));
+            return decoded;
+        };
+        decoded.read += 2;
+        decoded.bytes.push(high << 4 | low);
+    }
+
+    // 7. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+    decoded
+}
+
+/// `Uint8Array.fromBase64 ( string [ , options ] )`
+fn from_base64(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    // 1. If string is not a String, throw a TypeError exception.
+    let string = get_string_argument(args, "Uint8Array.fromBase64")?;
+
+    // 2. Let opts be ? GetOptionsObject(options).
+    let options = get_options_object(args.get_or_undefined(1))?;
+
+    // 3. Let alphabet be ? Get(opts, "alphabet").
+    // 4. If alphabet is undefined, set alphabet to "base64".
+    // 5. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+    let alphabet = get_alphabet(options.as_ref(), context)?;
+
+    // 6. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+    // 7. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+    // 8. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+    let last_chunk_handling = get_last_chunk_handling(options.as_ref(), context)?;
+
+    // 9. Let result be FromBase64(string, alphabet, lastChunkHandling).
+    let result = decode_base64(&string.to_vec(), alphabet, last_chunk_handling, MAX_LENGTH);
+
+    // 10. If result.[[Error]] is not none, then
+    if let Some(error) = result.error {
+        // a. Throw result.[[Error]].
+        return Err(error.into());
+    }
+
+    // 11. Let resultLength be the length of result.[[Bytes]].
+    // 12. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+    // 13. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value
+    //     at the corresponding index of result.[[Bytes]].
+    // 14. Return ta.
+    create_uint8_array(&result.bytes, context)
+}
+
+/// `Uint8Array.fromHex ( string )`
+fn from_hex(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    // 1. If string is not a String, throw a TypeError exception.
+    let string = get_string_argument(args, "Uint8Array.fromHex")?;
+
+    // 2. Let result be FromHex(string).
+    let result = decode_hex(&string.to_vec(), MAX_LENGTH);
+
+    // 3. If result.[[Error]] is not none, then
+    if let Some(error) = result.error {
+        // a. Throw result.[[Error]].
+        return Err(error.into());
+    }
+
+    // 4. Let resultLength be the length of result.[[Bytes]].
+    // 5. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+    // 6. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value
+    //    at the corresponding index of result.[[Bytes]].
+    // 7. Return ta.
+    create_uint8_array(&result.bytes, context)
+}
+
+/// Gets the length of the `Uint8Array` target of the `setFrom*` methods.
+fn get_target_length(into: &JsObject<TypedArray>) -> JsResult<usize> {
+    // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+    // 5. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    // 6. Let byteLength be TypedArrayLength(taRecord).
+    let into = into.borrow();
+    let into = &into.data;
+    let buffer = into.viewed_array_buffer().as_buffer();
+    let length = buffer
+        .bytes(Ordering::SeqCst)
+        .filter(|buffer| !into.is_out_of_bounds(buffer.len()))
+        .map(|buffer| into.array_length(buffer.len()) as usize);
+
+    length.ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message("typed array is outside the bounds of its inner buffer")
+            .into()
+    })
+}
+
+/// Writes the `decoded` bytes into `into`, returning the `{ read, written }` result object.
+fn set_from_decoded(
+    into: &JsObject<TypedArray>,
+    decoded: Decoded,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 8. Let bytes be result.[[Bytes]].
+    // 9. Let written be the length of bytes.
+    // 10. NOTE: FromBase64 does not invoke any user code, so the ArrayBuffer backing into cannot
+    //     have been detached or shrunk.
+    // 11. Assert: written ≤ byteLength.
+    // 12. Perform SetUint8ArrayBytes(into, bytes).
+    set_uint8_array_bytes(into, &decoded.bytes);
+
+    // 13. If result.[[Error]] is not none, then
+    if let Some(error) = decoded.error {
+        // a. Throw result.[[Error]].
+        return Err(error.into());
+    }
+
+    // 14. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+    // 15. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(result.[[Read]])).
+    // 16. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+    // 17. Return resultObject.
+    Ok(create_result_object(
+        decoded.read,
+        decoded.bytes.len(),
+        context,
+    ))
+}
+
+/// `Uint8Array.prototype.setFromBase64 ( string [ , options ] )`
+fn set_from_base64(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    // 1. Let into be the this value.
+    // 2. Perform ? ValidateUint8Array(into).
+    let into = validate_uint8_array(this, "Uint8Array.prototype.setFromBase64")?;
+
+    // 3. If string is not a String, throw a TypeError exception.
+    let string = get_string_argument(args, "Uint8Array.prototype.setFromBase64")?;
+
+    // 4. Let opts be ? GetOptionsObject(options).
+    let options = get_options_object(args.get_or_undefined(1))?;
+
+    // 5. Let alphabet be ? Get(opts, "alphabet").
+    // 6. If alphabet is undefined, set alphabet to "base64".
+    // 7. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+    let alphabet = get_alphabet(options.as_ref(), context)?;
+
+    // 8. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+    // 9. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+    // 10. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+    let last_chunk_handling = get_last_chunk_handling(options.as_ref(), context)?;
+
+    // 11. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+    // 12. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    // 13. Let byteLength be TypedArrayLength(taRecord).
+    let byte_length = get_target_length(&into)?;
+
+    // 14. Let result be FromBase64(string, alphabet, lastChunkHandling, byteLength).
+    let result = decode_base64(&string.to_vec(), alphabet, last_chunk_handling, byte_length);
+
+    set_from_decoded(&into, result, context)
+}
+
+/// `Uint8Array.prototype.setFromHex ( string )`
+fn set_from_hex(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    // 1. Let into be the this value.
+    // 2. Perform ? ValidateUint8Array(into).
+    let into = validate_uint8_array(this, "Uint8Array.prototype.setFromHex")?;
+
+    // 3. If string is not a String, throw a TypeError exception.
+    let string = get_string_argument(args, "Uint8Array.prototype.setFromHex")?;
+
+    // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+    // 5. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    // 6. Let byteLength be TypedArrayLength(taRecord).
+    let byte_length = get_target_length(&into)?;
+
+    // 7. Let result be FromHex(string, byteLength).
+    let result = decode_hex(&string.to_vec(), byte_length);
+
+    set_from_decoded(&into, result, context)
+}
+
+/// `Uint8Array.prototype.toBase64 ( [ options ] )`
+fn to_base64(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    // 1. Let O be the this value.
+    // 2. Perform ? ValidateUint8Array(O).
+    let o = validate_uint8_array(this, "Uint8Array.prototype.toBase64")?;
+
+    // 3. Let opts be ? GetOptionsObject(options).
+    let options = get_options_object(args.get_or_undefined(0))?;
+
+    // 4. Let alphabet be ? Get(opts, "alphabet").
+    // 5. If alphabet is undefined, set alphabet to "base64".
+    // 6. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+    let alphabet = get_alphabet(options.as_ref(), context)?;
+
+    // 7. Let omitPadding be ToBoolean(? Get(opts, "omitPadding")).
+    let omit_padding = match &options {
+        Some(options) => options
+            .get(js_string!("omitPadding"), context)?
+            .to_boolean(),
+        None => false,
+    };
+
+    // 8. Let toEncode be ? GetUint8ArrayBytes(O).
+    let to_encode = get_uint8_array_bytes(&o)?;
+
+    // 9. If alphabet is "base64", then
+    //     a. Let outAscii be the sequence of code points which results from encoding toEncode
+    //        according to the base64 encoding specified in Section 4 of RFC 4648. Padding is
+    //        included if and only if omitPadding is false.
+    // 10. Else,
+    //     a. Assert: alphabet is "base64url".
+    //     b. Let outAscii be the sequence of code points which results from encoding toEncode
+    //        according to the base64url encoding specified in Section 5 of RFC 4648. Padding is
+    //        included if and only if omitPadding is false.
+    let table = match alphabet {
+        Alphabet::Base64 => BASE64_ALPHABET,
+        Alphabet::Base64Url => BASE64_URL_ALPHABET,
+    };
+    let mut out_ascii = Vec::with_capacity(to_encode.len().div_ceil(3) * 4);
+    for chunk in to_encode.chunks(3) {
+        let mut bytes = [0u8; 3];
+        bytes[..chunk.len()].copy_from_slice(chunk);
+        let bits = bytes
+            .iter()
+            .fold(0u32, |bits, &byte| bits << 8 | u32::from(byte));
+        let encoded =
+            [bits >> 18, bits >> 12, bits >> 6, bits].map(|value| table[(value & 0x3F) as usize]);
+
+        out_ascii.extend_from_slice(&encoded[..=chunk.len()]);
+        if !omit_padding {
+            out_ascii.resize(out_ascii.len() + 3 - chunk.len(), b'=');
+        }
+    }
+
+    // 11. Return CodePointsToString(outAscii).
+    Ok(JsString::from(out_ascii.into_iter().map(u16::from).collect::<Vec<_>>()).into())
+}
+
+/// `Uint8Array.prototype.toHex ( )`
+fn to_hex(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    // 1. Let O be the this value.
+    // 2. Perform ? ValidateUint8Array(O).
+    let o = validate_uint8_array(this, "Uint8Array.prototype.toHex")?;
+
+    // 3. Let toEncode be ? GetUint8ArrayBytes(O).
+    let to_encode = get_uint8_array_bytes(&o)?;
+
+    // 4. Let out be the empty String.
+    // 5. For each byte byte of toEncode, do
+    //     a. Let hex be Number::toString(𝔽(byte), 16).
+    //     b. Set hex to StringPad(hex, 2, "0", start).
+    //     c. Set out to the string-concatenation of out and hex.
+    let out = to_encode
+        .iter()
+        .flat_map(|byte| {
+            [byte >> 4, byte & 0xF].map(|digit| u16::from(b"0123456789abcdef"[digit as usize]))
+        })
+        .collect::<Vec<_>>();
+
+    // 6. Return out.
+    Ok(JsString::from(out).into())
+}
@@ -7,7 +7,8 @@ use boa_macros::utf16;
 use num_traits::Zero;
 
 use super::{
-    object::typed_array_set_element, ContentType, TypedArray, TypedArrayKind, TypedArrayMarker,
+    object::typed_array_set_element, ClampedU8, ContentType, Element, TypedArray, TypedArrayKind,
+    TypedArrayMarker,
 };
 use crate::{
     builtins::{
@@ -1210,7 +1211,7 @@ impl BuiltinTypedArray {
         for k in 0..len {
             // a. If k > 0, set R to the string-concatenation of R and sep.
             if k > 0 {
-                r.extend_from_slice(&sep);
+                r.extend(sep.iter());
             }
 
             // b. Let element be ! Get(O, ! ToString(𝔽(k))).
@@ -1219,7 +1220,7 @@ impl BuiltinTypedArray {
             // c. If element is undefined, let next be the empty String; otherwise, let next be ! ToString(element).
             // d. Set R to the string-concatenation of R and next.
             if !element.is_undefined() {
-                r.extend_from_slice(&element.to_string(context)?);
+                r.extend(element.to_string(context)?.iter());
             }
         }
 
@@ -2241,6 +2242,13 @@ impl BuiltinTypedArray {
         // 4. Let len be TypedArrayLength(taRecord).
         let len = ta.borrow().data.array_length(buf_len);
 
+        // Without a comparefn no user code can run while sorting, so the elements can be sorted
+        // directly in the buffer.
+        if compare_fn.is_none() {
+            sort_buffer_elements(&ta.borrow().data, len);
+            return Ok(ta.upcast().into());
+        }
+
         // 5. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.30.
         // 6. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
         let sort_compare =
@@ -2296,6 +2304,47 @@ impl BuiltinTypedArray {
         // 5. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
         let new_array = Self::from_kind_and_length(ta.borrow().data.kind(), len, context)?;
 
+        // Without a comparefn no user code can run while sorting, so the elements can be copied
+        // to the new array and sorted directly in its buffer.
+        if compare_fn.is_none() {
+            let src = ta.borrow();
+            let src = &src.data;
+            let target = new_array
+                .downcast_ref::<TypedArray>()
+                .expect("TypedArrayCreateSameType must return a typed array");
+            let byte_count = (len * src.kind().element_size()) as usize;
+
+            {
+                let src_buffer = src.viewed_array_buffer().as_buffer();
+                let src_bytes = src_buffer
+                    .bytes(Ordering::SeqCst)
+                    .expect("ValidateTypedArray ensures the buffer is not detached");
+                let src_bytes = src_bytes.subslice(src.byte_offset() as usize..);
+
+                let mut target_buffer = target.viewed_array_buffer().as_buffer_mut();
+                let mut target_bytes = target_buffer
+                    .bytes_with_len(byte_count)
+                    .expect("newly created array cannot be detached");
+
+                #[cfg(debug_assertions)]
+                {
+                    assert!(src_bytes.len() >= byte_count);
+                    assert!(target_bytes.len() >= byte_count);
+                }
+
+                // SAFETY: `src` was validated to have at least `len` elements, and the new array
+                // was allocated with exactly `len` elements of the same type.
+                unsafe {
+                    memcpy(src_bytes.as_ptr(), target_bytes.as_ptr(), byte_count);
+                }
+            }
+
+            sort_buffer_elements(&target, len);
+            drop(target);
+
+            return Ok(new_array.into());
+        }
+
         // 6. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.34.
         // 7. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
         let sort_compare =
@@ -2498,7 +2547,7 @@ impl BuiltinTypedArray {
                     )?
                     .to_string(context)?;
 
-                r.extend_from_slice(&s);
+                r.extend(s.iter());
             };
         }
 
@@ -3128,15 +3177,8 @@ fn compare_typed_array_elements(
             .to_number(context)?;
 
         // b. If v is NaN, return +0𝔽.
-        if v.is_nan() {
-            return Ok(cmp::Ordering::Equal);
-        }
-
         // c. Return v.
-        if v.is_sign_positive() {
-            return Ok(cmp::Ordering::Greater);
-        }
-        return Ok(cmp::Ordering::Less);
+        return Ok(v.partial_cmp(&0.0).unwrap_or(cmp::Ordering::Equal));
     }
 
     match (x, y) {
@@ -3197,6 +3239,93 @@ fn compare_typed_array_elements(
     }
 }
 
+/// Sorts the first `len` elements of `array` in place, using the default numeric ordering of
+/// [`CompareTypedArrayElements`][spec].
+///
+/// The elements are read directly from the array buffer instead of being converted to `JsValue`s,
+/// which makes this much faster than going through `SortIndexedProperties`.
+///
+/// # Panics
+///
+/// Panics if the buffer of `array` is detached or has less than `len` elements.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-comparetypedarrayelements
+fn sort_buffer_elements(array: &TypedArray, len: u64) {
+    /// Sorts `len` elements of type `E` from the start of `bytes` using `compare`.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must have at least `len` elements of type `E`, and must be aligned to the
+    /// alignment of `E`.
+    unsafe fn sort_elements<E: Element>(
+        mut bytes: SliceRefMut<'_>,
+        len: usize,
+        compare: fn(&E, &E) -> cmp::Ordering,
+    ) {
+        let size = std::mem::size_of::<E>();
+
+        let mut elements = Vec::with_capacity(len);
+        for i in 0..len {
+            // SAFETY: The invariants of this operation are ensured by the caller.
+            let element = unsafe { E::read_mut(bytes.subslice_mut(i * size..)) };
+            elements.push(element.load(atomic::Ordering::Relaxed));
+        }
+
+        elements.sort_by(compare);
+
+        for (i, element) in elements.into_iter().enumerate() {
+            // SAFETY: The invariants of this operation are ensured by the caller.
+            let mut slot = unsafe { E::read_mut(bytes.subslice_mut(i * size..)) };
+            slot.store(element, atomic::Ordering::Relaxed);
+        }
+    }
+
+    /// The ordering of `CompareTypedArrayElements` for Numbers: `NaN`s are sorted after
+    /// every other value, and `-0` is sorted before `+0`.
+    fn compare_floats(x: f64, y: f64) -> cmp::Ordering {
+        match (x.is_nan(), y.is_nan()) {
+            (true, true) => cmp::Ordering::Equal,
+            (true, false) => cmp::Ordering::Greater,
+            (false, true) => cmp::Ordering::Less,
+            (false, false) => x.total_cmp(&y),
+        }
+    }
+
+    let mut buffer = array.viewed_array_buffer().as_buffer_mut();
+    let mut bytes = buffer
+        .bytes(Ordering::SeqCst)
+        .expect("ValidateTypedArray ensures the buffer is not detached");
+    let bytes = bytes.subslice_mut(array.byte_offset() as usize..);
+    let len = len as usize;
+
+    #[cfg(debug_assertions)]
+    {
+        assert!(bytes.len() >= len * array.kind().element_size() as usize);
+    }
+
+    // SAFETY: The array was validated to have at least `len` elements, and all typed arrays are
+    // aligned to the size of their elements in the underlying buffer.
+    unsafe {
+        match array.kind() {
+            TypedArrayKind::Int8 => sort_elements::<i8>(bytes, len, Ord::cmp),
+            TypedArrayKind::Uint8 => sort_elements::<u8>(bytes, len, Ord::cmp),
+            TypedArrayKind::Uint8Clamped => sort_elements::<ClampedU8>(bytes, len, Ord::cmp),
+            TypedArrayKind::Int16 => sort_elements::<i16>(bytes, len, Ord::cmp),
+            TypedArrayKind::Uint16 => sort_elements::<u16>(bytes, len, Ord::cmp),
+            TypedArrayKind::Int32 => sort_elements::<i32>(bytes, len, Ord::cmp),
+            TypedArrayKind::Uint32 => sort_elements::<u32>(bytes, len, Ord::cmp),
+            TypedArrayKind::BigInt64 => sort_elements::<i64>(bytes, len, Ord::cmp),
+            TypedArrayKind::BigUint64 => sort_elements::<u64>(bytes, len, Ord::cmp),
+            TypedArrayKind::Float32 => sort_elements::<f32>(bytes, len, |x, y| {
+                compare_floats(f64::from(*x), f64::from(*y))
+            }),
+            TypedArrayKind::Float64 => {
+                sort_elements::<f64>(bytes, len, |x, y| compare_floats(*x, *y));
+            }
+        }
+    }
+}
+
 /// Abstract operation `IsValidIntegerIndex ( O, index )`.
 ///
 /// Returns `true` if the index is valid, or `false` otherwise.
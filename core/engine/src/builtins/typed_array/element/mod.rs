@@ -49,6 +49,14 @@ pub(crate) enum ElementRefMut<'a, E: Element> {
 }
 
 impl<E: Element> ElementRefMut<'_, E> {
+    /// Loads the value of this mutable reference.
+    pub(crate) fn load(&self, order: Ordering) -> E {
+        match self {
+            ElementRefMut::Atomic(num) => E::from_plain(num.load(order)),
+            ElementRefMut::Plain(num) => **num,
+        }
+    }
+
     /// Stores `value` on this mutable reference.
     pub(crate) fn store(&mut self, value: E, order: Ordering) {
         match self {
@@ -31,9 +31,12 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
+mod base64;
 mod builtin;
 mod element;
 mod object;
+#[cfg(test)]
+mod tests;
 
 pub(crate) use builtin::{is_valid_integer_index, BuiltinTypedArray};
 pub(crate) use element::{Atomic, ClampedU8, Element};
@@ -56,7 +59,7 @@ impl<T: TypedArrayMarker> IntrinsicObject for T {
             .name(js_string!("get [Symbol.species]"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .prototype(
                 realm
                     .intrinsics()
@@ -82,8 +85,15 @@ impl<T: TypedArrayMarker> IntrinsicObject for T {
                 js_string!("BYTES_PER_ELEMENT"),
                 std::mem::size_of::<T::Element>(),
                 Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
-            )
-            .build();
+            );
+
+        // Only `Uint8Array` can be converted to and from base64 and hex strings.
+        if T::ERASED == TypedArrayKind::Uint8 {
+            base64::add_methods(builder)
+        } else {
+            builder
+        }
+        .build();
     }
 }
 
@@ -341,17 +351,28 @@ pub(crate) enum ContentType {
 /// List of all typed array kinds.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Trace, Finalize)]
 #[boa_gc(empty_trace)]
-pub(crate) enum TypedArrayKind {
+pub enum TypedArrayKind {
+    /// `Int8Array`
     Int8,
+    /// `Uint8Array`
     Uint8,
+    /// `Uint8ClampedArray`
     Uint8Clamped,
+    /// `Int16Array`
     Int16,
+    /// `Uint16Array`
     Uint16,
+    /// `Int32Array`
     Int32,
+    /// `Uint32Array`
     Uint32,
+    /// `BigInt64Array`
     BigInt64,
+    /// `BigUint64Array`
     BigUint64,
+    /// `Float32Array`
     Float32,
+    /// `Float64Array`
     Float64,
 }
 
@@ -433,7 +454,8 @@ impl TypedArrayKind {
     }
 
     /// Gets the size of the type of element of this `TypedArrayKind`.
-    pub(crate) const fn element_size(self) -> u64 {
+    #[must_use]
+    pub const fn element_size(self) -> u64 {
         match self {
             TypedArrayKind::Int8 | TypedArrayKind::Uint8 | TypedArrayKind::Uint8Clamped => {
                 std::mem::size_of::<u8>() as u64
@@ -125,6 +125,14 @@ impl TypedArray {
         &self.viewed_array_buffer
     }
 
+    /// Creates a copy of this `TypedArray` which views `buffer` instead of its current buffer.
+    pub(crate) fn with_viewed_array_buffer(&self, buffer: BufferObject) -> Self {
+        Self {
+            viewed_array_buffer: buffer,
+            ..self.clone()
+        }
+    }
+
     /// [`TypedArrayByteLength ( taRecord )`][spec].
     ///
     /// Get the `TypedArray` object's byte length.
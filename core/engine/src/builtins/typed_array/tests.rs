@@ -0,0 +1,229 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn base64_round_trip() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function bytes(string) {
+                return new Uint8Array(Array.from(string, c => c.charCodeAt(0)));
+            }
+        "#}),
+        TestAction::assert_eq("bytes('f').toBase64()", js_string!("Zg==")),
+        TestAction::assert_eq("bytes('fo').toBase64()", js_string!("Zm8=")),
+        TestAction::assert_eq("bytes('foo').toBase64()", js_string!("Zm9v")),
+        TestAction::assert_eq(
+            "bytes('foob').toBase64({ omitPadding: true })",
+            js_string!("Zm9vYg"),
+        ),
+        TestAction::assert_eq(
+            "new Uint8Array([251, 255]).toBase64({ alphabet: 'base64url' })",
+            js_string!("-_8="),
+        ),
+        TestAction::assert_eq(
+            "Uint8Array.fromBase64('Zm9vYg==').join()",
+            js_string!("102,111,111,98"),
+        ),
+        TestAction::assert_eq(
+            "Uint8Array.fromBase64(' Zm9v\\nYg ').join()",
+            js_string!("102,111,111,98"),
+        ),
+        TestAction::assert_eq(
+            "Uint8Array.fromBase64('-_8', { alphabet: 'base64url' }).join()",
+            js_string!("251,255"),
+        ),
+        TestAction::assert_eq(
+            "Uint8Array.fromBase64('Zm9vYg', { lastChunkHandling: 'stop-before-partial' }).join()",
+            js_string!("102,111,111"),
+        ),
+        TestAction::assert(indoc! {r#"
+            var random = new Uint8Array(Array.from({ length: 100 }, (_, i) => i * 7 % 256));
+            Uint8Array.fromBase64(random.toBase64({ alphabet: "base64url" }), { alphabet: "base64url" })
+                .every((byte, i) => byte === random[i])
+        "#}),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('Zm9vYg', { lastChunkHandling: 'strict' })",
+            JsNativeErrorKind::Syntax,
+            "base64 string is missing its padding",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('Zm9vYh==', { lastChunkHandling: 'strict' })",
+            JsNativeErrorKind::Syntax,
+            "base64 string has non-zero padding bits",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('-_8=')",
+            JsNativeErrorKind::Syntax,
+            "invalid character in base64 string",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('Zm9v', { alphabet: 'base32' })",
+            JsNativeErrorKind::Type,
+            "invalid `alphabet` option",
+        ),
+    ]);
+}
+
+#[test]
+fn hex_round_trip() {
+    run_test_actions([
+        TestAction::assert_eq("new Uint8Array([0, 15, 255]).toHex()", js_string!("000fff")),
+        TestAction::assert_eq(
+            "Uint8Array.fromHex('000fFF').join()",
+            js_string!("0,15,255"),
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromHex('0g')",
+            JsNativeErrorKind::Syntax,
+            "invalid character in hex string",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromHex('000')",
+            JsNativeErrorKind::Syntax,
+            "hex string must have an even length",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.prototype.toHex.call(new Int8Array(1))",
+            JsNativeErrorKind::Type,
+            "Uint8Array.prototype.toHex: `this` is not a Uint8Array",
+        ),
+    ]);
+}
+
+#[test]
+fn set_from_base64_and_hex() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var target = new Uint8Array(4);
+            var result = target.setFromBase64("Zm9vYmFy");
+        "#}),
+        TestAction::assert_eq("result.read", 4),
+        TestAction::assert_eq("result.written", 3),
+        TestAction::assert_eq("target.join()", js_string!("102,111,111,0")),
+        TestAction::run(indoc! {r#"
+            target = new Uint8Array(new ArrayBuffer(8), 2, 3);
+            result = target.setFromHex("0102030405");
+        "#}),
+        TestAction::assert_eq("result.read", 6),
+        TestAction::assert_eq("result.written", 3),
+        TestAction::assert_eq(
+            "new Uint8Array(target.buffer).join()",
+            js_string!("0,0,1,2,3,0,0,0"),
+        ),
+        TestAction::assert_native_error(
+            "target = new Uint8Array(4); target.setFromHex('01zz')",
+            JsNativeErrorKind::Syntax,
+            "invalid character in hex string",
+        ),
+        TestAction::assert_eq("target.join()", js_string!("1,0,0,0")),
+    ]);
+}
+
+#[test]
+fn sort_without_comparefn() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Int8Array([3, -1, 127, -128, 0]).sort().join()",
+            js_string!("-128,-1,0,3,127"),
+        ),
+        TestAction::assert_eq(
+            "new Uint8ClampedArray([300, 2, -5, 1]).sort().join()",
+            js_string!("0,1,2,255"),
+        ),
+        TestAction::assert_eq(
+            "new Uint32Array([4294967295, 0, 2147483648]).sort().join()",
+            js_string!("0,2147483648,4294967295"),
+        ),
+        TestAction::assert_eq(
+            "new BigInt64Array([9223372036854775807n, -9223372036854775808n, 0n, -1n]).sort().join()",
+            js_string!("-9223372036854775808,-1,0,9223372036854775807"),
+        ),
+        TestAction::assert_eq(
+            "new BigUint64Array([18446744073709551615n, 0n, 9223372036854775808n]).sort().join()",
+            js_string!("0,9223372036854775808,18446744073709551615"),
+        ),
+        TestAction::assert_eq(
+            "Array.from(new Float64Array([NaN, 1, -0, 0, -Infinity, Infinity, -1]).sort(), x => Object.is(x, -0) ? '-0' : String(x)).join()",
+            js_string!("-Infinity,-1,-0,0,1,Infinity,NaN"),
+        ),
+        TestAction::assert_eq(
+            "Array.from(new Float32Array([0, NaN, -0, 0.5]).sort(), x => Object.is(x, -0) ? '-0' : String(x)).join()",
+            js_string!("-0,0,0.5,NaN"),
+        ),
+        TestAction::assert_eq(
+            "new Int16Array(new Int16Array([5, 4, 3, 2, 1]).buffer, 2, 3).sort().join()",
+            js_string!("2,3,4"),
+        ),
+    ]);
+}
+
+#[test]
+fn to_sorted_without_comparefn() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var ta = new Float64Array([3, NaN, -2, 1]);
+            var sorted = ta.toSorted();
+        "#}),
+        TestAction::assert_eq("sorted.join()", js_string!("-2,1,3,NaN")),
+        TestAction::assert_eq("ta.join()", js_string!("3,NaN,-2,1")),
+        TestAction::assert("sorted instanceof Float64Array"),
+        TestAction::assert_eq(
+            "new Uint16Array(new Uint16Array([9, 8, 7, 6]).buffer, 2, 2).toSorted().join()",
+            js_string!("7,8"),
+        ),
+    ]);
+}
+
+#[test]
+fn sort_with_comparefn() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Int32Array([1, 2, 3, 4]).sort((a, b) => b - a).join()",
+            js_string!("4,3,2,1"),
+        ),
+        // Elements comparing equal keep their original order.
+        TestAction::assert_eq(
+            "new Uint8Array([21, 12, 11, 22, 13]).sort((a, b) => (a % 10) - (b % 10)).join()",
+            js_string!("21,11,12,22,13"),
+        ),
+        TestAction::assert_eq(
+            "new Uint8Array([3, 1, 2]).sort(() => NaN).join()",
+            js_string!("3,1,2"),
+        ),
+        TestAction::assert_eq(
+            "new Uint8Array([3, 1, 2]).sort(() => 0).join()",
+            js_string!("3,1,2"),
+        ),
+        TestAction::assert_eq(
+            "new Uint8Array([3, 1, 2]).toSorted((a, b) => a - b).join()",
+            js_string!("1,2,3"),
+        ),
+        TestAction::assert_native_error(
+            "new Uint8Array([1, 2]).sort((a, b) => { throw new RangeError('stop'); })",
+            JsNativeErrorKind::Range,
+            "stop",
+        ),
+        TestAction::assert_native_error(
+            "new Uint8Array([1]).sort(1)",
+            JsNativeErrorKind::Type,
+            "TypedArray.sort called with non-callable comparefn",
+        ),
+    ]);
+}
+
+#[test]
+fn sort_with_comparefn_detaching_buffer() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var ta = new Uint8Array([3, 2, 1]);
+            var result = ta.sort((a, b) => {
+                if (!ta.buffer.detached) {
+                    ta.buffer.transfer();
+                }
+                return a - b;
+            });
+        "#}),
+        TestAction::assert("result === ta"),
+        TestAction::assert_eq("ta.length", 0),
+    ]);
+}
@@ -265,3 +265,46 @@ impl WeakMap {
         Ok(this.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{run_test_actions, Source, TestAction};
+
+    #[test]
+    fn key_value_cycles_collected() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var map = new WeakMap();
+                var set = new WeakSet();
+                function fill() {
+                    for (let i = 0; i < 1000; i++) {
+                        const key = { data: new Array(100).fill(i) };
+                        map.set(key, { key });
+                        set.add(key);
+                        key.ref = new WeakRef(key);
+                    }
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                boa_gc::force_collect();
+                let before = boa_gc::stats().bytes_allocated;
+
+                context.eval(Source::from_bytes("fill()")).unwrap();
+                assert!(boa_gc::stats().bytes_allocated > before + (1 << 20));
+
+                // The entries are cleared by the first collection, and the ephemerons that held
+                // them are released by the second one.
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+                boa_gc::force_collect();
+                let after = boa_gc::stats().bytes_allocated;
+                assert!(
+                    after < before + (256 << 10),
+                    "{after} >= {before} + 256 KiB"
+                );
+            }),
+        ]);
+    }
+}
@@ -7,6 +7,7 @@ use boa_ast::{
     expression::Identifier,
     function::{Class, ClassElement, FormalParameterList},
     property::{MethodDefinition, PropertyName},
+    Spanned,
 };
 use boa_gc::Gc;
 use boa_interner::Sym;
@@ -58,13 +59,15 @@ impl ByteCompiler<'_> {
         );
 
         compiler.code_block_flags |= CodeBlockFlags::IS_CLASS_CONSTRUCTOR;
+        compiler.source_text.clone_from(&self.source_text);
+        compiler.source_span = class.span();
 
         // Function environment
         let _ = compiler.push_compile_environment(true);
 
         if let Some(expr) = class.constructor() {
             compiler.length = expr.parameters().length();
-            compiler.params = expr.parameters().clone();
+            compiler.set_parameters(expr.parameters());
 
             compiler.function_declaration_instantiation(
                 expr.body(),
@@ -289,6 +292,7 @@ impl ByteCompiler<'_> {
                         self.lexical_environment.clone(),
                         self.context,
                     );
+                    field_compiler.source_text.clone_from(&self.source_text);
 
                     // Function environment
                     let _ = field_compiler.push_compile_environment(true);
@@ -317,6 +321,7 @@ impl ByteCompiler<'_> {
                         self.lexical_environment.clone(),
                         self.context,
                     );
+                    field_compiler.source_text.clone_from(&self.source_text);
                     let _ = field_compiler.push_compile_environment(true);
                     if let Some(node) = field {
                         field_compiler.compile_expr(node, true);
@@ -355,6 +360,7 @@ impl ByteCompiler<'_> {
                         self.lexical_environment.clone(),
                         self.context,
                     );
+                    field_compiler.source_text.clone_from(&self.source_text);
                     let _ = field_compiler.push_compile_environment(true);
                     if let Some(node) = field {
                         field_compiler.compile_expr(node, true);
@@ -389,6 +395,7 @@ impl ByteCompiler<'_> {
                         self.lexical_environment.clone(),
                         self.context,
                     );
+                    compiler.source_text.clone_from(&self.source_text);
                     let _ = compiler.push_compile_environment(true);
 
                     compiler.function_declaration_instantiation(
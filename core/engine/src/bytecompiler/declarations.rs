@@ -1,9 +1,10 @@
 use std::rc::Rc;
 
 use crate::{
+    builtins::function::OrdinaryFunction,
     bytecompiler::{ByteCompiler, FunctionCompiler, FunctionSpec, NodeKind},
     environments::CompileTimeEnvironment,
-    vm::{create_function_object_fast, BindingOpcode, Opcode},
+    vm::{create_function_object_fast, ActiveRunnable, BindingOpcode, Opcode},
     JsNativeError, JsResult,
 };
 use boa_ast::{
@@ -15,13 +16,16 @@ use boa_ast::{
         LexicallyScopedDeclaration, VarScopedDeclaration,
     },
     visitor::NodeRef,
-    Declaration, Script, StatementListItem,
+    Declaration, Script, Spanned, StatementListItem,
 };
 use boa_interner::Sym;
 
 #[cfg(feature = "annex-b")]
 use boa_ast::operations::annex_b_function_declarations_names;
 
+#[cfg(feature = "bytecode-cache")]
+use crate::vm::GlobalDeclarations;
+
 use super::{Operand, ToJsString};
 
 impl ByteCompiler<'_> {
@@ -35,7 +39,11 @@ impl ByteCompiler<'_> {
         &mut self,
         script: &Script,
         env: &Rc<CompileTimeEnvironment>,
+        script_or_module: &ActiveRunnable,
     ) -> JsResult<()> {
+        #[cfg(feature = "bytecode-cache")]
+        let mut declarations = GlobalDeclarations::default();
+
         // 1. Let lexNames be the LexicallyDeclaredNames of script.
         let lex_names = lexically_declared_names(script);
 
@@ -86,6 +94,9 @@ impl ByteCompiler<'_> {
                     .with_message("duplicate lexical declaration")
                     .into());
             }
+
+            #[cfg(feature = "bytecode-cache")]
+            declarations.var_names.push(name);
         }
 
         // 5. Let varDeclarations be the VarScopedDeclarations of script.
@@ -197,6 +208,9 @@ impl ByteCompiler<'_> {
                 if !lex_names.contains(&f) {
                     let f_string = self.resolve_identifier_expect(f);
 
+                    #[cfg(feature = "bytecode-cache")]
+                    declarations.annex_b_functions.push(f_string.clone());
+
                     // a. If env.HasLexicalDeclaration(F) is false, then
                     if !env.has_lex_binding(&f_string) {
                         // i. Let fnDefinable be ? env.CanDeclareGlobalVar(F).
@@ -245,18 +259,28 @@ impl ByteCompiler<'_> {
                     Declaration::Class(class) => {
                         for name in bound_names(class) {
                             let name = name.to_js_string(self.interner());
+                            #[cfg(feature = "bytecode-cache")]
+                            declarations.lexical.push((name.clone(), false));
                             env.create_mutable_binding(name, false);
                         }
                     }
                     Declaration::Lexical(LexicalDeclaration::Let(declaration)) => {
                         for name in bound_names(declaration) {
                             let name = name.to_js_string(self.interner());
+                            #[cfg(feature = "bytecode-cache")]
+                            declarations.lexical.push((name.clone(), false));
                             env.create_mutable_binding(name, false);
                         }
                     }
-                    Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                    Declaration::Lexical(
+                        LexicalDeclaration::Const(declaration)
+                        | LexicalDeclaration::Using(declaration)
+                        | LexicalDeclaration::AwaitUsing(declaration),
+                    ) => {
                         for name in bound_names(declaration) {
                             let name = name.to_js_string(self.interner());
+                            #[cfg(feature = "bytecode-cache")]
+                            declarations.lexical.push((name.clone(), true));
                             env.create_immutable_binding(name, true);
                         }
                     }
@@ -268,18 +292,18 @@ impl ByteCompiler<'_> {
         // 16. For each Parse Node f of functionsToInitialize, do
         for function in functions_to_initialize {
             // a. Let fn be the sole element of the BoundNames of f.
-            let (name, generator, r#async, parameters, body) = match &function {
+            let (name, generator, r#async, parameters, body, span) = match &function {
                 VarScopedDeclaration::Function(f) => {
-                    (f.name(), false, false, f.parameters(), f.body())
+                    (f.name(), false, false, f.parameters(), f.body(), f.span())
                 }
                 VarScopedDeclaration::Generator(f) => {
-                    (f.name(), true, false, f.parameters(), f.body())
+                    (f.name(), true, false, f.parameters(), f.body(), f.span())
                 }
                 VarScopedDeclaration::AsyncFunction(f) => {
-                    (f.name(), false, true, f.parameters(), f.body())
+                    (f.name(), false, true, f.parameters(), f.body(), f.span())
                 }
                 VarScopedDeclaration::AsyncGenerator(f) => {
-                    (f.name(), true, true, f.parameters(), f.body())
+                    (f.name(), true, true, f.parameters(), f.body(), f.span())
                 }
                 VarScopedDeclaration::VariableDeclaration(_) => {
                     continue;
@@ -293,6 +317,7 @@ impl ByteCompiler<'_> {
                 .r#async(r#async)
                 .strict(self.strict())
                 .binding_identifier(Some(name.sym().to_js_string(self.interner())))
+                .source_text(self.source_text.clone(), span)
                 .compile(
                     parameters,
                     body,
@@ -301,14 +326,24 @@ impl ByteCompiler<'_> {
                     self.context,
                 );
 
-            // Ensures global functions are printed when generating the global flowgraph.
-            let _ = self.push_function_to_constants(code.clone());
+            // Ensures global functions are printed when generating the global flowgraph, and can be
+            // instantiated when the script is loaded from a bytecode cache.
+            #[cfg_attr(not(feature = "bytecode-cache"), allow(unused_variables))]
+            let index = self.push_function_to_constants(code.clone());
 
             // b. Let fo be InstantiateFunctionObject of f with arguments env and privateEnv.
             let function = create_function_object_fast(code, self.context);
 
+            // Note: the script hasn't started running yet, so its functions must be associated
+            // with it manually.
+            if let Some(mut function) = function.downcast_mut::<OrdinaryFunction>() {
+                function.script_or_module = Some(script_or_module.clone());
+            }
+
             // c. Perform ? env.CreateGlobalFunctionBinding(fn, fo, false).
             let name = name.to_js_string(self.interner());
+            #[cfg(feature = "bytecode-cache")]
+            declarations.functions.push((name.clone(), index));
             self.context
                 .create_global_function_binding(name, function, false)?;
         }
@@ -317,9 +352,16 @@ impl ByteCompiler<'_> {
         for var in declared_var_names {
             // a. Perform ? env.CreateGlobalVarBinding(vn, false).
             let var = var.to_js_string(self.interner());
+            #[cfg(feature = "bytecode-cache")]
+            declarations.vars.push(var.clone());
             self.context.create_global_var_binding(var, false)?;
         }
 
+        #[cfg(feature = "bytecode-cache")]
+        {
+            self.global_declarations = Some(Box::new(declarations));
+        }
+
         // 18. Return unused.
         Ok(())
     }
@@ -346,7 +388,11 @@ impl ByteCompiler<'_> {
         // 3. For each element d of declarations, do
         for d in &declarations {
             // i. If IsConstantDeclaration of d is true, then
-            if let LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(d)) = d
+            if let LexicallyScopedDeclaration::LexicalDeclaration(
+                LexicalDeclaration::Const(d)
+                | LexicalDeclaration::Using(d)
+                | LexicalDeclaration::AwaitUsing(d),
+            ) = d
             {
                 // a. For each element dn of the BoundNames of d, do
                 for dn in bound_names::<'_, VariableList>(d) {
@@ -491,7 +537,7 @@ impl ByteCompiler<'_> {
             .map(|ident| {
                 self.context
                     .interner()
-                    .get(ident.as_slice())
+                    .get(ident.to_vec().as_slice())
                     .expect("string should be in interner")
             })
             .collect();
@@ -726,7 +772,11 @@ impl ByteCompiler<'_> {
                             lex_env.create_mutable_binding(name, false);
                         }
                     }
-                    Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                    Declaration::Lexical(
+                        LexicalDeclaration::Const(declaration)
+                        | LexicalDeclaration::Using(declaration)
+                        | LexicalDeclaration::AwaitUsing(declaration),
+                    ) => {
                         for name in bound_names(declaration) {
                             let name = name.to_js_string(self.interner());
                             lex_env.create_immutable_binding(name, true);
@@ -740,18 +790,18 @@ impl ByteCompiler<'_> {
         // 17. For each Parse Node f of functionsToInitialize, do
         for function in functions_to_initialize {
             // a. Let fn be the sole element of the BoundNames of f.
-            let (name, generator, r#async, parameters, body) = match &function {
+            let (name, generator, r#async, parameters, body, span) = match &function {
                 VarScopedDeclaration::Function(f) => {
-                    (f.name(), false, false, f.parameters(), f.body())
+                    (f.name(), false, false, f.parameters(), f.body(), f.span())
                 }
                 VarScopedDeclaration::Generator(f) => {
-                    (f.name(), true, false, f.parameters(), f.body())
+                    (f.name(), true, false, f.parameters(), f.body(), f.span())
                 }
                 VarScopedDeclaration::AsyncFunction(f) => {
-                    (f.name(), false, true, f.parameters(), f.body())
+                    (f.name(), false, true, f.parameters(), f.body(), f.span())
                 }
                 VarScopedDeclaration::AsyncGenerator(f) => {
-                    (f.name(), true, true, f.parameters(), f.body())
+                    (f.name(), true, true, f.parameters(), f.body(), f.span())
                 }
                 VarScopedDeclaration::VariableDeclaration(_) => {
                     continue;
@@ -764,6 +814,7 @@ impl ByteCompiler<'_> {
                 .r#async(r#async)
                 .strict(self.strict())
                 .binding_identifier(Some(name.sym().to_js_string(self.interner())))
+                .source_text(self.source_text.clone(), span)
                 .compile(
                     parameters,
                     body,
@@ -1241,7 +1292,11 @@ impl ByteCompiler<'_> {
                             lex_env.create_mutable_binding(name, false);
                         }
                     }
-                    Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                    Declaration::Lexical(
+                        LexicalDeclaration::Const(declaration)
+                        | LexicalDeclaration::Using(declaration)
+                        | LexicalDeclaration::AwaitUsing(declaration),
+                    ) => {
                         for name in bound_names(declaration) {
                             let name = name.to_js_string(self.interner());
                             lex_env.create_immutable_binding(name, true);
@@ -12,6 +12,9 @@ impl ByteCompiler<'_> {
             self.lexical_environment.clone(),
             function_scope,
         ));
+        if self.has_direct_eval {
+            env.set_dynamic();
+        }
 
         let index = self.constants.len() as u32;
         self.constants
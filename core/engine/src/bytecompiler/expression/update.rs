@@ -29,7 +29,10 @@ impl ByteCompiler<'_> {
                     .get_identifier_reference(name.clone());
                 let index = self.get_or_insert_binding(binding.locator());
 
-                if binding.is_lexical() {
+                let resolved = binding.is_lexical() || binding.is_static();
+                if binding.is_static() {
+                    self.emit_with_varying_operand(Opcode::GetBinding, index);
+                } else if resolved {
                     self.emit_with_varying_operand(Opcode::GetName, index);
                 } else {
                     self.emit_with_varying_operand(Opcode::GetNameAndLocator, index);
@@ -42,11 +45,16 @@ impl ByteCompiler<'_> {
                     self.emit_opcode(Opcode::Dup);
                 }
 
-                if binding.is_lexical() {
+                if resolved {
+                    let opcode = if binding.is_static() {
+                        Opcode::SetBinding
+                    } else {
+                        Opcode::SetName
+                    };
                     match self.lexical_environment.set_mutable_binding(name.clone()) {
                         Ok(binding) => {
                             let index = self.get_or_insert_binding(binding);
-                            self.emit_with_varying_operand(Opcode::SetName, index);
+                            self.emit_with_varying_operand(opcode, index);
                         }
                         Err(BindingLocatorError::MutateImmutable) => {
                             let index = self.get_or_insert_string(name);
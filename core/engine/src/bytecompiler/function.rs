@@ -8,8 +8,13 @@ use crate::{
     vm::{CodeBlock, CodeBlockFlags, Opcode},
     Context, JsString,
 };
-use boa_ast::function::{FormalParameterList, FunctionBody};
+use boa_ast::{
+    function::{FormalParameterList, FunctionBody},
+    operations::contains_direct_eval,
+    Span,
+};
 use boa_gc::Gc;
+use boa_parser::source::SourceText;
 
 /// `FunctionCompiler` is used to compile AST functions to bytecode.
 #[derive(Debug, Clone)]
@@ -22,6 +27,8 @@ pub(crate) struct FunctionCompiler {
     arrow: bool,
     method: bool,
     binding_identifier: Option<JsString>,
+    source_text: Option<Rc<SourceText>>,
+    span: Option<Span>,
 }
 
 impl FunctionCompiler {
@@ -35,6 +42,8 @@ impl FunctionCompiler {
             arrow: false,
             method: false,
             binding_identifier: None,
+            source_text: None,
+            span: None,
         }
     }
 
@@ -84,6 +93,17 @@ impl FunctionCompiler {
         self
     }
 
+    /// Set the source text where the function was defined, and the span of the function in it.
+    pub(crate) fn source_text(
+        mut self,
+        source_text: Option<Rc<SourceText>>,
+        span: Option<Span>,
+    ) -> Self {
+        self.source_text = source_text;
+        self.span = span;
+        self
+    }
+
     /// Compile a function statement list and it's parameters into bytecode.
     pub(crate) fn compile(
         mut self,
@@ -106,6 +126,10 @@ impl FunctionCompiler {
             context,
         );
         compiler.length = length;
+        compiler.source_text = self.source_text;
+        compiler.source_span = self.span;
+        compiler.has_direct_eval =
+            !self.strict && (contains_direct_eval(parameters) || contains_direct_eval(body));
         compiler
             .code_block_flags
             .set(CodeBlockFlags::IS_ASYNC, self.r#async);
@@ -180,7 +204,7 @@ impl FunctionCompiler {
 
         compiler.compile_statement_list(body.statements(), false, false);
 
-        compiler.params = parameters.clone();
+        compiler.set_parameters(parameters);
 
         Gc::new(compiler.finish())
     }
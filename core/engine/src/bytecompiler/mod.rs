@@ -13,10 +13,13 @@ mod utils;
 
 use std::{cell::Cell, rc::Rc};
 
+#[cfg(feature = "bytecode-cache")]
+use crate::vm::GlobalDeclarations;
 use crate::{
     builtins::function::ThisMode,
     environments::{BindingLocator, BindingLocatorError, CompileTimeEnvironment},
     js_string,
+    optimizer::{peephole, OptimizerOptions},
     vm::{
         BindingOpcode, CodeBlock, CodeBlockFlags, Constant, GeneratorResumeKind, Handler,
         InlineCache, Opcode, VaryingOperandKind,
@@ -34,16 +37,18 @@ use boa_ast::{
         ArrowFunction, AsyncArrowFunction, AsyncFunction, AsyncGenerator, Class,
         FormalParameterList, Function, FunctionBody, Generator, PrivateName,
     },
-    operations::returns_value,
+    operations::{bound_names, returns_value},
     pattern::Pattern,
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Position, Span, Spanned, Statement, StatementList, StatementListItem,
 };
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
+use boa_parser::source::SourceText;
 use rustc_hash::FxHashMap;
 
 pub(crate) use function::FunctionCompiler;
 pub(crate) use jump_control::JumpControlInfo;
+use statement::using_declarations_kind;
 use thin_vec::ThinVec;
 
 pub(crate) trait ToJsString {
@@ -102,6 +107,7 @@ pub(crate) struct FunctionSpec<'a> {
     parameters: &'a FormalParameterList,
     body: &'a FunctionBody,
     has_binding_identifier: bool,
+    span: Option<Span>,
 }
 
 impl<'a> From<&'a Function> for FunctionSpec<'a> {
@@ -112,6 +118,7 @@ impl<'a> From<&'a Function> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            span: function.span(),
         }
     }
 }
@@ -124,6 +131,7 @@ impl<'a> From<&'a ArrowFunction> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: false,
+            span: function.span(),
         }
     }
 }
@@ -136,6 +144,7 @@ impl<'a> From<&'a AsyncArrowFunction> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: false,
+            span: function.span(),
         }
     }
 }
@@ -148,6 +157,7 @@ impl<'a> From<&'a AsyncFunction> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            span: function.span(),
         }
     }
 }
@@ -160,6 +170,7 @@ impl<'a> From<&'a Generator> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            span: function.span(),
         }
     }
 }
@@ -172,6 +183,7 @@ impl<'a> From<&'a AsyncGenerator> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            span: function.span(),
         }
     }
 }
@@ -260,8 +272,11 @@ pub struct ByteCompiler<'ctx> {
     /// \[\[ThisMode\]\]
     pub(crate) this_mode: ThisMode,
 
-    /// Parameters passed to this function.
-    pub(crate) params: FormalParameterList,
+    /// The number of formal parameters, including the rest parameter.
+    pub(crate) parameter_count: u32,
+
+    /// The bound names of the formal parameters if they are a simple parameter list.
+    pub(crate) parameter_names: Vec<JsString>,
 
     /// Bytecode
     pub(crate) bytecode: Vec<u8>,
@@ -281,6 +296,16 @@ pub struct ByteCompiler<'ctx> {
     current_stack_value_count: u32,
     pub(crate) code_block_flags: CodeBlockFlags,
     handlers: ThinVec<Handler>,
+
+    /// The source position where each statement starts, as `(pc, position)` pairs sorted by
+    /// `pc`.
+    positions: Vec<(u32, Position)>,
+
+    /// The source text of the script or module being compiled, if it is available.
+    pub(crate) source_text: Option<Rc<SourceText>>,
+
+    /// The span of the compiled function in the source text.
+    pub(crate) source_span: Option<Span>,
     pub(crate) ic: Vec<InlineCache>,
     literals_map: FxHashMap<Literal, u32>,
     names_map: FxHashMap<Identifier, u32>,
@@ -293,11 +318,20 @@ pub struct ByteCompiler<'ctx> {
     pub(crate) async_handler: Option<u32>,
     json_parse: bool,
 
+    /// Indicates if the compiled code contains a non-strict direct `eval` call, which can add
+    /// bindings to the environments of the code at runtime.
+    pub(crate) has_direct_eval: bool,
+
     // TODO: remove when we separate scripts from the context
     pub(crate) context: &'ctx mut Context,
 
     #[cfg(feature = "annex-b")]
     annex_b_function_names: Vec<Identifier>,
+
+    /// The global declarations of the compiled script, recorded to replay them when the script is
+    /// loaded from a bytecode cache.
+    #[cfg(feature = "bytecode-cache")]
+    global_declarations: Option<Box<GlobalDeclarations>>,
 }
 
 impl<'ctx> ByteCompiler<'ctx> {
@@ -326,13 +360,17 @@ impl<'ctx> ByteCompiler<'ctx> {
             constants: ThinVec::default(),
             bindings: Vec::default(),
             this_mode: ThisMode::Global,
-            params: FormalParameterList::default(),
+            parameter_count: 0,
+            parameter_names: Vec::new(),
             current_open_environments_count: 0,
 
             register_count: 0,
             current_stack_value_count: 0,
             code_block_flags,
             handlers: ThinVec::default(),
+            positions: Vec::default(),
+            source_text: None,
+            source_span: None,
             ic: Vec::default(),
 
             literals_map: FxHashMap::default(),
@@ -341,12 +379,16 @@ impl<'ctx> ByteCompiler<'ctx> {
             jump_info: Vec::new(),
             async_handler: None,
             json_parse,
+            has_direct_eval: false,
             variable_environment,
             lexical_environment,
             context,
 
             #[cfg(feature = "annex-b")]
             annex_b_function_names: Vec::new(),
+
+            #[cfg(feature = "bytecode-cache")]
+            global_declarations: None,
         }
     }
 
@@ -370,6 +412,17 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.context.interner()
     }
 
+    /// Records the formal parameters of the compiled function in the code block.
+    pub(crate) fn set_parameters(&mut self, parameters: &FormalParameterList) {
+        self.parameter_count = parameters.as_ref().len() as u32;
+        if parameters.is_simple() {
+            self.parameter_names = bound_names(parameters)
+                .into_iter()
+                .map(|name| name.to_js_string(self.interner()))
+                .collect();
+        }
+    }
+
     fn get_or_insert_literal(&mut self, literal: Literal) -> u32 {
         if let Some(index) = self.literals_map.get(&literal) {
             return *index;
@@ -457,10 +510,11 @@ impl<'ctx> ByteCompiler<'ctx> {
                 self.emit_with_varying_operand(Opcode::PutLexicalValue, index);
             }
             BindingOpcode::SetName => {
+                let opcode = self.set_name_opcode(name.clone());
                 match self.lexical_environment.set_mutable_binding(name.clone()) {
                     Ok(binding) => {
                         let index = self.get_or_insert_binding(binding);
-                        self.emit_with_varying_operand(Opcode::SetName, index);
+                        self.emit_with_varying_operand(opcode, index);
                     }
                     Err(BindingLocatorError::MutateImmutable) => {
                         let index = self.get_or_insert_string(name);
@@ -479,6 +533,19 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.bytecode.len() as u32
     }
 
+    /// Records that the code of a statement starting at `position` begins at the next
+    /// instruction.
+    fn record_position(&mut self, position: Position) {
+        let pc = self.next_opcode_location();
+        match self.positions.last_mut() {
+            // A nested statement that starts before any code of its parent, like the first
+            // statement of a block, is more precise than its parent.
+            Some(last) if last.0 == pc => last.1 = position,
+            Some(last) if last.1 == position => {}
+            _ => self.positions.push((pc, position)),
+        }
+    }
+
     pub(crate) fn emit(&mut self, opcode: Opcode, operands: &[Operand]) {
         let mut varying_kind = VaryingOperandKind::U8;
         for operand in operands {
@@ -733,6 +800,19 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.patch_jump_with_target(label, target);
     }
 
+    /// Returns the opcode to assign the binding with the given name.
+    fn set_name_opcode(&self, name: JsString) -> Opcode {
+        if self
+            .lexical_environment
+            .get_identifier_reference(name)
+            .is_static()
+        {
+            Opcode::SetBinding
+        } else {
+            Opcode::SetName
+        }
+    }
+
     fn resolve_identifier_expect(&self, identifier: Identifier) -> JsString {
         js_string!(self.interner().resolve_expect(identifier.sym()).utf16())
     }
@@ -743,7 +823,12 @@ impl<'ctx> ByteCompiler<'ctx> {
                 let name = self.resolve_identifier_expect(name);
                 let binding = self.lexical_environment.get_identifier_reference(name);
                 let index = self.get_or_insert_binding(binding.locator());
-                self.emit_with_varying_operand(Opcode::GetName, index);
+                let opcode = if binding.is_static() {
+                    Opcode::GetBinding
+                } else {
+                    Opcode::GetName
+                };
+                self.emit_with_varying_operand(opcode, index);
             }
             Access::Property { access } => match access {
                 PropertyAccess::Simple(access) => match access.field() {
@@ -811,7 +896,10 @@ impl<'ctx> ByteCompiler<'ctx> {
                     .get_identifier_reference(name.clone());
                 let index = self.get_or_insert_binding(binding.locator());
 
-                if !binding.is_lexical() {
+                // Static bindings cannot change while evaluating the expression, so the reference
+                // doesn't have to be resolved beforehand.
+                let resolved = binding.is_lexical() || binding.is_static();
+                if !resolved {
                     self.emit_with_varying_operand(Opcode::GetLocator, index);
                 }
 
@@ -820,11 +908,16 @@ impl<'ctx> ByteCompiler<'ctx> {
                     self.emit(Opcode::Dup, &[]);
                 }
 
-                if binding.is_lexical() {
+                if resolved {
+                    let opcode = if binding.is_static() {
+                        Opcode::SetBinding
+                    } else {
+                        Opcode::SetName
+                    };
                     match self.lexical_environment.set_mutable_binding(name.clone()) {
                         Ok(binding) => {
                             let index = self.get_or_insert_binding(binding);
-                            self.emit_with_varying_operand(Opcode::SetName, index);
+                            self.emit_with_varying_operand(opcode, index);
                         }
                         Err(BindingLocatorError::MutateImmutable) => {
                             let index = self.get_or_insert_string(name);
@@ -930,7 +1023,25 @@ impl<'ctx> ByteCompiler<'ctx> {
     }
 
     /// Compile a [`StatementList`].
+    ///
+    /// If the list contains `using` declarations, its items are compiled inside a scope that
+    /// disposes their resources.
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool, block: bool) {
+        match using_declarations_kind(list.statements()) {
+            Some(r#async) => self.compile_using_scope(r#async, use_expr, |compiler| {
+                compiler.compile_statement_list_items(list, use_expr, block);
+            }),
+            None => self.compile_statement_list_items(list, use_expr, block),
+        }
+    }
+
+    /// Compiles the items of a [`StatementList`], without a scope for its `using` declarations.
+    pub(crate) fn compile_statement_list_items(
+        &mut self,
+        list: &StatementList,
+        use_expr: bool,
+        block: bool,
+    ) {
         if use_expr || self.jump_control_info_has_use_expr() {
             let mut use_expr_index = 0;
             for (i, statement) in list.statements().iter().enumerate() {
@@ -1214,6 +1325,22 @@ impl<'ctx> ByteCompiler<'ctx> {
                     }
                 }
             }
+            LexicalDeclaration::Using(decls) | LexicalDeclaration::AwaitUsing(decls) => {
+                let r#async = matches!(decl, LexicalDeclaration::AwaitUsing(_));
+                for variable in decls.as_ref() {
+                    let Binding::Identifier(ident) = variable.binding() else {
+                        unreachable!("using declarations cannot contain binding patterns");
+                    };
+                    let ident = ident.to_js_string(self.interner());
+                    let init = variable
+                        .init()
+                        .expect("using declaration must have initializer");
+                    self.compile_expr(init, true);
+                    self.emit_opcode(Opcode::Dup);
+                    self.emit(Opcode::AddDisposableResource, &[Operand::Bool(r#async)]);
+                    self.emit_binding(BindingOpcode::InitLexical, ident);
+                }
+            }
         };
     }
 
@@ -1223,7 +1350,12 @@ impl<'ctx> ByteCompiler<'ctx> {
             StatementListItem::Statement(stmt) => {
                 self.compile_stmt(stmt, use_expr, false);
             }
-            StatementListItem::Declaration(decl) => self.compile_decl(decl, block),
+            StatementListItem::Declaration(decl) => {
+                if let Some(span) = decl.span() {
+                    self.record_position(span.start());
+                }
+                self.compile_decl(decl, block);
+            }
         }
     }
 
@@ -1281,6 +1413,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             parameters,
             body,
             has_binding_identifier,
+            span,
             ..
         } = function;
 
@@ -1303,6 +1436,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             .strict(self.strict())
             .arrow(arrow)
             .binding_identifier(binding_identifier)
+            .source_text(self.source_text.clone(), span)
             .compile(
                 parameters,
                 body,
@@ -1355,6 +1489,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             parameters,
             body,
             has_binding_identifier,
+            span,
             ..
         } = function;
 
@@ -1378,6 +1513,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             .arrow(arrow)
             .method(true)
             .binding_identifier(binding_identifier)
+            .source_text(self.source_text.clone(), span)
             .compile(
                 parameters,
                 body,
@@ -1402,6 +1538,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             parameters,
             body,
             has_binding_identifier,
+            span,
             ..
         } = function;
 
@@ -1425,6 +1562,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             .arrow(arrow)
             .method(true)
             .binding_identifier(binding_identifier)
+            .source_text(self.source_text.clone(), span)
             .compile(
                 parameters,
                 body,
@@ -1538,18 +1676,33 @@ impl<'ctx> ByteCompiler<'ctx> {
             handler.stack_count += self.register_count;
         }
 
+        let mut bytecode = self.bytecode;
+        if self
+            .context
+            .optimizer_options()
+            .contains(OptimizerOptions::PEEPHOLE)
+        {
+            bytecode = peephole::optimize(bytecode, &mut self.handlers, &mut self.positions);
+        }
+
         CodeBlock {
             name: self.function_name,
             length: self.length,
             register_count: self.register_count,
             this_mode: self.this_mode,
-            params: self.params,
-            bytecode: self.bytecode.into_boxed_slice(),
+            parameter_count: self.parameter_count,
+            parameter_names: self.parameter_names.into_boxed_slice(),
+            bytecode: bytecode.into_boxed_slice(),
             constants: self.constants,
             bindings: self.bindings.into_boxed_slice(),
             handlers: self.handlers,
+            positions: self.positions.into_boxed_slice(),
             flags: Cell::new(self.code_block_flags),
             ic: self.ic.into_boxed_slice(),
+            source_text: self.source_text,
+            source_span: self.source_span,
+            #[cfg(feature = "bytecode-cache")]
+            global_declarations: self.global_declarations,
         }
     }
 
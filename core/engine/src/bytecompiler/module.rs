@@ -1,6 +1,6 @@
 use crate::vm::{BindingOpcode, Opcode};
 
-use super::{ByteCompiler, Literal, Operand, ToJsString};
+use super::{using_declarations_kind, ByteCompiler, Literal, Operand, ToJsString};
 use boa_ast::{declaration::ExportDeclaration, ModuleItem, ModuleItemList};
 use boa_interner::Sym;
 
@@ -8,8 +8,24 @@ impl ByteCompiler<'_> {
     /// Compiles a [`ModuleItemList`].
     #[inline]
     pub fn compile_module_item_list(&mut self, list: &ModuleItemList) {
-        for node in list.items() {
-            self.compile_module_item(node);
+        let statements = list.items().iter().filter_map(|item| match item {
+            ModuleItem::StatementListItem(item) => Some(item),
+            _ => None,
+        });
+
+        // The resources of the `using` declarations of a module are disposed once it has been
+        // evaluated.
+        match using_declarations_kind(statements) {
+            Some(r#async) => self.compile_using_scope(r#async, false, |compiler| {
+                for node in list.items() {
+                    compiler.compile_module_item(node);
+                }
+            }),
+            None => {
+                for node in list.items() {
+                    self.compile_module_item(node);
+                }
+            }
         }
     }
 
@@ -70,8 +70,9 @@ impl ByteCompiler<'_> {
             .set_start_address(start_address);
 
         if let Some((let_binding_indices, env_index)) = &let_binding_indices {
+            // The bindings are declared in the current environment, so they cannot be shadowed.
             for index in let_binding_indices {
-                self.emit_with_varying_operand(Opcode::GetName, *index);
+                self.emit_with_varying_operand(Opcode::GetBinding, *index);
             }
 
             self.emit_opcode(Opcode::PopEnvironment);
@@ -1,6 +1,6 @@
 use crate::{bytecompiler::ByteCompiler, vm::Opcode};
 
-use boa_ast::Statement;
+use boa_ast::{Spanned, Statement};
 
 use super::jump_control::{JumpRecord, JumpRecordAction, JumpRecordKind};
 
@@ -12,11 +12,18 @@ mod labelled;
 mod r#loop;
 mod switch;
 mod r#try;
+mod using;
 mod with;
 
+pub(crate) use using::using_declarations_kind;
+
 impl ByteCompiler<'_> {
     /// Compiles a [`Statement`] `boa_ast` node.
     pub fn compile_stmt(&mut self, node: &Statement, use_expr: bool, root_statement: bool) {
+        if let Some(span) = node.span() {
+            self.record_position(span.start());
+        }
+
         match node {
             Statement::Var(var) => self.compile_var_decl(var),
             Statement::If(node) => self.compile_if(node, use_expr),
@@ -84,6 +91,10 @@ impl ByteCompiler<'_> {
             }
             Statement::With(with) => self.compile_with(with, use_expr),
             Statement::Empty => {}
+            Statement::Debugger(_) => self.emit_opcode(Opcode::Debugger),
+            Statement::Error(_) => {
+                unreachable!("scripts recovered from syntax errors must not be compiled")
+            }
         }
     }
 
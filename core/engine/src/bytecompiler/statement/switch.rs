@@ -1,5 +1,22 @@
-use crate::{bytecompiler::ByteCompiler, vm::Opcode};
-use boa_ast::statement::Switch;
+use crate::{
+    bytecompiler::{statement::using_declarations_kind, ByteCompiler, Label, Operand},
+    vm::Opcode,
+    JsString,
+};
+use boa_ast::{
+    expression::literal::Literal,
+    statement::{Case, Switch},
+    Expression,
+};
+
+/// The minimum number of cases of a `switch` to dispatch them with a table.
+const MIN_TABLE_CASES: usize = 4;
+
+/// The value of a `case` that can be looked up in a table.
+enum CaseKey {
+    Integer(i32),
+    String(JsString),
+}
 
 impl ByteCompiler<'_> {
     /// Compile a [`Switch`] `boa_ast` node
@@ -16,44 +33,179 @@ impl ByteCompiler<'_> {
         let start_address = self.next_opcode_location();
         self.push_switch_control_info(None, start_address, use_expr);
 
-        let mut labels = Vec::with_capacity(switch.cases().len());
-        for case in switch.cases() {
+        // The `using` declarations of all the cases share the scope of the case block.
+        match using_declarations_kind(
+            switch
+                .cases()
+                .iter()
+                .flat_map(|case| case.body().statements()),
+        ) {
+            Some(r#async) => self.compile_using_scope(r#async, use_expr, |compiler| {
+                compiler.compile_switch_cases(switch, use_expr);
+            }),
+            None => self.compile_switch_cases(switch, use_expr),
+        }
+
+        self.pop_switch_control_info();
+
+        self.pop_compile_environment();
+        self.lexical_environment = old_lex_env;
+        self.emit_opcode(Opcode::PopEnvironment);
+    }
+
+    /// Compiles the dispatch to the cases of a [`Switch`] and their bodies.
+    fn compile_switch_cases(&mut self, switch: &Switch, use_expr: bool) {
+        let (labels, default_labels) = self
+            .compile_switch_table(switch.cases())
+            .unwrap_or_else(|| self.compile_switch_comparisons(switch.cases()));
+
+        let mut default_labels = Some(default_labels);
+        for (labels, case) in labels.into_iter().zip(switch.cases()) {
+            if case.is_default() {
+                for label in default_labels.take().into_iter().flatten() {
+                    self.patch_jump(label);
+                }
+            }
+            for label in labels {
+                self.patch_jump(label);
+            }
+
+            self.compile_statement_list_items(case.body(), use_expr, true);
+        }
+
+        for label in default_labels.into_iter().flatten() {
+            self.patch_jump(label);
+        }
+    }
+
+    /// Compares the discriminant with each of the `cases` in order.
+    ///
+    /// Returns the labels to patch with the start of the body of each case, and the labels to
+    /// patch with the start of the default case.
+    fn compile_switch_comparisons(&mut self, cases: &[Case]) -> (Vec<Vec<Label>>, Vec<Label>) {
+        let mut labels = Vec::with_capacity(cases.len());
+        for case in cases {
             // If it does not have a condition it is the default case.
-            let label = if let Some(condition) = case.condition() {
+            if let Some(condition) = case.condition() {
                 self.compile_expr(condition, true);
 
-                self.emit_opcode_with_operand(Opcode::Case)
+                labels.push(vec![self.emit_opcode_with_operand(Opcode::Case)]);
             } else {
-                Self::DUMMY_LABEL
-            };
-
-            labels.push(label);
+                labels.push(Vec::new());
+            }
         }
 
         let default_label = self.emit_opcode_with_operand(Opcode::Default);
-        let mut default_label_set = false;
+        (labels, vec![default_label])
+    }
 
-        for (label, case) in labels.into_iter().zip(switch.cases()) {
-            // Check if it's the default case.
-            let label = if label == Self::DUMMY_LABEL {
-                default_label_set = true;
-                default_label
-            } else {
-                label
-            };
-            self.patch_jump(label);
+    /// Looks up the discriminant in a table of the `cases`, if all of them are integer or string
+    /// literals.
+    ///
+    /// Evaluating a literal has no side effects, so skipping the evaluation of the cases that are
+    /// not compared doesn't change the behaviour of the `switch`.
+    ///
+    /// Returns the same labels as [`Self::compile_switch_comparisons`].
+    fn compile_switch_table(&mut self, cases: &[Case]) -> Option<(Vec<Vec<Label>>, Vec<Label>)> {
+        let keys = cases
+            .iter()
+            .enumerate()
+            .filter_map(|(index, case)| Some((index, case.condition()?)))
+            .map(|(index, condition)| Some((index, self.case_key(condition)?)))
+            .collect::<Option<Vec<_>>>()?;
+        if keys.len() < MIN_TABLE_CASES {
+            return None;
+        }
 
-            self.compile_statement_list(case.body(), use_expr, true);
+        let mut integers = Vec::with_capacity(keys.len());
+        let mut strings = Vec::with_capacity(keys.len());
+        for (index, key) in keys {
+            match key {
+                CaseKey::Integer(value) => integers.push((index, value)),
+                CaseKey::String(value) => strings.push((index, value)),
+            }
         }
 
-        if !default_label_set {
-            self.patch_jump(default_label);
+        let mut labels = vec![Vec::new(); cases.len()];
+        let mut default_labels = Vec::new();
+        if strings.is_empty() {
+            let min = integers.iter().map(|(_, value)| *value).min()?;
+            let max = integers.iter().map(|(_, value)| *value).max()?;
+            let span = usize::try_from(i64::from(max) - i64::from(min) + 1).ok()?;
+            if span > integers.len() * 2 {
+                return None;
+            }
+
+            // Cases with the same value are never reached, since the first one is taken.
+            let mut slots = vec![None; span];
+            for (index, value) in integers {
+                slots[(i64::from(value) - i64::from(min)) as usize].get_or_insert(index);
+            }
+
+            let start = self.next_opcode_location();
+            let mut operands = vec![
+                Operand::U32(Self::DUMMY_ADDRESS),
+                Operand::I32(min),
+                Operand::U32(span as u32),
+            ];
+            operands.extend(slots.iter().map(|_| Operand::U32(Self::DUMMY_ADDRESS)));
+            self.emit(Opcode::SwitchInteger, &operands);
+
+            default_labels.push(Label { index: start });
+            for (i, slot) in slots.into_iter().enumerate() {
+                let label = Label {
+                    index: start + 12 + 4 * i as u32,
+                };
+                match slot {
+                    Some(index) => labels[index].push(label),
+                    None => default_labels.push(label),
+                }
+            }
+        } else if integers.is_empty() {
+            // The binary search at runtime expects the keys to be sorted and unique.
+            strings.sort_by(|(a_index, a), (b_index, b)| a.cmp(b).then(a_index.cmp(b_index)));
+            strings.dedup_by(|(_, a), (_, b)| a == b);
+
+            let count = strings.len() as u32;
+            let mut operands = vec![Operand::U32(Self::DUMMY_ADDRESS), Operand::U32(count)];
+            for (_, value) in &strings {
+                operands.push(Operand::U32(self.get_or_insert_string(value.clone())));
+            }
+            operands.push(Operand::U32(count));
+            operands.extend(strings.iter().map(|_| Operand::U32(Self::DUMMY_ADDRESS)));
+
+            let start = self.next_opcode_location();
+            self.emit(Opcode::SwitchString, &operands);
+
+            default_labels.push(Label { index: start });
+            for (i, (index, _)) in strings.into_iter().enumerate() {
+                labels[index].push(Label {
+                    index: start + 12 + 4 * (count + i as u32),
+                });
+            }
+        } else {
+            return None;
         }
 
-        self.pop_switch_control_info();
+        Some((labels, default_labels))
+    }
 
-        self.pop_compile_environment();
-        self.lexical_environment = old_lex_env;
-        self.emit_opcode(Opcode::PopEnvironment);
+    /// Returns the value of a `case` condition, if it can be looked up in a table.
+    #[allow(clippy::float_cmp)]
+    fn case_key(&self, condition: &Expression) -> Option<CaseKey> {
+        let Expression::Literal(literal) = condition else {
+            return None;
+        };
+        match literal.literal() {
+            Literal::Int(value) => Some(CaseKey::Integer(*value)),
+            // `-0` is strictly equal to `+0`, so it can share its key.
+            Literal::Num(value) if f64::from(*value as i32) == *value => {
+                Some(CaseKey::Integer(*value as i32))
+            }
+            Literal::String(value) => Some(CaseKey::String(
+                self.interner().resolve_expect(*value).into_common(false),
+            )),
+            _ => None,
+        }
     }
 }
@@ -0,0 +1,117 @@
+use crate::{
+    bytecompiler::{jump_control::JumpControlInfoFlags, ByteCompiler},
+    vm::{GeneratorResumeKind, Opcode},
+};
+use boa_ast::{declaration::LexicalDeclaration, Declaration, StatementListItem};
+
+impl ByteCompiler<'_> {
+    /// Compiles the code emitted by `body` inside a scope that disposes the resources of the
+    /// `using` declarations evaluated by it when the scope is exited.
+    ///
+    /// The scope is compiled like a `try` statement with a `finally` block, so the resources are
+    /// also disposed on `break`, `continue`, `return` and when an exception is thrown. If `async`
+    /// is `true`, the results of the dispose methods are awaited.
+    pub(crate) fn compile_using_scope<F>(&mut self, r#async: bool, use_expr: bool, body: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.emit_opcode(Opcode::PushDisposeScope);
+        self.push_try_with_finally_control_info(use_expr);
+
+        let try_handler = self.push_handler();
+        body(self);
+
+        // stack: false, 0
+        self.emit_opcode(Opcode::PushZero);
+        self.emit_opcode(Opcode::PushFalse);
+        let finally = self.jump();
+
+        self.patch_handler(try_handler);
+
+        // If we are in a generator, we still need to handle `return()` calls on generators.
+        let catch_handler = self.is_generator().then(|| self.push_handler());
+
+        // stack: true, 0
+        self.emit_opcode(Opcode::Exception);
+        self.emit_opcode(Opcode::SetDisposeError);
+        self.emit_opcode(Opcode::PushZero);
+        self.emit_opcode(Opcode::PushTrue);
+
+        if let Some(catch_handler) = catch_handler {
+            let exit = self.jump();
+            self.patch_handler(catch_handler);
+
+            // This is a generator `return()` empty exception, rethrown after the resources are
+            // disposed.
+            self.emit_opcode(Opcode::PushZero);
+            self.emit_opcode(Opcode::PushTrue);
+
+            self.patch_jump(exit);
+        }
+
+        self.patch_jump(finally);
+
+        let finally_start = self.next_opcode_location();
+        let info = self
+            .jump_info
+            .last_mut()
+            .expect("there should be a try block");
+        info.flags |= JumpControlInfoFlags::IN_FINALLY;
+        let has_jumps = !info.jumps.is_empty();
+
+        self.current_stack_value_count += 3;
+        self.emit_opcode(Opcode::GetReturnValue);
+        if r#async {
+            self.emit_opcode(Opcode::PushUndefined);
+            self.emit_resume_kind(GeneratorResumeKind::Normal);
+
+            let start = self.next_opcode_location();
+            self.emit_opcode(Opcode::DisposeNext);
+            let done = self.jump_if_true();
+            self.emit_opcode(Opcode::Await);
+            let jump = self.jump();
+            self.patch_jump_with_target(jump, start);
+
+            self.patch_jump(done);
+            self.emit_opcode(Opcode::Pop);
+        } else {
+            self.emit_opcode(Opcode::DisposeResources);
+        }
+        self.emit_opcode(Opcode::SetReturnValue);
+        self.current_stack_value_count -= 3;
+
+        // Rethrow the exception or the generator `return()` empty exception, if any. The errors
+        // recorded while the scope was running are thrown when the resources are disposed.
+        let do_not_throw_exit = self.jump_if_false();
+        self.emit_opcode(Opcode::ReThrow);
+        self.patch_jump(do_not_throw_exit);
+
+        self.pop_try_with_finally_control_info(finally_start);
+
+        // The jump table index is only consumed if there were jumps to handle.
+        if !has_jumps {
+            self.emit_opcode(Opcode::Pop);
+        }
+    }
+}
+
+/// Returns `Some(true)` if `items` directly contain an `await using` declaration,
+/// `Some(false)` if they only contain `using` declarations and `None` otherwise.
+pub(crate) fn using_declarations_kind<'a, I>(items: I) -> Option<bool>
+where
+    I: IntoIterator<Item = &'a StatementListItem>,
+{
+    let mut kind = None;
+    for item in items {
+        match item {
+            StatementListItem::Declaration(Declaration::Lexical(
+                LexicalDeclaration::AwaitUsing(_),
+            )) => return Some(true),
+            StatementListItem::Declaration(Declaration::Lexical(LexicalDeclaration::Using(_))) => {
+                kind = Some(false);
+            }
+            _ => {}
+        }
+    }
+    kind
+}
@@ -8,6 +8,7 @@ impl ByteCompiler<'_> {
 
         let old_lex_env = self.lexical_environment.clone();
         let _ = self.push_compile_environment(false);
+        self.lexical_environment.set_dynamic();
         self.emit_opcode(Opcode::PushObjectEnvironment);
 
         self.compile_stmt(with.statement(), use_expr, true);
@@ -106,9 +106,14 @@
 use crate::{
     context::intrinsics::StandardConstructor,
     error::JsNativeError,
+    js_string,
     native_function::NativeFunction,
-    object::{ConstructorBuilder, FunctionBinding, JsFunction, JsObject, NativeObject, PROTOTYPE},
+    object::{
+        ConstructorBuilder, FunctionBinding, FunctionObjectBuilder, JsFunction, JsObject,
+        NativeObject, PROTOTYPE,
+    },
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    string::utf16,
     Context, JsResult, JsValue,
 };
 
@@ -238,6 +243,59 @@ pub trait Class: NativeObject + Sized {
 }
 
 /// Class builder which allows adding methods and static methods to the class.
+///
+/// # Examples
+///
+/// Exposing a counter backed by native data, with a `value` getter, an `increment` method and a
+/// static `Counter.zero()` constructor:
+///
+/// ```
+/// # use boa_engine::{
+/// #     class::{Class, ClassBuilder}, js_string, Context, JsArgs, JsData, JsResult, JsValue,
+/// #     NativeFunction, Source,
+/// # };
+/// # use boa_gc::{Finalize, Trace};
+/// #[derive(Debug, Trace, Finalize, JsData)]
+/// struct Counter {
+///     n: u64,
+/// }
+///
+/// impl Class for Counter {
+///     const NAME: &'static str = "Counter";
+///     const LENGTH: usize = 1;
+///
+///     fn data_constructor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+///         let n: Option<u64> = args.try_get(0, context)?;
+///         Ok(Self { n: n.unwrap_or_default() })
+///     }
+///
+///     fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+///         class
+///             .data_getter(js_string!("value"), |c: &Self| c.n as f64)
+///             .data_method(js_string!("increment"), 0, |c: &mut Self, _, _| {
+///                 c.n += 1;
+///                 Ok(c.n as f64)
+///             })
+///             .static_method(
+///                 js_string!("zero"),
+///                 0,
+///                 NativeFunction::from_fn_ptr(|_, _, context| {
+///                     Self::from_data(Self { n: 0 }, context).map(JsValue::from)
+///                 }),
+///             );
+///         Ok(())
+///     }
+/// }
+///
+/// let context = &mut Context::default();
+/// context.register_global_class::<Counter>()?;
+///
+/// let result = context.eval(Source::from_bytes(
+///     "const c = new Counter(41); c.increment(); [c.value, Counter.zero().value].join()",
+/// ))?;
+/// assert_eq!(result, js_string!("42,0").into());
+/// # Ok::<(), boa_engine::JsError>(())
+/// ```
 #[derive(Debug)]
 pub struct ClassBuilder<'ctx> {
     builder: ConstructorBuilder<'ctx>,
@@ -370,9 +428,74 @@ impl<'ctx> ClassBuilder<'ctx> {
         self
     }
 
+    /// Add a getter to the class that reads from the native data of `this`.
+    ///
+    /// It is added to `prototype` as a configurable, non-enumerable accessor, and throws a
+    /// `TypeError` when called on an object without native data of type `T`.
+    pub fn data_getter<K, T, V>(&mut self, key: K, getter: fn(&T) -> V) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+        T: NativeObject,
+        V: Into<JsValue> + 'static,
+    {
+        let function = NativeFunction::from_copy_closure(move |this, _, _| {
+            let data = this
+                .as_object()
+                .and_then(JsObject::downcast_ref::<T>)
+                .ok_or_else(incompatible_receiver)?;
+            Ok(getter(&data).into())
+        });
+        let key = key.into();
+        let name = match &key {
+            PropertyKey::String(name) => name.clone(),
+            PropertyKey::Symbol(symbol) => symbol.fn_name(),
+            PropertyKey::Index(index) => js_string!(index.get().to_string()),
+        };
+        let getter = FunctionObjectBuilder::new(self.context().realm(), function)
+            .name(js_string!(utf16!("get "), &name))
+            .build();
+        self.builder
+            .accessor(key, Some(getter), None, Attribute::CONFIGURABLE);
+        self
+    }
+
+    /// Add a method to the class that receives a mutable reference to the native data of `this`.
+    ///
+    /// It is added to `prototype`, and throws a `TypeError` when called on an object without
+    /// native data of type `T`.
+    ///
+    /// The native data stays borrowed while `method` runs, so `method` must not access it again
+    /// through the context (e.g. by calling back into JavaScript that uses `this`).
+    pub fn data_method<N, T, R>(
+        &mut self,
+        name: N,
+        length: usize,
+        method: fn(&mut T, &[JsValue], &mut Context) -> JsResult<R>,
+    ) -> &mut Self
+    where
+        N: Into<FunctionBinding>,
+        T: NativeObject,
+        R: Into<JsValue> + 'static,
+    {
+        let function = NativeFunction::from_copy_closure(move |this, args, context| {
+            let object = this.as_object().ok_or_else(incompatible_receiver)?;
+            let mut data = object
+                .downcast_mut::<T>()
+                .ok_or_else(incompatible_receiver)?;
+            method(&mut data, args, context).map(Into::into)
+        });
+        self.builder.method(function, name, length);
+        self
+    }
+
     /// Return the current context.
     #[inline]
     pub fn context(&mut self) -> &mut Context {
         self.builder.context()
     }
 }
+
+/// Creates the error thrown by native data accessors called on the wrong object.
+fn incompatible_receiver() -> JsNativeError {
+    JsNativeError::typ().with_message("native class method called on incompatible receiver")
+}
@@ -6,6 +6,7 @@ use crate::{
     realm::Realm,
     Context, JsResult, JsValue,
 };
+use std::time::Instant;
 use time::{OffsetDateTime, UtcOffset};
 
 #[cfg(test)]
@@ -181,6 +182,28 @@ pub trait HostHooks {
         now.unix_timestamp() * 1000 + i64::from(now.millisecond())
     }
 
+    /// Gets the current time of the monotonic clock of the host.
+    ///
+    /// Unlike [`HostHooks::utc_now`], which is used by `Date` and `Temporal.Now`, this clock
+    /// never goes backwards, so it can be used to measure elapsed time, e.g. by the
+    /// `performance.now()` function of the runtime.
+    ///
+    /// Defaults to using [`Instant::now`], which can cause panics if the target doesn't support
+    /// it.
+    fn monotonic_now(&self) -> Instant {
+        Instant::now()
+    }
+
+    /// Gets a random number in the range `[0, 1)`, returned by `Math.random()`.
+    ///
+    /// Overriding this hook allows hosts to make the random numbers reproducible, e.g. by using a
+    /// seeded generator in tests.
+    ///
+    /// Defaults to using [`rand::random`].
+    fn math_random(&self) -> f64 {
+        rand::random()
+    }
+
     /// Returns the offset of the local timezone to the `utc` timezone in seconds.
     fn local_timezone_offset_seconds(&self, unix_time_seconds: i64) -> i32 {
         // Safety: This is needed during tests because cargo is running tests in multiple threads.
@@ -42,10 +42,11 @@ impl Intrinsics {
     pub(crate) fn uninit(root_shape: &RootShape) -> Option<Self> {
         let constructors = StandardConstructors::default();
         let templates = ObjectTemplates::new(root_shape, &constructors);
+        let objects = IntrinsicObjects::uninit(constructors.iterator().prototype())?;
 
         Some(Self {
             constructors,
-            objects: IntrinsicObjects::uninit()?,
+            objects,
             templates,
         })
     }
@@ -131,6 +132,7 @@ pub struct StandardConstructors {
     async_function: StandardConstructor,
     generator_function: StandardConstructor,
     async_generator_function: StandardConstructor,
+    iterator: StandardConstructor,
     array: StandardConstructor,
     bigint: StandardConstructor,
     number: StandardConstructor,
@@ -146,6 +148,7 @@ pub struct StandardConstructors {
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
     aggregate_error: StandardConstructor,
+    suppressed_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
     typed_array: StandardConstructor,
@@ -168,6 +171,9 @@ pub struct StandardConstructors {
     weak_ref: StandardConstructor,
     weak_map: StandardConstructor,
     weak_set: StandardConstructor,
+    disposable_stack: StandardConstructor,
+    async_disposable_stack: StandardConstructor,
+    shadow_realm: StandardConstructor,
     #[cfg(feature = "intl")]
     collator: StandardConstructor,
     #[cfg(feature = "intl")]
@@ -179,7 +185,13 @@ pub struct StandardConstructors {
     #[cfg(feature = "intl")]
     plural_rules: StandardConstructor,
     #[cfg(feature = "intl")]
+    relative_time_format: StandardConstructor,
+    #[cfg(feature = "intl")]
     number_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    duration_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    display_names: StandardConstructor,
     #[cfg(feature = "temporal")]
     instant: StandardConstructor,
     #[cfg(feature = "temporal")]
@@ -218,6 +230,7 @@ impl Default for StandardConstructors {
             },
             async_function: StandardConstructor::default(),
             generator_function: StandardConstructor::default(),
+            iterator: StandardConstructor::default(),
             array: StandardConstructor::with_prototype(JsObject::from_proto_and_data(None, Array)),
             bigint: StandardConstructor::default(),
             number: StandardConstructor::with_prototype(JsObject::from_proto_and_data(None, 0.0)),
@@ -238,6 +251,7 @@ impl Default for StandardConstructors {
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
             aggregate_error: StandardConstructor::default(),
+            suppressed_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
             typed_array: StandardConstructor::default(),
@@ -260,6 +274,9 @@ impl Default for StandardConstructors {
             weak_ref: StandardConstructor::default(),
             weak_map: StandardConstructor::default(),
             weak_set: StandardConstructor::default(),
+            disposable_stack: StandardConstructor::default(),
+            async_disposable_stack: StandardConstructor::default(),
+            shadow_realm: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             collator: StandardConstructor::default(),
             #[cfg(feature = "intl")]
@@ -271,7 +288,13 @@ impl Default for StandardConstructors {
             #[cfg(feature = "intl")]
             plural_rules: StandardConstructor::default(),
             #[cfg(feature = "intl")]
+            relative_time_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
             number_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            duration_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            display_names: StandardConstructor::default(),
             #[cfg(feature = "temporal")]
             instant: StandardConstructor::default(),
             #[cfg(feature = "temporal")]
@@ -309,6 +332,18 @@ impl StandardConstructors {
         &self.async_generator_function
     }
 
+    /// Returns the `Iterator` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator-constructor
+    #[inline]
+    #[must_use]
+    pub const fn iterator(&self) -> &StandardConstructor {
+        &self.iterator
+    }
+
     /// Returns the `Object` constructor.
     ///
     /// More information:
@@ -561,6 +596,18 @@ impl StandardConstructors {
         &self.aggregate_error
     }
 
+    /// Returns the `SuppressedError` constructor.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-constructor
+    #[inline]
+    #[must_use]
+    pub const fn suppressed_error(&self) -> &StandardConstructor {
+        &self.suppressed_error
+    }
+
     /// Returns the `Map` constructor.
     ///
     /// More information:
@@ -825,6 +872,42 @@ impl StandardConstructors {
         &self.weak_set
     }
 
+    /// Returns the `DisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn disposable_stack(&self) -> &StandardConstructor {
+        &self.disposable_stack
+    }
+
+    /// Returns the `AsyncDisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn async_disposable_stack(&self) -> &StandardConstructor {
+        &self.async_disposable_stack
+    }
+
+    /// Returns the `ShadowRealm` constructor.
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-constructor
+    #[inline]
+    #[must_use]
+    pub const fn shadow_realm(&self) -> &StandardConstructor {
+        &self.shadow_realm
+    }
+
     /// Returns the `Intl.Collator` constructor.
     ///
     /// More information:
@@ -890,6 +973,19 @@ impl StandardConstructors {
         &self.plural_rules
     }
 
+    /// Returns the `Intl.RelativeTimeFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-relativetimeformat-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn relative_time_format(&self) -> &StandardConstructor {
+        &self.relative_time_format
+    }
+
     /// Returns the `Intl.NumberFormat` constructor.
     ///
     /// More information:
@@ -903,6 +999,32 @@ impl StandardConstructors {
         &self.number_format
     }
 
+    /// Returns the `Intl.DurationFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-intl-durationformat-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn duration_format(&self) -> &StandardConstructor {
+        &self.duration_format
+    }
+
+    /// Returns the `Intl.DisplayNames` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-displaynames-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn display_names(&self) -> &StandardConstructor {
+        &self.display_names
+    }
+
     /// Returns the `Temporal.Instant` constructor.
     ///
     /// More information:
@@ -1055,6 +1177,9 @@ pub struct IntrinsicObjects {
     /// [`%Array.prototype.toString%`](https://tc39.es/ecma262/#sec-array.prototype.tostring)
     array_prototype_to_string: JsFunction,
 
+    /// [`%ArrayIteratorPrototype%.next`](https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next)
+    array_iterator_prototype_next: JsFunction,
+
     /// Cached iterator prototypes.
     iterator_prototypes: IteratorPrototypes,
 
@@ -1085,6 +1210,9 @@ pub struct IntrinsicObjects {
     /// [`%parseInt%`](https://tc39.es/ecma262/#sec-parseint-string-radix)
     parse_int: JsFunction,
 
+    /// [`%structuredClone%`](https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone)
+    structured_clone: JsFunction,
+
     /// [`%escape%`](https://tc39.es/ecma262/#sec-escape-string)
     #[cfg(feature = "annex-b")]
     escape: JsFunction,
@@ -1118,8 +1246,11 @@ impl IntrinsicObjects {
     /// To initialize all the intrinsic objects with their spec properties, see [`Realm::initialize`].
     ///
     /// [`Realm::initialize`]: crate::realm::Realm::initialize
+    ///
+    /// The `%Iterator.prototype%` object is shared with the `Iterator` constructor, so it must be
+    /// created beforehand.
     #[allow(clippy::unnecessary_wraps)]
-    pub(crate) fn uninit() -> Option<Self> {
+    pub(crate) fn uninit(iterator_prototype: JsObject) -> Option<Self> {
         Some(Self {
             reflect: JsObject::default(),
             math: JsObject::default(),
@@ -1127,7 +1258,8 @@ impl IntrinsicObjects {
             throw_type_error: JsFunction::empty_intrinsic_function(false),
             array_prototype_values: JsFunction::empty_intrinsic_function(false),
             array_prototype_to_string: JsFunction::empty_intrinsic_function(false),
-            iterator_prototypes: IteratorPrototypes::default(),
+            array_iterator_prototype_next: JsFunction::empty_intrinsic_function(false),
+            iterator_prototypes: IteratorPrototypes::new(iterator_prototype),
             generator: JsObject::default(),
             async_generator: JsObject::default(),
             atomics: JsObject::default(),
@@ -1137,6 +1269,7 @@ impl IntrinsicObjects {
             is_nan: JsFunction::empty_intrinsic_function(false),
             parse_float: JsFunction::empty_intrinsic_function(false),
             parse_int: JsFunction::empty_intrinsic_function(false),
+            structured_clone: JsFunction::empty_intrinsic_function(false),
             #[cfg(feature = "annex-b")]
             escape: JsFunction::empty_intrinsic_function(false),
             #[cfg(feature = "annex-b")]
@@ -1179,6 +1312,15 @@ impl IntrinsicObjects {
         self.array_prototype_to_string.clone()
     }
 
+    /// Gets the [`%ArrayIteratorPrototype%.next`][spec] intrinsic function.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next
+    #[inline]
+    #[must_use]
+    pub(crate) fn array_iterator_prototype_next(&self) -> JsFunction {
+        self.array_iterator_prototype_next.clone()
+    }
+
     /// Gets the cached iterator prototypes.
     #[inline]
     #[must_use]
@@ -1292,6 +1434,15 @@ impl IntrinsicObjects {
         self.parse_int.clone()
     }
 
+    /// Gets the [`%structuredClone%`][spec] intrinsic function.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+    #[inline]
+    #[must_use]
+    pub fn structured_clone(&self) -> JsFunction {
+        self.structured_clone.clone()
+    }
+
     /// Gets the [`%escape%`][spec] intrinsic function.
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-escape-string
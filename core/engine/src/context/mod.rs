@@ -33,10 +33,11 @@ use crate::{
     JsNativeError, JsResult, JsString, JsValue, Source,
 };
 use boa_ast::StatementList;
+use boa_gc::GcConfig;
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
-use crate::vm::RuntimeLimits;
+use crate::vm::{DebugHandler, InterruptHandle, Profile, RuntimeLimits, StackFrames, Tracer};
 
 use self::intrinsics::StandardConstructor;
 
@@ -185,11 +186,21 @@ impl Context {
     ///
     /// Note that this won't run any scheduled promise jobs; you need to call [`Context::run_jobs`]
     /// on the context or [`JobQueue::run_jobs`] on the provided queue to run them.
+    ///
+    /// The strings interned while parsing the source are removed from the [`Interner`] once it
+    /// is compiled, so evaluating many different sources doesn't grow the interner.
     #[allow(clippy::unit_arg, dropping_copy_types)]
     pub fn eval<R: ReadChar>(&mut self, src: Source<'_, R>) -> JsResult<JsValue> {
         let main_timer = Profiler::global().start_event("Script evaluation", "Main");
 
-        let result = Script::parse(src, None, self)?.evaluate(self);
+        let checkpoint = self.interner_mut().checkpoint();
+        let script = Script::parse(src, None, self)
+            .and_then(|script| script.codeblock(self).map(|_| script));
+        // The compiled code doesn't reference the symbols of the script, so they can be removed
+        // before running it, which can parse and keep other scripts or modules.
+        self.interner_mut().rollback(checkpoint);
+
+        let result = script?.evaluate(self);
 
         // The main_timer needs to be dropped before the Profiler is.
         drop(main_timer);
@@ -198,6 +209,37 @@ impl Context {
         result
     }
 
+    /// Compiles the given source to a [`Script`], using `cache` to skip parsing and compiling it.
+    ///
+    /// If `cache` holds the code of `source`, serialized by a previous call in any context, the
+    /// script is loaded from it. Otherwise, the source is parsed and compiled, and `cache` is
+    /// replaced by the serialized code of the script.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::Context;
+    /// let source = "function add(a, b) { return a + b; } add(1, 3)";
+    /// let mut cache = Vec::new();
+    ///
+    /// let mut context = Context::default();
+    /// let script = context.compile_cached(source, &mut cache).unwrap();
+    /// assert_eq!(script.evaluate(&mut context).unwrap().as_number(), Some(4.0));
+    ///
+    /// // The second context loads the script from the cache.
+    /// let mut context = Context::default();
+    /// let script = context.compile_cached(source, &mut cache).unwrap();
+    /// assert_eq!(script.evaluate(&mut context).unwrap().as_number(), Some(4.0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be parsed or compiled, or if the global declarations
+    /// of the script conflict with the declarations of the realm.
+    #[cfg(feature = "bytecode-cache")]
+    pub fn compile_cached(&mut self, source: &str, cache: &mut Vec<u8>) -> JsResult<Script> {
+        Script::compile_cached(source, cache, self)
+    }
+
     /// Applies optimizations to the [`StatementList`] inplace.
     pub fn optimize_statement_list(
         &mut self,
@@ -448,11 +490,85 @@ impl Context {
         &self.vm.realm
     }
 
-    /// Set the value of trace on the context
-    #[cfg(feature = "trace")]
+    /// Installs a [`Tracer`] that will receive the events of the execution of the VM, replacing
+    /// the previous one.
+    ///
+    /// This can be called at any time, including from native functions called by scripts.
+    #[inline]
+    pub fn set_tracer(&mut self, tracer: Box<dyn Tracer>) {
+        self.vm.tracer = Some(tracer);
+    }
+
+    /// Removes the installed [`Tracer`], if any, and returns it.
+    #[inline]
+    pub fn take_tracer(&mut self) -> Option<Box<dyn Tracer>> {
+        self.vm.tracer.take()
+    }
+
+    /// Starts recording the calls of the functions executed by the VM, discarding the calls
+    /// recorded by a previous call to this method that weren't taken yet.
+    ///
+    /// This can be called at any time, but the functions that are already being executed are
+    /// only recorded from their next call.
+    #[inline]
+    pub fn start_profiling(&mut self) {
+        self.vm.profiler = Some(Box::default());
+    }
+
+    /// Stops recording the calls of the functions executed by the VM, and returns the [`Profile`]
+    /// of the calls recorded since [`Context::start_profiling`], if it was called.
+    #[inline]
+    pub fn take_profile(&mut self) -> Option<Profile> {
+        self.vm.profiler.take().map(|profiler| profiler.finish())
+    }
+
+    /// Installs a [`DebugHandler`] that will be called when the execution pauses, replacing the
+    /// previous one.
+    #[inline]
+    pub fn set_debug_handler(&mut self, handler: Box<dyn DebugHandler>) {
+        self.vm.debugger.handler = Some(handler);
+    }
+
+    /// Removes the installed [`DebugHandler`], if any, and returns it.
+    ///
+    /// This also cancels any pending step.
+    #[inline]
+    pub fn take_debug_handler(&mut self) -> Option<Box<dyn DebugHandler>> {
+        self.vm.debugger.step = None;
+        self.vm.debugger.handler.take()
+    }
+
+    /// Sets a breakpoint at the statements that start at `line` of the source at `path`.
+    ///
+    /// Breakpoints only pause the execution while a [`DebugHandler`] is installed.
+    pub fn set_breakpoint(&mut self, path: &Path, line: u32) {
+        self.vm
+            .debugger
+            .breakpoints
+            .entry(path.to_path_buf())
+            .or_default()
+            .insert(line);
+    }
+
+    /// Removes the breakpoint at `line` of the source at `path`.
+    ///
+    /// Returns `true` if the breakpoint existed.
+    pub fn remove_breakpoint(&mut self, path: &Path, line: u32) -> bool {
+        let breakpoints = &mut self.vm.debugger.breakpoints;
+        let Some(lines) = breakpoints.get_mut(path) else {
+            return false;
+        };
+        let removed = lines.remove(&line);
+        if lines.is_empty() {
+            breakpoints.remove(path);
+        }
+        removed
+    }
+
+    /// Removes all the breakpoints.
     #[inline]
-    pub fn set_trace(&mut self, trace: bool) {
-        self.vm.trace = trace;
+    pub fn clear_breakpoints(&mut self) {
+        self.vm.debugger.breakpoints.clear();
     }
 
     /// Get optimizer options.
@@ -518,6 +634,16 @@ impl Context {
         self.vm.frames.iter().rev()
     }
 
+    /// Walks the functions that are currently being executed, from the innermost one.
+    ///
+    /// Unlike [`Context::stack_trace`], this includes the calls to native functions, so it can be
+    /// called from a native function to find out which code called it.
+    #[inline]
+    #[must_use]
+    pub fn stack_frames(&self) -> StackFrames<'_> {
+        StackFrames::new(self)
+    }
+
     /// Replaces the currently active realm with `realm`, and returns the old realm.
     #[inline]
     pub fn enter_realm(&mut self, realm: Realm) -> Realm {
@@ -528,6 +654,10 @@ impl Context {
     }
 
     /// Create a new Realm with the default global bindings.
+    ///
+    /// The new realm has its own global object and intrinsics, but shares the heap, job queue
+    /// and module loader of this context. Use [`Context::eval_in_realm`] or
+    /// [`Context::enter_realm`] to run code inside it.
     pub fn create_realm(&mut self) -> JsResult<Realm> {
         let realm = Realm::create(self.host_hooks, &self.root_shape)?;
 
@@ -538,6 +668,33 @@ impl Context {
         Ok(self.enter_realm(old_realm))
     }
 
+    /// Evaluates the given source inside `realm`, restoring the currently active realm after
+    /// the evaluation finishes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{js_string, Context, Source};
+    /// let mut context = Context::default();
+    /// let realm = context.create_realm().unwrap();
+    ///
+    /// context
+    ///     .eval_in_realm(&realm, Source::from_bytes("var x = 5;"))
+    ///     .unwrap();
+    ///
+    /// assert!(realm.global_object().has_own_property(js_string!("x"), &mut context).unwrap());
+    /// assert!(!context.global_object().has_own_property(js_string!("x"), &mut context).unwrap());
+    /// ```
+    pub fn eval_in_realm<R: ReadChar>(
+        &mut self,
+        realm: &Realm,
+        src: Source<'_, R>,
+    ) -> JsResult<JsValue> {
+        let old_realm = self.enter_realm(realm.clone());
+        let result = self.eval(src);
+        self.enter_realm(old_realm);
+        result
+    }
+
     /// Get the [`RootShape`].
     #[inline]
     #[must_use]
@@ -584,12 +741,85 @@ impl Context {
         &mut self.vm.runtime_limits
     }
 
+    /// Gets a handle that can be used to interrupt the execution of this context, possibly from
+    /// another thread.
+    #[inline]
+    #[must_use]
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.vm.interrupt.clone()
+    }
+
+    /// Sets whether interruptions requested through an [`InterruptHandle`] throw an `Error`
+    /// that can be caught by scripts.
+    ///
+    /// By default, interruptions cannot be caught, and unwind all the way up to the caller of
+    /// the context.
+    #[inline]
+    pub fn set_catchable_interrupts(&mut self, catchable: bool) {
+        self.vm.catchable_interrupts = catchable;
+    }
+
+    /// Set the maximum number of nested function calls before a `RangeError` is thrown.
+    ///
+    /// This is a shorthand for [`RuntimeLimits::set_recursion_limit`].
+    #[inline]
+    pub fn set_recursion_limit(&mut self, limit: usize) {
+        self.vm.runtime_limits.set_recursion_limit(limit);
+    }
+
     /// Returns `true` if this context can be suspended by an `Atomics.wait` call.
     #[inline]
     #[must_use]
     pub fn can_block(&self) -> bool {
         self.can_block
     }
+
+    /// Forces a garbage collection of the heap.
+    ///
+    /// If called inside of [`Context::defer_gc`], the collection runs when the outermost deferred
+    /// scope ends.
+    #[inline]
+    #[allow(clippy::unused_self)]
+    pub fn gc(&self) {
+        boa_gc::force_collect();
+    }
+
+    /// Gets the [`GcConfig`] of the garbage collector.
+    ///
+    /// The garbage collector is shared by all the contexts of a thread, so this is the
+    /// configuration of all of them.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn gc_config(&self) -> GcConfig {
+        boa_gc::config()
+    }
+
+    /// Sets the [`GcConfig`] of the garbage collector.
+    ///
+    /// The garbage collector is shared by all the contexts of a thread, so this also changes the
+    /// configuration of the other contexts of the thread.
+    #[inline]
+    #[allow(clippy::unused_self)]
+    pub fn set_gc_config(&mut self, config: GcConfig) {
+        boa_gc::set_config(config);
+    }
+
+    /// Runs `f` without collecting the heap, which allows the host to hold raw pointers to
+    /// garbage collected values during `f`.
+    ///
+    /// Collections requested with [`Context::gc`] while deferred are run at the end of the
+    /// outermost deferred scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns an uncatchable error if `f` allocated more than
+    /// [`GcConfig::max_deferred_bytes`]. The values allocated by `f` are still valid.
+    pub fn defer_gc<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> JsResult<R> {
+        let result = boa_gc::defer_collections(|| f(self));
+        Self::check_memory_limit()?;
+        Ok(result)
+    }
 }
 
 // ==== Private API ====
@@ -819,8 +1049,13 @@ impl Context {
     ///
     /// [spec]: https://tc39.es/ecma262/#active-function-object
     pub(crate) fn active_function_object(&self) -> Option<JsObject> {
-        if self.vm.native_active_function.is_some() {
-            return self.vm.native_active_function.clone();
+        if let Some(native) = self
+            .vm
+            .native_frames
+            .last()
+            .filter(|native| native.depth == self.vm.frames.len())
+        {
+            return Some(native.function.clone());
         }
 
         if let Some(frame) = self.vm.frames.last() {
@@ -1,4 +1,7 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
 use crate::{environments::runtime::BindingLocator, JsString};
 use boa_gc::{empty_trace, Finalize, Trace};
@@ -27,6 +30,10 @@ pub(crate) struct CompileTimeEnvironment {
     environment_index: u32,
     bindings: RefCell<FxHashMap<JsString, CompileTimeBinding>>,
     function_scope: bool,
+
+    /// Indicates if bindings of the outer environments can be shadowed at runtime from this
+    /// environment, by a `with` statement or a direct `eval` call.
+    dynamic: Cell<bool>,
 }
 
 // Safety: Nothing in this struct needs tracing, so this is safe.
@@ -42,6 +49,7 @@ impl CompileTimeEnvironment {
             environment_index: 0,
             bindings: RefCell::default(),
             function_scope: true,
+            dynamic: Cell::new(false),
         }
     }
 
@@ -53,6 +61,7 @@ impl CompileTimeEnvironment {
             environment_index: index,
             bindings: RefCell::default(),
             function_scope,
+            dynamic: Cell::new(false),
         }
     }
 
@@ -78,7 +87,9 @@ impl CompileTimeEnvironment {
                 binding.lex,
             )
         } else if let Some(outer) = &self.outer {
-            outer.get_identifier_reference(name)
+            let mut reference = outer.get_identifier_reference(name);
+            reference.dynamic |= self.dynamic.get();
+            reference
         } else {
             IdentifierReference::new(BindingLocator::global(name), false)
         }
@@ -99,6 +110,12 @@ impl CompileTimeEnvironment {
         self.function_scope
     }
 
+    /// Mark that bindings of the outer environments can be shadowed at runtime from this
+    /// environment.
+    pub(crate) fn set_dynamic(&self) {
+        self.dynamic.set(true);
+    }
+
     /// Check if the environment is a global environment.
     pub(crate) const fn is_global(&self) -> bool {
         self.outer.is_none()
@@ -195,16 +212,77 @@ impl CompileTimeEnvironment {
     }
 }
 
+#[cfg(feature = "bytecode-cache")]
+impl CompileTimeEnvironment {
+    /// Creates a compile time environment with the given bindings, as returned by
+    /// [`CompileTimeEnvironment::bindings`].
+    pub(crate) fn with_bindings(
+        parent: Rc<Self>,
+        function_scope: bool,
+        dynamic: bool,
+        bindings: Vec<(JsString, u32, bool, bool, bool)>,
+    ) -> Self {
+        let env = Self::new(parent, function_scope);
+        env.dynamic.set(dynamic);
+        env.bindings.borrow_mut().extend(bindings.into_iter().map(
+            |(name, index, mutable, lex, strict)| {
+                (
+                    name,
+                    CompileTimeBinding {
+                        index,
+                        mutable,
+                        lex,
+                        strict,
+                    },
+                )
+            },
+        ));
+        env
+    }
+
+    /// Returns the bindings of this environment as `(name, index, mutable, lex, strict)` tuples,
+    /// sorted by index.
+    pub(crate) fn bindings(&self) -> Vec<(JsString, u32, bool, bool, bool)> {
+        let mut bindings = self
+            .bindings
+            .borrow()
+            .iter()
+            .map(|(name, binding)| {
+                (
+                    name.clone(),
+                    binding.index,
+                    binding.mutable,
+                    binding.lex,
+                    binding.strict,
+                )
+            })
+            .collect::<Vec<_>>();
+        bindings.sort_unstable_by_key(|binding| binding.1);
+        bindings
+    }
+
+    /// Check if bindings of the outer environments can be shadowed at runtime from this
+    /// environment.
+    pub(crate) fn is_dynamic(&self) -> bool {
+        self.dynamic.get()
+    }
+}
+
 /// A reference to an identifier in a compile time environment.
 pub(crate) struct IdentifierReference {
     locator: BindingLocator,
     lexical: bool,
+    dynamic: bool,
 }
 
 impl IdentifierReference {
     /// Create a new identifier reference.
     pub(crate) fn new(locator: BindingLocator, lexical: bool) -> Self {
-        Self { locator, lexical }
+        Self {
+            locator,
+            lexical,
+            dynamic: false,
+        }
     }
 
     /// Get the binding locator for this identifier reference.
@@ -216,4 +294,12 @@ impl IdentifierReference {
     pub(crate) fn is_lexical(&self) -> bool {
         self.lexical
     }
+
+    /// Check if this identifier reference always resolves to the binding of its locator.
+    ///
+    /// This is the case for declarative bindings that cannot be shadowed at runtime, which can
+    /// be accessed by their indices without any runtime lookup.
+    pub(crate) fn is_static(&self) -> bool {
+        !self.locator.is_global() && !self.dynamic
+    }
 }
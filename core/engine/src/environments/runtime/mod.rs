@@ -325,6 +325,24 @@ impl EnvironmentStack {
         }
     }
 
+    /// Get the value of a lexical binding, or `None` if it is uninitialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the environment or binding index are out of range.
+    #[track_caller]
+    pub(crate) fn get_lexical_value(
+        &self,
+        environment_index: u32,
+        binding_index: u32,
+    ) -> Option<JsValue> {
+        self.stack
+            .get(environment_index as usize)
+            .expect("environment index must be in range")
+            .declarative_expect()
+            .get(binding_index)
+    }
+
     /// Set the value of a lexical binding.
     ///
     /// # Panics
@@ -444,7 +462,7 @@ impl BindingLocator {
     }
 
     /// Creates a binding locator that indicates that the binding is on the global object.
-    pub(super) const fn global(name: JsString) -> Self {
+    pub(crate) const fn global(name: JsString) -> Self {
         Self {
             name,
             environment_index: 0,
@@ -1,17 +1,22 @@
 //! Error-related types and conversions.
 
-use std::{error, fmt};
+use std::{cell::OnceCell, error, fmt, path::Path, rc::Rc};
 
 use crate::{
-    builtins::{error::ErrorObject, Array},
+    builtins::{
+        error::{Error, ErrorObject},
+        Array,
+    },
     js_string,
     object::JsObject,
     property::PropertyDescriptor,
     realm::Realm,
     string::utf16,
+    vm::CodeBlock,
     Context, JsString, JsValue,
 };
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_ast::Position;
+use boa_gc::{custom_trace, Finalize, Gc, Trace};
 use thiserror::Error;
 
 /// The error type returned by all operations related
@@ -46,12 +51,26 @@ use thiserror::Error;
 /// let kind = &native_error.as_native().unwrap().kind;
 /// assert!(matches!(kind, JsNativeErrorKind::Type));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize)]
+#[derive(Debug, Clone, Trace, Finalize)]
 #[boa_gc(unsafe_no_drop)]
 pub struct JsError {
     inner: Repr,
+
+    /// The call stack at the moment the error was thrown, if it was thrown by the VM.
+    #[unsafe_ignore_trace]
+    stack: Option<Rc<CapturedStack>>,
+}
+
+// The captured stack is just debugging information, so it is not taken into account when
+// comparing errors.
+impl PartialEq for JsError {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
 }
 
+impl Eq for JsError {}
+
 /// Internal representation of a [`JsError`].
 ///
 /// `JsError` is represented by an opaque enum because it restricts
@@ -140,6 +159,7 @@ impl JsError {
     pub const fn from_native(err: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(err),
+            stack: None,
         }
     }
 
@@ -157,6 +177,7 @@ impl JsError {
     pub const fn from_opaque(value: JsValue) -> Self {
         Self {
             inner: Repr::Opaque(value),
+            stack: None,
         }
     }
 
@@ -178,11 +199,53 @@ impl JsError {
     /// ```
     pub fn to_opaque(&self, context: &mut Context) -> JsValue {
         match &self.inner {
-            Repr::Native(e) => e.to_opaque(context).into(),
+            Repr::Native(e) => {
+                let o = e.to_opaque(context);
+                let stack = self
+                    .stack
+                    .clone()
+                    .unwrap_or_else(|| CapturedStack::capture(context));
+                Error::install_error_stack(&o, &e.kind.to_string(), stack, context);
+                o.into()
+            }
             Repr::Opaque(v) => v.clone(),
         }
     }
 
+    /// Gets the call stack at the moment this error was thrown, starting from the innermost
+    /// frame.
+    ///
+    /// This is only available for errors thrown while executing JavaScript code, and contains
+    /// at most `Error.stackTraceLimit` frames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, Source};
+    /// let context = &mut Context::default();
+    ///
+    /// let error = context
+    ///     .eval(Source::from_bytes("function thrower() { null.x } thrower()"))
+    ///     .unwrap_err();
+    /// let frames = error.stack_frames().unwrap();
+    ///
+    /// assert_eq!(frames[0].function_name().to_std_string_escaped(), "thrower");
+    /// assert_eq!(frames[1].function_name().to_std_string_escaped(), "<main>");
+    /// ```
+    #[must_use]
+    pub fn stack_frames(&self) -> Option<&[StackFrame]> {
+        self.stack.as_deref().map(CapturedStack::frames)
+    }
+
+    /// Captures the current call stack of `context` in this error, if it doesn't already
+    /// have one.
+    pub(crate) fn with_stack_frames(mut self, context: &mut Context) -> Self {
+        if self.stack.is_none() {
+            self.stack = Some(CapturedStack::capture(context));
+        }
+        self
+    }
+
     /// Unwraps the inner error if this contains a native error.
     /// Otherwise, inspects the opaque error and tries to extract the
     /// necessary information to construct a native error similar to the provided
@@ -301,6 +364,20 @@ impl JsError {
 
                         JsNativeErrorKind::Aggregate(error_list)
                     }
+                    ErrorObject::Suppressed => {
+                        let mut get_error = |name| {
+                            obj.get(js_string!(name), context)
+                                .map(|value| Box::new(Self::from_opaque(value)))
+                                .map_err(|e| TryNativeError::InaccessibleProperty {
+                                    property: name,
+                                    source: e,
+                                })
+                        };
+                        JsNativeErrorKind::Suppressed {
+                            error: get_error("error")?,
+                            suppressed: get_error("suppressed")?,
+                        }
+                    }
                 };
 
                 let realm = try_get_property(js_string!("constructor"), "constructor", context)?
@@ -426,6 +503,12 @@ impl JsError {
             JsNativeErrorKind::Eval => JsErasedNativeErrorKind::Eval,
             JsNativeErrorKind::Range => JsErasedNativeErrorKind::Range,
             JsNativeErrorKind::Reference => JsErasedNativeErrorKind::Reference,
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                JsErasedNativeErrorKind::Suppressed {
+                    error: Box::new(error.into_erased(context)),
+                    suppressed: Box::new(suppressed.into_erased(context)),
+                }
+            }
             JsNativeErrorKind::Syntax => JsErasedNativeErrorKind::Syntax,
             JsNativeErrorKind::Type => JsErasedNativeErrorKind::Type,
             JsNativeErrorKind::Uri => JsErasedNativeErrorKind::Uri,
@@ -476,6 +559,7 @@ impl From<JsNativeError> for JsError {
     fn from(error: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(error),
+            stack: None,
         }
     }
 }
@@ -489,6 +573,170 @@ impl fmt::Display for JsError {
     }
 }
 
+/// A frame of the JavaScript call stack, captured when an error is thrown.
+///
+/// See [`JsError::stack_frames`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+    function_name: JsString,
+    source_path: Option<Rc<Path>>,
+    line: Option<u32>,
+    column: Option<u32>,
+}
+
+impl StackFrame {
+    /// Gets the name of the function executing in this frame.
+    ///
+    /// This is `<main>` for the top level code of scripts and modules, and `<anonymous>` for
+    /// functions without a name.
+    #[inline]
+    #[must_use]
+    pub const fn function_name(&self) -> &JsString {
+        &self.function_name
+    }
+
+    /// Gets the path of the source that contains the code of this frame, if any.
+    #[inline]
+    #[must_use]
+    pub fn source_path(&self) -> Option<&Path> {
+        self.source_path.as_deref()
+    }
+
+    /// Gets the line of the source that was being executed by this frame, if known.
+    ///
+    /// For the frames below the innermost one, this is the line of the call to the next frame.
+    #[inline]
+    #[must_use]
+    pub const fn line(&self) -> Option<u32> {
+        self.line
+    }
+
+    /// Gets the column of the source where the statement that was being executed by this frame
+    /// starts, if known.
+    #[inline]
+    #[must_use]
+    pub const fn column(&self) -> Option<u32> {
+        self.column
+    }
+}
+
+/// A call stack captured when an error is thrown.
+///
+/// Capturing the stack only records the code block and program counter of each frame, since
+/// the stack of most errors is never read. The [`StackFrame`]s are built the first time they
+/// are requested.
+#[derive(Debug)]
+pub(crate) struct CapturedStack {
+    /// The frames of the stack, from the innermost one.
+    raw: Box<[RawFrame]>,
+    frames: OnceCell<Box<[StackFrame]>>,
+}
+
+/// The state of a call frame needed to build its [`StackFrame`].
+///
+/// The code block isn't traced, so it stays rooted while the captured stack is alive.
+#[derive(Debug)]
+struct RawFrame {
+    code_block: Gc<CodeBlock>,
+    pc: u32,
+    source_path: Option<Rc<Path>>,
+}
+
+impl CapturedStack {
+    /// Captures the call stack of `context`, up to `Error.stackTraceLimit` frames.
+    pub(crate) fn capture(context: &mut Context) -> Rc<Self> {
+        Self::capture_below(None, context)
+    }
+
+    /// Captures the call stack of `context`, up to `Error.stackTraceLimit` frames.
+    ///
+    /// If `function` is not `None`, the frames above the innermost call to `function`, including
+    /// that call, are omitted, and no frames are captured if `function` is not on the stack.
+    pub(crate) fn capture_below(function: Option<&JsObject>, context: &mut Context) -> Rc<Self> {
+        let limit = context
+            .intrinsics()
+            .constructors()
+            .error()
+            .constructor()
+            .borrow()
+            .properties()
+            .get(&js_string!("stackTraceLimit").into())
+            .and_then(|desc| desc.value().and_then(JsValue::as_number))
+            .filter(|limit| *limit > 0.0)
+            .map_or(0, |limit| limit as usize);
+
+        // Native functions don't push call frames, so they are only inspected to find `function`.
+        let mut skipped = 0;
+        if let Some(function) = function {
+            // Skips every frame up to the call to `function`, or all of them if it isn't found.
+            context.stack_frames().any(|frame| {
+                skipped += usize::from(!frame.is_native());
+                frame.function() == Some(function)
+            });
+        }
+
+        let raw = context
+            .vm
+            .frames
+            .iter()
+            .rev()
+            .skip(skipped)
+            .take(limit)
+            .map(|frame| RawFrame {
+                code_block: frame.code_block().clone(),
+                pc: frame.pc,
+                source_path: frame.source_path_rc(),
+            })
+            .collect();
+
+        Rc::new(Self {
+            raw,
+            frames: OnceCell::new(),
+        })
+    }
+
+    /// Gets the frames of the stack, building them the first time.
+    pub(crate) fn frames(&self) -> &[StackFrame] {
+        self.frames.get_or_init(|| {
+            self.raw
+                .iter()
+                .map(|frame| {
+                    let name = frame.code_block.name();
+                    // NOTE: -1 because the pc already points to the next instruction.
+                    let position = frame.code_block.position_at(frame.pc.saturating_sub(1));
+                    StackFrame {
+                        function_name: if name.is_empty() {
+                            js_string!("<anonymous>")
+                        } else {
+                            name.clone()
+                        },
+                        source_path: frame.source_path.clone(),
+                        line: position.map(Position::line_number),
+                        column: position.map(Position::column_number),
+                    }
+                })
+                .collect()
+        })
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.function_name.to_std_string_escaped())?;
+        if let Some(path) = &self.source_path {
+            write!(f, " ({}", path.display())?;
+            if let Some(line) = self.line {
+                write!(f, ":{line}")?;
+                if let Some(column) = self.column {
+                    write!(f, ":{column}")?;
+                }
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
 /// Native representation of an ideal `Error` object from Javascript.
 ///
 /// This representation is more space efficient than its [`JsObject`] equivalent,
@@ -594,6 +842,40 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::Aggregate(_))
     }
 
+    /// Creates a new `JsNativeError` of kind `SuppressedError` from the error that was thrown
+    /// while the `suppressed` error was being handled, with empty `message` and undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::suppressed(
+    ///     JsNativeError::typ().into(),
+    ///     JsNativeError::syntax().into(),
+    /// );
+    ///
+    /// assert!(matches!(error.kind, JsNativeErrorKind::Suppressed { .. }));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn suppressed(error: JsError, suppressed: JsError) -> Self {
+        Self::new(
+            JsNativeErrorKind::Suppressed {
+                error: Box::new(error),
+                suppressed: Box::new(suppressed),
+            },
+            Box::default(),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Suppressed`].
+    #[must_use]
+    #[inline]
+    pub const fn is_suppressed(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Suppressed { .. })
+    }
+
     /// Creates a new `JsNativeError` of kind `Error`, with empty `message` and undefined `cause`.
     ///
     /// # Examples
@@ -921,6 +1203,10 @@ impl JsNativeError {
                 constructors.reference_error().prototype(),
                 ErrorObject::Reference,
             ),
+            JsNativeErrorKind::Suppressed { .. } => (
+                constructors.suppressed_error().prototype(),
+                ErrorObject::Suppressed,
+            ),
             JsNativeErrorKind::Syntax => {
                 (constructors.syntax_error().prototype(), ErrorObject::Syntax)
             }
@@ -971,9 +1257,32 @@ impl JsNativeError {
             )
             .expect("The spec guarantees this succeeds for a newly created object ");
         }
+
+        if let JsNativeErrorKind::Suppressed { error, suppressed } = kind {
+            let error = error.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw(js_string!("error"), error, context);
+            let suppressed = suppressed.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw(
+                js_string!("suppressed"),
+                suppressed,
+                context,
+            );
+        }
         o
     }
 
+    /// Creates a new `SyntaxError` from a parser error.
+    ///
+    /// If `path` is provided, the message of the error points to the location of the
+    /// error inside that source (e.g. `script.js:3:12`).
+    pub(crate) fn from_parse_error(err: &boa_parser::Error, path: Option<&Path>) -> Self {
+        let message = match path {
+            Some(path) => err.display_with_path(path).to_string(),
+            None => err.to_string(),
+        };
+        Self::syntax().with_message(message)
+    }
+
     /// Sets the realm of this error.
     pub(crate) fn with_realm(mut self, realm: Realm) -> Self {
         self.realm = Some(realm);
@@ -989,7 +1298,7 @@ impl JsNativeError {
 
 impl From<boa_parser::Error> for JsNativeError {
     fn from(err: boa_parser::Error) -> Self {
-        Self::syntax().with_message(err.to_string())
+        Self::from_parse_error(&err, None)
     }
 }
 
@@ -1050,6 +1359,20 @@ pub enum JsNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-referenceerror
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError
     Reference,
+    /// An error thrown while another error was being handled, which suppresses it.
+    ///
+    /// More information:
+    /// - [Explicit Resource Management proposal][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown.
+        error: Box<JsError>,
+        /// The error that was being handled when `error` was thrown.
+        suppressed: Box<JsError>,
+    },
     /// An error representing an invalid syntax in the Javascript language.
     ///
     /// More information:
@@ -1097,6 +1420,10 @@ unsafe impl Trace for JsNativeErrorKind {
         mark,
         match &this {
             Self::Aggregate(errors) => mark(errors),
+            Self::Suppressed { error, suppressed } => {
+                mark(error);
+                mark(suppressed);
+            }
             Self::Error
             | Self::Eval
             | Self::Range
@@ -1121,6 +1448,7 @@ impl JsNativeErrorKind {
             | Self::Eval
             | Self::Range
             | Self::Reference
+            | Self::Suppressed { .. }
             | Self::Syntax
             | Self::Type
             | Self::Uri => true,
@@ -1140,6 +1468,7 @@ impl PartialEq<ErrorObject> for JsNativeErrorKind {
                 | (Self::Eval, ErrorObject::Eval)
                 | (Self::Range, ErrorObject::Range)
                 | (Self::Reference, ErrorObject::Reference)
+                | (Self::Suppressed { .. }, ErrorObject::Suppressed)
                 | (Self::Syntax, ErrorObject::Syntax)
                 | (Self::Type, ErrorObject::Type)
                 | (Self::Uri, ErrorObject::Uri)
@@ -1155,6 +1484,7 @@ impl fmt::Display for JsNativeErrorKind {
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
             Self::Reference => "ReferenceError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
@@ -1299,6 +1629,20 @@ pub enum JsErasedNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-referenceerror
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError
     Reference,
+    /// An error thrown while another error was being handled, which suppresses it.
+    ///
+    /// More information:
+    /// - [Explicit Resource Management proposal][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown.
+        error: Box<JsErasedError>,
+        /// The error that was being handled when `error` was thrown.
+        suppressed: Box<JsErasedError>,
+    },
     /// An error representing an invalid syntax in the Javascript language.
     ///
     /// More information:
@@ -1344,6 +1688,7 @@ impl fmt::Display for JsErasedNativeErrorKind {
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
             Self::Reference => "ReferenceError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
@@ -279,9 +279,16 @@ impl JobQueue for IdleJobQueue {
 /// This is the default job queue for the [`Context`], but it is mostly pretty limited for
 /// custom event queues.
 ///
+/// Future jobs are only driven when running the queue. After running all the pending promise
+/// jobs, the queue blocks the current thread until the oldest future job completes, then runs the
+/// jobs enqueued by it.
+///
 /// To disable running promise jobs on the engine, see [`IdleJobQueue`].
 #[derive(Default)]
-pub struct SimpleJobQueue(RefCell<VecDeque<NativeJob>>);
+pub struct SimpleJobQueue {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    futures: RefCell<VecDeque<FutureJob>>,
+}
 
 impl Debug for SimpleJobQueue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -299,24 +306,31 @@ impl SimpleJobQueue {
 
 impl JobQueue for SimpleJobQueue {
     fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
-        self.0.borrow_mut().push_back(job);
+        self.jobs.borrow_mut().push_back(job);
     }
 
     fn run_jobs(&self, context: &mut Context) {
-        // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
-        // dropped after calling `pop_front`.
-        let mut next_job = self.0.borrow_mut().pop_front();
-        while let Some(job) = next_job {
-            if job.call(context).is_err() {
-                self.0.borrow_mut().clear();
+        loop {
+            // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
+            // dropped after calling `pop_front`.
+            let mut next_job = self.jobs.borrow_mut().pop_front();
+            while let Some(job) = next_job {
+                if job.call(context).is_err() {
+                    self.jobs.borrow_mut().clear();
+                    return;
+                }
+                next_job = self.jobs.borrow_mut().pop_front();
+            }
+
+            let Some(future) = self.futures.borrow_mut().pop_front() else {
                 return;
             };
-            next_job = self.0.borrow_mut().pop_front();
+            let job = pollster::block_on(future);
+            self.enqueue_promise_job(job, context);
         }
     }
 
-    fn enqueue_future_job(&self, future: FutureJob, context: &mut Context) {
-        let job = pollster::block_on(future);
-        self.enqueue_promise_job(job, context);
+    fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
+        self.futures.borrow_mut().push_back(future);
     }
 }
@@ -147,6 +147,19 @@ pub trait JsArgs {
     ///
     /// This returns a reference for efficiency, in case you only need to call methods of `JsValue`.
     fn get_or_undefined(&self, index: usize) -> &JsValue;
+
+    /// Utility function to convert a parameter from a `[JsValue]` to `T` using [`TryFromJs`],
+    /// treating missing parameters as `undefined`.
+    ///
+    /// [`TryFromJs`]: value::TryFromJs
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`TryFromJs::try_from_js`][value::TryFromJs::try_from_js] if the
+    /// conversion fails.
+    fn try_get<T: value::TryFromJs>(&self, index: usize, context: &mut Context) -> JsResult<T> {
+        self.get_or_undefined(index).try_js_into(context)
+    }
 }
 
 impl JsArgs for [JsValue] {
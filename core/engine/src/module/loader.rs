@@ -1,4 +1,8 @@
-use std::path::{Path, PathBuf};
+use std::{
+    cell::RefCell,
+    future::Future,
+    path::{Component, Path, PathBuf},
+};
 
 use rustc_hash::FxHashMap;
 
@@ -7,8 +11,8 @@ use boa_parser::Source;
 
 use crate::script::Script;
 use crate::{
-    js_string, object::JsObject, realm::Realm, vm::ActiveRunnable, Context, JsError, JsNativeError,
-    JsResult, JsString,
+    job::NativeJob, js_string, object::JsObject, realm::Realm, vm::ActiveRunnable, Context,
+    JsError, JsNativeError, JsResult, JsString, JsValue,
 };
 
 use super::Module;
@@ -58,6 +62,12 @@ pub trait ModuleLoader {
     /// - The operation must treat payload as an opaque value to be passed through to
     /// `FinishLoadingImportedModule`. (can be ignored)
     ///
+    /// # Asynchronous loading
+    ///
+    /// `finish_load` doesn't need to be called before returning from this method. Loaders that
+    /// need to fetch modules without blocking the engine (e.g. from the network) can call it
+    /// later from a job, which is easier done using [`finish_load_async`].
+    ///
     /// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
     /// [finish]: https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
     #[allow(clippy::type_complexity)]
@@ -104,6 +114,74 @@ pub trait ModuleLoader {
     fn init_import_meta(&self, _import_meta: &JsObject, _module: &Module, _context: &mut Context) {}
 }
 
+/// Completes a module load request asynchronously.
+///
+/// Enqueues `future` as a [`FutureJob`] on the [`JobQueue`] of `context`, allowing a
+/// [`ModuleLoader`] to fetch the source of a module without blocking the engine. When `future`
+/// completes, the job queue calls the closure it resolved to with the [`Context`] (which allows
+/// parsing the fetched source into a [`Module`]), then calls `finish_load` with its result.
+///
+/// Note that module graph loading only makes progress when the job queue drives the enqueued
+/// futures, which usually happens on [`Context::run_jobs`]. Evaluation order of the graph
+/// doesn't depend on the order in which the loads complete.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #     module::{finish_load_async, ModuleLoader, Referrer},
+/// #     Context, JsResult, JsString, Module, Source,
+/// # };
+/// struct DelayedLoader;
+///
+/// impl ModuleLoader for DelayedLoader {
+///     fn load_imported_module(
+///         &self,
+///         _referrer: Referrer,
+///         specifier: JsString,
+///         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+///         context: &mut Context,
+///     ) {
+///         finish_load_async(
+///             async move {
+///                 // Fetch the source of `specifier` asynchronously here.
+///                 let source = format!("export default {};", specifier.len());
+///                 move |context: &mut Context| {
+///                     Module::parse(Source::from_bytes(&source), None, context)
+///                 }
+///             },
+///             finish_load,
+///             context,
+///         );
+///     }
+/// }
+/// ```
+///
+/// [`FutureJob`]: crate::job::FutureJob
+/// [`JobQueue`]: crate::job::JobQueue
+#[allow(clippy::type_complexity)]
+pub fn finish_load_async<Fut, F>(
+    future: Fut,
+    finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+    context: &mut Context,
+) where
+    Fut: Future<Output = F> + 'static,
+    F: FnOnce(&mut Context) -> JsResult<Module> + 'static,
+{
+    let future = async move {
+        let load = future.await;
+        NativeJob::new(move |context| {
+            let result = load(context);
+            finish_load(result, context);
+            Ok(JsValue::undefined())
+        })
+    };
+
+    context
+        .job_queue()
+        .enqueue_future_job(Box::pin(future), context);
+}
+
 /// A module loader that throws when trying to load any modules.
 ///
 /// Useful to disable the module system on platforms that don't have a filesystem, for example.
@@ -129,15 +207,27 @@ impl ModuleLoader for IdleModuleLoader {
 
 /// A simple module loader that loads modules relative to a root path.
 ///
-/// # Note
+/// Specifiers starting with `./` or `../` are resolved relative to the path of the importing
+/// module or script, while any other specifier is resolved relative to the root path. Referrers
+/// parsed from a relative path (or without a path at all) are treated as if they were located
+/// inside the root directory.
 ///
-/// This loader only works by using the type methods [`SimpleModuleLoader::insert`] and
-/// [`SimpleModuleLoader::get`]. The utility methods on [`ModuleLoader`] don't work at the moment,
-/// but we'll unify both APIs in the future.
+/// By default, specifiers cannot refer to modules outside the root path; this can be changed
+/// using [`SimpleModuleLoader::allow_escaping_root`].
+///
+/// Parsed modules are cached by their canonical path, which means that importing the same
+/// file from multiple modules (even through different specifiers) always returns the same
+/// [`Module`], and thus evaluates it only once.
+///
+/// Errors loading a module list the chain of modules that imported it, starting from the module
+/// that requested it.
 #[derive(Debug)]
 pub struct SimpleModuleLoader {
     root: PathBuf,
+    allow_escaping_root: bool,
     module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    // The path of the module that first imported each loaded module.
+    importers: RefCell<FxHashMap<PathBuf, PathBuf>>,
 }
 
 impl SimpleModuleLoader {
@@ -156,10 +246,26 @@ impl SimpleModuleLoader {
         })?;
         Ok(Self {
             root: absolute,
+            allow_escaping_root: false,
             module_map: GcRefCell::default(),
+            importers: RefCell::default(),
         })
     }
 
+    /// Allows or disallows specifiers resolving to paths outside of the root path.
+    #[must_use]
+    pub const fn allow_escaping_root(mut self, allow: bool) -> Self {
+        self.allow_escaping_root = allow;
+        self
+    }
+
+    /// Gets the root path of the loader.
+    #[inline]
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
     /// Inserts a new module onto the module map.
     #[inline]
     pub fn insert(&self, path: PathBuf, module: Module) {
@@ -171,28 +277,101 @@ impl SimpleModuleLoader {
     pub fn get(&self, path: &Path) -> Option<Module> {
         self.module_map.borrow().get(path).cloned()
     }
+
+    /// Resolves `specifier` to the path of a module, using the path of `referrer` as the base
+    /// for relative specifiers.
+    ///
+    /// The path is canonicalized if it exists, resolving any symbolic links, and normalized
+    /// lexically otherwise; this doesn't check if the module exists.
+    pub fn resolve_path(&self, referrer: Option<&Referrer>, specifier: &str) -> JsResult<PathBuf> {
+        let relative = specifier.starts_with("./") || specifier.starts_with("../");
+
+        let referrer_path = referrer.and_then(|referrer| match referrer {
+            Referrer::Module(module) => module.path(),
+            Referrer::Script(script) => script.path(),
+            Referrer::Realm(_) => None,
+        });
+
+        let base = match referrer_path.and_then(Path::parent) {
+            Some(dir) if relative => self.root.join(dir),
+            _ => self.root.clone(),
+        };
+
+        let joined = base.join(specifier);
+        let path = joined.canonicalize().unwrap_or_else(|_| {
+            let mut path = PathBuf::new();
+            for component in joined.components() {
+                match component {
+                    Component::CurDir => {}
+                    Component::ParentDir => {
+                        path.pop();
+                    }
+                    component => path.push(component),
+                }
+            }
+            path
+        });
+
+        if !self.allow_escaping_root && !path.starts_with(&self.root) {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "module specifier `{specifier}` resolves outside of the module root"
+                ))
+                .into());
+        }
+
+        Ok(path)
+    }
+
+    /// Gets the key of the module map for the module at `path`.
+    fn cache_key(path: &Path) -> PathBuf {
+        path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+    }
+
+    /// Adds the chain of modules that imported `referrer` to the message of `err`, which is an
+    /// error loading a module imported by `referrer`.
+    fn with_import_chain(&self, err: JsError, referrer: &Path, context: &mut Context) -> JsError {
+        let Ok(err) = err.try_native(context) else {
+            return err;
+        };
+
+        let importers = self.importers.borrow();
+        let mut chain = Vec::new();
+        let mut importer = Some(referrer);
+        while let Some(path) = importer {
+            let relative = path.strip_prefix(&self.root).unwrap_or(path);
+            chain.push(format!("\n    imported by `{}`", relative.display()));
+            importer = importers.get(path).map(PathBuf::as_path);
+        }
+
+        let message = format!("{}{}", err.message(), chain.concat());
+        err.with_message(message).into()
+    }
 }
 
 impl ModuleLoader for SimpleModuleLoader {
     fn load_imported_module(
         &self,
-        _referrer: Referrer,
+        referrer: Referrer,
         specifier: JsString,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
+        let referrer_path = match &referrer {
+            Referrer::Module(module) => module.path(),
+            Referrer::Script(script) => script.path(),
+            Referrer::Realm(_) => None,
+        }
+        .map(Self::cache_key);
+
         let result = (|| {
-            let path = specifier
+            let specifier = specifier
                 .to_std_string()
                 .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
-            let short_path = Path::new(&path);
-            let path = self.root.join(short_path);
+            let path = self.resolve_path(Some(&referrer), &specifier)?;
             let path = path.canonicalize().map_err(|err| {
                 JsNativeError::typ()
-                    .with_message(format!(
-                        "could not canonicalize path `{}`",
-                        short_path.display()
-                    ))
+                    .with_message(format!("could not canonicalize path `{specifier}`"))
                     .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
             })?;
             if let Some(module) = self.get(&path) {
@@ -200,18 +379,43 @@ impl ModuleLoader for SimpleModuleLoader {
             }
             let source = Source::from_filepath(&path).map_err(|err| {
                 JsNativeError::typ()
-                    .with_message(format!("could not open file `{}`", short_path.display()))
+                    .with_message(format!("could not open file `{specifier}`"))
                     .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
             })?;
             let module = Module::parse(source, None, context).map_err(|err| {
                 JsNativeError::syntax()
-                    .with_message(format!("could not parse module `{}`", short_path.display()))
+                    .with_message(format!("could not parse module `{specifier}`"))
                     .with_cause(err)
             })?;
+            if let Some(referrer_path) = &referrer_path {
+                self.importers
+                    .borrow_mut()
+                    .insert(path.clone(), referrer_path.clone());
+            }
             self.insert(path, module.clone());
             Ok(module)
         })();
 
+        let result = match (result, &referrer_path) {
+            (Err(err), Some(referrer_path)) => {
+                Err(self.with_import_chain(err, referrer_path, context))
+            }
+            (result, _) => result,
+        };
+
         finish_load(result, context);
     }
+
+    fn register_module(&self, specifier: JsString, module: Module) {
+        if let Ok(path) = self.resolve_path(None, &specifier.to_std_string_escaped()) {
+            self.insert(Self::cache_key(&path), module);
+        }
+    }
+
+    fn get_module(&self, specifier: JsString) -> Option<Module> {
+        let path = self
+            .resolve_path(None, &specifier.to_std_string_escaped())
+            .ok()?;
+        self.get(&Self::cache_key(&path))
+    }
 }
@@ -25,6 +25,8 @@ mod loader;
 mod namespace;
 mod source;
 mod synthetic;
+#[cfg(test)]
+mod tests;
 use boa_parser::source::ReadChar;
 pub use loader::*;
 pub use namespace::ModuleNamespace;
@@ -34,6 +36,7 @@ pub use synthetic::{SyntheticModule, SyntheticModuleInitializer};
 use std::cell::{Cell, RefCell};
 use std::collections::HashSet;
 use std::hash::Hash;
+use std::path::Path;
 use std::rc::Rc;
 
 use rustc_hash::FxHashSet;
@@ -48,7 +51,7 @@ use crate::{
     environments::DeclarativeEnvironment,
     object::{JsObject, JsPromise},
     realm::Realm,
-    Context, HostDefined, JsError, JsResult, JsString, JsValue, NativeFunction,
+    Context, HostDefined, JsError, JsNativeError, JsResult, JsString, JsValue, NativeFunction,
 };
 
 /// ECMAScript's [**Abstract module record**][spec].
@@ -75,6 +78,8 @@ struct ModuleRepr {
     namespace: GcRefCell<Option<JsObject>>,
     kind: ModuleKind,
     host_defined: HostDefined,
+    #[unsafe_ignore_trace]
+    path: Option<Rc<Path>>,
 }
 
 /// The kind of a [`Module`].
@@ -156,10 +161,14 @@ impl Module {
     ) -> JsResult<Self> {
         let _timer = Profiler::global().start_event("Module parsing", "Main");
         let mut parser = Parser::new(src);
+        let path = parser.path();
         parser.set_identifier(context.next_parser_identifier());
-        let module = parser.parse_module(context.interner_mut())?;
+        parser.set_nesting_limit(context.runtime_limits().nesting_limit());
+        let module = parser
+            .parse_module(context.interner_mut())
+            .map_err(|err| JsNativeError::from_parse_error(&err, path))?;
 
-        let src = SourceTextModule::new(module, context.interner());
+        let src = SourceTextModule::new(module, parser.take_source_text(), context.interner());
 
         Ok(Self {
             inner: Gc::new(ModuleRepr {
@@ -167,6 +176,7 @@ impl Module {
                 namespace: GcRefCell::default(),
                 kind: ModuleKind::SourceText(src),
                 host_defined: HostDefined::default(),
+                path: path.map(Rc::from),
             }),
         })
     }
@@ -194,6 +204,7 @@ impl Module {
                 namespace: GcRefCell::default(),
                 kind: ModuleKind::Synthetic(synth),
                 host_defined: HostDefined::default(),
+                path: None,
             }),
         }
     }
@@ -214,6 +225,19 @@ impl Module {
         &self.inner.host_defined
     }
 
+    /// Gets the path of the source this module was parsed from, if any.
+    ///
+    /// This is always `None` for synthetic modules.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.inner.path.as_deref()
+    }
+
+    pub(crate) fn path_rc(&self) -> Option<Rc<Path>> {
+        self.inner.path.clone()
+    }
+
     /// Gets the kind of this `Module`.
     pub(crate) fn kind(&self) -> &ModuleKind {
         &self.inner.kind
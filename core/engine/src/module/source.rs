@@ -13,6 +13,7 @@ use boa_ast::{
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_interner::Interner;
 use boa_macros::utf16;
+use boa_parser::source::SourceText;
 use indexmap::IndexSet;
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
@@ -233,6 +234,7 @@ struct ModuleCode {
     has_tla: bool,
     requested_modules: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
     source: boa_ast::Module,
+    source_text: Rc<SourceText>,
     import_entries: Vec<ImportEntry>,
     local_export_entries: Vec<LocalExportEntry>,
     indirect_export_entries: Vec<IndirectExportEntry>,
@@ -245,7 +247,7 @@ impl SourceTextModule {
     /// Contains part of the abstract operation [`ParseModule`][parse].
     ///
     /// [parse]: https://tc39.es/ecma262/#sec-parsemodule
-    pub(super) fn new(code: boa_ast::Module, interner: &Interner) -> Self {
+    pub(super) fn new(code: boa_ast::Module, source_text: SourceText, interner: &Interner) -> Self {
         // 3. Let requestedModules be the ModuleRequests of body.
         let requested_modules = code
             .items()
@@ -336,6 +338,7 @@ impl SourceTextModule {
             import_meta: GcRefCell::default(),
             code: ModuleCode {
                 source: code,
+                source_text: Rc::new(source_text),
                 requested_modules,
                 has_tla,
                 import_entries,
@@ -1435,6 +1438,7 @@ impl SourceTextModule {
             context,
         );
 
+        compiler.source_text = Some(self.code.source_text.clone());
         compiler.code_block_flags |= CodeBlockFlags::IS_ASYNC;
         compiler.async_handler = Some(compiler.push_handler());
 
@@ -1580,9 +1584,11 @@ impl SourceTextModule {
                         continue;
                     }
                     // i. If IsConstantDeclaration of d is true, then
-                    LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(
-                        c,
-                    )) => {
+                    LexicallyScopedDeclaration::LexicalDeclaration(
+                        LexicalDeclaration::Const(c)
+                        | LexicalDeclaration::Using(c)
+                        | LexicalDeclaration::AwaitUsing(c),
+                    ) => {
                         // a. For each element dn of the BoundNames of d, do
                         for name in bound_names(c) {
                             let name = name.to_js_string(compiler.interner());
@@ -0,0 +1,361 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use futures_lite::future;
+use indoc::indoc;
+
+use crate::{
+    builtins::promise::PromiseState,
+    job::{FutureJob, JobQueue, NativeJob},
+    js_string,
+    module::{finish_load_async, ModuleLoader, Referrer, SimpleModuleLoader},
+    Context, JsError, JsNativeErrorKind, JsResult, JsString, JsValue, Module, Source,
+};
+
+/// A job queue that polls all pending futures once per iteration, without blocking on them.
+#[derive(Default)]
+struct PollingQueue {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    futures: RefCell<Vec<FutureJob>>,
+}
+
+impl JobQueue for PollingQueue {
+    fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
+        self.jobs.borrow_mut().push_back(job);
+    }
+
+    fn run_jobs(&self, context: &mut Context) {
+        loop {
+            let jobs = std::mem::take(&mut *self.jobs.borrow_mut());
+            for job in jobs {
+                job.call(context).expect("jobs should not fail");
+            }
+
+            let futures = std::mem::take(&mut *self.futures.borrow_mut());
+            for mut fut in futures {
+                match future::block_on(future::poll_once(&mut fut)) {
+                    Some(job) => self.jobs.borrow_mut().push_back(job),
+                    None => self.futures.borrow_mut().push(fut),
+                }
+            }
+
+            if self.jobs.borrow().is_empty() && self.futures.borrow().is_empty() {
+                return;
+            }
+        }
+    }
+
+    fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
+        self.futures.borrow_mut().push(future);
+    }
+}
+
+/// A module loader that completes every load after yielding to the job queue a number of times.
+struct DelayedLoader {
+    modules: &'static [(&'static str, usize, &'static str)],
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+impl ModuleLoader for DelayedLoader {
+    fn load_imported_module(
+        &self,
+        _referrer: Referrer,
+        specifier: JsString,
+        finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+        context: &mut Context,
+    ) {
+        let specifier = specifier.to_std_string_escaped();
+        let (_, delay, source) = *self
+            .modules
+            .iter()
+            .find(|(name, _, _)| *name == specifier)
+            .expect("test should only import known modules");
+        let log = self.log.clone();
+
+        finish_load_async(
+            async move {
+                for _ in 0..delay {
+                    future::yield_now().await;
+                }
+                log.borrow_mut().push(format!("loaded {specifier}"));
+                move |context: &mut Context| {
+                    Module::parse(Source::from_bytes(source), None, context)
+                }
+            },
+            finish_load,
+            context,
+        );
+    }
+}
+
+fn evaluation_log(context: &mut Context) -> JsValue {
+    context
+        .eval(Source::from_bytes("globalThis.order.join()"))
+        .unwrap()
+}
+
+#[test]
+fn async_loads_preserve_evaluation_order() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let loader = Rc::new(DelayedLoader {
+        modules: &[
+            ("slow", 5, "globalThis.order.push('slow');"),
+            ("fast", 1, "globalThis.order.push('fast');"),
+        ],
+        log: log.clone(),
+    });
+    let context = &mut Context::builder()
+        .job_queue(Rc::new(PollingQueue::default()))
+        .module_loader(loader)
+        .build()
+        .unwrap();
+    context
+        .eval(Source::from_bytes("globalThis.order = [];"))
+        .unwrap();
+
+    let module = Module::parse(
+        Source::from_bytes(indoc! {r#"
+            import "slow";
+            import "fast";
+            globalThis.order.push('main');
+        "#}),
+        None,
+        context,
+    )
+    .unwrap();
+
+    let promise = module.load_link_evaluate(context);
+    assert!(matches!(promise.state(), PromiseState::Pending));
+
+    context.run_jobs();
+
+    assert_eq!(
+        promise.state(),
+        PromiseState::Fulfilled(JsValue::undefined())
+    );
+    assert_eq!(*log.borrow(), ["loaded fast", "loaded slow"]);
+    assert_eq!(evaluation_log(context), js_string!("slow,fast,main").into());
+}
+
+#[test]
+fn async_dynamic_import() {
+    let loader = Rc::new(DelayedLoader {
+        modules: &[("lazy", 3, "export const value = 42;")],
+        log: Rc::default(),
+    });
+    let context = &mut Context::builder()
+        .job_queue(Rc::new(PollingQueue::default()))
+        .module_loader(loader)
+        .build()
+        .unwrap();
+
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            globalThis.order = [];
+            import("lazy").then((ns) => globalThis.order.push(ns.value));
+            globalThis.order.push("sync");
+        "#}))
+        .unwrap();
+    assert_eq!(evaluation_log(context), js_string!("sync").into());
+
+    context.run_jobs();
+
+    assert_eq!(evaluation_log(context), js_string!("sync,42").into());
+}
+
+#[test]
+fn string_export_names_link() {
+    let loader = Rc::new(DelayedLoader {
+        modules: &[
+            (
+                "dep",
+                0,
+                r#"const foo = 1; export { foo as "not an identifier" };"#,
+            ),
+            (
+                "reexport",
+                0,
+                r#"export { "not an identifier" as "also not one" } from "dep";"#,
+            ),
+        ],
+        log: Rc::default(),
+    });
+    let context = &mut Context::builder()
+        .job_queue(Rc::new(PollingQueue::default()))
+        .module_loader(loader)
+        .build()
+        .unwrap();
+
+    let module = Module::parse(
+        Source::from_bytes(indoc! {r#"
+            import { "not an identifier" as a } from "dep";
+            import { "also not one" as b } from "reexport";
+            import * as ns from "reexport";
+            globalThis.order = [a, b, ns["also not one"]];
+        "#}),
+        None,
+        context,
+    )
+    .unwrap();
+
+    let promise = module.load_link_evaluate(context);
+    context.run_jobs();
+
+    assert_eq!(
+        promise.state(),
+        PromiseState::Fulfilled(JsValue::undefined())
+    );
+    assert_eq!(evaluation_log(context), js_string!("1,1,1").into());
+}
+
+/// Creates a fresh directory in the system temp directory with the given files.
+fn module_dir(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("boa-{name}-{}", std::process::id()));
+    drop(std::fs::remove_dir_all(&dir));
+    for (path, source) in files {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, source).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn simple_loader_diamond_evaluates_once() {
+    let dir = module_dir(
+        "diamond",
+        &[
+            ("a.js", "import './lib/b.js'; import './lib/c.js';"),
+            ("lib/b.js", "import '../d.js';"),
+            ("lib/c.js", "import './../lib/../d.js';"),
+            ("d.js", "globalThis.count = (globalThis.count ?? 0) + 1;"),
+        ],
+    );
+    let loader = Rc::new(SimpleModuleLoader::new(&dir).unwrap());
+    let context = &mut Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+
+    let main = dir.join("a.js");
+    let source = Source::from_filepath(&main).unwrap();
+    let module = Module::parse(source, None, context).unwrap();
+    let promise = module.load_link_evaluate(context);
+    context.run_jobs();
+
+    assert_eq!(
+        promise.state(),
+        PromiseState::Fulfilled(JsValue::undefined())
+    );
+    assert_eq!(
+        context.eval(Source::from_bytes("count")).unwrap(),
+        JsValue::from(1)
+    );
+    assert!(loader.get_module(js_string!("d.js")).is_some());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn simple_loader_rejects_escaping_root() {
+    let dir = module_dir(
+        "escape",
+        &[
+            ("root/main.js", "import '../outside.js';"),
+            ("outside.js", "globalThis.escaped = true;"),
+        ],
+    );
+
+    for allow in [false, true] {
+        let loader = SimpleModuleLoader::new(dir.join("root"))
+            .unwrap()
+            .allow_escaping_root(allow);
+        let context = &mut Context::builder()
+            .module_loader(Rc::new(loader))
+            .build()
+            .unwrap();
+
+        let main = dir.join("root/main.js");
+        let source = Source::from_filepath(&main).unwrap();
+        let module = Module::parse(source, None, context).unwrap();
+        let promise = module.load_link_evaluate(context);
+        context.run_jobs();
+
+        match promise.state() {
+            PromiseState::Fulfilled(_) => assert!(allow),
+            PromiseState::Rejected(err) => {
+                assert!(!allow);
+                let err = JsError::from_opaque(err).try_native(context).unwrap();
+                assert_eq!(err.kind, JsNativeErrorKind::Type);
+            }
+            PromiseState::Pending => panic!("module should have settled"),
+        }
+    }
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn simple_loader_resolves_inside_root() {
+    let dir = module_dir("resolve", &[("root/lib/a.js", ""), ("outside/b.js", "")]);
+    let root = dir.join("root").canonicalize().unwrap();
+    let loader = SimpleModuleLoader::new(&root).unwrap();
+
+    assert_eq!(
+        loader.resolve_path(None, "./lib/../lib/a.js").unwrap(),
+        root.join("lib/a.js")
+    );
+    assert_eq!(
+        loader.resolve_path(None, "./lib/missing.js").unwrap(),
+        root.join("lib/missing.js")
+    );
+    for specifier in [
+        "../outside/b.js",
+        "./lib/../../outside/b.js",
+        "../missing.js",
+    ] {
+        assert!(loader.resolve_path(None, specifier).is_err(), "{specifier}");
+    }
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(dir.join("outside"), root.join("link")).unwrap();
+        assert!(loader.resolve_path(None, "./link/b.js").is_err());
+    }
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn simple_loader_reports_import_chain() {
+    let dir = module_dir(
+        "chain",
+        &[
+            ("main.js", "import './lib/a.js';"),
+            ("lib/a.js", "import './missing.js';"),
+        ],
+    );
+    let loader = SimpleModuleLoader::new(&dir).unwrap();
+    let context = &mut Context::builder()
+        .module_loader(Rc::new(loader))
+        .build()
+        .unwrap();
+
+    let main = dir.join("main.js");
+    let source = Source::from_filepath(&main).unwrap();
+    let module = Module::parse(source, None, context).unwrap();
+    let promise = module.load_link_evaluate(context);
+    context.run_jobs();
+
+    let PromiseState::Rejected(err) = promise.state() else {
+        panic!("module should have been rejected");
+    };
+    let err = JsError::from_opaque(err).try_native(context).unwrap();
+    let chain = format!(
+        "\n    imported by `{}`\n    imported by `main.js`",
+        std::path::Path::new("lib").join("a.js").display()
+    );
+    assert!(err.message().contains("`./missing.js`"), "{err}");
+    assert!(err.message().ends_with(&chain), "{err}");
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
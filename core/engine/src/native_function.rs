@@ -16,6 +16,7 @@ use crate::{
         FunctionObjectBuilder, JsData, JsFunction, JsPromise,
     },
     realm::Realm,
+    vm::NativeFrame,
     Context, JsNativeError, JsObject, JsResult, JsValue,
 };
 
@@ -350,7 +351,6 @@ pub(crate) fn native_function_call(
     // We technically don't need this since native functions don't push any new frames to the
     // vm, but we'll eventually have to combine the native stack with the vm stack.
     context.check_runtime_limits()?;
-    let this_function_object = obj.clone();
 
     let NativeFunctionObject {
         f: function,
@@ -364,7 +364,12 @@ pub(crate) fn native_function_call(
     let mut realm = realm.unwrap_or_else(|| context.realm().clone());
 
     context.swap_realm(&mut realm);
-    context.vm.native_active_function = Some(this_function_object);
+    context.vm.native_frames.push(NativeFrame {
+        function: obj.clone(),
+        this: Some(this.clone()),
+        argument_count,
+        depth: context.vm.frames.len(),
+    });
 
     let result = if constructor.is_some() {
         function.call(&JsValue::undefined(), &args, context)
@@ -373,7 +378,7 @@ pub(crate) fn native_function_call(
     }
     .map_err(|err| err.inject_realm(context.realm().clone()));
 
-    context.vm.native_active_function = None;
+    context.vm.native_frames.pop();
     context.swap_realm(&mut realm);
 
     context.vm.push(result?);
@@ -395,7 +400,6 @@ fn native_function_construct(
     // We technically don't need this since native functions don't push any new frames to the
     // vm, but we'll eventually have to combine the native stack with the vm stack.
     context.check_runtime_limits()?;
-    let this_function_object = obj.clone();
 
     let NativeFunctionObject {
         f: function,
@@ -409,7 +413,12 @@ fn native_function_construct(
     let mut realm = realm.unwrap_or_else(|| context.realm().clone());
 
     context.swap_realm(&mut realm);
-    context.vm.native_active_function = Some(this_function_object);
+    context.vm.native_frames.push(NativeFrame {
+        function: obj.clone(),
+        this: None,
+        argument_count,
+        depth: context.vm.frames.len(),
+    });
 
     let new_target = context.vm.pop();
     let args = context.vm.pop_n_values(argument_count);
@@ -440,7 +449,7 @@ fn native_function_construct(
             }
         });
 
-    context.vm.native_active_function = None;
+    context.vm.native_frames.pop();
     context.swap_realm(&mut realm);
 
     context.vm.push(result?);
@@ -126,6 +126,61 @@ impl JsArrayBuffer {
         Ok(Self { inner: obj })
     }
 
+    /// Create a new array buffer backed by memory owned by the host, without copying it.
+    ///
+    /// Typed arrays and `DataView`s over the buffer read and write `data` directly. Ownership
+    /// of `data` can be taken back with [`JsArrayBuffer::detach_external`], after which every
+    /// view of the buffer throws a `TypeError` as with any detached buffer. External buffers
+    /// have a fixed length and cannot be resized.
+    ///
+    /// # Aliasing
+    ///
+    /// The engine obtains `&mut [u8]` slices of `data` through [`AsMut`] whenever a script
+    /// accesses the buffer. If the host keeps another way to reach the same memory (e.g. a
+    /// memory-mapped file or a raw pointer), it must only access it while no script is running
+    /// and no reference returned by [`JsArrayBuffer::data`] or [`JsArrayBuffer::data_mut`] is
+    /// alive. Detaching the buffer from JavaScript (e.g. with `ArrayBuffer.prototype.transfer`)
+    /// copies the bytes into engine-owned memory and drops `data`.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsArrayBuffer,
+    /// # Context, JsResult, JsValue,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let host_data: Box<[u8]> = vec![1, 2, 3].into_boxed_slice();
+    /// let array_buffer = JsArrayBuffer::from_external(host_data, context)?;
+    ///
+    /// array_buffer.data_mut().expect("buffer is attached")[0] = 10;
+    ///
+    /// let host_data = array_buffer
+    ///     .detach_external::<Box<[u8]>>(&JsValue::undefined())?
+    ///     .expect("buffer is backed by a boxed slice");
+    /// assert_eq!(&*host_data, &[10, 2, 3]);
+    /// assert!(array_buffer.data().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_external<T>(data: T, context: &mut Context) -> JsResult<Self>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]> + 'static,
+    {
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .array_buffer()
+            .prototype();
+
+        let obj = JsObject::new(
+            context.root_shape(),
+            prototype,
+            ArrayBuffer::from_external(data, JsValue::Undefined),
+        );
+
+        Ok(Self { inner: obj })
+    }
+
     /// Create a [`JsArrayBuffer`] from a [`JsObject`], if the object is not an array buffer throw a `TypeError`.
     ///
     /// This does not clone the fields of the array buffer, it only does a shallow clone of the object.
@@ -211,6 +266,25 @@ impl JsArrayBuffer {
             })
     }
 
+    /// Detach the external memory backing the buffer, returning ownership of it to the host.
+    ///
+    /// Returns `Ok(None)` without detaching if the buffer is not backed by external memory of
+    /// type `T`. See [`JsArrayBuffer::from_external`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the detach key is invalid or the buffer was already detached.
+    #[inline]
+    pub fn detach_external<T: 'static>(&self, detach_key: &JsValue) -> JsResult<Option<T>> {
+        let mut buffer = self.inner.borrow_mut();
+        if buffer.data.is_detached() {
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer was already detached")
+                .into());
+        }
+        buffer.data.detach_external(detach_key)
+    }
+
     /// Get an immutable reference to the [`JsArrayBuffer`]'s data.
     ///
     /// Returns `None` if detached.
@@ -1,7 +1,10 @@
 //! Rust API wrappers for the `TypedArray` Builtin ECMAScript Objects
 use crate::{
     builtins::typed_array::BuiltinTypedArray,
-    builtins::{typed_array::TypedArray, BuiltInConstructor},
+    builtins::{
+        typed_array::{TypedArray, TypedArrayKind},
+        BuiltInConstructor,
+    },
     error::JsNativeError,
     object::{JsArrayBuffer, JsFunction, JsObject, JsObjectType},
     value::{IntoOrUndefined, TryFromJs},
@@ -34,6 +37,16 @@ impl JsTypedArray {
         }
     }
 
+    /// Gets the kind of the typed array, e.g. [`TypedArrayKind::Uint8`] for an `Uint8Array`.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> TypedArrayKind {
+        self.inner
+            .downcast_ref::<TypedArray>()
+            .expect("must be a typed array")
+            .kind()
+    }
+
     /// Get the length of the array.
     ///
     /// Same as `array.length` in JavaScript.
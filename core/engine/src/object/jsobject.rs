@@ -21,7 +21,7 @@ use crate::{
     value::PreferredType,
     Context, JsResult, JsString, JsValue,
 };
-use boa_gc::{self, Finalize, Gc, GcBox, GcRefCell, Trace};
+use boa_gc::{self, Finalize, Gc, GcBox, GcRefCell, Trace, WeakGc};
 use std::{
     cell::RefCell,
     collections::HashMap,
@@ -569,6 +569,14 @@ Cannot both specify accessors and a value or writable attribute",
 
         Err(self)
     }
+
+    /// Creates a [`PropertiesBuilder`] to define several properties on this object at once.
+    ///
+    /// [`PropertiesBuilder`]: super::PropertiesBuilder
+    #[must_use]
+    pub fn properties<'ctx>(&self, context: &'ctx mut Context) -> super::PropertiesBuilder<'ctx> {
+        super::PropertiesBuilder::new(self.clone(), context)
+    }
 }
 
 impl<T: NativeObject + ?Sized> JsObject<T> {
@@ -779,6 +787,20 @@ impl<T: NativeObject + ?Sized> JsObject<T> {
         &self.inner
     }
 
+    /// Creates a [`WeakJsObject`] pointing to this object.
+    ///
+    /// The weak handle does not keep the object alive, making it suitable for host-side caches
+    /// that must not leak JavaScript objects.
+    #[inline]
+    #[must_use]
+    pub fn downgrade(&self) -> WeakJsObject<T> {
+        let ptr: *const _ = self.as_ref();
+        WeakJsObject {
+            inner: WeakGc::new(&self.inner),
+            addr: ptr.cast::<()>() as usize,
+        }
+    }
+
     /// Create a new private name with this object as the unique identifier.
     pub(crate) fn private_name(&self, description: JsString) -> PrivateName {
         let ptr: *const _ = self.as_ref();
@@ -814,6 +836,71 @@ impl<T: NativeObject + ?Sized> Hash for JsObject<T> {
     }
 }
 
+/// A weak reference to a [`JsObject`].
+///
+/// Unlike [`JsObject`], this handle does not keep the object alive, so [`WeakJsObject::upgrade`]
+/// returns `None` once the object has been garbage collected.
+///
+/// Weak handles can be used as keys of hash maps: the hash is derived from the address of the
+/// object at the time the handle was created, so it stays stable after the object is collected.
+#[derive(Trace, Finalize)]
+pub struct WeakJsObject<T: NativeObject + ?Sized = dyn NativeObject> {
+    inner: WeakGc<VTableObject<T>>,
+    #[unsafe_ignore_trace]
+    addr: usize,
+}
+
+impl<T: NativeObject + ?Sized> WeakJsObject<T> {
+    /// Returns a [`JsObject`] pointing to the object if it is still alive, or `None` if it was
+    /// already garbage collected.
+    #[inline]
+    #[must_use]
+    pub fn upgrade(&self) -> Option<JsObject<T>> {
+        self.inner.upgrade().map(JsObject::from)
+    }
+
+    /// Checks if the object is still alive.
+    #[inline]
+    #[must_use]
+    pub fn is_upgradable(&self) -> bool {
+        self.inner.is_upgradable()
+    }
+}
+
+impl<T: NativeObject + ?Sized> Clone for WeakJsObject<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            addr: self.addr,
+        }
+    }
+}
+
+impl<T: NativeObject + ?Sized> PartialEq for WeakJsObject<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // A collected object could have its address reused by a new allocation, so a dead handle
+        // is never equal to a live one.
+        self.addr == other.addr && self.is_upgradable() == other.is_upgradable()
+    }
+}
+
+impl<T: NativeObject + ?Sized> Eq for WeakJsObject<T> {}
+
+impl<T: NativeObject + ?Sized> Hash for WeakJsObject<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.addr.hash(state);
+    }
+}
+
+impl<T: NativeObject + ?Sized> Debug for WeakJsObject<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakJsObject")
+            .field("addr", &format_args!("0x{:X}", self.addr))
+            .field("alive", &self.is_upgradable())
+            .finish_non_exhaustive()
+    }
+}
+
 /// An error returned by [`JsObject::try_borrow`](struct.JsObject.html#method.try_borrow).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BorrowError;
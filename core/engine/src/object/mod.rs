@@ -23,7 +23,7 @@ use crate::{
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     string::utf16,
-    Context, JsString, JsSymbol, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsSymbol, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
@@ -716,6 +716,163 @@ impl<'ctx> ObjectInitializer<'ctx> {
     }
 }
 
+/// Builder for defining several properties on an existing [`JsObject`].
+///
+/// Created by [`JsObject::properties`]. The properties are collected and defined in order when
+/// [`PropertiesBuilder::build`] is called, using the `DefinePropertyOrThrow` operation.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #     js_string, object::FunctionObjectBuilder, property::Attribute, Context, JsValue,
+/// #     NativeFunction,
+/// # };
+/// let context = &mut Context::default();
+/// let prototype = context.intrinsics().constructors().object().prototype();
+/// let getter = FunctionObjectBuilder::new(
+///     context.realm(),
+///     NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::new(2))),
+/// )
+/// .build();
+///
+/// prototype
+///     .properties(context)
+///     .data(js_string!("x"), 1)
+///     .accessor(js_string!("y"), Some(getter), None, Attribute::CONFIGURABLE)
+///     .method(
+///         NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::undefined())),
+///         js_string!("run"),
+///         0,
+///     )
+///     .build()?;
+///
+/// assert_eq!(prototype.get(js_string!("y"), context)?, JsValue::new(2));
+/// # Ok::<(), boa_engine::JsError>(())
+/// ```
+#[derive(Debug)]
+pub struct PropertiesBuilder<'ctx> {
+    context: &'ctx mut Context,
+    object: JsObject,
+    properties: Vec<(PropertyKey, PropertyDescriptor)>,
+}
+
+impl<'ctx> PropertiesBuilder<'ctx> {
+    /// Create a new `PropertiesBuilder` for `object`.
+    pub(crate) fn new(object: JsObject, context: &'ctx mut Context) -> Self {
+        Self {
+            context,
+            object,
+            properties: Vec::new(),
+        }
+    }
+
+    /// Add a writable, enumerable and configurable data property.
+    pub fn data<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+        V: Into<JsValue>,
+    {
+        self.property(key, value, Attribute::all())
+    }
+
+    /// Add a data property with the given attributes.
+    pub fn property<K, V>(&mut self, key: K, value: V, attribute: Attribute) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+        V: Into<JsValue>,
+    {
+        let property = PropertyDescriptor::builder()
+            .value(value)
+            .writable(attribute.writable())
+            .enumerable(attribute.enumerable())
+            .configurable(attribute.configurable());
+        self.properties.push((key.into(), property.build()));
+        self
+    }
+
+    /// Add an accessor property with the given attributes.
+    ///
+    /// # Panics
+    ///
+    /// If both getter or setter are [`None`].
+    pub fn accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<JsFunction>,
+        set: Option<JsFunction>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+    {
+        // Accessors should have at least one function.
+        assert!(set.is_some() || get.is_some());
+
+        let property = PropertyDescriptor::builder()
+            .maybe_get(get)
+            .maybe_set(set)
+            .enumerable(attribute.enumerable())
+            .configurable(attribute.configurable());
+        self.properties.push((key.into(), property.build()));
+        self
+    }
+
+    /// Add a non-enumerable method, like the ones defined on builtin prototypes.
+    pub fn method<B>(&mut self, function: NativeFunction, binding: B, length: usize) -> &mut Self
+    where
+        B: Into<FunctionBinding>,
+    {
+        let binding = binding.into();
+        let function = FunctionObjectBuilder::new(self.context.realm(), function)
+            .name(binding.name)
+            .length(length)
+            .constructor(false)
+            .build();
+
+        let property = PropertyDescriptor::builder()
+            .value(function)
+            .writable(true)
+            .enumerable(false)
+            .configurable(true);
+        self.properties.push((binding.binding, property.build()));
+        self
+    }
+
+    /// Define all the added properties on the object, in order.
+    ///
+    /// # Errors
+    ///
+    /// If a property couldn't be defined because of a `TypeError`, returns a `TypeError` naming
+    /// the property, with the original error as its cause. Other errors, like the ones thrown by
+    /// proxy traps or raised when reaching a runtime limit, are returned unchanged. Properties
+    /// added before the failing one remain defined.
+    pub fn build(&mut self) -> JsResult<()> {
+        for (key, property) in std::mem::take(&mut self.properties) {
+            match self
+                .object
+                .define_property_or_throw(key.clone(), property, self.context)
+            {
+                Ok(_) => {}
+                Err(err) if err.as_native().is_some_and(JsNativeError::is_type) => {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!("failed to define property `{key}`"))
+                        .with_cause(err)
+                        .into());
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the context used to create the properties.
+    #[inline]
+    pub fn context(&mut self) -> &mut Context {
+        self.context
+    }
+}
+
 /// Builder for creating constructors objects, like `Array`.
 #[derive(Debug)]
 pub struct ConstructorBuilder<'ctx> {
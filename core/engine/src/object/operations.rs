@@ -1,7 +1,7 @@
 use crate::{
     builtins::{
         function::{BoundFunction, ClassFieldDefinition, OrdinaryFunction},
-        Array, Proxy,
+        Array, Number, Proxy,
     },
     context::intrinsics::{StandardConstructor, StandardConstructors},
     error::JsNativeError,
@@ -343,6 +343,38 @@ impl JsObject {
         Ok(desc.is_some())
     }
 
+    /// Gets the descriptor of an own property of the object, calling its `[[GetOwnProperty]]`
+    /// internal method.
+    ///
+    /// This never calls the getter of an accessor property, which makes it useful to inspect
+    /// objects without side effects, unless the object is a proxy.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getownproperty-p
+    pub fn get_own_property<K>(
+        &self,
+        key: K,
+        context: &mut Context,
+    ) -> JsResult<Option<PropertyDescriptor>>
+    where
+        K: Into<PropertyKey>,
+    {
+        self.__get_own_property__(&key.into(), &mut InternalMethodContext::new(context))
+    }
+
+    /// Gets the keys of the own properties of the object, calling its `[[OwnPropertyKeys]]`
+    /// internal method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-ownpropertykeys
+    pub fn own_property_keys(&self, context: &mut Context) -> JsResult<Vec<PropertyKey>> {
+        self.__own_property_keys__(context)
+    }
+
     /// `Call ( F, V [ , argumentsList ] )`
     ///
     /// # Panics
@@ -634,13 +666,20 @@ impl JsObject {
             .into())
     }
 
-    /// It is used to iterate over names of object's keys.
+    /// Abstract operation `EnumerableOwnPropertyNames ( O, kind )`
+    ///
+    /// Collects the keys, values or entries of the enumerable own string-keyed properties of the
+    /// object.
     ///
     /// More information:
     /// - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-enumerableownpropertynames
-    pub(crate) fn enumerable_own_property_names(
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the internal methods of the object throws.
+    pub fn enumerable_own_property_names(
         &self,
         kind: PropertyNameKind,
         context: &mut Context,
@@ -656,7 +695,7 @@ impl JsObject {
             // a. If Type(key) is String, then
             let key_str = match &key {
                 PropertyKey::String(s) => Some(s.clone()),
-                PropertyKey::Index(i) => Some(i.get().to_string().into()),
+                PropertyKey::Index(i) => Some(Number::integer_to_js_string(i.get().into())),
                 PropertyKey::Symbol(_) => None,
             };
 
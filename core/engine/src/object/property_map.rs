@@ -8,10 +8,15 @@ use super::{
     JsPrototype, ObjectStorage, PropertyDescriptor, PropertyKey,
 };
 use crate::{property::PropertyDescriptorBuilder, JsString, JsSymbol, JsValue};
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::{
+    custom_trace, track_external_allocation, track_external_deallocation, Finalize, MemoryKind,
+    Trace,
+};
 use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHasher};
-use std::{collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator};
+use std::{
+    cmp::Ordering, collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator, mem,
+};
 use thin_vec::ThinVec;
 
 /// Wrapper around `indexmap::IndexMap` for usage in `PropertyMap`.
@@ -184,6 +189,14 @@ impl IndexedProperties {
         removed
     }
 
+    /// Returns the number of bytes allocated to store the properties.
+    fn allocated_bytes(&self) -> usize {
+        match self {
+            Self::Dense(vec) => vec.capacity() * mem::size_of::<JsValue>(),
+            Self::Sparse(map) => map.capacity() * mem::size_of::<(u32, PropertyDescriptor)>(),
+        }
+    }
+
     /// Check if we contain the key to a property descriptor.
     fn contains_key(&self, key: u32) -> bool {
         match self {
@@ -217,13 +230,26 @@ impl IndexedProperties {
 /// A [`PropertyMap`] contains all the properties of an object.
 ///
 /// The property values are stored in different data structures based on keys.
+///
+/// The memory allocated for the property values is reported to the garbage collector as
+/// [`MemoryKind::Object`] memory.
 #[derive(Default, Debug, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
 pub struct PropertyMap {
     /// Properties stored with integers as keys.
     indexed_properties: IndexedProperties,
 
     pub(crate) shape: Shape,
     pub(crate) storage: ObjectStorage,
+
+    /// The number of bytes reported to the garbage collector.
+    tracked_bytes: usize,
+}
+
+impl Drop for PropertyMap {
+    fn drop(&mut self) {
+        track_external_deallocation(MemoryKind::Object, self.tracked_bytes);
+    }
 }
 
 impl PropertyMap {
@@ -231,11 +257,14 @@ impl PropertyMap {
     #[must_use]
     #[inline]
     pub fn new(shape: Shape, elements: ThinVec<JsValue>) -> Self {
-        Self {
+        let mut map = Self {
             indexed_properties: IndexedProperties::new(elements),
             shape,
             storage: Vec::default(),
-        }
+            tracked_bytes: 0,
+        };
+        map.track_memory();
+        map
     }
 
     /// Construct a [`PropertyMap`] from with the given prototype with an unique [`Shape`].
@@ -246,6 +275,7 @@ impl PropertyMap {
             indexed_properties: IndexedProperties::default(),
             shape: UniqueShape::new(prototype, PropertyTableInner::default()).into(),
             storage: Vec::default(),
+            tracked_bytes: 0,
         }
     }
 
@@ -261,6 +291,7 @@ impl PropertyMap {
             indexed_properties: IndexedProperties::default(),
             shape: shape.into(),
             storage: Vec::default(),
+            tracked_bytes: 0,
         }
     }
 
@@ -333,6 +364,19 @@ impl PropertyMap {
         key: &PropertyKey,
         property: PropertyDescriptor,
         out_slot: &mut Slot,
+    ) -> bool {
+        let replaced = self.insert_untracked(key, property, out_slot);
+        self.track_memory();
+        replaced
+    }
+
+    /// Insert the given property descriptor with the given key [`PropertyMap`], without
+    /// reporting the allocated memory to the garbage collector.
+    fn insert_untracked(
+        &mut self,
+        key: &PropertyKey,
+        property: PropertyDescriptor,
+        out_slot: &mut Slot,
     ) -> bool {
         if let PropertyKey::Index(index) = key {
             return self.indexed_properties.insert(index.get(), property);
@@ -430,6 +474,14 @@ impl PropertyMap {
 
     /// Remove the property with the given key from the [`PropertyMap`].
     pub fn remove(&mut self, key: &PropertyKey) -> bool {
+        let removed = self.remove_untracked(key);
+        self.track_memory();
+        removed
+    }
+
+    /// Remove the property with the given key from the [`PropertyMap`], without reporting the
+    /// allocated memory to the garbage collector.
+    fn remove_untracked(&mut self, key: &PropertyKey) -> bool {
         if let PropertyKey::Index(index) = key {
             return self.indexed_properties.remove(index.get());
         }
@@ -450,6 +502,25 @@ impl PropertyMap {
     /// Overrides all the indexed properties, setting it to dense storage.
     pub(crate) fn override_indexed_properties(&mut self, properties: ThinVec<JsValue>) {
         self.indexed_properties = IndexedProperties::Dense(properties);
+        self.track_memory();
+    }
+
+    /// Reports the changes in the memory allocated for the properties to the garbage collector.
+    ///
+    /// This must be called after modifying the storage of the properties directly.
+    pub(crate) fn track_memory(&mut self) {
+        let bytes = self.storage.capacity() * mem::size_of::<JsValue>()
+            + self.indexed_properties.allocated_bytes();
+        match bytes.cmp(&self.tracked_bytes) {
+            Ordering::Greater => {
+                track_external_allocation(MemoryKind::Object, bytes - self.tracked_bytes);
+            }
+            Ordering::Less => {
+                track_external_deallocation(MemoryKind::Object, self.tracked_bytes - bytes);
+            }
+            Ordering::Equal => return,
+        }
+        self.tracked_bytes = bytes;
     }
 
     /// Returns the vec of dense indexed properties if they exist.
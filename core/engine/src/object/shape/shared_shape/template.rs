@@ -115,6 +115,7 @@ impl ObjectTemplate {
         };
 
         object.properties.storage = storage;
+        object.properties.track_memory();
 
         JsObject::from_object_and_vtable(object, internal_methods)
     }
@@ -138,6 +139,7 @@ impl ObjectTemplate {
         };
 
         object.properties.storage = storage;
+        object.properties.track_memory();
 
         JsObject::from_object_and_vtable(object, internal_methods)
     }
@@ -1,4 +1,8 @@
-use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use crate::{
+    js_string, object::JsObject, property::Attribute, run_test_actions, Context, JsData,
+    JsNativeErrorKind, NativeFunction, Source, TestAction,
+};
+use boa_gc::{Finalize, HeapSnapshot, Trace};
 use indoc::indoc;
 
 #[test]
@@ -38,3 +42,165 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn properties_builder() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let prototype = context.intrinsics().constructors().string().prototype();
+            prototype
+                .properties(context)
+                .data(js_string!("answer"), 42)
+                .property(js_string!("hidden"), true, Attribute::empty())
+                .method(
+                    NativeFunction::from_fn_ptr(|this, _, context| {
+                        Ok(this.to_string(context)?.len().into())
+                    }),
+                    js_string!("size"),
+                    0,
+                )
+                .build()
+                .unwrap();
+        }),
+        TestAction::assert_eq("'abc'.answer", 42),
+        TestAction::assert_eq("'abc'.size()", 3),
+        TestAction::assert("!Object.keys(String.prototype).includes('size')"),
+        TestAction::inspect_context(|context| {
+            let object = context
+                .eval(Source::from_bytes("Object.freeze({ a: 1 })"))
+                .unwrap();
+            let object = object.as_object().unwrap();
+            let error = object
+                .properties(context)
+                .data(js_string!("a"), 2)
+                .data(js_string!("b"), 3)
+                .build()
+                .unwrap_err()
+                .try_native(context)
+                .unwrap();
+
+            assert_eq!(error.kind, JsNativeErrorKind::Type);
+            assert_eq!(error.message(), "failed to define property `a`");
+        }),
+        TestAction::inspect_context(|context| {
+            let object = context
+                .eval(Source::from_bytes(
+                    "new Proxy({}, { defineProperty() { throw 5; } })",
+                ))
+                .unwrap();
+            let error = object
+                .as_object()
+                .unwrap()
+                .properties(context)
+                .data(js_string!("a"), 1)
+                .build()
+                .unwrap_err();
+
+            assert_eq!(error.as_opaque(), Some(&5.into()));
+        }),
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_loop_iteration_limit(10);
+            let object = context
+                .eval(Source::from_bytes(
+                    "new Proxy({}, { defineProperty() { for (;;) {} } })",
+                ))
+                .unwrap();
+            let error = object
+                .as_object()
+                .unwrap()
+                .properties(context)
+                .data(js_string!("a"), 1)
+                .build()
+                .unwrap_err();
+
+            assert!(error
+                .as_native()
+                .is_some_and(|error| error.kind == JsNativeErrorKind::RuntimeLimit));
+        }),
+    ]);
+}
+
+#[test]
+fn weak_object_handle() {
+    let context = &mut Context::default();
+
+    let object = JsObject::with_object_proto(context.intrinsics());
+    let live = object.downgrade();
+    let weak = JsObject::with_object_proto(context.intrinsics()).downgrade();
+
+    boa_gc::force_collect();
+
+    assert_eq!(live.upgrade(), Some(object.clone()));
+    assert_eq!(live, object.downgrade());
+    assert!(!weak.is_upgradable());
+    assert!(weak.upgrade().is_none());
+    assert_ne!(live, weak);
+}
+
+#[test]
+fn heap_snapshot_path_from_global() {
+    #[derive(Trace, Finalize, JsData)]
+    struct Retained;
+
+    let context = &mut Context::default();
+    let object = JsObject::from_proto_and_data(None, Retained);
+    let id = HeapSnapshot::id_of(object.inner());
+    context
+        .register_global_property(js_string!("retained"), object, Attribute::all())
+        .unwrap();
+    let global = HeapSnapshot::id_of(context.global_object().inner());
+
+    let snapshot = boa_gc::heap_snapshot();
+    let index = snapshot.index_of(id).unwrap();
+    assert!(snapshot.nodes()[index].type_name.contains("Retained"));
+    assert!(!snapshot.nodes()[index].root);
+
+    let path = snapshot.path_to(index).unwrap();
+    assert!(path.contains(&snapshot.index_of(global).unwrap()));
+}
+
+#[test]
+fn indexed_properties_storage() {
+    fn is_dense(name: &str, context: &mut Context) -> bool {
+        let array = context.eval(Source::from_bytes(name)).unwrap();
+        let array = array.as_object().unwrap().borrow();
+        array.properties().dense_indexed_properties().is_some()
+    }
+
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var a = [];
+            for (let i = 0; i < 100; i++) a[i] = i;
+            var b = [1, 2, 3, 4];
+            b.length = 2;
+            b.push(5);
+            var c = [1, 2, 3];
+            delete c[2];
+
+            var d = [1, 2, 3];
+            delete d[0];
+            var e = [0];
+            e[1e6] = 1;
+            var f = [1, 2, 3];
+            Object.defineProperty(f, 1, { value: 5, writable: false });
+            var g = [1, 2, 3];
+            Object.defineProperty(g, 0, { get() { return 0; } });
+        "#}),
+        TestAction::inspect_context(|context| {
+            for name in ["a", "b", "c"] {
+                assert!(is_dense(name, context), "`{name}` should be dense");
+            }
+            for name in ["d", "e", "f", "g"] {
+                assert!(!is_dense(name, context), "`{name}` should be sparse");
+            }
+        }),
+        TestAction::assert("arrayEquals(b, [1, 2, 5])"),
+        TestAction::assert_eq("Object.keys(d).join()", js_string!("1,2")),
+        TestAction::assert_eq("d.join()", js_string!(",2,3")),
+        TestAction::assert_eq("e.length", 1_000_001),
+        TestAction::assert_eq("Object.keys(e).join()", js_string!("0,1000000")),
+        TestAction::assert_eq("f[1] = 7; f.join()", js_string!("1,5,3")),
+        TestAction::assert_eq("g.join()", js_string!("0,2,3")),
+    ]);
+}
@@ -1,6 +1,7 @@
 //! Implements optimizations.
 
 pub(crate) mod pass;
+pub(crate) mod peephole;
 pub(crate) mod walker;
 
 use self::{pass::ConstantFolding, walker::Walker};
@@ -19,8 +20,11 @@ bitflags! {
         /// Apply constant folding optimization.
         const CONSTANT_FOLDING = 0b0000_0010;
 
+        /// Apply peephole optimizations over the generated bytecode.
+        const PEEPHOLE = 0b0000_0100;
+
         /// Apply all optimizations.
-        const OPTIMIZE_ALL = Self::CONSTANT_FOLDING.bits();
+        const OPTIMIZE_ALL = Self::CONSTANT_FOLDING.bits() | Self::PEEPHOLE.bits();
     }
 }
 
@@ -32,7 +32,9 @@ fn js_value_to_literal(value: JsValue, context: &mut Context) -> Literal {
         JsValue::Null => Literal::Null,
         JsValue::Undefined => Literal::Undefined,
         JsValue::Boolean(v) => Literal::Bool(v),
-        JsValue::String(v) => Literal::String(context.interner_mut().get_or_intern(v.as_ref())),
+        JsValue::String(v) => {
+            Literal::String(context.interner_mut().get_or_intern(v.to_vec().as_slice()))
+        }
         JsValue::Rational(v) => Literal::Num(v),
         JsValue::Integer(v) => Literal::Int(v),
         JsValue::BigInt(v) => Literal::BigInt(Box::new(v.as_inner().clone())),
@@ -42,6 +44,12 @@ fn js_value_to_literal(value: JsValue, context: &mut Context) -> Literal {
     }
 }
 
+/// The maximum length of a string that is produced by folding a concatenation.
+///
+/// Every folded string is interned, so longer strings are left to be concatenated at runtime
+/// instead of filling the interner with the intermediate results of a long chain.
+const MAX_FOLDED_STRING_LENGTH: usize = 1024;
+
 #[derive(Debug, Default)]
 pub(crate) struct ConstantFolding {}
 
@@ -64,7 +72,7 @@ impl ConstantFolding {
         let Expression::Literal(literal) = unary.target() else {
             return PassAction::Keep;
         };
-        let value = match (literal, unary.op()) {
+        let value = match (literal.literal(), unary.op()) {
             (literal, UnaryOp::Minus) => literal_to_js_value(literal, context).neg(context),
             (literal, UnaryOp::Plus) => literal_to_js_value(literal, context)
                 .to_number(context)
@@ -85,11 +93,9 @@ impl ConstantFolding {
                 literal_to_js_value(literal, context).js_type_of(),
             )),
             (_, UnaryOp::Delete) => {
-                return PassAction::Replace(Expression::Literal(Literal::Bool(true)))
-            }
-            (_, UnaryOp::Void) => {
-                return PassAction::Replace(Expression::Literal(Literal::Undefined))
+                return PassAction::Replace(Expression::from(Literal::Bool(true)))
             }
+            (_, UnaryOp::Void) => return PassAction::Replace(Expression::from(Literal::Undefined)),
         };
 
         // If it fails then revert changes
@@ -97,7 +103,7 @@ impl ConstantFolding {
             return PassAction::Keep;
         };
 
-        PassAction::Replace(Expression::Literal(js_value_to_literal(value, context)))
+        PassAction::Replace(Expression::from(js_value_to_literal(value, context)))
     }
 
     fn constant_fold_binary_expr(
@@ -124,20 +130,20 @@ impl ConstantFolding {
             if !matches!(binary.rhs(), Expression::Literal(_)) {
                 // If left-hand side is already undefined then just keep it,
                 // so we don't cause an infinite loop.
-                if *binary.lhs() == Expression::Literal(Literal::Undefined) {
+                if *binary.lhs() == Expression::from(Literal::Undefined) {
                     return PassAction::Keep;
                 }
 
-                *binary.lhs_mut() = Expression::Literal(Literal::Undefined);
+                *binary.lhs_mut() = Expression::from(Literal::Undefined);
                 return PassAction::Modified;
             }
 
             // We take rhs, by replacing with a dummy value.
-            let rhs = std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined));
+            let rhs = std::mem::replace(binary.rhs_mut(), Expression::from(Literal::Undefined));
             return PassAction::Replace(rhs);
         }
 
-        let lhs = literal_to_js_value(lhs, context);
+        let lhs = literal_to_js_value(lhs.literal(), context);
 
         // Do the following optimizations if it's a logical binary expression:
         //
@@ -156,23 +162,23 @@ impl ConstantFolding {
             let expr = match op {
                 LogicalOp::And => {
                     if lhs.to_boolean() {
-                        std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(binary.rhs_mut(), Expression::from(Literal::Undefined))
                     } else {
-                        std::mem::replace(binary.lhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(binary.lhs_mut(), Expression::from(Literal::Undefined))
                     }
                 }
                 LogicalOp::Or => {
                     if lhs.to_boolean() {
-                        std::mem::replace(binary.lhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(binary.lhs_mut(), Expression::from(Literal::Undefined))
                     } else {
-                        std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(binary.rhs_mut(), Expression::from(Literal::Undefined))
                     }
                 }
                 LogicalOp::Coalesce => {
                     if lhs.is_null_or_undefined() {
-                        std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(binary.rhs_mut(), Expression::from(Literal::Undefined))
                     } else {
-                        std::mem::replace(binary.lhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(binary.lhs_mut(), Expression::from(Literal::Undefined))
                     }
                 }
             };
@@ -183,7 +189,7 @@ impl ConstantFolding {
             return PassAction::Keep;
         };
 
-        let rhs = literal_to_js_value(rhs, context);
+        let rhs = literal_to_js_value(rhs.literal(), context);
 
         let value = match binary.op() {
             BinaryOp::Arithmetic(op) => match op {
@@ -224,6 +230,13 @@ impl ConstantFolding {
             return PassAction::Keep;
         };
 
-        PassAction::Replace(Expression::Literal(js_value_to_literal(value, context)))
+        if value
+            .as_string()
+            .is_some_and(|string| string.len() > MAX_FOLDED_STRING_LENGTH)
+        {
+            return PassAction::Keep;
+        }
+
+        PassAction::Replace(Expression::from(js_value_to_literal(value, context)))
     }
 }
@@ -0,0 +1,418 @@
+//! Peephole optimizations over the generated bytecode.
+//!
+//! The bytecompiler emits code without looking at its surroundings, which leaves jumps to other
+//! jumps or to the next instruction, code after unconditional control transfers and values that
+//! are pushed just to be popped right away. This pass decodes the finished bytecode, cleans those
+//! patterns up, and encodes it again, fixing every jump target, exception handler range and
+//! statement location.
+
+use crate::vm::{Handler, Instruction, InstructionIterator, VaryingOperandKind};
+use boa_ast::Position;
+
+/// The maximum number of times the passes are repeated until no more changes are found.
+const MAX_ITERATIONS: usize = 8;
+
+/// A decoded instruction.
+///
+/// While optimizing, all the addresses of the instruction are indices into the instruction list
+/// instead of bytecode offsets.
+#[derive(Debug)]
+struct Entry {
+    kind: VaryingOperandKind,
+    instruction: Instruction,
+    removed: bool,
+}
+
+/// Runs the peephole optimizations over `bytecode`, updating the ranges of `handlers` and the
+/// `(pc, position)` locations of the statements in `positions`.
+///
+/// The bytecode is returned unchanged if it contains an address that is not the start of an
+/// instruction.
+pub(crate) fn optimize(
+    bytecode: Vec<u8>,
+    handlers: &mut [Handler],
+    positions: &mut Vec<(u32, Position)>,
+) -> Vec<u8> {
+    let Some(mut optimizer) = Optimizer::new(&bytecode, handlers, positions) else {
+        return bytecode;
+    };
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = optimizer.thread_jumps();
+        changed |= optimizer.fuse_instructions();
+        changed |= optimizer.remove_unreachable();
+        changed |= optimizer.remove_jumps_to_next();
+        if !changed {
+            break;
+        }
+    }
+
+    optimizer.encode(handlers, positions)
+}
+
+#[derive(Debug)]
+struct Optimizer {
+    entries: Vec<Entry>,
+
+    /// The `(start, end)` indices of the exception handlers.
+    handlers: Vec<(u32, u32)>,
+
+    /// The `(index, position)` pairs of the instructions where statements start.
+    positions: Vec<(u32, Position)>,
+}
+
+impl Optimizer {
+    /// Decodes `bytecode`, converting all addresses into instruction indices.
+    fn new(bytecode: &[u8], handlers: &[Handler], positions: &[(u32, Position)]) -> Option<Self> {
+        let mut pcs = Vec::new();
+        let mut entries = Vec::new();
+        for (pc, kind, instruction) in InstructionIterator::new(bytecode) {
+            pcs.push(pc as u32);
+            entries.push(Entry {
+                kind,
+                instruction,
+                removed: false,
+            });
+        }
+
+        let index_of = |address: u32| -> Option<u32> {
+            if address as usize == bytecode.len() {
+                return Some(pcs.len() as u32);
+            }
+            pcs.binary_search(&address).ok().map(|index| index as u32)
+        };
+
+        let mut valid = true;
+        for entry in &mut entries {
+            for_each_address(&mut entry.instruction, |address| match index_of(*address) {
+                Some(index) => *address = index,
+                None => valid = false,
+            });
+        }
+
+        let handlers = handlers
+            .iter()
+            .map(|handler| Some((index_of(handler.start)?, index_of(handler.end)?)))
+            .collect::<Option<Vec<_>>>()?;
+        let positions = positions
+            .iter()
+            .map(|&(pc, position)| Some((index_of(pc)?, position)))
+            .collect::<Option<Vec<_>>>()?;
+
+        valid.then_some(Self {
+            entries,
+            handlers,
+            positions,
+        })
+    }
+
+    /// Returns the index of the first instruction at or after `index` that was not removed.
+    fn live_from(&self, index: u32) -> u32 {
+        let mut index = index as usize;
+        while self.entries.get(index).is_some_and(|entry| entry.removed) {
+            index += 1;
+        }
+        index as u32
+    }
+
+    /// Returns the index of the first instruction after `index` that was not removed.
+    fn next_live(&self, index: usize) -> u32 {
+        self.live_from(index as u32 + 1)
+    }
+
+    /// Returns which instructions can be reached other than by falling through to them.
+    fn targets(&mut self) -> Vec<bool> {
+        let mut targets = vec![false; self.entries.len() + 1];
+        for index in 0..self.entries.len() {
+            if self.entries[index].removed {
+                continue;
+            }
+            let mut addresses = Vec::new();
+            for_each_address(&mut self.entries[index].instruction, |address| {
+                addresses.push(*address);
+            });
+            for address in addresses {
+                targets[self.live_from(address) as usize] = true;
+            }
+        }
+        for &(start, end) in &self.handlers {
+            targets[self.live_from(start) as usize] = true;
+            targets[self.live_from(end) as usize] = true;
+        }
+        targets
+    }
+
+    /// Retargets jumps to unconditional jumps to their final destination.
+    fn thread_jumps(&mut self) -> bool {
+        let mut changed = false;
+        for index in 0..self.entries.len() {
+            if self.entries[index].removed {
+                continue;
+            }
+
+            let mut addresses = Vec::new();
+            for_each_address(&mut self.entries[index].instruction, |address| {
+                addresses.push(*address);
+            });
+            let mut threaded = addresses
+                .into_iter()
+                .map(|address| self.thread(address))
+                .collect::<Vec<_>>()
+                .into_iter();
+
+            for_each_address(&mut self.entries[index].instruction, |address| {
+                let new = threaded.next().expect("the same addresses are visited");
+                if *address != new {
+                    *address = new;
+                    changed = true;
+                }
+            });
+        }
+        changed
+    }
+
+    /// Follows the chain of unconditional jumps starting at `address`.
+    fn thread(&self, mut address: u32) -> u32 {
+        // Bounded to avoid looping forever on cycles of jumps, like the ones of `for (;;) {}`.
+        for _ in 0..self.entries.len() {
+            match self.entries.get(self.live_from(address) as usize) {
+                Some(Entry {
+                    instruction:
+                        Instruction::Jump {
+                            address: destination,
+                        },
+                    ..
+                }) if *destination != address => address = *destination,
+                _ => break,
+            }
+        }
+        address
+    }
+
+    /// Removes pure values that are popped right after being pushed, and negations feeding a
+    /// conditional jump.
+    fn fuse_instructions(&mut self) -> bool {
+        let targets = self.targets();
+        let mut changed = false;
+        for index in 0..self.entries.len() {
+            if self.entries[index].removed {
+                continue;
+            }
+            let next = self.next_live(index) as usize;
+            if next >= self.entries.len() || targets[next] {
+                continue;
+            }
+
+            let first = &self.entries[index].instruction;
+            let second = &self.entries[next].instruction;
+            if is_pure_push(first) && matches!(second, Instruction::Pop) {
+                self.entries[index].removed = true;
+                self.entries[next].removed = true;
+                changed = true;
+            } else if matches!(first, Instruction::LogicalNot) {
+                let flipped = match *second {
+                    Instruction::JumpIfTrue { address } => Instruction::JumpIfFalse { address },
+                    Instruction::JumpIfFalse { address } => Instruction::JumpIfTrue { address },
+                    _ => continue,
+                };
+                self.entries[index].removed = true;
+                self.entries[next].instruction = flipped;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Removes the instructions that cannot be reached from the start of the code or from an
+    /// exception handler.
+    fn remove_unreachable(&mut self) -> bool {
+        let mut reachable = vec![false; self.entries.len()];
+        let mut pending = vec![self.live_from(0)];
+        for &(_, end) in &self.handlers {
+            pending.push(self.live_from(end));
+        }
+
+        while let Some(index) = pending.pop() {
+            let index = index as usize;
+            if index >= self.entries.len() || reachable[index] {
+                continue;
+            }
+            reachable[index] = true;
+
+            let mut addresses = Vec::new();
+            for_each_address(&mut self.entries[index].instruction, |address| {
+                addresses.push(*address);
+            });
+            pending.extend(addresses.into_iter().map(|address| self.live_from(address)));
+            if !is_terminator(&self.entries[index].instruction) {
+                pending.push(self.next_live(index));
+            }
+        }
+
+        let mut changed = false;
+        for (entry, reachable) in self.entries.iter_mut().zip(reachable) {
+            if !entry.removed && !reachable {
+                entry.removed = true;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Removes unconditional jumps to the instruction that follows them.
+    fn remove_jumps_to_next(&mut self) -> bool {
+        let mut changed = false;
+        for index in 0..self.entries.len() {
+            let entry = &self.entries[index];
+            if entry.removed {
+                continue;
+            }
+            if let Instruction::Jump { address } = entry.instruction {
+                if self.live_from(address) == self.next_live(index) {
+                    self.entries[index].removed = true;
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Encodes the remaining instructions, converting all indices back into bytecode offsets.
+    fn encode(mut self, handlers: &mut [Handler], positions: &mut Vec<(u32, Position)>) -> Vec<u8> {
+        // Addresses are always encoded as `u32`s, so the size of an instruction doesn't depend
+        // on the targets of its jumps.
+        let mut scratch = Vec::new();
+        let mut offsets = vec![0; self.entries.len() + 1];
+        let mut len = 0;
+        for (index, entry) in self.entries.iter().enumerate() {
+            offsets[index] = len;
+            if !entry.removed {
+                scratch.clear();
+                encode_instruction(entry, &mut scratch);
+                len += scratch.len() as u32;
+            }
+        }
+        offsets[self.entries.len()] = len;
+
+        // A removed instruction has the offset of the next instruction that was kept, so any
+        // reference to it now points to the code that would have run after it.
+        let mut bytecode = Vec::with_capacity(len as usize);
+        for entry in &mut self.entries {
+            if entry.removed {
+                continue;
+            }
+            for_each_address(&mut entry.instruction, |address| {
+                *address = offsets[*address as usize];
+            });
+            encode_instruction(entry, &mut bytecode);
+        }
+
+        for (handler, (start, end)) in handlers.iter_mut().zip(self.handlers) {
+            handler.start = offsets[start as usize];
+            handler.end = offsets[end as usize];
+        }
+
+        // Statements whose code was removed now start at the same offset as the next statement,
+        // which is the one that is executed there.
+        positions.clear();
+        for (index, position) in self.positions {
+            let pc = offsets[index as usize];
+            match positions.last_mut() {
+                Some(last) if last.0 == pc => *last = (pc, position),
+                Some(last) if last.1 == position => {}
+                _ => positions.push((pc, position)),
+            }
+        }
+
+        bytecode
+    }
+}
+
+/// Encodes an instruction with its operand width prefix.
+fn encode_instruction(entry: &Entry, bytes: &mut Vec<u8>) {
+    match entry.kind {
+        VaryingOperandKind::U8 => {}
+        VaryingOperandKind::U16 => Instruction::U16Operands.to_bytecode(bytes),
+        VaryingOperandKind::U32 => Instruction::U32Operands.to_bytecode(bytes),
+    }
+    entry.instruction.to_bytecode(bytes);
+}
+
+/// Calls `f` with every code address of `instruction`.
+fn for_each_address(instruction: &mut Instruction, mut f: impl FnMut(&mut u32)) {
+    match instruction {
+        Instruction::LogicalAnd { exit }
+        | Instruction::LogicalOr { exit }
+        | Instruction::Coalesce { exit }
+        | Instruction::JumpIfNotResumeKind { exit, .. }
+        | Instruction::TemplateLookup { exit, .. }
+        | Instruction::Jump { address: exit }
+        | Instruction::JumpIfTrue { address: exit }
+        | Instruction::JumpIfFalse { address: exit }
+        | Instruction::JumpIfNotUndefined { address: exit }
+        | Instruction::JumpIfNullOrUndefined { address: exit }
+        | Instruction::Case { address: exit }
+        | Instruction::Default { address: exit } => f(exit),
+        Instruction::JumpTable { default, addresses }
+        | Instruction::SwitchInteger {
+            default, addresses, ..
+        }
+        | Instruction::SwitchString {
+            default, addresses, ..
+        } => {
+            f(default);
+            addresses.iter_mut().for_each(f);
+        }
+        Instruction::GeneratorDelegateNext {
+            throw_method_undefined,
+            return_method_undefined,
+        } => {
+            f(throw_method_undefined);
+            f(return_method_undefined);
+        }
+        Instruction::GeneratorDelegateResume { r#return, exit } => {
+            f(r#return);
+            f(exit);
+        }
+        _ => {}
+    }
+}
+
+/// Returns `true` if execution never continues with the instruction after `instruction`.
+const fn is_terminator(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::Jump { .. }
+            | Instruction::JumpTable { .. }
+            | Instruction::SwitchInteger { .. }
+            | Instruction::SwitchString { .. }
+            | Instruction::Default { .. }
+            | Instruction::Return
+            | Instruction::Throw
+            | Instruction::ReThrow
+            | Instruction::ThrowNewTypeError { .. }
+    )
+}
+
+/// Returns `true` if `instruction` only pushes a value without any other effect.
+const fn is_pure_push(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::Dup
+            | Instruction::PushZero
+            | Instruction::PushOne
+            | Instruction::PushInt8 { .. }
+            | Instruction::PushInt16 { .. }
+            | Instruction::PushInt32 { .. }
+            | Instruction::PushFloat { .. }
+            | Instruction::PushDouble { .. }
+            | Instruction::PushNaN
+            | Instruction::PushPositiveInfinity
+            | Instruction::PushNegativeInfinity
+            | Instruction::PushNull
+            | Instruction::PushTrue
+            | Instruction::PushFalse
+            | Instruction::PushUndefined
+            | Instruction::PushLiteral { .. }
+    )
+}
@@ -1,7 +1,7 @@
 use super::PassAction;
 use boa_ast::{
     visitor::{VisitWith, VisitorMut},
-    Expression,
+    Expression, Spanned,
 };
 use std::{convert::Infallible, ops::ControlFlow};
 
@@ -48,7 +48,11 @@ where
         match (self.f)(expr) {
             PassAction::Keep => {}
             PassAction::Modified => self.changed = true,
-            PassAction::Replace(new) => {
+            PassAction::Replace(mut new) => {
+                // The replacement takes the place of the original node in the source code.
+                if let Some(span) = expr.span() {
+                    new.set_span(span);
+                }
                 *expr = new;
                 self.changed = true;
             }
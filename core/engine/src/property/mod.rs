@@ -18,7 +18,9 @@
 mod attribute;
 mod nonmaxu32;
 
-use crate::{js_string, object::shape::slot::SlotAttributes, JsString, JsSymbol, JsValue};
+use crate::{
+    builtins::Number, js_string, object::shape::slot::SlotAttributes, JsString, JsSymbol, JsValue,
+};
 use boa_gc::{Finalize, Trace};
 use std::{fmt, iter::FusedIterator};
 
@@ -680,7 +682,7 @@ impl From<&[u16]> for PropertyKey {
 impl From<JsString> for PropertyKey {
     #[inline]
     fn from(string: JsString) -> Self {
-        parse_u32_index(string.as_slice().iter().copied()).map_or(Self::String(string), Self::Index)
+        parse_u32_index(string.as_str().iter()).map_or(Self::String(string), Self::Index)
     }
 }
 
@@ -721,7 +723,7 @@ impl From<PropertyKey> for JsValue {
         match property_key {
             PropertyKey::String(ref string) => string.clone().into(),
             PropertyKey::Symbol(ref symbol) => symbol.clone().into(),
-            PropertyKey::Index(index) => js_string!(index.get().to_string()).into(),
+            PropertyKey::Index(index) => Number::integer_to_js_string(index.get().into()).into(),
         }
     }
 }
@@ -795,10 +797,9 @@ impl From<f64> for PropertyKey {
     fn from(value: f64) -> Self {
         use num_traits::cast::FromPrimitive;
 
-        u32::from_f64(value).and_then(NonMaxU32::new).map_or(
-            Self::String(ryu_js::Buffer::new().format(value).into()),
-            Self::Index,
-        )
+        u32::from_f64(value)
+            .and_then(NonMaxU32::new)
+            .map_or(Self::String(Number::to_js_string(value)), Self::Index)
     }
 }
 
@@ -811,9 +812,15 @@ impl PartialEq<[u16]> for PropertyKey {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub(crate) enum PropertyNameKind {
+/// The kind of property names collected by [`JsObject::enumerable_own_property_names`].
+///
+/// [`JsObject::enumerable_own_property_names`]: crate::object::JsObject::enumerable_own_property_names
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyNameKind {
+    /// Only the keys of the properties.
     Key,
+    /// Only the values of the properties.
     Value,
+    /// `[key, value]` arrays with the key and value of each property.
     KeyAndValue,
 }
@@ -156,7 +156,10 @@ impl Realm {
         &self.inner.environment
     }
 
-    pub(crate) fn global_object(&self) -> &JsObject {
+    /// Gets the global object of this realm.
+    #[inline]
+    #[must_use]
+    pub fn global_object(&self) -> &JsObject {
         &self.inner.global_object
     }
 
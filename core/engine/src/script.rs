@@ -8,17 +8,23 @@
 //! [spec]: https://tc39.es/ecma262/#sec-scripts
 //! [script]: https://tc39.es/ecma262/#sec-script-records
 
+use boa_ast::operations::contains_error_placeholder;
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
-use boa_parser::{source::ReadChar, Parser, Source};
+use boa_parser::{
+    source::{ReadChar, SourceText},
+    Parser, Source,
+};
 use boa_profiler::Profiler;
 use rustc_hash::FxHashMap;
+use std::{path::Path, rc::Rc};
 
 use crate::{
     bytecompiler::ByteCompiler,
     js_string,
+    optimizer::OptimizerOptions,
     realm::Realm,
     vm::{ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock},
-    Context, HostDefined, JsResult, JsString, JsValue, Module,
+    Context, HostDefined, JsNativeError, JsResult, JsString, JsValue, Module,
 };
 
 /// ECMAScript's [**Script Record**][spec].
@@ -44,6 +50,10 @@ struct Inner {
     realm: Realm,
     #[unsafe_ignore_trace]
     source: boa_ast::Script,
+    #[unsafe_ignore_trace]
+    source_text: Rc<SourceText>,
+    #[unsafe_ignore_trace]
+    path: Option<Rc<Path>>,
     codeblock: GcRefCell<Option<Gc<CodeBlock>>>,
     loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
     host_defined: HostDefined,
@@ -64,6 +74,16 @@ impl Script {
         &self.inner.host_defined
     }
 
+    /// Gets the path of the source this script was parsed from, if any.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.inner.path.as_deref()
+    }
+
+    pub(crate) fn path_rc(&self) -> Option<Rc<Path>> {
+        self.inner.path.clone()
+    }
+
     /// Gets the loaded modules of this script.
     pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
         &self.inner.loaded_modules
@@ -81,12 +101,19 @@ impl Script {
     ) -> JsResult<Self> {
         let _timer = Profiler::global().start_event("Script parsing", "Main");
         let mut parser = Parser::new(src);
+        let path = parser.path();
         parser.set_identifier(context.next_parser_identifier());
+        parser.set_nesting_limit(context.runtime_limits().nesting_limit());
         if context.is_strict() {
             parser.set_strict();
         }
-        let mut code = parser.parse_script(context.interner_mut())?;
-        if !context.optimizer_options().is_empty() {
+        let mut code = parser
+            .parse_script(context.interner_mut())
+            .map_err(|err| JsNativeError::from_parse_error(&err, path))?;
+        if context
+            .optimizer_options()
+            .intersects(OptimizerOptions::CONSTANT_FOLDING | OptimizerOptions::STATISTICS)
+        {
             context.optimize_statement_list(code.statements_mut());
         }
 
@@ -94,6 +121,8 @@ impl Script {
             inner: Gc::new(Inner {
                 realm: realm.unwrap_or_else(|| context.realm().clone()),
                 source: code,
+                source_text: Rc::new(parser.take_source_text()),
+                path: path.map(Rc::from),
                 codeblock: GcRefCell::default(),
                 loaded_modules: GcRefCell::default(),
                 host_defined: HostDefined::default(),
@@ -101,6 +130,48 @@ impl Script {
         })
     }
 
+    /// Loads the script of `source` from `cache` if it holds the code of the script, or parses and
+    /// compiles the script and replaces `cache` by its serialized code otherwise.
+    #[cfg(feature = "bytecode-cache")]
+    pub(crate) fn compile_cached(
+        source: &str,
+        cache: &mut Vec<u8>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        if !crate::vm::is_cache_of(cache, source) {
+            let checkpoint = context.interner_mut().checkpoint();
+            let script = Self::parse(Source::from_bytes(source), None, context)
+                .and_then(|script| script.codeblock(context).map(|code| (script, code)));
+            context.interner_mut().rollback(checkpoint);
+
+            let (script, code) = script?;
+            *cache = code.to_bytes();
+            return Ok(script);
+        }
+
+        let _timer = Profiler::global().start_event("Script loading", "Main");
+        let source_text = source.encode_utf16().collect::<Vec<_>>();
+        let script = Self {
+            inner: Gc::new(Inner {
+                realm: context.realm().clone(),
+                source: boa_ast::Script::default(),
+                source_text: Rc::new(SourceText::from_utf16(&source_text)),
+                path: None,
+                codeblock: GcRefCell::default(),
+                loaded_modules: GcRefCell::default(),
+                host_defined: HostDefined::default(),
+            }),
+        };
+        let codeblock = CodeBlock::load(
+            cache,
+            Some(&ActiveRunnable::Script(script.clone())),
+            context,
+        )?;
+        *script.inner.codeblock.borrow_mut() = Some(codeblock);
+
+        Ok(script)
+    }
+
     /// Compiles the codeblock of this script.
     ///
     /// This is a no-op if this has been called previously.
@@ -113,6 +184,12 @@ impl Script {
 
         let _timer = Profiler::global().start_event("Script compilation", "Main");
 
+        if contains_error_placeholder(&self.inner.source) {
+            return Err(JsNativeError::syntax()
+                .with_message("cannot compile a script recovered from syntax errors")
+                .into());
+        }
+
         let mut compiler = ByteCompiler::new(
             js_string!("<main>"),
             self.inner.source.strict(),
@@ -121,10 +198,12 @@ impl Script {
             self.inner.realm.environment().compile_env(),
             context,
         );
+        compiler.source_text = Some(self.inner.source_text.clone());
         // TODO: move to `Script::evaluate` to make this operation infallible.
         compiler.global_declaration_instantiation(
             &self.inner.source,
             &self.inner.realm.environment().compile_env(),
+            &ActiveRunnable::Script(self.clone()),
         )?;
         compiler.compile_statement_list(self.inner.source.statements(), true, false);
 
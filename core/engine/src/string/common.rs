@@ -2,8 +2,7 @@ use std::hash::BuildHasherDefault;
 
 use crate::tagged::Tagged;
 
-use super::JsString;
-use boa_macros::utf16;
+use super::{JsStr, JsString};
 use paste::paste;
 use rustc_hash::{FxHashMap, FxHasher};
 
@@ -15,7 +14,7 @@ macro_rules! well_known_statics {
                 #[allow(unused)]
                 pub(crate) const $name: JsString = JsString {
                     ptr: Tagged::from_tag(
-                        Self::find_index(utf16!($string)),
+                        Self::find_index($string.as_bytes()),
                     ),
                 };
             }
@@ -26,13 +25,14 @@ macro_rules! well_known_statics {
 /// List of commonly used strings in Javascript code.
 ///
 /// Any strings defined here are used as a static [`JsString`] instead of allocating on the heap.
+/// They are all ASCII, so they are stored as Latin-1.
 #[derive(Debug)]
 pub(crate) struct StaticJsStrings;
 
 impl StaticJsStrings {
     // useful to search at compile time a certain string in the array
-    const fn find_index(candidate: &[u16]) -> usize {
-        const fn const_eq(lhs: &[u16], rhs: &[u16]) -> bool {
+    const fn find_index(candidate: &[u8]) -> usize {
+        const fn const_eq(lhs: &[u8], rhs: &[u8]) -> bool {
             if lhs.len() != rhs.len() {
                 return false;
             }
@@ -59,21 +59,43 @@ impl StaticJsStrings {
 
     /// Gets the `JsString` corresponding to `string`, or `None` if the string
     /// doesn't exist inside the static array.
-    pub(crate) fn get_string(string: &[u16]) -> Option<JsString> {
+    pub(crate) fn get_string(string: JsStr<'_>) -> Option<JsString> {
         if string.len() > MAX_STATIC_LENGTH {
             return None;
         }
 
-        let index = RAW_STATICS_CACHE.with(|map| map.get(string).copied())?;
+        let index = match string {
+            JsStr::Latin1(string) => RAW_STATICS_CACHE.with(|map| map.get(string).copied()),
+            JsStr::Utf16(string) => {
+                let mut buf = [0; MAX_STATIC_LENGTH];
+                for (dst, &unit) in buf.iter_mut().zip(string) {
+                    *dst = u8::try_from(unit).ok()?;
+                }
+                RAW_STATICS_CACHE.with(|map| map.get(&buf[..string.len()]).copied())
+            }
+        }?;
 
         Some(JsString {
             ptr: Tagged::from_tag(index),
         })
     }
 
-    /// Gets the `&[u16]` slice corresponding to the provided index, or `None` if the index
-    /// provided exceeds the size of the static array.
-    pub(crate) fn get(index: usize) -> Option<&'static [u16]> {
+    /// Gets the static `JsString` for the decimal representation of `value`, or `None` if
+    /// `value` isn't in the range of small integers that are stored as static strings.
+    pub(crate) fn get_integer(value: i64) -> Option<JsString> {
+        let offset = usize::try_from(value.checked_sub(MIN_STATIC_INTEGER)?).ok()?;
+        if offset >= STATIC_INTEGERS_COUNT {
+            return None;
+        }
+
+        Some(JsString {
+            ptr: Tagged::from_tag(STATIC_INTEGERS_START + offset),
+        })
+    }
+
+    /// Gets the Latin-1 `&[u8]` slice corresponding to the provided index, or `None` if the
+    /// index provided exceeds the size of the static array.
+    pub(crate) fn get(index: usize) -> Option<&'static [u8]> {
         RAW_STATICS.get(index).copied()
     }
 
@@ -81,7 +103,9 @@ impl StaticJsStrings {
     well_known_statics! {
         (EMPTY_STRING, ""),
         // Symbols
+        (SYMBOL_ASYNC_DISPOSE, "Symbol.asyncDispose"),
         (SYMBOL_ASYNC_ITERATOR, "Symbol.asyncIterator"),
+        (SYMBOL_DISPOSE, "Symbol.dispose"),
         (SYMBOL_HAS_INSTANCE, "Symbol.hasInstance"),
         (SYMBOL_IS_CONCAT_SPREADABLE, "Symbol.isConcatSpreadable"),
         (SYMBOL_ITERATOR, "Symbol.iterator"),
@@ -94,7 +118,9 @@ impl StaticJsStrings {
         (SYMBOL_TO_PRIMITIVE, "Symbol.toPrimitive"),
         (SYMBOL_TO_STRING_TAG, "Symbol.toStringTag"),
         (SYMBOL_UNSCOPABLES, "Symbol.unscopables"),
+        (FN_SYMBOL_ASYNC_DISPOSE, "[Symbol.asyncDispose]"),
         (FN_SYMBOL_ASYNC_ITERATOR, "[Symbol.asyncIterator]"),
+        (FN_SYMBOL_DISPOSE, "[Symbol.dispose]"),
         (FN_SYMBOL_HAS_INSTANCE, "[Symbol.hasInstance]"),
         (FN_SYMBOL_IS_CONCAT_SPREADABLE, "[Symbol.isConcatSpreadable]"),
         (FN_SYMBOL_ITERATOR, "[Symbol.iterator]"),
@@ -111,6 +137,7 @@ impl StaticJsStrings {
         (ARRAY, "Array"),
         (ARRAY_BUFFER, "ArrayBuffer"),
         (SHARED_ARRAY_BUFFER, "SharedArrayBuffer"),
+        (ASYNC_DISPOSABLE_STACK, "AsyncDisposableStack"),
         (ASYNC_FUNCTION, "AsyncFunction"),
         (ASYNC_GENERATOR, "AsyncGenerator"),
         (ASYNC_GENERATOR_FUNCTION, "AsyncGeneratorFunction"),
@@ -119,11 +146,13 @@ impl StaticJsStrings {
         (BOOLEAN, "Boolean"),
         (DATA_VIEW, "DataView"),
         (DATE, "Date"),
+        (DISPOSABLE_STACK, "DisposableStack"),
         (ERROR, "Error"),
         (AGGREGATE_ERROR, "AggregateError"),
         (EVAL_ERROR, "EvalError"),
         (RANGE_ERROR, "RangeError"),
         (REFERENCE_ERROR, "ReferenceError"),
+        (SUPPRESSED_ERROR, "SuppressedError"),
         (SYNTAX_ERROR, "SyntaxError"),
         (TYPE_ERROR, "TypeError"),
         (URI_ERROR, "URIError"),
@@ -133,13 +162,17 @@ impl StaticJsStrings {
         (FUNCTION, "Function"),
         (GENERATOR, "Generator"),
         (GENERATOR_FUNCTION, "GeneratorFunction"),
+        (ITERATOR, "Iterator"),
         (INTL, "Intl"),
         (COLLATOR, "Collator"),
         (LIST_FORMAT, "ListFormat"),
         (LOCALE, "Locale"),
         (PLURAL_RULES, "PluralRules"),
+        (RELATIVE_TIME_FORMAT, "RelativeTimeFormat"),
         (SEGMENTER, "Segmenter"),
         (DATE_TIME_FORMAT, "DateTimeFormat"),
+        (DURATION_FORMAT, "DurationFormat"),
+        (DISPLAY_NAMES, "DisplayNames"),
         (NUMBER_FORMAT, "NumberFormat"),
         (JSON, "JSON"),
         (MAP, "Map"),
@@ -155,7 +188,9 @@ impl StaticJsStrings {
         (REFLECT, "Reflect"),
         (REG_EXP, "RegExp"),
         (SET, "Set"),
+        (SHADOW_REALM, "ShadowRealm"),
         (STRING, "String"),
+        (STRUCTURED_CLONE, "structuredClone"),
         (SYMBOL, "Symbol"),
         (TYPED_ARRAY, "TypedArray"),
         (INT8_ARRAY, "Int8Array"),
@@ -191,7 +226,7 @@ impl StaticJsStrings {
     }
 }
 
-static MAX_STATIC_LENGTH: usize = {
+pub(super) const MAX_STATIC_LENGTH: usize = {
     let mut max = 0;
     let mut i = 0;
     while i < RAW_STATICS.len() {
@@ -204,9 +239,19 @@ static MAX_STATIC_LENGTH: usize = {
     max
 };
 
+/// The smallest integer that is stored as a static string.
+const MIN_STATIC_INTEGER: i64 = -1;
+
+/// The number of consecutive integers, starting from [`MIN_STATIC_INTEGER`], that are stored as
+/// static strings.
+const STATIC_INTEGERS_COUNT: usize = 258;
+
+/// The index of [`MIN_STATIC_INTEGER`] in [`RAW_STATICS`].
+const STATIC_INTEGERS_START: usize = StaticJsStrings::find_index(b"-1");
+
 thread_local! {
     /// Map from a string inside [`RAW_STATICS`] to its corresponding static index on `RAW_STATICS`.
-    static RAW_STATICS_CACHE: FxHashMap<&'static [u16], usize> = {
+    static RAW_STATICS_CACHE: FxHashMap<&'static [u8], usize> = {
         let mut constants = FxHashMap::with_capacity_and_hasher(
             RAW_STATICS.len(),
             BuildHasherDefault::<FxHasher>::default(),
@@ -221,495 +266,767 @@ thread_local! {
 }
 
 /// Array of raw static strings that aren't reference counted.
-const RAW_STATICS: &[&[u16]] = &[
-    utf16!(""),
+const RAW_STATICS: &[&[u8]] = &[
+    b"",
     // Well known symbols
-    utf16!("Symbol.asyncIterator"),
-    utf16!("[Symbol.asyncIterator]"),
-    utf16!("Symbol.hasInstance"),
-    utf16!("[Symbol.hasInstance]"),
-    utf16!("Symbol.isConcatSpreadable"),
-    utf16!("[Symbol.isConcatSpreadable]"),
-    utf16!("Symbol.iterator"),
-    utf16!("[Symbol.iterator]"),
-    utf16!("Symbol.match"),
-    utf16!("[Symbol.match]"),
-    utf16!("Symbol.matchAll"),
-    utf16!("[Symbol.matchAll]"),
-    utf16!("Symbol.replace"),
-    utf16!("[Symbol.replace]"),
-    utf16!("Symbol.search"),
-    utf16!("[Symbol.search]"),
-    utf16!("Symbol.species"),
-    utf16!("[Symbol.species]"),
-    utf16!("Symbol.split"),
-    utf16!("[Symbol.split]"),
-    utf16!("Symbol.toPrimitive"),
-    utf16!("[Symbol.toPrimitive]"),
-    utf16!("Symbol.toStringTag"),
-    utf16!("[Symbol.toStringTag]"),
-    utf16!("Symbol.unscopables"),
-    utf16!("[Symbol.unscopables]"),
+    b"Symbol.asyncDispose",
+    b"[Symbol.asyncDispose]",
+    b"Symbol.asyncIterator",
+    b"[Symbol.asyncIterator]",
+    b"Symbol.dispose",
+    b"[Symbol.dispose]",
+    b"Symbol.hasInstance",
+    b"[Symbol.hasInstance]",
+    b"Symbol.isConcatSpreadable",
+    b"[Symbol.isConcatSpreadable]",
+    b"Symbol.iterator",
+    b"[Symbol.iterator]",
+    b"Symbol.match",
+    b"[Symbol.match]",
+    b"Symbol.matchAll",
+    b"[Symbol.matchAll]",
+    b"Symbol.replace",
+    b"[Symbol.replace]",
+    b"Symbol.search",
+    b"[Symbol.search]",
+    b"Symbol.species",
+    b"[Symbol.species]",
+    b"Symbol.split",
+    b"[Symbol.split]",
+    b"Symbol.toPrimitive",
+    b"[Symbol.toPrimitive]",
+    b"Symbol.toStringTag",
+    b"[Symbol.toStringTag]",
+    b"Symbol.unscopables",
+    b"[Symbol.unscopables]",
     // Well known builtins
-    utf16!("Array"),
-    utf16!("ArrayBuffer"),
-    utf16!("SharedArrayBuffer"),
-    utf16!("AsyncFunction"),
-    utf16!("AsyncGenerator"),
-    utf16!("AsyncGeneratorFunction"),
-    utf16!("Atomics"),
-    utf16!("BigInt"),
-    utf16!("Boolean"),
-    utf16!("DataView"),
-    utf16!("Date"),
-    utf16!("Error"),
-    utf16!("AggregateError"),
-    utf16!("EvalError"),
-    utf16!("RangeError"),
-    utf16!("ReferenceError"),
-    utf16!("SyntaxError"),
-    utf16!("TypeError"),
-    utf16!("URIError"),
-    utf16!("escape"),
-    utf16!("unescape"),
-    utf16!("eval"),
-    utf16!("Function"),
-    utf16!("Generator"),
-    utf16!("GeneratorFunction"),
-    utf16!("Intl"),
-    utf16!("Collator"),
-    utf16!("ListFormat"),
-    utf16!("Locale"),
-    utf16!("PluralRules"),
-    utf16!("Segmenter"),
-    utf16!("DateTimeFormat"),
-    utf16!("NumberFormat"),
-    utf16!("JSON"),
-    utf16!("Map"),
-    utf16!("Math"),
-    utf16!("Number"),
-    utf16!("isFinite"),
-    utf16!("isNaN"),
-    utf16!("parseInt"),
-    utf16!("parseFloat"),
-    utf16!("Object"),
-    utf16!("Promise"),
-    utf16!("Proxy"),
-    utf16!("Reflect"),
-    utf16!("RegExp"),
-    utf16!("Set"),
-    utf16!("String"),
-    utf16!("Symbol"),
-    utf16!("TypedArray"),
-    utf16!("Int8Array"),
-    utf16!("Uint8Array"),
-    utf16!("Uint8ClampedArray"),
-    utf16!("Int16Array"),
-    utf16!("Uint16Array"),
-    utf16!("Int32Array"),
-    utf16!("Uint32Array"),
-    utf16!("BigInt64Array"),
-    utf16!("BigUint64Array"),
-    utf16!("Float32Array"),
-    utf16!("Float64Array"),
-    utf16!("encodeURI"),
-    utf16!("encodeURIComponent"),
-    utf16!("decodeURI"),
-    utf16!("decodeURIComponent"),
-    utf16!("WeakRef"),
-    utf16!("WeakMap"),
-    utf16!("WeakSet"),
-    utf16!("Temporal"),
-    utf16!("Temporal.Now"),
-    utf16!("Temporal.Instant"),
-    utf16!("Temporal.Duration"),
-    utf16!("Temporal.Calendar"),
-    utf16!("Temporal.PlainDate"),
-    utf16!("Temporal.PlainDateTime"),
-    utf16!("Temporal.PlainMonthDay"),
-    utf16!("Temporal.PlainYearMonth"),
-    utf16!("Temporal.PlainTime"),
-    utf16!("Temporal.TimeZone"),
-    utf16!("Temporal.ZonedDateTime"),
+    b"Array",
+    b"ArrayBuffer",
+    b"SharedArrayBuffer",
+    b"AsyncDisposableStack",
+    b"AsyncFunction",
+    b"AsyncGenerator",
+    b"AsyncGeneratorFunction",
+    b"Atomics",
+    b"BigInt",
+    b"Boolean",
+    b"DataView",
+    b"Date",
+    b"DisposableStack",
+    b"Error",
+    b"AggregateError",
+    b"EvalError",
+    b"RangeError",
+    b"ReferenceError",
+    b"SuppressedError",
+    b"SyntaxError",
+    b"TypeError",
+    b"URIError",
+    b"escape",
+    b"unescape",
+    b"eval",
+    b"Function",
+    b"Generator",
+    b"GeneratorFunction",
+    b"Iterator",
+    b"Intl",
+    b"Collator",
+    b"ListFormat",
+    b"Locale",
+    b"PluralRules",
+    b"RelativeTimeFormat",
+    b"Segmenter",
+    b"DateTimeFormat",
+    b"DurationFormat",
+    b"DisplayNames",
+    b"NumberFormat",
+    b"JSON",
+    b"Map",
+    b"Math",
+    b"Number",
+    b"isFinite",
+    b"isNaN",
+    b"parseInt",
+    b"parseFloat",
+    b"Object",
+    b"Promise",
+    b"Proxy",
+    b"Reflect",
+    b"RegExp",
+    b"Set",
+    b"ShadowRealm",
+    b"String",
+    b"structuredClone",
+    b"Symbol",
+    b"TypedArray",
+    b"Int8Array",
+    b"Uint8Array",
+    b"Uint8ClampedArray",
+    b"Int16Array",
+    b"Uint16Array",
+    b"Int32Array",
+    b"Uint32Array",
+    b"BigInt64Array",
+    b"BigUint64Array",
+    b"Float32Array",
+    b"Float64Array",
+    b"encodeURI",
+    b"encodeURIComponent",
+    b"decodeURI",
+    b"decodeURIComponent",
+    b"WeakRef",
+    b"WeakMap",
+    b"WeakSet",
+    b"Temporal",
+    b"Temporal.Now",
+    b"Temporal.Instant",
+    b"Temporal.Duration",
+    b"Temporal.Calendar",
+    b"Temporal.PlainDate",
+    b"Temporal.PlainDateTime",
+    b"Temporal.PlainMonthDay",
+    b"Temporal.PlainYearMonth",
+    b"Temporal.PlainTime",
+    b"Temporal.TimeZone",
+    b"Temporal.ZonedDateTime",
     // Misc
-    utf16!(","),
-    utf16!(":"),
+    b",",
+    b":",
     // Generic use
-    utf16!("name"),
-    utf16!("length"),
-    utf16!("arguments"),
-    utf16!("prototype"),
-    utf16!("constructor"),
-    utf16!("return"),
-    utf16!("throw"),
-    utf16!("global"),
-    utf16!("globalThis"),
+    b"name",
+    b"length",
+    b"arguments",
+    b"prototype",
+    b"constructor",
+    b"return",
+    b"throw",
+    b"global",
+    b"globalThis",
     // typeof
-    utf16!("null"),
-    utf16!("undefined"),
-    utf16!("number"),
-    utf16!("string"),
-    utf16!("symbol"),
-    utf16!("bigint"),
-    utf16!("object"),
-    utf16!("function"),
+    b"null",
+    b"undefined",
+    b"number",
+    b"string",
+    b"symbol",
+    b"bigint",
+    b"object",
+    b"function",
     // Property descriptor
-    utf16!("value"),
-    utf16!("get"),
-    utf16!("set"),
-    utf16!("writable"),
-    utf16!("enumerable"),
-    utf16!("configurable"),
+    b"value",
+    b"get",
+    b"set",
+    b"writable",
+    b"enumerable",
+    b"configurable",
     // Object object
-    utf16!("Object"),
-    utf16!("assign"),
-    utf16!("create"),
-    utf16!("toString"),
-    utf16!("valueOf"),
-    utf16!("is"),
-    utf16!("seal"),
-    utf16!("isSealed"),
-    utf16!("freeze"),
-    utf16!("isFrozen"),
-    utf16!("isExtensible"),
-    utf16!("hasOwnProperty"),
-    utf16!("isPrototypeOf"),
-    utf16!("setPrototypeOf"),
-    utf16!("getPrototypeOf"),
-    utf16!("defineProperty"),
-    utf16!("defineProperties"),
-    utf16!("deleteProperty"),
-    utf16!("construct"),
-    utf16!("hasOwn"),
-    utf16!("ownKeys"),
-    utf16!("keys"),
-    utf16!("values"),
-    utf16!("entries"),
-    utf16!("fromEntries"),
+    b"Object",
+    b"assign",
+    b"create",
+    b"toString",
+    b"valueOf",
+    b"is",
+    b"seal",
+    b"isSealed",
+    b"freeze",
+    b"isFrozen",
+    b"isExtensible",
+    b"hasOwnProperty",
+    b"isPrototypeOf",
+    b"setPrototypeOf",
+    b"getPrototypeOf",
+    b"defineProperty",
+    b"defineProperties",
+    b"deleteProperty",
+    b"construct",
+    b"hasOwn",
+    b"ownKeys",
+    b"keys",
+    b"values",
+    b"entries",
+    b"fromEntries",
     // Function object
-    utf16!("apply"),
-    utf16!("bind"),
-    utf16!("call"),
+    b"apply",
+    b"bind",
+    b"call",
     // Array object
-    utf16!("at"),
-    utf16!("from"),
-    utf16!("isArray"),
-    utf16!("of"),
-    utf16!("copyWithin"),
-    utf16!("every"),
-    utf16!("fill"),
-    utf16!("filter"),
-    utf16!("find"),
-    utf16!("findIndex"),
-    utf16!("findLast"),
-    utf16!("findLastIndex"),
-    utf16!("flat"),
-    utf16!("flatMap"),
-    utf16!("forEach"),
-    utf16!("includes"),
-    utf16!("indexOf"),
-    utf16!("join"),
-    utf16!("map"),
-    utf16!("next"),
-    utf16!("reduce"),
-    utf16!("reduceRight"),
-    utf16!("reverse"),
-    utf16!("shift"),
-    utf16!("slice"),
-    utf16!("splice"),
-    utf16!("some"),
-    utf16!("sort"),
-    utf16!("unshift"),
-    utf16!("push"),
-    utf16!("pop"),
+    b"at",
+    b"from",
+    b"isArray",
+    b"of",
+    b"copyWithin",
+    b"every",
+    b"fill",
+    b"filter",
+    b"find",
+    b"findIndex",
+    b"findLast",
+    b"findLastIndex",
+    b"flat",
+    b"flatMap",
+    b"forEach",
+    b"includes",
+    b"indexOf",
+    b"join",
+    b"map",
+    b"next",
+    b"reduce",
+    b"reduceRight",
+    b"reverse",
+    b"shift",
+    b"slice",
+    b"splice",
+    b"some",
+    b"sort",
+    b"unshift",
+    b"push",
+    b"pop",
     // String object
-    utf16!("charAt"),
-    utf16!("charCodeAt"),
-    utf16!("codePointAt"),
-    utf16!("concat"),
-    utf16!("endsWith"),
-    utf16!("fromCharCode"),
-    utf16!("fromCodePoint"),
-    utf16!("lastIndexOf"),
-    utf16!("match"),
-    utf16!("matchAll"),
-    utf16!("normalize"),
-    utf16!("padEnd"),
-    utf16!("padStart"),
-    utf16!("raw"),
-    utf16!("repeat"),
-    utf16!("replace"),
-    utf16!("replaceAll"),
-    utf16!("search"),
-    utf16!("split"),
-    utf16!("startsWith"),
-    utf16!("substr"),
-    utf16!("substring"),
-    utf16!("toLocaleString"),
-    utf16!("toLowerCase"),
-    utf16!("toUpperCase"),
-    utf16!("trim"),
-    utf16!("trimEnd"),
-    utf16!("trimStart"),
+    b"charAt",
+    b"charCodeAt",
+    b"codePointAt",
+    b"concat",
+    b"endsWith",
+    b"fromCharCode",
+    b"fromCodePoint",
+    b"lastIndexOf",
+    b"match",
+    b"matchAll",
+    b"normalize",
+    b"padEnd",
+    b"padStart",
+    b"raw",
+    b"repeat",
+    b"replace",
+    b"replaceAll",
+    b"search",
+    b"split",
+    b"startsWith",
+    b"substr",
+    b"substring",
+    b"toLocaleString",
+    b"toLowerCase",
+    b"toUpperCase",
+    b"trim",
+    b"trimEnd",
+    b"trimStart",
     // Number object
-    utf16!("Infinity"),
-    utf16!("NaN"),
-    utf16!("EPSILON"),
-    utf16!("MAX_SAFE_INTEGER"),
-    utf16!("MIN_SAFE_INTEGER"),
-    utf16!("MAX_VALUE"),
-    utf16!("MIN_VALUE"),
-    utf16!("isSafeInteger"),
-    utf16!("isInteger"),
-    utf16!("toExponential"),
-    utf16!("toFixed"),
-    utf16!("toPrecision"),
+    b"Infinity",
+    b"NaN",
+    b"EPSILON",
+    b"MAX_SAFE_INTEGER",
+    b"MIN_SAFE_INTEGER",
+    b"MAX_VALUE",
+    b"MIN_VALUE",
+    b"isSafeInteger",
+    b"isInteger",
+    b"toExponential",
+    b"toFixed",
+    b"toPrecision",
     // BigInt object
-    utf16!("asIntN"),
-    utf16!("asUintN"),
+    b"asIntN",
+    b"asUintN",
     // RegExp object
-    utf16!("exec"),
-    utf16!("test"),
-    utf16!("flags"),
-    utf16!("index"),
-    utf16!("lastIndex"),
-    utf16!("hasIndices"),
-    utf16!("ignoreCase"),
-    utf16!("multiline"),
-    utf16!("dotAll"),
-    utf16!("unicode"),
-    utf16!("sticky"),
-    utf16!("source"),
-    utf16!("get hasIndices"),
-    utf16!("get global"),
-    utf16!("get ignoreCase"),
-    utf16!("get multiline"),
-    utf16!("get dotAll"),
-    utf16!("get unicode"),
-    utf16!("get sticky"),
-    utf16!("get flags"),
-    utf16!("get source"),
+    b"exec",
+    b"test",
+    b"flags",
+    b"index",
+    b"lastIndex",
+    b"hasIndices",
+    b"ignoreCase",
+    b"multiline",
+    b"dotAll",
+    b"unicode",
+    b"sticky",
+    b"source",
+    b"get hasIndices",
+    b"get global",
+    b"get ignoreCase",
+    b"get multiline",
+    b"get dotAll",
+    b"get unicode",
+    b"get sticky",
+    b"get flags",
+    b"get source",
     // Symbol object
-    utf16!("for"),
-    utf16!("keyFor"),
-    utf16!("description"),
-    utf16!("asyncIterator"),
-    utf16!("hasInstance"),
-    utf16!("species"),
-    utf16!("unscopables"),
-    utf16!("iterator"),
-    utf16!("toStringTag"),
-    utf16!("toPrimitive"),
-    utf16!("get description"),
+    b"for",
+    b"keyFor",
+    b"description",
+    b"asyncIterator",
+    b"hasInstance",
+    b"species",
+    b"unscopables",
+    b"iterator",
+    b"toStringTag",
+    b"toPrimitive",
+    b"get description",
     // Map object
-    utf16!("clear"),
-    utf16!("delete"),
-    utf16!("has"),
-    utf16!("size"),
+    b"clear",
+    b"delete",
+    b"has",
+    b"size",
     // Set object
-    utf16!("add"),
+    b"add",
     // Reflect object
     // Proxy object
-    utf16!("revocable"),
+    b"revocable",
     // Error objects
-    utf16!("message"),
+    b"message",
     // Date object
-    utf16!("toJSON"),
-    utf16!("getDate"),
-    utf16!("getDay"),
-    utf16!("getFullYear"),
-    utf16!("getHours"),
-    utf16!("getMilliseconds"),
-    utf16!("getMinutes"),
-    utf16!("getMonth"),
-    utf16!("getSeconds"),
-    utf16!("getTime"),
-    utf16!("getYear"),
-    utf16!("getUTCDate"),
-    utf16!("getUTCDay"),
-    utf16!("getUTCFullYear"),
-    utf16!("getUTCHours"),
-    utf16!("getUTCMinutes"),
-    utf16!("getUTCMonth"),
-    utf16!("getUTCSeconds"),
-    utf16!("setDate"),
-    utf16!("setFullYear"),
-    utf16!("setHours"),
-    utf16!("setMilliseconds"),
-    utf16!("setMinutes"),
-    utf16!("setMonth"),
-    utf16!("setSeconds"),
-    utf16!("setYear"),
-    utf16!("setTime"),
-    utf16!("setUTCDate"),
-    utf16!("setUTCFullYear"),
-    utf16!("setUTCHours"),
-    utf16!("setUTCMinutes"),
-    utf16!("setUTCMonth"),
-    utf16!("setUTCSeconds"),
-    utf16!("toDateString"),
-    utf16!("toGMTString"),
-    utf16!("toISOString"),
-    utf16!("toTimeString"),
-    utf16!("toUTCString"),
-    utf16!("now"),
-    utf16!("UTC"),
+    b"toJSON",
+    b"getDate",
+    b"getDay",
+    b"getFullYear",
+    b"getHours",
+    b"getMilliseconds",
+    b"getMinutes",
+    b"getMonth",
+    b"getSeconds",
+    b"getTime",
+    b"getYear",
+    b"getUTCDate",
+    b"getUTCDay",
+    b"getUTCFullYear",
+    b"getUTCHours",
+    b"getUTCMinutes",
+    b"getUTCMonth",
+    b"getUTCSeconds",
+    b"setDate",
+    b"setFullYear",
+    b"setHours",
+    b"setMilliseconds",
+    b"setMinutes",
+    b"setMonth",
+    b"setSeconds",
+    b"setYear",
+    b"setTime",
+    b"setUTCDate",
+    b"setUTCFullYear",
+    b"setUTCHours",
+    b"setUTCMinutes",
+    b"setUTCMonth",
+    b"setUTCSeconds",
+    b"toDateString",
+    b"toGMTString",
+    b"toISOString",
+    b"toTimeString",
+    b"toUTCString",
+    b"now",
+    b"UTC",
     // JSON object
-    utf16!("parse"),
-    utf16!("stringify"),
+    b"parse",
+    b"stringify",
     // Iterator object
-    utf16!("Array Iterator"),
-    utf16!("Set Iterator"),
-    utf16!("String Iterator"),
-    utf16!("Map Iterator"),
-    utf16!("For In Iterator"),
+    b"Array Iterator",
+    b"Set Iterator",
+    b"String Iterator",
+    b"Map Iterator",
+    b"For In Iterator",
     // Math object
-    utf16!("LN10"),
-    utf16!("LN2"),
-    utf16!("LOG10E"),
-    utf16!("LOG2E"),
-    utf16!("PI"),
-    utf16!("SQRT1_2"),
-    utf16!("SQRT2"),
-    utf16!("abs"),
-    utf16!("acos"),
-    utf16!("acosh"),
-    utf16!("asin"),
-    utf16!("asinh"),
-    utf16!("atan"),
-    utf16!("atanh"),
-    utf16!("atan2"),
-    utf16!("cbrt"),
-    utf16!("ceil"),
-    utf16!("clz32"),
-    utf16!("cos"),
-    utf16!("cosh"),
-    utf16!("exp"),
-    utf16!("expm1"),
-    utf16!("floor"),
-    utf16!("fround"),
-    utf16!("hypot"),
-    utf16!("imul"),
-    utf16!("log"),
-    utf16!("log1p"),
-    utf16!("log10"),
-    utf16!("log2"),
-    utf16!("max"),
-    utf16!("min"),
-    utf16!("pow"),
-    utf16!("random"),
-    utf16!("round"),
-    utf16!("sign"),
-    utf16!("sin"),
-    utf16!("sinh"),
-    utf16!("sqrt"),
-    utf16!("tan"),
-    utf16!("tanh"),
-    utf16!("trunc"),
+    b"LN10",
+    b"LN2",
+    b"LOG10E",
+    b"LOG2E",
+    b"PI",
+    b"SQRT1_2",
+    b"SQRT2",
+    b"abs",
+    b"acos",
+    b"acosh",
+    b"asin",
+    b"asinh",
+    b"atan",
+    b"atanh",
+    b"atan2",
+    b"cbrt",
+    b"ceil",
+    b"clz32",
+    b"cos",
+    b"cosh",
+    b"exp",
+    b"expm1",
+    b"floor",
+    b"fround",
+    b"hypot",
+    b"imul",
+    b"log",
+    b"log1p",
+    b"log10",
+    b"log2",
+    b"max",
+    b"min",
+    b"pow",
+    b"random",
+    b"round",
+    b"sign",
+    b"sin",
+    b"sinh",
+    b"sqrt",
+    b"tan",
+    b"tanh",
+    b"trunc",
     // TypedArray object
-    utf16!("buffer"),
-    utf16!("byteLength"),
-    utf16!("byteOffset"),
-    utf16!("isView"),
-    utf16!("subarray"),
-    utf16!("get byteLength"),
-    utf16!("get buffer"),
-    utf16!("get byteOffset"),
-    utf16!("get size"),
-    utf16!("get length"),
+    b"buffer",
+    b"byteLength",
+    b"byteOffset",
+    b"isView",
+    b"subarray",
+    b"get byteLength",
+    b"get buffer",
+    b"get byteOffset",
+    b"get size",
+    b"get length",
     // DataView object
-    utf16!("getBigInt64"),
-    utf16!("getBigUint64"),
-    utf16!("getFloat32"),
-    utf16!("getFloat64"),
-    utf16!("getInt8"),
-    utf16!("getInt16"),
-    utf16!("getInt32"),
-    utf16!("getUint8"),
-    utf16!("getUint16"),
-    utf16!("getUint32"),
-    utf16!("setBigInt64"),
-    utf16!("setBigUint64"),
-    utf16!("setFloat32"),
-    utf16!("setFloat64"),
-    utf16!("setInt8"),
-    utf16!("setInt16"),
-    utf16!("setInt32"),
-    utf16!("setUint8"),
-    utf16!("setUint16"),
-    utf16!("setUint32"),
+    b"getBigInt64",
+    b"getBigUint64",
+    b"getFloat32",
+    b"getFloat64",
+    b"getInt8",
+    b"getInt16",
+    b"getInt32",
+    b"getUint8",
+    b"getUint16",
+    b"getUint32",
+    b"setBigInt64",
+    b"setBigUint64",
+    b"setFloat32",
+    b"setFloat64",
+    b"setInt8",
+    b"setInt16",
+    b"setInt32",
+    b"setUint8",
+    b"setUint16",
+    b"setUint32",
     // Console object
-    utf16!("console"),
-    utf16!("assert"),
-    utf16!("debug"),
-    utf16!("error"),
-    utf16!("info"),
-    utf16!("trace"),
-    utf16!("warn"),
-    utf16!("exception"),
-    utf16!("count"),
-    utf16!("countReset"),
-    utf16!("group"),
-    utf16!("groupCollapsed"),
-    utf16!("groupEnd"),
-    utf16!("time"),
-    utf16!("timeLog"),
-    utf16!("timeEnd"),
-    utf16!("dir"),
-    utf16!("dirxml"),
+    b"console",
+    b"assert",
+    b"debug",
+    b"error",
+    b"info",
+    b"trace",
+    b"warn",
+    b"exception",
+    b"count",
+    b"countReset",
+    b"group",
+    b"groupCollapsed",
+    b"groupEnd",
+    b"time",
+    b"timeLog",
+    b"timeEnd",
+    b"dir",
+    b"dirxml",
     // Minified name
-    utf16!("a"),
-    utf16!("b"),
-    utf16!("c"),
-    utf16!("d"),
-    utf16!("e"),
-    utf16!("f"),
-    utf16!("g"),
-    utf16!("h"),
-    utf16!("i"),
-    utf16!("j"),
-    utf16!("k"),
-    utf16!("l"),
-    utf16!("m"),
-    utf16!("n"),
-    utf16!("o"),
-    utf16!("p"),
-    utf16!("q"),
-    utf16!("r"),
-    utf16!("s"),
-    utf16!("t"),
-    utf16!("u"),
-    utf16!("v"),
-    utf16!("w"),
-    utf16!("x"),
-    utf16!("y"),
-    utf16!("z"),
-    utf16!("A"),
-    utf16!("B"),
-    utf16!("C"),
-    utf16!("D"),
-    utf16!("E"),
-    utf16!("F"),
-    utf16!("G"),
-    utf16!("H"),
-    utf16!("I"),
-    utf16!("J"),
-    utf16!("K"),
-    utf16!("L"),
-    utf16!("M"),
-    utf16!("N"),
-    utf16!("O"),
-    utf16!("P"),
-    utf16!("Q"),
-    utf16!("R"),
-    utf16!("S"),
-    utf16!("T"),
-    utf16!("U"),
-    utf16!("V"),
-    utf16!("W"),
-    utf16!("X"),
-    utf16!("Y"),
-    utf16!("Z"),
-    utf16!("_"),
-    utf16!("$"),
+    b"a",
+    b"b",
+    b"c",
+    b"d",
+    b"e",
+    b"f",
+    b"g",
+    b"h",
+    b"i",
+    b"j",
+    b"k",
+    b"l",
+    b"m",
+    b"n",
+    b"o",
+    b"p",
+    b"q",
+    b"r",
+    b"s",
+    b"t",
+    b"u",
+    b"v",
+    b"w",
+    b"x",
+    b"y",
+    b"z",
+    b"A",
+    b"B",
+    b"C",
+    b"D",
+    b"E",
+    b"F",
+    b"G",
+    b"H",
+    b"I",
+    b"J",
+    b"K",
+    b"L",
+    b"M",
+    b"N",
+    b"O",
+    b"P",
+    b"Q",
+    b"R",
+    b"S",
+    b"T",
+    b"U",
+    b"V",
+    b"W",
+    b"X",
+    b"Y",
+    b"Z",
+    b"_",
+    b"$",
+    // Small integers, see `StaticJsStrings::get_integer`.
+    b"-1",
+    b"0",
+    b"1",
+    b"2",
+    b"3",
+    b"4",
+    b"5",
+    b"6",
+    b"7",
+    b"8",
+    b"9",
+    b"10",
+    b"11",
+    b"12",
+    b"13",
+    b"14",
+    b"15",
+    b"16",
+    b"17",
+    b"18",
+    b"19",
+    b"20",
+    b"21",
+    b"22",
+    b"23",
+    b"24",
+    b"25",
+    b"26",
+    b"27",
+    b"28",
+    b"29",
+    b"30",
+    b"31",
+    b"32",
+    b"33",
+    b"34",
+    b"35",
+    b"36",
+    b"37",
+    b"38",
+    b"39",
+    b"40",
+    b"41",
+    b"42",
+    b"43",
+    b"44",
+    b"45",
+    b"46",
+    b"47",
+    b"48",
+    b"49",
+    b"50",
+    b"51",
+    b"52",
+    b"53",
+    b"54",
+    b"55",
+    b"56",
+    b"57",
+    b"58",
+    b"59",
+    b"60",
+    b"61",
+    b"62",
+    b"63",
+    b"64",
+    b"65",
+    b"66",
+    b"67",
+    b"68",
+    b"69",
+    b"70",
+    b"71",
+    b"72",
+    b"73",
+    b"74",
+    b"75",
+    b"76",
+    b"77",
+    b"78",
+    b"79",
+    b"80",
+    b"81",
+    b"82",
+    b"83",
+    b"84",
+    b"85",
+    b"86",
+    b"87",
+    b"88",
+    b"89",
+    b"90",
+    b"91",
+    b"92",
+    b"93",
+    b"94",
+    b"95",
+    b"96",
+    b"97",
+    b"98",
+    b"99",
+    b"100",
+    b"101",
+    b"102",
+    b"103",
+    b"104",
+    b"105",
+    b"106",
+    b"107",
+    b"108",
+    b"109",
+    b"110",
+    b"111",
+    b"112",
+    b"113",
+    b"114",
+    b"115",
+    b"116",
+    b"117",
+    b"118",
+    b"119",
+    b"120",
+    b"121",
+    b"122",
+    b"123",
+    b"124",
+    b"125",
+    b"126",
+    b"127",
+    b"128",
+    b"129",
+    b"130",
+    b"131",
+    b"132",
+    b"133",
+    b"134",
+    b"135",
+    b"136",
+    b"137",
+    b"138",
+    b"139",
+    b"140",
+    b"141",
+    b"142",
+    b"143",
+    b"144",
+    b"145",
+    b"146",
+    b"147",
+    b"148",
+    b"149",
+    b"150",
+    b"151",
+    b"152",
+    b"153",
+    b"154",
+    b"155",
+    b"156",
+    b"157",
+    b"158",
+    b"159",
+    b"160",
+    b"161",
+    b"162",
+    b"163",
+    b"164",
+    b"165",
+    b"166",
+    b"167",
+    b"168",
+    b"169",
+    b"170",
+    b"171",
+    b"172",
+    b"173",
+    b"174",
+    b"175",
+    b"176",
+    b"177",
+    b"178",
+    b"179",
+    b"180",
+    b"181",
+    b"182",
+    b"183",
+    b"184",
+    b"185",
+    b"186",
+    b"187",
+    b"188",
+    b"189",
+    b"190",
+    b"191",
+    b"192",
+    b"193",
+    b"194",
+    b"195",
+    b"196",
+    b"197",
+    b"198",
+    b"199",
+    b"200",
+    b"201",
+    b"202",
+    b"203",
+    b"204",
+    b"205",
+    b"206",
+    b"207",
+    b"208",
+    b"209",
+    b"210",
+    b"211",
+    b"212",
+    b"213",
+    b"214",
+    b"215",
+    b"216",
+    b"217",
+    b"218",
+    b"219",
+    b"220",
+    b"221",
+    b"222",
+    b"223",
+    b"224",
+    b"225",
+    b"226",
+    b"227",
+    b"228",
+    b"229",
+    b"230",
+    b"231",
+    b"232",
+    b"233",
+    b"234",
+    b"235",
+    b"236",
+    b"237",
+    b"238",
+    b"239",
+    b"240",
+    b"241",
+    b"242",
+    b"243",
+    b"244",
+    b"245",
+    b"246",
+    b"247",
+    b"248",
+    b"249",
+    b"250",
+    b"251",
+    b"252",
+    b"253",
+    b"254",
+    b"255",
+    b"256",
 ];
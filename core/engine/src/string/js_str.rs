@@ -0,0 +1,343 @@
+//! A borrowed view of the code units of a [`JsString`][super::JsString].
+
+use std::{
+    hash::Hasher,
+    iter::FusedIterator,
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+    slice::{self, SliceIndex},
+};
+
+use crate::builtins::string::is_trimmable_whitespace;
+
+use super::JsString;
+
+/// A borrowed view of the code units of a [`JsString`][super::JsString].
+///
+/// Strings whose code units all fit in a single byte are stored using Latin-1, halving their
+/// memory footprint. `JsStr` exposes both representations without having to widen the narrow one,
+/// yielding `u16` code units in either case.
+#[derive(Debug, Clone, Copy)]
+pub enum JsStr<'a> {
+    /// A string where every code unit is in the `0..=0xFF` range.
+    Latin1(&'a [u8]),
+
+    /// A string stored as UTF-16 code units.
+    Utf16(&'a [u16]),
+}
+
+impl<'a> JsStr<'a> {
+    /// Gets the number of code units of the string.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Latin1(s) => s.len(),
+            Self::Utf16(s) => s.len(),
+        }
+    }
+
+    /// Returns `true` if the string has no code units.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the string is stored using Latin-1.
+    #[must_use]
+    pub const fn is_latin1(&self) -> bool {
+        matches!(self, Self::Latin1(_))
+    }
+
+    /// Gets the code unit at an index, or the substring in a range of indices, or `None` if
+    /// it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::js_string;
+    /// let string = js_string!("Hello");
+    /// let string = string.as_str();
+    /// assert_eq!(string.get(1), Some(u16::from(b'e')));
+    /// assert_eq!(string.get(1..3), Some(js_string!("el").as_str()));
+    /// assert_eq!(string.get(4..6), None);
+    /// ```
+    #[must_use]
+    pub fn get<I: JsSliceIndex<'a>>(&self, index: I) -> Option<I::Value> {
+        I::get(*self, index)
+    }
+
+    /// Gets the code unit at an index, or the substring in a range of indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[track_caller]
+    #[must_use]
+    pub fn get_expect<I: JsSliceIndex<'a>>(&self, index: I) -> I::Value {
+        self.get(index).expect("index out of bounds")
+    }
+
+    /// Gets an iterator over the code units of the string.
+    #[must_use]
+    pub fn iter(&self) -> JsStrIter<'a> {
+        match *self {
+            Self::Latin1(s) => JsStrIter::Latin1(s.iter()),
+            Self::Utf16(s) => JsStrIter::Utf16(s.iter()),
+        }
+    }
+
+    /// Returns the string without its leading and trailing whitespace and line terminators.
+    #[must_use]
+    pub fn trim(&self) -> Self {
+        self.trim_start().trim_end()
+    }
+
+    /// Returns the string without its leading whitespace and line terminators.
+    #[must_use]
+    pub fn trim_start(&self) -> Self {
+        let start = self
+            .iter()
+            .position(|unit| !is_trimmable_unit(unit))
+            .unwrap_or(self.len());
+        self.get_expect(start..)
+    }
+
+    /// Returns the string without its trailing whitespace and line terminators.
+    #[must_use]
+    pub fn trim_end(&self) -> Self {
+        let end = self
+            .iter()
+            .rposition(|unit| !is_trimmable_unit(unit))
+            .map_or(0, |end| end + 1);
+        self.get_expect(..end)
+    }
+
+    /// Returns `true` if the string contains the code unit `unit`.
+    #[must_use]
+    pub fn contains(&self, unit: u16) -> bool {
+        match self {
+            Self::Latin1(s) => u8::try_from(unit).is_ok_and(|unit| s.contains(&unit)),
+            Self::Utf16(s) => s.contains(&unit),
+        }
+    }
+
+    /// Returns `true` if the string starts with `prefix`.
+    #[must_use]
+    pub fn starts_with(&self, prefix: JsStr<'_>) -> bool {
+        self.get(..prefix.len()) == Some(prefix)
+    }
+
+    /// Returns `true` if the string ends with `suffix`.
+    #[must_use]
+    pub fn ends_with(&self, suffix: JsStr<'_>) -> bool {
+        self.len()
+            .checked_sub(suffix.len())
+            .is_some_and(|start| self.get_expect(start..) == suffix)
+    }
+
+    /// Copies the code units of the string into a new UTF-16 [`Vec`].
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<u16> {
+        match self {
+            Self::Latin1(s) => s.iter().copied().map(u16::from).collect(),
+            Self::Utf16(s) => s.to_vec(),
+        }
+    }
+
+    /// Feeds the code units of the string into `state`, producing the same hash as the
+    /// equivalent `[u16]` slice for streaming hashers.
+    pub(crate) fn hash_code_units<H: Hasher>(&self, state: &mut H) {
+        const CHUNK: usize = 32;
+
+        match self {
+            Self::Utf16(s) => std::hash::Hash::hash(s, state),
+            Self::Latin1(s) => {
+                state.write_usize(s.len());
+                let mut buf = [0u8; CHUNK * 2];
+                for chunk in s.chunks(CHUNK) {
+                    for (dst, &unit) in buf.chunks_exact_mut(2).zip(chunk) {
+                        dst.copy_from_slice(&u16::from(unit).to_ne_bytes());
+                    }
+                    state.write(&buf[..chunk.len() * 2]);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> IntoIterator for &JsStr<'a> {
+    type Item = u16;
+    type IntoIter = JsStrIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> From<&'a [u16]> for JsStr<'a> {
+    #[inline]
+    fn from(s: &'a [u16]) -> Self {
+        Self::Utf16(s)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [u16; N]> for JsStr<'a> {
+    #[inline]
+    fn from(s: &'a [u16; N]) -> Self {
+        Self::Utf16(s)
+    }
+}
+
+impl<'a> From<&'a JsString> for JsStr<'a> {
+    #[inline]
+    fn from(s: &'a JsString) -> Self {
+        s.as_str()
+    }
+}
+
+impl<'a> From<&JsStr<'a>> for JsStr<'a> {
+    #[inline]
+    fn from(s: &JsStr<'a>) -> Self {
+        *s
+    }
+}
+
+impl PartialEq for JsStr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Latin1(x), Self::Latin1(y)) => x == y,
+            (Self::Utf16(x), Self::Utf16(y)) => x == y,
+            _ => self.len() == other.len() && self.iter().eq(other.iter()),
+        }
+    }
+}
+
+impl Eq for JsStr<'_> {}
+
+impl PartialEq<JsString> for JsStr<'_> {
+    fn eq(&self, other: &JsString) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<[u16]> for JsStr<'_> {
+    fn eq(&self, other: &[u16]) -> bool {
+        match self {
+            Self::Latin1(s) => s.len() == other.len() && self.iter().eq(other.iter().copied()),
+            Self::Utf16(s) => *s == other,
+        }
+    }
+}
+
+impl Ord for JsStr<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::Latin1(x), Self::Latin1(y)) => x.cmp(y),
+            (Self::Utf16(x), Self::Utf16(y)) => x.cmp(y),
+            _ => self.iter().cmp(other.iter()),
+        }
+    }
+}
+
+impl PartialOrd for JsStr<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Returns `true` if the code unit is a whitespace or a line terminator.
+fn is_trimmable_unit(unit: u16) -> bool {
+    char::from_u32(u32::from(unit)).is_some_and(is_trimmable_whitespace)
+}
+
+/// A type that can index a [`JsStr`], like the indices and ranges of indices that index slices.
+pub trait JsSliceIndex<'a>: SliceIndex<[u8]> + SliceIndex<[u16]> {
+    /// The code unit or substring at the index.
+    type Value;
+
+    /// Gets the value at the index, or `None` if it is out of bounds.
+    fn get(string: JsStr<'a>, index: Self) -> Option<Self::Value>;
+}
+
+impl<'a> JsSliceIndex<'a> for usize {
+    type Value = u16;
+
+    #[inline]
+    fn get(string: JsStr<'a>, index: Self) -> Option<Self::Value> {
+        match string {
+            JsStr::Latin1(s) => s.get(index).copied().map(u16::from),
+            JsStr::Utf16(s) => s.get(index).copied(),
+        }
+    }
+}
+
+macro_rules! impl_js_slice_index_for_ranges {
+    ($($range:ty),* $(,)?) => {
+        $(
+            impl<'a> JsSliceIndex<'a> for $range {
+                type Value = JsStr<'a>;
+
+                #[inline]
+                fn get(string: JsStr<'a>, index: Self) -> Option<Self::Value> {
+                    match string {
+                        JsStr::Latin1(s) => s.get(index).map(JsStr::Latin1),
+                        JsStr::Utf16(s) => s.get(index).map(JsStr::Utf16),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_js_slice_index_for_ranges!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+);
+
+/// An iterator over the code units of a [`JsStr`].
+#[derive(Debug, Clone)]
+pub enum JsStrIter<'a> {
+    /// Iterator over Latin-1 code units.
+    Latin1(slice::Iter<'a, u8>),
+
+    /// Iterator over UTF-16 code units.
+    Utf16(slice::Iter<'a, u16>),
+}
+
+impl Iterator for JsStrIter<'_> {
+    type Item = u16;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Latin1(it) => it.next().copied().map(u16::from),
+            Self::Utf16(it) => it.next().copied(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Latin1(it) => it.size_hint(),
+            Self::Utf16(it) => it.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for JsStrIter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Latin1(it) => it.next_back().copied().map(u16::from),
+            Self::Utf16(it) => it.next_back().copied(),
+        }
+    }
+}
+
+impl ExactSizeIterator for JsStrIter<'_> {}
+
+impl FusedIterator for JsStrIter<'_> {}
@@ -5,6 +5,12 @@
 //!
 //! The [`js_string`][crate::js_string] macro is used when you need to create a new [`JsString`],
 //! and the [`utf16`] macro is used for const conversions of string literals to UTF-16.
+//!
+//! Strings whose code units all fit in a single byte are stored using Latin-1, and can be
+//! inspected without widening through the [`JsStr`] view returned by [`JsString::as_str`].
+//!
+//! Long concatenations made with [`JsString::concat_lazy`] are stored as ropes, which are only
+//! copied into a contiguous string the first time their code units are needed.
 
 // Required per unsafe code standards to ensure every unsafe usage is properly documented.
 // - `unsafe_op_in_unsafe_fn` will be warn-by-default in edition 2024:
@@ -22,31 +28,32 @@
 #![allow(unstable_name_collisions)]
 
 pub(crate) mod common;
+mod js_str;
 
 use crate::{
     builtins::string::is_trimmable_whitespace,
     tagged::{Tagged, UnwrappedTagged},
     JsBigInt,
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{track_external_allocation, track_external_deallocation, Finalize, MemoryKind, Trace};
 pub use boa_macros::utf16;
 
 use std::{
     alloc::{alloc, dealloc, Layout},
-    borrow::Borrow,
-    cell::Cell,
+    cell::{Cell, UnsafeCell},
     convert::Infallible,
     hash::{Hash, Hasher},
     iter::Peekable,
-    ops::{Deref, Index},
+    mem,
     process::abort,
     ptr::{self, addr_of, addr_of_mut, NonNull},
-    slice::SliceIndex,
     str::FromStr,
 };
 
 use self::common::StaticJsStrings;
 
+pub use self::js_str::{JsSliceIndex, JsStr, JsStrIter};
+
 fn alloc_overflow() -> ! {
     panic!("detected overflow during string allocation")
 }
@@ -66,14 +73,15 @@ fn alloc_overflow() -> ! {
 /// ```
 ///
 ///
-/// You can create a `JsString` from a string literal, which completely skips the runtime
-/// conversion from [`&str`] to <code>[&\[u16\]][slice]</code>:
+/// You can create a `JsString` from a string literal, which is stored as Latin-1 if it only
+/// contains ASCII characters:
 ///
 /// ```
 /// # use boa_engine::js_string;
 /// # use boa_engine::string::utf16;
 /// let hw = js_string!("Hello, world!");
 /// assert_eq!(&hw, utf16!("Hello, world!"));
+/// assert!(hw.is_latin1());
 /// ```
 ///
 /// Any `&[u16]` slice is a valid `JsString`, including unpaired surrogates:
@@ -83,15 +91,15 @@ fn alloc_overflow() -> ! {
 /// let array = js_string!(&[0xD8AFu16, 0x00A0, 0xD8FF, 0x00F0]);
 /// ```
 ///
-/// You can also pass it any number of `&[u16]` as arguments to create a new `JsString` with
-/// the concatenation of every slice:
+/// You can also pass it any number of `&JsString`, [`JsStr`] or `&[u16]` as arguments to create
+/// a new `JsString` with the concatenation of every string:
 ///
 /// ```
 /// # use boa_engine::js_string;
 /// # use boa_engine::string::utf16;
 /// const NAME: &[u16] = utf16!("human! ");
 /// let greeting = js_string!("Hello, ");
-/// let msg = js_string!(&greeting, &NAME, utf16!("Nice to meet you!"));
+/// let msg = js_string!(&greeting, NAME, utf16!("Nice to meet you!"));
 ///
 /// assert_eq!(&msg, utf16!("Hello, human! Nice to meet you!"));
 /// ```
@@ -101,16 +109,16 @@ macro_rules! js_string {
         $crate::JsString::default()
     };
     ($s:literal) => {
-        $crate::JsString::from($crate::string::utf16!($s))
+        $crate::JsString::from($s)
     };
     ($s:expr) => {
         $crate::JsString::from($s)
     };
     ( $x:expr, $y:expr ) => {
-        $crate::JsString::concat($x, $y)
+        $crate::JsString::concat($crate::string::JsStr::from($x), $crate::string::JsStr::from($y))
     };
     ( $( $s:expr ),+ ) => {
-        $crate::JsString::concat_array(&[ $( $s ),+ ])
+        $crate::JsString::concat_array(&[ $( $crate::string::JsStr::from($s) ),+ ])
     };
 }
 
@@ -177,8 +185,9 @@ impl CodePoint {
 /// The raw representation of a [`JsString`] in the heap.
 #[repr(C)]
 struct RawJsString {
-    /// The UTF-16 length.
-    len: usize,
+    /// The number of code units, with [`LATIN1_FLAG`] set if the string is stored as Latin-1, or
+    /// [`ROPE_FLAG`] set if the string is a rope.
+    tagged_len: usize,
 
     /// The number of references to the string.
     ///
@@ -186,10 +195,118 @@ struct RawJsString {
     refcount: Cell<usize>,
 
     /// An empty array which is used to get the offset of string data.
+    ///
+    /// UTF-16 strings store their code units here, Latin-1 strings store one byte per code unit
+    /// and ropes store a [`RopeData`].
     data: [u16; 0],
 }
 
-const DATA_OFFSET: usize = std::mem::size_of::<RawJsString>();
+impl RawJsString {
+    /// Gets the number of code units of the string.
+    const fn len(&self) -> usize {
+        self.tagged_len & !(LATIN1_FLAG | ROPE_FLAG)
+    }
+
+    /// Returns `true` if the string is stored as Latin-1.
+    const fn is_latin1(&self) -> bool {
+        self.tagged_len & LATIN1_FLAG != 0
+    }
+
+    /// Returns `true` if the string is a rope.
+    const fn is_rope(&self) -> bool {
+        self.tagged_len & ROPE_FLAG != 0
+    }
+}
+
+/// The data of a rope [`RawJsString`], the lazy concatenation of two strings.
+#[repr(C)]
+struct RopeData {
+    /// The depth of the tree of ropes that has this rope as its root.
+    depth: usize,
+
+    /// The contents of the rope.
+    ///
+    /// This is only modified once, when the rope is flattened, and never while a reference to it
+    /// is alive.
+    state: UnsafeCell<RopeState>,
+}
+
+/// The contents of a rope.
+enum RopeState {
+    /// The two strings that are concatenated.
+    Halves(JsString, JsString),
+
+    /// The concatenation of the halves, after the rope was flattened.
+    Flat(JsString),
+}
+
+/// Flag stored in the highest bit of [`RawJsString::tagged_len`] for Latin-1 strings.
+///
+/// Allocations can never exceed `isize::MAX` bytes, so this bit is never part of a valid length.
+const LATIN1_FLAG: usize = 1 << (usize::BITS - 1);
+
+/// Flag stored in the second highest bit of [`RawJsString::tagged_len`] for ropes.
+///
+/// Strings with this bit set in their length are never allocated, so it is never part of a
+/// valid length either.
+const ROPE_FLAG: usize = 1 << (usize::BITS - 2);
+
+/// The minimum number of code units of a concatenation to store it as a rope.
+///
+/// Copying short strings is cheaper than allocating a rope and flattening it later.
+const MIN_ROPE_LENGTH: usize = 128;
+
+/// The maximum depth of a tree of ropes.
+///
+/// Concatenations that would be deeper are flattened right away, which bounds the recursion when
+/// ropes are dropped.
+const MAX_ROPE_DEPTH: usize = 512;
+
+/// Computes the layout of a [`RawJsString`] storing `len` code units.
+fn raw_layout(len: usize, latin1: bool) -> Option<Layout> {
+    if len & (LATIN1_FLAG | ROPE_FLAG) != 0 {
+        return None;
+    }
+
+    let header = Layout::new::<RawJsString>();
+    let (layout, _) = if latin1 {
+        Layout::array::<u8>(len).and_then(|arr| header.extend(arr))
+    } else {
+        Layout::array::<u16>(len).and_then(|arr| header.extend(arr))
+    }
+    .ok()?;
+
+    Some(layout.pad_to_align())
+}
+
+/// Computes the layout of a rope [`RawJsString`].
+fn rope_layout() -> Layout {
+    Layout::new::<RawJsString>()
+        .extend(Layout::new::<RopeData>())
+        .expect("the layout of a rope must be valid")
+        .0
+        .pad_to_align()
+}
+
+/// Gets a pointer to the bytes of the Latin-1 [`RawJsString`] `raw`.
+///
+/// # Safety
+///
+/// `raw` must point to a valid Latin-1 [`RawJsString`].
+unsafe fn latin1_bytes(raw: NonNull<RawJsString>) -> *mut u8 {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { addr_of_mut!((*raw.as_ptr()).data).cast() }
+}
+
+/// Gets a pointer to the [`RopeData`] of `raw`.
+///
+/// # Safety
+///
+/// `raw` must point to a valid rope [`RawJsString`].
+unsafe fn rope_data(raw: NonNull<RawJsString>) -> *mut RopeData {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { addr_of_mut!((*raw.as_ptr()).data).cast() }
+}
 
 /// A UTF-16–encoded, reference counted, immutable string.
 ///
@@ -200,10 +317,16 @@ const DATA_OFFSET: usize = std::mem::size_of::<RawJsString>();
 /// We define some commonly used string constants in an interner. For these strings, we don't allocate
 /// memory on the heap to reduce the overhead of memory allocation and reference counting.
 ///
-/// # Deref
+/// Strings whose code units are all in the `0..=0xFF` range are stored as Latin-1, using a single
+/// byte per code unit. Their representation can be inspected with [`JsString::as_str`], and
+/// equality, ordering and hashing never depend on it.
+///
+/// Long concatenations can be stored as ropes, which reference the concatenated strings instead of
+/// copying them. Ropes are flattened into a contiguous string the first time their code units are
+/// read, and [`JsString::len`] is the only method that doesn't need to flatten them.
 ///
-/// [`JsString`] implements <code>[Deref]<Target = \[u16\]></code>, inheriting all of
-/// <code>\[u16\]</code>'s methods.
+/// The code units are read through the [`JsStr`] view returned by [`JsString::as_str`], or
+/// copied into a UTF-16 [`Vec`] with [`JsString::to_vec`].
 #[derive(Trace, Finalize)]
 // Safety: `JsString` does not contain any objects which needs to be traced, so this is safe.
 #[boa_gc(unsafe_empty_trace)]
@@ -215,35 +338,186 @@ pub struct JsString {
 sa::assert_eq_size!(JsString, *const ());
 
 impl JsString {
-    /// Obtains the underlying [`&[u16]`][slice] slice of a [`JsString`]
+    /// Obtains a [`JsStr`] view of the code units of a [`JsString`], without widening Latin-1
+    /// strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{js_string, string::JsStr};
+    /// let ascii = js_string!("Hello");
+    /// assert!(matches!(ascii.as_str(), JsStr::Latin1(b"Hello")));
+    ///
+    /// let wide = js_string!("\u{1F600}");
+    /// assert!(!wide.as_str().is_latin1());
+    /// assert_eq!(wide.as_str().len(), 2);
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> JsStr<'_> {
+        match self.ptr.unwrap() {
+            // SAFETY: The reference count of `JsString` guarantees that `h` is always valid.
+            UnwrappedTagged::Ptr(h) if unsafe { h.as_ref().is_rope() } => {
+                // SAFETY: `h` is a valid rope, which lives at least as long as `self`.
+                unsafe { Self::flatten_rope(h) }.as_str()
+            }
+            UnwrappedTagged::Ptr(h) => {
+                // SAFETY: The reference count of `JsString` guarantees that `h` is always valid.
+                let (len, latin1) = unsafe { (h.as_ref().len(), h.as_ref().is_latin1()) };
+                if latin1 {
+                    // SAFETY:
+                    // - `h` is a Latin-1 string, so it stores `len` bytes in `data`.
+                    // - The lifetime of the slice is bound to `self`, so it doesn't outlive the
+                    //   allocation.
+                    unsafe { JsStr::Latin1(std::slice::from_raw_parts(latin1_bytes(h), len)) }
+                } else {
+                    // SAFETY:
+                    // - `h` is a UTF-16 string, so it stores `len` aligned code units in `data`.
+                    // - The lifetime of the slice is bound to `self`, so it doesn't outlive the
+                    //   allocation.
+                    unsafe {
+                        JsStr::Utf16(std::slice::from_raw_parts(
+                            addr_of!((*h.as_ptr()).data).cast(),
+                            len,
+                        ))
+                    }
+                }
+            }
+            UnwrappedTagged::Tag(index) => {
+                // SAFETY: all static strings are valid indices on `STATIC_JS_STRINGS`, so `get` should always
+                // return `Some`.
+                JsStr::Latin1(unsafe { StaticJsStrings::get(index).unwrap_unchecked() })
+            }
+        }
+    }
+
+    /// Gets the number of UTF-16 code units of the string.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self.ptr.unwrap() {
+            // SAFETY: The reference count of `JsString` guarantees that `h` is always valid.
+            UnwrappedTagged::Ptr(h) => unsafe { h.as_ref().len() },
+            UnwrappedTagged::Tag(_) => self.as_str().len(),
+        }
+    }
+
+    /// Returns `true` if the string has no code units.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the string is stored as Latin-1.
+    #[inline]
+    #[must_use]
+    pub fn is_latin1(&self) -> bool {
+        self.as_str().is_latin1()
+    }
+
+    /// Gets the code unit at an index, or the substring in a range of indices, or `None` if
+    /// it is out of bounds.
+    ///
+    /// See [`JsStr::get`].
+    #[inline]
+    #[must_use]
+    pub fn get<'a, I: JsSliceIndex<'a>>(&'a self, index: I) -> Option<I::Value> {
+        self.as_str().get(index)
+    }
+
+    /// Gets the code unit at an index, or the substring in a range of indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn get_expect<'a, I: JsSliceIndex<'a>>(&'a self, index: I) -> I::Value {
+        self.as_str().get_expect(index)
+    }
+
+    /// Gets an iterator over the code units of the string.
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> JsStrIter<'_> {
+        self.as_str().iter()
+    }
+
+    /// Copies the code units of the string into a new UTF-16 [`Vec`].
+    #[inline]
     #[must_use]
-    pub fn as_slice(&self) -> &[u16] {
-        self
+    pub fn to_vec(&self) -> Vec<u16> {
+        self.as_str().to_vec()
     }
 
     /// Creates a new [`JsString`] from the concatenation of `x` and `y`.
     #[must_use]
-    pub fn concat(x: &[u16], y: &[u16]) -> Self {
+    pub fn concat(x: JsStr<'_>, y: JsStr<'_>) -> Self {
         Self::concat_array(&[x, y])
     }
 
-    /// Creates a new [`JsString`] from the concatenation of every element of
-    /// `strings`.
+    /// Creates a new [`JsString`] from the concatenation of every element of `strings`, storing
+    /// the result as Latin-1 if possible.
     #[must_use]
-    pub fn concat_array(strings: &[&[u16]]) -> Self {
+    pub fn concat_array(strings: &[JsStr<'_>]) -> Self {
         let mut full_count = 0usize;
-        for &string in strings {
+        for string in strings {
             let Some(sum) = full_count.checked_add(string.len()) else {
                 alloc_overflow()
             };
             full_count = sum;
         }
 
-        let ptr = Self::allocate_inner(full_count);
+        let latin1 = strings.iter().all(|string| match string {
+            JsStr::Latin1(_) => true,
+            JsStr::Utf16(s) => is_latin1(s),
+        });
 
-        let string = {
+        // Static strings are all Latin-1.
+        if latin1 && full_count <= common::MAX_STATIC_LENGTH {
+            let mut buf = [0; common::MAX_STATIC_LENGTH];
+            for (dst, unit) in buf.iter_mut().zip(strings.iter().flat_map(JsStr::iter)) {
+                // Checked by `is_latin1` above.
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    *dst = unit as u8;
+                }
+            }
+            if let Some(string) = StaticJsStrings::get_string(JsStr::Latin1(&buf[..full_count])) {
+                return string;
+            }
+        }
+
+        let ptr = Self::allocate_inner(full_count, latin1);
+
+        if latin1 {
+            // SAFETY: `allocate_inner` guarantees that `ptr` is a valid Latin-1 pointer.
+            let mut data = unsafe { latin1_bytes(ptr) };
+            for string in strings {
+                let count = string.len();
+                // SAFETY:
+                // The sum of all `count` for each `string` equals `full_count`, and since we're
+                // iteratively writing each of them to `data`, all writes stay in-bounds of the
+                // `full_count` bytes allocated by `allocate_inner`, which never overlap with
+                // any `string`.
+                unsafe {
+                    match string {
+                        JsStr::Latin1(s) => ptr::copy_nonoverlapping(s.as_ptr(), data, count),
+                        JsStr::Utf16(s) => {
+                            for (i, &unit) in s.iter().enumerate() {
+                                // Checked by `is_latin1` above.
+                                #[allow(clippy::cast_possible_truncation)]
+                                data.add(i).write(unit as u8);
+                            }
+                        }
+                    }
+                    data = data.add(count);
+                }
+            }
+        } else {
             // SAFETY: `allocate_inner` guarantees that `ptr` is a valid pointer.
-            let mut data = unsafe { addr_of_mut!((*ptr.as_ptr()).data).cast() };
+            let mut data: *mut u16 = unsafe { addr_of_mut!((*ptr.as_ptr()).data).cast() };
             for string in strings {
                 let count = string.len();
                 // SAFETY:
@@ -257,30 +531,138 @@ impl JsString {
                 // `allocate_inner` must return a valid pointer to newly allocated memory, meaning
                 // `ptr` and all `string`s should never overlap.
                 unsafe {
-                    ptr::copy_nonoverlapping(string.as_ptr(), data, count);
+                    match string {
+                        JsStr::Latin1(s) => {
+                            for (i, &unit) in s.iter().enumerate() {
+                                data.add(i).write(u16::from(unit));
+                            }
+                        }
+                        JsStr::Utf16(s) => ptr::copy_nonoverlapping(s.as_ptr(), data, count),
+                    }
                     data = data.add(count);
                 }
             }
-            Self {
-                // Safety: We already know it's a valid heap pointer.
-                ptr: unsafe { Tagged::from_ptr(ptr.as_ptr()) },
-            }
+        }
+
+        Self {
+            // Safety: We already know it's a valid heap pointer.
+            ptr: Tagged::from_non_null(ptr),
+        }
+    }
+
+    /// Creates a new [`JsString`] from the concatenation of `x` and `y`.
+    ///
+    /// Unlike [`JsString::concat_array`], long results are stored as a rope that references `x` and
+    /// `y` instead of copying them, so building a string by repeatedly appending to it takes linear
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{js_string, JsString};
+    /// let mut string = js_string!();
+    /// for _ in 0..1000 {
+    ///     string = JsString::concat_lazy(&string, &js_string!("chunk "));
+    /// }
+    /// assert_eq!(string.len(), 6000);
+    /// assert!(string.as_str().iter().take(6).eq(js_string!("chunk ").as_str().iter()));
+    /// ```
+    #[must_use]
+    pub fn concat_lazy(x: &Self, y: &Self) -> Self {
+        let Some(len) = x.len().checked_add(y.len()) else {
+            alloc_overflow()
+        };
+        if x.is_empty() {
+            return y.clone();
+        }
+        if y.is_empty() {
+            return x.clone();
+        }
+        if len < MIN_ROPE_LENGTH {
+            return Self::concat_array(&[x.as_str(), y.as_str()]);
+        }
+        if len & (LATIN1_FLAG | ROPE_FLAG) != 0 {
+            alloc_overflow()
+        }
+
+        let depth = x.rope_depth().max(y.rope_depth()) + 1;
+        let layout = rope_layout();
+
+        #[allow(clippy::cast_ptr_alignment)]
+        // SAFETY:
+        // The layout size of `RawJsString` is never zero, since it has to store
+        // the length of the string and the reference count.
+        let inner = unsafe { alloc(layout).cast::<RawJsString>() };
+        let Some(inner) = NonNull::new(inner) else {
+            std::alloc::handle_alloc_error(layout)
         };
+        track_external_allocation(MemoryKind::String, layout.size());
+
+        // SAFETY:
+        // `NonNull` verified for us that the pointer returned by `alloc` is valid,
+        // meaning we can write to its pointed memory.
+        unsafe {
+            inner.as_ptr().write(RawJsString {
+                tagged_len: len | ROPE_FLAG,
+                refcount: Cell::new(1),
+                data: [0; 0],
+            });
+            rope_data(inner).write(RopeData {
+                depth,
+                state: UnsafeCell::new(RopeState::Halves(x.clone(), y.clone())),
+            });
+        }
 
-        StaticJsStrings::get_string(&string[..]).unwrap_or(string)
+        let rope = Self {
+            // Safety: We already know it's a valid heap pointer.
+            ptr: Tagged::from_non_null(inner),
+        };
+        if depth > MAX_ROPE_DEPTH {
+            // SAFETY: `inner` is a valid rope, which is alive while `rope` is.
+            return unsafe { Self::flatten_rope(inner) }.clone();
+        }
+        rope
+    }
+
+    /// Creates a new [`JsString`] from the concatenation of every element of `strings`, storing
+    /// long results as a balanced tree of ropes.
+    ///
+    /// See [`JsString::concat_lazy`].
+    #[must_use]
+    pub fn concat_lazy_array(strings: &[Self]) -> Self {
+        match strings {
+            [] => Self::default(),
+            [string] => string.clone(),
+            _ if strings.iter().map(Self::len).sum::<usize>() < MIN_ROPE_LENGTH => {
+                Self::concat_array(&strings.iter().map(Self::as_str).collect::<Vec<_>>())
+            }
+            _ => {
+                let (left, right) = strings.split_at(strings.len() / 2);
+                Self::concat_lazy(
+                    &Self::concat_lazy_array(left),
+                    &Self::concat_lazy_array(right),
+                )
+            }
+        }
     }
 
     /// Decodes a [`JsString`] into a [`String`], replacing invalid data with its escaped representation
     /// in 4 digit hexadecimal.
     #[must_use]
     pub fn to_std_string_escaped(&self) -> String {
-        self.to_string_escaped()
+        match self.as_str() {
+            JsStr::Latin1(s) => s.iter().copied().map(char::from).collect(),
+            JsStr::Utf16(s) => s.to_string_escaped(),
+        }
     }
 
     /// Decodes a [`JsString`] into a [`String`], returning
     /// [`FromUtf16Error`][std::string::FromUtf16Error] if it contains any invalid data.
     pub fn to_std_string(&self) -> Result<String, std::string::FromUtf16Error> {
-        String::from_utf16(self)
+        match self.as_str() {
+            JsStr::Latin1(s) => Ok(s.iter().copied().map(char::from).collect()),
+            JsStr::Utf16(s) => String::from_utf16(s),
+        }
     }
 
     /// Decodes a [`JsString`] into an iterator of [`Result<String, u16>`], returning surrogates as
@@ -355,7 +737,7 @@ impl JsString {
 
     /// Gets an iterator of all the Unicode codepoints of a [`JsString`].
     pub fn code_points(&self) -> impl Iterator<Item = CodePoint> + Clone + '_ {
-        char::decode_utf16(self.iter().copied()).map(|res| match res {
+        char::decode_utf16(self.as_str().iter()).map(|res| match res {
             Ok(c) => CodePoint::Unicode(c),
             Err(e) => CodePoint::UnpairedSurrogate(e.unpaired_surrogate()),
         })
@@ -370,7 +752,7 @@ impl JsString {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-stringindexof
-    pub(crate) fn index_of(&self, search_value: &[u16], from_index: usize) -> Option<usize> {
+    pub(crate) fn index_of(&self, search_value: JsStr<'_>, from_index: usize) -> Option<usize> {
         // 1. Assert: Type(string) is String.
         // 2. Assert: Type(searchValue) is String.
         // 3. Assert: fromIndex is a non-negative integer.
@@ -392,10 +774,9 @@ impl JsString {
         // a. Let candidate be the substring of string from i to i + searchLen.
         // b. If candidate is the same sequence of code units as searchValue, return i.
         // 8. Return -1.
-        self.windows(search_value.len())
-            .skip(from_index)
-            .position(|s| s == search_value)
-            .map(|i| i + from_index)
+        let string = self.as_str();
+        let last = len.checked_sub(search_value.len())?;
+        (from_index..=last).find(|&i| string.get_expect(i..i + search_value.len()) == search_value)
     }
 
     /// Abstract operation `CodePointAt( string, position )`.
@@ -432,9 +813,9 @@ impl JsString {
         // We can skip the checks and instead use the `char::decode_utf16` function to take care of that for us.
         let code_point = self
             .get(position..=position + 1)
-            .unwrap_or(&self[position..=position]);
+            .unwrap_or_else(|| self.get_expect(position..=position));
 
-        match char::decode_utf16(code_point.iter().copied())
+        match char::decode_utf16(code_point.iter())
             .next()
             .expect("code_point always has a value")
         {
@@ -520,13 +901,14 @@ impl JsString {
         JsBigInt::from_string(self.to_std_string().ok().as_ref()?)
     }
 
-    /// Allocates a new [`RawJsString`] with an internal capacity of `str_len` chars.
+    /// Allocates a new [`RawJsString`] with an internal capacity of `str_len` code units,
+    /// stored as Latin-1 if `latin1` is `true`.
     ///
     /// # Panics
     ///
     /// Panics if `try_allocate_inner` returns `Err`.
-    fn allocate_inner(str_len: usize) -> NonNull<RawJsString> {
-        match Self::try_allocate_inner(str_len) {
+    fn allocate_inner(str_len: usize, latin1: bool) -> NonNull<RawJsString> {
+        match Self::try_allocate_inner(str_len, latin1) {
             Ok(v) => v,
             Err(None) => alloc_overflow(),
             Err(Some(layout)) => std::alloc::handle_alloc_error(layout),
@@ -534,20 +916,19 @@ impl JsString {
     }
 
     // This is marked as safe because it is always valid to call this function to request any number
-    // of `u16`, since this function ought to fail on an OOM error.
-    /// Allocates a new [`RawJsString`] with an internal capacity of `str_len` chars.
+    // of code units, since this function ought to fail on an OOM error.
+    /// Allocates a new [`RawJsString`] with an internal capacity of `str_len` code units,
+    /// stored as Latin-1 if `latin1` is `true`.
     ///
     /// # Errors
     ///
     /// Returns `Err(None)` on integer overflows `usize::MAX`.
     /// Returns `Err(Some(Layout))` on allocation error.
-    fn try_allocate_inner(str_len: usize) -> Result<NonNull<RawJsString>, Option<Layout>> {
-        let (layout, offset) = Layout::array::<u16>(str_len)
-            .and_then(|arr| Layout::new::<RawJsString>().extend(arr))
-            .map(|(layout, offset)| (layout.pad_to_align(), offset))
-            .map_err(|_| None)?;
-
-        debug_assert_eq!(offset, DATA_OFFSET);
+    fn try_allocate_inner(
+        str_len: usize,
+        latin1: bool,
+    ) -> Result<NonNull<RawJsString>, Option<Layout>> {
+        let layout = raw_layout(str_len, latin1).ok_or(None)?;
 
         #[allow(clippy::cast_ptr_alignment)]
         // SAFETY:
@@ -559,6 +940,7 @@ impl JsString {
         // we should abort, since an allocation error is pretty unrecoverable for us
         // right now.
         let inner = NonNull::new(inner).ok_or(Some(layout))?;
+        track_external_allocation(MemoryKind::String, layout.size());
 
         // SAFETY:
         // `NonNull` verified for us that the pointer returned by `alloc` is valid,
@@ -566,49 +948,125 @@ impl JsString {
         unsafe {
             // Write the first part, the `RawJsString`.
             inner.as_ptr().write(RawJsString {
-                len: str_len,
+                tagged_len: if latin1 {
+                    str_len | LATIN1_FLAG
+                } else {
+                    str_len
+                },
                 refcount: Cell::new(1),
                 data: [0; 0],
             });
         }
 
-        debug_assert!({
-            let inner = inner.as_ptr();
+        Ok(inner)
+    }
+
+    /// Returns the contents of the string if it is a rope.
+    ///
+    /// The returned reference must not be held while any rope is flattened.
+    fn rope_state(&self) -> Option<&RopeState> {
+        match self.ptr.unwrap() {
             // SAFETY:
-            // - `inner` must be a valid pointer, since it comes from a `NonNull`,
-            // meaning we can safely dereference it to `RawJsString`.
-            // - `offset` should point us to the beginning of the array,
-            // and since we requested an `RawJsString` layout with a trailing
-            // `[u16; str_len]`, the memory of the array must be in the `usize`
-            // range for the allocation to succeed.
-            unsafe {
-                ptr::eq(
-                    inner.cast::<u8>().add(offset).cast(),
-                    (*inner).data.as_mut_ptr(),
-                )
+            // - The reference count of `JsString` guarantees that `h` is always valid.
+            // - The state of the rope is only modified when flattening it, which never happens
+            //   while the returned reference is alive.
+            UnwrappedTagged::Ptr(h) if unsafe { h.as_ref().is_rope() } => unsafe {
+                Some(&*(*rope_data(h)).state.get())
+            },
+            _ => None,
+        }
+    }
+
+    /// Gets the depth of the tree of ropes of the string, which is `0` for flat strings.
+    fn rope_depth(&self) -> usize {
+        match (self.rope_state(), self.ptr.unwrap()) {
+            // SAFETY: `h` is a valid rope, since it has a rope state.
+            (Some(RopeState::Halves(..)), UnwrappedTagged::Ptr(h)) => unsafe {
+                (*rope_data(h)).depth
+            },
+            _ => 0,
+        }
+    }
+
+    /// Returns the flat string of the rope `raw`, copying the code units of its halves into it
+    /// the first time it is needed.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must point to a valid rope [`RawJsString`] that outlives `'a`.
+    unsafe fn flatten_rope<'a>(raw: NonNull<RawJsString>) -> &'a Self {
+        // SAFETY: Guaranteed by the caller.
+        let state = unsafe { &(*rope_data(raw)).state };
+
+        let flat = {
+            // SAFETY: There are no references to the state of a rope while it is being flattened.
+            let (left, right) = match unsafe { &*state.get() } {
+                RopeState::Flat(string) => return string,
+                RopeState::Halves(left, right) => (left, right),
+            };
+
+            // The tree is traversed with an explicit stack, since it can be deep.
+            let mut leaves = Vec::new();
+            let mut pending = vec![right, left];
+            while let Some(string) = pending.pop() {
+                match string.rope_state() {
+                    Some(RopeState::Halves(left, right)) => {
+                        pending.push(right);
+                        pending.push(left);
+                    }
+                    Some(RopeState::Flat(string)) => leaves.push(string.as_str()),
+                    None => leaves.push(string.as_str()),
+                }
             }
-        });
+            Self::concat_array(&leaves)
+        };
 
-        Ok(inner)
+        // SAFETY:
+        // - All the references to the halves were dropped above, and the rope is not flat, so
+        //   there are no references to its state.
+        // - A flat rope is never modified again, so the returned reference lives as long as the
+        //   rope, which outlives `'a`.
+        unsafe {
+            let halves = mem::replace(&mut *state.get(), RopeState::Flat(flat));
+            drop(halves);
+            match &*state.get() {
+                RopeState::Flat(string) => string,
+                RopeState::Halves(..) => unreachable!("the rope was just flattened"),
+            }
+        }
     }
 
     /// Creates a new [`JsString`] from `data`, without checking if the string is in the interner.
     fn from_slice_skip_interning(string: &[u16]) -> Self {
         let count = string.len();
-        let ptr = Self::allocate_inner(count);
-
-        // SAFETY: `allocate_inner` guarantees that `ptr` is a valid pointer.
-        let data = unsafe { addr_of_mut!((*ptr.as_ptr()).data) };
-        // SAFETY:
-        // - We read `count = data.len()` elements from `data`, which is within the bounds of the slice.
-        // - `allocate_inner` must allocate at least `count` elements, which allows us to safely
-        //   write at least `count` elements.
-        // - `allocate_inner` should already take care of the alignment of `ptr`, and `data` must be
-        //   aligned to be a valid slice.
-        // - `allocate_inner` must return a valid pointer to newly allocated memory, meaning `ptr`
-        //   and `data` should never overlap.
-        unsafe {
-            ptr::copy_nonoverlapping(string.as_ptr(), data.cast(), count);
+        let latin1 = is_latin1(string);
+        let ptr = Self::allocate_inner(count, latin1);
+
+        if latin1 {
+            // SAFETY: `allocate_inner` guarantees that `ptr` is a valid Latin-1 pointer.
+            let data = unsafe { latin1_bytes(ptr) };
+            for (i, &unit) in string.iter().enumerate() {
+                // SAFETY: `allocate_inner` allocated `count` bytes for `data`.
+                // Checked by `is_latin1` above.
+                #[allow(clippy::cast_possible_truncation)]
+                unsafe {
+                    data.add(i).write(unit as u8);
+                }
+            }
+        } else {
+            // SAFETY: `allocate_inner` guarantees that `ptr` is a valid pointer.
+            let data = unsafe { addr_of_mut!((*ptr.as_ptr()).data) };
+            // SAFETY:
+            // - We read `count = data.len()` elements from `data`, which is within the bounds of the slice.
+            // - `allocate_inner` must allocate at least `count` elements, which allows us to safely
+            //   write at least `count` elements.
+            // - `allocate_inner` should already take care of the alignment of `ptr`, and `data` must be
+            //   aligned to be a valid slice.
+            // - `allocate_inner` must return a valid pointer to newly allocated memory, meaning `ptr`
+            //   and `data` should never overlap.
+            unsafe {
+                ptr::copy_nonoverlapping(string.as_ptr(), data.cast(), count);
+            }
         }
         Self {
             // Safety: `allocate_inner` guarantees `ptr` is a valid heap pointer.
@@ -617,15 +1075,18 @@ impl JsString {
     }
 }
 
-impl AsRef<[u16]> for JsString {
-    fn as_ref(&self) -> &[u16] {
-        self
-    }
+/// Returns `true` if every code unit of `string` can be stored as Latin-1.
+fn is_latin1(string: &[u16]) -> bool {
+    string.iter().all(|&unit| unit <= 0xFF)
 }
 
-impl Borrow<[u16]> for JsString {
-    fn borrow(&self) -> &[u16] {
-        self
+impl<'a> IntoIterator for &'a JsString {
+    type Item = u16;
+    type IntoIter = JsStrIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
@@ -664,74 +1125,61 @@ impl Drop for JsString {
                 return;
             }
 
+            if inner.is_rope() {
+                // SAFETY:
+                // - `raw` is a valid rope, and this is the last reference to it, so its data can
+                //   be dropped.
+                // - Ropes are always allocated with `rope_layout`.
+                unsafe {
+                    ptr::drop_in_place(rope_data(raw));
+                    dealloc(raw.as_ptr().cast(), rope_layout());
+                }
+                track_external_deallocation(MemoryKind::String, rope_layout().size());
+                return;
+            }
+
+            let (len, latin1) = (inner.len(), inner.is_latin1());
+
             // SAFETY:
             // All the checks for the validity of the layout have already been made on `alloc_inner`,
             // so we can skip the unwrap.
-            let layout = unsafe {
-                Layout::for_value(inner)
-                    .extend(Layout::array::<u16>(inner.len).unwrap_unchecked())
-                    .unwrap_unchecked()
-                    .0
-                    .pad_to_align()
-            };
+            let layout = unsafe { raw_layout(len, latin1).unwrap_unchecked() };
             // Safety:
             // If refcount is 0 and we call drop, that means this is the last `JsString` which
             // points to this memory allocation, so deallocating it is safe.
             unsafe {
                 dealloc(raw.as_ptr().cast(), layout);
             }
+            track_external_deallocation(MemoryKind::String, layout.size());
         }
     }
 }
 
 impl std::fmt::Debug for JsString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::char::decode_utf16(self.as_slice().to_owned())
-            .map(|r| {
-                r.map_or_else(
-                    |err| format!("<0x{:04x}>", err.unpaired_surrogate()),
-                    String::from,
-                )
+        self.code_points()
+            .map(|cp| match cp {
+                CodePoint::Unicode(c) => String::from(c),
+                CodePoint::UnpairedSurrogate(surr) => format!("<0x{surr:04x}>"),
             })
             .collect::<String>()
             .fmt(f)
     }
 }
 
-impl Deref for JsString {
-    type Target = [u16];
-
-    fn deref(&self) -> &Self::Target {
-        match self.ptr.unwrap() {
-            UnwrappedTagged::Ptr(h) => {
-                // SAFETY:
-                // - The `RawJsString` type has all the necessary information to reconstruct a valid
-                //   slice (length and starting pointer).
-                //
-                // - We aligned `h.data` on allocation, and the block is of size `h.len`, so this
-                //   should only generate valid reads.
-                //
-                // - The lifetime of `&Self::Target` is shorter than the lifetime of `self`, as seen
-                //   by its signature, so this doesn't outlive `self`.
-                unsafe {
-                    let h = h.as_ptr();
-                    std::slice::from_raw_parts(addr_of!((*h).data).cast(), (*h).len)
-                }
-            }
-            UnwrappedTagged::Tag(index) => {
-                // SAFETY: all static strings are valid indices on `STATIC_JS_STRINGS`, so `get` should always
-                // return `Some`.
-                unsafe { StaticJsStrings::get(index).unwrap_unchecked() }
-            }
-        }
-    }
-}
-
 impl Eq for JsString {}
 
 impl From<&[u16]> for JsString {
     fn from(s: &[u16]) -> Self {
-        StaticJsStrings::get_string(s).unwrap_or_else(|| Self::from_slice_skip_interning(s))
+        StaticJsStrings::get_string(JsStr::Utf16(s))
+            .unwrap_or_else(|| Self::from_slice_skip_interning(s))
+    }
+}
+
+impl From<JsStr<'_>> for JsString {
+    #[inline]
+    fn from(s: JsStr<'_>) -> Self {
+        Self::concat_array(&[s])
     }
 }
 
@@ -744,8 +1192,12 @@ impl From<Vec<u16>> for JsString {
 impl From<&str> for JsString {
     #[inline]
     fn from(s: &str) -> Self {
-        let s = s.encode_utf16().collect::<Vec<_>>();
+        // The UTF-8 bytes of ASCII strings are also their Latin-1 code units.
+        if s.is_ascii() {
+            return Self::from(JsStr::Latin1(s.as_bytes()));
+        }
 
+        let s = s.encode_utf16().collect::<Vec<_>>();
         Self::from(&s[..])
     }
 }
@@ -765,34 +1217,25 @@ impl<const N: usize> From<&[u16; N]> for JsString {
 
 impl Hash for JsString {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self[..].hash(state);
-    }
-}
-
-impl<I: SliceIndex<[u16]>> Index<I> for JsString {
-    type Output = I::Output;
-
-    #[inline]
-    fn index(&self, index: I) -> &Self::Output {
-        Index::index(&**self, index)
+        self.as_str().hash_code_units(state);
     }
 }
 
 impl Ord for JsString {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self[..].cmp(other)
+        self.as_str().cmp(&other.as_str())
     }
 }
 
 impl PartialEq for JsString {
     fn eq(&self, other: &Self) -> bool {
-        self[..] == other[..]
+        self.as_str() == other.as_str()
     }
 }
 
 impl PartialEq<JsString> for [u16] {
     fn eq(&self, other: &JsString) -> bool {
-        self == &**other
+        other.as_str() == *self
     }
 }
 
@@ -804,7 +1247,13 @@ impl<const N: usize> PartialEq<JsString> for [u16; N] {
 
 impl PartialEq<[u16]> for JsString {
     fn eq(&self, other: &[u16]) -> bool {
-        &**self == other
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<JsStr<'_>> for JsString {
+    fn eq(&self, other: &JsStr<'_>) -> bool {
+        self.as_str() == *other
     }
 }
 
@@ -852,45 +1301,6 @@ impl FromStr for JsString {
     }
 }
 
-/// Utility trait that adds trimming functionality to every `UTF-16` string.
-pub(crate) trait Utf16Trim {
-    /// Trims both leading and trailing space from `self`.
-    fn trim(&self) -> &Self {
-        self.trim_start().trim_end()
-    }
-
-    /// Trims all leading space from `self`.
-    fn trim_start(&self) -> &Self;
-
-    /// Trims all trailing space from `self`.
-    fn trim_end(&self) -> &Self;
-}
-
-impl Utf16Trim for [u16] {
-    fn trim_start(&self) -> &Self {
-        if let Some(left) = self.iter().copied().position(|r| {
-            !char::from_u32(u32::from(r))
-                .map(is_trimmable_whitespace)
-                .unwrap_or_default()
-        }) {
-            &self[left..]
-        } else {
-            &[]
-        }
-    }
-    fn trim_end(&self) -> &Self {
-        if let Some(right) = self.iter().copied().rposition(|r| {
-            !char::from_u32(u32::from(r))
-                .map(is_trimmable_whitespace)
-                .unwrap_or_default()
-        }) {
-            &self[..=right]
-        } else {
-            &[]
-        }
-    }
-}
-
 /// Utility trait that adds a `UTF-16` escaped representation to every [`[u16]`][slice].
 pub(crate) trait ToStringEscaped {
     /// Decodes `self` as an `UTF-16` encoded string, escaping any unpaired surrogates by its
@@ -915,7 +1325,7 @@ mod tests {
     use crate::tagged::UnwrappedTagged;
 
     use super::utf16;
-    use super::JsString;
+    use super::{JsStr, JsString};
 
     impl JsString {
         /// Gets the number of `JsString`s which point to this allocation.
@@ -934,7 +1344,7 @@ mod tests {
     #[test]
     fn empty() {
         let s = js_string!();
-        assert_eq!(*s, "".encode_utf16().collect::<Vec<u16>>());
+        assert_eq!(s.to_vec(), "".encode_utf16().collect::<Vec<u16>>());
     }
 
     #[test]
@@ -1008,7 +1418,7 @@ mod tests {
         const HELLO: &str = "Hello";
         let x = js_string!(HELLO);
 
-        assert_eq!(*x, HELLO.encode_utf16().collect::<Vec<u16>>());
+        assert_eq!(x.to_vec(), HELLO.encode_utf16().collect::<Vec<u16>>());
     }
 
     #[test]
@@ -1019,7 +1429,7 @@ mod tests {
         const HELLOWORLD: &[u16] = utf16!("Hello World!");
         let x = js_string!(HELLOWORLD);
 
-        assert_eq!(&*x, HELLOWORLD);
+        assert_eq!(x, *HELLOWORLD);
 
         let mut hasher = DefaultHasher::new();
         HELLOWORLD.hash(&mut hasher);
@@ -1052,4 +1462,159 @@ mod tests {
         assert_eq!(&xyzw, utf16!("hello, world!"));
         assert_eq!(xyzw.refcount(), Some(1));
     }
+
+    #[test]
+    fn latin1() {
+        let ascii = js_string!("Hello, world!");
+        assert!(ascii.is_latin1());
+        assert!(matches!(ascii.as_str(), JsStr::Latin1(b"Hello, world!")));
+
+        let latin1 = js_string!("caf\u{e9}");
+        assert!(latin1.is_latin1());
+        assert_eq!(latin1.to_std_string_escaped(), "caf\u{e9}");
+
+        let wide = js_string!("\u{3b1}\u{3b2}");
+        assert!(!wide.is_latin1());
+        assert_eq!(wide.len(), 2);
+
+        // Reading the code units doesn't widen the string.
+        assert_eq!(ascii.to_vec(), utf16!("Hello, world!"));
+        assert_eq!(ascii.get_expect(..5), js_string!("Hello"));
+        assert_eq!(ascii.get(7), Some(u16::from(b'w')));
+        assert!(ascii.get_expect(..5).is_latin1());
+
+        let mixed = js_string!(&ascii, &wide);
+        assert!(!mixed.is_latin1());
+        assert_eq!(&mixed, utf16!("Hello, world!\u{3b1}\u{3b2}"));
+
+        let narrow = JsString::concat_array(&[ascii.as_str(), latin1.as_str()]);
+        assert!(narrow.is_latin1());
+        assert_eq!(narrow.to_std_string_escaped(), "Hello, world!caf\u{e9}");
+    }
+
+    #[test]
+    fn static_strings_are_latin1() {
+        use super::common::StaticJsStrings;
+
+        let length = js_string!("length");
+        assert!(length.ptr.is_tagged());
+        assert!(matches!(length.as_str(), JsStr::Latin1(b"length")));
+        assert!(StaticJsStrings::SYMBOL_ASYNC_ITERATOR.is_latin1());
+
+        let concatenated = js_string!(utf16!("len"), utf16!("gth"));
+        assert!(concatenated.ptr.is_tagged());
+        assert_eq!(concatenated, length);
+    }
+
+    #[test]
+    fn static_integers() {
+        use super::common::StaticJsStrings;
+
+        for value in -1..=256 {
+            let string = StaticJsStrings::get_integer(value).expect("small integers are static");
+            assert!(string.ptr.is_tagged());
+            assert_eq!(string.to_std_string_escaped(), value.to_string());
+        }
+        assert!(StaticJsStrings::get_integer(-2).is_none());
+        assert!(StaticJsStrings::get_integer(257).is_none());
+        assert!(StaticJsStrings::get_integer(i64::MIN).is_none());
+    }
+
+    #[test]
+    fn rope() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let chunk = js_string!("a chunk of text, ");
+        let mut rope = js_string!();
+        let mut expected = String::new();
+        for _ in 0..100 {
+            rope = JsString::concat_lazy(&rope, &chunk);
+            expected.push_str("a chunk of text, ");
+        }
+
+        // The length is known without flattening the rope.
+        assert_eq!(rope.len(), expected.len());
+        assert!(rope.rope_depth() > 0);
+
+        let flat = js_string!(expected.as_str());
+        assert_eq!(rope, flat);
+        assert_eq!(rope.rope_depth(), 0);
+        assert_eq!(hash(&rope), hash(&flat));
+        assert!(rope.is_latin1());
+        assert_eq!(rope.to_std_string_escaped(), expected);
+
+        // Flattening a rope doesn't change the strings it was built from.
+        let wide = js_string!("\u{3b1}".repeat(200).as_str());
+        let mixed = JsString::concat_lazy(&rope, &wide);
+        assert_eq!(mixed.len(), rope.len() + 200);
+        assert!(!mixed.is_latin1());
+        assert_eq!(mixed.get_expect(..rope.len()), rope);
+        assert_eq!(mixed.get_expect(rope.len()..), wide);
+
+        // Short concatenations are copied right away.
+        let short = JsString::concat_lazy(&chunk, &chunk);
+        assert_eq!(short.rope_depth(), 0);
+        assert_eq!(short.refcount(), Some(1));
+    }
+
+    #[test]
+    fn rope_depth_is_bounded() {
+        let chunk = js_string!("x");
+        let mut string = js_string!("y".repeat(200).as_str());
+        for _ in 0..100_000 {
+            string = JsString::concat_lazy(&string, &chunk);
+            assert!(string.rope_depth() <= super::MAX_ROPE_DEPTH);
+        }
+        assert_eq!(string.len(), 100_200);
+
+        let strings = vec![js_string!("a string that is long enough"); 10_000];
+        let joined = JsString::concat_lazy_array(&strings);
+        assert_eq!(joined.len(), 280_000);
+        assert!(joined.rope_depth() <= 16);
+        assert_eq!(
+            joined.get_expect(..28),
+            js_string!("a string that is long enough")
+        );
+
+        // Dropping the rope must not overflow the stack.
+        drop(string);
+        drop(joined);
+    }
+
+    #[test]
+    fn representation_independent() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let text = "a string longer than a single hashing chunk of thirty-two units";
+        let units = text.encode_utf16().collect::<Vec<_>>();
+        let narrow = js_string!(text);
+        assert!(narrow.is_latin1());
+
+        assert_eq!(narrow.as_str(), JsStr::Utf16(&units));
+        assert_eq!(narrow, units[..]);
+        assert_eq!(hash(&narrow), hash(&units[..]));
+
+        for (lhs, rhs) in [("abc", "abd"), ("ab", "abc"), ("\u{e9}", "\u{3b1}")] {
+            let lhs_units = lhs.encode_utf16().collect::<Vec<_>>();
+            let rhs_units = rhs.encode_utf16().collect::<Vec<_>>();
+            assert_eq!(
+                js_string!(lhs).cmp(&js_string!(rhs)),
+                lhs_units.cmp(&rhs_units)
+            );
+        }
+    }
 }
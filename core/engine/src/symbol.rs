@@ -61,7 +61,9 @@ fn get_id() -> Option<u64> {
 #[derive(Debug, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 enum WellKnown {
+    AsyncDispose,
     AsyncIterator,
+    Dispose,
     HasInstance,
     IsConcatSpreadable,
     Iterator,
@@ -79,7 +81,9 @@ enum WellKnown {
 impl WellKnown {
     const fn description(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::SYMBOL_ITERATOR,
@@ -97,7 +101,9 @@ impl WellKnown {
 
     const fn fn_name(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::FN_SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::FN_SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::FN_SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::FN_SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::FN_SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::FN_SYMBOL_ITERATOR,
@@ -167,7 +173,7 @@ impl JsSymbol {
         let hash = get_id()?;
         let arc = Arc::new(Inner {
             hash,
-            description: description.map(|s| Box::from(&*s)),
+            description: description.map(|s| s.to_vec().into_boxed_slice()),
         });
 
         Some(Self {
@@ -209,7 +215,7 @@ impl JsSymbol {
             return wk.fn_name();
         }
         self.description()
-            .map(|s| js_string!(utf16!("["), &*s, utf16!("]")))
+            .map(|s| js_string!(utf16!("["), &s, utf16!("]")))
             .unwrap_or_default()
     }
 
@@ -248,8 +254,12 @@ impl JsSymbol {
     }
 
     well_known_symbols! {
+        /// Gets the static `JsSymbol` for `"Symbol.asyncDispose"`.
+        (async_dispose, WellKnown::AsyncDispose),
         /// Gets the static `JsSymbol` for `"Symbol.asyncIterator"`.
         (async_iterator, WellKnown::AsyncIterator),
+        /// Gets the static `JsSymbol` for `"Symbol.dispose"`.
+        (dispose, WellKnown::Dispose),
         /// Gets the static `JsSymbol` for `"Symbol.hasInstance"`.
         (has_instance, WellKnown::HasInstance),
         /// Gets the static `JsSymbol` for `"Symbol.isConcatSpreadable"`.
@@ -0,0 +1,86 @@
+use boa_gc::{Finalize, Trace};
+use indoc::indoc;
+
+use crate::{
+    class::{Class, ClassBuilder},
+    js_string, run_test_actions, Context, JsArgs, JsData, JsNativeErrorKind, JsResult, JsValue,
+    TestAction,
+};
+
+#[derive(Debug, Trace, Finalize, JsData)]
+struct Point {
+    x: f64,
+}
+
+impl Class for Point {
+    const NAME: &'static str = "Point";
+    const LENGTH: usize = 1;
+
+    fn data_constructor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            x: args.try_get(0, context)?,
+        })
+    }
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        class
+            .data_getter(js_string!("x"), |p: &Self| p.x)
+            .data_method(js_string!("scale"), 1, |p: &mut Self, args, context| {
+                p.x *= args.try_get::<f64>(0, context)?;
+                Ok(JsValue::undefined())
+            });
+        Ok(())
+    }
+}
+
+#[test]
+fn native_data_accessors() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context.register_global_class::<Point>().unwrap();
+        }),
+        TestAction::run("var p = new Point(2); p.scale(3);"),
+        TestAction::assert_eq("p.x", 6.0),
+        TestAction::assert("!Object.keys(Point.prototype).includes('x')"),
+        TestAction::assert_eq(
+            "Object.getOwnPropertyDescriptor(Point.prototype, 'x').get.name",
+            js_string!("get x"),
+        ),
+        TestAction::assert_native_error(
+            "Object.getOwnPropertyDescriptor(Point.prototype, 'x').get.call({})",
+            JsNativeErrorKind::Type,
+            "native class method called on incompatible receiver",
+        ),
+        TestAction::assert_native_error(
+            "new Point('a')",
+            JsNativeErrorKind::Type,
+            "cannot convert value to a f64",
+        ),
+    ]);
+}
+
+#[test]
+fn static_block() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class Counter {
+                static #count;
+                static {
+                    this.#count = 40;
+                    Counter.#count += 2;
+                    this.self = this === Counter;
+                }
+                static get count() {
+                    return Counter.#count;
+                }
+            }
+        "#}),
+        TestAction::assert_eq("Counter.count", 42),
+        TestAction::assert("Counter.self"),
+        TestAction::assert_native_error(
+            "class A { static { arguments; } }",
+            JsNativeErrorKind::Syntax,
+            "'arguments' not allowed in class static block at line 1, col 20",
+        ),
+    ]);
+}
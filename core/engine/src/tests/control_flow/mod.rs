@@ -428,6 +428,121 @@ fn bigger_switch_example() {
     ]);
 }
 
+#[test]
+fn table_switch_fallthrough() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function f(a) {
+                    let b = "";
+
+                    switch (a) {
+                        case 1:
+                            b += "1";
+                        case 2:
+                            b += "2";
+                            break;
+                        default:
+                            b += "d";
+                        case 4:
+                            b += "4";
+                        case 5:
+                            b += "5";
+                            break;
+                        case 1:
+                            b += "unreachable";
+                    }
+                    return b;
+                }
+            "#}),
+        TestAction::assert_eq("f(1)", js_string!("12")),
+        TestAction::assert_eq("f(2)", js_string!("2")),
+        TestAction::assert_eq("f(3)", js_string!("d45")),
+        TestAction::assert_eq("f(4)", js_string!("45")),
+        TestAction::assert_eq("f(5)", js_string!("5")),
+        TestAction::assert_eq("f(6)", js_string!("d45")),
+        TestAction::assert_eq("f(1.0)", js_string!("12")),
+        TestAction::assert_eq("f(1.5)", js_string!("d45")),
+        TestAction::assert_eq("f(NaN)", js_string!("d45")),
+        TestAction::assert_eq("f('1')", js_string!("d45")),
+        TestAction::assert_eq("f(2 ** 31 + 1)", js_string!("d45")),
+    ]);
+}
+
+#[test]
+fn table_switch_negative_zero() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            function f(a) {
+                switch (a) {
+                    case 0: return "zero";
+                    case 1: return "one";
+                    case 2: return "two";
+                    case 3: return "three";
+                }
+                return "none";
+            }
+            [f(-0), f(0), f(3), f(-1)].join()
+        "#},
+        js_string!("zero,zero,three,none"),
+    )]);
+}
+
+#[test]
+fn table_switch_without_default() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            let log = [];
+            switch ((log.push("discriminant"), 10)) {
+                case 1:
+                case 2:
+                case 3:
+                case 4:
+                    log.push("case");
+            }
+            log.push("end");
+            log.join()
+        "#},
+        js_string!("discriminant,end"),
+    )]);
+}
+
+#[test]
+fn string_table_switch() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function f(a) {
+                    let b = "";
+
+                    switch (a) {
+                        case "add":
+                            b += "+";
+                        case "sub":
+                            b += "-";
+                            break;
+                        case "mul":
+                            b += "*";
+                            break;
+                        default:
+                            b += "?";
+                        case "div":
+                            b += "/";
+                            break;
+                        case "add":
+                            b += "unreachable";
+                    }
+                    return b;
+                }
+            "#}),
+        TestAction::assert_eq("f('add')", js_string!("+-")),
+        TestAction::assert_eq("f('sub')", js_string!("-")),
+        TestAction::assert_eq("f('mul')", js_string!("*")),
+        TestAction::assert_eq("f('div')", js_string!("/")),
+        TestAction::assert_eq("f('mod')", js_string!("?/")),
+        TestAction::assert_eq("f(new String('add'))", js_string!("?/")),
+        TestAction::assert_eq("f(1)", js_string!("?/")),
+    ]);
+}
+
 #[test]
 fn break_labelled_if_statement() {
     run_test_actions([TestAction::assert_eq(
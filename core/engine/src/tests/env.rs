@@ -46,3 +46,130 @@ fn with_env_not_panic() {
         "k is not defined",
     )]);
 }
+
+#[test]
+fn static_bindings_per_iteration_closures() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                var fns = [];
+                for (let i = 0; i < 3; i++) {
+                    fns.push(() => i++);
+                    i++;
+                }
+                fns.map(f => f()).join();
+            "#},
+            js_string!("1,3"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                function counter() {
+                    let count = 0;
+                    return { inc() { count += 1; return count; }, get: () => count };
+                }
+                let c = counter();
+                c.inc();
+                c.inc();
+                c.get();
+            "#},
+            2,
+        ),
+    ]);
+}
+
+#[test]
+fn static_bindings_shadowed_by_eval() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function() {
+                    let x = "outer";
+                    function inner() {
+                        let get = () => x;
+                        eval("var x = 'eval'");
+                        return get();
+                    }
+                    return inner() + "," + x;
+                })();
+            "#},
+            js_string!("eval,outer"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function() {
+                    let x = "outer";
+                    function inner() {
+                        "use strict";
+                        eval("var x = 'eval'");
+                        return x;
+                    }
+                    return inner();
+                })();
+            "#},
+            js_string!("outer"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                (function f() {
+                    eval("var f = 'eval'");
+                    return f;
+                })();
+            "#},
+            js_string!("eval"),
+        ),
+    ]);
+}
+
+#[test]
+fn static_bindings_shadowed_by_with() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+                (function() {
+                    let y = "let";
+                    let o = { y: "with" };
+                    with (o) {
+                        var result = (() => y)();
+                        y = "assigned";
+                    }
+                    return [result, y, o.y].join();
+                })();
+            "#},
+        js_string!("with,let,assigned"),
+    )]);
+}
+
+#[test]
+fn static_bindings_tdz() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            indoc! {r#"
+                (function() {
+                    x;
+                    let x;
+                })();
+            "#},
+            JsNativeErrorKind::Reference,
+            "x is not defined",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                (function() {
+                    x += 1;
+                    let x = 0;
+                })();
+            "#},
+            JsNativeErrorKind::Reference,
+            "x is not defined",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                (function() {
+                    x = 1;
+                    let x;
+                })();
+            "#},
+            JsNativeErrorKind::Reference,
+            "cannot assign to uninitialized binding `x`",
+        ),
+    ]);
+}
@@ -1,6 +1,6 @@
 use indoc::indoc;
 
-use crate::{run_test_actions, TestAction};
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
 
 #[test]
 fn iterator_close_in_continue_before_jobs() {
@@ -220,3 +220,55 @@ fn mixed_iterators_close_in_continue() {
         "#}),
     ]);
 }
+
+#[test]
+fn for_of_array_fast_path() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var holes = [];
+            for (const x of [1, , 3]) holes.push(x);
+            Array.prototype[1] = "proto";
+            for (const x of [1, , 3]) holes.push(x);
+            delete Array.prototype[1];
+
+            var mutated = [];
+            var array = [1, 2, 3];
+            for (const x of array) {
+                mutated.push(x);
+                if (x === 1) {
+                    array.push(4);
+                    array[6] = "sparse";
+                }
+            }
+
+            var patched = [];
+            var iterator = Array.prototype[Symbol.iterator];
+            Array.prototype[Symbol.iterator] = function* () { yield "patched"; };
+            for (const x of [1, 2]) patched.push(x);
+            Array.prototype[Symbol.iterator] = iterator;
+
+            var ArrayIteratorPrototype = Object.getPrototypeOf([][Symbol.iterator]());
+            var next = ArrayIteratorPrototype.next;
+            ArrayIteratorPrototype.next = function () {
+                var result = next.call(this);
+                if (!result.done) result.value *= 10;
+                return result;
+            };
+            for (const x of [1, 2]) patched.push(x);
+            ArrayIteratorPrototype.next = next;
+
+            var entries = [];
+            for (const [key, value] of ["a", "b"].entries()) entries.push(key + value);
+        "#}),
+        TestAction::assert("arrayEquals(holes, [1, undefined, 3, 1, 'proto', 3])"),
+        TestAction::assert("arrayEquals(mutated, [1, 2, 3, 4, undefined, undefined, 'sparse'])"),
+        TestAction::assert("arrayEquals(patched, ['patched', 10, 20])"),
+        TestAction::assert("arrayEquals(entries, ['0a', '1b'])"),
+        TestAction::assert_native_error(
+            "for (const x of { [Symbol.iterator]() { return { next }; } }) {}",
+            JsNativeErrorKind::Type,
+            "`this` is not an ArrayIterator",
+        ),
+    ]);
+}
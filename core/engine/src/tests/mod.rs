@@ -1,11 +1,13 @@
 use indoc::indoc;
 
+mod class;
 mod control_flow;
 mod env;
 mod function;
 mod iterators;
 mod operators;
 mod promise;
+mod realm;
 mod spread;
 
 use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
@@ -500,3 +502,33 @@ fn null_bool_in_object_pattern() {
         TestAction::assert_eq("c", 100),
     ]);
 }
+
+#[test]
+fn syntax_error_includes_source_path() {
+    use crate::{Context, Source};
+    use std::path::Path;
+
+    let context = &mut Context::default();
+    let path = Path::new("script.js");
+
+    let err = context
+        .eval(Source::from_reader(
+            "let a = 5;\nlet b = );".as_bytes(),
+            Some(path),
+        ))
+        .unwrap_err();
+    let err = err.as_native().expect("must be a native error");
+    assert_eq!(err.kind, JsNativeErrorKind::Syntax);
+    assert_eq!(
+        err.message(),
+        "unexpected token ')', primary expression at script.js:2:9"
+    );
+
+    let err = context
+        .eval(Source::from_bytes("let a = 5;\nlet b = );"))
+        .unwrap_err();
+    assert_eq!(
+        err.as_native().expect("must be a native error").message(),
+        "unexpected token ')', primary expression at line 2, col 9"
+    );
+}
@@ -643,4 +643,36 @@ mod in_operator {
             "right-hand side of 'in' should be an object, got `undefined`",
         )]);
     }
+
+    #[test]
+    fn private_name_in_object() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                class Point {
+                    #x = 0;
+                    #y() {}
+                    static is(candidate) {
+                        return #x in candidate && #y in candidate;
+                    }
+                }
+            "#}),
+            TestAction::assert("Point.is(new Point())"),
+            TestAction::assert("!Point.is({ x: 0, y() {} })"),
+            TestAction::assert("!Point.is(Object.create(Point.prototype))"),
+            TestAction::assert_native_error(
+                "Point.is(1)",
+                JsNativeErrorKind::Type,
+                "right-hand side of 'in' should be an object, got `number`",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn private_name_in_object_outside_class() {
+        run_test_actions([TestAction::assert_native_error(
+            "function f(o) { return #x in o; }",
+            JsNativeErrorKind::Syntax,
+            "invalid private identifier usage at line 1, col 1",
+        )]);
+    }
 }
@@ -0,0 +1,31 @@
+use crate::{
+    js_string, object::JsObject, property::Attribute, run_test_actions, Source, TestAction,
+};
+
+#[test]
+fn cross_realm_instanceof() {
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            let realm = ctx.create_realm().unwrap();
+            let foreign = ctx
+                .eval_in_realm(&realm, Source::from_bytes("var local = []; local"))
+                .unwrap();
+
+            assert!(!JsObject::equals(
+                &realm.intrinsics().constructors().array().prototype(),
+                &ctx.intrinsics().constructors().array().prototype(),
+            ));
+            assert!(realm
+                .global_object()
+                .has_own_property(js_string!("local"), ctx)
+                .unwrap());
+
+            ctx.register_global_property(js_string!("foreign"), foreign, Attribute::all())
+                .unwrap();
+        }),
+        TestAction::assert_eq("typeof local", js_string!("undefined")),
+        TestAction::assert("!(foreign instanceof Array)"),
+        TestAction::assert("Array.isArray(foreign)"),
+        TestAction::assert("Object.getPrototypeOf(foreign) !== Array.prototype"),
+    ]);
+}
@@ -473,7 +473,7 @@ impl JsValue {
             Self::Undefined => Ok("undefined".into()),
             Self::Boolean(boolean) => Ok(boolean.to_string().into()),
             Self::Rational(rational) => Ok(Number::to_js_string(*rational)),
-            Self::Integer(integer) => Ok(integer.to_string().into()),
+            Self::Integer(integer) => Ok(Number::integer_to_js_string(i64::from(*integer))),
             Self::String(string) => Ok(string.clone()),
             Self::Symbol(_) => Err(JsNativeError::typ()
                 .with_message("can't convert symbol to string")
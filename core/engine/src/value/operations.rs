@@ -4,7 +4,7 @@ use crate::{
         Number,
     },
     error::JsNativeError,
-    js_string,
+    string::JsString,
     value::{JsSymbol, Numeric, PreferredType},
     Context, JsBigInt, JsResult, JsValue,
 };
@@ -24,15 +24,21 @@ impl JsValue {
             (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::add(x, y)),
 
             // String concat
-            (Self::String(ref x), Self::String(ref y)) => Self::from(js_string!(x, y)),
+            (Self::String(ref x), Self::String(ref y)) => Self::from(JsString::concat_lazy(x, y)),
 
             // Slow path:
             (_, _) => match (
                 self.to_primitive(context, PreferredType::Default)?,
                 other.to_primitive(context, PreferredType::Default)?,
             ) {
-                (Self::String(ref x), ref y) => Self::from(js_string!(x, &y.to_string(context)?)),
-                (ref x, Self::String(ref y)) => Self::from(js_string!(&x.to_string(context)?, y)),
+                (Self::String(ref x), ref y) => {
+                    let y = y.to_string(context)?;
+                    Self::from(JsString::concat_lazy(x, &y))
+                }
+                (ref x, Self::String(ref y)) => {
+                    let x = x.to_string(context)?;
+                    Self::from(JsString::concat_lazy(&x, y))
+                }
                 (x, y) => match (x.to_numeric(context)?, y.to_numeric(context)?) {
                     (Numeric::Number(x), Numeric::Number(y)) => Self::new(x + y),
                     (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => {
@@ -80,8 +86,10 @@ impl JsValue {
     pub fn mul(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
         Ok(match (self, other) {
             // Fast path:
+            // A zero product of a negative operand is `-0`, which is not representable as an integer.
             (Self::Integer(x), Self::Integer(y)) => x
                 .checked_mul(*y)
+                .filter(|mul| *mul != 0 || (*x >= 0 && *y >= 0))
                 .map_or_else(|| Self::new(f64::from(*x) * f64::from(*y)), Self::new),
             (Self::Rational(x), Self::Rational(y)) => Self::new(x * y),
             (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x) * y),
@@ -108,7 +116,7 @@ impl JsValue {
             // Fast path:
             (Self::Integer(x), Self::Integer(y)) => x
                 .checked_div(*y)
-                .filter(|div| *y * div == *x)
+                .filter(|div| *y * div == *x && (*div != 0 || *y > 0))
                 .map_or_else(|| Self::new(f64::from(*x) / f64::from(*y)), Self::new),
             (Self::Rational(x), Self::Rational(y)) => Self::new(x / y),
             (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x) / y),
@@ -151,7 +159,7 @@ impl JsValue {
                 if *y == 0 {
                     Self::nan()
                 } else {
-                    match x % *y {
+                    match x.wrapping_rem(*y) {
                         rem if rem == 0 && *x < 0 => Self::new(-0.0),
                         rem => Self::new(rem),
                     }
@@ -478,9 +486,11 @@ impl JsValue {
             Self::String(ref str) => Self::new(-str.to_number()),
             Self::Rational(num) => Self::new(-num),
             Self::Integer(0) => Self::new(-f64::from(0)),
-            Self::Integer(num) => Self::new(-num),
-            Self::Boolean(true) => Self::new(1),
-            Self::Boolean(false) | Self::Null => Self::new(0),
+            Self::Integer(num) => num
+                .checked_neg()
+                .map_or_else(|| Self::new(-f64::from(num)), Self::new),
+            Self::Boolean(true) => Self::new(-1),
+            Self::Boolean(false) | Self::Null => Self::new(-0.0),
             Self::BigInt(ref x) => Self::new(JsBigInt::neg(x)),
         })
     }
@@ -0,0 +1,739 @@
+//! Serialization of compiled [`CodeBlock`]s.
+//!
+//! This makes it possible to cache the code blocks of a script, and to skip parsing and compiling
+//! it the next time it is run. Interned strings are resolved to their text, so a cache can be
+//! loaded in any [`Context`].
+//!
+//! The format is private to the Boa version that wrote it: every cache starts with a hash of the
+//! format, the engine version and the opcode table, followed by a hash of its contents, and caches
+//! with a different format or with corrupted contents are rejected.
+
+use std::{
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use boa_ast::{Position, Span};
+use boa_gc::Gc;
+use boa_parser::source::SourceText;
+use rustc_hash::{FxHashMap, FxHasher};
+use thin_vec::ThinVec;
+
+use crate::{
+    builtins::function::{OrdinaryFunction, ThisMode},
+    environments::{BindingLocator, CompileTimeEnvironment},
+    Context, JsBigInt, JsError, JsNativeError, JsResult, JsString,
+};
+
+use super::{
+    create_function_object_fast, ActiveRunnable, CodeBlock, CodeBlockFlags, Constant, Handler,
+    InlineCache, Instruction, InstructionIterator, Opcode,
+};
+
+/// The magic number at the start of every cache.
+const MAGIC: [u8; 4] = *b"BOAC";
+
+/// The version of the cache format, which must be bumped whenever the format changes.
+const FORMAT_VERSION: u32 = 1;
+
+/// Computes the hash of the format of the caches written by this build of the engine.
+///
+/// The bytecode is stored as is, so the hash also covers the opcode table and the features that
+/// change the output of the compiler.
+fn format_hash() -> u64 {
+    let mut hasher = FxHasher::default();
+    FORMAT_VERSION.hash(&mut hasher);
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    cfg!(target_endian = "little").hash(&mut hasher);
+    cfg!(feature = "annex-b").hash(&mut hasher);
+    for opcode in 0..=u8::MAX {
+        Opcode::from(opcode).as_str().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Computes the hash of the contents of a cache.
+fn contents_hash(bytes: &[u8]) -> u64 {
+    let mut hasher = FxHasher::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+fn invalid_cache() -> JsError {
+    JsNativeError::error()
+        .with_message("invalid bytecode cache")
+        .into()
+}
+
+/// The global declarations of a script.
+///
+/// They are recorded while compiling the `GlobalDeclarationInstantiation` of the script, so they
+/// can be replayed on the realm that loads the script from a cache.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GlobalDeclarations {
+    /// The lexically declared names, and whether they are constant declarations.
+    pub(crate) lexical: Vec<(JsString, bool)>,
+
+    /// The var declared names, including the names of the functions.
+    pub(crate) var_names: Vec<JsString>,
+
+    /// The functions to initialize, with the index of their code block in the constants of the
+    /// script.
+    pub(crate) functions: Vec<(JsString, u32)>,
+
+    /// The declared var names, excluding the names of the functions.
+    pub(crate) vars: Vec<JsString>,
+
+    /// The names of the functions declared in blocks that may get a var binding.
+    pub(crate) annex_b_functions: Vec<JsString>,
+}
+
+impl GlobalDeclarations {
+    /// Performs the checks of `GlobalDeclarationInstantiation` and creates the global lexical
+    /// bindings of the script, which must exist before its code blocks can be linked.
+    fn declare(&self, env: &Rc<CompileTimeEnvironment>, context: &mut Context) -> JsResult<()> {
+        for (name, _) in &self.lexical {
+            if env.has_binding(name) {
+                return Err(JsNativeError::syntax()
+                    .with_message("duplicate lexical declaration")
+                    .into());
+            }
+            if context.has_restricted_global_property(name)? {
+                return Err(JsNativeError::syntax()
+                    .with_message("cannot redefine non-configurable global property")
+                    .into());
+            }
+        }
+
+        for name in &self.var_names {
+            if env.has_lex_binding(name) {
+                return Err(JsNativeError::syntax()
+                    .with_message("duplicate lexical declaration")
+                    .into());
+            }
+        }
+
+        for (name, _) in &self.functions {
+            if !context.can_declare_global_function(name)? {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot declare global function")
+                    .into());
+            }
+        }
+
+        for name in &self.vars {
+            if !context.can_declare_global_var(name)? {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot declare global variable")
+                    .into());
+            }
+        }
+
+        #[cfg(feature = "annex-b")]
+        {
+            let mut declared = self
+                .functions
+                .iter()
+                .map(|(name, _)| name)
+                .chain(&self.vars)
+                .cloned()
+                .collect::<Vec<_>>();
+            for name in &self.annex_b_functions {
+                if !env.has_lex_binding(name)
+                    && context.can_declare_global_function(name)?
+                    && !declared.contains(name)
+                {
+                    context.create_global_var_binding(name.clone(), false)?;
+                    declared.push(name.clone());
+                }
+            }
+        }
+
+        for (name, constant) in &self.lexical {
+            if *constant {
+                env.create_immutable_binding(name.clone(), true);
+            } else {
+                env.create_mutable_binding(name.clone(), false);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Instantiates the global functions and creates the global var bindings of the script.
+    fn instantiate(
+        &self,
+        code: &CodeBlock,
+        script_or_module: Option<&ActiveRunnable>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        for (name, index) in &self.functions {
+            let function =
+                create_function_object_fast(code.constant_function(*index as usize), context);
+            if let Some(script_or_module) = script_or_module {
+                if let Some(mut function) = function.downcast_mut::<OrdinaryFunction>() {
+                    function.script_or_module = Some(script_or_module.clone());
+                }
+            }
+            context.create_global_function_binding(name.clone(), function, false)?;
+        }
+
+        for name in &self.vars {
+            context.create_global_var_binding(name.clone(), false)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl CodeBlock {
+    /// Serializes this code block, its constants and the code blocks of its nested functions.
+    ///
+    /// The bytes can be loaded with [`CodeBlock::from_bytes`] by the same version of Boa.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = Writer::default();
+        writer.option(
+            self.global_declarations.as_deref(),
+            Writer::global_declarations,
+        );
+        writer.code_block(self);
+        let source_text = writer.source_text.take();
+
+        let mut contents = Writer::default();
+        contents.option(source_text.as_deref(), |w, text| {
+            w.u16s(text.as_slice());
+        });
+        contents.bytes.extend(writer.bytes);
+
+        let mut bytes = Vec::with_capacity(contents.bytes.len() + 20);
+        bytes.extend(MAGIC);
+        bytes.extend(format_hash().to_le_bytes());
+        bytes.extend(contents_hash(&contents.bytes).to_le_bytes());
+        bytes.extend(contents.bytes);
+        bytes
+    }
+
+    /// Loads a code block serialized by [`CodeBlock::to_bytes`] in the current realm.
+    ///
+    /// The bindings of the code block are linked to the global environment of the realm. If the
+    /// code block is the code of a script, this also performs the global declarations of the
+    /// script: its global lexical bindings are created, and its global functions are instantiated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes were written by a different version of Boa or are corrupted,
+    /// or if the global declarations of the script conflict with the declarations of the realm.
+    pub fn from_bytes(bytes: &[u8], context: &mut Context) -> JsResult<Gc<Self>> {
+        Self::load(bytes, None, context)
+    }
+
+    /// Loads a serialized code block, associating the global functions of the script with
+    /// `script_or_module`.
+    pub(crate) fn load(
+        bytes: &[u8],
+        script_or_module: Option<&ActiveRunnable>,
+        context: &mut Context,
+    ) -> JsResult<Gc<Self>> {
+        let mut reader = Reader::new(contents(bytes).ok_or_else(invalid_cache)?);
+        let source_text = reader
+            .option(|r| Ok(SourceText::from_utf16(&r.u16s()?)))?
+            .map(Rc::new);
+        let declarations = reader.option(Reader::global_declarations)?;
+
+        let global_environment = context.realm().environment().compile_env();
+        if let Some(declarations) = &declarations {
+            declarations.declare(&global_environment, context)?;
+        }
+
+        let mut loader = Loader {
+            reader,
+            global_environment,
+            environments: Vec::new(),
+            source_text,
+            site_identifier: context.next_parser_identifier(),
+        };
+        let mut code = loader.code_block()?;
+        if !loader.reader.is_empty() {
+            return Err(invalid_cache());
+        }
+        code.global_declarations = declarations.map(Box::new);
+
+        let code = Gc::new(code);
+        if let Some(declarations) = &code.global_declarations {
+            declarations.instantiate(&code, script_or_module, context)?;
+        }
+
+        Ok(code)
+    }
+}
+
+/// Returns the contents of a valid cache.
+fn contents(bytes: &[u8]) -> Option<&[u8]> {
+    let mut reader = Reader::new(bytes);
+    let magic = reader.take(MAGIC.len()).ok()?;
+    let format = reader.u64().ok()?;
+    let hash = reader.u64().ok()?;
+    (magic == MAGIC && format == format_hash() && hash == contents_hash(reader.bytes))
+        .then_some(reader.bytes)
+}
+
+/// Checks if `bytes` is a valid cache of the code of `source`.
+pub(crate) fn is_cache_of(bytes: &[u8], source: &str) -> bool {
+    let Some(contents) = contents(bytes) else {
+        return false;
+    };
+    let mut reader = Reader::new(contents);
+    matches!(
+        reader.option(Reader::u16s),
+        Ok(Some(text)) if text.iter().copied().eq(source.encode_utf16())
+    )
+}
+
+#[derive(Default)]
+struct Writer {
+    bytes: Vec<u8>,
+
+    /// The identifiers of the compile time environments that were written.
+    environments: FxHashMap<*const CompileTimeEnvironment, u32>,
+
+    /// The source text of the code blocks, which is written only once.
+    source_text: Option<Rc<SourceText>>,
+}
+
+impl Writer {
+    fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.u8(value.into());
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.bytes.extend(value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend(value.to_le_bytes());
+    }
+
+    fn len(&mut self, len: usize) {
+        self.u32(u32::try_from(len).expect("cannot serialize more than u32::MAX elements"));
+    }
+
+    fn u16s(&mut self, value: &[u16]) {
+        self.len(value.len());
+        for unit in value {
+            self.u16(*unit);
+        }
+    }
+
+    fn string(&mut self, value: &JsString) {
+        self.u16s(&value.to_vec());
+    }
+
+    fn option<T: ?Sized>(&mut self, value: Option<&T>, write: impl FnOnce(&mut Self, &T)) {
+        self.bool(value.is_some());
+        if let Some(value) = value {
+            write(self, value);
+        }
+    }
+
+    fn list<T>(&mut self, values: &[T], mut write: impl FnMut(&mut Self, &T)) {
+        self.len(values.len());
+        for value in values {
+            write(self, value);
+        }
+    }
+
+    fn position(&mut self, position: Position) {
+        self.u32(position.line_number());
+        self.u32(position.column_number());
+    }
+
+    fn global_declarations(&mut self, declarations: &GlobalDeclarations) {
+        self.list(&declarations.lexical, |w, (name, constant)| {
+            w.string(name);
+            w.bool(*constant);
+        });
+        self.list(&declarations.var_names, Self::string);
+        self.list(&declarations.functions, |w, (name, index)| {
+            w.string(name);
+            w.u32(*index);
+        });
+        self.list(&declarations.vars, Self::string);
+        self.list(&declarations.annex_b_functions, Self::string);
+    }
+
+    /// Writes a compile time environment.
+    ///
+    /// Environments are written the first time they are referenced, after their outer
+    /// environments, and later references only write their identifier. The global environment is
+    /// not written, since it is the global environment of the realm that loads the cache.
+    fn environment(&mut self, env: &Rc<CompileTimeEnvironment>) {
+        let Some(outer) = env.outer() else {
+            self.u8(0);
+            return;
+        };
+        if let Some(&id) = self.environments.get(&Rc::as_ptr(env)) {
+            self.u8(1);
+            self.u32(id);
+            return;
+        }
+
+        self.u8(2);
+        self.environment(&outer);
+        self.bool(env.is_function());
+        self.bool(env.is_dynamic());
+        self.list(&env.bindings(), |w, (name, index, mutable, lex, strict)| {
+            w.string(name);
+            w.u32(*index);
+            w.bool(*mutable);
+            w.bool(*lex);
+            w.bool(*strict);
+        });
+
+        let id = self.environments.len() as u32;
+        self.environments.insert(Rc::as_ptr(env), id);
+    }
+
+    fn code_block(&mut self, code: &CodeBlock) {
+        self.string(&code.name);
+        self.u16(code.flags.get().bits());
+        self.u32(code.length);
+        self.u32(code.register_count);
+        self.u8(match code.this_mode {
+            ThisMode::Lexical => 0,
+            ThisMode::Strict => 1,
+            ThisMode::Global => 2,
+        });
+        self.u32(code.parameter_count);
+        self.list(&code.parameter_names, Self::string);
+        self.len(code.bytecode.len());
+        self.bytes.extend_from_slice(&code.bytecode);
+        self.list(&code.constants, |w, constant| match constant {
+            Constant::String(string) => {
+                w.u8(0);
+                w.string(string);
+            }
+            Constant::Function(function) => {
+                w.u8(1);
+                w.code_block(function);
+            }
+            Constant::BigInt(bigint) => {
+                w.u8(2);
+                w.string(&bigint.to_string_radix(16).into());
+            }
+            Constant::CompileTimeEnvironment(env) => {
+                w.u8(3);
+                w.environment(env);
+            }
+        });
+        self.list(&code.bindings, |w, binding| {
+            w.string(binding.name());
+            w.u32(binding.environment_index());
+            w.u32(binding.binding_index());
+            w.bool(binding.is_global());
+        });
+        self.list(&code.handlers, |w, handler| {
+            w.u32(handler.start);
+            w.u32(handler.end);
+            w.u32(handler.stack_count);
+            w.u32(handler.environment_count);
+        });
+        self.list(&code.positions, |w, (pc, position)| {
+            w.u32(*pc);
+            w.position(*position);
+        });
+        self.list(&code.ic, |w, ic| w.string(&ic.name));
+        self.bool(code.source_text.is_some());
+        if self.source_text.is_none() {
+            self.source_text.clone_from(&code.source_text);
+        }
+        self.option(code.source_span.as_ref(), |w, span| {
+            w.position(span.start());
+            w.position(span.end());
+        });
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take(&mut self, len: usize) -> JsResult<&'a [u8]> {
+        if len > self.bytes.len() {
+            return Err(invalid_cache());
+        }
+        let (bytes, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> JsResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn bool(&mut self) -> JsResult<bool> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_cache()),
+        }
+    }
+
+    fn u16(&mut self) -> JsResult<u16> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> JsResult<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn u64(&mut self) -> JsResult<u64> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn len(&mut self) -> JsResult<usize> {
+        self.u32().map(|len| len as usize)
+    }
+
+    fn u16s(&mut self) -> JsResult<Vec<u16>> {
+        let len = self.len()?;
+        Ok(self
+            .take(len.checked_mul(2).ok_or_else(invalid_cache)?)?
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect())
+    }
+
+    fn string(&mut self) -> JsResult<JsString> {
+        Ok(JsString::from(&self.u16s()?[..]))
+    }
+
+    fn option<T>(&mut self, read: impl FnOnce(&mut Self) -> JsResult<T>) -> JsResult<Option<T>> {
+        if self.bool()? {
+            read(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn list<T, C: FromIterator<T>>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> JsResult<T>,
+    ) -> JsResult<C> {
+        let len = self.len()?;
+        (0..len).map(|_| read(self)).collect()
+    }
+
+    fn position(&mut self) -> JsResult<Position> {
+        let line = self.u32()?;
+        let column = self.u32()?;
+        if line == 0 || column == 0 {
+            return Err(invalid_cache());
+        }
+        Ok(Position::new(line, column))
+    }
+
+    fn global_declarations(&mut self) -> JsResult<GlobalDeclarations> {
+        Ok(GlobalDeclarations {
+            lexical: self.list(|r| Ok((r.string()?, r.bool()?)))?,
+            var_names: self.list(Self::string)?,
+            functions: self.list(|r| Ok((r.string()?, r.u32()?)))?,
+            vars: self.list(Self::string)?,
+            annex_b_functions: self.list(Self::string)?,
+        })
+    }
+}
+
+/// Reads the code blocks of a cache, linking them to the realm that loads them.
+struct Loader<'a> {
+    reader: Reader<'a>,
+
+    /// The global environment of the realm.
+    global_environment: Rc<CompileTimeEnvironment>,
+
+    /// The compile time environments that were read, by identifier.
+    environments: Vec<Rc<CompileTimeEnvironment>>,
+
+    /// The source text of the code blocks.
+    source_text: Option<Rc<SourceText>>,
+
+    /// The parser identifier of the tagged templates of the code blocks.
+    ///
+    /// The identifiers of tagged templates must be unique in a realm, so the identifier of the
+    /// context that compiled the code is replaced by a fresh one.
+    site_identifier: u32,
+}
+
+impl Loader<'_> {
+    fn environment(&mut self) -> JsResult<Rc<CompileTimeEnvironment>> {
+        match self.reader.u8()? {
+            0 => Ok(self.global_environment.clone()),
+            1 => {
+                let id = self.reader.len()?;
+                self.environments.get(id).cloned().ok_or_else(invalid_cache)
+            }
+            2 => {
+                let outer = self.environment()?;
+                let function_scope = self.reader.bool()?;
+                let dynamic = self.reader.bool()?;
+                let bindings = self
+                    .reader
+                    .list(|r| Ok((r.string()?, r.u32()?, r.bool()?, r.bool()?, r.bool()?)))?;
+                let env = Rc::new(CompileTimeEnvironment::with_bindings(
+                    outer,
+                    function_scope,
+                    dynamic,
+                    bindings,
+                ));
+                self.environments.push(env.clone());
+                Ok(env)
+            }
+            _ => Err(invalid_cache()),
+        }
+    }
+
+    /// Reads a binding locator.
+    ///
+    /// Declarative bindings of the global environment are located by their name, because their
+    /// indices depend on the scripts that were run in the realm before.
+    fn binding(&mut self) -> JsResult<BindingLocator> {
+        let name = self.reader.string()?;
+        let environment_index = self.reader.u32()?;
+        let binding_index = self.reader.u32()?;
+        if self.reader.bool()? {
+            return Ok(BindingLocator::global(name));
+        }
+        if environment_index == 0 {
+            return Ok(self
+                .global_environment
+                .get_binding(&name)
+                .unwrap_or_else(|| BindingLocator::global(name)));
+        }
+        Ok(BindingLocator::declarative(
+            name,
+            environment_index,
+            binding_index,
+        ))
+    }
+
+    /// Reads the instruction stream of a code block.
+    fn bytecode(&mut self) -> JsResult<Box<[u8]>> {
+        let len = self.reader.len()?;
+        let mut bytecode = self.reader.take(len)?.to_vec();
+
+        let mut sites = Vec::new();
+        let mut iterator = InstructionIterator::new(&bytecode);
+        while let Some((_, _, instruction)) = iterator.next() {
+            if matches!(
+                instruction,
+                Instruction::TemplateLookup { .. } | Instruction::TemplateCreate { .. }
+            ) {
+                // The site is the last operand of the instruction.
+                sites.push(iterator.pc() - 8);
+            }
+        }
+        for offset in sites {
+            let bytes = &mut bytecode[offset..offset + 8];
+            let mut site = [0; 8];
+            site.copy_from_slice(bytes);
+            let site =
+                (u64::from_ne_bytes(site) & !u64::from(u32::MAX)) | u64::from(self.site_identifier);
+            bytes.copy_from_slice(&site.to_ne_bytes());
+        }
+
+        Ok(bytecode.into_boxed_slice())
+    }
+
+    fn code_block(&mut self) -> JsResult<CodeBlock> {
+        let name = self.reader.string()?;
+        let flags = CodeBlockFlags::from_bits(self.reader.u16()?).ok_or_else(invalid_cache)?;
+        let length = self.reader.u32()?;
+        let register_count = self.reader.u32()?;
+        let this_mode = match self.reader.u8()? {
+            0 => ThisMode::Lexical,
+            1 => ThisMode::Strict,
+            2 => ThisMode::Global,
+            _ => return Err(invalid_cache()),
+        };
+        let parameter_count = self.reader.u32()?;
+        let parameter_names = self.reader.list(Reader::string)?;
+        let bytecode = self.bytecode()?;
+
+        let len = self.reader.len()?;
+        let mut constants = ThinVec::with_capacity(len);
+        for _ in 0..len {
+            constants.push(match self.reader.u8()? {
+                0 => Constant::String(self.reader.string()?),
+                1 => Constant::Function(Gc::new(self.code_block()?)),
+                2 => Constant::BigInt(
+                    JsBigInt::from_string_radix(&self.reader.string()?.to_std_string_escaped(), 16)
+                        .ok_or_else(invalid_cache)?,
+                ),
+                3 => Constant::CompileTimeEnvironment(self.environment()?),
+                _ => return Err(invalid_cache()),
+            });
+        }
+
+        let len = self.reader.len()?;
+        let bindings = (0..len).map(|_| self.binding()).collect::<JsResult<_>>()?;
+        let handlers = self.reader.list(|r| {
+            Ok(Handler {
+                start: r.u32()?,
+                end: r.u32()?,
+                stack_count: r.u32()?,
+                environment_count: r.u32()?,
+            })
+        })?;
+        let positions = self.reader.list(|r| Ok((r.u32()?, r.position()?)))?;
+        let ic = self.reader.list(|r| Ok(InlineCache::new(r.string()?)))?;
+        let source_text = if self.reader.bool()? {
+            Some(self.source_text.clone().ok_or_else(invalid_cache)?)
+        } else {
+            None
+        };
+        let source_span = self.reader.option(|r| {
+            let start = r.position()?;
+            let end = r.position()?;
+            if start > end {
+                return Err(invalid_cache());
+            }
+            Ok(Span::new(start, end))
+        })?;
+
+        Ok(CodeBlock {
+            name,
+            flags: flags.into(),
+            length,
+            register_count,
+            this_mode,
+            parameter_count,
+            parameter_names,
+            bytecode,
+            constants,
+            bindings,
+            handlers,
+            positions,
+            ic,
+            source_text,
+            source_span,
+            global_declarations: None,
+        })
+    }
+}
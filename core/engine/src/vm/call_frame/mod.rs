@@ -4,6 +4,7 @@
 
 use crate::{
     builtins::{
+        disposable_stack::DisposeCapability,
         iterable::IteratorRecord,
         promise::{PromiseCapability, ResolvingFunctions},
     },
@@ -11,9 +12,10 @@ use crate::{
     object::{JsFunction, JsObject},
     realm::Realm,
     vm::CodeBlock,
-    JsValue,
+    JsError, JsValue,
 };
 use boa_gc::{Finalize, Gc, Trace};
+use std::{path::Path, rc::Rc};
 use thin_vec::ThinVec;
 
 use super::{ActiveRunnable, Vm};
@@ -51,6 +53,9 @@ pub struct CallFrame {
     // Iterators and their `[[Done]]` flags that must be closed when an abrupt completion is thrown.
     pub(crate) iterators: ThinVec<IteratorRecord>,
 
+    // The resources of the `using` declarations that must be disposed when their scope is exited.
+    pub(crate) disposables: ThinVec<DisposeScope>,
+
     // The stack of bindings being updated.
     pub(crate) binding_stack: Vec<BindingLocator>,
 
@@ -71,6 +76,14 @@ pub struct CallFrame {
     pub(crate) flags: CallFrameFlags,
 }
 
+/// The resources added by the `using` declarations of a block, and the error thrown while
+/// evaluating or disposing them, if any.
+#[derive(Clone, Debug, Default, Finalize, Trace)]
+pub(crate) struct DisposeScope {
+    pub(crate) capability: DisposeCapability,
+    pub(crate) error: Option<JsError>,
+}
+
 /// ---- `CallFrame` public API ----
 impl CallFrame {
     /// Retrieves the [`CodeBlock`] of this call frame.
@@ -79,6 +92,26 @@ impl CallFrame {
     pub const fn code_block(&self) -> &Gc<CodeBlock> {
         &self.code_block
     }
+
+    /// Retrieves the path of the source that contains the code of this call frame, if any.
+    ///
+    /// This is the path of the [`Source`][crate::Source] that the active script or module was
+    /// parsed from.
+    #[must_use]
+    pub fn source_path(&self) -> Option<&Path> {
+        match self.active_runnable.as_ref()? {
+            ActiveRunnable::Script(script) => script.path(),
+            ActiveRunnable::Module(module) => module.path(),
+        }
+    }
+
+    /// Same as [`CallFrame::source_path`], but returns a shared reference to the path.
+    pub(crate) fn source_path_rc(&self) -> Option<Rc<Path>> {
+        match self.active_runnable.as_ref()? {
+            ActiveRunnable::Script(script) => script.path_rc(),
+            ActiveRunnable::Module(module) => module.path_rc(),
+        }
+    }
 }
 
 /// ---- `CallFrame` creation methods ----
@@ -153,6 +186,7 @@ impl CallFrame {
             env_fp: 0,
             argument_count: 0,
             iterators: ThinVec::new(),
+            disposables: ThinVec::new(),
             binding_stack: Vec::new(),
             loop_iteration_count: 0,
             active_runnable,
@@ -8,17 +8,20 @@ use crate::{
         OrdinaryObject,
     },
     environments::{BindingLocator, CompileTimeEnvironment},
-    object::JsObject,
+    object::{shape::WeakShape, JsObject},
     Context, JsBigInt, JsString, JsValue,
 };
 use bitflags::bitflags;
-use boa_ast::function::FormalParameterList;
+use boa_ast::{Position, Span};
 use boa_gc::{empty_trace, Finalize, Gc, Trace};
+use boa_parser::source::SourceText;
 use boa_profiler::Profiler;
 use std::{cell::Cell, fmt::Display, mem::size_of, rc::Rc};
 use thin_vec::ThinVec;
 
-use super::{InlineCache, Instruction, InstructionIterator};
+#[cfg(feature = "bytecode-cache")]
+use super::GlobalDeclarations;
+use super::{Disassembly, InlineCache, Instruction, InstructionIterator};
 
 /// This represents whether a value can be read from [`CodeBlock`] code.
 ///
@@ -67,8 +70,7 @@ bitflags! {
         /// Arrow and method functions don't have `"prototype"` property.
         const HAS_PROTOTYPE_PROPERTY = 0b1000_0000;
 
-        /// Trace instruction execution to `stdout`.
-        #[cfg(feature = "trace")]
+        /// The code block was marked to be traced.
         const TRACEABLE = 0b1000_0000_0000_0000;
     }
 }
@@ -142,9 +144,13 @@ pub struct CodeBlock {
     /// \[\[ThisMode\]\]
     pub(crate) this_mode: ThisMode,
 
-    /// Parameters passed to this function.
+    /// The number of formal parameters, including the rest parameter.
+    pub(crate) parameter_count: u32,
+
+    /// The bound names of the formal parameters if they are a simple parameter list, used to map
+    /// the `arguments` object to the parameter bindings.
     #[unsafe_ignore_trace]
-    pub(crate) params: FormalParameterList,
+    pub(crate) parameter_names: Box<[JsString]>,
 
     /// Bytecode
     #[unsafe_ignore_trace]
@@ -160,8 +166,26 @@ pub struct CodeBlock {
     #[unsafe_ignore_trace]
     pub(crate) handlers: ThinVec<Handler>,
 
+    /// The source position where each statement starts, as `(pc, position)` pairs sorted by
+    /// `pc`.
+    #[unsafe_ignore_trace]
+    pub(crate) positions: Box<[(u32, Position)]>,
+
     /// inline caching
     pub(crate) ic: Box<[InlineCache]>,
+
+    /// The source text of the script or module where the function was defined.
+    #[unsafe_ignore_trace]
+    pub(crate) source_text: Option<Rc<SourceText>>,
+
+    /// The span of the function in its source text.
+    #[unsafe_ignore_trace]
+    pub(crate) source_span: Option<Span>,
+
+    /// The global declarations of the script, if this is the code block of a script.
+    #[cfg(feature = "bytecode-cache")]
+    #[unsafe_ignore_trace]
+    pub(crate) global_declarations: Option<Box<GlobalDeclarations>>,
 }
 
 /// ---- `CodeBlock` public API ----
@@ -180,9 +204,15 @@ impl CodeBlock {
             length,
             register_count: 0,
             this_mode: ThisMode::Global,
-            params: FormalParameterList::default(),
+            parameter_count: 0,
+            parameter_names: Box::default(),
             handlers: ThinVec::default(),
+            positions: Box::default(),
             ic: Box::default(),
+            source_text: None,
+            source_span: None,
+            #[cfg(feature = "bytecode-cache")]
+            global_declarations: None,
         }
     }
 
@@ -192,13 +222,26 @@ impl CodeBlock {
         &self.name
     }
 
-    /// Check if the function is traced.
-    #[cfg(feature = "trace")]
-    pub(crate) fn traceable(&self) -> bool {
+    /// Disassembles the bytecode of this code block, and of the functions defined in it.
+    #[must_use]
+    pub fn disassemble(&self) -> Disassembly {
+        Disassembly::new(self)
+    }
+
+    /// Gets the source text of the function, if it is available.
+    pub(crate) fn source_text_slice(&self) -> Option<&[u16]> {
+        self.source_text.as_deref()?.slice(self.source_span?)
+    }
+
+    /// Check if the code block was marked to be traced with [`Self::set_traceable`].
+    #[must_use]
+    pub fn traceable(&self) -> bool {
         self.flags.get().contains(CodeBlockFlags::TRACEABLE)
     }
-    /// Enable or disable instruction tracing to `stdout`.
-    #[cfg(feature = "trace")]
+    /// Mark or unmark the code block to be traced.
+    ///
+    /// This doesn't trace anything by itself, but a [`Tracer`](crate::vm::Tracer) can check it
+    /// to only trace some functions.
     #[inline]
     pub fn set_traceable(&self, value: bool) {
         let mut flags = self.flags.get();
@@ -376,7 +419,7 @@ impl CodeBlock {
             }
             .to_owned(),
             Instruction::RotateLeft { n } | Instruction::RotateRight { n } => n.to_string(),
-            Instruction::Generator { r#async } => {
+            Instruction::Generator { r#async } | Instruction::AddDisposableResource { r#async } => {
                 format!("async: {async}")
             }
             Instruction::PushInt8 { value } => value.to_string(),
@@ -455,10 +498,12 @@ impl CodeBlock {
             | Instruction::DefInitVar { index }
             | Instruction::PutLexicalValue { index }
             | Instruction::GetName { index }
+            | Instruction::GetBinding { index }
             | Instruction::GetLocator { index }
             | Instruction::GetNameAndLocator { index }
             | Instruction::GetNameOrUndefined { index }
             | Instruction::SetName { index }
+            | Instruction::SetBinding { index }
             | Instruction::DeleteName { index } => {
                 format!(
                     "{:04}: '{}'",
@@ -504,7 +549,7 @@ impl CodeBlock {
                     "{:04}: '{}', Shape: 0x{:x}, Slot: index: {}, attributes {:?}",
                     index.value(),
                     ic.name.to_std_string_escaped(),
-                    ic.shape.borrow().to_addr_usize(),
+                    ic.shapes().last().map_or(0, WeakShape::to_addr_usize),
                     slot.index,
                     slot.attributes,
                 )
@@ -519,6 +564,34 @@ impl CodeBlock {
                 }
                 operands
             }
+            Instruction::SwitchInteger {
+                default,
+                min,
+                addresses,
+            } => {
+                let cases = addresses
+                    .iter()
+                    .enumerate()
+                    .map(|(i, address)| format!("{}: {address}", i64::from(*min) + i as i64));
+                std::iter::once(format!("#{}: Default: {default:4}", addresses.len()))
+                    .chain(cases)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+            Instruction::SwitchString {
+                default,
+                keys,
+                addresses,
+            } => {
+                let cases = keys.iter().zip(addresses).map(|(key, address)| {
+                    let key = self.constant_string(*key as usize).to_std_string_escaped();
+                    format!("'{key}': {address}")
+                });
+                std::iter::once(format!("#{}: Default: {default:4}", addresses.len()))
+                    .chain(cases)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
             Instruction::JumpIfNotResumeKind { exit, resume_kind } => {
                 format!("ResumeKind: {resume_kind:?}, exit: {exit}")
             }
@@ -656,7 +729,12 @@ impl CodeBlock {
             | Instruction::BindThisValue
             | Instruction::CreateMappedArgumentsObject
             | Instruction::CreateUnmappedArgumentsObject
-            | Instruction::Nop => String::new(),
+            | Instruction::Nop
+            | Instruction::Debugger
+            | Instruction::PushDisposeScope
+            | Instruction::SetDisposeError
+            | Instruction::DisposeResources
+            | Instruction::DisposeNext => String::new(),
 
             Instruction::U16Operands
             | Instruction::U32Operands
@@ -707,19 +785,28 @@ impl CodeBlock {
             | Instruction::Reserved45
             | Instruction::Reserved46
             | Instruction::Reserved47
-            | Instruction::Reserved48
-            | Instruction::Reserved49
-            | Instruction::Reserved50
-            | Instruction::Reserved51
-            | Instruction::Reserved52
-            | Instruction::Reserved53
-            | Instruction::Reserved54
-            | Instruction::Reserved55
-            | Instruction::Reserved56
-            | Instruction::Reserved57
-            | Instruction::Reserved58 => unreachable!("Reserved opcodes are unrechable"),
+            | Instruction::Reserved48 => unreachable!("Reserved opcodes are unrechable"),
         }
     }
+
+    /// Returns the start of the statement of the source that contains the instruction at `pc`,
+    /// if known.
+    pub(crate) fn position_at(&self, pc: u32) -> Option<Position> {
+        let index = self.positions.partition_point(|(start, _)| *start <= pc);
+        index.checked_sub(1).map(|index| self.positions[index].1)
+    }
+
+    /// Returns the line of the source of the statement that starts at `pc`, if it is the first
+    /// statement executed on that line.
+    pub(crate) fn statement_line_at(&self, pc: u32) -> Option<u32> {
+        let index = self
+            .positions
+            .binary_search_by_key(&pc, |(start, _)| *start)
+            .ok()?;
+        let line = self.positions[index].1.line_number();
+        let previous = index.checked_sub(1).map(|index| self.positions[index].1);
+        (previous.map(Position::line_number) != Some(line)).then_some(line)
+    }
 }
 
 impl Display for CodeBlock {
@@ -0,0 +1,246 @@
+//! Hooks to pause the execution of the virtual machine.
+//!
+//! A [`DebugHandler`] installed with [`Context::set_debug_handler`] is called every time the
+//! execution reaches a breakpoint set with [`Context::set_breakpoint`], a `debugger` statement,
+//! or the next statement after a step requested by the handler.
+//!
+//! [`Context::set_debug_handler`]: crate::Context::set_debug_handler
+//! [`Context::set_breakpoint`]: crate::Context::set_breakpoint
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use boa_ast::Position;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    environments::BindingLocator,
+    vm::{ActiveRunnable, Opcode},
+    Context, JsResult, JsString, JsValue,
+};
+
+/// Receives the pauses of the execution of the VM.
+pub trait DebugHandler {
+    /// Called when the execution is paused, before executing the statement at
+    /// [`PausedFrame::line`].
+    ///
+    /// The returned [`DebugAction`] tells the VM how to resume the execution.
+    fn on_pause(&mut self, frame: &mut PausedFrame<'_>) -> DebugAction;
+}
+
+impl fmt::Debug for dyn DebugHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DebugHandler")
+    }
+}
+
+/// Why the execution was paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseReason {
+    /// The execution reached a line with a breakpoint.
+    Breakpoint,
+
+    /// The execution reached a `debugger` statement.
+    DebuggerStatement,
+
+    /// The execution reached the statement requested by a [`DebugAction`] step.
+    Step,
+}
+
+/// How to resume the execution after a pause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DebugAction {
+    /// Run until the next breakpoint or `debugger` statement.
+    #[default]
+    Continue,
+
+    /// Pause at the next statement, even if it is in a function called by the current one.
+    StepInto,
+
+    /// Pause at the next statement of the current function, or of its caller if it returns.
+    StepOver,
+
+    /// Pause at the next statement of the caller of the current function.
+    StepOut,
+}
+
+/// The frame where the execution is paused, passed to [`DebugHandler::on_pause`].
+#[derive(Debug)]
+pub struct PausedFrame<'a> {
+    context: &'a mut Context,
+    reason: PauseReason,
+    line: Option<u32>,
+}
+
+impl PausedFrame<'_> {
+    /// Gets the reason of the pause.
+    #[must_use]
+    pub const fn reason(&self) -> PauseReason {
+        self.reason
+    }
+
+    /// Gets the line of the source of the statement that is about to be executed, if known.
+    #[must_use]
+    pub const fn line(&self) -> Option<u32> {
+        self.line
+    }
+
+    /// Gets the name of the function of the paused frame.
+    #[must_use]
+    pub fn function_name(&self) -> &JsString {
+        self.context.vm.frame().code_block().name()
+    }
+
+    /// Gets the path of the source of the paused frame, if any.
+    #[must_use]
+    pub fn source_path(&self) -> Option<&Path> {
+        match self.context.vm.frame().active_runnable.as_ref()? {
+            ActiveRunnable::Script(script) => script.path(),
+            ActiveRunnable::Module(module) => module.path(),
+        }
+    }
+
+    /// Gets the value of the binding `name` as seen by the paused code.
+    ///
+    /// Returns `None` if there is no such binding, if it is still uninitialized, or if it is a
+    /// property of the global object instead of a local binding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if looking up the binding in a `with` statement object throws.
+    pub fn local(&mut self, name: &str) -> JsResult<Option<JsValue>> {
+        let Some(locator) = self.find_local(name)? else {
+            return Ok(None);
+        };
+        self.context.get_binding(&locator)
+    }
+
+    /// Sets the value of the binding `name` as seen by the paused code.
+    ///
+    /// Returns `false` if there is no such binding, or if it is a property of the global object
+    /// instead of a local binding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if setting the binding in a `with` statement object throws.
+    pub fn set_local(&mut self, name: &str, value: JsValue) -> JsResult<bool> {
+        let Some(locator) = self.find_local(name)? else {
+            return Ok(false);
+        };
+        self.context.set_binding(&locator, value, true)?;
+        Ok(true)
+    }
+
+    /// Gets the [`Context`] of the paused execution, to inspect it further.
+    pub fn context(&mut self) -> &mut Context {
+        self.context
+    }
+
+    fn find_local(&mut self, name: &str) -> JsResult<Option<BindingLocator>> {
+        let mut locator = self
+            .context
+            .vm
+            .environments
+            .current_compile_environment()
+            .get_identifier_reference(JsString::from(name))
+            .locator();
+        self.context.find_runtime_binding(&mut locator)?;
+        Ok((!locator.is_global()).then_some(locator))
+    }
+}
+
+/// The breakpoints, handler and pending step of a [`Context`].
+#[derive(Debug, Default)]
+pub(crate) struct Debugger {
+    pub(crate) handler: Option<Box<dyn DebugHandler>>,
+    pub(crate) breakpoints: FxHashMap<PathBuf, FxHashSet<u32>>,
+
+    /// The step to pause at, and the number of frames when it was requested.
+    pub(crate) step: Option<(DebugAction, usize)>,
+}
+
+impl Debugger {
+    /// Returns `true` if the statements that are executed have to be checked for a pause.
+    pub(crate) fn is_active(&self) -> bool {
+        self.handler.is_some() && (self.step.is_some() || !self.breakpoints.is_empty())
+    }
+
+    fn should_pause(&self, path: Option<&Path>, line: u32, depth: usize) -> Option<PauseReason> {
+        let stepped = match self.step {
+            Some((DebugAction::StepInto, _)) => true,
+            Some((DebugAction::StepOver, start)) => depth <= start,
+            Some((DebugAction::StepOut, start)) => depth < start,
+            Some((DebugAction::Continue, _)) | None => false,
+        };
+        if stepped {
+            return Some(PauseReason::Step);
+        }
+
+        path.and_then(|path| self.breakpoints.get(path))
+            .is_some_and(|lines| lines.contains(&line))
+            .then_some(PauseReason::Breakpoint)
+    }
+}
+
+impl Context {
+    /// Pauses the execution if the next instruction starts a statement that has a breakpoint or
+    /// was requested by a step.
+    pub(crate) fn check_pause(&mut self) {
+        let frame = self.vm.frame();
+        let code_block = frame.code_block();
+        let pc = frame.pc;
+
+        // `debugger` statements pause by themselves when they are executed.
+        if code_block.bytecode.get(pc as usize) == Some(&(Opcode::Debugger as u8)) {
+            return;
+        }
+        let Some(line) = code_block.statement_line_at(pc) else {
+            return;
+        };
+        let path = frame.source_path_rc();
+        let Some(reason) =
+            self.vm
+                .debugger
+                .should_pause(path.as_deref(), line, self.vm.frames.len())
+        else {
+            return;
+        };
+
+        self.pause_at(reason, pc);
+    }
+
+    /// Pauses the execution at the `debugger` statement that is being executed.
+    pub(crate) fn pause(&mut self, reason: PauseReason) {
+        // NOTE: -1 because the opcode was already read.
+        let pc = self.vm.frame().pc.saturating_sub(1);
+        self.pause_at(reason, pc);
+    }
+
+    fn pause_at(&mut self, reason: PauseReason, pc: u32) {
+        let Some(mut handler) = self.vm.debugger.handler.take() else {
+            return;
+        };
+
+        let depth = self.vm.frames.len();
+        let line = self
+            .vm
+            .frame()
+            .code_block()
+            .position_at(pc)
+            .map(Position::line_number);
+        let action = handler.on_pause(&mut PausedFrame {
+            context: self,
+            reason,
+            line,
+        });
+
+        // The handler could have installed a new handler while the execution was paused.
+        self.vm.debugger.handler.get_or_insert(handler);
+        self.vm.debugger.step = match action {
+            DebugAction::Continue => None,
+            action => Some((action, depth)),
+        };
+    }
+}
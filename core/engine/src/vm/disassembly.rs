@@ -0,0 +1,318 @@
+//! Disassembly of compiled [`CodeBlock`]s, to inspect the bytecode generated for a script.
+//!
+//! The disassembly decodes the operands of every instruction, inlining the values of the
+//! constants they refer to, and names the targets of jumps and exception handlers with labels.
+//! It can be printed as text, or serialized to other formats like JSON.
+
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+use crate::vm::{
+    CodeBlock, Constant, Handler, Instruction, InstructionIterator, VaryingOperandKind,
+};
+
+/// The disassembly of a [`CodeBlock`], and of the functions defined in it.
+///
+/// Created with [`CodeBlock::disassemble`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Disassembly {
+    /// The name of the function, which is `<main>` for the top-level code of a script.
+    pub name: String,
+    /// The number of arguments expected by the function.
+    pub length: u32,
+    /// The instructions of the bytecode, in order.
+    pub instructions: Vec<DisassembledInstruction>,
+    /// The exception handlers of the function.
+    pub handlers: Vec<DisassembledHandler>,
+    /// The disassembly of the functions defined in the code of this one.
+    pub functions: Vec<Disassembly>,
+}
+
+/// An instruction of a [`Disassembly`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DisassembledInstruction {
+    /// The offset of the instruction in the bytecode.
+    pub offset: u32,
+    /// The label of the instruction, if it is the target of a jump or an exception handler.
+    pub label: Option<String>,
+    /// The name of the opcode, with the width of its operands as a suffix if they are wider than
+    /// a byte, like `GetName.U16`.
+    pub opcode: String,
+    /// The decoded operands, which are empty if the instruction has none.
+    pub operands: String,
+}
+
+/// An exception handler of a [`Disassembly`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DisassembledHandler {
+    /// The offset of the first instruction covered by the handler.
+    pub start: u32,
+    /// The offset after the last instruction covered by the handler.
+    pub end: u32,
+    /// The label of the instruction that handles the exceptions.
+    pub target: String,
+    /// The size of the stack to restore before handling an exception.
+    pub stack_count: u32,
+    /// The number of environments to restore before handling an exception.
+    pub environment_count: u32,
+}
+
+impl Disassembly {
+    /// Disassembles `code` and the functions defined in it.
+    pub(crate) fn new(code: &CodeBlock) -> Self {
+        let instructions: Vec<_> = InstructionIterator::new(&code.bytecode).collect();
+
+        let mut targets: Vec<u32> = instructions
+            .iter()
+            .flat_map(|(_, _, instruction)| jump_targets(instruction))
+            .chain(code.handlers.iter().map(Handler::handler))
+            .collect();
+        targets.sort_unstable();
+        targets.dedup();
+        let labels = Labels { targets };
+
+        let instructions = instructions
+            .iter()
+            .map(|(offset, kind, instruction)| {
+                let suffix = match kind {
+                    VaryingOperandKind::U8 => "",
+                    VaryingOperandKind::U16 => ".U16",
+                    VaryingOperandKind::U32 => ".U32",
+                };
+                DisassembledInstruction {
+                    offset: *offset as u32,
+                    label: labels.get(*offset as u32),
+                    opcode: format!("{}{suffix}", instruction.opcode().as_str()),
+                    operands: operands(code, instruction, &labels),
+                }
+            })
+            .collect();
+
+        let handlers = code
+            .handlers
+            .iter()
+            .map(|handler| DisassembledHandler {
+                start: handler.start,
+                end: handler.end,
+                target: labels.name(handler.handler()),
+                stack_count: handler.stack_count,
+                environment_count: handler.environment_count,
+            })
+            .collect();
+
+        let functions = code
+            .constants
+            .iter()
+            .filter_map(|constant| match constant {
+                Constant::Function(function) => Some(Self::new(function)),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            name: code.name().to_std_string_escaped(),
+            length: code.length,
+            instructions,
+            handlers,
+            functions,
+        }
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        writeln!(
+            f,
+            "{:indent$}function {} (length: {})",
+            "", self.name, self.length
+        )?;
+
+        for instruction in &self.instructions {
+            let label = instruction
+                .label
+                .as_ref()
+                .map_or_else(String::new, |label| format!("{label}:"));
+            let line = format!(
+                "{label:>6} {:06}  {:<28}{}",
+                instruction.offset, instruction.opcode, instruction.operands
+            );
+            writeln!(f, "{:indent$}{}", "", line.trim_end())?;
+        }
+
+        if !self.handlers.is_empty() {
+            writeln!(f, "{:indent$}  handlers:", "")?;
+            for handler in &self.handlers {
+                writeln!(
+                    f,
+                    "{:indent$}    [{:06}, {:06}) -> {}, stack: {}, environments: {}",
+                    "",
+                    handler.start,
+                    handler.end,
+                    handler.target,
+                    handler.stack_count,
+                    handler.environment_count
+                )?;
+            }
+        }
+
+        for function in &self.functions {
+            writeln!(f)?;
+            function.fmt_indented(f, indent + 4)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Disassembly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// The labels of the jump targets of a [`CodeBlock`], numbered by their offset.
+struct Labels {
+    targets: Vec<u32>,
+}
+
+impl Labels {
+    /// Gets the label of the instruction at `offset`, if it is a jump target.
+    fn get(&self, offset: u32) -> Option<String> {
+        self.targets
+            .binary_search(&offset)
+            .ok()
+            .map(|index| format!("L{index}"))
+    }
+
+    /// Gets the label of a jump target.
+    fn name(&self, target: u32) -> String {
+        self.get(target)
+            .unwrap_or_else(|| format!("<invalid target {target}>"))
+    }
+}
+
+/// Gets the offsets that `instruction` can jump to.
+fn jump_targets(instruction: &Instruction) -> Vec<u32> {
+    match instruction {
+        Instruction::Jump { address }
+        | Instruction::JumpIfTrue { address }
+        | Instruction::JumpIfFalse { address }
+        | Instruction::JumpIfNotUndefined { address }
+        | Instruction::JumpIfNullOrUndefined { address }
+        | Instruction::Case { address }
+        | Instruction::Default { address }
+        | Instruction::LogicalAnd { exit: address }
+        | Instruction::LogicalOr { exit: address }
+        | Instruction::Coalesce { exit: address }
+        | Instruction::JumpIfNotResumeKind { exit: address, .. }
+        | Instruction::TemplateLookup { exit: address, .. } => vec![*address],
+        Instruction::GeneratorDelegateNext {
+            throw_method_undefined: first,
+            return_method_undefined: second,
+        }
+        | Instruction::GeneratorDelegateResume {
+            r#return: first,
+            exit: second,
+        } => vec![*first, *second],
+        Instruction::JumpTable { default, addresses }
+        | Instruction::SwitchInteger {
+            default, addresses, ..
+        }
+        | Instruction::SwitchString {
+            default, addresses, ..
+        } => std::iter::once(*default)
+            .chain(addresses.iter().copied())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Decodes the operands of `instruction`, replacing jump targets by their labels and constant
+/// indices by the values of the constants.
+fn operands(code: &CodeBlock, instruction: &Instruction, labels: &Labels) -> String {
+    match instruction {
+        Instruction::Jump { address }
+        | Instruction::JumpIfTrue { address }
+        | Instruction::JumpIfFalse { address }
+        | Instruction::JumpIfNotUndefined { address }
+        | Instruction::JumpIfNullOrUndefined { address }
+        | Instruction::Case { address }
+        | Instruction::Default { address }
+        | Instruction::LogicalAnd { exit: address }
+        | Instruction::LogicalOr { exit: address }
+        | Instruction::Coalesce { exit: address } => labels.name(*address),
+        Instruction::JumpIfNotResumeKind { exit, resume_kind } => {
+            format!("ResumeKind: {resume_kind:?}, exit: {}", labels.name(*exit))
+        }
+        Instruction::GeneratorDelegateNext {
+            throw_method_undefined,
+            return_method_undefined,
+        } => format!(
+            "throw method undefined: {}, return method undefined: {}",
+            labels.name(*throw_method_undefined),
+            labels.name(*return_method_undefined)
+        ),
+        Instruction::GeneratorDelegateResume { r#return, exit } => format!(
+            "return: {}, exit: {}",
+            labels.name(*r#return),
+            labels.name(*exit)
+        ),
+        Instruction::TemplateLookup { exit, site } => {
+            format!("exit: {}, site: {site}", labels.name(*exit))
+        }
+        Instruction::JumpTable { default, addresses } => {
+            let cases = addresses
+                .iter()
+                .enumerate()
+                .map(|(i, address)| format!("{i}: {}", labels.name(*address)));
+            switch_operands(&labels.name(*default), cases)
+        }
+        Instruction::SwitchInteger {
+            default,
+            min,
+            addresses,
+        } => {
+            let cases = (i64::from(*min)..)
+                .zip(addresses)
+                .map(|(case, address)| format!("{case}: {}", labels.name(*address)));
+            switch_operands(&labels.name(*default), cases)
+        }
+        Instruction::SwitchString {
+            default,
+            keys,
+            addresses,
+        } => {
+            let cases = keys.iter().zip(addresses).map(|(key, address)| {
+                format!(
+                    "{}: {}",
+                    constant(code, *key as usize),
+                    labels.name(*address)
+                )
+            });
+            switch_operands(&labels.name(*default), cases)
+        }
+        Instruction::PushLiteral { index } => constant(code, index.value() as usize),
+        Instruction::ThrowNewTypeError { message } => constant(code, message.value() as usize),
+        _ => code.instruction_operands(instruction),
+    }
+}
+
+/// Formats the operands of a switch instruction, starting with its default target.
+fn switch_operands(default: &str, cases: impl Iterator<Item = String>) -> String {
+    std::iter::once(format!("default: {default}"))
+        .chain(cases)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Formats the constant at `index` like a JavaScript literal.
+fn constant(code: &CodeBlock, index: usize) -> String {
+    match &code.constants[index] {
+        Constant::String(string) => format!("{:?}", string.to_std_string_escaped()),
+        Constant::BigInt(bigint) => format!("{bigint}n"),
+        Constant::Function(function) => {
+            format!("function {}", function.name().to_std_string_escaped())
+        }
+        Constant::CompileTimeEnvironment(environment) => {
+            format!("environment {}", environment.environment_index())
+        }
+    }
+}
@@ -246,10 +246,12 @@ impl CodeBlock {
                 | Instruction::DefInitVar { .. }
                 | Instruction::PutLexicalValue { .. }
                 | Instruction::GetName { .. }
+                | Instruction::GetBinding { .. }
                 | Instruction::GetLocator { .. }
                 | Instruction::GetNameAndLocator { .. }
                 | Instruction::GetNameOrUndefined { .. }
                 | Instruction::SetName { .. }
+                | Instruction::SetBinding { .. }
                 | Instruction::DeleteName { .. } => {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
@@ -331,6 +333,56 @@ impl CodeBlock {
                         );
                     }
                 }
+                Instruction::SwitchInteger {
+                    default,
+                    min,
+                    addresses,
+                } => {
+                    graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
+                    graph.add_edge(
+                        previous_pc,
+                        default as usize,
+                        Some("DEFAULT".into()),
+                        Color::None,
+                        EdgeStyle::Line,
+                    );
+
+                    for (i, address) in addresses.iter().enumerate() {
+                        let key = i64::from(min) + i as i64;
+                        graph.add_edge(
+                            previous_pc,
+                            *address as usize,
+                            Some(format!("Case: {key}").into()),
+                            Color::None,
+                            EdgeStyle::Line,
+                        );
+                    }
+                }
+                Instruction::SwitchString {
+                    default,
+                    keys,
+                    addresses,
+                } => {
+                    graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
+                    graph.add_edge(
+                        previous_pc,
+                        default as usize,
+                        Some("DEFAULT".into()),
+                        Color::None,
+                        EdgeStyle::Line,
+                    );
+
+                    for (key, address) in keys.iter().zip(addresses.iter()) {
+                        let key = self.constant_string(*key as usize).to_std_string_escaped();
+                        graph.add_edge(
+                            previous_pc,
+                            *address as usize,
+                            Some(format!("Case: '{key}'").into()),
+                            Color::None,
+                            EdgeStyle::Line,
+                        );
+                    }
+                }
                 Instruction::Pop
                 | Instruction::Dup
                 | Instruction::Swap
@@ -450,7 +502,13 @@ impl CodeBlock {
                 | Instruction::CreateMappedArgumentsObject
                 | Instruction::CreateUnmappedArgumentsObject
                 | Instruction::CreateGlobalFunctionBinding { .. }
-                | Instruction::Nop => {
+                | Instruction::Nop
+                | Instruction::Debugger
+                | Instruction::PushDisposeScope
+                | Instruction::AddDisposableResource { .. }
+                | Instruction::SetDisposeError
+                | Instruction::DisposeResources
+                | Instruction::DisposeNext => {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
@@ -506,17 +564,7 @@ impl CodeBlock {
                 | Instruction::Reserved45
                 | Instruction::Reserved46
                 | Instruction::Reserved47
-                | Instruction::Reserved48
-                | Instruction::Reserved49
-                | Instruction::Reserved50
-                | Instruction::Reserved51
-                | Instruction::Reserved52
-                | Instruction::Reserved53
-                | Instruction::Reserved54
-                | Instruction::Reserved55
-                | Instruction::Reserved56
-                | Instruction::Reserved57
-                | Instruction::Reserved58 => unreachable!("Reserved opcodes are unrechable"),
+                | Instruction::Reserved48 => unreachable!("Reserved opcodes are unrechable"),
             }
         }
 
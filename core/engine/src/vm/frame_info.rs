@@ -0,0 +1,264 @@
+//! Inspection of the call stack of the virtual machine.
+//!
+//! [`Context::stack_frames`] walks the frames of the functions that are currently being executed,
+//! including the native functions, which don't push a [`CallFrame`].
+//!
+//! [`Context::stack_frames`]: crate::Context::stack_frames
+
+use std::{path::Path, rc::Rc};
+
+use boa_ast::Position;
+
+use crate::{js_string, vm::CallFrame, Context, JsObject, JsString, JsValue};
+
+/// A call to a native function, which is executed without pushing a [`CallFrame`].
+#[derive(Debug, Clone)]
+pub(crate) struct NativeFrame {
+    pub(crate) function: JsObject,
+
+    /// The `this` value of the call, or `None` if it is a constructor call.
+    pub(crate) this: Option<JsValue>,
+    pub(crate) argument_count: usize,
+
+    /// The number of [`CallFrame`]s that were on the stack when the function was called.
+    pub(crate) depth: usize,
+}
+
+/// The kind of function of a [`FrameInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameKind {
+    /// A native function, implemented in Rust.
+    Native,
+
+    /// The top-level code of a script or module, or the code of an `eval` call.
+    Script,
+
+    /// An ordinary function, arrow function, method or class constructor.
+    Function,
+
+    /// A generator function.
+    Generator,
+
+    /// An async function.
+    AsyncFunction,
+
+    /// An async generator function.
+    AsyncGenerator,
+}
+
+/// A function that is currently being executed, yielded by [`Context::stack_frames`].
+///
+/// [`Context::stack_frames`]: crate::Context::stack_frames
+#[derive(Debug, Clone)]
+pub struct FrameInfo {
+    kind: FrameKind,
+    function_name: JsString,
+    function: Option<JsObject>,
+    receiver: Option<JsValue>,
+    argument_count: usize,
+    source_path: Option<Rc<Path>>,
+    line: Option<u32>,
+    column: Option<u32>,
+    resumed: bool,
+}
+
+impl FrameInfo {
+    /// Gets the kind of function of the frame.
+    #[must_use]
+    pub const fn kind(&self) -> FrameKind {
+        self.kind
+    }
+
+    /// Returns `true` if the frame is a call to a native function.
+    #[must_use]
+    pub fn is_native(&self) -> bool {
+        self.kind == FrameKind::Native
+    }
+
+    /// Returns `true` if the frame is a generator or async function that was suspended by a
+    /// `yield` or `await`, and then resumed.
+    #[must_use]
+    pub const fn is_resumed(&self) -> bool {
+        self.resumed
+    }
+
+    /// Gets the name of the function of the frame.
+    ///
+    /// The top-level code of scripts and modules has the name `<main>`.
+    #[must_use]
+    pub const fn function_name(&self) -> &JsString {
+        &self.function_name
+    }
+
+    /// Gets the function object of the frame, if it isn't the top-level code of a script.
+    #[must_use]
+    pub const fn function(&self) -> Option<&JsObject> {
+        self.function.as_ref()
+    }
+
+    /// Gets the `this` value passed to the function.
+    ///
+    /// Returns `None` for constructor calls and for the top-level code of scripts.
+    #[must_use]
+    pub const fn receiver(&self) -> Option<&JsValue> {
+        self.receiver.as_ref()
+    }
+
+    /// Gets the number of arguments passed to the function.
+    #[must_use]
+    pub const fn argument_count(&self) -> usize {
+        self.argument_count
+    }
+
+    /// Gets the path of the source of the frame, if any.
+    ///
+    /// Native functions don't have a source.
+    #[must_use]
+    pub fn source_path(&self) -> Option<&Path> {
+        self.source_path.as_deref()
+    }
+
+    /// Gets the line of the source that is being executed by the frame, if known.
+    ///
+    /// For the frames below the innermost one, this is the line of the call to the next frame.
+    #[must_use]
+    pub const fn line(&self) -> Option<u32> {
+        self.line
+    }
+
+    /// Gets the column of the source where the statement that is being executed by the frame
+    /// starts, if known.
+    #[must_use]
+    pub const fn column(&self) -> Option<u32> {
+        self.column
+    }
+
+    fn native(frame: &NativeFrame) -> Self {
+        let name = frame
+            .function
+            .borrow()
+            .properties()
+            .get(&js_string!("name").into())
+            .and_then(|desc| desc.value().and_then(JsValue::as_string).cloned())
+            .unwrap_or_default();
+
+        Self {
+            kind: FrameKind::Native,
+            function_name: name,
+            function: Some(frame.function.clone()),
+            receiver: frame.this.clone(),
+            argument_count: frame.argument_count,
+            source_path: None,
+            line: None,
+            column: None,
+            resumed: false,
+        }
+    }
+
+    fn call_frame(frame: &CallFrame, stack: &[JsValue]) -> Self {
+        let code_block = frame.code_block();
+        let function_index = frame.rp - frame.argument_count - CallFrame::FUNCTION_POSITION;
+        let this_index = frame.rp - frame.argument_count - CallFrame::THIS_POSITION;
+        let function = stack
+            .get(function_index as usize)
+            .and_then(JsValue::as_object)
+            .cloned();
+
+        let kind = if function.is_none() {
+            FrameKind::Script
+        } else if code_block.is_async_generator() {
+            FrameKind::AsyncGenerator
+        } else if code_block.is_generator() {
+            FrameKind::Generator
+        } else if code_block.is_async() {
+            FrameKind::AsyncFunction
+        } else {
+            FrameKind::Function
+        };
+        let receiver = if kind == FrameKind::Script || frame.construct() {
+            None
+        } else {
+            stack.get(this_index as usize).cloned()
+        };
+
+        // NOTE: -1 because the pc already points to the next instruction.
+        let position = code_block.position_at(frame.pc.saturating_sub(1));
+
+        Self {
+            kind,
+            function_name: code_block.name().clone(),
+            receiver,
+            argument_count: if function.is_some() {
+                frame.argument_count as usize
+            } else {
+                0
+            },
+            function,
+            source_path: frame.source_path_rc(),
+            line: position.map(Position::line_number),
+            column: position.map(Position::column_number),
+            resumed: frame.registers_already_pushed(),
+        }
+    }
+}
+
+/// An iterator over the frames of a [`Context`], from the innermost one.
+///
+/// Returned by [`Context::stack_frames`].
+#[derive(Debug)]
+pub struct StackFrames<'a> {
+    context: &'a Context,
+
+    /// The number of call frames and native frames that haven't been yielded.
+    frames: usize,
+    native_frames: usize,
+
+    /// The stack of the next call frame, and the number of suspended stacks left.
+    stack: &'a [JsValue],
+    suspended_stacks: usize,
+}
+
+impl<'a> StackFrames<'a> {
+    pub(crate) fn new(context: &'a Context) -> Self {
+        Self {
+            context,
+            frames: context.vm.frames.len(),
+            native_frames: context.vm.native_frames.len(),
+            stack: &context.vm.stack,
+            suspended_stacks: context.vm.suspended_stacks.len(),
+        }
+    }
+}
+
+impl Iterator for StackFrames<'_> {
+    type Item = FrameInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let vm = &self.context.vm;
+
+        // A native function was called after all the call frames below it were pushed.
+        if let Some(native) = self
+            .native_frames
+            .checked_sub(1)
+            .map(|index| &vm.native_frames[index])
+            .filter(|native| native.depth == self.frames)
+        {
+            self.native_frames -= 1;
+            return Some(FrameInfo::native(native));
+        }
+
+        self.frames = self.frames.checked_sub(1)?;
+        let frame = &vm.frames[self.frames];
+        let info = FrameInfo::call_frame(frame, self.stack);
+
+        // The frames below a resumed generator use the stack that was active before resuming it.
+        if frame.registers_already_pushed() {
+            if let Some(index) = self.suspended_stacks.checked_sub(1) {
+                self.suspended_stacks = index;
+                self.stack = &vm.suspended_stacks[index];
+            }
+        }
+
+        Some(info)
+    }
+}
@@ -1,61 +1,141 @@
-use std::cell::Cell;
-
 use boa_gc::GcRefCell;
 use boa_macros::{Finalize, Trace};
 
 use crate::{
-    object::shape::{slot::Slot, Shape, WeakShape},
+    object::shape::{
+        slot::{Slot, SlotAttributes},
+        Shape, WeakShape,
+    },
     JsString,
 };
 
 #[cfg(test)]
 mod tests;
 
-/// An inline cache entry for a property access.
-#[derive(Clone, Debug, Trace, Finalize)]
-pub(crate) struct InlineCache {
-    /// The property that is accessed.
-    pub(crate) name: JsString,
+/// The maximum number of shapes an [`InlineCache`] remembers.
+///
+/// When a new shape is cached after reaching this limit, the oldest entry is evicted.
+const MAX_ENTRIES: usize = 4;
 
+/// A cached location of a property, for objects of a given shape.
+#[derive(Clone, Debug, Trace, Finalize)]
+struct Entry {
     /// A pointer is kept to the shape to avoid the shape from being deallocated.
-    pub(crate) shape: GcRefCell<WeakShape>,
+    shape: WeakShape,
+
+    /// The shape of the prototype at the time the property was cached, if the property was
+    /// found in the prototype.
+    ///
+    /// Changing the properties of the prototype doesn't change the shape of the object, so this
+    /// is checked to detect when the cached slot is no longer valid.
+    prototype_shape: WeakShape,
 
     /// The [`Slot`] of the property.
     #[unsafe_ignore_trace]
-    pub(crate) slot: Cell<Slot>,
+    slot: Slot,
+}
+
+impl Entry {
+    /// Returns `true` if the prototype still has the shape it had when the property was cached.
+    fn prototype_matches(&self, shape: &Shape) -> bool {
+        if !self.slot.attributes.contains(SlotAttributes::PROTOTYPE) {
+            return true;
+        }
+        let Some(prototype) = shape.prototype() else {
+            return false;
+        };
+        let prototype_shape = prototype.borrow().shape().to_addr_usize();
+        self.prototype_shape
+            .upgrade()
+            .is_some_and(|cached| cached.to_addr_usize() == prototype_shape)
+    }
+}
+
+/// A polymorphic inline cache for a property access.
+#[derive(Clone, Debug, Trace, Finalize)]
+pub(crate) struct InlineCache {
+    /// The property that is accessed.
+    pub(crate) name: JsString,
+
+    /// The cached shapes, from the oldest to the most recent one.
+    entries: GcRefCell<Vec<Entry>>,
 }
 
 impl InlineCache {
     pub(crate) const fn new(name: JsString) -> Self {
         Self {
             name,
-            shape: GcRefCell::new(WeakShape::None),
-            slot: Cell::new(Slot::new()),
+            entries: GcRefCell::new(Vec::new()),
         }
     }
 
+    /// Caches the `slot` of the property for objects with the given `shape`.
     pub(crate) fn set(&self, shape: &Shape, slot: Slot) {
-        *self.shape.borrow_mut() = shape.into();
-        self.slot.set(slot);
+        let prototype_shape = if slot.attributes.contains(SlotAttributes::PROTOTYPE) {
+            // Unique shapes are modified in place when a property is added, so the property
+            // could be shadowed by an own property without any change to the shape.
+            if shape.is_unique() {
+                return;
+            }
+            let Some(prototype) = shape.prototype() else {
+                return;
+            };
+            let prototype = prototype.borrow();
+            WeakShape::from(prototype.shape())
+        } else {
+            WeakShape::None
+        };
+
+        let mut entries = self.entries.borrow_mut();
+        entries.retain(|entry| entry.shape.to_addr_usize() != shape.to_addr_usize());
+        if entries.len() == MAX_ENTRIES {
+            entries.remove(0);
+        }
+        entries.push(Entry {
+            shape: shape.into(),
+            prototype_shape,
+            slot,
+        });
+    }
+
+    /// Returns the shapes that are currently cached, from the oldest to the most recent one.
+    pub(crate) fn shapes(&self) -> Vec<WeakShape> {
+        self.entries
+            .borrow()
+            .iter()
+            .map(|entry| entry.shape.clone())
+            .collect()
     }
 
+    /// Returns the most recently cached [`Slot`].
     pub(crate) fn slot(&self) -> Slot {
-        self.slot.get()
+        self.entries
+            .borrow()
+            .last()
+            .map_or_else(Slot::new, |entry| entry.slot)
     }
 
-    /// Returns true, if the [`InlineCache`]'s shape matches with the given shape.
+    /// Returns the cached [`Slot`] for the given shape, if any.
     ///
-    /// Otherwise we reset the internal weak reference to [`WeakShape::None`],
-    /// so it can be deallocated by the GC.
+    /// Entries of shapes that were deallocated, or whose prototype changed since they were
+    /// cached, are removed so they can be deallocated by the GC.
     pub(crate) fn match_or_reset(&self, shape: &Shape) -> Option<(Shape, Slot)> {
-        let mut old = self.shape.borrow_mut();
-
-        let old_upgraded = old.upgrade();
-        if old_upgraded.as_ref().map_or(0, Shape::to_addr_usize) == shape.to_addr_usize() {
-            return old_upgraded.map(|shape| (shape, self.slot()));
-        }
+        let mut entries = self.entries.borrow_mut();
 
-        *old = WeakShape::None;
-        None
+        let mut found = None;
+        entries.retain(|entry| {
+            let Some(cached) = entry.shape.upgrade() else {
+                return false;
+            };
+            if found.is_some() || cached.to_addr_usize() != shape.to_addr_usize() {
+                return true;
+            }
+            if !entry.prototype_matches(&cached) {
+                return false;
+            }
+            found = Some((cached, entry.slot));
+            true
+        });
+        found
     }
 }
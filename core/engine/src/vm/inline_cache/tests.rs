@@ -331,7 +331,7 @@ fn set_property_by_name_set_inline_cache_on_property_load() -> JsResult<()> {
     let (function, code) = get_codeblock(&function).unwrap();
 
     assert_eq!(code.ic.len(), 1);
-    assert_eq!(code.ic[0].shape.borrow().clone(), WeakShape::None);
+    assert!(code.ic[0].shapes().is_empty());
 
     let o = ObjectInitializer::new(context)
         .property(js_string!("test"), 0, Attribute::all())
@@ -340,7 +340,7 @@ fn set_property_by_name_set_inline_cache_on_property_load() -> JsResult<()> {
 
     function.call(&JsValue::undefined(), &[o.clone().into()], context)?;
 
-    assert_eq!(code.ic[0].shape.borrow().clone(), WeakShape::from(&o_shape));
+    assert_eq!(code.ic[0].shapes(), [WeakShape::from(&o_shape)]);
 
     Ok(())
 }
@@ -352,7 +352,7 @@ fn get_property_by_name_set_inline_cache_on_property_load() -> JsResult<()> {
     let (function, code) = get_codeblock(&function).unwrap();
 
     assert_eq!(code.ic.len(), 1);
-    assert_eq!(code.ic[0].shape.borrow().clone(), WeakShape::None);
+    assert!(code.ic[0].shapes().is_empty());
 
     let o = ObjectInitializer::new(context)
         .property(js_string!("test"), 0, Attribute::all())
@@ -361,7 +361,116 @@ fn get_property_by_name_set_inline_cache_on_property_load() -> JsResult<()> {
 
     function.call(&JsValue::undefined(), &[o.clone().into()], context)?;
 
-    assert_eq!(code.ic[0].shape.borrow().clone(), WeakShape::from(&o_shape));
+    assert_eq!(code.ic[0].shapes(), [WeakShape::from(&o_shape)]);
+
+    Ok(())
+}
+
+#[test]
+fn inline_cache_is_polymorphic() -> JsResult<()> {
+    let context = &mut Context::default();
+    let function = context.eval(Source::from_bytes("(function (o) { return o.x; })"))?;
+    let (function, code) = get_codeblock(&function).unwrap();
+
+    let objects = context.eval(Source::from_bytes(
+        "[{ x: 1 }, { a: 0, x: 2 }, { b: 0, x: 3 }, { c: 0, x: 4 }, { d: 0, x: 5 }]",
+    ))?;
+    let objects = objects.as_object().unwrap().clone();
+
+    let mut shapes = Vec::new();
+    for i in 0..5 {
+        let object = objects.get(i, context)?;
+        shapes.push(WeakShape::from(
+            object.as_object().unwrap().borrow().shape(),
+        ));
+
+        let result = function.call(&JsValue::undefined(), &[object], context)?;
+        assert_eq!(result, JsValue::new(i + 1));
+    }
+
+    // Only the most recent shapes are kept.
+    assert_eq!(code.ic[0].shapes(), shapes[1..]);
+
+    // Every cached shape hits the cache with its own slot.
+    for i in 1..5 {
+        let object = objects.get(i, context)?;
+        let result = function.call(&JsValue::undefined(), &[object], context)?;
+        assert_eq!(result, JsValue::new(i + 1));
+    }
+    assert_eq!(code.ic[0].shapes(), shapes[1..]);
+
+    Ok(())
+}
+
+#[test]
+fn inline_cache_invalidation() -> JsResult<()> {
+    let context = &mut Context::default();
+
+    let result = context.eval(Source::from_bytes(
+        r#"
+        let proto = { x: 1 };
+        let object = Object.create(proto);
+        let own = { x: 1, y: 2 };
+        let results = [];
+        for (let i = 0; i < 5; i++) {
+            results.push(`${object.x} ${own.y}`);
+            switch (i) {
+                case 0:
+                    Object.defineProperty(proto, "x", { get() { return "get"; }, configurable: true });
+                    delete own.x;
+                    break;
+                case 1:
+                    delete proto.x;
+                    Object.defineProperty(own, "y", { get() { return "get"; }, configurable: true });
+                    break;
+                case 2:
+                    proto.x = 3;
+                    delete own.y;
+                    break;
+                case 3:
+                    object.x = "own";
+                    own.y = 4;
+                    break;
+            }
+        }
+        results.join()
+    "#,
+    ))?;
+    assert_eq!(
+        result.as_string().unwrap().to_std_string_escaped(),
+        "1 2,get 2,undefined get,3 undefined,own 4"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn inline_cache_invalidation_of_unique_shapes() -> JsResult<()> {
+    let context = &mut Context::default();
+
+    // Objects with many properties have unique shapes, which are modified in place.
+    let result = context.eval(Source::from_bytes(
+        r#"
+        function get(o) { return o.x; }
+        function set(o, value) { "use strict"; o.x = value; }
+        let unique = {};
+        for (let i = 0; i < 1100; i++) unique["p" + i] = i;
+        Object.setPrototypeOf(unique, { x: "proto" });
+
+        let results = [get(unique)];
+        unique.x = "own";
+        results.push(get(unique));
+        set(unique, "set");
+        Object.defineProperty(unique, "x", { writable: false });
+        try { set(unique, "non-writable"); } catch (e) { results.push(e.name); }
+        results.push(get(unique));
+        results.join()
+    "#,
+    ))?;
+    assert_eq!(
+        result.as_string().unwrap().to_std_string_escaped(),
+        "proto,own,TypeError,set"
+    );
 
     Ok(())
 }
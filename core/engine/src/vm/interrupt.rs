@@ -0,0 +1,71 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::{JsError, JsNativeError};
+
+/// A handle used to interrupt the execution of a [`Context`][crate::Context], possibly from
+/// another thread.
+///
+/// The VM checks for interruptions on loop back-edges and function calls, which means that
+/// even scripts stuck in an infinite loop will eventually stop. Once an interruption is
+/// observed, the handle is reset, and the currently running script throws an error (see
+/// [`Context::set_catchable_interrupts`][crate::Context::set_catchable_interrupts]).
+///
+/// # Examples
+///
+/// ```
+/// # use std::{thread, time::Duration};
+/// # use boa_engine::{Context, Source};
+/// let mut context = Context::default();
+/// let handle = context.interrupt_handle();
+///
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_millis(10));
+///     handle.interrupt();
+/// });
+///
+/// assert!(context.eval(Source::from_bytes("while (true) {}")).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle {
+    interrupted: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Requests the interruption of the context associated with this handle.
+    #[inline]
+    pub fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if an interruption was requested, but not yet observed by the context.
+    #[inline]
+    #[must_use]
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::Relaxed)
+    }
+
+    /// Cancels a pending interruption request.
+    #[inline]
+    pub fn reset(&self) {
+        self.interrupted.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns an error if an interruption was requested, resetting the handle.
+    #[inline]
+    pub(crate) fn check(&self, catchable: bool) -> Result<(), JsError> {
+        if !self.is_interrupted() {
+            return Ok(());
+        }
+        self.reset();
+
+        let error = if catchable {
+            JsNativeError::error()
+        } else {
+            JsNativeError::runtime_limit()
+        };
+        Err(error.with_message("execution interrupted").into())
+    }
+}
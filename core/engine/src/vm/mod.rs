@@ -6,39 +6,59 @@
 
 use crate::{
     environments::EnvironmentStack, realm::Realm, script::Script, vm::code_block::Readable,
-    Context, JsError, JsNativeError, JsObject, JsResult, JsValue, Module,
+    Context, JsError, JsNativeError, JsResult, JsValue, Module,
 };
 
 use boa_gc::{custom_trace, Finalize, Trace};
 use boa_profiler::Profiler;
 use std::{future::Future, mem::size_of, ops::ControlFlow, pin::Pin, task};
 
-#[cfg(feature = "trace")]
 use crate::sys::time::Instant;
 
 mod call_frame;
 mod code_block;
 mod completion_record;
+mod debugger;
+mod disassembly;
+mod frame_info;
 mod inline_cache;
+mod interrupt;
 mod opcode;
+mod profile;
 mod runtime_limits;
+mod tracer;
 
 #[cfg(feature = "flowgraph")]
 pub mod flowgraph;
 
+#[cfg(feature = "bytecode-cache")]
+mod cache;
+
+#[cfg(feature = "bytecode-cache")]
+pub(crate) use cache::{is_cache_of, GlobalDeclarations};
+
+pub(crate) use debugger::Debugger;
+pub(crate) use frame_info::NativeFrame;
 pub(crate) use inline_cache::InlineCache;
+pub(crate) use profile::ProfileRecorder;
 
 // TODO: see if this can be exposed on all features.
+pub use debugger::{DebugAction, DebugHandler, PauseReason, PausedFrame};
+pub use disassembly::{DisassembledHandler, DisassembledInstruction, Disassembly};
+pub use frame_info::{FrameInfo, FrameKind, StackFrames};
+pub use interrupt::InterruptHandle;
 #[allow(unused_imports)]
 pub(crate) use opcode::{Instruction, InstructionIterator, Opcode, VaryingOperandKind};
+pub use profile::{FunctionProfile, Profile};
 pub use runtime_limits::RuntimeLimits;
+pub use tracer::{TracedInstruction, Tracer};
 pub use {
     call_frame::{CallFrame, GeneratorResumeKind},
     code_block::CodeBlock,
 };
 
 pub(crate) use {
-    call_frame::CallFrameFlags,
+    call_frame::{CallFrameFlags, DisposeScope},
     code_block::{
         create_function_object, create_function_object_fast, CodeBlockFlags, Constant, Handler,
     },
@@ -68,15 +88,31 @@ pub struct Vm {
     pub(crate) environments: EnvironmentStack,
     pub(crate) runtime_limits: RuntimeLimits,
 
-    /// This is used to assign a native (rust) function as the active function,
-    /// because we don't push a frame for them.
-    pub(crate) native_active_function: Option<JsObject>,
+    /// Pending interruption requests from the host.
+    pub(crate) interrupt: InterruptHandle,
+
+    /// Whether interruptions throw an error that can be caught by scripts.
+    pub(crate) catchable_interrupts: bool,
+
+    /// The calls to native (rust) functions that are being executed, because we don't push a
+    /// frame for them.
+    pub(crate) native_frames: Vec<NativeFrame>,
+
+    /// The stacks of the frames below the generators that are being resumed, which execute on
+    /// their own stack.
+    pub(crate) suspended_stacks: Vec<Vec<JsValue>>,
 
     /// realm holds both the global object and the environment
     pub(crate) realm: Realm,
 
-    #[cfg(feature = "trace")]
-    pub(crate) trace: bool,
+    /// Receives the events of the execution, if any.
+    pub(crate) tracer: Option<Box<dyn Tracer>>,
+
+    /// The breakpoints and the handler called when the execution pauses.
+    pub(crate) debugger: Debugger,
+
+    /// Records the calls of the code blocks while profiling.
+    pub(crate) profiler: Option<Box<ProfileRecorder>>,
 }
 
 /// Active runnable in the current vm context.
@@ -105,10 +141,14 @@ impl Vm {
             environments: EnvironmentStack::new(realm.environment().clone()),
             pending_exception: None,
             runtime_limits: RuntimeLimits::default(),
-            native_active_function: None,
+            interrupt: InterruptHandle::default(),
+            catchable_interrupts: false,
+            native_frames: Vec::new(),
+            suspended_stacks: Vec::new(),
             realm,
-            #[cfg(feature = "trace")]
-            trace: false,
+            tracer: None,
+            debugger: Debugger::default(),
+            profiler: None,
         }
     }
 
@@ -175,6 +215,17 @@ impl Vm {
         }
 
         self.frames.push(frame);
+
+        if let Some(tracer) = &mut self.tracer {
+            let code_block = &self.frames[self.frames.len() - 1].code_block;
+            if tracer.should_trace(code_block) {
+                tracer.on_frame_push(code_block, self.frames.len());
+            }
+        }
+
+        if let Some(profiler) = &mut self.profiler {
+            profiler.enter(&self.frames[self.frames.len() - 1]);
+        }
     }
 
     pub(crate) fn push_frame_with_stack(
@@ -194,6 +245,16 @@ impl Vm {
         if let Some(frame) = &mut frame {
             std::mem::swap(&mut self.environments, &mut frame.environments);
             std::mem::swap(&mut self.realm, &mut frame.realm);
+
+            if let Some(tracer) = &mut self.tracer {
+                if tracer.should_trace(&frame.code_block) {
+                    tracer.on_frame_pop(&frame.code_block, self.frames.len());
+                }
+            }
+
+            if let Some(profiler) = &mut self.profiler {
+                profiler.exit();
+            }
         }
 
         frame
@@ -256,120 +317,46 @@ pub(crate) enum CompletionType {
     Yield,
 }
 
-#[cfg(feature = "trace")]
 impl Context {
-    const COLUMN_WIDTH: usize = 26;
-    const TIME_COLUMN_WIDTH: usize = Self::COLUMN_WIDTH / 2;
-    const OPCODE_COLUMN_WIDTH: usize = Self::COLUMN_WIDTH;
-    const OPERAND_COLUMN_WIDTH: usize = Self::COLUMN_WIDTH;
-    const NUMBER_OF_COLUMNS: usize = 4;
-
-    pub(crate) fn trace_call_frame(&self) {
-        let msg = if self.vm.frames.last().is_some() {
-            format!(
-                " Call Frame -- {} ",
-                self.vm.frame().code_block().name().to_std_string_escaped()
-            )
-        } else {
-            " VM Start ".to_string()
-        };
-
-        println!("{}", self.vm.frame().code_block);
-        println!(
-            "{msg:-^width$}",
-            width = Self::COLUMN_WIDTH * Self::NUMBER_OF_COLUMNS - 10
-        );
-        println!(
-            "{:<TIME_COLUMN_WIDTH$} {:<OPCODE_COLUMN_WIDTH$} {:<OPERAND_COLUMN_WIDTH$} Stack\n",
-            "Time",
-            "Opcode",
-            "Operands",
-            TIME_COLUMN_WIDTH = Self::TIME_COLUMN_WIDTH,
-            OPCODE_COLUMN_WIDTH = Self::OPCODE_COLUMN_WIDTH,
-            OPERAND_COLUMN_WIDTH = Self::OPERAND_COLUMN_WIDTH,
-        );
-    }
-
+    /// Executes the next instruction, reporting it to the installed [`Tracer`].
     fn trace_execute_instruction<F>(&mut self, f: F) -> JsResult<CompletionType>
     where
         F: FnOnce(Opcode, &mut Context) -> JsResult<CompletionType>,
     {
-        let bytecodes = &self.vm.frame().code_block.bytecode;
-        let pc = self.vm.frame().pc as usize;
-        let (_, varying_operand_kind, instruction) = InstructionIterator::with_pc(bytecodes, pc)
-            .next()
-            .expect("There should be an instruction left");
-        let operands = self
+        let frame = self.vm.frame();
+        let code_block = frame.code_block.clone();
+        let pc = frame.pc;
+        if !self
             .vm
-            .frame()
-            .code_block
-            .instruction_operands(&instruction);
-
-        let opcode = instruction.opcode();
-        match opcode {
-            Opcode::Call
-            | Opcode::CallSpread
-            | Opcode::CallEval
-            | Opcode::CallEvalSpread
-            | Opcode::New
-            | Opcode::NewSpread
-            | Opcode::Return
-            | Opcode::SuperCall
-            | Opcode::SuperCallSpread
-            | Opcode::SuperCallDerived => {
-                println!();
-            }
-            _ => {}
+            .tracer
+            .as_ref()
+            .is_some_and(|tracer| tracer.should_trace(&code_block))
+        {
+            return self.execute_instruction(f);
         }
 
+        let (_, operand_kind, instruction) =
+            InstructionIterator::with_pc(&code_block.bytecode, pc as usize)
+                .next()
+                .expect("There should be an instruction left");
+
         let instant = Instant::now();
         let result = self.execute_instruction(f);
         let duration = instant.elapsed();
 
-        let fp = self
-            .vm
-            .frames
-            .last()
-            .map(CallFrame::fp)
-            .map(|fp| fp as usize);
-
-        let stack = {
-            let mut stack = String::from("[ ");
-            for (i, (j, value)) in self.vm.stack.iter().enumerate().rev().enumerate() {
-                match value {
-                    value if value.is_callable() => stack.push_str("[function]"),
-                    value if value.is_object() => stack.push_str("[object]"),
-                    value => stack.push_str(&value.display().to_string()),
-                }
-
-                if fp == Some(j) {
-                    let frame_index = self.vm.frames.len() - 1;
-                    stack.push_str(&format!(" |{frame_index}|"));
-                } else if i + 1 != self.vm.stack.len() {
-                    stack.push(',');
-                }
-
-                stack.push(' ');
-            }
-
-            stack.push(']');
-            stack
-        };
-
-        let varying_operand_kind = match varying_operand_kind {
-            VaryingOperandKind::U8 => "",
-            VaryingOperandKind::U16 => ".U16",
-            VaryingOperandKind::U32 => ".U32",
-        };
-
-        println!(
-            "{:<TIME_COLUMN_WIDTH$} {:<OPCODE_COLUMN_WIDTH$} {operands:<OPERAND_COLUMN_WIDTH$} {stack}",
-            format!("{}μs", duration.as_micros()),
-            format!("{}{varying_operand_kind}", opcode.as_str()),
-            TIME_COLUMN_WIDTH = Self::TIME_COLUMN_WIDTH,
-            OPCODE_COLUMN_WIDTH = Self::OPCODE_COLUMN_WIDTH,
-            OPERAND_COLUMN_WIDTH = Self::OPERAND_COLUMN_WIDTH,
-        );
+        // The tracer could have been removed while executing the instruction.
+        if let Some(tracer) = &mut self.vm.tracer {
+            tracer.on_instruction(&TracedInstruction {
+                code_block: &code_block,
+                instruction: &instruction,
+                operand_kind,
+                pc,
+                stack: &self.vm.stack,
+                frame_pointer: self.vm.frames.last().map(|frame| frame.fp() as usize),
+                depth: self.vm.frames.len(),
+                duration,
+            });
+        }
 
         result
     }
@@ -410,19 +397,21 @@ impl Context {
             self.instructions_remaining -= 1;
         }
 
-        #[cfg(feature = "trace")]
-        let result = if self.vm.trace || self.vm.frame().code_block.traceable() {
+        if self.vm.debugger.is_active() {
+            self.check_pause();
+        }
+
+        let result = if self.vm.tracer.is_some() {
             self.trace_execute_instruction(f)
         } else {
             self.execute_instruction(f)
         };
 
-        #[cfg(not(feature = "trace"))]
-        let result = self.execute_instruction(f);
-
         let result = match result {
             Ok(result) => result,
             Err(err) => {
+                let err = err.with_stack_frames(self);
+
                 // If we hit the execution step limit, bubble up the error to the
                 // (Rust) caller instead of trying to handle as an exception.
                 if !err.is_catchable() {
@@ -498,6 +487,8 @@ impl Context {
                     }
 
                     if exit_early {
+                        self.vm.environments.truncate(env_fp as usize);
+                        self.vm.stack.truncate(fp as usize);
                         return ControlFlow::Break(CompletionRecord::Throw(
                             self.vm
                                 .pending_exception
@@ -532,11 +523,6 @@ impl Context {
     pub(crate) async fn run_async_with_budget(&mut self, budget: u32) -> CompletionRecord {
         let _timer = Profiler::global().start_event("run_async_with_budget", "vm");
 
-        #[cfg(feature = "trace")]
-        if self.vm.trace {
-            self.trace_call_frame();
-        }
-
         let mut runtime_budget: u32 = budget;
 
         loop {
@@ -557,11 +543,6 @@ impl Context {
     pub(crate) fn run(&mut self) -> CompletionRecord {
         let _timer = Profiler::global().start_event("run", "vm");
 
-        #[cfg(feature = "trace")]
-        if self.vm.trace {
-            self.trace_call_frame();
-        }
-
         loop {
             match self.execute_one(Opcode::execute) {
                 ControlFlow::Continue(()) => {}
@@ -572,16 +553,27 @@ impl Context {
 
     /// Checks if we haven't exceeded the defined runtime limits.
     pub(crate) fn check_runtime_limits(&self) -> JsResult<()> {
-        // Must throw if the number of recursive calls exceeds the defined limit.
-        if self.vm.runtime_limits.recursion_limit() <= self.vm.frames.len() {
-            return Err(JsNativeError::runtime_limit()
-                .with_message("exceeded maximum number of recursive calls")
+        self.vm.interrupt.check(self.vm.catchable_interrupts)?;
+
+        // Must throw if the number of recursive calls or the stack size exceeds the defined limits.
+        if self.vm.runtime_limits.recursion_limit() <= self.vm.frames.len()
+            || self.vm.runtime_limits.stack_size_limit() <= self.vm.stack.len()
+        {
+            return Err(JsNativeError::range()
+                .with_message("Maximum call stack size exceeded")
                 .into());
         }
-        // Must throw if the stack size exceeds the defined maximum length.
-        if self.vm.runtime_limits.stack_size_limit() <= self.vm.stack.len() {
+
+        Self::check_memory_limit()
+    }
+
+    /// Checks if the allocation hook of the garbage collector denied an allocation.
+    ///
+    /// See [`boa_gc::AllocationHook`].
+    pub(crate) fn check_memory_limit() -> JsResult<()> {
+        if boa_gc::take_denied_allocations() {
             return Err(JsNativeError::runtime_limit()
-                .with_message("exceeded maximum call stack length")
+                .with_message("Memory limit exceeded")
                 .into());
         }
 
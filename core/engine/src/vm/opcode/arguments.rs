@@ -31,7 +31,7 @@ impl Operation for CreateMappedArgumentsObject {
         let env = context.vm.environments.current();
         let arguments = MappedArguments::new(
             &function_object,
-            &code.params,
+            &code.parameter_names,
             &args,
             env.declarative_expect(),
             context,
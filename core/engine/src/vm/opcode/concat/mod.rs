@@ -17,12 +17,7 @@ impl ConcatToString {
             strings.push(context.vm.pop().to_string(context)?);
         }
         strings.reverse();
-        let s = JsString::concat_array(
-            &strings
-                .iter()
-                .map(JsString::as_slice)
-                .collect::<Vec<&[u16]>>(),
-        );
+        let s = JsString::concat_lazy_array(&strings);
         context.vm.push(s);
         Ok(CompletionType::Normal)
     }
@@ -16,7 +16,7 @@ impl Operation for Throw {
     const COST: u8 = 6;
 
     fn execute(context: &mut Context) -> JsResult<CompletionType> {
-        let error = JsError::from_opaque(context.vm.pop());
+        let error = JsError::from_opaque(context.vm.pop()).with_stack_frames(context);
         context.vm.pending_exception = Some(error);
 
         // Note: -1 because we increment after fetching the opcode.
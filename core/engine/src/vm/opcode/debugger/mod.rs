@@ -0,0 +1,22 @@
+use crate::{
+    vm::{opcode::Operation, CompletionType, PauseReason},
+    Context, JsResult,
+};
+
+/// `Debugger` implements the Opcode Operation for `Opcode::Debugger`
+///
+/// Operation:
+///  - Pauses the execution if a debug handler is installed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Debugger;
+
+impl Operation for Debugger {
+    const NAME: &'static str = "Debugger";
+    const INSTRUCTION: &'static str = "INST - Debugger";
+    const COST: u8 = 1;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        context.pause(PauseReason::DebuggerStatement);
+        Ok(CompletionType::Normal)
+    }
+}
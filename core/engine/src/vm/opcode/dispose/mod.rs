@@ -0,0 +1,180 @@
+use crate::{
+    builtins::disposable_stack::{suppress_error, DisposableResource, DisposeHint},
+    vm::{opcode::Operation, CompletionType, DisposeScope, GeneratorResumeKind},
+    Context, JsError, JsResult, JsValue,
+};
+
+/// `PushDisposeScope` implements the Opcode Operation for `Opcode::PushDisposeScope`
+///
+/// Operation:
+///  - Push a new scope for the resources of `using` declarations.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushDisposeScope;
+
+impl Operation for PushDisposeScope {
+    const NAME: &'static str = "PushDisposeScope";
+    const INSTRUCTION: &'static str = "INST - PushDisposeScope";
+    const COST: u8 = 1;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        context
+            .vm
+            .frame_mut()
+            .disposables
+            .push(DisposeScope::default());
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `AddDisposableResource` implements the Opcode Operation for `Opcode::AddDisposableResource`
+///
+/// Operation:
+///  - Add the value on the top of the stack as a resource of the current dispose scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddDisposableResource;
+
+impl Operation for AddDisposableResource {
+    const NAME: &'static str = "AddDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddDisposableResource";
+    const COST: u8 = 3;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let r#async = context.vm.read::<u8>() != 0;
+        let value = context.vm.pop();
+
+        let hint = if r#async {
+            DisposeHint::Async
+        } else {
+            DisposeHint::Sync
+        };
+
+        if let Some(resource) = DisposableResource::from_value(&value, hint, context)? {
+            context
+                .vm
+                .frame_mut()
+                .disposables
+                .last_mut()
+                .expect("must be inside a dispose scope")
+                .capability
+                .push(resource);
+        }
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `SetDisposeError` implements the Opcode Operation for `Opcode::SetDisposeError`
+///
+/// Operation:
+///  - Record the error thrown inside the current dispose scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SetDisposeError;
+
+impl Operation for SetDisposeError {
+    const NAME: &'static str = "SetDisposeError";
+    const INSTRUCTION: &'static str = "INST - SetDisposeError";
+    const COST: u8 = 1;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let error = JsError::from_opaque(context.vm.pop());
+        set_dispose_error(context, error);
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `DisposeResources` implements the Opcode Operation for `Opcode::DisposeResources`
+///
+/// Operation:
+///  - Pop the current dispose scope and dispose its resources, throwing the error of the scope
+///    if there is one.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeResources;
+
+impl Operation for DisposeResources {
+    const NAME: &'static str = "DisposeResources";
+    const INSTRUCTION: &'static str = "INST - DisposeResources";
+    const COST: u8 = 4;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let mut scope = context
+            .vm
+            .frame_mut()
+            .disposables
+            .pop()
+            .expect("must be inside a dispose scope");
+
+        let completion = scope.error.take().map_or(Ok(JsValue::undefined()), Err);
+        std::mem::take(&mut scope.capability).dispose_resources(completion, context)?;
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `DisposeNext` implements the Opcode Operation for `Opcode::DisposeNext`
+///
+/// Operation:
+///  - Dispose the resources of the current dispose scope until one of them must be awaited,
+///    then pop the scope, throwing the error of the scope if there is one.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeNext;
+
+impl Operation for DisposeNext {
+    const NAME: &'static str = "DisposeNext";
+    const INSTRUCTION: &'static str = "INST - DisposeNext";
+    const COST: u8 = 4;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let resume_kind = context.vm.pop().to_generator_resume_kind();
+        let value = context.vm.pop();
+
+        if resume_kind == GeneratorResumeKind::Throw {
+            set_dispose_error(context, JsError::from_opaque(value));
+        }
+
+        while let Some(resource) = context
+            .vm
+            .frame_mut()
+            .disposables
+            .last_mut()
+            .expect("must be inside a dispose scope")
+            .capability
+            .pop()
+        {
+            match resource.dispose(context) {
+                Ok(result) if resource.hint() == DisposeHint::Async => {
+                    context.vm.push(result);
+                    context.vm.push(false);
+                    return Ok(CompletionType::Normal);
+                }
+                Ok(_) => {}
+                Err(error) => set_dispose_error(context, error),
+            }
+        }
+
+        let mut scope = context
+            .vm
+            .frame_mut()
+            .disposables
+            .pop()
+            .expect("must be inside a dispose scope");
+
+        if let Some(error) = scope.error.take() {
+            return Err(error);
+        }
+
+        context.vm.push(JsValue::undefined());
+        context.vm.push(true);
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// Records `error` as the error of the current dispose scope, suppressing the previous error.
+fn set_dispose_error(context: &mut Context, error: JsError) {
+    let scope = context
+        .vm
+        .frame_mut()
+        .disposables
+        .last_mut()
+        .expect("must be inside a dispose scope");
+    scope.error = Some(suppress_error(error, scope.error.take()));
+}
@@ -46,6 +46,51 @@ impl Operation for GetName {
     }
 }
 
+/// `GetBinding` implements the Opcode Operation for `Opcode::GetBinding`
+///
+/// Operation:
+///  - Push the value of a binding that was resolved at compile time.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GetBinding;
+
+impl GetBinding {
+    fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
+        let locator = &context.vm.frame().code_block.bindings[index];
+        let value = context
+            .vm
+            .environments
+            .get_lexical_value(locator.environment_index(), locator.binding_index())
+            .ok_or_else(|| {
+                let name = locator.name().to_std_string_escaped();
+                JsNativeError::reference().with_message(format!("{name} is not defined"))
+            })?;
+
+        context.vm.push(value);
+        Ok(CompletionType::Normal)
+    }
+}
+
+impl Operation for GetBinding {
+    const NAME: &'static str = "GetBinding";
+    const INSTRUCTION: &'static str = "INST - GetBinding";
+    const COST: u8 = 2;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u8>();
+        Self::operation(context, index as usize)
+    }
+
+    fn execute_with_u16_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u16>() as usize;
+        Self::operation(context, index)
+    }
+
+    fn execute_with_u32_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u32>();
+        Self::operation(context, index as usize)
+    }
+}
+
 /// `GetLocator` implements the Opcode Operation for `Opcode::GetLocator`
 ///
 /// Operation:
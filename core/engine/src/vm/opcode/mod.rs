@@ -11,8 +11,10 @@ mod call;
 mod concat;
 mod control_flow;
 mod copy;
+mod debugger;
 mod define;
 mod delete;
+mod dispose;
 mod dup;
 mod environment;
 mod generator;
@@ -48,10 +50,14 @@ pub(crate) use control_flow::*;
 #[doc(inline)]
 pub(crate) use copy::*;
 #[doc(inline)]
+pub(crate) use debugger::*;
+#[doc(inline)]
 pub(crate) use define::*;
 #[doc(inline)]
 pub(crate) use delete::*;
 #[doc(inline)]
+pub(crate) use dispose::*;
+#[doc(inline)]
 pub(crate) use dup::*;
 #[doc(inline)]
 pub(crate) use environment::*;
@@ -1109,6 +1115,16 @@ generate_opcodes! {
     /// Stack: **=>** value
     GetName { index: VaryingOperand },
 
+    /// Push the value of a binding that was resolved at compile time.
+    ///
+    /// Unlike [`Opcode::GetName`], the binding is accessed by its indices without checking the
+    /// environments for bindings added at runtime.
+    ///
+    /// Operands: index: `u32`
+    ///
+    /// Stack: **=>** value
+    GetBinding { index: VaryingOperand },
+
     /// Find a binding on the environment and set the `current_binding` of the current frame.
     ///
     /// Operands: index: `u32`
@@ -1138,6 +1154,16 @@ generate_opcodes! {
     /// Stack: value **=>**
     SetName { index: VaryingOperand },
 
+    /// Assign the value of a binding that was resolved at compile time.
+    ///
+    /// Unlike [`Opcode::SetName`], the binding is accessed by its indices without checking the
+    /// environments for bindings added at runtime.
+    ///
+    /// Operands: index: `u32`
+    ///
+    /// Stack: value **=>**
+    SetBinding { index: VaryingOperand },
+
     /// Assigns a value to the binding pointed by the top of the `bindings_stack`.
     ///
     /// Stack: value **=>**
@@ -1672,6 +1698,28 @@ generate_opcodes! {
     /// Stack: `value` **=>**
     Default { address: u32 },
 
+    /// Pops the discriminant of a `switch` and jumps to the case that matches it, using a table
+    /// of consecutive integer cases.
+    ///
+    /// The discriminant `min + i` jumps to `addresses[i]`, any other value jumps to `default`.
+    ///
+    /// Operands: default: `u32`, min: `i32`, count: `u32`, address: `u32` * count
+    ///
+    /// Stack: value **=>**
+    SwitchInteger { default: u32, min: i32, addresses: ThinVec<u32> },
+
+    /// Pops the discriminant of a `switch` and jumps to the case that matches it, using a table
+    /// of string cases.
+    ///
+    /// The `keys` are indices of string constants, sorted by their value. The discriminant that
+    /// is equal to the string of `keys[i]` jumps to `addresses[i]`, any other value jumps to
+    /// `default`.
+    ///
+    /// Operands: default: `u32`, count: `u32`, key: `u32` * count, count: `u32`, address: `u32` * count
+    ///
+    /// Stack: value **=>**
+    SwitchString { default: u32, keys: ThinVec<u32>, addresses: ThinVec<u32> },
+
     /// Get function from the pre-compiled inner functions.
     ///
     /// Operands: index: `VaryingOperand`
@@ -2092,6 +2140,58 @@ generate_opcodes! {
     /// Stack: **=>**
     Nop,
 
+    /// Pauses the execution if a [`DebugHandler`](crate::vm::DebugHandler) is installed,
+    /// otherwise does nothing.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    Debugger,
+
+    /// Push a new scope for the resources of `using` declarations.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PushDisposeScope,
+
+    /// Performs [`AddDisposableResource ( disposeCapability, V, hint )`][spec] on the
+    /// current dispose scope.
+    ///
+    /// Operands: async: `bool`
+    ///
+    /// Stack: value **=>**
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    AddDisposableResource { r#async: bool },
+
+    /// Records the error thrown inside the current dispose scope.
+    ///
+    /// Operands:
+    ///
+    /// Stack: error **=>**
+    SetDisposeError,
+
+    /// Pops the current dispose scope and performs
+    /// [`DisposeResources ( disposeCapability, completion )`][spec] on it.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    DisposeResources,
+
+    /// Disposes the resources of the current dispose scope until one of them must be awaited.
+    ///
+    /// If `resume_kind` is `Throw`, `value` is recorded as an error of the scope. Once all the
+    /// resources are disposed, the scope is popped and its error is thrown, if any.
+    ///
+    /// Operands:
+    ///
+    /// Stack: `value`, `resume_kind` **=>** `result`, `done`
+    DisposeNext,
+
     /// Opcode prefix modifier, makes all [`VaryingOperand`]s of an instruction [`u16`] sized.
     ///
     /// Operands: opcode (operands if any).
@@ -2202,26 +2302,6 @@ generate_opcodes! {
     Reserved47 => Reserved,
     /// Reserved [`Opcode`].
     Reserved48 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved49 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved50 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved51 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved52 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved53 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved54 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved55 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved56 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved57 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved58 => Reserved,
 }
 
 /// Specific opcodes for bindings.
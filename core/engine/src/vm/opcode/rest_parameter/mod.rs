@@ -18,7 +18,7 @@ impl Operation for RestParameterInit {
 
     fn execute(context: &mut Context) -> JsResult<CompletionType> {
         let argument_count = context.vm.frame().argument_count as usize;
-        let param_count = context.vm.frame().code_block().params.as_ref().len();
+        let param_count = context.vm.frame().code_block().parameter_count as usize;
 
         let array = if argument_count >= param_count {
             let rest_count = argument_count - param_count + 1;
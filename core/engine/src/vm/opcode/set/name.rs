@@ -92,6 +92,61 @@ impl Operation for SetName {
     }
 }
 
+/// `SetBinding` implements the Opcode Operation for `Opcode::SetBinding`
+///
+/// Operation:
+///  - Assign the value of a binding that was resolved at compile time.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SetBinding;
+
+impl SetBinding {
+    fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
+        let value = context.vm.pop();
+        let locator = &context.vm.frame().code_block.bindings[index];
+        let (environment_index, binding_index) =
+            (locator.environment_index(), locator.binding_index());
+
+        if context
+            .vm
+            .environments
+            .get_lexical_value(environment_index, binding_index)
+            .is_none()
+        {
+            let name = locator.name().to_std_string_escaped();
+            return Err(JsNativeError::reference()
+                .with_message(format!("cannot assign to uninitialized binding `{name}`"))
+                .into());
+        }
+        context
+            .vm
+            .environments
+            .put_lexical_value(environment_index, binding_index, value);
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+impl Operation for SetBinding {
+    const NAME: &'static str = "SetBinding";
+    const INSTRUCTION: &'static str = "INST - SetBinding";
+    const COST: u8 = 2;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u8>();
+        Self::operation(context, index as usize)
+    }
+
+    fn execute_with_u16_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u16>() as usize;
+        Self::operation(context, index)
+    }
+
+    fn execute_with_u32_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u32>();
+        Self::operation(context, index as usize)
+    }
+}
+
 /// `SetNameByLocator` implements the Opcode Operation for `Opcode::SetNameByLocator`
 ///
 /// Operation:
@@ -169,6 +169,7 @@ impl Operation for SetPropertyByValue {
                             context.vm.push(value);
 
                             let len = dense_elements.len() as u32;
+                            object_borrowed.properties_mut().track_memory();
                             let length_key = PropertyKey::from(utf16!("length"));
                             let length = object_borrowed
                                 .properties_mut()
@@ -1,6 +1,8 @@
+use std::cmp::Ordering;
+
 use crate::{
     vm::{opcode::Operation, CompletionType},
-    Context, JsResult,
+    Context, JsResult, JsValue,
 };
 
 /// `Case` implements the Opcode Operation for `Opcode::Case`
@@ -49,3 +51,93 @@ impl Operation for Default {
         Ok(CompletionType::Normal)
     }
 }
+
+/// `SwitchInteger` implements the Opcode Operation for `Opcode::SwitchInteger`
+///
+/// Operation:
+///  - Pops the discriminant of a `switch` and jumps to the integer case that matches it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SwitchInteger;
+
+impl Operation for SwitchInteger {
+    const NAME: &'static str = "SwitchInteger";
+    const INSTRUCTION: &'static str = "INST - SwitchInteger";
+    const COST: u8 = 2;
+
+    #[allow(clippy::float_cmp)]
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let default = context.vm.read::<u32>();
+        let min = context.vm.read::<i32>();
+        let count = context.vm.read::<u32>();
+        let addresses = context.vm.frame().pc as usize;
+
+        // Strict equality doesn't distinguish `-0` from `+0`, and integral rationals from
+        // integers.
+        let value = match context.vm.pop() {
+            JsValue::Integer(value) => Some(value),
+            JsValue::Rational(value) if f64::from(value as i32) == value => Some(value as i32),
+            _ => None,
+        };
+
+        let index = value
+            .and_then(|value| value.checked_sub(min))
+            .and_then(|index| u32::try_from(index).ok())
+            .filter(|index| *index < count);
+
+        context.vm.frame_mut().pc = match index {
+            Some(index) => context
+                .vm
+                .frame()
+                .code_block()
+                .read::<u32>(addresses + index as usize * 4),
+            None => default,
+        };
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `SwitchString` implements the Opcode Operation for `Opcode::SwitchString`
+///
+/// Operation:
+///  - Pops the discriminant of a `switch` and jumps to the string case that matches it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SwitchString;
+
+impl Operation for SwitchString {
+    const NAME: &'static str = "SwitchString";
+    const INSTRUCTION: &'static str = "INST - SwitchString";
+    const COST: u8 = 3;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let default = context.vm.read::<u32>();
+        let count = context.vm.read::<u32>() as usize;
+        let keys = context.vm.frame().pc as usize;
+        let addresses = keys + count * 4 + 4;
+        context.vm.frame_mut().pc = (addresses + count * 4) as u32;
+
+        let value = context.vm.pop();
+        let Some(value) = value.as_string() else {
+            context.vm.frame_mut().pc = default;
+            return Ok(CompletionType::Normal);
+        };
+
+        let code_block = context.vm.frame().code_block();
+        let (mut low, mut high) = (0, count);
+        let mut target = default;
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let key = code_block.read::<u32>(keys + middle * 4);
+            match code_block.constant_string(key as usize).cmp(value) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => {
+                    target = code_block.read::<u32>(addresses + middle * 4);
+                    break;
+                }
+            }
+        }
+
+        context.vm.frame_mut().pc = target;
+        Ok(CompletionType::Normal)
+    }
+}
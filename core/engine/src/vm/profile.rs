@@ -0,0 +1,190 @@
+//! Aggregated profiles of the functions executed by the virtual machine.
+//!
+//! While profiling is enabled with [`Context::start_profiling`], the VM records how many times
+//! each [`CodeBlock`] is called and how much time is spent executing it. The results are
+//! returned by [`Context::take_profile`].
+//!
+//! [`Context::start_profiling`]: crate::Context::start_profiling
+//! [`Context::take_profile`]: crate::Context::take_profile
+
+use std::{path::Path, ptr, rc::Rc};
+
+use boa_gc::Gc;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    sys::time::{Duration, Instant},
+    vm::{CallFrame, CodeBlock},
+    JsString,
+};
+
+/// The report returned by [`Context::take_profile`].
+///
+/// [`Context::take_profile`]: crate::Context::take_profile
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    functions: Vec<FunctionProfile>,
+}
+
+impl Profile {
+    /// Gets the profiles of all the functions that were called, sorted by their self time, from
+    /// the highest one.
+    #[must_use]
+    pub fn functions(&self) -> &[FunctionProfile] {
+        &self.functions
+    }
+}
+
+/// The calls and execution time of a function, part of a [`Profile`].
+#[derive(Debug, Clone)]
+pub struct FunctionProfile {
+    name: JsString,
+    source_path: Option<Rc<Path>>,
+    calls: u64,
+    total: Duration,
+    self_time: Duration,
+}
+
+impl FunctionProfile {
+    /// Gets the name of the function.
+    ///
+    /// The top-level code of scripts and modules has the name `<main>`.
+    #[must_use]
+    pub const fn name(&self) -> &JsString {
+        &self.name
+    }
+
+    /// Gets the path of the source of the function, if any.
+    #[must_use]
+    pub fn source_path(&self) -> Option<&Path> {
+        self.source_path.as_deref()
+    }
+
+    /// Gets the number of times the function was called.
+    ///
+    /// Resuming a generator or an async function isn't counted as a new call.
+    #[must_use]
+    pub const fn calls(&self) -> u64 {
+        self.calls
+    }
+
+    /// Gets the time spent executing the function, including the functions called by it, in
+    /// nanoseconds.
+    ///
+    /// The time of recursive calls is only counted once, by the outermost call.
+    #[must_use]
+    pub fn total_nanos(&self) -> u128 {
+        self.total.as_nanos()
+    }
+
+    /// Gets the time spent executing the function itself, in nanoseconds.
+    ///
+    /// This excludes the time of the script functions called by it, but includes the time of the
+    /// native functions, since they aren't profiled.
+    #[must_use]
+    pub fn self_nanos(&self) -> u128 {
+        self.self_time.as_nanos()
+    }
+}
+
+/// The totals of a code block that has been called while profiling.
+#[derive(Debug)]
+struct FunctionRecord {
+    /// Keeps the code block alive, so its address isn't reused by another one.
+    code_block: Gc<CodeBlock>,
+    source_path: Option<Rc<Path>>,
+    calls: u64,
+
+    /// The number of calls of the code block that are being executed.
+    active: u32,
+    total: Duration,
+    self_time: Duration,
+}
+
+/// A frame pushed while profiling.
+#[derive(Debug)]
+struct ShadowFrame {
+    code_block: *const CodeBlock,
+    start: Instant,
+
+    /// The time spent in the frames pushed on top of this one.
+    children: Duration,
+}
+
+/// Records the calls of the code blocks, using a shadow stack of the frames pushed by the VM.
+#[derive(Debug, Default)]
+pub(crate) struct ProfileRecorder {
+    functions: FxHashMap<*const CodeBlock, FunctionRecord>,
+    stack: Vec<ShadowFrame>,
+}
+
+impl ProfileRecorder {
+    /// Records that `frame` has been pushed.
+    pub(crate) fn enter(&mut self, frame: &CallFrame) {
+        let code_block = ptr::addr_of!(**frame.code_block());
+        let record = self
+            .functions
+            .entry(code_block)
+            .or_insert_with(|| FunctionRecord {
+                code_block: frame.code_block().clone(),
+                source_path: frame.source_path_rc(),
+                calls: 0,
+                active: 0,
+                total: Duration::ZERO,
+                self_time: Duration::ZERO,
+            });
+        if !frame.registers_already_pushed() {
+            record.calls += 1;
+        }
+        record.active += 1;
+
+        self.stack.push(ShadowFrame {
+            code_block,
+            start: Instant::now(),
+            children: Duration::ZERO,
+        });
+    }
+
+    /// Records that the last frame has been popped.
+    pub(crate) fn exit(&mut self) {
+        // The frames pushed before profiling started aren't recorded.
+        let Some(frame) = self.stack.pop() else {
+            return;
+        };
+        let elapsed = frame.start.elapsed();
+        let record = self
+            .functions
+            .get_mut(&frame.code_block)
+            .expect("entered code blocks must have a record");
+
+        record.self_time += elapsed.saturating_sub(frame.children);
+        if record.active == 1 {
+            record.total += elapsed;
+        }
+        record.active -= 1;
+
+        if let Some(parent) = self.stack.last_mut() {
+            parent.children += elapsed;
+        }
+    }
+
+    /// Builds the report of the calls recorded so far.
+    ///
+    /// The frames that are still being executed are not included in the times.
+    pub(crate) fn finish(self) -> Profile {
+        let mut functions = self
+            .functions
+            .into_values()
+            .map(|record| FunctionProfile {
+                name: record.code_block.name().clone(),
+                source_path: record.source_path,
+                calls: record.calls,
+                total: record.total,
+                self_time: record.self_time,
+            })
+            .collect::<Vec<_>>();
+        functions.sort_by(|a, b| b.self_time.cmp(&a.self_time).then(b.calls.cmp(&a.calls)));
+
+        Profile { functions }
+    }
+}
@@ -1,3 +1,5 @@
+use boa_parser::parser::DEFAULT_NESTING_LIMIT;
+
 /// Represents the limits of different runtime operations.
 #[derive(Debug, Clone, Copy)]
 pub struct RuntimeLimits {
@@ -9,6 +11,9 @@ pub struct RuntimeLimits {
 
     /// Max function recursion limit
     resursion_limit: usize,
+
+    /// Max nesting depth of statements and expressions while parsing.
+    nesting_limit: usize,
 }
 
 impl Default for RuntimeLimits {
@@ -18,6 +23,7 @@ impl Default for RuntimeLimits {
             loop_iteration_limit: u64::MAX,
             resursion_limit: 512,
             stack_size_limit: 1024,
+            nesting_limit: DEFAULT_NESTING_LIMIT,
         }
     }
 }
@@ -75,4 +81,18 @@ impl RuntimeLimits {
     pub fn set_recursion_limit(&mut self, value: usize) {
         self.resursion_limit = value;
     }
+
+    /// Get the nesting limit of the parser.
+    #[inline]
+    #[must_use]
+    pub const fn nesting_limit(&self) -> usize {
+        self.nesting_limit
+    }
+
+    /// Set the maximum nesting depth of statements and expressions before a `SyntaxError` is
+    /// thrown while parsing.
+    #[inline]
+    pub fn set_nesting_limit(&mut self, value: usize) {
+        self.nesting_limit = value;
+    }
 }
@@ -1,9 +1,18 @@
 use crate::{
-    js_string, property::Attribute, run_test_actions, Context, JsNativeErrorKind, JsValue,
-    TestAction,
+    js_string,
+    optimizer::OptimizerOptions,
+    property::Attribute,
+    run_test_actions, run_test_actions_with,
+    script::Script,
+    vm::{
+        CodeBlock, DebugAction, DebugHandler, Disassembly, PauseReason, PausedFrame,
+        TracedInstruction, Tracer,
+    },
+    Context, JsNativeError, JsNativeErrorKind, JsResult, JsValue, NativeFunction, TestAction,
 };
 use boa_parser::Source;
 use indoc::indoc;
+use std::{cell::RefCell, path::Path, rc::Rc};
 
 #[test]
 fn typeof_string() {
@@ -296,8 +305,8 @@ fn recursion_runtime_limit() {
         }),
         TestAction::assert_native_error(
             "factorial(11)",
-            JsNativeErrorKind::RuntimeLimit,
-            "exceeded maximum number of recursive calls",
+            JsNativeErrorKind::Range,
+            "Maximum call stack size exceeded",
         ),
         TestAction::assert_eq("factorial(8)", JsValue::new(40_320)),
         TestAction::assert_native_error(
@@ -308,8 +317,59 @@ fn recursion_runtime_limit() {
 
                 x()
             "#},
-            JsNativeErrorKind::RuntimeLimit,
-            "exceeded maximum number of recursive calls",
+            JsNativeErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+    ]);
+}
+
+#[test]
+fn unbounded_recursion_throws_range_error() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "function f() { return f() } f()",
+            JsNativeErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+        TestAction::assert_native_error(
+            "function g() { [1].forEach(g) } g()",
+            JsNativeErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                function h() { return h() }
+                try { h() } catch (e) { e instanceof RangeError }
+            "#},
+            true,
+        ),
+    ]);
+}
+
+#[test]
+fn parser_nesting_limit() {
+    let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+
+    run_test_actions([
+        TestAction::assert_eq(nested(50), 1),
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_nesting_limit(10);
+        }),
+        TestAction::assert_eq(nested(5), 1),
+        TestAction::assert_native_error(
+            nested(100_000),
+            JsNativeErrorKind::Syntax,
+            "maximum nesting depth exceeded at line 1, col 10",
+        ),
+        TestAction::assert_native_error(
+            format!("{}1", "- ".repeat(100_000)),
+            JsNativeErrorKind::Syntax,
+            "maximum nesting depth exceeded at line 1, col 17",
+        ),
+        TestAction::assert_native_error(
+            format!("{}{}", "{".repeat(100_000), "}".repeat(100_000)),
+            JsNativeErrorKind::Syntax,
+            "maximum nesting depth exceeded at line 1, col 11",
         ),
     ]);
 }
@@ -431,3 +491,727 @@ fn long_object_chain_gc_trace_stack_overflow() {
         TestAction::inspect_context(|_| boa_gc::force_collect()),
     ]);
 }
+
+#[test]
+fn interrupt_infinite_loop() {
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
+
+    let context = &mut Context::default();
+
+    let interrupt_after = |context: &Context, delay: u64| {
+        let handle = context.interrupt_handle();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(delay));
+            handle.interrupt();
+        })
+    };
+
+    let start = Instant::now();
+    let thread = interrupt_after(context, 50);
+    let err = context
+        .eval(Source::from_bytes(
+            "try { while (true) {} } catch (e) { 'caught' }",
+        ))
+        .unwrap_err();
+    thread.join().unwrap();
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert!(err.as_native().is_some_and(JsNativeError::is_runtime_limit));
+    assert!(!context.interrupt_handle().is_interrupted());
+
+    context.set_catchable_interrupts(true);
+    let thread = interrupt_after(context, 50);
+    let result = context
+        .eval(Source::from_bytes(indoc! {r#"
+            function spin() { for (;;) {} }
+            try { spin() } catch (e) { e.message }
+        "#}))
+        .unwrap();
+    thread.join().unwrap();
+    assert_eq!(result, js_string!("execution interrupted").into());
+
+    // The context can still be used after an interruption.
+    assert_eq!(
+        context.eval(Source::from_bytes("1 + 1")).unwrap(),
+        JsValue::new(2)
+    );
+}
+
+#[test]
+fn gc_stats_include_strings_and_property_storage() {
+    let context = &mut Context::default();
+
+    let before = boa_gc::stats();
+    context
+        .eval(Source::from_bytes("globalThis.text = 'x'.repeat(1 << 20);"))
+        .unwrap();
+    let strings = boa_gc::stats()
+        .string_bytes
+        .saturating_sub(before.string_bytes);
+    assert!(
+        (1 << 20..3 << 20).contains(&strings),
+        "a string of 1 MiB reported {strings} bytes"
+    );
+
+    let before = boa_gc::stats();
+    context
+        .eval(Source::from_bytes(
+            "globalThis.array = new Array(100000).fill(0);",
+        ))
+        .unwrap();
+    let objects = boa_gc::stats()
+        .object_bytes
+        .saturating_sub(before.object_bytes);
+    let expected = 100_000 * std::mem::size_of::<JsValue>();
+    assert!(
+        (expected..expected * 3).contains(&objects),
+        "an array of 100000 elements reported {objects} bytes"
+    );
+}
+
+#[test]
+fn allocation_hook_limits_memory() {
+    use boa_gc::{AllocationAction, AllocationHook, GcStats};
+
+    struct Limit(usize);
+
+    impl AllocationHook for Limit {
+        fn on_allocation(&self, stats: &GcStats, size: usize) -> AllocationAction {
+            if stats.bytes_allocated + size > self.0 {
+                AllocationAction::Deny
+            } else {
+                AllocationAction::Continue
+            }
+        }
+    }
+
+    let context = &mut Context::default();
+    let limit = boa_gc::stats().bytes_allocated + (8 << 20);
+    boa_gc::set_allocation_hook(Some(Rc::new(Limit(limit))));
+
+    run_test_actions_with(
+        [
+            TestAction::assert_native_error(
+                "const objects = []; while (true) { objects.push({}); }",
+                JsNativeErrorKind::RuntimeLimit,
+                "Memory limit exceeded",
+            ),
+            TestAction::inspect_context(|_| boa_gc::set_allocation_hook(None)),
+            TestAction::assert_eq("[1, 2, 3].length", 3),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn defer_gc_delays_collections() {
+    let context = &mut Context::default();
+    context.set_gc_config(boa_gc::GcConfig {
+        threshold: 0,
+        max_deferred_bytes: 1 << 20,
+        ..boa_gc::GcConfig::default()
+    });
+
+    let collections = boa_gc::stats().collections;
+    let value = context
+        .defer_gc(|context| {
+            context.gc();
+            let value = context.eval(Source::from_bytes(
+                "const objects = []; for (let i = 0; i < 100; i++) { objects.push({}); } objects.length",
+            ));
+            assert_eq!(boa_gc::stats().collections, collections);
+            value
+        })
+        .unwrap()
+        .unwrap();
+    assert_eq!(value, JsValue::new(100));
+    assert!(boa_gc::stats().collections > collections);
+
+    let error = context
+        .defer_gc(|context| {
+            context.eval(Source::from_bytes(
+                "const values = []; for (let i = 0; i < 100000; i++) { values.push({}); }",
+            ))
+        })
+        .and_then(|result| result)
+        .unwrap_err();
+    let error = error.as_native().unwrap();
+    assert_eq!(error.kind, JsNativeErrorKind::RuntimeLimit);
+    assert_eq!(error.message(), "Memory limit exceeded");
+
+    context.set_gc_config(boa_gc::GcConfig::default());
+}
+
+#[test]
+fn peephole_preserves_behavior() {
+    const CASES: &[&str] = &[
+        "let s = 0; for (let i = 0; !(i >= 10); i++) { if (!(i % 2)) continue; s += i; } s",
+        "(function (x) { if (x) { return 'a'; } else { return 'b'; } return 'c'; })(0)",
+        "(function () { try { throw 1; return 2; } catch (e) { return e + 1; } finally { 3; } })()",
+        "(function () { try { return 'try'; } finally { globalThis.fin = 'fin'; } })() + fin",
+        "let r = ''; outer: for (const a of [1, 2, 3]) { for (const b of [1, 2]) { if (b == 2) continue outer; if (a == 3) break outer; r += a + '' + b; } } r",
+        "let t = ''; switch (3) { case 1: t += 1; case 3: t += 3; case 4: t += 4; break; default: t += 'd'; } t",
+        "[...(function* () { try { yield 1; yield* [2, 3]; } finally { 4; } })()].join()",
+        "!!0 + ' ' + !1 + ' ' + !!!'' + ' ' + (!(1 > 2) ? 'y' : 'n')",
+        "let o = { a: { b: null } }; `${o?.a?.b?.c} ${o.x ?? 'nullish'} ${o.a && 5} ${0 || 'or'}`",
+        "label: { try { break label; } finally { 1; } 2; } 3",
+        "let n = 0; do { n++; } while (!(n > 4)); n",
+        "eval('1; if (true) { 2 } else { 3 }') + eval('try { 4 } finally { 5 }')",
+    ];
+
+    for case in CASES {
+        let results = [OptimizerOptions::PEEPHOLE, OptimizerOptions::empty()].map(|options| {
+            let context = &mut Context::default();
+            context.set_optimizer_options(options);
+            context
+                .eval(Source::from_bytes(case))
+                .unwrap_or_else(|err| panic!("`{case}` failed: {err}"))
+                .display()
+                .to_string()
+        });
+        assert_eq!(results[0], results[1], "`{case}` changed behavior");
+    }
+}
+
+#[test]
+fn constant_folding_preserves_behavior() {
+    const CASES: &[&str] = &[
+        "2 * 60 * 60",
+        "0.1 + 0.2",
+        "1 / (0 * -5)",
+        "1 / (-3 * 0)",
+        "1 / (0 / -5)",
+        "1 / (-4 % 2)",
+        "1 / -0",
+        "1 / -false",
+        "1 / -null",
+        "-true",
+        "-2147483648 * -1",
+        "-(-2147483648)",
+        "-2147483648 % -1",
+        "-2147483648 / -1",
+        "2 ** 31",
+        "2 ** -1",
+        "NaN === NaN",
+        "0 / 0",
+        "-1 / 0",
+        "1 ** Infinity",
+        "'a' + 'b' + 1 + 2",
+        "1 + 2 + 'a'",
+        "!true + !0 + !''",
+        "~5 + ~-1",
+        "1 << 31",
+        "-1 >>> 0",
+        "typeof null + typeof 1n + typeof 'a'",
+        "'10' == 10",
+        "null == undefined",
+        "null >= 0",
+        "'b' > 'a'",
+        "2n ** 64n",
+        "void 0",
+    ];
+
+    // Longer than the size limit of folded strings.
+    let long = format!("'{}' + 'b' + 'c'", "a".repeat(2000));
+
+    for case in CASES.iter().copied().chain([long.as_str()]) {
+        let results = [
+            OptimizerOptions::CONSTANT_FOLDING,
+            OptimizerOptions::empty(),
+        ]
+        .map(|options| {
+            let context = &mut Context::default();
+            context.set_optimizer_options(options);
+            context
+                .eval(Source::from_bytes(case))
+                .unwrap_or_else(|err| panic!("`{case}` failed: {err}"))
+                .display()
+                .to_string()
+        });
+        assert_eq!(results[0], results[1], "`{case}` changed behavior");
+    }
+}
+
+#[test]
+fn constant_folding_keeps_runtime_errors() {
+    for case in ["+1n", "1n + 1", "1n / 0n", "'a' in 'b'", "1 instanceof 2"] {
+        let context = &mut Context::default();
+        context.set_optimizer_options(OptimizerOptions::CONSTANT_FOLDING);
+        assert!(
+            context.eval(Source::from_bytes(case)).is_err(),
+            "`{case}` should throw"
+        );
+    }
+}
+
+#[test]
+fn peephole_shrinks_bytecode() {
+    let bytecode_len = |options: OptimizerOptions| {
+        let context = &mut Context::default();
+        context.set_optimizer_options(options);
+        let script = Script::parse(
+            Source::from_bytes("if (!a) { b(); } else { c(); } while (!d) { e(); }"),
+            None,
+            context,
+        )
+        .unwrap();
+        script.codeblock(context).unwrap().bytecode.len()
+    };
+
+    assert!(bytecode_len(OptimizerOptions::PEEPHOLE) < bytecode_len(OptimizerOptions::empty()));
+}
+
+#[derive(Debug, Default)]
+struct TraceEvents {
+    pushed: Vec<String>,
+    popped: usize,
+    opcodes: Vec<&'static str>,
+}
+
+struct RecordingTracer(Rc<RefCell<TraceEvents>>);
+
+impl Tracer for RecordingTracer {
+    fn on_frame_push(&mut self, code_block: &CodeBlock, _depth: usize) {
+        self.0
+            .borrow_mut()
+            .pushed
+            .push(code_block.name().to_std_string_escaped());
+    }
+
+    fn on_frame_pop(&mut self, _code_block: &CodeBlock, _depth: usize) {
+        self.0.borrow_mut().popped += 1;
+    }
+
+    fn on_instruction(&mut self, instruction: &TracedInstruction<'_>) {
+        self.0.borrow_mut().opcodes.push(instruction.opcode());
+    }
+}
+
+#[test]
+fn tracer_observes_execution() {
+    let events = Rc::new(RefCell::new(TraceEvents::default()));
+    let context = &mut Context::default();
+    context.set_tracer(Box::new(RecordingTracer(events.clone())));
+
+    let result = context
+        .eval(Source::from_bytes(
+            "function f(a) { return a + 1; } f(1) + f(2);",
+        ))
+        .unwrap();
+    assert_eq!(result, JsValue::new(5));
+
+    {
+        let events = events.borrow();
+        assert_eq!(events.pushed, ["<main>", "f", "f"]);
+        assert_eq!(events.popped, 3);
+        assert_eq!(events.opcodes.first(), Some(&"PushUndefined"));
+        assert_eq!(events.opcodes.last(), Some(&"Return"));
+        assert_eq!(events.opcodes.iter().filter(|op| **op == "Add").count(), 3);
+    }
+
+    assert!(context.take_tracer().is_some());
+    let opcodes = events.borrow().opcodes.len();
+    context.eval(Source::from_bytes("f(3)")).unwrap();
+    assert_eq!(events.borrow().opcodes.len(), opcodes);
+}
+
+#[test]
+fn stack_frames_include_native_and_resumed_frames() {
+    #[allow(clippy::unnecessary_wraps)]
+    fn frames(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let frames = context
+            .stack_frames()
+            .map(|frame| {
+                format!(
+                    "{:?} {} {} {} {:?} {}",
+                    frame.kind(),
+                    frame.function_name().to_std_string_escaped(),
+                    frame.argument_count(),
+                    frame.receiver().map_or("-", JsValue::type_of),
+                    frame.line(),
+                    frame.is_resumed(),
+                )
+            })
+            .collect::<Vec<_>>();
+        Ok(js_string!(frames.join("\n")).into())
+    }
+
+    let context = &mut Context::default();
+    context
+        .register_global_builtin_callable(
+            js_string!("frames"),
+            0,
+            NativeFunction::from_fn_ptr(frames),
+        )
+        .unwrap();
+
+    let result = context
+        .eval(Source::from_bytes(indoc! {r#"
+            function outer(a, b) {
+                return [1].map(function inner() { return frames(1); })[0];
+            }
+            outer.call(7, 1, 2);
+        "#}))
+        .unwrap();
+    assert_eq!(
+        result.as_string().unwrap().to_std_string_escaped(),
+        indoc! {"
+            Native frames 1 undefined None false
+            Function inner 3 undefined Some(2) false
+            Native map 1 object None false
+            Function outer 2 number Some(2) false
+            Native call 3 function None false
+            Script <main> 0 - Some(4) false"}
+    );
+
+    let result = context
+        .eval(Source::from_bytes(indoc! {r#"
+            function* gen() {
+                yield 1;
+                yield frames();
+            }
+            const it = gen();
+            it.next();
+            it.next().value;
+        "#}))
+        .unwrap();
+    assert_eq!(
+        result.as_string().unwrap().to_std_string_escaped(),
+        indoc! {"
+            Native frames 0 undefined None false
+            Generator gen 0 undefined Some(3) true
+            Native next 0 object None false
+            Script <main> 0 - Some(7) false"}
+    );
+}
+
+#[test]
+fn stack_frame_columns() {
+    #[allow(clippy::unnecessary_wraps)]
+    fn positions(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let positions = context
+            .stack_frames()
+            .filter(|frame| !frame.is_native())
+            .map(|frame| format!("{:?}:{:?}", frame.line(), frame.column()))
+            .collect::<Vec<_>>();
+        Ok(js_string!(positions.join(" ")).into())
+    }
+
+    let context = &mut Context::default();
+    context
+        .register_global_builtin_callable(
+            js_string!("positions"),
+            0,
+            NativeFunction::from_fn_ptr(positions),
+        )
+        .unwrap();
+
+    let result = context
+        .eval(Source::from_bytes(indoc! {r#"
+            function f() {
+                return positions();
+            }
+            let x = 1;  f();
+        "#}))
+        .unwrap();
+    assert_eq!(
+        result.as_string().unwrap().to_std_string_escaped(),
+        "Some(2):Some(5) Some(4):Some(13)"
+    );
+}
+
+#[test]
+fn profile_attributes_recursive_and_reentrant_calls() {
+    let context = &mut Context::default();
+    context.start_profiling();
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            function fib(n) {
+                return n < 2 ? n : fib(n - 1) + fib(n - 2);
+            }
+            function apply(f) {
+                return [10].map(f)[0];
+            }
+            apply(fib);
+        "#}))
+        .unwrap();
+
+    let profile = context.take_profile().unwrap();
+    let function = |name: &str| {
+        profile
+            .functions()
+            .iter()
+            .find(|function| function.name().to_std_string_escaped() == name)
+            .unwrap()
+    };
+    let (main, apply, fib) = (function("<main>"), function("apply"), function("fib"));
+    assert_eq!(profile.functions().len(), 3);
+    assert_eq!((main.calls(), apply.calls(), fib.calls()), (1, 1, 177));
+
+    // The time of the recursive calls is only counted once, and the time of `fib` isn't
+    // counted as time of `apply`, even though it is called by the native `map`.
+    assert_eq!(fib.self_nanos(), fib.total_nanos());
+    assert_eq!(apply.total_nanos(), apply.self_nanos() + fib.total_nanos());
+    assert!(main.total_nanos() >= apply.total_nanos());
+
+    assert!(context.take_profile().is_none());
+}
+
+#[test]
+fn breakpoint_mutates_local() {
+    struct SkipIterations(Rc<RefCell<Vec<JsValue>>>);
+
+    impl DebugHandler for SkipIterations {
+        fn on_pause(&mut self, frame: &mut PausedFrame<'_>) -> DebugAction {
+            assert_eq!(frame.reason(), PauseReason::Breakpoint);
+            assert_eq!(frame.line(), Some(3));
+            assert_eq!(frame.source_path(), Some(Path::new("loop.js")));
+            assert_eq!(frame.local("missing").unwrap(), None);
+
+            let i = frame.local("i").unwrap().unwrap();
+            if self.0.borrow().is_empty() {
+                assert!(frame.set_local("i", JsValue::new(3)).unwrap());
+            }
+            self.0.borrow_mut().push(i);
+            DebugAction::Continue
+        }
+    }
+
+    let source = indoc! {r#"
+        let sum = 0;
+        for (let i = 0; i < 5; i++) {
+            sum += i;
+        }
+        sum;
+    "#};
+
+    let hits = Rc::new(RefCell::new(Vec::new()));
+    let context = &mut Context::default();
+    context.set_debug_handler(Box::new(SkipIterations(hits.clone())));
+    context.set_breakpoint(Path::new("loop.js"), 3);
+
+    let result = context
+        .eval(Source::from_reader(
+            source.as_bytes(),
+            Some(Path::new("loop.js")),
+        ))
+        .unwrap();
+
+    assert_eq!(result, JsValue::new(7));
+    assert_eq!(*hits.borrow(), [JsValue::new(0), JsValue::new(4)]);
+
+    assert!(context.remove_breakpoint(Path::new("loop.js"), 3));
+    assert!(!context.remove_breakpoint(Path::new("loop.js"), 3));
+}
+
+#[test]
+fn debugger_statement_and_steps() {
+    type Pause = (PauseReason, Option<u32>, String);
+
+    struct Stepper {
+        actions: Vec<DebugAction>,
+        pauses: Rc<RefCell<Vec<Pause>>>,
+    }
+
+    impl DebugHandler for Stepper {
+        fn on_pause(&mut self, frame: &mut PausedFrame<'_>) -> DebugAction {
+            self.pauses.borrow_mut().push((
+                frame.reason(),
+                frame.line(),
+                frame.function_name().to_std_string_escaped(),
+            ));
+            self.actions.pop().unwrap_or_default()
+        }
+    }
+
+    let source = indoc! {r#"
+        function f(x) {
+            if (x) debugger;
+            let a = x;
+            return a;
+        }
+        f(true);
+        f(false);
+    "#};
+
+    let pauses = Rc::new(RefCell::new(Vec::new()));
+    let context = &mut Context::default();
+    context.set_debug_handler(Box::new(Stepper {
+        actions: vec![
+            DebugAction::StepInto,
+            DebugAction::StepOut,
+            DebugAction::StepOver,
+        ],
+        pauses: pauses.clone(),
+    }));
+
+    context
+        .eval(Source::from_reader(
+            source.as_bytes(),
+            Some(Path::new("steps.js")),
+        ))
+        .unwrap();
+
+    let main = || "<main>".to_owned();
+    let f = || "f".to_owned();
+    assert_eq!(
+        *pauses.borrow(),
+        [
+            (PauseReason::DebuggerStatement, Some(2), f()),
+            (PauseReason::Step, Some(3), f()),
+            (PauseReason::Step, Some(7), main()),
+            (PauseReason::Step, Some(2), f()),
+        ]
+    );
+
+    // Without a handler `debugger` statements do nothing.
+    assert!(context.take_debug_handler().is_some());
+    context.eval(Source::from_bytes("f(true)")).unwrap();
+    assert_eq!(pauses.borrow().len(), 4);
+}
+
+#[test]
+fn eval_does_not_grow_interner() {
+    let context = &mut Context::default();
+    context
+        .eval(Source::from_bytes(
+            "function read(name) { return globalThis[name]; }",
+        ))
+        .unwrap();
+
+    let len = context.interner().len();
+    for i in 0..10_000 {
+        let value = context
+            .eval(Source::from_bytes(&format!(
+                "var value{i} = {{ property{i}: 'string{i}' }}; read('value{i}').property{i}"
+            )))
+            .unwrap();
+        assert_eq!(value, JsValue::from(js_string!(format!("string{i}"))));
+    }
+    assert_eq!(context.interner().len(), len);
+
+    let value = context
+        .eval(Source::from_bytes(
+            "value9999.property9999 + read('value0').property0",
+        ))
+        .unwrap();
+    assert_eq!(value, JsValue::from(js_string!("string9999string0")));
+}
+
+#[test]
+fn disassembly_labels_jumps_and_nests_functions() {
+    let context = &mut Context::default();
+    let source = Source::from_bytes(indoc! {r#"
+        function add(a, b) { return a + b; }
+        let value = "text";
+        if (add(1, 2) > 2) { value = 10n; }
+        try { throw value; } catch {}
+    "#});
+    let script = Script::parse(source, None, context).unwrap();
+    let disassembly = script.codeblock(context).unwrap().disassemble();
+
+    assert_eq!(disassembly.name, "<main>");
+    let [add]: &[Disassembly] = &disassembly.functions[..] else {
+        panic!("expected a single nested function");
+    };
+    assert_eq!((add.name.as_str(), add.length), ("add", 2));
+
+    // Literals are inlined.
+    let has_operand = |operand: &str| {
+        disassembly
+            .instructions
+            .iter()
+            .any(|instruction| instruction.operands == operand)
+    };
+    assert!(has_operand("\"text\""));
+    assert!(has_operand("10n"));
+
+    // Jumps and handlers refer to labels of instructions.
+    let is_label = |label: &str| {
+        disassembly
+            .instructions
+            .iter()
+            .any(|instruction| instruction.label.as_deref() == Some(label))
+    };
+    let jumps: Vec<_> = disassembly
+        .instructions
+        .iter()
+        .filter(|instruction| instruction.opcode.starts_with("JumpIf"))
+        .collect();
+    assert!(!jumps.is_empty());
+    assert!(jumps.iter().all(|jump| is_label(&jump.operands)));
+    assert!(!disassembly.handlers.is_empty());
+    assert!(disassembly
+        .handlers
+        .iter()
+        .all(|handler| is_label(&handler.target)));
+
+    let text = disassembly.to_string();
+    assert!(text.starts_with("function <main> (length: 0)\n"));
+    assert!(text.contains("\n    function add (length: 2)\n"));
+
+    let json = serde_json::to_value(&disassembly).unwrap();
+    assert_eq!(json["functions"][0]["name"], "add");
+    assert_eq!(
+        json["instructions"].as_array().map(Vec::len),
+        Some(disassembly.instructions.len())
+    );
+}
+
+#[cfg(feature = "bytecode-cache")]
+#[test]
+fn code_block_cache_round_trip() {
+    const SOURCE: &str = indoc! {r#"
+        const base = 10n;
+        let counter = 0;
+        var caught = false;
+        function add(a, b) { arguments[0] = 5; return a + b; }
+        function sum(...rest) { return rest.reduce((x, y) => x + y, 0); }
+        function* range(n) { for (let i = 0; i < n; i++) yield i; }
+        function tag(strings) { return strings; }
+        function site() { return tag`a${1}b`; }
+        class Point { #x; constructor(x) { this.#x = x; } get x() { return this.#x; } }
+        for (const i of range(4)) { counter += i; }
+        try { null.x; } catch (e) { caught = e instanceof TypeError; }
+        [
+            add(1, 2), sum(1, 2, 3), counter, caught, new Point(7).x, base * 2n,
+            site() === site(), add.toString()
+        ].join("|");
+    "#};
+
+    let context = &mut Context::default();
+    let script = Script::parse(Source::from_bytes(SOURCE), None, context).unwrap();
+    let expected = script.evaluate(context).unwrap();
+    assert_eq!(
+        expected,
+        js_string!("7|6|6|true|7|20|true|function add(a, b) { arguments[0] = 5; return a + b; }")
+            .into()
+    );
+    let bytes = script.codeblock(context).unwrap().to_bytes();
+    assert!(crate::vm::is_cache_of(&bytes, SOURCE));
+
+    // The code runs in a fresh context without parsing the source.
+    let context = &mut Context::default();
+    let code = CodeBlock::from_bytes(&bytes, context).unwrap();
+    assert_eq!(code.to_bytes(), bytes);
+
+    let context = &mut Context::default();
+    let mut cache = bytes.clone();
+    let script = context.compile_cached(SOURCE, &mut cache).unwrap();
+    assert_eq!(script.evaluate(context).unwrap(), expected);
+    assert_eq!(cache, bytes);
+
+    // Corrupted caches and caches of other sources are rejected.
+    let mut corrupted = bytes.clone();
+    *corrupted.last_mut().unwrap() ^= 1;
+    let context = &mut Context::default();
+    assert!(CodeBlock::from_bytes(&corrupted, context).is_err());
+    assert!(!crate::vm::is_cache_of(&bytes, "1 + 1"));
+
+    let context = &mut Context::default();
+    let script = context.compile_cached(SOURCE, &mut corrupted).unwrap();
+    assert_eq!(script.evaluate(context).unwrap(), expected);
+    assert_eq!(corrupted, bytes);
+}
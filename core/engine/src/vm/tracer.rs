@@ -0,0 +1,128 @@
+//! Hooks to observe the execution of the virtual machine.
+//!
+//! A [`Tracer`] installed with [`Context::set_tracer`] is notified of every frame pushed and
+//! popped by the VM, and of every instruction it executes.
+//!
+//! [`Context::set_tracer`]: crate::Context::set_tracer
+
+use std::fmt;
+
+use crate::{
+    sys::time::Duration,
+    vm::{CodeBlock, Instruction, VaryingOperandKind},
+    JsString, JsValue,
+};
+
+/// Receives the events of the execution of the VM.
+///
+/// All the methods have empty default implementations, so a tracer only has to implement the
+/// events it is interested in.
+pub trait Tracer {
+    /// Returns `true` if the execution of `code_block` should be reported to this tracer.
+    ///
+    /// This is checked before reporting every event, so tracing can be enabled and disabled at
+    /// any time. By default all code blocks are traced.
+    fn should_trace(&self, _code_block: &CodeBlock) -> bool {
+        true
+    }
+
+    /// Called after a frame that executes `code_block` is pushed.
+    ///
+    /// `depth` is the number of frames on the stack, including the new frame.
+    fn on_frame_push(&mut self, _code_block: &CodeBlock, _depth: usize) {}
+
+    /// Called after the frame that executes `code_block` is popped.
+    ///
+    /// `depth` is the number of frames that remain on the stack.
+    fn on_frame_pop(&mut self, _code_block: &CodeBlock, _depth: usize) {}
+
+    /// Called after an instruction is executed.
+    fn on_instruction(&mut self, _instruction: &TracedInstruction<'_>) {}
+}
+
+impl fmt::Debug for dyn Tracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Tracer")
+    }
+}
+
+/// An instruction executed by the VM, reported to [`Tracer::on_instruction`].
+#[derive(Debug)]
+pub struct TracedInstruction<'a> {
+    pub(crate) code_block: &'a CodeBlock,
+    pub(crate) instruction: &'a Instruction,
+    pub(crate) operand_kind: VaryingOperandKind,
+    pub(crate) pc: u32,
+    pub(crate) stack: &'a [JsValue],
+    pub(crate) frame_pointer: Option<usize>,
+    pub(crate) depth: usize,
+    pub(crate) duration: Duration,
+}
+
+impl TracedInstruction<'_> {
+    /// Returns the code block that contains the instruction.
+    #[must_use]
+    pub const fn code_block(&self) -> &CodeBlock {
+        self.code_block
+    }
+
+    /// Returns the name of the function that contains the instruction.
+    #[must_use]
+    pub const fn function_name(&self) -> &JsString {
+        self.code_block.name()
+    }
+
+    /// Returns the address of the instruction in the bytecode of its code block.
+    #[must_use]
+    pub const fn pc(&self) -> u32 {
+        self.pc
+    }
+
+    /// Returns the name of the opcode of the instruction.
+    #[must_use]
+    pub fn opcode(&self) -> &'static str {
+        self.instruction.opcode().as_str()
+    }
+
+    /// Returns the size in bytes of the varying operands of the instruction.
+    #[must_use]
+    pub const fn operand_width(&self) -> u8 {
+        match self.operand_kind {
+            VaryingOperandKind::U8 => 1,
+            VaryingOperandKind::U16 => 2,
+            VaryingOperandKind::U32 => 4,
+        }
+    }
+
+    /// Returns the operands of the instruction, formatted like in the bytecode listing of the
+    /// code block.
+    #[must_use]
+    pub fn operands(&self) -> String {
+        self.code_block.instruction_operands(self.instruction)
+    }
+
+    /// Returns the values on the stack after the instruction was executed, from the bottom to
+    /// the top.
+    #[must_use]
+    pub const fn stack(&self) -> &[JsValue] {
+        self.stack
+    }
+
+    /// Returns the index in [`Self::stack`] where the values of the current frame start.
+    #[must_use]
+    pub const fn frame_pointer(&self) -> Option<usize> {
+        self.frame_pointer
+    }
+
+    /// Returns the number of frames on the stack after the instruction was executed.
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the time it took to execute the instruction.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+}
@@ -0,0 +1,49 @@
+//! Configuration of the heuristics of the garbage collector.
+
+/// Controls when the collector runs collections.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GcMode {
+    /// Collections are run automatically when the allocated memory grows past the threshold.
+    #[default]
+    Automatic,
+    /// Collections are only run when explicitly requested with [`force_collect`][crate::force_collect].
+    Explicit,
+}
+
+/// The configuration of the garbage collector of a thread.
+///
+/// The collections can also be delayed for a region of code with
+/// [`defer_collections`][crate::defer_collections], regardless of the [`GcMode`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GcConfig {
+    /// The number of allocated bytes that triggers the first collection.
+    pub threshold: usize,
+    /// How much the threshold grows after a collection.
+    ///
+    /// If the memory left after a collection is bigger than the threshold divided by this factor,
+    /// the threshold becomes the memory left multiplied by this factor. Must be greater than 1.
+    pub growth_factor: f64,
+    /// Controls when the collections are run.
+    pub mode: GcMode,
+    /// The maximum number of bytes that can be allocated while the collections are deferred.
+    ///
+    /// Allocating past this limit is denied, like an allocation denied by an
+    /// [`AllocationHook`][crate::AllocationHook].
+    pub max_deferred_bytes: usize,
+    /// The number of bytes allocated in the young generation that triggers a minor collection.
+    #[cfg(feature = "generational")]
+    pub nursery_size: usize,
+}
+
+impl Default for GcConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 1024,
+            growth_factor: 100.0 / 70.0,
+            mode: GcMode::Automatic,
+            max_deferred_bytes: 64 * 1024 * 1024,
+            #[cfg(feature = "generational")]
+            nursery_size: 256 * 1024,
+        }
+    }
+}
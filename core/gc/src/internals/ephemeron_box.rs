@@ -1,3 +1,5 @@
+#[cfg(feature = "generational")]
+use crate::GcErasedPointer;
 use crate::{trace::Trace, Gc, GcBox, Tracer};
 use std::{cell::UnsafeCell, ptr::NonNull};
 
@@ -126,6 +128,10 @@ pub(crate) trait ErasedEphemeronBox {
 
     fn trace_non_roots(&self);
 
+    /// Gets the type erased pointer to the ephemeron's key, or `None` if it was already cleared.
+    #[cfg(feature = "generational")]
+    fn key_erased(&self) -> Option<GcErasedPointer>;
+
     /// Runs the finalization logic of the `EphemeronBox`'s held value, if the key is still live,
     /// and clears its contents.
     fn finalize_and_clear(&self);
@@ -173,6 +179,13 @@ impl<K: Trace + ?Sized, V: Trace> ErasedEphemeronBox for EphemeronBox<K, V> {
         }
     }
 
+    #[cfg(feature = "generational")]
+    fn key_erased(&self) -> Option<GcErasedPointer> {
+        // SAFETY: the garbage collector ensures the ephemeron doesn't mutate until
+        // finalization.
+        unsafe { self.key_ptr() }.map(NonNull::cast)
+    }
+
     fn finalize_and_clear(&self) {
         // SAFETY: the invariants of the garbage collector ensures this is only executed when
         // there are no remaining references to the inner data.
@@ -1,14 +1,16 @@
 use std::{cell::Cell, fmt};
 
 const MARK_MASK: u32 = 1 << (u32::BITS - 1);
-const NON_ROOTS_MASK: u32 = !MARK_MASK;
+const YOUNG_MASK: u32 = 1 << (u32::BITS - 2);
+const NON_ROOTS_MASK: u32 = !(MARK_MASK | YOUNG_MASK);
 const NON_ROOTS_MAX: u32 = NON_ROOTS_MASK;
 
 /// The `Gcheader` contains the `GcBox`'s and `EphemeronBox`'s current state for the `Collector`'s
 /// Mark/Sweep as well as a pointer to the next node in the heap.
 ///
 /// `ref_count` is the number of Gc instances, and `non_root_count` is the number of
-/// Gc instances in the heap. `non_root_count` also includes Mark Flag bit.
+/// Gc instances in the heap. `non_root_count` also includes Mark Flag bit and the Young Flag bit,
+/// which is only set by the generational collector for nodes of the young generation.
 ///
 /// The next node is set by the `Allocator` during initialization and by the
 /// `Collector` during the sweep phase.
@@ -40,6 +42,13 @@ impl GcHeader {
     pub(crate) fn inc_non_root_count(&self) {
         let non_root_count = self.non_root_count.get();
 
+        // Minor collections only count the handles of the young nodes, so the counts of the mature
+        // nodes must be left untouched.
+        #[cfg(feature = "generational")]
+        if non_root_count & YOUNG_MASK == 0 && crate::minor_collection_running() {
+            return;
+        }
+
         if (non_root_count & NON_ROOTS_MASK) < NON_ROOTS_MAX {
             self.non_root_count.set(non_root_count.wrapping_add(1));
         } else {
@@ -91,6 +100,26 @@ impl GcHeader {
         self.non_root_count
             .set(self.non_root_count.get() & !MARK_MASK);
     }
+
+    /// Returns a bool for whether [`GcHeader`]'s young bit is 1.
+    #[cfg(feature = "generational")]
+    pub(crate) fn is_young(&self) -> bool {
+        self.non_root_count.get() & YOUNG_MASK != 0
+    }
+
+    /// Sets [`GcHeader`]'s young bit to 1, placing the node in the young generation.
+    #[cfg(feature = "generational")]
+    pub(crate) fn set_young(&self) {
+        self.non_root_count
+            .set(self.non_root_count.get() | YOUNG_MASK);
+    }
+
+    /// Sets [`GcHeader`]'s young bit to 0, promoting the node to the mature space.
+    #[cfg(feature = "generational")]
+    pub(crate) fn promote(&self) {
+        self.non_root_count
+            .set(self.non_root_count.get() & !YOUNG_MASK);
+    }
 }
 
 impl fmt::Debug for GcHeader {
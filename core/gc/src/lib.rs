@@ -3,6 +3,33 @@
 //! # Crate Overview
 //! **`boa_gc`** is a mark-sweep garbage collector that implements a [`Trace`] and [`Finalize`] trait
 //! for garbage collected values.
+//!
+//! # Ephemerons
+//!
+//! An [`Ephemeron`] only keeps its value alive while its key is reachable from somewhere else,
+//! which is what backs [`WeakGc`] and [`WeakMap`]. The marking phase only traces the value of an
+//! ephemeron once its key is marked, repeating until no more values get traced, so a value that
+//! references its own key doesn't keep either of them alive. The entries whose keys died are
+//! then cleared from their weak maps.
+//!
+//! # Generational collection
+//!
+//! With the `generational` feature enabled, new allocations belong to a young generation, which
+//! is collected on its own by minor collections once it grows past a configurable size. The full
+//! collections still run when the mature space grows past its threshold, or on [`force_collect`].
+//!
+//! This is a non-moving generational scheme:
+//!
+//! - Nodes are allocated individually, not bump-allocated in a nursery, and the survivors of a
+//!   minor collection are promoted to the mature space in place, by clearing their young bit.
+//!   [`Gc`] handles are raw pointers to their boxes that cannot be updated after a move, so the
+//!   survivors cannot be evacuated.
+//! - There is no write barrier and no remembered set. The roots are found by comparing the
+//!   reference count of every node with the number of handles stored in the heap, and minor
+//!   collections only count the handles stored in young nodes. So every handle stored in the
+//!   mature space, including the ones stored after the node was promoted, makes the young node
+//!   it points to behave as a root, which is what a remembered set of old-to-young edges would
+//!   provide.
 #![doc = include_str!("../ABOUT.md")]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg",
@@ -18,7 +45,10 @@
 extern crate self as boa_gc;
 
 mod cell;
+mod config;
 mod pointers;
+mod snapshot;
+mod stats;
 mod trace;
 
 pub(crate) mod internals;
@@ -26,53 +56,64 @@ pub(crate) mod internals;
 use boa_profiler::Profiler;
 use internals::{EphemeronBox, ErasedEphemeronBox, ErasedWeakMapBox, WeakMapBox};
 use pointers::{NonTraceable, RawWeakMap};
+use stats::ExternalMemory;
 use std::{
     cell::{Cell, RefCell},
     mem,
     ptr::NonNull,
+    time::{Duration, Instant},
 };
 
 pub use crate::trace::{Finalize, Trace, Tracer};
 pub use boa_macros::{Finalize, Trace};
 pub use cell::{GcRef, GcRefCell, GcRefMut};
+pub use config::{GcConfig, GcMode};
 pub use internals::GcBox;
 pub use pointers::{Ephemeron, Gc, WeakGc, WeakMap};
+pub use snapshot::{HeapNode, HeapSnapshot};
+pub use stats::{
+    set_allocation_hook, take_denied_allocations, track_external_allocation,
+    track_external_deallocation, AllocationAction, AllocationHook, GcStats, MemoryKind,
+};
 
 type GcErasedPointer = NonNull<GcBox<NonTraceable>>;
 type EphemeronPointer = NonNull<dyn ErasedEphemeronBox>;
 type ErasedWeakMapBoxPointer = NonNull<dyn ErasedWeakMapBox>;
 
 thread_local!(static GC_DROPPING: Cell<bool> = Cell::new(false));
+#[cfg(feature = "generational")]
+thread_local!(static GC_MINOR: Cell<bool> = Cell::new(false));
 thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
     config: GcConfig::default(),
-    runtime: GcRuntimeData::default(),
+    runtime: GcRuntimeData {
+        threshold: GcConfig::default().threshold,
+        ..GcRuntimeData::default()
+    },
     strongs: Vec::default(),
+    #[cfg(feature = "generational")]
+    young: Vec::default(),
     weaks: Vec::default(),
     weak_maps: Vec::default(),
 }));
 
-#[derive(Debug, Clone, Copy)]
-struct GcConfig {
-    threshold: usize,
-    used_space_percentage: usize,
-}
-
-// Setting the defaults to an arbitrary value currently.
-//
-// TODO: Add a configure later
-impl Default for GcConfig {
-    fn default() -> Self {
-        Self {
-            threshold: 1024,
-            used_space_percentage: 70,
-        }
-    }
-}
-
 #[derive(Default, Debug, Clone, Copy)]
 struct GcRuntimeData {
+    /// The number of allocated bytes that triggers the next collection.
+    threshold: usize,
+    /// The number of nested [`defer_collections`] scopes.
+    deferred: usize,
+    /// The number of bytes allocated since the collections were deferred.
+    deferred_bytes: usize,
+    /// Whether a collection was requested while the collections were deferred.
+    pending_collection: bool,
     collections: usize,
     bytes_allocated: usize,
+    live_bytes: usize,
+    pause_time: Duration,
+    #[cfg(feature = "generational")]
+    minor_collections: usize,
+    #[cfg(feature = "generational")]
+    young_bytes_allocated: usize,
 }
 
 #[derive(Debug)]
@@ -80,10 +121,30 @@ struct BoaGc {
     config: GcConfig,
     runtime: GcRuntimeData,
     strongs: Vec<GcErasedPointer>,
+    /// The nodes allocated since the last collection, which are yet to be promoted to `strongs`.
+    #[cfg(feature = "generational")]
+    young: Vec<GcErasedPointer>,
     weaks: Vec<EphemeronPointer>,
     weak_maps: Vec<ErasedWeakMapBoxPointer>,
 }
 
+impl BoaGc {
+    /// Gets the current statistics of the collector.
+    fn stats(&self) -> GcStats {
+        let external = ExternalMemory::current();
+        GcStats {
+            bytes_allocated: self.runtime.bytes_allocated + external.total(),
+            live_bytes: self.runtime.live_bytes,
+            collections: self.runtime.collections,
+            pause_time: self.runtime.pause_time,
+            heap_bytes: self.runtime.bytes_allocated,
+            object_bytes: external.objects,
+            string_bytes: external.strings,
+            other_bytes: external.other,
+        }
+    }
+}
+
 impl Drop for BoaGc {
     fn drop(&mut self) {
         Collector::dump(self);
@@ -118,6 +179,54 @@ pub fn finalizer_safe() -> bool {
     GC_DROPPING.with(|dropping| !dropping.get())
 }
 
+/// Gets the current time of the monotonic clock, or `None` if the target doesn't have one.
+#[cfg(not(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi"))
+)))]
+#[allow(clippy::unnecessary_wraps)]
+fn monotonic_now() -> Option<Instant> {
+    Some(Instant::now())
+}
+
+/// Gets the current time of the monotonic clock, or `None` if the target doesn't have one.
+#[cfg(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi"))
+))]
+fn monotonic_now() -> Option<Instant> {
+    None
+}
+
+/// `MinorGuard` flags whether the Collector is currently counting the handles of the young nodes
+/// during a minor collection.
+///
+/// While the `MinorGuard` is active, only the non-roots count of the young nodes is incremented.
+#[cfg(feature = "generational")]
+#[derive(Debug, Clone)]
+struct MinorGuard;
+
+#[cfg(feature = "generational")]
+impl MinorGuard {
+    fn new() -> Self {
+        GC_MINOR.with(|minor| minor.set(true));
+        Self
+    }
+}
+
+#[cfg(feature = "generational")]
+impl Drop for MinorGuard {
+    fn drop(&mut self) {
+        GC_MINOR.with(|minor| minor.set(false));
+    }
+}
+
+/// Returns `true` if the Collector is counting the handles of the young nodes.
+#[cfg(feature = "generational")]
+fn minor_collection_running() -> bool {
+    GC_MINOR.with(Cell::get)
+}
+
 /// The Allocator handles allocation of garbage collected values.
 ///
 /// The allocator can trigger a garbage collection.
@@ -132,11 +241,19 @@ impl Allocator {
         BOA_GC.with(|st| {
             let mut gc = st.borrow_mut();
 
-            Self::manage_state(&mut gc);
+            Self::manage_state(&mut gc, element_size);
+            #[cfg(feature = "generational")]
+            value.header.set_young();
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
             let erased: NonNull<GcBox<NonTraceable>> = ptr.cast();
 
+            #[cfg(feature = "generational")]
+            {
+                gc.young.push(erased);
+                gc.runtime.young_bytes_allocated += element_size;
+            }
+            #[cfg(not(feature = "generational"))]
             gc.strongs.push(erased);
             gc.runtime.bytes_allocated += element_size;
 
@@ -152,7 +269,7 @@ impl Allocator {
         BOA_GC.with(|st| {
             let mut gc = st.borrow_mut();
 
-            Self::manage_state(&mut gc);
+            Self::manage_state(&mut gc, element_size);
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
             let erased: NonNull<dyn ErasedEphemeronBox> = ptr;
@@ -187,17 +304,65 @@ impl Allocator {
         })
     }
 
-    fn manage_state(gc: &mut BoaGc) {
-        if gc.runtime.bytes_allocated > gc.config.threshold {
-            Collector::collect(gc);
+    fn manage_state(gc: &mut BoaGc, size: usize) {
+        let deferred = gc.runtime.deferred > 0;
+        if deferred {
+            gc.runtime.deferred_bytes += size;
+            if gc.runtime.deferred_bytes > gc.config.max_deferred_bytes {
+                stats::deny_allocation();
+            }
+        }
 
-            if gc.runtime.bytes_allocated
-                > gc.config.threshold / 100 * gc.config.used_space_percentage
-            {
-                gc.config.threshold =
-                    gc.runtime.bytes_allocated / gc.config.used_space_percentage * 100;
+        if let Some(hook) = stats::allocation_hook() {
+            match hook.on_allocation(&gc.stats(), size) {
+                AllocationAction::Continue => {}
+                AllocationAction::Collect if deferred => gc.runtime.pending_collection = true,
+                AllocationAction::Collect => {
+                    Collector::collect(gc);
+                    return;
+                }
+                AllocationAction::Deny => stats::deny_allocation(),
             }
         }
+
+        if deferred || gc.config.mode == GcMode::Explicit {
+            return;
+        }
+
+        #[cfg(feature = "generational")]
+        if gc.runtime.young_bytes_allocated > gc.config.nursery_size {
+            Collector::collect_young(gc);
+        }
+
+        // The young generation is not accounted for in the threshold of the full collections.
+        #[cfg(feature = "generational")]
+        let mature_bytes = gc.runtime.bytes_allocated - gc.runtime.young_bytes_allocated;
+        #[cfg(not(feature = "generational"))]
+        let mature_bytes = gc.runtime.bytes_allocated;
+
+        if mature_bytes + ExternalMemory::current().total() > gc.runtime.threshold {
+            Collector::collect(gc);
+
+            gc.runtime.threshold = Self::grow_threshold(
+                gc.runtime.threshold,
+                gc.runtime.live_bytes,
+                gc.config.growth_factor,
+            );
+        }
+    }
+
+    /// Computes the threshold of the next collection, given the memory left by a collection.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn grow_threshold(threshold: usize, live_bytes: usize, growth_factor: f64) -> usize {
+        if live_bytes as f64 > threshold as f64 / growth_factor {
+            (live_bytes as f64 * growth_factor) as usize
+        } else {
+            threshold
+        }
     }
 }
 
@@ -224,8 +389,20 @@ impl Collector {
     /// Run a collection on the full heap.
     fn collect(gc: &mut BoaGc) {
         let _timer = Profiler::global().start_event("Gc Full Collection", "gc");
+        let start = monotonic_now();
         gc.runtime.collections += 1;
 
+        // A full collection sweeps the young generation as well, so all the survivors end up promoted.
+        #[cfg(feature = "generational")]
+        {
+            for node in &gc.young {
+                // SAFETY: node must be valid as this phase cannot drop any node.
+                unsafe { node.as_ref() }.header.promote();
+            }
+            gc.strongs.append(&mut gc.young);
+            gc.runtime.young_bytes_allocated = 0;
+        }
+
         Self::trace_non_roots(gc);
 
         let mut tracer = Tracer::new();
@@ -276,6 +453,72 @@ impl Collector {
         gc.strongs.shrink_to(gc.strongs.len() >> 2);
         gc.weaks.shrink_to(gc.weaks.len() >> 2);
         gc.weak_maps.shrink_to(gc.weak_maps.len() >> 2);
+
+        Self::record_pause(gc, start);
+    }
+
+    /// Records the memory left and the time spent after a collection that started at `start`.
+    fn record_pause(gc: &mut BoaGc, start: Option<Instant>) {
+        gc.runtime.live_bytes = gc.runtime.bytes_allocated + ExternalMemory::current().total();
+        if let Some(start) = start {
+            gc.runtime.pause_time += start.elapsed();
+        }
+    }
+
+    /// Run a collection on the young generation, promoting its survivors to the mature space.
+    ///
+    /// The handles stored in the mature space and in the ephemerons are not counted, so the nodes
+    /// they point to are deemed as rooted. The keys of the ephemerons are kept alive as well, until
+    /// the next full collection.
+    #[cfg(feature = "generational")]
+    fn collect_young(gc: &mut BoaGc) {
+        let _timer = Profiler::global().start_event("Gc Minor Collection", "gc");
+        let start = monotonic_now();
+        gc.runtime.minor_collections += 1;
+
+        {
+            let _guard = MinorGuard::new();
+            for node in &gc.young {
+                // SAFETY: node must be valid as this phase cannot drop any node.
+                let trace_non_roots_fn = unsafe { node.as_ref() }.trace_non_roots_fn();
+
+                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+                unsafe {
+                    trace_non_roots_fn(*node);
+                }
+            }
+        }
+
+        let mut tracer = Tracer::new();
+
+        let unreachables = Self::mark_young(&mut tracer, &gc.young, &gc.weaks);
+
+        // Only finalize if there are any unreachable nodes.
+        if !unreachables.is_empty() {
+            // Finalize all the unreachable nodes.
+            // SAFETY: All passed pointers are valid, since we won't deallocate until `Self::sweep_young`.
+            unsafe {
+                Self::finalize(Unreachables {
+                    strong: unreachables,
+                    weak: Vec::new(),
+                });
+            }
+
+            // Reuse the tracer's already allocated capacity.
+            let _final_unreachables = Self::mark_young(&mut tracer, &gc.young, &gc.weaks);
+        }
+
+        // SAFETY: All the young nodes are valid per the invariants of our GC.
+        unsafe {
+            Self::sweep_young(
+                &mut gc.young,
+                &mut gc.strongs,
+                &mut gc.runtime.bytes_allocated,
+            );
+        }
+        gc.runtime.young_bytes_allocated = 0;
+
+        Self::record_pause(gc, start);
     }
 
     fn trace_non_roots(gc: &BoaGc) {
@@ -311,6 +554,29 @@ impl Collector {
         let mut strong_dead = Vec::new();
         let mut pending_ephemerons = Vec::new();
 
+        // Nodes are only traced the first time they are reached, which also stops the tracing of
+        // cycles, including the ones reached from the values of ephemerons.
+        let mark_reachable = |tracer: &mut Tracer| {
+            while let Some(node) = tracer.next() {
+                // SAFETY: the gc heap object should be alive if there is a root.
+                let node_ref = unsafe { node.as_ref() };
+
+                if !node_ref.header.is_marked() {
+                    node_ref.header.mark();
+
+                    // SAFETY: if `GcBox::trace_inner()` has been called, then,
+                    // this box must have been deemed as reachable via tracing
+                    // from a root, which by extension means that value has not
+                    // been dropped either.
+
+                    let trace_fn = node_ref.trace_fn();
+
+                    // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+                    unsafe { trace_fn(node, tracer) }
+                }
+            }
+        };
+
         // === Preliminary mark phase ===
         //
         // 0. Get the naive list of possibly dead nodes.
@@ -320,24 +586,7 @@ impl Collector {
             if node_ref.is_rooted() {
                 tracer.enqueue(*node);
 
-                while let Some(node) = tracer.next() {
-                    // SAFETY: the gc heap object should be alive if there is a root.
-                    let node_ref = unsafe { node.as_ref() };
-
-                    if !node_ref.header.is_marked() {
-                        node_ref.header.mark();
-
-                        // SAFETY: if `GcBox::trace_inner()` has been called, then,
-                        // this box must have been deemed as reachable via tracing
-                        // from a root, which by extension means that value has not
-                        // been dropped either.
-
-                        let trace_fn = node_ref.trace_fn();
-
-                        // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                        unsafe { trace_fn(node, tracer) }
-                    }
-                }
+                mark_reachable(tracer);
             } else if !node_ref.is_marked() {
                 strong_dead.push(*node);
             }
@@ -373,15 +622,7 @@ impl Collector {
                 pending_ephemerons.push(*eph);
             }
 
-            while let Some(node) = tracer.next() {
-                // SAFETY: node must be valid as this phase cannot drop any node.
-                let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                unsafe {
-                    trace_fn(node, tracer);
-                }
-            }
+            mark_reachable(tracer);
         }
 
         // 2. Trace all the weak pointers in the live weak maps to make sure they do not get swept.
@@ -392,15 +633,7 @@ impl Collector {
             // SAFETY: The garbage collector ensures that all nodes are valid.
             unsafe { node_ref.trace(tracer) };
 
-            while let Some(node) = tracer.next() {
-                // SAFETY: node must be valid as this phase cannot drop any node.
-                let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                unsafe {
-                    trace_fn(node, tracer);
-                }
-            }
+            mark_reachable(tracer);
         }
 
         // 3. Iterate through all pending ephemerons, removing the ones which have been successfully
@@ -414,15 +647,7 @@ impl Collector {
                 // SAFETY: the garbage collector ensures `eph_ref` always points to valid data.
                 let is_key_marked = unsafe { !eph_ref.trace(tracer) };
 
-                while let Some(node) = tracer.next() {
-                    // SAFETY: node must be valid as this phase cannot drop any node.
-                    let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                    // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                    unsafe {
-                        trace_fn(node, tracer);
-                    }
-                }
+                mark_reachable(tracer);
 
                 is_key_marked
             });
@@ -448,6 +673,57 @@ impl Collector {
         }
     }
 
+    /// Walk the young generation and mark any young nodes deemed reachable, returning the unreachable
+    /// ones.
+    #[cfg(feature = "generational")]
+    fn mark_young(
+        tracer: &mut Tracer,
+        young: &[GcErasedPointer],
+        weaks: &[EphemeronPointer],
+    ) -> Vec<GcErasedPointer> {
+        let _timer = Profiler::global().start_event("Gc Minor Marking", "gc");
+
+        // Only the young nodes are marked, since the mature ones are not swept.
+        let mark_reachable = |tracer: &mut Tracer| {
+            while let Some(node) = tracer.next() {
+                // SAFETY: the gc heap object should be alive if there is a root.
+                let node_ref = unsafe { node.as_ref() };
+
+                if node_ref.header.is_young() && !node_ref.header.is_marked() {
+                    node_ref.header.mark();
+
+                    let trace_fn = node_ref.trace_fn();
+
+                    // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+                    unsafe { trace_fn(node, tracer) }
+                }
+            }
+        };
+
+        for eph in weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            if let Some(key) = unsafe { eph.as_ref() }.key_erased() {
+                tracer.enqueue(key);
+                mark_reachable(tracer);
+            }
+        }
+
+        for node in young {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            if unsafe { node.as_ref() }.is_rooted() {
+                tracer.enqueue(*node);
+                mark_reachable(tracer);
+            }
+        }
+
+        young
+            .iter()
+            .copied()
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            .filter(|node| unsafe { !node.as_ref().is_marked() })
+            .collect()
+    }
+
     /// # Safety
     ///
     /// Passing a `strong` or a `weak` vec with invalid pointers will result in Undefined Behaviour.
@@ -529,6 +805,44 @@ impl Collector {
         });
     }
 
+    /// # Safety
+    ///
+    /// - Providing an invalid pointer in `young` will result in Undefined Behaviour.
+    /// - Providing a list of pointers that weren't allocated by `Box::into_raw(Box::new(..))`
+    /// will result in Undefined Behaviour.
+    #[cfg(feature = "generational")]
+    unsafe fn sweep_young(
+        young: &mut Vec<GcErasedPointer>,
+        strong: &mut Vec<GcErasedPointer>,
+        total_allocated: &mut usize,
+    ) {
+        let _timer = Profiler::global().start_event("Gc Minor Sweeping", "gc");
+        let _guard = DropGuard::new();
+
+        for node in young.drain(..) {
+            // SAFETY: The caller must ensure the validity of every node of `young`.
+            let node_ref = unsafe { node.as_ref() };
+            if node_ref.is_marked() {
+                node_ref.header.unmark();
+                node_ref.header.promote();
+                node_ref.reset_non_root_count();
+
+                strong.push(node);
+            } else {
+                // SAFETY: The algorithm ensures only unmarked/unreachable pointers are dropped.
+                // The caller must ensure all pointers were allocated by `Box::into_raw(Box::new(..))`.
+                let drop_fn = node_ref.drop_fn();
+                let size = node_ref.size();
+                *total_allocated -= size;
+
+                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+                unsafe {
+                    drop_fn(node);
+                }
+            }
+        }
+    }
+
     // Clean up the heap when BoaGc is dropped
     fn dump(gc: &mut BoaGc) {
         // Weak maps have to be dropped first, since the process dereferences GcBoxes.
@@ -543,6 +857,9 @@ impl Collector {
         // Not initializing a dropguard since this should only be invoked when BOA_GC is being dropped.
         let _guard = DropGuard::new();
 
+        #[cfg(feature = "generational")]
+        gc.strongs.append(&mut gc.young);
+
         for node in std::mem::take(&mut gc.strongs) {
             // SAFETY:
             // The `Allocator` must always ensure its start node is a valid, non-null pointer that
@@ -565,16 +882,92 @@ impl Collector {
 }
 
 /// Forcefully runs a garbage collection of all unaccessible nodes.
+///
+/// If the collections are deferred, the collection runs when the outermost
+/// [`defer_collections`] scope ends.
 pub fn force_collect() {
     BOA_GC.with(|current| {
         let mut gc = current.borrow_mut();
 
-        if gc.runtime.bytes_allocated > 0 {
+        if gc.runtime.deferred > 0 {
+            gc.runtime.pending_collection = true;
+        } else if gc.runtime.bytes_allocated > 0 {
             Collector::collect(&mut gc);
         }
     });
 }
 
+/// Gets the configuration of the garbage collector of this thread.
+#[must_use]
+pub fn config() -> GcConfig {
+    BOA_GC.with(|current| current.borrow().config)
+}
+
+/// Sets the configuration of the garbage collector of this thread.
+///
+/// This also resets the threshold of the next collection to the one in `config`.
+pub fn set_config(config: GcConfig) {
+    BOA_GC.with(|current| {
+        let mut gc = current.borrow_mut();
+        gc.config = config;
+        gc.runtime.threshold = config.threshold;
+    });
+}
+
+/// `DeferGuard` delays the collections of the garbage collector while it is alive.
+#[derive(Debug)]
+struct DeferGuard;
+
+impl DeferGuard {
+    fn new() -> Self {
+        BOA_GC.with(|current| current.borrow_mut().runtime.deferred += 1);
+        Self
+    }
+}
+
+impl Drop for DeferGuard {
+    fn drop(&mut self) {
+        BOA_GC.with(|current| {
+            let mut gc = current.borrow_mut();
+            gc.runtime.deferred -= 1;
+            if gc.runtime.deferred > 0 {
+                return;
+            }
+
+            gc.runtime.deferred_bytes = 0;
+            if mem::take(&mut gc.runtime.pending_collection) {
+                Collector::collect(&mut gc);
+            }
+        });
+    }
+}
+
+/// Runs `f` without running any collection, which makes it possible to hold raw pointers to
+/// garbage collected values for the duration of `f`.
+///
+/// The collections requested with [`force_collect`] or by an [`AllocationHook`] are delayed until
+/// the outermost scope ends, while the automatic collections just resume. Allocating more than
+/// [`GcConfig::max_deferred_bytes`] inside of `f` is denied, which can be checked with
+/// [`take_denied_allocations`].
+pub fn defer_collections<R>(f: impl FnOnce() -> R) -> R {
+    let _guard = DeferGuard::new();
+    f()
+}
+
+/// Gets the current memory statistics of the garbage collector of this thread.
+#[must_use]
+pub fn stats() -> GcStats {
+    BOA_GC.with(|current| current.borrow().stats())
+}
+
+/// Takes a [`HeapSnapshot`] of the values reachable from the roots of the heap of this thread.
+///
+/// This doesn't run a collection, but the unreachable values are not included in the snapshot.
+#[must_use]
+pub fn heap_snapshot() -> HeapSnapshot {
+    BOA_GC.with(|current| HeapSnapshot::take(&current.borrow()))
+}
+
 #[cfg(test)]
 mod test;
 
@@ -0,0 +1,332 @@
+//! Heap snapshots, which record the live values of the heap and the references between them to
+//! find out what keeps a value alive.
+
+use std::{
+    collections::VecDeque,
+    io::{self, Write},
+};
+
+use hashbrown::HashMap;
+
+use crate::{BoaGc, Gc, GcErasedPointer, Trace, Tracer};
+
+/// A live garbage collected value in a [`HeapSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeapNode {
+    /// The identifier of the node, which is the address of its allocation.
+    pub id: usize,
+    /// The name of the type of the value.
+    pub type_name: &'static str,
+    /// The size of the allocation of the value, including its header.
+    pub size: usize,
+    /// Whether the value is referenced from outside of the heap.
+    pub root: bool,
+    /// The indices of the nodes referenced by this value.
+    pub edges: Vec<usize>,
+}
+
+/// A snapshot of the values reachable from the roots of the heap.
+///
+/// Only the strong references are recorded, so the values of the ephemerons don't have incoming
+/// edges, even if their keys are alive.
+#[derive(Debug, Clone, Default)]
+pub struct HeapSnapshot {
+    nodes: Vec<HeapNode>,
+}
+
+impl HeapSnapshot {
+    /// Walks the heap from its roots, recording every reachable node.
+    pub(crate) fn take(gc: &BoaGc) -> Self {
+        #[cfg(not(feature = "generational"))]
+        let heap = gc.strongs.clone();
+        #[cfg(feature = "generational")]
+        let heap = [gc.strongs.as_slice(), gc.young.as_slice()].concat();
+
+        // Count the handles stored in the heap to find the roots, like a collection does.
+        for node in &heap {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let trace_non_roots_fn = unsafe { node.as_ref() }.trace_non_roots_fn();
+
+            // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+            unsafe {
+                trace_non_roots_fn(*node);
+            }
+        }
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { eph.as_ref() }.trace_non_roots();
+        }
+
+        let mut snapshot = Self::default();
+        let mut indices = HashMap::new();
+        let mut queue = VecDeque::new();
+        for node in &heap {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            if unsafe { node.as_ref() }.is_rooted() {
+                snapshot.push(*node, true, &mut indices, &mut queue);
+            }
+        }
+
+        for node in &heap {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { node.as_ref() }.reset_non_root_count();
+        }
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { eph.as_ref() }.header().reset_non_root_count();
+        }
+
+        let mut tracer = Tracer::new();
+        while let Some((index, node)) = queue.pop_front() {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let trace_fn = unsafe { node.as_ref() }.trace_fn();
+
+            // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+            unsafe {
+                trace_fn(node, &mut tracer);
+            }
+
+            let mut edges = Vec::new();
+            while let Some(child) = tracer.next() {
+                edges.push(snapshot.push(child, false, &mut indices, &mut queue));
+            }
+            snapshot.nodes[index].edges = edges;
+        }
+
+        // Tracing an ephemeron marks it, which must only happen during a collection.
+        for eph in &gc.weaks {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            unsafe { eph.as_ref() }.header().unmark();
+        }
+
+        snapshot
+    }
+
+    /// Adds `node` to the snapshot if it wasn't already recorded, returning its index.
+    fn push(
+        &mut self,
+        node: GcErasedPointer,
+        root: bool,
+        indices: &mut HashMap<GcErasedPointer, usize>,
+        queue: &mut VecDeque<(usize, GcErasedPointer)>,
+    ) -> usize {
+        *indices.entry(node).or_insert_with(|| {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let node_ref = unsafe { node.as_ref() };
+            let index = self.nodes.len();
+            self.nodes.push(HeapNode {
+                id: node.as_ptr() as usize,
+                type_name: node_ref.type_name(),
+                size: node_ref.size(),
+                root,
+                edges: Vec::new(),
+            });
+            queue.push_back((index, node));
+            index
+        })
+    }
+
+    /// Returns the identifier that the node of `value` has in the snapshots.
+    #[must_use]
+    pub fn id_of<T: Trace + ?Sized>(value: &Gc<T>) -> usize {
+        value.as_erased().as_ptr() as usize
+    }
+
+    /// Gets the recorded nodes.
+    #[must_use]
+    pub fn nodes(&self) -> &[HeapNode] {
+        &self.nodes
+    }
+
+    /// Gets the index of the node with the given identifier, if it is alive.
+    #[must_use]
+    pub fn index_of(&self, id: usize) -> Option<usize> {
+        self.nodes.iter().position(|node| node.id == id)
+    }
+
+    /// Gets the total size of the recorded nodes.
+    #[must_use]
+    pub fn total_size(&self) -> usize {
+        self.nodes.iter().map(|node| node.size).sum()
+    }
+
+    /// Returns the shortest path from a root to the node at `index`, as a list of indices that
+    /// starts with the root and ends with `index`.
+    #[must_use]
+    pub fn path_to(&self, index: usize) -> Option<Vec<usize>> {
+        let mut parents = vec![None; self.nodes.len()];
+        let mut queue = VecDeque::new();
+        for (root, node) in self.nodes.iter().enumerate() {
+            if node.root {
+                parents[root] = Some(root);
+                queue.push_back(root);
+            }
+        }
+
+        while let Some(current) = queue.pop_front() {
+            if current == index {
+                let mut path = vec![current];
+                let mut node = current;
+                while let Some(parent) = parents[node].filter(|&parent| parent != node) {
+                    path.push(parent);
+                    node = parent;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for &edge in &self.nodes[current].edges {
+                if parents[edge].is_none() {
+                    parents[edge] = Some(current);
+                    queue.push_back(edge);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Writes the snapshot as JSON.
+    ///
+    /// The output is an object with a `nodes` array, where each node has the `id`, `type`,
+    /// `size`, `root` and `edges` fields. The edges are indices into the `nodes` array.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    pub fn write_json<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        write!(out, "{{\"nodes\":[")?;
+        for (index, node) in self.nodes.iter().enumerate() {
+            if index > 0 {
+                write!(out, ",")?;
+            }
+            write!(out, "{{\"id\":{},\"type\":", node.id)?;
+            write_json_string(out, node.type_name)?;
+            write!(
+                out,
+                ",\"size\":{},\"root\":{},\"edges\":[",
+                node.size, node.root
+            )?;
+            write_list(out, &node.edges)?;
+            write!(out, "]}}")?;
+        }
+        write!(out, "]}}")
+    }
+
+    /// Writes the snapshot in the `.heapsnapshot` format of the Chrome developer tools, which can
+    /// be loaded in their memory panel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    pub fn write_heapsnapshot<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        const NODE_FIELDS: usize = 6;
+        const SYNTHETIC: usize = 9;
+        const OBJECT: usize = 3;
+        const ELEMENT: usize = 1;
+
+        // The names of the nodes are indices into the table of strings.
+        let mut strings = vec!["(GC roots)"];
+        let mut names = HashMap::new();
+        let name_indices: Vec<usize> = self
+            .nodes
+            .iter()
+            .map(|node| {
+                *names.entry(node.type_name).or_insert_with(|| {
+                    strings.push(node.type_name);
+                    strings.len() - 1
+                })
+            })
+            .collect();
+
+        let roots: Vec<usize> = (0..self.nodes.len())
+            .filter(|&index| self.nodes[index].root)
+            .collect();
+        let edge_count = roots.len() + self.nodes.iter().map(|n| n.edges.len()).sum::<usize>();
+
+        write!(
+            out,
+            concat!(
+                "{{\"snapshot\":{{\"meta\":{{",
+                "\"node_fields\":[\"type\",\"name\",\"id\",\"self_size\",\"edge_count\",",
+                "\"trace_node_id\"],",
+                "\"node_types\":[[\"hidden\",\"array\",\"string\",\"object\",\"code\",\"closure\",",
+                "\"regexp\",\"number\",\"native\",\"synthetic\",\"concatenated string\",",
+                "\"sliced string\",\"symbol\",\"bigint\"],",
+                "\"string\",\"number\",\"number\",\"number\",\"number\"],",
+                "\"edge_fields\":[\"type\",\"name_or_index\",\"to_node\"],",
+                "\"edge_types\":[[\"context\",\"element\",\"property\",\"internal\",\"hidden\",",
+                "\"shortcut\",\"weak\"],\"string_or_number\",\"node\"],",
+                "\"trace_function_info_fields\":[],\"trace_node_fields\":[],",
+                "\"sample_fields\":[],\"location_fields\":[]}},",
+                "\"node_count\":{},\"edge_count\":{},\"trace_function_count\":0}},",
+            ),
+            self.nodes.len() + 1,
+            edge_count
+        )?;
+
+        // The first node is a synthetic root that references all the roots of the heap, and the
+        // identifiers of the other nodes are odd numbers, like in the snapshots of V8.
+        write!(out, "\"nodes\":[{SYNTHETIC},0,1,0,{},0", roots.len())?;
+        for (index, node) in self.nodes.iter().enumerate() {
+            write!(
+                out,
+                ",{OBJECT},{},{},{},{},0",
+                name_indices[index],
+                index * 2 + 3,
+                node.size,
+                node.edges.len()
+            )?;
+        }
+
+        write!(out, "],\"edges\":[")?;
+        let edges = roots.iter().enumerate().chain(
+            self.nodes
+                .iter()
+                .flat_map(|node| node.edges.iter().enumerate()),
+        );
+        for (index, (name, &to)) in edges.enumerate() {
+            if index > 0 {
+                write!(out, ",")?;
+            }
+            write!(out, "{ELEMENT},{name},{}", (to + 1) * NODE_FIELDS)?;
+        }
+
+        write!(
+            out,
+            "],\"trace_function_infos\":[],\"trace_tree\":[],\"samples\":[],\"locations\":[],\"strings\":["
+        )?;
+        for (index, string) in strings.iter().enumerate() {
+            if index > 0 {
+                write!(out, ",")?;
+            }
+            write_json_string(out, string)?;
+        }
+        write!(out, "]}}")
+    }
+}
+
+/// Writes a list of numbers separated by commas.
+fn write_list<W: Write>(out: &mut W, list: &[usize]) -> io::Result<()> {
+    for (index, item) in list.iter().enumerate() {
+        if index > 0 {
+            write!(out, ",")?;
+        }
+        write!(out, "{item}")?;
+    }
+    Ok(())
+}
+
+/// Writes `string` as a JSON string literal.
+fn write_json_string<W: Write>(out: &mut W, string: &str) -> io::Result<()> {
+    write!(out, "\"")?;
+    for c in string.chars() {
+        match c {
+            '"' => write!(out, "\\\"")?,
+            '\\' => write!(out, "\\\\")?,
+            c if c.is_control() => write!(out, "\\u{:04x}", u32::from(c))?,
+            c => write!(out, "{c}")?,
+        }
+    }
+    write!(out, "\"")
+}
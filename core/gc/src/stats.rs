@@ -0,0 +1,144 @@
+//! Memory statistics of the garbage collector, and the accounting of the memory allocated outside
+//! of it.
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+thread_local!(static EXTERNAL_MEMORY: Cell<ExternalMemory> = Cell::new(ExternalMemory::default()));
+thread_local!(static ALLOCATION_HOOK: RefCell<Option<Rc<dyn AllocationHook>>> = RefCell::new(None));
+thread_local!(static ALLOCATION_DENIED: Cell<bool> = Cell::new(false));
+
+/// A snapshot of the memory used by the garbage collector of the current thread.
+///
+/// The total amount of allocated memory is split between the garbage collected values, and the
+/// memory owned by them that was reported with [`track_external_allocation`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GcStats {
+    /// The total number of allocated bytes.
+    pub bytes_allocated: usize,
+    /// The total number of allocated bytes right after the last collection.
+    pub live_bytes: usize,
+    /// The number of full collections.
+    pub collections: usize,
+    /// The total time spent in collections.
+    ///
+    /// This is always zero on the targets that don't have a monotonic clock.
+    pub pause_time: Duration,
+    /// The number of bytes of the garbage collected values, including their headers.
+    pub heap_bytes: usize,
+    /// The number of bytes of the property storage of the objects.
+    pub object_bytes: usize,
+    /// The number of bytes of the strings.
+    pub string_bytes: usize,
+    /// The number of bytes of any other memory reported to the collector.
+    pub other_bytes: usize,
+}
+
+/// The kinds of memory that can be reported to the collector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryKind {
+    /// The storage of the properties of an object.
+    Object,
+    /// The contents of a string.
+    String,
+    /// Any other kind of memory.
+    Other,
+}
+
+/// The memory allocated outside of the garbage collector, by kind.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ExternalMemory {
+    pub(crate) objects: usize,
+    pub(crate) strings: usize,
+    pub(crate) other: usize,
+}
+
+impl ExternalMemory {
+    /// Gets the external memory of the current thread.
+    pub(crate) fn current() -> Self {
+        EXTERNAL_MEMORY.with(Cell::get)
+    }
+
+    /// Returns the total number of bytes of external memory.
+    pub(crate) const fn total(self) -> usize {
+        self.objects + self.strings + self.other
+    }
+
+    fn update(kind: MemoryKind, f: impl FnOnce(usize) -> usize) {
+        EXTERNAL_MEMORY.with(|memory| {
+            let mut current = memory.get();
+            let bytes = match kind {
+                MemoryKind::Object => &mut current.objects,
+                MemoryKind::String => &mut current.strings,
+                MemoryKind::Other => &mut current.other,
+            };
+            *bytes = f(*bytes);
+            memory.set(current);
+        });
+    }
+}
+
+/// Reports `bytes` of memory owned by garbage collected values, but allocated outside of the
+/// garbage collector.
+///
+/// This memory is included in the [`GcStats`] and counts towards the threshold that triggers a
+/// collection, which is useful to collect small values that keep big allocations alive.
+pub fn track_external_allocation(kind: MemoryKind, bytes: usize) {
+    ExternalMemory::update(kind, |current| current + bytes);
+}
+
+/// Reports that `bytes` of memory previously reported by [`track_external_allocation`] were
+/// deallocated.
+pub fn track_external_deallocation(kind: MemoryKind, bytes: usize) {
+    ExternalMemory::update(kind, |current| current.saturating_sub(bytes));
+}
+
+/// The decision of an [`AllocationHook`] about a new allocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocationAction {
+    /// Continue with the allocation, collecting only if the collector decides to.
+    Continue,
+    /// Run a full collection before the allocation.
+    Collect,
+    /// Deny the allocation.
+    ///
+    /// Since allocations cannot fail, the allocation still happens, but
+    /// [`take_denied_allocations`] will return `true`, which lets the engine stop the execution
+    /// at the next opportunity.
+    Deny,
+}
+
+/// A hook called before every allocation of a garbage collected value, which can be used by hosts
+/// to enforce memory limits.
+pub trait AllocationHook {
+    /// Decides what to do with an allocation of `size` bytes, given the current statistics of the
+    /// collector.
+    ///
+    /// This must not allocate or access garbage collected values.
+    fn on_allocation(&self, stats: &GcStats, size: usize) -> AllocationAction;
+}
+
+/// Sets the [`AllocationHook`] of the current thread, or removes it if `hook` is `None`.
+pub fn set_allocation_hook(hook: Option<Rc<dyn AllocationHook>>) {
+    ALLOCATION_HOOK.with(|current| *current.borrow_mut() = hook);
+}
+
+/// Gets the [`AllocationHook`] of the current thread.
+pub(crate) fn allocation_hook() -> Option<Rc<dyn AllocationHook>> {
+    ALLOCATION_HOOK.with(|hook| hook.borrow().clone())
+}
+
+/// Flags that an [`AllocationHook`] denied an allocation.
+pub(crate) fn deny_allocation() {
+    ALLOCATION_DENIED.with(|denied| denied.set(true));
+}
+
+/// Returns `true` if an [`AllocationHook`] denied an allocation since the last call to this
+/// function.
+#[must_use]
+pub fn take_denied_allocations() -> bool {
+    ALLOCATION_DENIED.with(|denied| denied.replace(false))
+}
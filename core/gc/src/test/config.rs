@@ -0,0 +1,121 @@
+use super::run_test;
+use crate::{
+    defer_collections, force_collect, set_config, stats, take_denied_allocations, Gc, GcBox,
+    GcConfig, GcMode,
+};
+
+const SIZE: usize = std::mem::size_of::<GcBox<[u8; 64]>>();
+
+/// Creates a configuration with the given threshold, where the allocations are promoted to the
+/// mature space right away.
+fn config(threshold: usize) -> GcConfig {
+    #[allow(unused_mut)]
+    let mut config = GcConfig {
+        threshold,
+        ..GcConfig::default()
+    };
+    #[cfg(feature = "generational")]
+    {
+        config.nursery_size = 0;
+    }
+    config
+}
+
+#[test]
+fn deferred_scope_does_not_collect() {
+    run_test(|| {
+        set_config(config(SIZE));
+
+        defer_collections(|| {
+            for _ in 0..1000 {
+                drop(Gc::new([0_u8; 64]));
+            }
+
+            assert_eq!(stats().collections, 0);
+            assert_eq!(stats().heap_bytes, SIZE * 1000);
+        });
+
+        // The next allocation collects the values allocated during the deferred scope.
+        let _value = Gc::new([0_u8; 64]);
+        assert_eq!(stats().heap_bytes, SIZE);
+        assert!(!take_denied_allocations());
+    });
+}
+
+#[test]
+fn forced_collection_waits_for_deferred_scope() {
+    run_test(|| {
+        set_config(config(usize::MAX));
+        drop(Gc::new([0_u8; 64]));
+
+        defer_collections(|| {
+            force_collect();
+            defer_collections(force_collect);
+            assert_eq!(stats().collections, 0);
+        });
+
+        assert_eq!(stats().collections, 1);
+        assert_eq!(stats().heap_bytes, 0);
+    });
+}
+
+#[test]
+fn threshold_changes_collection_frequency() {
+    run_test(|| {
+        fn collections_with(threshold: usize) -> usize {
+            set_config(config(threshold));
+            let start = stats().collections;
+            let values: Vec<_> = (0..2000).map(|_| Gc::new([0_u8; 64])).collect();
+            drop(values);
+            stats().collections - start
+        }
+
+        let small = collections_with(SIZE);
+        let big = collections_with(SIZE * 500);
+        let huge = collections_with(SIZE * 10000);
+
+        assert!(small > big, "{small} <= {big}");
+        assert!(big > huge, "{big} <= {huge}");
+        assert_eq!(huge, 0);
+    });
+}
+
+#[test]
+fn explicit_mode_only_collects_on_request() {
+    run_test(|| {
+        set_config(GcConfig {
+            mode: GcMode::Explicit,
+            ..config(SIZE)
+        });
+
+        for _ in 0..1000 {
+            drop(Gc::new([0_u8; 64]));
+        }
+        assert_eq!(stats().collections, 0);
+
+        force_collect();
+        assert_eq!(stats().collections, 1);
+        assert_eq!(stats().heap_bytes, 0);
+    });
+}
+
+#[test]
+fn deferred_allocations_are_capped() {
+    run_test(|| {
+        set_config(GcConfig {
+            max_deferred_bytes: SIZE * 2,
+            ..config(usize::MAX)
+        });
+
+        let values = defer_collections(|| {
+            let values: Vec<_> = (0..2).map(|_| Gc::new([0_u8; 64])).collect();
+            assert!(!take_denied_allocations());
+            values
+        });
+        drop(values);
+
+        let value = defer_collections(|| Gc::new([0_u8; 200]));
+        assert_eq!(value[0], 0);
+        assert!(take_denied_allocations());
+    });
+}
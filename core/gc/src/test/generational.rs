@@ -0,0 +1,137 @@
+use super::{run_test, Harness};
+use crate::{force_collect, Finalize, Gc, GcRefCell, Trace, WeakGc};
+
+#[derive(Debug, Trace, Finalize)]
+struct Node {
+    value: usize,
+    next: Option<Gc<Node>>,
+}
+
+#[test]
+fn minor_collection_promotes_survivors() {
+    run_test(|| {
+        let survivor = Gc::new(1_u8);
+        drop(Gc::new(2_u8));
+        Harness::assert_generations(2, 0);
+
+        Harness::collect_young();
+        Harness::assert_generations(0, 1);
+        Harness::assert_collections(0);
+        assert_eq!(*survivor, 1);
+
+        drop(survivor);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn minor_collection_keeps_nodes_reachable_from_mature_space() {
+    run_test(|| {
+        let mature = Gc::new(GcRefCell::new(None));
+        force_collect();
+        Harness::assert_generations(0, 1);
+
+        *mature.borrow_mut() = Some(Gc::new(Node {
+            value: 1,
+            next: Some(Gc::new(Node {
+                value: 2,
+                next: None,
+            })),
+        }));
+        drop(Gc::new(Node {
+            value: 3,
+            next: None,
+        }));
+        Harness::assert_generations(3, 1);
+
+        Harness::collect_young();
+        Harness::assert_generations(0, 3);
+
+        let first = mature.borrow().clone().expect("node was set");
+        let second = first.next.clone().expect("node was set");
+        assert_eq!((first.value, second.value), (1, 2));
+
+        drop((first, second, mature));
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn minor_collection_releases_references_to_mature_space() {
+    run_test(|| {
+        let mature = Gc::new(Node {
+            value: 1,
+            next: None,
+        });
+        force_collect();
+
+        drop(Gc::new(Node {
+            value: 2,
+            next: Some(mature.clone()),
+        }));
+        Harness::collect_young();
+        Harness::assert_generations(0, 1);
+
+        drop(mature);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn minor_collection_keeps_weak_keys_until_full_collection() {
+    run_test(|| {
+        let weak = WeakGc::new(&Gc::new(3));
+
+        Harness::collect_young();
+        assert_eq!(
+            *weak
+                .upgrade()
+                .expect("young keys survive minor collections"),
+            3
+        );
+
+        force_collect();
+        assert!(weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn minor_collection_keeps_nodes_stored_after_promotion() {
+    run_test(|| {
+        let promoted = Gc::new(GcRefCell::new(None));
+        Harness::collect_young();
+        Harness::assert_generations(0, 1);
+
+        // The old-to-young edge is created after the promotion, without any write barrier, and
+        // it is the only reference to the young node.
+        *promoted.borrow_mut() = Some(Gc::new(Node {
+            value: 1,
+            next: None,
+        }));
+        Harness::collect_young();
+        Harness::assert_generations(0, 2);
+        assert_eq!(promoted.borrow().as_ref().map(|node| node.value), Some(1));
+
+        // Minor collections don't sweep the mature space, so the young nodes referenced by an
+        // unreachable mature node are promoted, and released by the next full collection.
+        let unreachable = Gc::new(GcRefCell::new(None));
+        Harness::collect_young();
+        *unreachable.borrow_mut() = Some(Gc::new(Node {
+            value: 2,
+            next: None,
+        }));
+        drop(unreachable);
+        Harness::collect_young();
+        Harness::assert_generations(0, 4);
+
+        force_collect();
+        Harness::assert_generations(0, 2);
+
+        drop(promoted);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
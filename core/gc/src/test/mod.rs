@@ -2,6 +2,11 @@ use crate::BOA_GC;
 
 mod allocation;
 mod cell;
+mod config;
+#[cfg(feature = "generational")]
+mod generational;
+mod snapshot;
+mod stats;
 mod weak;
 mod weak_map;
 
@@ -22,10 +27,30 @@ impl Harness {
             let gc = current.borrow();
 
             assert!(gc.strongs.is_empty());
+            #[cfg(feature = "generational")]
+            assert!(gc.young.is_empty());
             assert!(gc.runtime.bytes_allocated == 0);
         });
     }
 
+    #[cfg(feature = "generational")]
+    fn collect_young() {
+        BOA_GC.with(|current| {
+            let mut gc = current.borrow_mut();
+            crate::Collector::collect_young(&mut gc);
+        });
+    }
+
+    #[cfg(feature = "generational")]
+    #[track_caller]
+    fn assert_generations(young: usize, mature: usize) {
+        BOA_GC.with(|current| {
+            let gc = current.borrow();
+            assert_eq!(gc.young.len(), young);
+            assert_eq!(gc.strongs.len(), mature);
+        });
+    }
+
     #[track_caller]
     fn assert_bytes_allocated() {
         BOA_GC.with(|current| {
@@ -0,0 +1,51 @@
+use super::run_test;
+use crate::{force_collect, heap_snapshot, stats, Gc, HeapSnapshot};
+
+#[test]
+fn snapshot_records_paths_from_roots() {
+    run_test(|| {
+        let leaf = Gc::new(7_u32);
+        let leaf_id = HeapSnapshot::id_of(&leaf);
+        let root = Gc::new(vec![Gc::new(vec![leaf])]);
+        drop(Gc::new(1_u8));
+
+        let snapshot = heap_snapshot();
+        assert_eq!(snapshot.nodes().len(), 3);
+
+        let leaf = snapshot.index_of(leaf_id).unwrap();
+        assert_eq!(snapshot.nodes()[leaf].type_name, "u32");
+        assert!(!snapshot.nodes()[leaf].root);
+
+        let path = snapshot.path_to(leaf).unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(snapshot.nodes()[path[0]].id, HeapSnapshot::id_of(&root));
+        assert!(snapshot.nodes()[path[0]].root);
+
+        // Taking a snapshot must not keep anything alive.
+        drop(root);
+        force_collect();
+        assert_eq!(stats().heap_bytes, 0);
+    });
+}
+
+#[test]
+fn snapshot_serialization() {
+    run_test(|| {
+        let _root = Gc::new(vec![Gc::new(7_u32)]);
+        let snapshot = heap_snapshot();
+
+        let mut json = Vec::new();
+        snapshot.write_json(&mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.starts_with("{\"nodes\":[{\"id\":"));
+        assert!(json.contains("\"type\":\"u32\",\"size\":"));
+        assert!(json.contains("\"root\":true,\"edges\":[1]"));
+
+        let mut chrome = Vec::new();
+        snapshot.write_heapsnapshot(&mut chrome).unwrap();
+        let chrome = String::from_utf8(chrome).unwrap();
+        assert!(chrome.starts_with("{\"snapshot\":{\"meta\":"));
+        assert!(chrome.contains("\"node_count\":3,\"edge_count\":2,"));
+        assert!(chrome.contains("\"strings\":[\"(GC roots)\","));
+    });
+}
@@ -0,0 +1,95 @@
+use std::{cell::Cell, rc::Rc};
+
+use super::run_test;
+use crate::{
+    force_collect, set_allocation_hook, stats, take_denied_allocations, track_external_allocation,
+    track_external_deallocation, AllocationAction, AllocationHook, Gc, GcBox, GcStats, MemoryKind,
+};
+
+#[test]
+fn stats_account_heap_and_external_memory() {
+    run_test(|| {
+        const SIZE: usize = std::mem::size_of::<GcBox<[u8; 64]>>();
+
+        let values: Vec<_> = (0..10).map(|_| Gc::new([0_u8; 64])).collect();
+        track_external_allocation(MemoryKind::String, 1000);
+        track_external_allocation(MemoryKind::Object, 200);
+
+        let current = stats();
+        assert_eq!(current.heap_bytes, SIZE * 10);
+        assert_eq!(current.string_bytes, 1000);
+        assert_eq!(current.object_bytes, 200);
+        assert_eq!(current.other_bytes, 0);
+        assert_eq!(current.bytes_allocated, SIZE * 10 + 1200);
+
+        drop(values);
+        track_external_deallocation(MemoryKind::String, 1000);
+        force_collect();
+
+        let current = stats();
+        assert_eq!(current.collections, 1);
+        assert_eq!(current.bytes_allocated, 200);
+        assert_eq!(current.live_bytes, 200);
+    });
+}
+
+struct Limit {
+    bytes: usize,
+    action: AllocationAction,
+    calls: Cell<usize>,
+}
+
+impl AllocationHook for Limit {
+    fn on_allocation(&self, stats: &GcStats, size: usize) -> AllocationAction {
+        self.calls.set(self.calls.get() + 1);
+        if stats.bytes_allocated + size > self.bytes {
+            self.action
+        } else {
+            AllocationAction::Continue
+        }
+    }
+}
+
+#[test]
+fn allocation_hook_can_collect() {
+    run_test(|| {
+        const SIZE: usize = std::mem::size_of::<GcBox<[u8; 64]>>();
+
+        let hook = Rc::new(Limit {
+            bytes: SIZE * 2,
+            action: AllocationAction::Collect,
+            calls: Cell::new(0),
+        });
+        set_allocation_hook(Some(hook.clone()));
+
+        for _ in 0..3 {
+            drop(Gc::new([0_u8; 64]));
+        }
+
+        assert_eq!(hook.calls.get(), 3);
+        assert_eq!(stats().collections, 1);
+        assert_eq!(stats().heap_bytes, SIZE);
+        assert!(!take_denied_allocations());
+    });
+}
+
+#[test]
+fn allocation_hook_can_deny() {
+    run_test(|| {
+        let hook = Rc::new(Limit {
+            bytes: 0,
+            action: AllocationAction::Deny,
+            calls: Cell::new(0),
+        });
+        set_allocation_hook(Some(hook));
+
+        let value = Gc::new(1_u8);
+        assert_eq!(*value, 1);
+        assert!(take_denied_allocations());
+        assert!(!take_denied_allocations());
+
+        set_allocation_hook(None);
+        let _value = Gc::new(2_u8);
+        assert!(!take_denied_allocations());
+    });
+}
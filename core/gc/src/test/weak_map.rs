@@ -1,5 +1,5 @@
 use super::run_test;
-use crate::{force_collect, has_weak_maps, Gc, WeakMap};
+use crate::{force_collect, has_weak_maps, stats, Gc, WeakMap};
 
 #[test]
 fn weak_map_basic() {
@@ -136,3 +136,24 @@ fn weak_map_key_live() {
         assert!(map.contains_key(&key_copy));
     });
 }
+
+#[test]
+fn weak_map_key_in_value_cycle() {
+    run_test(|| {
+        let mut map = WeakMap::new();
+        let baseline = stats().heap_bytes;
+
+        for _ in 0..100 {
+            let key = Gc::new([0_u8; 64]);
+            map.insert(&key, key.clone());
+        }
+        assert!(stats().heap_bytes > baseline);
+
+        // The first collection clears the dead entries, and the second one frees their ephemerons.
+        force_collect();
+        force_collect();
+
+        assert_eq!(stats().heap_bytes, baseline);
+        drop(map);
+    });
+}
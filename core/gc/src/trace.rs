@@ -521,3 +521,53 @@ mod icu {
         empty_trace!();
     }
 }
+
+#[cfg(feature = "indexmap")]
+mod indexmap {
+    use std::hash::{BuildHasher, Hash};
+
+    use ::indexmap::{IndexMap, IndexSet};
+
+    use crate::{Finalize, Trace};
+
+    impl<K: Eq + Hash + Trace, V: Trace, S: BuildHasher> Finalize for IndexMap<K, V, S> {}
+    // SAFETY: All the elements of the `IndexMap` are correctly marked.
+    unsafe impl<K: Eq + Hash + Trace, V: Trace, S: BuildHasher> Trace for IndexMap<K, V, S> {
+        custom_trace!(this, mark, {
+            for (k, v) in this {
+                mark(k);
+                mark(v);
+            }
+        });
+    }
+
+    impl<T: Eq + Hash + Trace, S: BuildHasher> Finalize for IndexSet<T, S> {}
+    // SAFETY: All the elements of the `IndexSet` are correctly marked.
+    unsafe impl<T: Eq + Hash + Trace, S: BuildHasher> Trace for IndexSet<T, S> {
+        custom_trace!(this, mark, {
+            for v in this {
+                mark(v);
+            }
+        });
+    }
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec {
+    use ::smallvec::{Array, SmallVec};
+
+    use crate::{Finalize, Trace};
+
+    impl<A: Array> Finalize for SmallVec<A> where A::Item: Trace {}
+    // SAFETY: All the inner elements of the `SmallVec` are correctly marked.
+    unsafe impl<A: Array> Trace for SmallVec<A>
+    where
+        A::Item: Trace,
+    {
+        custom_trace!(this, mark, {
+            for e in this {
+                mark(e);
+            }
+        });
+    }
+}
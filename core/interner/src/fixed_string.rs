@@ -33,6 +33,21 @@ impl<Char> FixedString<Char> {
     pub(super) fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
+
+    /// Returns the length of the [`FixedString`].
+    pub(super) fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Shortens the [`FixedString`] to `len` characters, without changing its capacity.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are no [`InternedStr`]s pointing to
+    /// the removed characters.
+    pub(super) unsafe fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
 }
 
 impl<Char> FixedString<Char>
@@ -20,21 +20,33 @@
     // TODO deny once false positive is fixed (https://github.com/rust-lang/rust-clippy/issues/9626).
     clippy::trait_duplication_in_bounds
 )]
-#![cfg_attr(not(feature = "arbitrary"), no_std)]
+#![cfg_attr(not(any(feature = "arbitrary", feature = "sync")), no_std)]
 
 extern crate alloc;
 
 mod fixed_string;
 mod interned_str;
 mod raw;
+mod scope;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "sync")]
+mod shared;
+mod snapshot;
 mod sym;
 
 #[cfg(test)]
 mod tests;
 
-use alloc::{borrow::Cow, format, string::String};
-use raw::RawInterner;
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+use raw::{RawCheckpoint, RawInterner};
 
+pub use scope::{InternerCheckpoint, InternerScope};
+#[cfg(feature = "serde")]
+pub use serialization::{ResolvedSym, SymSeed};
+#[cfg(feature = "sync")]
+pub use shared::SharedInterner;
+pub use snapshot::{InternerSnapshot, SymRemap};
 pub use sym::*;
 
 /// An enumeration of all slice types [`Interner`] can internally store.
@@ -165,10 +177,29 @@ impl core::fmt::Display for JSInternedStrRef<'_, '_> {
 }
 
 /// The string interner for Boa.
+///
+/// The interner only grows by default, but the strings that are only needed for a while can be
+/// freed by interning them inside an [`Interner::scope`], or between an [`Interner::checkpoint`]
+/// and an [`Interner::rollback`].
+///
+/// With the `sync` feature, many interners can share their strings and symbols through a
+/// `SharedInterner`.
 #[derive(Debug, Default)]
 pub struct Interner {
     utf8_interner: RawInterner<u8>,
     utf16_interner: RawInterner<u16>,
+    checkpoints: Vec<Checkpoint>,
+    next_checkpoint: usize,
+    #[cfg(feature = "sync")]
+    shared: Option<shared::SharedHandle>,
+}
+
+/// The state of both interners when a checkpoint was taken.
+#[derive(Debug, Clone, Copy)]
+struct Checkpoint {
+    id: usize,
+    utf8: RawCheckpoint,
+    utf16: RawCheckpoint,
 }
 
 impl Interner {
@@ -186,6 +217,10 @@ impl Interner {
         Self {
             utf8_interner: RawInterner::with_capacity(capacity),
             utf16_interner: RawInterner::with_capacity(capacity),
+            checkpoints: Vec::new(),
+            next_checkpoint: 0,
+            #[cfg(feature = "sync")]
+            shared: None,
         }
     }
 
@@ -193,6 +228,11 @@ impl Interner {
     #[inline]
     #[must_use]
     pub fn len(&self) -> usize {
+        #[cfg(feature = "sync")]
+        if let Some(shared) = &self.shared {
+            return shared.len();
+        }
+
         // `utf16_interner.len()` == `utf8_interner.len()`,
         // so we can use any of them.
         COMMON_STRINGS_UTF8.len() + self.utf16_interner.len()
@@ -202,6 +242,11 @@ impl Interner {
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
+        #[cfg(feature = "sync")]
+        if let Some(shared) = &self.shared {
+            return shared.is_empty();
+        }
+
         COMMON_STRINGS_UTF8.is_empty() && self.utf16_interner.is_empty()
     }
 
@@ -213,6 +258,12 @@ impl Interner {
         T: Into<JStrRef<'a>>,
     {
         let string = string.into();
+
+        #[cfg(feature = "sync")]
+        if let Some(shared) = &self.shared {
+            return shared.get(string);
+        }
+
         Self::get_common(string).or_else(|| {
             let index = match string {
                 JStrRef::Utf8(s) => self.utf8_interner.get(s.as_bytes()),
@@ -238,6 +289,12 @@ impl Interner {
         T: Into<JStrRef<'a>>,
     {
         let string = string.into();
+
+        #[cfg(feature = "sync")]
+        if let Some(shared) = &mut self.shared {
+            return shared.get_or_intern(string);
+        }
+
         self.get(string).unwrap_or_else(|| {
             let (utf8, utf16) = match string {
                 JStrRef::Utf8(s) => (
@@ -286,6 +343,11 @@ impl Interner {
     ///
     /// If the interner already interns the maximum number of strings possible by the chosen symbol type.
     pub fn get_or_intern_static(&mut self, utf8: &'static str, utf16: &'static [u16]) -> Sym {
+        #[cfg(feature = "sync")]
+        if let Some(shared) = &mut self.shared {
+            return shared.get_or_intern_static(utf8, utf16);
+        }
+
         // Uses the utf8 because it's quicker to check inside `COMMON_STRINGS_UTF8`
         // (which is a perfect hash set) than to check inside `COMMON_STRINGS_UTF16`
         // (which is a lazy static hash set).
@@ -311,6 +373,11 @@ impl Interner {
     /// not have the same size
     #[must_use]
     pub fn resolve(&self, symbol: Sym) -> Option<JSInternedStrRef<'_, '_>> {
+        #[cfg(feature = "sync")]
+        if let Some(shared) = &self.shared {
+            return shared.resolve(symbol);
+        }
+
         let index = symbol.get() - 1;
 
         if let Some(utf8) = COMMON_STRINGS_UTF8.index(index).copied() {
@@ -358,6 +425,74 @@ impl Interner {
         self.resolve(symbol).expect("string disappeared")
     }
 
+    /// Records the current state of the interner, so that the strings interned from now on can be
+    /// removed by [`Interner::rollback`].
+    ///
+    /// Checkpoints can be nested, and rolling back or committing a checkpoint also discards all
+    /// the checkpoints taken after it.
+    pub fn checkpoint(&mut self) -> InternerCheckpoint {
+        let id = self.next_checkpoint;
+        self.next_checkpoint = self.next_checkpoint.wrapping_add(1);
+        self.checkpoints.push(Checkpoint {
+            id,
+            utf8: self.utf8_interner.checkpoint(),
+            utf16: self.utf16_interner.checkpoint(),
+        });
+        InternerCheckpoint::new(id, self.checkpoints.len() - 1)
+    }
+
+    /// Removes all the strings interned after `checkpoint` was taken, freeing their memory.
+    ///
+    /// The common strings are never removed. The symbols of the removed strings must not be used
+    /// anymore, since they will either fail to resolve or resolve to strings interned later.
+    ///
+    /// This does nothing if `checkpoint` was already discarded, or if the strings are shared with
+    /// other interners.
+    pub fn rollback(&mut self, checkpoint: InternerCheckpoint) {
+        if let Some(state) = self.take_checkpoint(checkpoint) {
+            self.utf8_interner.rollback(state.utf8);
+            self.utf16_interner.rollback(state.utf16);
+        }
+    }
+
+    /// Discards `checkpoint`, keeping all the strings interned after it was taken.
+    pub fn commit(&mut self, checkpoint: InternerCheckpoint) {
+        self.take_checkpoint(checkpoint);
+    }
+
+    /// Returns an [`InternerScope`] that removes the strings interned through it when dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_interner::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// let len = interner.len();
+    /// {
+    ///     let mut scope = interner.scope();
+    ///     let sym = scope.get_or_intern("temporary");
+    ///     assert_eq!(scope.resolve_expect(sym).utf8(), Some("temporary"));
+    /// }
+    /// assert_eq!(interner.len(), len);
+    /// assert!(interner.get("temporary").is_none());
+    /// ```
+    pub fn scope(&mut self) -> InternerScope<'_> {
+        InternerScope::new(self)
+    }
+
+    /// Removes `checkpoint` and the checkpoints taken after it, returning its state if it wasn't
+    /// already discarded.
+    fn take_checkpoint(&mut self, checkpoint: InternerCheckpoint) -> Option<Checkpoint> {
+        let (id, depth) = checkpoint.into_parts();
+        let state = *self.checkpoints.get(depth)?;
+        if state.id != id {
+            return None;
+        }
+        self.checkpoints.truncate(depth);
+        Some(state)
+    }
+
     /// Gets the symbol of the common string if one of them
     fn get_common(string: JStrRef<'_>) -> Option<Sym> {
         match string {
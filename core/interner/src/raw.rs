@@ -27,6 +27,14 @@ pub(super) struct RawInterner<Char> {
     full: Vec<FixedString<Char>>,
 }
 
+/// The state of a [`RawInterner`] at some point, which it can be rolled back to.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RawCheckpoint {
+    spans: usize,
+    head: usize,
+    full: usize,
+}
+
 impl<Char> Default for RawInterner<Char> {
     fn default() -> Self {
         Self {
@@ -58,6 +66,15 @@ impl<Char> RawInterner<Char> {
     pub(super) fn is_empty(&self) -> bool {
         self.spans.is_empty()
     }
+
+    /// Returns the current state of the interner.
+    pub(super) fn checkpoint(&self) -> RawCheckpoint {
+        RawCheckpoint {
+            spans: self.spans.len(),
+            head: self.head.len(),
+            full: self.full.len(),
+        }
+    }
 }
 
 impl<Char> RawInterner<Char>
@@ -105,6 +122,46 @@ where
         unsafe { self.next_index(string) }
     }
 
+    /// Removes all the strings interned after `checkpoint` was taken, and frees the memory that
+    /// stored them.
+    ///
+    /// `checkpoint` must be a state of this interner that wasn't already discarded by rolling back
+    /// to an earlier checkpoint.
+    pub(super) fn rollback(&mut self, checkpoint: RawCheckpoint) {
+        if checkpoint.spans >= self.spans.len() {
+            return;
+        }
+
+        for (offset, string) in self.spans.drain(checkpoint.spans..).enumerate() {
+            // Strings interned by `intern_static` can share the same contents, so only the
+            // entries pointing to the removed indices are removed.
+            if self.symbol_cache.get(&string) == Some(&(checkpoint.spans + offset)) {
+                self.symbol_cache.remove(&string);
+            }
+        }
+
+        if self.full.len() > checkpoint.full {
+            // `head` was replaced after the checkpoint. If it had strings by then, it was pushed
+            // to `full` right after the heads that were already there, and it becomes the `head`
+            // again. Otherwise, none of the heads pushed since then have strings interned before
+            // the checkpoint.
+            if checkpoint.head == 0 {
+                self.full.truncate(checkpoint.full);
+            } else {
+                self.full.truncate(checkpoint.full + 1);
+                if let Some(head) = self.full.pop() {
+                    self.head = head;
+                }
+            }
+        }
+
+        // SAFETY: The strings after `checkpoint.head` were interned after the checkpoint, and we
+        // just removed them from `spans` and `symbol_cache`.
+        unsafe {
+            self.head.truncate(checkpoint.head);
+        }
+    }
+
     /// Returns the string for the given index if any.
     pub(super) fn index(&self, index: usize) -> Option<&[Char]> {
         self.spans.get(index).map(|ptr|
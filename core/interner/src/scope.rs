@@ -0,0 +1,79 @@
+//! Scoped interning, which frees the strings that are only needed for a while.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::Interner;
+
+/// A state of an [`Interner`] returned by [`Interner::checkpoint`], which it can be rolled back to
+/// with [`Interner::rollback`].
+#[derive(Debug, PartialEq, Eq)]
+#[must_use = "the strings are only freed by passing the checkpoint to `Interner::rollback`"]
+#[allow(missing_copy_implementations)] // A checkpoint can only be rolled back or committed once.
+pub struct InternerCheckpoint {
+    id: usize,
+    depth: usize,
+}
+
+impl InternerCheckpoint {
+    /// Creates a new checkpoint with the given identifier and position in the checkpoints of the
+    /// interner.
+    pub(crate) const fn new(id: usize, depth: usize) -> Self {
+        Self { id, depth }
+    }
+
+    /// Consumes the checkpoint, returning its identifier and its position in the checkpoints of
+    /// the interner.
+    pub(crate) const fn into_parts(self) -> (usize, usize) {
+        (self.id, self.depth)
+    }
+}
+
+/// A guard returned by [`Interner::scope`] that gives access to the [`Interner`], and removes all
+/// the strings interned through it when dropped.
+///
+/// The symbols of those strings must not be used after the scope ends.
+#[derive(Debug)]
+pub struct InternerScope<'a> {
+    interner: &'a mut Interner,
+    checkpoint: Option<InternerCheckpoint>,
+}
+
+impl<'a> InternerScope<'a> {
+    /// Creates a new scope over `interner`.
+    pub(crate) fn new(interner: &'a mut Interner) -> Self {
+        let checkpoint = interner.checkpoint();
+        Self {
+            interner,
+            checkpoint: Some(checkpoint),
+        }
+    }
+
+    /// Ends the scope, keeping all the strings interned through it.
+    pub fn commit(mut self) {
+        if let Some(checkpoint) = self.checkpoint.take() {
+            self.interner.commit(checkpoint);
+        }
+    }
+}
+
+impl Deref for InternerScope<'_> {
+    type Target = Interner;
+
+    fn deref(&self) -> &Self::Target {
+        self.interner
+    }
+}
+
+impl DerefMut for InternerScope<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.interner
+    }
+}
+
+impl Drop for InternerScope<'_> {
+    fn drop(&mut self) {
+        if let Some(checkpoint) = self.checkpoint.take() {
+            self.interner.rollback(checkpoint);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Serialization support for [`Sym`].
+//!
+//! A [`Sym`] serializes as its raw index, which is only meaningful together with the [`Interner`]
+//! that produced it. [`ResolvedSym`] serializes the interned string itself instead, and [`SymSeed`]
+//! interns such a string when deserializing, so the string can be loaded into a completely
+//! different [`Interner`].
+
+use crate::{Interner, Sym};
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{
+    de::{self, DeserializeSeed, SeqAccess, Visitor},
+    Deserializer, Serialize, Serializer,
+};
+
+/// Serializes a [`Sym`] as the string it resolves to in an [`Interner`], instead of its raw
+/// index.
+///
+/// Strings that are valid UTF-8 are serialized as strings; strings containing unpaired surrogates
+/// are serialized as sequences of UTF-16 code units.
+///
+/// # Panics
+///
+/// Serializing a [`Sym`] that is not present in the interner panics.
+#[derive(Debug, Clone, Copy)]
+pub struct ResolvedSym<'a> {
+    interner: &'a Interner,
+    sym: Sym,
+}
+
+impl<'a> ResolvedSym<'a> {
+    /// Creates a new `ResolvedSym` that serializes `sym` as its string in `interner`.
+    #[must_use]
+    pub const fn new(interner: &'a Interner, sym: Sym) -> Self {
+        Self { interner, sym }
+    }
+}
+
+impl Serialize for ResolvedSym<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let string = self.interner.resolve_expect(self.sym);
+        match string.utf8() {
+            Some(utf8) => serializer.serialize_str(utf8),
+            None => string.utf16().serialize(serializer),
+        }
+    }
+}
+
+/// Deserializes a [`Sym`] from its string representation, as produced by [`ResolvedSym`], and
+/// interns it into an [`Interner`].
+#[derive(Debug)]
+pub struct SymSeed<'a> {
+    interner: &'a mut Interner,
+}
+
+impl<'a> SymSeed<'a> {
+    /// Creates a new `SymSeed` that interns the deserialized string into `interner`.
+    pub fn new(interner: &'a mut Interner) -> Self {
+        Self { interner }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for SymSeed<'_> {
+    type Value = Sym;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for SymSeed<'_> {
+    type Value = Sym;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a string or a sequence of UTF-16 code units")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(self.interner.get_or_intern(v))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut utf16 = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(unit) = seq.next_element::<u16>()? {
+            utf16.push(unit);
+        }
+        Ok(self.interner.get_or_intern(&utf16[..]))
+    }
+}
@@ -0,0 +1,209 @@
+//! An interner that can be shared between threads, which allows parsing many sources in parallel
+//! while resolving to the same symbols.
+
+use core::hash::BuildHasherDefault;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use hashbrown::HashMap;
+use rustc_hash::FxHasher;
+
+use crate::{interned_str::InternedStr, Interner, JSInternedStrRef, JStrRef, Sym};
+
+type Map<T, U> = HashMap<T, U, BuildHasherDefault<FxHasher>>;
+
+/// The strings of a [`SharedInterner`].
+#[derive(Debug, Default)]
+struct SharedStrings {
+    // Strings are never removed from this interner, so the memory of an interned string stays
+    // valid until the `SharedInterner` is dropped.
+    interner: Interner,
+}
+
+// SAFETY: The pointers stored by the interner only point to memory owned by the interner itself,
+// and it is only accessed behind a `Mutex`.
+unsafe impl Send for SharedStrings {}
+
+/// A string interner that can be shared between threads.
+///
+/// A `SharedInterner` cannot intern strings by itself. Instead, every thread creates its own
+/// [`Interner`] with [`SharedInterner::interner`], which interns and resolves strings through
+/// the shared one, so the same string gets the same [`Sym`] on every thread. The static symbols
+/// of [`Sym`] are the same as with any other [`Interner`].
+///
+/// Every [`Interner`] caches the symbols it already interned, so the lock is only taken for the
+/// strings that are new to it, and for resolving symbols.
+///
+/// # Example
+///
+/// ```
+/// use boa_interner::SharedInterner;
+///
+/// let shared = SharedInterner::new();
+/// let sym = std::thread::scope(|s| {
+///     s.spawn(|| shared.interner().get_or_intern("shared"))
+///         .join()
+///         .unwrap()
+/// });
+///
+/// let interner = shared.interner();
+/// assert_eq!(interner.get("shared"), Some(sym));
+/// assert_eq!(interner.resolve_expect(sym).utf8(), Some("shared"));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SharedInterner {
+    strings: Arc<Mutex<SharedStrings>>,
+}
+
+impl SharedInterner {
+    /// Creates a new, empty [`SharedInterner`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an [`Interner`] that interns its strings in this [`SharedInterner`].
+    ///
+    /// Strings interned through the returned [`Interner`] are never removed, even when rolling
+    /// back a checkpoint.
+    #[must_use]
+    pub fn interner(&self) -> Interner {
+        Interner {
+            shared: Some(SharedHandle {
+                shared: self.clone(),
+                utf8_cache: Map::default(),
+                utf16_cache: Map::default(),
+            }),
+            ..Interner::default()
+        }
+    }
+
+    /// Returns the number of strings interned by the interner.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().interner.len()
+    }
+
+    /// Returns `true` if the [`SharedInterner`] contains no interned strings.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().interner.is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SharedStrings> {
+        // Interning doesn't leave the interner in an inconsistent state if it panics, so the
+        // poisoning can be ignored.
+        self.strings.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The connection of an [`Interner`] to a [`SharedInterner`].
+#[derive(Debug)]
+pub(crate) struct SharedHandle {
+    // The cached strings point to the memory of `shared`, which lives at least as long as the
+    // handle.
+    shared: SharedInterner,
+    utf8_cache: Map<InternedStr<u8>, Sym>,
+    utf16_cache: Map<InternedStr<u16>, Sym>,
+}
+
+impl SharedHandle {
+    /// Returns the symbol for the given string if any.
+    pub(crate) fn get(&self, string: JStrRef<'_>) -> Option<Sym> {
+        self.cached(string)
+            .or_else(|| self.shared.lock().interner.get(string))
+    }
+
+    /// Interns the given string.
+    pub(crate) fn get_or_intern(&mut self, string: JStrRef<'_>) -> Sym {
+        if let Some(sym) = self.cached(string) {
+            return sym;
+        }
+        let mut strings = self.shared.lock();
+        let sym = strings.interner.get_or_intern(string);
+        Self::cache(
+            &mut self.utf8_cache,
+            &mut self.utf16_cache,
+            &strings.interner,
+            sym,
+        );
+        sym
+    }
+
+    /// Interns the given `'static` string.
+    pub(crate) fn get_or_intern_static(
+        &mut self,
+        utf8: &'static str,
+        utf16: &'static [u16],
+    ) -> Sym {
+        if let Some(sym) = self.cached(JStrRef::Utf8(utf8)) {
+            return sym;
+        }
+        let mut strings = self.shared.lock();
+        let sym = strings.interner.get_or_intern_static(utf8, utf16);
+        Self::cache(
+            &mut self.utf8_cache,
+            &mut self.utf16_cache,
+            &strings.interner,
+            sym,
+        );
+        sym
+    }
+
+    /// Returns the string for the given symbol if any.
+    pub(crate) fn resolve(&self, symbol: Sym) -> Option<JSInternedStrRef<'_, '_>> {
+        let strings = self.shared.lock();
+        let string = strings.interner.resolve(symbol)?;
+        let utf8 = string.utf8().map(|utf8| -> *const str { utf8 });
+        let utf16: *const [u16] = string.utf16();
+
+        // SAFETY: Strings are never removed from the shared interner, and their memory is never
+        // moved, so it stays valid while `self` keeps the shared interner alive.
+        unsafe {
+            Some(JSInternedStrRef {
+                utf8: utf8.map(|utf8| &*utf8),
+                utf16: &*utf16,
+            })
+        }
+    }
+
+    /// Returns the number of strings interned by the shared interner.
+    pub(crate) fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Returns `true` if the shared interner contains no interned strings.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.shared.is_empty()
+    }
+
+    fn cached(&self, string: JStrRef<'_>) -> Option<Sym> {
+        // SAFETY: `string` is a valid slice that doesn't outlive the created `InternedStr`.
+        unsafe {
+            match string {
+                JStrRef::Utf8(s) => self
+                    .utf8_cache
+                    .get(&InternedStr::new(s.as_bytes().into()))
+                    .copied(),
+                JStrRef::Utf16(s) => self.utf16_cache.get(&InternedStr::new(s.into())).copied(),
+            }
+        }
+    }
+
+    fn cache(
+        utf8_cache: &mut Map<InternedStr<u8>, Sym>,
+        utf16_cache: &mut Map<InternedStr<u16>, Sym>,
+        interner: &Interner,
+        sym: Sym,
+    ) {
+        let string = interner.resolve_expect(sym);
+
+        // SAFETY: The strings of the shared interner stay valid while the handle that owns the
+        // caches keeps it alive.
+        unsafe {
+            if let Some(utf8) = string.utf8() {
+                utf8_cache.insert(InternedStr::new(utf8.as_bytes().into()), sym);
+            }
+            utf16_cache.insert(InternedStr::new(string.utf16().into()), sym);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Snapshots of the strings of an [`Interner`], which allow loading data that stores raw [`Sym`]s
+//! into a different [`Interner`].
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{Interner, Sym, COMMON_STRINGS_UTF8};
+
+/// A string stored in an [`InternerSnapshot`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SnapshotString {
+    Utf8(Box<str>),
+    Utf16(Box<[u16]>),
+}
+
+/// The strings interned by an [`Interner`], in the order of their symbols.
+///
+/// The common strings are the same in every [`Interner`], so they are not stored in the
+/// snapshot.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InternerSnapshot {
+    strings: Vec<SnapshotString>,
+}
+
+impl InternerSnapshot {
+    /// Returns the number of strings in the snapshot.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if the snapshot contains no strings.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+/// Maps the symbols of an [`InternerSnapshot`] to the symbols of the [`Interner`] it was
+/// restored into.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymRemap {
+    syms: Vec<Sym>,
+}
+
+impl SymRemap {
+    /// Gets the symbol that `sym` of the snapshot has in the restored [`Interner`], or `None` if
+    /// `sym` isn't part of the snapshot.
+    #[must_use]
+    pub fn get(&self, sym: Sym) -> Option<Sym> {
+        match sym.get().checked_sub(COMMON_STRINGS_UTF8.len() + 1) {
+            None => Some(sym),
+            Some(index) => self.syms.get(index).copied(),
+        }
+    }
+
+    /// Returns `true` if every symbol of the snapshot kept its value, which happens when the
+    /// snapshot is restored into an empty [`Interner`].
+    #[must_use]
+    pub fn is_identity(&self) -> bool {
+        self.syms
+            .iter()
+            .enumerate()
+            .all(|(index, sym)| sym.get() == index + COMMON_STRINGS_UTF8.len() + 1)
+    }
+}
+
+impl Interner {
+    /// Takes a snapshot of the strings interned by the interner.
+    ///
+    /// Restoring the snapshot into another interner with [`Interner::restore`] gives the symbols
+    /// the strings have there, so data that stores the raw symbols of this interner can be used
+    /// with the other one.
+    #[must_use]
+    pub fn snapshot(&self) -> InternerSnapshot {
+        let strings = (COMMON_STRINGS_UTF8.len() + 1..=self.len())
+            .filter_map(Sym::new)
+            .map(|sym| {
+                let string = self.resolve_expect(sym);
+                match string.utf8() {
+                    Some(utf8) => SnapshotString::Utf8(utf8.into()),
+                    None => SnapshotString::Utf16(string.utf16().into()),
+                }
+            })
+            .collect();
+
+        InternerSnapshot { strings }
+    }
+
+    /// Interns all the strings of `snapshot`, returning the symbols they got in this interner.
+    ///
+    /// If the interner is empty, the strings keep the symbols they had when the snapshot was
+    /// taken.
+    pub fn restore(&mut self, snapshot: &InternerSnapshot) -> SymRemap {
+        let syms = snapshot
+            .strings
+            .iter()
+            .map(|string| match string {
+                SnapshotString::Utf8(utf8) => self.get_or_intern(&**utf8),
+                SnapshotString::Utf16(utf16) => self.get_or_intern(&**utf16),
+            })
+            .collect();
+
+        SymRemap { syms }
+    }
+}
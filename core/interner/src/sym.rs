@@ -6,6 +6,11 @@ use core::num::NonZeroUsize;
 ///
 /// This symbol type is internally a `NonZeroUsize`, which makes it pointer-width in size and it's
 /// optimized so that it can occupy 1 pointer width even in an `Option` type.
+///
+/// With the `serde` feature, a `Sym` is serialized as its raw index, which can only be resolved
+/// with the [`Interner`][crate::Interner] that produced it, or remapped with a
+/// [`SymRemap`][crate::SymRemap]. See [`ResolvedSym`][crate::ResolvedSym] and
+/// [`SymSeed`][crate::SymSeed] to serialize the interned string instead.
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
@@ -149,5 +154,6 @@ static_syms! {
     "name",
     "await",
     ("*default*", DEFAULT_EXPORT),
-    "meta"
+    "meta",
+    "using"
 }
@@ -1,4 +1,5 @@
 use crate::{Interner, Sym, COMMON_STRINGS_UTF16, COMMON_STRINGS_UTF8};
+use alloc::{format, string::String};
 use boa_macros::utf16;
 
 #[track_caller]
@@ -130,3 +131,137 @@ fn check_capacity() {
 
     assert!(interner.resolve(sym).is_none());
 }
+
+#[test]
+fn check_scope_frees_strings() {
+    let mut interner = Interner::default();
+    let kept = interner.get_or_intern("kept");
+    let len = interner.len();
+
+    {
+        let mut scope = interner.scope();
+        let sym = scope.get_or_intern("temporary");
+        let unp = scope.get_or_intern(&[0xDC15u16, 'a' as u16][..]);
+        assert_eq!(scope.get_or_intern("kept"), kept);
+        assert_eq!(scope.resolve_expect(sym).utf8(), Some("temporary"));
+        assert_eq!(scope.resolve_expect(unp).utf16(), &[0xDC15u16, 'a' as u16]);
+        assert_eq!(scope.len(), len + 2);
+    }
+
+    assert_eq!(interner.len(), len);
+    assert!(interner.get("temporary").is_none());
+    assert_eq!(interner.resolve_expect(kept).utf8(), Some("kept"));
+
+    let sym = interner.get_or_intern("another");
+    assert_eq!(interner.resolve_expect(sym).utf8(), Some("another"));
+}
+
+#[test]
+fn check_scope_commit() {
+    let mut interner = Interner::default();
+
+    let mut scope = interner.scope();
+    let sym = scope.get_or_intern("kept");
+    scope.commit();
+
+    assert_eq!(interner.resolve_expect(sym).utf8(), Some("kept"));
+}
+
+#[test]
+fn check_nested_checkpoints() {
+    let mut interner = Interner::default();
+
+    let outer = interner.checkpoint();
+    let first = interner.get_or_intern("first");
+    let inner = interner.checkpoint();
+    interner.get_or_intern("second");
+    interner.rollback(inner);
+
+    assert!(interner.get("second").is_none());
+    assert_eq!(interner.resolve_expect(first).utf8(), Some("first"));
+
+    let inner = interner.checkpoint();
+    interner.rollback(outer);
+    assert!(interner.get("first").is_none());
+
+    // `inner` was discarded by rolling back `outer`.
+    let third = interner.get_or_intern("third");
+    interner.rollback(inner);
+    assert_eq!(interner.resolve_expect(third).utf8(), Some("third"));
+}
+
+#[test]
+fn check_rollback_keeps_old_heads() {
+    let mut interner = Interner::with_capacity(8);
+    let before: [Sym; 2] = [interner.get_or_intern("abc"), interner.get_or_intern("def")];
+
+    {
+        // Fills the current head and forces the interner to allocate new ones.
+        let mut scope = interner.scope();
+        for i in 0..100 {
+            scope.get_or_intern(format!("a longer temporary string {i}").as_str());
+        }
+    }
+
+    assert_eq!(interner.resolve_expect(before[0]).utf8(), Some("abc"));
+    assert_eq!(interner.resolve_expect(before[1]).utf8(), Some("def"));
+
+    let long = String::from("x").repeat(100);
+    let sym = interner.get_or_intern(long.as_str());
+    let short = interner.get_or_intern("ghi");
+    assert_eq!(interner.resolve_expect(before[0]).utf8(), Some("abc"));
+    assert_eq!(interner.resolve_expect(sym).utf8(), Some(long.as_str()));
+    assert_eq!(interner.resolve_expect(short).utf8(), Some("ghi"));
+}
+
+#[test]
+fn check_scoped_interning_is_bounded() {
+    let mut interner = Interner::default();
+    let len = interner.len();
+
+    for i in 0..100_000 {
+        let mut scope = interner.scope();
+        let sym = scope.get_or_intern(format!("let x{i} = {i};").as_str());
+        assert_eq!(sym.get(), len + 1);
+    }
+
+    assert_eq!(interner.len(), len);
+}
+
+#[test]
+fn check_snapshot_restore() {
+    let mut interner = Interner::default();
+    let unp = &[0xDC15u16, 'a' as u16][..];
+    let syms = [
+        interner.get_or_intern("first"),
+        interner.get_or_intern("arguments"),
+        interner.get_or_intern(unp),
+        interner.get_or_intern(utf16!("last")),
+    ];
+
+    // The common strings are not part of the snapshot.
+    let snapshot = interner.snapshot();
+    assert_eq!(snapshot.len(), 3);
+
+    let mut empty = Interner::default();
+    let remap = empty.restore(&snapshot);
+    assert!(remap.is_identity());
+    for sym in syms {
+        assert_eq!(remap.get(sym), Some(sym));
+    }
+
+    let mut other = Interner::default();
+    let shifted = other.get_or_intern("last");
+    let remap = other.restore(&snapshot);
+    assert!(!remap.is_identity());
+    assert_eq!(remap.get(syms[1]), Some(syms[1]));
+    assert_eq!(remap.get(syms[3]), Some(shifted));
+    for sym in syms {
+        let remapped = remap.get(sym).unwrap();
+        assert_eq!(other.resolve_expect(remapped), interner.resolve_expect(sym));
+    }
+
+    assert!(remap
+        .get(sym_from_usize(COMMON_STRINGS_UTF8.len() + 4))
+        .is_none());
+}
@@ -7,12 +7,14 @@
 #![cfg_attr(not(test), forbid(clippy::unwrap_used))]
 
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input,
     punctuated::Punctuated,
-    Data, DeriveInput, Expr, ExprLit, Fields, FieldsNamed, Ident, Lit, LitStr, Token,
+    spanned::Spanned,
+    Data, DeriveInput, Expr, ExprLit, Fields, FieldsNamed, GenericArgument, Ident, Lit, LitStr,
+    PathArguments, PathSegment, Token, Type,
 };
 use synstructure::{decl_derive, AddBounds, Structure};
 
@@ -240,8 +242,30 @@ fn derive_trace(mut s: Structure<'_>) -> proc_macro2::TokenStream {
             .iter()
             .any(|attr| attr.path().is_ident("unsafe_ignore_trace"))
     });
-    let trace_body = s.each(|bi| quote!(::boa_gc::Trace::trace(#bi, tracer)));
-    let trace_other_body = s.each(|bi| quote!(mark(#bi)));
+
+    for variant in s.variants() {
+        for bi in variant.bindings() {
+            let ty = &bi.ast().ty;
+            if let Some((found, suggestion)) = untraceable_type(ty) {
+                let msg = format!(
+                    "{found} cannot be traced by the garbage collector, use {suggestion} instead, \
+                    or mark the field with `#[unsafe_ignore_trace]` if it never holds garbage \
+                    collected values"
+                );
+                return syn::Error::new_spanned(ty, msg).into_compile_error();
+            }
+        }
+    }
+
+    // Span the calls with the types of the fields, so that a missing `Trace` implementation
+    // points to the offending field. Only the calls are spanned, the `unsafe` blocks belong to
+    // the macro.
+    let trace_body = s.each(|bi| {
+        let call = quote_spanned!(bi.ast().ty.span()=> ::boa_gc::Trace::trace(#bi, tracer));
+        // SAFETY: The implementor must ensure that `trace` is correctly implemented.
+        quote!(unsafe { #call })
+    });
+    let trace_other_body = s.each(|bi| quote_spanned!(bi.ast().ty.span()=> mark(#bi)));
 
     s.add_bounds(AddBounds::Fields);
     let trace_impl = s.unsafe_bound_impl(
@@ -309,6 +333,37 @@ fn derive_trace(mut s: Structure<'_>) -> proc_macro2::TokenStream {
     }
 }
 
+/// Returns the last segment of the path of `ty`, if it is a path.
+fn last_segment(ty: &Type) -> Option<&PathSegment> {
+    match ty {
+        Type::Path(path) => path.path.segments.last(),
+        _ => None,
+    }
+}
+
+/// Returns a description of `ty` and the suggested replacement, if `ty` is a shared or interior
+/// mutability type that can never be traced.
+fn untraceable_type(ty: &Type) -> Option<(String, &'static str)> {
+    let segment = last_segment(ty)?;
+    let name = segment.ident.to_string();
+    match name.as_str() {
+        "RefCell" | "Mutex" | "RwLock" => Some((format!("`{name}<T>`"), "`GcRefCell<T>`")),
+        "Rc" | "Arc" => {
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            let inner = args.args.iter().find_map(|arg| match arg {
+                GenericArgument::Type(ty) => last_segment(ty),
+                _ => None,
+            })?;
+            let inner_name = inner.ident.to_string();
+            matches!(inner_name.as_str(), "RefCell" | "Cell" | "Mutex" | "RwLock")
+                .then(|| (format!("`{name}<{inner_name}<T>>`"), "`Gc<GcRefCell<T>>`"))
+        }
+        _ => None,
+    }
+}
+
 decl_derive! {
     [Finalize] =>
     /// Derive the `Finalize` trait.
@@ -0,0 +1,84 @@
+#![allow(unused_crate_dependencies, missing_docs)]
+
+//! Benchmarks of parsing a multi-megabyte script, with and without the bump arena.
+
+use boa_interner::Interner;
+use boa_parser::{arena::ArenaAllocator, Parser, Source};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Write,
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Counts the allocations that reach the system allocator.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: Forwards everything to the system allocator.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: Guaranteed by the caller.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: Guaranteed by the caller.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOC: ArenaAllocator<Counting> = ArenaAllocator::new(Counting);
+
+/// Generates a script of about 5 MB.
+fn source() -> String {
+    let mut source = String::new();
+    for i in 0..20_000 {
+        writeln!(
+            source,
+            "function f{i}(a, b) {{ const list = [a, b, {i}, 'item{i}', {{ key: a + b * {i} }}]; \
+             for (let j = 0; j < list.length; j++) {{ if (list[j] > b) {{ a += list[j]; }} \
+             else {{ b -= j; }} }} return `${{a}}:${{b}}` + [1, 2, 3].map((x) => x * a).join(','); }}"
+        )
+        .expect("writing to a string cannot fail");
+    }
+    source
+}
+
+fn parse(source: &str, arena: bool) -> boa_ast::Script {
+    let mut interner = Interner::default();
+    let mut parser = Parser::new(Source::from_bytes(source));
+    if arena {
+        parser.set_arena();
+    }
+    parser
+        .parse_script(&mut interner)
+        .expect("the source must parse")
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let source = source();
+    let size = source.len() / 1_000_000;
+
+    for (name, arena) in [("Box", false), ("Arena", true)] {
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        drop(parse(&source, arena));
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+        println!("Parse {size} MB ({name}): {allocations} allocations");
+
+        c.bench_function(&format!("Parse {size} MB ({name})"), |b| {
+            b.iter(|| parse(black_box(&source), arena));
+        });
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_parse
+}
+criterion_main!(benches);
@@ -0,0 +1,337 @@
+//! A bump arena for the allocations made while parsing.
+//!
+//! Every node of the AST lives in its own allocation (`Box<Expression>`, `Box<[Statement]>`, ...),
+//! so parsing a large input makes millions of small allocations. [`ArenaAllocator`] is a global
+//! allocator that, while [`with_arena`] runs on the current thread, serves allocations by bumping
+//! a pointer into large chunks requested from an inner allocator.
+//!
+//! The AST keeps using plain `Box`es, so nothing changes for its consumers: nodes allocated in
+//! the arena can be freed as usual, sent to other threads and outlive the arena. Every chunk
+//! counts its live allocations and goes back to the inner allocator once all of them are freed.
+//! The memory of a chunk is not reused before that, so the temporary allocations of the parser
+//! raise its peak memory usage, and a long-lived allocation made while parsing (like a new string
+//! in the [`Interner`][boa_interner::Interner]) keeps its whole chunk alive.
+//!
+//! The allocator must be installed as the global allocator for the arena to have any effect:
+//!
+//! ```
+//! use boa_parser::arena::ArenaAllocator;
+//! use std::alloc::System;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: ArenaAllocator<System> = ArenaAllocator::new(System);
+//! # fn main() {}
+//! ```
+//!
+//! Then the arena can be enabled on a [`Parser`][crate::Parser] with
+//! [`Parser::set_arena`][crate::Parser::set_arena].
+
+#![allow(clippy::cast_ptr_alignment)] // Headers are aligned to a word, and chunks to a `Chunk`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    mem::{align_of, size_of},
+    ptr,
+    sync::atomic::{fence, AtomicUsize, Ordering},
+};
+
+/// Size of the chunks requested from the inner allocator.
+const CHUNK_SIZE: usize = 1 << 20;
+
+/// Allocations bigger than this go directly to the inner allocator.
+const MAX_ARENA_SIZE: usize = CHUNK_SIZE / 16;
+
+/// Allocations with a bigger alignment than this go directly to the inner allocator.
+const MAX_ARENA_ALIGN: usize = 4096;
+
+/// Layout of the chunks requested from the inner allocator.
+const CHUNK_LAYOUT: Layout = match Layout::from_size_align(CHUNK_SIZE, align_of::<Chunk>()) {
+    Ok(layout) => layout,
+    Err(_) => panic!("invalid chunk layout"),
+};
+
+/// The header at the start of every chunk.
+struct Chunk {
+    /// Number of live allocations in the chunk, plus one while the chunk is the current chunk of
+    /// an arena.
+    live: AtomicUsize,
+    /// The [`ArenaAllocator`] that requested the chunk.
+    allocator: *const (),
+    /// Returns the chunk to the inner allocator of `allocator`.
+    free: unsafe fn(*const (), *mut Chunk),
+}
+
+/// The arena of the current thread.
+#[derive(Clone, Copy)]
+struct Arena {
+    /// Number of nested [`with_arena`] calls running on the thread.
+    depth: usize,
+    /// The chunk allocations are currently bumped into, or null.
+    chunk: *mut Chunk,
+    /// Offset of the first free byte of `chunk`.
+    offset: usize,
+}
+
+impl Arena {
+    const EMPTY: Self = Self {
+        depth: 0,
+        chunk: ptr::null_mut(),
+        offset: 0,
+    };
+}
+
+thread_local! {
+    static ARENA: Cell<Arena> = const { Cell::new(Arena::EMPTY) };
+}
+
+/// Runs `f` with the arena enabled on the current thread.
+///
+/// Every allocation made on the current thread while `f` runs is bump allocated, if the
+/// [`ArenaAllocator`] is the global allocator. Otherwise, this just calls `f`.
+pub fn with_arena<T>(f: impl FnOnce() -> T) -> T {
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let _ = ARENA.try_with(|arena| {
+                let mut state = arena.get();
+                state.depth -= 1;
+                if state.depth == 0 && !state.chunk.is_null() {
+                    // SAFETY: The current chunk holds a reference to itself.
+                    unsafe { release(state.chunk) };
+                    state = Arena::EMPTY;
+                }
+                arena.set(state);
+            });
+        }
+    }
+
+    let _ = ARENA.try_with(|arena| {
+        let mut state = arena.get();
+        state.depth += 1;
+        arena.set(state);
+    });
+    let _guard = Guard;
+    f()
+}
+
+/// Checks if the arena is enabled on the current thread.
+fn arena_enabled() -> bool {
+    ARENA
+        .try_with(|arena| arena.get().depth > 0)
+        .unwrap_or(false)
+}
+
+/// Drops a reference to `chunk`, freeing it if it was the last one.
+///
+/// # Safety
+///
+/// The caller must own a reference to `chunk`.
+unsafe fn release(chunk: *mut Chunk) {
+    // SAFETY: The chunk is alive while a reference to it exists.
+    let chunk_ref = unsafe { &*chunk };
+    if chunk_ref.live.fetch_sub(1, Ordering::Release) == 1 {
+        fence(Ordering::Acquire);
+        // SAFETY: This was the last reference to the chunk.
+        unsafe { (chunk_ref.free)(chunk_ref.allocator, chunk) };
+    }
+}
+
+/// Frees a chunk requested by an `ArenaAllocator<A>`.
+///
+/// # Safety
+///
+/// `allocator` must point to the `ArenaAllocator<A>` that requested the unreferenced `chunk`.
+unsafe fn free_chunk<A: GlobalAlloc>(allocator: *const (), chunk: *mut Chunk) {
+    // SAFETY: Guaranteed by the caller.
+    unsafe {
+        (*allocator.cast::<ArenaAllocator<A>>())
+            .inner
+            .dealloc(chunk.cast(), CHUNK_LAYOUT);
+    }
+}
+
+/// A global allocator that bump allocates while the arena is enabled on the current thread.
+///
+/// Outside of [`with_arena`], allocations are forwarded to the `inner` allocator. See the
+/// [module documentation][self] for more information.
+///
+/// Every allocation is prefixed by a header of at least one word, pointing to its chunk.
+#[derive(Debug, Default)]
+pub struct ArenaAllocator<A = System> {
+    inner: A,
+}
+
+impl<A> ArenaAllocator<A> {
+    /// Creates a new `ArenaAllocator` requesting memory from `inner`.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+/// Computes the size of the header and the layout including it for an allocation with `layout`.
+fn with_header(layout: Layout) -> Option<(usize, Layout)> {
+    let align = layout.align().max(size_of::<*mut Chunk>());
+    let size = layout.size().checked_add(align)?;
+    Some((align, Layout::from_size_align(size, align).ok()?))
+}
+
+impl<A: GlobalAlloc> ArenaAllocator<A> {
+    /// Bump allocates `layout`, which includes a header of `header` bytes.
+    ///
+    /// Returns null if the arena is not enabled or a new chunk could not be allocated.
+    fn bump(&self, header: usize, layout: Layout) -> *mut u8 {
+        ARENA
+            .try_with(|arena| {
+                let mut state = arena.get();
+                if state.depth == 0 {
+                    return ptr::null_mut();
+                }
+                let mut start = align_offset(state.chunk, state.offset, layout.align());
+                if state.chunk.is_null() || start + layout.size() > CHUNK_SIZE {
+                    if !state.chunk.is_null() {
+                        // SAFETY: The current chunk holds a reference to itself.
+                        unsafe { release(state.chunk) };
+                    }
+                    // SAFETY: The chunk layout has a non-zero size.
+                    let chunk = unsafe { self.inner.alloc(CHUNK_LAYOUT) }.cast::<Chunk>();
+                    if chunk.is_null() {
+                        arena.set(Arena {
+                            depth: state.depth,
+                            ..Arena::EMPTY
+                        });
+                        return ptr::null_mut();
+                    }
+                    // SAFETY: The chunk is valid for writes and aligned for a `Chunk`.
+                    unsafe {
+                        chunk.write(Chunk {
+                            live: AtomicUsize::new(1),
+                            allocator: ptr::addr_of!(*self).cast(),
+                            free: free_chunk::<A>,
+                        });
+                    }
+                    state.chunk = chunk;
+                    start = align_offset(chunk, size_of::<Chunk>(), layout.align());
+                }
+                state.offset = start + layout.size();
+                arena.set(state);
+
+                // SAFETY: The chunk is alive, since it is the current chunk.
+                unsafe { &*state.chunk }
+                    .live
+                    .fetch_add(1, Ordering::Relaxed);
+                // SAFETY: `start + header` is in bounds of the chunk.
+                let ptr = unsafe { state.chunk.cast::<u8>().add(start + header) };
+                // SAFETY: The header is in bounds of the allocation and aligned to a word.
+                unsafe { ptr.cast::<*mut Chunk>().sub(1).write(state.chunk) };
+                ptr
+            })
+            .unwrap_or(ptr::null_mut())
+    }
+}
+
+/// Computes the offset from `chunk` of the next address at or after `offset` aligned to `align`.
+fn align_offset(chunk: *mut Chunk, offset: usize, align: usize) -> usize {
+    let address = (chunk as usize).wrapping_add(offset);
+    offset + (address.wrapping_neg() & (align - 1))
+}
+
+/// Tries to resize the last bump allocation of the current chunk in place.
+fn resize_in_place(chunk: *mut Chunk, ptr: *mut u8, size: usize, new_size: usize) -> bool {
+    ARENA
+        .try_with(|arena| {
+            let mut state = arena.get();
+            if state.depth == 0 || state.chunk != chunk {
+                return false;
+            }
+            let start = ptr as usize - chunk as usize;
+            if start + size != state.offset || start + new_size > CHUNK_SIZE {
+                return false;
+            }
+            state.offset = start + new_size;
+            arena.set(state);
+            true
+        })
+        .unwrap_or(false)
+}
+
+// SAFETY: Bump allocations never overlap, since the offset of the current chunk is always past
+// the end of its last allocation, and chunks are only freed after all of their allocations.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for ArenaAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let Some((header, full)) = with_header(layout) else {
+            return ptr::null_mut();
+        };
+        if full.size() <= MAX_ARENA_SIZE && full.align() <= MAX_ARENA_ALIGN {
+            let ptr = self.bump(header, full);
+            if !ptr.is_null() {
+                return ptr;
+            }
+        }
+
+        // SAFETY: The layout has a non-zero size, since it includes the header.
+        let base = unsafe { self.inner.alloc(full) };
+        if base.is_null() {
+            return base;
+        }
+        // SAFETY: The header is in bounds of the allocation and aligned to a word.
+        unsafe {
+            let ptr = base.add(header);
+            ptr.cast::<*mut Chunk>().sub(1).write(ptr::null_mut());
+            ptr
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` was returned by `alloc`, so it is prefixed by a header.
+        let chunk = unsafe { ptr.cast::<*mut Chunk>().sub(1).read() };
+        if chunk.is_null() {
+            let Some((header, full)) = with_header(layout) else {
+                return;
+            };
+            // SAFETY: The allocation was made by the inner allocator with the `full` layout.
+            unsafe { self.inner.dealloc(ptr.sub(header), full) };
+        } else {
+            // SAFETY: Every bump allocation holds a reference to its chunk.
+            unsafe { release(chunk) };
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: `ptr` was returned by `alloc`, so it is prefixed by a header.
+        let chunk = unsafe { ptr.cast::<*mut Chunk>().sub(1).read() };
+        if chunk.is_null() {
+            if !arena_enabled() {
+                let (Some((header, full)), Some(new_full)) = (
+                    with_header(layout),
+                    new_size.checked_add(layout.align().max(size_of::<*mut Chunk>())),
+                ) else {
+                    return ptr::null_mut();
+                };
+                // SAFETY: The allocation was made by the inner allocator with the `full` layout.
+                let base = unsafe { self.inner.realloc(ptr.sub(header), full, new_full) };
+                if base.is_null() {
+                    return base;
+                }
+                // SAFETY: The header was kept by `realloc`.
+                return unsafe { base.add(header) };
+            }
+        } else if resize_in_place(chunk, ptr, layout.size(), new_size) {
+            return ptr;
+        }
+
+        // SAFETY: Guaranteed by the caller.
+        let new_layout = unsafe { Layout::from_size_align_unchecked(new_size, layout.align()) };
+        // SAFETY: Guaranteed by the caller.
+        let new_ptr = unsafe { self.alloc(new_layout) };
+        if !new_ptr.is_null() {
+            // SAFETY: Both allocations are valid for at least the smaller of both sizes.
+            unsafe {
+                ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+                self.dealloc(ptr, layout);
+            }
+        }
+        new_ptr
+    }
+}
@@ -5,7 +5,7 @@ mod tests;
 
 use crate::lexer::Error as LexError;
 use boa_ast::{Position, Span};
-use std::fmt;
+use std::{fmt, io, path::Path};
 
 /// Result of a parsing operation.
 pub type ParseResult<T> = Result<T, Error>;
@@ -176,14 +176,82 @@ impl Error {
     }
 }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Error {
+    /// Gets the position in the source code where the error occurred, if any.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        match self {
+            Self::Expected { span, .. } | Self::Unexpected { span, .. } => Some(span.start()),
+            Self::General { position, .. }
+            | Self::Lex {
+                err: LexError::Syntax(_, position),
+            } => Some(*position),
+            Self::AbruptEnd | Self::Lex { .. } => None,
+        }
+    }
+
+    /// Returns `true` if the error was caused by the source code ending too early, which means
+    /// that appending more source code could make it valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_interner::Interner;
+    /// # use boa_parser::{Parser, Source};
+    /// let incomplete = Parser::new(Source::from_bytes("function f() {"))
+    ///     .parse_script(&mut Interner::default())
+    ///     .unwrap_err();
+    /// assert!(incomplete.is_abrupt_end());
+    ///
+    /// let invalid = Parser::new(Source::from_bytes("function f() }"))
+    ///     .parse_script(&mut Interner::default())
+    ///     .unwrap_err();
+    /// assert!(!invalid.is_abrupt_end());
+    /// ```
+    #[must_use]
+    pub fn is_abrupt_end(&self) -> bool {
+        match self {
+            Self::AbruptEnd => true,
+            Self::Lex {
+                err: LexError::IO(err),
+            } => err.kind() == io::ErrorKind::UnexpectedEof,
+            _ => false,
+        }
+    }
+
+    /// Returns an object that displays the error, pointing to its location inside the source
+    /// file at `path` (e.g. `script.js:3:12`) instead of just a line and column pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_interner::Interner;
+    /// # use boa_parser::{Parser, Source};
+    /// # use std::path::Path;
+    /// let path = Path::new("script.js");
+    /// let source = Source::from_reader("let a = ;".as_bytes(), Some(path));
+    /// let err = Parser::new(source)
+    ///     .parse_script(&mut Interner::default())
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.display_with_path(path).to_string(),
+    ///     "unexpected token ';', primary expression at script.js:1:9"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn display_with_path<'a>(&'a self, path: &'a Path) -> impl fmt::Display + 'a {
+        DisplayWithPath { error: self, path }
+    }
+
+    /// Writes the error message, without any location information.
+    fn fmt_message(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Expected {
                 expected,
                 found,
-                span,
                 context,
+                ..
             } => {
                 write!(f, "expected ")?;
                 match &**expected {
@@ -202,31 +270,54 @@ impl fmt::Display for Error {
                         }
                     }
                 }
-                write!(
-                    f,
-                    ", got '{found}' in {context} at line {}, col {}",
-                    span.start().line_number(),
-                    span.start().column_number()
-                )
+                write!(f, ", got '{found}' in {context}")
+            }
+            Self::Unexpected { found, message, .. } => {
+                write!(f, "unexpected token '{found}', {message}")
             }
-            Self::Unexpected {
-                found,
-                span,
-                message,
-            } => write!(
-                f,
-                "unexpected token '{found}', {message} at line {}, col {}",
-                span.start().line_number(),
-                span.start().column_number()
-            ),
             Self::AbruptEnd => f.write_str("abrupt end"),
-            Self::General { message, position } => write!(
+            Self::General { message, .. }
+            | Self::Lex {
+                err: LexError::Syntax(message, _),
+            } => f.write_str(message),
+            Self::Lex { err } => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_message(f)?;
+        if let Some(position) = self.position() {
+            write!(
+                f,
+                " at line {}, col {}",
+                position.line_number(),
+                position.column_number()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays an [`Error`] together with the path of the source that produced it.
+struct DisplayWithPath<'a> {
+    error: &'a Error,
+    path: &'a Path,
+}
+
+impl fmt::Display for DisplayWithPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt_message(f)?;
+        write!(f, " at {}", self.path.display())?;
+        if let Some(position) = self.error.position() {
+            write!(
                 f,
-                "{message} at line {}, col {}",
+                ":{}:{}",
                 position.line_number(),
                 position.column_number()
-            ),
-            Self::Lex { err } => err.fmt(f),
+            )?;
         }
+        Ok(())
     }
 }
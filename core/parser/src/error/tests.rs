@@ -1,4 +1,5 @@
 use super::*;
+use std::path::Path;
 
 #[test]
 fn context() {
@@ -164,3 +165,56 @@ fn display() {
 
     assert_eq!(err.to_string(), "testing at line 1, col 1");
 }
+
+#[test]
+fn display_with_path() {
+    let path = Path::new("script.js");
+
+    let err = Error::general("message", Position::new(3, 12));
+    assert_eq!(err.to_string(), "message at line 3, col 12");
+    assert_eq!(
+        err.display_with_path(path).to_string(),
+        "message at script.js:3:12"
+    );
+
+    let err = Error::lex(LexError::syntax("testing", Position::new(1, 5)));
+    assert_eq!(
+        err.display_with_path(path).to_string(),
+        "testing at script.js:1:5"
+    );
+
+    let err = Error::AbruptEnd;
+    assert_eq!(
+        err.display_with_path(path).to_string(),
+        "abrupt end at script.js"
+    );
+}
+
+#[test]
+fn abrupt_end() {
+    use crate::{Parser, Source};
+    use boa_interner::Interner;
+
+    let parse = |source: &str| {
+        Parser::new(Source::from_bytes(source))
+            .parse_script(&mut Interner::default())
+            .expect_err("the source must not parse")
+    };
+
+    for incomplete in [
+        "function f() {",
+        "let object = {\n  a: 1,",
+        "[1, 2",
+        "if (condition)",
+        "let x =",
+        "`template ${value}",
+        "/* comment",
+        "'string\\",
+    ] {
+        assert!(parse(incomplete).is_abrupt_end(), "{incomplete}");
+    }
+
+    for invalid in ["function f() }", "let let = 1;", "'string\nend'", "1 +* 2"] {
+        assert!(!parse(invalid).is_abrupt_end(), "{invalid}");
+    }
+}
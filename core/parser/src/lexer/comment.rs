@@ -5,6 +5,7 @@ use crate::source::ReadChar;
 use boa_ast::{Position, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
+use std::io::{self, ErrorKind};
 
 /// Lexes a single line comment.
 ///
@@ -91,10 +92,10 @@ impl<R> Tokenizer<R> for MultiLineComment {
             };
         }
 
-        Err(Error::syntax(
+        Err(Error::from(io::Error::new(
+            ErrorKind::UnexpectedEof,
             "unterminated multiline comment",
-            cursor.pos(),
-        ))
+        )))
     }
 }
 
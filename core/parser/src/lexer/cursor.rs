@@ -1,6 +1,6 @@
 //! Boa's lexer cursor that manages the input byte stream.
 
-use crate::source::{ReadChar, UTF8Input};
+use crate::source::{ReadChar, SourceText, UTF8Input};
 use boa_ast::Position;
 use boa_profiler::Profiler;
 use std::io::{self, Error, ErrorKind};
@@ -13,6 +13,7 @@ pub(super) struct Cursor<R> {
     module: bool,
     strict: bool,
     peeked: [Option<u32>; 4],
+    source_text: SourceText,
 }
 
 impl<R> Cursor<R> {
@@ -32,6 +33,12 @@ impl<R> Cursor<R> {
     fn next_line(&mut self) {
         let next_line = self.pos.line_number() + 1;
         self.pos = Position::new(next_line, 1);
+        self.source_text.new_line();
+    }
+
+    /// Takes the source text consumed so far.
+    pub(super) fn take_source_text(&mut self) -> SourceText {
+        std::mem::take(&mut self.source_text)
     }
 
     /// Returns if strict mode is currently active.
@@ -65,6 +72,7 @@ impl<R: ReadChar> Cursor<R> {
             strict: false,
             module: false,
             peeked: [None; 4],
+            source_text: SourceText::default(),
         }
     }
 
@@ -184,6 +192,10 @@ impl<R: ReadChar> Cursor<R> {
             self.iter.next_char()?
         };
 
+        if let Some(c) = ch {
+            self.source_text.push(c);
+        }
+
         match ch {
             Some(0xD) => {
                 // Try to take a newline if it's next, for windows "\r\n" newlines
@@ -191,6 +203,7 @@ impl<R: ReadChar> Cursor<R> {
                 if self.peek_char()? == Some(0xA) {
                     self.peeked[0] = None;
                     self.peeked.rotate_left(1);
+                    self.source_text.push(0xA);
                 }
                 self.next_line();
             }
@@ -41,7 +41,7 @@ use self::{
     string::StringLiteral,
     template::TemplateLiteral,
 };
-use crate::source::{ReadChar, UTF8Input};
+use crate::source::{ReadChar, SourceText, UTF8Input};
 use boa_ast::{Position, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -72,7 +72,7 @@ pub struct Lexer<R> {
 
 impl<R> Lexer<R> {
     /// Sets the goal symbol for the lexer.
-    pub(crate) fn set_goal(&mut self, elm: InputElement) {
+    pub fn set_goal(&mut self, elm: InputElement) {
         self.goal_symbol = elm;
     }
 
@@ -101,6 +101,11 @@ impl<R> Lexer<R> {
         self.cursor.set_module(module);
     }
 
+    /// Takes the source text consumed by the lexer so far.
+    pub(crate) fn take_source_text(&mut self) -> SourceText {
+        self.cursor.take_source_text()
+    }
+
     /// Creates a new lexer.
     pub fn new(reader: R) -> Self
     where
@@ -202,13 +207,45 @@ impl<R> Lexer<R> {
         Ok(())
     }
 
-    /// Retrieves the next token from the lexer.
+    /// Skips the [hashbang comment][spec] at the start of a `Script` or `Module`, if any.
+    ///
+    /// A leading byte order mark is not part of the source text, so it is skipped before looking
+    /// for the hashbang. This does nothing if any character has already been lexed, since a
+    /// hashbang is only allowed at the very start of the source.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` on invalid reads of the bytes being lexed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hashbang
+    pub(crate) fn skip_hashbang(&mut self, interner: &mut Interner) -> Result<(), Error>
+    where
+        R: ReadChar,
+    {
+        if self.cursor.pos() != Position::new(1, 1) {
+            return Ok(());
+        }
+
+        if self.cursor.peek_char()? == Some(0xFEFF) {
+            self.cursor.next_char()?;
+        }
+
+        // #!
+        if self.cursor.peek_n(2)?[..2] == [Some(0x23), Some(0x21)] {
+            let start = self.cursor.pos();
+            HashbangComment.lex(&mut self.cursor, start, interner)?;
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the next token from the lexer, including comments.
     ///
     /// # Errors
     ///
     /// Will return `Err` on invalid tokens and invalid reads of the bytes being lexed.
     // We intentionally don't implement Iterator trait as Result<Option> is cleaner to handle.
-    pub(crate) fn next_no_skip(&mut self, interner: &mut Interner) -> Result<Option<Token>, Error>
+    pub fn next_no_skip(&mut self, interner: &mut Interner) -> Result<Option<Token>, Error>
     where
         R: ReadChar,
     {
@@ -226,17 +263,6 @@ impl<R> Lexer<R> {
             }
         };
 
-        //handle hashbang here so the below match block still throws error on
-        //# if position isn't (1, 1)
-        if start.column_number() == 1
-            && start.line_number() == 1
-            && next_ch == 0x23
-            && self.cursor.peek_char()? == Some(0x21)
-        {
-            let _token = HashbangComment.lex(&mut self.cursor, start, interner);
-            return self.next(interner);
-        };
-
         if let Ok(c) = char::try_from(next_ch) {
             let token = match c {
                 '\r' | '\n' | '\u{2028}' | '\u{2029}' => Ok(Token::new(
@@ -365,12 +391,15 @@ impl<R> Lexer<R> {
         }
     }
 
-    /// Performs the lexing of a template literal.
-    pub(crate) fn lex_template(
-        &mut self,
-        start: Position,
-        interner: &mut Interner,
-    ) -> Result<Token, Error>
+    /// Performs the lexing of the rest of a template literal, after the `}` that closes a
+    /// substitution.
+    ///
+    /// The span of the returned token starts at `start`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the template literal is invalid or unterminated.
+    pub fn lex_template(&mut self, start: Position, interner: &mut Interner) -> Result<Token, Error>
     where
         R: ReadChar,
     {
@@ -387,10 +416,16 @@ impl<'a> From<&'a [u8]> for Lexer<UTF8Input<&'a [u8]>> {
 /// ECMAScript goal symbols.
 ///
 /// <https://tc39.es/ecma262/#sec-ecmascript-language-lexical-grammar>
+///
+/// The goal symbol decides how a `/` is lexed, since the lexer doesn't know whether an
+/// expression or an operator is expected.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum InputElement {
+pub enum InputElement {
+    /// A `/` is a division operator.
     Div,
+    /// A `/` starts a regular expression literal.
     RegExp,
+    /// A `/` is a division operator, and a `}` can continue a template literal.
     TemplateTail,
 }
 
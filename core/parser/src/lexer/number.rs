@@ -181,6 +181,24 @@ where
     }
 }
 
+/// Parses the digits of an integer literal in the given base.
+///
+/// `num_bigint` parses bases that aren't powers of two one machine word of digits at a time,
+/// which takes quadratic time in the number of digits, so long literals are split recursively in
+/// halves that are joined with big multiplications instead.
+fn parse_big_integer(digits: &[u8], base: u32) -> BigInt {
+    /// Literals with fewer digits are parsed by `num_bigint` directly.
+    const THRESHOLD_DIGITS: usize = 1024;
+
+    if base.is_power_of_two() || digits.len() <= THRESHOLD_DIGITS {
+        return BigInt::parse_bytes(digits, base).expect("Could not convert to BigInt");
+    }
+
+    let (high, low) = digits.split_at(digits.len() / 2);
+    let exponent = u32::try_from(low.len()).expect("the literal is too long");
+    parse_big_integer(high, base) * BigInt::from(base).pow(exponent) + parse_big_integer(low, base)
+}
+
 impl<R> Tokenizer<R> for NumberLiteral {
     fn lex(
         &mut self,
@@ -399,7 +417,7 @@ impl<R> Tokenizer<R> for NumberLiteral {
         let num = match kind {
             NumericKind::BigInt(base) => {
                 Numeric::BigInt(
-                    BigInt::parse_bytes(num_str.as_bytes(), base).expect("Could not convert to BigInt").into()
+                    parse_big_integer(num_str.as_bytes(), base).into()
                     )
             }
             // casting precisely to check if the float doesn't lose info on truncation
@@ -420,7 +438,7 @@ impl<R> Tokenizer<R> for NumberLiteral {
             },
             NumericKind::Integer(base) => {
                 i32::from_str_radix(num_str, base).map_or_else(|_| {
-                    let num = BigInt::parse_bytes(num_str.as_bytes(), base).expect("Failed to parse integer after checks");
+                    let num = parse_big_integer(num_str.as_bytes(), base);
                     Numeric::Rational(num.to_f64().unwrap_or(f64::INFINITY))
                 }, Numeric::Integer)
             }
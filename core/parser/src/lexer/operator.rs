@@ -9,20 +9,21 @@ use boa_profiler::Profiler;
 /// `vop` tests the next token to see if we're on an assign operation of just a plain binary operation.
 ///
 /// If the next value is not an assignment operation it will pattern match  the provided values and return the corresponding token.
+///
+/// An operator at the end of the source is lexed as is, to let the parser report the abrupt end.
 macro_rules! vop {
     ($cursor:ident, $assign_op:expr, $op:expr) => ({
-        match $cursor.peek_char()? {
-            None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
+        let punc: Result<Punctuator, Error> = match $cursor.peek_char()? {
             Some(0x3D /* = */) => {
                 $cursor.next_char()?.expect("= token vanished");
                 $assign_op
             }
-            Some(_) => $op,
-        }
+            _ => $op,
+        };
+        punc
     });
     ($cursor:ident, $assign_op:expr, $op:expr, {$($case:pat => $block:expr), +}) => ({
-        match $cursor.peek_char()? {
-            None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
+        let punc: Result<Punctuator, Error> = match $cursor.peek_char()? {
             Some(0x3D /* = */) => {
                 $cursor.next_char()?.expect("= token vanished");
                 $assign_op
@@ -32,7 +33,8 @@ macro_rules! vop {
                 $block
             })+,
             _ => $op,
-        }
+        };
+        punc
     });
 }
 
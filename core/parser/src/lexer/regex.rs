@@ -4,11 +4,14 @@ use crate::lexer::{Cursor, Error, Span, Token, TokenKind, Tokenizer};
 use crate::source::ReadChar;
 use bitflags::bitflags;
 use boa_ast::Position;
-use boa_interner::{Interner, Sym};
+use boa_interner::Interner;
 use boa_profiler::Profiler;
 use regress::{Flags, Regex};
 use std::str::{self, FromStr};
 
+mod emoji;
+mod unicode_sets;
+
 /// Regex literal lexing.
 ///
 /// Lexes Division, Assigndiv or Regex literal.
@@ -126,23 +129,99 @@ impl<R> Tokenizer<R> for RegexLiteral {
             }
         }
 
-        if let Err(error) = Regex::from_unicode(body.into_iter(), flags_str) {
-            return Err(Error::Syntax(
-                format!("Invalid regular expression literal: {error}").into(),
-                start_pos,
-            ));
-        }
+        let flags = match compile_regexp(body.into_iter(), flags_str) {
+            Ok((_, flags)) => flags,
+            Err(RegExpError::Flags(message)) => {
+                return Err(Error::Syntax(message.into(), flags_start));
+            }
+            Err(RegExpError::Pattern(message)) => {
+                return Err(Error::Syntax(
+                    format!("Invalid regular expression literal: {message}").into(),
+                    start_pos,
+                ));
+            }
+        };
 
         Ok(Token::new(
             TokenKind::regular_expression_literal(
                 interner.get_or_intern(body_utf16.as_slice()),
-                parse_regex_flags(flags_str, flags_start, interner)?,
+                interner.get_or_intern(flags.to_string().as_str()),
             ),
             Span::new(start_pos, cursor.pos()),
         ))
     }
 }
 
+/// An error found while validating a regular expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegExpError {
+    /// The flags contain an unknown or repeated flag.
+    Flags(String),
+
+    /// The pattern is not valid for the given flags.
+    Pattern(String),
+}
+
+impl std::fmt::Display for RegExpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Flags(message) | Self::Pattern(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for RegExpError {}
+
+/// Validates the flags and the pattern of a regular expression, compiling it into a matcher.
+///
+/// This is shared by regular expression literals, which must report invalid patterns as early
+/// errors while lexing, and the `RegExp` constructor, which reports them at runtime.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// # Errors
+///
+/// Returns [`RegExpError::Flags`] if `flags` contains an unknown or repeated flag, and
+/// [`RegExpError::Pattern`] if `pattern` cannot be parsed with those flags.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-regexpinitialize
+pub fn compile_regexp<I>(pattern: I, flags: &str) -> Result<(Regex, RegExpFlags), RegExpError>
+where
+    I: Iterator<Item = u32> + Clone,
+{
+    let flags = RegExpFlags::from_str(flags).map_err(RegExpError::Flags)?;
+    let matcher = if flags.contains(RegExpFlags::UNICODE_SETS) {
+        // `regress` doesn't support the `v` flag, so the pattern is translated to an equivalent
+        // pattern for the `u` flag.
+        let pattern = unicode_sets::translate(
+            &pattern.collect::<Vec<_>>(),
+            flags.contains(RegExpFlags::IGNORE_CASE),
+        )
+        .map_err(RegExpError::Pattern)?;
+        Regex::from_unicode(pattern.into_iter(), Flags::from(flags))
+    } else if flags.contains(RegExpFlags::UNICODE) {
+        Regex::from_unicode(pattern, Flags::from(flags))
+    } else {
+        // Without the `u` flag the pattern matches code units, so the code points outside of the
+        // BMP must be matched as their surrogate pairs.
+        Regex::from_unicode(pattern.flat_map(code_units), Flags::from(flags))
+    }
+    .map_err(|error| RegExpError::Pattern(error.text))?;
+    Ok((matcher, flags))
+}
+
+/// Splits a code point into its UTF-16 code units.
+fn code_units(cp: u32) -> impl Iterator<Item = u32> + Clone {
+    let (first, second) = if cp > 0xFFFF {
+        let cp = cp - 0x1_0000;
+        (0xD800 | (cp >> 10), Some(0xDC00 | (cp & 0x3FF)))
+    } else {
+        (cp, None)
+    };
+    std::iter::once(first).chain(second)
+}
+
 bitflags! {
     /// Flags of a regular expression.
     #[derive(Debug, Default, Copy, Clone)]
@@ -169,6 +248,10 @@ bitflags! {
         /// Whether the regular expression result exposes the start and end indices of
         /// captured substrings.
         const HAS_INDICES = 0b0100_0000;
+
+        /// Whether or not the Unicode features and the set notation of character classes are
+        /// enabled.
+        const UNICODE_SETS = 0b1000_0000;
     }
 }
 
@@ -186,6 +269,7 @@ impl FromStr for RegExpFlags {
                 b'u' => Self::UNICODE,
                 b'y' => Self::STICKY,
                 b'd' => Self::HAS_INDICES,
+                b'v' => Self::UNICODE_SETS,
                 _ => return Err(format!("invalid regular expression flag {}", char::from(c))),
             };
 
@@ -198,14 +282,11 @@ impl FromStr for RegExpFlags {
             flags.insert(new_flag);
         }
 
-        Ok(flags)
-    }
-}
+        if flags.contains(Self::UNICODE | Self::UNICODE_SETS) {
+            return Err("regular expression flags u and v cannot be used together".to_owned());
+        }
 
-fn parse_regex_flags(s: &str, start: Position, interner: &mut Interner) -> Result<Sym, Error> {
-    match RegExpFlags::from_str(s) {
-        Err(message) => Err(Error::Syntax(message.into(), start)),
-        Ok(flags) => Ok(interner.get_or_intern(flags.to_string().as_str())),
+        Ok(flags)
     }
 }
 
@@ -230,6 +311,9 @@ impl ToString for RegExpFlags {
         if self.contains(Self::UNICODE) {
             s.push('u');
         }
+        if self.contains(Self::UNICODE_SETS) {
+            s.push('v');
+        }
         if self.contains(Self::STICKY) {
             s.push('y');
         }
@@ -243,7 +327,7 @@ impl From<RegExpFlags> for Flags {
             icase: value.contains(RegExpFlags::IGNORE_CASE),
             multiline: value.contains(RegExpFlags::MULTILINE),
             dot_all: value.contains(RegExpFlags::DOT_ALL),
-            unicode: value.contains(RegExpFlags::UNICODE),
+            unicode: value.intersects(RegExpFlags::UNICODE | RegExpFlags::UNICODE_SETS),
             ..Self::default()
         }
     }
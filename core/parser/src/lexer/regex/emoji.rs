@@ -0,0 +1,173 @@
+//! The properties of strings of regular expressions with the `v` flag.
+//!
+//! The sequences are built from the binary properties of `icu_properties`, which doesn't include
+//! the lists of recommended emoji sequences. `Basic_Emoji`, `Emoji_Keycap_Sequence`,
+//! `RGI_Emoji_Flag_Sequence` and `RGI_Emoji_Tag_Sequence` are exact, but:
+//!
+//!  - `RGI_Emoji_Modifier_Sequence` contains every `Emoji_Modifier_Base` followed by an
+//!    `Emoji_Modifier`, which includes a few sequences that aren't recommended.
+//!  - `RGI_Emoji_ZWJ_Sequence` contains every sequence of `Extended_Pictographic` elements joined
+//!    by zero width joiners, instead of only the recommended ones.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#table-binary-unicode-properties-of-strings
+
+use std::ops::RangeInclusive;
+
+use icu_properties::sets;
+
+/// Requests the emoji presentation of the previous code point.
+pub(super) const EMOJI_PRESENTATION_SELECTOR: u32 = 0xFE0F;
+
+/// Joins the elements of an emoji ZWJ sequence.
+pub(super) const ZERO_WIDTH_JOINER: u32 = 0x200D;
+
+/// The `Emoji_Modifier` code points, which select the skin tone of the previous one.
+const EMOJI_MODIFIERS: RangeInclusive<u32> = 0x1F3FB..=0x1F3FF;
+
+/// Matches the `RGI_Emoji_ZWJ_Sequence` strings with the `u` flag.
+pub(super) const ZWJ_SEQUENCE_PATTERN: &str = concat!(
+    r"(?:\p{Extended_Pictographic}(?:\u{FE0F}|[\u{1F3FB}-\u{1F3FF}])?",
+    r"(?:\u{200D}\p{Extended_Pictographic}(?:\u{FE0F}|[\u{1F3FB}-\u{1F3FF}])?)+)"
+);
+
+/// The regions that have a recommended flag sequence.
+const RGI_FLAG_REGIONS: &str = "\
+    AC AD AE AF AG AI AL AM AO AQ AR AS AT AU AW AX AZ BA BB BD BE BF BG BH BI BJ BL BM BN BO \
+    BQ BR BS BT BV BW BY BZ CA CC CD CF CG CH CI CK CL CM CN CO CP CR CU CV CW CX CY CZ DE DG \
+    DJ DK DM DO DZ EA EC EE EG EH ER ES ET EU FI FJ FK FM FO FR GA GB GD GE GF GG GH GI GL GM \
+    GN GP GQ GR GS GT GU GW GY HK HM HN HR HT HU IC ID IE IL IM IN IO IQ IR IS IT JE JM JO JP \
+    KE KG KH KI KM KN KP KR KW KY KZ LA LB LC LI LK LR LS LT LU LV LY MA MC MD ME MF MG MH MK \
+    ML MM MN MO MP MQ MR MS MT MU MV MW MX MY MZ NA NC NE NF NG NI NL NO NP NR NU NZ OM PA PE \
+    PF PG PH PK PL PM PN PR PS PT PW PY QA RE RO RS RU RW SA SB SC SD SE SG SH SI SJ SK SL SM \
+    SN SO SR SS ST SV SX SY SZ TA TC TD TF TG TH TJ TK TL TM TN TO TR TT TV TW TZ UA UG UM UN \
+    US UY UZ VA VC VE VG VI VN VU WF WS XK YE YT ZA ZM ZW";
+
+/// The subdivisions that have a recommended flag tag sequence.
+const RGI_TAG_SUBDIVISIONS: [&str; 3] = ["gbeng", "gbsct", "gbwls"];
+
+/// The code points and strings of a property of strings.
+#[derive(Debug, Default)]
+pub(super) struct StringProperty {
+    pub(super) code_points: Vec<RangeInclusive<u32>>,
+    pub(super) strings: Vec<Vec<u32>>,
+
+    /// Whether the property contains the strings of `RGI_Emoji_ZWJ_Sequence`, which aren't
+    /// listed in `strings`.
+    pub(super) zwj_sequences: bool,
+}
+
+impl StringProperty {
+    /// Gets the property of strings with the given name, if it exists.
+    pub(super) fn get(name: &str) -> Option<Self> {
+        let mut property = Self::default();
+        match name {
+            "Basic_Emoji" => property.add_basic_emoji(),
+            "Emoji_Keycap_Sequence" => property.add_keycap_sequences(),
+            "RGI_Emoji_Flag_Sequence" => property.add_flag_sequences(),
+            "RGI_Emoji_Modifier_Sequence" => property.add_modifier_sequences(),
+            "RGI_Emoji_Tag_Sequence" => property.add_tag_sequences(),
+            "RGI_Emoji_ZWJ_Sequence" => property.zwj_sequences = true,
+            "RGI_Emoji" => {
+                property.add_basic_emoji();
+                property.add_keycap_sequences();
+                property.add_flag_sequences();
+                property.add_modifier_sequences();
+                property.add_tag_sequences();
+                property.zwj_sequences = true;
+            }
+            _ => return None,
+        }
+        Some(property)
+    }
+
+    fn add_code_point(&mut self, cp: u32) {
+        match self.code_points.last_mut() {
+            Some(last) if *last.end() + 1 == cp => *last = *last.start()..=cp,
+            _ => self.code_points.push(cp..=cp),
+        }
+    }
+
+    fn add_basic_emoji(&mut self) {
+        let basic_emoji = sets::basic_emoji();
+        let mut presentation = String::new();
+        for cp in sets::emoji().iter_ranges().flatten() {
+            if basic_emoji.contains32(cp) {
+                self.add_code_point(cp);
+            }
+
+            let Some(c) = char::from_u32(cp) else {
+                continue;
+            };
+            presentation.clear();
+            presentation.push(c);
+            presentation.push('\u{FE0F}');
+            if basic_emoji.contains(&presentation) {
+                self.strings.push(vec![cp, EMOJI_PRESENTATION_SELECTOR]);
+            }
+        }
+    }
+
+    fn add_keycap_sequences(&mut self) {
+        for key in "#*0123456789".chars() {
+            self.strings
+                .push(vec![u32::from(key), EMOJI_PRESENTATION_SELECTOR, 0x20E3]);
+        }
+    }
+
+    fn add_flag_sequences(&mut self) {
+        /// Maps an ASCII uppercase letter to its regional indicator symbol.
+        fn regional_indicator(letter: u8) -> u32 {
+            0x1F1E6 + u32::from(letter - b'A')
+        }
+
+        for region in RGI_FLAG_REGIONS.split_whitespace() {
+            self.strings
+                .push(region.bytes().map(regional_indicator).collect());
+        }
+    }
+
+    fn add_modifier_sequences(&mut self) {
+        for base in sets::emoji_modifier_base().iter_ranges().flatten() {
+            for modifier in EMOJI_MODIFIERS {
+                self.strings.push(vec![base, modifier]);
+            }
+        }
+    }
+
+    fn add_tag_sequences(&mut self) {
+        /// The black flag, which is the base of the flags of subdivisions.
+        const WAVING_BLACK_FLAG: u32 = 0x1F3F4;
+        const CANCEL_TAG: u32 = 0xE007F;
+
+        for subdivision in RGI_TAG_SUBDIVISIONS {
+            let mut sequence = vec![WAVING_BLACK_FLAG];
+            sequence.extend(subdivision.bytes().map(|tag| 0xE0000 + u32::from(tag)));
+            sequence.push(CANCEL_TAG);
+            self.strings.push(sequence);
+        }
+    }
+}
+
+/// Checks if `string` is matched by [`ZWJ_SEQUENCE_PATTERN`].
+pub(super) fn is_zwj_sequence(string: &[u32]) -> bool {
+    let pictographic = sets::extended_pictographic();
+    let mut elements = 0;
+    for element in string.split(|cp| *cp == ZERO_WIDTH_JOINER) {
+        let valid = match *element {
+            [base] => pictographic.contains32(base),
+            [base, suffix] => {
+                pictographic.contains32(base)
+                    && (suffix == EMOJI_PRESENTATION_SELECTOR || EMOJI_MODIFIERS.contains(&suffix))
+            }
+            _ => false,
+        };
+        if !valid {
+            return false;
+        }
+        elements += 1;
+    }
+    elements > 1
+}
@@ -0,0 +1,729 @@
+//! Translation of the patterns of regular expressions with the `v` flag.
+//!
+//! `regress` doesn't support the `v` flag, so these patterns are translated to equivalent
+//! patterns for the `u` flag. Both flags share the same syntax outside of character classes,
+//! so only the character classes and the properties of strings are rewritten:
+//!
+//!  - intersections `[A&&B]` become lookaheads like `(?:(?=A)B)`.
+//!  - subtractions `[A--B]` become negative lookaheads like `(?:(?!B)A)`.
+//!  - strings, like `\q{abc}` or `\p{RGI_Emoji}`, become alternatives that match the longest
+//!    strings first, like the `v` flag requires.
+//!  - complements become `(?:(?!A)[\s\S])` when ignoring case, which matches any code point that
+//!    doesn't match `A` case insensitively, like the case folding of the `v` flag.
+//!
+//! The case folding relies on `regress`, which doesn't fold the case of property escapes, so
+//! `/\P{Ll}/vi` still matches `A`, like it does with the `u` flag.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-ClassSetExpression
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use super::emoji::{self, StringProperty};
+
+/// Translates `pattern`, which uses the syntax of the `v` flag, to a pattern with the same
+/// matches with the `u` flag.
+///
+/// Returns an error if `pattern` contains an invalid character class.
+pub(super) fn translate(pattern: &[u32], ignore_case: bool) -> Result<Vec<u32>, String> {
+    let mut parser = Parser {
+        pattern,
+        position: 0,
+        ignore_case,
+    };
+    let mut output = Vec::with_capacity(pattern.len());
+    while let Some(cp) = parser.next() {
+        match char::from_u32(cp) {
+            Some('\\') => match parser.peek() {
+                Some(c @ ('p' | 'P')) => {
+                    parser.position += 1;
+                    parser.property(c == 'P')?.emit(ignore_case, &mut output);
+                }
+                // The escapes outside of character classes are the same as with the `u` flag.
+                Some(_) => {
+                    output.push(cp);
+                    output.extend(parser.next());
+                }
+                None => output.push(cp),
+            },
+            Some('[') => parser.class()?.emit(ignore_case, &mut output),
+            _ => output.push(cp),
+        }
+    }
+    Ok(output)
+}
+
+/// The characters that can't be used unescaped in a character class.
+const CLASS_SET_SYNTAX_CHARACTERS: &str = "()[]{}/-\\|";
+
+/// The characters that can't be repeated in a character class, since they are reserved for
+/// future set operations.
+const CLASS_SET_RESERVED_DOUBLE_PUNCTUATORS: &str = "&!#$%*+,.:;<=>?@^`~";
+
+/// The characters that can be escaped in a character class, in addition to the ones that can be
+/// escaped with the `u` flag.
+const CLASS_SET_RESERVED_PUNCTUATORS: &str = "&-!#%,:;<=>@`~";
+
+/// The characters that can be escaped with the `u` flag.
+const SYNTAX_CHARACTERS: &str = "^$\\.*+?()[]{}|/";
+
+/// An error message for the character classes.
+fn error(message: &str) -> String {
+    format!("Invalid character class: {message}")
+}
+
+fn push_str(output: &mut Vec<u32>, s: &str) {
+    output.extend(s.chars().map(u32::from));
+}
+
+/// Pushes `cp` escaped, so it always matches itself.
+fn push_code_point(output: &mut Vec<u32>, cp: u32) {
+    push_str(output, &format!("\\u{{{cp:X}}}"));
+}
+
+/// Pushes a `u` flag character class with the given contents, or a single code point.
+fn push_class(output: &mut Vec<u32>, negated: bool, contents: &[u32]) {
+    output.push(u32::from('['));
+    if negated {
+        output.push(u32::from('^'));
+    }
+    output.extend_from_slice(contents);
+    output.push(u32::from(']'));
+}
+
+/// The code points of a [`ClassSet`].
+#[derive(Debug, Clone, Default)]
+struct CodePoints {
+    /// The contents of a character class with the `u` flag, like `\u{61}-\u{7A}\p{L}`.
+    class: Vec<u32>,
+
+    /// The set operations whose code points are also part of the set.
+    operations: Vec<Operation>,
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Intersection(CodePoints, CodePoints),
+    Difference(CodePoints, CodePoints),
+    Complement(CodePoints),
+}
+
+impl CodePoints {
+    fn is_empty(&self) -> bool {
+        self.class.is_empty() && self.operations.is_empty()
+    }
+
+    fn union(&mut self, other: Self) {
+        self.class.extend(other.class);
+        self.operations.extend(other.operations);
+    }
+
+    /// Pushes a pattern that matches a single code point of the set.
+    fn emit(&self, ignore_case: bool, output: &mut Vec<u32>) {
+        let alternatives = usize::from(!self.class.is_empty()) + self.operations.len();
+        if alternatives != 1 {
+            push_str(output, "(?:");
+        }
+        if !self.class.is_empty() || alternatives == 0 {
+            push_class(output, false, &self.class);
+        }
+        for (index, operation) in self.operations.iter().enumerate() {
+            if index > 0 || !self.class.is_empty() {
+                push_str(output, "|");
+            }
+            match operation {
+                Operation::Intersection(a, b) => {
+                    push_str(output, "(?:(?=");
+                    a.emit(ignore_case, output);
+                    push_str(output, ")");
+                    b.emit(ignore_case, output);
+                    push_str(output, ")");
+                }
+                Operation::Difference(a, b) => {
+                    push_str(output, "(?:(?!");
+                    b.emit(ignore_case, output);
+                    push_str(output, ")");
+                    a.emit(ignore_case, output);
+                    push_str(output, ")");
+                }
+                // NOTE: With the `i` flag, the code points that are in the set case
+                // insensitively aren't part of the complement, so `[^a]` has to reject `A`.
+                Operation::Complement(a) if !ignore_case && a.operations.is_empty() => {
+                    push_class(output, true, &a.class);
+                }
+                Operation::Complement(a) => {
+                    push_str(output, "(?:(?!");
+                    a.emit(ignore_case, output);
+                    push_str(output, r")[\s\S])");
+                }
+            }
+        }
+        if alternatives != 1 {
+            push_str(output, ")");
+        }
+    }
+}
+
+/// The code points and strings matched by a character class with the `v` flag.
+#[derive(Debug, Clone, Default)]
+struct ClassSet {
+    code_points: CodePoints,
+
+    /// The strings of the set that don't have exactly one code point.
+    strings: BTreeSet<Vec<u32>>,
+
+    /// If the set contains the strings of `RGI_Emoji_ZWJ_Sequence`, the ones that are excluded
+    /// from it.
+    zwj_sequences: Option<BTreeSet<Vec<u32>>>,
+
+    /// The static semantics `MayContainStrings` of the class.
+    may_contain_strings: bool,
+}
+
+impl ClassSet {
+    fn code_point(cp: u32) -> Self {
+        Self::range(cp, cp)
+    }
+
+    fn range(start: u32, end: u32) -> Self {
+        let mut class = Vec::new();
+        push_code_point(&mut class, start);
+        if start != end {
+            class.push(u32::from('-'));
+            push_code_point(&mut class, end);
+        }
+        Self::class_escape(class)
+    }
+
+    /// Creates a set from an escape that can be used in a character class with the `u` flag.
+    fn class_escape(class: Vec<u32>) -> Self {
+        Self {
+            code_points: CodePoints {
+                class,
+                operations: Vec::new(),
+            },
+            ..Self::default()
+        }
+    }
+
+    fn strings(strings: Vec<Vec<u32>>) -> Self {
+        let mut set = Self::default();
+        for string in strings {
+            if let [cp] = *string {
+                set.code_points.union(Self::code_point(cp).code_points);
+            } else {
+                set.may_contain_strings = true;
+                set.strings.insert(string);
+            }
+        }
+        set
+    }
+
+    fn string_property(property: StringProperty) -> Self {
+        let mut set = Self::strings(property.strings);
+        for range in property.code_points {
+            set.code_points
+                .union(Self::range(*range.start(), *range.end()).code_points);
+        }
+        set.zwj_sequences = property.zwj_sequences.then(BTreeSet::new);
+        set.may_contain_strings = true;
+        set
+    }
+
+    fn contains_zwj_sequence(&self, string: &[u32]) -> bool {
+        self.zwj_sequences
+            .as_ref()
+            .is_some_and(|excluded| !excluded.contains(string) && emoji::is_zwj_sequence(string))
+    }
+
+    fn contains_string(&self, string: &[u32]) -> bool {
+        self.strings.contains(string) || self.contains_zwj_sequence(string)
+    }
+
+    fn union(&mut self, other: Self) {
+        self.code_points.union(other.code_points);
+        self.strings.extend(other.strings);
+        self.zwj_sequences = match (self.zwj_sequences.take(), other.zwj_sequences) {
+            (Some(excluded), Some(other)) => Some(&excluded & &other),
+            (excluded, None) | (None, excluded) => excluded,
+        };
+        self.may_contain_strings |= other.may_contain_strings;
+    }
+
+    fn intersection(self, other: Self) -> Self {
+        let code_points = if self.code_points.is_empty() || other.code_points.is_empty() {
+            CodePoints::default()
+        } else {
+            CodePoints {
+                class: Vec::new(),
+                operations: vec![Operation::Intersection(
+                    self.code_points.clone(),
+                    other.code_points.clone(),
+                )],
+            }
+        };
+        let strings = self
+            .strings
+            .iter()
+            .filter(|string| other.contains_string(string))
+            .chain(
+                other
+                    .strings
+                    .iter()
+                    .filter(|string| self.contains_zwj_sequence(string)),
+            )
+            .cloned()
+            .collect();
+        let zwj_sequences = match (self.zwj_sequences, other.zwj_sequences) {
+            (Some(excluded), Some(other)) => Some(&excluded | &other),
+            _ => None,
+        };
+
+        Self {
+            code_points,
+            strings,
+            zwj_sequences,
+            may_contain_strings: self.may_contain_strings && other.may_contain_strings,
+        }
+    }
+
+    fn difference(self, other: Self) -> Self {
+        let code_points = if self.code_points.is_empty() || other.code_points.is_empty() {
+            self.code_points
+        } else {
+            CodePoints {
+                class: Vec::new(),
+                operations: vec![Operation::Difference(
+                    self.code_points,
+                    other.code_points.clone(),
+                )],
+            }
+        };
+        let strings = self
+            .strings
+            .iter()
+            .filter(|string| !other.contains_string(string))
+            .cloned()
+            .collect();
+        let zwj_sequences = match self.zwj_sequences {
+            Some(mut excluded) if other.zwj_sequences.is_none() => {
+                excluded.extend(
+                    other
+                        .strings
+                        .into_iter()
+                        .filter(|string| emoji::is_zwj_sequence(string)),
+                );
+                Some(excluded)
+            }
+            _ => None,
+        };
+
+        Self {
+            code_points,
+            strings,
+            zwj_sequences,
+            may_contain_strings: self.may_contain_strings,
+        }
+    }
+
+    fn complement(self) -> Self {
+        Self::from_operation(Operation::Complement(self.code_points))
+    }
+
+    fn from_operation(operation: Operation) -> Self {
+        Self {
+            code_points: CodePoints {
+                class: Vec::new(),
+                operations: vec![operation],
+            },
+            ..Self::default()
+        }
+    }
+
+    /// Pushes a pattern that matches the set, trying the longest strings first.
+    fn emit(&self, ignore_case: bool, output: &mut Vec<u32>) {
+        if self.strings.is_empty() && self.zwj_sequences.is_none() {
+            self.code_points.emit(ignore_case, output);
+            return;
+        }
+
+        push_str(output, "(?:");
+        let mut first = true;
+        let mut separate = |output: &mut Vec<u32>| {
+            if !std::mem::take(&mut first) {
+                push_str(output, "|");
+            }
+        };
+
+        if let Some(excluded) = &self.zwj_sequences {
+            separate(output);
+            if !excluded.is_empty() {
+                // NOTE: An excluded sequence only rejects the match if it isn't the beginning of
+                // a longer sequence.
+                push_str(output, "(?!(?:");
+                emit_strings(excluded, output);
+                push_str(output, r")(?![\u{200D}\u{FE0F}\u{1F3FB}-\u{1F3FF}]))");
+            }
+            push_str(output, emoji::ZWJ_SEQUENCE_PATTERN);
+        }
+        if self.strings.iter().any(|string| !string.is_empty()) {
+            separate(output);
+            emit_strings(&self.strings, output);
+        }
+        if !self.code_points.is_empty() {
+            separate(output);
+            self.code_points.emit(ignore_case, output);
+        }
+        if self.strings.contains(&Vec::new()) {
+            separate(output);
+        }
+        push_str(output, ")");
+    }
+}
+
+/// Pushes alternatives that match the non-empty `strings`, from the longest ones.
+///
+/// The strings of the same length whose only difference is their last code point are grouped
+/// like `ab[cd]`, and then the ones with two code points and the same last code points are
+/// grouped like `[ab][cd]`, to keep the properties of strings short.
+fn emit_strings(strings: &BTreeSet<Vec<u32>>, output: &mut Vec<u32>) {
+    let mut last_code_points = BTreeMap::<_, Vec<u32>>::new();
+    for string in strings {
+        if let Some((last, prefix)) = string.split_last() {
+            last_code_points
+                .entry((Reverse(string.len()), prefix))
+                .or_default()
+                .push(*last);
+        }
+    }
+    let mut prefixes = BTreeMap::<_, Vec<&[u32]>>::new();
+    for ((length, prefix), last) in last_code_points {
+        prefixes.entry((length, last)).or_default().push(prefix);
+    }
+
+    let push_any = |output: &mut Vec<u32>, code_points: &[u32]| {
+        if let [cp] = *code_points {
+            push_code_point(output, cp);
+        } else {
+            let mut class = Vec::new();
+            for cp in code_points {
+                push_code_point(&mut class, *cp);
+            }
+            push_class(output, false, &class);
+        }
+    };
+    let mut first = true;
+    for ((Reverse(length), last), prefixes) in prefixes {
+        let mut push_alternative = |output: &mut Vec<u32>, prefix: &dyn Fn(&mut Vec<u32>)| {
+            if !std::mem::take(&mut first) {
+                push_str(output, "|");
+            }
+            prefix(output);
+            push_any(output, &last);
+        };
+
+        if length == 2 {
+            let firsts = prefixes.iter().map(|prefix| prefix[0]).collect::<Vec<_>>();
+            push_alternative(output, &|output| push_any(output, &firsts));
+        } else {
+            for prefix in prefixes {
+                push_alternative(output, &|output| {
+                    for cp in prefix {
+                        push_code_point(output, *cp);
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// An intersection or subtraction of two class sets.
+type SetOperation = fn(ClassSet, ClassSet) -> ClassSet;
+
+/// An operand of a class set expression.
+enum Operand {
+    /// A single code point, which can also be the start or end of a range.
+    Character(u32),
+    Set(ClassSet),
+}
+
+impl Operand {
+    fn into_set(self) -> ClassSet {
+        match self {
+            Self::Character(cp) => ClassSet::code_point(cp),
+            Self::Set(set) => set,
+        }
+    }
+}
+
+struct Parser<'a> {
+    pattern: &'a [u32],
+    position: usize,
+    ignore_case: bool,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<u32> {
+        let cp = *self.pattern.get(self.position)?;
+        self.position += 1;
+        Some(cp)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.pattern
+            .get(self.position + offset)
+            .and_then(|cp| char::from_u32(*cp))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_at(0)
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let matches = s
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| self.peek_at(offset) == Some(c));
+        if matches {
+            self.position += s.chars().count();
+        }
+        matches
+    }
+
+    /// Parses a `ClassSetExpression`, after its opening `[`.
+    fn class(&mut self) -> Result<ClassSet, String> {
+        let negated = self.eat("^");
+        let set = self.class_contents()?;
+        if !negated {
+            return Ok(set);
+        }
+        if set.may_contain_strings {
+            return Err(error("negated character class may contain strings"));
+        }
+        Ok(set.complement())
+    }
+
+    /// Parses a `ClassUnion`, `ClassIntersection` or `ClassSubtraction`, and the closing `]`.
+    fn class_contents(&mut self) -> Result<ClassSet, String> {
+        if self.eat("]") {
+            return Ok(ClassSet::default());
+        }
+
+        let first = self.class_operand()?;
+        let operations: [(_, SetOperation); 2] =
+            [("&&", ClassSet::intersection), ("--", ClassSet::difference)];
+        for (operator, operation) in operations {
+            if !self.eat(operator) {
+                continue;
+            }
+            let mut set = first.into_set();
+            loop {
+                set = operation(set, self.class_operand()?.into_set());
+                if self.eat("]") {
+                    return Ok(set);
+                }
+                if !self.eat(operator) {
+                    return Err(error("set operations cannot be mixed"));
+                }
+            }
+        }
+
+        let mut set = ClassSet::default();
+        let mut operand = first;
+        loop {
+            match operand {
+                Operand::Character(start)
+                    if self.peek() == Some('-') && self.peek_at(1) != Some('-') =>
+                {
+                    self.position += 1;
+                    let Operand::Character(end) = self.class_operand()? else {
+                        return Err(error("range bounds must be characters"));
+                    };
+                    if start > end {
+                        return Err(error("range out of order"));
+                    }
+                    set.union(ClassSet::range(start, end));
+                }
+                operand => set.union(operand.into_set()),
+            }
+
+            if self.eat("]") {
+                return Ok(set);
+            }
+            if self.eat("&&") || self.eat("--") {
+                return Err(error("set operations cannot be mixed with unions"));
+            }
+            operand = self.class_operand()?;
+        }
+    }
+
+    /// Parses a `ClassSetOperand`.
+    fn class_operand(&mut self) -> Result<Operand, String> {
+        let Some(cp) = self.next() else {
+            return Err(error("unterminated character class"));
+        };
+        match char::from_u32(cp) {
+            Some('[') => self.class().map(Operand::Set),
+            Some('\\') => self.class_escape(),
+            Some(c) if CLASS_SET_SYNTAX_CHARACTERS.contains(c) => {
+                Err(error(&format!("`{c}` must be escaped")))
+            }
+            Some(c)
+                if CLASS_SET_RESERVED_DOUBLE_PUNCTUATORS.contains(c) && self.peek() == Some(c) =>
+            {
+                Err(error(&format!("`{c}{c}` is reserved")))
+            }
+            _ => Ok(Operand::Character(cp)),
+        }
+    }
+
+    /// Parses an escape in a character class, after its `\`.
+    fn class_escape(&mut self) -> Result<Operand, String> {
+        let Some(c) = self.peek() else {
+            return Err(error("unterminated character class"));
+        };
+        self.position += 1;
+        let cp = match c {
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => {
+                let escape = vec![u32::from('\\'), u32::from(c)];
+                return Ok(Operand::Set(ClassSet::class_escape(escape)));
+            }
+            'p' | 'P' => return self.property(c == 'P').map(Operand::Set),
+            'q' => return self.class_string_disjunction().map(Operand::Set),
+            'b' => 0x08,
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            'c' => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.position += 1;
+                    u32::from(letter) % 32
+                }
+                _ => return Err(error("invalid control escape")),
+            },
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => 0,
+            'x' => self
+                .hex_digits(2)
+                .ok_or_else(|| error("invalid hexadecimal escape"))?,
+            'u' => self
+                .unicode_escape()
+                .ok_or_else(|| error("invalid Unicode escape"))?,
+            c if SYNTAX_CHARACTERS.contains(c) || CLASS_SET_RESERVED_PUNCTUATORS.contains(c) => {
+                u32::from(c)
+            }
+            _ => return Err(error(&format!("invalid escape `\\{c}`"))),
+        };
+        Ok(Operand::Character(cp))
+    }
+
+    fn hex_digits(&mut self, count: usize) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..count {
+            value = value * 16 + self.peek()?.to_digit(16)?;
+            self.position += 1;
+        }
+        Some(value)
+    }
+
+    /// Parses the code point of a `\u` escape, after the `u`.
+    fn unicode_escape(&mut self) -> Option<u32> {
+        if self.eat("{") {
+            let mut value = 0u32;
+            let mut digits = 0;
+            while let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) {
+                value = value.checked_mul(16)? + digit;
+                digits += 1;
+                self.position += 1;
+            }
+            return (digits > 0 && value <= 0x10_FFFF && self.eat("}")).then_some(value);
+        }
+
+        let lead = self.hex_digits(4)?;
+        if (0xD800..=0xDBFF).contains(&lead)
+            && self.peek() == Some('\\')
+            && self.peek_at(1) == Some('u')
+        {
+            let start = self.position;
+            self.position += 2;
+            match self.hex_digits(4) {
+                Some(trail @ 0xDC00..=0xDFFF) => {
+                    return Some(0x1_0000 + ((lead - 0xD800) << 10) + (trail - 0xDC00));
+                }
+                _ => self.position = start,
+            }
+        }
+        Some(lead)
+    }
+
+    /// Parses a `ClassStringDisjunction`, after its `\q`.
+    fn class_string_disjunction(&mut self) -> Result<ClassSet, String> {
+        if !self.eat("{") {
+            return Err(error("expected `{` after `\\q`"));
+        }
+        let mut strings = Vec::new();
+        let mut string = Vec::new();
+        loop {
+            if self.eat("}") {
+                strings.push(string);
+                return Ok(ClassSet::strings(strings));
+            }
+            if self.eat("|") {
+                strings.push(std::mem::take(&mut string));
+                continue;
+            }
+            if self.peek() == Some('[') {
+                return Err(error("`[` must be escaped"));
+            }
+            match self.class_operand()? {
+                Operand::Character(cp) => string.push(cp),
+                Operand::Set(_) => return Err(error("class strings can only contain characters")),
+            }
+        }
+    }
+
+    /// Parses a property escape, after its `\p` or `\P`.
+    fn property(&mut self, negated: bool) -> Result<ClassSet, String> {
+        let start = self.position;
+        let mut name = String::new();
+        if self.eat("{") {
+            while let Some(c) = self.peek() {
+                self.position += 1;
+                if c == '}' {
+                    break;
+                }
+                name.push(c);
+            }
+        }
+        let escape = &self.pattern[start..self.position];
+
+        if let Some(property) = StringProperty::get(&name) {
+            if negated {
+                return Err(error(&format!(
+                    "property of strings `{name}` cannot be negated"
+                )));
+            }
+            return Ok(ClassSet::string_property(property));
+        }
+
+        let mut class = vec![u32::from('\\'), u32::from('p')];
+        class.extend_from_slice(escape);
+        let set = ClassSet::class_escape(class);
+        Ok(match (negated, self.ignore_case) {
+            (false, _) => set,
+            (true, true) => set.complement(),
+            (true, false) => {
+                // `regress` validates the escape.
+                let mut class = vec![u32::from('\\'), u32::from('P')];
+                class.extend_from_slice(escape);
+                ClassSet::class_escape(class)
+            }
+        })
+    }
+}
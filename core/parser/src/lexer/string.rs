@@ -151,7 +151,10 @@ impl StringLiteral {
                 Some(ch) if !Self::is_line_terminator(ch) => {
                     buf.push_code_point(ch);
                 }
-                _ => {
+                Some(_) => {
+                    return Err(Error::syntax("unterminated string literal", ch_start_pos));
+                }
+                None => {
                     return Err(Error::from(io::Error::new(
                         ErrorKind::UnexpectedEof,
                         "unterminated string literal",
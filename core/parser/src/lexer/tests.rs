@@ -583,6 +583,25 @@ fn big_literal_numbers() {
     expect_tokens(&mut lexer, &expected, interner);
 }
 
+#[test]
+fn long_big_int_literals() {
+    // Long enough to be parsed by splitting the digits.
+    let digits = "1234567890".repeat(500);
+    let source = format!("{digits}n 0x{digits}n {digits}");
+    let mut lexer = Lexer::from(source.as_bytes());
+    let interner = &mut Interner::default();
+
+    let value = num_bigint::BigInt::parse_bytes(digits.as_bytes(), 10).expect("valid digits");
+    let hex_value = num_bigint::BigInt::parse_bytes(digits.as_bytes(), 16).expect("valid digits");
+    let expected = [
+        TokenKind::numeric_literal(value),
+        TokenKind::numeric_literal(hex_value),
+        TokenKind::numeric_literal(f64::INFINITY),
+    ];
+
+    expect_tokens(&mut lexer, &expected, interner);
+}
+
 #[test]
 fn implicit_octal_edge_case() {
     let mut lexer = Lexer::from(&b"044.5 094.5"[..]);
@@ -698,6 +717,30 @@ fn regex_literal_flags_err() {
         .expect_err("Lexer did not handle regex literal with error");
 }
 
+#[test]
+fn regex_literal_pattern_err() {
+    for (source, position) in [
+        (&br"/(?<dup>a)(?<dup>b)/"[..], Position::new(1, 1)),
+        (&br"/\c/u"[..], Position::new(1, 1)),
+        (&br"/a{2,1}/gmii"[..], Position::new(1, 9)),
+        (&br"/a/uv"[..], Position::new(1, 4)),
+        (&br"/[a&&&b]/v"[..], Position::new(1, 1)),
+        (&br"/[^\q{ab}]/v"[..], Position::new(1, 1)),
+    ] {
+        let mut lexer = Lexer::from(source);
+        let interner = &mut Interner::default();
+
+        if let Error::Syntax(_, pos) = lexer
+            .next(interner)
+            .expect_err("Lexer did not handle invalid regex literal")
+        {
+            assert_eq!(pos, position);
+        } else {
+            panic!("invalid error type");
+        }
+    }
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::from(&b"1+1"[..]);
@@ -25,6 +25,8 @@
     clippy::struct_field_names
 )]
 
+#[cfg(feature = "arena")]
+pub mod arena;
 pub mod error;
 pub mod lexer;
 pub mod parser;
@@ -1,7 +1,7 @@
 use crate::{
     lexer::{InputElement, Lexer, Token, TokenKind},
     parser::ParseResult,
-    source::{ReadChar, UTF8Input},
+    source::{ReadChar, SourceText, UTF8Input},
     Error,
 };
 use boa_ast::Position;
@@ -122,6 +122,22 @@ where
         self.lexer.set_module(module);
     }
 
+    /// Takes the source text consumed by the lexer so far, including peeked tokens.
+    pub(super) fn take_source_text(&mut self) -> SourceText {
+        self.lexer.take_source_text()
+    }
+
+    /// Skips the hashbang comment at the start of the source, if any.
+    ///
+    /// This must be called before peeking any token.
+    pub(super) fn skip_hashbang(&mut self, interner: &mut Interner) -> ParseResult<()> {
+        debug_assert!(
+            self.read_index == self.write_index,
+            "tokens were lexed before skipping the hashbang"
+        );
+        self.lexer.skip_hashbang(interner).map_err(Error::from)
+    }
+
     /// Fills the peeking buffer with the next token.
     ///
     /// It will not fill two line terminators one after the other.
@@ -2,15 +2,20 @@
 mod buffered_lexer;
 
 use crate::{
-    lexer::{InputElement, Lexer, Token, TokenKind},
+    lexer::{Error as LexError, InputElement, Lexer, Token, TokenKind},
     parser::{OrAbrupt, ParseResult},
-    source::ReadChar,
+    source::{ReadChar, SourceText},
     Error,
 };
-use boa_ast::{Position, Punctuator};
+use boa_ast::{Position, Punctuator, Span, Spanned};
 use boa_interner::Interner;
 use buffered_lexer::BufferedLexer;
 
+/// The default maximum nesting depth of statements and expressions.
+///
+/// See [`Parser::set_nesting_limit`][crate::Parser::set_nesting_limit].
+pub const DEFAULT_NESTING_LIMIT: usize = 400;
+
 /// The result of a peek for a semicolon.
 #[derive(Debug)]
 pub(super) enum SemicolonResult<'s> {
@@ -37,6 +42,50 @@ pub(super) struct Cursor<R> {
 
     /// Tracks the number of tagged templates that are currently being parsed.
     tagged_templates_count: u32,
+
+    /// The current nesting depth of statements and expressions.
+    depth: usize,
+
+    /// The maximum nesting depth before an error is thrown.
+    depth_limit: usize,
+
+    /// The end position of the last consumed token.
+    last_end: Position,
+
+    /// The syntax errors recovered from so far, or `None` if error recovery is disabled.
+    recovered_errors: Option<Vec<Error>>,
+}
+
+/// Returns `true` if `error` was caused by invalid source text found by the lexer, after which
+/// lexing can continue, as opposed to a failure to read the source.
+pub(super) fn is_lexing_error(error: &Error) -> bool {
+    match error {
+        Error::Lex {
+            err: LexError::Syntax(..),
+        } => true,
+        // Unterminated literals are reported as an unexpected end of the input.
+        Error::Lex {
+            err: LexError::IO(error),
+        } => error.kind() == std::io::ErrorKind::UnexpectedEof,
+        _ => false,
+    }
+}
+
+/// The state of a [`Cursor`] before parsing a statement, restored when recovering from an error
+/// in the middle of it.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RecoveryPoint {
+    start: Position,
+    depth: usize,
+    strict: bool,
+    arrow: bool,
+}
+
+impl RecoveryPoint {
+    /// Gets the start position of the statement.
+    pub(super) const fn start(self) -> Position {
+        self.start
+    }
 }
 
 impl<R> Cursor<R>
@@ -51,6 +100,10 @@ where
             json_parse: false,
             identifier: 0,
             tagged_templates_count: 0,
+            depth: 0,
+            depth_limit: DEFAULT_NESTING_LIMIT,
+            last_end: Position::new(1, 1),
+            recovered_errors: None,
         }
     }
 
@@ -64,6 +117,16 @@ where
         self.buffered_lexer.module()
     }
 
+    /// Takes the source text read so far.
+    pub(super) fn take_source_text(&mut self) -> SourceText {
+        self.buffered_lexer.take_source_text()
+    }
+
+    /// Skips the hashbang comment at the start of a `Script` or `Module` source, if any.
+    pub(super) fn skip_hashbang(&mut self, interner: &mut Interner) -> ParseResult<()> {
+        self.buffered_lexer.skip_hashbang(interner)
+    }
+
     pub(super) fn set_goal(&mut self, elm: InputElement) {
         self.buffered_lexer.set_goal(elm);
     }
@@ -73,7 +136,9 @@ where
         start: Position,
         interner: &mut Interner,
     ) -> ParseResult<Token> {
-        self.buffered_lexer.lex_regex(start, interner)
+        let token = self.buffered_lexer.lex_regex(start, interner)?;
+        self.last_end = token.span().end();
+        Ok(token)
     }
 
     pub(super) fn lex_template(
@@ -81,12 +146,18 @@ where
         start: Position,
         interner: &mut Interner,
     ) -> ParseResult<Token> {
-        self.buffered_lexer.lex_template(start, interner)
+        let token = self.buffered_lexer.lex_template(start, interner)?;
+        self.last_end = token.span().end();
+        Ok(token)
     }
 
     /// Advances the cursor and returns the next token.
     pub(super) fn next(&mut self, interner: &mut Interner) -> ParseResult<Option<Token>> {
-        self.buffered_lexer.next(true, interner)
+        let token = self.buffered_lexer.next(true, interner)?;
+        if let Some(token) = &token {
+            self.last_end = token.span().end();
+        }
+        Ok(token)
     }
 
     /// Advances the cursor without returning the next token.
@@ -147,6 +218,112 @@ where
         self.identifier = identifier;
     }
 
+    /// Sets the maximum nesting depth of statements and expressions.
+    pub(super) fn set_nesting_limit(&mut self, limit: usize) {
+        self.depth_limit = limit;
+    }
+
+    /// Enters a new nesting level, returning an error if the nesting limit is exceeded.
+    ///
+    /// Every successful call must be paired with a call to [`Cursor::exit_nesting`].
+    pub(super) fn enter_nesting(&mut self, position: Position) -> ParseResult<()> {
+        if self.depth >= self.depth_limit {
+            return Err(Error::general("maximum nesting depth exceeded", position));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Exits the current nesting level.
+    pub(super) fn exit_nesting(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// Enables error recovery, making parsers record syntax errors instead of failing on them
+    /// whenever they are able to continue.
+    pub(super) fn set_error_recovery(&mut self) {
+        self.recovered_errors.get_or_insert_with(Vec::new);
+    }
+
+    /// Takes the syntax errors recovered from so far.
+    pub(super) fn take_recovered_errors(&mut self) -> Vec<Error> {
+        self.recovered_errors
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Records `error` if error recovery is enabled, or returns it otherwise.
+    pub(super) fn record_error(&mut self, error: Error) -> ParseResult<()> {
+        match &mut self.recovered_errors {
+            Some(errors) => {
+                errors.push(error);
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
+    /// Saves the state of the cursor before parsing a statement starting at the next token.
+    ///
+    /// If error recovery is enabled, syntax errors found while lexing the next token are recorded,
+    /// since the lexer skips the offending characters and can continue after them.
+    pub(super) fn recovery_point(&mut self, interner: &mut Interner) -> ParseResult<RecoveryPoint> {
+        let start = loop {
+            match self.start_position(interner) {
+                Ok(start) => break start,
+                Err(error) if is_lexing_error(&error) => self.record_error(error)?,
+                Err(error) => return Err(error),
+            }
+        };
+        Ok(RecoveryPoint {
+            start,
+            depth: self.depth,
+            strict: self.strict(),
+            arrow: self.arrow,
+        })
+    }
+
+    /// Records `error` and restores the state saved in `point`, or returns the error if error
+    /// recovery is disabled.
+    pub(super) fn recover(&mut self, point: RecoveryPoint, error: Error) -> ParseResult<()> {
+        self.record_error(error)?;
+        self.depth = point.depth;
+        self.set_strict(point.strict);
+        self.arrow = point.arrow;
+        self.set_goal(InputElement::RegExp);
+        Ok(())
+    }
+
+    /// Gets the start position of the next token, or the end of the last consumed token if there
+    /// are no more tokens.
+    pub(super) fn start_position(&mut self, interner: &mut Interner) -> ParseResult<Position> {
+        let last_end = self.last_end;
+        Ok(self
+            .peek(0, interner)?
+            .map_or(last_end, |token| token.span().start()))
+    }
+
+    /// Gets the span from `start` to the end of the last consumed token.
+    pub(super) fn span_from(&self, start: Position) -> Span {
+        Span::new(start, self.last_end.max(start))
+    }
+
+    /// Sets the span of `node` to cover the tokens consumed since `start`.
+    pub(super) fn spanned<N: Spanned>(&self, start: Position, mut node: N) -> N {
+        node.set_span(self.span_from(start));
+        node
+    }
+
+    /// Sets the span of `node` to cover the tokens consumed since `start`, unless the node already
+    /// got a span while it was being parsed.
+    pub(super) fn fill_span<N: Spanned>(&self, start: Position, mut node: N) -> N {
+        if node.span().is_none() {
+            node.set_span(self.span_from(start));
+        }
+        node
+    }
+
     /// Get the identifier for a tagged template.
     #[inline]
     pub(super) fn tagged_template_identifier(&mut self) -> u64 {
@@ -214,7 +391,11 @@ where
     ) -> ParseResult<()> {
         match self.peek_semicolon(interner)? {
             SemicolonResult::Found(Some(tk)) => match *tk.kind() {
-                TokenKind::Punctuator(Punctuator::Semicolon) | TokenKind::LineTerminator => {
+                TokenKind::Punctuator(Punctuator::Semicolon) => {
+                    self.next(interner)?;
+                    Ok(())
+                }
+                TokenKind::LineTerminator => {
                     let _next = self.buffered_lexer.next(false, interner)?;
                     Ok(())
                 }
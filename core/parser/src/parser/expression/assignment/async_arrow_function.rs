@@ -179,23 +179,20 @@ where
     type Output = ast::function::FunctionBody;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        let body =
-            match cursor.peek(0, interner).or_abrupt()?.kind() {
-                TokenKind::Punctuator(Punctuator::OpenBlock) => {
-                    cursor.advance(interner);
-                    let body = FunctionBody::new(false, true).parse(cursor, interner)?;
-                    cursor.expect(Punctuator::CloseBlock, "async arrow function", interner)?;
-                    body
-                }
-                _ => ast::function::FunctionBody::new(StatementList::from(vec![
-                    ast::Statement::Return(Return::new(
-                        ExpressionBody::new(self.allow_in, true)
-                            .parse(cursor, interner)?
-                            .into(),
-                    ))
-                    .into(),
-                ])),
-            };
+        let token = cursor.peek(0, interner).or_abrupt()?;
+        let start = token.span().start();
+        let body = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenBlock) {
+            cursor.advance(interner);
+            let body = FunctionBody::new(false, true).parse(cursor, interner)?;
+            cursor.expect(Punctuator::CloseBlock, "async arrow function", interner)?;
+            body
+        } else {
+            let expr = ExpressionBody::new(self.allow_in, true).parse(cursor, interner)?;
+            let ret = cursor.spanned(start, Return::new(expr.into()));
+            ast::function::FunctionBody::new(StatementList::from(vec![
+                ast::Statement::Return(ret).into()
+            ]))
+        };
 
         Ok(body)
     }
@@ -70,6 +70,7 @@ where
         let _timer = Profiler::global().start_event("ExponentiationExpression", "Parsing");
 
         let next = cursor.peek(0, interner).or_abrupt()?;
+        let start = next.span().start();
         match next.kind() {
             TokenKind::Keyword((Keyword::Delete | Keyword::Void | Keyword::TypeOf, _))
             | TokenKind::Punctuator(
@@ -90,12 +91,10 @@ where
         if let Some(tok) = cursor.peek(0, interner)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::Exp) {
                 cursor.advance(interner);
-                return Ok(Binary::new(
-                    ArithmeticOp::Exp.into(),
-                    lhs,
-                    self.parse(cursor, interner)?,
-                )
-                .into());
+                let rhs = self.parse(cursor, interner)?;
+                return Ok(cursor
+                    .spanned(start, Binary::new(ArithmeticOp::Exp.into(), lhs, rhs))
+                    .into());
             }
         }
         Ok(lhs)
@@ -96,10 +96,24 @@ where
 {
     type Output = Expression;
 
-    fn parse(mut self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Expression> {
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Expression> {
         let _timer = Profiler::global().start_event("AssignmentExpression", "Parsing");
         cursor.set_goal(InputElement::RegExp);
 
+        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        cursor.enter_nesting(position)?;
+        let result = self.parse_assignment(cursor, interner);
+        cursor.exit_nesting();
+        result.map(|expr| cursor.fill_span(position, expr))
+    }
+}
+
+impl AssignmentExpression {
+    fn parse_assignment<R: ReadChar>(
+        mut self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<Expression> {
         match cursor.peek(0, interner).or_abrupt()?.kind() {
             // [+Yield]YieldExpression[?In, ?Await]
             TokenKind::Keyword((Keyword::Yield, _)) if self.allow_yield.0 => {
@@ -96,14 +96,12 @@ where
                 }
             }
 
-            if cursor.next_if(Punctuator::Spread, interner)?.is_some() {
-                args.push(
-                    Spread::new(
-                        AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
-                            .parse(cursor, interner)?,
-                    )
-                    .into(),
-                );
+            if let Some(spread) = cursor.next_if(Punctuator::Spread, interner)? {
+                let start = spread.span().start();
+                let target =
+                    AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                args.push(cursor.spanned(start, Spread::new(target)).into());
             } else {
                 args.push(
                     AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
@@ -21,10 +21,10 @@ use ast::function::PrivateName;
 use boa_ast::{
     self as ast,
     expression::{
-        access::{PrivatePropertyAccess, SimplePropertyAccess},
+        access::{PrivatePropertyAccess, PropertyAccess, SimplePropertyAccess},
         Call,
     },
-    Punctuator,
+    Position, Punctuator,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -39,6 +39,7 @@ use boa_profiler::Profiler;
 pub(super) struct CallExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    start: Position,
     first_member_expr: ast::Expression,
 }
 
@@ -47,6 +48,7 @@ impl CallExpression {
     pub(super) fn new<Y, A>(
         allow_yield: Y,
         allow_await: A,
+        start: Position,
         first_member_expr: ast::Expression,
     ) -> Self
     where
@@ -56,6 +58,7 @@ impl CallExpression {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            start,
             first_member_expr,
         }
     }
@@ -75,7 +78,9 @@ where
         let lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            Call::new(self.first_member_expr, args).into()
+            cursor
+                .spanned(self.start, Call::new(self.first_member_expr, args))
+                .into()
         } else {
             let next_token = cursor.next(interner)?.expect("token vanished");
             return Err(Error::expected(
@@ -86,7 +91,8 @@ where
             ));
         };
 
-        CallExpressionTail::new(self.allow_yield, self.allow_await, lhs).parse(cursor, interner)
+        CallExpressionTail::new(self.allow_yield, self.allow_await, self.start, lhs)
+            .parse(cursor, interner)
     }
 }
 
@@ -95,12 +101,18 @@ where
 pub(super) struct CallExpressionTail {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    start: Position,
     call: ast::Expression,
 }
 
 impl CallExpressionTail {
     /// Creates a new `CallExpressionTail` parser.
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, call: ast::Expression) -> Self
+    pub(super) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        start: Position,
+        call: ast::Expression,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -108,6 +120,7 @@ impl CallExpressionTail {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            start,
             call,
         }
     }
@@ -128,12 +141,12 @@ where
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
                     let args = Arguments::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    lhs = ast::Expression::from(Call::new(lhs, args));
+                    lhs = cursor.spanned(self.start, Call::new(lhs, args)).into();
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.advance(interner);
 
-                    let access = match cursor.next(interner).or_abrupt()?.kind() {
+                    let access: PropertyAccess = match cursor.next(interner).or_abrupt()?.kind() {
                         TokenKind::IdentifierName((name, _)) => {
                             SimplePropertyAccess::new(lhs, *name).into()
                         }
@@ -162,25 +175,25 @@ where
                         }
                     };
 
-                    lhs = ast::Expression::PropertyAccess(access);
+                    lhs = cursor.spanned(self.start, access).into();
                 }
                 TokenKind::Punctuator(Punctuator::OpenBracket) => {
                     cursor.advance(interner);
                     let idx = Expression::new(None, true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
                     cursor.expect(Punctuator::CloseBracket, "call expression", interner)?;
-                    lhs =
-                        ast::Expression::PropertyAccess(SimplePropertyAccess::new(lhs, idx).into());
+                    let access = PropertyAccess::from(SimplePropertyAccess::new(lhs, idx));
+                    lhs = cursor.spanned(self.start, access).into();
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
-                    lhs = TaggedTemplateLiteral::new(
+                    let tagged = TaggedTemplateLiteral::new(
                         self.allow_yield,
                         self.allow_await,
                         tok.span().start(),
                         lhs,
                     )
-                    .parse(cursor, interner)?
-                    .into();
+                    .parse(cursor, interner)?;
+                    lhs = cursor.spanned(self.start, tagged).into();
                 }
                 _ => break,
             }
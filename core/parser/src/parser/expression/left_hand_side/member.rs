@@ -22,7 +22,8 @@ use boa_ast::{
     self as ast,
     expression::{
         access::{
-            PrivatePropertyAccess, PropertyAccessField, SimplePropertyAccess, SuperPropertyAccess,
+            PrivatePropertyAccess, PropertyAccess, PropertyAccessField, SimplePropertyAccess,
+            SuperPropertyAccess,
         },
         Call, Identifier, New,
     },
@@ -152,7 +153,7 @@ where
                         }
                         _ => Box::new([]),
                     };
-                    let call_node = Call::new(lhs_inner, args);
+                    let call_node = cursor.spanned(position, Call::new(lhs_inner, args));
 
                     ast::Expression::from(New::from(call_node))
                 };
@@ -214,6 +215,7 @@ where
             _ => PrimaryExpression::new(self.name, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?,
         };
+        lhs = cursor.fill_span(position, lhs);
 
         cursor.set_goal(InputElement::TemplateTail);
 
@@ -226,7 +228,7 @@ where
 
                     let token = cursor.next(interner).or_abrupt()?;
 
-                    let access = match token.kind() {
+                    let access: PropertyAccess = match token.kind() {
                         TokenKind::IdentifierName((name, _)) => {
                             SimplePropertyAccess::new(lhs, *name).into()
                         }
@@ -255,7 +257,7 @@ where
                         }
                     };
 
-                    lhs = ast::Expression::PropertyAccess(access);
+                    lhs = cursor.spanned(position, access).into();
                 }
                 TokenKind::Punctuator(Punctuator::OpenBracket) => {
                     cursor
@@ -264,18 +266,18 @@ where
                     let idx = Expression::new(None, true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
                     cursor.expect(Punctuator::CloseBracket, "member expression", interner)?;
-                    lhs =
-                        ast::Expression::PropertyAccess(SimplePropertyAccess::new(lhs, idx).into());
+                    let access = PropertyAccess::from(SimplePropertyAccess::new(lhs, idx));
+                    lhs = cursor.spanned(position, access).into();
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
-                    lhs = TaggedTemplateLiteral::new(
+                    let tagged = TaggedTemplateLiteral::new(
                         self.allow_yield,
                         self.allow_await,
                         tok.span().start(),
                         lhs,
                     )
-                    .parse(cursor, interner)?
-                    .into();
+                    .parse(cursor, interner)?;
+                    lhs = cursor.spanned(position, tagged).into();
                 }
                 _ => break,
             }
@@ -118,12 +118,13 @@ where
         let _timer = Profiler::global().start_event("LeftHandSideExpression", "Parsing");
 
         cursor.set_goal(InputElement::TemplateTail);
+        let start = cursor.start_position(interner)?;
 
         let mut lhs = if is_keyword_call(Keyword::Super, cursor, interner)? {
             cursor.advance(interner);
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            SuperCall::new(args).into()
+            cursor.spanned(start, SuperCall::new(args)).into()
         } else if is_keyword_call(Keyword::Import, cursor, interner)? {
             // `import`
             cursor.advance(interner);
@@ -139,18 +140,15 @@ where
                 interner,
             )?;
 
-            CallExpressionTail::new(
-                self.allow_yield,
-                self.allow_await,
-                ImportCall::new(arg).into(),
-            )
-            .parse(cursor, interner)?
+            let call = cursor.spanned(start, ImportCall::new(arg));
+            CallExpressionTail::new(self.allow_yield, self.allow_await, start, call.into())
+                .parse(cursor, interner)?
         } else {
             let mut member = MemberExpression::new(self.name, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
             if let Some(tok) = cursor.peek(0, interner)? {
                 if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                    member = CallExpression::new(self.allow_yield, self.allow_await, member)
+                    member = CallExpression::new(self.allow_yield, self.allow_await, start, member)
                         .parse(cursor, interner)?;
                 }
             }
@@ -159,12 +157,12 @@ where
 
         if let Some(tok) = cursor.peek(0, interner)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::Optional) {
-                lhs = OptionalExpression::new(self.allow_yield, self.allow_await, lhs)
-                    .parse(cursor, interner)?
-                    .into();
+                let optional = OptionalExpression::new(self.allow_yield, self.allow_await, lhs)
+                    .parse(cursor, interner)?;
+                lhs = cursor.spanned(start, optional).into();
             }
         }
 
-        Ok(lhs)
+        Ok(cursor.fill_span(start, lhs))
     }
 }
@@ -5,7 +5,7 @@ use boa_ast::{
         access::PropertyAccessField, literal::Literal, Identifier, Optional, OptionalOperation,
         OptionalOperationKind,
     },
-    Expression, Statement,
+    Statement,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -56,7 +56,7 @@ fn complex_chain() {
                     ),
                     OptionalOperation::new(
                         OptionalOperationKind::Call {
-                            args: vec![Expression::Literal(Literal::Bool(true))].into(),
+                            args: vec![Literal::Bool(true).into()].into(),
                         },
                         false,
                     ),
@@ -84,17 +84,19 @@ macro_rules! expression {
                     cursor.set_goal($goal.unwrap());
                 }
 
+                let start = cursor.start_position(interner)?;
                 let mut lhs = $lower::new($( self.$low_param ),*).parse(cursor, interner)?;
                 self.name = None;
                 while let Some(tok) = cursor.peek(0, interner)? {
                     match *tok.kind() {
                         TokenKind::Punctuator(op) if $( op == $op )||* => {
                             cursor.advance(interner);
-                            lhs = Binary::new(
+                            let rhs = $lower::new($( self.$low_param ),*).parse(cursor, interner)?;
+                            lhs = cursor.spanned(start, Binary::new(
                                 op.as_binary_op().expect("Could not get binary operation."),
                                 lhs,
-                                $lower::new($( self.$low_param ),*).parse(cursor, interner)?
-                            ).into();
+                                rhs,
+                            )).into();
                         }
                         _ => break
                     }
@@ -153,6 +155,9 @@ where
     ) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Expression", "Parsing");
 
+        // The assignment expression sets this goal before peeking its first token anyway.
+        cursor.set_goal(InputElement::RegExp);
+        let start = cursor.start_position(interner)?;
         let mut lhs =
             AssignmentExpression::new(self.name, self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
@@ -174,20 +179,25 @@ where
 
                     cursor.advance(interner);
 
-                    lhs = Binary::new(
-                        Punctuator::Comma
-                            .as_binary_op()
-                            .expect("Could not get binary operation."),
-                        lhs,
-                        AssignmentExpression::new(
-                            self.name,
-                            self.allow_in,
-                            self.allow_yield,
-                            self.allow_await,
-                        )
-                        .parse(cursor, interner)?,
+                    let rhs = AssignmentExpression::new(
+                        self.name,
+                        self.allow_in,
+                        self.allow_yield,
+                        self.allow_await,
                     )
-                    .into();
+                    .parse(cursor, interner)?;
+                    lhs = cursor
+                        .spanned(
+                            start,
+                            Binary::new(
+                                Punctuator::Comma
+                                    .as_binary_op()
+                                    .expect("Could not get binary operation."),
+                                lhs,
+                                rhs,
+                            ),
+                        )
+                        .into();
                 }
                 _ => break,
             }
@@ -271,6 +281,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ShortCircuitExpression", "Parsing");
 
+        let start = cursor.start_position(interner)?;
         let mut current_node =
             BitwiseORExpression::new(self.name, self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
@@ -296,8 +307,12 @@ where
                     )
                     .parse(cursor, interner)?;
 
-                    current_node =
-                        Binary::new(BinaryOp::Logical(LogicalOp::And), current_node, rhs).into();
+                    current_node = cursor
+                        .spanned(
+                            start,
+                            Binary::new(BinaryOp::Logical(LogicalOp::And), current_node, rhs),
+                        )
+                        .into();
                 }
                 TokenKind::Punctuator(Punctuator::BoolOr) => {
                     if previous == PreviousExpr::Coalesce {
@@ -317,8 +332,12 @@ where
                         PreviousExpr::Logical,
                     )
                     .parse(cursor, interner)?;
-                    current_node =
-                        Binary::new(BinaryOp::Logical(LogicalOp::Or), current_node, rhs).into();
+                    current_node = cursor
+                        .spanned(
+                            start,
+                            Binary::new(BinaryOp::Logical(LogicalOp::Or), current_node, rhs),
+                        )
+                        .into();
                 }
                 TokenKind::Punctuator(Punctuator::Coalesce) => {
                     if previous == PreviousExpr::Logical {
@@ -338,9 +357,12 @@ where
                         self.allow_await,
                     )
                     .parse(cursor, interner)?;
-                    current_node =
-                        Binary::new(BinaryOp::Logical(LogicalOp::Coalesce), current_node, rhs)
-                            .into();
+                    current_node = cursor
+                        .spanned(
+                            start,
+                            Binary::new(BinaryOp::Logical(LogicalOp::Coalesce), current_node, rhs),
+                        )
+                        .into();
                 }
                 _ => break,
             }
@@ -565,6 +587,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Relation Expression", "Parsing");
 
+        let start = cursor.start_position(interner)?;
         if self.allow_in.0 {
             let token = cursor.peek(0, interner).or_abrupt()?;
             if let TokenKind::PrivateIdentifier(identifier) = token.kind() {
@@ -585,7 +608,12 @@ where
                             ShiftExpression::new(self.name, self.allow_yield, self.allow_await)
                                 .parse(cursor, interner)?;
 
-                        return Ok(BinaryInPrivate::new(PrivateName::new(identifier), rhs).into());
+                        return Ok(cursor
+                            .spanned(
+                                start,
+                                BinaryInPrivate::new(PrivateName::new(identifier), rhs),
+                            )
+                            .into());
                     }
                     _ => {}
                 }
@@ -604,13 +632,18 @@ where
                         || op == Punctuator::GreaterThanOrEq =>
                 {
                     cursor.advance(interner);
-                    lhs = Binary::new(
-                        op.as_binary_op().expect("Could not get binary operation."),
-                        lhs,
-                        ShiftExpression::new(self.name, self.allow_yield, self.allow_await)
-                            .parse(cursor, interner)?,
-                    )
-                    .into();
+                    let rhs = ShiftExpression::new(self.name, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                    lhs = cursor
+                        .spanned(
+                            start,
+                            Binary::new(
+                                op.as_binary_op().expect("Could not get binary operation."),
+                                lhs,
+                                rhs,
+                            ),
+                        )
+                        .into();
                 }
                 TokenKind::Keyword((Keyword::InstanceOf | Keyword::In, true)) => {
                     return Err(Error::general(
@@ -623,13 +656,18 @@ where
                         || (op == Keyword::In && self.allow_in == AllowIn(true)) =>
                 {
                     cursor.advance(interner);
-                    lhs = Binary::new(
-                        op.as_binary_op().expect("Could not get binary operation."),
-                        lhs,
-                        ShiftExpression::new(self.name, self.allow_yield, self.allow_await)
-                            .parse(cursor, interner)?,
-                    )
-                    .into();
+                    let rhs = ShiftExpression::new(self.name, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                    lhs = cursor
+                        .spanned(
+                            start,
+                            Binary::new(
+                                op.as_binary_op().expect("Could not get binary operation."),
+                                lhs,
+                                rhs,
+                            ),
+                        )
+                        .into();
                 }
                 _ => break,
             }
@@ -98,11 +98,12 @@ where
                     ));
                 }
                 TokenKind::Punctuator(Punctuator::Spread) => {
+                    let start = token.span().start();
                     cursor.advance(interner);
                     let node =
                         AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?;
-                    elements.push(Some(Spread::new(node).into()));
+                    elements.push(Some(cursor.spanned(start, Spread::new(node)).into()));
                     next_comma = true;
                     last_spread = true;
                 }
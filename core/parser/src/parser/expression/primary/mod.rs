@@ -106,7 +106,7 @@ where
         let tok = cursor.peek(0, interner).or_abrupt()?;
         let tok_position = tok.span().start();
 
-        match tok.kind() {
+        let expr = match tok.kind() {
             TokenKind::Keyword((Keyword::This, true))
             | TokenKind::BooleanLiteral((_, ContainsEscapeSequence(true)))
             | TokenKind::NullLiteral(ContainsEscapeSequence(true)) => Err(Error::general(
@@ -282,7 +282,9 @@ where
                 tok.span(),
                 "primary expression",
             )),
-        }
+        }?;
+
+        Ok(cursor.fill_span(tok_position, expr))
     }
 }
 
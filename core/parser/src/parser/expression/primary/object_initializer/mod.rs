@@ -36,7 +36,7 @@ use boa_ast::{
         bound_names, contains, has_direct_super, lexically_declared_names, ContainsSymbol,
     },
     property::{self, MethodDefinition},
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, Punctuator, Spanned,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -90,9 +90,14 @@ where
 
             let position = cursor.peek(0, interner).or_abrupt()?.span().start();
 
-            let property = PropertyDefinition::new(self.allow_yield, self.allow_await)
+            let mut property = PropertyDefinition::new(self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
+            // The source text of a method includes its `get`, `set`, `async` or `*` prefix.
+            if let property::PropertyDefinition::MethodDefinition(_, method) = &mut property {
+                method.set_span(cursor.span_from(position));
+            }
+
             if matches!(
                 property,
                 property::PropertyDefinition::Property(
@@ -595,9 +600,9 @@ where
                 (*name).into()
             }
             TokenKind::NumericLiteral(num) => match num {
-                Numeric::Rational(num) => Expression::Literal(Literal::from(*num)).into(),
-                Numeric::Integer(num) => Expression::Literal(Literal::from(*num)).into(),
-                Numeric::BigInt(num) => Expression::Literal(Literal::from(num.clone())).into(),
+                Numeric::Rational(num) => Expression::from(Literal::from(*num)).into(),
+                Numeric::Integer(num) => Expression::from(Literal::from(*num)).into(),
+                Numeric::BigInt(num) => Expression::from(Literal::from(num.clone())).into(),
             },
             TokenKind::Keyword((word, _)) => {
                 let (utf8, utf16) = word.as_str();
@@ -1,4 +1,7 @@
-use crate::parser::tests::{check_invalid_script, check_script_parser};
+use crate::{
+    parser::tests::{check_invalid_script, check_script_parser},
+    Parser, Source,
+};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable},
     expression::{
@@ -633,6 +636,21 @@ fn check_relational_operations() {
     );
 }
 
+/// Checks private name `in` brand checks.
+#[test]
+fn check_private_name_in_relational() {
+    check_invalid_script("#x in o");
+    check_invalid_script("function f(o) { return #x in o; }");
+    check_invalid_script("class C { #x; m(o) { return #y in o; } }");
+    check_invalid_script("class C { #x; m(o) { return o in #x; } }");
+    check_invalid_script("class C { #x; m(o) { return #x; } }");
+    assert!(Parser::new(Source::from_bytes(
+        "class C { #x; m(o) { return #x in o && (#x in o) in o; } }"
+    ))
+    .parse_script(&mut Interner::default())
+    .is_ok());
+}
+
 #[test]
 fn check_logical_expressions() {
     let interner = &mut Interner::default();
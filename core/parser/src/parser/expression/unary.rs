@@ -67,6 +67,20 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("UnaryExpression", "Parsing");
 
+        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        cursor.enter_nesting(position)?;
+        let result = self.parse_unary(cursor, interner);
+        cursor.exit_nesting();
+        result.map(|expr| cursor.fill_span(position, expr))
+    }
+}
+
+impl UnaryExpression {
+    fn parse_unary<R: ReadChar>(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<Expression> {
         let tok = cursor.peek(0, interner).or_abrupt()?;
         let token_start = tok.span().start();
         match tok.kind() {
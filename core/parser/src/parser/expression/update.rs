@@ -113,7 +113,11 @@ where
                             position,
                         )))
                     },
-                    |target| Ok(Update::new(UpdateOp::IncrementPre, target).into()),
+                    |target| {
+                        Ok(cursor
+                            .spanned(position, Update::new(UpdateOp::IncrementPre, target))
+                            .into())
+                    },
                 );
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
@@ -132,7 +136,11 @@ where
                             position,
                         )))
                     },
-                    |target| Ok(Update::new(UpdateOp::DecrementPre, target).into()),
+                    |target| {
+                        Ok(cursor
+                            .spanned(position, Update::new(UpdateOp::DecrementPre, target))
+                            .into())
+                    },
                 );
             }
             _ => {}
@@ -161,7 +169,11 @@ where
                                 token_start,
                             )))
                         },
-                        |target| Ok(Update::new(UpdateOp::IncrementPost, target).into()),
+                        |target| {
+                            Ok(cursor
+                                .spanned(position, Update::new(UpdateOp::IncrementPost, target))
+                                .into())
+                        },
                     );
                 }
                 TokenKind::Punctuator(Punctuator::Dec) => {
@@ -177,7 +189,11 @@ where
                                 token_start,
                             )))
                         },
-                        |target| Ok(Update::new(UpdateOp::DecrementPost, target).into()),
+                        |target| {
+                            Ok(cursor
+                                .spanned(position, Update::new(UpdateOp::DecrementPost, target))
+                                .into())
+                        },
                     );
                 }
                 _ => {}
@@ -9,6 +9,8 @@ pub(crate) mod function;
 #[cfg(test)]
 mod tests;
 
+pub use cursor::DEFAULT_NESTING_LIMIT;
+
 use crate::{
     error::ParseResult,
     lexer::Error as LexError,
@@ -16,7 +18,7 @@ use crate::{
         cursor::Cursor,
         function::{FormalParameters, FunctionStatementList},
     },
-    source::ReadChar,
+    source::{ReadChar, SourceText},
     Error, Source,
 };
 use boa_ast::{
@@ -26,7 +28,7 @@ use boa_ast::{
         all_private_identifiers_valid, check_labels, contains, contains_invalid_object_literal,
         lexically_declared_names, var_declared_names, ContainsSymbol,
     },
-    Position, StatementList,
+    Declaration, Position, Span, Spanned, StatementList, StatementListItem,
 };
 use boa_interner::Interner;
 use rustc_hash::FxHashSet;
@@ -116,10 +118,12 @@ impl From<bool> for AllowDefault {
 #[derive(Debug)]
 pub struct Parser<'a, R> {
     /// Path to the source being parsed.
-    #[allow(unused)] // Good to have for future improvements.
     path: Option<&'a Path>,
     /// Cursor of the parser, pointing to the lexer and used to get tokens for the parser.
     cursor: Cursor<R>,
+    /// Whether to allocate in the [arena][crate::arena] while parsing.
+    #[cfg(feature = "arena")]
+    arena: bool,
 }
 
 impl<'a, R: ReadChar> Parser<'a, R> {
@@ -128,9 +132,20 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         Self {
             path: source.path,
             cursor: Cursor::new(source.reader),
+            #[cfg(feature = "arena")]
+            arena: false,
         }
     }
 
+    /// Runs `parse` on the cursor of the parser, in the arena if it is enabled.
+    fn run<T>(&mut self, parse: impl FnOnce(&mut Cursor<R>) -> T) -> T {
+        #[cfg(feature = "arena")]
+        if self.arena {
+            return crate::arena::with_arena(|| parse(&mut self.cursor));
+        }
+        parse(&mut self.cursor)
+    }
+
     /// Parse the full input as a [ECMAScript Script][spec] into the boa AST representation.
     /// The resulting `Script` can be compiled into boa bytecode and executed in the boa vm.
     ///
@@ -140,7 +155,32 @@ impl<'a, R: ReadChar> Parser<'a, R> {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-Script
     pub fn parse_script(&mut self, interner: &mut Interner) -> ParseResult<boa_ast::Script> {
-        ScriptParser::new(false).parse(&mut self.cursor, interner)
+        self.run(|cursor| ScriptParser::new(false).parse(cursor, interner))
+    }
+
+    /// Parse the full input as a [ECMAScript Script][spec], recovering from syntax errors.
+    ///
+    /// Instead of failing on the first syntax error, the parser records it, skips to the next
+    /// statement boundary and replaces the statement that failed to parse with a
+    /// [`Statement::Error`][boa_ast::Statement::Error] placeholder. This returns the partial
+    /// `Script` together with every error found, which is useful for tooling like editors.
+    ///
+    /// A `Script` recovered from errors can be inspected and visited, but it must never be
+    /// compiled or executed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-Script
+    pub fn parse_script_with_recovery(
+        &mut self,
+        interner: &mut Interner,
+    ) -> (boa_ast::Script, Vec<Error>) {
+        self.cursor.set_error_recovery();
+        let result = self.run(|cursor| ScriptParser::new(false).parse(cursor, interner));
+        let mut errors = self.cursor.take_recovered_errors();
+        let script = result.unwrap_or_else(|error| {
+            errors.push(error);
+            boa_ast::Script::default()
+        });
+        (script, errors)
     }
 
     /// Parse the full input as an [ECMAScript Module][spec] into the boa AST representation.
@@ -155,7 +195,7 @@ impl<'a, R: ReadChar> Parser<'a, R> {
     where
         R: ReadChar,
     {
-        ModuleParser.parse(&mut self.cursor, interner)
+        self.run(|cursor| ModuleParser.parse(cursor, interner))
     }
 
     /// [`19.2.1.1 PerformEval ( x, strictCaller, direct )`][spec]
@@ -172,7 +212,7 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         direct: bool,
         interner: &mut Interner,
     ) -> ParseResult<boa_ast::Script> {
-        ScriptParser::new(direct).parse(&mut self.cursor, interner)
+        self.run(|cursor| ScriptParser::new(direct).parse(cursor, interner))
     }
 
     /// Parses the full input as an [ECMAScript `FunctionBody`][spec] into the boa AST representation.
@@ -188,7 +228,9 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         allow_yield: bool,
         allow_await: bool,
     ) -> ParseResult<FunctionBody> {
-        FunctionStatementList::new(allow_yield, allow_await).parse(&mut self.cursor, interner)
+        self.run(|cursor| {
+            FunctionStatementList::new(allow_yield, allow_await).parse(cursor, interner)
+        })
     }
 
     /// Parses the full input as an [ECMAScript `FormalParameterList`][spec] into the boa AST representation.
@@ -204,11 +246,25 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         allow_yield: bool,
         allow_await: bool,
     ) -> ParseResult<FormalParameterList> {
-        FormalParameters::new(allow_yield, allow_await).parse(&mut self.cursor, interner)
+        self.run(|cursor| FormalParameters::new(allow_yield, allow_await).parse(cursor, interner))
+    }
+
+    /// Takes the source text read by the parser so far.
+    ///
+    /// After parsing a full input, this is the whole source text, which can be sliced with the
+    /// [`Span`][boa_ast::Span]s of the parsed nodes to recover their original source code.
+    pub fn take_source_text(&mut self) -> SourceText {
+        self.cursor.take_source_text()
     }
 }
 
-impl<R> Parser<'_, R> {
+impl<'a, R> Parser<'a, R> {
+    /// Gets the path of the source being parsed, if any.
+    #[must_use]
+    pub const fn path(&self) -> Option<&'a Path> {
+        self.path
+    }
+
     /// Set the parser strict mode to true.
     pub fn set_strict(&mut self)
     where
@@ -217,6 +273,16 @@ impl<R> Parser<'_, R> {
         self.cursor.set_strict(true);
     }
 
+    /// Allocate the parsed AST in the [arena][crate::arena].
+    ///
+    /// This replaces the millions of allocations made while parsing a large input with a few big
+    /// chunks, but it has no effect unless the [`ArenaAllocator`][crate::arena::ArenaAllocator]
+    /// is the global allocator.
+    #[cfg(feature = "arena")]
+    pub fn set_arena(&mut self) {
+        self.arena = true;
+    }
+
     /// Set the parser JSON mode to true.
     pub fn set_json_parse(&mut self)
     where
@@ -232,6 +298,17 @@ impl<R> Parser<'_, R> {
     {
         self.cursor.set_identifier(identifier);
     }
+
+    /// Set the maximum nesting depth of statements and expressions.
+    ///
+    /// Sources nested deeper than this limit produce a syntax error instead of overflowing the
+    /// native stack while parsing.
+    pub fn set_nesting_limit(&mut self, limit: usize)
+    where
+        R: ReadChar,
+    {
+        self.cursor.set_nesting_limit(limit);
+    }
 }
 
 /// Parses a full script.
@@ -260,6 +337,10 @@ where
     type Output = boa_ast::Script;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        if !cursor.json_parse() {
+            cursor.skip_hashbang(interner)?;
+        }
+
         let script = boa_ast::Script::new(
             ScriptBody::new(true, cursor.strict(), self.direct_eval).parse(cursor, interner)?,
         );
@@ -268,20 +349,22 @@ where
         let mut lexical_names = FxHashSet::default();
         for name in lexically_declared_names(&script) {
             if !lexical_names.insert(name) {
-                return Err(Error::general(
+                cursor.record_error(Error::general(
                     "lexical name declared multiple times",
                     Position::new(1, 1),
-                ));
+                ))?;
+                break;
             }
         }
 
         // It is a Syntax Error if any element of the LexicallyDeclaredNames of ScriptBody also occurs in the VarDeclaredNames of ScriptBody.
         for name in var_declared_names(&script) {
             if lexical_names.contains(&name) {
-                return Err(Error::general(
+                cursor.record_error(Error::general(
                     "lexical name declared multiple times",
                     Position::new(1, 1),
-                ));
+                ))?;
+                break;
             }
         }
 
@@ -336,41 +419,56 @@ where
             // code that is being processed by a direct eval.
             // Additional early error rules for super within direct eval are defined in 19.2.1.1.
             if contains(&body, ContainsSymbol::Super) {
-                return Err(Error::general("invalid super usage", Position::new(1, 1)));
+                cursor.record_error(Error::general("invalid super usage", Position::new(1, 1)))?;
             }
             // It is a Syntax Error if StatementList Contains NewTarget unless the source text containing NewTarget
             // is eval code that is being processed by a direct eval.
             // Additional early error rules for NewTarget in direct eval are defined in 19.2.1.1.
             if contains(&body, ContainsSymbol::NewTarget) {
-                return Err(Error::general(
+                cursor.record_error(Error::general(
                     "invalid new.target usage",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
 
             // It is a Syntax Error if AllPrivateIdentifiersValid of StatementList with
             // argument « » is false unless the source text containing ScriptBody is
             // eval code that is being processed by a direct eval.
             if !all_private_identifiers_valid(&body, Vec::new()) {
-                return Err(Error::general(
+                cursor.record_error(Error::general(
                     "invalid private identifier usage",
                     Position::new(1, 1),
-                ));
+                ))?;
             }
         }
 
         if let Err(error) = check_labels(&body) {
-            return Err(Error::lex(LexError::Syntax(
+            cursor.record_error(Error::lex(LexError::Syntax(
                 error.message(interner).into(),
                 Position::new(1, 1),
-            )));
+            )))?;
         }
 
         if contains_invalid_object_literal(&body) {
-            return Err(Error::lex(LexError::Syntax(
+            cursor.record_error(Error::lex(LexError::Syntax(
                 "invalid object literal in script statement list".into(),
                 Position::new(1, 1),
-            )));
+            )))?;
+        }
+
+        // It is a Syntax Error if the goal symbol is Script and UsingDeclaration is not contained,
+        // either directly or indirectly, within a Block, CaseBlock, ForStatement,
+        // ForInOfStatement, FunctionBody, GeneratorBody, AsyncGeneratorBody, AsyncFunctionBody,
+        // ClassStaticBlockBody, or ClassBody.
+        for item in body.statements() {
+            if let StatementListItem::Declaration(Declaration::Lexical(declaration)) = item {
+                if declaration.is_using() {
+                    cursor.record_error(Error::general(
+                        "using declarations are not allowed at the top level of a script",
+                        item.span().map_or(Position::new(1, 1), Span::start),
+                    ))?;
+                }
+            }
         }
 
         Ok(body)
@@ -394,6 +492,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         cursor.set_module();
+        cursor.skip_hashbang(interner)?;
 
         let module = boa_ast::Module::new(ModuleItemList.parse(cursor, interner)?);
 
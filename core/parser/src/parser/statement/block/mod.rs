@@ -77,11 +77,14 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Block", "Parsing");
-        cursor.expect(Punctuator::OpenBlock, "block", interner)?;
+        let start = cursor
+            .expect(Punctuator::OpenBlock, "block", interner)?
+            .span()
+            .start();
         if let Some(tk) = cursor.peek(0, interner)? {
             if tk.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) {
                 cursor.advance(interner);
-                return Ok(statement::Block::from(vec![]));
+                return Ok(cursor.spanned(start, statement::Block::from(vec![])));
             }
         }
         let position = cursor.peek(0, interner).or_abrupt()?.span().start();
@@ -126,6 +129,6 @@ where
             }
         }
 
-        Ok(statement_list)
+        Ok(cursor.spanned(start, statement_list))
     }
 }
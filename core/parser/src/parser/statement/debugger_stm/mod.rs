@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    parser::{Cursor, ParseResult, TokenParser},
+    source::ReadChar,
+};
+use boa_ast::{statement::Debugger, Keyword};
+use boa_interner::Interner;
+use boa_profiler::Profiler;
+
+/// Debugger statement parsing
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/debugger
+/// [spec]: https://tc39.es/ecma262/#prod-DebuggerStatement
+#[derive(Debug, Clone, Copy)]
+pub(super) struct DebuggerStatement;
+
+impl<R> TokenParser<R> for DebuggerStatement
+where
+    R: ReadChar,
+{
+    type Output = Debugger;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let _timer = Profiler::global().start_event("DebuggerStatement", "Parsing");
+        cursor.expect((Keyword::Debugger, false), "debugger statement", interner)?;
+        cursor.expect_semicolon("debugger statement", interner)?;
+
+        Ok(Debugger::new())
+    }
+}
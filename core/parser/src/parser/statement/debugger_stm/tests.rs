@@ -0,0 +1,33 @@
+use crate::parser::tests::{check_invalid_script, check_script_parser};
+use boa_ast::{
+    statement::{Block, Debugger},
+    Statement,
+};
+use boa_interner::Interner;
+
+#[test]
+fn debugger_statement() {
+    check_script_parser(
+        "debugger;",
+        vec![Statement::Debugger(Debugger::new()).into()],
+        &mut Interner::default(),
+    );
+}
+
+#[test]
+fn debugger_statement_asi() {
+    check_script_parser(
+        "{ debugger\ndebugger }",
+        vec![Statement::Block(Block::from(vec![
+            Statement::Debugger(Debugger::new()).into(),
+            Statement::Debugger(Debugger::new()).into(),
+        ]))
+        .into()],
+        &mut Interner::default(),
+    );
+}
+
+#[test]
+fn debugger_statement_same_line() {
+    check_invalid_script("debugger debugger");
+}
@@ -79,6 +79,7 @@ where
     type Output = AsyncFunction;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let start = cursor.start_position(interner)?;
         cursor.expect(
             (Keyword::Async, false),
             "async function declaration",
@@ -93,11 +94,9 @@ where
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(AsyncFunction::new(
-            Some(result.0),
-            result.1,
-            result.2,
-            false,
+        Ok(cursor.spanned(
+            start,
+            AsyncFunction::new(Some(result.0), result.1, result.2, false),
         ))
     }
 }
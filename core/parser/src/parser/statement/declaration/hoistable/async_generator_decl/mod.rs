@@ -92,6 +92,7 @@ where
     type Output = AsyncGenerator;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let start = cursor.start_position(interner)?;
         cursor.expect(
             (Keyword::Async, false),
             "async generator declaration",
@@ -107,11 +108,9 @@ where
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(AsyncGenerator::new(
-            Some(result.0),
-            result.1,
-            result.2,
-            false,
+        Ok(cursor.spanned(
+            start,
+            AsyncGenerator::new(Some(result.0), result.1, result.2, false),
         ))
     }
 }
@@ -27,7 +27,7 @@ use boa_ast::{
     function::{self, Class, FormalParameterList, Function},
     operations::{contains, contains_arguments, has_direct_super, ContainsSymbol},
     property::{ClassElementName, MethodDefinition},
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, Punctuator, Spanned,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -71,6 +71,7 @@ where
     type Output = Class;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let start = cursor.start_position(interner)?;
         cursor.expect((Keyword::Class, false), "class declaration", interner)?;
         let strict = cursor.strict();
         cursor.set_strict(true);
@@ -95,13 +96,15 @@ where
         };
         cursor.set_strict(strict);
 
-        ClassTail::new(
+        let class = ClassTail::new(
             name,
             has_binding_identifier,
             self.allow_yield,
             self.allow_await,
         )
-        .parse(cursor, interner)
+        .parse(cursor, interner)?;
+
+        Ok(cursor.spanned(start, class))
     }
 }
 
@@ -622,7 +625,7 @@ where
 
         let token = cursor.peek(0, interner).or_abrupt()?;
         let position = token.span().start();
-        let element = match token.kind() {
+        let mut element = match token.kind() {
             TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
                 cursor.advance(interner);
                 let strict = cursor.strict();
@@ -1364,6 +1367,17 @@ where
             _ => return Err(Error::general("unexpected token", token.span().start())),
         };
 
+        // The source text of a method starts after the `static` keyword.
+        match &mut element {
+            function::ClassElement::MethodDefinition(_, method)
+            | function::ClassElement::StaticMethodDefinition(_, method)
+            | function::ClassElement::PrivateMethodDefinition(_, method)
+            | function::ClassElement::PrivateStaticMethodDefinition(_, method) => {
+                method.set_span(cursor.span_from(position));
+            }
+            _ => {}
+        }
+
         match &element {
             // FieldDefinition : ClassElementName Initializer [opt]
             // It is a Syntax Error if Initializer is present and ContainsArguments of Initializer is true.
@@ -1,4 +1,4 @@
-use crate::parser::tests::check_script_parser;
+use crate::parser::tests::{check_invalid_script, check_script_parser};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable, VariableList},
     expression::{
@@ -8,7 +8,7 @@ use boa_ast::{
     },
     function::{Class, ClassElement, FormalParameterList, Function, FunctionBody},
     property::{MethodDefinition, PropertyName},
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Script, Statement, StatementList, StatementListItem,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -171,3 +171,47 @@ fn check_new_target_with_property_access() {
         interner,
     );
 }
+
+#[test]
+fn check_static_block() {
+    let interner = &mut Interner::default();
+
+    let body = Statement::Expression(
+        Call::new(
+            Identifier::new(interner.get_or_intern_static("init", utf16!("init"))).into(),
+            Box::default(),
+        )
+        .into(),
+    );
+
+    check_script_parser(
+        "class A {
+            static { init(); }
+         }
+        ",
+        [Declaration::Class(Class::new(
+            Some(interner.get_or_intern_static("A", utf16!("A")).into()),
+            None,
+            None,
+            vec![ClassElement::StaticBlock(Script::new(StatementList::new(
+                [body.into()],
+                true,
+            )))]
+            .into(),
+            true,
+        ))
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_static_block_early_errors() {
+    check_invalid_script("class A { static { arguments; } }");
+    check_invalid_script("class A { static { await; } }");
+    check_invalid_script("class A { static { var await; } }");
+    check_invalid_script("async function f() { class A { static { await 1; } } }");
+    check_invalid_script("class A { static { return; } }");
+    check_invalid_script("class A { static { super(); } }");
+    check_invalid_script("class A { static { let a; var a; } }");
+}
@@ -77,10 +77,11 @@ where
     type Output = Function;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let start = cursor.start_position(interner)?;
         cursor.expect((Keyword::Function, false), "function declaration", interner)?;
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(Function::new(Some(result.0), result.1, result.2))
+        Ok(cursor.spanned(start, Function::new(Some(result.0), result.1, result.2)))
     }
 }
@@ -79,6 +79,7 @@ where
     type Output = Generator;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let start = cursor.start_position(interner)?;
         cursor.expect(
             (Keyword::Function, false),
             "generator declaration",
@@ -88,6 +89,9 @@ where
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(Generator::new(Some(result.0), result.1, result.2, false))
+        Ok(cursor.spanned(
+            start,
+            Generator::new(Some(result.0), result.1, result.2, false),
+        ))
     }
 }
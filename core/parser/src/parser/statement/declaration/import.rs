@@ -155,6 +155,7 @@ where
         };
 
         let module_identifier = FromClause::new("import declaration").parse(cursor, interner)?;
+        cursor.expect_semicolon("import declaration", interner)?;
 
         Ok(import_clause.with_specifier(module_identifier))
     }
@@ -1,11 +1,13 @@
 //! Lexical declaration parsing.
 //!
-//! This parses `let` and `const` declarations.
+//! This parses `let`, `const`, `using` and `await using` declarations.
 //!
 //! More information:
 //!  - [ECMAScript specification][spec]
+//!  - [Explicit resource management proposal][proposal]
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
+//! [proposal]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations
 
 use crate::{
     lexer::{Error as LexError, TokenKind},
@@ -81,7 +83,7 @@ where
                 self.allow_in,
                 self.allow_yield,
                 self.allow_await,
-                true,
+                BindingListKind::Const,
                 self.loop_init,
             )
             .parse(cursor, interner)?,
@@ -89,10 +91,33 @@ where
                 self.allow_in,
                 self.allow_yield,
                 self.allow_await,
-                false,
+                BindingListKind::Let,
                 self.loop_init,
             )
             .parse(cursor, interner)?,
+            TokenKind::IdentifierName((Sym::USING, _)) => BindingList::new(
+                self.allow_in,
+                self.allow_yield,
+                self.allow_await,
+                BindingListKind::Using,
+                self.loop_init,
+            )
+            .parse(cursor, interner)?,
+            TokenKind::Keyword((Keyword::Await, _)) => {
+                cursor.expect(
+                    TokenKind::identifier(Sym::USING),
+                    "await using declaration",
+                    interner,
+                )?;
+                BindingList::new(
+                    self.allow_in,
+                    self.allow_yield,
+                    self.allow_await,
+                    BindingListKind::AwaitUsing,
+                    self.loop_init,
+                )
+                .parse(cursor, interner)?
+            }
             _ => unreachable!("unknown token found: {:?}", tok),
         };
 
@@ -119,14 +144,35 @@ where
             }
         }
 
-        Ok(lexical_declaration)
+        Ok(cursor.spanned(tok.span().start(), lexical_declaration))
+    }
+}
+
+/// The kind of declaration a [`BindingList`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BindingListKind {
+    Let,
+    Const,
+    Using,
+    AwaitUsing,
+}
+
+impl BindingListKind {
+    /// Returns `true` if the bindings must be initialized.
+    const fn requires_initializer(self) -> bool {
+        !matches!(self, Self::Let)
+    }
+
+    /// Returns `true` if the bindings must be identifiers.
+    const fn is_using(self) -> bool {
+        matches!(self, Self::Using | Self::AwaitUsing)
     }
 }
 
 /// Parses a binding list.
 ///
-/// It will return an error if a `const` declaration is being parsed and there is no
-/// initializer.
+/// It will return an error if a `const` or `using` declaration is being parsed and there is no
+/// initializer, or if a `using` declaration contains a binding pattern.
 ///
 /// More information:
 ///  - [ECMAScript specification][spec]
@@ -137,7 +183,7 @@ struct BindingList {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    is_const: bool,
+    kind: BindingListKind,
     loop_init: bool,
 }
 
@@ -147,7 +193,7 @@ impl BindingList {
         allow_in: I,
         allow_yield: Y,
         allow_await: A,
-        is_const: bool,
+        kind: BindingListKind,
         loop_init: bool,
     ) -> Self
     where
@@ -159,7 +205,7 @@ impl BindingList {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            is_const,
+            kind,
             loop_init,
         }
     }
@@ -179,20 +225,35 @@ where
         let mut decls = Vec::new();
 
         loop {
+            if self.kind.is_using() {
+                let next = cursor.peek(0, interner).or_abrupt()?;
+                if matches!(
+                    next.kind(),
+                    TokenKind::Punctuator(Punctuator::OpenBlock | Punctuator::OpenBracket)
+                ) {
+                    return Err(Error::general(
+                        "using declarations cannot contain binding patterns",
+                        next.span().start(),
+                    ));
+                }
+            }
+
             let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
-            if self.is_const {
+            if self.kind.requires_initializer() {
                 let init_is_some = decl.init().is_some();
 
                 if init_is_some || self.loop_init {
                     decls.push(decl);
                 } else {
                     let next = cursor.next(interner).or_abrupt()?;
-                    return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        next.span().start(),
-                    ));
+                    let message = if self.kind.is_using() {
+                        "Expected initializer for using declaration"
+                    } else {
+                        "Expected initializer for const declaration"
+                    };
+                    return Err(Error::general(message, next.span().start()));
                 }
             } else {
                 decls.push(decl);
@@ -238,11 +299,12 @@ where
             .try_into()
             .expect("`LexicalBinding` must return at least one variable");
 
-        if self.is_const {
-            Ok(ast::declaration::LexicalDeclaration::Const(decls))
-        } else {
-            Ok(ast::declaration::LexicalDeclaration::Let(decls))
-        }
+        Ok(match self.kind {
+            BindingListKind::Let => ast::declaration::LexicalDeclaration::Let(decls),
+            BindingListKind::Const => ast::declaration::LexicalDeclaration::Const(decls),
+            BindingListKind::Using => ast::declaration::LexicalDeclaration::Using(decls),
+            BindingListKind::AwaitUsing => ast::declaration::LexicalDeclaration::AwaitUsing(decls),
+        })
     }
 }
 
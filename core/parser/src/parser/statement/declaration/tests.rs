@@ -1,8 +1,19 @@
-use crate::parser::tests::{check_invalid_script, check_script_parser};
+use crate::{
+    parser::tests::{check_invalid_script, check_script_parser},
+    Parser, Source,
+};
 use boa_ast::{
     declaration::{LexicalDeclaration, VarDeclaration, Variable},
-    expression::literal::Literal,
-    Declaration, Statement,
+    expression::{
+        literal::Literal,
+        operator::{
+            assign::{AssignOp, AssignTarget},
+            Assign,
+        },
+        Identifier,
+    },
+    statement::Block,
+    Declaration, Statement, StatementListItem,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -360,3 +371,103 @@ fn lexical_declaration_early_errors() {
     check_invalid_script("for (let a = 0, a = 0; ; ) {}");
     check_invalid_script("for (const a = 0, a = 0; ; ) {}");
 }
+
+/// Checks `using` declaration parsing.
+#[test]
+fn using_declaration() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    let b = interner.get_or_intern_static("b", utf16!("b"));
+    check_script_parser(
+        "{ using a = b; }",
+        vec![Statement::Block(Block::from(vec![Declaration::Lexical(
+            LexicalDeclaration::Using(
+                vec![Variable::from_identifier(
+                    a.into(),
+                    Some(Identifier::new(b).into()),
+                )]
+                .try_into()
+                .unwrap(),
+            ),
+        )
+        .into()]))
+        .into()],
+        interner,
+    );
+}
+
+/// Checks `await using` declaration parsing.
+#[test]
+fn await_using_declaration() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    let b = interner.get_or_intern_static("b", utf16!("b"));
+    let script = Parser::new(Source::from_bytes(
+        "async function f() { await using a = b; }",
+    ))
+    .parse_script(interner)
+    .expect("failed to parse");
+    let Some(StatementListItem::Declaration(Declaration::AsyncFunction(function))) =
+        script.statements().first()
+    else {
+        panic!("expected an async function declaration");
+    };
+    assert_eq!(
+        function.body().statements().statements(),
+        &[Declaration::Lexical(LexicalDeclaration::AwaitUsing(
+            vec![Variable::from_identifier(
+                a.into(),
+                Some(Identifier::new(b).into()),
+            )]
+            .try_into()
+            .unwrap(),
+        ))
+        .into()]
+    );
+}
+
+/// Checks that `using` is still parsed as an identifier when it doesn't start a declaration.
+#[test]
+fn using_identifier() {
+    let interner = &mut Interner::default();
+    let using = interner.get_or_intern_static("using", utf16!("using"));
+    check_script_parser(
+        "using;",
+        vec![Statement::Expression(Identifier::new(using).into()).into()],
+        interner,
+    );
+
+    let interner = &mut Interner::default();
+    let using = interner.get_or_intern_static("using", utf16!("using"));
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    check_script_parser(
+        "{ using\na = 1; }",
+        vec![Statement::Block(Block::from(vec![
+            Statement::Expression(Identifier::new(using).into()).into(),
+            Statement::Expression(
+                Assign::new(
+                    AssignOp::Assign,
+                    AssignTarget::Identifier(Identifier::new(a)),
+                    Literal::from(1).into(),
+                )
+                .into(),
+            )
+            .into(),
+        ]))
+        .into()],
+        interner,
+    );
+}
+
+/// Checks `using` declaration early errors.
+#[test]
+fn using_declaration_early_errors() {
+    check_invalid_script("using a = b;");
+    check_invalid_script("{ using a; }");
+    check_invalid_script("{ using a = 0, a = 1; }");
+    check_invalid_script("{ using let = 0; }");
+    check_invalid_script("for (using a of b) {}");
+    check_invalid_script("for (using a = b; ; ) {}");
+    check_invalid_script("function f() { await using a = b; }");
+    check_invalid_script("async function f() { await using a; }");
+}
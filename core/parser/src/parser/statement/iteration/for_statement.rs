@@ -109,12 +109,10 @@ where
 
         let init = match cursor.peek(0, interner).or_abrupt()?.kind() {
             TokenKind::Keyword((Keyword::Var, _)) => {
-                cursor.advance(interner);
-                Some(
-                    VariableDeclarationList::new(false, self.allow_yield, self.allow_await)
-                        .parse(cursor, interner)?
-                        .into(),
-                )
+                let start = cursor.next(interner).or_abrupt()?.span().start();
+                let list = VariableDeclarationList::new(false, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                Some(cursor.spanned(start, list).into())
             }
             TokenKind::Keyword((Keyword::Let, _)) => Some('exit: {
                 if !cursor.strict() {
@@ -371,14 +369,22 @@ fn initializer_to_iterable_loop_initializer(
                         position,
                     )));
                 }
-                Ok(match decl {
-                    ast::declaration::LexicalDeclaration::Const(_) => {
-                        IterableLoopInitializer::Const(declaration.binding().clone())
-                    }
-                    ast::declaration::LexicalDeclaration::Let(_) => {
-                        IterableLoopInitializer::Let(declaration.binding().clone())
+                match decl {
+                    ast::declaration::LexicalDeclaration::Const(_) => Ok(
+                        IterableLoopInitializer::Const(declaration.binding().clone()),
+                    ),
+                    ast::declaration::LexicalDeclaration::Let(_) => Ok(
+                        IterableLoopInitializer::Let(declaration.binding().clone()),
+                    ),
+                    ast::declaration::LexicalDeclaration::Using(_)
+                    | ast::declaration::LexicalDeclaration::AwaitUsing(_) => {
+                        Err(Error::lex(LexError::Syntax(
+                            format!("using declarations are not supported in the head of a {loop_type} loop")
+                                .into(),
+                            position,
+                        )))
                     }
-                })
+                }
             }
             _ => Err(Error::lex(LexError::Syntax(
                 format!("only one variable can be declared in the head of a {loop_type} loop")
@@ -10,6 +10,7 @@
 mod block;
 mod break_stm;
 mod continue_stm;
+mod debugger_stm;
 mod declaration;
 mod expression;
 mod if_stm;
@@ -26,7 +27,8 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::{Declaration, ExportDeclaration, ImportDeclaration},
+    debugger_stm::DebuggerStatement,
+    declaration::{Declaration, ExportDeclaration, ImportDeclaration, LexicalDeclaration},
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -39,8 +41,12 @@ use self::{
     with::WithStatement,
 };
 use crate::{
-    lexer::{token::EscapeSequence, Error as LexError, InputElement, Token, TokenKind},
+    lexer::{
+        token::{ContainsEscapeSequence, EscapeSequence},
+        Error as LexError, InputElement, Token, TokenKind,
+    },
     parser::{
+        cursor::{is_lexing_error, RecoveryPoint},
         expression::{BindingIdentifier, Initializer, PropertyName},
         AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
@@ -56,7 +62,7 @@ use boa_ast::{
     pattern::{ArrayPattern, ArrayPatternElement, ObjectPatternElement},
     Keyword, Punctuator,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
 use boa_profiler::Profiler;
 
@@ -119,6 +125,21 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Statement", "Parsing");
+
+        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        cursor.enter_nesting(position)?;
+        let result = self.parse_statement(cursor, interner);
+        cursor.exit_nesting();
+        result.map(|statement| cursor.fill_span(position, statement))
+    }
+}
+
+impl Statement {
+    fn parse_statement<R: ReadChar>(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<ast::Statement> {
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
@@ -191,6 +212,9 @@ where
                     .parse(cursor, interner)
                     .map(ast::Statement::from)
             }
+            TokenKind::Keyword((Keyword::Debugger, _)) => DebuggerStatement
+                .parse(cursor, interner)
+                .map(ast::Statement::from),
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 BlockStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor, interner)
@@ -297,6 +321,7 @@ where
         let mut directives_stack = Vec::new();
 
         loop {
+            let point = cursor.recovery_point(interner)?;
             match cursor.peek(0, interner)? {
                 Some(token) if self.break_nodes.contains(token.kind()) => break,
                 Some(token) if directive_prologues => {
@@ -309,15 +334,32 @@ where
             }
 
             let item =
-                StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
-                    .parse(cursor, interner)?;
+                match StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor, interner)
+                {
+                    Ok(item) => item,
+                    Err(error) => {
+                        cursor.recover(point, error)?;
+                        synchronize(cursor, point, self.break_nodes, interner)?;
+                        items.push(ast::Statement::Error(cursor.span_from(point.start())).into());
+                        directive_prologues = false;
+                        continue;
+                    }
+                };
 
             if directive_prologues {
-                match &item {
+                let directive = match &item {
                     ast::StatementListItem::Statement(ast::Statement::Expression(
-                        ast::Expression::Literal(ast::expression::literal::Literal::String(string)),
-                    )) if !strict => {
-                        if interner.resolve_expect(*string).join(
+                        ast::Expression::Literal(lit),
+                    )) => match lit.literal() {
+                        ast::expression::literal::Literal::String(string) => Some(*string),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                match directive {
+                    Some(string) if !strict => {
+                        if interner.resolve_expect(string).join(
                             |s| s == "use strict",
                             |g| g == utf16!("use strict"),
                             true,
@@ -331,29 +373,25 @@ where
 
                             for (position, escape) in std::mem::take(&mut directives_stack) {
                                 if escape.contains(EscapeSequence::LEGACY_OCTAL) {
-                                    return Err(Error::general(
+                                    cursor.record_error(Error::general(
                                         "legacy octal escape sequences are not allowed in strict mode",
                                         position,
-                                    ));
+                                    ))?;
                                 }
 
                                 if escape.contains(EscapeSequence::NON_OCTAL_DECIMAL) {
-                                    return Err(Error::general(
+                                    cursor.record_error(Error::general(
                                         "decimal escape sequences are not allowed in strict mode",
                                         position,
-                                    ));
+                                    ))?;
                                 }
                             }
                         }
                     }
-                    ast::StatementListItem::Statement(ast::Statement::Expression(
-                        ast::Expression::Literal(ast::expression::literal::Literal::String(
-                            _string,
-                        )),
-                    )) => {
+                    Some(_string) => {
                         // TODO: should store directives in some place
                     }
-                    _ => {
+                    None => {
                         directive_prologues = false;
                         directives_stack.clear();
                     }
@@ -369,6 +407,85 @@ where
     }
 }
 
+/// Skips the remaining tokens of a statement that failed to parse, while recovering from a syntax
+/// error.
+///
+/// Outside of nested brackets, skipping stops after a `;`, before a token in `break_nodes` (like
+/// the `}` closing the enclosing block), or before a keyword starting a statement on a new line.
+/// At least one token is always skipped if the statement consumed none, to ensure progress.
+fn synchronize<R>(
+    cursor: &mut Cursor<R>,
+    point: RecoveryPoint,
+    break_nodes: &[TokenKind],
+    interner: &mut Interner,
+) -> ParseResult<()>
+where
+    R: ReadChar,
+{
+    let mut advanced = false;
+    let mut depth = 0_usize;
+
+    loop {
+        let kind = match cursor.peek(0, interner) {
+            Ok(Some(token)) => {
+                advanced |= token.span().start() != point.start();
+                token.kind().clone()
+            }
+            Ok(None) => break,
+            Err(error) if is_lexing_error(&error) => {
+                cursor.record_error(error)?;
+                advanced = true;
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+        if depth == 0 && advanced {
+            if break_nodes.contains(&kind) {
+                break;
+            }
+            if let TokenKind::Keyword((keyword, false)) = kind {
+                let starts_statement = matches!(
+                    keyword,
+                    Keyword::Var
+                        | Keyword::Let
+                        | Keyword::Const
+                        | Keyword::Function
+                        | Keyword::Class
+                        | Keyword::If
+                        | Keyword::For
+                        | Keyword::While
+                        | Keyword::Do
+                        | Keyword::Switch
+                        | Keyword::Try
+                        | Keyword::Throw
+                        | Keyword::Return
+                        | Keyword::Break
+                        | Keyword::Continue
+                        | Keyword::Debugger
+                );
+                if starts_statement && cursor.peek_is_line_terminator(0, interner)? == Some(true) {
+                    break;
+                }
+            }
+        }
+
+        cursor.advance(interner);
+        advanced = true;
+        match kind {
+            TokenKind::Punctuator(Punctuator::Semicolon) if depth == 0 => break,
+            TokenKind::Punctuator(
+                Punctuator::OpenBlock | Punctuator::OpenParen | Punctuator::OpenBracket,
+            ) => depth += 1,
+            TokenKind::Punctuator(
+                Punctuator::CloseBlock | Punctuator::CloseParen | Punctuator::CloseBracket,
+            ) => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
 /// Statement list item parsing
 ///
 /// A statement list item can either be an statement or a declaration.
@@ -410,9 +527,18 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("StatementListItem", "Parsing");
-        let tok = cursor.peek(0, interner).or_abrupt()?;
+        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
 
-        match *tok.kind() {
+        if is_using_declaration(cursor, self.allow_await, interner)? {
+            let declaration =
+                LexicalDeclaration::new(true, self.allow_yield, self.allow_await, false)
+                    .parse(cursor, interner)?;
+            let item = ast::StatementListItem::from(ast::Declaration::from(declaration));
+            return Ok(cursor.fill_span(position, item));
+        }
+
+        let tok = cursor.peek(0, interner).or_abrupt()?;
+        let item = match *tok.kind() {
             TokenKind::Keyword(
                 (Keyword::Function | Keyword::Class | Keyword::Const, _) | (Keyword::Let, false),
             ) => Declaration::new(self.allow_yield, self.allow_await)
@@ -442,8 +568,59 @@ where
             _ => Statement::new(self.allow_yield, self.allow_await, self.allow_return)
                 .parse(cursor, interner)
                 .map(ast::StatementListItem::from),
+        }?;
+
+        Ok(cursor.fill_span(position, item))
+    }
+}
+
+/// Checks if the next tokens start a `using` declaration, or an `await using` declaration if
+/// `await` expressions are allowed.
+///
+/// The keywords must be followed by a binding identifier, without line terminators between them.
+///
+/// More information:
+///  - [Explicit resource management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#prod-UsingDeclaration
+fn is_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    allow_await: AllowAwait,
+    interner: &mut Interner,
+) -> ParseResult<bool>
+where
+    R: ReadChar,
+{
+    let keywords = match cursor.peek(0, interner).or_abrupt()?.kind() {
+        TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false))) => 1,
+        TokenKind::Keyword((Keyword::Await, false)) if allow_await.0 => {
+            match cursor.peek(1, interner)?.map(Token::kind) {
+                Some(TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false)))) => 2,
+                _ => return Ok(false),
+            }
+        }
+        _ => return Ok(false),
+    };
+
+    // Note: The first token could be preceded by a line terminator.
+    let mut index = usize::from(cursor.peek_is_line_terminator(0, interner).or_abrupt()?);
+    for _ in 0..keywords {
+        index += 1;
+        if cursor
+            .peek_is_line_terminator(index, interner)?
+            .unwrap_or(true)
+        {
+            return Ok(false);
         }
     }
+
+    Ok(matches!(
+        cursor.peek(keywords, interner)?.map(Token::kind),
+        Some(
+            TokenKind::IdentifierName(_)
+                | TokenKind::Keyword((Keyword::Async | Keyword::Let | Keyword::Yield, _))
+        )
+    ))
 }
 
 /// `ObjectBindingPattern` pattern parsing.
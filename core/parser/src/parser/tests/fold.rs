@@ -0,0 +1,87 @@
+use crate::{Parser, Source};
+use boa_ast::{
+    expression::{Call, Identifier},
+    visitor::Fold,
+    Expression, Script, Spanned, Statement, StatementListItem,
+};
+use boa_interner::{Interner, Sym, ToInternedString};
+
+/// Replaces every array literal whose elements are all literals with a call to `marker`.
+struct LiteralArrays {
+    marker: Sym,
+}
+
+impl Fold for LiteralArrays {
+    fn fold_expression(&mut self, node: Expression) -> Expression {
+        match node {
+            Expression::ArrayLiteral(array)
+                if array
+                    .as_ref()
+                    .iter()
+                    .all(|element| matches!(element, Some(Expression::Literal(_)))) =>
+            {
+                Call::new(Identifier::new(self.marker).into(), Box::default()).into()
+            }
+            node => node,
+        }
+    }
+}
+
+fn parse(source: &str, interner: &mut Interner) -> Script {
+    Parser::new(Source::from_bytes(source))
+        .parse_script(interner)
+        .expect("parsing failed")
+}
+
+#[test]
+fn fold_literal_arrays() {
+    let interner = &mut Interner::default();
+    let mut script = parse(
+        r#"
+        let a = [1, "two", [3, 4]];
+        f([x, 1], [true]);
+        if ([null].length) {
+            throw [[5], [6]];
+        }
+        "#,
+        interner,
+    );
+    let expected = parse(
+        r#"
+        let a = [1, "two", marker()];
+        f([x, 1], marker());
+        if (marker().length) {
+            throw [marker(), marker()];
+        }
+        "#,
+        interner,
+    );
+
+    let marker = interner.get_or_intern_static("marker", boa_macros::utf16!("marker"));
+    LiteralArrays { marker }.fold(&mut script);
+
+    assert_eq!(
+        script.to_interned_string(interner),
+        expected.to_interned_string(interner)
+    );
+}
+
+#[test]
+fn fold_replaces_root_and_keeps_span() {
+    let interner = &mut Interner::default();
+    let script = parse("[1, 2];", interner);
+    let StatementListItem::Statement(Statement::Expression(expr)) =
+        &script.statements().statements()[0]
+    else {
+        panic!("expected an expression statement");
+    };
+    let mut expr = expr.clone();
+    let span = expr.span();
+
+    let marker = interner.get_or_intern_static("marker", boa_macros::utf16!("marker"));
+    LiteralArrays { marker }.fold(&mut expr);
+
+    assert!(matches!(expr, Expression::Call(_)));
+    assert_eq!(expr.span(), span);
+    assert!(span.is_some());
+}
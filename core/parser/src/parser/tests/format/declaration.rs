@@ -25,6 +25,9 @@ fn binding_pattern() {
         };
         var [] = [];
         var [ , ] = [];
+        var [ , , ] = [];
+        var [ , , a_v8 ] = [1, 2, 3];
+        var [ a_v9, , ] = [1, 2, 3];
         var [ a_v1 ] = [1, 2, 3];
         var [ a_v2, a_v3 ] = [1, 2, 3];
         var [ a_v2, , a_v3 ] = [1, 2, 3];
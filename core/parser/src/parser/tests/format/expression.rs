@@ -80,6 +80,11 @@ fn array() {
         r#"
             let a = [1, 2, 3, "words", "more words"];
             let b = [];
+            let c = [1, , 3];
+            let d = [, ,];
+            let e = [1, ,];
+            let f = [,];
+            let g = [, 2];
             "#,
     );
 }
@@ -1,6 +1,7 @@
 mod declaration;
 mod expression;
 mod function;
+mod pretty;
 mod statement;
 
 /// This parses the given source code, and then makes sure that
@@ -14,10 +15,47 @@ mod statement;
 /// level.
 #[cfg(test)]
 fn test_formatting(source: &'static str) {
-    // Remove preceding newline.
-
     use crate::{Parser, Source};
     use boa_interner::{Interner, ToInternedString};
+
+    let scenario = remove_indentation(source);
+    let source = Source::from_bytes(&source[1..]);
+    let interner = &mut Interner::default();
+    let result = Parser::new(source)
+        .parse_script(interner)
+        .expect("parsing failed")
+        .to_interned_string(interner);
+    assert_formatted(&scenario, &result);
+}
+
+/// Like [`test_formatting`], but prints the parsed script using a
+/// [`PrettyPrinter`][boa_ast::pretty::PrettyPrinter] with the given options.
+///
+/// The expected output is `expected` if given, or the source code itself
+/// otherwise.
+#[cfg(test)]
+fn test_pretty_formatting(
+    source: &'static str,
+    expected: Option<&'static str>,
+    options: boa_ast::pretty::PrettyPrinterOptions,
+) {
+    use crate::{Parser, Source};
+    use boa_ast::pretty::PrettyPrinter;
+    use boa_interner::Interner;
+
+    let scenario = remove_indentation(expected.unwrap_or(source));
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(&source[1..]))
+        .parse_script(interner)
+        .expect("parsing failed");
+    let result = PrettyPrinter::with_options(interner, options).print_script(&script);
+    assert_formatted(&scenario, &result);
+}
+
+/// Removes the preceding newline of `source`, and the indentation of its first line from every line.
+#[cfg(test)]
+fn remove_indentation(source: &str) -> String {
+    // Remove preceding newline.
     let source = &source[1..];
 
     // Find out how much the code is indented
@@ -25,17 +63,15 @@ fn test_formatting(source: &'static str) {
     let trimmed_first_line = first_line.trim();
     let characters_to_remove = first_line.len() - trimmed_first_line.len();
 
-    let scenario = source
+    source
         .lines()
         .map(|l| &l[characters_to_remove..]) // Remove preceding whitespace from each line
-        .collect::<Vec<&'static str>>()
-        .join("\n");
-    let source = Source::from_bytes(source);
-    let interner = &mut Interner::default();
-    let result = Parser::new(source)
-        .parse_script(interner)
-        .expect("parsing failed")
-        .to_interned_string(interner);
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+fn assert_formatted(scenario: &str, result: &str) {
     if scenario != result {
         eprint!("========= Expected:\n{scenario}");
         eprint!("========= Got:\n{result}");
@@ -0,0 +1,294 @@
+use crate::parser::tests::format::test_pretty_formatting;
+use boa_ast::pretty::{PrettyPrinterOptions, QuoteStyle};
+
+const LONG_ARRAY: &str = r#"
+        let numbers = [100000, 200000, 300000, 400000, 500000, 600000];
+        "#;
+
+#[test]
+fn array_fits_line_width() {
+    test_pretty_formatting(
+        LONG_ARRAY,
+        None,
+        PrettyPrinterOptions {
+            line_width: 100,
+            ..PrettyPrinterOptions::default()
+        },
+    );
+}
+
+#[test]
+fn array_wraps_at_line_width() {
+    test_pretty_formatting(
+        LONG_ARRAY,
+        Some(
+            r#"
+            let numbers = [
+                100000,
+                200000,
+                300000,
+                400000,
+                500000,
+                600000,
+            ];
+            "#,
+        ),
+        PrettyPrinterOptions {
+            line_width: 40,
+            ..PrettyPrinterOptions::default()
+        },
+    );
+}
+
+#[test]
+fn nested_lists_wrap_independently() {
+    test_pretty_formatting(
+        r#"
+        call({ first: "a value", second: [1, 2, 3] }, [, , "some other value"]);
+        "#,
+        Some(
+            r#"
+            call(
+              { first: "a value", second: [1, 2, 3] },
+              [, , "some other value"],
+            );
+            "#,
+        ),
+        PrettyPrinterOptions {
+            indent_width: 2,
+            line_width: 50,
+            ..PrettyPrinterOptions::default()
+        },
+    );
+}
+
+#[test]
+fn indent_width() {
+    test_pretty_formatting(
+        r#"
+        function f(a, b = 1, ...rest) {
+          if (a) {
+            return b;
+          } else if (b) {
+            for (let i = 0; i < rest.length; i++) g(rest[i]);
+          }
+        }
+        "#,
+        Some(
+            r#"
+            function f(a, b = 1, ...rest) {
+              if (a) {
+                return b;
+              } else if (b) {
+                for (let i = 0; i < rest.length; i++)
+                  g(rest[i]);
+              }
+            }
+            "#,
+        ),
+        PrettyPrinterOptions {
+            indent_width: 2,
+            ..PrettyPrinterOptions::default()
+        },
+    );
+}
+
+#[test]
+fn quote_style() {
+    test_pretty_formatting(
+        r#"
+        let a = "it's";
+        let b = 'say "hi"\n';
+        let c = { "not-an-identifier": 1, 2: "two", valid: 'line\u2028separator' };
+        "#,
+        Some(
+            r#"
+            let a = 'it\'s';
+            let b = 'say "hi"\n';
+            let c = { 'not-an-identifier': 1, 2: 'two', valid: 'line\u2028separator' };
+            "#,
+        ),
+        PrettyPrinterOptions {
+            quote_style: QuoteStyle::Single,
+            ..PrettyPrinterOptions::default()
+        },
+    );
+}
+
+#[test]
+fn without_semicolons() {
+    test_pretty_formatting(
+        r#"
+        let a = 1;
+        [a].forEach(f);
+        (function() {})();
+        do {
+            a++;
+        } while (a < 10);
+        "#,
+        Some(
+            r#"
+            let a = 1
+            ;[a].forEach(f)
+            ;(function() {})()
+            do {
+                a++
+            } while (a < 10)
+            "#,
+        ),
+        PrettyPrinterOptions {
+            semicolons: false,
+            ..PrettyPrinterOptions::default()
+        },
+    );
+}
+
+#[test]
+fn without_semicolons_class_fields() {
+    test_pretty_formatting(
+        r#"
+        class A {
+            a = 1;
+            b;
+            [c]() {}
+            d = 2;
+            *e() {}
+            get;
+            f() {}
+            static #g = 3;
+        }
+        "#,
+        Some(
+            r#"
+            class A {
+                a = 1
+                b;
+                [c]() {}
+                d = 2;
+                *e() {}
+                get;
+                f() {}
+                static #g = 3
+            }
+            "#,
+        ),
+        PrettyPrinterOptions {
+            semicolons: false,
+            ..PrettyPrinterOptions::default()
+        },
+    );
+}
+
+#[test]
+fn without_semicolons_round_trip() {
+    use crate::{Parser, Source};
+    use boa_ast::pretty::PrettyPrinter;
+    use boa_interner::Interner;
+
+    fn format(source: &str) -> String {
+        let interner = &mut Interner::default();
+        let script = Parser::new(Source::from_bytes(source))
+            .parse_script(interner)
+            .expect("parsing failed");
+        let options = PrettyPrinterOptions {
+            semicolons: false,
+            ..PrettyPrinterOptions::default()
+        };
+        PrettyPrinter::with_options(interner, options).print_script(&script)
+    }
+
+    let source = "let a = 1;
+                  [a].forEach(f);
+                  (function() {})();
+                  { `x`; -a; }
+                  switch (a) { case 1: +a; }
+                  class A { x = 1; [y]() {} static; z() {} in = 2; }
+";
+    let once = format(source);
+    assert!(!once.contains("\n;\n"), "lone semicolon in:\n{once}");
+    assert_eq!(format(&once), once);
+}
+
+#[test]
+fn default_options() {
+    test_pretty_formatting(
+        r#"
+        class A extends B {
+            constructor(x) {
+                super(x);
+            }
+            static #count = 0;
+            *values() {}
+            get size() {
+                return this.#count;
+            }
+        }
+        const f = async (x) => ({ x });
+        const { a, b: [c, , d = 2], ...rest } = tag`raw ${x} string`;
+        switch (a) {
+            case 1:
+                break;
+            default:
+                throw new Error(`unknown ${a}`);
+        }
+        label: for (const key in obj) {
+            try {
+                continue label;
+            } catch {
+            } finally {
+                a?.b?.[c]?.(d);
+            }
+        }
+        "#,
+        Some(
+            r#"
+            class A extends B {
+                constructor(x) {
+                    super(x);
+                }
+                static #count = 0;
+                *values() {}
+                get size() {
+                    return this.#count;
+                }
+            }
+            const f = async (x) => ({ x });
+            const { a, b: [c, , d = 2], ...rest } = tag`raw ${x} string`;
+            switch (a) {
+                case 1:
+                    break;
+                default:
+                    throw new Error(`unknown ${a}`);
+            }
+            label: for (const key in obj) {
+                try {
+                    continue label;
+                } catch {} finally {
+                    a?.b?.[c]?.(d);
+                }
+            }
+            "#,
+        ),
+        PrettyPrinterOptions::default(),
+    );
+}
+
+#[test]
+fn module_items() {
+    use crate::{Parser, Source};
+    use boa_ast::pretty::PrettyPrinter;
+    use boa_interner::Interner;
+
+    let source = "import def, { a as b, \"string name\" as c } from \"./x.js\";\n\
+                  import * as ns from \"./y.js\";\n\
+                  import \"./z.js\";\n\
+                  export { b as renamed, c };\n\
+                  export * as z from \"./z.js\";\n\
+                  export default function() {}\n";
+    let interner = &mut Interner::default();
+    let module = Parser::new(Source::from_bytes(source))
+        .parse_module(interner)
+        .expect("parsing failed");
+    let result = PrettyPrinter::new(interner).print_module(&module);
+    assert_eq!(result, source);
+}
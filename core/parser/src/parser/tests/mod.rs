@@ -1,6 +1,10 @@
 //! Tests for the parser.
 
+mod fold;
 mod format;
+mod parallel;
+mod recovery;
+mod serialization;
 
 use std::convert::TryInto;
 
@@ -24,7 +28,7 @@ use boa_ast::{
     },
     property::PropertyDefinition,
     statement::{If, Return},
-    Expression, Script, Statement, StatementList, StatementListItem,
+    Expression, Position, Script, Span, Spanned, Statement, StatementList, StatementListItem,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -605,6 +609,87 @@ fn hashbang_comment() {
     check_script_parser(r"#!Comment Here", vec![], &mut Interner::default());
 }
 
+#[test]
+fn hashbang_followed_by_code() {
+    let interner = &mut Interner::default();
+    check_script_parser(
+        "#!/usr/bin/env boa\nx;",
+        vec![Statement::Expression(
+            Identifier::new(interner.get_or_intern_static("x", utf16!("x"))).into(),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn hashbang_after_byte_order_mark() {
+    check_script_parser(
+        "\u{FEFF}#!/usr/bin/env boa\n",
+        vec![],
+        &mut Interner::default(),
+    );
+}
+
+#[test]
+fn hashbang_in_module() {
+    let module = Parser::new(Source::from_bytes("#!/usr/bin/env boa\nexport {};"))
+        .parse_module(&mut Interner::default())
+        .expect("failed to parse");
+    assert_eq!(module.items().items().len(), 1);
+}
+
+#[test]
+fn deny_hashbang_not_at_start() {
+    check_invalid_script(" #!/usr/bin/env boa");
+    check_invalid_script("\n#!/usr/bin/env boa");
+    check_invalid_script("/**/#!/usr/bin/env boa");
+    check_invalid_script("x;\n#!/usr/bin/env boa");
+    check_invalid_script("\u{FEFF}\u{FEFF}#!/usr/bin/env boa");
+}
+
+#[test]
+fn deny_hashbang_in_function_body() {
+    assert!(Parser::new(Source::from_bytes("#!/usr/bin/env boa\n"))
+        .parse_function_body(&mut Interner::default(), false, false)
+        .is_err());
+}
+
+#[test]
+fn module_string_specifier_names() {
+    let parse = |source: &str| {
+        Parser::new(Source::from_bytes(source)).parse_module(&mut Interner::default())
+    };
+
+    assert!(parse(r#"import { "a b" as x } from "m"; export { x as "c d" };"#).is_ok());
+    assert!(parse(r#"export { "a b" as "c d", "e f" } from "m";"#).is_ok());
+    assert!(parse(r#"export * as "a b" from "m";"#).is_ok());
+
+    assert!(parse(r#"import { "a b" } from "m";"#).is_err());
+    assert!(parse(r#"const x = 1; export { "x" };"#).is_err());
+    assert!(parse(r#"import { "\uD800" as x } from "m";"#).is_err());
+    assert!(parse(r#"const x = 1; export { x as "\uD800" };"#).is_err());
+}
+
+#[test]
+fn import_declaration_semicolons() {
+    let items = |source: &str| {
+        Parser::new(Source::from_bytes(source))
+            .parse_module(&mut Interner::default())
+            .map(|module| module.items().items().len())
+            .ok()
+    };
+
+    // The semicolon ends the declaration instead of being parsed as an empty statement.
+    assert_eq!(items(r#"import x from "m";"#), Some(1));
+    assert_eq!(items(r#"import { x } from "m";"#), Some(1));
+    assert_eq!(items(r#"import * as x from "m";"#), Some(1));
+    assert_eq!(items("import x from \"m\"\nx"), Some(2));
+
+    assert!(items(r#"import x from "m" x"#).is_none());
+    assert!(items(r#"import { x } from "m" x"#).is_none());
+}
+
 #[test]
 fn deny_unicode_escape_in_false_expression() {
     check_invalid_script(r"let x = f\u{61}lse;");
@@ -619,3 +704,58 @@ fn deny_unicode_escape_in_true_expression() {
 fn deny_unicode_escape_in_null_expression() {
     check_invalid_script(r"let x = n\u{75}ll;");
 }
+
+#[test]
+fn node_spans() {
+    fn span(start: (u32, u32), end: (u32, u32)) -> Span {
+        Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+    }
+
+    let script = Parser::new(Source::from_bytes("let x = a + 2 * c;\nif (x) { f(x.y); }"))
+        .parse_script(&mut Interner::default())
+        .expect("failed to parse");
+    let items = script.statements().statements();
+
+    let StatementListItem::Declaration(Declaration::Lexical(LexicalDeclaration::Let(list))) =
+        &items[0]
+    else {
+        panic!("expected a lexical declaration");
+    };
+    assert_eq!(list.span(), Some(span((1, 1), (1, 19))));
+    let Some(Expression::Binary(add)) = list.as_ref()[0].init() else {
+        panic!("expected a binary expression");
+    };
+    assert_eq!(add.span(), Some(span((1, 9), (1, 18))));
+    assert_eq!(add.lhs().span(), Some(span((1, 9), (1, 10))));
+    assert_eq!(add.rhs().span(), Some(span((1, 13), (1, 18))));
+    let Expression::Binary(mul) = add.rhs() else {
+        panic!("expected a binary expression");
+    };
+    assert_eq!(mul.lhs().span(), Some(span((1, 13), (1, 14))));
+
+    let StatementListItem::Statement(Statement::If(if_stmt)) = &items[1] else {
+        panic!("expected an if statement");
+    };
+    assert_eq!(if_stmt.span(), Some(span((2, 1), (2, 19))));
+    assert_eq!(if_stmt.cond().span(), Some(span((2, 5), (2, 6))));
+    let Statement::Block(block) = if_stmt.body() else {
+        panic!("expected a block");
+    };
+    assert_eq!(block.span(), Some(span((2, 8), (2, 19))));
+    let StatementListItem::Statement(Statement::Expression(Expression::Call(call))) =
+        &block.statement_list().statements()[0]
+    else {
+        panic!("expected a call expression statement");
+    };
+    assert_eq!(call.span(), Some(span((2, 10), (2, 16))));
+    assert_eq!(call.args()[0].span(), Some(span((2, 12), (2, 15))));
+}
+
+#[test]
+fn programmatic_nodes_have_no_span() {
+    let interner = &mut Interner::default();
+    let ident = Identifier::new(interner.get_or_intern_static("x", utf16!("x")));
+    assert_eq!(Expression::from(ident).span(), None);
+    assert_eq!(Expression::from(Literal::Int(1)).span(), None);
+    assert_eq!(Statement::Empty.span(), None);
+}
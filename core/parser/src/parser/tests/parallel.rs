@@ -0,0 +1,64 @@
+//! Tests for parsing many sources in parallel with a shared interner.
+
+use crate::{Parser, Source};
+use boa_interner::{SharedInterner, Sym};
+use rayon::prelude::*;
+
+/// Strings that appear in every module.
+const NAMES: [&str; 5] = ["helper", "shared", "value", "./common.js", "Ω"];
+
+fn module_source(index: usize) -> String {
+    format!(
+        "import {{ helper, shared }} from './common.js';\n\
+         export const value{index} = helper(shared, 'Ω', {index});\n\
+         export function value() {{ return value{index} + arguments.length; }}\n"
+    )
+}
+
+#[test]
+fn parse_modules_in_parallel() {
+    let shared = SharedInterner::new();
+    let sources: Vec<String> = (0..48).map(module_source).collect();
+
+    let parsed: Vec<_> = sources
+        .par_iter()
+        .map(|source| {
+            let mut interner = shared.interner();
+            let module = Parser::new(Source::from_bytes(source))
+                .parse_module(&mut interner)
+                .expect("failed to parse");
+            let syms: Vec<Option<Sym>> = NAMES.iter().map(|name| interner.get(*name)).collect();
+            (module, syms)
+        })
+        .collect();
+
+    let mut interner = shared.interner();
+    let len = interner.len();
+    for (source, (module, syms)) in sources.iter().zip(&parsed) {
+        assert_eq!(syms, &parsed[0].1);
+
+        // Parsing the source again must resolve every string to the symbol used by the thread
+        // that parsed it first.
+        let expected = Parser::new(Source::from_bytes(source))
+            .parse_module(&mut interner)
+            .expect("failed to parse");
+        assert_eq!(module, &expected);
+    }
+    assert_eq!(interner.len(), len);
+
+    for (name, sym) in NAMES.iter().zip(&parsed[0].1) {
+        let sym = sym.expect("string must be interned");
+        assert_eq!(interner.resolve_expect(sym).utf8(), Some(*name));
+    }
+    for index in 0..48 {
+        let name = format!("value{index}");
+        assert!(interner.get(name.as_str()).is_some());
+    }
+
+    // The static symbols keep their indices.
+    assert_eq!(interner.get("arguments"), Some(Sym::ARGUMENTS));
+    assert_eq!(
+        boa_interner::Interner::default().get_or_intern("arguments"),
+        Sym::ARGUMENTS
+    );
+}
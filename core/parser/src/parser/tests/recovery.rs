@@ -0,0 +1,121 @@
+use std::{convert::Infallible, ops::ControlFlow};
+
+use crate::{Parser, Source};
+use boa_ast::{
+    expression::Call,
+    operations::contains_error_placeholder,
+    visitor::{VisitWith, Visitor},
+    Position, Statement,
+};
+use boa_interner::{Interner, ToInternedString};
+use indoc::indoc;
+
+/// Counts the error placeholders and the calls of a recovered script.
+#[derive(Default)]
+struct Counter {
+    errors: usize,
+    calls: usize,
+}
+
+impl<'ast> Visitor<'ast> for Counter {
+    type BreakTy = Infallible;
+
+    fn visit_statement(&mut self, node: &'ast Statement) -> ControlFlow<Self::BreakTy> {
+        if matches!(node, Statement::Error(_)) {
+            self.errors += 1;
+        }
+        node.visit_with(self)
+    }
+
+    fn visit_call(&mut self, node: &'ast Call) -> ControlFlow<Self::BreakTy> {
+        self.calls += 1;
+        node.visit_with(self)
+    }
+}
+
+const THREE_ERRORS: &str = indoc! {"
+    let a = 1;
+    let b = ;
+    function f() {
+        return 1 +;
+    }
+    const c = [1, 2;
+    if (a) {
+        g(a);
+    }
+    f(b);
+"};
+
+#[test]
+fn recover_independent_errors() {
+    let interner = &mut Interner::default();
+    let (script, errors) =
+        Parser::new(Source::from_bytes(THREE_ERRORS)).parse_script_with_recovery(interner);
+
+    let positions: Vec<_> = errors.iter().map(crate::Error::position).collect();
+    assert_eq!(
+        positions,
+        [
+            Some(Position::new(2, 9)),
+            Some(Position::new(4, 15)),
+            Some(Position::new(6, 16)),
+        ]
+    );
+
+    let mut counter = Counter::default();
+    assert!(script.visit_with(&mut counter).is_continue());
+    assert_eq!(counter.errors, 3);
+    assert_eq!(counter.calls, 2);
+    assert!(contains_error_placeholder(&script));
+
+    assert_eq!(
+        script.to_interned_string(interner),
+        indoc! {"
+            let a = 1;
+            /* syntax error */
+            function f() {
+                /* syntax error */
+            }
+            /* syntax error */
+            if (a) {
+                g(a);
+            }
+            f(b);
+        "}
+    );
+}
+
+#[test]
+fn recovery_is_opt_in() {
+    let interner = &mut Interner::default();
+    let error = Parser::new(Source::from_bytes(THREE_ERRORS))
+        .parse_script(interner)
+        .expect_err("the script has syntax errors");
+    assert_eq!(error.position(), Some(Position::new(2, 9)));
+}
+
+#[test]
+fn recover_from_lexing_errors() {
+    let interner = &mut Interner::default();
+    let (script, errors) = Parser::new(Source::from_bytes("x; 'unterminated\nf(); @\ng();"))
+        .parse_script_with_recovery(interner);
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(script.to_interned_string(interner), "x;\nf();\ng();\n");
+}
+
+#[test]
+fn recover_without_errors() {
+    let interner = &mut Interner::default();
+    let (script, errors) = Parser::new(Source::from_bytes("let a = [1, 2];\nf(a);"))
+        .parse_script_with_recovery(interner);
+
+    assert!(errors.is_empty());
+    assert!(!contains_error_placeholder(&script));
+    assert_eq!(
+        script,
+        Parser::new(Source::from_bytes("let a = [1, 2];\nf(a);"))
+            .parse_script(interner)
+            .expect("the script is valid")
+    );
+}
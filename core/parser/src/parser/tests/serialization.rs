@@ -0,0 +1,164 @@
+use crate::{Parser, Source};
+use boa_ast::{
+    pretty::PrettyPrinter,
+    serialization::{remap_syms, AstDeserializer, AstSerializer},
+    visitor::VisitWith,
+    Module, Script,
+};
+use boa_interner::{Interner, InternerSnapshot, ToInternedString};
+use serde::de::DeserializeSeed;
+
+const SCRIPT: &str = r#"
+    "use strict";
+    class Counter extends Base {
+        static #instances = 0;
+        #count = 0;
+        constructor(start = 0, ...rest) {
+            super(rest);
+            this.#count = start;
+            Counter.#instances++;
+        }
+        *[Symbol.iterator]() {
+            for (let i = 0; i < this.#count; i++) yield i;
+        }
+        get count() {
+            return this.#count;
+        }
+    }
+    const { a, b: [c, , d = 2], ...others } = { a: 1, b: [3, 4], "quoted key": 5 };
+    async function fetchAll(urls) {
+        try {
+            return await Promise.all(urls.map(async (url) => (await fetch(url))?.json?.()));
+        } catch {
+            return null;
+        } finally {
+            label: for (const key in others) {
+                if (key === "skip") continue label;
+                switch (typeof key) {
+                    case "string":
+                        break label;
+                    default:
+                        throw new TypeError(`unexpected ${key}`);
+                }
+            }
+        }
+    }
+    let lone = "\uD800 surrogate", big = 123n ** 2n, re = /ab+c/gi;
+    new Counter(1, 2, 3).count;
+"#;
+
+/// Serializes `node` with its symbols resolved, and deserializes it into a fresh interner.
+fn round_trip<T>(interner: &Interner, node: &T) -> (T, Interner)
+where
+    T: serde::Serialize + for<'de> serde::Deserialize<'de> + VisitWith,
+{
+    let json = serde_json::to_string(&AstSerializer::new(interner, node))
+        .expect("could not serialize the AST");
+
+    let mut interner = Interner::default();
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let node = AstDeserializer::new(&mut interner)
+        .deserialize(&mut deserializer)
+        .expect("could not deserialize the AST");
+    (node, interner)
+}
+
+#[test]
+fn script_round_trip() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(SCRIPT))
+        .parse_script(interner)
+        .expect("parsing failed");
+
+    let (deserialized, new_interner): (Script, _) = round_trip(interner, &script);
+
+    assert_eq!(
+        deserialized.to_interned_string(&new_interner),
+        script.to_interned_string(interner)
+    );
+}
+
+#[test]
+fn module_round_trip() {
+    let source = r#"
+        import def, { a as b, "string name" as c } from "./x.js";
+        import * as ns from "./y.js";
+        export { b as renamed, c };
+        export * as z from "./z.js";
+        export default function () { return ns.value + def; }
+    "#;
+    let interner = &mut Interner::default();
+    let module = Parser::new(Source::from_bytes(source))
+        .parse_module(interner)
+        .expect("parsing failed");
+
+    let (deserialized, new_interner): (Module, _) = round_trip(interner, &module);
+
+    // Modules don't implement `ToInternedString`, so the pretty-printer is used to compare them.
+    assert_eq!(
+        PrettyPrinter::new(&new_interner).print_module(&deserialized),
+        PrettyPrinter::new(interner).print_module(&module)
+    );
+}
+
+#[test]
+fn resolved_symbols_are_inlined() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes("let someIdentifier = 'some text';"))
+        .parse_script(interner)
+        .expect("parsing failed");
+
+    let json = serde_json::to_string(&AstSerializer::new(interner, &script))
+        .expect("could not serialize the AST");
+    assert!(json.contains("\"someIdentifier\""));
+    assert!(json.contains("\"some text\""));
+
+    // Without a resolving serializer, symbols are still serialized as their raw index.
+    let raw = serde_json::to_string(&script).expect("could not serialize the AST");
+    assert!(!raw.contains("someIdentifier"));
+}
+
+#[test]
+fn missing_symbols_are_rejected() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes("let someIdentifier = 'some text';"))
+        .parse_script(interner)
+        .expect("parsing failed");
+
+    let mut json = serde_json::to_value(AstSerializer::new(interner, &script))
+        .expect("could not serialize the AST");
+    json["symbols"] = serde_json::Value::Array(Vec::new());
+
+    let result: Result<Script, _> =
+        AstDeserializer::new(&mut Interner::default()).deserialize(json);
+    assert!(result.is_err());
+}
+
+#[test]
+fn snapshot_round_trip() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(SCRIPT))
+        .parse_script(interner)
+        .expect("parsing failed");
+
+    // The raw symbols are stored next to a snapshot of the interner that produced them.
+    let json = serde_json::to_string(&script).expect("could not serialize the AST");
+    let snapshot =
+        serde_json::to_string(&interner.snapshot()).expect("could not serialize the snapshot");
+
+    let mut new_interner = Interner::default();
+    new_interner.get_or_intern("a string that shifts the other symbols");
+    let snapshot: InternerSnapshot =
+        serde_json::from_str(&snapshot).expect("could not deserialize the snapshot");
+    let remap = new_interner.restore(&snapshot);
+    assert!(!remap.is_identity());
+
+    let mut deserialized: Script =
+        serde_json::from_str(&json).expect("could not deserialize the AST");
+    remap_syms(&mut deserialized, &remap).expect("could not remap the symbols");
+
+    assert_eq!(
+        deserialized.to_interned_string(&new_interner),
+        script.to_interned_string(interner)
+    );
+}
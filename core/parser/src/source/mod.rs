@@ -1,5 +1,6 @@
 //! Boa parser input source types.
 
+mod text;
 mod utf16;
 mod utf8;
 
@@ -9,6 +10,7 @@ use std::{
     path::Path,
 };
 
+pub use text::SourceText;
 pub use utf16::UTF16Input;
 pub use utf8::UTF8Input;
 
@@ -0,0 +1,170 @@
+use boa_ast::{Position, Span};
+
+/// The source text read by the lexer, encoded as UTF-16.
+///
+/// The lexer records every character it consumes, so that slices of the original source code
+/// can be recovered from the [`Span`]s of the parsed nodes, e.g. to implement
+/// `Function.prototype.toString`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceText {
+    text: Vec<u16>,
+    /// The offset of the first code unit of every line in `text`.
+    line_starts: Vec<usize>,
+}
+
+impl Default for SourceText {
+    fn default() -> Self {
+        Self {
+            text: Vec::new(),
+            line_starts: vec![0],
+        }
+    }
+}
+
+impl SourceText {
+    /// Creates a new `SourceText` from UTF-16 code units, e.g. a source text synthesized at
+    /// runtime.
+    #[must_use]
+    pub fn from_utf16(code: &[u16]) -> Self {
+        let mut text = Self::default();
+        let mut chars = char::decode_utf16(code.iter().copied())
+            .map(|c| c.map_or_else(|e| u32::from(e.unpaired_surrogate()), u32::from))
+            .peekable();
+        while let Some(c) = chars.next() {
+            text.push(c);
+            match c {
+                // "\r\n" is a single line terminator.
+                0xD => {
+                    if chars.next_if_eq(&0xA).is_some() {
+                        text.push(0xA);
+                    }
+                    text.new_line();
+                }
+                0xA | 0x2028 | 0x2029 => text.new_line(),
+                _ => {}
+            }
+        }
+        text
+    }
+
+    /// Appends a code point to the source text.
+    pub(crate) fn push(&mut self, c: u32) {
+        if let Ok(c) = u16::try_from(c) {
+            self.text.push(c);
+        } else {
+            let c = c - 0x1_0000;
+            #[allow(clippy::cast_possible_truncation)]
+            self.text
+                .extend([0xD800 | (c >> 10) as u16, 0xDC00 | (c & 0x3FF) as u16]);
+        }
+    }
+
+    /// Marks the end of the current line, after its line terminator has been pushed.
+    pub(crate) fn new_line(&mut self) {
+        self.line_starts.push(self.text.len());
+    }
+
+    /// Gets the whole source text.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u16] {
+        &self.text
+    }
+
+    /// Gets the span covering the whole source text.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn span(&self) -> Span {
+        let last_line = self.line_starts.len();
+        let last_line_start = self.line_starts[last_line - 1];
+        let columns = char::decode_utf16(self.text[last_line_start..].iter().copied()).count();
+        Span::new(
+            Position::new(1, 1),
+            Position::new(last_line as u32, columns as u32 + 1),
+        )
+    }
+
+    /// Gets the offset in code units of a position in the source text, if it is inside it.
+    #[must_use]
+    pub fn offset(&self, position: Position) -> Option<usize> {
+        let line = position.line_number() as usize - 1;
+        let mut offset = *self.line_starts.get(line)?;
+        let line_end = self
+            .line_starts
+            .get(line + 1)
+            .copied()
+            .unwrap_or(self.text.len());
+
+        // Columns count code points, so surrogate pairs take a single column.
+        for _ in 1..position.column_number() {
+            if offset >= line_end {
+                return None;
+            }
+            let is_pair = matches!(self.text[offset], 0xD800..=0xDBFF)
+                && matches!(self.text.get(offset + 1), Some(0xDC00..=0xDFFF));
+            offset += if is_pair { 2 } else { 1 };
+        }
+
+        Some(offset)
+    }
+
+    /// Gets the source text covered by `span`, if the span is inside the source text.
+    #[must_use]
+    pub fn slice(&self, span: Span) -> Option<&[u16]> {
+        let start = self.offset(span.start())?;
+        let end = self.offset(span.end())?;
+        self.text.get(start..end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SourceText;
+    use boa_ast::{Position, Span};
+
+    fn source_text(code: &str) -> SourceText {
+        SourceText::from_utf16(&code.encode_utf16().collect::<Vec<_>>())
+    }
+
+    fn slice(text: &SourceText, start: (u32, u32), end: (u32, u32)) -> Option<String> {
+        let span = Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1));
+        text.slice(span).map(String::from_utf16_lossy)
+    }
+
+    #[test]
+    fn slice_lines() {
+        let text = source_text("let a;\r\nfunction f() {\n  return 1;\n}");
+
+        assert_eq!(slice(&text, (1, 1), (1, 4)).as_deref(), Some("let"));
+        assert_eq!(
+            slice(&text, (2, 1), (4, 2)).as_deref(),
+            Some("function f() {\n  return 1;\n}")
+        );
+        assert_eq!(slice(&text, (4, 1), (4, 3)), None);
+        assert_eq!(slice(&text, (5, 1), (5, 1)), None);
+    }
+
+    #[test]
+    fn full_span() {
+        let code = "function f() {\r\n  return '😀';\n}";
+        let text = source_text(code);
+
+        assert_eq!(
+            text.span(),
+            Span::new(Position::new(1, 1), Position::new(3, 2))
+        );
+        assert_eq!(
+            text.slice(text.span())
+                .map(String::from_utf16_lossy)
+                .as_deref(),
+            Some(code)
+        );
+    }
+
+    #[test]
+    fn slice_surrogate_pairs() {
+        let text = source_text("'😀' + x");
+
+        assert_eq!(slice(&text, (1, 1), (1, 4)).as_deref(), Some("'😀'"));
+        assert_eq!(slice(&text, (1, 7), (1, 8)).as_deref(), Some("x"));
+    }
+}
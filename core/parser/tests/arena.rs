@@ -0,0 +1,106 @@
+//! Tests for the bump arena of the parser, which needs its own global allocator.
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "arena")]
+
+use boa_interner::Interner;
+use boa_parser::{arena::ArenaAllocator, Parser, Source};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Write,
+};
+
+/// Counts the allocations of the current thread that reach the system allocator.
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static LIVE: Cell<isize> = const { Cell::new(0) };
+}
+
+// SAFETY: Forwards everything to the system allocator.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        let _ = LIVE.try_with(|live| live.set(live.get() + 1));
+        // SAFETY: Guaranteed by the caller.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE.try_with(|live| live.set(live.get() - 1));
+        // SAFETY: Guaranteed by the caller.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: ArenaAllocator<Counting> = ArenaAllocator::new(Counting);
+
+fn source() -> String {
+    let mut source = String::new();
+    for i in 0..2000 {
+        writeln!(
+            source,
+            "function f{i}(a, b) {{ const list = [a, b, {i}, 'item{i}', {{ key: a + b * {i} }}]; \
+             for (let j = 0; j < list.length; j++) {{ if (list[j] > b) {{ a += list[j]; }} }} \
+             return `${{a}}:${{b}}` + [1, 2, 3].map((x) => x * a).join(','); }}"
+        )
+        .expect("writing to a string cannot fail");
+    }
+    source
+}
+
+fn parse(source: &str, arena: bool) -> (boa_ast::Script, usize) {
+    let mut interner = Interner::default();
+    let before = ALLOCATIONS.with(Cell::get);
+    let mut parser = Parser::new(Source::from_bytes(source));
+    if arena {
+        parser.set_arena();
+    }
+    let script = parser
+        .parse_script(&mut interner)
+        .expect("the source must parse");
+    (script, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn arena_collapses_allocations() {
+    let source = source();
+    let (boxed, boxed_allocations) = parse(&source, false);
+    let (arena, arena_allocations) = parse(&source, true);
+
+    assert_eq!(boxed, arena);
+    assert!(
+        arena_allocations * 100 < boxed_allocations,
+        "{arena_allocations} allocations in the arena, {boxed_allocations} without it"
+    );
+}
+
+#[test]
+fn arena_chunks_are_freed() {
+    let source = source();
+
+    // Initialize the lazy statics used by the parser.
+    drop(parse(&source, false));
+
+    let live = LIVE.with(Cell::get);
+    let (script, _) = parse(&source, true);
+    drop(script);
+    assert_eq!(LIVE.with(Cell::get), live);
+}
+
+#[test]
+fn arena_nodes_outlive_the_arena() {
+    let source = source();
+    let (script, _) = parse(&source, true);
+
+    // The nodes can be used and freed from other threads.
+    std::thread::spawn(move || {
+        let cloned = script.clone();
+        assert_eq!(cloned, script);
+    })
+    .join()
+    .expect("the thread must not panic");
+}
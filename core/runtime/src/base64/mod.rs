@@ -0,0 +1,165 @@
+//! Boa's implementation of the `atob` and `btoa` Web APIs.
+//!
+//! Both functions work with "binary strings", where every code unit represents a single byte.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG specification][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#atob
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/atob
+
+#[cfg(test)]
+mod tests;
+
+use crate::dom_exception::DomException;
+use boa_engine::{
+    js_string, native_function::NativeFunction, Context, JsArgs, JsResult, JsString, JsValue,
+};
+
+/// The characters of the base64 alphabet, indexed by their value.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The `atob` and `btoa` functions.
+#[derive(Debug, Clone, Copy)]
+pub struct Base64;
+
+impl Base64 {
+    /// Registers the `atob` and `btoa` functions in the global object of the context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the functions cannot be defined in the global object.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_builtin_callable(
+            js_string!("atob"),
+            1,
+            NativeFunction::from_fn_ptr(Self::atob),
+        )?;
+        context.register_global_builtin_callable(
+            js_string!("btoa"),
+            1,
+            NativeFunction::from_fn_ptr(Self::btoa),
+        )?;
+
+        Ok(())
+    }
+
+    /// `atob(data)`
+    ///
+    /// Decodes `data` using the [forgiving-base64 decode][spec] algorithm.
+    ///
+    /// [spec]: https://infra.spec.whatwg.org/#forgiving-base64-decode
+    fn atob(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let data = args.get_or_undefined(0).to_string(context)?;
+
+        match decode(&data) {
+            Some(bytes) => {
+                let units = bytes.into_iter().map(u16::from).collect::<Vec<_>>();
+                Ok(JsString::from(units).into())
+            }
+            None => Err(DomException::InvalidCharacterError.to_error(
+                "atob: the string to be decoded is not correctly encoded",
+                context,
+            )),
+        }
+    }
+
+    /// `btoa(data)`
+    ///
+    /// Encodes the binary string `data` using the [forgiving-base64 encode][spec] algorithm.
+    ///
+    /// [spec]: https://infra.spec.whatwg.org/#forgiving-base64-encode
+    fn btoa(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let data = args.get_or_undefined(0).to_string(context)?;
+
+        // 1. If data contains any code unit greater than U+00FF, then throw an
+        //    "InvalidCharacterError" DOMException.
+        let Some(bytes) = data
+            .iter()
+            .map(|unit| u8::try_from(unit).ok())
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Err(DomException::InvalidCharacterError.to_error(
+                "btoa: the string to be encoded contains characters outside of the Latin1 range",
+                context,
+            ));
+        };
+
+        // 2-3. Return the result of forgiving-base64 encoding the bytes of data.
+        Ok(JsString::from(encode(&bytes)).into())
+    }
+}
+
+/// Encodes `bytes` as base64, with padding.
+fn encode(bytes: &[u8]) -> String {
+    let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk.iter().enumerate().fold(0u32, |buffer, (i, &byte)| {
+            buffer | (u32::from(byte) << (16 - 8 * i))
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(char::from(
+                    ALPHABET[((buffer >> (18 - 6 * i)) & 0x3F) as usize],
+                ));
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+/// Decodes the base64 string `data`, returning `None` if it is not correctly encoded.
+fn decode(data: &JsString) -> Option<Vec<u8>> {
+    // 1. Remove all ASCII whitespace from data.
+    let mut data = data
+        .iter()
+        .filter(|unit| !matches!(unit, 0x09 | 0x0A | 0x0C | 0x0D | 0x20))
+        .collect::<Vec<_>>();
+
+    // 2. If data's code point length divides by 4 leaving no remainder, then:
+    //     a. If data ends with one or two U+003D (=) code points, then remove them from data.
+    if data.len() % 4 == 0 {
+        for _ in 0..2 {
+            if data.last() == Some(&u16::from(b'=')) {
+                data.pop();
+            }
+        }
+    }
+
+    // 3. If data's code point length divides by 4 leaving a remainder of 1, return failure.
+    if data.len() % 4 == 1 {
+        return None;
+    }
+
+    // 4. If data contains a code point that is not one of U+002B (+), U+002F (/) or ASCII
+    //    alphanumeric, then return failure.
+    // 5-8. Decode every code point as 6 bits, appending a byte to the output every time 8 bits
+    //      are available. If the input ends with 12 or 18 bits, the last 4 or 2 bits are
+    //      discarded.
+    let mut output = Vec::with_capacity(data.len() / 4 * 3 + 2);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for unit in data {
+        let value = match u8::try_from(unit).ok()? {
+            c @ b'A'..=b'Z' => c - b'A',
+            c @ b'a'..=b'z' => c - b'a' + 26,
+            c @ b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            #[allow(clippy::cast_possible_truncation)]
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(output)
+}
@@ -0,0 +1,129 @@
+use super::Base64;
+use crate::test::{run_test_actions_with, TestAction};
+use boa_engine::{js_string, Context};
+use indoc::indoc;
+
+fn context() -> Context {
+    let mut context = Context::default();
+    Base64::register(&mut context).unwrap();
+    context
+}
+
+#[test]
+fn encode() {
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("btoa('')", js_string!("")),
+            TestAction::assert_eq("btoa('f')", js_string!("Zg==")),
+            TestAction::assert_eq("btoa('fo')", js_string!("Zm8=")),
+            TestAction::assert_eq("btoa('foo')", js_string!("Zm9v")),
+            TestAction::assert_eq("btoa('foobar')", js_string!("Zm9vYmFy")),
+            TestAction::assert_eq("btoa('\\xFF\\xFE\\x00')", js_string!("//4A")),
+            TestAction::assert_eq("btoa(null)", js_string!("bnVsbA==")),
+            TestAction::assert_eq("btoa.length", 1),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn decode() {
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("atob('')", js_string!("")),
+            TestAction::assert_eq("atob('Zm9vYmFy')", js_string!("foobar")),
+            TestAction::assert_eq("atob('Zg==')", js_string!("f")),
+            TestAction::assert_eq("atob('Zm8=')", js_string!("fo")),
+            TestAction::assert_eq("atob('Zg')", js_string!("f")),
+            TestAction::assert_eq("atob('Zm8')", js_string!("fo")),
+            TestAction::assert_eq("atob(' Zm\\t9v\\nYm\\fFy\\r ')", js_string!("foobar")),
+            TestAction::assert_eq("atob('//4A')", js_string!("\u{FF}\u{FE}\0")),
+            // The bits that don't make a full byte are discarded.
+            TestAction::assert_eq("atob('Zh')", js_string!("f")),
+            TestAction::assert_eq("atob.length", 1),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn round_trip_binary_strings() {
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                var all = '';
+                for (var i = 0; i <= 0xFF; i++) {
+                    all += String.fromCharCode(i);
+                }
+            "#}),
+            TestAction::assert("atob(btoa(all)) === all"),
+            TestAction::assert(indoc! {r#"
+                [1, 2, 3, 4, 5, 6].every(length => {
+                    for (var start = 0; start + length <= all.length; start++) {
+                        var data = all.slice(start, start + length);
+                        if (atob(btoa(data)) !== data) {
+                            return false;
+                        }
+                    }
+                    return true;
+                })
+            "#}),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn invalid_characters() {
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                function error(f) {
+                    try {
+                        f();
+                    } catch (e) {
+                        return e instanceof Error && `${e.name} ${e.code}`;
+                    }
+                    return 'no error';
+                }
+            "#}),
+            TestAction::assert_eq(
+                r#"error(() => btoa("\u{1F600}"))"#,
+                js_string!("InvalidCharacterError 5"),
+            ),
+            TestAction::assert_eq(
+                "error(() => btoa('\\u0100'))",
+                js_string!("InvalidCharacterError 5"),
+            ),
+            TestAction::assert_eq(
+                "error(() => atob('Z'))",
+                js_string!("InvalidCharacterError 5"),
+            ),
+            TestAction::assert_eq(
+                "error(() => atob('Zm9vY'))",
+                js_string!("InvalidCharacterError 5"),
+            ),
+            TestAction::assert_eq(
+                "error(() => atob('Zg=='.repeat(2)))",
+                js_string!("InvalidCharacterError 5"),
+            ),
+            TestAction::assert_eq(
+                "error(() => atob('Zg='))",
+                js_string!("InvalidCharacterError 5"),
+            ),
+            TestAction::assert_eq(
+                "error(() => atob('Zm9v='))",
+                js_string!("InvalidCharacterError 5"),
+            ),
+            TestAction::assert_eq(
+                "error(() => atob('Zm9-'))",
+                js_string!("InvalidCharacterError 5"),
+            ),
+            TestAction::assert_eq(
+                "error(() => atob('Zm\\u00A09v'))",
+                js_string!("InvalidCharacterError 5"),
+            ),
+        ],
+        &mut context(),
+    );
+}
@@ -14,129 +14,278 @@
 #[cfg(test)]
 mod tests;
 
+mod table;
+
+use self::table::Table;
+use crate::{Clock, HostClock};
 use boa_engine::{
+    context::DefaultHooks,
     js_string,
     native_function::NativeFunction,
-    object::{JsObject, ObjectInitializer},
+    object::{
+        builtins::{JsArray, JsMap, JsSet},
+        JsObject, ObjectInitializer,
+    },
+    property::PropertyNameKind,
     string::utf16,
-    value::{JsValue, Numeric},
-    Context, JsArgs, JsData, JsResult, JsString,
+    value::JsValue,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString,
 };
 use boa_gc::{Finalize, Trace};
 // use boa_profiler::Profiler;
 use rustc_hash::FxHashMap;
-use std::{cell::RefCell, rc::Rc, time::SystemTime};
-
-/// This represents the different types of log messages.
-#[derive(Debug)]
-enum LogMessage {
-    Log(String),
-    Info(String),
-    Warn(String),
-    Error(String),
+use std::{
+    cell::RefCell,
+    fmt::Write,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+/// The log level of a message printed by the console.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogLevel {
+    /// Messages printed by `console.trace`.
+    Trace,
+    /// Messages printed by `console.debug`.
+    Debug,
+    /// Messages printed by `console.log` and `console.timeLog`.
+    Log,
+    /// Messages printed by `console.info` and most of the other methods, e.g. `console.count`.
+    Info,
+    /// Warnings, e.g. printed by `console.warn` or when using a timer that doesn't exist.
+    Warn,
+    /// Messages printed by `console.error` and failed assertions.
+    Error,
+}
+
+/// The destination of the messages printed by the console.
+///
+/// Implementing this trait allows embedders to redirect the output of the console, e.g. to their
+/// own logging pipeline or to a buffer. Loggers are not traced by the garbage collector, so they
+/// must not store JavaScript values.
+pub trait Logger {
+    /// Prints a formatted message with the given level.
+    ///
+    /// `indent` is the nesting level of the message, i.e. the number of groups open when it was
+    /// printed, and `console` is the state of the console, e.g. to get the labels of the groups.
+    fn log(&self, level: LogLevel, message: String, indent: usize, console: &Console);
 }
 
-/// Helper function for logging messages.
-fn logger(msg: LogMessage, console_state: &Console) {
-    let indent = 2 * console_state.groups.len();
+/// The default [`Logger`], printing errors to the standard error and all the other messages to
+/// the standard output, indented by two spaces for every group.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultLogger;
 
-    match msg {
-        LogMessage::Error(msg) => {
-            eprintln!("{msg:>indent$}");
-        }
-        LogMessage::Log(msg) | LogMessage::Info(msg) | LogMessage::Warn(msg) => {
-            println!("{msg:>indent$}");
+impl Logger for DefaultLogger {
+    fn log(&self, level: LogLevel, message: String, indent: usize, _: &Console) {
+        let message = indented(&message, indent);
+
+        match level {
+            LogLevel::Error => {
+                eprintln!("{message}");
+            }
+            LogLevel::Trace | LogLevel::Debug | LogLevel::Log | LogLevel::Info | LogLevel::Warn => {
+                println!("{message}");
+            }
         }
     }
 }
 
+/// Prefixes every line of a message with two spaces for each level of `indent`.
+fn indented(message: &str, indent: usize) -> String {
+    let prefix = "  ".repeat(indent);
+    message
+        .split('\n')
+        .map(|line| format!("{prefix}{line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// This represents the `console` formatter.
+///
+/// If the first argument is a string, its format specifiers are replaced by the following
+/// arguments, and the arguments left are appended to the message, separated by spaces.
+/// Specifiers without a matching argument are printed verbatim, and a format string without
+/// arguments is printed as is, like the [`Logger`][logger] does.
+///
+/// More information:
+///  - [WHATWG `console` specification][spec]
+///
+/// [spec]: https://console.spec.whatwg.org/#formatter
+/// [logger]: https://console.spec.whatwg.org/#logger
 fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
-    match data {
-        [] => Ok(String::new()),
-        [val] => Ok(val.to_string(context)?.to_std_string_escaped()),
-        data => {
-            let mut formatted = String::new();
-            let mut arg_index = 1;
-            let target = data
-                .get_or_undefined(0)
-                .to_string(context)?
-                .to_std_string_escaped();
-            let mut chars = target.chars();
-            while let Some(c) = chars.next() {
-                if c == '%' {
-                    let fmt = chars.next().unwrap_or('%');
-                    match fmt {
-                        /* integer */
-                        'd' | 'i' => {
-                            let arg = match data.get_or_undefined(arg_index).to_numeric(context)? {
-                                Numeric::Number(r) => (r.floor() + 0.0).to_string(),
-                                Numeric::BigInt(int) => int.to_string(),
-                            };
-                            formatted.push_str(&arg);
-                            arg_index += 1;
-                        }
-                        /* float */
-                        'f' => {
-                            let arg = data.get_or_undefined(arg_index).to_number(context)?;
-                            formatted.push_str(&format!("{arg:.6}"));
-                            arg_index += 1;
-                        }
-                        /* object, FIXME: how to render this properly? */
-                        'o' | 'O' => {
-                            let arg = data.get_or_undefined(arg_index);
-                            formatted.push_str(&arg.display().to_string());
-                            arg_index += 1;
-                        }
-                        /* string */
-                        's' => {
-                            let arg = data
-                                .get_or_undefined(arg_index)
-                                .to_string(context)?
-                                .to_std_string_escaped();
-                            formatted.push_str(&arg);
-                            arg_index += 1;
-                        }
-                        '%' => formatted.push('%'),
-                        /* TODO: %c is not implemented */
-                        c => {
-                            formatted.push('%');
-                            formatted.push(c);
-                        }
-                    }
-                } else {
-                    formatted.push(c);
+    let Some((first, mut rest)) = data.split_first() else {
+        return Ok(String::new());
+    };
+    if rest.is_empty() {
+        return to_display_string(first, context);
+    }
+
+    let Some(target) = first.as_string() else {
+        let mut formatted = to_display_string(first, context)?;
+        for arg in rest {
+            formatted.push(' ');
+            formatted.push_str(&to_display_string(arg, context)?);
+        }
+        return Ok(formatted);
+    };
+
+    let target = target.to_std_string_escaped();
+    let mut formatted = String::new();
+    let mut chars = target.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            formatted.push(c);
+            continue;
+        }
+
+        let Some(&fmt) = chars.peek() else {
+            formatted.push('%');
+            break;
+        };
+        match (fmt, rest.split_first()) {
+            ('%', _) => formatted.push('%'),
+            /* integer */
+            ('d' | 'i', Some((arg, remaining))) => {
+                let arg = match arg {
+                    JsValue::BigInt(int) => int.to_string(),
+                    JsValue::Object(_) | JsValue::Symbol(_) => "NaN".to_owned(),
+                    arg => JsValue::new(arg.to_number(context)?.trunc())
+                        .to_string(context)?
+                        .to_std_string_escaped(),
                 };
+                formatted.push_str(&arg);
+                rest = remaining;
             }
-
-            /* unformatted data */
-            for rest in data.iter().skip(arg_index) {
-                formatted.push_str(&format!(
-                    " {}",
-                    rest.to_string(context)?.to_std_string_escaped()
-                ));
+            /* float */
+            ('f', Some((arg, remaining))) => {
+                let arg = match arg {
+                    JsValue::BigInt(int) => int.to_f64(),
+                    JsValue::Object(_) | JsValue::Symbol(_) => f64::NAN,
+                    arg => arg.to_number(context)?,
+                };
+                if arg.is_finite() {
+                    write!(formatted, "{arg:.6}").expect("writing to a string cannot fail");
+                } else {
+                    formatted.push_str(&JsValue::new(arg).display().to_string());
+                }
+                rest = remaining;
+            }
+            /* object, FIXME: how to render this properly? */
+            ('o' | 'O', Some((arg, remaining))) => {
+                formatted.push_str(&arg.display().to_string());
+                rest = remaining;
+            }
+            /* string */
+            ('s', Some((arg, remaining))) => {
+                formatted.push_str(&to_display_string(arg, context)?);
+                rest = remaining;
+            }
+            /* CSS styles are ignored */
+            ('c', Some((_, remaining))) => rest = remaining,
+            _ => {
+                // Unknown specifiers, and specifiers without an argument, are printed verbatim.
+                formatted.push('%');
+                continue;
             }
-
-            Ok(formatted)
         }
+        chars.next();
+    }
+
+    /* unformatted data */
+    for arg in rest {
+        formatted.push(' ');
+        formatted.push_str(&to_display_string(arg, context)?);
     }
+
+    Ok(formatted)
+}
+
+/// Converts a value to the string printed by the console, which is its string conversion except
+/// for symbols, which cannot be converted to strings implicitly.
+fn to_display_string(value: &JsValue, context: &mut Context) -> JsResult<String> {
+    Ok(match value {
+        JsValue::Symbol(symbol) => symbol.descriptive_string().to_std_string_escaped(),
+        value => value.to_string(context)?.to_std_string_escaped(),
+    })
 }
 
 /// This is the internal console object state.
-#[derive(Debug, Default, Trace, Finalize, JsData)]
+#[derive(Trace, Finalize, JsData)]
 pub struct Console {
     count_map: FxHashMap<JsString, u32>,
-    timer_map: FxHashMap<JsString, u128>,
+    #[unsafe_ignore_trace]
+    timer_map: FxHashMap<JsString, Instant>,
     groups: Vec<String>,
+    #[unsafe_ignore_trace]
+    logger: Box<dyn Logger>,
+    #[unsafe_ignore_trace]
+    clock: Rc<dyn Clock>,
+}
+
+impl Default for Console {
+    fn default() -> Self {
+        Self {
+            count_map: FxHashMap::default(),
+            timer_map: FxHashMap::default(),
+            groups: Vec::new(),
+            logger: Box::new(DefaultLogger),
+            clock: Rc::new(HostClock::new(&DefaultHooks)),
+        }
+    }
+}
+
+impl std::fmt::Debug for Console {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Console")
+            .field("count_map", &self.count_map)
+            .field("timer_map", &self.timer_map)
+            .field("groups", &self.groups)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Console {
     /// Name of the built-in `console` property.
     pub const NAME: &'static str = "console";
 
-    /// Initializes the `console` built-in object.
-    #[allow(clippy::too_many_lines)]
+    /// Gets the labels of the currently open groups, from the outermost one.
+    #[must_use]
+    pub fn groups(&self) -> &[String] {
+        &self.groups
+    }
+
+    /// Prints a message with the logger of the console.
+    fn print(&self, level: LogLevel, message: String) {
+        self.logger.log(level, message, self.groups.len(), self);
+    }
+
+    /// Initializes the `console` built-in object, printing with the [`DefaultLogger`].
     pub fn init(context: &mut Context) -> JsObject {
+        Self::init_with_logger(DefaultLogger, context)
+    }
+
+    /// Initializes the `console` built-in object, printing all the messages with `logger` and
+    /// measuring the durations of the timers with the [`HostClock`] of the context.
+    pub fn init_with_logger<L>(logger: L, context: &mut Context) -> JsObject
+    where
+        L: Logger + 'static,
+    {
+        let clock = HostClock::new(context.host_hooks());
+        Self::init_with_logger_and_clock(logger, Rc::new(clock), context)
+    }
+
+    /// Initializes the `console` built-in object, printing all the messages with `logger` and
+    /// measuring the durations of the timers with `clock`.
+    #[allow(clippy::too_many_lines)]
+    pub fn init_with_logger_and_clock<L>(
+        logger: L,
+        clock: Rc<dyn Clock>,
+        context: &mut Context,
+    ) -> JsObject
+    where
+        L: Logger + 'static,
+    {
         fn console_method(
             f: fn(&JsValue, &[JsValue], &Console, &mut Context) -> JsResult<JsValue>,
             state: Rc<RefCell<Console>>,
@@ -161,7 +310,10 @@ impl Console {
         }
         // let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
-        let state = Rc::new(RefCell::new(Self::default()));
+        let mut console = Self::default();
+        console.logger = Box::new(logger);
+        console.clock = clock;
+        let state = Rc::new(RefCell::new(console));
 
         ObjectInitializer::with_native_data(Self::default(), context)
             .function(
@@ -249,7 +401,12 @@ impl Console {
                 js_string!("dir"),
                 0,
             )
-            .function(console_method(Self::dir, state), js_string!("dirxml"), 0)
+            .function(
+                console_method(Self::dir, state.clone()),
+                js_string!("dirxml"),
+                0,
+            )
+            .function(console_method(Self::table, state), js_string!("table"), 1)
             .build()
     }
 
@@ -275,17 +432,14 @@ impl Console {
         if !assertion {
             let mut args: Vec<JsValue> = args.iter().skip(1).cloned().collect();
             let message = js_string!("Assertion failed");
-            if args.is_empty() {
-                args.push(JsValue::new(message));
-            } else if !args[0].is_string() {
-                args.insert(0, JsValue::new(message));
-            } else {
-                let value: Vec<u16> = args[0].display().to_string().encode_utf16().collect();
-                let concat = js_string!(&message, utf16!(": "), &value);
+            if let Some(first) = args.first().and_then(JsValue::as_string) {
+                let concat = js_string!(&message, utf16!(": "), first);
                 args[0] = JsValue::new(concat);
+            } else {
+                args.insert(0, JsValue::new(message));
             }
 
-            logger(LogMessage::Error(formatter(&args, context)?), console);
+            console.print(LogLevel::Error, formatter(&args, context)?);
         }
 
         Ok(JsValue::undefined())
@@ -323,7 +477,7 @@ impl Console {
         console: &Self,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Log(formatter(args, context)?), console);
+        console.print(LogLevel::Debug, formatter(args, context)?);
         Ok(JsValue::undefined())
     }
 
@@ -343,7 +497,7 @@ impl Console {
         console: &Self,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Error(formatter(args, context)?), console);
+        console.print(LogLevel::Error, formatter(args, context)?);
         Ok(JsValue::undefined())
     }
 
@@ -363,7 +517,7 @@ impl Console {
         console: &Self,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Info(formatter(args, context)?), console);
+        console.print(LogLevel::Info, formatter(args, context)?);
         Ok(JsValue::undefined())
     }
 
@@ -383,7 +537,7 @@ impl Console {
         console: &Self,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Log(formatter(args, context)?), console);
+        console.print(LogLevel::Log, formatter(args, context)?);
         Ok(JsValue::undefined())
     }
 
@@ -404,7 +558,7 @@ impl Console {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         if !args.is_empty() {
-            logger(LogMessage::Log(formatter(args, context)?), console);
+            console.print(LogLevel::Trace, formatter(args, context)?);
         }
 
         let stack_trace_dump = context
@@ -415,7 +569,7 @@ impl Console {
             .map(JsString::to_std_string_escaped)
             .collect::<Vec<_>>()
             .join("\n");
-        logger(LogMessage::Log(stack_trace_dump), console);
+        console.print(LogLevel::Trace, stack_trace_dump);
 
         Ok(JsValue::undefined())
     }
@@ -436,7 +590,7 @@ impl Console {
         console: &Self,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Warn(formatter(args, context)?), console);
+        console.print(LogLevel::Warn, formatter(args, context)?);
         Ok(JsValue::undefined())
     }
 
@@ -461,11 +615,12 @@ impl Console {
             None => "default".into(),
         };
 
-        let msg = format!("count {}:", label.to_std_string_escaped());
+        let msg = format!("{}:", label.to_std_string_escaped());
         let c = console.count_map.entry(label).or_insert(0);
         *c += 1;
 
-        logger(LogMessage::Info(format!("{msg} {c}")), console);
+        let msg = format!("{msg} {c}");
+        console.print(LogLevel::Info, msg);
         Ok(JsValue::undefined())
     }
 
@@ -490,22 +645,31 @@ impl Console {
             None => "default".into(),
         };
 
-        console.count_map.remove(&label);
-
-        logger(
-            LogMessage::Warn(format!("countReset {}", label.to_std_string_escaped())),
-            console,
-        );
+        if let Some(count) = console.count_map.get_mut(&label) {
+            *count = 0;
+        } else {
+            console.print(
+                LogLevel::Warn,
+                format!(
+                    "Count for '{}' does not exist",
+                    label.to_std_string_escaped()
+                ),
+            );
+        }
 
         Ok(JsValue::undefined())
     }
 
-    /// Returns current system time in ms.
-    fn system_time_in_ms() -> u128 {
-        let now = SystemTime::now();
-        now.duration_since(SystemTime::UNIX_EPOCH)
-            .expect("negative duration")
-            .as_millis()
+    /// Formats the duration of a timer in milliseconds, with up to three decimals.
+    fn format_duration(label: &JsString, duration: Duration) -> String {
+        // Rounding to microseconds avoids the floating point noise of the conversion.
+        #[allow(clippy::cast_precision_loss)]
+        let ms = duration.as_micros() as f64 / 1000.0;
+        format!(
+            "{}: {}ms",
+            label.to_std_string_escaped(),
+            JsValue::new(ms).display()
+        )
     }
 
     /// `console.time(label)`
@@ -530,15 +694,12 @@ impl Console {
         };
 
         if console.timer_map.get(&label).is_some() {
-            logger(
-                LogMessage::Warn(format!(
-                    "Timer '{}' already exist",
-                    label.to_std_string_escaped()
-                )),
-                console,
+            console.print(
+                LogLevel::Warn,
+                format!("Timer '{}' already exist", label.to_std_string_escaped()),
             );
         } else {
-            let time = Self::system_time_in_ms();
+            let time = console.clock.now();
             console.timer_map.insert(label, time);
         }
 
@@ -566,25 +727,19 @@ impl Console {
             None => "default".into(),
         };
 
-        console.timer_map.get(&label).map_or_else(
-            || {
-                logger(
-                    LogMessage::Warn(format!(
-                        "Timer '{}' doesn't exist",
-                        label.to_std_string_escaped()
-                    )),
-                    console,
-                );
-            },
-            |t| {
-                let time = Self::system_time_in_ms();
-                let mut concat = format!("{}: {} ms", label.to_std_string_escaped(), time - t);
-                for msg in args.iter().skip(1) {
-                    concat = concat + " " + &msg.display().to_string();
-                }
-                logger(LogMessage::Log(concat), console);
-            },
-        );
+        if let Some(&t) = console.timer_map.get(&label) {
+            let mut concat = Self::format_duration(&label, console.clock.now() - t);
+            for msg in args.iter().skip(1) {
+                concat.push(' ');
+                concat.push_str(&to_display_string(msg, context)?);
+            }
+            console.print(LogLevel::Log, concat);
+        } else {
+            console.print(
+                LogLevel::Warn,
+                format!("Timer '{}' doesn't exist", label.to_std_string_escaped()),
+            );
+        }
 
         Ok(JsValue::undefined())
     }
@@ -612,33 +767,24 @@ impl Console {
 
         console.timer_map.remove(&label).map_or_else(
             || {
-                logger(
-                    LogMessage::Warn(format!(
-                        "Timer '{}' doesn't exist",
-                        label.to_std_string_escaped()
-                    )),
-                    console,
+                console.print(
+                    LogLevel::Warn,
+                    format!("Timer '{}' doesn't exist", label.to_std_string_escaped()),
                 );
             },
             |t| {
-                let time = Self::system_time_in_ms();
-                logger(
-                    LogMessage::Info(format!(
-                        "{}: {} ms - timer removed",
-                        label.to_std_string_escaped(),
-                        time - t
-                    )),
-                    console,
-                );
+                let duration = console.clock.now() - t;
+                console.print(LogLevel::Info, Self::format_duration(&label, duration));
             },
         );
 
         Ok(JsValue::undefined())
     }
 
-    /// `console.group(...data)`
+    /// `console.group(...data)` and `console.groupCollapsed(...data)`
     ///
-    /// Adds new group with name from formatted data to stack.
+    /// Prints the formatted data as the label of a new group, and adds the group to the stack,
+    /// indenting the following messages until the group ends.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
@@ -654,7 +800,7 @@ impl Console {
     ) -> JsResult<JsValue> {
         let group_label = formatter(args, context)?;
 
-        logger(LogMessage::Info(format!("group: {group_label}")), console);
+        console.print(LogLevel::Info, group_label.clone());
         console.groups.push(group_label);
 
         Ok(JsValue::undefined())
@@ -662,7 +808,7 @@ impl Console {
 
     /// `console.groupEnd(label)`
     ///
-    /// Removes the last group from the stack.
+    /// Removes the last group from the stack, if there is any.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
@@ -694,10 +840,128 @@ impl Console {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/dir
     #[allow(clippy::unnecessary_wraps)]
     fn dir(_: &JsValue, args: &[JsValue], console: &Self, _: &mut Context) -> JsResult<JsValue> {
-        logger(
-            LogMessage::Info(args.get_or_undefined(0).display_obj(true)),
-            console,
-        );
+        console.print(LogLevel::Info, args.get_or_undefined(0).display_obj(true));
+        Ok(JsValue::undefined())
+    }
+
+    /// `console.table(tabularData, properties)`
+    ///
+    /// Prints the tabular data as a grid, with a row for each of its entries and a column for
+    /// each of their properties. Data that cannot be rendered as a table is logged instead.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [WHATWG `console` specification][spec]
+    ///
+    /// [spec]: https://console.spec.whatwg.org/#table
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/table
+    fn table(
+        this: &JsValue,
+        args: &[JsValue],
+        console: &Self,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let Some(data) = args.get_or_undefined(0).as_object() else {
+            return Self::log(this, args, console, context);
+        };
+
+        let properties = match args.get_or_undefined(1) {
+            JsValue::Undefined => None,
+            properties => {
+                let properties = properties
+                    .as_object()
+                    .and_then(|properties| JsArray::from_object(properties.clone()).ok())
+                    .ok_or_else(|| {
+                        JsNativeError::typ()
+                            .with_message("console.table: the properties must be an array")
+                    })?;
+                let length = properties.length(context)?;
+                let mut keys = Vec::new();
+                for index in 0..length {
+                    keys.push(properties.get(index, context)?.to_property_key(context)?);
+                }
+                Some(keys)
+            }
+        };
+
+        // Maps and sets are indexed by their iteration order, and maps also get a column for
+        // their keys.
+        let (mut table, entries) = if let Ok(map) = JsMap::from_object(data.clone()) {
+            let iterator = map.entries(context)?;
+            let mut entries = Vec::new();
+            for (position, entry) in iterator_values(|context| iterator.next(context), context)?
+                .into_iter()
+                .enumerate()
+            {
+                let entry = entry.to_object(context)?;
+                let key = formatter(&[entry.get(0, context)?], context)?;
+                entries.push((position.to_string(), Some(key), entry.get(1, context)?));
+            }
+            (Table::new("(iteration index)", true), entries)
+        } else if let Ok(set) = JsSet::from_object(data.clone()) {
+            let iterator = set.values(context)?;
+            let entries = iterator_values(|context| iterator.next(context), context)?
+                .into_iter()
+                .enumerate()
+                .map(|(position, value)| (position.to_string(), None, value))
+                .collect();
+            (Table::new("(iteration index)", false), entries)
+        } else {
+            let mut entries = Vec::new();
+            for key in data.enumerable_own_property_names(PropertyNameKind::Key, context)? {
+                let key = key.to_property_key(context)?;
+                let value = data.get(key.clone(), context)?;
+                entries.push((key.to_string(), None, value));
+            }
+            (Table::new("(index)", false), entries)
+        };
+
+        if let Some(properties) = &properties {
+            for key in properties {
+                table.add_column(&key.to_string());
+            }
+        }
+
+        for (index, key, value) in entries {
+            match value.as_object() {
+                Some(row) if !row.is_callable() => {
+                    let keys = if let Some(properties) = &properties {
+                        properties.clone()
+                    } else {
+                        row.enumerable_own_property_names(PropertyNameKind::Key, context)?
+                            .into_iter()
+                            .map(|key| key.to_property_key(context))
+                            .collect::<JsResult<_>>()?
+                    };
+                    let mut cells = Vec::with_capacity(keys.len());
+                    for key in keys {
+                        if row.has_own_property(key.clone(), context)? {
+                            let cell = to_display_string(&row.get(key.clone(), context)?, context)?;
+                            cells.push((key.to_string(), cell));
+                        }
+                    }
+                    table.push_object(index, key, cells);
+                }
+                _ => table.push_value(index, key, to_display_string(&value, context)?),
+            }
+        }
+
+        console.print(LogLevel::Log, table.render());
         Ok(JsValue::undefined())
     }
 }
+
+/// Collects the remaining values of a built-in iterator.
+fn iterator_values<F>(mut next: F, context: &mut Context) -> JsResult<Vec<JsValue>>
+where
+    F: FnMut(&mut Context) -> JsResult<JsValue>,
+{
+    let mut values = Vec::new();
+    loop {
+        let result = next(context)?.to_object(context)?;
+        if result.get(js_string!("done"), context)?.to_boolean() {
+            return Ok(values);
+        }
+        values.push(result.get(js_string!("value"), context)?);
+    }
+}
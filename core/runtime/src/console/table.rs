@@ -0,0 +1,155 @@
+//! Rendering of the tabular data printed by `console.table`.
+
+use rustc_hash::FxHashMap;
+
+/// The maximum width of a cell, in characters, after which its content is truncated.
+const MAX_CELL_WIDTH: usize = 32;
+
+/// A row of a [`Table`].
+#[derive(Debug)]
+struct Row {
+    index: String,
+    key: Option<String>,
+    cells: FxHashMap<String, String>,
+    value: Option<String>,
+}
+
+/// The data of `console.table`, collected before being rendered as a grid.
+///
+/// Rows whose data is an object are split in a column for each of its properties, while other
+/// rows are rendered in the "Values" column.
+#[derive(Debug)]
+pub(super) struct Table {
+    index_header: &'static str,
+    has_keys: bool,
+    columns: Vec<String>,
+    has_values: bool,
+    rows: Vec<Row>,
+}
+
+impl Table {
+    /// Creates an empty table, with a "Key" column if `has_keys` is `true`.
+    pub(super) const fn new(index_header: &'static str, has_keys: bool) -> Self {
+        Self {
+            index_header,
+            has_keys,
+            columns: Vec::new(),
+            has_values: false,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Adds a column, unless the table already has it.
+    pub(super) fn add_column(&mut self, column: &str) {
+        if !self.columns.iter().any(|c| c == column) {
+            self.columns.push(column.to_owned());
+        }
+    }
+
+    /// Adds a row whose data is an object, with a cell for each of the given properties.
+    pub(super) fn push_object(
+        &mut self,
+        index: String,
+        key: Option<String>,
+        properties: Vec<(String, String)>,
+    ) {
+        for (column, _) in &properties {
+            self.add_column(column);
+        }
+        self.rows.push(Row {
+            index,
+            key,
+            cells: properties.into_iter().collect(),
+            value: None,
+        });
+    }
+
+    /// Adds a row whose data is rendered in the "Values" column.
+    pub(super) fn push_value(&mut self, index: String, key: Option<String>, value: String) {
+        self.has_values = true;
+        self.rows.push(Row {
+            index,
+            key,
+            cells: FxHashMap::default(),
+            value: Some(value),
+        });
+    }
+
+    /// Renders the table as a grid, with one line per row.
+    pub(super) fn render(&self) -> String {
+        let mut headers = vec![self.index_header.to_owned()];
+        if self.has_keys {
+            headers.push("Key".to_owned());
+        }
+        headers.extend(self.columns.iter().map(|column| truncate(column)));
+        if self.has_values {
+            headers.push("Values".to_owned());
+        }
+
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut cells = vec![truncate(&row.index)];
+                if self.has_keys {
+                    cells.push(row.key.as_deref().map(truncate).unwrap_or_default());
+                }
+                cells.extend(self.columns.iter().map(|column| {
+                    row.cells
+                        .get(column)
+                        .map(|c| truncate(c))
+                        .unwrap_or_default()
+                }));
+                if self.has_values {
+                    cells.push(row.value.as_deref().map(truncate).unwrap_or_default());
+                }
+                cells
+            })
+            .collect();
+
+        let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut lines = Vec::with_capacity(rows.len() + 4);
+        lines.push(border(&widths, '┌', '┬', '┐'));
+        lines.push(line(&widths, &headers));
+        lines.push(border(&widths, '├', '┼', '┤'));
+        lines.extend(rows.iter().map(|row| line(&widths, row)));
+        lines.push(border(&widths, '└', '┴', '┘'));
+        lines.join("\n")
+    }
+}
+
+/// Escapes the line terminators of a cell and truncates it to [`MAX_CELL_WIDTH`] characters.
+fn truncate(cell: &str) -> String {
+    let cell = cell.replace('\r', "\\r").replace('\n', "\\n");
+    if cell.chars().count() <= MAX_CELL_WIDTH {
+        return cell;
+    }
+    let mut truncated: String = cell.chars().take(MAX_CELL_WIDTH - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Renders a horizontal border of the grid.
+fn border(widths: &[usize], left: char, middle: char, right: char) -> String {
+    let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+    format!("{left}{}{right}", segments.join(&middle.to_string()))
+}
+
+/// Renders a line of cells, padded to the width of their column.
+fn line(widths: &[usize], cells: &[String]) -> String {
+    let cells: Vec<String> = widths
+        .iter()
+        .zip(cells)
+        .map(|(width, cell)| {
+            let padding = width - cell.chars().count();
+            format!(" {cell}{} ", " ".repeat(padding))
+        })
+        .collect();
+    format!("│{}│", cells.join("│"))
+}
@@ -1,7 +1,13 @@
-use super::{formatter, Console};
+use super::{formatter, indented, Console, LogLevel, Logger};
 use crate::test::{run_test_actions, run_test_actions_with, TestAction};
-use boa_engine::{js_string, property::Attribute, Context, JsValue};
+use crate::Clock;
+use boa_engine::{js_string, property::Attribute, Context, JsValue, Source};
 use indoc::indoc;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 #[test]
 fn formatter_no_args_is_empty_string() {
@@ -92,6 +98,78 @@ fn formatter_float_format_works() {
     })]);
 }
 
+#[test]
+fn formatter_substitutes_arguments() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        assert_eq!(
+            formatter(
+                &[
+                    JsValue::new(js_string!("count: %d items from %s")),
+                    JsValue::new(3),
+                    JsValue::new(js_string!("cache")),
+                ],
+                ctx
+            )
+            .unwrap(),
+            "count: 3 items from cache"
+        );
+    })]);
+}
+
+#[test]
+fn formatter_too_few_arguments_renders_specifiers_verbatim() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        assert_eq!(
+            formatter(
+                &[
+                    JsValue::new(js_string!("%s and %d, %c%o%%")),
+                    JsValue::new(js_string!("a")),
+                ],
+                ctx
+            )
+            .unwrap(),
+            "a and %d, %c%o%"
+        );
+    })]);
+}
+
+#[test]
+fn formatter_too_many_arguments_are_appended() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        assert_eq!(
+            formatter(
+                &[
+                    JsValue::new(js_string!("%s:")),
+                    JsValue::new(js_string!("a")),
+                    JsValue::new(1),
+                    JsValue::new(js_string!("%s")),
+                ],
+                ctx
+            )
+            .unwrap(),
+            "a: 1 %s"
+        );
+    })]);
+}
+
+#[test]
+fn formatter_only_formats_string_targets() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        assert_eq!(
+            formatter(
+                &[
+                    JsValue::new(1),
+                    JsValue::new(js_string!("%d")),
+                    JsValue::new(2)
+                ],
+                ctx
+            )
+            .unwrap(),
+            "1 %d 2"
+        );
+    })]);
+}
+
 #[test]
 fn console_log_cyclic() {
     let mut context = Context::default();
@@ -110,3 +188,337 @@ fn console_log_cyclic() {
     );
     // Should not stack overflow
 }
+
+/// A logger that records the messages, prefixed by the labels of the open groups.
+#[derive(Clone, Default)]
+struct RecordingLogger {
+    messages: Rc<RefCell<Vec<(LogLevel, String)>>>,
+}
+
+impl Logger for RecordingLogger {
+    fn log(&self, level: LogLevel, message: String, _: usize, console: &Console) {
+        let mut prefix = console.groups().join("/");
+        if !prefix.is_empty() {
+            prefix.push_str(": ");
+        }
+        self.messages
+            .borrow_mut()
+            .push((level, format!("{prefix}{message}")));
+    }
+}
+
+#[test]
+fn custom_logger() {
+    let logger = RecordingLogger::default();
+    let mut context = Context::default();
+    let console = Console::init_with_logger(logger.clone(), &mut context);
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+                console.log('a', 1);
+                console.warn('b %d', 2);
+                console.error('c');
+                console.group('outer');
+                console.info('d');
+                console.group('inner');
+                console.count();
+                console.count();
+                console.groupEnd();
+                console.debug('e');
+                console.groupEnd();
+                console.assert(false, 3);
+                console.assert(true, 'not logged');
+                console.countReset('missing');
+            "#})],
+        &mut context,
+    );
+
+    assert_eq!(
+        *logger.messages.borrow(),
+        [
+            (LogLevel::Log, "a 1".to_owned()),
+            (LogLevel::Warn, "b 2".to_owned()),
+            (LogLevel::Error, "c".to_owned()),
+            (LogLevel::Info, "outer".to_owned()),
+            (LogLevel::Info, "outer: d".to_owned()),
+            (LogLevel::Info, "outer: inner".to_owned()),
+            (LogLevel::Info, "outer/inner: default: 1".to_owned()),
+            (LogLevel::Info, "outer/inner: default: 2".to_owned()),
+            (LogLevel::Debug, "outer: e".to_owned()),
+            (LogLevel::Error, "Assertion failed 3".to_owned()),
+            (
+                LogLevel::Warn,
+                "Count for 'missing' does not exist".to_owned()
+            ),
+        ]
+    );
+}
+
+/// Runs `source` with a console printing to a [`RecordingLogger`], returning the messages.
+fn recorded_messages(source: &'static str) -> Vec<(LogLevel, String)> {
+    let logger = RecordingLogger::default();
+    let mut context = Context::default();
+    let console = Console::init_with_logger(logger.clone(), &mut context);
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with([TestAction::run(source)], &mut context);
+
+    logger.messages.take()
+}
+
+#[test]
+fn table_objects_with_missing_keys() {
+    let messages =
+        recorded_messages("console.table([{ a: 1, b: 'x' }, { b: 'y', c: true }, { a: 3 }]);");
+
+    assert_eq!(
+        messages,
+        [(
+            LogLevel::Log,
+            indoc! {"
+                ┌─────────┬───┬───┬──────┐
+                │ (index) │ a │ b │ c    │
+                ├─────────┼───┼───┼──────┤
+                │ 0       │ 1 │ x │      │
+                │ 1       │   │ y │ true │
+                │ 2       │ 3 │   │      │
+                └─────────┴───┴───┴──────┘"}
+            .to_owned()
+        )]
+    );
+}
+
+#[test]
+fn table_values_and_truncation() {
+    let messages = recorded_messages("console.table([1, 'two', { a: 'x'.repeat(40) }]);");
+
+    assert_eq!(
+        messages,
+        [(
+            LogLevel::Log,
+            indoc! {"
+                ┌─────────┬──────────────────────────────────┬────────┐
+                │ (index) │ a                                │ Values │
+                ├─────────┼──────────────────────────────────┼────────┤
+                │ 0       │                                  │ 1      │
+                │ 1       │                                  │ two    │
+                │ 2       │ xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx… │        │
+                └─────────┴──────────────────────────────────┴────────┘"}
+            .to_owned()
+        )]
+    );
+}
+
+#[test]
+fn table_columns_maps_and_sets() {
+    let messages = recorded_messages(indoc! {r#"
+        console.table([{ a: 1, b: 2 }, { b: 3 }], ['b', 'z']);
+        console.table(new Map([['k', { v: 1 }], ['l', 2]]));
+        console.table(new Set(['s']));
+        console.table('not tabular');
+    "#});
+
+    assert_eq!(
+        messages,
+        [
+            (
+                LogLevel::Log,
+                indoc! {"
+                    ┌─────────┬───┬───┐
+                    │ (index) │ b │ z │
+                    ├─────────┼───┼───┤
+                    │ 0       │ 2 │   │
+                    │ 1       │ 3 │   │
+                    └─────────┴───┴───┘"}
+                .to_owned()
+            ),
+            (
+                LogLevel::Log,
+                indoc! {"
+                    ┌───────────────────┬─────┬───┬────────┐
+                    │ (iteration index) │ Key │ v │ Values │
+                    ├───────────────────┼─────┼───┼────────┤
+                    │ 0                 │ k   │ 1 │        │
+                    │ 1                 │ l   │   │ 2      │
+                    └───────────────────┴─────┴───┴────────┘"}
+                .to_owned()
+            ),
+            (
+                LogLevel::Log,
+                indoc! {"
+                    ┌───────────────────┬────────┐
+                    │ (iteration index) │ Values │
+                    ├───────────────────┼────────┤
+                    │ 0                 │ s      │
+                    └───────────────────┴────────┘"}
+                .to_owned()
+            ),
+            (LogLevel::Log, "not tabular".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn format_specifiers() {
+    let messages = recorded_messages(indoc! {r#"
+        console.log('%d %i %d %d', 1.9, -1.9, {}, 5n);
+        console.info('%f %f %f', '1.5', [], Infinity);
+        console.warn('%cstyled%c!', 'color: red', '');
+        console.error('%s %s', Symbol('x'), { toString() { return 'object'; } });
+        console.debug('%O', 'quoted');
+        console.assert(false, 'expected %d, got %s', 4, 'nothing');
+    "#});
+
+    assert_eq!(
+        messages,
+        [
+            (LogLevel::Log, "1 -1 NaN 5".to_owned()),
+            (LogLevel::Info, "1.500000 NaN Infinity".to_owned()),
+            (LogLevel::Warn, "styled!".to_owned()),
+            (LogLevel::Error, "Symbol(x) object".to_owned()),
+            (LogLevel::Debug, "\"quoted\"".to_owned()),
+            (
+                LogLevel::Error,
+                "Assertion failed: expected 4, got nothing".to_owned()
+            ),
+        ]
+    );
+}
+
+/// A logger that records the messages indented like the [`DefaultLogger`](super::DefaultLogger).
+#[derive(Clone, Default)]
+struct IndentingLogger {
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl Logger for IndentingLogger {
+    fn log(&self, _: LogLevel, message: String, indent: usize, _: &Console) {
+        self.output.borrow_mut().push(indented(&message, indent));
+    }
+}
+
+#[test]
+fn nested_groups_indent_messages() {
+    let logger = IndentingLogger::default();
+    let mut context = Context::default();
+    let console = Console::init_with_logger(logger.clone(), &mut context);
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+                console.log('start');
+                console.group('outer %d', 1);
+                console.log('in outer');
+                console.groupCollapsed('inner');
+                console.log('in inner');
+                console.table([1]);
+                console.dir('dir');
+                console.groupEnd();
+                console.log('back in outer');
+                console.groupEnd();
+                console.groupEnd();
+                console.log('end');
+            "#})],
+        &mut context,
+    );
+
+    assert_eq!(
+        logger.output.borrow().join("\n"),
+        indoc! {r#"
+            start
+            outer 1
+              in outer
+              inner
+                in inner
+                ┌─────────┬────────┐
+                │ (index) │ Values │
+                ├─────────┼────────┤
+                │ 0       │ 1      │
+                └─────────┴────────┘
+                "dir"
+              back in outer
+            end"#}
+    );
+}
+
+#[test]
+fn count_and_count_reset() {
+    let messages = recorded_messages(indoc! {r#"
+        console.count();
+        console.count('a');
+        console.count();
+        console.countReset();
+        console.count();
+        console.countReset('b');
+    "#});
+
+    assert_eq!(
+        messages,
+        [
+            (LogLevel::Info, "default: 1".to_owned()),
+            (LogLevel::Info, "a: 1".to_owned()),
+            (LogLevel::Info, "default: 2".to_owned()),
+            (LogLevel::Info, "default: 1".to_owned()),
+            (LogLevel::Warn, "Count for 'b' does not exist".to_owned()),
+        ]
+    );
+}
+
+/// A clock that only moves forward when told to.
+struct ManualClock {
+    start: Instant,
+    elapsed: Cell<Duration>,
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed.get()
+    }
+}
+
+#[test]
+fn timers_use_the_clock() {
+    let logger = RecordingLogger::default();
+    let clock = Rc::new(ManualClock {
+        start: Instant::now(),
+        elapsed: Cell::new(Duration::ZERO),
+    });
+    let mut context = Context::default();
+    let console = Console::init_with_logger_and_clock(logger.clone(), clock.clone(), &mut context);
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    let mut run = |source: &str, elapsed: Duration| {
+        clock.elapsed.set(clock.elapsed.get() + elapsed);
+        context.eval(Source::from_bytes(source)).unwrap();
+    };
+    run("console.time(); console.time('other');", Duration::ZERO);
+    run("console.timeLog();", Duration::from_micros(12_340));
+    run(
+        "console.timeLog('other', 'data', 1);",
+        Duration::from_millis(5),
+    );
+    run("console.time();", Duration::ZERO);
+    run("console.timeEnd();", Duration::from_micros(2_500_001));
+    run("console.timeLog(); console.timeEnd();", Duration::ZERO);
+
+    assert_eq!(
+        *logger.messages.borrow(),
+        [
+            (LogLevel::Log, "default: 12.34ms".to_owned()),
+            (LogLevel::Log, "other: 17.34ms data 1".to_owned()),
+            (LogLevel::Warn, "Timer 'default' already exist".to_owned()),
+            (LogLevel::Info, "default: 2517.341ms".to_owned()),
+            (LogLevel::Warn, "Timer 'default' doesn't exist".to_owned()),
+            (LogLevel::Warn, "Timer 'default' doesn't exist".to_owned()),
+        ]
+    );
+}
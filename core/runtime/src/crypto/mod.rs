@@ -0,0 +1,262 @@
+//! Boa's implementation of the `crypto` Web API object.
+//!
+//! Only the random number generation functions of the Web Crypto API are implemented, drawing
+//! their entropy from a [`RandomSource`].
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [W3C Web Cryptography API specification][spec]
+//!
+//! [spec]: https://w3c.github.io/webcrypto/#crypto-interface
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Crypto
+
+#[cfg(test)]
+mod tests;
+
+use crate::dom_exception::DomException;
+use boa_engine::{
+    builtins::typed_array::TypedArrayKind,
+    js_string,
+    native_function::NativeFunction,
+    object::{builtins::JsTypedArray, ObjectInitializer},
+    property::Attribute,
+    Context, JsArgs, JsBigInt, JsData, JsNativeError, JsResult, JsString, JsSymbol, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use rand::RngCore;
+use std::{fmt::Write, rc::Rc};
+
+/// The maximum number of bytes that `crypto.getRandomValues` can fill in a single call.
+const MAX_RANDOM_BYTES: usize = 65536;
+
+/// The source of the random bytes returned by the `crypto` object.
+///
+/// Implementing this trait allows hosts to control the entropy given to the scripts, e.g. to use
+/// a seeded generator in tests.
+pub trait RandomSource {
+    /// Fills `bytes` with random data.
+    fn fill_bytes(&self, bytes: &mut [u8]);
+
+    /// Gets a random number in the range `[0, 1)`, which can be used to implement
+    /// [`HostHooks::math_random`][boa_engine::context::HostHooks::math_random] with the same
+    /// source.
+    // Both casts are exact, since the numbers have at most 53 significant bits.
+    #[allow(clippy::cast_precision_loss)]
+    fn next_f64(&self) -> f64 {
+        let mut bytes = [0; 8];
+        self.fill_bytes(&mut bytes);
+
+        // Use the 53 most significant bits as the significand of the number.
+        let value = (u64::from_le_bytes(bytes) >> 11) as f64;
+        value / (1_u64 << 53) as f64
+    }
+}
+
+/// A [`RandomSource`] using the cryptographically secure generator of the current thread, which is
+/// seeded by the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemRandomSource;
+
+impl RandomSource for SystemRandomSource {
+    fn fill_bytes(&self, bytes: &mut [u8]) {
+        rand::thread_rng().fill_bytes(bytes);
+    }
+}
+
+/// The [`RandomSource`] of a realm, stored in its host defined data.
+#[derive(Trace, Finalize, JsData)]
+struct Entropy {
+    #[unsafe_ignore_trace]
+    source: Rc<dyn RandomSource>,
+}
+
+/// The `crypto` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Crypto;
+
+impl Crypto {
+    /// Name of the built-in `crypto` property.
+    pub const NAME: &'static str = "crypto";
+
+    /// Registers the `crypto` object in the global object of the context, using the
+    /// [`SystemRandomSource`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `crypto` property cannot be defined in the global object.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        Self::register_with_source(Rc::new(SystemRandomSource), context)
+    }
+
+    /// Registers the `crypto` object in the global object of the context, drawing the random
+    /// values from `source`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `crypto` property cannot be defined in the global object.
+    pub fn register_with_source(
+        source: Rc<dyn RandomSource>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        context
+            .realm()
+            .host_defined_mut()
+            .insert(Entropy { source });
+
+        let crypto = ObjectInitializer::new(context)
+            .function(
+                NativeFunction::from_fn_ptr(Self::get_random_values),
+                js_string!("getRandomValues"),
+                1,
+            )
+            .function(
+                NativeFunction::from_fn_ptr(Self::random_uuid),
+                js_string!("randomUUID"),
+                0,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Crypto"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+
+        context.register_global_property(
+            js_string!(Self::NAME),
+            crypto,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+
+    /// Fills `bytes` with the random source of the current realm.
+    fn fill_bytes(bytes: &mut [u8], context: &Context) -> JsResult<()> {
+        let source = context
+            .realm()
+            .host_defined()
+            .get::<Entropy>()
+            .map(|entropy| entropy.source.clone())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("crypto: the random source is not registered")
+            })?;
+        source.fill_bytes(bytes);
+        Ok(())
+    }
+
+    /// `crypto.getRandomValues(array)`
+    ///
+    /// Overwrites the elements of an integer typed array with random values, and returns it.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [W3C Web Cryptography API specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/webcrypto/#Crypto-method-getRandomValues
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Crypto/getRandomValues
+    fn get_random_values(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let array = args
+            .get_or_undefined(0)
+            .as_object()
+            .and_then(|array| JsTypedArray::from_object(array.clone()).ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("crypto.getRandomValues: the argument must be a typed array")
+            })?;
+
+        // 1. If array is not an Int8Array, Uint8Array, Uint8ClampedArray, Int16Array, Uint16Array,
+        //    Int32Array, Uint32Array, BigInt64Array, or BigUint64Array, then throw a
+        //    TypeMismatchError and terminate the algorithm.
+        let element: fn(&[u8]) -> JsValue = match array.kind() {
+            TypedArrayKind::Int8 => |bytes| i8::from_ne_bytes([bytes[0]]).into(),
+            TypedArrayKind::Uint8 | TypedArrayKind::Uint8Clamped => |bytes| bytes[0].into(),
+            TypedArrayKind::Int16 => |bytes| i16::from_ne_bytes([bytes[0], bytes[1]]).into(),
+            TypedArrayKind::Uint16 => |bytes| u16::from_ne_bytes([bytes[0], bytes[1]]).into(),
+            TypedArrayKind::Int32 => {
+                |bytes| i32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]).into()
+            }
+            TypedArrayKind::Uint32 => {
+                |bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]).into()
+            }
+            TypedArrayKind::BigInt64 => |bytes| {
+                let bytes = bytes
+                    .try_into()
+                    .expect("chunks have the size of the elements");
+                JsBigInt::from(i64::from_ne_bytes(bytes)).into()
+            },
+            TypedArrayKind::BigUint64 => |bytes| {
+                let bytes = bytes
+                    .try_into()
+                    .expect("chunks have the size of the elements");
+                JsBigInt::from(u64::from_ne_bytes(bytes)).into()
+            },
+            TypedArrayKind::Float32 | TypedArrayKind::Float64 => {
+                return Err(DomException::TypeMismatchError.to_error(
+                    "crypto.getRandomValues: the typed array must have an integer type",
+                    context,
+                ));
+            }
+        };
+
+        // 2. If the byteLength of array is greater than 65536, throw a QuotaExceededError and
+        //    terminate the algorithm.
+        let byte_length = array.byte_length(context)?;
+        if byte_length > MAX_RANDOM_BYTES {
+            return Err(DomException::QuotaExceededError.to_error(
+                "crypto.getRandomValues: the typed array cannot be larger than 65536 bytes",
+                context,
+            ));
+        }
+
+        // 3. Overwrite all elements of array with cryptographically strong random values of the
+        //    appropriate type.
+        let mut bytes = vec![0; byte_length];
+        Self::fill_bytes(&mut bytes, context)?;
+        #[allow(clippy::cast_possible_truncation)]
+        let element_size = array.kind().element_size() as usize;
+        for (index, chunk) in bytes.chunks_exact(element_size).enumerate() {
+            array.set(index, element(chunk), true, context)?;
+        }
+
+        // 4. Return array.
+        Ok(array.into())
+    }
+
+    /// `crypto.randomUUID()`
+    ///
+    /// Generates a random version 4 UUID.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [W3C Web Cryptography API specification][spec]
+    ///
+    /// [spec]: https://w3c.github.io/webcrypto/#Crypto-method-randomUUID
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Crypto/randomUUID
+    fn random_uuid(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let bytes be a byte sequence of length 16.
+        // 2. Fill bytes with cryptographically secure random bytes.
+        let mut bytes = [0_u8; 16];
+        Self::fill_bytes(&mut bytes, context)?;
+
+        // 3. Set the 4 most significant bits of bytes[6], which represent the UUID version, to
+        //    0100.
+        bytes[6] = (bytes[6] & 0x0F) | 0x40;
+
+        // 4. Set the 2 most significant bits of bytes[8], which represent the UUID variant, to 10.
+        bytes[8] = (bytes[8] & 0x3F) | 0x80;
+
+        // 5. Return the string concatenation of the hexadecimal representation of the bytes,
+        //    separated by "-" after the 4th, 6th, 8th and 10th bytes.
+        let mut uuid = String::with_capacity(36);
+        for (index, byte) in bytes.iter().enumerate() {
+            if matches!(index, 4 | 6 | 8 | 10) {
+                uuid.push('-');
+            }
+            write!(uuid, "{byte:02x}").expect("writing to a string cannot fail");
+        }
+
+        Ok(JsString::from(uuid).into())
+    }
+}
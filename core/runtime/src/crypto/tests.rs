@@ -0,0 +1,148 @@
+use super::{Crypto, RandomSource};
+use crate::test::{run_test_actions_with, TestAction};
+use boa_engine::{
+    context::{ContextBuilder, HostHooks},
+    js_string, Context,
+};
+use indoc::indoc;
+use std::{cell::Cell, rc::Rc};
+
+/// A source returning the bytes 0, 1, 2, ... in order.
+#[derive(Default)]
+struct CountingSource {
+    next: Cell<u8>,
+}
+
+impl RandomSource for CountingSource {
+    fn fill_bytes(&self, bytes: &mut [u8]) {
+        for byte in bytes {
+            *byte = self.next.get();
+            self.next.set(byte.wrapping_add(1));
+        }
+    }
+}
+
+fn context_with_source() -> Context {
+    let mut context = Context::default();
+    Crypto::register_with_source(Rc::new(CountingSource::default()), &mut context).unwrap();
+    context
+}
+
+#[test]
+fn get_random_values() {
+    let mut context = context_with_source();
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                var bytes = new Uint8Array(4);
+                var result = crypto.getRandomValues(bytes);
+                var signed = crypto.getRandomValues(new Int8Array(new ArrayBuffer(8), 4, 2));
+                var big = crypto.getRandomValues(new BigUint64Array(1));
+            "#}),
+            TestAction::assert("result === bytes"),
+            TestAction::assert("bytes.join() === '0,1,2,3'"),
+            TestAction::assert("signed.join() === '4,5'"),
+            TestAction::assert("typeof big[0] === 'bigint'"),
+            TestAction::assert_eq(
+                "crypto.getRandomValues(new Uint8ClampedArray(2)).join()",
+                js_string!("14,15"),
+            ),
+            TestAction::assert_eq(
+                "crypto.getRandomValues(new Uint8Array(65536)).length",
+                65536,
+            ),
+        ],
+        &mut context,
+    );
+}
+
+#[test]
+fn get_random_values_errors() {
+    let mut context = context_with_source();
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                function error(f) {
+                    try {
+                        f();
+                    } catch (e) {
+                        return `${e.name} ${e.code}`;
+                    }
+                }
+            "#}),
+            TestAction::assert_eq(
+                "error(() => crypto.getRandomValues(new Float64Array(1)))",
+                js_string!("TypeMismatchError 17"),
+            ),
+            TestAction::assert_eq(
+                "error(() => crypto.getRandomValues(new Uint8Array(65537)))",
+                js_string!("QuotaExceededError 22"),
+            ),
+            TestAction::assert_eq(
+                "error(() => crypto.getRandomValues([1, 2]))",
+                js_string!("TypeError undefined"),
+            ),
+        ],
+        &mut context,
+    );
+}
+
+#[test]
+fn random_uuid() {
+    let mut context = context_with_source();
+    run_test_actions_with(
+        [
+            TestAction::assert_eq(
+                "crypto.randomUUID()",
+                js_string!("00010203-0405-4607-8809-0a0b0c0d0e0f"),
+            ),
+            TestAction::assert_eq(
+                "crypto.randomUUID()",
+                js_string!("10111213-1415-4617-9819-1a1b1c1d1e1f"),
+            ),
+        ],
+        &mut context,
+    );
+
+    let mut context = Context::default();
+    Crypto::register(&mut context).unwrap();
+    run_test_actions_with(
+        [TestAction::assert(
+            "/^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/.test(crypto.randomUUID())",
+        )],
+        &mut context,
+    );
+}
+
+/// Host hooks drawing the numbers of `Math.random()` from a [`RandomSource`].
+struct SourceHooks {
+    source: Rc<CountingSource>,
+}
+
+impl HostHooks for SourceHooks {
+    fn math_random(&self) -> f64 {
+        self.source.next_f64()
+    }
+}
+
+#[test]
+fn math_random_shares_the_source() {
+    let source = Rc::new(CountingSource::default());
+    let hooks: &'static SourceHooks = Box::leak(Box::new(SourceHooks {
+        source: source.clone(),
+    }));
+    let mut context = ContextBuilder::new().host_hooks(hooks).build().unwrap();
+    Crypto::register_with_source(source, &mut context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert("Math.random() === 0.02743560169165704"),
+            TestAction::assert_eq(
+                "crypto.getRandomValues(new Uint8Array(2)).join()",
+                js_string!("8,9"),
+            ),
+            TestAction::assert("Math.random() >= 0 && Math.random() < 1"),
+        ],
+        &mut context,
+    );
+}
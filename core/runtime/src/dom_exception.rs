@@ -0,0 +1,66 @@
+//! Errors shaped like the `DOMException`s thrown by the Web APIs.
+//!
+//! The runtime doesn't implement the `DOMException` interface, so these are plain `Error` objects
+//! with the `name` and the legacy `code` of the exception they represent.
+//!
+//! More information:
+//!  - [WebIDL specification][spec]
+//!
+//! [spec]: https://webidl.spec.whatwg.org/#idl-DOMException-error-names
+
+use boa_engine::{
+    js_string, property::PropertyDescriptor, Context, JsError, JsNativeError, JsString, JsValue,
+};
+
+/// The names and legacy codes of the `DOMException`s thrown by the runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)] // The variants are named after the exceptions.
+pub(crate) enum DomException {
+    InvalidCharacterError,
+    TypeMismatchError,
+    QuotaExceededError,
+}
+
+impl DomException {
+    /// Gets the name of the exception.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::InvalidCharacterError => "InvalidCharacterError",
+            Self::TypeMismatchError => "TypeMismatchError",
+            Self::QuotaExceededError => "QuotaExceededError",
+        }
+    }
+
+    /// Gets the legacy code of the exception.
+    const fn code(self) -> i32 {
+        match self {
+            Self::InvalidCharacterError => 5,
+            Self::TypeMismatchError => 17,
+            Self::QuotaExceededError => 22,
+        }
+    }
+
+    /// Creates an error shaped like this `DOMException`.
+    pub(crate) fn to_error(self, message: &'static str, context: &mut Context) -> JsError {
+        let error = JsNativeError::error()
+            .with_message(message)
+            .to_opaque(context);
+        for (key, value) in [
+            (js_string!("name"), JsString::from(self.name()).into()),
+            (js_string!("code"), JsValue::new(self.code())),
+        ] {
+            error
+                .define_property_or_throw(
+                    key,
+                    PropertyDescriptor::builder()
+                        .value(value)
+                        .writable(true)
+                        .enumerable(false)
+                        .configurable(true),
+                    context,
+                )
+                .expect("defining a property of a new error object cannot fail");
+        }
+        JsError::from_opaque(error.into())
+    }
+}
@@ -51,10 +51,28 @@
     clippy::let_unit_value
 )]
 
+mod base64;
 mod console;
+mod crypto;
+mod dom_exception;
+mod performance;
+mod timers;
+#[cfg(feature = "url")]
+mod url;
 
 #[doc(inline)]
-pub use console::Console;
+pub use base64::Base64;
+#[doc(inline)]
+pub use console::{Console, DefaultLogger, LogLevel, Logger};
+#[doc(inline)]
+pub use crypto::{Crypto, RandomSource, SystemRandomSource};
+#[doc(inline)]
+pub use performance::Performance;
+#[doc(inline)]
+pub use timers::{Clock, HostClock, Timers};
+#[cfg(feature = "url")]
+#[doc(inline)]
+pub use url::{Url, UrlSearchParams};
 
 #[cfg(test)]
 pub(crate) mod test {
@@ -115,6 +133,24 @@ pub(crate) mod test {
         pub(crate) fn inspect_context(op: fn(&mut Context)) -> Self {
             Self(Inner::InspectContext { op })
         }
+
+        /// Asserts that evaluating `source` returns the `true` value.
+        pub(crate) fn assert(source: impl Into<Cow<'static, str>>) -> Self {
+            Self(Inner::Assert {
+                source: source.into(),
+            })
+        }
+
+        /// Asserts that the script returns `expected` when evaluating `source`.
+        pub(crate) fn assert_eq(
+            source: impl Into<Cow<'static, str>>,
+            expected: impl Into<JsValue>,
+        ) -> Self {
+            Self(Inner::AssertEq {
+                source: source.into(),
+                expected: expected.into(),
+            })
+        }
     }
 
     /// Executes a list of test actions on a new, default context.
@@ -0,0 +1,79 @@
+//! Boa's implementation of the `performance` Web API object.
+//!
+//! Only the timing functions of the High Resolution Time specification are implemented, using the
+//! monotonic clock of the [`HostHooks`][boa_engine::context::HostHooks].
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [W3C High Resolution Time specification][spec]
+//!
+//! [spec]: https://w3c.github.io/hr-time/#the-performance-attribute
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Performance
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::{
+    js_string, native_function::NativeFunction, object::ObjectInitializer, property::Attribute,
+    Context, JsResult, JsSymbol, JsValue,
+};
+use std::time::Duration;
+
+/// Converts a duration to milliseconds, rounded to microseconds.
+#[allow(clippy::cast_precision_loss)]
+fn as_millis_f64(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+/// The `performance` object.
+#[derive(Debug, Clone, Copy)]
+pub struct Performance;
+
+impl Performance {
+    /// Name of the built-in `performance` property.
+    pub const NAME: &'static str = "performance";
+
+    /// Registers the `performance` object in the global object of the context.
+    ///
+    /// The time origin of `performance.now()` is the moment this function is called, which should
+    /// be right after creating the context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `performance` property cannot be defined in the global object.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        let hooks = context.host_hooks();
+        let origin = hooks.monotonic_now();
+        #[allow(clippy::cast_precision_loss)]
+        let time_origin = hooks.utc_now() as f64;
+
+        // `performance.now()` returns the milliseconds elapsed since the time origin.
+        let now = NativeFunction::from_copy_closure(move |_, _, context| {
+            let elapsed = context
+                .host_hooks()
+                .monotonic_now()
+                .saturating_duration_since(origin);
+            Ok(JsValue::new(as_millis_f64(elapsed)))
+        });
+
+        let performance = ObjectInitializer::new(context)
+            .function(now, js_string!("now"), 0)
+            .property(
+                js_string!("timeOrigin"),
+                time_origin,
+                Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Performance"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+
+        context.register_global_property(
+            js_string!(Self::NAME),
+            performance,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        )
+    }
+}
@@ -0,0 +1,73 @@
+use super::Performance;
+use boa_engine::{
+    context::{ContextBuilder, HostHooks},
+    js_string, Context, JsValue, Source,
+};
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+/// Host hooks with a clock that only moves forward when told to.
+struct FakeClockHooks {
+    start: Instant,
+    elapsed: Cell<Duration>,
+}
+
+impl HostHooks for FakeClockHooks {
+    fn utc_now(&self) -> i64 {
+        1_700_000_000_000
+    }
+
+    fn monotonic_now(&self) -> Instant {
+        self.start + self.elapsed.get()
+    }
+}
+
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    context.eval(Source::from_bytes(source)).unwrap()
+}
+
+#[test]
+fn now_uses_the_monotonic_clock() {
+    let hooks: &'static FakeClockHooks = Box::leak(Box::new(FakeClockHooks {
+        start: Instant::now(),
+        elapsed: Cell::new(Duration::from_secs(10)),
+    }));
+    let mut context = ContextBuilder::new().host_hooks(hooks).build().unwrap();
+    Performance::register(&mut context).unwrap();
+
+    assert_eq!(eval(&mut context, "performance.now()"), JsValue::new(0));
+
+    hooks.elapsed.set(Duration::from_micros(10_012_345));
+    assert_eq!(
+        eval(&mut context, "performance.now()"),
+        JsValue::new(12.345)
+    );
+
+    hooks.elapsed.set(Duration::from_secs(12));
+    assert_eq!(eval(&mut context, "performance.now()"), JsValue::new(2000));
+}
+
+#[test]
+fn time_origin_uses_the_wall_clock() {
+    let hooks: &'static FakeClockHooks = Box::leak(Box::new(FakeClockHooks {
+        start: Instant::now(),
+        elapsed: Cell::new(Duration::ZERO),
+    }));
+    let mut context = ContextBuilder::new().host_hooks(hooks).build().unwrap();
+    Performance::register(&mut context).unwrap();
+
+    assert_eq!(
+        eval(&mut context, "performance.timeOrigin"),
+        JsValue::new(1_700_000_000_000_i64)
+    );
+    assert_eq!(
+        eval(&mut context, "Date.now() === performance.timeOrigin"),
+        JsValue::new(true)
+    );
+    assert_eq!(
+        eval(&mut context, "Object.prototype.toString.call(performance)"),
+        js_string!("[object Performance]").into()
+    );
+}
@@ -0,0 +1,423 @@
+//! Boa's implementation of the `setTimeout`, `setInterval` and `queueMicrotask` Web APIs.
+//!
+//! Timers are stored in the [`Realm`][boa_engine::realm::Realm] of the context, and are run by an
+//! event loop driven by the host, either with [`Timers::run_event_loop`], which blocks the current
+//! thread while waiting for the next timer, or with [`Timers::run_event_loop_async`], which waits
+//! using the futures of an async runtime.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG timers specification][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#timers
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/setTimeout
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::{
+    context::HostHooks, job::NativeJob, js_string, native_function::NativeFunction,
+    object::builtins::JsFunction, Context, JsArgs, JsData, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use rustc_hash::FxHashMap;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    future::Future,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+/// The source of time used to schedule timers and to measure the durations of `console.time`.
+///
+/// By default, this is the [`HostClock`] of the context. Implementing this trait allows hosts to
+/// control how time passes for the scripts, e.g. to use a virtual clock in tests.
+pub trait Clock {
+    /// Gets the current time.
+    fn now(&self) -> Instant;
+
+    /// Blocks the current thread until `deadline`, used by [`Timers::run_event_loop`] while
+    /// waiting for the next timer.
+    fn sleep_until(&self, deadline: Instant) {
+        let now = self.now();
+        if deadline > now {
+            std::thread::sleep(deadline - now);
+        }
+    }
+}
+
+/// A [`Clock`] reading the [`HostHooks::monotonic_now`] hook, which is also the clock of
+/// `performance.now()`.
+///
+/// This is the monotonic clock of the system, unless the host overrides the hook.
+#[derive(Clone, Copy)]
+pub struct HostClock {
+    hooks: &'static dyn HostHooks,
+}
+
+impl HostClock {
+    /// Creates a new `HostClock` reading the time from `hooks`.
+    #[must_use]
+    pub const fn new(hooks: &'static dyn HostHooks) -> Self {
+        Self { hooks }
+    }
+}
+
+impl fmt::Debug for HostClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HostClock").finish_non_exhaustive()
+    }
+}
+
+impl Clock for HostClock {
+    fn now(&self) -> Instant {
+        self.hooks.monotonic_now()
+    }
+}
+
+/// A timer created by `setTimeout` or `setInterval`.
+#[derive(Trace, Finalize)]
+struct Timer {
+    callback: JsFunction,
+    arguments: Vec<JsValue>,
+    /// The delay between two runs of the timer, if it was created by `setInterval`.
+    #[unsafe_ignore_trace]
+    interval: Option<Duration>,
+    /// The key of the timer in [`TimerQueue::schedule`].
+    #[unsafe_ignore_trace]
+    key: (Instant, u64),
+}
+
+/// The pending timers of a realm.
+#[derive(Trace, Finalize, JsData)]
+struct TimerQueue {
+    #[unsafe_ignore_trace]
+    clock: Rc<dyn Clock>,
+    /// The identifier of the last created timer.
+    last_id: u32,
+    /// The number of times a timer was scheduled, used to run timers with the same deadline in
+    /// the order they were scheduled.
+    scheduled: u64,
+    timers: FxHashMap<u32, Timer>,
+    /// The identifiers of the pending timers, ordered by deadline.
+    #[unsafe_ignore_trace]
+    schedule: BTreeMap<(Instant, u64), u32>,
+}
+
+impl TimerQueue {
+    fn new(clock: Rc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            last_id: 0,
+            scheduled: 0,
+            timers: FxHashMap::default(),
+            schedule: BTreeMap::new(),
+        }
+    }
+
+    /// Gets the key of a timer that is run after `delay`.
+    fn next_key(&mut self, delay: Duration) -> (Instant, u64) {
+        self.scheduled += 1;
+        (self.clock.now() + delay, self.scheduled)
+    }
+
+    /// Adds a new timer, returning its identifier.
+    fn insert(
+        &mut self,
+        callback: JsFunction,
+        arguments: Vec<JsValue>,
+        delay: Duration,
+        repeat: bool,
+    ) -> u32 {
+        self.last_id += 1;
+        let id = self.last_id;
+        let key = self.next_key(delay);
+        self.schedule.insert(key, id);
+        self.timers.insert(
+            id,
+            Timer {
+                callback,
+                arguments,
+                interval: repeat.then_some(delay),
+                key,
+            },
+        );
+        id
+    }
+
+    /// Removes the timer with identifier `id`, if it exists.
+    fn remove(&mut self, id: u32) {
+        if let Some(timer) = self.timers.remove(&id) {
+            self.schedule.remove(&timer.key);
+        }
+    }
+
+    /// Gets the callback and arguments of the next timer if it is due.
+    ///
+    /// Timeouts are removed from the queue, while intervals are scheduled again, so that calling
+    /// `clearInterval` from the callback stops the interval.
+    fn pop_due(&mut self) -> Option<(JsFunction, Vec<JsValue>)> {
+        let (&(deadline, _), &id) = self.schedule.first_key_value()?;
+        if deadline > self.clock.now() {
+            return None;
+        }
+        self.schedule.pop_first();
+
+        let interval = self.timers.get(&id)?.interval;
+        if let Some(interval) = interval {
+            let key = self.next_key(interval);
+            self.schedule.insert(key, id);
+            let timer = self.timers.get_mut(&id)?;
+            timer.key = key;
+            Some((timer.callback.clone(), timer.arguments.clone()))
+        } else {
+            // `Timer` implements `Drop`, so its fields cannot be moved out.
+            let timer = self.timers.remove(&id)?;
+            Some((timer.callback.clone(), timer.arguments.clone()))
+        }
+    }
+}
+
+/// The `setTimeout`, `setInterval`, `clearTimeout`, `clearInterval` and `queueMicrotask`
+/// functions.
+///
+/// # Example
+///
+/// ```
+/// use boa_engine::{Context, Source};
+/// use boa_runtime::Timers;
+///
+/// let mut context = Context::default();
+/// Timers::register(&mut context).unwrap();
+///
+/// context
+///     .eval(Source::from_bytes("setTimeout(() => { globalThis.done = true; }, 10);"))
+///     .unwrap();
+/// Timers::run_event_loop(&mut context).unwrap();
+///
+/// assert!(context.eval(Source::from_bytes("done")).unwrap().to_boolean());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Timers;
+
+impl Timers {
+    /// Registers the timer functions in the global object of the context, using the
+    /// [`HostClock`] of the context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the functions cannot be defined in the global object.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        let clock = HostClock::new(context.host_hooks());
+        Self::register_with_clock(Rc::new(clock), context)
+    }
+
+    /// Registers the timer functions in the global object of the context, using `clock` to
+    /// schedule the timers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the functions cannot be defined in the global object.
+    pub fn register_with_clock(clock: Rc<dyn Clock>, context: &mut Context) -> JsResult<()> {
+        context
+            .realm()
+            .host_defined_mut()
+            .insert(TimerQueue::new(clock));
+
+        context.register_global_builtin_callable(
+            js_string!("setTimeout"),
+            1,
+            NativeFunction::from_fn_ptr(Self::set_timeout),
+        )?;
+        context.register_global_builtin_callable(
+            js_string!("setInterval"),
+            1,
+            NativeFunction::from_fn_ptr(Self::set_interval),
+        )?;
+        context.register_global_builtin_callable(
+            js_string!("clearTimeout"),
+            0,
+            NativeFunction::from_fn_ptr(Self::clear_timer),
+        )?;
+        context.register_global_builtin_callable(
+            js_string!("clearInterval"),
+            0,
+            NativeFunction::from_fn_ptr(Self::clear_timer),
+        )?;
+        context.register_global_builtin_callable(
+            js_string!("queueMicrotask"),
+            1,
+            NativeFunction::from_fn_ptr(Self::queue_microtask),
+        )?;
+
+        Ok(())
+    }
+
+    /// Gets the time at which the next timer is due, or `None` if there are no pending timers.
+    #[must_use]
+    pub fn next_deadline(context: &Context) -> Option<Instant> {
+        context
+            .realm()
+            .host_defined()
+            .get::<TimerQueue>()?
+            .schedule
+            .first_key_value()
+            .map(|(&(deadline, _), _)| deadline)
+    }
+
+    /// Runs the callback of the next timer if it is due, and then runs the pending promise jobs.
+    ///
+    /// Returns `true` if a timer was run.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error thrown by the callback of the timer.
+    pub fn run_next_timer(context: &mut Context) -> JsResult<bool> {
+        let realm = context.realm().clone();
+        let Some((callback, arguments)) = realm
+            .host_defined_mut()
+            .get_mut::<TimerQueue>()
+            .and_then(TimerQueue::pop_due)
+        else {
+            return Ok(false);
+        };
+
+        let this = context.global_object().into();
+        let result = callback.call(&this, &arguments, context);
+        context.run_jobs();
+        result.map(|_| true)
+    }
+
+    /// Runs the pending promise jobs and timers until there is nothing left to run, blocking the
+    /// current thread with [`Clock::sleep_until`] while waiting for the next timer.
+    ///
+    /// Promise jobs are run after every timer callback, so the microtasks enqueued by a timer
+    /// always run before the next timer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error thrown by a timer callback. The remaining timers are kept, so
+    /// the event loop can be resumed by calling this function again.
+    pub fn run_event_loop(context: &mut Context) -> JsResult<()> {
+        context.run_jobs();
+        while let Some(deadline) = Self::next_deadline(context) {
+            if !Self::run_next_timer(context)? {
+                let clock = context
+                    .realm()
+                    .host_defined()
+                    .get::<TimerQueue>()
+                    .map(|queue| queue.clock.clone());
+                if let Some(clock) = clock {
+                    clock.sleep_until(deadline);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the pending promise jobs and timers until there is nothing left to run, awaiting the
+    /// future returned by `sleep_until` while waiting for the next timer.
+    ///
+    /// This allows driving the timers from an async runtime, e.g. passing
+    /// `|deadline| tokio::time::sleep_until(deadline.into())` when using `tokio`. The promise
+    /// jobs are run with [`Context::run_jobs_async`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error thrown by a timer callback. The remaining timers are kept, so
+    /// the event loop can be resumed by calling this function again.
+    #[allow(clippy::future_not_send)]
+    pub async fn run_event_loop_async<F, Fut>(
+        context: &mut Context,
+        mut sleep_until: F,
+    ) -> JsResult<()>
+    where
+        F: FnMut(Instant) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        context.run_jobs_async().await;
+        while let Some(deadline) = Self::next_deadline(context) {
+            if !Self::run_next_timer(context)? {
+                sleep_until(deadline).await;
+            }
+            context.run_jobs_async().await;
+        }
+        Ok(())
+    }
+
+    /// `setTimeout(handler, timeout = 0, ...arguments)`
+    fn set_timeout(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::initialize_timer(args, false, context)
+    }
+
+    /// `setInterval(handler, timeout = 0, ...arguments)`
+    fn set_interval(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::initialize_timer(args, true, context)
+    }
+
+    /// The [timer initialization steps][spec] shared by `setTimeout` and `setInterval`.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#timer-initialisation-steps
+    fn initialize_timer(
+        args: &[JsValue],
+        repeat: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let callback = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .and_then(JsFunction::from_object)
+            .ok_or_else(|| JsNativeError::typ().with_message("timer handler is not a function"))?;
+
+        // The timeout is converted to a WebIDL `long`, and negative timeouts are treated as 0.
+        let timeout = args.get_or_undefined(1).to_i32(context)?;
+        let delay = Duration::from_millis(u64::try_from(timeout).unwrap_or(0));
+        let arguments = args.get(2..).unwrap_or_default().to_vec();
+
+        let realm = context.realm().clone();
+        let mut host_defined = realm.host_defined_mut();
+        let queue = host_defined
+            .get_mut::<TimerQueue>()
+            .ok_or_else(|| JsNativeError::typ().with_message("timers are not registered"))?;
+
+        Ok(queue.insert(callback, arguments, delay, repeat).into())
+    }
+
+    /// `clearTimeout(id = 0)` and `clearInterval(id = 0)`
+    fn clear_timer(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let id = args.get_or_undefined(0).to_i32(context)?;
+
+        // Unknown identifiers are ignored.
+        if let Ok(id) = u32::try_from(id) {
+            if let Some(queue) = context.realm().host_defined_mut().get_mut::<TimerQueue>() {
+                queue.remove(id);
+            }
+        }
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `queueMicrotask(callback)`
+    fn queue_microtask(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let callback = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .and_then(JsFunction::from_object)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("queueMicrotask: callback is not a function")
+            })?;
+
+        let realm = context.realm().clone();
+        let job = NativeJob::with_realm(
+            move |context| callback.call(&JsValue::undefined(), &[], context),
+            realm,
+            context,
+        );
+        context.enqueue_job(job);
+
+        Ok(JsValue::undefined())
+    }
+}
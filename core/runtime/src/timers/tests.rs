@@ -0,0 +1,223 @@
+use super::{Clock, Timers};
+use boa_engine::{
+    context::{ContextBuilder, HostHooks},
+    js_string, Context, JsNativeErrorKind, JsValue, Source,
+};
+use indoc::indoc;
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+/// A clock that jumps to the deadline instead of sleeping.
+struct ManualClock {
+    start: Instant,
+    elapsed: Cell<Duration>,
+}
+
+impl ManualClock {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: Cell::new(Duration::ZERO),
+        }
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed.get()
+    }
+
+    fn sleep_until(&self, deadline: Instant) {
+        if deadline > self.now() {
+            self.elapsed.set(deadline - self.start);
+        }
+    }
+}
+
+fn context_with_clock() -> (Context, Rc<ManualClock>) {
+    let mut context = Context::default();
+    let clock = Rc::new(ManualClock::new());
+    Timers::register_with_clock(clock.clone(), &mut context).unwrap();
+    (context, clock)
+}
+
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    context.eval(Source::from_bytes(source)).unwrap()
+}
+
+#[test]
+fn microtasks_run_between_timers() {
+    let (mut context, _) = context_with_clock();
+    eval(
+        &mut context,
+        indoc! {r#"
+            var log = [];
+            setTimeout(() => log.push('timeout 1'), 0);
+            Promise.resolve().then(() => log.push('promise 1'));
+            queueMicrotask(() => log.push('microtask'));
+            setTimeout(() => {
+                log.push('timeout 2');
+                Promise.resolve().then(() => log.push('promise 2'));
+                queueMicrotask(() => log.push('microtask 2'));
+            }, 0);
+            setTimeout(() => log.push('timeout 3'), 0);
+            log.push('script');
+        "#},
+    );
+    Timers::run_event_loop(&mut context).unwrap();
+
+    assert_eq!(
+        eval(&mut context, "log.join()"),
+        js_string!(
+            "script,promise 1,microtask,timeout 1,timeout 2,promise 2,microtask 2,timeout 3"
+        )
+        .into()
+    );
+}
+
+#[test]
+fn timeouts_run_in_deadline_order() {
+    let (mut context, clock) = context_with_clock();
+    eval(
+        &mut context,
+        indoc! {r#"
+            var log = [];
+            setTimeout(() => log.push('a'), 20);
+            setTimeout((x, y) => log.push(x + y), 10, 'b', 'c');
+            setTimeout(() => log.push('d'), 10);
+            setTimeout(() => setTimeout(() => log.push('e'), 5), 10);
+            setTimeout(() => log.push('f'), -5);
+        "#},
+    );
+    Timers::run_event_loop(&mut context).unwrap();
+
+    assert_eq!(
+        eval(&mut context, "log.join()"),
+        js_string!("f,bc,d,e,a").into()
+    );
+    assert_eq!(clock.elapsed.get(), Duration::from_millis(20));
+}
+
+#[test]
+fn intervals_repeat_until_cleared() {
+    let (mut context, clock) = context_with_clock();
+    eval(
+        &mut context,
+        indoc! {r#"
+            var count = 0;
+            var id = setInterval(() => {
+                count++;
+                if (count === 3) {
+                    clearInterval(id);
+                }
+            }, 10);
+        "#},
+    );
+    Timers::run_event_loop(&mut context).unwrap();
+
+    assert_eq!(eval(&mut context, "count"), JsValue::new(3));
+    assert_eq!(clock.elapsed.get(), Duration::from_millis(30));
+    assert_eq!(Timers::next_deadline(&context), None);
+}
+
+#[test]
+fn cleared_timeouts_do_not_run() {
+    let (mut context, _) = context_with_clock();
+    eval(
+        &mut context,
+        indoc! {r#"
+            var ran = false;
+            var first = setTimeout(() => { ran = true; }, 10);
+            var second = setInterval(() => { ran = true; }, 10);
+            clearTimeout(first);
+            clearTimeout(second);
+            clearTimeout(1000);
+            clearInterval();
+        "#},
+    );
+    assert!(eval(&mut context, "first > 0 && second > first").to_boolean());
+    assert_eq!(Timers::next_deadline(&context), None);
+
+    Timers::run_event_loop(&mut context).unwrap();
+    assert_eq!(eval(&mut context, "ran"), JsValue::new(false));
+}
+
+#[test]
+fn only_due_timers_run() {
+    let (mut context, clock) = context_with_clock();
+    eval(
+        &mut context,
+        "var ran = false; setTimeout(() => { ran = true; }, 10);",
+    );
+
+    assert!(!Timers::run_next_timer(&mut context).unwrap());
+    assert_eq!(
+        Timers::next_deadline(&context),
+        Some(clock.now() + Duration::from_millis(10))
+    );
+
+    clock.elapsed.set(Duration::from_millis(10));
+    assert!(Timers::run_next_timer(&mut context).unwrap());
+    assert_eq!(eval(&mut context, "ran"), JsValue::new(true));
+}
+
+#[test]
+fn errors_resume_the_event_loop() {
+    let (mut context, _) = context_with_clock();
+    eval(
+        &mut context,
+        indoc! {r#"
+            var log = [];
+            setTimeout(() => { throw new Error('boom'); }, 0);
+            setTimeout(() => log.push('after'), 0);
+        "#},
+    );
+
+    assert!(Timers::run_event_loop(&mut context).is_err());
+    Timers::run_event_loop(&mut context).unwrap();
+    assert_eq!(eval(&mut context, "log.join()"), js_string!("after").into());
+}
+
+#[test]
+fn handlers_must_be_callable() {
+    let (mut context, _) = context_with_clock();
+
+    for source in [
+        "setTimeout('code')",
+        "setInterval({}, 10)",
+        "queueMicrotask(1)",
+    ] {
+        let error = context.eval(Source::from_bytes(source)).unwrap_err();
+        assert!(
+            error
+                .try_native(&mut context)
+                .is_ok_and(|error| error.kind == JsNativeErrorKind::Type),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn default_clock_uses_the_host_hooks() {
+    struct FixedClockHooks(Instant);
+
+    impl HostHooks for FixedClockHooks {
+        fn monotonic_now(&self) -> Instant {
+            self.0
+        }
+    }
+
+    let start = Instant::now() + Duration::from_secs(3600);
+    let hooks: &'static FixedClockHooks = Box::leak(Box::new(FixedClockHooks(start)));
+    let mut context = ContextBuilder::new().host_hooks(hooks).build().unwrap();
+    Timers::register(&mut context).unwrap();
+
+    eval(&mut context, "setTimeout(() => {}, 50)");
+    assert_eq!(
+        Timers::next_deadline(&context),
+        Some(start + Duration::from_millis(50))
+    );
+}
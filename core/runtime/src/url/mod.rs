@@ -0,0 +1,309 @@
+//! Boa's implementation of the `URL` and `URLSearchParams` Web APIs, using the [`url`] crate to
+//! parse and serialize URLs.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `URL` specification][spec]
+//!
+//! [spec]: https://url.spec.whatwg.org/#api
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/URL
+
+#[cfg(test)]
+mod tests;
+
+mod search_params;
+
+#[doc(inline)]
+pub use search_params::UrlSearchParams;
+
+use ::url::quirks;
+use boa_engine::{
+    class::{Class, ClassBuilder},
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+/// Reads a component of a URL.
+type Getter = fn(&::url::Url) -> &str;
+
+/// Updates a component of a URL, returning an error only if the whole URL must be rejected.
+type Setter = fn(&mut ::url::Url, &str) -> JsResult<()>;
+
+/// The components of a URL exposed as accessors of `URL.prototype`.
+///
+/// Following the specification, invalid values are ignored by all the setters except `href`.
+const COMPONENTS: [(&str, Getter, Setter); 10] = [
+    ("href", quirks::href, |url, value| {
+        quirks::set_href(url, value).map_err(|err| {
+            JsNativeError::typ()
+                .with_message(format!("URL: {err}"))
+                .into()
+        })
+    }),
+    ("protocol", quirks::protocol, |url, value| {
+        let _ = quirks::set_protocol(url, value);
+        Ok(())
+    }),
+    ("username", quirks::username, |url, value| {
+        let _ = quirks::set_username(url, value);
+        Ok(())
+    }),
+    ("password", quirks::password, |url, value| {
+        let _ = quirks::set_password(url, value);
+        Ok(())
+    }),
+    ("host", quirks::host, |url, value| {
+        let _ = quirks::set_host(url, value);
+        Ok(())
+    }),
+    ("hostname", quirks::hostname, |url, value| {
+        let _ = quirks::set_hostname(url, value);
+        Ok(())
+    }),
+    ("port", quirks::port, |url, value| {
+        let _ = quirks::set_port(url, value);
+        Ok(())
+    }),
+    ("pathname", quirks::pathname, |url, value| {
+        quirks::set_pathname(url, value);
+        Ok(())
+    }),
+    ("search", quirks::search, |url, value| {
+        quirks::set_search(url, value);
+        Ok(())
+    }),
+    ("hash", quirks::hash, |url, value| {
+        quirks::set_hash(url, value);
+        Ok(())
+    }),
+];
+
+/// Converts a value to a Web IDL `USVString`, replacing the lone surrogates with U+FFFD.
+fn to_usv_string(value: &JsValue, context: &mut Context) -> JsResult<String> {
+    Ok(String::from_utf16_lossy(
+        &value.to_string(context)?.to_vec(),
+    ))
+}
+
+/// The `URL` class.
+///
+/// The `searchParams` object of every URL is linked to it: changing the search parameters updates
+/// the query of the URL, and changing the URL updates the list of search parameters.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct Url {
+    #[unsafe_ignore_trace]
+    url: ::url::Url,
+    /// The `URLSearchParams` object returned by `searchParams`.
+    search_params: Option<JsObject>,
+}
+
+impl Url {
+    /// Registers the `URL` and `URLSearchParams` classes in the global object of the context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the classes cannot be defined in the global object.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()?;
+        context.register_global_class::<UrlSearchParams>()?;
+        Ok(())
+    }
+
+    /// Gets the parsed URL.
+    #[must_use]
+    pub const fn url(&self) -> &::url::Url {
+        &self.url
+    }
+
+    /// The [API URL parser][spec], returning `None` if the URL or its base is invalid.
+    ///
+    /// [spec]: https://url.spec.whatwg.org/#api-url-parser
+    fn parse(url: &JsValue, base: &JsValue, context: &mut Context) -> JsResult<Option<::url::Url>> {
+        let url = to_usv_string(url, context)?;
+
+        // 1. Let parsedBase be null.
+        // 2. If base is non-null:
+        let base = if base.is_undefined() {
+            None
+        } else {
+            // a. Set parsedBase to the result of running the basic URL parser on base.
+            // b. If parsedBase is failure, then return failure.
+            match ::url::Url::parse(&to_usv_string(base, context)?) {
+                Ok(base) => Some(base),
+                Err(_) => return Ok(None),
+            }
+        };
+
+        // 3. Return the result of running the basic URL parser on url with parsedBase.
+        Ok(::url::Url::options()
+            .base_url(base.as_ref())
+            .parse(&url)
+            .ok())
+    }
+
+    /// Gets the `URL` object of `this`.
+    fn this_url(this: &JsValue) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("URL method called on incompatible receiver")
+                    .into()
+            })
+    }
+
+    /// Creates the getter and setter of a component of the URL.
+    fn component(class: &mut ClassBuilder<'_>, name: &'static str, getter: Getter, setter: Setter) {
+        let get = NativeFunction::from_copy_closure(move |this, _, _| {
+            let object = Self::this_url(this)?;
+            let url = object.downcast_ref::<Self>().expect("checked to be a URL");
+            Ok(JsString::from(getter(&url.url)).into())
+        });
+        let set = NativeFunction::from_copy_closure(move |this, args, context| {
+            let object = Self::this_url(this)?;
+            let value = to_usv_string(args.get_or_undefined(0), context)?;
+
+            let search_params = {
+                let mut url = object.downcast_mut::<Self>().expect("checked to be a URL");
+                let query = url.url.query().map(str::to_owned);
+                setter(&mut url.url, &value)?;
+
+                // Update the list of the search parameters if the setter changed the query.
+                (url.url.query() != query.as_deref())
+                    .then(|| url.search_params.clone())
+                    .flatten()
+                    .map(|params| (params, url.url.query_pairs().into_owned().collect()))
+            };
+            if let Some((params, list)) = search_params {
+                if let Some(mut params) = params.downcast_mut::<UrlSearchParams>() {
+                    params.set_list(list);
+                }
+            }
+
+            Ok(JsValue::undefined())
+        });
+
+        let realm = class.context().realm().clone();
+        let get = FunctionObjectBuilder::new(&realm, get)
+            .name(format!("get {name}"))
+            .build();
+        let set = FunctionObjectBuilder::new(&realm, set)
+            .name(format!("set {name}"))
+            .length(1)
+            .build();
+        class.accessor(
+            js_string!(name),
+            Some(get),
+            Some(set),
+            Attribute::CONFIGURABLE | Attribute::ENUMERABLE,
+        );
+    }
+
+    /// `URL.prototype.toString()` and `URL.prototype.toJSON()`
+    fn href(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let object = Self::this_url(this)?;
+        let url = object.downcast_ref::<Self>().expect("checked to be a URL");
+        Ok(JsString::from(url.url.as_str()).into())
+    }
+
+    /// `URL.canParse(url, base)`
+    fn can_parse(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(
+            Self::parse(args.get_or_undefined(0), args.get_or_undefined(1), context)?
+                .is_some()
+                .into(),
+        )
+    }
+}
+
+impl Class for Url {
+    const NAME: &'static str = "URL";
+    const LENGTH: usize = 1;
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        for (name, getter, setter) in COMPONENTS {
+            Self::component(class, name, getter, setter);
+        }
+
+        let realm = class.context().realm().clone();
+        let origin = FunctionObjectBuilder::new(
+            &realm,
+            NativeFunction::from_fn_ptr(|this, _, _| {
+                let object = Self::this_url(this)?;
+                let url = object.downcast_ref::<Self>().expect("checked to be a URL");
+                Ok(JsString::from(quirks::origin(&url.url)).into())
+            }),
+        )
+        .name("get origin")
+        .build();
+
+        class
+            .accessor(
+                js_string!("origin"),
+                Some(origin),
+                None,
+                Attribute::CONFIGURABLE | Attribute::ENUMERABLE,
+            )
+            .data_getter(js_string!("searchParams"), |url: &Self| {
+                url.search_params
+                    .clone()
+                    .map_or_else(JsValue::undefined, JsValue::from)
+            })
+            .method(
+                js_string!("toString"),
+                0,
+                NativeFunction::from_fn_ptr(Self::href),
+            )
+            .method(
+                js_string!("toJSON"),
+                0,
+                NativeFunction::from_fn_ptr(Self::href),
+            )
+            .static_method(
+                js_string!("canParse"),
+                1,
+                NativeFunction::from_fn_ptr(Self::can_parse),
+            );
+
+        Ok(())
+    }
+
+    fn data_constructor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        let url = Self::parse(args.get_or_undefined(0), args.get_or_undefined(1), context)?
+            .ok_or_else(|| JsNativeError::typ().with_message("URL: invalid URL"))?;
+
+        Ok(Self {
+            url,
+            search_params: None,
+        })
+    }
+
+    fn object_constructor(
+        instance: &JsObject,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let list = instance
+            .downcast_ref::<Self>()
+            .expect("constructed object must be a URL")
+            .url
+            .query_pairs()
+            .into_owned()
+            .collect();
+
+        let search_params =
+            UrlSearchParams::from_data(UrlSearchParams::linked(list, instance.clone()), context)?;
+
+        instance
+            .downcast_mut::<Self>()
+            .expect("constructed object must be a URL")
+            .search_params = Some(search_params);
+
+        Ok(())
+    }
+}
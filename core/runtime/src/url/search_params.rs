@@ -0,0 +1,510 @@
+//! Boa's implementation of the `URLSearchParams` Web API.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `URLSearchParams` specification][spec]
+//!
+//! [spec]: https://url.spec.whatwg.org/#interface-urlsearchparams
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams
+
+use super::{to_usv_string, Url};
+use ::url::form_urlencoded;
+use boa_engine::{
+    builtins::iterable::create_iter_result_object,
+    class::{Class, ClassBuilder},
+    js_string,
+    native_function::NativeFunction,
+    object::{builtins::JsArray, FunctionObjectBuilder, JsObject, ObjectInitializer},
+    property::{Attribute, PropertyNameKind},
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsSymbol, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+/// The `URLSearchParams` class.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct UrlSearchParams {
+    #[unsafe_ignore_trace]
+    list: Vec<(String, String)>,
+    /// The `URL` object whose query is updated when the list changes.
+    url: Option<JsObject>,
+}
+
+impl UrlSearchParams {
+    /// Creates the search parameters of the `URL` object `url`.
+    pub(super) const fn linked(list: Vec<(String, String)>, url: JsObject) -> Self {
+        Self {
+            list,
+            url: Some(url),
+        }
+    }
+
+    /// Replaces the list of search parameters, without updating the linked URL.
+    pub(super) fn set_list(&mut self, list: Vec<(String, String)>) {
+        self.list = list;
+    }
+
+    /// Gets the name-value pairs of the search parameters.
+    #[must_use]
+    pub fn list(&self) -> &[(String, String)] {
+        &self.list
+    }
+
+    /// Serializes the list as `application/x-www-form-urlencoded`.
+    fn serialize(&self) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(&self.list)
+            .finish()
+    }
+
+    /// The [update steps][spec], setting the query of the linked URL to the serialized list.
+    ///
+    /// [spec]: https://url.spec.whatwg.org/#concept-urlsearchparams-update
+    fn update(&self) {
+        // 1. If query's URL object is null, then return.
+        let Some(url) = &self.url else {
+            return;
+        };
+
+        // 2. Let serializedQuery be the serialization of query's list.
+        // 3. If serializedQuery is the empty string, then set serializedQuery to null.
+        let serialized = self.serialize();
+        let serialized = (!serialized.is_empty()).then_some(serialized.as_str());
+
+        // 4. Set query's URL object's URL's query to serializedQuery.
+        if let Some(mut url) = url.downcast_mut::<Url>() {
+            url.url.set_query(serialized);
+        }
+    }
+
+    /// `URLSearchParams.prototype.append(name, value)`
+    fn append(&mut self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let name = to_usv_string(args.get_or_undefined(0), context)?;
+        let value = to_usv_string(args.get_or_undefined(1), context)?;
+
+        self.list.push((name, value));
+        self.update();
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `URLSearchParams.prototype.delete(name, value)`
+    fn delete(&mut self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let name = to_usv_string(args.get_or_undefined(0), context)?;
+        let value = optional_value(args.get_or_undefined(1), context)?;
+
+        self.list
+            .retain(|(n, v)| *n != name || value.as_ref().is_some_and(|value| v != value));
+        self.update();
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `URLSearchParams.prototype.get(name)`
+    fn get(&mut self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let name = to_usv_string(args.get_or_undefined(0), context)?;
+
+        Ok(self
+            .list
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map_or_else(JsValue::null, |(_, v)| JsString::from(v.as_str()).into()))
+    }
+
+    /// `URLSearchParams.prototype.getAll(name)`
+    fn get_all(&mut self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let name = to_usv_string(args.get_or_undefined(0), context)?;
+
+        let values = self
+            .list
+            .iter()
+            .filter(|(n, _)| *n == name)
+            .map(|(_, v)| JsString::from(v.as_str()).into())
+            .collect::<Vec<_>>();
+
+        Ok(JsArray::from_iter(values, context).into())
+    }
+
+    /// `URLSearchParams.prototype.has(name, value)`
+    fn has(&mut self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let name = to_usv_string(args.get_or_undefined(0), context)?;
+        let value = optional_value(args.get_or_undefined(1), context)?;
+
+        Ok(self
+            .list
+            .iter()
+            .any(|(n, v)| *n == name && value.as_ref().map_or(true, |value| v == value))
+            .into())
+    }
+
+    /// `URLSearchParams.prototype.set(name, value)`
+    fn set(&mut self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let name = to_usv_string(args.get_or_undefined(0), context)?;
+        let value = to_usv_string(args.get_or_undefined(1), context)?;
+
+        // Set the value of the first pair with the name, and remove the others.
+        let mut value = Some(value);
+        self.list.retain_mut(|(n, v)| {
+            if *n != name {
+                return true;
+            }
+            match value.take() {
+                Some(value) => {
+                    *v = value;
+                    true
+                }
+                None => false,
+            }
+        });
+        if let Some(value) = value {
+            self.list.push((name, value));
+        }
+        self.update();
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `URLSearchParams.prototype.sort()`
+    #[allow(clippy::unnecessary_wraps)]
+    fn sort(&mut self, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // Names are compared by their UTF-16 code units, keeping the relative order of the pairs
+        // with the same name.
+        self.list
+            .sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+        self.update();
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `URLSearchParams.prototype.toString()`
+    #[allow(clippy::unnecessary_wraps)]
+    fn stringifier(&mut self, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Ok(JsString::from(self.serialize()).into())
+    }
+
+    /// `URLSearchParams.prototype.forEach(callback, thisArg)`
+    fn for_each(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let object = this_params(this)?;
+        let callback = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("URLSearchParams.prototype.forEach: callback is not a function")
+        })?;
+        let this_arg = args.get_or_undefined(1);
+
+        // The list is read again after every call, since the callback can modify it.
+        let mut index = 0;
+        loop {
+            let Some((name, value)) = object
+                .downcast_ref::<Self>()
+                .and_then(|params| params.list.get(index).cloned())
+            else {
+                return Ok(JsValue::undefined());
+            };
+            callback.call(
+                this_arg,
+                &[
+                    JsString::from(value).into(),
+                    JsString::from(name).into(),
+                    this.clone(),
+                ],
+                context,
+            )?;
+            index += 1;
+        }
+    }
+
+    /// `URLSearchParams.prototype.entries()`, `keys()` and `values()`
+    fn iterator(
+        this: &JsValue,
+        kind: PropertyNameKind,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let params = this_params(this)?;
+        let prototype = iterator_prototype(context);
+
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            SearchParamsIterator {
+                params,
+                index: 0,
+                kind,
+            },
+        )
+        .into())
+    }
+}
+
+impl Class for UrlSearchParams {
+    const NAME: &'static str = "URLSearchParams";
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        let realm = class.context().realm().clone();
+        let entries = FunctionObjectBuilder::new(
+            &realm,
+            NativeFunction::from_fn_ptr(|this, _, context| {
+                Self::iterator(this, PropertyNameKind::KeyAndValue, context)
+            }),
+        )
+        .name("entries")
+        .build();
+
+        class
+            .data_method(js_string!("append"), 2, Self::append)
+            .data_method(js_string!("delete"), 1, Self::delete)
+            .data_method(js_string!("get"), 1, Self::get)
+            .data_method(js_string!("getAll"), 1, Self::get_all)
+            .data_method(js_string!("has"), 1, Self::has)
+            .data_method(js_string!("set"), 2, Self::set)
+            .data_method(js_string!("sort"), 0, Self::sort)
+            .data_method(js_string!("toString"), 0, Self::stringifier)
+            .method(
+                js_string!("forEach"),
+                1,
+                NativeFunction::from_fn_ptr(Self::for_each),
+            )
+            .method(
+                js_string!("keys"),
+                0,
+                NativeFunction::from_fn_ptr(|this, _, context| {
+                    Self::iterator(this, PropertyNameKind::Key, context)
+                }),
+            )
+            .method(
+                js_string!("values"),
+                0,
+                NativeFunction::from_fn_ptr(|this, _, context| {
+                    Self::iterator(this, PropertyNameKind::Value, context)
+                }),
+            )
+            .property(
+                js_string!("entries"),
+                entries.clone(),
+                Attribute::WRITABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::iterator(),
+                entries,
+                Attribute::WRITABLE | Attribute::CONFIGURABLE,
+            )
+            .data_getter(js_string!("size"), |params: &Self| params.list.len())
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!(Self::NAME),
+                Attribute::CONFIGURABLE,
+            );
+
+        Ok(())
+    }
+
+    fn data_constructor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        let init = args.get_or_undefined(0);
+
+        let list = if let Some(object) = init.as_object() {
+            let method = object.get(JsSymbol::iterator(), context)?;
+            if method.is_null_or_undefined() {
+                record_to_list(object, context)?
+            } else {
+                sequence_to_list(object, &method, context)?
+            }
+        } else if init.is_undefined() {
+            Vec::new()
+        } else {
+            // If init starts with "?", remove the first code point from init.
+            let init = to_usv_string(init, context)?;
+            let init = init.strip_prefix('?').unwrap_or(&init);
+            form_urlencoded::parse(init.as_bytes())
+                .into_owned()
+                .collect()
+        };
+
+        Ok(Self { list, url: None })
+    }
+}
+
+/// Gets the `URLSearchParams` object of `this`.
+fn this_params(this: &JsValue) -> JsResult<JsObject> {
+    this.as_object()
+        .filter(|object| object.is::<UrlSearchParams>())
+        .cloned()
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("URLSearchParams method called on incompatible receiver")
+                .into()
+        })
+}
+
+/// Converts the optional `value` argument of `delete` and `has`.
+fn optional_value(value: &JsValue, context: &mut Context) -> JsResult<Option<String>> {
+    if value.is_undefined() {
+        Ok(None)
+    } else {
+        to_usv_string(value, context).map(Some)
+    }
+}
+
+/// Converts `init` to a Web IDL `sequence<sequence<USVString>>`, where every inner sequence must
+/// be a name-value pair.
+fn sequence_to_list(
+    init: &JsObject,
+    method: &JsValue,
+    context: &mut Context,
+) -> JsResult<Vec<(String, String)>> {
+    let pairs = iterable_to_list(&init.clone().into(), method, context)?;
+
+    let mut list = Vec::with_capacity(pairs.len());
+    for pair in pairs {
+        let pair = match pair.as_object() {
+            Some(object) => {
+                let method = object.get(JsSymbol::iterator(), context)?;
+                iterable_to_list(&pair, &method, context)?
+            }
+            None => Vec::new(),
+        };
+        let [name, value] = &pair[..] else {
+            return Err(JsNativeError::typ()
+                .with_message("URLSearchParams: every pair must have exactly two items")
+                .into());
+        };
+        list.push((
+            to_usv_string(name, context)?,
+            to_usv_string(value, context)?,
+        ));
+    }
+
+    Ok(list)
+}
+
+/// Converts `init` to a Web IDL `record<USVString, USVString>`.
+fn record_to_list(init: &JsObject, context: &mut Context) -> JsResult<Vec<(String, String)>> {
+    let keys = init.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+
+    let mut list: Vec<(String, String)> = Vec::with_capacity(keys.len());
+    for key in keys {
+        let name = to_usv_string(&key, context)?;
+        let value = init.get(key.to_property_key(context)?, context)?;
+        let value = to_usv_string(&value, context)?;
+
+        // Different keys can be equal after replacing their lone surrogates.
+        match list.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => *v = value,
+            None => list.push((name, value)),
+        }
+    }
+
+    Ok(list)
+}
+
+/// Collects the values of the iterator returned by calling `method` on `iterable`.
+fn iterable_to_list(
+    iterable: &JsValue,
+    method: &JsValue,
+    context: &mut Context,
+) -> JsResult<Vec<JsValue>> {
+    let not_iterable =
+        || JsNativeError::typ().with_message("URLSearchParams: value is not iterable");
+
+    let method = method.as_callable().ok_or_else(not_iterable)?;
+    let iterator = method.call(iterable, &[], context)?;
+    let iterator = iterator.as_object().ok_or_else(not_iterable)?;
+    let next = iterator.get(js_string!("next"), context)?;
+    let next = next.as_callable().ok_or_else(not_iterable)?;
+
+    let mut values = Vec::new();
+    loop {
+        let result = next.call(&iterator.clone().into(), &[], context)?;
+        let result = result
+            .as_object()
+            .ok_or_else(|| JsNativeError::typ().with_message("iterator result is not an object"))?;
+        if result.get(js_string!("done"), context)?.to_boolean() {
+            return Ok(values);
+        }
+        values.push(result.get(js_string!("value"), context)?);
+    }
+}
+
+/// An iterator over the pairs of a `URLSearchParams` object.
+///
+/// The pairs are read from the object on every step, so the iterator sees the changes made to
+/// the list while iterating.
+#[derive(Debug, Trace, Finalize, JsData)]
+struct SearchParamsIterator {
+    params: JsObject,
+    index: usize,
+    #[unsafe_ignore_trace]
+    kind: PropertyNameKind,
+}
+
+impl SearchParamsIterator {
+    /// `%URLSearchParamsIteratorPrototype%.next()`
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let object = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("`this` is not a URLSearchParams iterator")
+        })?;
+        let (pair, kind) = {
+            let mut iterator = object.downcast_mut::<Self>().ok_or_else(|| {
+                JsNativeError::typ().with_message("`this` is not a URLSearchParams iterator")
+            })?;
+            let pair = iterator
+                .params
+                .downcast_ref::<UrlSearchParams>()
+                .and_then(|params| params.list.get(iterator.index).cloned());
+            if pair.is_some() {
+                iterator.index += 1;
+            }
+            (pair, iterator.kind)
+        };
+
+        let Some((name, value)) = pair else {
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        };
+        let name = JsValue::from(JsString::from(name));
+        let value = JsValue::from(JsString::from(value));
+        let result = match kind {
+            PropertyNameKind::Key => name,
+            PropertyNameKind::Value => value,
+            PropertyNameKind::KeyAndValue => JsArray::from_iter([name, value], context).into(),
+        };
+
+        Ok(create_iter_result_object(result, false, context))
+    }
+}
+
+/// The prototype of the `URLSearchParams` iterators of a realm.
+#[derive(Trace, Finalize, JsData)]
+struct IteratorPrototype(JsObject);
+
+/// Gets `%URLSearchParamsIteratorPrototype%`, creating it the first time it is needed.
+fn iterator_prototype(context: &mut Context) -> JsObject {
+    let realm = context.realm().clone();
+    if let Some(IteratorPrototype(prototype)) = realm.host_defined().get::<IteratorPrototype>() {
+        return prototype.clone();
+    }
+
+    let prototype = ObjectInitializer::new(context)
+        .function(
+            NativeFunction::from_fn_ptr(SearchParamsIterator::next),
+            js_string!("next"),
+            0,
+        )
+        .property(
+            JsSymbol::to_string_tag(),
+            js_string!("URLSearchParams Iterator"),
+            Attribute::CONFIGURABLE,
+        )
+        .build();
+    prototype.set_prototype(Some(
+        realm
+            .intrinsics()
+            .objects()
+            .iterator_prototypes()
+            .iterator(),
+    ));
+
+    realm
+        .host_defined_mut()
+        .insert(IteratorPrototype(prototype.clone()));
+    prototype
+}
@@ -0,0 +1,295 @@
+use super::Url;
+use crate::test::{run_test_actions_with, TestAction};
+use boa_engine::{js_string, Context};
+use indoc::indoc;
+
+fn context() -> Context {
+    let mut context = Context::default();
+    Url::register(&mut context).unwrap();
+    context
+}
+
+#[test]
+fn wpt_parsing() {
+    run_test_actions_with(
+        [
+            TestAction::run(format!(
+                "var tests = {};",
+                include_str!("fixtures/urltestdata.json")
+            )),
+            TestAction::run(indoc! {r#"
+                var failures = [];
+                for (const test of tests) {
+                    if (typeof test === 'string') {
+                        continue;
+                    }
+                    const name = `${JSON.stringify(test.input)} against ${JSON.stringify(test.base)}`;
+                    const base = test.base ?? undefined;
+                    if (test.failure) {
+                        if (URL.canParse(test.input, base)) {
+                            failures.push(`${name}: expected failure`);
+                        }
+                        try {
+                            new URL(test.input, base);
+                            failures.push(`${name}: expected the constructor to throw`);
+                        } catch (e) {
+                            if (!(e instanceof TypeError)) {
+                                failures.push(`${name}: expected a TypeError`);
+                            }
+                        }
+                        continue;
+                    }
+                    const url = new URL(test.input, base);
+                    for (const key of ['href', 'origin', 'protocol', 'username', 'password',
+                                       'host', 'hostname', 'port', 'pathname', 'search', 'hash']) {
+                        if (url[key] !== test[key]) {
+                            failures.push(
+                                `${name}: ${key} is ${JSON.stringify(url[key])}, expected ${JSON.stringify(test[key])}`
+                            );
+                        }
+                    }
+                }
+            "#}),
+            TestAction::assert_eq("failures.join('\\n')", js_string!()),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn wpt_setters() {
+    run_test_actions_with(
+        [
+            TestAction::run(format!(
+                "var tests = {};",
+                include_str!("fixtures/setters_tests.json")
+            )),
+            TestAction::run(indoc! {r#"
+                var failures = [];
+                for (const [setter, cases] of Object.entries(tests)) {
+                    if (setter === 'comment') {
+                        continue;
+                    }
+                    for (const test of cases) {
+                        const url = new URL(test.href);
+                        url[setter] = test.new_value;
+                        for (const [key, expected] of Object.entries(test.expected)) {
+                            if (url[key] !== expected) {
+                                failures.push(
+                                    `setting ${setter} of ${test.href} to ${JSON.stringify(test.new_value)}: ` +
+                                    `${key} is ${JSON.stringify(url[key])}, expected ${JSON.stringify(expected)}`
+                                );
+                            }
+                        }
+                    }
+                }
+            "#}),
+            TestAction::assert_eq("failures.join('\\n')", js_string!()),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn href_setter_rejects_invalid_urls() {
+    run_test_actions_with(
+        [
+            TestAction::run("var url = new URL('https://example.net/path?a=1');"),
+            TestAction::assert(indoc! {r#"
+                try {
+                    url.href = 'not a url';
+                    false;
+                } catch (e) {
+                    e instanceof TypeError;
+                }
+            "#}),
+            TestAction::assert_eq("url.href", js_string!("https://example.net/path?a=1")),
+            TestAction::run("url.href = 'http://example.com/?b=2&b=3';"),
+            TestAction::assert_eq("url.searchParams.getAll('b').join()", js_string!("2,3")),
+            TestAction::assert_eq("url.searchParams.has('a')", false),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn url_methods() {
+    run_test_actions_with(
+        [
+            TestAction::run("var url = new URL('/a?b#c', 'https://example.net');"),
+            TestAction::assert_eq("url.toString()", js_string!("https://example.net/a?b#c")),
+            TestAction::assert_eq(
+                "JSON.stringify({ url })",
+                js_string!(r#"{"url":"https://example.net/a?b#c"}"#),
+            ),
+            TestAction::assert_eq("String(url)", js_string!("https://example.net/a?b#c")),
+            TestAction::assert_eq("URL.canParse('/a', 'https://example.net')", true),
+            TestAction::assert_eq("URL.canParse('/a')", false),
+            TestAction::assert_eq("URL.canParse('/a', 'not a base')", false),
+            TestAction::assert("url.searchParams === url.searchParams"),
+            TestAction::assert(indoc! {r#"
+                try {
+                    URL('https://example.net');
+                    false;
+                } catch (e) {
+                    e instanceof TypeError;
+                }
+            "#}),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn search_params_constructor() {
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                function entries(params) {
+                    return [...params].map(([name, value]) => `${name}=${value}`).join();
+                }
+            "#}),
+            TestAction::assert_eq(
+                "entries(new URLSearchParams('?a=1&b=x+y&c=%26&a=2&&d'))",
+                js_string!("a=1,b=x y,c=&,a=2,d="),
+            ),
+            TestAction::assert_eq(
+                "entries(new URLSearchParams([['a', 1], new Set(['b', 2])]))",
+                js_string!("a=1,b=2"),
+            ),
+            TestAction::assert_eq(
+                "entries(new URLSearchParams(Object.defineProperty({ a: 1, b: 'two' }, 'c', { value: 3 })))",
+                js_string!("a=1,b=two"),
+            ),
+            TestAction::assert_eq(
+                "entries(new URLSearchParams(new URLSearchParams('a=1&a=2')))",
+                js_string!("a=1,a=2"),
+            ),
+            TestAction::assert_eq("entries(new URLSearchParams())", js_string!()),
+            TestAction::assert(indoc! {r#"
+                try {
+                    new URLSearchParams([['a', 1, 2]]);
+                    false;
+                } catch (e) {
+                    e instanceof TypeError;
+                }
+            "#}),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn search_params_multiple_values() {
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                var params = new URLSearchParams('a=1&b=2&a=3');
+                params.append('c', 'x y');
+            "#}),
+            TestAction::assert_eq("params.get('a')", js_string!("1")),
+            TestAction::assert_eq("params.get('d')", boa_engine::JsValue::null()),
+            TestAction::assert_eq("params.getAll('a').join()", js_string!("1,3")),
+            TestAction::assert_eq("params.has('b')", true),
+            TestAction::assert_eq("params.has('a', '3')", true),
+            TestAction::assert_eq("params.has('a', '2')", false),
+            TestAction::assert_eq("params.size", 4),
+            TestAction::assert_eq("params.toString()", js_string!("a=1&b=2&a=3&c=x+y")),
+            TestAction::run("params.set('a', 'z');"),
+            TestAction::assert_eq("params.toString()", js_string!("a=z&b=2&c=x+y")),
+            TestAction::run("params.append('b', '4'); params.delete('b', '2');"),
+            TestAction::assert_eq("params.toString()", js_string!("a=z&c=x+y&b=4")),
+            TestAction::run("params.delete('a');"),
+            TestAction::assert_eq("params.toString()", js_string!("c=x+y&b=4")),
+            TestAction::run("params.set('é', '&=');"),
+            TestAction::assert_eq("params.toString()", js_string!("c=x+y&b=4&%C3%A9=%26%3D")),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn search_params_sort_and_iteration() {
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                var params = new URLSearchParams('z=1&a=2&\u{FFFD}=3&\u{1F600}=4&a=1&z=0');
+                params.sort();
+            "#}),
+            // Names are sorted by code units, so the surrogate pair sorts before U+FFFD.
+            TestAction::assert_eq(
+                "[...params.keys()].join()",
+                js_string!("a,a,z,z,\u{1F600},\u{FFFD}"),
+            ),
+            TestAction::assert_eq("[...params.values()].join()", js_string!("2,1,1,0,4,3")),
+            TestAction::assert_eq(
+                "[...params.entries()].map(entry => entry.join(':')).join()",
+                js_string!("a:2,a:1,z:1,z:0,\u{1F600}:4,\u{FFFD}:3"),
+            ),
+            TestAction::assert(
+                "URLSearchParams.prototype[Symbol.iterator] === URLSearchParams.prototype.entries",
+            ),
+            TestAction::assert_eq(
+                "Object.prototype.toString.call(params.keys())",
+                js_string!("[object URLSearchParams Iterator]"),
+            ),
+            TestAction::assert(indoc! {r#"
+                const iterator = params.keys();
+                Object.getPrototypeOf(Object.getPrototypeOf(iterator)) ===
+                    Object.getPrototypeOf(Object.getPrototypeOf([].keys()))
+            "#}),
+            // Iterators and forEach see the changes made while iterating.
+            TestAction::run(indoc! {r#"
+                var seen = [];
+                var live = new URLSearchParams('a=1&b=2');
+                for (const [name] of live) {
+                    seen.push(name);
+                    if (name === 'a') {
+                        live.append('c', '3');
+                    }
+                }
+                live.forEach(function (value, name, object) {
+                    seen.push(`${name}=${value}:${this.tag}:${object === live}`);
+                    if (name === 'b') {
+                        live.delete('c');
+                    }
+                }, { tag: 'this' });
+            "#}),
+            TestAction::assert_eq(
+                "seen.join()",
+                js_string!("a,b,c,a=1:this:true,b=2:this:true"),
+            ),
+        ],
+        &mut context(),
+    );
+}
+
+#[test]
+fn search_params_linked_to_url() {
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                var url = new URL('https://example.net/path?a=1#frag');
+                var params = url.searchParams;
+                params.append('b', 'x y');
+            "#}),
+            TestAction::assert_eq(
+                "url.href",
+                js_string!("https://example.net/path?a=1&b=x+y#frag"),
+            ),
+            TestAction::run("params.delete('a'); params.delete('b');"),
+            TestAction::assert_eq("url.href", js_string!("https://example.net/path#frag")),
+            TestAction::run("url.search = '?c=3&d=4';"),
+            TestAction::assert_eq("params.toString()", js_string!("c=3&d=4")),
+            TestAction::run("url.hash = 'other'; params.sort();"),
+            TestAction::assert_eq(
+                "url.href",
+                js_string!("https://example.net/path?c=3&d=4#other"),
+            ),
+            TestAction::run("url.search = '';"),
+            TestAction::assert_eq("params.size", 0),
+            TestAction::assert("url.searchParams === params"),
+        ],
+        &mut context(),
+    );
+}
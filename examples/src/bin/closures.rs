@@ -85,11 +85,11 @@ fn main() -> Result<(), JsError> {
                     utf16!("message from `"),
                     &name.to_string(context)?,
                     utf16!("`: "),
-                    greeting
+                    &*greeting
                 );
 
                 // We can also mutate the moved data inside the closure.
-                captures.greeting = js_string!(greeting, utf16!(" Hello!"));
+                captures.greeting = js_string!(&*greeting, utf16!(" Hello!"));
 
                 println!("{}", message.to_std_string_escaped());
                 println!();
@@ -0,0 +1,32 @@
+#![allow(unused)]
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+};
+
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use indexmap::{IndexMap, IndexSet};
+use smallvec::SmallVec;
+
+#[derive(Trace, Finalize)]
+struct HostData {
+    map: HashMap<String, Gc<u32>>,
+    tree: BTreeMap<u32, Gc<u32>>,
+    index_map: IndexMap<String, Gc<u32>>,
+    index_set: IndexSet<u32>,
+    small: SmallVec<[Gc<u32>; 4]>,
+    array: Option<[Gc<u32>; 2]>,
+    shared: Gc<GcRefCell<Vec<u32>>>,
+    #[unsafe_ignore_trace]
+    host: Rc<RefCell<Vec<u32>>>,
+}
+
+#[derive(Trace, Finalize)]
+enum HostValue {
+    Map(IndexMap<u32, Gc<u32>>),
+    Small(SmallVec<[u32; 8]>),
+}
+
+fn main() {}
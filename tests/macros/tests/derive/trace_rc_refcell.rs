@@ -0,0 +1,10 @@
+use std::{cell::RefCell, rc::Rc};
+
+use boa_gc::{Finalize, Trace};
+
+#[derive(Trace, Finalize)]
+struct HostData {
+    values: Rc<RefCell<Vec<u32>>>,
+}
+
+fn main() {}
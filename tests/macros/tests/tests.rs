@@ -6,3 +6,10 @@ fn try_from_js() {
     t.pass("tests/derive/simple_struct.rs");
     t.pass("tests/derive/from_js_with.rs");
 }
+
+#[test]
+fn trace() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/derive/trace_containers.rs");
+    t.compile_fail("tests/derive/trace_rc_refcell.rs");
+}
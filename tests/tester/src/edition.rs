@@ -41,10 +41,6 @@ static FEATURE_EDITION: phf::Map<&'static str, SpecEdition> = phf::phf_map! {
     // https://github.com/tc39/proposal-json-modules
     "json-modules"  => SpecEdition::ESNext,
 
-    // ArrayBuffer transfer
-    // https://github.com/tc39/proposal-arraybuffer-transfer
-    "arraybuffer-transfer" => SpecEdition::ESNext,
-
     // Temporal
     // https://github.com/tc39/proposal-temporal
     "Temporal" => SpecEdition::ESNext,
@@ -87,6 +83,7 @@ static FEATURE_EDITION: phf::Map<&'static str, SpecEdition> = phf::phf_map! {
     "String.prototype.isWellFormed" => SpecEdition::ESNext,
     "String.prototype.toWellFormed" => SpecEdition::ESNext,
     "resizable-arraybuffer" => SpecEdition::ESNext,
+    "arraybuffer-transfer" => SpecEdition::ESNext,
     "promise-with-resolvers" => SpecEdition::ESNext,
     "array-grouping" => SpecEdition::ESNext,
 
@@ -26,6 +26,7 @@ use self::js262::WorkerHandles;
 
 impl TestSuite {
     /// Runs the test suite.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn run(
         &self,
         harness: &Harness,
@@ -34,6 +35,7 @@ impl TestSuite {
         max_edition: SpecEdition,
         optimizer_options: OptimizerOptions,
         console: bool,
+        detect_flaky: bool,
     ) -> SuiteResult {
         if verbose != 0 {
             println!("Suite {}:", self.path.display());
@@ -50,6 +52,7 @@ impl TestSuite {
                         max_edition,
                         optimizer_options,
                         console,
+                        detect_flaky,
                     )
                 })
                 .collect()
@@ -64,6 +67,7 @@ impl TestSuite {
                         max_edition,
                         optimizer_options,
                         console,
+                        detect_flaky,
                     )
                 })
                 .collect()
@@ -73,13 +77,17 @@ impl TestSuite {
             self.tests
                 .par_iter()
                 .filter(|test| test.edition <= max_edition)
-                .flat_map(|test| test.run(harness, verbose, optimizer_options, console))
+                .flat_map(|test| {
+                    test.run(harness, verbose, optimizer_options, console, detect_flaky)
+                })
                 .collect()
         } else {
             self.tests
                 .iter()
                 .filter(|test| test.edition <= max_edition)
-                .flat_map(|test| test.run(harness, verbose, optimizer_options, console))
+                .flat_map(|test| {
+                    test.run(harness, verbose, optimizer_options, console, detect_flaky)
+                })
                 .collect()
         };
 
@@ -162,29 +170,82 @@ impl TestSuite {
 
 impl Test {
     /// Runs the test.
+    ///
+    /// If `detect_flaky` is `true`, failed runs are retried once, and the test is marked as flaky
+    /// if the retry passes.
     pub(crate) fn run(
         &self,
         harness: &Harness,
         verbose: u8,
         optimizer_options: OptimizerOptions,
         console: bool,
+        detect_flaky: bool,
     ) -> Vec<TestResult> {
+        let run = |strict| {
+            let mut result = self.run_once(harness, strict, verbose, optimizer_options, console);
+            if detect_flaky
+                && matches!(
+                    result.result,
+                    TestOutcomeResult::Failed | TestOutcomeResult::Panic
+                )
+            {
+                let retry = self.run_once(harness, strict, verbose, optimizer_options, console);
+                result.flaky = retry.result == TestOutcomeResult::Passed;
+            }
+            self.report(&result, verbose);
+            result
+        };
+
         let mut results = Vec::new();
         if self.flags.contains(TestFlags::MODULE) {
-            results.push(self.run_once(harness, false, verbose, optimizer_options, console));
+            results.push(run(false));
         } else {
             if self.flags.contains(TestFlags::STRICT) && !self.flags.contains(TestFlags::RAW) {
-                results.push(self.run_once(harness, true, verbose, optimizer_options, console));
+                results.push(run(true));
             }
 
             if self.flags.contains(TestFlags::NO_STRICT) || self.flags.contains(TestFlags::RAW) {
-                results.push(self.run_once(harness, false, verbose, optimizer_options, console));
+                results.push(run(false));
             }
         }
 
         results
     }
 
+    /// Prints the outcome of a run of the test.
+    fn report(&self, result: &TestResult, verbose: u8) {
+        let strict = if result.strict { " (strict mode)" } else { "" };
+        if verbose > 1 {
+            println!(
+                "`{}`{strict}: {}",
+                self.path.display(),
+                match result.result {
+                    _ if result.flaky => "Flaky".yellow(),
+                    TestOutcomeResult::Passed => "Passed".green(),
+                    TestOutcomeResult::Ignored => "Ignored".yellow(),
+                    TestOutcomeResult::Failed => "Failed".red(),
+                    TestOutcomeResult::Panic => "⚠ Panic ⚠".red(),
+                }
+            );
+        } else {
+            print!(
+                "{}",
+                match result.result {
+                    _ if result.flaky => "F".yellow(),
+                    TestOutcomeResult::Passed => ".".green(),
+                    TestOutcomeResult::Ignored => "-".yellow(),
+                    TestOutcomeResult::Failed | TestOutcomeResult::Panic => "F".red(),
+                }
+            );
+        }
+
+        if verbose > 2 && result.result != TestOutcomeResult::Ignored {
+            println!("`{}`{strict}: result text", self.path.display());
+            println!("{}", result.result_text);
+            println!();
+        }
+    }
+
     /// Runs the test once, in strict or non-strict mode
     fn run_once(
         &self,
@@ -195,42 +256,14 @@ impl Test {
         console: bool,
     ) -> TestResult {
         let Ok(source) = Source::from_filepath(&self.path) else {
-            if verbose > 1 {
-                println!(
-                    "`{}`{}: {}",
-                    self.path.display(),
-                    if strict { " (strict mode)" } else { "" },
-                    "Invalid file".red()
-                );
-            } else {
-                print!("{}", "F".red());
-            }
-            return TestResult {
-                name: self.name.clone(),
-                edition: self.edition,
+            return self.result(
                 strict,
-                result: TestOutcomeResult::Failed,
-                result_text: Box::from("Could not read test file."),
-            };
+                TestOutcomeResult::Failed,
+                Box::from("Could not read test file."),
+            );
         };
         if self.ignored {
-            if verbose > 1 {
-                println!(
-                    "`{}`{}: {}",
-                    self.path.display(),
-                    if strict { " (strict mode)" } else { "" },
-                    "Ignored".yellow()
-                );
-            } else {
-                print!("{}", "-".yellow());
-            }
-            return TestResult {
-                name: self.name.clone(),
-                edition: self.edition,
-                strict,
-                result: TestOutcomeResult::Ignored,
-                result_text: Box::default(),
-            };
+            return self.result(strict, TestOutcomeResult::Ignored, Box::default());
         }
         if verbose > 1 {
             println!(
@@ -542,46 +575,19 @@ impl Test {
             },
         );
 
-        if verbose > 1 {
-            println!(
-                "`{}`{}: {}",
-                self.path.display(),
-                if strict { " (strict mode)" } else { "" },
-                if result == TestOutcomeResult::Passed {
-                    "Passed".green()
-                } else if result == TestOutcomeResult::Failed {
-                    "Failed".red()
-                } else {
-                    "⚠ Panic ⚠".red()
-                }
-            );
-        } else {
-            print!(
-                "{}",
-                if result == TestOutcomeResult::Passed {
-                    ".".green()
-                } else {
-                    "F".red()
-                }
-            );
-        }
-
-        if verbose > 2 {
-            println!(
-                "`{}`{}: result text",
-                self.path.display(),
-                if strict { " (strict mode)" } else { "" },
-            );
-            println!("{result_text}");
-            println!();
-        }
+        self.result(strict, result, result_text.into_boxed_str())
+    }
 
+    /// Creates the result of a run of the test.
+    fn result(&self, strict: bool, result: TestOutcomeResult, result_text: Box<str>) -> TestResult {
         TestResult {
             name: self.name.clone(),
             edition: self.edition,
             strict,
+            result_text,
+            features: self.features.iter().cloned().collect(),
             result,
-            result_text: result_text.into_boxed_str(),
+            flaky: false,
         }
     }
 
@@ -21,7 +21,7 @@ mod results;
 
 use self::{
     read::{read_harness, read_suite, read_test, MetaData, Negative, TestFlag},
-    results::{compare_results, write_json},
+    results::{compare_results, print_diff, read_baseline, write_json},
 };
 use bitflags::bitflags;
 use boa_engine::optimizer::OptimizerOptions;
@@ -171,6 +171,23 @@ enum Cli {
         /// Injects the `Console` object into every context created.
         #[arg(long)]
         console: bool,
+
+        /// Displays the conformance results per test262 feature.
+        #[arg(long)]
+        feature_stats: bool,
+
+        /// Runs failed tests a second time, reporting the ones that pass on the retry as flaky.
+        #[arg(long)]
+        detect_flaky: bool,
+
+        /// Previous results of the suite to compare against, as written to `latest.json` by
+        /// `--output`. Lists the tests that started failing or passing since then.
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        diff: Option<PathBuf>,
+
+        /// How to group the tests listed by `--diff`.
+        #[arg(long, value_enum, default_value_t = DiffGrouping::Directory, requires = "diff")]
+        group_by: DiffGrouping,
     },
     /// Compare two test suite results.
     Compare {
@@ -188,6 +205,26 @@ enum Cli {
     },
 }
 
+/// How to group the tests that changed between two runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum DiffGrouping {
+    /// Groups the tests by the directory containing them.
+    Directory,
+    /// Groups the tests by the test262 features they use.
+    Feature,
+}
+
+/// Additional reports produced from the results of a test run.
+#[derive(Debug, Clone, Copy)]
+struct Analysis<'a> {
+    /// Displays the conformance results per test262 feature.
+    feature_stats: bool,
+    /// Runs failed tests a second time, reporting the ones that pass on the retry as flaky.
+    detect_flaky: bool,
+    /// Previous results to compare against, and how to group the tests that changed.
+    diff: Option<(&'a Path, DiffGrouping)>,
+}
+
 const DEFAULT_TEST262_DIRECTORY: &str = "test262";
 
 /// Program entry point.
@@ -214,6 +251,10 @@ fn main() -> Result<()> {
             edition,
             versioned,
             console,
+            feature_stats,
+            detect_flaky,
+            diff,
+            group_by,
         } => {
             let config: Config = {
                 let input = std::fs::read_to_string(config_path)?;
@@ -248,6 +289,11 @@ fn main() -> Result<()> {
                     OptimizerOptions::empty()
                 },
                 console,
+                Analysis {
+                    feature_stats,
+                    detect_flaky,
+                    diff: diff.as_deref().map(|path| (path, group_by)),
+                },
             )
         }
         Cli::Compare {
@@ -407,7 +453,14 @@ fn run_test_suite(
     versioned: bool,
     optimizer_options: OptimizerOptions,
     console: bool,
+    analysis: Analysis<'_>,
 ) -> Result<()> {
+    let Analysis {
+        feature_stats,
+        detect_flaky,
+        diff,
+    } = analysis;
+
     if let Some(path) = output {
         if path.exists() {
             if !path.is_dir() {
@@ -424,6 +477,10 @@ fn run_test_suite(
     let harness = read_harness(test262_path).wrap_err("could not read harness")?;
 
     if suite.to_string_lossy().ends_with(".js") {
+        if diff.is_some() {
+            bail!("`--diff` can only be used when running a test suite");
+        }
+
         let test = read_test(&test262_path.join(suite)).wrap_err_with(|| {
             let suite = suite.display();
             format!("could not read the test {suite}")
@@ -433,7 +490,7 @@ fn run_test_suite(
             if verbose != 0 {
                 println!("Test loaded, starting...");
             }
-            test.run(&harness, verbose, optimizer_options, console);
+            test.run(&harness, verbose, optimizer_options, console, detect_flaky);
         } else {
             println!(
                 "Minimum spec edition of test is bigger than the specified edition. Skipping."
@@ -442,8 +499,14 @@ fn run_test_suite(
 
         println!();
     } else {
-        let suite =
-            read_suite(&test262_path.join(suite), config.ignored(), false).wrap_err_with(|| {
+        // Reads the baseline first, to not run the whole suite before finding it is invalid.
+        let baseline = match diff {
+            Some((baseline, grouping)) => Some((read_baseline(baseline, suite)?, grouping)),
+            None => None,
+        };
+
+        let test_suite = read_suite(&test262_path.join(suite), config.ignored(), false)
+            .wrap_err_with(|| {
                 let suite = suite.display();
                 format!("could not read the suite {suite}")
             })?;
@@ -451,50 +514,26 @@ fn run_test_suite(
         if verbose != 0 {
             println!("Test suite loaded, starting tests...");
         }
-        let results = suite.run(
+        let results = test_suite.run(
             &harness,
             verbose,
             parallel,
             edition,
             optimizer_options,
             console,
+            detect_flaky,
         );
 
         if versioned {
-            let mut table = comfy_table::Table::new();
-            table.load_preset(comfy_table::presets::UTF8_HORIZONTAL_ONLY);
-            table.set_header(vec![
-                "Edition", "Total", "Passed", "Ignored", "Failed", "Panics", "%",
-            ]);
-            for column in table.column_iter_mut().skip(1) {
-                column.set_cell_alignment(comfy_table::CellAlignment::Right);
-            }
-            for (v, stats) in SpecEdition::all_editions()
-                .filter(|v| *v <= edition)
-                .map(|v| {
-                    let stats = results.versioned_stats.get(v).unwrap_or(results.stats);
-                    (v, stats)
-                })
-            {
-                let Statistics {
-                    total,
-                    passed,
-                    ignored,
-                    panic,
-                } = stats;
-                let failed = total - passed - ignored;
-                let conformance = (passed as f64 / total as f64) * 100.0;
-                let conformance = format!("{conformance:.2}");
-                table.add_row(vec![
-                    v.to_string(),
-                    total.to_string(),
-                    passed.to_string(),
-                    ignored.to_string(),
-                    failed.to_string(),
-                    panic.to_string(),
-                    conformance,
-                ]);
-            }
+            let table = stats_table(
+                "Edition",
+                SpecEdition::all_editions()
+                    .filter(|v| *v <= edition)
+                    .map(|v| {
+                        let stats = results.versioned_stats.get(v).unwrap_or(results.stats);
+                        (v.to_string(), stats)
+                    }),
+            );
             println!("\n\nResults\n");
             println!("{table}");
         } else {
@@ -519,6 +558,33 @@ fn run_test_suite(
             );
         }
 
+        if feature_stats {
+            let table = stats_table(
+                "Feature",
+                results
+                    .feature_stats()
+                    .into_iter()
+                    .map(|(feature, stats)| (feature.to_owned(), stats)),
+            );
+            println!("\n\nResults per feature\n");
+            println!("{table}");
+        }
+
+        if detect_flaky {
+            let flaky = results.flaky_tests(suite);
+            if !flaky.is_empty() {
+                println!();
+                println!("Flaky tests ({}):", flaky.len());
+                for test in flaky {
+                    println!("{test}");
+                }
+            }
+        }
+
+        if let Some((baseline, grouping)) = baseline {
+            print_diff(&baseline, suite, &results, grouping);
+        }
+
         if let Some(output) = output {
             write_json(results, output, verbose, test262_path)
                 .wrap_err("could not write the results to the output JSON file")?;
@@ -528,6 +594,42 @@ fn run_test_suite(
     Ok(())
 }
 
+/// Creates a table with the given statistics, one row for each label.
+fn stats_table(
+    label: &str,
+    rows: impl IntoIterator<Item = (String, Statistics)>,
+) -> comfy_table::Table {
+    let mut table = comfy_table::Table::new();
+    table.load_preset(comfy_table::presets::UTF8_HORIZONTAL_ONLY);
+    table.set_header(vec![
+        label, "Total", "Passed", "Ignored", "Failed", "Panics", "%",
+    ]);
+    for column in table.column_iter_mut().skip(1) {
+        column.set_cell_alignment(comfy_table::CellAlignment::Right);
+    }
+    for (label, stats) in rows {
+        let Statistics {
+            total,
+            passed,
+            ignored,
+            panic,
+        } = stats;
+        let failed = total - passed - ignored;
+        let conformance = (passed as f64 / total as f64) * 100.0;
+        let conformance = format!("{conformance:.2}");
+        table.add_row(vec![
+            label,
+            total.to_string(),
+            passed.to_string(),
+            ignored.to_string(),
+            failed.to_string(),
+            panic.to_string(),
+            conformance,
+        ]);
+    }
+    table
+}
+
 /// All the harness include files.
 #[derive(Debug, Clone)]
 struct Harness {
@@ -769,8 +871,18 @@ struct TestResult {
     strict: bool,
     #[serde(skip)]
     result_text: Box<str>,
+    #[serde(skip)]
+    features: Box<[Box<str>]>,
     #[serde(rename = "r")]
     result: TestOutcomeResult,
+    /// Whether the test failed, but passed when running it again.
+    #[serde(rename = "k", default, skip_serializing_if = "is_false")]
+    flaky: bool,
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_false(value: &bool) -> bool {
+    !*value
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -1,18 +1,31 @@
-use crate::{Statistics, VersionedStats};
+use crate::{DiffGrouping, Statistics, TestOutcomeResult, TestResult, VersionedStats};
 
 use super::SuiteResult;
-use color_eyre::{eyre::WrapErr, Result};
-use rustc_hash::FxHashSet;
+use color_eyre::{
+    eyre::{bail, WrapErr},
+    Result,
+};
+use colored::Colorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     env, fs,
     io::{BufReader, BufWriter},
     path::Path,
 };
 
+/// Version of the format of the full results file.
+///
+/// Files without a version were written before it was introduced, and are read as version `0`.
+/// Version `1` added the flaky marker of the tests.
+const RESULTS_VERSION: u32 = 1;
+
 /// Structure to store full result information.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 struct ResultInfo {
+    #[serde(rename = "v", default)]
+    version: u32,
     #[serde(rename = "c")]
     commit: Box<str>,
     #[serde(rename = "u")]
@@ -112,6 +125,7 @@ pub(crate) fn write_json(
     let latest = output_dir.join(LATEST_FILE_NAME);
 
     let new_results = ResultInfo {
+        version: RESULTS_VERSION,
         commit: env::var("GITHUB_SHA").unwrap_or_default().into_boxed_str(),
         test262_commit: get_test262_commit(test262_path)?,
         results,
@@ -197,18 +211,37 @@ fn update_gh_pages_repo(path: &Path, verbose: u8) {
     }
 }
 
+/// Reads the full results of a test suite run, written by any version of the tester.
+fn read_results(path: &Path) -> Result<ResultInfo> {
+    /// The version of a results file, read before the rest of the file to know its format.
+    #[derive(Deserialize)]
+    struct Version {
+        #[serde(rename = "v", default)]
+        version: u32,
+    }
+
+    let input = fs::read_to_string(path).wrap_err("could not open the results file")?;
+    let Version { version } =
+        serde_json::from_str(&input).wrap_err("could not read the results version")?;
+
+    match version {
+        // Version 1 only added optional fields to version 0.
+        0 | 1 => serde_json::from_str(&input).wrap_err("could not read the results"),
+        _ => bail!(
+            "the results have version {version}, but this tester can only read versions up to \
+             {RESULTS_VERSION}"
+        ),
+    }
+}
+
 /// Compares the results of two test suite runs.
 #[allow(clippy::cast_possible_wrap)]
 pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) -> Result<()> {
-    let base_results: ResultInfo = serde_json::from_reader(BufReader::new(
-        fs::File::open(base).wrap_err("could not open the base results file")?,
-    ))
-    .wrap_err("could not read the base results")?;
+    let base_results = read_results(base)
+        .wrap_err_with(|| format!("invalid base results `{}`", base.display()))?;
 
-    let new_results: ResultInfo = serde_json::from_reader(BufReader::new(
-        fs::File::open(new).wrap_err("could not open the new results file")?,
-    ))
-    .wrap_err("could not read the new results")?;
+    let new_results =
+        read_results(new).wrap_err_with(|| format!("invalid new results `{}`", new.display()))?;
 
     let base_total = base_results.results.stats.total as isize;
     let new_total = new_results.results.stats.total as isize;
@@ -505,3 +538,174 @@ fn compute_result_diff(
 
     final_diff
 }
+
+impl SuiteResult {
+    /// Calls `f` with every test result of the suite and its sub-suites, along with the path of
+    /// the directory containing the test.
+    fn visit_tests<'a>(&'a self, directory: &Path, f: &mut impl FnMut(&Path, &'a TestResult)) {
+        for test in &self.tests {
+            f(directory, test);
+        }
+        for suite in &self.suites {
+            suite.visit_tests(&directory.join(suite.name.as_ref()), f);
+        }
+    }
+
+    /// Gets the statistics of the tests using each test262 feature, sorted by feature.
+    pub(crate) fn feature_stats(&self) -> BTreeMap<&str, Statistics> {
+        let mut features = BTreeMap::<&str, Statistics>::new();
+        self.visit_tests(Path::new(""), &mut |_, test| {
+            for feature in &*test.features {
+                let stats = features.entry(&**feature).or_default();
+                match test.result {
+                    TestOutcomeResult::Passed => stats.passed += 1,
+                    TestOutcomeResult::Ignored => stats.ignored += 1,
+                    TestOutcomeResult::Panic => stats.panic += 1,
+                    TestOutcomeResult::Failed => {}
+                }
+                stats.total += 1;
+            }
+        });
+        features
+    }
+
+    /// Gets the tests that failed but passed when retrying them, with their paths relative to
+    /// the `Test262` directory, given the path of this suite.
+    pub(crate) fn flaky_tests(&self, suite: &Path) -> Vec<String> {
+        let mut flaky = Vec::new();
+        self.visit_tests(suite, &mut |directory, test| {
+            if test.flaky {
+                flaky.push(test_path(directory, test));
+            }
+        });
+        flaky
+    }
+
+    /// Finds the results of the suite at `path`, relative to the `Test262` directory, in these
+    /// results, which can be the results of the suite or of one of its parents.
+    fn find(&self, path: &Path) -> Option<&Self> {
+        let components: Vec<_> = path.iter().collect();
+        components
+            .iter()
+            .enumerate()
+            .filter(|(_, component)| **component == self.name.as_ref())
+            .find_map(|(index, _)| {
+                components[index + 1..]
+                    .iter()
+                    .try_fold(self, |suite, name| {
+                        suite
+                            .suites
+                            .iter()
+                            .find(|suite| suite.name.as_ref() == *name)
+                    })
+            })
+    }
+}
+
+/// Formats the path of `test`, inside `directory`, adding whether it ran in strict mode.
+fn test_path(directory: &Path, test: &TestResult) -> String {
+    format!(
+        "{}{}",
+        directory.join(format!("{}.js", test.name)).display(),
+        if test.strict { " [strict mode]" } else { "" }
+    )
+}
+
+/// Reads the results of `suite` from the `baseline` results file, to compare them with a new run
+/// using [`print_diff`].
+///
+/// The baseline can be a run of `suite` or of any of its parent suites.
+pub(crate) fn read_baseline(baseline: &Path, suite: &Path) -> Result<SuiteResult> {
+    let base = read_results(baseline)
+        .wrap_err_with(|| format!("invalid baseline results `{}`", baseline.display()))?;
+    let Some(base_suite) = base.results.find(suite) else {
+        bail!(
+            "the baseline results `{}` don't contain the suite `{}`",
+            baseline.display(),
+            suite.display()
+        );
+    };
+    Ok(base_suite.clone())
+}
+
+/// Prints the tests of `results` that started failing or passing since the `base_suite` run,
+/// grouped by directory or feature.
+///
+/// Tests that are only present in one of the runs are not compared, and neither are the tests
+/// marked as flaky in any of them.
+pub(crate) fn print_diff(
+    base_suite: &SuiteResult,
+    suite: &Path,
+    results: &SuiteResult,
+    grouping: DiffGrouping,
+) {
+    let mut base_tests = FxHashMap::default();
+    base_suite.visit_tests(suite, &mut |directory, test| {
+        base_tests.insert((directory.to_path_buf(), &*test.name, test.strict), test);
+    });
+
+    let mut broken = BTreeMap::<String, Vec<String>>::new();
+    let mut fixed = BTreeMap::<String, Vec<String>>::new();
+    results.visit_tests(suite, &mut |directory, test| {
+        let key = (directory.to_path_buf(), &*test.name, test.strict);
+        let Some(base_test) = base_tests.get(&key) else {
+            return;
+        };
+        if test.flaky || base_test.flaky {
+            return;
+        }
+
+        let changes = match (base_test.result, test.result) {
+            (TestOutcomeResult::Passed, TestOutcomeResult::Failed | TestOutcomeResult::Panic) => {
+                &mut broken
+            }
+            (previous, TestOutcomeResult::Passed) if previous != TestOutcomeResult::Passed => {
+                &mut fixed
+            }
+            _ => return,
+        };
+        let entry = format!(
+            "{} (previously {:?}, now {:?})",
+            test_path(directory, test),
+            base_test.result,
+            test.result
+        );
+        for group in groups(directory, test, grouping) {
+            changes.entry(group).or_default().push(entry.clone());
+        }
+    });
+
+    println!();
+    if broken.is_empty() && fixed.is_empty() {
+        println!("No tests changed their result since the baseline");
+        return;
+    }
+    print_changes(&"Newly failing tests".red().to_string(), &broken);
+    print_changes(&"Newly passing tests".green().to_string(), &fixed);
+}
+
+/// Gets the groups of a test that changed its result.
+fn groups(directory: &Path, test: &TestResult, grouping: DiffGrouping) -> Vec<String> {
+    match grouping {
+        DiffGrouping::Directory => vec![directory.display().to_string()],
+        DiffGrouping::Feature if test.features.is_empty() => vec!["(no features)".to_owned()],
+        DiffGrouping::Feature => test.features.iter().map(ToString::to_string).collect(),
+    }
+}
+
+/// Prints the tests that changed their result in the same way, by group.
+fn print_changes(title: &str, changes: &BTreeMap<String, Vec<String>>) {
+    if changes.is_empty() {
+        return;
+    }
+
+    // A test is in more than one group if it uses several features.
+    let total: FxHashSet<_> = changes.values().flatten().collect();
+    println!("{title} ({}):", total.len());
+    for (group, tests) in changes {
+        println!("  {group} ({}):", tests.len());
+        for test in tests {
+            println!("    {test}");
+        }
+    }
+}